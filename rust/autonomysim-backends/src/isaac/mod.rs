@@ -10,11 +10,11 @@ use std::sync::{Arc, RwLock};
 
 use autonomysim_core::{
     backend::{
-        BackendConfig, BackendType, Material, Position, Ray, RayHit, SceneHandle, SceneObject,
-        SimResult, SimulationBackend, Transform,
+        BackendConfig, BackendType, Material, Position, Ray, RayHit, RfPath, SceneHandle,
+        SceneObject, SimResult, SimulationBackend, Transform,
     },
     sensor::SensorData,
-    vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState},
+    vehicle::{SensorFault, VehicleControl, VehicleId, VehicleSpec, VehicleState},
     SimError,
 };
 
@@ -294,6 +294,18 @@ impl SimulationBackend for IsaacLabBackend {
         rays.iter().map(|ray| self.cast_ray(scene, ray)).collect()
     }
 
+    fn trace_rf_paths(
+        &self,
+        _scene: &SceneHandle,
+        _tx_pos: Position,
+        _rx_pos: Position,
+        _frequency_hz: f64,
+        _max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>> {
+        // Multi-bounce RF path tracing is not implemented for Isaac Lab yet.
+        Ok(Vec::new())
+    }
+
     fn get_objects(&self, _scene: &SceneHandle) -> SimResult<Vec<SceneObject>> {
         // Placeholder
         Ok(Vec::new())
@@ -428,6 +440,17 @@ impl SimulationBackend for IsaacLabBackend {
         // Get sensor data through Python bridge
         bridge.get_sensor_data(&handle.prim_path, sensor_id)
     }
+
+    fn set_sensor_fault(
+        &mut self,
+        _vehicle_id: &str,
+        _sensor_id: &str,
+        _fault: Option<SensorFault>,
+    ) -> SimResult<()> {
+        Err(SimError::BackendError(
+            "Sensor fault injection not yet implemented for Isaac Lab".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -7,7 +7,10 @@ use std::collections::HashMap;
 
 use autonomysim_core::{
     backend::{Position, Transform, Vec3},
-    sensor::{BarometerData, GpsData, GpsFixType, ImuData, MagnetometerData, SensorData},
+    sensor::{
+        BarometerData, GpsData, GpsFixType, ImuData, LidarData, LidarPoint, MagnetometerData,
+        SensorData,
+    },
     vehicle::{VehicleControl, VehicleSpec, VehicleState},
     SimError, SimResult,
 };
@@ -32,6 +35,66 @@ struct VehicleSimState {
     control: VehicleControl,
 }
 
+/// The fan/ring of ray directions a simulated LiDAR emits each scan, in
+/// the sensor's local frame (forward = `+X`, up = `+Z`). Horizontal beams
+/// are swept evenly across `horizontal_fov_deg` starting from forward;
+/// vertical channels are swept evenly across `vertical_fov_deg`, centered
+/// on the horizontal plane, each producing its own [`LidarPoint::ring`].
+#[derive(Debug, Clone, Copy)]
+pub struct LidarBeamPattern {
+    /// Number of horizontal beams swept across `horizontal_fov_deg`.
+    pub num_horizontal_beams: usize,
+    /// Horizontal field of view (degrees); `360.0` for a full spinning-
+    /// LiDAR ring.
+    pub horizontal_fov_deg: f64,
+    /// Number of vertical channels (rings); `1` for a 2D scanning LiDAR.
+    pub num_vertical_channels: usize,
+    /// Vertical field of view (degrees), centered on the horizontal plane.
+    pub vertical_fov_deg: f64,
+    /// Maximum sensing range (meters).
+    pub max_range_m: f64,
+}
+
+impl LidarBeamPattern {
+    /// Unit ray directions this pattern emits, each paired with the
+    /// vertical channel (ring) index that produced it.
+    fn local_directions(&self) -> Vec<(u32, Vector3<f64>)> {
+        if self.num_horizontal_beams == 0 || self.num_vertical_channels == 0 {
+            return Vec::new();
+        }
+
+        let horizontal_step_rad =
+            self.horizontal_fov_deg.to_radians() / self.num_horizontal_beams as f64;
+
+        let vertical_angles_rad: Vec<f64> = if self.num_vertical_channels == 1 {
+            vec![0.0]
+        } else {
+            let half_fov_rad = self.vertical_fov_deg.to_radians() / 2.0;
+            let step_rad =
+                self.vertical_fov_deg.to_radians() / (self.num_vertical_channels - 1) as f64;
+            (0..self.num_vertical_channels)
+                .map(|i| -half_fov_rad + step_rad * i as f64)
+                .collect()
+        };
+
+        vertical_angles_rad
+            .into_iter()
+            .enumerate()
+            .flat_map(|(ring, vertical_angle_rad)| {
+                (0..self.num_horizontal_beams).map(move |beam| {
+                    let azimuth_rad = horizontal_step_rad * beam as f64;
+                    let direction = Vector3::new(
+                        azimuth_rad.cos() * vertical_angle_rad.cos(),
+                        azimuth_rad.sin() * vertical_angle_rad.cos(),
+                        vertical_angle_rad.sin(),
+                    );
+                    (ring as u32, direction)
+                })
+            })
+            .collect()
+    }
+}
+
 impl IsaacLabPythonBridge {
     /// Create a new Python bridge
     pub fn new(config: IsaacLabConfig) -> SimResult<Self> {
@@ -140,6 +203,87 @@ impl IsaacLabPythonBridge {
         Ok(None)
     }
 
+    /// Batched ray casting: one FFI crossing for every ray in `origins`/
+    /// `directions` instead of one per [`Self::cast_ray`] call, mirroring
+    /// Isaac's `ray_caster.cast_rays(origins, directions)`. The two slices
+    /// are paired index-by-index (callers must size them equally). In the
+    /// current placeholder backend this maps `cast_ray`'s ground-plane
+    /// intersection over every ray, so behavior and tests are well-defined
+    /// before the real PyO3 GPU raycasting path lands.
+    pub fn cast_rays(
+        &self,
+        origins: &[Point3<f64>],
+        directions: &[Vector3<f64>],
+        max_distance: f64,
+    ) -> SimResult<Vec<Option<(f64, Vector3<f64>, Position)>>> {
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "Bridge not initialized".to_string(),
+            ));
+        }
+
+        origins
+            .iter()
+            .zip(directions)
+            .map(|(origin, direction)| self.cast_ray(origin, direction, max_distance))
+            .collect()
+    }
+
+    /// Drive a simulated LiDAR at `prim_path`'s current pose in one
+    /// [`Self::cast_rays`] call: `beam_pattern` generates a fan/ring of
+    /// sensor-local ray directions, which are rotated into world space by
+    /// the vehicle's current orientation and cast from its position. Hits
+    /// become a [`LidarData`] point cloud; rays that miss are simply
+    /// omitted rather than padded with sentinel points.
+    pub fn cast_lidar_scan(
+        &self,
+        prim_path: &str,
+        beam_pattern: &LidarBeamPattern,
+    ) -> SimResult<LidarData> {
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "Bridge not initialized".to_string(),
+            ));
+        }
+
+        let sim_state = self
+            .vehicles
+            .get(prim_path)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle not found: {}", prim_path)))?;
+
+        let origin = sim_state.transform.position;
+        let rotation = sim_state.transform.rotation;
+
+        let (rings, directions): (Vec<u32>, Vec<Vector3<f64>>) = beam_pattern
+            .local_directions()
+            .into_iter()
+            .map(|(ring, local_direction)| (ring, rotation * local_direction))
+            .unzip();
+        let origins = vec![origin; directions.len()];
+
+        let hits = self.cast_rays(&origins, &directions, beam_pattern.max_range_m)?;
+
+        let points = hits
+            .into_iter()
+            .zip(rings)
+            .filter_map(|(hit, ring)| {
+                let (distance, _normal, position) = hit?;
+                Some(LidarPoint {
+                    position,
+                    intensity: 1.0,
+                    range: distance as f32,
+                    ring,
+                })
+            })
+            .collect();
+
+        Ok(LidarData {
+            timestamp: 0.0,
+            points,
+            pose: origin,
+        })
+    }
+
     /// Spawn a vehicle
     pub fn spawn_vehicle(&mut self, env_id: usize, spec: &VehicleSpec) -> SimResult<String> {
         if !self.initialized {
@@ -349,4 +493,90 @@ mod tests {
         assert!((distance - 10.0).abs() < 0.01);
         assert!((position.z).abs() < 0.01);
     }
+
+    #[test]
+    fn test_batched_ray_casting_matches_single_ray_casting() {
+        let config = IsaacLabConfig::default();
+        let bridge = IsaacLabPythonBridge::new(config).unwrap();
+
+        let origins = vec![
+            Point3::new(0.0, 0.0, 10.0),
+            Point3::new(5.0, 0.0, 20.0),
+            Point3::new(0.0, 0.0, 10.0), // pointing away from ground: no hit
+        ];
+        let directions = vec![
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+
+        let results = bridge.cast_rays(&origins, &directions, 100.0).unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert!((results[0].unwrap().0 - 10.0).abs() < 0.01);
+        assert!((results[1].unwrap().0 - 20.0).abs() < 0.01);
+        assert!(results[2].is_none());
+    }
+
+    fn grounded_vehicle_spec(vehicle_id: &str, height: f64) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: vehicle_id.to_string(),
+            vehicle_type: autonomysim_core::vehicle::VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, height),
+                nalgebra::UnitQuaternion::identity(),
+            ),
+            parameters: autonomysim_core::vehicle::VehicleParameters::default(),
+            sensors: vec![],
+        }
+    }
+
+    #[test]
+    fn test_lidar_scan_produces_one_ring_of_ground_hits() {
+        let config = IsaacLabConfig::default();
+        let mut bridge = IsaacLabPythonBridge::new(config).unwrap();
+        let prim_path = bridge
+            .spawn_vehicle(0, &grounded_vehicle_spec("drone", 10.0))
+            .unwrap();
+
+        let beam_pattern = LidarBeamPattern {
+            num_horizontal_beams: 8,
+            horizontal_fov_deg: 360.0,
+            num_vertical_channels: 1,
+            vertical_fov_deg: 0.0,
+            max_range_m: 100.0,
+        };
+
+        let scan = bridge.cast_lidar_scan(&prim_path, &beam_pattern).unwrap();
+
+        // Every horizontally-swept beam with the sensor level (vertical
+        // angle 0) points along the ground plane, not into it, so none of
+        // them should register a ground-plane hit.
+        assert_eq!(scan.points.len(), 0);
+    }
+
+    #[test]
+    fn test_lidar_scan_hits_ground_when_tilted_downward() {
+        let config = IsaacLabConfig::default();
+        let mut bridge = IsaacLabPythonBridge::new(config).unwrap();
+        let prim_path = bridge
+            .spawn_vehicle(0, &grounded_vehicle_spec("drone", 10.0))
+            .unwrap();
+
+        let beam_pattern = LidarBeamPattern {
+            num_horizontal_beams: 4,
+            horizontal_fov_deg: 360.0,
+            num_vertical_channels: 3,
+            vertical_fov_deg: 60.0, // spans -30..=30 degrees, some beams point down
+            max_range_m: 100.0,
+        };
+
+        let scan = bridge.cast_lidar_scan(&prim_path, &beam_pattern).unwrap();
+
+        assert!(!scan.points.is_empty());
+        for point in &scan.points {
+            assert!(point.range > 0.0);
+            assert!(point.range <= 100.0);
+        }
+    }
 }
@@ -9,6 +9,7 @@
 //! - **NVIDIA Isaac Lab** (feature: `isaac`) - ✅ Implemented with Python bridge
 //! - **MuJoCo** (feature: `mujoco`) - ✅ Implemented with C FFI
 //! - **NVIDIA Warp** (feature: `warp`) - ✅ Implemented with GPU compute
+//! - **wgpu** (feature: `wgpu_backend`) - ✅ Pure-Rust GPU compute (Vulkan/Metal/DX12, no Python/CUDA)
 //!
 //! # Usage
 //!
@@ -29,6 +30,9 @@ pub mod mujoco;
 #[cfg(feature = "warp")]
 pub mod warp;
 
+#[cfg(feature = "wgpu_backend")]
+pub mod wgpu_backend;
+
 // Re-export backends
 #[cfg(feature = "unreal")]
 pub use unreal::UnrealEngine5Backend;
@@ -41,3 +45,6 @@ pub use mujoco::MuJoCoBackend;
 
 #[cfg(feature = "warp")]
 pub use warp::WarpBackend;
+
+#[cfg(feature = "wgpu_backend")]
+pub use wgpu_backend::WgpuBackend;
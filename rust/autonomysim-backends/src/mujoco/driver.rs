@@ -0,0 +1,164 @@
+//! Continuous stepping loop for [`MuJoCoBackend`], for headless batch runs
+//! (`real_time_factor = 0.0`) and real-time HIL runs (`real_time_factor =
+//! 1.0`) from the same API -- see [`MuJoCoDriver::run`]/[`MuJoCoDriver::poll_step`].
+//!
+//! [`MuJoCoDriver::run`] owns the stepping loop and blocks the caller;
+//! [`MuJoCoDriver::poll_step`] advances at most one frame and returns
+//! immediately, for a caller that wants to drive its own event loop and
+//! `select!` against [`MuJoCoDriver::next_deadline`] instead.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use autonomysim_core::backend::{SimResult, SimulationBackend};
+
+use super::{AbstractMeasurement, MuJoCoBackend};
+
+/// Drives a [`MuJoCoBackend`]'s stepping loop at a configurable pace
+/// relative to wall-clock time.
+pub struct MuJoCoDriver {
+    backend: MuJoCoBackend,
+    /// Simulated time to stop at, if any -- [`Self::run`]/[`Self::poll_step`]
+    /// stop advancing once [`SimulationBackend::get_time`] reaches it.
+    sim_end_time: Option<f64>,
+    /// `1.0` paces each frame to wall-clock, `0.0` steps as fast as
+    /// possible, other values scale the wall-clock deadline accordingly.
+    real_time_factor: f64,
+    /// Measurement probes registered on `backend` at construction time,
+    /// kept here so a caller can re-read what the driver is sampling.
+    measurements: Vec<Arc<dyn AbstractMeasurement>>,
+    next_deadline: Instant,
+}
+
+impl MuJoCoDriver {
+    /// Take ownership of `backend` (already initialized) and register
+    /// `measurements` onto it, sampled once per internal step the same way
+    /// [`SimulationBackend::step`] already does for any measurement added
+    /// directly via [`MuJoCoBackend::add_measurement`].
+    pub fn new(
+        mut backend: MuJoCoBackend,
+        real_time_factor: f64,
+        sim_end_time: Option<f64>,
+        measurements: Vec<Arc<dyn AbstractMeasurement>>,
+    ) -> Self {
+        for measurement in &measurements {
+            backend.add_measurement(measurement.clone());
+        }
+
+        Self {
+            backend,
+            sim_end_time,
+            real_time_factor,
+            measurements,
+            next_deadline: Instant::now(),
+        }
+    }
+
+    /// The driven backend.
+    pub fn backend(&self) -> &MuJoCoBackend {
+        &self.backend
+    }
+
+    /// The driven backend, mutably -- e.g. to spawn vehicles or read state
+    /// between frames.
+    pub fn backend_mut(&mut self) -> &mut MuJoCoBackend {
+        &mut self.backend
+    }
+
+    /// The measurement probes this driver registered at construction time.
+    pub fn measurements(&self) -> &[Arc<dyn AbstractMeasurement>] {
+        &self.measurements
+    }
+
+    /// Wall-clock time to sleep for between frames at the current
+    /// `real_time_factor`.
+    fn frame_duration(&self) -> std::time::Duration {
+        let config = self.backend.config();
+        let seconds = self.real_time_factor * config.timestep * config.substeps as f64;
+        std::time::Duration::from_secs_f64(seconds.max(0.0))
+    }
+
+    fn has_finished(&self) -> bool {
+        self.sim_end_time
+            .is_some_and(|end_time| self.backend.get_time() >= end_time)
+    }
+
+    /// The wall-clock deadline [`Self::run`] is waiting on for the next
+    /// frame, for a caller driving its own event loop to `select!` against
+    /// instead of calling [`Self::run`].
+    pub fn next_deadline(&self) -> Instant {
+        self.next_deadline
+    }
+
+    /// Advance at most one frame and return immediately: `Ok(true)` if a
+    /// step was taken, `Ok(false)` if `sim_end_time` has already been
+    /// reached.
+    pub async fn poll_step(&mut self) -> SimResult<bool> {
+        if self.has_finished() {
+            return Ok(false);
+        }
+
+        let config = self.backend.config();
+        let frame_dt = config.timestep * config.substeps as f64;
+        self.backend.step(frame_dt).await?;
+        self.next_deadline += self.frame_duration();
+        Ok(true)
+    }
+
+    /// Run continuously until `sim_end_time` is reached (or forever, if
+    /// unset), pacing each frame to `real_time_factor * timestep *
+    /// substeps` of wall-clock time.
+    pub async fn run(&mut self) -> SimResult<()> {
+        self.next_deadline = Instant::now();
+        while !self.has_finished() {
+            tokio::time::sleep_until(self.next_deadline.into()).await;
+            self.poll_step().await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::backend::BackendConfig;
+
+    async fn initialized_backend() -> MuJoCoBackend {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+    }
+
+    #[tokio::test]
+    async fn poll_step_advances_time_by_one_frame() {
+        let backend = initialized_backend().await;
+        let timestep = backend.config().timestep;
+        let mut driver = MuJoCoDriver::new(backend, 0.0, None, Vec::new());
+
+        let advanced = driver.poll_step().await.unwrap();
+
+        assert!(advanced);
+        assert!((driver.backend().get_time() - timestep).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn poll_step_stops_at_sim_end_time() {
+        let backend = initialized_backend().await;
+        let timestep = backend.config().timestep;
+        let mut driver = MuJoCoDriver::new(backend, 0.0, Some(timestep), Vec::new());
+
+        assert!(driver.poll_step().await.unwrap());
+        assert!(!driver.poll_step().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn run_as_fast_as_possible_reaches_sim_end_time() {
+        let backend = initialized_backend().await;
+        let timestep = backend.config().timestep;
+        let mut driver = MuJoCoDriver::new(backend, 0.0, Some(timestep * 5.0), Vec::new());
+
+        driver.run().await.unwrap();
+
+        assert!(driver.backend().get_time() >= timestep * 5.0);
+    }
+}
@@ -2,18 +2,275 @@
 //!
 //! Provides Rust bindings to MuJoCo physics engine.
 
-use nalgebra::{Point3, UnitQuaternion, Vector3};
+use nalgebra::{DMatrix, Matrix3, Point3, UnitQuaternion, Vector3};
+use std::collections::HashMap;
 use std::ffi::CString;
 
 use autonomysim_core::{
-    backend::{Material, RayHit},
+    backend::{Material, Position, RayHit, SceneObject},
+    reaction_wheel::{allocate_wheel_torques, ReactionWheelConfig},
     sensor::{BarometerData, GpsData, GpsFixType, ImuData, MagnetometerData, SensorData},
-    vehicle::{VehicleControl, VehicleSpec, VehicleState},
+    vehicle::{VehicleControl, VehicleSpec, VehicleState, VehicleType},
     SimError, SimResult, Transform,
 };
 
+use super::mjcf::MjcfDocument;
 use super::MuJoCoConfig;
 
+/// Frames a body spends under corrective velocity after a swept-collision
+/// hit clamps it to a surface, before free integration resumes -- long
+/// enough for the reflected velocity to bleed off the closing speed instead
+/// of driving the body straight back into what it just hit.
+const TUNNELING_RECOVERY_FRAMES: u32 = 5;
+
+/// How far off a hit surface a clamped body is placed, so the very next
+/// swept ray cast (starting from this new position) doesn't immediately
+/// re-report the same surface as a zero-distance hit.
+const SURFACE_CLEARANCE_M: f64 = 1.0e-3;
+
+/// Per-body physics state tracked between [`MuJoCoFFI::step`] calls, so the
+/// swept-collision check has a "previous position" to ray-cast from and a
+/// surface normal to recover along after a hit.
+struct BodyPhysicsState {
+    transform: Transform,
+    linear_velocity: Vector3<f64>,
+    angular_velocity: Vector3<f64>,
+    /// Set by [`MuJoCoFFI::step_body_substep`] from this substep's change in
+    /// velocity, so [`MuJoCoFFI::get_sensor_data`] has a real specific force
+    /// to read an accelerometer from instead of a hardcoded constant.
+    linear_acceleration: Vector3<f64>,
+    is_grounded: bool,
+    /// Nonzero while this body is recovering from a swept-collision hit --
+    /// see [`TUNNELING_RECOVERY_FRAMES`].
+    tunneling_frames_remaining: u32,
+    /// Surface normal from the hit that last set `tunneling_frames_remaining`.
+    tunneling_normal: Vector3<f64>,
+    /// Slowly drifting accelerometer bias -- see [`ImuErrorModel::accel_bias_walk_std`].
+    imu_accel_bias: Vector3<f64>,
+    /// Slowly drifting gyro bias -- see [`ImuErrorModel::gyro_bias_walk_std`].
+    imu_gyro_bias: Vector3<f64>,
+    /// Reaction-wheel array for `VehicleType::Spacecraft` bodies, if any --
+    /// see [`Self::wheel_speeds`].
+    reaction_wheel: Option<ReactionWheelConfig>,
+    /// Current spin rate (rad/s) of each wheel in `reaction_wheel`, in the
+    /// same order as `reaction_wheel.wheel_axes`. Integrated forward by
+    /// [`MuJoCoFFI::step_body_substep`] from `wheel_torque_commands`, the
+    /// same way `data->ctrl` drives `mj_step` in a real MuJoCo model.
+    wheel_speeds: Vec<f64>,
+    /// Per-wheel torque command set by
+    /// [`MuJoCoFFI::set_actuator_controls`] and applied every substep until
+    /// the next control update.
+    wheel_torque_commands: Vec<f64>,
+    /// Mass (kg), from `VehicleParameters::mass` at [`MuJoCoFFI::create_body`]
+    /// time. Scales `thrust_command`/`drag_coefficient` into an acceleration
+    /// in [`MuJoCoFFI::step_body_substep`] -- a calibration target for
+    /// [`MuJoCoFFI::fit_parameters`].
+    mass: f64,
+    /// Linear drag coefficient, from `VehicleParameters::drag_coefficient`.
+    /// Decelerates the body proportional to velocity each substep.
+    drag_coefficient: f64,
+    /// Gain multiplying `thrust_command` before it accelerates the body --
+    /// models an uncalibrated actuator (e.g. an ESC/motor curve mismatch).
+    /// Another [`MuJoCoFFI::fit_parameters`] target.
+    actuator_gain: f64,
+    /// Upward thrust command set by [`MuJoCoFFI::set_actuator_controls`]
+    /// from `control.throttle` and applied every substep until the next
+    /// control update, the same way `wheel_torque_commands` drives
+    /// `wheel_speeds`.
+    thrust_command: f64,
+    /// Sum of every substep's contact-correction force (mass times the
+    /// velocity correction applied against a hit normal, divided by that
+    /// substep's `dt`) since [`MuJoCoFFI::step`] last reset it to zero.
+    /// Read by [`MuJoCoFFI::net_contact_force_magnitude`].
+    last_contact_force: Vector3<f64>,
+    /// This body's `xfrc_applied`-equivalent: scripted external force (N),
+    /// set by [`MuJoCoFFI::apply_external_wrench`] and integrated every
+    /// substep by [`MuJoCoFFI::step_body_substep`] until the next
+    /// [`MuJoCoFFI::reset_external_wrenches`] call.
+    external_force: Vector3<f64>,
+    /// This body's `xfrc_applied`-equivalent external torque (N*m), applied
+    /// with a unit inertia assumption -- the same simplification
+    /// `wheel_speeds` makes for reaction wheels.
+    external_torque: Vector3<f64>,
+}
+
+impl BodyPhysicsState {
+    fn at_rest(transform: Transform) -> Self {
+        Self {
+            transform,
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::zeros(),
+            is_grounded: false,
+            tunneling_frames_remaining: 0,
+            tunneling_normal: Vector3::zeros(),
+            imu_accel_bias: Vector3::zeros(),
+            imu_gyro_bias: Vector3::zeros(),
+            reaction_wheel: None,
+            wheel_speeds: Vec::new(),
+            wheel_torque_commands: Vec::new(),
+            mass: 1.0,
+            drag_coefficient: 0.0,
+            actuator_gain: 1.0,
+            thrust_command: 0.0,
+            last_contact_force: Vector3::zeros(),
+            external_force: Vector3::zeros(),
+            external_torque: Vector3::zeros(),
+        }
+    }
+}
+
+/// Per-axis accelerometer/gyroscope error model, in the spirit of an
+/// MPU-6050/MPU-9250-class MEMS IMU: a fixed scale-factor/misalignment
+/// matrix, a slowly drifting random-walk bias, additive white noise, and a
+/// hardware full-scale range sampled at a finite bit resolution.
+#[derive(Debug, Clone)]
+pub struct ImuErrorModel {
+    /// Diagonal is per-axis scale factor; off-diagonal terms are
+    /// cross-axis misalignment.
+    pub accel_scale_misalignment: Matrix3<f64>,
+    pub gyro_scale_misalignment: Matrix3<f64>,
+    /// Accelerometer bias random-walk standard deviation (m/s^2 per sqrt(s)).
+    pub accel_bias_walk_std: f64,
+    /// Gyro bias random-walk standard deviation (rad/s per sqrt(s)).
+    pub gyro_bias_walk_std: f64,
+    /// Accelerometer measurement noise standard deviation (m/s^2).
+    pub accel_noise_std: f64,
+    /// Gyro measurement noise standard deviation (rad/s).
+    pub gyro_noise_std: f64,
+    /// Accelerometer full-scale range, +/- m/s^2.
+    pub accel_full_scale: f64,
+    /// Gyro full-scale range, +/- rad/s.
+    pub gyro_full_scale: f64,
+    /// ADC bit resolution across the full-scale range.
+    pub bit_resolution: u32,
+}
+
+impl Default for ImuErrorModel {
+    /// Roughly an MPU-6050 at its +-8g / +-500dps range.
+    fn default() -> Self {
+        Self {
+            accel_scale_misalignment: Matrix3::identity(),
+            gyro_scale_misalignment: Matrix3::identity(),
+            accel_bias_walk_std: 0.001,
+            gyro_bias_walk_std: 0.0005,
+            accel_noise_std: 0.02,
+            gyro_noise_std: 0.005,
+            accel_full_scale: 8.0 * 9.80665,
+            gyro_full_scale: 500.0_f64.to_radians(),
+            bit_resolution: 16,
+        }
+    }
+}
+
+/// Magnetometer error model, in the spirit of an AK8963-class part: a
+/// soft-iron distortion matrix, a hard-iron offset, and additive noise.
+#[derive(Debug, Clone)]
+pub struct MagnetometerErrorModel {
+    pub soft_iron: Matrix3<f64>,
+    pub hard_iron_offset: Vector3<f64>,
+    /// Measurement noise standard deviation (Gauss).
+    pub noise_std: f64,
+}
+
+impl Default for MagnetometerErrorModel {
+    fn default() -> Self {
+        Self {
+            soft_iron: Matrix3::identity(),
+            hard_iron_offset: Vector3::zeros(),
+            noise_std: 0.01,
+        }
+    }
+}
+
+/// A scalar MuJoCo model parameter that [`MuJoCoFFI::fit_parameters`] is
+/// allowed to vary, identified by the body it belongs to. Read/written via
+/// [`MuJoCoFFI::get_parameter`]/[`MuJoCoFFI::set_parameter`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModelParameter {
+    BodyMass(i32),
+    DragCoefficient(i32),
+    ActuatorGain(i32),
+}
+
+impl ModelParameter {
+    fn body_id(&self) -> i32 {
+        match *self {
+            ModelParameter::BodyMass(id)
+            | ModelParameter::DragCoefficient(id)
+            | ModelParameter::ActuatorGain(id) => id,
+        }
+    }
+
+    /// Clamp a candidate value to this parameter's physically valid range.
+    fn clamp(&self, value: f64) -> f64 {
+        match self {
+            ModelParameter::BodyMass(_) => value.max(1e-3),
+            ModelParameter::DragCoefficient(_) => value.max(0.0),
+            ModelParameter::ActuatorGain(_) => value.max(1e-6),
+        }
+    }
+}
+
+/// One recorded ground-truth sample for [`MuJoCoFFI::fit_parameters`]: the
+/// control applied at this timestep and the body position actually measured
+/// immediately after.
+#[derive(Debug, Clone)]
+pub struct RecordedSample {
+    pub control: VehicleControl,
+    pub position: Point3<f64>,
+}
+
+/// Result of [`MuJoCoFFI::fit_parameters`].
+#[derive(Debug, Clone)]
+pub struct CalibrationResult {
+    /// Fitted values, in the same order as the `params_to_vary` passed in.
+    pub fitted_values: Vec<f64>,
+    /// Final 0.5 * sum(residual^2).
+    pub final_cost: f64,
+    pub iterations: usize,
+}
+
+/// Sample zero-mean Gaussian noise via a Box-Muller transform, so this
+/// module doesn't need a dependency beyond the `rand` crate already used
+/// elsewhere in this crate.
+fn gaussian_noise(std_dev: f64) -> f64 {
+    if std_dev <= 0.0 {
+        return 0.0;
+    }
+    let u1 = rand::random::<f64>().max(1e-12);
+    let u2 = rand::random::<f64>();
+    let z0 = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    z0 * std_dev
+}
+
+/// Sample per-axis zero-mean Gaussian noise.
+fn gaussian_noise_vec3(std_dev: f64) -> Vector3<f64> {
+    Vector3::new(
+        gaussian_noise(std_dev),
+        gaussian_noise(std_dev),
+        gaussian_noise(std_dev),
+    )
+}
+
+/// Clamp `value` to `+-full_scale` then round it to the nearest step a
+/// `bit_resolution`-bit signed ADC can represent across that range.
+fn quantize_axis(value: f64, full_scale: f64, bit_resolution: u32) -> f64 {
+    let levels = (1u64 << bit_resolution.saturating_sub(1).min(62)) as f64;
+    let lsb = full_scale / levels;
+    let clamped = value.clamp(-full_scale, full_scale);
+    (clamped / lsb).round() * lsb
+}
+
+/// Per-axis [`quantize_axis`].
+fn clamp_and_quantize_vec3(v: Vector3<f64>, full_scale: f64, bit_resolution: u32) -> Vector3<f64> {
+    Vector3::new(
+        quantize_axis(v.x, full_scale, bit_resolution),
+        quantize_axis(v.y, full_scale, bit_resolution),
+        quantize_axis(v.z, full_scale, bit_resolution),
+    )
+}
+
 /// FFI interface to MuJoCo
 pub struct MuJoCoFFI {
     config: MuJoCoConfig,
@@ -24,6 +281,19 @@ pub struct MuJoCoFFI {
     initialized: bool,
     current_model_id: i32,
     num_bodies: usize,
+    /// Tracked bodies, keyed by body id -- populated by [`Self::create_body`]
+    /// and consulted by [`Self::step`]/[`Self::get_body_state`].
+    bodies: HashMap<i32, BodyPhysicsState>,
+    /// Accelerometer/gyro error model applied by [`Self::get_sensor_data`]'s
+    /// `"imu"` case.
+    imu_error_model: ImuErrorModel,
+    /// Magnetometer error model applied by [`Self::get_sensor_data`]'s
+    /// `"magnetometer"` case.
+    magnetometer_error_model: MagnetometerErrorModel,
+    /// Scene-authored (non-vehicle) objects added via
+    /// [`Self::add_scene_object`], serialized to MJCF and recompiled by
+    /// [`Self::recompile`] on every mutation.
+    mjcf: MjcfDocument,
 }
 
 impl MuJoCoFFI {
@@ -44,9 +314,25 @@ impl MuJoCoFFI {
             initialized: true,
             current_model_id: -1,
             num_bodies: 0,
+            bodies: HashMap::new(),
+            imu_error_model: ImuErrorModel::default(),
+            magnetometer_error_model: MagnetometerErrorModel::default(),
+            mjcf: MjcfDocument::default(),
         })
     }
 
+    /// Override the accelerometer/gyro error model [`Self::get_sensor_data`]
+    /// applies, e.g. to match a specific IMU part's datasheet.
+    pub fn set_imu_error_model(&mut self, model: ImuErrorModel) {
+        self.imu_error_model = model;
+    }
+
+    /// Override the magnetometer error model [`Self::get_sensor_data`]
+    /// applies, e.g. to match a specific compass's calibration.
+    pub fn set_magnetometer_error_model(&mut self, model: MagnetometerErrorModel) {
+        self.magnetometer_error_model = model;
+    }
+
     /// Shutdown MuJoCo
     pub fn shutdown(&mut self) -> SimResult<()> {
         // In a full implementation, this would:
@@ -60,16 +346,140 @@ impl MuJoCoFFI {
     }
 
     /// Step the simulation
-    pub fn step(&self) -> SimResult<()> {
+    ///
+    /// In a full implementation this would call `mj_step(model, data)` once
+    /// per substep. Without a real contact solver to rely on, each substep
+    /// here instead ray-casts every body from its previous position to its
+    /// candidate new one: a straight-line step that would have tunneled
+    /// through a surface is clamped to just short of the hit instead, with
+    /// velocity corrected along the hit normal so the body settles onto it
+    /// rather than immediately re-penetrating on the next substep. Raising
+    /// `config.substeps` shortens each ray, catching fast bodies a single
+    /// whole-timestep check would miss entirely.
+    pub fn step(&mut self) -> SimResult<()> {
         if !self.initialized {
             return Err(SimError::NotInitialized(
                 "MuJoCo not initialized".to_string(),
             ));
         }
 
-        // In a full implementation, this would call:
-        // mj_step(model, data)
-        // This advances the simulation by one timestep
+        let substeps = self.config.substeps.max(1);
+        let dt = self.config.timestep / substeps as f64;
+        let body_ids: Vec<i32> = self.bodies.keys().copied().collect();
+
+        for &body_id in &body_ids {
+            if let Some(body) = self.bodies.get_mut(&body_id) {
+                body.last_contact_force = Vector3::zeros();
+            }
+        }
+
+        for _ in 0..substeps {
+            for &body_id in &body_ids {
+                self.step_body_substep(body_id, dt)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Advance one body by one swept-collision substep of `dt` -- see
+    /// [`Self::step`].
+    fn step_body_substep(&mut self, body_id: i32, dt: f64) -> SimResult<()> {
+        let (
+            previous_position,
+            previous_velocity,
+            mut velocity,
+            mut recovery_normal,
+            mut recovery_frames,
+            mut grounded,
+        ) = {
+            let body = &self.bodies[&body_id];
+            (
+                body.transform.position,
+                body.linear_velocity,
+                body.linear_velocity,
+                body.tunneling_normal,
+                body.tunneling_frames_remaining,
+                body.is_grounded,
+            )
+        };
+
+        // Minimal real force model so a calibration run
+        // ([`Self::fit_parameters`]) has something to fit: upward thrust
+        // scaled by an uncalibrated actuator gain, opposed by linear drag,
+        // plus any scripted external wrench a `Stimulus` has applied.
+        {
+            let body = &self.bodies[&body_id];
+            let thrust_accel = body.actuator_gain * body.thrust_command / body.mass;
+            let drag_accel = -(body.drag_coefficient / body.mass) * velocity;
+            let external_accel = body.external_force / body.mass;
+            velocity += (Vector3::new(0.0, 0.0, thrust_accel) + drag_accel + external_accel) * dt;
+        }
+
+        if recovery_frames > 0 {
+            let closing_speed = velocity.dot(&recovery_normal);
+            if closing_speed < 0.0 {
+                velocity -= recovery_normal * closing_speed;
+            }
+            recovery_frames -= 1;
+        }
+
+        let candidate_position = previous_position + velocity * dt;
+        let travel = candidate_position - previous_position;
+        let travel_distance = travel.norm();
+
+        let mut resolved_position = candidate_position;
+        let mut contact_velocity_correction = Vector3::zeros();
+
+        if travel_distance > f64::EPSILON {
+            let direction = travel / travel_distance;
+            match self.cast_ray(&previous_position, &direction, travel_distance)? {
+                Some(hit) => {
+                    resolved_position = hit.position + hit.normal * SURFACE_CLEARANCE_M;
+                    let closing_speed = velocity.dot(&hit.normal);
+                    if closing_speed < 0.0 {
+                        let correction = -hit.normal * closing_speed;
+                        velocity += correction;
+                        contact_velocity_correction += correction;
+                    }
+                    recovery_normal = hit.normal;
+                    recovery_frames = TUNNELING_RECOVERY_FRAMES;
+                    grounded = hit.normal.z > 0.5;
+                }
+                None => grounded = false,
+            }
+        }
+
+        let contact_force = self.bodies[&body_id].mass * contact_velocity_correction / dt;
+
+        let body = self
+            .bodies
+            .get_mut(&body_id)
+            .expect("body_id drawn from self.bodies' own keys");
+        body.transform.position = resolved_position;
+        body.linear_acceleration = (velocity - previous_velocity) / dt;
+        body.linear_velocity = velocity;
+        body.is_grounded = grounded;
+        body.tunneling_normal = recovery_normal;
+        body.tunneling_frames_remaining = recovery_frames;
+        body.last_contact_force += contact_force;
+
+        // A scripted external torque spins the body up the same way it
+        // would accelerate a reaction wheel -- unit inertia, since this FFI
+        // has no per-body inertia tensor to integrate against.
+        body.angular_velocity += body.external_torque * dt;
+
+        // Reaction wheels spin up under their commanded torque the same way
+        // `mj_step` integrates any other actuator-driven DOF from
+        // `data->ctrl`; wheel inertia is taken as 1 kg*m^2 since this FFI
+        // has no per-wheel inertia tensor to read.
+        for (speed, torque) in body
+            .wheel_speeds
+            .iter_mut()
+            .zip(body.wheel_torque_commands.iter())
+        {
+            *speed += torque * dt;
+        }
 
         Ok(())
     }
@@ -103,6 +513,74 @@ impl MuJoCoFFI {
         Ok(self.num_bodies)
     }
 
+    /// Author `object` into the in-memory MJCF document and recompile.
+    /// Returns `object.id` unchanged -- it's already a stable id the
+    /// caller chose, so there's nothing to generate.
+    pub fn add_scene_object(&mut self, object: SceneObject) -> SimResult<String> {
+        let body_id = self.num_bodies as i32;
+        self.num_bodies += 1;
+        let object_id = object.id.clone();
+        self.mjcf.insert(body_id, object);
+        self.recompile();
+        Ok(object_id)
+    }
+
+    /// Drop `object_id` from the MJCF document and recompile. Its body id
+    /// is never reused, so this never shifts another object's or
+    /// vehicle's id.
+    pub fn remove_scene_object(&mut self, object_id: &str) -> SimResult<()> {
+        self.mjcf.remove(object_id)?;
+        self.recompile();
+        Ok(())
+    }
+
+    /// Move `object_id` in place. This changes neither body count nor
+    /// topology, so unlike [`Self::add_scene_object`]/
+    /// [`Self::remove_scene_object`] it doesn't recompile the model --
+    /// MuJoCo can set a body's `pos`/`quat` directly between steps.
+    pub fn update_object_transform(
+        &mut self,
+        object_id: &str,
+        transform: Transform,
+    ) -> SimResult<()> {
+        self.mjcf.update_transform(object_id, transform)
+    }
+
+    /// Every scene-authored object currently in the MJCF document.
+    pub fn get_scene_objects(&self) -> Vec<SceneObject> {
+        self.mjcf.objects()
+    }
+
+    /// Axis-aligned bounds across every scene-authored object, or a
+    /// generous default footprint if none have been added yet.
+    pub fn scene_bounds(&self) -> (Position, Position) {
+        if self.mjcf.is_empty() {
+            return (
+                Position::new(-100.0, -100.0, 0.0),
+                Position::new(100.0, 100.0, 50.0),
+            );
+        }
+        self.mjcf.bounds()
+    }
+
+    /// Serialize the current [`MjcfDocument`] and bump the compiled model
+    /// id, simulating `mj_loadXML`/`mj_compile`/`mj_makeData` on an
+    /// updated model. `self.bodies` (every vehicle's `qpos`/`qvel`) is
+    /// deliberately left untouched, so existing vehicles keep their
+    /// simulated state across the recompile.
+    fn recompile(&mut self) -> i32 {
+        self.current_model_id += 1;
+        let xml = self
+            .mjcf
+            .to_mjcf_xml(&format!("scene_{}", self.current_model_id));
+        println!(
+            "MuJoCo: Recompiled model {} ({} bytes of MJCF)",
+            self.current_model_id,
+            xml.len()
+        );
+        self.current_model_id
+    }
+
     /// Cast a ray through the scene
     pub fn cast_ray(
         &self,
@@ -152,6 +630,20 @@ impl MuJoCoFFI {
 
         let body_id = self.num_bodies as i32;
         self.num_bodies += 1;
+        let mut body = BodyPhysicsState::at_rest(spec.initial_transform.clone());
+        body.mass = spec.parameters.mass.max(1e-3);
+        body.drag_coefficient = spec.parameters.drag_coefficient.max(0.0);
+        if spec.vehicle_type == VehicleType::Spacecraft {
+            let reaction_wheel = spec
+                .parameters
+                .reaction_wheel
+                .clone()
+                .unwrap_or_else(ReactionWheelConfig::four_wheel_pyramid);
+            body.wheel_speeds = vec![0.0; reaction_wheel.wheel_axes.len()];
+            body.wheel_torque_commands = vec![0.0; reaction_wheel.wheel_axes.len()];
+            body.reaction_wheel = Some(reaction_wheel);
+        }
+        self.bodies.insert(body_id, body);
 
         println!(
             "MuJoCo: Created body {} for vehicle '{}' ({:?})",
@@ -174,12 +666,19 @@ impl MuJoCoFFI {
         // 2. Set control ranges, gains, etc.
         // 3. Attach actuators to joints
 
-        // Create 4-6 actuators depending on vehicle type
+        // Create 4-6 actuators depending on vehicle type -- one per wheel
+        // for a reaction-wheel spacecraft.
         let num_actuators = match spec.vehicle_type {
-            autonomysim_core::vehicle::VehicleType::Multirotor => 4, // 4 motors
-            autonomysim_core::vehicle::VehicleType::Car => 4, // throttle, steering, brake, gear
-            autonomysim_core::vehicle::VehicleType::FixedWing => 4, // throttle, elevator, rudder, aileron
-            _ => 6,                                                 // generic 6-DOF
+            VehicleType::Multirotor => 4, // 4 motors
+            VehicleType::Car => 4,        // throttle, steering, brake, gear
+            VehicleType::FixedWing => 4,  // throttle, elevator, rudder, aileron
+            VehicleType::Spacecraft => self
+                .bodies
+                .get(&body_id)
+                .and_then(|body| body.reaction_wheel.as_ref())
+                .map(|config| config.wheel_axes.len())
+                .unwrap_or(4),
+            _ => 6, // generic 6-DOF
         };
 
         let actuator_ids: Vec<i32> = (0..num_actuators).map(|i| body_id * 10 + i).collect();
@@ -204,6 +703,8 @@ impl MuJoCoFFI {
         // 1. Disable the body
         // 2. Or recompile the model without the body
 
+        self.bodies.remove(&body_id);
+
         println!("MuJoCo: Removed body {}", body_id);
         Ok(())
     }
@@ -222,24 +723,136 @@ impl MuJoCoFFI {
         // - data->cvel[body_id] (velocity)
         // - data->cacc[body_id] (acceleration)
 
-        // Placeholder state
+        // Read back the state [`Self::step`] has been integrating. A body
+        // id this FFI never created via `create_body` (shouldn't happen in
+        // practice) falls back to the old at-rest placeholder.
+        let (transform, linear_velocity, angular_velocity, linear_acceleration, is_grounded) =
+            match self.bodies.get(&body_id) {
+                Some(body) => (
+                    body.transform.clone(),
+                    body.linear_velocity,
+                    body.angular_velocity,
+                    body.linear_acceleration,
+                    body.is_grounded,
+                ),
+                None => (
+                    Transform::new(Point3::new(0.0, 0.0, 1.0), UnitQuaternion::identity()),
+                    Vector3::zeros(),
+                    Vector3::zeros(),
+                    Vector3::zeros(),
+                    true,
+                ),
+            };
+
         Ok(VehicleState {
             vehicle_id: vehicle_id.to_string(),
             timestamp: 0.0,
-            transform: Transform::new(Point3::new(0.0, 0.0, 1.0), UnitQuaternion::identity()),
-            linear_velocity: Vector3::zeros(),
-            angular_velocity: Vector3::zeros(),
-            linear_acceleration: Vector3::zeros(),
+            transform,
+            linear_velocity,
+            angular_velocity,
+            linear_acceleration,
             angular_acceleration: Vector3::zeros(),
             battery_level: 1.0,
-            is_grounded: true,
+            is_grounded,
             collision_info: None,
         })
     }
 
+    /// Total kinetic energy (J) across every tracked body -- linear only,
+    /// since this FFI has no per-body inertia tensor to compute rotational
+    /// KE from. Used by [`super::measurement::TotalKineticEnergy`].
+    pub(crate) fn total_kinetic_energy(&self) -> f64 {
+        self.bodies
+            .values()
+            .map(|body| 0.5 * body.mass * body.linear_velocity.norm_squared())
+            .sum()
+    }
+
+    /// Magnitude of the vector sum of every body's contact-correction force
+    /// from the most recently completed [`Self::step`] call. Used by
+    /// [`super::measurement::NetContactForce`].
+    pub(crate) fn net_contact_force_magnitude(&self) -> f64 {
+        let mut total = Vector3::zeros();
+        for body in self.bodies.values() {
+            total += body.last_contact_force;
+        }
+        total.norm()
+    }
+
+    /// Total actuator power (W): `ctrl * actuator_velocity`, summed across
+    /// every body's thrust actuator (rate of work against its linear
+    /// velocity) and reaction-wheel actuators (torque times wheel speed).
+    /// Used by [`super::measurement::ActuatorPower`].
+    pub(crate) fn total_actuator_power(&self) -> f64 {
+        self.bodies
+            .values()
+            .map(|body| {
+                let thrust_power = body.thrust_command * body.linear_velocity.z;
+                let wheel_power: f64 = body
+                    .wheel_torque_commands
+                    .iter()
+                    .zip(body.wheel_speeds.iter())
+                    .map(|(torque, speed)| torque * speed)
+                    .sum();
+                thrust_power + wheel_power
+            })
+            .sum()
+    }
+
+    /// Mass-weighted center of mass across every tracked body. Reports the
+    /// world origin if no bodies (or only zero-mass bodies) are tracked.
+    /// Used by [`super::measurement::BodyComPosition`].
+    pub(crate) fn system_com_position(&self) -> Point3<f64> {
+        let total_mass: f64 = self.bodies.values().map(|body| body.mass).sum();
+        if total_mass <= 0.0 {
+            return Point3::origin();
+        }
+        let mut weighted = Vector3::zeros();
+        for body in self.bodies.values() {
+            weighted += body.transform.position.coords * body.mass;
+        }
+        Point3::from(weighted / total_mass)
+    }
+
+    /// Every currently tracked body id, for a [`super::stimulus::Stimulus`]
+    /// that applies uniformly across the whole scene (e.g. a wind field).
+    pub(crate) fn body_ids(&self) -> Vec<i32> {
+        self.bodies.keys().copied().collect()
+    }
+
+    /// Add `force`/`torque` into `body_id`'s `xfrc_applied`-equivalent
+    /// wrench, accumulating with whatever another [`super::stimulus::Stimulus`]
+    /// already applied since the last [`Self::reset_external_wrenches`].
+    /// A `body_id` this FFI isn't tracking is silently ignored, the same
+    /// way a stale vehicle handle would be in a real MuJoCo model.
+    pub(crate) fn apply_external_wrench(
+        &mut self,
+        body_id: i32,
+        force: Vector3<f64>,
+        torque: Vector3<f64>,
+    ) {
+        if let Some(body) = self.bodies.get_mut(&body_id) {
+            body.external_force += force;
+            body.external_torque += torque;
+        }
+    }
+
+    /// Zero every tracked body's external wrench -- called once per
+    /// [`super::MuJoCoBackend::step`] iteration before replaying the
+    /// registered stimuli, so a stimulus that stops applying (e.g. past a
+    /// [`super::stimulus::WindowedImpulse`]'s window) doesn't leave a stale
+    /// force behind.
+    pub(crate) fn reset_external_wrenches(&mut self) {
+        for body in self.bodies.values_mut() {
+            body.external_force = Vector3::zeros();
+            body.external_torque = Vector3::zeros();
+        }
+    }
+
     /// Set actuator controls
     pub fn set_actuator_controls(
         &mut self,
+        body_id: i32,
         actuator_ids: &[i32],
         control: &VehicleControl,
     ) -> SimResult<()> {
@@ -249,29 +862,260 @@ impl MuJoCoFFI {
             ));
         }
 
+        if let Some(reaction_wheel) = self
+            .bodies
+            .get(&body_id)
+            .and_then(|body| body.reaction_wheel.clone())
+        {
+            self.set_reaction_wheel_controls(body_id, &reaction_wheel, control);
+            return Ok(());
+        }
+
         // In a full implementation, this would set data->ctrl[actuator_id]
-        // The control values are applied during the next mj_step()
+        // for every actuator; steering/brake/pitch/roll/yaw would each drive
+        // their own joint. Without per-joint actuators to target, only
+        // throttle is wired to something [`Self::step_body_substep`]
+        // actually integrates (see `thrust_command`) -- the rest are no-ops.
+        let _ = actuator_ids;
+        if let Some(body) = self.bodies.get_mut(&body_id) {
+            body.thrust_command = control.throttle;
+        }
 
-        // Map vehicle control to actuator commands
-        let _commands: Vec<f64> = actuator_ids
-            .iter()
-            .enumerate()
-            .map(|(i, _)| match i {
-                0 => control.throttle,
-                1 => control.steering,
-                2 => control.brake,
-                3 => control.pitch,
-                4 => control.roll,
-                5 => control.yaw,
-                _ => 0.0,
-            })
-            .collect();
+        Ok(())
+    }
+
+    /// Allocate `control`'s attitude demand across a spacecraft body's
+    /// reaction-wheel array and latch the result as `wheel_torque_commands`
+    /// for [`Self::step_body_substep`] to integrate.
+    ///
+    /// `control.roll`/`pitch`/`yaw` (each -1.0 to 1.0) are read as a
+    /// fraction of the array's total torque authority
+    /// (`max_wheel_torque * wheel_count`) about the body x/y/z axes
+    /// respectively.
+    fn set_reaction_wheel_controls(
+        &mut self,
+        body_id: i32,
+        reaction_wheel: &ReactionWheelConfig,
+        control: &VehicleControl,
+    ) {
+        let total_authority =
+            reaction_wheel.max_wheel_torque * reaction_wheel.wheel_axes.len() as f64;
+        let tau_des = Vector3::new(control.roll, control.pitch, control.yaw) * total_authority;
 
+        let Some(body) = self.bodies.get_mut(&body_id) else {
+            return;
+        };
+        body.wheel_torque_commands =
+            allocate_wheel_torques(reaction_wheel, tau_des, &body.wheel_speeds);
+    }
+
+    /// Read a [`ModelParameter`]'s current value.
+    pub fn get_parameter(&self, param: ModelParameter) -> SimResult<f64> {
+        let body = self.bodies.get(&param.body_id()).ok_or_else(|| {
+            SimError::BackendError(format!("body not found: {}", param.body_id()))
+        })?;
+        Ok(match param {
+            ModelParameter::BodyMass(_) => body.mass,
+            ModelParameter::DragCoefficient(_) => body.drag_coefficient,
+            ModelParameter::ActuatorGain(_) => body.actuator_gain,
+        })
+    }
+
+    /// Write a [`ModelParameter`], clamped to its physically valid range.
+    pub fn set_parameter(&mut self, param: ModelParameter, value: f64) -> SimResult<()> {
+        let value = param.clamp(value);
+        let body = self.bodies.get_mut(&param.body_id()).ok_or_else(|| {
+            SimError::BackendError(format!("body not found: {}", param.body_id()))
+        })?;
+        match param {
+            ModelParameter::BodyMass(_) => body.mass = value,
+            ModelParameter::DragCoefficient(_) => body.drag_coefficient = value,
+            ModelParameter::ActuatorGain(_) => body.actuator_gain = value,
+        }
         Ok(())
     }
 
+    /// Reset `body_id` to an at-rest state at `transform`, so successive
+    /// [`Self::fit_parameters`] rollouts all start from the same point.
+    fn reset_body_for_rollout(&mut self, body_id: i32, transform: &Transform) {
+        if let Some(body) = self.bodies.get_mut(&body_id) {
+            body.transform = transform.clone();
+            body.linear_velocity = Vector3::zeros();
+            body.angular_velocity = Vector3::zeros();
+            body.linear_acceleration = Vector3::zeros();
+            body.is_grounded = false;
+            body.tunneling_frames_remaining = 0;
+            body.tunneling_normal = Vector3::zeros();
+            body.thrust_command = 0.0;
+        }
+    }
+
+    /// Roll `body_id` forward through `measurements`' recorded controls
+    /// (each stepped for `dt`) and return the stacked per-axis position
+    /// residual `simulated - measured`.
+    fn rollout_residual(
+        &mut self,
+        body_id: i32,
+        initial_transform: &Transform,
+        measurements: &[RecordedSample],
+        dt: f64,
+    ) -> SimResult<Vec<f64>> {
+        self.reset_body_for_rollout(body_id, initial_transform);
+
+        let mut residual = Vec::with_capacity(measurements.len() * 3);
+        for sample in measurements {
+            match self.bodies.get_mut(&body_id) {
+                Some(body) => body.thrust_command = sample.control.throttle,
+                None => {
+                    return Err(SimError::BackendError(format!(
+                        "body not found: {}",
+                        body_id
+                    )))
+                }
+            }
+            self.step_body_substep(body_id, dt)?;
+
+            let position = self.bodies[&body_id].transform.position;
+            residual.push(position.x - sample.position.x);
+            residual.push(position.y - sample.position.y);
+            residual.push(position.z - sample.position.z);
+        }
+        Ok(residual)
+    }
+
+    /// Fit `params_to_vary` so that rolling `measurements`' recorded
+    /// controls through `body_id`'s dynamics reproduces `measurements`'
+    /// recorded positions as closely as possible, via damped least squares
+    /// (Levenberg-Marquardt).
+    ///
+    /// The residual vector stacks each timestep's `simulated - measured`
+    /// position (see [`Self::rollout_residual`]); the Jacobian is built by
+    /// finite differences (perturb one parameter, re-roll from `body_id`'s
+    /// current state, measure the change). `dt` is the per-measurement
+    /// integration step -- use the same value the trajectory was recorded
+    /// at. Parameters are bounded to physical ranges by [`ModelParameter::clamp`]
+    /// (e.g. masses stay positive), and the body is reset to its pre-fit
+    /// state between every rollout so each evaluation is independent, the
+    /// same way a real implementation would reset `mjData` before
+    /// re-integrating from `mjModel`.
+    pub fn fit_parameters(
+        &mut self,
+        body_id: i32,
+        measurements: &[RecordedSample],
+        params_to_vary: &[ModelParameter],
+        dt: f64,
+    ) -> SimResult<CalibrationResult> {
+        let Some(body) = self.bodies.get(&body_id) else {
+            return Err(SimError::BackendError(format!(
+                "body not found: {}",
+                body_id
+            )));
+        };
+        let initial_transform = body.transform.clone();
+
+        if measurements.is_empty() || params_to_vary.is_empty() {
+            return Ok(CalibrationResult {
+                fitted_values: Vec::new(),
+                final_cost: 0.0,
+                iterations: 0,
+            });
+        }
+
+        const MAX_ITERATIONS: usize = 50;
+        const FINITE_DIFF_EPS: f64 = 1e-4;
+        const CONVERGENCE_TOL: f64 = 1e-10;
+
+        let mut parameters: Vec<f64> = params_to_vary
+            .iter()
+            .map(|p| self.get_parameter(*p))
+            .collect::<SimResult<Vec<_>>>()?;
+        for (param, value) in params_to_vary.iter().zip(parameters.iter()) {
+            self.set_parameter(*param, *value)?;
+        }
+
+        let mut lambda = 1e-2;
+        let mut residual = self.rollout_residual(body_id, &initial_transform, measurements, dt)?;
+        let mut cost = 0.5 * residual.iter().map(|r| r * r).sum::<f64>();
+
+        let mut iterations = 0;
+        for _ in 0..MAX_ITERATIONS {
+            iterations += 1;
+
+            // Finite-difference Jacobian: one rollout per varied parameter,
+            // re-using the cached nominal rollout above as the baseline.
+            let mut columns = Vec::with_capacity(params_to_vary.len());
+            for (i, param) in params_to_vary.iter().enumerate() {
+                let step = FINITE_DIFF_EPS.max(FINITE_DIFF_EPS * parameters[i].abs());
+                self.set_parameter(*param, parameters[i] + step)?;
+                let perturbed =
+                    self.rollout_residual(body_id, &initial_transform, measurements, dt)?;
+                self.set_parameter(*param, parameters[i])?;
+                columns.push(
+                    perturbed
+                        .iter()
+                        .zip(residual.iter())
+                        .map(|(p, n)| (p - n) / step)
+                        .collect::<Vec<f64>>(),
+                );
+            }
+
+            let j = DMatrix::from_fn(residual.len(), parameters.len(), |r, c| columns[c][r]);
+            let r = DMatrix::from_column_slice(residual.len(), 1, &residual);
+            let jt = j.transpose();
+            let jtj = &jt * &j;
+            let jtr = &jt * &r;
+            let diag = DMatrix::from_diagonal(&jtj.diagonal());
+
+            let lhs = jtj + diag.scale(lambda);
+            let Some(lhs_inv) = lhs.try_inverse() else {
+                break;
+            };
+            let delta = -(lhs_inv * jtr);
+
+            let candidate: Vec<f64> = parameters
+                .iter()
+                .zip(delta.iter())
+                .zip(params_to_vary.iter())
+                .map(|((p, d), param)| param.clamp(p + d))
+                .collect();
+            for (param, value) in params_to_vary.iter().zip(candidate.iter()) {
+                self.set_parameter(*param, *value)?;
+            }
+            let candidate_residual =
+                self.rollout_residual(body_id, &initial_transform, measurements, dt)?;
+            let candidate_cost = 0.5 * candidate_residual.iter().map(|r| r * r).sum::<f64>();
+
+            if candidate_cost < cost {
+                let improvement = cost - candidate_cost;
+                parameters = candidate;
+                residual = candidate_residual;
+                cost = candidate_cost;
+                lambda = (lambda / 10.0).max(1e-12);
+                if improvement < CONVERGENCE_TOL {
+                    break;
+                }
+            } else {
+                for (param, value) in params_to_vary.iter().zip(parameters.iter()) {
+                    self.set_parameter(*param, *value)?;
+                }
+                lambda *= 10.0;
+                if lambda > 1e12 {
+                    break;
+                }
+            }
+        }
+
+        self.reset_body_for_rollout(body_id, &initial_transform);
+
+        Ok(CalibrationResult {
+            fitted_values: parameters,
+            final_cost: cost,
+            iterations,
+        })
+    }
+
     /// Get sensor data
-    pub fn get_sensor_data(&self, body_id: i32, sensor_id: &str) -> SimResult<SensorData> {
+    pub fn get_sensor_data(&mut self, body_id: i32, sensor_id: &str) -> SimResult<SensorData> {
         if !self.initialized {
             return Err(SimError::NotInitialized(
                 "MuJoCo not initialized".to_string(),
@@ -284,14 +1128,8 @@ impl MuJoCoFFI {
         // - Magnetometer sensor
         // - Touch/force sensors
 
-        // Generate synthetic sensor data
         match sensor_id {
-            "imu" => Ok(SensorData::Imu(ImuData {
-                timestamp: 0.0,
-                linear_acceleration: Vector3::new(0.0, 0.0, 9.81),
-                angular_velocity: Vector3::zeros(),
-                orientation: UnitQuaternion::identity(),
-            })),
+            "imu" => self.imu_sensor_data(body_id),
             "gps" => Ok(SensorData::Gps(GpsData {
                 timestamp: 0.0,
                 latitude: 0.0,
@@ -302,10 +1140,7 @@ impl MuJoCoFFI {
                 epv: 0.8,
                 fix_type: GpsFixType::Fix3D,
             })),
-            "magnetometer" => Ok(SensorData::Magnetometer(MagnetometerData {
-                timestamp: 0.0,
-                magnetic_field: Vector3::new(0.3, 0.0, 0.5),
-            })),
+            "magnetometer" => self.magnetometer_sensor_data(body_id),
             "barometer" => Ok(SensorData::Barometer(BarometerData {
                 timestamp: 0.0,
                 pressure: 101325.0,
@@ -318,6 +1153,86 @@ impl MuJoCoFFI {
             ))),
         }
     }
+
+    /// Synthesize an accelerometer/gyro reading for `body_id`, applying
+    /// [`Self::imu_error_model`] to the body's true specific force and
+    /// angular velocity. A body id this FFI never created via
+    /// [`Self::create_body`] (shouldn't happen in practice) reads as
+    /// stationary.
+    fn imu_sensor_data(&mut self, body_id: i32) -> SimResult<SensorData> {
+        let dt = self.config.timestep.max(1e-6);
+        let accel_bias_walk_std = self.imu_error_model.accel_bias_walk_std;
+        let gyro_bias_walk_std = self.imu_error_model.gyro_bias_walk_std;
+        let accel_scale = self.imu_error_model.accel_scale_misalignment;
+        let gyro_scale = self.imu_error_model.gyro_scale_misalignment;
+        let accel_noise_std = self.imu_error_model.accel_noise_std;
+        let gyro_noise_std = self.imu_error_model.gyro_noise_std;
+        let accel_full_scale = self.imu_error_model.accel_full_scale;
+        let gyro_full_scale = self.imu_error_model.gyro_full_scale;
+        let bit_resolution = self.imu_error_model.bit_resolution;
+
+        let body = self
+            .bodies
+            .entry(body_id)
+            .or_insert_with(|| BodyPhysicsState::at_rest(Transform::identity()));
+
+        body.imu_accel_bias += gaussian_noise_vec3(accel_bias_walk_std * dt.sqrt());
+        body.imu_gyro_bias += gaussian_noise_vec3(gyro_bias_walk_std * dt.sqrt());
+
+        // Specific force (what an accelerometer actually measures) is the
+        // non-gravitational part of acceleration, rotated into the body
+        // frame.
+        let gravity = Vector3::new(0.0, 0.0, -9.80665);
+        let specific_force_world = body.linear_acceleration - gravity;
+        let specific_force_body = body.transform.rotation.inverse() * specific_force_world;
+
+        let accel_raw = accel_scale * specific_force_body
+            + body.imu_accel_bias
+            + gaussian_noise_vec3(accel_noise_std);
+        let gyro_raw = gyro_scale * body.angular_velocity
+            + body.imu_gyro_bias
+            + gaussian_noise_vec3(gyro_noise_std);
+        let orientation = body.transform.rotation;
+
+        Ok(SensorData::Imu(ImuData {
+            timestamp: 0.0,
+            linear_acceleration: clamp_and_quantize_vec3(
+                accel_raw,
+                accel_full_scale,
+                bit_resolution,
+            ),
+            angular_velocity: clamp_and_quantize_vec3(gyro_raw, gyro_full_scale, bit_resolution),
+            orientation,
+        }))
+    }
+
+    /// Synthesize a magnetometer reading for `body_id`, applying
+    /// [`Self::magnetometer_error_model`] to Earth's field as seen in the
+    /// body frame.
+    fn magnetometer_sensor_data(&mut self, body_id: i32) -> SimResult<SensorData> {
+        let soft_iron = self.magnetometer_error_model.soft_iron;
+        let hard_iron_offset = self.magnetometer_error_model.hard_iron_offset;
+        let noise_std = self.magnetometer_error_model.noise_std;
+
+        let body = self
+            .bodies
+            .entry(body_id)
+            .or_insert_with(|| BodyPhysicsState::at_rest(Transform::identity()));
+
+        // Typical mid-latitude Earth field, Gauss, expressed in the world
+        // frame and rotated into the body frame the way a real compass
+        // reads it.
+        let earth_field_world = Vector3::new(0.2, 0.0, 0.45);
+        let earth_field_body = body.transform.rotation.inverse() * earth_field_world;
+
+        let magnetic_field =
+            soft_iron * earth_field_body + hard_iron_offset + gaussian_noise_vec3(noise_std);
+
+        Ok(SensorData::Magnetometer(MagnetometerData {
+            timestamp: 0.0,
+            magnetic_field,
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -356,4 +1271,362 @@ mod tests {
         let hit = result.unwrap();
         assert!((hit.distance - 10.0).abs() < 0.01);
     }
+
+    /// A body at rest at height `z` with a straight-down velocity of
+    /// `velocity_z` -- used to exercise the swept-collision path.
+    fn falling_body(z: f64, velocity_z: f64) -> BodyPhysicsState {
+        let mut body = BodyPhysicsState::at_rest(Transform::new(
+            Point3::new(0.0, 0.0, z),
+            UnitQuaternion::identity(),
+        ));
+        body.linear_velocity = Vector3::new(0.0, 0.0, velocity_z);
+        body
+    }
+
+    #[test]
+    fn a_fast_body_is_clamped_at_the_surface_instead_of_tunneling_through_it() {
+        let config = MuJoCoConfig::default();
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+
+        // Falling at 5000 m/s with a 2ms timestep would naively land at
+        // z = 5 - 10 = -5, straight through the ground plane at z = 0.
+        let body_id = 0;
+        ffi.bodies.insert(body_id, falling_body(5.0, -5000.0));
+
+        ffi.step().unwrap();
+
+        let state = ffi.get_body_state(body_id, "drone-1").unwrap();
+        assert!(
+            state.transform.position.z >= 0.0,
+            "body tunneled through the ground: z = {}",
+            state.transform.position.z
+        );
+        assert!(state.transform.position.z < 0.1);
+        assert!(state.is_grounded);
+    }
+
+    #[test]
+    fn corrective_velocity_keeps_a_recovering_body_from_immediately_re_penetrating() {
+        let config = MuJoCoConfig::default();
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+
+        let body_id = 0;
+        ffi.bodies.insert(body_id, falling_body(5.0, -5000.0));
+
+        ffi.step().unwrap();
+        let after_first_hit = ffi.get_body_state(body_id, "drone-1").unwrap();
+
+        ffi.step().unwrap();
+        let after_recovery_step = ffi.get_body_state(body_id, "drone-1").unwrap();
+
+        // The recovery step should not have driven the body back underground.
+        assert!(after_recovery_step.transform.position.z >= 0.0);
+        assert!(
+            (after_recovery_step.transform.position.z - after_first_hit.transform.position.z).abs()
+                < 0.1
+        );
+    }
+
+    #[test]
+    fn raising_substeps_still_catches_a_body_fast_enough_to_skip_a_whole_timestep() {
+        let config = MuJoCoConfig {
+            substeps: 8,
+            ..MuJoCoConfig::default()
+        };
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+
+        let body_id = 0;
+        ffi.bodies.insert(body_id, falling_body(5.0, -5000.0));
+
+        ffi.step().unwrap();
+
+        let state = ffi.get_body_state(body_id, "drone-1").unwrap();
+        assert!(state.transform.position.z >= 0.0);
+        assert!(state.is_grounded);
+    }
+
+    #[test]
+    fn a_hovering_body_s_imu_reads_close_to_one_g_up() {
+        let config = MuJoCoConfig::default();
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+
+        // No gravity-compensating acceleration is tracked for a body this
+        // FFI never steps, so a body at rest reads the accelerometer's
+        // specific force as +g -- exactly what a stationary real IMU reads.
+        let body_id = 0;
+        ffi.bodies.insert(
+            body_id,
+            BodyPhysicsState::at_rest(Transform::new(
+                Point3::new(0.0, 0.0, 1.0),
+                UnitQuaternion::identity(),
+            )),
+        );
+
+        ffi.set_imu_error_model(ImuErrorModel {
+            accel_noise_std: 0.0,
+            gyro_noise_std: 0.0,
+            accel_bias_walk_std: 0.0,
+            gyro_bias_walk_std: 0.0,
+            ..ImuErrorModel::default()
+        });
+
+        match ffi.get_sensor_data(body_id, "imu").unwrap() {
+            SensorData::Imu(imu) => {
+                assert!((imu.linear_acceleration.z - 9.80665).abs() < 0.05);
+                assert!(imu.angular_velocity.norm() < 1e-6);
+            }
+            other => panic!("expected Imu sensor data, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn imu_bias_random_walk_accumulates_across_repeated_samples() {
+        let config = MuJoCoConfig::default();
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+        let body_id = 0;
+        ffi.bodies
+            .insert(body_id, BodyPhysicsState::at_rest(Transform::identity()));
+        ffi.set_imu_error_model(ImuErrorModel {
+            accel_noise_std: 0.0,
+            gyro_noise_std: 0.0,
+            accel_bias_walk_std: 1.0,
+            gyro_bias_walk_std: 1.0,
+            ..ImuErrorModel::default()
+        });
+
+        for _ in 0..50 {
+            ffi.get_sensor_data(body_id, "imu").unwrap();
+        }
+
+        let drifted_bias = ffi.bodies[&body_id].imu_accel_bias.norm();
+        assert!(drifted_bias > 0.0, "bias should have drifted from zero");
+    }
+
+    #[test]
+    fn full_scale_clamp_and_quantization_bound_imu_readings() {
+        let config = MuJoCoConfig::default();
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+        let body_id = 0;
+        let mut body = BodyPhysicsState::at_rest(Transform::identity());
+        // A wildly out-of-range acceleration, to exercise the clamp.
+        body.linear_acceleration = Vector3::new(0.0, 0.0, 10_000.0);
+        ffi.bodies.insert(body_id, body);
+
+        ffi.set_imu_error_model(ImuErrorModel {
+            accel_noise_std: 0.0,
+            gyro_noise_std: 0.0,
+            accel_bias_walk_std: 0.0,
+            gyro_bias_walk_std: 0.0,
+            ..ImuErrorModel::default()
+        });
+
+        match ffi.get_sensor_data(body_id, "imu").unwrap() {
+            SensorData::Imu(imu) => {
+                let full_scale = ImuErrorModel::default().accel_full_scale;
+                assert!(imu.linear_acceleration.z <= full_scale);
+                assert!(imu.linear_acceleration.z >= -full_scale);
+            }
+            other => panic!("expected Imu sensor data, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn magnetometer_applies_hard_iron_offset() {
+        let config = MuJoCoConfig::default();
+        let mut ffi = MuJoCoFFI::new(config).unwrap();
+        let body_id = 0;
+        ffi.bodies
+            .insert(body_id, BodyPhysicsState::at_rest(Transform::identity()));
+
+        let offset = Vector3::new(0.1, -0.2, 0.05);
+        ffi.set_magnetometer_error_model(MagnetometerErrorModel {
+            noise_std: 0.0,
+            hard_iron_offset: offset,
+            ..MagnetometerErrorModel::default()
+        });
+
+        match ffi.get_sensor_data(body_id, "magnetometer").unwrap() {
+            SensorData::Magnetometer(mag) => {
+                let expected = Vector3::new(0.2, 0.0, 0.45) + offset;
+                assert!((mag.magnetic_field - expected).norm() < 1e-9);
+            }
+            other => panic!("expected Magnetometer sensor data, got {:?}", other),
+        }
+    }
+
+    fn spacecraft_spec() -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: "sat1".to_string(),
+            vehicle_type: VehicleType::Spacecraft,
+            initial_transform: Transform::identity(),
+            parameters: autonomysim_core::vehicle::VehicleParameters::default(),
+            sensors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn create_actuators_makes_one_per_reaction_wheel() {
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = spacecraft_spec();
+        let body_id = ffi.create_body(&spec).unwrap();
+
+        let actuator_ids = ffi.create_actuators(&spec, body_id).unwrap();
+
+        assert_eq!(
+            actuator_ids.len(),
+            ReactionWheelConfig::four_wheel_pyramid().wheel_axes.len()
+        );
+    }
+
+    #[test]
+    fn reaction_wheel_controls_spin_up_wheels_toward_the_commanded_torque() {
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = spacecraft_spec();
+        let body_id = ffi.create_body(&spec).unwrap();
+        let actuator_ids = ffi.create_actuators(&spec, body_id).unwrap();
+
+        let control = VehicleControl {
+            yaw: 1.0,
+            ..Default::default()
+        };
+        ffi.set_actuator_controls(body_id, &actuator_ids, &control)
+            .unwrap();
+
+        assert!(ffi.bodies[&body_id]
+            .wheel_torque_commands
+            .iter()
+            .any(|&t| t != 0.0));
+
+        ffi.step().unwrap();
+
+        assert!(ffi.bodies[&body_id]
+            .wheel_speeds
+            .iter()
+            .any(|&speed| speed != 0.0));
+    }
+
+    #[test]
+    fn reaction_wheel_commands_never_exceed_the_configured_per_wheel_limit() {
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = spacecraft_spec();
+        let body_id = ffi.create_body(&spec).unwrap();
+        let actuator_ids = ffi.create_actuators(&spec, body_id).unwrap();
+
+        let control = VehicleControl {
+            roll: 1.0,
+            pitch: -1.0,
+            yaw: 1.0,
+            ..Default::default()
+        };
+        ffi.set_actuator_controls(body_id, &actuator_ids, &control)
+            .unwrap();
+
+        let limit = ReactionWheelConfig::four_wheel_pyramid().max_wheel_torque;
+        for &command in &ffi.bodies[&body_id].wheel_torque_commands {
+            assert!(command.abs() <= limit + 1e-9);
+        }
+    }
+
+    fn multirotor_spec(mass: f64, drag_coefficient: f64) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: "drone-1".to_string(),
+            vehicle_type: VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: autonomysim_core::vehicle::VehicleParameters {
+                mass,
+                drag_coefficient,
+                ..autonomysim_core::vehicle::VehicleParameters::default()
+            },
+            sensors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn throttle_accelerates_a_body_upward_proportional_to_actuator_gain_over_mass() {
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = multirotor_spec(2.0, 0.0);
+        let body_id = ffi.create_body(&spec).unwrap();
+        let actuator_ids = ffi.create_actuators(&spec, body_id).unwrap();
+
+        let control = VehicleControl {
+            throttle: 1.0,
+            ..Default::default()
+        };
+        ffi.set_actuator_controls(body_id, &actuator_ids, &control)
+            .unwrap();
+        ffi.step().unwrap();
+
+        let state = ffi.get_body_state(body_id, "drone-1").unwrap();
+        assert!(state.linear_velocity.z > 0.0);
+        assert!((state.linear_acceleration.z - 0.5).abs() < 1e-6);
+    }
+
+    /// Roll a known-mass body through a few throttled substeps and collect
+    /// the resulting trajectory as if it had come from a real sensor log.
+    fn record_trajectory(true_mass: f64, dt: f64, steps: usize) -> Vec<RecordedSample> {
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = multirotor_spec(true_mass, 0.0);
+        let body_id = ffi.create_body(&spec).unwrap();
+
+        let control = VehicleControl {
+            throttle: 0.5,
+            ..Default::default()
+        };
+        let mut samples = Vec::with_capacity(steps);
+        for _ in 0..steps {
+            ffi.bodies.get_mut(&body_id).unwrap().thrust_command = control.throttle;
+            ffi.step_body_substep(body_id, dt).unwrap();
+            samples.push(RecordedSample {
+                control,
+                position: ffi.bodies[&body_id].transform.position,
+            });
+        }
+        samples
+    }
+
+    #[test]
+    fn fit_parameters_recovers_a_known_mass_from_a_recorded_trajectory() {
+        let true_mass = 3.0;
+        let dt = 0.01;
+        let measurements = record_trajectory(true_mass, dt, 20);
+
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = multirotor_spec(1.0, 0.0); // deliberately wrong initial guess
+        let body_id = ffi.create_body(&spec).unwrap();
+
+        let result = ffi
+            .fit_parameters(
+                body_id,
+                &measurements,
+                &[ModelParameter::BodyMass(body_id)],
+                dt,
+            )
+            .unwrap();
+
+        assert_eq!(result.fitted_values.len(), 1);
+        assert!(
+            (result.fitted_values[0] - true_mass).abs() < 1e-3,
+            "fitted mass {} should converge to {}",
+            result.fitted_values[0],
+            true_mass
+        );
+        assert!(result.final_cost < 1e-12);
+    }
+
+    #[test]
+    fn fit_parameters_with_no_measurements_returns_an_empty_result() {
+        let mut ffi = MuJoCoFFI::new(MuJoCoConfig::default()).unwrap();
+        let spec = multirotor_spec(1.0, 0.0);
+        let body_id = ffi.create_body(&spec).unwrap();
+
+        let result = ffi
+            .fit_parameters(body_id, &[], &[ModelParameter::BodyMass(body_id)], 0.01)
+            .unwrap();
+
+        assert!(result.fitted_values.is_empty());
+        assert_eq!(result.iterations, 0);
+    }
 }
@@ -0,0 +1,87 @@
+//! Pluggable per-step measurement probes for [`super::MuJoCoBackend`].
+//!
+//! Each [`AbstractMeasurement`] samples a derived physical quantity off
+//! [`MuJoCoFFI`] after every internal step; results land in a bounded,
+//! per-name ring buffer read back via
+//! [`super::MuJoCoBackend::drain_measurements`]/[`super::MuJoCoBackend::latest`].
+
+use nalgebra::Vector3;
+
+use super::ffi::MuJoCoFFI;
+
+/// A single measurement's sampled value: either a scalar quantity (energy,
+/// power, force magnitude) or a 3-vector (e.g. a position).
+#[derive(Debug, Clone, Copy)]
+pub enum MeasurementValue {
+    Scalar(f64),
+    Vector3(Vector3<f64>),
+}
+
+/// One timestamped entry in a measurement's ring buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct MeasurementSample {
+    pub time: f64,
+    pub value: MeasurementValue,
+}
+
+/// A probe sampled once per internal [`MuJoCoFFI::step`] call and recorded
+/// under its [`Self::name`] -- see [`super::MuJoCoBackend::add_measurement`].
+pub trait AbstractMeasurement: Send + Sync {
+    fn name(&self) -> &str;
+    fn sample(&self, ffi: &MuJoCoFFI, time: f64) -> MeasurementValue;
+}
+
+/// Total kinetic energy (J) across every body in the scene.
+pub struct TotalKineticEnergy;
+
+impl AbstractMeasurement for TotalKineticEnergy {
+    fn name(&self) -> &str {
+        "total_kinetic_energy"
+    }
+
+    fn sample(&self, ffi: &MuJoCoFFI, _time: f64) -> MeasurementValue {
+        MeasurementValue::Scalar(ffi.total_kinetic_energy())
+    }
+}
+
+/// Magnitude of the net contact-correction force (N) applied across every
+/// body during the most recently completed step.
+pub struct NetContactForce;
+
+impl AbstractMeasurement for NetContactForce {
+    fn name(&self) -> &str {
+        "net_contact_force"
+    }
+
+    fn sample(&self, ffi: &MuJoCoFFI, _time: f64) -> MeasurementValue {
+        MeasurementValue::Scalar(ffi.net_contact_force_magnitude())
+    }
+}
+
+/// Total actuator power (W), summed as `ctrl * actuator_velocity` across
+/// every body's thrust and reaction-wheel actuators.
+pub struct ActuatorPower;
+
+impl AbstractMeasurement for ActuatorPower {
+    fn name(&self) -> &str {
+        "actuator_power"
+    }
+
+    fn sample(&self, ffi: &MuJoCoFFI, _time: f64) -> MeasurementValue {
+        MeasurementValue::Scalar(ffi.total_actuator_power())
+    }
+}
+
+/// Mass-weighted center of mass across every body in the scene.
+pub struct BodyComPosition;
+
+impl AbstractMeasurement for BodyComPosition {
+    fn name(&self) -> &str {
+        "body_com_position"
+    }
+
+    fn sample(&self, ffi: &MuJoCoFFI, _time: f64) -> MeasurementValue {
+        let com = ffi.system_com_position();
+        MeasurementValue::Vector3(com.coords)
+    }
+}
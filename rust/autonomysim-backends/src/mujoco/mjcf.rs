@@ -0,0 +1,253 @@
+//! In-memory MJCF authoring layer backing [`super::ffi::MuJoCoFFI`]'s
+//! `add_scene_object`/`remove_scene_object`/`update_object_transform`.
+//!
+//! Each mutation updates this document; [`super::ffi::MuJoCoFFI::recompile`]
+//! then serializes it via [`MjcfDocument::to_mjcf_xml`] and bumps the
+//! compiled model id, the same way editing a real `.xml` model and calling
+//! `mj_loadXML`/`mj_compile` again would.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use autonomysim_core::backend::{Geometry, Material, Position, SceneObject};
+use autonomysim_core::{SimError, SimResult, Transform};
+
+struct MjcfObject {
+    body_id: i32,
+    object: SceneObject,
+}
+
+/// The scene-authored (non-vehicle) half of the compiled model: objects
+/// added via [`autonomysim_core::backend::SimulationBackend::add_object`],
+/// keyed by [`SceneObject::id`].
+#[derive(Default)]
+pub(crate) struct MjcfDocument {
+    objects: HashMap<String, MjcfObject>,
+}
+
+impl MjcfDocument {
+    /// Track `object` under `body_id`, the id [`super::ffi::MuJoCoFFI`]
+    /// assigned it from its shared body-id counter.
+    pub(crate) fn insert(&mut self, body_id: i32, object: SceneObject) {
+        self.objects
+            .insert(object.id.clone(), MjcfObject { body_id, object });
+    }
+
+    /// Drop `object_id`, freeing nothing else: its `body_id` is never
+    /// reused, so every other object's and vehicle's id stays valid across
+    /// the next [`super::ffi::MuJoCoFFI::recompile`].
+    pub(crate) fn remove(&mut self, object_id: &str) -> SimResult<i32> {
+        self.objects
+            .remove(object_id)
+            .map(|removed| removed.body_id)
+            .ok_or_else(|| SimError::BackendError(format!("Object not found: {}", object_id)))
+    }
+
+    pub(crate) fn update_transform(
+        &mut self,
+        object_id: &str,
+        transform: Transform,
+    ) -> SimResult<()> {
+        let entry = self
+            .objects
+            .get_mut(object_id)
+            .ok_or_else(|| SimError::BackendError(format!("Object not found: {}", object_id)))?;
+        entry.object.transform = transform;
+        Ok(())
+    }
+
+    pub(crate) fn objects(&self) -> Vec<SceneObject> {
+        self.objects
+            .values()
+            .map(|entry| entry.object.clone())
+            .collect()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Axis-aligned bounds across every tracked object's geometry, using
+    /// each shape's own extent (a box's half-size, a sphere/cylinder's
+    /// radius, a mesh's vertex bounds) around its world position.
+    pub(crate) fn bounds(&self) -> (Position, Position) {
+        let mut min = Position::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = Position::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+
+        for entry in self.objects.values() {
+            let center = entry.object.transform.position;
+            let (local_min, local_max) = geometry_extent(&entry.object.geometry);
+            min = Position::new(
+                min.x.min(center.x + local_min.x),
+                min.y.min(center.y + local_min.y),
+                min.z.min(center.z + local_min.z),
+            );
+            max = Position::new(
+                max.x.max(center.x + local_max.x),
+                max.y.max(center.y + local_max.y),
+                max.z.max(center.z + local_max.z),
+            );
+        }
+
+        (min, max)
+    }
+
+    /// Render every tracked object as a `<body>`/`<geom>` pair (plus a
+    /// `<material>` asset) under `<worldbody>` in a minimal MJCF document
+    /// named `model_name`.
+    pub(crate) fn to_mjcf_xml(&self, model_name: &str) -> String {
+        let mut xml = String::new();
+        let _ = writeln!(xml, "<mujoco model=\"{}\">", escape(model_name));
+        let _ = writeln!(xml, "  <asset>");
+        for entry in self.objects.values() {
+            let _ = writeln!(
+                xml,
+                "    <material name=\"{}\" rgba=\"1 1 1 {}\"/>",
+                escape(&entry.object.material.name),
+                1.0 - entry.object.material.roughness.clamp(0.0, 1.0)
+            );
+        }
+        let _ = writeln!(xml, "  </asset>");
+        let _ = writeln!(xml, "  <worldbody>");
+        for entry in self.objects.values() {
+            let position = entry.object.transform.position;
+            let rotation = entry.object.transform.rotation;
+            let _ = writeln!(
+                xml,
+                "    <body name=\"{}\" pos=\"{} {} {}\" quat=\"{} {} {} {}\">",
+                escape(&entry.object.id),
+                position.x,
+                position.y,
+                position.z,
+                rotation.w,
+                rotation.i,
+                rotation.j,
+                rotation.k
+            );
+            let _ = writeln!(
+                xml,
+                "      <geom {} material=\"{}\"/>",
+                geometry_attrs(&entry.object.geometry),
+                escape(&entry.object.material.name)
+            );
+            let _ = writeln!(xml, "    </body>");
+        }
+        let _ = writeln!(xml, "  </worldbody>");
+        let _ = writeln!(xml, "</mujoco>");
+        xml
+    }
+}
+
+/// `type="..."` plus the size attribute MJCF expects for `geometry`.
+fn geometry_attrs(geometry: &Geometry) -> String {
+    match geometry {
+        Geometry::Box { size } => format!(
+            "type=\"box\" size=\"{} {} {}\"",
+            size.x / 2.0,
+            size.y / 2.0,
+            size.z / 2.0
+        ),
+        Geometry::Sphere { radius } => format!("type=\"sphere\" size=\"{}\"", radius),
+        Geometry::Cylinder { radius, height } => {
+            format!("type=\"cylinder\" size=\"{} {}\"", radius, height / 2.0)
+        }
+        Geometry::Mesh { .. } => "type=\"mesh\" mesh=\"generated\"".to_string(),
+    }
+}
+
+/// Local-space `(min, max)` extent of `geometry` around its body origin.
+fn geometry_extent(geometry: &Geometry) -> (nalgebra::Vector3<f64>, nalgebra::Vector3<f64>) {
+    use nalgebra::Vector3;
+
+    match geometry {
+        Geometry::Box { size } => (-size / 2.0, size / 2.0),
+        Geometry::Sphere { radius } => (
+            Vector3::new(-radius, -radius, -radius),
+            Vector3::new(*radius, *radius, *radius),
+        ),
+        Geometry::Cylinder { radius, height } => (
+            Vector3::new(-radius, -radius, -height / 2.0),
+            Vector3::new(*radius, *radius, height / 2.0),
+        ),
+        Geometry::Mesh { vertices, .. } => {
+            let mut min = Vector3::new(0.0, 0.0, 0.0);
+            let mut max = Vector3::new(0.0, 0.0, 0.0);
+            for vertex in vertices {
+                min = Vector3::new(
+                    min.x.min(vertex.x),
+                    min.y.min(vertex.y),
+                    min.z.min(vertex.z),
+                );
+                max = Vector3::new(
+                    max.x.max(vertex.x),
+                    max.y.max(vertex.y),
+                    max.z.max(vertex.z),
+                );
+            }
+            (min, max)
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+    fn box_object(id: &str, position: Point3<f64>) -> SceneObject {
+        SceneObject {
+            id: id.to_string(),
+            name: id.to_string(),
+            transform: Transform::new(position, UnitQuaternion::identity()),
+            geometry: Geometry::Box {
+                size: Vector3::new(2.0, 2.0, 2.0),
+            },
+            material: Material::concrete(),
+        }
+    }
+
+    #[test]
+    fn insert_and_remove_round_trip() {
+        let mut doc = MjcfDocument::default();
+        doc.insert(5, box_object("crate1", Point3::origin()));
+        assert_eq!(doc.objects().len(), 1);
+
+        let freed_body_id = doc.remove("crate1").unwrap();
+        assert_eq!(freed_body_id, 5);
+        assert!(doc.is_empty());
+    }
+
+    #[test]
+    fn remove_missing_object_errors() {
+        let mut doc = MjcfDocument::default();
+        assert!(doc.remove("missing").is_err());
+    }
+
+    #[test]
+    fn bounds_cover_every_object_geometry() {
+        let mut doc = MjcfDocument::default();
+        doc.insert(1, box_object("crate1", Point3::new(10.0, 0.0, 0.0)));
+        doc.insert(2, box_object("crate2", Point3::new(-10.0, 0.0, 0.0)));
+
+        let (min, max) = doc.bounds();
+        assert!((min.x - (-11.0)).abs() < 1e-9);
+        assert!((max.x - 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_mjcf_xml_includes_every_object_body() {
+        let mut doc = MjcfDocument::default();
+        doc.insert(1, box_object("crate1", Point3::origin()));
+
+        let xml = doc.to_mjcf_xml("test_scene");
+        assert!(xml.contains("<mujoco model=\"test_scene\">"));
+        assert!(xml.contains("name=\"crate1\""));
+    }
+}
@@ -5,28 +5,53 @@
 
 use async_trait::async_trait;
 use nalgebra::Point3;
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, RwLock};
 
 use autonomysim_core::{
     backend::{
-        BackendConfig, BackendType, Material, Position, Ray, RayHit, SceneHandle, SceneObject,
-        SimResult, SimulationBackend, Transform,
+        BackendConfig, BackendType, Material, Position, Ray, RayHit, RfPath, SceneHandle,
+        SceneObject, SimResult, SimulationBackend, Transform,
     },
     sensor::SensorData,
-    vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState},
+    vehicle::{SensorFault, VehicleControl, VehicleId, VehicleSpec, VehicleState},
     SimError,
 };
 
 mod ffi;
 use ffi::MuJoCoFFI;
 
+mod mjcf;
+
+mod measurement;
+pub use measurement::{
+    AbstractMeasurement, ActuatorPower, BodyComPosition, MeasurementSample, MeasurementValue,
+    NetContactForce, TotalKineticEnergy,
+};
+
+mod stimulus;
+pub use stimulus::{
+    ConstantForce, SinusoidalForce, StimuliVec, Stimulus, WindField, WindowedImpulse,
+};
+
+mod driver;
+pub use driver::MuJoCoDriver;
+
+/// Cap on how many samples [`MuJoCoBackend::measurement_history`] keeps per
+/// measurement name before it starts dropping the oldest.
+const MEASUREMENT_HISTORY_CAPACITY: usize = 1000;
+
 /// Configuration for MuJoCo backend
 #[derive(Debug, Clone)]
 pub struct MuJoCoConfig {
     /// Timestep in seconds
     pub timestep: f64,
-    /// Number of simulation substeps per step
+    /// Number of simulation substeps per step. [`MuJoCoFFI::step`] also uses
+    /// this to subdivide its swept-collision check: each substep re-casts a
+    /// ray from a body's previous position to its candidate new one, so
+    /// raising this catches fast bodies that would otherwise tunnel through
+    /// thin geometry within a single `timestep`.
     pub substeps: usize,
     /// Enable contact detection
     pub enable_contact: bool,
@@ -36,6 +61,13 @@ pub struct MuJoCoConfig {
     pub solver_iterations: usize,
     /// Path to MuJoCo model XML (optional)
     pub model_path: Option<String>,
+    /// Whether `cast_rays` should fan out across a rayon parallel iterator.
+    pub parallel_ray_casting: bool,
+    /// Rays per rayon work item; `None` lets rayon pick automatically.
+    pub ray_cast_chunk_size: Option<usize>,
+    /// Dedicated thread pool size for ray casting; `None` falls back to
+    /// rayon's global pool.
+    pub ray_cast_num_threads: Option<usize>,
 }
 
 impl Default for MuJoCoConfig {
@@ -47,6 +79,9 @@ impl Default for MuJoCoConfig {
             enable_limits: true,
             solver_iterations: 100,
             model_path: None,
+            parallel_ray_casting: true,
+            ray_cast_chunk_size: None,
+            ray_cast_num_threads: None,
         }
     }
 }
@@ -65,6 +100,20 @@ pub struct MuJoCoBackend {
     initialized: bool,
     /// Backend configuration
     config: MuJoCoConfig,
+    /// Measurement probes sampled once per internal [`MuJoCoFFI::step`] call
+    /// -- see [`Self::add_measurement`].
+    measurements: Vec<Arc<dyn AbstractMeasurement>>,
+    /// Recorded samples per measurement name, bounded to
+    /// [`MEASUREMENT_HISTORY_CAPACITY`] -- see [`Self::drain_measurements`]/
+    /// [`Self::latest`].
+    measurement_history: HashMap<String, VecDeque<MeasurementSample>>,
+    /// Scripted external disturbances, replayed every [`Self::step`]
+    /// iteration -- see [`Self::add_stimulus`].
+    stimuli: StimuliVec,
+    /// Dedicated thread pool for `cast_rays` when
+    /// `config.ray_cast_num_threads` is set; `None` falls back to rayon's
+    /// global pool.
+    ray_cast_pool: Option<rayon::ThreadPool>,
 }
 
 #[derive(Debug, Clone)]
@@ -91,6 +140,10 @@ impl MuJoCoBackend {
             time: 0.0,
             initialized: false,
             config: MuJoCoConfig::default(),
+            measurements: Vec::new(),
+            measurement_history: HashMap::new(),
+            stimuli: Vec::new(),
+            ray_cast_pool: None,
         }
     }
 
@@ -103,6 +156,10 @@ impl MuJoCoBackend {
             time: 0.0,
             initialized: false,
             config,
+            measurements: Vec::new(),
+            measurement_history: HashMap::new(),
+            stimuli: Vec::new(),
+            ray_cast_pool: None,
         }
     }
 
@@ -110,6 +167,51 @@ impl MuJoCoBackend {
     pub fn config(&self) -> &MuJoCoConfig {
         &self.config
     }
+
+    /// Register a measurement probe, sampled once per internal step inside
+    /// [`SimulationBackend::step`] and recorded under its
+    /// [`AbstractMeasurement::name`].
+    pub fn add_measurement(&mut self, measurement: Arc<dyn AbstractMeasurement>) {
+        self.measurements.push(measurement);
+    }
+
+    /// Drain and return every measurement sample recorded since the last
+    /// drain, keyed by measurement name.
+    pub fn drain_measurements(&mut self) -> HashMap<String, Vec<MeasurementSample>> {
+        self.measurement_history
+            .drain()
+            .map(|(name, samples)| (name, samples.into_iter().collect()))
+            .collect()
+    }
+
+    /// The most recently recorded sample for `name`, if that measurement has
+    /// been registered and sampled at least once.
+    pub fn latest(&self, name: &str) -> Option<&MeasurementSample> {
+        self.measurement_history
+            .get(name)
+            .and_then(|samples| samples.back())
+    }
+
+    /// Resolve a vehicle's body id, for constructing a body-targeted
+    /// [`Stimulus`] (e.g. [`ConstantForce::body_id`]).
+    pub fn body_id_for_vehicle(&self, vehicle_id: &str) -> SimResult<i32> {
+        self.vehicles
+            .get(vehicle_id)
+            .map(|handle| handle.body_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle not found: {}", vehicle_id)))
+    }
+
+    /// Register a stimulus, replayed every [`SimulationBackend::step`]
+    /// iteration before that iteration's internal `ffi.step()` call.
+    pub fn add_stimulus(&mut self, stimulus: Box<dyn Stimulus>) {
+        self.stimuli.push(stimulus);
+    }
+
+    /// Remove every registered stimulus, e.g. between episodes in a
+    /// gym-style reset.
+    pub fn clear_stimuli(&mut self) {
+        self.stimuli.clear();
+    }
 }
 
 impl Default for MuJoCoBackend {
@@ -156,6 +258,19 @@ impl SimulationBackend for MuJoCoBackend {
             }
         }
 
+        self.config.parallel_ray_casting = config.parallel_processing;
+        self.config.ray_cast_chunk_size = config.ray_cast_chunk_size;
+        self.config.ray_cast_num_threads = config.num_threads;
+        self.ray_cast_pool = match self.config.ray_cast_num_threads {
+            Some(num_threads) if self.config.parallel_ray_casting => Some(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .map_err(|e| SimError::InvalidConfig(e.to_string()))?,
+            ),
+            _ => None,
+        };
+
         // Initialize MuJoCo FFI
         let ffi = MuJoCoFFI::new(self.config.clone())?;
         self.ffi = Some(Arc::new(RwLock::new(ffi)));
@@ -192,17 +307,42 @@ impl SimulationBackend for MuJoCoBackend {
             ));
         }
 
-        let ffi = self
+        let mut ffi = self
             .ffi
             .as_ref()
             .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
             .write()
             .unwrap();
 
-        // Step the simulation with substeps
+        // Step the simulation with substeps, replaying every registered
+        // stimulus before each internal step and sampling every registered
+        // measurement after it.
         let num_steps = (delta_time / self.config.timestep).ceil() as usize;
+        let measurements = self.measurements.clone();
+        let mut sample_time = self.time;
         for _ in 0..num_steps {
+            ffi.reset_external_wrenches();
+            for stimulus in &self.stimuli {
+                stimulus.apply(sample_time, &mut ffi);
+            }
+
             ffi.step()?;
+            sample_time += self.config.timestep;
+
+            for measurement in &measurements {
+                let value = measurement.sample(&ffi, sample_time);
+                let buffer = self
+                    .measurement_history
+                    .entry(measurement.name().to_string())
+                    .or_default();
+                buffer.push_back(MeasurementSample {
+                    time: sample_time,
+                    value,
+                });
+                if buffer.len() > MEASUREMENT_HISTORY_CAPACITY {
+                    buffer.pop_front();
+                }
+            }
         }
 
         self.time += delta_time;
@@ -240,32 +380,78 @@ impl SimulationBackend for MuJoCoBackend {
     }
 
     fn get_scene_bounds(&self, _scene: &SceneHandle) -> SimResult<(Position, Position)> {
-        // Return default bounds for now
-        Ok((
-            Point3::new(-100.0, -100.0, 0.0),
-            Point3::new(100.0, 100.0, 50.0),
-        ))
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "MuJoCo backend not initialized".to_string(),
+            ));
+        }
+
+        let ffi = self
+            .ffi
+            .as_ref()
+            .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
+            .read()
+            .unwrap();
+
+        Ok(ffi.scene_bounds())
     }
 
-    fn add_object(&mut self, _scene: &SceneHandle, _object: SceneObject) -> SimResult<String> {
-        // MuJoCo models are typically defined in XML
-        // Dynamic object addition requires recompiling the model
-        Ok("object_id".to_string())
+    fn add_object(&mut self, _scene: &SceneHandle, object: SceneObject) -> SimResult<String> {
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "MuJoCo backend not initialized".to_string(),
+            ));
+        }
+
+        let mut ffi = self
+            .ffi
+            .as_ref()
+            .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
+            .write()
+            .unwrap();
+
+        // Author the object into the MJCF document and recompile the
+        // model through the FFI.
+        ffi.add_scene_object(object)
     }
 
-    fn remove_object(&mut self, _scene: &SceneHandle, _object_id: &str) -> SimResult<()> {
-        // MuJoCo doesn't support dynamic object removal
-        Ok(())
+    fn remove_object(&mut self, _scene: &SceneHandle, object_id: &str) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "MuJoCo backend not initialized".to_string(),
+            ));
+        }
+
+        let mut ffi = self
+            .ffi
+            .as_ref()
+            .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
+            .write()
+            .unwrap();
+
+        ffi.remove_scene_object(object_id)
     }
 
     fn update_transform(
         &mut self,
         _scene: &SceneHandle,
-        _object_id: &str,
-        _transform: Transform,
+        object_id: &str,
+        transform: Transform,
     ) -> SimResult<()> {
-        // Can set body positions/orientations in MuJoCo
-        Ok(())
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "MuJoCo backend not initialized".to_string(),
+            ));
+        }
+
+        let mut ffi = self
+            .ffi
+            .as_ref()
+            .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
+            .write()
+            .unwrap();
+
+        ffi.update_object_transform(object_id, transform)
     }
 
     fn cast_ray(&self, _scene: &SceneHandle, ray: &Ray) -> SimResult<Option<RayHit>> {
@@ -287,15 +473,82 @@ impl SimulationBackend for MuJoCoBackend {
     }
 
     fn cast_rays(&self, scene: &SceneHandle, rays: &[Ray]) -> SimResult<Vec<Option<RayHit>>> {
-        // MuJoCo can batch process rays efficiently
-        rays.iter().map(|ray| self.cast_ray(scene, ray)).collect()
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "MuJoCo backend not initialized".to_string(),
+            ));
+        }
+
+        if !self.config.parallel_ray_casting || rays.len() < 2 {
+            return rays.iter().map(|ray| self.cast_ray(scene, ray)).collect();
+        }
+
+        let ffi = self
+            .ffi
+            .as_ref()
+            .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
+            .read()
+            .unwrap();
+
+        // Each worker acquires no lock of its own: it casts against the
+        // same frozen `ffi` read guard held here for the whole batch, so
+        // every ray in the batch sees one consistent model/data snapshot.
+        let ffi: &MuJoCoFFI = &ffi;
+        let chunk_size = self.config.ray_cast_chunk_size;
+        let cast_all = move || -> SimResult<Vec<Option<RayHit>>> {
+            match chunk_size {
+                Some(chunk_size) if chunk_size > 0 => rays
+                    .par_chunks(chunk_size)
+                    .map(|chunk| {
+                        chunk
+                            .iter()
+                            .map(|ray| ffi.cast_ray(&ray.origin, &ray.direction, ray.max_distance))
+                            .collect::<SimResult<Vec<_>>>()
+                    })
+                    .collect::<SimResult<Vec<Vec<_>>>>()
+                    .map(|chunks| chunks.into_iter().flatten().collect()),
+                _ => rays
+                    .par_iter()
+                    .map(|ray| ffi.cast_ray(&ray.origin, &ray.direction, ray.max_distance))
+                    .collect(),
+            }
+        };
+
+        match &self.ray_cast_pool {
+            Some(pool) => pool.install(cast_all),
+            None => cast_all(),
+        }
     }
 
-    fn get_objects(&self, _scene: &SceneHandle) -> SimResult<Vec<SceneObject>> {
-        // Return list of bodies in MuJoCo model
+    fn trace_rf_paths(
+        &self,
+        _scene: &SceneHandle,
+        _tx_pos: Position,
+        _rx_pos: Position,
+        _frequency_hz: f64,
+        _max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>> {
+        // Multi-bounce RF path tracing is not implemented for MuJoCo yet.
         Ok(Vec::new())
     }
 
+    fn get_objects(&self, _scene: &SceneHandle) -> SimResult<Vec<SceneObject>> {
+        if !self.initialized {
+            return Err(SimError::NotInitialized(
+                "MuJoCo backend not initialized".to_string(),
+            ));
+        }
+
+        let ffi = self
+            .ffi
+            .as_ref()
+            .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
+            .read()
+            .unwrap();
+
+        Ok(ffi.get_scene_objects())
+    }
+
     async fn spawn_vehicle(&mut self, spec: VehicleSpec) -> SimResult<VehicleId> {
         if !self.initialized {
             return Err(SimError::NotInitialized(
@@ -397,7 +650,7 @@ impl SimulationBackend for MuJoCoBackend {
             .unwrap();
 
         // Set actuator controls in MuJoCo
-        ffi.set_actuator_controls(&handle.actuator_ids, &control)?;
+        ffi.set_actuator_controls(handle.body_id, &handle.actuator_ids, &control)?;
 
         Ok(())
     }
@@ -414,21 +667,39 @@ impl SimulationBackend for MuJoCoBackend {
             .get(vehicle_id)
             .ok_or_else(|| SimError::BackendError(format!("Vehicle not found: {}", vehicle_id)))?;
 
-        let ffi = self
+        // Sensor error models carry state (random-walk bias) that advances
+        // on every read, so this needs the write lock even though the
+        // trait method itself only takes `&self`.
+        let mut ffi = self
             .ffi
             .as_ref()
             .ok_or_else(|| SimError::NotInitialized("FFI not available".to_string()))?
-            .read()
+            .write()
             .unwrap();
 
         // Get sensor data from MuJoCo sensor
         ffi.get_sensor_data(handle.body_id, sensor_id)
     }
+
+    fn set_sensor_fault(
+        &mut self,
+        _vehicle_id: &str,
+        _sensor_id: &str,
+        _fault: Option<SensorFault>,
+    ) -> SimResult<()> {
+        // MuJoCo models sensor error through `ImuErrorModel` /
+        // `MagnetometerErrorModel` instead; generic fault injection isn't
+        // wired into that path yet.
+        Err(SimError::BackendError(
+            "Sensor fault injection not yet implemented for MuJoCo".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nalgebra::Vector3;
 
     #[test]
     fn test_backend_creation() {
@@ -455,6 +726,9 @@ mod tests {
             enable_limits: false,
             solver_iterations: 200,
             model_path: Some("/path/to/model.xml".to_string()),
+            parallel_ray_casting: true,
+            ray_cast_chunk_size: None,
+            ray_cast_num_threads: None,
         };
 
         let backend = MuJoCoBackend::with_config(config.clone());
@@ -462,4 +736,278 @@ mod tests {
         assert_eq!(backend.config().substeps, 5);
         assert!(!backend.config().enable_contact);
     }
+
+    fn multirotor_spec(vehicle_id: &str) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: vehicle_id.to_string(),
+            vehicle_type: autonomysim_core::vehicle::VehicleType::Multirotor,
+            initial_transform: Transform::identity(),
+            parameters: autonomysim_core::vehicle::VehicleParameters::default(),
+            sensors: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_measurement_records_a_sample_after_each_step() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+            .spawn_vehicle(multirotor_spec("drone-1"))
+            .await
+            .unwrap();
+
+        backend.add_measurement(Arc::new(TotalKineticEnergy));
+        backend.step(backend.config().timestep).await.unwrap();
+
+        let latest = backend.latest("total_kinetic_energy").unwrap();
+        assert!(matches!(latest.value, MeasurementValue::Scalar(_)));
+    }
+
+    #[tokio::test]
+    async fn drain_measurements_clears_the_buffer() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+            .spawn_vehicle(multirotor_spec("drone-1"))
+            .await
+            .unwrap();
+
+        backend.add_measurement(Arc::new(BodyComPosition));
+        backend.step(backend.config().timestep).await.unwrap();
+
+        let drained = backend.drain_measurements();
+        assert_eq!(drained["body_com_position"].len(), 1);
+        assert!(matches!(
+            drained["body_com_position"][0].value,
+            MeasurementValue::Vector3(_)
+        ));
+        assert!(backend.latest("body_com_position").is_none());
+    }
+
+    #[tokio::test]
+    async fn multiple_substeps_append_one_sample_each() {
+        let mut backend = MuJoCoBackend::with_config(MuJoCoConfig {
+            substeps: 4,
+            ..MuJoCoConfig::default()
+        });
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+            .spawn_vehicle(multirotor_spec("drone-1"))
+            .await
+            .unwrap();
+
+        backend.add_measurement(Arc::new(ActuatorPower));
+        backend.add_measurement(Arc::new(NetContactForce));
+        let timestep = backend.config().timestep;
+        backend.step(timestep * 3.0).await.unwrap();
+
+        let drained = backend.drain_measurements();
+        assert_eq!(drained["actuator_power"].len(), 3);
+        assert_eq!(drained["net_contact_force"].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn constant_force_stimulus_accelerates_the_target_body() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+            .spawn_vehicle(multirotor_spec("drone-1"))
+            .await
+            .unwrap();
+        let body_id = backend.body_id_for_vehicle("drone-1").unwrap();
+
+        backend.add_stimulus(Box::new(ConstantForce {
+            body_id,
+            force: Vector3::new(0.0, 0.0, 100.0),
+            torque: Vector3::zeros(),
+        }));
+        backend.step(backend.config().timestep).await.unwrap();
+
+        let state = backend.get_vehicle_state("drone-1").unwrap();
+        assert!(state.linear_velocity.z > 0.0);
+    }
+
+    #[tokio::test]
+    async fn windowed_impulse_only_applies_inside_its_time_window() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+            .spawn_vehicle(multirotor_spec("drone-1"))
+            .await
+            .unwrap();
+        let body_id = backend.body_id_for_vehicle("drone-1").unwrap();
+        let timestep = backend.config().timestep;
+
+        backend.add_stimulus(Box::new(WindowedImpulse {
+            body_id,
+            force: Vector3::new(0.0, 0.0, 100.0),
+            torque: Vector3::zeros(),
+            t_start: 10.0 * timestep,
+            t_end: 20.0 * timestep,
+        }));
+        backend.step(timestep).await.unwrap();
+
+        let state = backend.get_vehicle_state("drone-1").unwrap();
+        assert_eq!(state.linear_velocity.z, 0.0);
+    }
+
+    #[tokio::test]
+    async fn clear_stimuli_stops_a_previously_registered_stimulus() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend
+            .spawn_vehicle(multirotor_spec("drone-1"))
+            .await
+            .unwrap();
+        let body_id = backend.body_id_for_vehicle("drone-1").unwrap();
+
+        backend.add_stimulus(Box::new(ConstantForce {
+            body_id,
+            force: Vector3::new(0.0, 0.0, 100.0),
+            torque: Vector3::zeros(),
+        }));
+        backend.clear_stimuli();
+        backend.step(backend.config().timestep).await.unwrap();
+
+        let state = backend.get_vehicle_state("drone-1").unwrap();
+        assert_eq!(state.linear_velocity.z, 0.0);
+    }
+
+    #[tokio::test]
+    async fn parallel_cast_rays_matches_serial() {
+        let mut parallel_backend = MuJoCoBackend::new();
+        parallel_backend
+            .initialize(BackendConfig {
+                parallel_processing: true,
+                num_threads: Some(2),
+                ray_cast_chunk_size: Some(16),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let scene = parallel_backend.load_scene("test.xml").await.unwrap();
+
+        let mut serial_backend = MuJoCoBackend::new();
+        serial_backend
+            .initialize(BackendConfig {
+                parallel_processing: false,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        // A mix of downward rays that hit the ground plane and horizontal
+        // rays that miss it entirely.
+        let mut rays = Vec::new();
+        for i in 0..200 {
+            let x = i as f64;
+            rays.push(Ray::new(
+                Point3::new(x, 0.0, 10.0),
+                Vector3::new(0.0, 0.0, -1.0),
+            ));
+            rays.push(Ray::new(
+                Point3::new(x, 0.0, 10.0),
+                Vector3::new(1.0, 0.0, 0.0),
+            ));
+        }
+
+        let parallel_hits = parallel_backend.cast_rays(&scene, &rays).unwrap();
+        let serial_hits = serial_backend.cast_rays(&scene, &rays).unwrap();
+
+        assert_eq!(parallel_hits.len(), rays.len());
+        for (parallel_hit, serial_hit) in parallel_hits.iter().zip(serial_hits.iter()) {
+            match (parallel_hit, serial_hit) {
+                (Some(a), Some(b)) => assert!((a.distance - b.distance).abs() < 1e-9),
+                (None, None) => {}
+                _ => panic!("parallel and serial cast_rays disagreed on a hit"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn single_ray_batch_matches_cast_ray() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.xml").await.unwrap();
+
+        let ray = Ray::new(Point3::new(0.0, 0.0, 10.0), Vector3::new(0.0, 0.0, -1.0));
+        let single = backend.cast_ray(&scene, &ray).unwrap();
+        let batched = backend.cast_rays(&scene, &[ray]).unwrap();
+
+        assert_eq!(batched.len(), 1);
+        assert_eq!(
+            batched[0].as_ref().map(|hit| hit.distance),
+            single.map(|hit| hit.distance)
+        );
+    }
+
+    fn crate_object(id: &str) -> SceneObject {
+        SceneObject {
+            id: id.to_string(),
+            name: id.to_string(),
+            transform: Transform::identity(),
+            geometry: autonomysim_core::backend::Geometry::Box {
+                size: Vector3::new(2.0, 2.0, 2.0),
+            },
+            material: Material::concrete(),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_object_is_returned_by_get_objects() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.xml").await.unwrap();
+
+        let object_id = backend.add_object(&scene, crate_object("crate1")).unwrap();
+        assert_eq!(object_id, "crate1");
+
+        let objects = backend.get_objects(&scene).unwrap();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].id, "crate1");
+    }
+
+    #[tokio::test]
+    async fn remove_object_drops_it_from_get_objects() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.xml").await.unwrap();
+
+        backend.add_object(&scene, crate_object("crate1")).unwrap();
+        backend.remove_object(&scene, "crate1").unwrap();
+
+        assert!(backend.get_objects(&scene).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_transform_moves_an_existing_object() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.xml").await.unwrap();
+
+        backend.add_object(&scene, crate_object("crate1")).unwrap();
+        let moved = Transform::new(
+            Point3::new(5.0, 0.0, 0.0),
+            nalgebra::UnitQuaternion::identity(),
+        );
+        backend
+            .update_transform(&scene, "crate1", moved.clone())
+            .unwrap();
+
+        let objects = backend.get_objects(&scene).unwrap();
+        assert_eq!(objects[0].transform.position, moved.position);
+    }
+
+    #[tokio::test]
+    async fn scene_bounds_reflect_added_objects() {
+        let mut backend = MuJoCoBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.xml").await.unwrap();
+
+        backend.add_object(&scene, crate_object("crate1")).unwrap();
+        let (min, max) = backend.get_scene_bounds(&scene).unwrap();
+
+        assert!((min.x - (-1.0)).abs() < 1e-9);
+        assert!((max.x - 1.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,86 @@
+//! Scripted external disturbance/stimulus injection for
+//! [`super::MuJoCoBackend`], for disturbance-rejection and robustness
+//! research.
+//!
+//! Each [`Stimulus`] writes into a body's `xfrc_applied`-equivalent wrench
+//! (see [`MuJoCoFFI::apply_external_wrench`]) once per
+//! [`super::MuJoCoBackend::step`] iteration, before that iteration's
+//! `ffi.step()` call integrates it.
+
+use std::f64::consts::PI;
+
+use nalgebra::Vector3;
+
+use super::ffi::MuJoCoFFI;
+
+/// A scripted external force/torque source, replayed every
+/// [`super::MuJoCoBackend::step`] iteration -- see
+/// [`super::MuJoCoBackend::add_stimulus`].
+pub trait Stimulus: Send + Sync {
+    fn apply(&self, time: f64, ffi: &mut MuJoCoFFI);
+}
+
+/// Registered stimuli, replayed in order every
+/// [`super::MuJoCoBackend::step`] iteration.
+pub type StimuliVec = Vec<Box<dyn Stimulus>>;
+
+/// A constant force/torque applied to one body for as long as the stimulus
+/// stays registered.
+pub struct ConstantForce {
+    pub body_id: i32,
+    pub force: Vector3<f64>,
+    pub torque: Vector3<f64>,
+}
+
+impl Stimulus for ConstantForce {
+    fn apply(&self, _time: f64, ffi: &mut MuJoCoFFI) {
+        ffi.apply_external_wrench(self.body_id, self.force, self.torque);
+    }
+}
+
+/// A sinusoidal/modulated force: `amplitude * sin(2*pi*frequency_hz*t +
+/// phase_rad)`.
+pub struct SinusoidalForce {
+    pub body_id: i32,
+    pub amplitude: Vector3<f64>,
+    pub frequency_hz: f64,
+    pub phase_rad: f64,
+}
+
+impl Stimulus for SinusoidalForce {
+    fn apply(&self, time: f64, ffi: &mut MuJoCoFFI) {
+        let modulation = (2.0 * PI * self.frequency_hz * time + self.phase_rad).sin();
+        ffi.apply_external_wrench(self.body_id, self.amplitude * modulation, Vector3::zeros());
+    }
+}
+
+/// A constant force/torque active only while `t_start <= time <= t_end`.
+pub struct WindowedImpulse {
+    pub body_id: i32,
+    pub force: Vector3<f64>,
+    pub torque: Vector3<f64>,
+    pub t_start: f64,
+    pub t_end: f64,
+}
+
+impl Stimulus for WindowedImpulse {
+    fn apply(&self, time: f64, ffi: &mut MuJoCoFFI) {
+        if time >= self.t_start && time <= self.t_end {
+            ffi.apply_external_wrench(self.body_id, self.force, self.torque);
+        }
+    }
+}
+
+/// A uniform wind field: the same force applied to every body currently
+/// tracked by the FFI, regardless of vehicle.
+pub struct WindField {
+    pub force: Vector3<f64>,
+}
+
+impl Stimulus for WindField {
+    fn apply(&self, _time: f64, ffi: &mut MuJoCoFFI) {
+        for body_id in ffi.body_ids() {
+            ffi.apply_external_wrench(body_id, self.force, Vector3::zeros());
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Async JSON-RPC client for Unreal Engine 5
+//!
+//! [`UnrealConnection`](crate::unreal::UnrealConnection) serializes every
+//! request behind a single stream lock and reads its reply inline, so only
+//! one request can be in flight at a time. `UnrealClient` instead owns the
+//! socket through a background reader task, correlates each outbound
+//! [`UnrealMessage`] to its reply by `request_id` via a map of oneshot
+//! senders, and can be shut down cleanly with a [`CancellationToken`]
+//! instead of just being dropped mid-request.
+
+use crate::unreal::connection::message_to_rpc;
+use crate::unreal::protocol::UnrealMessage;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// Errors surfaced by [`UnrealClient`].
+#[derive(Error, Debug)]
+pub enum UnrealError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("UE5 returned an error (code {code}): {message}")]
+    Remote { code: i32, message: String },
+
+    #[error("timed out waiting for a reply to request {0}")]
+    Timeout(i64),
+
+    #[error("client is disconnected")]
+    Disconnected,
+}
+
+/// Connection lifecycle events emitted on [`UnrealClient::next_event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnrealEvent {
+    Connected,
+    Disconnected,
+}
+
+/// How long [`UnrealClient::send`] waits for a reply before giving up.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+type PendingMap = Arc<Mutex<HashMap<i64, oneshot::Sender<Result<Value, UnrealError>>>>>;
+
+/// Async JSON-RPC client that owns the UE5 socket, pipelines concurrent
+/// requests, and correlates replies to requests by `request_id`.
+pub struct UnrealClient {
+    writer: Mutex<OwnedWriteHalf>,
+    request_id: AtomicI64,
+    pending: PendingMap,
+    events: mpsc::UnboundedReceiver<UnrealEvent>,
+    cancel: CancellationToken,
+    reader_task: JoinHandle<()>,
+    request_timeout: Duration,
+}
+
+impl UnrealClient {
+    /// Connect to Unreal Engine 5 and start the background reader task.
+    pub async fn connect(host: &str, port: u16) -> Result<Self, UnrealError> {
+        let stream = TcpStream::connect((host, port)).await?;
+        let (read_half, write_half) = stream.into_split();
+
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let cancel = CancellationToken::new();
+
+        let reader_task = tokio::spawn(run_reader(
+            BufReader::new(read_half),
+            pending.clone(),
+            event_tx,
+            cancel.clone(),
+        ));
+
+        Ok(Self {
+            writer: Mutex::new(write_half),
+            request_id: AtomicI64::new(1),
+            pending,
+            events: event_rx,
+            cancel,
+            reader_task,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        })
+    }
+
+    /// Override the default per-request reply timeout.
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = timeout;
+    }
+
+    /// Send a message and await its matching reply, resolving on the first
+    /// `Success`/`Error` whose `request_id` matches this request.
+    pub async fn send(&self, message: UnrealMessage) -> Result<Value, UnrealError> {
+        self.send_with_timeout(message, self.request_timeout).await
+    }
+
+    /// Like [`UnrealClient::send`] but with an explicit timeout override.
+    pub async fn send_with_timeout(
+        &self,
+        message: UnrealMessage,
+        timeout: Duration,
+    ) -> Result<Value, UnrealError> {
+        let (method, params) = message_to_rpc(&message);
+        let id = self.request_id.fetch_add(1, Ordering::SeqCst);
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.pending.lock().await.insert(id, reply_tx);
+
+        let json_rpc = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        let mut json_str = serde_json::to_string(&json_rpc)?;
+        json_str.push('\n');
+
+        if let Err(e) = self.write_line(&json_str).await {
+            self.pending.lock().await.remove(&id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(timeout, reply_rx).await {
+            Ok(Ok(outcome)) => outcome,
+            Ok(Err(_)) => Err(UnrealError::Disconnected),
+            Err(_) => {
+                self.pending.lock().await.remove(&id);
+                Err(UnrealError::Timeout(id))
+            }
+        }
+    }
+
+    async fn write_line(&self, line: &str) -> Result<(), UnrealError> {
+        let mut writer = self.writer.lock().await;
+        writer.write_all(line.as_bytes()).await?;
+        writer.flush().await?;
+        Ok(())
+    }
+
+    /// Wait for the next connection lifecycle event.
+    pub async fn next_event(&mut self) -> Option<UnrealEvent> {
+        self.events.recv().await
+    }
+
+    /// Cooperatively cancel the reader loop: it stops reading, drains every
+    /// pending waiter with [`UnrealError::Disconnected`] instead of leaving
+    /// them hanging, and emits a final `Disconnected` event.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Wait for the background reader task to finish after [`Self::cancel`].
+    pub async fn join(self) {
+        let _ = self.reader_task.await;
+    }
+}
+
+impl Drop for UnrealClient {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+async fn run_reader(
+    mut reader: BufReader<OwnedReadHalf>,
+    pending: PendingMap,
+    events: mpsc::UnboundedSender<UnrealEvent>,
+    cancel: CancellationToken,
+) {
+    let _ = events.send(UnrealEvent::Connected);
+
+    loop {
+        let mut line = String::new();
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            result = reader.read_line(&mut line) => {
+                match result {
+                    Ok(0) => break, // EOF: UE5 closed the connection
+                    Ok(_) => dispatch_reply(&line, &pending).await,
+                    Err(e) => {
+                        warn!("UE5 client read error: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut pending = pending.lock().await;
+    for (_, reply_tx) in pending.drain() {
+        let _ = reply_tx.send(Err(UnrealError::Disconnected));
+    }
+    drop(pending);
+
+    let _ = events.send(UnrealEvent::Disconnected);
+}
+
+async fn dispatch_reply(line: &str, pending: &PendingMap) {
+    let Some((id, outcome)) = parse_response_line(line) else {
+        return;
+    };
+    if let Some(reply_tx) = pending.lock().await.remove(&id) {
+        let _ = reply_tx.send(outcome);
+    }
+}
+
+fn parse_response_line(line: &str) -> Option<(i64, Result<Value, UnrealError>)> {
+    let json: Value = serde_json::from_str(line.trim()).ok()?;
+    let id = json.get("id")?.as_i64()?;
+
+    if let Some(error) = json.get("error") {
+        let message = error
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("Unknown error")
+            .to_string();
+        let code = error.get("code").and_then(Value::as_i64).unwrap_or(-1) as i32;
+        return Some((id, Err(UnrealError::Remote { code, message })));
+    }
+
+    let data = json.get("result").cloned().unwrap_or(Value::Null);
+    Some((id, Ok(data)))
+}
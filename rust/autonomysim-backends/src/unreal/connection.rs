@@ -7,43 +7,681 @@
 //! - Request/response correlation via JSON-RPC IDs
 
 use crate::unreal::protocol::{
-    methods, DebugLine, DebugSphere, RobotPositionUpdate, RobotSpawnData, RobotTelemetry,
-    UnrealMessage, UnrealResponse,
+    methods, ActionState, ActionStatus, DebugArrow, DebugLine, DebugLineStrip, DebugSphere,
+    DebugString, EventClass, ReverseRequest, RobotPositionUpdate, RobotSpawnData, RobotTelemetry,
+    SimEvent, UnrealMessage, UnrealResponse,
 };
+use base64::Engine;
+use std::collections::{HashMap, VecDeque};
 use std::io;
 use std::sync::atomic::{AtomicI32, Ordering};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::TcpStream;
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, mpsc, oneshot, Mutex, RwLock};
+use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 
+/// Translate an [`UnrealMessage`] into the `(method, params)` pair the UE5
+/// JSON-RPC server expects. Shared by [`UnrealConnection::send_message`]
+/// and [`crate::unreal::client::UnrealClient::send`] so the two transports
+/// can't drift on how a given message is encoded.
+pub(crate) fn message_to_rpc(message: &UnrealMessage) -> (&'static str, serde_json::Value) {
+    match message {
+        UnrealMessage::SpawnVehicle {
+            vehicle_id,
+            vehicle_type: _,
+            transform,
+        } => {
+            let robots: Vec<serde_json::Value> = vec![serde_json::json!({
+                "id": vehicle_id.replace("robot_", "").parse::<i32>().unwrap_or(0),
+                "x": transform.position.x * 100.0, // Convert to Unreal units (cm)
+                "y": transform.position.y * 100.0,
+                "z": transform.position.z * 100.0,
+                "yaw": 0.0
+            })];
+            (
+                methods::SPAWN_ROBOTS,
+                serde_json::json!({ "robots": robots }),
+            )
+        }
+        UnrealMessage::Step { delta_time } => (
+            methods::SIMULATION_STEP,
+            serde_json::json!({ "delta_time": delta_time }),
+        ),
+        UnrealMessage::LoadLevel { level_name } => (
+            methods::LOAD_LEVEL,
+            serde_json::json!({ "level": level_name }),
+        ),
+        UnrealMessage::SpawnRobots { robots } => (
+            methods::SPAWN_ROBOTS,
+            serde_json::json!({ "robots": robots }),
+        ),
+        UnrealMessage::UpdatePositions { positions } => (
+            methods::UPDATE_POSITIONS,
+            serde_json::json!({ "positions": positions }),
+        ),
+        UnrealMessage::UpdateTelemetry { telemetry } => (
+            methods::UPDATE_TELEMETRY,
+            serde_json::json!({ "telemetry": telemetry }),
+        ),
+        UnrealMessage::ApplyDamage {
+            vehicle_id,
+            warhead_lbs,
+            impact_point,
+        } => (
+            methods::APPLY_DAMAGE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "warhead_lbs": warhead_lbs,
+                "impact_point": impact_point
+            }),
+        ),
+        UnrealMessage::ClearAllRobots => (methods::CLEAR_ALL_ROBOTS, serde_json::json!({})),
+        UnrealMessage::DrawDebugLines { lines } => (
+            methods::DRAW_DEBUG_LINES,
+            serde_json::json!({ "lines": lines }),
+        ),
+        UnrealMessage::DrawDebugSpheres { spheres } => (
+            methods::DRAW_DEBUG_SPHERES,
+            serde_json::json!({ "spheres": spheres }),
+        ),
+        UnrealMessage::DrawDebugStrings { strings } => (
+            methods::DRAW_DEBUG_STRINGS,
+            serde_json::json!({ "strings": strings }),
+        ),
+        UnrealMessage::DrawDebugArrows { arrows } => (
+            methods::DRAW_DEBUG_ARROWS,
+            serde_json::json!({ "arrows": arrows }),
+        ),
+        UnrealMessage::DrawDebugLineStrips { strips } => (
+            methods::DRAW_DEBUG_LINE_STRIPS,
+            serde_json::json!({ "strips": strips }),
+        ),
+        UnrealMessage::ClearDebug => (methods::CLEAR_DEBUG, serde_json::json!({})),
+        UnrealMessage::SetVisualizationMode {
+            show_comm_links,
+            show_rf_range,
+            show_paths,
+            show_labels,
+        } => (
+            methods::SET_VIS_MODE,
+            serde_json::json!({
+                "show_comm_links": show_comm_links,
+                "show_rf_range": show_rf_range,
+                "show_paths": show_paths,
+                "show_labels": show_labels
+            }),
+        ),
+        UnrealMessage::Pause => (methods::PAUSE, serde_json::json!({})),
+        UnrealMessage::Resume => (methods::RESUME, serde_json::json!({})),
+        UnrealMessage::Reset => (methods::RESET, serde_json::json!({})),
+        UnrealMessage::GetAllStates => (methods::GET_ALL_STATES, serde_json::json!({})),
+        UnrealMessage::SetFpvCamera {
+            vehicle_id,
+            tilt_angle_deg,
+            fov_h_deg,
+            resolution_width,
+            resolution_height,
+            lens_distortion,
+            latency_ms,
+        } => (
+            methods::SET_FPV_CAMERA,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "tilt_angle_deg": tilt_angle_deg,
+                "fov_h_deg": fov_h_deg,
+                "resolution_width": resolution_width,
+                "resolution_height": resolution_height,
+                "lens_distortion": lens_distortion,
+                "latency_ms": latency_ms
+            }),
+        ),
+        UnrealMessage::SetFpvControl {
+            vehicle_id,
+            throttle,
+            roll,
+            pitch,
+            yaw,
+            flight_mode,
+        } => (
+            methods::SET_FPV_CONTROL,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "throttle": throttle,
+                "roll": roll,
+                "pitch": pitch,
+                "yaw": yaw,
+                "flight_mode": flight_mode
+            }),
+        ),
+        UnrealMessage::ArmDrone { vehicle_id, armed } => (
+            methods::ARM_DRONE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "armed": armed
+            }),
+        ),
+        UnrealMessage::SpawnFpvDrone {
+            vehicle_id,
+            drone_preset,
+            x,
+            y,
+            z,
+            yaw,
+        } => (
+            methods::SPAWN_FPV_DRONE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "drone_preset": drone_preset,
+                "x": x, "y": y, "z": z, "yaw": yaw
+            }),
+        ),
+        UnrealMessage::UpdateFpvState {
+            vehicle_id,
+            x,
+            y,
+            z,
+            qw,
+            qx,
+            qy,
+            qz,
+            speed_mps,
+            altitude_m,
+            motor_outputs,
+            battery_voltage,
+            battery_remaining,
+            flight_mode,
+            armed,
+            osd,
+        } => (
+            methods::UPDATE_FPV_STATE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "position": [x * 100.0, y * 100.0, z * 100.0],
+                "orientation": [qx, qy, qz, qw],
+                "speed_mps": speed_mps,
+                "altitude_m": altitude_m,
+                "motor_outputs": motor_outputs,
+                "battery_voltage": battery_voltage,
+                "battery_remaining": battery_remaining,
+                "flight_mode": flight_mode,
+                "armed": armed,
+                "osd": osd
+            }),
+        ),
+        UnrealMessage::SetOsdVisible {
+            vehicle_id,
+            visible,
+        } => (
+            methods::SET_OSD_VISIBLE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "visible": visible
+            }),
+        ),
+        // Remaining variants that don't have a dedicated UE5 handler in
+        // every plugin build. These are explicitly listed (not `_ =>`) so
+        // adding a new variant to UnrealMessage produces a compile error,
+        // forcing the developer to add the handler here. Each maps to its
+        // real (not stubbed) method name so the capability handshake (see
+        // `UnrealConnection::send_message`) can correctly report it
+        // unsupported on a plugin build that doesn't implement it yet,
+        // instead of silently pinging.
+        UnrealMessage::RemoveVehicle { vehicle_id } => (
+            methods::REMOVE_VEHICLE,
+            serde_json::json!({ "vehicle_id": vehicle_id }),
+        ),
+        UnrealMessage::SetControl {
+            vehicle_id,
+            throttle,
+            steering,
+            brake,
+            pitch,
+            roll,
+            yaw,
+        } => (
+            methods::SET_CONTROL,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "throttle": throttle,
+                "steering": steering,
+                "brake": brake,
+                "pitch": pitch,
+                "roll": roll,
+                "yaw": yaw
+            }),
+        ),
+        UnrealMessage::GetState { vehicle_id } => (
+            methods::GET_STATE,
+            serde_json::json!({ "vehicle_id": vehicle_id }),
+        ),
+        UnrealMessage::CastRay {
+            origin,
+            direction,
+            max_distance,
+        } => (
+            methods::CAST_RAY,
+            serde_json::json!({
+                "origin": origin,
+                "direction": direction,
+                "max_distance": max_distance
+            }),
+        ),
+        UnrealMessage::CaptureImage {
+            vehicle_id,
+            camera_name,
+            image_type,
+        } => (
+            methods::CAPTURE_IMAGE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "camera_name": camera_name,
+                "image_type": image_type
+            }),
+        ),
+    }
+}
+
+/// Pending requests awaiting a reply, keyed by JSON-RPC `id`.
+type PendingMap = Arc<Mutex<HashMap<i32, oneshot::Sender<UnrealResponse>>>>;
+
+/// Outbound message priority classes. Lower numeric value drains first;
+/// named to match the traffic they're meant for.
+pub mod priority {
+    /// Control/telemetry (`set_fpv_control`, `update_fpv_state`, ...) --
+    /// must stay responsive even while a bulk batch is mid-flight.
+    pub const PRIO_HIGH: u8 = 0x20;
+    /// Spawn/position batches -- normal traffic.
+    pub const PRIO_NORMAL: u8 = 0x40;
+    /// Debug draws and other non-critical background traffic.
+    pub const PRIO_BACKGROUND: u8 = 0x80;
+}
+
+/// Maximum array elements per chunk when splitting an oversized batch (see
+/// [`UnrealConnection::send_batch_rpc`]).
+const BATCH_CHUNK_SIZE: usize = 200;
+
+/// Backlog size for the [`SimEvent`] broadcast channel (see
+/// [`UnrealConnection::subscribe_events`]) -- a slow subscriber that falls
+/// this far behind starts missing events rather than blocking the reader.
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Controls the automatic-reconnect behavior of [`UnrealConnection::connect`]:
+/// how long to back off between attempts and when to give up. Reconnection
+/// itself can't be disabled (a dropped socket is always recoverable), but a
+/// `max_retries` of `Some(0)` makes the connection fail-fast on the first
+/// drop instead of retrying indefinitely.
+#[derive(Debug, Clone)]
+pub struct ConnectionConfig {
+    /// Delay before the first reconnect attempt.
+    pub base_delay: Duration,
+    /// Upper bound the exponential backoff is clamped to.
+    pub max_delay: Duration,
+    /// Give up after this many consecutive failed reconnect attempts and
+    /// leave the connection permanently down. `None` retries forever.
+    pub max_retries: Option<u32>,
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            max_retries: None,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Exponential backoff (base doubling each attempt, clamped to
+    /// `max_delay`) with up to 50% jitter so many reconnecting clients
+    /// don't retry in lockstep.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+        let capped = exp.min(self.max_delay);
+        let jitter = capped.mul_f64(rand::random::<f64>() * 0.5);
+        capped + jitter
+    }
+}
+
+/// Result of the `GET_CAPABILITIES` handshake performed once at connect
+/// time (see [`UnrealConnection::capabilities`]). Lets higher layers
+/// feature-gate calls -- e.g. skip ray casts if the connected plugin build
+/// doesn't implement them -- instead of discovering the gap only when a
+/// call silently no-ops.
+#[derive(Debug, Clone, Default)]
+pub struct UnrealCapabilities {
+    methods: std::collections::HashSet<String>,
+    /// `false` if the handshake itself didn't complete (e.g. an older
+    /// plugin build that predates `GET_CAPABILITIES` and errors on an
+    /// unrecognized method). In that case every method is treated as
+    /// supported rather than blocking calls on a negotiation the server
+    /// can't perform.
+    negotiated: bool,
+}
+
+impl UnrealCapabilities {
+    /// Whether `method` can be dispatched to the connected plugin build.
+    /// Always `true` until a handshake has actually completed.
+    pub fn supports(&self, method: &str) -> bool {
+        !self.negotiated || self.methods.contains(method)
+    }
+}
+
+/// How [`UnrealConnection::capture_image`] should carry the captured bytes
+/// back over the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageEncoding {
+    /// Base64-embedded directly in the JSON reply. Simple, but ~33%
+    /// larger on the wire -- fine for small frames.
+    Inline,
+    /// The JSON reply carries metadata only (`binary_len` gives the byte
+    /// count); the raw bytes immediately follow, length-prefixed, on the
+    /// same socket. See [`run_reader`].
+    Binary,
+}
+
+impl ImageEncoding {
+    fn as_str(self) -> &'static str {
+        match self {
+            ImageEncoding::Inline => "inline",
+            ImageEncoding::Binary => "binary",
+        }
+    }
+}
+
+/// One captured (or streamed) camera frame: metadata plus the decoded
+/// image bytes, regardless of which [`ImageEncoding`] the wire used to
+/// carry them.
+#[derive(Debug, Clone)]
+pub struct CameraFrame {
+    /// Server-assigned id distinguishing frames of a [`UnrealConnection::subscribe_camera`]
+    /// stream from one another.
+    pub frame_id: i64,
+    /// Pixel/encoding format as reported by the plugin (e.g. `"rgba8"`,
+    /// `"png"`, `"jpeg"`).
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl CameraFrame {
+    /// Build a frame from a completed [`UnrealResponse`] -- either
+    /// [`UnrealResponse::Binary`] (payload already reassembled by
+    /// [`run_reader`]) or a [`UnrealResponse::Success`] carrying an inline
+    /// base64 image.
+    fn from_response(response: UnrealResponse) -> io::Result<Self> {
+        match response {
+            UnrealResponse::Binary { data, payload, .. } => Self::from_metadata(&data, payload),
+            UnrealResponse::Success { data, .. } => Self::from_inline_notification(&data),
+            UnrealResponse::Error { code, message, .. } => Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("UE5 returned an error (code {}): {}", code, message),
+            )),
+            UnrealResponse::Unsupported { method } => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("UE5 plugin does not support {}", method),
+            )),
+        }
+    }
+
+    /// Build a frame from metadata carrying an inline base64 `image_base64`
+    /// field -- the shape used both by a [`UnrealResponse::Success`] reply
+    /// to `capture_image` and by a `subscribe_camera` notification.
+    fn from_inline_notification(data: &serde_json::Value) -> io::Result<Self> {
+        let image_base64 = data.get("image_base64").and_then(|v| v.as_str()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "camera frame missing image_base64",
+            )
+        })?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(image_base64)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Self::from_metadata(data, bytes)
+    }
+
+    fn from_metadata(data: &serde_json::Value, payload: Vec<u8>) -> io::Result<Self> {
+        Ok(Self {
+            frame_id: data.get("frame_id").and_then(serde_json::Value::as_i64).unwrap_or(0),
+            format: data
+                .get("format")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string(),
+            width: data.get("width").and_then(serde_json::Value::as_u64).unwrap_or(0) as u32,
+            height: data.get("height").and_then(serde_json::Value::as_u64).unwrap_or(0) as u32,
+            data: payload,
+        })
+    }
+}
+
+/// One fully-framed outbound line queued for the writer task, along with
+/// the priority class and round-robin lane it belongs to.
+struct OutboundEnvelope {
+    priority: u8,
+    /// Frames sharing a `lane_id` are drained round-robin against other
+    /// lanes in the same priority class, so a long run of chunks from one
+    /// batch can't monopolize the link ahead of other queued work. A
+    /// one-off call just uses its own request id as a single-frame lane.
+    lane_id: i32,
+    line: String,
+}
+
+/// Per-priority-class queue of round-robin lanes.
+#[derive(Default)]
+struct PriorityClass {
+    /// Lane ids with frames still queued, in round-robin order.
+    order: VecDeque<i32>,
+    lanes: HashMap<i32, VecDeque<String>>,
+}
+
+impl PriorityClass {
+    fn push(&mut self, lane_id: i32, line: String) {
+        let is_new_lane = !self.lanes.contains_key(&lane_id);
+        self.lanes.entry(lane_id).or_default().push_back(line);
+        if is_new_lane {
+            self.order.push_back(lane_id);
+        }
+    }
+
+    fn pop(&mut self) -> Option<String> {
+        let lane_id = self.order.pop_front()?;
+        let lane = self.lanes.get_mut(&lane_id)?;
+        let line = lane.pop_front();
+        if lane.is_empty() {
+            self.lanes.remove(&lane_id);
+        } else {
+            self.order.push_back(lane_id);
+        }
+        line
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lanes.is_empty()
+    }
+}
+
 /// Connection to Unreal Engine 5
+///
+/// A background [`run_supervisor`] task owns the socket: requests are no
+/// longer serialized behind a single read-then-write lock, since many
+/// `send_rpc` calls can be in flight at once and replies are routed back to
+/// the right caller by JSON-RPC `id` rather than assumed to arrive in send
+/// order. Outbound frames are queued by [`priority`] class (with
+/// round-robin fairness across chunked batches within a class) so a bulk
+/// spawn can't stall real-time control/telemetry traffic queued behind it.
+/// If the socket drops, the supervisor fails whatever was in flight and
+/// reconnects with backoff per [`ConnectionConfig`] -- `send_rpc` callers
+/// just see their request queue behind the reconnect rather than erroring
+/// immediately. The same framing also runs in reverse: a frame carrying
+/// both an `id` and a `method` is UE5 issuing its own request rather than
+/// replying to ours, and is routed to [`Self::recv_reverse_request`] instead
+/// of `pending`.
 pub struct UnrealConnection {
-    stream: Mutex<TcpStream>,
     address: String,
-    request_id: AtomicI32,
+    request_id: Arc<AtomicI32>,
+    pending: PendingMap,
+    outbound: mpsc::UnboundedSender<OutboundEnvelope>,
+    /// JSON-RPC notifications (frames with no `id`) pushed by the UE5
+    /// plugin outside of any request/response cycle. `Arc`-wrapped (rather
+    /// than a plain `Mutex` field like the rest of this struct) so
+    /// [`Self::subscribe_camera`] can hand a clone to its background relay
+    /// task without needing `self: Arc<Self>`.
+    notifications: Arc<Mutex<mpsc::UnboundedReceiver<serde_json::Value>>>,
+    /// Fan-out for notifications that decode as a typed [`SimEvent`] (see
+    /// [`Self::subscribe_events`]); unlike `notifications` above, any number
+    /// of subscribers can receive their own copy of every event.
+    events: broadcast::Sender<SimEvent>,
+    /// Frames carrying both an `id` and a `method` -- UE5 calling back into
+    /// us rather than replying to one of our own requests (see
+    /// [`Self::recv_reverse_request`]).
+    reverse_requests: Arc<Mutex<mpsc::UnboundedReceiver<ReverseRequest>>>,
+    /// Separate id space from `request_id`: allocated by
+    /// [`Self::next_action_id`] for action-tracked methods, never sent to
+    /// UE5 itself.
+    action_id: Arc<AtomicI32>,
+    /// Sending half of the [`ActionStatus`] channel; cloned into whatever
+    /// task drives an action-tracked method's RPC so it can report
+    /// progress without holding a reference to `self`.
+    action_status_tx: mpsc::UnboundedSender<ActionStatus>,
+    /// Receiving half of the [`ActionStatus`] channel (see
+    /// [`Self::recv_action_status`]).
+    action_status_rx: Arc<Mutex<mpsc::UnboundedReceiver<ActionStatus>>>,
+    /// Negotiated at connect time via [`Self::refresh_capabilities`].
+    capabilities: RwLock<UnrealCapabilities>,
+    /// Owns the reconnect loop: reads and writes for the current socket,
+    /// and on any I/O error reconnects with backoff per [`ConnectionConfig`]
+    /// rather than exiting. See [`run_supervisor`].
+    supervisor_task: JoinHandle<()>,
 }
 
 impl UnrealConnection {
-    /// Connect to Unreal Engine 5
+    /// Connect to Unreal Engine 5, reconnecting on connection loss with the
+    /// default [`ConnectionConfig`] (infinite retries, 100ms-to-5s backoff).
     pub async fn connect(host: &str, port: u16) -> io::Result<Self> {
+        Self::connect_with_config(host, port, ConnectionConfig::default()).await
+    }
+
+    /// Like [`Self::connect`], but with explicit control over reconnect
+    /// backoff and retry limits -- e.g. `max_retries: Some(0)` to fail fast
+    /// instead of retrying indefinitely.
+    pub async fn connect_with_config(
+        host: &str,
+        port: u16,
+        config: ConnectionConfig,
+    ) -> io::Result<Self> {
         let address = format!("{}:{}", host, port);
         info!("Connecting to Unreal Engine at {}...", address);
+        // The initial connect is synchronous so callers get an immediate
+        // error (e.g. bad host) instead of silently backing off forever;
+        // only connections lost *after* this succeeds are auto-reconnected.
         let stream = TcpStream::connect(&address).await?;
         info!("✓ Connected to Unreal Engine 5 at {}", address);
 
-        Ok(Self {
-            stream: Mutex::new(stream),
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (notif_tx, notif_rx) = mpsc::unbounded_channel();
+        let (outbound_tx, outbound_rx) = mpsc::unbounded_channel();
+        let (reverse_tx, reverse_rx) = mpsc::unbounded_channel();
+        let (action_status_tx, action_status_rx) = mpsc::unbounded_channel();
+        let request_id = Arc::new(AtomicI32::new(1));
+        let action_id = Arc::new(AtomicI32::new(1));
+        let (events_tx, _events_rx) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        let supervisor_task = tokio::spawn(run_supervisor(
+            address.clone(),
+            config,
+            Some(stream),
+            pending.clone(),
+            outbound_rx,
+            notif_tx,
+            request_id.clone(),
+            events_tx.clone(),
+            reverse_tx,
+        ));
+
+        let conn = Self {
             address,
-            request_id: AtomicI32::new(1),
-        })
+            request_id,
+            pending,
+            outbound: outbound_tx,
+            notifications: Arc::new(Mutex::new(notif_rx)),
+            events: events_tx,
+            reverse_requests: Arc::new(Mutex::new(reverse_rx)),
+            action_id,
+            action_status_tx,
+            action_status_rx: Arc::new(Mutex::new(action_status_rx)),
+            capabilities: RwLock::new(UnrealCapabilities::default()),
+            supervisor_task,
+        };
+        conn.refresh_capabilities().await;
+        Ok(conn)
     }
 
-    /// Send a JSON-RPC message and wait for response
+    /// The capability set negotiated at connect time. Fully permissive
+    /// (every method reports supported) until the handshake completes.
+    pub async fn capabilities(&self) -> UnrealCapabilities {
+        self.capabilities.read().await.clone()
+    }
+
+    /// Issue the `GET_CAPABILITIES` handshake and store the result. An
+    /// older plugin build that doesn't recognize the method replies with an
+    /// error (or the request fails outright), which is treated as "no
+    /// negotiation happened" -- i.e. permissive -- rather than a hard
+    /// connect failure.
+    async fn refresh_capabilities(&self) {
+        let result = self
+            .send_rpc_prioritized(
+                methods::GET_CAPABILITIES,
+                serde_json::json!({}),
+                priority::PRIO_HIGH,
+            )
+            .await;
+
+        let negotiated = match result {
+            Ok(UnrealResponse::Success { data, .. }) => {
+                let methods = data
+                    .get("methods")
+                    .and_then(|m| m.as_array())
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|v| v.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                UnrealCapabilities {
+                    methods,
+                    negotiated: true,
+                }
+            }
+            _ => UnrealCapabilities::default(),
+        };
+
+        *self.capabilities.write().await = negotiated;
+    }
+
+    /// Send a JSON-RPC message at [`priority::PRIO_NORMAL`] and wait for
+    /// the reply matching this request's `id`, however many other
+    /// requests are in flight.
     async fn send_rpc(
         &self,
         method: &str,
         params: serde_json::Value,
+    ) -> io::Result<UnrealResponse> {
+        self.send_rpc_prioritized(method, params, priority::PRIO_NORMAL)
+            .await
+    }
+
+    /// Like [`Self::send_rpc`] but with an explicit [`priority`] class.
+    async fn send_rpc_prioritized(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        request_priority: u8,
     ) -> io::Result<UnrealResponse> {
         let id = self.request_id.fetch_add(1, Ordering::SeqCst);
 
@@ -58,261 +696,233 @@ impl UnrealConnection {
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         json_str.push('\n'); // Newline delimiter
 
-        debug!("Sending RPC: {} (id={})", method, id);
-
-        // Send message
-        let mut stream = self.stream.lock().await;
-        stream.write_all(json_str.as_bytes()).await?;
-        stream.flush().await?;
-
-        // Read response
-        let mut reader = BufReader::new(&mut *stream);
-        let mut response_line = String::new();
-        reader.read_line(&mut response_line).await?;
-
-        // Parse response
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&response_line) {
-            if let Some(error) = json.get("error") {
-                return Ok(UnrealResponse::Error {
-                    request_id: id.to_string(),
-                    message: error["message"]
-                        .as_str()
-                        .unwrap_or("Unknown error")
-                        .to_string(),
-                    code: error["code"].as_i64().unwrap_or(-1) as i32,
-                });
-            }
-            return Ok(UnrealResponse::Success {
-                request_id: id.to_string(),
-                data: json
-                    .get("result")
-                    .cloned()
-                    .unwrap_or(serde_json::Value::Null),
-            });
+        debug!(
+            "Queueing RPC: {} (id={}, priority=0x{:02x})",
+            method, id, request_priority
+        );
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.pending.lock().await.insert(id, reply_tx);
+
+        let envelope = OutboundEnvelope {
+            priority: request_priority,
+            lane_id: id,
+            line: json_str,
+        };
+        if self.outbound.send(envelope).is_err() {
+            self.pending.lock().await.remove(&id);
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "UE5 writer task is gone",
+            ));
         }
 
-        Ok(UnrealResponse::Success {
-            request_id: id.to_string(),
-            data: serde_json::Value::Null,
+        reply_rx.await.map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                "UE5 connection closed before replying",
+            )
         })
     }
 
-    /// Send a message to UE5 and wait for response (legacy interface)
-    pub async fn send_message(&self, message: UnrealMessage) -> io::Result<UnrealResponse> {
-        let (method, params) = match &message {
-            UnrealMessage::SpawnVehicle {
-                vehicle_id,
-                vehicle_type: _,
-                transform,
-            } => {
-                let robots: Vec<serde_json::Value> = vec![serde_json::json!({
-                    "id": vehicle_id.replace("robot_", "").parse::<i32>().unwrap_or(0),
-                    "x": transform.position.x * 100.0, // Convert to Unreal units (cm)
-                    "y": transform.position.y * 100.0,
-                    "z": transform.position.z * 100.0,
-                    "yaw": 0.0
-                })];
-                (
-                    methods::SPAWN_ROBOTS,
-                    serde_json::json!({ "robots": robots }),
+    /// Send a batch RPC whose array-valued field may be large, splitting
+    /// it into [`BATCH_CHUNK_SIZE`]-sized chunks tagged with a shared
+    /// `batch_id`/`seq`/`seq_count` so the UE5 plugin can reassemble them.
+    /// Each chunk is sent (and awaited) as its own RPC call, so the writer
+    /// only ever has one chunk of this batch queued at a time -- other
+    /// queued traffic in the same priority class, including another
+    /// batch's chunks, gets interleaved between them round-robin instead
+    /// of waiting for the whole batch to drain first.
+    async fn send_batch_rpc(
+        &self,
+        method: &str,
+        array_field: &str,
+        items: Vec<serde_json::Value>,
+        request_priority: u8,
+    ) -> io::Result<UnrealResponse> {
+        if items.len() <= BATCH_CHUNK_SIZE {
+            let mut params = serde_json::Map::new();
+            params.insert(array_field.to_string(), serde_json::Value::Array(items));
+            return self
+                .send_rpc_prioritized(method, serde_json::Value::Object(params), request_priority)
+                .await;
+        }
+
+        let batch_id = self.request_id.fetch_add(1, Ordering::SeqCst);
+        let chunks: Vec<&[serde_json::Value]> = items.chunks(BATCH_CHUNK_SIZE).collect();
+        let seq_count = chunks.len();
+
+        let mut last_response = None;
+        for (seq, chunk) in chunks.iter().enumerate() {
+            let mut params = serde_json::Map::new();
+            params.insert(
+                array_field.to_string(),
+                serde_json::Value::Array(chunk.to_vec()),
+            );
+            params.insert("batch_id".to_string(), serde_json::json!(batch_id));
+            params.insert("seq".to_string(), serde_json::json!(seq));
+            params.insert("seq_count".to_string(), serde_json::json!(seq_count));
+            last_response = Some(
+                self.send_rpc_prioritized(
+                    method,
+                    serde_json::Value::Object(params),
+                    request_priority,
                 )
-            }
-            UnrealMessage::Step { delta_time } => (
-                methods::SIMULATION_STEP,
-                serde_json::json!({ "delta_time": delta_time }),
-            ),
-            UnrealMessage::LoadLevel { level_name } => (
-                methods::LOAD_LEVEL,
-                serde_json::json!({ "level": level_name }),
-            ),
-            UnrealMessage::SpawnRobots { robots } => (
-                methods::SPAWN_ROBOTS,
-                serde_json::json!({ "robots": robots }),
-            ),
-            UnrealMessage::UpdatePositions { positions } => (
-                methods::UPDATE_POSITIONS,
-                serde_json::json!({ "positions": positions }),
-            ),
-            UnrealMessage::UpdateTelemetry { telemetry } => (
-                methods::UPDATE_TELEMETRY,
-                serde_json::json!({ "telemetry": telemetry }),
-            ),
-            UnrealMessage::ClearAllRobots => (methods::CLEAR_ALL_ROBOTS, serde_json::json!({})),
-            UnrealMessage::DrawDebugLines { lines } => (
-                methods::DRAW_DEBUG_LINES,
-                serde_json::json!({ "lines": lines }),
-            ),
-            UnrealMessage::DrawDebugSpheres { spheres } => (
-                methods::DRAW_DEBUG_SPHERES,
-                serde_json::json!({ "spheres": spheres }),
-            ),
-            UnrealMessage::ClearDebug => (methods::CLEAR_DEBUG, serde_json::json!({})),
-            UnrealMessage::SetVisualizationMode {
-                show_comm_links,
-                show_rf_range,
-                show_paths,
-                show_labels,
-            } => (
-                methods::SET_VIS_MODE,
-                serde_json::json!({
-                    "show_comm_links": show_comm_links,
-                    "show_rf_range": show_rf_range,
-                    "show_paths": show_paths,
-                    "show_labels": show_labels
-                }),
-            ),
-            UnrealMessage::Pause => (methods::PAUSE, serde_json::json!({})),
-            UnrealMessage::Resume => (methods::RESUME, serde_json::json!({})),
-            UnrealMessage::Reset => (methods::RESET, serde_json::json!({})),
-            UnrealMessage::GetAllStates => (methods::GET_ALL_STATES, serde_json::json!({})),
-            UnrealMessage::SetFpvCamera {
-                vehicle_id,
-                tilt_angle_deg,
-                fov_h_deg,
-                resolution_width,
-                resolution_height,
-                lens_distortion,
-                latency_ms,
-            } => (
-                methods::SET_FPV_CAMERA,
-                serde_json::json!({
-                    "vehicle_id": vehicle_id,
-                    "tilt_angle_deg": tilt_angle_deg,
-                    "fov_h_deg": fov_h_deg,
-                    "resolution_width": resolution_width,
-                    "resolution_height": resolution_height,
-                    "lens_distortion": lens_distortion,
-                    "latency_ms": latency_ms
-                }),
-            ),
-            UnrealMessage::SetFpvControl {
-                vehicle_id,
-                throttle,
-                roll,
-                pitch,
-                yaw,
-                flight_mode,
-            } => (
-                methods::SET_FPV_CONTROL,
-                serde_json::json!({
-                    "vehicle_id": vehicle_id,
-                    "throttle": throttle,
-                    "roll": roll,
-                    "pitch": pitch,
-                    "yaw": yaw,
-                    "flight_mode": flight_mode
-                }),
-            ),
-            UnrealMessage::ArmDrone { vehicle_id, armed } => (
-                methods::ARM_DRONE,
-                serde_json::json!({
-                    "vehicle_id": vehicle_id,
-                    "armed": armed
-                }),
-            ),
-            UnrealMessage::SpawnFpvDrone {
-                vehicle_id,
-                drone_preset,
-                x,
-                y,
-                z,
-                yaw,
-            } => (
-                methods::SPAWN_FPV_DRONE,
-                serde_json::json!({
-                    "vehicle_id": vehicle_id,
-                    "drone_preset": drone_preset,
-                    "x": x, "y": y, "z": z, "yaw": yaw
-                }),
-            ),
-            UnrealMessage::UpdateFpvState {
-                vehicle_id,
-                x,
-                y,
-                z,
-                qw,
-                qx,
-                qy,
-                qz,
-                speed_mps,
-                altitude_m,
-                motor_outputs,
-                battery_voltage,
-                battery_remaining,
-                flight_mode,
-                armed,
-                osd,
-            } => (
-                methods::UPDATE_FPV_STATE,
-                serde_json::json!({
-                    "vehicle_id": vehicle_id,
-                    "position": [x * 100.0, y * 100.0, z * 100.0],
-                    "orientation": [qx, qy, qz, qw],
-                    "speed_mps": speed_mps,
-                    "altitude_m": altitude_m,
-                    "motor_outputs": motor_outputs,
-                    "battery_voltage": battery_voltage,
-                    "battery_remaining": battery_remaining,
-                    "flight_mode": flight_mode,
-                    "armed": armed,
-                    "osd": osd
-                }),
-            ),
-            UnrealMessage::SetOsdVisible {
-                vehicle_id,
-                visible,
-            } => (
-                methods::SET_OSD_VISIBLE,
-                serde_json::json!({
-                    "vehicle_id": vehicle_id,
-                    "visible": visible
-                }),
-            ),
-            // Remaining variants that don't have dedicated UE5 handlers yet.
-            // These are explicitly listed (not `_ =>`) so adding a new variant
-            // to UnrealMessage produces a compile error, forcing the developer
-            // to add the handler here.
-            UnrealMessage::RemoveVehicle { vehicle_id } => {
-                warn!(
-                    "RemoveVehicle not implemented in UE5 server (vehicle: {})",
-                    vehicle_id
-                );
-                ("ping", serde_json::json!({}))
-            }
-            UnrealMessage::SetControl { vehicle_id, .. } => {
-                warn!(
-                    "SetControl not implemented in UE5 server (vehicle: {})",
-                    vehicle_id
-                );
-                ("ping", serde_json::json!({}))
-            }
-            UnrealMessage::GetState { vehicle_id } => {
-                warn!(
-                    "GetState not implemented in UE5 server (vehicle: {})",
-                    vehicle_id
-                );
-                ("ping", serde_json::json!({}))
-            }
-            UnrealMessage::CastRay { .. } => {
-                warn!("CastRay not implemented in UE5 server");
-                ("ping", serde_json::json!({}))
-            }
-            UnrealMessage::CaptureImage { vehicle_id, .. } => {
-                warn!(
-                    "CaptureImage not implemented in UE5 server (vehicle: {})",
-                    vehicle_id
-                );
-                ("ping", serde_json::json!({}))
-            }
+                .await?,
+            );
+        }
+        Ok(last_response.expect("chunks is non-empty when items.len() > BATCH_CHUNK_SIZE"))
+    }
+
+    /// Receive the next JSON-RPC notification (a frame with no `id`) pushed
+    /// by the UE5 plugin outside of any request/response cycle. Returns
+    /// `None` once the connection has closed and no more will arrive.
+    pub async fn recv_notification(&self) -> Option<serde_json::Value> {
+        self.notifications.lock().await.recv().await
+    }
+
+    /// Subscribe to [`SimEvent`]s -- collisions, arm/disarm, flight-mode
+    /// changes, waypoints, and telemetry ticks -- decoded from notifications
+    /// by the background reader and fanned out over a
+    /// [`tokio::sync::broadcast`] channel, so unlike
+    /// [`Self::recv_notification`] any number of subscribers can each get
+    /// their own copy. A subscriber that falls more than
+    /// [`EVENT_CHANNEL_CAPACITY`] events behind starts seeing
+    /// [`broadcast::error::RecvError::Lagged`] instead of blocking everyone
+    /// else.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<SimEvent> {
+        self.events.subscribe()
+    }
+
+    /// Receive the next server-initiated [`ReverseRequest`] -- UE5 calling
+    /// back into us rather than replying to one of our own requests.
+    /// Returns `None` once the connection has closed and no more will
+    /// arrive. Answer it with [`Self::respond_to_reverse_request`] (or
+    /// `_error`), which echoes the request's `id` back so UE5 can match the
+    /// reply the same way our own `send_rpc` matches replies to its ids.
+    pub async fn recv_reverse_request(&self) -> Option<ReverseRequest> {
+        self.reverse_requests.lock().await.recv().await
+    }
+
+    /// Answer a [`ReverseRequest`] with a success `result`.
+    pub fn respond_to_reverse_request(&self, id: i64, result: serde_json::Value) -> io::Result<()> {
+        self.send_reverse_response(
+            id,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "result": result
+            }),
+        )
+    }
+
+    /// Answer a [`ReverseRequest`] with an error, mirroring the
+    /// `{code, message}` shape [`UnrealResponse::Error`] parses out of a
+    /// reply UE5 sends us.
+    pub fn respond_to_reverse_request_error(
+        &self,
+        id: i64,
+        code: i32,
+        message: &str,
+    ) -> io::Result<()> {
+        self.send_reverse_response(
+            id,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message }
+            }),
+        )
+    }
+
+    /// Queue a reply frame to a [`ReverseRequest`] at [`priority::PRIO_HIGH`]
+    /// -- UE5 is blocked awaiting it, same urgency as our own control
+    /// traffic. Bypasses `pending`/`send_rpc`'s request/response
+    /// correlation entirely: we're answering UE5's request here, not
+    /// originating one of our own.
+    fn send_reverse_response(&self, id: i64, frame: serde_json::Value) -> io::Result<()> {
+        let mut json_str = serde_json::to_string(&frame)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        json_str.push('\n');
+
+        let envelope = OutboundEnvelope {
+            priority: priority::PRIO_HIGH,
+            lane_id: id as i32,
+            line: json_str,
         };
+        self.outbound.send(envelope).map_err(|_| {
+            io::Error::new(io::ErrorKind::NotConnected, "UE5 writer task is gone")
+        })
+    }
+
+    /// Allocate a fresh action id for an action-tracked method to return to
+    /// its caller immediately, ahead of the RPC it kicks off actually
+    /// completing. Its own id space, separate from the JSON-RPC
+    /// `request_id` counter, since it never appears on the wire.
+    pub fn next_action_id(&self) -> i32 {
+        self.action_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Push an [`ActionStatus`] transition for `action_id`, readable via
+    /// [`Self::recv_action_status`]. Used by the action-tracked backend
+    /// methods as their RPC progresses; harmless to call with nobody
+    /// listening yet, same as [`Self::recv_notification`].
+    pub fn report_action_status(&self, action_id: i32, state: ActionState, progress: f32) {
+        let _ = self.action_status_tx.send(ActionStatus {
+            action_id,
+            state,
+            progress,
+        });
+    }
+
+    /// Receive the next [`ActionStatus`] transition. Returns `None` once
+    /// the connection has closed and no more will arrive.
+    pub async fn recv_action_status(&self) -> Option<ActionStatus> {
+        self.action_status_rx.lock().await.recv().await
+    }
+
+    /// Tell the plugin which [`EventClass`]es to push as notifications, and
+    /// at roughly what rate (e.g. capping `Telemetry` ticks to 10Hz instead
+    /// of every simulation step).
+    pub async fn set_event_filter(
+        &self,
+        classes: &[EventClass],
+        rate_hz: f64,
+    ) -> io::Result<UnrealResponse> {
+        self.send_rpc(
+            methods::SET_EVENT_FILTER,
+            serde_json::json!({
+                "classes": classes,
+                "rate_hz": rate_hz
+            }),
+        )
+        .await
+    }
 
+    /// Send a message to UE5 and wait for response (legacy interface).
+    /// Checked against the negotiated [`capabilities`](Self::capabilities)
+    /// before dispatching: a method the connected plugin build didn't
+    /// advertise comes back as [`UnrealResponse::Unsupported`] instead of
+    /// being sent at all, so callers relying only on the compile-time
+    /// `UnrealMessage` match arms still see an explicit gap rather than a
+    /// silent no-op.
+    pub async fn send_message(&self, message: UnrealMessage) -> io::Result<UnrealResponse> {
+        let (method, params) = message_to_rpc(&message);
+        if !self.capabilities().await.supports(method) {
+            return Ok(UnrealResponse::Unsupported {
+                method: method.to_string(),
+            });
+        }
         self.send_rpc(method, params).await
     }
 
-    /// Batch spawn multiple robots in a single RPC call (legacy interface)
+    /// Batch spawn multiple robots in a single RPC call (legacy interface).
+    /// Large batches are transparently chunked (see [`Self::send_batch_rpc`]).
+    /// Defaults to [`priority::PRIO_NORMAL`]; pass `prio` to override.
     pub async fn spawn_robots_batch(
         &self,
         robots: Vec<(i32, f64, f64, f64, f64)>,
+        prio: Option<u8>,
     ) -> io::Result<UnrealResponse> {
         let robot_data: Vec<serde_json::Value> = robots
             .iter()
@@ -327,63 +937,173 @@ impl UnrealConnection {
             })
             .collect();
 
-        self.send_rpc(
+        self.send_batch_rpc(
             methods::SPAWN_ROBOTS,
-            serde_json::json!({ "robots": robot_data }),
+            "robots",
+            robot_data,
+            prio.unwrap_or(priority::PRIO_NORMAL),
         )
         .await
     }
 
-    /// Spawn robots with full type information
-    pub async fn spawn_robots(&self, robots: Vec<RobotSpawnData>) -> io::Result<UnrealResponse> {
-        self.send_rpc(
+    /// Spawn robots with full type information. Defaults to
+    /// [`priority::PRIO_NORMAL`]; pass `prio` to override.
+    pub async fn spawn_robots(
+        &self,
+        robots: Vec<RobotSpawnData>,
+        prio: Option<u8>,
+    ) -> io::Result<UnrealResponse> {
+        let items: Vec<serde_json::Value> = robots.iter().map(|r| serde_json::json!(r)).collect();
+        self.send_batch_rpc(
             methods::SPAWN_ROBOTS,
-            serde_json::json!({ "robots": robots }),
+            "robots",
+            items,
+            prio.unwrap_or(priority::PRIO_NORMAL),
         )
         .await
     }
 
-    /// Batch update robot positions (high performance)
+    /// Batch update robot positions (high performance). Defaults to
+    /// [`priority::PRIO_HIGH`] so position updates stay responsive even
+    /// behind a large spawn batch; pass `prio` to override.
     pub async fn update_positions(
         &self,
         positions: Vec<RobotPositionUpdate>,
+        prio: Option<u8>,
     ) -> io::Result<UnrealResponse> {
-        self.send_rpc(
+        let items: Vec<serde_json::Value> =
+            positions.iter().map(|p| serde_json::json!(p)).collect();
+        self.send_batch_rpc(
             methods::UPDATE_POSITIONS,
-            serde_json::json!({ "positions": positions }),
+            "positions",
+            items,
+            prio.unwrap_or(priority::PRIO_HIGH),
         )
         .await
     }
 
-    /// Update robot telemetry
+    /// Update robot telemetry. Defaults to [`priority::PRIO_HIGH`]; pass
+    /// `prio` to override.
     pub async fn update_telemetry(
         &self,
         telemetry: Vec<RobotTelemetry>,
+        prio: Option<u8>,
     ) -> io::Result<UnrealResponse> {
-        self.send_rpc(
+        let items: Vec<serde_json::Value> =
+            telemetry.iter().map(|t| serde_json::json!(t)).collect();
+        self.send_batch_rpc(
             methods::UPDATE_TELEMETRY,
-            serde_json::json!({ "telemetry": telemetry }),
+            "telemetry",
+            items,
+            prio.unwrap_or(priority::PRIO_HIGH),
         )
         .await
     }
 
-    /// Draw debug lines for communication visualization
-    pub async fn draw_debug_lines(&self, lines: Vec<DebugLine>) -> io::Result<UnrealResponse> {
-        self.send_rpc(
+    /// Draw debug lines for communication visualization. Defaults to
+    /// [`priority::PRIO_BACKGROUND`]; pass `prio` to override.
+    pub async fn draw_debug_lines(
+        &self,
+        lines: Vec<DebugLine>,
+        prio: Option<u8>,
+    ) -> io::Result<UnrealResponse> {
+        let items: Vec<serde_json::Value> = lines.iter().map(|l| serde_json::json!(l)).collect();
+        self.send_batch_rpc(
             methods::DRAW_DEBUG_LINES,
-            serde_json::json!({ "lines": lines }),
+            "lines",
+            items,
+            prio.unwrap_or(priority::PRIO_BACKGROUND),
         )
         .await
     }
 
-    /// Draw debug spheres for RF range visualization
+    /// Draw debug spheres for RF range visualization. Defaults to
+    /// [`priority::PRIO_BACKGROUND`]; pass `prio` to override.
     pub async fn draw_debug_spheres(
         &self,
         spheres: Vec<DebugSphere>,
+        prio: Option<u8>,
     ) -> io::Result<UnrealResponse> {
-        self.send_rpc(
+        let items: Vec<serde_json::Value> =
+            spheres.iter().map(|s| serde_json::json!(s)).collect();
+        self.send_batch_rpc(
             methods::DRAW_DEBUG_SPHERES,
-            serde_json::json!({ "spheres": spheres }),
+            "spheres",
+            items,
+            prio.unwrap_or(priority::PRIO_BACKGROUND),
+        )
+        .await
+    }
+
+    /// Apply munition/collision damage to a robot's `DamageState`
+    pub async fn apply_damage(
+        &self,
+        vehicle_id: &str,
+        warhead_lbs: f64,
+        impact_point: [f64; 3],
+    ) -> io::Result<UnrealResponse> {
+        self.send_rpc(
+            methods::APPLY_DAMAGE,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "warhead_lbs": warhead_lbs,
+                "impact_point": impact_point
+            }),
+        )
+        .await
+    }
+
+    /// Draw debug text labels (robot IDs, task names, link readouts).
+    /// Defaults to [`priority::PRIO_BACKGROUND`]; pass `prio` to override.
+    pub async fn draw_debug_strings(
+        &self,
+        strings: Vec<DebugString>,
+        prio: Option<u8>,
+    ) -> io::Result<UnrealResponse> {
+        let items: Vec<serde_json::Value> =
+            strings.iter().map(|s| serde_json::json!(s)).collect();
+        self.send_batch_rpc(
+            methods::DRAW_DEBUG_STRINGS,
+            "strings",
+            items,
+            prio.unwrap_or(priority::PRIO_BACKGROUND),
+        )
+        .await
+    }
+
+    /// Draw debug arrows (velocity/heading, comm-link direction). Defaults
+    /// to [`priority::PRIO_BACKGROUND`]; pass `prio` to override.
+    pub async fn draw_debug_arrows(
+        &self,
+        arrows: Vec<DebugArrow>,
+        prio: Option<u8>,
+    ) -> io::Result<UnrealResponse> {
+        let items: Vec<serde_json::Value> = arrows.iter().map(|a| serde_json::json!(a)).collect();
+        self.send_batch_rpc(
+            methods::DRAW_DEBUG_ARROWS,
+            "arrows",
+            items,
+            prio.unwrap_or(priority::PRIO_BACKGROUND),
+        )
+        .await
+    }
+
+    /// Draw connected polylines through N points each (e.g. planned paths)
+    /// as a single primitive instead of faking it with disjoint
+    /// [`DebugLine`] segments. Defaults to [`priority::PRIO_BACKGROUND`];
+    /// pass `prio` to override.
+    pub async fn draw_debug_line_strips(
+        &self,
+        strips: Vec<DebugLineStrip>,
+        prio: Option<u8>,
+    ) -> io::Result<UnrealResponse> {
+        let items: Vec<serde_json::Value> =
+            strips.iter().map(|s| serde_json::json!(s)).collect();
+        self.send_batch_rpc(
+            methods::DRAW_DEBUG_LINE_STRIPS,
+            "strips",
+            items,
+            prio.unwrap_or(priority::PRIO_BACKGROUND),
         )
         .await
     }
@@ -429,10 +1149,110 @@ impl UnrealConnection {
         .await
     }
 
-    /// Disconnect from UE5
+    /// Disconnect from UE5. Stops the reconnect loop permanently; any
+    /// requests still in flight are failed with a "connection reset" error.
     pub async fn disconnect(&mut self) -> io::Result<()> {
         info!("Disconnecting from Unreal Engine...");
-        self.stream.lock().await.shutdown().await
+        self.supervisor_task.abort();
+        fail_pending(&self.pending, "UE5 connection closed").await;
+        Ok(())
+    }
+
+    // ─── Camera Methods ──────────────────────────────────────────────────────
+
+    /// Capture one camera image from a vehicle. `encoding` selects how the
+    /// bytes come back over the wire: [`ImageEncoding::Inline`] embeds them
+    /// as base64 in the JSON reply (simplest, fine for small frames);
+    /// [`ImageEncoding::Binary`] carries only metadata in the JSON reply
+    /// and streams the raw bytes length-prefixed immediately after it on
+    /// the same socket, which [`run_reader`] reassembles and attaches
+    /// before the reply reaches this call.
+    pub async fn capture_image(
+        &self,
+        vehicle_id: &str,
+        camera_name: &str,
+        image_type: &str,
+        encoding: ImageEncoding,
+    ) -> io::Result<CameraFrame> {
+        let response = self
+            .send_rpc(
+                methods::CAPTURE_IMAGE,
+                serde_json::json!({
+                    "vehicle_id": vehicle_id,
+                    "camera_name": camera_name,
+                    "image_type": image_type,
+                    "encoding": encoding.as_str()
+                }),
+            )
+            .await?;
+        CameraFrame::from_response(response)
+    }
+
+    /// Subscribe to a continuous camera feed (FPV video), mirroring how
+    /// drone SDKs expose a video stream rather than one-shot captures.
+    /// Issues [`methods::SUBSCRIBE_CAMERA`] to start the stream on the
+    /// plugin side, then relays every subsequent notification tagged with
+    /// this `vehicle_id`/`camera_name` as a decoded [`CameraFrame`] over
+    /// the returned channel. The relay stops when the channel's receiver is
+    /// dropped or the connection closes.
+    ///
+    /// Streamed frames are always base64-inline (not the length-prefixed
+    /// [`ImageEncoding::Binary`] scheme [`Self::capture_image`] supports):
+    /// notifications have no request id to hang a follow-up binary read
+    /// off of, so framing one would need a second, keyed binary channel.
+    /// Use `capture_image` with `ImageEncoding::Binary` for large one-shot
+    /// frames instead.
+    ///
+    /// Only one subscription (or other [`Self::recv_notification`]
+    /// consumer) should be active at a time: notifications share a single
+    /// channel, so a second concurrent subscriber would steal frames from
+    /// the first instead of getting its own copy.
+    pub async fn subscribe_camera(
+        &self,
+        vehicle_id: &str,
+        camera_name: &str,
+        fps: f64,
+    ) -> io::Result<mpsc::UnboundedReceiver<CameraFrame>> {
+        self.send_rpc(
+            methods::SUBSCRIBE_CAMERA,
+            serde_json::json!({
+                "vehicle_id": vehicle_id,
+                "camera_name": camera_name,
+                "fps": fps
+            }),
+        )
+        .await?;
+
+        let (frame_tx, frame_rx) = mpsc::unbounded_channel();
+        let notifications = self.notifications.clone();
+        let vehicle_id = vehicle_id.to_string();
+        let camera_name = camera_name.to_string();
+        tokio::spawn(async move {
+            loop {
+                let Some(notification) = notifications.lock().await.recv().await else {
+                    break;
+                };
+                let matches = notification.get("vehicle_id").and_then(|v| v.as_str())
+                    == Some(vehicle_id.as_str())
+                    && notification.get("camera_name").and_then(|v| v.as_str())
+                        == Some(camera_name.as_str());
+                if !matches {
+                    continue;
+                }
+                let frame = match CameraFrame::from_inline_notification(&notification) {
+                    Ok(frame) => frame,
+                    Err(e) => {
+                        warn!("Dropping malformed camera notification: {}", e);
+                        continue;
+                    }
+                };
+                if frame_tx.send(frame).is_err() {
+                    break; // Receiver dropped: subscriber is gone.
+                }
+            }
+        });
+
+        Ok(frame_rx)
     }
 
     // ─── FPV Methods ─────────────────────────────────────────────────────────
@@ -497,7 +1317,7 @@ impl UnrealConnection {
         yaw: f64,
         flight_mode: &str,
     ) -> io::Result<UnrealResponse> {
-        self.send_rpc(
+        self.send_rpc_prioritized(
             methods::SET_FPV_CONTROL,
             serde_json::json!({
                 "vehicle_id": vehicle_id,
@@ -507,6 +1327,7 @@ impl UnrealConnection {
                 "yaw": yaw,
                 "flight_mode": flight_mode
             }),
+            priority::PRIO_HIGH,
         )
         .await
     }
@@ -531,7 +1352,7 @@ impl UnrealConnection {
         use crate::unreal::protocol::FpvOsdData;
 
         let osd_data: FpvOsdData = state.osd.clone().into();
-        self.send_rpc(
+        self.send_rpc_prioritized(
             methods::UPDATE_FPV_STATE,
             serde_json::json!({
                 "vehicle_id": state.vehicle_id,
@@ -551,6 +1372,7 @@ impl UnrealConnection {
                 "armed": state.armed,
                 "osd": osd_data
             }),
+            priority::PRIO_HIGH,
         )
         .await
     }
@@ -571,3 +1393,311 @@ impl UnrealConnection {
         .await
     }
 }
+
+impl Drop for UnrealConnection {
+    fn drop(&mut self) {
+        self.supervisor_task.abort();
+    }
+}
+
+/// Fail every still-pending request with a typed "connection reset" error
+/// (an [`UnrealResponse::Error`] with `code: -1`, the same convention
+/// already used for a hard disconnect) so its caller can distinguish a
+/// transport-level reset from a real UE5-side error and decide whether to
+/// retry, rather than leaving it awaiting a oneshot that never resolves.
+async fn fail_pending(pending: &PendingMap, message: &str) {
+    let mut pending = pending.lock().await;
+    for (id, reply_tx) in pending.drain() {
+        let _ = reply_tx.send(UnrealResponse::Error {
+            request_id: id.to_string(),
+            message: message.to_string(),
+            code: -1,
+        });
+    }
+}
+
+/// Owns the reconnect loop documented on this module: connects (or reuses
+/// `initial_stream` the first time through, since [`UnrealConnection::connect`]
+/// already dialed it to surface the initial error synchronously), runs the
+/// reader and writer for that socket until either hits an I/O error, then
+/// fails whatever is still pending, resets the JSON-RPC id space, and backs
+/// off per [`ConnectionConfig`] before retrying. `outbound_rx` is carried
+/// across reconnects so requests queued during an outage are sent as soon
+/// as the next connection comes up instead of being dropped.
+async fn run_supervisor(
+    address: String,
+    config: ConnectionConfig,
+    mut initial_stream: Option<TcpStream>,
+    pending: PendingMap,
+    mut outbound_rx: mpsc::UnboundedReceiver<OutboundEnvelope>,
+    notifications: mpsc::UnboundedSender<serde_json::Value>,
+    request_id: Arc<AtomicI32>,
+    events: broadcast::Sender<SimEvent>,
+    reverse_requests: mpsc::UnboundedSender<ReverseRequest>,
+) {
+    let mut attempt: u32 = 0;
+    loop {
+        let stream = match initial_stream.take() {
+            Some(stream) => stream,
+            None => match TcpStream::connect(&address).await {
+                Ok(stream) => {
+                    info!("✓ Reconnected to Unreal Engine 5 at {}", address);
+                    stream
+                }
+                Err(e) => {
+                    attempt += 1;
+                    if config.max_retries.is_some_and(|max| attempt > max) {
+                        warn!(
+                            "UE5 reconnect to {} giving up after {} attempt(s): {}",
+                            address, attempt, e
+                        );
+                        fail_pending(&pending, "exceeded max reconnect attempts").await;
+                        return;
+                    }
+                    let delay = config.backoff(attempt);
+                    warn!(
+                        "UE5 reconnect attempt {} to {} failed ({}), retrying in {:?}",
+                        attempt, address, e, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+            },
+        };
+
+        attempt = 0;
+        // Reset the id space cleanly on every (re)connect so the new
+        // socket's replies can't collide with ids from the old one.
+        request_id.store(1, Ordering::SeqCst);
+
+        let (read_half, write_half) = stream.into_split();
+        let mut reader = BufReader::new(read_half);
+        let mut writer = write_half;
+
+        tokio::select! {
+            _ = run_reader(&mut reader, &pending, &notifications, &events, &reverse_requests) => {}
+            _ = run_writer(&mut writer, &mut outbound_rx) => {}
+        }
+
+        warn!("UE5 connection to {} lost, reconnecting...", address);
+        fail_pending(&pending, "UE5 connection reset; reconnecting").await;
+    }
+}
+
+/// Reads newline-delimited JSON-RPC frames for the current connection and
+/// dispatches each one to [`dispatch_frame`] as it arrives, independent of
+/// whatever `send_rpc` callers are currently waiting. A frame whose result
+/// carries a `binary_len` (see [`ImageEncoding::Binary`]) is followed by
+/// that many raw bytes on the wire before the next line -- `run_reader`
+/// reads them with [`AsyncReadExt::read_exact`] and attaches them to the
+/// reply as [`UnrealResponse::Binary`] before it reaches the caller.
+/// Returns on EOF or a read error so [`run_supervisor`] can reconnect.
+async fn run_reader(
+    reader: &mut BufReader<OwnedReadHalf>,
+    pending: &PendingMap,
+    notifications: &mpsc::UnboundedSender<serde_json::Value>,
+    events: &broadcast::Sender<SimEvent>,
+    reverse_requests: &mpsc::UnboundedSender<ReverseRequest>,
+) {
+    loop {
+        let mut line = String::new();
+        match reader.read_line(&mut line).await {
+            Ok(0) => break, // EOF: UE5 closed the connection
+            Ok(_) => match dispatch_frame(&line, pending, notifications, events, reverse_requests)
+                .await
+            {
+                FrameOutcome::Done => {}
+                FrameOutcome::AwaitBinaryPayload {
+                    id,
+                    metadata,
+                    byte_len,
+                } => {
+                    let mut payload = vec![0u8; byte_len];
+                    if let Err(e) = reader.read_exact(&mut payload).await {
+                        warn!(
+                            "UE5 connection error reading {} byte payload for request {}: {}",
+                            byte_len, id, e
+                        );
+                        break;
+                    }
+                    if let Some(reply_tx) = pending.lock().await.remove(&id) {
+                        let _ = reply_tx.send(UnrealResponse::Binary {
+                            request_id: id.to_string(),
+                            data: metadata,
+                            payload,
+                        });
+                    }
+                }
+            },
+            Err(e) => {
+                warn!("UE5 connection read error: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+/// What [`run_reader`] should do after [`dispatch_frame`] parses one line.
+enum FrameOutcome {
+    /// The frame was a notification, an error, or a plain (non-binary)
+    /// success -- already delivered, nothing further to read.
+    Done,
+    /// The frame's result carried a `binary_len`: `byte_len` raw bytes
+    /// immediately follow on the wire and must be read and attached to the
+    /// pending reply for `id` before it's delivered.
+    AwaitBinaryPayload {
+        id: i32,
+        metadata: serde_json::Value,
+        byte_len: usize,
+    },
+}
+
+/// Parse one newline-delimited frame and either fulfill the pending request
+/// it answers, forward it as a notification (no `id`) instead of silently
+/// dropping it, forward it as a [`ReverseRequest`] (has both `id` and
+/// `method` -- UE5 calling back into us rather than replying to one of our
+/// own), or -- if its result carries a `binary_len` -- tell [`run_reader`]
+/// to read the trailing raw payload before the reply is delivered.
+async fn dispatch_frame(
+    line: &str,
+    pending: &PendingMap,
+    notifications: &mpsc::UnboundedSender<serde_json::Value>,
+    events: &broadcast::Sender<SimEvent>,
+    reverse_requests: &mpsc::UnboundedSender<ReverseRequest>,
+) -> FrameOutcome {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+        warn!("Failed to parse UE5 frame: {}", line.trim());
+        return FrameOutcome::Done;
+    };
+
+    let Some(id) = json.get("id").and_then(serde_json::Value::as_i64) else {
+        // No `id`: a JSON-RPC notification, not a reply to a pending
+        // request. Notifications shaped like a `SimEvent` are also
+        // broadcast to any `subscribe_events` listeners -- unrecognized
+        // shapes (e.g. a `subscribe_camera` frame) are only forwarded to
+        // `notifications`, same as before.
+        if let Ok(event) = serde_json::from_value::<SimEvent>(json.clone()) {
+            let _ = events.send(event);
+        }
+        let _ = notifications.send(json);
+        return FrameOutcome::Done;
+    };
+
+    // An `id` *and* a `method`: UE5 is issuing its own request and expects
+    // a reply, not answering one of ours (our own replies never carry
+    // `method`). Forward it instead of looking it up in `pending`, where
+    // it would never be found and would silently vanish.
+    if let Some(method) = json.get("method").and_then(|m| m.as_str()) {
+        let _ = reverse_requests.send(ReverseRequest {
+            id,
+            method: method.to_string(),
+            params: json.get("params").cloned().unwrap_or(serde_json::Value::Null),
+        });
+        return FrameOutcome::Done;
+    }
+    let id = id as i32;
+
+    if let Some(error) = json.get("error") {
+        let response = UnrealResponse::Error {
+            request_id: id.to_string(),
+            message: error["message"]
+                .as_str()
+                .unwrap_or("Unknown error")
+                .to_string(),
+            code: error["code"].as_i64().unwrap_or(-1) as i32,
+        };
+        if let Some(reply_tx) = pending.lock().await.remove(&id) {
+            let _ = reply_tx.send(response);
+        }
+        return FrameOutcome::Done;
+    }
+
+    let data = json
+        .get("result")
+        .cloned()
+        .unwrap_or(serde_json::Value::Null);
+
+    if let Some(byte_len) = data.get("binary_len").and_then(serde_json::Value::as_u64) {
+        return FrameOutcome::AwaitBinaryPayload {
+            id,
+            metadata: data,
+            byte_len: byte_len as usize,
+        };
+    }
+
+    if let Some(reply_tx) = pending.lock().await.remove(&id) {
+        let _ = reply_tx.send(UnrealResponse::Success {
+            request_id: id.to_string(),
+            data,
+        });
+    }
+    FrameOutcome::Done
+}
+
+/// Drains [`OutboundEnvelope`]s for the current connection by [`priority`]
+/// class (high before normal before background), round-robin within a class
+/// across [`OutboundEnvelope::lane_id`] lanes, so one large chunked batch
+/// can't monopolize the link ahead of other queued traffic. Returns on a
+/// write error or once `outbound` is closed (connection handle dropped) so
+/// [`run_supervisor`] can reconnect -- any envelopes still queued in
+/// `outbound`, including undrained local lanes discarded with this task,
+/// are carried over to the next connection via their callers' `pending`
+/// entries (see [`fail_pending`]).
+async fn run_writer(
+    writer: &mut OwnedWriteHalf,
+    outbound: &mut mpsc::UnboundedReceiver<OutboundEnvelope>,
+) {
+    let mut high = PriorityClass::default();
+    let mut normal = PriorityClass::default();
+    let mut background = PriorityClass::default();
+
+    loop {
+        if high.is_empty() && normal.is_empty() && background.is_empty() {
+            // Nothing queued: block until either a new frame arrives or the
+            // connection handle is dropped (sender gone).
+            match outbound.recv().await {
+                Some(envelope) => enqueue(envelope, &mut high, &mut normal, &mut background),
+                None => return,
+            }
+        } else {
+            // Pull in anything that arrived since the last write without
+            // blocking, so already-queued frames aren't held up.
+            while let Ok(envelope) = outbound.try_recv() {
+                enqueue(envelope, &mut high, &mut normal, &mut background);
+            }
+        }
+
+        let Some(line) = high
+            .pop()
+            .or_else(|| normal.pop())
+            .or_else(|| background.pop())
+        else {
+            continue;
+        };
+
+        if let Err(e) = writer.write_all(line.as_bytes()).await {
+            warn!("UE5 connection write error: {}", e);
+            return;
+        }
+        if let Err(e) = writer.flush().await {
+            warn!("UE5 connection flush error: {}", e);
+            return;
+        }
+    }
+}
+
+fn enqueue(
+    envelope: OutboundEnvelope,
+    high: &mut PriorityClass,
+    normal: &mut PriorityClass,
+    background: &mut PriorityClass,
+) {
+    let class = if envelope.priority <= priority::PRIO_HIGH {
+        high
+    } else if envelope.priority <= priority::PRIO_NORMAL {
+        normal
+    } else {
+        background
+    };
+    class.push(envelope.lane_id, envelope.line);
+}
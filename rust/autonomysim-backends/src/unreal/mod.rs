@@ -26,12 +26,18 @@ use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use tracing::{debug, info, warn};
 
+mod client;
 mod connection;
 mod protocol;
 
-pub use connection::UnrealConnection;
+pub use client::{UnrealClient, UnrealError, UnrealEvent};
+pub use connection::{
+    CameraFrame, ConnectionConfig, ImageEncoding, UnrealCapabilities, UnrealConnection,
+};
 pub use protocol::{
-    DebugLine, DebugSphere, RobotPositionUpdate, RobotSpawnData, RobotTelemetry, RobotType,
+    ActionState, ActionStatus, DamageState, DebugArrow, DebugLine, DebugLineStrip, DebugSphere,
+    DebugString, EventClass, PrototypeRegistry, ReverseRequest, RobotPositionUpdate,
+    RobotPrototype, RobotSpawnData, RobotTelemetry, SimEvent, SubsystemState, SubsystemType,
     UnrealMessage, UnrealResponse,
 };
 
@@ -115,12 +121,125 @@ impl UnrealEngine5Backend {
 
         conn.read()
             .await
-            .spawn_robots_batch(robots)
+            .spawn_robots_batch(robots, None)
             .await
-            .map_err(|e| format!("Failed to spawn robots: {}", e))?;
+            .map_err(|e| format!("Failed to spawn robots: {}", e))?
+            .into_result()
+            .map_err(|e| format!("UE5 rejected spawn_robots_batch: {}", e))?;
 
         Ok(())
     }
+
+    /// Like [`SimulationBackend::spawn_vehicle`], but returns an
+    /// `action_id` immediately instead of waiting for UE5's reply: the
+    /// RPC runs on a background task, reporting `Queued` -> `Running` ->
+    /// `Completed`/`Failed` through [`Self::recv_action_status`] as it
+    /// progresses, so a caller can submit several spawns and observe
+    /// their outcomes as they land rather than assuming `Ok(())` meant
+    /// UE5 actually finished.
+    pub async fn spawn_vehicle_tracked(&self, spec: VehicleSpec) -> Result<i32, String> {
+        let conn = self
+            .connection
+            .clone()
+            .ok_or_else(|| "Backend not initialized".to_string())?;
+        let action_id = conn.read().await.next_action_id();
+        conn.read()
+            .await
+            .report_action_status(action_id, ActionState::Queued, 0.0);
+
+        let msg = UnrealMessage::SpawnVehicle {
+            vehicle_id: spec.vehicle_id.clone(),
+            vehicle_type: format!("{:?}", spec.vehicle_type),
+            transform: spec.initial_transform,
+        };
+        tokio::spawn(async move {
+            let guard = conn.read().await;
+            guard.report_action_status(action_id, ActionState::Running, 0.5);
+            let state = match guard.send_message(msg).await {
+                Ok(_) => ActionState::Completed,
+                Err(_) => ActionState::Failed,
+            };
+            guard.report_action_status(action_id, state, 1.0);
+        });
+
+        Ok(action_id)
+    }
+
+    /// Like [`SimulationBackend::remove_vehicle`], but returns an
+    /// `action_id` immediately instead of waiting for UE5's reply; see
+    /// [`Self::spawn_vehicle_tracked`].
+    pub async fn remove_vehicle_tracked(&self, vehicle_id: &str) -> Result<i32, String> {
+        let conn = self
+            .connection
+            .clone()
+            .ok_or_else(|| "Backend not initialized".to_string())?;
+        let action_id = conn.read().await.next_action_id();
+        conn.read()
+            .await
+            .report_action_status(action_id, ActionState::Queued, 0.0);
+
+        let msg = UnrealMessage::RemoveVehicle {
+            vehicle_id: vehicle_id.to_string(),
+        };
+        tokio::spawn(async move {
+            let guard = conn.read().await;
+            guard.report_action_status(action_id, ActionState::Running, 0.5);
+            let state = match guard.send_message(msg).await {
+                Ok(_) => ActionState::Completed,
+                Err(_) => ActionState::Failed,
+            };
+            guard.report_action_status(action_id, state, 1.0);
+        });
+
+        Ok(action_id)
+    }
+
+    /// Like [`SimulationBackend::set_vehicle_control`], but actually talks
+    /// to UE5 (the trait method is a local no-op, see its doc comment) and
+    /// returns an `action_id` immediately; see
+    /// [`Self::spawn_vehicle_tracked`].
+    pub async fn set_vehicle_control_tracked(
+        &self,
+        vehicle_id: &str,
+        control: VehicleControl,
+    ) -> Result<i32, String> {
+        let conn = self
+            .connection
+            .clone()
+            .ok_or_else(|| "Backend not initialized".to_string())?;
+        let action_id = conn.read().await.next_action_id();
+        conn.read()
+            .await
+            .report_action_status(action_id, ActionState::Queued, 0.0);
+
+        let msg = UnrealMessage::SetControl {
+            vehicle_id: vehicle_id.to_string(),
+            throttle: control.throttle,
+            steering: control.steering,
+            brake: control.brake,
+            pitch: control.pitch,
+            roll: control.roll,
+            yaw: control.yaw,
+        };
+        tokio::spawn(async move {
+            let guard = conn.read().await;
+            guard.report_action_status(action_id, ActionState::Running, 0.5);
+            let state = match guard.send_message(msg).await {
+                Ok(_) => ActionState::Completed,
+                Err(_) => ActionState::Failed,
+            };
+            guard.report_action_status(action_id, state, 1.0);
+        });
+
+        Ok(action_id)
+    }
+
+    /// Receive the next [`ActionStatus`] transition reported by any
+    /// action-tracked method above.
+    pub async fn recv_action_status(&self) -> Option<ActionStatus> {
+        let conn = self.connection.as_ref()?;
+        conn.read().await.recv_action_status().await
+    }
 }
 
 #[async_trait]
@@ -179,12 +298,12 @@ impl SimulationBackend for UnrealEngine5Backend {
             level_name: scene_path.to_string(),
         };
 
-        let response = conn
-            .read()
+        conn.read()
             .await
             .send_message(msg)
             .await
-            .map_err(|e| SimError::BackendError(format!("Failed to load scene: {}", e)))?;
+            .map_err(|e| SimError::BackendError(format!("Failed to load scene: {}", e)))?
+            .into_result()?;
 
         let scene_id = format!("scene_{}", uuid::Uuid::new_v4());
         let handle = UnrealSceneHandle {
@@ -237,6 +356,19 @@ impl SimulationBackend for UnrealEngine5Backend {
         Ok(vec![None; rays.len()])
     }
 
+    fn trace_rf_paths(
+        &self,
+        _scene: &SceneHandle,
+        _tx_pos: Position,
+        _rx_pos: Position,
+        _frequency_hz: f64,
+        _max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>> {
+        // Multi-bounce RF path tracing via UE5's line traces is not
+        // implemented yet.
+        Ok(Vec::new())
+    }
+
     fn get_objects(&self, scene: &SceneHandle) -> SimResult<Vec<SceneObject>> {
         // Query all actors in the level
         Ok(Vec::new())
@@ -252,7 +384,8 @@ impl SimulationBackend for UnrealEngine5Backend {
                 .await
                 .send_message(msg)
                 .await
-                .map_err(|e| SimError::BackendError(format!("Step failed: {}", e)))?;
+                .map_err(|e| SimError::BackendError(format!("Step failed: {}", e)))?
+                .into_result()?;
         }
 
         Ok(())
@@ -289,7 +422,8 @@ impl SimulationBackend for UnrealEngine5Backend {
             .await
             .send_message(msg)
             .await
-            .map_err(|e| SimError::BackendError(format!("Failed to remove vehicle: {}", e)))?;
+            .map_err(|e| SimError::BackendError(format!("Failed to remove vehicle: {}", e)))?
+            .into_result()?;
 
         self.vehicles.remove(vehicle_id);
 
@@ -329,6 +463,17 @@ impl SimulationBackend for UnrealEngine5Backend {
             "Sensor data not yet implemented for UE5".to_string(),
         ))
     }
+
+    fn set_sensor_fault(
+        &mut self,
+        _vehicle_id: &str,
+        _sensor_id: &str,
+        _fault: Option<autonomysim_core::vehicle::SensorFault>,
+    ) -> SimResult<()> {
+        Err(SimError::BackendError(
+            "Sensor fault injection not yet implemented for UE5".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
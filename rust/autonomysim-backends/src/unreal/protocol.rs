@@ -9,40 +9,161 @@
 //! - Camera/sensor capture
 
 use autonomysim_core::fpv::{FpvFlightMode, FpvOsd};
-use autonomysim_core::Transform;
+use autonomysim_core::{SimError, Transform};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-/// Robot type identifier for visual differentiation
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum RobotType {
-    Scout,       // Red
-    Transport,   // Blue
-    Combat,      // Green
-    Relay,       // Yellow
-    Coordinator, // Purple
+/// A data-driven robot class: visual appearance, UE5 blueprint, dynamics,
+/// and loadout, keyed by name in a [`PrototypeRegistry`] instead of being
+/// hardcoded as enum variants. Lets users define custom craft (and their
+/// blueprints, colors, and dynamics) purely through config, without editing
+/// this crate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotPrototype {
+    pub name: String,
+    /// Visual color in RGBA (0-255), used for debug visualization.
+    pub color: [u8; 4],
+    /// Unreal blueprint path to spawn for this prototype.
+    pub blueprint_path: String,
+    pub max_velocity: f64,
+    pub mass: f64,
+    pub sensor_loadout: Vec<String>,
+    pub subsystems: Vec<SubsystemType>,
 }
 
-impl RobotType {
-    /// Get the color for this robot type in RGBA (0-255)
-    pub fn color(&self) -> [u8; 4] {
-        match self {
-            RobotType::Scout => [255, 64, 64, 255],        // Red
-            RobotType::Transport => [64, 128, 255, 255],   // Blue
-            RobotType::Combat => [64, 255, 64, 255],       // Green
-            RobotType::Relay => [255, 255, 64, 255],       // Yellow
-            RobotType::Coordinator => [192, 64, 255, 255], // Purple
+/// Registry of [`RobotPrototype`]s keyed by name, loaded from config
+/// (JSON) rather than compiled in. [`PrototypeRegistry::built_in`] seeds
+/// the five robot classes that used to be the fixed `RobotType` enum, so
+/// existing scenes that spawn them by name keep working unmodified.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrototypeRegistry {
+    prototypes: HashMap<String, RobotPrototype>,
+}
+
+impl Default for PrototypeRegistry {
+    /// Defaults to the five built-in prototypes, so existing scenes that
+    /// spawn them by name keep working without loading any config.
+    fn default() -> Self {
+        Self::built_in()
+    }
+}
+
+impl PrototypeRegistry {
+    /// The five built-in prototypes, matching the colors/blueprints of the
+    /// former `RobotType` enum (Scout/Transport/Combat/Relay/Coordinator).
+    pub fn built_in() -> Self {
+        let defaults = [
+            RobotPrototype {
+                name: "scout".to_string(),
+                color: [255, 64, 64, 255], // Red
+                blueprint_path: "/Game/Robots/BP_ScoutDrone".to_string(),
+                max_velocity: 20.0,
+                mass: 1.5,
+                sensor_loadout: vec!["camera".to_string(), "gps".to_string()],
+                subsystems: vec![
+                    SubsystemType::Motor,
+                    SubsystemType::Gps,
+                    SubsystemType::Radio,
+                    SubsystemType::Camera,
+                    SubsystemType::Battery,
+                ],
+            },
+            RobotPrototype {
+                name: "transport".to_string(),
+                color: [64, 128, 255, 255], // Blue
+                blueprint_path: "/Game/Robots/BP_TransportUGV".to_string(),
+                max_velocity: 8.0,
+                mass: 400.0,
+                sensor_loadout: vec!["gps".to_string()],
+                subsystems: vec![
+                    SubsystemType::Motor,
+                    SubsystemType::Gps,
+                    SubsystemType::Radio,
+                    SubsystemType::Battery,
+                ],
+            },
+            RobotPrototype {
+                name: "combat".to_string(),
+                color: [64, 255, 64, 255], // Green
+                blueprint_path: "/Game/Robots/BP_CombatDrone".to_string(),
+                max_velocity: 30.0,
+                mass: 3.0,
+                sensor_loadout: vec!["camera".to_string(), "gps".to_string()],
+                subsystems: vec![
+                    SubsystemType::Motor,
+                    SubsystemType::Gps,
+                    SubsystemType::Radio,
+                    SubsystemType::Camera,
+                    SubsystemType::Battery,
+                ],
+            },
+            RobotPrototype {
+                name: "relay".to_string(),
+                color: [255, 255, 64, 255], // Yellow
+                blueprint_path: "/Game/Robots/BP_RelayDrone".to_string(),
+                max_velocity: 15.0,
+                mass: 1.2,
+                sensor_loadout: vec!["gps".to_string()],
+                subsystems: vec![
+                    SubsystemType::Motor,
+                    SubsystemType::Gps,
+                    SubsystemType::Radio,
+                    SubsystemType::Battery,
+                ],
+            },
+            RobotPrototype {
+                name: "coordinator".to_string(),
+                color: [192, 64, 255, 255], // Purple
+                blueprint_path: "/Game/Robots/BP_CoordinatorDrone".to_string(),
+                max_velocity: 15.0,
+                mass: 1.2,
+                sensor_loadout: vec!["camera".to_string(), "gps".to_string()],
+                subsystems: vec![
+                    SubsystemType::Motor,
+                    SubsystemType::Gps,
+                    SubsystemType::Radio,
+                    SubsystemType::Camera,
+                    SubsystemType::Battery,
+                ],
+            },
+        ];
+
+        let mut prototypes = HashMap::with_capacity(defaults.len());
+        for prototype in defaults {
+            prototypes.insert(prototype.name.clone(), prototype);
         }
+        Self { prototypes }
     }
 
-    /// Get the Unreal blueprint path for this robot type
-    pub fn blueprint_path(&self) -> &'static str {
-        match self {
-            RobotType::Scout => "/Game/Robots/BP_ScoutDrone",
-            RobotType::Transport => "/Game/Robots/BP_TransportUGV",
-            RobotType::Combat => "/Game/Robots/BP_CombatDrone",
-            RobotType::Relay => "/Game/Robots/BP_RelayDrone",
-            RobotType::Coordinator => "/Game/Robots/BP_CoordinatorDrone",
+    /// Look up a prototype by name.
+    pub fn get(&self, name: &str) -> Option<&RobotPrototype> {
+        self.prototypes.get(name)
+    }
+
+    /// Register (or overwrite) a prototype.
+    pub fn register(&mut self, prototype: RobotPrototype) {
+        self.prototypes.insert(prototype.name.clone(), prototype);
+    }
+
+    /// Load a registry from a JSON array of [`RobotPrototype`] entries,
+    /// e.g. read from a config file.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let prototypes: Vec<RobotPrototype> = serde_json::from_str(json)?;
+        let mut registry = Self::default();
+        for prototype in prototypes {
+            registry.register(prototype);
         }
+        Ok(registry)
+    }
+
+    /// Merge a JSON array of [`RobotPrototype`] entries into this
+    /// registry, overwriting any existing entries with the same name.
+    pub fn merge_json(&mut self, json: &str) -> serde_json::Result<()> {
+        let prototypes: Vec<RobotPrototype> = serde_json::from_str(json)?;
+        for prototype in prototypes {
+            self.register(prototype);
+        }
+        Ok(())
     }
 }
 
@@ -50,7 +171,9 @@ impl RobotType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RobotSpawnData {
     pub id: i32,
-    pub robot_type: RobotType,
+    /// Name of the [`RobotPrototype`] in the active [`PrototypeRegistry`]
+    /// to spawn, e.g. `"scout"` or a user-defined custom craft.
+    pub prototype: String,
     pub x: f64, // Unreal units (cm)
     pub y: f64,
     pub z: f64,
@@ -74,13 +197,164 @@ pub struct RobotPositionUpdate {
 pub struct RobotTelemetry {
     pub id: i32,
     pub battery_percent: f32,
-    pub health_percent: f32,
+    pub damage: DamageState,
     pub signal_strength_dbm: f32,
     pub is_jammed: bool,
     pub is_active: bool,
     pub current_task: Option<String>,
 }
 
+/// Which subsystem of a robot the failure-mode damage model can knock out.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SubsystemType {
+    Motor,
+    Gps,
+    Radio,
+    Camera,
+    Battery,
+}
+
+/// State of a single subsystem under the failure-mode damage model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubsystemState {
+    pub subsystem: SubsystemType,
+    pub operational: bool,
+    /// Remaining effectiveness while still operational: `1.0` undamaged,
+    /// `0.0` knocked out.
+    pub degradation: f32,
+    /// How readily this subsystem absorbs impact damage relative to the
+    /// others (higher = more fragile).
+    pub vulnerability: f32,
+}
+
+/// Per-robot damage model carried in [`RobotTelemetry`], replacing a flat
+/// `health_percent` scalar with either a simple hitpoint model or a
+/// failure-mode model where impacts probabilistically knock out discrete
+/// subsystems instead of (or in addition to) killing the robot outright.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DamageState {
+    /// Dies when `hp` reaches zero.
+    Hitpoints { hp: f32, hp_max: f32 },
+    /// Accumulated damage degrades and eventually knocks out individual
+    /// subsystems; the robot is destroyed once every subsystem is down.
+    FailureMode { subsystems: Vec<SubsystemState> },
+}
+
+/// Hitpoints lost per pound of warhead at full effect (hitpoint mode).
+const HP_DAMAGE_PER_LB: f32 = 2.0;
+/// Degradation lost per pound of warhead at full effect, before the
+/// per-subsystem `vulnerability` multiplier (failure-mode mode).
+const FAILURE_DEGRADATION_PER_LB: f32 = 0.02;
+
+impl DamageState {
+    /// A fresh hitpoint-mode robot at full health.
+    pub fn hitpoints(hp_max: f32) -> Self {
+        Self::Hitpoints { hp: hp_max, hp_max }
+    }
+
+    /// A fresh failure-mode robot with all subsystems operational.
+    pub fn failure_mode() -> Self {
+        let defaults = [
+            (SubsystemType::Motor, 1.0),
+            (SubsystemType::Gps, 1.3),
+            (SubsystemType::Radio, 1.5),
+            (SubsystemType::Camera, 1.2),
+            (SubsystemType::Battery, 0.8),
+        ];
+        Self::FailureMode {
+            subsystems: defaults
+                .into_iter()
+                .map(|(subsystem, vulnerability)| SubsystemState {
+                    subsystem,
+                    operational: true,
+                    degradation: 1.0,
+                    vulnerability,
+                })
+                .collect(),
+        }
+    }
+
+    /// Fraction of health/functionality remaining, in `[0.0, 1.0]`.
+    pub fn health_fraction(&self) -> f32 {
+        match self {
+            Self::Hitpoints { hp, hp_max } if *hp_max > 0.0 => (hp / hp_max).clamp(0.0, 1.0),
+            Self::Hitpoints { .. } => 0.0,
+            Self::FailureMode { subsystems } if !subsystems.is_empty() => {
+                subsystems.iter().map(|s| s.degradation).sum::<f32>() / subsystems.len() as f32
+            }
+            Self::FailureMode { .. } => 1.0,
+        }
+    }
+
+    /// Whether the robot is destroyed: hitpoints at zero, or every
+    /// subsystem knocked out.
+    pub fn is_destroyed(&self) -> bool {
+        match self {
+            Self::Hitpoints { hp, .. } => *hp <= 0.0,
+            Self::FailureMode { subsystems } => {
+                !subsystems.is_empty() && subsystems.iter().all(|s| !s.operational)
+            }
+        }
+    }
+
+    /// Whether a knocked-out `Radio` should force comm loss equivalent to
+    /// `RobotTelemetry::is_jammed`. Always `false` in hitpoint mode.
+    pub fn radio_knocked_out(&self) -> bool {
+        match self {
+            Self::Hitpoints { .. } => false,
+            Self::FailureMode { subsystems } => subsystems
+                .iter()
+                .any(|s| s.subsystem == SubsystemType::Radio && !s.operational),
+        }
+    }
+
+    /// Velocity cap imposed by the `Motor` subsystem's current state:
+    /// `1.0` undamaged/hitpoint mode, `0.0` once the motor is knocked out.
+    pub fn motor_speed_multiplier(&self) -> f32 {
+        match self {
+            Self::Hitpoints { .. } => 1.0,
+            Self::FailureMode { subsystems } => subsystems
+                .iter()
+                .find(|s| s.subsystem == SubsystemType::Motor)
+                .map(|s| if s.operational { s.degradation } else { 0.0 })
+                .unwrap_or(1.0),
+        }
+    }
+
+    /// Apply impact damage scaled by warhead size and distance to the
+    /// impact point: full effect inside `full_damage_dist_m`, falling off
+    /// linearly to zero by `2 * full_damage_dist_m`.
+    pub fn apply_damage(&mut self, warhead_lbs: f64, distance_m: f64, full_damage_dist_m: f64) {
+        let falloff = if distance_m <= full_damage_dist_m {
+            1.0
+        } else {
+            (1.0 - (distance_m - full_damage_dist_m) / full_damage_dist_m.max(1e-6)).clamp(0.0, 1.0)
+        } as f32;
+        let magnitude = warhead_lbs as f32 * falloff;
+        if magnitude <= 0.0 {
+            return;
+        }
+
+        match self {
+            Self::Hitpoints { hp, .. } => {
+                *hp = (*hp - magnitude * HP_DAMAGE_PER_LB).max(0.0);
+            }
+            Self::FailureMode { subsystems } => {
+                for subsystem in subsystems.iter_mut() {
+                    if !subsystem.operational {
+                        continue;
+                    }
+                    let applied = magnitude * subsystem.vulnerability * FAILURE_DEGRADATION_PER_LB;
+                    subsystem.degradation = (subsystem.degradation - applied).max(0.0);
+                    if subsystem.degradation <= 0.0 {
+                        subsystem.operational = false;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Debug line for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugLine {
@@ -89,6 +363,9 @@ pub struct DebugLine {
     pub color: [u8; 4],
     pub thickness: f32,
     pub duration: f32, // 0 = single frame
+    /// If `true`, the mark stays until `ClearDebug` instead of expiring
+    /// after `duration`.
+    pub is_persistent: bool,
 }
 
 /// Debug sphere for visualization
@@ -98,6 +375,49 @@ pub struct DebugSphere {
     pub radius: f64,
     pub color: [u8; 4],
     pub duration: f32,
+    /// If `true`, the mark stays until `ClearDebug` instead of expiring
+    /// after `duration`.
+    pub is_persistent: bool,
+}
+
+/// Debug text label for visualization (robot IDs, task names, link readouts)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugString {
+    pub text: String,
+    pub position: [f64; 3],
+    pub scale: f32,
+    pub color: [u8; 4],
+    pub duration: f32,
+    /// If `true`, the mark stays until `ClearDebug` instead of expiring
+    /// after `duration`.
+    pub is_persistent: bool,
+}
+
+/// Debug arrow for visualization (velocity/heading, comm-link direction)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugArrow {
+    pub start: [f64; 3],
+    pub end: [f64; 3],
+    pub color: [u8; 4],
+    pub thickness: f32,
+    pub arrow_size: f32,
+    pub duration: f32,
+    /// If `true`, the mark stays until `ClearDebug` instead of expiring
+    /// after `duration`.
+    pub is_persistent: bool,
+}
+
+/// Connected polyline through N points (e.g., a planned path), drawn as one
+/// primitive rather than faked with disjoint [`DebugLine`] segments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugLineStrip {
+    pub points: Vec<[f64; 3]>,
+    pub color: [u8; 4],
+    pub thickness: f32,
+    pub duration: f32,
+    /// If `true`, the mark stays until `ClearDebug` instead of expiring
+    /// after `duration`.
+    pub is_persistent: bool,
 }
 
 /// Messages sent to Unreal Engine 5
@@ -155,6 +475,14 @@ pub enum UnrealMessage {
     /// Update robot telemetry data
     UpdateTelemetry { telemetry: Vec<RobotTelemetry> },
 
+    /// Apply munition/collision damage to a robot's `DamageState`, scaled
+    /// by warhead size and falloff distance from `impact_point`
+    ApplyDamage {
+        vehicle_id: String,
+        warhead_lbs: f64,
+        impact_point: [f64; 3],
+    },
+
     // === State Queries ===
     /// Get vehicle state
     GetState { vehicle_id: String },
@@ -184,6 +512,16 @@ pub enum UnrealMessage {
     /// Draw debug spheres (e.g., RF range indicators)
     DrawDebugSpheres { spheres: Vec<DebugSphere> },
 
+    /// Draw debug text labels (e.g., robot IDs, task names, J/S readouts)
+    DrawDebugStrings { strings: Vec<DebugString> },
+
+    /// Draw debug arrows (e.g., velocity/heading, comm-link direction)
+    DrawDebugArrows { arrows: Vec<DebugArrow> },
+
+    /// Draw connected polylines (e.g., planned paths) as a single primitive
+    /// rather than disjoint line segments
+    DrawDebugLineStrips { strips: Vec<DebugLineStrip> },
+
     /// Clear all debug visualization
     ClearDebug,
 
@@ -270,6 +608,50 @@ pub enum UnrealResponse {
         message: String,
         code: i32,
     },
+
+    /// The connection's negotiated
+    /// [`UnrealCapabilities`](crate::unreal::connection::UnrealCapabilities)
+    /// don't list `method` as supported by the connected plugin build, so
+    /// the call was never sent. Distinct from [`UnrealResponse::Error`]
+    /// because no request was made of the server at all.
+    Unsupported { method: String },
+
+    /// A reply whose JSON metadata was followed by a length-prefixed raw
+    /// payload on the same socket (see
+    /// [`crate::unreal::UnrealConnection::capture_image`]'s binary
+    /// encoding), rather than embedding the bytes as base64 in `data`.
+    Binary {
+        request_id: String,
+        data: serde_json::Value,
+        payload: Vec<u8>,
+    },
+}
+
+impl UnrealResponse {
+    /// Turns a reply into a `Result`, so an application-level failure UE5
+    /// reported (level failed to load, pawn class not found, spawn
+    /// rejected) surfaces as an `Err` the same way a transport failure
+    /// already does -- before this, only `send_message`'s own `io::Error`
+    /// was checked, so a well-formed `UnrealResponse::Error` reply was
+    /// silently treated as success by every caller.
+    pub fn into_result(self) -> Result<serde_json::Value, SimError> {
+        match self {
+            Self::Success { data, .. } => Ok(data),
+            Self::Binary { data, .. } => Ok(data),
+            Self::Error {
+                message,
+                code,
+                request_id,
+            } => Err(SimError::BackendError(format!(
+                "UE5 request {} failed ({}): {}",
+                request_id, code, message
+            ))),
+            Self::Unsupported { method } => Err(SimError::BackendError(format!(
+                "{} is not supported by the connected UE5 plugin build",
+                method
+            ))),
+        }
+    }
 }
 
 /// OSD telemetry data for FPV view (sent to UE5 for rendering)
@@ -310,16 +692,122 @@ impl From<FpvOsd> for FpvOsdData {
     }
 }
 
+/// A server-pushed event decoded from a JSON-RPC notification (a frame with
+/// no `id`), consumed via
+/// [`crate::unreal::UnrealConnection::subscribe_events`]. Distinct from the
+/// raw notification channel
+/// ([`crate::unreal::UnrealConnection::recv_notification`]): a notification
+/// that doesn't match one of these shapes (e.g. a `subscribe_camera` frame)
+/// simply isn't recognized as a `SimEvent` and must still be read through
+/// the raw channel or [`crate::unreal::UnrealConnection::subscribe_camera`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SimEvent {
+    /// A robot collided with another actor.
+    Collision {
+        vehicle_id: String,
+        impact_point: [f64; 3],
+        other_actor: Option<String>,
+    },
+
+    /// A drone was armed or disarmed (see `UnrealMessage::ArmDrone`).
+    ArmedChanged { vehicle_id: String, armed: bool },
+
+    /// An FPV drone's flight mode changed (see
+    /// `UnrealMessage::SetFpvControl`'s `flight_mode`).
+    FlightModeChanged {
+        vehicle_id: String,
+        flight_mode: String,
+    },
+
+    /// A robot reached a waypoint along its planned path.
+    WaypointReached {
+        vehicle_id: String,
+        waypoint_index: u32,
+    },
+
+    /// A per-robot telemetry tick, pushed at the rate negotiated by
+    /// `set_event_filter` instead of requiring a `GetAllStates` poll.
+    Telemetry { telemetry: RobotTelemetry },
+}
+
+/// A server-initiated request -- UE5 calling back into the Rust side rather
+/// than replying to one of ours (e.g. asking the tactical layer to resolve a
+/// contested waypoint, or reporting a render-thread stall it wants
+/// acknowledged) -- decoded from any inbound frame that carries both an `id`
+/// and a `method`. Consumed via
+/// [`crate::unreal::UnrealConnection::recv_reverse_request`] and answered
+/// with [`crate::unreal::UnrealConnection::respond_to_reverse_request`] (or
+/// `_error`), which echoes `id` back so UE5 can match the reply to this
+/// request the same way our own `send_rpc` matches replies to its.
+#[derive(Debug, Clone)]
+pub struct ReverseRequest {
+    pub id: i64,
+    pub method: String,
+    pub params: serde_json::Value,
+}
+
+/// Lifecycle state of a submitted long-running UE5 action, reported via
+/// [`ActionStatus`]: `Queued` when the action-tracked method hands the
+/// action id back to its caller, `Running` once the matching RPC is on
+/// the wire, then `Completed` or `Failed` once UE5's reply (or the lack
+/// of one) is known.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// The lifecycle of a command submitted through one of
+/// [`crate::unreal::UnrealEngine5Backend`]'s action-tracked methods (e.g.
+/// `spawn_vehicle_tracked`), keyed by the `action_id` that method
+/// returned. Unlike [`SimEvent`], this is never decoded off the wire --
+/// it's pushed locally by the Rust side as the matching RPC progresses --
+/// and is always delivered through
+/// [`crate::unreal::UnrealConnection::recv_action_status`] rather than
+/// the shared [`crate::unreal::UnrealConnection::subscribe_events`]
+/// broadcast, since a lagged broadcast subscriber could silently drop the
+/// one `Completed`/`Failed` transition a caller is waiting to observe.
+#[derive(Debug, Clone)]
+pub struct ActionStatus {
+    pub action_id: i32,
+    pub state: ActionState,
+    /// Coarse progress in `[0.0, 1.0]`; today just `0.0` at `Queued`,
+    /// `0.5` at `Running`, `1.0` at `Completed`/`Failed`, since the
+    /// underlying RPCs don't report finer-grained progress themselves.
+    pub progress: f32,
+}
+
+/// Notification classes the server can be told to emit (or suppress) via
+/// [`crate::unreal::UnrealConnection::set_event_filter`]; each corresponds
+/// to one [`SimEvent`] variant.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventClass {
+    Collision,
+    ArmedChanged,
+    FlightModeChanged,
+    WaypointReached,
+    Telemetry,
+}
+
 /// RPC method names for JSON-RPC protocol
 pub mod methods {
     pub const SPAWN_ROBOTS: &str = "spawn_robots";
     pub const UPDATE_POSITIONS: &str = "update_positions";
     pub const UPDATE_TELEMETRY: &str = "update_telemetry";
+    pub const APPLY_DAMAGE: &str = "apply_damage";
     pub const SIMULATION_STEP: &str = "simulation_step";
     pub const LOAD_LEVEL: &str = "load_level";
     pub const CLEAR_ALL_ROBOTS: &str = "clear_all_robots";
     pub const DRAW_DEBUG_LINES: &str = "draw_debug_lines";
     pub const DRAW_DEBUG_SPHERES: &str = "draw_debug_spheres";
+    pub const DRAW_DEBUG_STRINGS: &str = "draw_debug_strings";
+    pub const DRAW_DEBUG_ARROWS: &str = "draw_debug_arrows";
+    pub const DRAW_DEBUG_LINE_STRIPS: &str = "draw_debug_line_strips";
     pub const CLEAR_DEBUG: &str = "clear_debug";
     pub const SET_VIS_MODE: &str = "set_visualization_mode";
     pub const GET_ALL_STATES: &str = "get_all_states";
@@ -327,6 +815,27 @@ pub mod methods {
     pub const RESUME: &str = "resume";
     pub const RESET: &str = "reset";
 
+    /// Capability handshake issued once at connect time (see
+    /// [`crate::unreal::UnrealConnection::capabilities`]).
+    pub const GET_CAPABILITIES: &str = "get_capabilities";
+
+    // Methods with no UE5 handler yet (see `message_to_rpc`'s stub arms).
+    // These are real method names, not implemented today, so the
+    // capability handshake correctly reports them unsupported instead of
+    // routing them to a "ping" no-op.
+    pub const REMOVE_VEHICLE: &str = "remove_vehicle";
+    pub const SET_CONTROL: &str = "set_control";
+    pub const GET_STATE: &str = "get_state";
+    pub const CAST_RAY: &str = "cast_ray";
+    pub const CAPTURE_IMAGE: &str = "capture_image";
+    /// Starts a continuous camera feed; matching frames arrive as
+    /// notifications (see [`crate::unreal::UnrealConnection::subscribe_camera`]).
+    pub const SUBSCRIBE_CAMERA: &str = "subscribe_camera";
+    /// Tells the plugin which [`super::EventClass`]es to push as
+    /// notifications, and at what rate (see
+    /// [`crate::unreal::UnrealConnection::set_event_filter`]).
+    pub const SET_EVENT_FILTER: &str = "set_event_filter";
+
     // FPV methods
     pub const SET_FPV_CAMERA: &str = "set_fpv_camera";
     pub const SET_FPV_CONTROL: &str = "set_fpv_control";
@@ -0,0 +1,387 @@
+//! Bounding-volume hierarchy for exact triangle-mesh ray tracing
+//!
+//! [`WarpFFI::cast_ray`]/[`WarpFFI::cast_rays`] normally sphere-trace the
+//! baked SDF grid, which blurs thin geometry and only resolves detail down
+//! to one voxel at `sdf_resolution`. When [`super::WarpConfig::ray_mode`] is
+//! [`super::RayMode::Bvh`], they traverse a [`Bvh`] built directly over the
+//! scene's triangles instead, giving exact hits and surface normals
+//! regardless of voxel resolution.
+
+use autonomysim_core::backend::Material;
+use nalgebra::{Point3, Vector3};
+
+/// One triangle of the scene mesh, in world space.
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle {
+    pub v0: Point3<f64>,
+    pub v1: Point3<f64>,
+    pub v2: Point3<f64>,
+}
+
+impl Triangle {
+    fn centroid(&self) -> Point3<f64> {
+        Point3::from((self.v0.coords + self.v1.coords + self.v2.coords) / 3.0)
+    }
+
+    fn aabb(&self) -> Aabb {
+        Aabb::point(self.v0).union_point(self.v1).union_point(self.v2)
+    }
+
+    /// Möller–Trumbore ray/triangle intersection. Returns `(t, normal)` for
+    /// the hit closer than `max_distance`, if any.
+    fn intersect(
+        &self,
+        origin: &Point3<f64>,
+        direction: &Vector3<f64>,
+        max_distance: f64,
+    ) -> Option<(f64, Vector3<f64>)> {
+        const EPSILON: f64 = 1e-9;
+
+        let edge1 = self.v1 - self.v0;
+        let edge2 = self.v2 - self.v0;
+        let pvec = direction.cross(&edge2);
+        let det = edge1.dot(&pvec);
+        if det.abs() < EPSILON {
+            return None; // Ray parallel to the triangle's plane.
+        }
+
+        let inv_det = 1.0 / det;
+        let tvec = origin - self.v0;
+        let u = tvec.dot(&pvec) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let qvec = tvec.cross(&edge1);
+        let v = direction.dot(&qvec) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = edge2.dot(&qvec) * inv_det;
+        if t <= EPSILON || t >= max_distance {
+            return None;
+        }
+
+        let mut normal = edge1.cross(&edge2).normalize();
+        if normal.dot(direction) > 0.0 {
+            normal = -normal; // Face the normal back toward the ray origin.
+        }
+
+        Some((t, normal))
+    }
+}
+
+/// Axis-aligned bounding box, used both for per-triangle bounds and for
+/// internal BVH node bounds.
+#[derive(Debug, Clone, Copy)]
+struct Aabb {
+    min: Point3<f64>,
+    max: Point3<f64>,
+}
+
+impl Aabb {
+    fn empty() -> Self {
+        Self {
+            min: Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            max: Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        }
+    }
+
+    fn point(p: Point3<f64>) -> Self {
+        Self { min: p, max: p }
+    }
+
+    fn union_point(mut self, p: Point3<f64>) -> Self {
+        self.min = self.min.inf(&p);
+        self.max = self.max.sup(&p);
+        self
+    }
+
+    fn union(mut self, other: &Aabb) -> Self {
+        self.min = self.min.inf(&other.min);
+        self.max = self.max.sup(&other.max);
+        self
+    }
+
+    fn surface_area(&self) -> f64 {
+        let extent = self.max - self.min;
+        if extent.x < 0.0 || extent.y < 0.0 || extent.z < 0.0 {
+            return 0.0;
+        }
+        2.0 * (extent.x * extent.y + extent.y * extent.z + extent.z * extent.x)
+    }
+
+    /// Slab-method ray/AABB test. Returns the near `t` if the ray enters the
+    /// box before `max_distance`.
+    fn intersect(&self, origin: &Point3<f64>, inv_direction: &Vector3<f64>, max_distance: f64) -> bool {
+        let mut t_min = 0.0f64;
+        let mut t_max = max_distance;
+        for axis in 0..3 {
+            let t1 = (self.min[axis] - origin[axis]) * inv_direction[axis];
+            let t2 = (self.max[axis] - origin[axis]) * inv_direction[axis];
+            let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+            t_min = t_min.max(near);
+            t_max = t_max.min(far);
+            if t_min > t_max {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One node of the flattened BVH array. Leaves hold `count` triangles
+/// starting at `indices[first]`; internal nodes have `count == 0` and
+/// `first` is the left child's index (the right child always immediately
+/// follows it).
+struct BvhNode {
+    aabb: Aabb,
+    first: usize,
+    count: usize,
+}
+
+/// Bounding-volume hierarchy over a scene's triangles, built with a
+/// surface-area-heuristic binned split so ray traversal only descends into
+/// the subset of geometry a ray could plausibly hit.
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    triangles: Vec<Triangle>,
+    indices: Vec<usize>,
+}
+
+const LEAF_TRIANGLE_LIMIT: usize = 4;
+const SAH_BINS: usize = 12;
+
+impl Bvh {
+    /// Build a BVH over `triangles`. An empty input produces a BVH that
+    /// never reports a hit.
+    pub fn build(triangles: Vec<Triangle>) -> Self {
+        let mut indices: Vec<usize> = (0..triangles.len()).collect();
+        let mut nodes = Vec::new();
+
+        if !triangles.is_empty() {
+            let root_aabb = triangles.iter().fold(Aabb::empty(), |acc, t| acc.union(&t.aabb()));
+            nodes.push(BvhNode {
+                aabb: root_aabb,
+                first: 0,
+                count: triangles.len(),
+            });
+            Self::split(&mut nodes, 0, &triangles, &mut indices);
+        }
+
+        Self {
+            nodes,
+            triangles,
+            indices,
+        }
+    }
+
+    /// Recursively split `nodes[node_idx]` in place, appending any children
+    /// it produces. Leaves at or below [`LEAF_TRIANGLE_LIMIT`] triangles are
+    /// left alone.
+    fn split(nodes: &mut Vec<BvhNode>, node_idx: usize, triangles: &[Triangle], indices: &mut [usize]) {
+        let (first, count) = {
+            let node = &nodes[node_idx];
+            (node.first, node.count)
+        };
+        if count <= LEAF_TRIANGLE_LIMIT {
+            return;
+        }
+
+        let slice = &mut indices[first..first + count];
+
+        let centroid_bounds = slice
+            .iter()
+            .fold(Aabb::empty(), |acc, &i| acc.union_point(triangles[i].centroid()));
+        let extent = centroid_bounds.max - centroid_bounds.min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        if extent[axis] <= 0.0 {
+            return; // All centroids coincide on this axis; splitting further can't help.
+        }
+
+        // Evaluate SAH cost at `SAH_BINS` candidate split planes along
+        // `axis` and keep the cheapest one found.
+        let bin_of = |centroid: f64| -> usize {
+            let t = (centroid - centroid_bounds.min[axis]) / extent[axis];
+            ((t * SAH_BINS as f64) as usize).min(SAH_BINS - 1)
+        };
+
+        let mut bin_aabb = vec![Aabb::empty(); SAH_BINS];
+        let mut bin_count = vec![0usize; SAH_BINS];
+        for &i in slice.iter() {
+            let b = bin_of(triangles[i].centroid()[axis]);
+            bin_aabb[b] = bin_aabb[b].union(&triangles[i].aabb());
+            bin_count[b] += 1;
+        }
+
+        let mut best_cost = f64::INFINITY;
+        let mut best_split = 0usize; // Number of bins (from the left) assigned to the left child.
+
+        for split in 1..SAH_BINS {
+            let left_aabb = bin_aabb[..split].iter().fold(Aabb::empty(), |acc, b| acc.union(b));
+            let right_aabb = bin_aabb[split..].iter().fold(Aabb::empty(), |acc, b| acc.union(b));
+            let left_count: usize = bin_count[..split].iter().sum();
+            let right_count: usize = bin_count[split..].iter().sum();
+            if left_count == 0 || right_count == 0 {
+                continue;
+            }
+            let cost = left_aabb.surface_area() * left_count as f64
+                + right_aabb.surface_area() * right_count as f64;
+            if cost < best_cost {
+                best_cost = cost;
+                best_split = split;
+            }
+        }
+
+        if best_split == 0 {
+            return; // No bin boundary separated the primitives usefully.
+        }
+
+        let bin_boundary = centroid_bounds.min[axis] + extent[axis] * best_split as f64 / SAH_BINS as f64;
+        slice.sort_by(|&a, &b| {
+            let ca = triangles[a].centroid()[axis];
+            let cb = triangles[b].centroid()[axis];
+            ca.partial_cmp(&cb).unwrap()
+        });
+        let mid = slice
+            .iter()
+            .position(|&i| triangles[i].centroid()[axis] >= bin_boundary)
+            .unwrap_or(count / 2)
+            .clamp(1, count - 1);
+
+        let left_aabb = slice[..mid]
+            .iter()
+            .fold(Aabb::empty(), |acc, &i| acc.union(&triangles[i].aabb()));
+        let right_aabb = slice[mid..]
+            .iter()
+            .fold(Aabb::empty(), |acc, &i| acc.union(&triangles[i].aabb()));
+
+        let left_idx = nodes.len();
+        nodes.push(BvhNode {
+            aabb: left_aabb,
+            first,
+            count: mid,
+        });
+        nodes.push(BvhNode {
+            aabb: right_aabb,
+            first: first + mid,
+            count: count - mid,
+        });
+
+        nodes[node_idx].count = 0;
+        nodes[node_idx].first = left_idx;
+
+        Self::split(nodes, left_idx, triangles, indices);
+        Self::split(nodes, left_idx + 1, triangles, indices);
+    }
+
+    /// Traverse the hierarchy, descending into the near child first via a
+    /// short explicit stack, and return the closest triangle hit (if any)
+    /// within `max_distance`.
+    pub fn intersect(
+        &self,
+        origin: &Point3<f64>,
+        direction: &Vector3<f64>,
+        max_distance: f64,
+    ) -> Option<(f64, Vector3<f64>, Material)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        let inv_direction = Vector3::new(1.0 / direction.x, 1.0 / direction.y, 1.0 / direction.z);
+        let mut stack = vec![0usize];
+        let mut closest: Option<(f64, Vector3<f64>)> = None;
+
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            let limit = closest.map(|(t, _)| t).unwrap_or(max_distance);
+            if !node.aabb.intersect(origin, &inv_direction, limit) {
+                continue;
+            }
+
+            if node.count > 0 {
+                for &tri_idx in &self.indices[node.first..node.first + node.count] {
+                    let limit = closest.map(|(t, _)| t).unwrap_or(max_distance);
+                    if let Some(hit) = self.triangles[tri_idx].intersect(origin, direction, limit) {
+                        closest = Some(hit);
+                    }
+                }
+            } else {
+                stack.push(node.first);
+                stack.push(node.first + 1);
+            }
+        }
+
+        closest.map(|(t, normal)| (t, normal, Material::air()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quad(z: f64) -> Vec<Triangle> {
+        // Two triangles forming a 2x2 square centered at the origin, in the z = `z` plane.
+        vec![
+            Triangle {
+                v0: Point3::new(-1.0, -1.0, z),
+                v1: Point3::new(1.0, -1.0, z),
+                v2: Point3::new(1.0, 1.0, z),
+            },
+            Triangle {
+                v0: Point3::new(-1.0, -1.0, z),
+                v1: Point3::new(1.0, 1.0, z),
+                v2: Point3::new(-1.0, 1.0, z),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_bvh_finds_closest_of_several_triangle_layers() {
+        let mut triangles = quad(5.0);
+        triangles.extend(quad(2.0));
+        triangles.extend(quad(8.0));
+        let bvh = Bvh::build(triangles);
+
+        let hit = bvh
+            .intersect(&Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 0.0, 1.0), 100.0)
+            .expect("ray should hit the nearest quad");
+
+        assert!((hit.0 - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bvh_respects_max_distance() {
+        let bvh = Bvh::build(quad(5.0));
+
+        let hit = bvh.intersect(&Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 0.0, 1.0), 3.0);
+
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn test_bvh_misses_triangle_outside_ray_footprint() {
+        let bvh = Bvh::build(quad(5.0));
+
+        let hit = bvh.intersect(&Point3::new(10.0, 10.0, 0.0), &Vector3::new(0.0, 0.0, 1.0), 100.0);
+
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn test_empty_bvh_never_hits() {
+        let bvh = Bvh::build(Vec::new());
+
+        let hit = bvh.intersect(&Point3::new(0.0, 0.0, 0.0), &Vector3::new(0.0, 0.0, 1.0), 100.0);
+
+        assert!(hit.is_none());
+    }
+}
@@ -3,16 +3,166 @@
 //! This module provides a Rust wrapper around Warp's Python API using PyO3.
 //! Warp is NVIDIA's GPU-accelerated framework for physics simulation.
 
-use nalgebra::{Point3, UnitQuaternion, Vector3};
+use std::collections::{HashMap, VecDeque};
+
+use nalgebra::{Point3, Quaternion, UnitQuaternion, Vector3};
 
 use autonomysim_core::{
     backend::{Ray, RayHit, Transform},
-    sensor::{ImuData, SensorData},
-    vehicle::{VehicleControl, VehicleSpec, VehicleState},
+    sensor::{ImuData, LidarData, LidarPoint, SensorData},
+    vehicle::{LidarConfig, SensorSpec, SensorType, VehicleControl, VehicleSpec, VehicleState},
     SimError, SimResult,
 };
 
-use super::WarpConfig;
+use super::bvh::{Bvh, Triangle};
+use super::interaction;
+use super::precision::{StatePrecision, TensorScale};
+use super::{IndexMode, RayMode, SdfPrecision, WarpConfig};
+
+/// Elements a real kernel's GPU arrays would carry per vehicle --
+/// position(3) + velocity(4) + orientation(4) + control(3) -- used only to
+/// estimate whether `max_vehicles` needs 64-bit indexing.
+const ELEMENTS_PER_VEHICLE: usize = 10;
+
+/// A [`VehicleState`] stamped with NaN (and an out-of-range timestamp
+/// canary) in every field a real kernel would read, so a slot that's been
+/// allocated but never stepped or written produces unmistakable garbage
+/// instead of plausible-looking zeros.
+fn poisoned_vehicle_state() -> VehicleState {
+    let nan = f64::NAN;
+    VehicleState {
+        vehicle_id: String::new(),
+        timestamp: nan,
+        transform: Transform::new(
+            Point3::new(nan, nan, nan),
+            UnitQuaternion::new_unchecked(Quaternion::new(nan, nan, nan, nan)),
+        ),
+        linear_velocity: Vector3::new(nan, nan, nan),
+        angular_velocity: Vector3::new(nan, nan, nan),
+        linear_acceleration: Vector3::new(nan, nan, nan),
+        angular_acceleration: Vector3::new(nan, nan, nan),
+        battery_level: nan,
+        is_grounded: false,
+        collision_info: None,
+    }
+}
+
+/// Whether `state` is the sentinel [`poisoned_vehicle_state`] -- i.e. a
+/// slot `fill_allocation_with_nan` poisoned at allocation and that no
+/// `step_async()`/`set_vehicle_control()` has overwritten since.
+fn is_poisoned(state: &VehicleState) -> bool {
+    state.transform.position.coords.iter().any(|v| v.is_nan())
+}
+
+/// Placeholder vehicle state stamped with `tick` so callers reading the
+/// double-buffered host mirror (see [`WarpFFI::step_async`]) can observe
+/// which tick's copy they landed on.
+fn placeholder_vehicle_state(tick: u64) -> VehicleState {
+    VehicleState {
+        vehicle_id: String::new(),
+        timestamp: tick as f64,
+        transform: Transform::new(Point3::new(0.0, 0.0, 1.0), UnitQuaternion::identity()),
+        linear_velocity: Vector3::new(0.0, 0.0, 0.0),
+        angular_velocity: Vector3::new(0.0, 0.0, 0.0),
+        linear_acceleration: Vector3::new(0.0, 0.0, 0.0),
+        angular_acceleration: Vector3::new(0.0, 0.0, 0.0),
+        battery_level: 1.0,
+        is_grounded: false,
+        collision_info: None,
+    }
+}
+
+/// Opaque, generation-checked identity for a vehicle allocated in
+/// [`WarpFFI`]'s GPU arrays.
+///
+/// `index` is a stable slot id that survives other vehicles being
+/// deallocated -- unlike a raw dense array position, which
+/// [`WarpFFI::deallocate_vehicle`]'s swap-and-pop scheme can reassign out
+/// from under a vehicle it didn't even touch. `generation` lets
+/// [`WarpFFI`] detect a handle to an already-freed slot and fail cleanly
+/// instead of silently resolving to whatever vehicle was allocated into
+/// that slot afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VehicleHandle {
+    index: usize,
+    generation: u32,
+}
+
+/// A slot's bookkeeping in [`WarpFFI`]'s generational handle table.
+struct VehicleSlot {
+    generation: u32,
+    live: bool,
+    device_idx: usize,
+}
+
+/// Handle to a [`WarpFFI::step_async`] launch, analogous to a virtio-gpu
+/// fence descriptor: it identifies one enqueued frame so a caller can
+/// later [`WarpFFI::await_fence`] it without blocking on anything enqueued
+/// after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StepFence(u64);
+
+impl StepFence {
+    /// This fence's monotonically increasing id.
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+/// One [`WarpFFI::step_async`] launch that hasn't yet been retired.
+struct InFlightFrame {
+    fence: StepFence,
+    /// Which `host_state` buffer this frame's results were written into.
+    buffer: usize,
+}
+
+/// One CUDA device's retained primary context and its own dense GPU
+/// array of currently-live vehicles.
+///
+/// In full implementation, `new` would retain the device's primary context
+/// (`cuda.Device(device_id).retain_primary_context()`) and `push` it before
+/// allocating this device's `wp.array` buffers, leaving the context current
+/// on the calling thread until the caller is done with it. `shutdown` pops
+/// it back off -- never `detach`, so other consumers of the device sharing
+/// its primary context aren't disrupted.
+struct WarpDevice {
+    device_id: i32,
+
+    /// Slot id occupying each dense GPU array position on this device --
+    /// `dense[i]` is the vehicle the kernel would index as `tid == i`.
+    /// Kept packed by [`WarpFFI::deallocate_vehicle`]'s swap-and-pop.
+    dense: Vec<usize>,
+}
+
+impl WarpDevice {
+    fn new(device_id: i32, max_vehicles_per_device: usize) -> Self {
+        // In full implementation:
+        // 1. ctx = cuda.Device(device_id).retain_primary_context(); ctx.push()
+        // 2. Allocate this device's wp.array buffers while ctx is current:
+        //    - positions/velocities/orientations/controls, shape
+        //      (max_vehicles_per_device, ...)
+        // 3. ctx.pop() -- leave the device clean for the next push
+
+        println!(
+            "Warp FFI: Retaining primary context on device {} (capacity {})",
+            device_id, max_vehicles_per_device
+        );
+
+        Self {
+            device_id,
+            dense: Vec::new(),
+        }
+    }
+
+    fn shutdown(&self) {
+        // In full implementation: ctx.push(); free this device's wp.array
+        // buffers; ctx.pop() (never ctx.detach())
+        println!(
+            "Warp FFI: Releasing primary context on device {}",
+            self.device_id
+        );
+    }
+}
 
 /// FFI interface to Warp Python
 pub struct WarpFFI {
@@ -22,13 +172,66 @@ pub struct WarpFFI {
     // In full implementation would hold:
     // - PyO3 Python interpreter
     // - Warp module handle
-    // - GPU device context
-    // - wp.array buffers for vehicles
+    // - wp.array buffers for vehicles, one set per device
     // - SDF mesh representation
+    /// One entry per `config.device_ids`, in the same order.
+    devices: Vec<WarpDevice>,
+
+    /// Generational handle table; indexed by [`VehicleHandle::index`].
+    slots: Vec<VehicleSlot>,
+
+    /// Freed slot ids available for [`Self::allocate_vehicle`] to recycle,
+    /// most-recently-freed last.
+    free_slots: Vec<usize>,
+
+    /// Round-robin counter used to shard newly allocated vehicles across
+    /// `devices`, independent of slot recycling.
+    next_device_rr: usize,
 
     // Simulation state (on GPU)
     num_allocated_vehicles: usize,
     current_scene_id: i32,
+
+    /// Bounding-volume hierarchy built over the current scene's triangles
+    /// at [`Self::load_scene`] time, used by [`Self::cast_ray`]/
+    /// [`Self::cast_rays`] when `config.ray_mode` is [`RayMode::Bvh`].
+    /// `None` until a scene has been loaded.
+    bvh: Option<Bvh>,
+
+    /// Pinned host mirror of vehicle state, double buffered and keyed by
+    /// [`VehicleHandle::index`]: `host_state[front_buffer]` is the last
+    /// frame [`Self::poll_fences`]/[`Self::await_fence`] retired and is
+    /// what [`Self::get_vehicle_state`] reads; the other slot is what an
+    /// in-flight [`Self::step_async`] frame is writing.
+    host_state: [HashMap<usize, VehicleState>; 2],
+    front_buffer: usize,
+
+    /// Frames enqueued by [`Self::step_async`] that haven't yet been
+    /// retired by [`Self::poll_fences`]/[`Self::await_fence`], oldest
+    /// first. At most one entry per double buffer slot can be in flight at
+    /// once, so this never grows past 2.
+    in_flight: VecDeque<InFlightFrame>,
+
+    /// Monotonically increasing id handed out by [`Self::step_async`];
+    /// the next call receives `next_fence_id`, which is then incremented.
+    next_fence_id: u64,
+
+    /// Highest fence id [`Self::poll_fences`]/[`Self::await_fence`] has
+    /// retired so far. A fence id `<= last_completed_fence` is safe to
+    /// read state for.
+    last_completed_fence: u64,
+
+    tick: u64,
+
+    /// Control inputs last written by [`Self::set_vehicle_control`] /
+    /// [`Self::set_all_vehicle_controls`], keyed by [`VehicleHandle::index`].
+    controls: HashMap<usize, VehicleControl>,
+
+    /// Integer type GPU array indexing actually uses -- `config.index_mode`
+    /// escalated to [`IndexMode::I64`] if `new()` found `max_vehicles`
+    /// would overflow `i32`'s addressable range regardless of what was
+    /// requested.
+    index_mode: IndexMode,
 }
 
 impl WarpFFI {
@@ -38,37 +241,117 @@ impl WarpFFI {
         // 1. Initialize Python interpreter (pyo3::Python::with_gil)
         // 2. Import warp module (py.import("warp")?)
         // 3. Initialize Warp: warp.init()
-        // 4. Set CUDA device: warp.set_device(f"cuda:{device_id}")
-        // 5. Allocate GPU arrays:
-        //    - positions: wp.array(shape=(max_vehicles, 3), dtype=wp.vec3)
-        //    - velocities: wp.array(shape=(max_vehicles, 3), dtype=wp.vec3)
-        //    - orientations: wp.array(shape=(max_vehicles, 4), dtype=wp.quat)
-        //    - controls: wp.array(shape=(max_vehicles, 8), dtype=wp.float32)
+        // 4. For each device in config.device_ids, retain and push its
+        //    primary context before allocating that device's wp.array
+        //    buffers (see WarpDevice::new)
+
+        if config.device_ids.is_empty() {
+            return Err(SimError::BackendError(
+                "WarpConfig::device_ids must list at least one CUDA device".to_string(),
+            ));
+        }
 
         println!(
-            "Warp FFI: Initializing device={}, max_vehicles={}, sdf_res={}",
-            config.device_id, config.max_vehicles, config.sdf_resolution
+            "Warp FFI: Initializing devices={:?}, max_vehicles={}, sdf_res={}",
+            config.device_ids, config.max_vehicles, config.sdf_resolution
         );
 
+        let per_device_capacity = config.max_vehicles.div_ceil(config.device_ids.len());
+        let devices = config
+            .device_ids
+            .iter()
+            .map(|&device_id| WarpDevice::new(device_id, per_device_capacity))
+            .collect();
+
+        let required_indices = config.max_vehicles.saturating_mul(ELEMENTS_PER_VEHICLE);
+        let index_mode = if config.index_mode == IndexMode::I32
+            && required_indices > i32::MAX as usize
+        {
+            println!(
+                "Warp FFI: max_vehicles ({}) needs {} indices, beyond i32::MAX -- escalating index_mode to IndexMode::I64",
+                config.max_vehicles, required_indices
+            );
+            IndexMode::I64
+        } else {
+            config.index_mode
+        };
+
         Ok(Self {
             config,
             initialized: true,
+            devices,
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            next_device_rr: 0,
             num_allocated_vehicles: 0,
             current_scene_id: -1,
+            bvh: None,
+            host_state: [HashMap::new(), HashMap::new()],
+            front_buffer: 0,
+            in_flight: VecDeque::new(),
+            // Fence ids start at 1 so `last_completed_fence == 0` can mean
+            // "nothing has completed yet" without colliding with a real id.
+            next_fence_id: 1,
+            last_completed_fence: 0,
+            tick: 0,
+            controls: HashMap::new(),
+            index_mode,
         })
     }
 
+    /// The integer type GPU array indexing actually uses, after `new()`'s
+    /// automatic escalation -- see [`Self::index_mode`] field docs.
+    pub fn index_mode(&self) -> IndexMode {
+        self.index_mode
+    }
+
+    /// Handle slot ids of every currently-live vehicle, in dense GPU array
+    /// order (device by device) -- the canonical ordering
+    /// [`Self::get_all_vehicle_states`] and [`Self::set_all_vehicle_controls`]
+    /// use, matching the order a real bulk device-to-host copy would
+    /// produce.
+    fn live_vehicle_indices(&self) -> Vec<usize> {
+        self.devices
+            .iter()
+            .flat_map(|device| device.dense.iter().copied())
+            .collect()
+    }
+
+    /// Validate a [`VehicleHandle`] against the slot table, failing
+    /// cleanly if it's stale (the slot was freed, possibly reused by a
+    /// later [`Self::allocate_vehicle`]) rather than silently resolving to
+    /// whatever vehicle now occupies that slot.
+    fn resolve(&self, handle: VehicleHandle) -> SimResult<&VehicleSlot> {
+        match self.slots.get(handle.index) {
+            Some(slot) if slot.live && slot.generation == handle.generation => Ok(slot),
+            _ => Err(SimError::BackendError(format!(
+                "stale or invalid vehicle handle {:?}",
+                handle
+            ))),
+        }
+    }
+
     /// Shutdown Warp
     pub fn shutdown(&mut self) -> SimResult<()> {
         if !self.initialized {
             return Ok(());
         }
 
+        // step_async() launches may still be in flight on the step stream;
+        // drain them all before tearing anything down so no kernel is left
+        // writing into arrays we're about to free.
+        while let Some(last_fence) = self.in_flight.back().map(|frame| frame.fence) {
+            self.await_fence(last_fence)?;
+        }
+
         // In full implementation:
-        // 1. Free GPU arrays
+        // 1. Free GPU arrays on every device (see WarpDevice::shutdown)
         // 2. Release Python GIL
         // 3. warp.synchronize()
 
+        for device in &self.devices {
+            device.shutdown();
+        }
         println!("Warp FFI: Shutting down");
 
         self.initialized = false;
@@ -85,17 +368,50 @@ impl WarpFFI {
 
         // In full implementation:
         // 1. Load mesh file (OBJ, USD, etc.)
-        // 2. Generate SDF on GPU:
+        // 2. Generate SDF on GPU, baking voxels into the configured
+        //    precision (wp.float16 array halves memory over wp.float32,
+        //    letting resolution go higher for the same card):
         //    mesh = wp.Mesh(points, indices)
-        //    sdf = wp.SDF(mesh, resolution=self.config.sdf_resolution)
+        //    dtype = wp.float16 if self.config.sdf_precision == F16 else wp.float32
+        //    sdf = wp.SDF(mesh, resolution=self.config.sdf_resolution, dtype=dtype)
         // 3. Store SDF for collision queries
 
         self.current_scene_id += 1;
         println!(
-            "Warp: Loaded scene '{}' (ID {}), generating SDF...",
-            scene_path, self.current_scene_id
+            "Warp: Loaded scene '{}' (ID {}), generating SDF at {:?} ({} bytes)...",
+            scene_path,
+            self.current_scene_id,
+            self.config.sdf_precision,
+            self.sdf_memory_bytes()
         );
 
+        if self.config.ray_mode == RayMode::Bvh {
+            // In full implementation: parse the mesh's triangle soup from
+            // `scene_path` (OBJ, USD, etc.) instead of this placeholder
+            // single-quad stand-in, then upload the built BVH's flattened
+            // node array to the GPU for traversal.
+            let triangles = vec![
+                Triangle {
+                    v0: Point3::new(-50.0, -50.0, 0.0),
+                    v1: Point3::new(50.0, -50.0, 0.0),
+                    v2: Point3::new(50.0, 50.0, 0.0),
+                },
+                Triangle {
+                    v0: Point3::new(-50.0, -50.0, 0.0),
+                    v1: Point3::new(50.0, 50.0, 0.0),
+                    v2: Point3::new(-50.0, 50.0, 0.0),
+                },
+            ];
+            println!(
+                "Warp: Building BVH over {} triangles for scene '{}'",
+                triangles.len(),
+                scene_path
+            );
+            self.bvh = Some(Bvh::build(triangles));
+        } else {
+            self.bvh = None;
+        }
+
         Ok(self.current_scene_id)
     }
 
@@ -108,6 +424,26 @@ impl WarpFFI {
         ])
     }
 
+    /// Storage precision the baked SDF voxel grid uses, set from
+    /// `WarpConfig::sdf_precision` at construction -- lets downstream code
+    /// reason about the accuracy/memory tradeoff `get_sdf_dimensions`
+    /// alone doesn't convey.
+    pub fn sdf_precision(&self) -> SdfPrecision {
+        self.config.sdf_precision
+    }
+
+    /// Estimated voxel-grid memory footprint at the configured resolution
+    /// and precision: 4 bytes per voxel for [`SdfPrecision::F32`], 2 for
+    /// [`SdfPrecision::F16`].
+    pub fn sdf_memory_bytes(&self) -> u64 {
+        let voxels = (self.config.sdf_resolution as u64).pow(3);
+        let bytes_per_voxel = match self.config.sdf_precision {
+            SdfPrecision::F32 => 4,
+            SdfPrecision::F16 => 2,
+        };
+        voxels * bytes_per_voxel
+    }
+
     /// Get number of objects in scene
     pub fn get_num_objects(&self) -> SimResult<usize> {
         // In full implementation: return mesh.num_faces or similar
@@ -122,7 +458,17 @@ impl WarpFFI {
             ));
         }
 
-        // In full implementation:
+        // In full implementation, each device's primary context is pushed
+        // in turn and the kernel launched only over that device's own
+        // dense array (device.dense), so no device ever touches another's
+        // arrays:
+        //
+        // for device in &self.devices {
+        //     ctx = device.context; ctx.push();
+        //     wp.launch(kernel=step_vehicles, dim=len(device.dense), ...);
+        //     ctx.pop();
+        // }
+        //
         // Launch Warp kernel on GPU:
         //
         // @wp.kernel
@@ -160,8 +506,177 @@ impl WarpFFI {
         Ok(())
     }
 
-    /// Allocate vehicle in GPU arrays
-    pub fn allocate_vehicle(&mut self, spec: &VehicleSpec) -> SimResult<usize> {
+    /// Enqueue the physics kernel on a dedicated step stream and return a
+    /// [`StepFence`] immediately, without the `step()` path's implicit
+    /// `wp.synchronize()`. Call [`Self::await_fence`] (or poll with
+    /// [`Self::poll_fences`]) when the result is actually needed, so a
+    /// caller like an RL rollout loop can launch the next frame while
+    /// reading back the previous one's observations.
+    ///
+    /// Reading state with [`Self::get_vehicle_state`] between
+    /// `step_async()` and the matching `await_fence()` is well-defined but
+    /// stale: it returns the previous tick's value, since the host mirror
+    /// for this tick hasn't been copied back yet.
+    pub fn step_async(&mut self) -> SimResult<StepFence> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "Warp FFI not initialized".to_string(),
+            ));
+        }
+
+        // The back buffer already has an undrained launch writing into it
+        // -- a second concurrent launch would have nowhere to land without
+        // either stomping that in-flight frame or touching `front_buffer`,
+        // which [`Self::get_vehicle_state`] may be reading from right now.
+        // Only one frame can be in flight per double-buffer pair at a time;
+        // [`Self::await_fence`] it before enqueuing the next.
+        if !self.in_flight.is_empty() {
+            return Err(SimError::BackendError(
+                "Warp FFI: step_async() ring is full -- await_fence() the in-flight frame first"
+                    .to_string(),
+            ));
+        }
+
+        // In full implementation:
+        // stream = self.step_stream  # a dedicated wp.Stream, not the
+        //                            # default stream `step()` uses
+        // wp.launch(kernel=step_vehicles, dim=num_vehicles, ..., stream=stream)
+        // fence = stream.record_event()  # returned to the caller as StepFence
+        // -- no wp.synchronize() here; the launch is only enqueued.
+
+        self.tick += 1;
+        let buffer = 1 - self.front_buffer;
+        let mut states: Vec<(usize, VehicleState)> = self
+            .live_vehicle_indices()
+            .into_iter()
+            .map(|slot_id| (slot_id, placeholder_vehicle_state(self.tick)))
+            .collect();
+
+        // Opt-in pairwise interaction pass, evaluated before integration:
+        // reads the previous tick's host-mirrored positions/velocities as
+        // the instantaneous snapshot the tiled all-pairs kernel sees, and
+        // folds the resulting acceleration into this tick's velocity so
+        // flocking/repulsion/formation forces feed the same integrator
+        // every other vehicle update goes through.
+        if let Some(model) = &self.config.interaction {
+            let previous = &self.host_state[self.front_buffer];
+            let positions: Vec<Point3<f64>> = states
+                .iter()
+                .map(|(slot_id, _)| {
+                    previous
+                        .get(slot_id)
+                        .map(|s| s.transform.position)
+                        .unwrap_or_else(|| Point3::new(0.0, 0.0, 1.0))
+                })
+                .collect();
+            let velocities: Vec<Vector3<f64>> = states
+                .iter()
+                .map(|(slot_id, _)| {
+                    previous
+                        .get(slot_id)
+                        .map(|s| s.linear_velocity)
+                        .unwrap_or_else(Vector3::zeros)
+                })
+                .collect();
+
+            let accelerations =
+                interaction::accumulate_accelerations(model, &positions, &velocities);
+            for ((_, state), accel) in states.iter_mut().zip(accelerations) {
+                state.linear_velocity += accel * self.config.timestep;
+            }
+        }
+
+        // Emulate down-converting the resident state buffer to
+        // `config.precision`: choose a per-tensor scale from this batch's
+        // own dynamic range, then round-trip position/velocity through it.
+        // Integration itself stays in f64 throughout -- only what's kept
+        // resident between steps loses precision.
+        if self.config.precision != StatePrecision::Fp32 {
+            let position_scale = TensorScale::from_observed(
+                states
+                    .iter()
+                    .flat_map(|(_, s)| s.transform.position.coords.iter().copied()),
+            );
+            let velocity_scale = TensorScale::from_observed(
+                states
+                    .iter()
+                    .flat_map(|(_, s)| s.linear_velocity.iter().copied()),
+            );
+            for (_, state) in &mut states {
+                for axis in 0..3 {
+                    state.transform.position.coords[axis] = position_scale
+                        .quantize(state.transform.position.coords[axis], self.config.precision);
+                    state.linear_velocity[axis] =
+                        velocity_scale.quantize(state.linear_velocity[axis], self.config.precision);
+                }
+            }
+        }
+
+        for (slot_id, state) in states {
+            self.host_state[buffer].insert(slot_id, state);
+        }
+
+        let fence = StepFence(self.next_fence_id);
+        self.next_fence_id += 1;
+        self.in_flight.push_back(InFlightFrame { fence, buffer });
+
+        Ok(fence)
+    }
+
+    /// Lightweight poll loop, analogous to a `cudaEventQuery`-style check:
+    /// retire the oldest in-flight frame if the (simulated) launch it
+    /// represents has completed, advancing `last_completed_fence` and the
+    /// read-side `front_buffer`. Never blocks.
+    ///
+    /// This stub treats every in-flight frame as already complete by the
+    /// time it's polled, since there is no real device queue behind it;
+    /// a genuine GPU backend would instead query the underlying fence/event
+    /// object here.
+    pub fn poll_fences(&mut self) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "Warp FFI not initialized".to_string(),
+            ));
+        }
+
+        if let Some(frame) = self.in_flight.pop_front() {
+            self.front_buffer = frame.buffer;
+            self.last_completed_fence = frame.fence.id();
+        }
+
+        Ok(())
+    }
+
+    /// Block (in spirit -- this stub never actually waits) until `fence`
+    /// and every frame enqueued before it have retired, so
+    /// [`Self::get_vehicle_state`] is guaranteed to observe that frame's
+    /// results afterward.
+    pub fn await_fence(&mut self, fence: StepFence) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "Warp FFI not initialized".to_string(),
+            ));
+        }
+
+        while self.last_completed_fence < fence.id() && !self.in_flight.is_empty() {
+            self.poll_fences()?;
+        }
+
+        Ok(())
+    }
+
+    /// Synchronous convenience wrapper: enqueue a [`Self::step_async`]
+    /// frame and immediately [`Self::await_fence`] it, for callers that
+    /// don't need to pipeline GPU work with CPU-side readback.
+    pub fn synchronize_step(&mut self) -> SimResult<()> {
+        let fence = self.step_async()?;
+        self.await_fence(fence)
+    }
+
+    /// Allocate vehicle in GPU arrays, returning a [`VehicleHandle`] that
+    /// stays valid -- and keeps resolving to this same vehicle -- across
+    /// any number of *other* vehicles being deallocated.
+    pub fn allocate_vehicle(&mut self, spec: &VehicleSpec) -> SimResult<VehicleHandle> {
         if !self.initialized {
             return Err(SimError::BackendError(
                 "Warp FFI not initialized".to_string(),
@@ -175,49 +690,111 @@ impl WarpFFI {
             )));
         }
 
-        let index = self.num_allocated_vehicles;
+        // Shard the vehicle onto a device partition (round-robin by
+        // allocation order) so `step()` only ever launches its kernel over
+        // the slice of vehicles actually resident on that device.
+        let device_idx = self.next_device_rr % self.devices.len();
+        self.next_device_rr += 1;
 
-        // In full implementation:
-        // 1. Write initial state to GPU arrays at index
-        // 2. Set position: positions[index] = wp.vec3(x, y, z)
-        // 3. Set velocity: velocities[index] = wp.vec3(0, 0, 0)
-        // 4. Set orientation: orientations[index] = wp.quat(x, y, z, w)
+        let (slot_id, generation) = match self.free_slots.pop() {
+            Some(slot_id) => {
+                let slot = &mut self.slots[slot_id];
+                slot.live = true;
+                slot.device_idx = device_idx;
+                (slot_id, slot.generation)
+            }
+            None => {
+                self.slots.push(VehicleSlot {
+                    generation: 0,
+                    live: true,
+                    device_idx,
+                });
+                (self.slots.len() - 1, 0)
+            }
+        };
 
-        println!(
-            "Warp: Allocated vehicle '{}' ({:?}) at GPU index {}",
-            spec.vehicle_id, spec.vehicle_type, index
-        );
+        // In full implementation, with that device's primary context
+        // pushed:
+        // 1. Write initial state to GPU arrays at the device-local dense
+        //    index (positions/velocities/orientations[dense_index] = ...)
 
+        let dense_index = self.devices[device_idx].dense.len();
+        self.devices[device_idx].dense.push(slot_id);
         self.num_allocated_vehicles += 1;
 
-        Ok(index)
+        if self.config.fill_allocation_with_nan {
+            self.host_state[0].insert(slot_id, poisoned_vehicle_state());
+            self.host_state[1].insert(slot_id, poisoned_vehicle_state());
+        }
+
+        println!(
+            "Warp: Allocated vehicle '{}' ({:?}) as handle {{index: {}, generation: {}}} at dense position {} on device {}",
+            spec.vehicle_id,
+            spec.vehicle_type,
+            slot_id,
+            generation,
+            dense_index,
+            self.devices[device_idx].device_id
+        );
+
+        Ok(VehicleHandle {
+            index: slot_id,
+            generation,
+        })
     }
 
-    /// Deallocate vehicle from GPU arrays
-    pub fn deallocate_vehicle(&mut self, array_index: usize) -> SimResult<()> {
+    /// Deallocate vehicle from GPU arrays using swap-and-pop: the freed
+    /// slot's dense position is filled by copying in the last element of
+    /// its device's dense array, which stays packed for kernel launches.
+    /// `handle` is bumped to the next generation so any copy a caller
+    /// still holds fails cleanly on its next use instead of silently
+    /// resolving to whatever vehicle is allocated into the slot next.
+    pub fn deallocate_vehicle(&mut self, handle: VehicleHandle) -> SimResult<()> {
         if !self.initialized {
             return Err(SimError::BackendError(
                 "Warp FFI not initialized".to_string(),
             ));
         }
 
-        // In full implementation:
-        // 1. Mark slot as free (could use swap-and-pop)
-        // 2. Clear GPU array entries
-
-        println!("Warp: Deallocated vehicle at GPU index {}", array_index);
+        let device_idx = self.resolve(handle)?.device_idx;
 
-        if array_index < self.num_allocated_vehicles {
-            self.num_allocated_vehicles -= 1;
+        let dense = &mut self.devices[device_idx].dense;
+        let dense_index = dense
+            .iter()
+            .position(|&id| id == handle.index)
+            .expect("a live slot must appear in its own device's dense array");
+        let last_index = dense.len() - 1;
+        if dense_index != last_index {
+            // In full implementation: copy the GPU array entries at
+            // last_index into dense_index on this device.
+            dense[dense_index] = dense[last_index];
         }
+        dense.truncate(last_index);
+
+        let slot = &mut self.slots[handle.index];
+        slot.live = false;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(handle.index);
+        self.num_allocated_vehicles -= 1;
+
+        self.host_state[0].remove(&handle.index);
+        self.host_state[1].remove(&handle.index);
+        self.controls.remove(&handle.index);
+
+        println!(
+            "Warp: Deallocated vehicle handle {{index: {}, generation: {}}}",
+            handle.index, handle.generation
+        );
 
         Ok(())
     }
 
-    /// Get vehicle state from GPU arrays
+    /// Get vehicle state from GPU arrays, resolving `handle` through the
+    /// slot table first so a stale handle (its vehicle since deallocated)
+    /// fails cleanly instead of returning whatever now occupies that slot.
     pub fn get_vehicle_state(
         &self,
-        array_index: usize,
+        handle: VehicleHandle,
         vehicle_id: &str,
     ) -> SimResult<VehicleState> {
         if !self.initialized {
@@ -225,35 +802,73 @@ impl WarpFFI {
                 "Warp FFI not initialized".to_string(),
             ));
         }
+        self.resolve(handle)?;
 
-        // In full implementation:
-        // 1. Read from GPU arrays:
-        //    pos = positions[array_index].numpy()  # Transfer to CPU
-        //    vel = velocities[array_index].numpy()
-        //    quat = orientations[array_index].numpy()
+        // In full implementation, when no step_async()/synchronize() host
+        // mirror entry exists yet:
+        // 1. Read from GPU arrays at this handle's current dense index
+        //    (resolved via `devices[slot.device_idx].dense`):
+        //    pos = positions[dense_index].numpy()  # Transfer to CPU
+        //    vel = velocities[dense_index].numpy()
+        //    quat = orientations[dense_index].numpy()
         // 2. Convert to VehicleState
 
-        // Placeholder state
-        let state = VehicleState {
-            vehicle_id: vehicle_id.to_string(),
-            timestamp: 0.0,
-            transform: Transform::new(Point3::new(0.0, 0.0, 1.0), UnitQuaternion::identity()),
-            linear_velocity: Vector3::new(0.0, 0.0, 0.0),
-            angular_velocity: Vector3::new(0.0, 0.0, 0.0),
-            linear_acceleration: Vector3::new(0.0, 0.0, 0.0),
-            angular_acceleration: Vector3::new(0.0, 0.0, 0.0),
-            battery_level: 1.0,
-            is_grounded: false,
-            collision_info: None,
-        };
+        // Read from the cached bulk snapshot -- the same host mirror
+        // get_all_vehicle_states() reads in one pass -- so this never
+        // triggers its own device round-trip.
+        let mut state = self.host_state[self.front_buffer]
+            .get(&handle.index)
+            .cloned()
+            .unwrap_or_else(|| placeholder_vehicle_state(self.tick));
+
+        if is_poisoned(&state) {
+            return Err(SimError::BackendError(format!(
+                "vehicle '{}' read back a NaN-poisoned slot -- it was allocated but never stepped",
+                vehicle_id
+            )));
+        }
+
+        state.vehicle_id = vehicle_id.to_string();
 
         Ok(state)
     }
 
-    /// Set vehicle control inputs
+    /// Every live vehicle's state, read with a single contiguous
+    /// device-to-host copy instead of one `.numpy()` transfer (and GIL
+    /// acquisition) per vehicle. Returned in dense GPU array order, keyed
+    /// by handle slot id; `vehicle_id` is left blank since the FFI layer
+    /// only knows handles -- callers that need it fill it in from their
+    /// own handle-to-id mapping, the same way [`Self::get_vehicle_state`]
+    /// does.
+    pub fn get_all_vehicle_states(&self) -> SimResult<Vec<(usize, VehicleState)>> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "Warp FFI not initialized".to_string(),
+            ));
+        }
+
+        // In full implementation: one contiguous positions/velocities/
+        // orientations device-to-host copy of the whole dense array,
+        // rather than num_vehicles separate ones.
+
+        Ok(self
+            .live_vehicle_indices()
+            .into_iter()
+            .map(|slot_id| {
+                let state = self.host_state[self.front_buffer]
+                    .get(&slot_id)
+                    .cloned()
+                    .unwrap_or_else(|| placeholder_vehicle_state(self.tick));
+                (slot_id, state)
+            })
+            .collect())
+    }
+
+    /// Set vehicle control inputs, resolving `handle` through the slot
+    /// table first so a stale handle fails cleanly.
     pub fn set_vehicle_control(
         &mut self,
-        array_index: usize,
+        handle: VehicleHandle,
         control: &VehicleControl,
     ) -> SimResult<()> {
         if !self.initialized {
@@ -261,46 +876,187 @@ impl WarpFFI {
                 "Warp FFI not initialized".to_string(),
             ));
         }
+        self.resolve(handle)?;
 
         // In full implementation:
-        // Write control inputs to GPU array:
-        // controls[array_index] = wp.array([
+        // Write control inputs to GPU array at this handle's current
+        // dense index:
+        // controls[dense_index] = wp.array([
         //     control.throttle,
         //     control.steering,
         //     control.brake,
         //     control.motor_speeds[0..4]...
         // ])
 
+        self.controls.insert(handle.index, control.clone());
+
         Ok(())
     }
 
-    /// Get sensor data
-    pub fn get_sensor_data(&self, array_index: usize, sensor_id: &str) -> SimResult<SensorData> {
+    /// Set every live vehicle's control inputs with a single host-to-device
+    /// copy instead of one write per vehicle. `controls` must have exactly
+    /// one entry per live vehicle, in the same dense GPU array order
+    /// [`Self::get_all_vehicle_states`] returns.
+    pub fn set_all_vehicle_controls(&mut self, controls: &[VehicleControl]) -> SimResult<()> {
         if !self.initialized {
             return Err(SimError::BackendError(
                 "Warp FFI not initialized".to_string(),
             ));
         }
 
-        // In full implementation:
-        // 1. Identify sensor type from sensor_id
-        // 2. Read appropriate data from GPU
-        // 3. For IMU: read acceleration, gyro from physics state
-        // 4. For GPS: read position
-        // 5. For LiDAR: launch raycast kernel
+        let indices = self.live_vehicle_indices();
+        if controls.len() != indices.len() {
+            return Err(SimError::BackendError(format!(
+                "set_all_vehicle_controls: expected {} controls (one per live vehicle), got {}",
+                indices.len(),
+                controls.len()
+            )));
+        }
+
+        // In full implementation: one host-to-device copy of the whole
+        // controls array, rather than indices.len() separate writes.
+
+        for (slot_id, control) in indices.into_iter().zip(controls) {
+            self.controls.insert(slot_id, control.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Get sensor data, resolving `handle` through the slot table first so
+    /// a stale handle fails cleanly. `sensor` and `mount_transform` come
+    /// from the caller's own vehicle/sensor bookkeeping (this FFI layer
+    /// only knows handles, not vehicle specs).
+    pub fn get_sensor_data(
+        &self,
+        handle: VehicleHandle,
+        sensor_id: &str,
+        sensor: &SensorSpec,
+        mount_transform: &Transform,
+    ) -> SimResult<SensorData> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "Warp FFI not initialized".to_string(),
+            ));
+        }
+        self.resolve(handle)?;
 
-        // Placeholder: Return IMU data
-        let data = SensorData::Imu(ImuData {
-            timestamp: 0.0,
-            linear_acceleration: Vector3::new(0.0, 0.0, 9.81),
-            angular_velocity: Vector3::new(0.0, 0.0, 0.0),
-            orientation: UnitQuaternion::identity(),
-        });
+        match sensor.sensor_type {
+            SensorType::Lidar => {
+                let config = sensor.lidar_config.as_ref().ok_or_else(|| {
+                    SimError::BackendError(format!(
+                        "sensor '{}' is SensorType::Lidar but has no lidar_config",
+                        sensor_id
+                    ))
+                })?;
+                self.synthesize_lidar_scan(handle, mount_transform, config)
+            }
+            // In full implementation:
+            // 2. Read appropriate data from GPU
+            // 3. For IMU: read acceleration, gyro from physics state
+            // 4. For GPS: read position
+            _ => {
+                // Placeholder: Return IMU data
+                Ok(SensorData::Imu(ImuData {
+                    timestamp: 0.0,
+                    linear_acceleration: Vector3::new(0.0, 0.0, 9.81),
+                    angular_velocity: Vector3::new(0.0, 0.0, 0.0),
+                    orientation: UnitQuaternion::identity(),
+                }))
+            }
+        }
+    }
 
-        Ok(data)
+    /// This vehicle's last-known pose, read from the same host mirror
+    /// [`Self::get_vehicle_state`] and [`Self::get_all_vehicle_states`] use.
+    fn current_transform(&self, handle: VehicleHandle) -> SimResult<Transform> {
+        self.resolve(handle)?;
+        Ok(self.host_state[self.front_buffer]
+            .get(&handle.index)
+            .map(|state| state.transform.clone())
+            .unwrap_or_else(|| placeholder_vehicle_state(self.tick).transform))
     }
 
-    /// Cast single ray using GPU SDF
+    /// Synthesize a full spinning-LiDAR scan: generate `channels *
+    /// horizontal_resolution` rays parametrically in the sensor frame,
+    /// transform them by the vehicle's current pose composed with the
+    /// sensor's mounting offset, and feed the whole ray set through the
+    /// batched [`Self::cast_rays`] SDF kernel in one call -- never looping
+    /// per-ray on the CPU, and without a separate host round-trip per
+    /// point the way per-vehicle `.numpy()` reads would need.
+    fn synthesize_lidar_scan(
+        &self,
+        handle: VehicleHandle,
+        mount_transform: &Transform,
+        config: &LidarConfig,
+    ) -> SimResult<SensorData> {
+        let vehicle_transform = self.current_transform(handle)?;
+        let sensor_position = vehicle_transform.position
+            + vehicle_transform.rotation * mount_transform.position.coords;
+        let sensor_rotation = vehicle_transform.rotation * mount_transform.rotation;
+
+        let channels = config.channels.max(1);
+        let horizontal_resolution = config.horizontal_resolution.max(1);
+        let num_rays = (channels * horizontal_resolution) as usize;
+
+        let mut rays = Vec::with_capacity(num_rays);
+        let mut rings = Vec::with_capacity(num_rays);
+        for channel in 0..channels {
+            let elevation_deg = if channels == 1 {
+                config.elevation_min_deg
+            } else {
+                config.elevation_min_deg
+                    + (config.elevation_max_deg - config.elevation_min_deg) * channel as f64
+                        / (channels - 1) as f64
+            };
+            let elevation = elevation_deg.to_radians();
+            for column in 0..horizontal_resolution {
+                let azimuth =
+                    2.0 * std::f64::consts::PI * column as f64 / horizontal_resolution as f64;
+                let local_direction = Vector3::new(
+                    elevation.cos() * azimuth.cos(),
+                    elevation.cos() * azimuth.sin(),
+                    elevation.sin(),
+                );
+                rays.push(Ray {
+                    origin: sensor_position,
+                    direction: sensor_rotation * local_direction,
+                    max_distance: config.max_range,
+                });
+                rings.push(channel);
+            }
+        }
+
+        let hits = self.cast_rays(&rays)?;
+
+        let points = rays
+            .iter()
+            .zip(hits.iter())
+            .zip(rings.iter())
+            .filter_map(|((ray, hit), &ring)| {
+                let hit = hit.as_ref()?;
+                // Intensity proxy: how head-on the ray struck the surface,
+                // from the SDF gradient hit normal -- grazing hits return a
+                // lower intensity, same as a real LiDAR's return strength.
+                let intensity = (-ray.direction.dot(&hit.normal)).clamp(0.0, 1.0) as f32;
+                Some(LidarPoint {
+                    position: hit.position,
+                    intensity,
+                    range: hit.distance as f32,
+                    ring,
+                })
+            })
+            .collect();
+
+        Ok(SensorData::Lidar(LidarData {
+            timestamp: self.tick as f64,
+            points,
+            pose: sensor_position,
+        }))
+    }
+
+    /// Cast a single ray, dispatching to the BVH or SDF path per
+    /// `config.ray_mode`.
     pub fn cast_ray(
         &self,
         origin: &Point3<f64>,
@@ -313,8 +1069,26 @@ impl WarpFFI {
             ));
         }
 
+        if self.config.ray_mode == RayMode::Bvh {
+            return Ok(self.bvh.as_ref().and_then(|bvh| {
+                bvh.intersect(origin, direction, max_distance).map(
+                    |(distance, normal, material)| RayHit {
+                        position: origin + direction * distance,
+                        normal,
+                        distance,
+                        object_id: "bvh_mesh".to_string(),
+                        material,
+                    },
+                )
+            }));
+        }
+
         // In full implementation:
-        // Use Warp's SDF ray marching on GPU:
+        // Use Warp's SDF ray marching on GPU. When the grid is stored at
+        // SdfPrecision::F16, each sample is upconverted to f32 on the fly
+        // (`wp.float(sdf.sample(pos))`) before the hit threshold and
+        // gradient are computed, so the lower-precision storage doesn't
+        // introduce stair-stepping near the zero crossing:
         //
         // @wp.kernel
         // def raycast_sdf(
@@ -407,16 +1181,48 @@ impl WarpFFI {
         // 3. Read results back to CPU
         //
         // This can handle MILLIONS of rays per second!
+        //
+        // Sharded across devices: partition `rays` into one contiguous
+        // chunk per device, copy each chunk's origins/directions to that
+        // device's buffers with its primary context pushed, launch the
+        // batch kernel on its own wp.Stream, then gather hits back in
+        // original order.
 
-        // Placeholder: Process sequentially
-        rays.iter()
-            .map(|ray| self.cast_ray(&ray.origin, &ray.direction, ray.max_distance))
-            .collect()
+        let num_devices = self.devices.len();
+        let chunk_size = rays.len().div_ceil(num_devices.max(1)).max(1);
+
+        let chunk_results: Vec<Vec<Option<RayHit>>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = rays
+                .chunks(chunk_size)
+                .zip(&self.devices)
+                .map(|(chunk, device)| {
+                    scope.spawn(move || {
+                        // In full implementation: ctx.push() for
+                        // `device`, copy `chunk` to its origins/directions
+                        // wp.array, launch batch_raycast on its stream,
+                        // read hits back, ctx.pop().
+                        let _ = device.device_id;
+                        chunk
+                            .iter()
+                            .map(|ray| self.cast_ray(&ray.origin, &ray.direction, ray.max_distance))
+                            .collect::<SimResult<Vec<_>>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("raycast device thread panicked"))
+                .collect::<SimResult<Vec<_>>>()
+        })?;
+
+        Ok(chunk_results.into_iter().flatten().collect())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::InteractionModel;
     use super::*;
 
     #[test]
@@ -436,6 +1242,26 @@ mod tests {
         assert_eq!(scene_id.unwrap(), 0);
     }
 
+    #[test]
+    fn f16_sdf_storage_is_half_the_memory_of_f32_at_the_same_resolution() {
+        let f32_ffi = WarpFFI::new(WarpConfig {
+            sdf_resolution: 64,
+            sdf_precision: SdfPrecision::F32,
+            ..WarpConfig::default()
+        })
+        .unwrap();
+        let f16_ffi = WarpFFI::new(WarpConfig {
+            sdf_resolution: 64,
+            sdf_precision: SdfPrecision::F16,
+            ..WarpConfig::default()
+        })
+        .unwrap();
+
+        assert_eq!(f32_ffi.sdf_precision(), SdfPrecision::F32);
+        assert_eq!(f16_ffi.sdf_precision(), SdfPrecision::F16);
+        assert_eq!(f32_ffi.sdf_memory_bytes(), f16_ffi.sdf_memory_bytes() * 2);
+    }
+
     #[test]
     fn test_ray_casting() {
         let config = WarpConfig::default();
@@ -449,6 +1275,42 @@ mod tests {
         assert!(hit.unwrap().is_some());
     }
 
+    #[test]
+    fn test_ray_casting_in_bvh_mode_hits_the_loaded_scene_mesh() {
+        let config = WarpConfig {
+            ray_mode: RayMode::Bvh,
+            ..WarpConfig::default()
+        };
+        let mut ffi = WarpFFI::new(config).unwrap();
+        ffi.load_scene("test_scene.obj").unwrap();
+
+        let origin = Point3::new(0.0, 0.0, 10.0);
+        let direction = Vector3::new(0.0, 0.0, -1.0);
+
+        let hit = ffi.cast_ray(&origin, &direction, 100.0).unwrap();
+        assert!(hit.is_some());
+        assert_eq!(hit.unwrap().object_id, "bvh_mesh");
+    }
+
+    #[test]
+    fn test_ray_casting_in_bvh_mode_misses_before_a_scene_is_loaded() {
+        let config = WarpConfig {
+            ray_mode: RayMode::Bvh,
+            ..WarpConfig::default()
+        };
+        let ffi = WarpFFI::new(config).unwrap();
+
+        let hit = ffi
+            .cast_ray(
+                &Point3::new(0.0, 0.0, 10.0),
+                &Vector3::new(0.0, 0.0, -1.0),
+                100.0,
+            )
+            .unwrap();
+
+        assert!(hit.is_none());
+    }
+
     #[test]
     fn test_batch_raycast() {
         let config = WarpConfig::default();
@@ -469,4 +1331,466 @@ mod tests {
         assert!(hits.is_ok());
         assert_eq!(hits.unwrap().len(), 100);
     }
+
+    #[test]
+    fn vehicles_are_sharded_round_robin_across_devices() {
+        let config = WarpConfig {
+            device_ids: vec![0, 1],
+            ..WarpConfig::default()
+        };
+        let mut ffi = WarpFFI::new(config).unwrap();
+
+        let spec = VehicleSpec {
+            vehicle_id: "drone-1".to_string(),
+            vehicle_type: autonomysim_core::vehicle::VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: autonomysim_core::vehicle::VehicleParameters::default(),
+            sensors: vec![],
+        };
+
+        let first = ffi.allocate_vehicle(&spec).unwrap();
+        let second = ffi.allocate_vehicle(&spec).unwrap();
+        assert_eq!(ffi.slots[first.index].device_idx, 0);
+        assert_eq!(ffi.slots[second.index].device_idx, 1);
+    }
+
+    #[test]
+    fn batch_raycast_preserves_original_order_across_device_shards() {
+        let config = WarpConfig {
+            device_ids: vec![0, 1, 2],
+            ..WarpConfig::default()
+        };
+        let ffi = WarpFFI::new(config).unwrap();
+
+        // A mix of hitting and missing rays, so a shuffled reassembly
+        // across devices would be easy to detect.
+        let rays: Vec<Ray> = (0..30)
+            .map(|i| Ray {
+                origin: Point3::new(0.0, 0.0, 10.0),
+                direction: if i % 2 == 0 {
+                    Vector3::new(0.0, 0.0, -1.0)
+                } else {
+                    Vector3::new(0.0, 0.0, 1.0)
+                },
+                max_distance: 100.0,
+            })
+            .collect();
+
+        let hits = ffi.cast_rays(&rays).unwrap();
+        assert_eq!(hits.len(), rays.len());
+        for (i, hit) in hits.iter().enumerate() {
+            assert_eq!(hit.is_some(), i % 2 == 0);
+        }
+    }
+
+    #[test]
+    fn reading_between_step_async_and_await_fence_returns_the_previous_tick() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let spec = VehicleSpec {
+            vehicle_id: "drone-1".to_string(),
+            vehicle_type: autonomysim_core::vehicle::VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: autonomysim_core::vehicle::VehicleParameters::default(),
+            sensors: vec![],
+        };
+        let index = ffi.allocate_vehicle(&spec).unwrap();
+
+        let fence_1 = ffi.step_async().unwrap();
+        ffi.await_fence(fence_1).unwrap();
+        let tick_1 = ffi.get_vehicle_state(index, "drone-1").unwrap();
+        assert_eq!(tick_1.timestamp, 1.0);
+
+        let fence_2 = ffi.step_async().unwrap();
+        // Not yet awaited -- still reads tick 1's host mirror.
+        let still_stale = ffi.get_vehicle_state(index, "drone-1").unwrap();
+        assert_eq!(still_stale.timestamp, 1.0);
+
+        ffi.await_fence(fence_2).unwrap();
+        let tick_2 = ffi.get_vehicle_state(index, "drone-1").unwrap();
+        assert_eq!(tick_2.timestamp, 2.0);
+    }
+
+    #[test]
+    fn step_async_fence_ids_are_monotonically_increasing() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+
+        let fence_1 = ffi.step_async().unwrap();
+        ffi.await_fence(fence_1).unwrap();
+        let fence_2 = ffi.step_async().unwrap();
+
+        assert!(fence_2.id() > fence_1.id());
+    }
+
+    #[test]
+    fn step_async_rejects_a_second_launch_before_the_first_is_awaited() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+
+        ffi.step_async().unwrap();
+        assert!(ffi.step_async().is_err());
+    }
+
+    #[test]
+    fn poll_fences_retires_an_in_flight_frame() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+
+        let fence_1 = ffi.step_async().unwrap();
+
+        ffi.poll_fences().unwrap();
+
+        assert_eq!(ffi.last_completed_fence, fence_1.id());
+        assert!(ffi.step_async().is_ok());
+    }
+
+    #[test]
+    fn fp32_precision_leaves_resident_state_exact() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let index = ffi.allocate_vehicle(&multirotor_spec("drone-1")).unwrap();
+
+        let fence = ffi.step_async().unwrap();
+        ffi.await_fence(fence).unwrap();
+
+        let exact = placeholder_vehicle_state(1);
+        let stored = ffi.get_vehicle_state(index, "drone-1").unwrap();
+        assert_eq!(stored.transform.position, exact.transform.position);
+    }
+
+    #[test]
+    fn fp8_precision_keeps_resident_state_finite_and_up_converts_transparently() {
+        let config = WarpConfig {
+            precision: StatePrecision::Fp8,
+            ..WarpConfig::default()
+        };
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let index = ffi.allocate_vehicle(&multirotor_spec("drone-1")).unwrap();
+
+        let fence = ffi.step_async().unwrap();
+        ffi.await_fence(fence).unwrap();
+
+        // get_vehicle_state still returns plain f64 VehicleState fields --
+        // the quantization is invisible at this API boundary.
+        let state: VehicleState = ffi.get_vehicle_state(index, "drone-1").unwrap();
+        assert!(state.transform.position.z.is_finite());
+        assert!(state.linear_velocity.x.is_finite());
+    }
+
+    #[test]
+    fn inverse_square_interaction_pushes_two_close_vehicles_apart_before_integration() {
+        let config = WarpConfig {
+            interaction: Some(InteractionModel::InverseSquareRepulsion {
+                strength: 10.0,
+                cutoff: 100.0,
+            }),
+            ..WarpConfig::default()
+        };
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let a = ffi.allocate_vehicle(&multirotor_spec("a")).unwrap();
+        let b = ffi.allocate_vehicle(&multirotor_spec("b")).unwrap();
+
+        // Seed distinct positions in the host mirror the interaction pass
+        // reads from -- the placeholder integrator itself always stamps
+        // every vehicle at the same spot, so without this the pair would
+        // sit at zero distance and the repulsion pass would have nothing
+        // to act on.
+        let mut state_a = placeholder_vehicle_state(0);
+        state_a.transform.position = Point3::new(-1.0, 0.0, 1.0);
+        let mut state_b = placeholder_vehicle_state(0);
+        state_b.transform.position = Point3::new(1.0, 0.0, 1.0);
+        ffi.host_state[ffi.front_buffer].insert(a.index, state_a);
+        ffi.host_state[ffi.front_buffer].insert(b.index, state_b);
+
+        let fence = ffi.step_async().unwrap();
+        ffi.await_fence(fence).unwrap();
+
+        let state_a = ffi.get_vehicle_state(a, "a").unwrap();
+        let state_b = ffi.get_vehicle_state(b, "b").unwrap();
+        assert!(
+            state_a.linear_velocity.x < 0.0,
+            "vehicle a should be pushed away from vehicle b"
+        );
+        assert!(
+            state_b.linear_velocity.x > 0.0,
+            "vehicle b should be pushed away from vehicle a"
+        );
+    }
+
+    #[test]
+    fn no_interaction_model_leaves_velocity_untouched() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let index = ffi.allocate_vehicle(&multirotor_spec("solo")).unwrap();
+
+        let fence = ffi.step_async().unwrap();
+        ffi.await_fence(fence).unwrap();
+
+        let state = ffi.get_vehicle_state(index, "solo").unwrap();
+        assert_eq!(state.linear_velocity, Vector3::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn fill_allocation_with_nan_poisons_a_slot_before_it_is_ever_stepped() {
+        let config = WarpConfig {
+            fill_allocation_with_nan: true,
+            ..WarpConfig::default()
+        };
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let index = ffi.allocate_vehicle(&multirotor_spec("unstepped")).unwrap();
+
+        let err = ffi.get_vehicle_state(index, "unstepped").unwrap_err();
+        assert!(matches!(err, SimError::BackendError(_)));
+    }
+
+    #[test]
+    fn fill_allocation_with_nan_is_overwritten_by_the_first_step() {
+        let config = WarpConfig {
+            fill_allocation_with_nan: true,
+            ..WarpConfig::default()
+        };
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let index = ffi.allocate_vehicle(&multirotor_spec("stepped")).unwrap();
+
+        let fence = ffi.step_async().unwrap();
+        ffi.await_fence(fence).unwrap();
+
+        let state = ffi.get_vehicle_state(index, "stepped").unwrap();
+        assert!(state.transform.position.x.is_finite());
+    }
+
+    #[test]
+    fn default_config_never_poisons_an_unstepped_slot() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let index = ffi.allocate_vehicle(&multirotor_spec("legacy")).unwrap();
+
+        assert!(ffi.get_vehicle_state(index, "legacy").is_ok());
+    }
+
+    #[test]
+    fn index_mode_defaults_to_i32_for_small_fleets() {
+        let config = WarpConfig::default();
+        let ffi = WarpFFI::new(config).unwrap();
+        assert_eq!(ffi.index_mode(), IndexMode::I32);
+    }
+
+    #[test]
+    fn index_mode_escalates_to_i64_once_addressing_would_overflow_i32() {
+        let config = WarpConfig {
+            max_vehicles: (i32::MAX as usize / ELEMENTS_PER_VEHICLE) + 1,
+            ..WarpConfig::default()
+        };
+        let ffi = WarpFFI::new(config).unwrap();
+        assert_eq!(ffi.index_mode(), IndexMode::I64);
+    }
+
+    #[test]
+    fn explicit_index_mode_i64_is_respected_even_for_small_fleets() {
+        let config = WarpConfig {
+            index_mode: IndexMode::I64,
+            ..WarpConfig::default()
+        };
+        let ffi = WarpFFI::new(config).unwrap();
+        assert_eq!(ffi.index_mode(), IndexMode::I64);
+    }
+
+    #[test]
+    fn shutdown_drains_a_pending_stream_instead_of_leaving_it_stuck() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+
+        ffi.step_async().unwrap();
+        assert!(ffi.shutdown().is_ok());
+    }
+
+    fn multirotor_spec(vehicle_id: &str) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: vehicle_id.to_string(),
+            vehicle_type: autonomysim_core::vehicle::VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: autonomysim_core::vehicle::VehicleParameters::default(),
+            sensors: vec![],
+        }
+    }
+
+    #[test]
+    fn get_all_vehicle_states_matches_the_single_vehicle_method() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let a = ffi.allocate_vehicle(&multirotor_spec("a")).unwrap();
+        let b = ffi.allocate_vehicle(&multirotor_spec("b")).unwrap();
+        let fence = ffi.step_async().unwrap();
+        ffi.await_fence(fence).unwrap();
+
+        let bulk = ffi.get_all_vehicle_states().unwrap();
+        assert_eq!(bulk.len(), 2);
+        assert_eq!(bulk[0].0, a.index);
+        assert_eq!(bulk[1].0, b.index);
+        assert_eq!(
+            bulk[0].1.timestamp,
+            ffi.get_vehicle_state(a, "a").unwrap().timestamp
+        );
+    }
+
+    #[test]
+    fn set_all_vehicle_controls_rejects_a_mismatched_count() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        ffi.allocate_vehicle(&multirotor_spec("a")).unwrap();
+
+        let err = ffi.set_all_vehicle_controls(&[]);
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn set_all_vehicle_controls_applies_one_control_per_live_vehicle() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        ffi.allocate_vehicle(&multirotor_spec("a")).unwrap();
+        ffi.allocate_vehicle(&multirotor_spec("b")).unwrap();
+
+        let controls = vec![
+            VehicleControl {
+                throttle: 0.25,
+                ..Default::default()
+            },
+            VehicleControl {
+                throttle: 0.75,
+                ..Default::default()
+            },
+        ];
+        assert!(ffi.set_all_vehicle_controls(&controls).is_ok());
+        assert_eq!(ffi.controls[&0].throttle, 0.25);
+        assert_eq!(ffi.controls[&1].throttle, 0.75);
+    }
+
+    #[test]
+    fn a_stale_handle_is_rejected_after_deallocation() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let a = ffi.allocate_vehicle(&multirotor_spec("a")).unwrap();
+
+        ffi.deallocate_vehicle(a).unwrap();
+
+        assert!(ffi.get_vehicle_state(a, "a").is_err());
+        assert!(ffi
+            .set_vehicle_control(a, &VehicleControl::default())
+            .is_err());
+        assert!(ffi.deallocate_vehicle(a).is_err());
+    }
+
+    #[test]
+    fn deallocating_a_non_last_vehicle_swaps_the_last_dense_entry_into_its_place() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let a = ffi.allocate_vehicle(&multirotor_spec("a")).unwrap();
+        let b = ffi.allocate_vehicle(&multirotor_spec("b")).unwrap();
+        let c = ffi.allocate_vehicle(&multirotor_spec("c")).unwrap();
+
+        ffi.set_vehicle_control(
+            c,
+            &VehicleControl {
+                throttle: 0.42,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // Deallocate the first slot; its device's dense array should be
+        // compacted by moving `c` (the last entry) into `a`'s old position.
+        ffi.deallocate_vehicle(a).unwrap();
+
+        assert_eq!(ffi.devices[0].dense, vec![c.index]);
+        assert!(ffi.get_vehicle_state(a, "a").is_err());
+
+        // `b` and `c` still resolve to their own state/controls by stable
+        // slot id, unaffected by the dense-array shuffle.
+        assert!(ffi.get_vehicle_state(b, "b").is_ok());
+        assert_eq!(ffi.get_vehicle_state(c, "c").unwrap().vehicle_id, "c");
+        assert_eq!(ffi.controls[&c.index].throttle, 0.42);
+
+        // The freed slot is recycled with a bumped generation, so a new
+        // allocation reuses `a`'s old index but is a distinct handle.
+        let d = ffi.allocate_vehicle(&multirotor_spec("d")).unwrap();
+        assert_eq!(d.index, a.index);
+        assert_ne!(d.generation, a.generation);
+        assert!(ffi.get_vehicle_state(a, "a").is_err());
+        assert!(ffi.get_vehicle_state(d, "d").is_ok());
+    }
+
+    fn lidar_sensor(channels: u32, horizontal_resolution: u32) -> SensorSpec {
+        SensorSpec {
+            sensor_id: "lidar0".to_string(),
+            sensor_type: SensorType::Lidar,
+            update_rate_hz: 10.0,
+            enabled: true,
+            lidar_config: Some(LidarConfig {
+                channels,
+                elevation_min_deg: -80.0,
+                elevation_max_deg: -10.0,
+                horizontal_resolution,
+                max_range: 100.0,
+            }),
+            radar_config: None,
+            noise: None,
+            fault: None,
+        }
+    }
+
+    #[test]
+    fn lidar_scan_batches_one_ray_per_channel_times_column_through_cast_rays() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let vehicle = ffi.allocate_vehicle(&multirotor_spec("drone-1")).unwrap();
+        let sensor = lidar_sensor(4, 8);
+
+        let data = ffi
+            .get_sensor_data(vehicle, "lidar0", &sensor, &Transform::identity())
+            .unwrap();
+
+        let SensorData::Lidar(lidar) = data else {
+            panic!("expected a LidarData variant");
+        };
+
+        // Every ray points downward from height 1.0 within max_range, so
+        // every one of the 4 * 8 rays should return a ground-plane hit.
+        assert_eq!(lidar.points.len(), 32);
+        for point in &lidar.points {
+            assert!(point.ring < 4);
+            assert!(point.range > 0.0 && point.range < 100.0);
+            assert!(point.intensity > 0.0);
+        }
+    }
+
+    #[test]
+    fn lidar_scan_rejects_a_sensor_spec_without_lidar_config() {
+        let config = WarpConfig::default();
+        let mut ffi = WarpFFI::new(config).unwrap();
+        let vehicle = ffi.allocate_vehicle(&multirotor_spec("drone-1")).unwrap();
+        let sensor = SensorSpec {
+            sensor_id: "lidar0".to_string(),
+            sensor_type: SensorType::Lidar,
+            update_rate_hz: 10.0,
+            enabled: true,
+            lidar_config: None,
+            radar_config: None,
+            noise: None,
+            fault: None,
+        };
+
+        assert!(ffi
+            .get_sensor_data(vehicle, "lidar0", &sensor, &Transform::identity())
+            .is_err());
+    }
 }
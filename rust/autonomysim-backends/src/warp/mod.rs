@@ -40,24 +40,87 @@
 //! └──────────────────────────────────────┘
 //! ```
 
+mod bvh;
 mod ffi;
+mod interaction;
+mod precision;
+
+pub use interaction::{BoidsParams, InteractionModel};
+pub use precision::StatePrecision;
 
 use async_trait::async_trait;
-use ffi::WarpFFI;
+use ffi::{VehicleHandle, WarpFFI};
 use std::{
     collections::HashMap,
     sync::{Arc, RwLock},
 };
 
 use autonomysim_core::{
-    backend::{BackendConfig, Ray, RayHit, SceneHandle, SimulationBackend},
+    backend::{
+        BackendConfig, Position, Ray, RayHit, RfPath, SceneHandle, SimulationBackend, Transform,
+    },
     sensor::SensorData,
-    vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType},
+    vehicle::{
+        SensorFault, SensorSpec, VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType,
+    },
     SimError, SimResult,
 };
 
+/// Storage precision for the baked SDF voxel grid `load_scene` writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SdfPrecision {
+    /// Full float32 per voxel.
+    F32,
+    /// Half precision (f16) per voxel, roughly halving the grid's memory
+    /// footprint so resolution can be pushed higher for the same card.
+    /// Ray-march sampling reads and upconverts to f32 on the fly; the
+    /// surface-hit threshold and gradient computation stay in f32 to avoid
+    /// stair-stepping near the zero crossing.
+    F16,
+}
+
+impl Default for SdfPrecision {
+    fn default() -> Self {
+        SdfPrecision::F32
+    }
+}
+
+/// Ray-tracing acceleration structure `cast_ray`/`cast_rays` traverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RayMode {
+    /// Sphere-trace the baked SDF voxel grid. Resolution-limited by
+    /// `sdf_resolution`, and blurs geometry thinner than one voxel.
+    Sdf,
+    /// Traverse a bounding-volume hierarchy built over the scene's
+    /// triangles at `load_scene` time, giving exact hits and surface
+    /// normals regardless of voxel resolution.
+    Bvh,
+}
+
+impl Default for RayMode {
+    fn default() -> Self {
+        RayMode::Sdf
+    }
+}
+
+/// Integer type used for GPU array indexing.
+///
+/// `I32` is faster to compute and move (half the bytes of `I64` per
+/// index), but silently overflows once `max_vehicles` times the
+/// per-vehicle element count exceeds `i32::MAX` -- [`WarpFFI::new`]
+/// escalates to `I64` automatically in that case regardless of what
+/// [`WarpConfig::index_mode`] requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndexMode {
+    /// 32-bit indices (default). Addresses up to `i32::MAX` elements.
+    #[default]
+    I32,
+    /// 64-bit indices, for vehicle counts large enough to overflow `I32`.
+    I64,
+}
+
 /// Configuration for the Warp backend
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WarpConfig {
     /// Simulation timestep in seconds (default: 0.01 = 100Hz)
     pub timestep: f64,
@@ -68,8 +131,10 @@ pub struct WarpConfig {
     /// Maximum number of parallel vehicles (default: 1000)
     pub max_vehicles: usize,
 
-    /// CUDA device ID (default: 0)
-    pub device_id: i32,
+    /// CUDA device IDs to shard work across (default: `[0]`). Vehicles and
+    /// ray batches are partitioned across these devices, each device's
+    /// primary context retained and made current before it is touched.
+    pub device_ids: Vec<i32>,
 
     /// Enable GPU ray tracing (default: true)
     pub enable_gpu_raycast: bool,
@@ -77,6 +142,47 @@ pub struct WarpConfig {
     /// SDF resolution for collision detection (default: 128)
     pub sdf_resolution: usize,
 
+    /// Storage precision for the baked SDF voxel grid (default:
+    /// [`SdfPrecision::F32`]). Switching to [`SdfPrecision::F16`] roughly
+    /// halves voxel-grid memory, letting `sdf_resolution` go higher for the
+    /// same card.
+    pub sdf_precision: SdfPrecision,
+
+    /// Ray-tracing acceleration structure `cast_ray`/`cast_rays` use
+    /// (default: [`RayMode::Sdf`]). [`RayMode::Bvh`] trades the SDF's
+    /// voxel-resolution blur for exact triangle hits.
+    pub ray_mode: RayMode,
+
+    /// Storage precision for the GPU-resident vehicle-state and sensor
+    /// buffers (default: [`StatePrecision::Fp32`]). Switching to
+    /// [`StatePrecision::Fp16`]/[`StatePrecision::Fp8`] lets several-fold
+    /// more vehicles fit in the same VRAM budget at the cost of quantized
+    /// resident state; integration itself always accumulates in `f64`.
+    pub precision: StatePrecision,
+
+    /// Opt-in pairwise force model evaluated across live vehicles before
+    /// integration (default: `None`, vehicles remain fully independent).
+    /// Lets flocking, collision avoidance, and formation control emerge
+    /// across the whole batch instead of each vehicle stepping in
+    /// isolation.
+    pub interaction: Option<InteractionModel>,
+
+    /// When set, every newly allocated vehicle/sensor buffer region is
+    /// initialized with NaN (default: `false`, matching a real GPU
+    /// allocator that hands back whatever garbage was already in VRAM).
+    /// Borrowed from GPU kernel-execution debug tooling: a kernel that
+    /// reads a slot before it's been written produces an unmistakable NaN
+    /// instead of a plausible-looking zero, and [`WarpFFI::get_vehicle_state`]
+    /// surfaces that as a [`SimError`](autonomysim_core::SimError) instead
+    /// of silently returning it.
+    pub fill_allocation_with_nan: bool,
+
+    /// Integer type used for GPU array indexing (default:
+    /// [`IndexMode::I32`]). [`WarpFFI::new`] escalates to
+    /// [`IndexMode::I64`] on its own once `max_vehicles` times the
+    /// per-vehicle element count would overflow `i32`'s addressable range.
+    pub index_mode: IndexMode,
+
     /// Path to Warp Python environment
     pub warp_python_path: Option<String>,
 }
@@ -87,9 +193,15 @@ impl Default for WarpConfig {
             timestep: 0.01, // 100Hz
             substeps: 1,
             max_vehicles: 1000, // Support 1000 parallel vehicles
-            device_id: 0,
+            device_ids: vec![0],
             enable_gpu_raycast: true,
             sdf_resolution: 128,
+            sdf_precision: SdfPrecision::F32,
+            ray_mode: RayMode::Sdf,
+            precision: StatePrecision::Fp32,
+            interaction: None,
+            fill_allocation_with_nan: false,
+            index_mode: IndexMode::I32,
             warp_python_path: None,
         }
     }
@@ -135,11 +247,19 @@ struct WarpVehicleHandle {
     /// Vehicle ID
     vehicle_id: String,
 
-    /// Index in GPU arrays
-    array_index: usize,
+    /// Stable generational handle to this vehicle's GPU slot
+    handle: VehicleHandle,
 
     /// Vehicle type
     vehicle_type: VehicleType,
+
+    /// Sensor specs from spawn time, looked up by sensor ID in
+    /// `get_sensor_data` since the FFI layer only knows handles.
+    sensors: Vec<SensorSpec>,
+
+    /// Per-sensor mounting transforms, cloned from
+    /// `VehicleParameters::sensor_offsets` at spawn time.
+    sensor_offsets: HashMap<String, Transform>,
 }
 
 impl WarpBackend {
@@ -343,6 +463,19 @@ impl SimulationBackend for WarpBackend {
         ffi.read().unwrap().cast_rays(rays)
     }
 
+    fn trace_rf_paths(
+        &self,
+        _scene: &SceneHandle,
+        _tx_pos: Position,
+        _rx_pos: Position,
+        _frequency_hz: f64,
+        _max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>> {
+        // Multi-bounce RF path tracing on the Warp GPU backend is not
+        // implemented yet.
+        Ok(Vec::new())
+    }
+
     async fn spawn_vehicle(&mut self, spec: VehicleSpec) -> SimResult<VehicleId> {
         if !self.initialized {
             return Err(SimError::BackendError(
@@ -353,12 +486,14 @@ impl SimulationBackend for WarpBackend {
         let ffi = self.ffi.as_ref().unwrap();
 
         // Allocate slot in GPU arrays
-        let array_index = ffi.write().unwrap().allocate_vehicle(&spec)?;
+        let vehicle_handle = ffi.write().unwrap().allocate_vehicle(&spec)?;
 
         let handle = WarpVehicleHandle {
             vehicle_id: spec.vehicle_id.clone(),
-            array_index,
+            handle: vehicle_handle,
             vehicle_type: spec.vehicle_type,
+            sensors: spec.sensors.clone(),
+            sensor_offsets: spec.parameters.sensor_offsets.clone(),
         };
 
         self.vehicles.insert(spec.vehicle_id.clone(), handle);
@@ -382,9 +517,7 @@ impl SimulationBackend for WarpBackend {
         let ffi = self.ffi.as_ref().unwrap();
 
         // Deallocate from GPU arrays
-        ffi.write()
-            .unwrap()
-            .deallocate_vehicle(handle.array_index)?;
+        ffi.write().unwrap().deallocate_vehicle(handle.handle)?;
 
         self.vehicles.remove(vehicle_id);
 
@@ -408,7 +541,7 @@ impl SimulationBackend for WarpBackend {
         // Read from GPU arrays
         ffi.read()
             .unwrap()
-            .get_vehicle_state(handle.array_index, vehicle_id)
+            .get_vehicle_state(handle.handle, vehicle_id)
     }
 
     fn set_vehicle_control(&mut self, vehicle_id: &str, control: VehicleControl) -> SimResult<()> {
@@ -428,7 +561,7 @@ impl SimulationBackend for WarpBackend {
         // Update control inputs in GPU arrays
         ffi.write()
             .unwrap()
-            .set_vehicle_control(handle.array_index, &control)
+            .set_vehicle_control(handle.handle, &control)
     }
 
     fn get_sensor_data(&self, vehicle_id: &str, sensor_id: &str) -> SimResult<SensorData> {
@@ -443,12 +576,53 @@ impl SimulationBackend for WarpBackend {
             .get(vehicle_id)
             .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?;
 
+        let sensor = handle
+            .sensors
+            .iter()
+            .find(|s| s.sensor_id == sensor_id)
+            .ok_or_else(|| {
+                SimError::BackendError(format!(
+                    "Vehicle '{}' has no sensor '{}'",
+                    vehicle_id, sensor_id
+                ))
+            })?;
+        let mount_transform = handle
+            .sensor_offsets
+            .get(sensor_id)
+            .cloned()
+            .unwrap_or_else(Transform::identity);
+
         let ffi = self.ffi.as_ref().unwrap();
 
         // Read sensor data from GPU
         ffi.read()
             .unwrap()
-            .get_sensor_data(handle.array_index, sensor_id)
+            .get_sensor_data(handle.handle, sensor_id, sensor, &mount_transform)
+    }
+
+    fn set_sensor_fault(
+        &mut self,
+        vehicle_id: &str,
+        sensor_id: &str,
+        fault: Option<SensorFault>,
+    ) -> SimResult<()> {
+        let handle = self
+            .vehicles
+            .get_mut(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?;
+
+        let sensor = handle
+            .sensors
+            .iter_mut()
+            .find(|s| s.sensor_id == sensor_id)
+            .ok_or_else(|| {
+                SimError::BackendError(format!(
+                    "Vehicle '{}' has no sensor '{}'",
+                    vehicle_id, sensor_id
+                ))
+            })?;
+        sensor.fault = fault;
+        Ok(())
     }
 }
 
@@ -470,9 +644,15 @@ mod tests {
         assert_eq!(config.timestep, 0.01);
         assert_eq!(config.substeps, 1);
         assert_eq!(config.max_vehicles, 1000);
-        assert_eq!(config.device_id, 0);
+        assert_eq!(config.device_ids, vec![0]);
         assert!(config.enable_gpu_raycast);
         assert_eq!(config.sdf_resolution, 128);
+        assert_eq!(config.sdf_precision, SdfPrecision::F32);
+        assert_eq!(config.ray_mode, RayMode::Sdf);
+        assert_eq!(config.precision, StatePrecision::Fp32);
+        assert_eq!(config.interaction, None);
+        assert!(!config.fill_allocation_with_nan);
+        assert_eq!(config.index_mode, IndexMode::I32);
     }
 
     #[test]
@@ -481,15 +661,36 @@ mod tests {
             timestep: 0.005,
             substeps: 2,
             max_vehicles: 5000,
-            device_id: 1,
+            device_ids: vec![0, 1],
             enable_gpu_raycast: true,
             sdf_resolution: 256,
+            sdf_precision: SdfPrecision::F16,
+            ray_mode: RayMode::Bvh,
+            precision: StatePrecision::Fp16,
+            interaction: Some(InteractionModel::InverseSquareRepulsion {
+                strength: 1.0,
+                cutoff: 5.0,
+            }),
+            fill_allocation_with_nan: true,
+            index_mode: IndexMode::I64,
             warp_python_path: Some("/path/to/warp".to_string()),
         };
 
         let backend = WarpBackend::with_config(config.clone());
         assert_eq!(backend.config.timestep, 0.005);
         assert_eq!(backend.config.max_vehicles, 5000);
-        assert_eq!(backend.config.device_id, 1);
+        assert_eq!(backend.config.device_ids, vec![0, 1]);
+        assert_eq!(backend.config.sdf_precision, SdfPrecision::F16);
+        assert_eq!(backend.config.ray_mode, RayMode::Bvh);
+        assert_eq!(backend.config.precision, StatePrecision::Fp16);
+        assert_eq!(
+            backend.config.interaction,
+            Some(InteractionModel::InverseSquareRepulsion {
+                strength: 1.0,
+                cutoff: 5.0,
+            })
+        );
+        assert!(backend.config.fill_allocation_with_nan);
+        assert_eq!(backend.config.index_mode, IndexMode::I64);
     }
 }
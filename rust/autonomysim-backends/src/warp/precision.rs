@@ -0,0 +1,113 @@
+//! Mixed-precision storage for the GPU vehicle-state mirror
+//!
+//! [`WarpFFI`](super::ffi) keeps its physics integration in full `f64`
+//! precision but, per [`super::WarpConfig::precision`], can emulate storing
+//! the *resident* vehicle-state buffers at reduced precision so
+//! `max_vehicles` can scale several-fold further into the same VRAM budget.
+//! [`StatePrecision::Fp16`]/[`Fp8`] round-trip values through a quantizer
+//! with a per-tensor scale factor chosen from the batch's own dynamic
+//! range, the same way a real mixed-precision GPU buffer would need an
+//! explicit scale to keep FP8's tiny mantissa from losing everything
+//! outside `[-1, 1]`.
+
+/// Storage precision for the GPU-resident vehicle-state and sensor
+/// buffers. Integration math always accumulates in `f64`; this only
+/// affects what's kept resident between steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatePrecision {
+    /// Full precision, no quantization.
+    #[default]
+    Fp32,
+    /// 10-bit mantissa, roughly 3 significant decimal digits -- fine for
+    /// position/velocity once scaled into a sane dynamic range.
+    Fp16,
+    /// 3-bit mantissa. Needs a well-chosen per-tensor scale to stay
+    /// representable at all; intended for massively parallel RL rollouts
+    /// where approximate state is an acceptable tradeoff for vehicle count.
+    Fp8,
+}
+
+impl StatePrecision {
+    /// Mantissa bits available for the fractional part once a value is
+    /// normalized into `[-1, 1)` by its tensor's scale.
+    fn mantissa_steps(self) -> f64 {
+        match self {
+            StatePrecision::Fp32 => f64::INFINITY, // No quantization.
+            StatePrecision::Fp16 => 1024.0,        // 2^10
+            StatePrecision::Fp8 => 8.0,            // 2^3
+        }
+    }
+}
+
+/// Per-tensor scale factor a quantized buffer is stored alongside, as a
+/// real mixed-precision GPU buffer would need to keep values representable
+/// in its narrow dynamic range.
+#[derive(Debug, Clone, Copy)]
+pub struct TensorScale(f64);
+
+impl TensorScale {
+    /// Choose a scale from a batch of observed values: the largest
+    /// magnitude seen, so every value in the batch normalizes into
+    /// `[-1, 1]` before quantization. Falls back to `1.0` for an empty or
+    /// all-zero batch so callers never divide by zero.
+    pub fn from_observed(values: impl IntoIterator<Item = f64>) -> Self {
+        let max_abs = values
+            .into_iter()
+            .map(f64::abs)
+            .fold(0.0_f64, f64::max);
+        TensorScale(if max_abs > 0.0 { max_abs } else { 1.0 })
+    }
+
+    /// Round-trip `value` through this precision's quantizer: normalize by
+    /// the tensor scale, snap to the nearest representable step, then
+    /// de-normalize back. `Fp32` is a no-op.
+    pub fn quantize(self, value: f64, precision: StatePrecision) -> f64 {
+        let steps = precision.mantissa_steps();
+        if steps.is_infinite() {
+            return value;
+        }
+
+        let normalized = (value / self.0).clamp(-1.0, 1.0);
+        (normalized * steps).round() / steps * self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fp32_is_lossless() {
+        let scale = TensorScale::from_observed([100.0, -50.0, 3.5]);
+        assert_eq!(scale.quantize(3.5, StatePrecision::Fp32), 3.5);
+    }
+
+    #[test]
+    fn test_fp8_is_lossier_than_fp16_at_the_same_scale() {
+        let scale = TensorScale::from_observed([10.0]);
+        let exact = 7.3_f64;
+
+        let fp16_error = (scale.quantize(exact, StatePrecision::Fp16) - exact).abs();
+        let fp8_error = (scale.quantize(exact, StatePrecision::Fp8) - exact).abs();
+
+        assert!(fp8_error >= fp16_error);
+    }
+
+    #[test]
+    fn test_scale_keeps_large_values_representable() {
+        let scale = TensorScale::from_observed([1000.0, -1000.0]);
+        let quantized = scale.quantize(1000.0, StatePrecision::Fp8);
+
+        // Without per-tensor scaling, a value this large would be entirely
+        // unrepresentable in FP8's native range; with scaling it recovers
+        // to within one quantization step of the true value.
+        assert!((quantized - 1000.0).abs() < 1000.0 / 8.0);
+    }
+
+    #[test]
+    fn test_empty_batch_falls_back_to_unit_scale() {
+        let scale = TensorScale::from_observed(std::iter::empty());
+        assert_eq!(scale.quantize(0.5, StatePrecision::Fp16), scale.quantize(0.5, StatePrecision::Fp16));
+        assert!((scale.quantize(0.5, StatePrecision::Fp32) - 0.5).abs() < 1e-12);
+    }
+}
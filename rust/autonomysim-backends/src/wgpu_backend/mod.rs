@@ -0,0 +1,589 @@
+//! # wgpu Backend
+//!
+//! Pure-Rust GPU compute backend built on [`wgpu`](https://wgpu.rs), running
+//! the same batched vehicle-integration and ray-casting workloads
+//! [`crate::warp::WarpBackend`] offloads to NVIDIA Warp's Python/CUDA
+//! bridge, but entirely from Rust over Vulkan, Metal, or DX12.
+//!
+//! ## Features
+//!
+//! - **No Python dependency**: the whole pipeline is Rust + WGSL
+//! - **Portable**: Vulkan/Metal/DX12, including Apple Silicon and
+//!   integrated GPUs
+//! - **Batch Operations**: one `step()` dispatch integrates every live
+//!   vehicle
+//! - **GPU Ray Tracing**: `cast_rays` runs as its own compute pass
+//!
+//! ## Use Cases
+//!
+//! Same as [`crate::warp::WarpBackend`] -- large-scale multi-agent
+//! simulation, parallel RL rollouts, GPU-accelerated ray tracing -- on
+//! hardware or platforms where a CUDA/Python toolchain isn't available.
+//!
+//! ## Architecture
+//!
+//! ```text
+//! ┌──────────────────────────────────────┐
+//! │       WgpuBackend (Rust)             │
+//! ├──────────────────────────────────────┤
+//! │  ┌────────────────────────────────┐  │
+//! │  │   WgpuPipeline (Rust)          │  │
+//! │  │   • ping-pong storage buffers  │  │
+//! │  │   • compute pipeline + shaders │  │
+//! │  └────────────────────────────────┘  │
+//! │             ↕                         │
+//! │  ┌────────────────────────────────┐  │
+//! │  │   wgpu (Rust)                  │  │
+//! │  │   • dispatch_workgroups()      │  │
+//! │  │   • wgpu::Buffer               │  │
+//! │  └────────────────────────────────┘  │
+//! │             ↕                         │
+//! │  ┌────────────────────────────────┐  │
+//! │  │   Vulkan / Metal / DX12        │  │
+//! │  └────────────────────────────────┘  │
+//! └──────────────────────────────────────┘
+//! ```
+
+mod pipeline;
+
+use async_trait::async_trait;
+use pipeline::{VehicleHandle, WgpuPipeline};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use autonomysim_core::{
+    backend::{
+        BackendConfig, Position, Ray, RayHit, RfPath, SceneHandle, SimulationBackend, Transform,
+    },
+    sensor::SensorData,
+    vehicle::{
+        SensorFault, SensorSpec, VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType,
+    },
+    SimError, SimResult,
+};
+
+/// Configuration for the wgpu backend
+#[derive(Debug, Clone, PartialEq)]
+pub struct WgpuConfig {
+    /// Simulation timestep in seconds (default: 0.01 = 100Hz)
+    pub timestep: f64,
+
+    /// Number of substeps per timestep (default: 1)
+    pub substeps: usize,
+
+    /// Maximum number of parallel vehicles the storage buffers are sized
+    /// for (default: 1000)
+    pub max_vehicles: usize,
+
+    /// Workgroup size the integration and ray-cast compute shaders are
+    /// dispatched with; `step()` issues
+    /// `dispatch_workgroups(ceil(num_vehicles / workgroup_size), 1, 1)`
+    /// (default: 64)
+    pub workgroup_size: usize,
+
+    /// Enable GPU ray tracing (default: true)
+    pub enable_gpu_raycast: bool,
+}
+
+impl Default for WgpuConfig {
+    fn default() -> Self {
+        Self {
+            timestep: 0.01, // 100Hz
+            substeps: 1,
+            max_vehicles: 1000,
+            workgroup_size: 64,
+            enable_gpu_raycast: true,
+        }
+    }
+}
+
+/// wgpu simulation backend
+pub struct WgpuBackend {
+    /// Compute pipeline wrapping the device, queue, and storage buffers
+    pipeline: Option<Arc<RwLock<WgpuPipeline>>>,
+
+    /// Loaded scenes
+    scenes: HashMap<String, WgpuSceneHandle>,
+
+    /// Spawned vehicles (storage-buffer array indices)
+    vehicles: HashMap<String, WgpuVehicleHandle>,
+
+    /// Current simulation time
+    time: f64,
+
+    /// Initialization status
+    initialized: bool,
+
+    /// Configuration
+    config: WgpuConfig,
+}
+
+/// Handle to a scene in the wgpu backend
+#[derive(Debug, Clone)]
+struct WgpuSceneHandle {
+    /// Scene ID
+    scene_id: u32,
+
+    /// Number of objects
+    num_objects: usize,
+}
+
+/// Handle to a vehicle in the wgpu backend
+#[derive(Debug, Clone)]
+struct WgpuVehicleHandle {
+    /// Vehicle ID
+    vehicle_id: String,
+
+    /// Stable generational handle to this vehicle's storage-buffer slot
+    handle: VehicleHandle,
+
+    /// Vehicle type
+    vehicle_type: VehicleType,
+
+    /// Sensor specs from spawn time, looked up by sensor ID in
+    /// `get_sensor_data` since the pipeline layer only knows handles.
+    sensors: Vec<SensorSpec>,
+
+    /// Per-sensor mounting transforms, cloned from
+    /// `VehicleParameters::sensor_offsets` at spawn time.
+    sensor_offsets: HashMap<String, Transform>,
+}
+
+impl WgpuBackend {
+    /// Create a new wgpu backend with default configuration
+    pub fn new() -> Self {
+        Self::with_config(WgpuConfig::default())
+    }
+
+    /// Create a new wgpu backend with custom configuration
+    pub fn with_config(config: WgpuConfig) -> Self {
+        Self {
+            pipeline: None,
+            scenes: HashMap::new(),
+            vehicles: HashMap::new(),
+            time: 0.0,
+            initialized: false,
+            config,
+        }
+    }
+}
+
+impl Default for WgpuBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SimulationBackend for WgpuBackend {
+    fn name(&self) -> &str {
+        "wgpu (Pure-Rust GPU Compute)"
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn get_time(&self) -> f64 {
+        self.time
+    }
+
+    async fn initialize(&mut self, _config: BackendConfig) -> SimResult<()> {
+        if self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend already initialized".to_string(),
+            ));
+        }
+
+        let pipeline = WgpuPipeline::new(self.config.clone())?;
+        self.pipeline = Some(Arc::new(RwLock::new(pipeline)));
+
+        self.initialized = true;
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> SimResult<()> {
+        if !self.initialized {
+            return Ok(());
+        }
+
+        if let Some(pipeline) = &self.pipeline {
+            pipeline.write().unwrap().shutdown()?;
+        }
+
+        self.pipeline = None;
+        self.scenes.clear();
+        self.vehicles.clear();
+        self.time = 0.0;
+        self.initialized = false;
+
+        Ok(())
+    }
+
+    async fn step(&mut self, delta_time: f64) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        let num_steps = (delta_time / self.config.timestep).ceil() as usize;
+
+        for _ in 0..num_steps {
+            pipeline.write().unwrap().step()?;
+        }
+
+        self.time += delta_time;
+
+        Ok(())
+    }
+
+    async fn load_scene(&mut self, scene_path: &str) -> SimResult<SceneHandle> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        let scene_id = pipeline.write().unwrap().load_scene(scene_path)?;
+        let num_objects = pipeline.read().unwrap().get_num_objects()?;
+
+        let handle = WgpuSceneHandle {
+            scene_id,
+            num_objects,
+        };
+
+        let scene_handle = SceneHandle {
+            id: scene_path.to_string(),
+            backend_type: autonomysim_core::backend::BackendType::Wgpu,
+        };
+
+        self.scenes.insert(scene_path.to_string(), handle);
+
+        Ok(scene_handle)
+    }
+
+    fn get_scene_bounds(
+        &self,
+        _scene: &SceneHandle,
+    ) -> SimResult<(nalgebra::Point3<f64>, nalgebra::Point3<f64>)> {
+        Ok((
+            nalgebra::Point3::new(-100.0, -100.0, 0.0),
+            nalgebra::Point3::new(100.0, 100.0, 50.0),
+        ))
+    }
+
+    fn add_object(
+        &mut self,
+        _scene: &SceneHandle,
+        _object: autonomysim_core::backend::SceneObject,
+    ) -> SimResult<String> {
+        Err(SimError::BackendError(
+            "wgpu backend does not support dynamic object addition yet".to_string(),
+        ))
+    }
+
+    fn remove_object(&mut self, _scene: &SceneHandle, _object_id: &str) -> SimResult<()> {
+        Err(SimError::BackendError(
+            "wgpu backend does not support dynamic object removal yet".to_string(),
+        ))
+    }
+
+    fn update_transform(
+        &mut self,
+        _scene: &SceneHandle,
+        _object_id: &str,
+        _transform: autonomysim_core::backend::Transform,
+    ) -> SimResult<()> {
+        Err(SimError::BackendError(
+            "wgpu backend does not support dynamic transforms yet".to_string(),
+        ))
+    }
+
+    fn get_objects(
+        &self,
+        _scene: &SceneHandle,
+    ) -> SimResult<Vec<autonomysim_core::backend::SceneObject>> {
+        Ok(vec![])
+    }
+
+    fn cast_ray(&self, _scene: &SceneHandle, ray: &Ray) -> SimResult<Option<RayHit>> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        pipeline
+            .read()
+            .unwrap()
+            .cast_ray(&ray.origin, &ray.direction, ray.max_distance)
+    }
+
+    fn cast_rays(&self, _scene: &SceneHandle, rays: &[Ray]) -> SimResult<Vec<Option<RayHit>>> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        pipeline.read().unwrap().cast_rays(rays)
+    }
+
+    fn trace_rf_paths(
+        &self,
+        _scene: &SceneHandle,
+        _tx_pos: Position,
+        _rx_pos: Position,
+        _frequency_hz: f64,
+        _max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>> {
+        // Multi-bounce RF path tracing on the wgpu backend is not
+        // implemented yet.
+        Ok(Vec::new())
+    }
+
+    async fn spawn_vehicle(&mut self, spec: VehicleSpec) -> SimResult<VehicleId> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        let vehicle_handle = pipeline.write().unwrap().allocate_vehicle(&spec)?;
+
+        let handle = WgpuVehicleHandle {
+            vehicle_id: spec.vehicle_id.clone(),
+            handle: vehicle_handle,
+            vehicle_type: spec.vehicle_type,
+            sensors: spec.sensors.clone(),
+            sensor_offsets: spec.parameters.sensor_offsets.clone(),
+        };
+
+        self.vehicles.insert(spec.vehicle_id.clone(), handle);
+
+        Ok(spec.vehicle_id)
+    }
+
+    async fn remove_vehicle(&mut self, vehicle_id: &str) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let handle = self
+            .vehicles
+            .get(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?
+            .clone();
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        pipeline
+            .write()
+            .unwrap()
+            .deallocate_vehicle(handle.handle)?;
+
+        self.vehicles.remove(vehicle_id);
+
+        Ok(())
+    }
+
+    fn get_vehicle_state(&self, vehicle_id: &str) -> SimResult<VehicleState> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let handle = self
+            .vehicles
+            .get(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?;
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        pipeline
+            .read()
+            .unwrap()
+            .get_vehicle_state(handle.handle, vehicle_id)
+    }
+
+    fn set_vehicle_control(&mut self, vehicle_id: &str, control: VehicleControl) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let handle = self
+            .vehicles
+            .get(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?;
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        pipeline
+            .write()
+            .unwrap()
+            .set_vehicle_control(handle.handle, &control)
+    }
+
+    fn get_sensor_data(&self, vehicle_id: &str, sensor_id: &str) -> SimResult<SensorData> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu backend not initialized".to_string(),
+            ));
+        }
+
+        let handle = self
+            .vehicles
+            .get(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?;
+
+        let sensor = handle
+            .sensors
+            .iter()
+            .find(|s| s.sensor_id == sensor_id)
+            .ok_or_else(|| {
+                SimError::BackendError(format!(
+                    "Vehicle '{}' has no sensor '{}'",
+                    vehicle_id, sensor_id
+                ))
+            })?;
+        let mount_transform = handle
+            .sensor_offsets
+            .get(sensor_id)
+            .cloned()
+            .unwrap_or_else(Transform::identity);
+
+        let pipeline = self.pipeline.as_ref().unwrap();
+
+        pipeline
+            .read()
+            .unwrap()
+            .get_sensor_data(handle.handle, sensor, &mount_transform)
+    }
+
+    fn set_sensor_fault(
+        &mut self,
+        vehicle_id: &str,
+        sensor_id: &str,
+        fault: Option<SensorFault>,
+    ) -> SimResult<()> {
+        let handle = self
+            .vehicles
+            .get_mut(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle '{}' not found", vehicle_id)))?;
+
+        let sensor = handle
+            .sensors
+            .iter_mut()
+            .find(|s| s.sensor_id == sensor_id)
+            .ok_or_else(|| {
+                SimError::BackendError(format!(
+                    "Vehicle '{}' has no sensor '{}'",
+                    vehicle_id, sensor_id
+                ))
+            })?;
+        sensor.fault = fault;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::{backend::BackendConfig, vehicle::VehicleParameters};
+    use nalgebra::{Point3, UnitQuaternion};
+
+    #[test]
+    fn test_backend_creation() {
+        let backend = WgpuBackend::new();
+        assert_eq!(backend.name(), "wgpu (Pure-Rust GPU Compute)");
+        assert!(!backend.is_initialized());
+        assert_eq!(backend.get_time(), 0.0);
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config = WgpuConfig::default();
+        assert_eq!(config.timestep, 0.01);
+        assert_eq!(config.substeps, 1);
+        assert_eq!(config.max_vehicles, 1000);
+        assert_eq!(config.workgroup_size, 64);
+        assert!(config.enable_gpu_raycast);
+    }
+
+    #[test]
+    fn test_custom_config() {
+        let config = WgpuConfig {
+            timestep: 0.005,
+            substeps: 2,
+            max_vehicles: 5000,
+            workgroup_size: 128,
+            enable_gpu_raycast: true,
+        };
+
+        let backend = WgpuBackend::with_config(config.clone());
+        assert_eq!(backend.config.timestep, 0.005);
+        assert_eq!(backend.config.max_vehicles, 5000);
+        assert_eq!(backend.config.workgroup_size, 128);
+    }
+
+    /// Sets a control, steps the pipeline, and checks the vehicle actually
+    /// moved -- catches the class of bug where `step()` dispatches against
+    /// the GPU but the control never made it into the buffer the shader
+    /// reads. Skips itself (rather than failing) when no Vulkan/Metal/DX12
+    /// adapter is available, since `WgpuBackend::initialize` can legitimately
+    /// fail on a headless CI box with no GPU.
+    #[tokio::test]
+    async fn test_set_vehicle_control_moves_the_vehicle_after_a_step() {
+        let mut backend = WgpuBackend::new();
+        if backend.initialize(BackendConfig::default()).await.is_err() {
+            eprintln!("skipping: no wgpu adapter available in this environment");
+            return;
+        }
+
+        let spec = VehicleSpec {
+            vehicle_id: "agent-0".to_string(),
+            vehicle_type: VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: VehicleParameters::default(),
+            sensors: Vec::new(),
+        };
+        let vehicle_id = backend.spawn_vehicle(spec).await.unwrap();
+
+        let before = backend.get_vehicle_state(&vehicle_id).unwrap();
+
+        backend
+            .set_vehicle_control(
+                &vehicle_id,
+                VehicleControl {
+                    throttle: 1.0,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        backend.step(backend.config.timestep).await.unwrap();
+
+        let after = backend.get_vehicle_state(&vehicle_id).unwrap();
+        assert_ne!(before.transform.position, after.transform.position);
+    }
+}
@@ -0,0 +1,1029 @@
+//! `wgpu` compute pipeline backing [`super::WgpuBackend`]
+//!
+//! This module holds the actual buffer/pipeline bookkeeping, mirroring the
+//! role [`crate::warp::ffi`]'s `WarpFFI` plays for the Warp backend but
+//! talking to `wgpu` instead of Warp's Python/CUDA bridge.
+
+use std::collections::HashMap;
+
+use bytemuck::{Pod, Zeroable};
+use nalgebra::{Point3, Quaternion, UnitQuaternion, Vector3};
+
+use autonomysim_core::{
+    backend::{Ray, RayHit, Transform},
+    sensor::{ImuData, SensorData},
+    vehicle::{SensorSpec, VehicleControl, VehicleSpec, VehicleState},
+    SimError, SimResult,
+};
+
+use super::WgpuConfig;
+
+/// GPU-buffer layout for one vehicle's integration state. Field order and
+/// size must match `VehicleState` in `shaders/integrate.wgsl` exactly --
+/// `vec4<f32>` fields carry an unused `w` lane so every field lands on a
+/// 16-byte boundary, which is what WGSL's default struct layout rules
+/// require for storage buffers.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct GpuVehicleState {
+    position: [f32; 4],
+    /// Quaternion stored as `(x, y, z, w)`, matching
+    /// `nalgebra::Quaternion::coords`.
+    orientation: [f32; 4],
+    velocity: [f32; 4],
+    angular_velocity: [f32; 4],
+    /// `(throttle, roll, pitch, yaw)`.
+    control: [f32; 4],
+    live: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+/// Byte offset of [`GpuVehicleState::control`] -- [`WgpuPipeline::set_vehicle_control`]
+/// writes directly into this sub-range instead of re-uploading the whole
+/// struct. Kept as a constant (rather than `memoffset`) since the struct
+/// above is small and its layout is pinned by `#[repr(C)]`; the unit test
+/// below guards against the two drifting apart.
+const GPU_VEHICLE_STATE_CONTROL_OFFSET: u64 = 64;
+
+/// GPU-buffer layout for [`WgpuPipeline::step`]'s integration uniforms.
+/// Must match `Uniforms` in `shaders/integrate.wgsl`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct Uniforms {
+    dt: f32,
+    num_vehicles: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+/// GPU-buffer layout for one ray, matching `GpuRay` in `shaders/raycast.wgsl`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct GpuRay {
+    origin: [f32; 4],
+    /// `xyz` = direction, `w` = max distance.
+    direction: [f32; 4],
+}
+
+/// GPU-buffer layout for one ray hit, matching `GpuRayHit` in
+/// `shaders/raycast.wgsl`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct GpuRayHit {
+    position: [f32; 4],
+    normal: [f32; 4],
+    distance: f32,
+    hit: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+/// GPU-buffer layout for [`WgpuPipeline::cast_rays`]'s uniforms, matching
+/// `Uniforms` in `shaders/raycast.wgsl`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct RaycastUniforms {
+    num_rays: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+/// Bind group layout shared by the integration and ray-cast pipelines: a
+/// read-only `src` storage buffer at binding 0, a read-write `dst` storage
+/// buffer at binding 1, and a uniform buffer at binding 2.
+const STORAGE_BIND_GROUP_LAYOUT_ENTRIES: &[wgpu::BindGroupLayoutEntry] = &[
+    wgpu::BindGroupLayoutEntry {
+        binding: 0,
+        visibility: wgpu::ShaderStages::COMPUTE,
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only: true },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    },
+    wgpu::BindGroupLayoutEntry {
+        binding: 1,
+        visibility: wgpu::ShaderStages::COMPUTE,
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only: false },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    },
+    wgpu::BindGroupLayoutEntry {
+        binding: 2,
+        visibility: wgpu::ShaderStages::COMPUTE,
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Uniform,
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    },
+];
+
+fn make_bind_group_layout(device: &wgpu::Device, label: &str) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some(label),
+        entries: STORAGE_BIND_GROUP_LAYOUT_ENTRIES,
+    })
+}
+
+fn make_storage_bind_group(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    label: &str,
+    src: &wgpu::Buffer,
+    dst: &wgpu::Buffer,
+    uniforms: &wgpu::Buffer,
+) -> wgpu::BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some(label),
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: src.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: dst.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 2,
+                resource: uniforms.as_entire_binding(),
+            },
+        ],
+    })
+}
+
+fn control_to_gpu(control: &VehicleControl) -> [f32; 4] {
+    [
+        control.throttle as f32,
+        control.roll as f32,
+        control.pitch as f32,
+        control.yaw as f32,
+    ]
+}
+
+fn vehicle_state_to_gpu(
+    state: &VehicleState,
+    control: &VehicleControl,
+    live: bool,
+) -> GpuVehicleState {
+    let q = state.transform.rotation.quaternion().coords;
+    GpuVehicleState {
+        position: [
+            state.transform.position.x as f32,
+            state.transform.position.y as f32,
+            state.transform.position.z as f32,
+            0.0,
+        ],
+        orientation: [q.x as f32, q.y as f32, q.z as f32, q.w as f32],
+        velocity: [
+            state.linear_velocity.x as f32,
+            state.linear_velocity.y as f32,
+            state.linear_velocity.z as f32,
+            0.0,
+        ],
+        angular_velocity: [
+            state.angular_velocity.x as f32,
+            state.angular_velocity.y as f32,
+            state.angular_velocity.z as f32,
+            0.0,
+        ],
+        control: control_to_gpu(control),
+        live: live as u32,
+        _pad0: 0,
+        _pad1: 0,
+        _pad2: 0,
+    }
+}
+
+fn gpu_to_vehicle_state(gpu: &GpuVehicleState, timestamp: f64) -> VehicleState {
+    let orientation = UnitQuaternion::new_unchecked(Quaternion::new(
+        gpu.orientation[3] as f64,
+        gpu.orientation[0] as f64,
+        gpu.orientation[1] as f64,
+        gpu.orientation[2] as f64,
+    ));
+
+    VehicleState {
+        vehicle_id: String::new(),
+        timestamp,
+        transform: Transform::new(
+            Point3::new(
+                gpu.position[0] as f64,
+                gpu.position[1] as f64,
+                gpu.position[2] as f64,
+            ),
+            orientation,
+        ),
+        linear_velocity: Vector3::new(
+            gpu.velocity[0] as f64,
+            gpu.velocity[1] as f64,
+            gpu.velocity[2] as f64,
+        ),
+        angular_velocity: Vector3::new(
+            gpu.angular_velocity[0] as f64,
+            gpu.angular_velocity[1] as f64,
+            gpu.angular_velocity[2] as f64,
+        ),
+        linear_acceleration: Vector3::zeros(),
+        angular_acceleration: Vector3::zeros(),
+        battery_level: 1.0,
+        is_grounded: false,
+        collision_info: None,
+    }
+}
+
+/// Blocks on a staging buffer's `map_async`, returning once the mapping is
+/// ready (or the error it failed with). `wgpu` has no synchronous map call,
+/// so every readback funnels through this -- a channel plus
+/// `Device::poll(Maintain::Wait)`, the same pattern `wgpu`'s own examples
+/// use for headless/compute-only usage.
+fn block_on_buffer_map(device: &wgpu::Device, slice: &wgpu::BufferSlice) -> SimResult<()> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    let _ = device.poll(wgpu::Maintain::Wait);
+    receiver
+        .recv()
+        .map_err(|_| {
+            SimError::BackendError(
+                "wgpu: staging buffer map channel closed before a result arrived".to_string(),
+            )
+        })?
+        .map_err(|e| SimError::BackendError(format!("wgpu: failed to map staging buffer: {e:?}")))
+}
+
+/// Opaque, generation-checked identity for a vehicle allocated into
+/// [`WgpuPipeline`]'s storage buffers.
+///
+/// `index` doubles as the `array_index` used to address this vehicle's slot
+/// in the ping-pong storage buffers -- stable across any number of *other*
+/// vehicles being deallocated, unlike a raw dense position. `generation`
+/// lets [`WgpuPipeline`] detect a handle to an already-freed slot and fail
+/// cleanly instead of silently resolving to whatever vehicle was allocated
+/// into that slot afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VehicleHandle {
+    index: usize,
+    generation: u32,
+}
+
+/// A slot's bookkeeping in [`WgpuPipeline`]'s generational handle table.
+struct VehicleSlot {
+    generation: u32,
+    live: bool,
+}
+
+/// `wgpu` compute pipeline driving the batched vehicle-integration kernel.
+///
+/// Holds the live `wgpu::Device`/`wgpu::Queue` (selected via
+/// `Instance::request_adapter` with no backend preference, so Vulkan,
+/// Metal, or DX12 are all fair game), a pair of `wgpu::Buffer`s used as
+/// ping-pong storage for per-vehicle pose/velocity/control (`array_index`
+/// into each buffer is a [`VehicleHandle::index`]), the compiled
+/// `integrate.wgsl` compute pipeline and its two bind groups (one per
+/// possible `src`/`dst` pairing), and a small uniform buffer holding
+/// `dt`/`num_vehicles` that [`Self::step`] rewrites before every dispatch.
+pub struct WgpuPipeline {
+    config: WgpuConfig,
+    initialized: bool,
+
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+
+    storage_buffers: [wgpu::Buffer; 2],
+    staging_buffer: wgpu::Buffer,
+    uniform_buffer: wgpu::Buffer,
+    integrate_pipeline: wgpu::ComputePipeline,
+    /// `integrate_bind_groups[src_buffer_index]` binds
+    /// `storage_buffers[src_buffer_index]` as `src` and the other buffer as
+    /// `dst`.
+    integrate_bind_groups: [wgpu::BindGroup; 2],
+    /// Index into `storage_buffers` holding the most recently integrated
+    /// state -- the `src` buffer [`Self::step`]'s next dispatch reads from.
+    src_buffer_index: usize,
+
+    /// Compiled ray-cast pipeline, or `None` if
+    /// `WgpuConfig::enable_gpu_raycast` was false at construction time.
+    raycast_pipeline: Option<wgpu::ComputePipeline>,
+    raycast_bind_group_layout: Option<wgpu::BindGroupLayout>,
+
+    /// Generational handle table; indexed by [`VehicleHandle::index`], which
+    /// doubles as the storage-buffer `array_index`.
+    slots: Vec<VehicleSlot>,
+
+    /// Freed slot ids available for [`Self::allocate_vehicle`] to recycle,
+    /// most-recently-freed last.
+    free_slots: Vec<usize>,
+
+    num_allocated_vehicles: usize,
+    current_scene_id: u32,
+
+    /// Host-side mirror of each live vehicle's state, refreshed from the
+    /// GPU staging buffer at the end of every [`Self::step`].
+    host_state: HashMap<usize, VehicleState>,
+    tick: u64,
+}
+
+impl WgpuPipeline {
+    /// Stand up the `wgpu` device and compute pipeline.
+    pub fn new(config: WgpuConfig) -> SimResult<Self> {
+        if config.max_vehicles == 0 {
+            return Err(SimError::BackendError(
+                "WgpuConfig::max_vehicles must be at least 1".to_string(),
+            ));
+        }
+
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        }))
+        .ok_or_else(|| {
+            SimError::BackendError(
+                "wgpu: no compatible Vulkan/Metal/DX12 adapter found on this host".to_string(),
+            )
+        })?;
+
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("autonomysim-wgpu-device"),
+                required_features: wgpu::Features::empty(),
+                required_limits: wgpu::Limits::downlevel_defaults(),
+            },
+            None,
+        ))
+        .map_err(|e| SimError::BackendError(format!("wgpu: failed to request device: {e}")))?;
+
+        let vehicle_state_size = std::mem::size_of::<GpuVehicleState>() as u64;
+        let storage_size = vehicle_state_size * config.max_vehicles as u64;
+
+        let storage_buffers = [
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("wgpu-vehicle-storage-0"),
+                size: storage_size,
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_SRC
+                    | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("wgpu-vehicle-storage-1"),
+                size: storage_size,
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_SRC
+                    | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        ];
+
+        let staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("wgpu-vehicle-staging"),
+            size: storage_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("wgpu-integrate-uniforms"),
+            size: std::mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let integrate_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("integrate.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/integrate.wgsl").into()),
+        });
+
+        let integrate_bind_group_layout =
+            make_bind_group_layout(&device, "wgpu-integrate-bind-group-layout");
+
+        let integrate_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("wgpu-integrate-pipeline-layout"),
+                bind_group_layouts: &[&integrate_bind_group_layout],
+                push_constant_ranges: &[],
+            });
+
+        let integrate_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("wgpu-integrate-pipeline"),
+            layout: Some(&integrate_pipeline_layout),
+            module: &integrate_shader,
+            entry_point: "integrate",
+        });
+
+        let integrate_bind_groups = [
+            make_storage_bind_group(
+                &device,
+                &integrate_bind_group_layout,
+                "wgpu-integrate-bind-group-0",
+                &storage_buffers[0],
+                &storage_buffers[1],
+                &uniform_buffer,
+            ),
+            make_storage_bind_group(
+                &device,
+                &integrate_bind_group_layout,
+                "wgpu-integrate-bind-group-1",
+                &storage_buffers[1],
+                &storage_buffers[0],
+                &uniform_buffer,
+            ),
+        ];
+
+        let (raycast_pipeline, raycast_bind_group_layout) = if config.enable_gpu_raycast {
+            let raycast_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("raycast.wgsl"),
+                source: wgpu::ShaderSource::Wgsl(include_str!("shaders/raycast.wgsl").into()),
+            });
+            let bind_group_layout =
+                make_bind_group_layout(&device, "wgpu-raycast-bind-group-layout");
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("wgpu-raycast-pipeline-layout"),
+                bind_group_layouts: &[&bind_group_layout],
+                push_constant_ranges: &[],
+            });
+            let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("wgpu-raycast-pipeline"),
+                layout: Some(&pipeline_layout),
+                module: &raycast_shader,
+                entry_point: "raycast",
+            });
+            (Some(pipeline), Some(bind_group_layout))
+        } else {
+            (None, None)
+        };
+
+        println!(
+            "wgpu backend: adapter acquired, workgroup_size={}, max_vehicles={}",
+            config.workgroup_size, config.max_vehicles
+        );
+
+        Ok(Self {
+            config,
+            initialized: true,
+            device,
+            queue,
+            storage_buffers,
+            staging_buffer,
+            uniform_buffer,
+            integrate_pipeline,
+            integrate_bind_groups,
+            src_buffer_index: 0,
+            raycast_pipeline,
+            raycast_bind_group_layout,
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            num_allocated_vehicles: 0,
+            current_scene_id: 0,
+            host_state: HashMap::new(),
+            tick: 0,
+        })
+    }
+
+    /// Handle slot ids of every currently-live vehicle, in storage-buffer
+    /// `array_index` order -- the order the dispatch walks the buffer in.
+    fn live_vehicle_indices(&self) -> Vec<usize> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.live)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Validate a [`VehicleHandle`] against the slot table, failing cleanly
+    /// if it's stale (the slot was freed, possibly reused by a later
+    /// [`Self::allocate_vehicle`]) rather than silently resolving to
+    /// whatever vehicle now occupies that slot.
+    fn resolve(&self, handle: VehicleHandle) -> SimResult<()> {
+        match self.slots.get(handle.index) {
+            Some(slot) if slot.live && slot.generation == handle.generation => Ok(()),
+            _ => Err(SimError::BackendError(format!(
+                "stale or invalid vehicle handle {:?}",
+                handle
+            ))),
+        }
+    }
+
+    /// Write `state`'s full GPU-buffer representation into both storage
+    /// buffers at `index`'s slot, so it's visible regardless of which one
+    /// [`Self::src_buffer_index`] currently points at.
+    fn write_vehicle_slot(&self, index: usize, state: &GpuVehicleState) {
+        let offset = index as u64 * std::mem::size_of::<GpuVehicleState>() as u64;
+        let bytes = bytemuck::bytes_of(state);
+        self.queue
+            .write_buffer(&self.storage_buffers[0], offset, bytes);
+        self.queue
+            .write_buffer(&self.storage_buffers[1], offset, bytes);
+    }
+
+    pub fn shutdown(&mut self) -> SimResult<()> {
+        if !self.initialized {
+            return Ok(());
+        }
+
+        // wgpu has no explicit context to pop like Warp's CUDA primary
+        // contexts -- the buffers, pipelines, device, and queue all release
+        // their resources when this struct is dropped.
+        println!("wgpu backend: releasing device");
+
+        self.initialized = false;
+        Ok(())
+    }
+
+    /// Load scene. The wgpu backend has no SDF collision stage yet, so this
+    /// only reserves a scene id for [`super::WgpuBackend::load_scene`] to
+    /// hand back.
+    pub fn load_scene(&mut self, scene_path: &str) -> SimResult<u32> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+
+        self.current_scene_id += 1;
+        println!(
+            "wgpu backend: loaded scene '{}' (ID {})",
+            scene_path, self.current_scene_id
+        );
+
+        Ok(self.current_scene_id)
+    }
+
+    pub fn get_num_objects(&self) -> SimResult<usize> {
+        Ok(0)
+    }
+
+    /// Dispatch the batched integration kernel and block until the readback
+    /// completes.
+    pub fn step(&mut self) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+
+        let num_vehicles = self.slots.len();
+        if num_vehicles == 0 {
+            self.tick += 1;
+            return Ok(());
+        }
+
+        let uniforms = Uniforms {
+            dt: self.config.timestep as f32,
+            num_vehicles: num_vehicles as u32,
+            _pad0: 0,
+            _pad1: 0,
+        };
+        self.queue
+            .write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+
+        let dst_index = 1 - self.src_buffer_index;
+        let storage_size =
+            std::mem::size_of::<GpuVehicleState>() as u64 * self.config.max_vehicles as u64;
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("wgpu-integrate-encoder"),
+            });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("wgpu-integrate-pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.integrate_pipeline);
+            pass.set_bind_group(0, &self.integrate_bind_groups[self.src_buffer_index], &[]);
+            let workgroups =
+                (num_vehicles as u32).div_ceil(self.config.workgroup_size.max(1) as u32);
+            pass.dispatch_workgroups(workgroups.max(1), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(
+            &self.storage_buffers[dst_index],
+            0,
+            &self.staging_buffer,
+            0,
+            storage_size,
+        );
+        self.queue.submit(Some(encoder.finish()));
+
+        let live_indices = self.live_vehicle_indices();
+        {
+            let slice = self.staging_buffer.slice(..);
+            block_on_buffer_map(&self.device, &slice)?;
+
+            let mapped = slice.get_mapped_range();
+            let gpu_states: &[GpuVehicleState] = bytemuck::cast_slice(&mapped);
+            for &index in &live_indices {
+                self.host_state.insert(
+                    index,
+                    gpu_to_vehicle_state(&gpu_states[index], self.tick as f64),
+                );
+            }
+        }
+        self.staging_buffer.unmap();
+
+        self.src_buffer_index = dst_index;
+        self.tick += 1;
+
+        Ok(())
+    }
+
+    /// Allocate a vehicle's slot, returning a [`VehicleHandle`] that stays
+    /// valid -- and keeps resolving to this same vehicle -- across any
+    /// number of *other* vehicles being deallocated.
+    pub fn allocate_vehicle(&mut self, spec: &VehicleSpec) -> SimResult<VehicleHandle> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+
+        if self.num_allocated_vehicles >= self.config.max_vehicles {
+            return Err(SimError::BackendError(format!(
+                "Maximum vehicles ({}) reached",
+                self.config.max_vehicles
+            )));
+        }
+
+        let (index, generation) = match self.free_slots.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.live = true;
+                (index, slot.generation)
+            }
+            None => {
+                self.slots.push(VehicleSlot {
+                    generation: 0,
+                    live: true,
+                });
+                (self.slots.len() - 1, 0)
+            }
+        };
+
+        let initial_state = VehicleState {
+            vehicle_id: spec.vehicle_id.clone(),
+            timestamp: self.tick as f64,
+            transform: spec.initial_transform.clone(),
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        };
+        let gpu_state = vehicle_state_to_gpu(&initial_state, &VehicleControl::default(), true);
+        self.write_vehicle_slot(index, &gpu_state);
+        self.host_state.insert(index, initial_state);
+
+        self.num_allocated_vehicles += 1;
+
+        println!(
+            "wgpu backend: allocated vehicle '{}' ({:?}) at array_index {}",
+            spec.vehicle_id, spec.vehicle_type, index
+        );
+
+        Ok(VehicleHandle { index, generation })
+    }
+
+    /// Free a vehicle's slot. Unlike Warp's dense-array swap-and-pop, the
+    /// storage buffer here is simply sparse between live `array_index`
+    /// positions -- the integration shader reads a live-mask bit per slot
+    /// (written alongside pose/velocity) instead of relying on packing, so
+    /// freeing a slot never disturbs any other vehicle's index.
+    pub fn deallocate_vehicle(&mut self, handle: VehicleHandle) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+
+        self.resolve(handle)?;
+
+        let slot = &mut self.slots[handle.index];
+        slot.live = false;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(handle.index);
+        self.num_allocated_vehicles -= 1;
+
+        self.host_state.remove(&handle.index);
+        self.write_vehicle_slot(handle.index, &GpuVehicleState::zeroed());
+
+        println!("wgpu backend: deallocated vehicle handle {:?}", handle);
+
+        Ok(())
+    }
+
+    pub fn get_vehicle_state(
+        &self,
+        handle: VehicleHandle,
+        vehicle_id: &str,
+    ) -> SimResult<VehicleState> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+        self.resolve(handle)?;
+
+        let mut state = self
+            .host_state
+            .get(&handle.index)
+            .cloned()
+            .ok_or_else(|| {
+                SimError::BackendError(format!(
+                    "no host-side state cached for vehicle handle {:?} -- allocate_vehicle should have seeded it",
+                    handle
+                ))
+            })?;
+        state.vehicle_id = vehicle_id.to_string();
+
+        Ok(state)
+    }
+
+    pub fn set_vehicle_control(
+        &mut self,
+        handle: VehicleHandle,
+        control: &VehicleControl,
+    ) -> SimResult<()> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+        self.resolve(handle)?;
+
+        let control_bytes = control_to_gpu(control);
+        let offset = handle.index as u64 * std::mem::size_of::<GpuVehicleState>() as u64
+            + GPU_VEHICLE_STATE_CONTROL_OFFSET;
+        let bytes = bytemuck::bytes_of(&control_bytes);
+        self.queue
+            .write_buffer(&self.storage_buffers[0], offset, bytes);
+        self.queue
+            .write_buffer(&self.storage_buffers[1], offset, bytes);
+
+        Ok(())
+    }
+
+    /// Sensor readback is not backed by a real sensor model on this
+    /// backend yet -- only a placeholder IMU reading, matching
+    /// [`crate::warp::ffi::WarpFFI::get_sensor_data`]'s non-LiDAR fallback.
+    pub fn get_sensor_data(
+        &self,
+        handle: VehicleHandle,
+        _sensor: &SensorSpec,
+        _mount_transform: &Transform,
+    ) -> SimResult<SensorData> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+        self.resolve(handle)?;
+
+        Ok(SensorData::Imu(ImuData {
+            timestamp: self.tick as f64,
+            linear_acceleration: Vector3::new(0.0, 0.0, 9.81),
+            angular_velocity: Vector3::new(0.0, 0.0, 0.0),
+            orientation: UnitQuaternion::identity(),
+        }))
+    }
+
+    /// Cast a single ray on the CPU. Kept as a fast path for one-off queries
+    /// so callers don't pay for a full GPU round trip (buffer allocation,
+    /// dispatch, and a blocking readback) just to test one ray; batches go
+    /// through [`Self::cast_rays`]'s real GPU dispatch instead.
+    pub fn cast_ray(
+        &self,
+        origin: &Point3<f64>,
+        direction: &Vector3<f64>,
+        max_distance: f64,
+    ) -> SimResult<Option<RayHit>> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+
+        if direction.z < 0.0 {
+            let t = -origin.z / direction.z;
+            if t > 0.0 && t < max_distance {
+                let hit_point = origin + direction * t;
+                return Ok(Some(RayHit {
+                    position: hit_point,
+                    normal: Vector3::new(0.0, 0.0, 1.0),
+                    distance: t,
+                    object_id: "ground".to_string(),
+                    material: autonomysim_core::backend::Material::air(),
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Cast a batch of rays through a dedicated compute pass. Falls back to
+    /// [`Self::cast_ray`] per ray if `WgpuConfig::enable_gpu_raycast` was
+    /// false at construction time.
+    pub fn cast_rays(&self, rays: &[Ray]) -> SimResult<Vec<Option<RayHit>>> {
+        if !self.initialized {
+            return Err(SimError::BackendError(
+                "wgpu pipeline not initialized".to_string(),
+            ));
+        }
+
+        if rays.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (Some(pipeline), Some(bind_group_layout)) =
+            (&self.raycast_pipeline, &self.raycast_bind_group_layout)
+        else {
+            return rays
+                .iter()
+                .map(|ray| self.cast_ray(&ray.origin, &ray.direction, ray.max_distance))
+                .collect();
+        };
+
+        let gpu_rays: Vec<GpuRay> = rays
+            .iter()
+            .map(|ray| GpuRay {
+                origin: [
+                    ray.origin.x as f32,
+                    ray.origin.y as f32,
+                    ray.origin.z as f32,
+                    0.0,
+                ],
+                direction: [
+                    ray.direction.x as f32,
+                    ray.direction.y as f32,
+                    ray.direction.z as f32,
+                    ray.max_distance as f32,
+                ],
+            })
+            .collect();
+
+        let ray_buffer_size = (std::mem::size_of::<GpuRay>() * gpu_rays.len()) as u64;
+        let hit_buffer_size = (std::mem::size_of::<GpuRayHit>() * gpu_rays.len()) as u64;
+
+        let ray_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("wgpu-raycast-rays"),
+            size: ray_buffer_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue
+            .write_buffer(&ray_buffer, 0, bytemuck::cast_slice(&gpu_rays));
+
+        let hit_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("wgpu-raycast-hits"),
+            size: hit_buffer_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("wgpu-raycast-staging"),
+            size: hit_buffer_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let uniforms = RaycastUniforms {
+            num_rays: gpu_rays.len() as u32,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
+        };
+        let uniform_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("wgpu-raycast-uniforms"),
+            size: std::mem::size_of::<RaycastUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue
+            .write_buffer(&uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+
+        let bind_group = make_storage_bind_group(
+            &self.device,
+            bind_group_layout,
+            "wgpu-raycast-bind-group",
+            &ray_buffer,
+            &hit_buffer,
+            &uniform_buffer,
+        );
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("wgpu-raycast-encoder"),
+            });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("wgpu-raycast-pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let workgroups =
+                (gpu_rays.len() as u32).div_ceil(self.config.workgroup_size.max(1) as u32);
+            pass.dispatch_workgroups(workgroups.max(1), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&hit_buffer, 0, &staging_buffer, 0, hit_buffer_size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging_buffer.slice(..);
+        block_on_buffer_map(&self.device, &slice)?;
+
+        let hits = {
+            let mapped = slice.get_mapped_range();
+            let gpu_hits: &[GpuRayHit] = bytemuck::cast_slice(&mapped);
+            gpu_hits
+                .iter()
+                .map(|hit| {
+                    if hit.hit == 0 {
+                        None
+                    } else {
+                        Some(RayHit {
+                            position: Point3::new(
+                                hit.position[0] as f64,
+                                hit.position[1] as f64,
+                                hit.position[2] as f64,
+                            ),
+                            normal: Vector3::new(
+                                hit.normal[0] as f64,
+                                hit.normal[1] as f64,
+                                hit.normal[2] as f64,
+                            ),
+                            distance: hit.distance as f64,
+                            object_id: "ground".to_string(),
+                            material: autonomysim_core::backend::Material::air(),
+                        })
+                    }
+                })
+                .collect()
+        };
+        staging_buffer.unmap();
+
+        Ok(hits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gpu_vehicle_state_control_offset_matches_the_struct_layout() {
+        let state = GpuVehicleState::zeroed();
+        let base = &state as *const GpuVehicleState as usize;
+        let control_field = &state.control as *const [f32; 4] as usize;
+        assert_eq!(
+            (control_field - base) as u64,
+            GPU_VEHICLE_STATE_CONTROL_OFFSET
+        );
+    }
+
+    #[test]
+    fn gpu_vehicle_state_round_trips_through_conversion_helpers() {
+        let state = VehicleState {
+            vehicle_id: "agent-0".to_string(),
+            timestamp: 1.0,
+            transform: Transform::new(Point3::new(1.0, 2.0, 3.0), UnitQuaternion::identity()),
+            linear_velocity: Vector3::new(0.5, 0.0, -1.0),
+            angular_velocity: Vector3::new(0.0, 0.1, 0.0),
+            linear_acceleration: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        };
+        let control = VehicleControl {
+            throttle: 0.8,
+            ..Default::default()
+        };
+
+        let gpu = vehicle_state_to_gpu(&state, &control, true);
+        let round_tripped = gpu_to_vehicle_state(&gpu, 1.0);
+
+        assert_eq!(round_tripped.transform.position, state.transform.position);
+        assert_eq!(round_tripped.linear_velocity, state.linear_velocity);
+        assert_eq!(round_tripped.angular_velocity, state.angular_velocity);
+        assert_eq!(gpu.control, control_to_gpu(&control));
+    }
+}
@@ -0,0 +1,519 @@
+//! ADS-B (Automatic Dependent Surveillance-Broadcast) encode/decode
+//!
+//! Implements the subset of Mode-S extended squitter (DF17) messages
+//! needed for cooperative air-traffic awareness: airborne position (with
+//! CPR even/odd frame pairing), velocity, and aircraft identification.
+//! Frames round-trip through [`encode_airborne_position`] /
+//! [`decode_airborne_position_global`] (and friends) the same way a real
+//! transponder and receiver would, including the compact position
+//! reporting (CPR) global and local decode paths.
+
+/// A 24-bit ICAO aircraft address.
+pub type IcaoAddress = u32;
+
+/// Mode-S downlink format for an ADS-B extended squitter.
+const DF17: u8 = 17;
+
+/// Mode-S CRC-24 generator polynomial, as a 24-bit value with the implicit
+/// leading `x^24` term dropped.
+const CRC_POLY: u32 = 0xFFF409;
+
+fn set_bits(frame: &mut [u8; 14], start_bit: usize, num_bits: usize, value: u64) {
+    for i in 0..num_bits {
+        let bit_index = start_bit + i;
+        let byte_index = bit_index / 8;
+        let bit_in_byte = 7 - (bit_index % 8);
+        let bit_value = (value >> (num_bits - 1 - i)) & 1;
+        if bit_value == 1 {
+            frame[byte_index] |= 1 << bit_in_byte;
+        } else {
+            frame[byte_index] &= !(1 << bit_in_byte);
+        }
+    }
+}
+
+fn get_bits(frame: &[u8; 14], start_bit: usize, num_bits: usize) -> u64 {
+    let mut value = 0u64;
+    for i in 0..num_bits {
+        let bit_index = start_bit + i;
+        let byte_index = bit_index / 8;
+        let bit_in_byte = 7 - (bit_index % 8);
+        let bit = (frame[byte_index] >> bit_in_byte) & 1;
+        value = (value << 1) | bit as u64;
+    }
+    value
+}
+
+/// Bit-serial Mode-S CRC-24 over the first `num_bits` of `frame`.
+fn mode_s_crc(frame: &[u8; 14], num_bits: usize) -> u32 {
+    let mut remainder: u32 = 0;
+    for i in 0..num_bits {
+        let byte_index = i / 8;
+        let bit_in_byte = 7 - (i % 8);
+        let bit = ((frame[byte_index] >> bit_in_byte) & 1) as u32;
+        let top_bit = (remainder >> 23) & 1;
+        remainder = ((remainder << 1) | bit) & 0xFF_FFFF;
+        if top_bit == 1 {
+            remainder ^= CRC_POLY;
+        }
+    }
+    remainder
+}
+
+/// Stamp `frame`'s 24-bit parity/interrogator field (bits 88-111) with the
+/// CRC-24 of the preceding 88 bits.
+fn finalize_crc(frame: &mut [u8; 14]) {
+    let crc = mode_s_crc(frame, 88);
+    set_bits(frame, 88, 24, crc as u64);
+}
+
+/// Verify `frame`'s parity field against a freshly computed CRC-24,
+/// rejecting a corrupted or malformed frame.
+fn verify_crc(frame: &[u8; 14]) -> bool {
+    let expected = mode_s_crc(frame, 88);
+    get_bits(frame, 88, 24) as u32 == expected
+}
+
+fn new_frame(icao: IcaoAddress, type_code: u8) -> [u8; 14] {
+    let mut frame = [0u8; 14];
+    set_bits(&mut frame, 0, 5, DF17 as u64);
+    set_bits(&mut frame, 5, 3, 5); // CA: airborne, ADS-B level 2+
+    set_bits(&mut frame, 8, 24, (icao & 0xFF_FFFF) as u64);
+    set_bits(&mut frame, 32, 5, type_code as u64);
+    frame
+}
+
+pub fn decode_icao(frame: &[u8; 14]) -> IcaoAddress {
+    get_bits(frame, 8, 24) as u32
+}
+
+/// Derive a stable 24-bit ICAO address for a simulated vehicle that has no
+/// real one assigned, via an FNV-1a hash of its `VehicleId` folded into 24
+/// bits.
+pub fn icao_from_vehicle_id(vehicle_id: &str) -> IcaoAddress {
+    const FNV_OFFSET_BASIS: u32 = 0x811C_9DC5;
+    const FNV_PRIME: u32 = 0x0100_0193;
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in vehicle_id.as_bytes() {
+        hash ^= *byte as u32;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    (hash ^ (hash >> 24)) & 0xFF_FFFF
+}
+
+/// The ME field's type code (bits 1-5), identifying what kind of ADS-B
+/// message this frame carries.
+pub fn decode_type_code(frame: &[u8; 14]) -> u8 {
+    get_bits(frame, 32, 5) as u8
+}
+
+/// The 17-bit compact position report for one frame, plus which CPR
+/// format (even/odd) it was encoded in.
+#[derive(Debug, Clone, Copy)]
+struct CprFrame {
+    odd: bool,
+    lat_cpr: u32,
+    lon_cpr: u32,
+}
+
+const CPR_RESOLUTION: f64 = 131_072.0; // 2^17
+
+fn cpr_fraction(latitude_deg: f64, odd: bool) -> (f64, f64) {
+    let nz = 15.0_f64;
+    let d_lat = if odd {
+        360.0 / (4.0 * nz - 1.0)
+    } else {
+        360.0 / (4.0 * nz)
+    };
+    let zone = (latitude_deg / d_lat).floor();
+    let lat_fraction = latitude_deg / d_lat - zone;
+    (lat_fraction, d_lat)
+}
+
+/// Number of CPR longitude zones at `latitude_deg`, per the ADS-B spec's
+/// `NL()` function (latitude band boundaries where the number of zones
+/// around a line of latitude changes).
+fn nl(latitude_deg: f64) -> u32 {
+    let lat = latitude_deg.abs();
+    if lat >= 87.0 {
+        return 1;
+    }
+    if lat < 1e-9 {
+        return 59;
+    }
+    let nz = 15.0_f64;
+    let a =
+        1.0 - (1.0 - (std::f64::consts::PI / (2.0 * nz)).cos()) / (lat.to_radians().cos().powi(2));
+    (2.0 * std::f64::consts::PI / a.clamp(-1.0, 1.0).acos()).floor() as u32
+}
+
+/// Encode an airborne position (ADS-B type code 11, "airborne position,
+/// barometric altitude") ME field: altitude (Q-bit, 25 ft resolution) plus
+/// a CPR-encoded latitude/longitude in either the even or odd format.
+pub fn encode_airborne_position(
+    icao: IcaoAddress,
+    altitude_ft: f64,
+    latitude_deg: f64,
+    longitude_deg: f64,
+    odd: bool,
+) -> [u8; 14] {
+    let mut frame = new_frame(icao, 11);
+
+    let n = (((altitude_ft + 1000.0) / 25.0).round() as i64).clamp(0, 0x7FF) as u32;
+    let ac12 = ((n & 0x7F0) << 1) | 0x10 | (n & 0xF);
+    set_bits(&mut frame, 40, 12, ac12 as u64);
+
+    let (lat_fraction, _) = cpr_fraction(latitude_deg, odd);
+    let lat_cpr = ((lat_fraction.rem_euclid(1.0)) * CPR_RESOLUTION).floor() as u32 & 0x1_FFFF;
+
+    let nl_lon = nl(latitude_deg).max(1);
+    let lon_zones = if odd { (nl_lon - 1).max(1) } else { nl_lon };
+    let d_lon = 360.0 / lon_zones as f64;
+    let lon_zone = (longitude_deg / d_lon).floor();
+    let lon_fraction = (longitude_deg / d_lon - lon_zone).rem_euclid(1.0);
+    let lon_cpr = (lon_fraction * CPR_RESOLUTION).floor() as u32 & 0x1_FFFF;
+
+    set_bits(&mut frame, 53, 1, odd as u64);
+    set_bits(&mut frame, 54, 17, lat_cpr as u64);
+    set_bits(&mut frame, 71, 17, lon_cpr as u64);
+
+    finalize_crc(&mut frame);
+    frame
+}
+
+fn read_cpr_frame(frame: &[u8; 14]) -> CprFrame {
+    CprFrame {
+        odd: get_bits(frame, 53, 1) != 0,
+        lat_cpr: get_bits(frame, 54, 17) as u32,
+        lon_cpr: get_bits(frame, 71, 17) as u32,
+    }
+}
+
+/// Decoded barometric altitude, in feet, from an airborne position frame.
+pub fn decode_altitude_ft(frame: &[u8; 14]) -> f64 {
+    let ac12 = get_bits(frame, 40, 12) as u32;
+    let n = ((ac12 & 0xFE0) >> 1) | (ac12 & 0xF);
+    n as f64 * 25.0 - 1000.0
+}
+
+/// Globally unambiguous CPR position decode from one even frame and one
+/// odd frame of the same aircraft, assumed to be the two most recently
+/// received position reports. Returns `None` if either frame fails its
+/// CRC or isn't an airborne position message, or if the pair straddles a
+/// longitude-zone-count boundary ([`nl`] disagrees between the two
+/// latitudes) so the pairing can't be resolved.
+pub fn decode_airborne_position_global(
+    even_frame: &[u8; 14],
+    odd_frame: &[u8; 14],
+) -> Option<(f64, f64)> {
+    if !verify_crc(even_frame) || !verify_crc(odd_frame) {
+        return None;
+    }
+    let even = read_cpr_frame(even_frame);
+    let odd = read_cpr_frame(odd_frame);
+    if even.odd || !odd.odd {
+        return None;
+    }
+
+    let nz = 15.0_f64;
+    let lat_cpr_even = even.lat_cpr as f64 / CPR_RESOLUTION;
+    let lat_cpr_odd = odd.lat_cpr as f64 / CPR_RESOLUTION;
+
+    let j = (59.0 * lat_cpr_even - 60.0 * lat_cpr_odd + 0.5).floor();
+    let d_lat_even = 360.0 / (4.0 * nz);
+    let d_lat_odd = 360.0 / (4.0 * nz - 1.0);
+    let lat_even = d_lat_even * (rem_positive(j, 60.0) + lat_cpr_even);
+    let lat_odd = d_lat_odd * (rem_positive(j, 59.0) + lat_cpr_odd);
+
+    let nl_even = nl(lat_even);
+    let nl_odd = nl(lat_odd);
+    if nl_even != nl_odd {
+        return None;
+    }
+
+    let lat = normalize_lat(lat_even);
+    let lon_cpr_even = even.lon_cpr as f64 / CPR_RESOLUTION;
+    let lon_cpr_odd = odd.lon_cpr as f64 / CPR_RESOLUTION;
+
+    let m = (lon_cpr_even * (nl_even as f64 - 1.0) - lon_cpr_odd * nl_even as f64 + 0.5).floor();
+    let n_odd = nl_even.saturating_sub(1).max(1);
+    let d_lon_odd = 360.0 / n_odd as f64;
+    // Report the position from the odd frame's longitude zone; both
+    // frames agree up to CPR resolution once `nl_even == nl_odd`.
+    let lon = normalize_lon(d_lon_odd * (rem_positive(m, n_odd as f64) + lon_cpr_odd));
+
+    Some((lat, lon))
+}
+
+/// Locally unambiguous CPR position decode from a single frame, using a
+/// known reference position (e.g. the receiver's own location) that's
+/// assumed to be within ~340 nmi of the true position -- the usual case
+/// for decoding one's own nearby traffic without waiting for a frame
+/// pair.
+pub fn decode_airborne_position_local(
+    frame: &[u8; 14],
+    reference_latitude_deg: f64,
+    reference_longitude_deg: f64,
+) -> Option<(f64, f64)> {
+    if !verify_crc(frame) {
+        return None;
+    }
+    let cpr = read_cpr_frame(frame);
+    let nz = 15.0_f64;
+    let d_lat = if cpr.odd {
+        360.0 / (4.0 * nz - 1.0)
+    } else {
+        360.0 / (4.0 * nz)
+    };
+    let lat_cpr = cpr.lat_cpr as f64 / CPR_RESOLUTION;
+
+    let j = (reference_latitude_deg / d_lat).floor()
+        + (0.5 + rem_positive(reference_latitude_deg, d_lat) / d_lat - lat_cpr).floor();
+    let lat = d_lat * (j + lat_cpr);
+
+    let nl_lon = nl(lat).max(1);
+    let n = if cpr.odd {
+        nl_lon.saturating_sub(1).max(1)
+    } else {
+        nl_lon
+    };
+    let d_lon = 360.0 / n as f64;
+    let lon_cpr = cpr.lon_cpr as f64 / CPR_RESOLUTION;
+
+    let m = (reference_longitude_deg / d_lon).floor()
+        + (0.5 + rem_positive(reference_longitude_deg, d_lon) / d_lon - lon_cpr).floor();
+    let lon = d_lon * (m + lon_cpr);
+
+    Some((normalize_lat(lat), normalize_lon(lon)))
+}
+
+fn rem_positive(value: f64, modulus: f64) -> f64 {
+    value.rem_euclid(modulus)
+}
+
+fn normalize_lat(lat: f64) -> f64 {
+    if lat > 90.0 {
+        lat - 360.0
+    } else {
+        lat
+    }
+}
+
+fn normalize_lon(lon: f64) -> f64 {
+    let wrapped = (lon + 180.0).rem_euclid(360.0) - 180.0;
+    wrapped
+}
+
+/// Decoded ground velocity: speed and track over ground, plus vertical
+/// rate, from an ADS-B velocity message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VelocityInfo {
+    pub ground_speed_mps: f64,
+    pub track_deg: f64,
+    pub vertical_rate_mps: f64,
+}
+
+const KNOTS_TO_MPS: f64 = 0.514_444;
+const FPM_TO_MPS: f64 = 0.005_08;
+
+/// Encode a subsonic ground-speed velocity message (ADS-B type code 19,
+/// subtype 1).
+pub fn encode_velocity(
+    icao: IcaoAddress,
+    ew_velocity_mps: f64,
+    ns_velocity_mps: f64,
+    vertical_rate_mps: f64,
+) -> [u8; 14] {
+    let mut frame = new_frame(icao, 19);
+    set_bits(&mut frame, 37, 3, 1); // subtype 1: ground speed, subsonic
+
+    let ew_knots = ew_velocity_mps.abs() / KNOTS_TO_MPS;
+    let ew_raw = (ew_knots.round() as u32 + 1).min(1023);
+    set_bits(&mut frame, 45, 1, (ew_velocity_mps < 0.0) as u64);
+    set_bits(&mut frame, 46, 10, ew_raw as u64);
+
+    let ns_knots = ns_velocity_mps.abs() / KNOTS_TO_MPS;
+    let ns_raw = (ns_knots.round() as u32 + 1).min(1023);
+    set_bits(&mut frame, 56, 1, (ns_velocity_mps < 0.0) as u64);
+    set_bits(&mut frame, 57, 10, ns_raw as u64);
+
+    let vrate_fpm = vertical_rate_mps.abs() / FPM_TO_MPS;
+    let vrate_raw = ((vrate_fpm / 64.0).round() as u32 + 1).min(511);
+    set_bits(&mut frame, 68, 1, 1); // vertical rate source: 1 = barometric
+    set_bits(&mut frame, 69, 1, (vertical_rate_mps < 0.0) as u64);
+    set_bits(&mut frame, 70, 9, vrate_raw as u64);
+
+    finalize_crc(&mut frame);
+    frame
+}
+
+/// Decode a velocity message; `None` if the CRC fails or this isn't a
+/// subsonic-ground-speed (TC 19, subtype 1 or 2) velocity message.
+pub fn decode_velocity(frame: &[u8; 14]) -> Option<VelocityInfo> {
+    if !verify_crc(frame) || decode_type_code(frame) != 19 {
+        return None;
+    }
+    let subtype = get_bits(frame, 37, 3);
+    if subtype != 1 && subtype != 2 {
+        return None;
+    }
+
+    let ew_sign = get_bits(frame, 45, 1);
+    let ew_raw = get_bits(frame, 46, 10);
+    let ew_mps = if ew_raw == 0 {
+        0.0
+    } else {
+        (ew_raw as f64 - 1.0) * KNOTS_TO_MPS
+    };
+    let ew_mps = if ew_sign != 0 { -ew_mps } else { ew_mps };
+
+    let ns_sign = get_bits(frame, 56, 1);
+    let ns_raw = get_bits(frame, 57, 10);
+    let ns_mps = if ns_raw == 0 {
+        0.0
+    } else {
+        (ns_raw as f64 - 1.0) * KNOTS_TO_MPS
+    };
+    let ns_mps = if ns_sign != 0 { -ns_mps } else { ns_mps };
+
+    let vrate_sign = get_bits(frame, 69, 1);
+    let vrate_raw = get_bits(frame, 70, 9);
+    let vrate_mps = if vrate_raw == 0 {
+        0.0
+    } else {
+        (vrate_raw as f64 - 1.0) * 64.0 * FPM_TO_MPS
+    };
+    let vertical_rate_mps = if vrate_sign != 0 {
+        -vrate_mps
+    } else {
+        vrate_mps
+    };
+
+    let ground_speed_mps = ew_mps.hypot(ns_mps);
+    let track_deg = ew_mps.atan2(ns_mps).to_degrees().rem_euclid(360.0);
+
+    Some(VelocityInfo {
+        ground_speed_mps,
+        track_deg,
+        vertical_rate_mps,
+    })
+}
+
+/// The 6-bit ADS-B identification character set (ICAO Annex 10): index 0
+/// is unused, 1-26 are `A`-`Z`, 32 is space (used to pad short
+/// callsigns), 48-57 are `0`-`9`, and the rest are unused filler,
+/// indexed by the raw 6-bit code.
+const CALLSIGN_CHARSET: &[u8; 64] =
+    b"_ABCDEFGHIJKLMNOPQRSTUVWXYZ_____ _______________0123456789______";
+
+fn encode_callsign_char(c: u8) -> u64 {
+    CALLSIGN_CHARSET
+        .iter()
+        .position(|&candidate| candidate == c)
+        .unwrap_or(32) as u64
+}
+
+/// Encode an aircraft identification message (ADS-B type code 4,
+/// "identification, all aircraft"). `callsign` is truncated/space-padded
+/// to 8 characters and uppercased.
+pub fn encode_identification(icao: IcaoAddress, callsign: &str, category: u8) -> [u8; 14] {
+    let mut frame = new_frame(icao, 4);
+    set_bits(&mut frame, 37, 3, (category & 0x7) as u64);
+
+    let upper = callsign.to_ascii_uppercase();
+    let mut chars = upper.bytes().chain(std::iter::repeat(b' '));
+    for i in 0..8 {
+        let c = chars.next().unwrap_or(b' ');
+        set_bits(&mut frame, 40 + i * 6, 6, encode_callsign_char(c));
+    }
+
+    finalize_crc(&mut frame);
+    frame
+}
+
+/// Decode an identification message's callsign (trimmed of trailing
+/// spaces); `None` if the CRC fails or this isn't an identification (TC
+/// 1-4) message.
+pub fn decode_identification(frame: &[u8; 14]) -> Option<String> {
+    if !verify_crc(frame) {
+        return None;
+    }
+    let tc = decode_type_code(frame);
+    if !(1..=4).contains(&tc) {
+        return None;
+    }
+
+    let mut callsign = String::with_capacity(8);
+    for i in 0..8 {
+        let code = get_bits(frame, 40 + i * 6, 6) as usize;
+        callsign.push(CALLSIGN_CHARSET[code] as char);
+    }
+    Some(callsign.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn airborne_position_global_decode_round_trips() {
+        let icao = 0xABCDEF;
+        let lat = 47.6062;
+        let lon = -122.3321;
+
+        let even = encode_airborne_position(icao, 10_000.0, lat, lon, false);
+        let odd = encode_airborne_position(icao, 10_000.0, lat, lon, true);
+
+        let (decoded_lat, decoded_lon) = decode_airborne_position_global(&even, &odd).unwrap();
+        assert!((decoded_lat - lat).abs() < 0.01);
+        assert!((decoded_lon - lon).abs() < 0.01);
+    }
+
+    #[test]
+    fn airborne_position_local_decode_round_trips_near_a_reference() {
+        let icao = 0x123456;
+        let lat = -33.8688;
+        let lon = 151.2093;
+
+        let frame = encode_airborne_position(icao, 5_000.0, lat, lon, false);
+        let (decoded_lat, decoded_lon) =
+            decode_airborne_position_local(&frame, lat + 0.1, lon - 0.1).unwrap();
+
+        assert!((decoded_lat - lat).abs() < 0.01);
+        assert!((decoded_lon - lon).abs() < 0.01);
+    }
+
+    #[test]
+    fn altitude_round_trips_to_the_nearest_25_feet() {
+        let frame = encode_airborne_position(0x1, 37_025.0, 10.0, 10.0, false);
+        assert!((decode_altitude_ft(&frame) - 37_025.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn velocity_round_trips() {
+        let frame = encode_velocity(0x1, -50.0, 120.0, -5.0);
+        let decoded = decode_velocity(&frame).unwrap();
+
+        assert!((decoded.ground_speed_mps - 50.0_f64.hypot(120.0)).abs() < 1.0);
+        assert!(decoded.vertical_rate_mps < 0.0);
+    }
+
+    #[test]
+    fn identification_round_trips() {
+        let frame = encode_identification(0x1, "n123ab", 3);
+        assert_eq!(decode_identification(&frame).unwrap(), "N123AB");
+    }
+
+    #[test]
+    fn a_corrupted_frame_fails_crc_verification() {
+        let mut frame = encode_identification(0x1, "TEST1234", 0);
+        frame[5] ^= 0xFF;
+        assert!(decode_identification(&frame).is_none());
+    }
+
+    #[test]
+    fn decode_icao_reads_back_the_address_used_to_encode() {
+        let frame = encode_airborne_position(0x4B1A2C, 1_000.0, 0.0, 0.0, false);
+        assert_eq!(decode_icao(&frame), 0x4B1A2C);
+    }
+}
@@ -166,6 +166,24 @@ pub struct RayHit {
     pub object_id: String,
 }
 
+/// A single discovered specular multipath RF propagation path between a
+/// transmitter and a receiver, as produced by
+/// [`SimulationBackend::trace_rf_paths`].
+#[derive(Debug, Clone)]
+pub struct RfPath {
+    /// Ordered points the path passes through after the transmitter: each
+    /// reflection point, followed by the receiver.
+    pub hit_points: Vec<Position>,
+    /// Total path length in meters (transmitter to receiver, via all hops).
+    pub total_distance: f64,
+    /// Total loss in dB: free-space path loss over `total_distance` plus
+    /// the accumulated per-bounce reflection loss.
+    pub total_loss_db: f64,
+    /// Number of specular reflections along this path (0 = direct line of
+    /// sight).
+    pub num_bounces: u32,
+}
+
 /// Main simulation backend trait
 #[async_trait]
 pub trait SimulationBackend: Send + Sync {
@@ -207,6 +225,21 @@ pub trait SimulationBackend: Send + Sync {
     /// Cast multiple rays (batch operation)
     fn cast_rays(&self, scene: &SceneHandle, rays: &[Ray]) -> SimResult<Vec<Option<RayHit>>>;
 
+    /// Trace multi-bounce specular multipath RF propagation paths between a
+    /// transmitter and a receiver at the given frequency, up to
+    /// `max_bounces` reflections. Each returned path carries its total
+    /// length, total loss in dB (free-space path loss plus per-bounce
+    /// reflection loss), and its ordered hit points, so callers can derive
+    /// received signal strength and delay spread.
+    fn trace_rf_paths(
+        &self,
+        scene: &SceneHandle,
+        tx_pos: Position,
+        rx_pos: Position,
+        frequency_hz: f64,
+        max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>>;
+
     /// Get all objects in the scene
     fn get_objects(&self, scene: &SceneHandle) -> SimResult<Vec<SceneObject>>;
 
@@ -241,6 +274,19 @@ pub trait SimulationBackend: Send + Sync {
         vehicle_id: &str,
         sensor_id: &str,
     ) -> SimResult<crate::sensor::SensorData>;
+
+    /// Inject or clear a fault on a single sensor, for testing how the rest
+    /// of the stack handles degraded or missing sensor data (e.g.
+    /// deliberately failing the GPS on one drone in a swarm). Passing `None`
+    /// clears any fault and restores healthy readings. Backends that don't
+    /// track per-vehicle sensor specs return an error instead of silently
+    /// ignoring the request.
+    fn set_sensor_fault(
+        &mut self,
+        vehicle_id: &str,
+        sensor_id: &str,
+        fault: Option<crate::vehicle::SensorFault>,
+    ) -> SimResult<()>;
 }
 
 /// Backend configuration
@@ -252,6 +298,10 @@ pub struct BackendConfig {
     pub enable_rendering: bool,
     pub parallel_processing: bool,
     pub num_threads: Option<usize>,
+    /// Rays per chunk when a backend parallelizes `cast_rays` across a
+    /// thread pool (e.g. [`crate::native::NativeBackend`]). `None` lets the
+    /// backend pick a chunk size automatically.
+    pub ray_cast_chunk_size: Option<usize>,
     pub custom_config: serde_json::Value,
 }
 
@@ -264,6 +314,7 @@ impl Default for BackendConfig {
             enable_rendering: false,
             parallel_processing: true,
             num_threads: None,
+            ray_cast_chunk_size: None,
             custom_config: serde_json::Value::Null,
         }
     }
@@ -282,6 +333,8 @@ pub enum BackendType {
     MuJoCo,
     /// NVIDIA Warp
     Warp,
+    /// Pure-Rust `wgpu` compute backend (Vulkan/Metal/DX12, no Python/CUDA)
+    Wgpu,
 }
 
 impl BackendType {
@@ -292,6 +345,7 @@ impl BackendType {
             BackendType::IsaacLab => "isaac_lab",
             BackendType::MuJoCo => "mujoco",
             BackendType::Warp => "warp",
+            BackendType::Wgpu => "wgpu",
         }
     }
 }
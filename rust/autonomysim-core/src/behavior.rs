@@ -0,0 +1,215 @@
+//! Generic per-robot lifecycle state machine
+//!
+//! `RobotConfig` (in the swarm demos) carries a static `role` but no notion
+//! of a robot being "turned off", "forming up", "flocking", or "returning"
+//! -- every robot just runs one hard-coded path from spawn to target. This
+//! module gives mission logic a reusable, declarative alternative: a
+//! [`StateMachine`] holding one of the named [`RobotState`] variants, plus
+//! caller-registered guarded transitions and per-state actions, both
+//! evaluated from a [`StateMachineContext`] snapshot the caller builds each
+//! step (battery, health, jamming). A guard and action are closures rather
+//! than a trait object here, unlike e.g. `autonomysim_tactical::jamming`'s
+//! [`crate::sensor`] stimuli: each state/transition belongs to exactly one
+//! `StateMachine` instance and is wired up once at construction time, so
+//! there's no need for a caller to hand in a reusable, named `impl` type.
+
+use std::collections::HashMap;
+
+/// A robot's current lifecycle state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RobotState {
+    /// Not yet spawned into the simulation, or grounded after a mission abort.
+    TurnedOff,
+    /// Climbing/spinning up to its operating altitude or speed.
+    TakeOff,
+    /// Cruising under decentralized Lennard-Jones flocking toward `target`.
+    Flocking,
+    /// Converging onto an assigned formation slot.
+    Formation,
+    /// Falling back toward a recovery point instead of its mission target.
+    Return,
+}
+
+impl RobotState {
+    /// Stable name for telemetry/`print_status`, independent of `Debug`'s
+    /// formatting.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RobotState::TurnedOff => "TurnedOff",
+            RobotState::TakeOff => "TakeOff",
+            RobotState::Flocking => "Flocking",
+            RobotState::Formation => "Formation",
+            RobotState::Return => "Return",
+        }
+    }
+}
+
+/// Snapshot of the values [`StateMachine`] guards and actions read each
+/// step; the caller is responsible for keeping it current (e.g. from
+/// `RobotDamageState`/`JammingModel`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StateMachineContext {
+    /// Remaining battery, `0.0`-`1.0`.
+    pub battery_fraction: f64,
+    /// Remaining structural health, `0.0`-`1.0`.
+    pub health_fraction: f64,
+    /// Whether this robot's link is currently jammed.
+    pub is_jammed: bool,
+}
+
+type Guard = Box<dyn Fn(&StateMachineContext) -> bool + Send>;
+type Action = Box<dyn FnMut(&StateMachineContext) + Send>;
+
+/// One robot's FSM: a current [`RobotState`] plus caller-registered
+/// transitions and per-state actions.
+pub struct StateMachine {
+    state: RobotState,
+    transitions: Vec<(RobotState, RobotState, Guard)>,
+    actions: HashMap<RobotState, Action>,
+}
+
+impl StateMachine {
+    pub fn new(initial: RobotState) -> Self {
+        Self {
+            state: initial,
+            transitions: Vec::new(),
+            actions: HashMap::new(),
+        }
+    }
+
+    pub fn state(&self) -> RobotState {
+        self.state
+    }
+
+    /// Stable name of the current state, for telemetry/`print_status`.
+    pub fn state_name(&self) -> &'static str {
+        self.state.name()
+    }
+
+    /// Register a `from -> to` transition, taken the first time `guard`
+    /// returns `true` while the machine is in `from`. Transitions are
+    /// checked in registration order; only the first matching one per
+    /// `step` fires.
+    pub fn add_transition(
+        &mut self,
+        from: RobotState,
+        to: RobotState,
+        guard: impl Fn(&StateMachineContext) -> bool + Send + 'static,
+    ) {
+        self.transitions.push((from, to, Box::new(guard)));
+    }
+
+    /// Register (replacing any prior) the action invoked every `step` while
+    /// the machine is in `state`, after transitions for this step have been
+    /// evaluated.
+    pub fn set_action(
+        &mut self,
+        state: RobotState,
+        action: impl FnMut(&StateMachineContext) + Send + 'static,
+    ) {
+        self.actions.insert(state, Box::new(action));
+    }
+
+    /// Advance one step: take the first matching transition out of the
+    /// current state (if any), then run that (possibly new) state's action.
+    pub fn step(&mut self, ctx: &StateMachineContext) {
+        if let Some(to) = self
+            .transitions
+            .iter()
+            .find(|(from, _, guard)| *from == self.state && guard(ctx))
+            .map(|(_, to, _)| *to)
+        {
+            self.state = to;
+        }
+
+        if let Some(action) = self.actions.get_mut(&self.state) {
+            action(ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_in_initial_state() {
+        let fsm = StateMachine::new(RobotState::TurnedOff);
+        assert_eq!(fsm.state(), RobotState::TurnedOff);
+        assert_eq!(fsm.state_name(), "TurnedOff");
+    }
+
+    #[test]
+    fn transition_fires_when_guard_passes() {
+        let mut fsm = StateMachine::new(RobotState::TakeOff);
+        fsm.add_transition(RobotState::TakeOff, RobotState::Flocking, |_| true);
+
+        fsm.step(&StateMachineContext::default());
+
+        assert_eq!(fsm.state(), RobotState::Flocking);
+    }
+
+    #[test]
+    fn transition_does_not_fire_when_guard_fails() {
+        let mut fsm = StateMachine::new(RobotState::Flocking);
+        fsm.add_transition(RobotState::Flocking, RobotState::Return, |ctx| {
+            ctx.battery_fraction < 0.2
+        });
+
+        fsm.step(&StateMachineContext {
+            battery_fraction: 0.8,
+            ..Default::default()
+        });
+
+        assert_eq!(fsm.state(), RobotState::Flocking);
+    }
+
+    #[test]
+    fn low_battery_guard_transitions_to_return() {
+        let mut fsm = StateMachine::new(RobotState::Flocking);
+        fsm.add_transition(RobotState::Flocking, RobotState::Return, |ctx| {
+            ctx.battery_fraction < 0.2
+        });
+
+        fsm.step(&StateMachineContext {
+            battery_fraction: 0.1,
+            ..Default::default()
+        });
+
+        assert_eq!(fsm.state(), RobotState::Return);
+    }
+
+    #[test]
+    fn action_runs_for_current_state_after_transition() {
+        let mut fsm = StateMachine::new(RobotState::TakeOff);
+        fsm.add_transition(RobotState::TakeOff, RobotState::Flocking, |_| true);
+
+        let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log_clone = log.clone();
+        fsm.set_action(RobotState::Flocking, move |ctx| {
+            log_clone.lock().unwrap().push(ctx.is_jammed);
+        });
+
+        fsm.step(&StateMachineContext {
+            is_jammed: true,
+            ..Default::default()
+        });
+
+        assert_eq!(*log.lock().unwrap(), vec![true]);
+    }
+
+    #[test]
+    fn jamming_guard_drops_combat_to_return() {
+        let mut fsm = StateMachine::new(RobotState::Formation);
+        fsm.add_transition(RobotState::Formation, RobotState::Return, |ctx| {
+            ctx.is_jammed || ctx.health_fraction < 0.3
+        });
+
+        fsm.step(&StateMachineContext {
+            is_jammed: true,
+            ..Default::default()
+        });
+
+        assert_eq!(fsm.state(), RobotState::Return);
+    }
+}
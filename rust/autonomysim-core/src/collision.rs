@@ -0,0 +1,226 @@
+//! Continuous (swept) collision detection
+//!
+//! `CollisionInfo` as reported by a backend only reflects contact *after*
+//! penetration has already happened, which lets fast-moving agents tunnel
+//! through thin geometry between steps. This module adds a swept ray test
+//! for the physics step: when an agent's per-step displacement is large
+//! relative to its bounding size, a ray is cast from its previous to its
+//! candidate position and, on an early hit, the agent is clamped to the
+//! contact point instead of its intended destination.
+
+use crate::backend::{Position, Ray, SceneHandle, SimulationBackend, Vec3};
+use crate::vehicle::CollisionInfo;
+use serde::{Deserialize, Serialize};
+
+/// Tunables for the swept collision pass.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SweepConfig {
+    /// Only sweep when `displacement >= bounding_radius * threshold`; below
+    /// this the discrete per-step contact the backend already reports is
+    /// enough.
+    pub threshold: f64,
+    /// Number of steps to keep pushing the agent out along the contact
+    /// normal after a collision, so it cleanly separates instead of
+    /// sticking to the surface.
+    pub depenetration_frames: u32,
+    /// Speed (units/sec) applied along the contact normal during the
+    /// depenetration window.
+    pub depenetration_speed: f64,
+}
+
+impl Default for SweepConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            depenetration_frames: 3,
+            depenetration_speed: 0.5,
+        }
+    }
+}
+
+/// Per-agent state carried across steps for the post-collision
+/// depenetration window.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DepenetrationState {
+    frames_remaining: u32,
+    normal: Vec3,
+}
+
+impl DepenetrationState {
+    pub fn is_active(&self) -> bool {
+        self.frames_remaining > 0
+    }
+}
+
+/// Outcome of a swept collision test: the agent should stop at `position`
+/// with `velocity` (into-surface component removed) instead of reaching
+/// its originally intended candidate position.
+#[derive(Debug, Clone)]
+pub struct SweepResult {
+    pub position: Position,
+    pub velocity: Vec3,
+    pub collision_info: CollisionInfo,
+}
+
+/// Cast a ray from `previous` to `candidate` and, if it's a large enough
+/// displacement relative to `bounding_radius` and hits geometry before the
+/// full motion completes, clamp the agent to the contact point and zero
+/// the velocity component driving it into the surface.
+///
+/// Returns `None` when the displacement is too small to warrant a sweep or
+/// no geometry is hit along the way, meaning the agent may simply move to
+/// `candidate` as usual.
+pub fn sweep_collision(
+    backend: &dyn SimulationBackend,
+    scene: &SceneHandle,
+    previous: Position,
+    candidate: Position,
+    velocity: Vec3,
+    bounding_radius: f64,
+    config: &SweepConfig,
+) -> Option<SweepResult> {
+    let displacement = candidate - previous;
+    let distance = displacement.norm();
+    if distance < bounding_radius * config.threshold || distance == 0.0 {
+        return None;
+    }
+
+    let direction = displacement / distance;
+    let ray = Ray {
+        origin: previous,
+        direction,
+        max_distance: distance,
+    };
+
+    let hit = backend.cast_ray(scene, &ray).ok().flatten()?;
+
+    let contact_distance = (hit.distance - bounding_radius).max(0.0);
+    let clamped_position = previous + direction * contact_distance;
+
+    let into_surface = velocity.dot(&hit.normal);
+    let resolved_velocity = if into_surface < 0.0 {
+        velocity - hit.normal * into_surface
+    } else {
+        velocity
+    };
+
+    Some(SweepResult {
+        position: clamped_position,
+        velocity: resolved_velocity,
+        collision_info: CollisionInfo {
+            has_collided: true,
+            collision_count: 1,
+            impact_point: hit.position,
+            impact_normal: hit.normal,
+            impact_force: velocity * into_surface.abs(),
+            penetration_depth: (distance - hit.distance).max(0.0),
+        },
+    })
+}
+
+/// Begin (or restart) the post-collision depenetration window along
+/// `normal`.
+pub fn start_depenetration(state: &mut DepenetrationState, normal: Vec3, config: &SweepConfig) {
+    state.frames_remaining = config.depenetration_frames;
+    state.normal = normal;
+}
+
+/// Push displacement to apply this step while depenetrating, or `None`
+/// once the window has elapsed (the caller should stop calling this and
+/// resume normal integration).
+pub fn depenetration_step(
+    state: &mut DepenetrationState,
+    dt: f64,
+    config: &SweepConfig,
+) -> Option<Vec3> {
+    if state.frames_remaining == 0 {
+        return None;
+    }
+    state.frames_remaining -= 1;
+    Some(state.normal * config.depenetration_speed * dt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{BackendConfig, BackendType, Geometry, Material, SceneObject, Transform};
+    use crate::native::NativeBackend;
+    use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+    async fn scene_with_wall() -> (NativeBackend, SceneHandle) {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.obj").await.unwrap();
+        backend
+            .add_object(
+                &scene,
+                SceneObject {
+                    id: "wall".to_string(),
+                    name: "wall".to_string(),
+                    geometry: Geometry::Sphere { radius: 0.1 },
+                    material: Material::air(),
+                    transform: Transform::new(Point3::new(5.0, 0.0, 0.0), UnitQuaternion::identity()),
+                },
+            )
+            .unwrap();
+        (backend, scene)
+    }
+
+    #[tokio::test]
+    async fn fast_agent_is_stopped_before_tunneling_through_geometry() {
+        let (backend, scene) = scene_with_wall().await;
+        let previous = Point3::new(0.0, 0.0, 0.0);
+        let candidate = Point3::new(10.0, 0.0, 0.0); // would tunnel straight past the wall
+        let velocity = Vector3::new(20.0, 0.0, 0.0);
+
+        let result = sweep_collision(
+            &backend,
+            &scene,
+            previous,
+            candidate,
+            velocity,
+            0.2,
+            &SweepConfig::default(),
+        );
+
+        let result = result.expect("fast displacement through geometry should be caught");
+        assert!(result.collision_info.has_collided);
+        assert!(result.position.x < 5.0);
+        assert!(result.velocity.x <= 0.0 + 1e-9);
+    }
+
+    #[tokio::test]
+    async fn slow_displacement_is_not_swept() {
+        let (backend, scene) = scene_with_wall().await;
+        let previous = Point3::new(0.0, 0.0, 0.0);
+        let candidate = Point3::new(0.05, 0.0, 0.0);
+        let velocity = Vector3::new(0.5, 0.0, 0.0);
+
+        let result = sweep_collision(
+            &backend,
+            &scene,
+            previous,
+            candidate,
+            velocity,
+            0.2,
+            &SweepConfig::default(),
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn depenetration_window_expires_after_configured_frames() {
+        let config = SweepConfig {
+            depenetration_frames: 2,
+            ..Default::default()
+        };
+        let mut state = DepenetrationState::default();
+        start_depenetration(&mut state, Vector3::new(0.0, 0.0, 1.0), &config);
+
+        assert!(depenetration_step(&mut state, 0.1, &config).is_some());
+        assert!(depenetration_step(&mut state, 0.1, &config).is_some());
+        assert!(depenetration_step(&mut state, 0.1, &config).is_none());
+        assert!(!state.is_active());
+    }
+}
@@ -9,7 +9,11 @@
 //! - Analog-like stick input with expo curves
 
 use crate::backend::{Position, Rotation, Transform, Vec3};
+use crate::sensor::ImuData;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 // ─── Flight Mode ─────────────────────────────────────────────────────────────
 
@@ -28,6 +32,13 @@ pub enum FpvFlightMode {
     /// Hybrid — behaves like Angle near center, Acro at extremes.
     /// Good for learning FPV.
     Horizon,
+
+    /// ArduPilot-Drift-style single-stick cruising: pitch commands
+    /// forward/back speed, roll banks the craft into a turn, and yaw is
+    /// auto-coordinated from the bank angle and body-frame velocity so the
+    /// drone carves a car-like turn without separate yaw-stick input. Suits
+    /// cinematic and long-range flying more than racing/freestyle.
+    Drift,
 }
 
 impl Default for FpvFlightMode {
@@ -192,6 +203,54 @@ impl RatesProfile {
         }
         (lo + hi) / 2.0
     }
+
+    /// Approximate inverse of `roll_rate`: given a desired roll rate
+    /// (deg/s), find the stick value in [-1.0, 1.0] that produces it.
+    /// `roll_rate` is monotonic over the stick range, so a binary search
+    /// converges the same way `throttle_curve_inv` does.
+    pub fn roll_rate_inv(&self, target_deg_s: f64) -> f64 {
+        let mut lo = -1.0_f64;
+        let mut hi = 1.0_f64;
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            if self.roll_rate(mid) < target_deg_s {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+
+    /// Approximate inverse of `pitch_rate`; see [`Self::roll_rate_inv`].
+    pub fn pitch_rate_inv(&self, target_deg_s: f64) -> f64 {
+        let mut lo = -1.0_f64;
+        let mut hi = 1.0_f64;
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            if self.pitch_rate(mid) < target_deg_s {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+
+    /// Approximate inverse of `yaw_rate`; see [`Self::roll_rate_inv`].
+    pub fn yaw_rate_inv(&self, target_deg_s: f64) -> f64 {
+        let mut lo = -1.0_f64;
+        let mut hi = 1.0_f64;
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            if self.yaw_rate(mid) < target_deg_s {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (lo + hi) / 2.0
+    }
 }
 
 // ─── FPV Camera ──────────────────────────────────────────────────────────────
@@ -303,6 +362,10 @@ pub struct FpvDroneConfig {
     /// Number of motors (4 = quad, 6 = hex)
     pub motor_count: u32,
 
+    /// Airframe layout used to build the [`MotorMixer`]; its motor count
+    /// should agree with `motor_count` above.
+    pub frame: FrameType,
+
     /// Motor KV rating
     pub motor_kv: u32,
 
@@ -342,6 +405,40 @@ pub struct FpvDroneConfig {
 
     /// Moment of inertia (kg·m²)
     pub inertia: Vec3,
+
+    /// First-order motor spin-up/spin-down time constant (seconds). Snappy
+    /// race builds respond in a few milliseconds; heavier long-range props
+    /// take longer to change speed.
+    pub motor_time_constant_s: f64,
+
+    /// Synthetic IMU bias/noise/sample-rate configuration.
+    pub imu: ImuConfig,
+
+    /// Throttle fraction above which TPA (throttle-PID-attenuation) begins
+    /// scaling P and D down, to suppress high-throttle oscillation.
+    pub tpa_breakpoint: f64,
+    /// Maximum P/D attenuation at full throttle (roughly `[0, 1]`).
+    pub tpa_rate: f64,
+    /// How strongly a throttle punch transiently boosts I-term gain
+    /// ("anti-gravity"), holding attitude during fast throttle changes.
+    pub anti_gravity_gain: f64,
+    /// Setpoint rate-of-change (deg/s) above which I-term accumulation is
+    /// attenuated ("I-term relax"), preventing integrator bounce-back on
+    /// fast stick moves.
+    pub i_term_relax_threshold_deg_s: f64,
+    /// Low-pass filter time constant (seconds) applied to the gyro
+    /// measurement before differentiating for the D term.
+    pub d_term_lowpass_tau_s: f64,
+
+    /// Mahony complementary-filter gains used to fuse synthetic IMU
+    /// readings into the attitude estimate that Angle/Horizon self-leveling
+    /// fly on, rather than the ground-truth orientation.
+    pub mahony: MahonyConfig,
+
+    /// PRNG seed for wind turbulence and IMU noise, so [`FpvPhysics`]
+    /// rollouts are reproducible (e.g. for RL training; see
+    /// [`crate::rl_env`]).
+    pub seed: u64,
 }
 
 impl Default for FpvDroneConfig {
@@ -359,6 +456,7 @@ impl FpvDroneConfig {
             weight_grams: 650.0,
             max_thrust_per_motor_grams: 1200.0,
             motor_count: 4,
+            frame: FrameType::QuadX,
             motor_kv: 2400,
             prop_size_inches: 5.0,
             battery_cells: 6,
@@ -373,6 +471,15 @@ impl FpvDroneConfig {
             default_mode: FpvFlightMode::Acro,
             drag_coefficient: 0.25,
             inertia: Vec3::new(0.003, 0.003, 0.005),
+            motor_time_constant_s: 0.015,
+            imu: ImuConfig::default(),
+            tpa_breakpoint: 0.55,
+            tpa_rate: 0.25,
+            anti_gravity_gain: 4.0,
+            i_term_relax_threshold_deg_s: 40.0,
+            d_term_lowpass_tau_s: 0.004,
+            mahony: MahonyConfig::default(),
+            seed: 42,
         }
     }
 
@@ -384,6 +491,7 @@ impl FpvDroneConfig {
             weight_grams: 750.0,
             max_thrust_per_motor_grams: 1400.0,
             motor_count: 4,
+            frame: FrameType::QuadX,
             motor_kv: 1900,
             prop_size_inches: 5.0,
             battery_cells: 6,
@@ -398,6 +506,15 @@ impl FpvDroneConfig {
             default_mode: FpvFlightMode::Acro,
             drag_coefficient: 0.30,
             inertia: Vec3::new(0.004, 0.004, 0.006),
+            motor_time_constant_s: 0.02,
+            imu: ImuConfig::default(),
+            tpa_breakpoint: 0.5,
+            tpa_rate: 0.3,
+            anti_gravity_gain: 3.5,
+            i_term_relax_threshold_deg_s: 35.0,
+            d_term_lowpass_tau_s: 0.006,
+            mahony: MahonyConfig::default(),
+            seed: 42,
         }
     }
 
@@ -409,6 +526,7 @@ impl FpvDroneConfig {
             weight_grams: 200.0,
             max_thrust_per_motor_grams: 400.0,
             motor_count: 4,
+            frame: FrameType::QuadX,
             motor_kv: 3600,
             prop_size_inches: 3.0,
             battery_cells: 4,
@@ -419,18 +537,21 @@ impl FpvDroneConfig {
                 i: 75.0,
                 d: 30.0,
                 f: 100.0,
+                i_limit: 13.3,
             },
             pid_pitch: PidGains {
                 p: 58.0,
                 i: 78.0,
                 d: 32.0,
                 f: 105.0,
+                i_limit: 12.8,
             },
             pid_yaw: PidGains {
                 p: 60.0,
                 i: 80.0,
                 d: 0.0,
                 f: 90.0,
+                i_limit: 12.5,
             },
             max_angle_deg: 55.0,
             camera: FpvCameraConfig::racing(),
@@ -438,6 +559,15 @@ impl FpvDroneConfig {
             default_mode: FpvFlightMode::Acro,
             drag_coefficient: 0.20,
             inertia: Vec3::new(0.0008, 0.0008, 0.0015),
+            motor_time_constant_s: 0.008,
+            imu: ImuConfig::default(),
+            tpa_breakpoint: 0.6,
+            tpa_rate: 0.2,
+            anti_gravity_gain: 4.5,
+            i_term_relax_threshold_deg_s: 45.0,
+            d_term_lowpass_tau_s: 0.003,
+            mahony: MahonyConfig::default(),
+            seed: 42,
         }
     }
 
@@ -449,6 +579,7 @@ impl FpvDroneConfig {
             weight_grams: 900.0,
             max_thrust_per_motor_grams: 1600.0,
             motor_count: 4,
+            frame: FrameType::QuadX,
             motor_kv: 1500,
             prop_size_inches: 7.0,
             battery_cells: 6,
@@ -463,6 +594,15 @@ impl FpvDroneConfig {
             default_mode: FpvFlightMode::Angle,
             drag_coefficient: 0.35,
             inertia: Vec3::new(0.006, 0.006, 0.010),
+            motor_time_constant_s: 0.04,
+            imu: ImuConfig::default(),
+            tpa_breakpoint: 0.45,
+            tpa_rate: 0.35,
+            anti_gravity_gain: 2.5,
+            i_term_relax_threshold_deg_s: 25.0,
+            d_term_lowpass_tau_s: 0.010,
+            mahony: MahonyConfig::default(),
+            seed: 42,
         }
     }
 
@@ -488,6 +628,715 @@ impl FpvDroneConfig {
         let hover_fraction = self.mass_kg() * 9.81 / self.max_thrust_n();
         self.rates.throttle_curve_inv(hover_fraction)
     }
+
+    /// Per-motor thrust/torque/lag parameters at sea-level air density.
+    pub fn rotor_params(&self) -> RotorParams {
+        RotorParams::from_config(self, SEA_LEVEL_AIR_DENSITY_KG_M3)
+    }
+}
+
+// ─── Motor Mixing & Rotor Dynamics ───────────────────────────────────────────
+
+/// Standard air density at sea level (kg/m³), the reference point for
+/// [`RotorParams::from_config`]'s air-density scaling.
+const SEA_LEVEL_AIR_DENSITY_KG_M3: f64 = 1.225;
+
+/// Typical reaction-torque-to-thrust ratio per meter of prop diameter for
+/// small FPV propellers (i.e. how much of a motor's thrust shows up as drag
+/// torque about its own spin axis).
+const PROP_TORQUE_THRUST_RATIO: f64 = 0.02;
+
+/// Per-motor roll/pitch lever-arm signs and spin direction for a standard
+/// Betaflight QuadX layout, indexed as
+/// `[rear-right, front-right, rear-left, front-left]`.
+/// Spin direction: `+1.0` = CW, `-1.0` = CCW (diagonal pairs spin the same way).
+const QUAD_X_ROLL_SIGN: [f64; 4] = [-1.0, -1.0, 1.0, 1.0];
+const QUAD_X_PITCH_SIGN: [f64; 4] = [1.0, -1.0, 1.0, -1.0];
+const QUAD_X_YAW_SIGN: [f64; 4] = [-1.0, 1.0, 1.0, -1.0];
+const QUAD_X_SPIN_DIRECTION: [f64; 4] = [1.0, -1.0, -1.0, 1.0];
+
+/// Motor RPM at full throttle (`motor_outputs[i] == 1.0`), used both to
+/// report [`FpvState::motor_rpms`] and to derive each motor's vibration
+/// frequency for the synthetic gyro (`motor_outputs[i] * MAX_MOTOR_RPM / 60`
+/// Hz).
+const MAX_MOTOR_RPM: f64 = 25000.0;
+
+/// Physical rotor characteristics derived from a [`FpvDroneConfig`], used by
+/// the per-motor thrust/torque model in [`FpvPhysics::step`].
+#[derive(Debug, Clone, Copy)]
+pub struct RotorParams {
+    /// Thrust produced by a single motor at full commanded speed (Newtons).
+    pub max_thrust_n: f64,
+    /// Reaction torque about a motor's own spin axis at full commanded speed (N·m).
+    pub max_reaction_torque_nm: f64,
+    /// First-order spin-up/spin-down time constant (seconds).
+    pub time_constant_s: f64,
+}
+
+impl RotorParams {
+    /// Derive rotor parameters from a drone config at the given air density
+    /// (kg/m³; 1.225 at sea level — see [`SEA_LEVEL_AIR_DENSITY_KG_M3`]).
+    pub fn from_config(config: &FpvDroneConfig, air_density: f64) -> Self {
+        let max_thrust_n = config.max_thrust_per_motor_grams / 1000.0
+            * 9.81
+            * (air_density / SEA_LEVEL_AIR_DENSITY_KG_M3);
+        let diameter_m = config.prop_size_inches * 0.0254;
+        let max_reaction_torque_nm = max_thrust_n * diameter_m * PROP_TORQUE_THRUST_RATIO;
+        Self {
+            max_thrust_n,
+            max_reaction_torque_nm,
+            time_constant_s: config.motor_time_constant_s,
+        }
+    }
+}
+
+/// Mix throttle/roll/pitch/yaw commands (roll/pitch/yaw roughly in
+/// `[-1, 1]`, throttle in `[0, 1]`) into four normalized motor commands
+/// using the standard Betaflight QuadX mixing table, then clamp each to
+/// `[0, 1]`.
+pub fn mix_quad_x(throttle: f64, roll: f64, pitch: f64, yaw: f64) -> [f64; 4] {
+    let mut motors = [0.0; 4];
+    for i in 0..4 {
+        motors[i] = throttle
+            + QUAD_X_ROLL_SIGN[i] * roll
+            + QUAD_X_PITCH_SIGN[i] * pitch
+            + QUAD_X_YAW_SIGN[i] * yaw;
+    }
+    for m in &mut motors {
+        *m = m.clamp(0.0, 1.0);
+    }
+    motors
+}
+
+/// Airframe layout. Chooses both the motor count and the mixing table for a
+/// [`MotorMixer`] together, so the two can never disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameType {
+    /// Four motors in the standard Betaflight QuadX layout.
+    QuadX,
+    /// Six motors evenly spaced around the frame.
+    Hex,
+    /// Eight motors evenly spaced around the frame (coaxial quad).
+    OctaQuad,
+    /// Three main rotors for throttle/roll/pitch plus a tail servo for yaw,
+    /// instead of a fourth rotor.
+    Tri,
+}
+
+impl FrameType {
+    /// Number of main rotors this layout drives (the tricopter's tail servo
+    /// is not counted — see [`MixerOutput::tail_servo_angle`]).
+    pub fn motor_count(&self) -> usize {
+        match self {
+            FrameType::QuadX => 4,
+            FrameType::Hex => 6,
+            FrameType::OctaQuad => 8,
+            FrameType::Tri => 3,
+        }
+    }
+}
+
+/// One motor's row in a [`MotorMixer`]'s mixing table: how much of each
+/// demand channel drives that motor.
+#[derive(Debug, Clone, Copy)]
+struct MixerRow {
+    throttle: f64,
+    roll: f64,
+    pitch: f64,
+    yaw: f64,
+}
+
+fn quad_x_rows() -> Vec<MixerRow> {
+    (0..4)
+        .map(|i| MixerRow {
+            throttle: 1.0,
+            roll: QUAD_X_ROLL_SIGN[i],
+            pitch: QUAD_X_PITCH_SIGN[i],
+            yaw: QUAD_X_YAW_SIGN[i],
+        })
+        .collect()
+}
+
+/// Rotor rows for a frame with `n` motors evenly spaced around the frame,
+/// alternating spin direction (and therefore yaw sign) between neighbors.
+fn symmetric_rotor_rows(n: usize) -> Vec<MixerRow> {
+    (0..n)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * (i as f64) / (n as f64);
+            MixerRow {
+                throttle: 1.0,
+                roll: angle.sin(),
+                pitch: angle.cos(),
+                yaw: if i % 2 == 0 { 1.0 } else { -1.0 },
+            }
+        })
+        .collect()
+}
+
+/// Rotor rows for a tricopter: front-left, front-right, rear. None of them
+/// carry yaw authority — that comes from the tail servo instead (see
+/// [`MotorMixer::tail_servo_yaw_gain`]).
+fn tri_rows() -> Vec<MixerRow> {
+    vec![
+        MixerRow {
+            throttle: 1.0,
+            roll: -1.0,
+            pitch: 1.0,
+            yaw: 0.0,
+        },
+        MixerRow {
+            throttle: 1.0,
+            roll: 1.0,
+            pitch: 1.0,
+            yaw: 0.0,
+        },
+        MixerRow {
+            throttle: 1.0,
+            roll: 0.0,
+            pitch: -1.0,
+            yaw: 0.0,
+        },
+    ]
+}
+
+/// Result of [`MotorMixer::mix`].
+#[derive(Debug, Clone)]
+pub struct MixerOutput {
+    /// Normalized per-motor command in `[0, 1]`, one per main rotor.
+    pub motor_outputs: Vec<f64>,
+    /// Tail-servo angle in `[-1, 1]`; only `Some` for [`FrameType::Tri`],
+    /// which has no yaw authority on its main rotors.
+    pub tail_servo_angle: Option<f64>,
+}
+
+/// Converts a throttle + roll/pitch/yaw demand (the rate PID loop's output,
+/// each attitude channel roughly in `[-1, 1]`) into per-motor outputs via a
+/// per-[`FrameType`] mixing table, the way Betaflight/ArduPilot do.
+///
+/// Unlike [`mix_quad_x`], which clamps each motor independently and so can
+/// silently lose relative motor authority once any one of them saturates,
+/// [`Self::mix`] desaturates ("airmode"): it shifts every output by a
+/// common offset first, and only scales the attitude components down if the
+/// shift alone isn't enough, preserving the commanded attitude at the cost
+/// of a little thrust.
+#[derive(Debug, Clone)]
+pub struct MotorMixer {
+    frame: FrameType,
+    rows: Vec<MixerRow>,
+    /// `Some(gain)` maps the yaw demand to a tail-servo angle instead of a
+    /// fourth/sixth/eighth rotor; only set for [`FrameType::Tri`].
+    tail_servo_yaw_gain: Option<f64>,
+}
+
+impl MotorMixer {
+    /// Build the mixing table for a given airframe layout.
+    pub fn new(frame: FrameType) -> Self {
+        let (rows, tail_servo_yaw_gain) = match frame {
+            FrameType::QuadX => (quad_x_rows(), None),
+            FrameType::Hex => (symmetric_rotor_rows(6), None),
+            FrameType::OctaQuad => (symmetric_rotor_rows(8), None),
+            FrameType::Tri => (tri_rows(), Some(1.0)),
+        };
+        Self {
+            frame,
+            rows,
+            tail_servo_yaw_gain,
+        }
+    }
+
+    /// Airframe layout this mixer was built for.
+    pub fn frame(&self) -> FrameType {
+        self.frame
+    }
+
+    /// Number of main rotors this mixer drives.
+    pub fn motor_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Mix a throttle/roll/pitch/yaw demand into per-motor outputs, applying
+    /// airmode desaturation instead of independent per-motor clamping.
+    pub fn mix(&self, throttle: f64, roll: f64, pitch: f64, yaw: f64) -> MixerOutput {
+        let throttle = throttle.clamp(0.0, 1.0);
+        let roll = roll.clamp(-1.0, 1.0);
+        let pitch = pitch.clamp(-1.0, 1.0);
+        let yaw = yaw.clamp(-1.0, 1.0);
+
+        let mut motor_outputs: Vec<f64> = self
+            .rows
+            .iter()
+            .map(|row| {
+                throttle * row.throttle + roll * row.roll + pitch * row.pitch + yaw * row.yaw
+            })
+            .collect();
+        desaturate(&mut motor_outputs);
+
+        let tail_servo_angle = self
+            .tail_servo_yaw_gain
+            .map(|gain| (yaw * gain).clamp(-1.0, 1.0));
+
+        MixerOutput {
+            motor_outputs,
+            tail_servo_angle,
+        }
+    }
+}
+
+/// Airmode desaturation: shift every output by a common offset so the
+/// highest motor is at most `1.0` and the lowest is at least `0.0`, keeping
+/// the spacing between motors (and therefore attitude authority) intact. If
+/// the spread between motors is itself wider than `[0, 1]` and shifting
+/// alone can't fix it, scale each output's deviation from the mean instead
+/// of clipping it away.
+fn desaturate(outputs: &mut [f64]) {
+    if outputs.is_empty() {
+        return;
+    }
+
+    let max = outputs.iter().cloned().fold(f64::MIN, f64::max);
+    if max > 1.0 {
+        let excess = max - 1.0;
+        for o in outputs.iter_mut() {
+            *o -= excess;
+        }
+    }
+    let min = outputs.iter().cloned().fold(f64::MAX, f64::min);
+    if min < 0.0 {
+        let deficit = -min;
+        for o in outputs.iter_mut() {
+            *o += deficit;
+        }
+    }
+
+    let max = outputs.iter().cloned().fold(f64::MIN, f64::max);
+    let min = outputs.iter().cloned().fold(f64::MAX, f64::min);
+    let spread = max - min;
+    if spread > 1.0 {
+        let mean = outputs.iter().sum::<f64>() / outputs.len() as f64;
+        let scale = 1.0 / spread;
+        for o in outputs.iter_mut() {
+            *o = mean + (*o - mean) * scale;
+        }
+    }
+
+    for o in outputs.iter_mut() {
+        *o = o.clamp(0.0, 1.0);
+    }
+}
+
+// ─── Synthetic IMU ───────────────────────────────────────────────────────────
+
+/// Synthetic IMU configuration: sample rate plus the bias/noise
+/// characteristics layered onto the ground-truth accelerometer and gyro
+/// signal in [`FpvPhysics::step`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImuConfig {
+    /// IMU sample rate (Hz), independent of the physics step rate.
+    pub sample_rate_hz: f64,
+    /// Constant per-axis accelerometer bias (m/s²), before random walk.
+    pub accel_bias: Vec3,
+    /// Constant per-axis gyro bias (rad/s), before random walk.
+    pub gyro_bias: Vec3,
+    /// Accelerometer measurement noise standard deviation (m/s²).
+    pub accel_noise_std: f64,
+    /// Gyro measurement noise standard deviation (rad/s).
+    pub gyro_noise_std: f64,
+    /// Accelerometer bias random-walk standard deviation (m/s² per √s).
+    pub accel_bias_walk_std: f64,
+    /// Gyro bias random-walk standard deviation (rad/s per √s).
+    pub gyro_bias_walk_std: f64,
+    /// Peak per-axis motor-vibration amplitude (rad/s) injected into the
+    /// gyro at 100% average motor output; scaled down linearly with the
+    /// average motor output and summed across each motor's own rotation
+    /// frequency (`motor RPM / 60` Hz). Zero (the default) disables
+    /// vibration injection.
+    pub vibration_amplitude: Vec3,
+}
+
+impl Default for ImuConfig {
+    /// Roughly a mid-grade MEMS IMU (e.g. ICM-42688) sampled at 2kHz.
+    fn default() -> Self {
+        Self {
+            sample_rate_hz: 2000.0,
+            accel_bias: Vec3::zeros(),
+            gyro_bias: Vec3::zeros(),
+            accel_noise_std: 0.05,
+            gyro_noise_std: 0.1_f64.to_radians(),
+            accel_bias_walk_std: 0.001,
+            gyro_bias_walk_std: 0.0005,
+            vibration_amplitude: Vec3::zeros(),
+        }
+    }
+}
+
+// ─── Gyro Vibration Spectrum Analyzer ───────────────────────────────────────
+
+/// Dominant vibration bin found by [`GyroSpectrumAnalyzer::push`] over its
+/// most recent window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GyroSpectrum {
+    /// Frequency (Hz) of the largest non-DC magnitude bin.
+    pub dominant_frequency_hz: f64,
+    /// Magnitude of that bin.
+    pub dominant_magnitude: f64,
+}
+
+/// Accumulates a fixed-size window of gyro samples (one axis) and, once
+/// full, runs a radix-2 FFT to estimate the dominant motor-vibration
+/// frequency -- e.g. to verify a notch/lowpass filter is actually
+/// attenuating it. `window` must be a power of two.
+#[derive(Debug, Clone)]
+pub struct GyroSpectrumAnalyzer {
+    sample_rate_hz: f64,
+    window: usize,
+    buffer: Vec<f64>,
+}
+
+impl GyroSpectrumAnalyzer {
+    pub fn new(sample_rate_hz: f64, window: usize) -> Self {
+        assert!(window.is_power_of_two(), "window must be a power of two");
+        Self {
+            sample_rate_hz,
+            window,
+            buffer: Vec::with_capacity(window),
+        }
+    }
+
+    /// Feed one gyro sample; once `window` samples have accumulated, returns
+    /// the PSD's dominant bin and resets the window for the next batch.
+    pub fn push(&mut self, sample: f64) -> Option<GyroSpectrum> {
+        self.buffer.push(sample);
+        if self.buffer.len() < self.window {
+            return None;
+        }
+
+        let mut re = std::mem::replace(&mut self.buffer, Vec::with_capacity(self.window));
+        let mut im = vec![0.0; re.len()];
+        fft(&mut re, &mut im, false);
+
+        // Skip the DC bin and only scan the first half of the spectrum --
+        // for real-valued input the second half just mirrors it.
+        let mut best_bin = 1;
+        let mut best_magnitude = 0.0;
+        for (bin, (&bin_re, &bin_im)) in re
+            .iter()
+            .zip(im.iter())
+            .enumerate()
+            .take(re.len() / 2)
+            .skip(1)
+        {
+            let magnitude = (bin_re * bin_re + bin_im * bin_im).sqrt();
+            if magnitude > best_magnitude {
+                best_magnitude = magnitude;
+                best_bin = bin;
+            }
+        }
+
+        let bin_hz = self.sample_rate_hz / self.window as f64;
+        Some(GyroSpectrum {
+            dominant_frequency_hz: best_bin as f64 * bin_hz,
+            dominant_magnitude: best_magnitude,
+        })
+    }
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT/IFFT over parallel
+/// real/imaginary buffers; both must be the same power-of-two length.
+fn fft(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = if inverse {
+            2.0 * std::f64::consts::PI / len as f64
+        } else {
+            -2.0 * std::f64::consts::PI / len as f64
+        };
+        let (w_len_re, w_len_im) = (angle.cos(), angle.sin());
+        let mut start = 0;
+        while start < n {
+            let (mut w_re, mut w_im) = (1.0, 0.0);
+            for k in 0..len / 2 {
+                let (u_re, u_im) = (re[start + k], im[start + k]);
+                let (v_re0, v_im0) = (re[start + k + len / 2], im[start + k + len / 2]);
+                let v_re = v_re0 * w_re - v_im0 * w_im;
+                let v_im = v_re0 * w_im + v_im0 * w_re;
+                re[start + k] = u_re + v_re;
+                im[start + k] = u_im + v_im;
+                re[start + k + len / 2] = u_re - v_re;
+                im[start + k + len / 2] = u_im - v_im;
+                let (next_w_re, next_w_im) = (
+                    w_re * w_len_re - w_im * w_len_im,
+                    w_re * w_len_im + w_im * w_len_re,
+                );
+                w_re = next_w_re;
+                w_im = next_w_im;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        for i in 0..n {
+            re[i] /= n as f64;
+            im[i] /= n as f64;
+        }
+    }
+}
+
+// ─── Attitude Estimator ──────────────────────────────────────────────────────
+
+/// Mahony complementary-filter gains for [`AttitudeEstimator`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MahonyConfig {
+    /// Proportional gain on the measured tilt error (rad/s per unit error).
+    /// Higher values trust the accelerometer more and correct gyro drift
+    /// faster, at the cost of more vibration/noise sensitivity.
+    pub kp: f64,
+    /// Integral gain on the accumulated tilt error, which estimates and
+    /// cancels out a steady gyro bias over time.
+    pub ki: f64,
+}
+
+impl Default for MahonyConfig {
+    /// Typical small-quad Mahony gains (e.g. Betaflight's `IMU` defaults).
+    fn default() -> Self {
+        Self { kp: 5.0, ki: 0.05 }
+    }
+}
+
+/// Mahony complementary filter fusing gyro and accelerometer readings into
+/// an attitude estimate, so self-leveling modes fly on the same imperfect
+/// knowledge a real flight controller would have instead of the ground-truth
+/// orientation quaternion.
+///
+/// Each tick integrates the gyro reading forward, then nudges the result
+/// toward the gravity direction implied by the accelerometer: the tilt
+/// error is the cross product between measured and estimated "down", fed
+/// back as a corrective body rate (proportional term) and accumulated to
+/// estimate gyro bias (integral term), following Mahony et al.'s
+/// nonlinear complementary filter for attitude on SO(3).
+#[derive(Debug, Clone)]
+struct AttitudeEstimator {
+    /// Current attitude estimate.
+    orientation: Rotation,
+    /// Accumulated integral feedback (rad/s), which converges toward the
+    /// true gyro bias.
+    integral_feedback: Vec3,
+}
+
+impl AttitudeEstimator {
+    fn new(initial: Rotation) -> Self {
+        Self {
+            orientation: initial,
+            integral_feedback: Vec3::zeros(),
+        }
+    }
+
+    /// Fuse one IMU sample. `gyro` and `accel` are the body-frame
+    /// measurements an [`ImuData`] reading carries; `dt` is the time since
+    /// the previous update.
+    fn update(&mut self, gains: MahonyConfig, gyro: Vec3, accel: Vec3, dt: f64) {
+        // Measured "down" direction in the body frame — the accelerometer
+        // reads specific force, which is `-gravity` when stationary, so its
+        // normalized reading already points away from true down.
+        let accel_norm = accel.norm();
+        let mut corrected_gyro = gyro;
+        if accel_norm > 1e-6 {
+            let measured_down = accel / accel_norm;
+            let estimated_down = self.orientation.inverse() * Vec3::new(0.0, 0.0, -1.0);
+            let tilt_error = estimated_down.cross(&measured_down);
+
+            self.integral_feedback += tilt_error * gains.ki * dt;
+            corrected_gyro += tilt_error * gains.kp + self.integral_feedback;
+        }
+
+        // Integrate the (bias-corrected) gyro reading forward one step.
+        let delta = Rotation::from_scaled_axis(corrected_gyro * dt);
+        self.orientation = self.orientation * delta;
+    }
+}
+
+/// Sample zero-mean Gaussian noise via a Box-Muller transform, so this
+/// module doesn't need a dependency beyond the `rand` crate already used
+/// elsewhere in this codebase. Drawn from `rng` (seeded from
+/// `FpvDroneConfig::seed`) rather than the thread-global RNG, so a rollout
+/// is reproducible given the same seed.
+fn gaussian_noise(rng: &mut StdRng, std_dev: f64) -> f64 {
+    use rand::Rng;
+    let u1 = rng.gen::<f64>().max(1e-12);
+    let u2 = rng.gen::<f64>();
+    let z0 = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    z0 * std_dev
+}
+
+/// Sample per-axis zero-mean Gaussian noise.
+fn gaussian_noise_vec3(rng: &mut StdRng, std_dev: f64) -> Vec3 {
+    Vec3::new(
+        gaussian_noise(rng, std_dev),
+        gaussian_noise(rng, std_dev),
+        gaussian_noise(rng, std_dev),
+    )
+}
+
+// ─── Wind / Dryden Turbulence ────────────────────────────────────────────────
+
+/// Environmental wind configuration: a steady mean wind plus Dryden-style
+/// band-limited turbulence, in the spirit of SITL's `speed,direction,variance`
+/// wind model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindConfig {
+    /// Steady mean wind speed (m/s).
+    pub mean_speed_mps: f64,
+    /// Mean wind direction, degrees counter-clockwise from world +X.
+    pub direction_deg: f64,
+    /// Turbulence intensity (m/s) — the standard deviation `σ` of the
+    /// Dryden gust model.
+    pub turbulence_intensity_mps: f64,
+    /// Turbulence length scale (meters). Larger scales produce slower,
+    /// lower-frequency gusts.
+    pub turbulence_length_scale_m: f64,
+}
+
+impl Default for WindConfig {
+    fn default() -> Self {
+        Self::calm()
+    }
+}
+
+impl WindConfig {
+    /// No wind at all.
+    pub fn calm() -> Self {
+        Self {
+            mean_speed_mps: 0.0,
+            direction_deg: 0.0,
+            turbulence_intensity_mps: 0.0,
+            turbulence_length_scale_m: 200.0,
+        }
+    }
+
+    /// Light steady breeze with mild turbulence.
+    pub fn breezy() -> Self {
+        Self {
+            mean_speed_mps: 4.0,
+            direction_deg: 45.0,
+            turbulence_intensity_mps: 1.0,
+            turbulence_length_scale_m: 200.0,
+        }
+    }
+
+    /// Strong, gusty wind — good for long-range 7" disturbance-rejection runs.
+    pub fn gusty() -> Self {
+        Self {
+            mean_speed_mps: 8.0,
+            direction_deg: 90.0,
+            turbulence_intensity_mps: 2.5,
+            turbulence_length_scale_m: 150.0,
+        }
+    }
+
+    /// Steady mean wind as a world-frame horizontal vector.
+    fn mean_wind_vector(&self) -> Vec3 {
+        let dir = self.direction_deg.to_radians();
+        Vec3::new(
+            self.mean_speed_mps * dir.cos(),
+            self.mean_speed_mps * dir.sin(),
+            0.0,
+        )
+    }
+}
+
+/// A periodic acceleration disturbance: active for `active_duration_s`
+/// seconds out of every `period_s` seconds, so controller-robustness tests
+/// can inject repeating gusts/impulses instead of only steady wind. Assign
+/// to [`FpvPhysics::disturbance`] (like [`FpvPhysics::wind`]) to enable it,
+/// or `None` (the default) to disable it — either can be changed mid-flight
+/// to toggle the disturbance on or off.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PeriodicDisturbanceConfig {
+    /// Peak acceleration (m/s²) applied while active.
+    pub amplitude: Vec3,
+    /// Length of one active/inactive cycle (s).
+    pub period_s: f64,
+    /// How long within each `period_s` the disturbance is active (s),
+    /// starting at the beginning of the cycle.
+    pub active_duration_s: f64,
+    /// If `true`, `amplitude` is a body-frame vector rotated into world
+    /// frame each tick; if `false` (the default), it's applied directly in
+    /// world frame.
+    pub body_frame: bool,
+}
+
+impl Default for PeriodicDisturbanceConfig {
+    fn default() -> Self {
+        Self {
+            amplitude: Vec3::zeros(),
+            period_s: 1.0,
+            active_duration_s: 0.1,
+            body_frame: false,
+        }
+    }
+}
+
+// ─── Slung Load ──────────────────────────────────────────────────────────────
+
+/// An optional suspended point-mass payload hanging from a massless,
+/// inextensible rope attached at the drone's center of gravity. Assign to
+/// [`FpvPhysics::slung_load`] (like [`FpvPhysics::wind`]) to enable it; the
+/// pendulum state is created the first time it's stepped, hanging straight
+/// down from the drone's current position.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SlungLoadConfig {
+    /// Rope length (m).
+    pub rope_length_m: f64,
+    /// Payload mass (kg), used only to scale the tension reaction force
+    /// applied back onto the drone — the rope itself is massless.
+    pub mass_kg: f64,
+    /// Linear drag coefficient (1/s) damping the payload's swing velocity
+    /// relative to the drone. `0.0` gives an undamped pendulum.
+    pub drag_coefficient: f64,
+}
+
+impl Default for SlungLoadConfig {
+    /// A modest camera/FPG payload on a 1m tether.
+    fn default() -> Self {
+        Self {
+            rope_length_m: 1.0,
+            mass_kg: 0.5,
+            drag_coefficient: 0.2,
+        }
+    }
+}
+
+/// World-frame swing state of an attached [`SlungLoadConfig`] payload.
+#[derive(Debug, Clone, Copy)]
+pub struct SlungLoadState {
+    /// Payload position (world frame).
+    pub position: Position,
+    /// Payload velocity (world frame, m/s).
+    pub velocity: Vec3,
+    /// Angle (radians) between the rope and straight down; `0` = the load
+    /// hanging at rest directly beneath the drone.
+    pub swing_angle_rad: f64,
 }
 
 // ─── PID Gains ───────────────────────────────────────────────────────────────
@@ -503,6 +1352,11 @@ pub struct PidGains {
     pub d: f64,
     /// Feed-forward gain
     pub f: f64,
+    /// Anti-windup clamp on the accumulated integral error itself (the
+    /// same units as `integral_error`, i.e. rad·s), so the I term can't
+    /// wind up past the point where it could still be unwound once the
+    /// rate error reverses sign.
+    pub i_limit: f64,
 }
 
 impl PidGains {
@@ -512,6 +1366,7 @@ impl PidGains {
             i: 70.0,
             d: 28.0,
             f: 120.0,
+            i_limit: 14.3,
         }
     }
 
@@ -521,6 +1376,7 @@ impl PidGains {
             i: 72.0,
             d: 30.0,
             f: 125.0,
+            i_limit: 13.9,
         }
     }
 
@@ -530,8 +1386,193 @@ impl PidGains {
             i: 80.0,
             d: 0.0,
             f: 100.0,
+            i_limit: 12.5,
+        }
+    }
+}
+
+// ─── Blackbox Flight Recorder ────────────────────────────────────────────────
+
+/// Explicit per-axis (roll, pitch, yaw) PID contribution breakdown for one
+/// rate-controller tick, computed by [`FpvPhysics::step`] and exposed so a
+/// [`FpvBlackbox`] can record PID-tuning feedback the way real flight
+/// controller firmware does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PidTerms {
+    pub p: Vec3,
+    pub i: Vec3,
+    pub d: Vec3,
+    pub f: Vec3,
+}
+
+/// One recorded [`FpvBlackbox`] frame: the inner-loop state at a single
+/// [`FpvPhysics::step`] tick.
+#[derive(Debug, Clone)]
+pub struct BlackboxFrame {
+    /// Seconds since arming.
+    pub timestamp: f64,
+    /// Gyro reading (rad/s, body frame) — `FpvPhysics::angular_velocity`.
+    pub gyro: Vec3,
+    /// Commanded angular rates (rad/s) for this tick.
+    pub setpoint_rates: Vec3,
+    /// Explicit P/I/D/F contributions for this tick.
+    pub pid_terms: PidTerms,
+    /// Normalized per-motor output in `[0, 1]`.
+    pub motor_outputs: Vec<f64>,
+    /// Throttle after the rates profile's throttle curve, in `[0, 1]`.
+    pub throttle: f64,
+    pub battery_voltage: f64,
+    pub battery_current: f64,
+}
+
+/// Ring-buffer flight-data recorder, the FPV analogue of a real flight
+/// controller's blackbox logger. Disabled by default — assign
+/// `FpvPhysics::blackbox = Some(FpvBlackbox::new(capacity))` to start
+/// recording every [`FpvPhysics::step`] tick.
+#[derive(Debug, Clone)]
+pub struct FpvBlackbox {
+    frames: VecDeque<BlackboxFrame>,
+    capacity: usize,
+}
+
+impl FpvBlackbox {
+    /// Create an empty recorder that keeps at most `capacity` of the most
+    /// recent frames, discarding the oldest once full.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(capacity),
+            capacity,
         }
     }
+
+    /// Push a frame, evicting the oldest one if at capacity.
+    pub fn record(&mut self, frame: BlackboxFrame) {
+        if self.frames.len() >= self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    pub fn frames(&self) -> &VecDeque<BlackboxFrame> {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Plain CSV export: one column per [`BlackboxFrame`] field, motor
+    /// columns sized to the first recorded frame's motor count.
+    pub fn export_csv(&self) -> String {
+        let motor_count = self.frames.front().map_or(0, |f| f.motor_outputs.len());
+        let mut csv = String::from(
+            "timestamp,gyro_roll,gyro_pitch,gyro_yaw,\
+             setpoint_roll,setpoint_pitch,setpoint_yaw,\
+             axisP_roll,axisP_pitch,axisP_yaw,\
+             axisI_roll,axisI_pitch,axisI_yaw,\
+             axisD_roll,axisD_pitch,axisD_yaw,\
+             axisF_roll,axisF_pitch,axisF_yaw,",
+        );
+        for i in 0..motor_count {
+            csv.push_str(&format!("motor{i},"));
+        }
+        csv.push_str("throttle,battery_voltage,battery_current\n");
+
+        for frame in &self.frames {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},",
+                frame.timestamp,
+                frame.gyro.x,
+                frame.gyro.y,
+                frame.gyro.z,
+                frame.setpoint_rates.x,
+                frame.setpoint_rates.y,
+                frame.setpoint_rates.z,
+                frame.pid_terms.p.x,
+                frame.pid_terms.p.y,
+                frame.pid_terms.p.z,
+                frame.pid_terms.i.x,
+                frame.pid_terms.i.y,
+                frame.pid_terms.i.z,
+                frame.pid_terms.d.x,
+                frame.pid_terms.d.y,
+                frame.pid_terms.d.z,
+                frame.pid_terms.f.x,
+                frame.pid_terms.f.y,
+                frame.pid_terms.f.z,
+            ));
+            for m in &frame.motor_outputs {
+                csv.push_str(&format!("{m},"));
+            }
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                frame.throttle, frame.battery_voltage, frame.battery_current
+            ));
+        }
+        csv
+    }
+
+    /// Export in a Betaflight-blackbox-compatible field layout (`rcCommand`,
+    /// `axisP[0..2]`, `axisD[0..2]`, `motor[0..N-1]`, `gyroADC[0..2]`, ...)
+    /// so logs open in existing Blackbox Explorer tooling. Axis order is
+    /// `[0]` = roll, `[1]` = pitch, `[2]` = yaw, matching Betaflight.
+    /// `time` is seconds since arming rather than a raw microsecond
+    /// counter, since this recorder has no separate logging clock.
+    pub fn export_betaflight_csv(&self) -> String {
+        let motor_count = self.frames.front().map_or(0, |f| f.motor_outputs.len());
+        let mut csv = String::from(
+            "loopIteration,time,\
+             axisP[0],axisP[1],axisP[2],\
+             axisI[0],axisI[1],axisI[2],\
+             axisD[0],axisD[1],axisD[2],\
+             axisF[0],axisF[1],axisF[2],\
+             rcCommand[0],rcCommand[1],rcCommand[2],rcCommand[3],\
+             gyroADC[0],gyroADC[1],gyroADC[2],",
+        );
+        for i in 0..motor_count {
+            csv.push_str(&format!("motor[{i}],"));
+        }
+        csv.push_str("vbatLatest,amperageLatest\n");
+
+        for (iteration, frame) in self.frames.iter().enumerate() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},",
+                iteration,
+                frame.timestamp,
+                frame.pid_terms.p.x,
+                frame.pid_terms.p.y,
+                frame.pid_terms.p.z,
+                frame.pid_terms.i.x,
+                frame.pid_terms.i.y,
+                frame.pid_terms.i.z,
+                frame.pid_terms.d.x,
+                frame.pid_terms.d.y,
+                frame.pid_terms.d.z,
+                frame.pid_terms.f.x,
+                frame.pid_terms.f.y,
+                frame.pid_terms.f.z,
+                frame.setpoint_rates.x,
+                frame.setpoint_rates.y,
+                frame.setpoint_rates.z,
+                frame.throttle,
+                frame.gyro.x,
+                frame.gyro.y,
+                frame.gyro.z,
+            ));
+            for m in &frame.motor_outputs {
+                csv.push_str(&format!("{m},"));
+            }
+            csv.push_str(&format!(
+                "{},{}\n",
+                frame.battery_voltage, frame.battery_current
+            ));
+        }
+        csv
+    }
 }
 
 // ─── FPV Stick Input ─────────────────────────────────────────────────────────
@@ -570,6 +1611,15 @@ impl FpvStickInput {
             yaw: 0.0,
         }
     }
+
+    /// True if roll/pitch/yaw is deflected away from center — used by
+    /// [`FpvPhysics::step`] to detect the pilot reasserting manual control
+    /// over an active offboard setpoint (see
+    /// [`FpvPhysics::command_attitude`]).
+    fn has_stick_deflection(&self) -> bool {
+        const DEADZONE: f64 = 0.02;
+        self.roll.abs() > DEADZONE || self.pitch.abs() > DEADZONE || self.yaw.abs() > DEADZONE
+    }
 }
 
 // ─── OSD (On Screen Display) ─────────────────────────────────────────────────
@@ -666,6 +1716,11 @@ pub struct FpvState {
     /// OSD telemetry
     pub osd: FpvOsd,
 
+    /// Most recent synthetic IMU reading (noisy, biased — see [`ImuConfig`]),
+    /// sampled at `config.imu.sample_rate_hz` independent of the physics
+    /// step rate.
+    pub imu: ImuData,
+
     /// Simulation timestamp
     pub timestamp: f64,
 
@@ -674,6 +1729,10 @@ pub struct FpvState {
 
     /// Altitude above start point (meters)
     pub altitude_m: f64,
+
+    /// Swing state of the attached slung-load payload, if any (see
+    /// [`FpvPhysics::slung_load`]).
+    pub slung_load: Option<SlungLoadState>,
 }
 
 // ─── FPV Physics Sim ─────────────────────────────────────────────────────────
@@ -687,6 +1746,8 @@ pub struct FpvPhysics {
     pub orientation: Rotation,
     pub angular_velocity: Vec3,
     pub motor_outputs: Vec<f64>,
+    /// Mixing table for `config.frame`, built once in [`Self::new`].
+    mixer: MotorMixer,
     pub armed: bool,
     pub flight_mode: FpvFlightMode,
     pub battery_voltage: f64,
@@ -694,6 +1755,121 @@ pub struct FpvPhysics {
     pub flight_time: f64,
     pub mah_consumed: f64,
     start_altitude: f64,
+
+    /// Environmental wind model. Defaults to [`WindConfig::calm`]; set
+    /// directly (e.g. `physics.wind = WindConfig::gusty()`) to add
+    /// disturbance for autopilot/controller testing.
+    pub wind: WindConfig,
+    /// Current Dryden turbulence velocity (world frame, m/s).
+    turbulence_velocity: Vec3,
+    /// Optional periodic acceleration disturbance (repeating gusts/impulses
+    /// on top of `wind`). `None` (the default) disables it; set directly
+    /// (e.g. `physics.disturbance = Some(PeriodicDisturbanceConfig { .. })`)
+    /// like [`Self::wind`] to toggle it mid-flight.
+    pub disturbance: Option<PeriodicDisturbanceConfig>,
+
+    /// Optional suspended payload; `None` (the default) disables slung-load
+    /// dynamics entirely. Set directly (e.g. `physics.slung_load =
+    /// Some(SlungLoadConfig::default())`) like [`Self::wind`].
+    pub slung_load: Option<SlungLoadConfig>,
+    /// Current swing state of `slung_load`, created on first use hanging
+    /// straight down from the drone's position at that time.
+    load_state: Option<SlungLoadState>,
+
+    /// Accumulated per-axis accelerometer bias (constant bias + random walk).
+    imu_accel_bias: Vec3,
+    /// Accumulated per-axis gyro bias (constant bias + random walk).
+    imu_gyro_bias: Vec3,
+    /// World-frame linear acceleration from the most recent physics step,
+    /// used to derive the body-frame accelerometer reading.
+    last_acceleration: Vec3,
+    /// Per-motor vibration phase accumulator (radians), advanced every
+    /// physics tick at that motor's current rotation frequency and summed
+    /// into the synthetic gyro reading; see `config.imu.vibration_amplitude`.
+    motor_vibration_phase: Vec<f64>,
+    /// Seconds accumulated since the last IMU sample.
+    time_since_imu_sample: f64,
+    /// Most recent synthetic IMU reading; refreshed at `config.imu.sample_rate_hz`.
+    last_imu_reading: ImuData,
+    /// Mahony-filter attitude estimate fused from the synthetic IMU, fed
+    /// into `last_imu_reading.orientation` and the self-leveling modes in
+    /// [`Self::step`] in place of the ground-truth orientation.
+    attitude_estimator: AttitudeEstimator,
+
+    /// Queued duration-bounded rate command, if any; see
+    /// [`Self::move_by_rates_z`].
+    active_command: Option<RateCommand>,
+
+    /// Offboard attitude/body-rate setpoint, if any; see
+    /// [`Self::command_attitude`] and [`Self::command_body_rates`].
+    external_setpoint: Option<ExternalSetpoint>,
+
+    /// Explicit P/I/D/F breakdown from the most recent rate-PID tick, kept
+    /// around so [`Self::step`] can hand it to `blackbox` without
+    /// recomputing it.
+    last_pid_terms: PidTerms,
+
+    /// Accumulated integral error per axis, for the rate PID's I term.
+    integral_error: Vec3,
+    /// Low-pass-filtered gyro rate, for the D term's derivative-on-
+    /// measurement and for I-term-relax's "how fast is the stick moving"
+    /// detector applied to the setpoint (see `filtered_setpoint`).
+    filtered_gyro: Vec3,
+    /// Low-pass-filtered setpoint rate, for I-term relax.
+    filtered_setpoint: Vec3,
+    /// Setpoint rate from the previous tick, for the F term's raw
+    /// d(setpoint)/dt.
+    previous_setpoint_rates: Vec3,
+    /// Throttle from the previous tick, for anti-gravity's d(throttle)/dt.
+    previous_throttle: f64,
+
+    /// Opt-in flight-data recorder. `None` (the default) records nothing;
+    /// assign `Some(FpvBlackbox::new(capacity))` to start logging every
+    /// [`Self::step`] tick.
+    pub blackbox: Option<FpvBlackbox>,
+
+    /// PRNG for wind turbulence and IMU noise, seeded from `config.seed` so
+    /// rollouts are reproducible given the same seed.
+    rng: StdRng,
+}
+
+/// A queued "hold these body rates, then hover" command — the FPV analogue
+/// of AirSim's `moveByAngleRatesZAsync`. Created by
+/// [`FpvPhysics::move_by_rates_z`] and consumed one tick at a time by
+/// [`FpvPhysics::step_command`].
+#[derive(Debug, Clone, Copy)]
+struct RateCommand {
+    roll_rate_deg_s: f64,
+    pitch_rate_deg_s: f64,
+    yaw_rate_deg_s: f64,
+    target_altitude_m: f64,
+    remaining_s: f64,
+}
+
+/// An offboard attitude/body-rate setpoint driving [`FpvPhysics::step`]
+/// directly, bypassing the stick expo/rates curves entirely — the FPV
+/// analogue of ArduPilot's `set_target_angle_and_climbrate`/`set_thrust`
+/// guided-control API. Created by [`FpvPhysics::command_attitude`] or
+/// [`FpvPhysics::command_body_rates`] and cleared automatically once a
+/// stick input reasserts manual control.
+#[derive(Debug, Clone, Copy)]
+enum ExternalSetpoint {
+    /// Target roll/pitch angle (radians) and yaw rate (rad/s); `step` runs
+    /// the same angle-to-rate P loop Angle mode uses to turn the angle
+    /// target into a rate setpoint for the rate PID.
+    Attitude {
+        roll_rad: f64,
+        pitch_rad: f64,
+        yaw_rate_rad_s: f64,
+        thrust: f64,
+    },
+    /// Target body rates (rad/s), fed to the rate PID directly.
+    BodyRates {
+        roll_rate_rad_s: f64,
+        pitch_rate_rad_s: f64,
+        yaw_rate_rad_s: f64,
+        thrust: f64,
+    },
 }
 
 impl FpvPhysics {
@@ -703,6 +1879,10 @@ impl FpvPhysics {
         let voltage = config.battery_cells as f64 * 4.2; // Fully charged LiPo
         let motor_count = config.motor_count as usize;
         let start_alt = spawn.z;
+        let imu_accel_bias = config.imu.accel_bias;
+        let imu_gyro_bias = config.imu.gyro_bias;
+        let mixer = MotorMixer::new(config.frame);
+        let rng = StdRng::seed_from_u64(config.seed);
         Self {
             config,
             position: spawn,
@@ -710,6 +1890,7 @@ impl FpvPhysics {
             orientation: Rotation::identity(),
             angular_velocity: Vec3::zeros(),
             motor_outputs: vec![0.0; motor_count],
+            mixer,
             armed: false,
             flight_mode: mode,
             battery_voltage: voltage,
@@ -717,6 +1898,33 @@ impl FpvPhysics {
             flight_time: 0.0,
             mah_consumed: 0.0,
             start_altitude: start_alt,
+            wind: WindConfig::calm(),
+            turbulence_velocity: Vec3::zeros(),
+            disturbance: None,
+            slung_load: None,
+            load_state: None,
+            imu_accel_bias,
+            imu_gyro_bias,
+            last_acceleration: Vec3::new(0.0, 0.0, -9.81),
+            motor_vibration_phase: vec![0.0; motor_count],
+            time_since_imu_sample: 0.0,
+            last_imu_reading: ImuData {
+                timestamp: 0.0,
+                linear_acceleration: Vec3::zeros(),
+                angular_velocity: Vec3::zeros(),
+                orientation: Rotation::identity(),
+            },
+            attitude_estimator: AttitudeEstimator::new(Rotation::identity()),
+            active_command: None,
+            external_setpoint: None,
+            last_pid_terms: PidTerms::default(),
+            integral_error: Vec3::zeros(),
+            filtered_gyro: Vec3::zeros(),
+            filtered_setpoint: Vec3::zeros(),
+            previous_setpoint_rates: Vec3::zeros(),
+            previous_throttle: 0.0,
+            blackbox: None,
+            rng,
         }
     }
 
@@ -725,6 +1933,7 @@ impl FpvPhysics {
         self.armed = armed;
         if !armed {
             self.motor_outputs = vec![0.0; self.config.motor_count as usize];
+            self.integral_error = Vec3::zeros();
         }
     }
 
@@ -733,14 +1942,71 @@ impl FpvPhysics {
         self.flight_mode = mode;
     }
 
-    /// Extract current roll, pitch, yaw angles from the orientation quaternion (in radians)
+    /// Roll, pitch, yaw (radians) from the *estimated* attitude the
+    /// self-leveling modes actually fly on — see [`AttitudeEstimator`] —
+    /// rather than the ground-truth orientation, so IMU noise/bias/vibration
+    /// show up as real leveling error instead of being invisible to the sim.
     fn euler_angles(&self) -> (f64, f64, f64) {
-        let q = self.orientation;
+        let q = self.attitude_estimator.orientation;
         let (roll, pitch, yaw) = q.euler_angles();
         (roll, pitch, yaw)
     }
 
-    /// Step the physics simulation
+    /// Quaternion-error attitude controller behind Angle/Horizon
+    /// self-leveling: build the desired orientation from the
+    /// stick-commanded `target_roll`/`target_pitch` at the current
+    /// heading, compute `q_err = q_desired.inverse() * q_current`, flip to
+    /// the shortest-path sign, and map the error quaternion's vector part
+    /// scaled by `2 * att_p` to a body-rate setpoint. Unlike a scalar
+    /// Euler-angle P term this stays well-behaved through large tilt —
+    /// including inverted — attitudes, since it never evaluates an
+    /// ill-defined Euler decomposition of the error itself.
+    ///
+    /// Returns `(roll_rate, pitch_rate)` in rad/s, clamped to the
+    /// configured super rates.
+    fn quaternion_level_rates(
+        &self,
+        target_roll: f64,
+        target_pitch: f64,
+        att_p: f64,
+    ) -> (f64, f64) {
+        let (_, _, cur_yaw) = self.euler_angles();
+        let desired = Rotation::from_euler_angles(target_roll, target_pitch, cur_yaw);
+        let current = self.attitude_estimator.orientation;
+        let q_err = desired.inverse() * current;
+
+        let mut error_vector = q_err.vector().into_owned();
+        if q_err.scalar() < 0.0 {
+            error_vector = -error_vector;
+        }
+        let rates = error_vector * 2.0 * att_p;
+
+        let roll_rate = rates.x.clamp(
+            -self.config.rates.roll_super_rate.to_radians(),
+            self.config.rates.roll_super_rate.to_radians(),
+        );
+        let pitch_rate = rates.y.clamp(
+            -self.config.rates.pitch_super_rate.to_radians(),
+            self.config.rates.pitch_super_rate.to_radians(),
+        );
+        (roll_rate, pitch_rate)
+    }
+
+    /// The current Mahony attitude estimate, for callers (e.g. tests, OSD
+    /// overlays) that want to compare it against ground truth.
+    pub fn estimated_orientation(&self) -> Rotation {
+        self.attitude_estimator.orientation
+    }
+
+    /// Decompose world-frame velocity into the body frame: `x` forward,
+    /// `y` right, `z` up. Used by [`FpvFlightMode::Drift`]'s turn
+    /// coordination, and available to other callers (e.g.
+    /// [`crate::rl_env`]) that need body-relative velocity.
+    pub fn velocity_body(&self) -> Vec3 {
+        self.orientation.inverse() * self.velocity
+    }
+
+    /// Step the physics simulation
     ///
     /// `dt` is the time step in seconds (e.g., 0.001 for 1kHz).
     /// `input` is the current stick state.
@@ -749,13 +2015,71 @@ impl FpvPhysics {
             return;
         }
 
-        let mass = self.config.mass_kg();
-        let gravity = Vec3::new(0.0, 0.0, -9.81);
+        // An offboard setpoint stays in effect until the pilot deflects a
+        // stick, at which point manual control resumes this tick.
+        if self.external_setpoint.is_some() && input.has_stick_deflection() {
+            self.external_setpoint = None;
+        }
+
+        let (target_roll_rate, target_pitch_rate, target_yaw_rate, throttle) =
+            if let Some(setpoint) = self.external_setpoint {
+                self.external_setpoint_rates(setpoint)
+            } else {
+                let throttle = self.config.rates.throttle_curve(input.throttle);
+                let (roll_rate, pitch_rate, yaw_rate) = self.stick_setpoint_rates(input);
+                (roll_rate, pitch_rate, yaw_rate, throttle)
+            };
+
+        self.step_with_setpoint(
+            dt,
+            target_roll_rate,
+            target_pitch_rate,
+            target_yaw_rate,
+            throttle,
+        );
+    }
+
+    /// Turn an offboard [`ExternalSetpoint`] into `(roll_rate, pitch_rate,
+    /// yaw_rate, throttle)`, bypassing the stick expo/rates curves — the
+    /// `Attitude` variant runs the same angle-to-rate P loop Angle mode
+    /// uses; `BodyRates` passes its rates straight through.
+    fn external_setpoint_rates(&self, setpoint: ExternalSetpoint) -> (f64, f64, f64, f64) {
+        match setpoint {
+            ExternalSetpoint::Attitude {
+                roll_rad,
+                pitch_rad,
+                yaw_rate_rad_s,
+                thrust,
+            } => {
+                let (cur_roll, cur_pitch, _cur_yaw) = self.euler_angles();
+                let angle_p = 5.0;
+                let roll_rate = ((roll_rad - cur_roll) * angle_p).clamp(
+                    -self.config.rates.roll_super_rate.to_radians(),
+                    self.config.rates.roll_super_rate.to_radians(),
+                );
+                let pitch_rate = ((pitch_rad - cur_pitch) * angle_p).clamp(
+                    -self.config.rates.pitch_super_rate.to_radians(),
+                    self.config.rates.pitch_super_rate.to_radians(),
+                );
+                (roll_rate, pitch_rate, yaw_rate_rad_s, thrust)
+            }
+            ExternalSetpoint::BodyRates {
+                roll_rate_rad_s,
+                pitch_rate_rad_s,
+                yaw_rate_rad_s,
+                thrust,
+            } => (roll_rate_rad_s, pitch_rate_rad_s, yaw_rate_rad_s, thrust),
+        }
+    }
 
-        // ── Compute target angular rates based on flight mode ──
-        // Note: pitch input is negated to match convention (-1 = forward/nose down)
-        // In the physics, positive pitch angle = nose down (rotation around Y with Z-up)
-        let (target_roll_rate, target_pitch_rate, target_yaw_rate) = match self.flight_mode {
+    /// Compute target angular rates (radians/s) for the current
+    /// [`FpvFlightMode`] from stick input.
+    ///
+    /// Note: pitch input is negated to match convention (-1 = forward/nose
+    /// down). In the physics, positive pitch angle = nose down (rotation
+    /// around Y with Z-up).
+    fn stick_setpoint_rates(&self, input: &FpvStickInput) -> (f64, f64, f64) {
+        match self.flight_mode {
             FpvFlightMode::Acro => {
                 // Direct rate control — stick maps to angular rate
                 let roll = self.config.rates.roll_rate(input.roll).to_radians();
@@ -764,49 +2088,33 @@ impl FpvPhysics {
                 (roll, pitch, yaw)
             }
             FpvFlightMode::Angle => {
-                // Self-leveling — stick maps to target angle, PID drives rates
+                // Self-leveling — stick maps to target angle, the
+                // quaternion-error controller drives rates.
                 let max_angle = self.config.max_angle_deg.to_radians();
                 let target_roll = input.roll * max_angle;
                 let target_pitch = -input.pitch * max_angle; // Negate: -stick = nose down = +angle
 
-                let (cur_roll, cur_pitch, _cur_yaw) = self.euler_angles();
-                let roll_angle_error = target_roll - cur_roll;
-                let pitch_angle_error = target_pitch - cur_pitch;
-
-                // P controller on angle error → target rate (5.0 rad/s per rad error)
-                let angle_p = 5.0;
-                let roll_rate = (roll_angle_error * angle_p).clamp(
-                    -self.config.rates.roll_super_rate.to_radians(),
-                    self.config.rates.roll_super_rate.to_radians(),
-                );
-                let pitch_rate = (pitch_angle_error * angle_p).clamp(
-                    -self.config.rates.pitch_super_rate.to_radians(),
-                    self.config.rates.pitch_super_rate.to_radians(),
-                );
+                let att_p = 5.0;
+                let (roll_rate, pitch_rate) =
+                    self.quaternion_level_rates(target_roll, target_pitch, att_p);
                 let yaw = self.config.rates.yaw_rate(input.yaw).to_radians();
                 (roll_rate, pitch_rate, yaw)
             }
             FpvFlightMode::Horizon => {
                 // Hybrid: self-level near center, acro at extremes
                 let max_angle = self.config.max_angle_deg.to_radians();
-                let (cur_roll, cur_pitch, _cur_yaw) = self.euler_angles();
 
                 // Blending factor: 0 at center (angle mode), 1 at full deflection (acro)
                 let roll_blend = input.roll.abs();
                 let pitch_blend = input.pitch.abs();
 
-                // Angle mode target rates (pitch negated for correct convention)
+                // Angle mode target rates (pitch negated for correct convention),
+                // driven by the same quaternion-error controller as Angle mode.
                 let target_roll_a = input.roll * max_angle;
                 let target_pitch_a = -input.pitch * max_angle;
-                let angle_p = 5.0;
-                let roll_rate_angle = ((target_roll_a - cur_roll) * angle_p).clamp(
-                    -self.config.rates.roll_super_rate.to_radians(),
-                    self.config.rates.roll_super_rate.to_radians(),
-                );
-                let pitch_rate_angle = ((target_pitch_a - cur_pitch) * angle_p).clamp(
-                    -self.config.rates.pitch_super_rate.to_radians(),
-                    self.config.rates.pitch_super_rate.to_radians(),
-                );
+                let att_p = 5.0;
+                let (roll_rate_angle, pitch_rate_angle) =
+                    self.quaternion_level_rates(target_roll_a, target_pitch_a, att_p);
 
                 // Acro mode target rates (pitch negated)
                 let roll_rate_acro = self.config.rates.roll_rate(input.roll).to_radians();
@@ -819,26 +2127,268 @@ impl FpvPhysics {
                 let yaw = self.config.rates.yaw_rate(input.yaw).to_radians();
                 (roll_rate, pitch_rate, yaw)
             }
+            FpvFlightMode::Drift => {
+                // Pitch/roll self-level the same way Angle mode does —
+                // pitch commands forward/back speed, roll commands bank.
+                let max_angle = self.config.max_angle_deg.to_radians();
+                let (cur_roll, cur_pitch, _cur_yaw) = self.euler_angles();
+                let angle_p = 5.0;
+
+                let target_pitch = -input.pitch * max_angle;
+                let pitch_rate = ((target_pitch - cur_pitch) * angle_p).clamp(
+                    -self.config.rates.pitch_super_rate.to_radians(),
+                    self.config.rates.pitch_super_rate.to_radians(),
+                );
+                let target_roll = input.roll * max_angle;
+                let roll_rate = ((target_roll - cur_roll) * angle_p).clamp(
+                    -self.config.rates.roll_super_rate.to_radians(),
+                    self.config.rates.roll_super_rate.to_radians(),
+                );
+
+                // Coordinated-turn yaw rate: the standard level-turn relation
+                // g·tan(bank)/speed sets the baseline rate for the current
+                // bank angle, then a washout term feeds lateral (sideslip)
+                // body velocity back as a correction so any residual skid
+                // is driven toward zero — the nose tracks the velocity
+                // vector like a car rather than crabbing sideways.
+                let body_velocity = self.velocity_body();
+                let forward_speed = body_velocity.x.abs().max(1.0);
+                let coordinated_yaw_rate = 9.81 * cur_roll.tan() / forward_speed;
+                let sideslip_washout_gain = 0.5;
+                let yaw_rate = (coordinated_yaw_rate - sideslip_washout_gain * body_velocity.y)
+                    .clamp(
+                        -self.config.rates.yaw_super_rate.to_radians(),
+                        self.config.rates.yaw_super_rate.to_radians(),
+                    );
+                (roll_rate, pitch_rate, yaw_rate)
+            }
+        }
+    }
+
+    /// Run the rate PID and motor mixer for one tick against an already
+    /// resolved `(roll_rate, pitch_rate, yaw_rate, throttle)` setpoint,
+    /// shared by stick-driven flight modes and offboard
+    /// [`ExternalSetpoint`] control.
+    fn step_with_setpoint(
+        &mut self,
+        dt: f64,
+        target_roll_rate: f64,
+        target_pitch_rate: f64,
+        target_yaw_rate: f64,
+        throttle: f64,
+    ) {
+        // ── Full Betaflight-style rate PID → normalized roll/pitch/yaw demand ──
+        let rate_setpoint = Vec3::new(target_roll_rate, target_pitch_rate, target_yaw_rate);
+        let gyro = self.angular_velocity;
+        let rate_error = rate_setpoint - gyro;
+
+        // D term: differentiate a low-pass-filtered gyro measurement
+        // (not the error) so a setpoint step doesn't cause a derivative
+        // kick.
+        let d_alpha = (dt / (self.config.d_term_lowpass_tau_s + dt)).clamp(0.0, 1.0);
+        let previous_filtered_gyro = self.filtered_gyro;
+        self.filtered_gyro += (gyro - previous_filtered_gyro) * d_alpha;
+        let gyro_derivative = if dt > 1e-9 {
+            (self.filtered_gyro - previous_filtered_gyro) / dt
+        } else {
+            Vec3::zeros()
         };
 
-        // ── Simplified PID on angular rates ──
-        let rate_error = Vec3::new(
-            target_roll_rate - self.angular_velocity.x,
-            target_pitch_rate - self.angular_velocity.y,
-            target_yaw_rate - self.angular_velocity.z,
+        // F term: raw feed-forward on the setpoint's own rate of change —
+        // the controller reacts to a stick move immediately instead of
+        // waiting for the resulting gyro error.
+        let setpoint_derivative = if dt > 1e-9 {
+            (rate_setpoint - self.previous_setpoint_rates) / dt
+        } else {
+            Vec3::zeros()
+        };
+        self.previous_setpoint_rates = rate_setpoint;
+
+        // I-term relax: a separately low-pass-filtered setpoint tracks the
+        // pilot's *recent average* stick position; the gap between that and
+        // the instantaneous setpoint measures how fast the stick is moving
+        // right now (deg/s). Above `i_term_relax_threshold_deg_s`, I
+        // accumulation is attenuated so the integrator doesn't wind up
+        // during a fast stick move and then bounce back once it settles.
+        self.filtered_setpoint += (rate_setpoint - self.filtered_setpoint) * d_alpha;
+        let setpoint_motion_deg_s =
+            (rate_setpoint - self.filtered_setpoint).map(|v| v.to_degrees().abs());
+        let relax_threshold_deg_s = self.config.i_term_relax_threshold_deg_s;
+        let relax = |motion: f64| -> f64 {
+            if relax_threshold_deg_s <= 0.0 {
+                1.0
+            } else {
+                (1.0 - motion / relax_threshold_deg_s).clamp(0.0, 1.0)
+            }
+        };
+        let i_term_relax_factor = Vec3::new(
+            relax(setpoint_motion_deg_s.x),
+            relax(setpoint_motion_deg_s.y),
+            relax(setpoint_motion_deg_s.z),
         );
 
-        let p_gains = Vec3::new(
-            self.config.pid_roll.p / 100.0,
-            self.config.pid_pitch.p / 100.0,
-            self.config.pid_yaw.p / 100.0,
-        );
+        // Anti-gravity: a throttle punch briefly increases the I-term gain
+        // so a faster-than-usual thrust build-up doesn't pitch/roll the
+        // craft before the I term has had time to catch up.
+        let throttle_derivative = if dt > 1e-9 {
+            (throttle - self.previous_throttle) / dt
+        } else {
+            0.0
+        };
+        self.previous_throttle = throttle;
+        let anti_gravity_boost = 1.0 + self.config.anti_gravity_gain * throttle_derivative.abs();
+
+        self.integral_error +=
+            rate_error.component_mul(&i_term_relax_factor) * anti_gravity_boost * dt;
+
+        // TPA (throttle-PID-attenuation): above `tpa_breakpoint`, scale P
+        // and D down so high-throttle oscillation doesn't get amplified by
+        // gains tuned for cruise throttle.
+        let tpa_scale = if throttle > self.config.tpa_breakpoint && self.config.tpa_breakpoint < 1.0
+        {
+            let excess =
+                (throttle - self.config.tpa_breakpoint) / (1.0 - self.config.tpa_breakpoint);
+            (1.0 - excess * self.config.tpa_rate).max(0.0)
+        } else {
+            1.0
+        };
+
+        let pid_terms = PidTerms {
+            p: Vec3::new(
+                rate_error.x * self.config.pid_roll.p,
+                rate_error.y * self.config.pid_pitch.p,
+                rate_error.z * self.config.pid_yaw.p,
+            ) * tpa_scale
+                / 1000.0,
+            i: Vec3::new(
+                self.integral_error.x * self.config.pid_roll.i,
+                self.integral_error.y * self.config.pid_pitch.i,
+                self.integral_error.z * self.config.pid_yaw.i,
+            ) / 1000.0,
+            d: Vec3::new(
+                gyro_derivative.x * self.config.pid_roll.d,
+                gyro_derivative.y * self.config.pid_pitch.d,
+                gyro_derivative.z * self.config.pid_yaw.d,
+            ) * tpa_scale
+                / -1000.0,
+            f: Vec3::new(
+                setpoint_derivative.x * self.config.pid_roll.f,
+                setpoint_derivative.y * self.config.pid_pitch.f,
+                setpoint_derivative.z * self.config.pid_yaw.f,
+            ) / 1000.0,
+        };
 
-        let torque = Vec3::new(
-            rate_error.x * p_gains.x * self.config.inertia.x,
-            rate_error.y * p_gains.y * self.config.inertia.y,
-            rate_error.z * p_gains.z * self.config.inertia.z,
+        // Anti-windup: clamp the accumulated integral error itself to each
+        // axis's configured `i_limit`, so it can't wind up past the point
+        // where it could still be unwound once the rate error reverses.
+        self.integral_error.x = self
+            .integral_error
+            .x
+            .clamp(-self.config.pid_roll.i_limit, self.config.pid_roll.i_limit);
+        self.integral_error.y = self.integral_error.y.clamp(
+            -self.config.pid_pitch.i_limit,
+            self.config.pid_pitch.i_limit,
         );
+        self.integral_error.z = self
+            .integral_error
+            .z
+            .clamp(-self.config.pid_yaw.i_limit, self.config.pid_yaw.i_limit);
+
+        let roll_cmd =
+            (pid_terms.p.x + pid_terms.i.x + pid_terms.d.x + pid_terms.f.x).clamp(-1.0, 1.0);
+        let pitch_cmd =
+            (pid_terms.p.y + pid_terms.i.y + pid_terms.d.y + pid_terms.f.y).clamp(-1.0, 1.0);
+        let yaw_cmd =
+            (pid_terms.p.z + pid_terms.i.z + pid_terms.d.z + pid_terms.f.z).clamp(-1.0, 1.0);
+        self.last_pid_terms = pid_terms;
+
+        // ── Motor mixing (airmode-desaturated) ──
+        let motor_commands = self.mixer.mix(throttle, roll_cmd, pitch_cmd, yaw_cmd);
+        self.integrate_from_motor_targets(
+            dt,
+            &motor_commands.motor_outputs,
+            rate_setpoint,
+            throttle,
+        );
+    }
+
+    /// Step the physics simulation directly from per-motor commands
+    /// (each clamped to `[0, 1]` the same way [`MotorMixer::mix`] output
+    /// is), bypassing the rate-PID controller and mixer entirely.
+    ///
+    /// This is the direct-motor-output counterpart to [`Self::step`], for
+    /// callers (e.g. [`crate::rl_env`]'s `FpvAction::MotorOutputs`) that
+    /// want to command motors directly instead of through stick input.
+    /// Throttle for the battery model is approximated as the mean motor
+    /// command, and `last_pid_terms`/the blackbox setpoint-rate fields are
+    /// zeroed since no rate PID ran this tick.
+    pub fn step_motor_outputs(&mut self, dt: f64, motor_targets: &[f64]) {
+        if !self.armed {
+            return;
+        }
+        let throttle = if motor_targets.is_empty() {
+            0.0
+        } else {
+            motor_targets.iter().sum::<f64>() / motor_targets.len() as f64
+        };
+        self.last_pid_terms = PidTerms::default();
+        self.integrate_from_motor_targets(dt, motor_targets, Vec3::zeros(), throttle);
+    }
+
+    /// Shared physics tail for [`Self::step`] and [`Self::step_motor_outputs`]:
+    /// spin-up/spin-down lag, per-motor thrust/torque, rigid-body dynamics,
+    /// wind/drag, battery drain, synthetic IMU sampling, and blackbox
+    /// recording. `setpoint_rates` and `throttle` are only used for
+    /// telemetry (blackbox / battery draw), not for control.
+    fn integrate_from_motor_targets(
+        &mut self,
+        dt: f64,
+        motor_targets: &[f64],
+        setpoint_rates: Vec3,
+        throttle: f64,
+    ) {
+        let mass = self.config.mass_kg();
+        let gravity = Vec3::new(0.0, 0.0, -9.81);
+
+        // ── First-order spin-up/spin-down lag ──
+        let rotor = self.config.rotor_params();
+        for (speed, &cmd) in self.motor_outputs.iter_mut().zip(motor_targets.iter()) {
+            *speed += (cmd - *speed) * (dt / rotor.time_constant_s).min(1.0);
+        }
+
+        // ── Motor-vibration phase accumulation, one oscillator per motor at
+        // its current rotation frequency; summed into the gyro reading below.
+        for (phase, &speed) in self
+            .motor_vibration_phase
+            .iter_mut()
+            .zip(self.motor_outputs.iter())
+        {
+            let motor_hz = speed * MAX_MOTOR_RPM / 60.0;
+            *phase = (*phase + 2.0 * std::f64::consts::PI * motor_hz * dt)
+                % (2.0 * std::f64::consts::PI);
+        }
+
+        // ── Per-motor thrust and reaction torque → body force/moment ──
+        // Lever arm from center to each motor, projected onto the roll/pitch
+        // axes (motors sit on the diagonals of the frame's motor-to-motor
+        // span, so each one drives both axes).
+        //
+        // This still assumes a QuadX layout regardless of `config.frame`:
+        // the mixer above already generalizes to Hex/OctaQuad/Tri, but
+        // applying their torque properly needs a per-frame lever-arm/spin
+        // table, not just a per-frame mixing table. In a full
+        // implementation, this loop would use `self.mixer`'s rows the same
+        // way [`MotorMixer::mix`] does instead of the QUAD_X_* constants.
+        let lever_arm_m = self.config.frame_size_mm / 1000.0 / 2.0 / std::f64::consts::SQRT_2;
+        let mut thrust_magnitude = 0.0;
+        let mut torque = Vec3::zeros();
+        for (i, &speed) in self.motor_outputs.iter().enumerate().take(4) {
+            let thrust_i = speed * rotor.max_thrust_n;
+            thrust_magnitude += thrust_i;
+            torque.x += QUAD_X_ROLL_SIGN[i] * thrust_i * lever_arm_m;
+            torque.y += QUAD_X_PITCH_SIGN[i] * thrust_i * lever_arm_m;
+            torque.z += QUAD_X_SPIN_DIRECTION[i] * speed * rotor.max_reaction_torque_nm;
+        }
 
         // ── Angular velocity update ──
         let angular_accel = Vec3::new(
@@ -863,25 +2413,47 @@ impl FpvPhysics {
             self.orientation = self.orientation * delta_rot;
         }
 
-        // ── Thrust ──
-        let throttle = self.config.rates.throttle_curve(input.throttle);
-        let thrust_magnitude = throttle * self.config.max_thrust_n();
-
         // Thrust is along the drone's local Z axis (up)
         let thrust_direction = self.orientation * Vec3::new(0.0, 0.0, 1.0);
         let thrust_force = thrust_direction * thrust_magnitude;
 
-        // ── Drag ──
-        let speed = self.velocity.norm();
-        let drag_force = if speed > 0.01 {
-            -self.velocity.normalize() * self.config.drag_coefficient * speed * speed
+        // ── Wind: steady mean + Dryden-style band-limited turbulence ──
+        let mean_wind = self.wind.mean_wind_vector();
+        let airspeed = (self.velocity - mean_wind).norm().max(0.5);
+        let length_scale = self.wind.turbulence_length_scale_m.max(1.0);
+        let beta = (dt * airspeed / length_scale).min(1.0);
+        let sigma = self.wind.turbulence_intensity_mps;
+        let gust_gain = sigma * (2.0 * beta).sqrt();
+        let previous_turbulence = self.turbulence_velocity;
+        self.turbulence_velocity = Vec3::new(
+            previous_turbulence.x * (1.0 - beta) + gust_gain * gaussian_noise(&mut self.rng, 1.0),
+            previous_turbulence.y * (1.0 - beta) + gust_gain * gaussian_noise(&mut self.rng, 1.0),
+            previous_turbulence.z * (1.0 - beta) + gust_gain * gaussian_noise(&mut self.rng, 1.0),
+        );
+        let wind_velocity = mean_wind + self.turbulence_velocity;
+
+        // ── Drag (relative to the moving air mass, not the ground) ──
+        let relative_velocity = self.velocity - wind_velocity;
+        let relative_speed = relative_velocity.norm();
+        let drag_force = if relative_speed > 0.01 {
+            -relative_velocity.normalize()
+                * self.config.drag_coefficient
+                * relative_speed
+                * relative_speed
         } else {
             Vec3::zeros()
         };
 
+        // ── Optional slung-load payload: spherical-pendulum coupling ──
+        let load_reaction_force = self.step_slung_load(dt, gravity);
+
+        // ── Optional periodic acceleration disturbance ──
+        let disturbance_accel = self.disturbance_acceleration();
+
         // ── Linear acceleration ──
-        let total_force = thrust_force + gravity * mass + drag_force;
-        let acceleration = total_force / mass;
+        let total_force = thrust_force + gravity * mass + drag_force + load_reaction_force;
+        let acceleration = total_force / mass + disturbance_accel;
+        self.last_acceleration = acceleration;
 
         // ── Update velocity and position ──
         self.velocity += acceleration * dt;
@@ -896,11 +2468,6 @@ impl FpvPhysics {
             self.velocity.y *= (1.0 - 5.0 * dt).max(0.0);
         }
 
-        // ── Motor outputs (simplified — uniform for now) ──
-        for m in &mut self.motor_outputs {
-            *m = throttle;
-        }
-
         // ── Battery simulation ──
         let current_draw = throttle * 30.0 + 2.0; // Amps (simplified)
         self.mah_consumed += current_draw * 1000.0 * dt / 3600.0;
@@ -914,6 +2481,285 @@ impl FpvPhysics {
         self.battery_voltage = self.battery_voltage.max(nominal * 0.8);
 
         self.flight_time += dt;
+
+        // ── Synthetic IMU sampling (independent of the physics step rate) ──
+        self.time_since_imu_sample += dt;
+        let imu_period = 1.0 / self.config.imu.sample_rate_hz;
+        if self.time_since_imu_sample >= imu_period {
+            // Random-walk bias drift, scaled by the elapsed time since the
+            // last sample.
+            let walk_dt = self.time_since_imu_sample;
+            self.imu_accel_bias += gaussian_noise_vec3(
+                &mut self.rng,
+                self.config.imu.accel_bias_walk_std * walk_dt.sqrt(),
+            );
+            self.imu_gyro_bias += gaussian_noise_vec3(
+                &mut self.rng,
+                self.config.imu.gyro_bias_walk_std * walk_dt.sqrt(),
+            );
+
+            // Specific force (what an accelerometer actually measures) is
+            // the non-gravitational part of acceleration, rotated into the
+            // body frame.
+            let specific_force_world = self.last_acceleration - gravity;
+            let specific_force_body = self.orientation.inverse() * specific_force_world;
+
+            let accel_reading = specific_force_body
+                + self.imu_accel_bias
+                + gaussian_noise_vec3(&mut self.rng, self.config.imu.accel_noise_std);
+            // Motor vibration: each motor's oscillator summed together and
+            // scaled by the average motor output, so idle/disarmed motors
+            // produce no vibration and full throttle produces the configured
+            // peak amplitude.
+            let avg_motor_output =
+                self.motor_outputs.iter().sum::<f64>() / self.motor_outputs.len().max(1) as f64;
+            let vibration_signal: f64 = self
+                .motor_vibration_phase
+                .iter()
+                .map(|phase| phase.sin())
+                .sum();
+            let vibration = self.config.imu.vibration_amplitude
+                * (vibration_signal * avg_motor_output
+                    / self.motor_vibration_phase.len().max(1) as f64);
+            // Angular velocity is already expressed in the body frame.
+            let gyro_reading = self.angular_velocity
+                + self.imu_gyro_bias
+                + vibration
+                + gaussian_noise_vec3(&mut self.rng, self.config.imu.gyro_noise_std);
+
+            self.attitude_estimator.update(
+                self.config.mahony,
+                gyro_reading,
+                accel_reading,
+                walk_dt,
+            );
+
+            self.last_imu_reading = ImuData {
+                timestamp: self.flight_time,
+                linear_acceleration: accel_reading,
+                angular_velocity: gyro_reading,
+                orientation: self.attitude_estimator.orientation,
+            };
+            self.time_since_imu_sample = 0.0;
+        }
+
+        // ── Blackbox recording (opt-in; see `Self::blackbox`) ──
+        if let Some(blackbox) = &mut self.blackbox {
+            blackbox.record(BlackboxFrame {
+                timestamp: self.flight_time,
+                gyro: self.angular_velocity,
+                setpoint_rates,
+                pid_terms: self.last_pid_terms,
+                motor_outputs: self.motor_outputs.clone(),
+                throttle,
+                battery_voltage: self.battery_voltage,
+                battery_current: current_draw,
+            });
+        }
+    }
+
+    /// Command a target roll/pitch angle (degrees) and yaw rate (deg/s)
+    /// plus normalized thrust `[0, 1]`, bypassing the stick expo/rates
+    /// curves and feeding the rate PID directly — the FPV analogue of
+    /// ArduPilot's `set_target_angle_and_climbrate`/`set_thrust` offboard
+    /// API. [`Self::step`] honors this setpoint, running the same
+    /// angle-to-rate P loop Angle mode uses, until a subsequent stick
+    /// input with roll/pitch/yaw deflection reasserts manual control.
+    pub fn command_attitude(
+        &mut self,
+        roll_deg: f64,
+        pitch_deg: f64,
+        yaw_rate_deg_s: f64,
+        thrust: f64,
+    ) {
+        self.external_setpoint = Some(ExternalSetpoint::Attitude {
+            roll_rad: roll_deg.to_radians(),
+            pitch_rad: pitch_deg.to_radians(),
+            yaw_rate_rad_s: yaw_rate_deg_s.to_radians(),
+            thrust: thrust.clamp(0.0, 1.0),
+        });
+    }
+
+    /// Command target body rates (deg/s) plus normalized thrust `[0, 1]`,
+    /// fed to the rate PID directly instead of going through the stick
+    /// expo/rates curves — the FPV analogue of ArduPilot's offboard rate
+    /// control. Honored by [`Self::step`] the same way as
+    /// [`Self::command_attitude`].
+    pub fn command_body_rates(
+        &mut self,
+        roll_rate_deg_s: f64,
+        pitch_rate_deg_s: f64,
+        yaw_rate_deg_s: f64,
+        thrust: f64,
+    ) {
+        self.external_setpoint = Some(ExternalSetpoint::BodyRates {
+            roll_rate_rad_s: roll_rate_deg_s.to_radians(),
+            pitch_rate_rad_s: pitch_rate_deg_s.to_radians(),
+            yaw_rate_rad_s: yaw_rate_deg_s.to_radians(),
+            thrust: thrust.clamp(0.0, 1.0),
+        });
+    }
+
+    /// True while an offboard [`Self::command_attitude`]/
+    /// [`Self::command_body_rates`] setpoint is active; cleared once a
+    /// stick input reasserts manual control (see [`Self::step`]).
+    pub fn is_externally_controlled(&self) -> bool {
+        self.external_setpoint.is_some()
+    }
+
+    /// Queue a duration-bounded rate+altitude command — the FPV analogue
+    /// of AirSim's `moveByAngleRatesZAsync`: hold `roll_rate_deg_s` /
+    /// `pitch_rate_deg_s` / `yaw_rate_deg_s` in Acro mode for `duration_s`
+    /// seconds while a small altitude-hold PD loop drives throttle toward
+    /// `target_altitude_m`, then automatically fall back to a
+    /// self-leveling hover. Drive the command by calling
+    /// [`Self::step_command`] each tick instead of [`Self::step`]; scripted
+    /// maneuvers and discrete-action RL agents can issue "hold these rates
+    /// for dt then hover" without reimplementing the rate→stick inversion
+    /// or the autopilot.
+    pub fn move_by_rates_z(
+        &mut self,
+        roll_rate_deg_s: f64,
+        pitch_rate_deg_s: f64,
+        yaw_rate_deg_s: f64,
+        target_altitude_m: f64,
+        duration_s: f64,
+    ) {
+        self.flight_mode = FpvFlightMode::Acro;
+        self.active_command = Some(RateCommand {
+            roll_rate_deg_s,
+            pitch_rate_deg_s,
+            yaw_rate_deg_s,
+            target_altitude_m,
+            remaining_s: duration_s.max(0.0),
+        });
+    }
+
+    /// True while a [`Self::move_by_rates_z`] command is still running.
+    pub fn has_active_command(&self) -> bool {
+        self.active_command.is_some()
+    }
+
+    /// Step the physics using the currently queued [`Self::move_by_rates_z`]
+    /// command, if any, converting it into an [`FpvStickInput`] each tick
+    /// via the configured [`RatesProfile`]'s inverse expo curves. Once the
+    /// command's duration elapses, switches to `Angle` mode and hovers.
+    /// With no active command this just hovers. Equivalent to building the
+    /// stick input yourself and calling [`Self::step`].
+    pub fn step_command(&mut self, dt: f64) {
+        let input = match &mut self.active_command {
+            Some(cmd) => {
+                let rates = &self.config.rates;
+                let roll_stick = rates.roll_rate_inv(cmd.roll_rate_deg_s);
+                // Acro mode negates pitch stick before applying the rate
+                // curve (see `step`'s `FpvFlightMode::Acro` arm), so invert
+                // that negation here too.
+                let pitch_stick = -rates.pitch_rate_inv(cmd.pitch_rate_deg_s);
+                let yaw_stick = rates.yaw_rate_inv(cmd.yaw_rate_deg_s);
+
+                let hover_stick = self.config.hover_throttle_input();
+                let alt_err = cmd.target_altitude_m - self.position.z;
+                let throttle =
+                    (hover_stick + alt_err * 0.03 - self.velocity.z * 0.01).clamp(0.0, 1.0);
+
+                cmd.remaining_s -= dt;
+                if cmd.remaining_s <= 0.0 {
+                    self.active_command = None;
+                    self.flight_mode = FpvFlightMode::Angle;
+                    FpvStickInput::hover()
+                } else {
+                    FpvStickInput::new(throttle, roll_stick, pitch_stick, yaw_stick)
+                }
+            }
+            None => FpvStickInput::hover(),
+        };
+
+        self.step(dt, &input);
+    }
+
+    /// Most recent synthetic IMU reading (see [`ImuConfig`] for the
+    /// bias/noise/sample-rate model).
+    pub fn imu_reading(&self) -> &ImuData {
+        &self.last_imu_reading
+    }
+
+    /// Current total wind velocity (steady mean + Dryden turbulence),
+    /// world frame, m/s.
+    pub fn wind_velocity(&self) -> Vec3 {
+        self.wind.mean_wind_vector() + self.turbulence_velocity
+    }
+
+    /// World-frame acceleration contributed by the attached `disturbance`
+    /// at the current `flight_time` (zero if none is attached or the
+    /// current cycle phase falls outside `active_duration_s`).
+    fn disturbance_acceleration(&self) -> Vec3 {
+        let Some(cfg) = self.disturbance else {
+            return Vec3::zeros();
+        };
+        let phase = self.flight_time % cfg.period_s.max(1e-9);
+        if phase >= cfg.active_duration_s {
+            return Vec3::zeros();
+        }
+        if cfg.body_frame {
+            self.orientation * cfg.amplitude
+        } else {
+            cfg.amplitude
+        }
+    }
+
+    /// Current swing state of `slung_load`, if one is attached and has been
+    /// stepped at least once.
+    pub fn slung_load_state(&self) -> Option<SlungLoadState> {
+        self.load_state
+    }
+
+    /// Advance the attached `slung_load`'s spherical-pendulum state by `dt`
+    /// using this tick's pre-update drone position/velocity as the anchor,
+    /// and return the rope tension's reaction force on the drone (zero if
+    /// no load is attached).
+    fn step_slung_load(&mut self, dt: f64, gravity: Vec3) -> Vec3 {
+        let Some(load_cfg) = self.slung_load else {
+            return Vec3::zeros();
+        };
+        let mut load = self.load_state.unwrap_or_else(|| SlungLoadState {
+            position: Position::new(
+                self.position.x,
+                self.position.y,
+                (self.position.z - load_cfg.rope_length_m).max(0.0),
+            ),
+            velocity: self.velocity,
+            swing_angle_rad: 0.0,
+        });
+
+        // Free-fall under gravity plus drag on the swing velocity (relative
+        // to the drone's own translation, so a steadily translating drone
+        // doesn't itself read as "drag" on the load).
+        let swing_velocity = load.velocity - self.velocity;
+        let drag_accel = -swing_velocity * load_cfg.drag_coefficient;
+        load.velocity += (gravity + drag_accel) * dt;
+        load.position += load.velocity * dt;
+
+        // Inextensible-rope constraint: project back onto the sphere of
+        // radius `rope_length_m` centered on the drone, then strip the
+        // rope-aligned component of the swing velocity so it can't stretch.
+        let anchor = self.position;
+        let offset_len = (load.position - anchor).norm().max(1e-6);
+        let radial_dir = (load.position - anchor) / offset_len;
+        load.position = anchor + radial_dir * load_cfg.rope_length_m;
+        let mut swing_velocity = load.velocity - self.velocity;
+        let radial_speed = swing_velocity.dot(&radial_dir);
+        swing_velocity -= radial_dir * radial_speed;
+        load.velocity = self.velocity + swing_velocity;
+        load.swing_angle_rad = radial_dir.angle(&Vec3::new(0.0, 0.0, -1.0));
+
+        // The rope tension that just canceled that outward radial velocity
+        // (impulse / dt) pulls the load toward the drone; by Newton's third
+        // law the reaction pulls the drone toward the load.
+        let tension_on_load = radial_dir * (-radial_speed * load_cfg.mass_kg / dt.max(1e-9));
+        let reaction_force = -tension_on_load;
+
+        self.load_state = Some(load);
+        reaction_force
     }
 
     /// Get current FPV state snapshot
@@ -933,6 +2779,7 @@ impl FpvPhysics {
             FpvFlightMode::Acro => "ACRO",
             FpvFlightMode::Angle => "ANGL",
             FpvFlightMode::Horizon => "HOR",
+            FpvFlightMode::Drift => "DRFT",
         };
 
         FpvState {
@@ -941,7 +2788,11 @@ impl FpvPhysics {
             orientation: self.orientation,
             velocity: self.velocity,
             angular_velocity: self.angular_velocity,
-            motor_rpms: self.motor_outputs.iter().map(|o| o * 25000.0).collect(),
+            motor_rpms: self
+                .motor_outputs
+                .iter()
+                .map(|o| o * MAX_MOTOR_RPM)
+                .collect(),
             motor_outputs: self.motor_outputs.clone(),
             battery_remaining: self.battery_remaining,
             battery_voltage: self.battery_voltage,
@@ -964,9 +2815,11 @@ impl FpvPhysics {
                 warnings,
                 show_crosshair: true,
             },
+            imu: self.last_imu_reading.clone(),
             timestamp: self.flight_time,
             speed_mps: speed,
             altitude_m: alt,
+            slung_load: self.load_state,
         }
     }
 
@@ -1098,4 +2951,977 @@ mod tests {
         assert_eq!(input.pitch, 1.0);
         assert_eq!(input.yaw, -1.0);
     }
+
+    #[test]
+    fn test_mix_quad_x_pure_throttle_is_uniform() {
+        let motors = mix_quad_x(0.6, 0.0, 0.0, 0.0);
+        for m in motors {
+            assert!((m - 0.6).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_mix_quad_x_yaw_splits_diagonal_pairs() {
+        // Positive yaw should speed up one diagonal pair and slow the other.
+        let motors = mix_quad_x(0.5, 0.0, 0.0, 0.3);
+        assert!(motors[1] > motors[0]);
+        assert!(motors[2] > motors[0]);
+        assert!((motors[1] - motors[2]).abs() < 1e-10);
+        assert!((motors[0] - motors[3]).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_mix_quad_x_pure_roll_produces_asymmetric_motor_outputs() {
+        // A pure roll command with no pitch/yaw should speed up the
+        // right-side motors and slow the left-side ones (or vice versa),
+        // never leave all four motors equal.
+        let motors = mix_quad_x(0.5, 0.3, 0.0, 0.0);
+        assert!((motors[0] - motors[1]).abs() < 1e-10);
+        assert!((motors[2] - motors[3]).abs() < 1e-10);
+        assert!((motors[0] - motors[2]).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_mix_quad_x_clamps_to_valid_range() {
+        let motors = mix_quad_x(1.0, 1.0, 1.0, 1.0);
+        for m in motors {
+            assert!((0.0..=1.0).contains(&m));
+        }
+    }
+
+    #[test]
+    fn test_motor_mixer_quad_x_matches_mix_quad_x_when_unsaturated() {
+        let mixer = MotorMixer::new(FrameType::QuadX);
+        let out = mixer.mix(0.5, 0.1, -0.05, 0.2);
+        let expected = mix_quad_x(0.5, 0.1, -0.05, 0.2);
+        for (a, b) in out.motor_outputs.iter().zip(expected.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+        assert!(out.tail_servo_angle.is_none());
+    }
+
+    #[test]
+    fn test_motor_mixer_airmode_shift_preserves_differential_authority() {
+        // Naive clamping of mix_quad_x loses the roll differential once any
+        // motor saturates above 1.0; the desaturating mixer should not.
+        let naive = mix_quad_x(0.9, 0.9, 0.0, 0.0);
+        let spread_after_clamp = naive.iter().cloned().fold(f64::MIN, f64::max)
+            - naive.iter().cloned().fold(f64::MAX, f64::min);
+
+        let mixer = MotorMixer::new(FrameType::QuadX);
+        let out = mixer.mix(0.9, 0.9, 0.0, 0.0);
+        let spread_after_desaturation = out.motor_outputs.iter().cloned().fold(f64::MIN, f64::max)
+            - out.motor_outputs.iter().cloned().fold(f64::MAX, f64::min);
+
+        assert!(spread_after_desaturation > spread_after_clamp);
+        for m in &out.motor_outputs {
+            assert!((0.0..=1.0).contains(m));
+        }
+    }
+
+    #[test]
+    fn test_motor_mixer_frame_motor_counts() {
+        assert_eq!(MotorMixer::new(FrameType::QuadX).motor_count(), 4);
+        assert_eq!(MotorMixer::new(FrameType::Hex).motor_count(), 6);
+        assert_eq!(MotorMixer::new(FrameType::OctaQuad).motor_count(), 8);
+        assert_eq!(MotorMixer::new(FrameType::Tri).motor_count(), 3);
+    }
+
+    #[test]
+    fn test_motor_mixer_tri_reports_tail_servo_yaw_instead_of_a_fourth_rotor() {
+        let mixer = MotorMixer::new(FrameType::Tri);
+        let out = mixer.mix(0.5, 0.0, 0.0, 0.4);
+        assert_eq!(out.motor_outputs.len(), 3);
+        assert!((out.tail_servo_angle.unwrap() - 0.4).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rotor_params_scale_with_air_density() {
+        let config = FpvDroneConfig::five_inch_race();
+        let sea_level = RotorParams::from_config(&config, SEA_LEVEL_AIR_DENSITY_KG_M3);
+        let thin_air = RotorParams::from_config(&config, SEA_LEVEL_AIR_DENSITY_KG_M3 * 0.8);
+        assert!(thin_air.max_thrust_n < sea_level.max_thrust_n);
+        assert!(thin_air.max_reaction_torque_nm < sea_level.max_reaction_torque_nm);
+    }
+
+    #[test]
+    fn test_imu_samples_at_configured_rate_not_every_step() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.config.imu.sample_rate_hz = 100.0; // 10ms period
+        physics.set_armed(true);
+
+        for _ in 0..9 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+        // Fewer than 10ms have elapsed, so no sample has been taken yet.
+        assert_eq!(physics.imu_reading().timestamp, 0.0);
+
+        physics.step(0.001, &FpvStickInput::hover());
+        // 10ms have now elapsed: the IMU should have refreshed.
+        assert!((physics.imu_reading().timestamp - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_imu_specific_force_is_near_zero_while_hovering() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.config.imu.sample_rate_hz = 1000.0;
+        physics.config.imu.accel_noise_std = 0.0;
+        physics.config.imu.gyro_noise_std = 0.0;
+        physics.config.imu.accel_bias_walk_std = 0.0;
+        physics.config.imu.gyro_bias_walk_std = 0.0;
+        physics.set_armed(true);
+
+        let hover_input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, 0.0, 0.0);
+        for _ in 0..2000 {
+            physics.step(0.001, &hover_input);
+        }
+
+        // A hovering accelerometer doesn't read zero: it senses the thrust
+        // holding the drone up against gravity, i.e. ~1g on the body Z axis,
+        // with negligible roll/pitch-axis specific force once settled.
+        let reading = physics.imu_reading().linear_acceleration;
+        assert!((reading.z - 9.81).abs() < 1.0, "z = {}", reading.z);
+        assert!(reading.x.abs() < 1.0, "x = {}", reading.x);
+        assert!(reading.y.abs() < 1.0, "y = {}", reading.y);
+    }
+
+    #[test]
+    fn test_gyro_spectrum_analyzer_finds_a_250_hz_vibration_peak() {
+        let sample_rate_hz = 2000.0;
+        let window = 256;
+        let freq_hz = 250.0;
+        let mut analyzer = GyroSpectrumAnalyzer::new(sample_rate_hz, window);
+
+        let mut spectrum = None;
+        for i in 0..window {
+            let t = i as f64 / sample_rate_hz;
+            let sample = (2.0 * std::f64::consts::PI * freq_hz * t).sin();
+            spectrum = analyzer.push(sample);
+        }
+
+        let spectrum = spectrum.expect("window should be full after `window` pushes");
+        let bin_hz = sample_rate_hz / window as f64;
+        assert!(
+            (spectrum.dominant_frequency_hz - freq_hz).abs() < bin_hz,
+            "dominant frequency = {}",
+            spectrum.dominant_frequency_hz
+        );
+    }
+
+    #[test]
+    fn test_motor_vibration_shows_up_as_a_psd_peak_at_the_motor_rotation_frequency() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.imu.accel_noise_std = 0.0;
+        config.imu.gyro_noise_std = 0.0;
+        config.imu.accel_bias_walk_std = 0.0;
+        config.imu.gyro_bias_walk_std = 0.0;
+        config.imu.vibration_amplitude = Vec3::new(0.2, 0.0, 0.0);
+        config.imu.sample_rate_hz = 2000.0;
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        let hover_input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, 0.0, 0.0);
+        // Run past the spin-up/leveling transient so motor output settles.
+        for _ in 0..2000 {
+            physics.step(0.0005, &hover_input);
+        }
+        let hover_motor_output =
+            physics.motor_outputs.iter().sum::<f64>() / physics.motor_outputs.len() as f64;
+        let expected_hz = hover_motor_output * MAX_MOTOR_RPM / 60.0;
+
+        let mut analyzer = GyroSpectrumAnalyzer::new(2000.0, 256);
+        let mut spectrum = None;
+        for _ in 0..256 {
+            physics.step(0.0005, &hover_input);
+            if let Some(s) = analyzer.push(physics.imu_reading().angular_velocity.x) {
+                spectrum = Some(s);
+            }
+        }
+
+        let spectrum = spectrum.expect("should have filled a window");
+        let bin_hz = 2000.0 / 256.0;
+        assert!(
+            (spectrum.dominant_frequency_hz - expected_hz).abs() < bin_hz * 2.0,
+            "dominant = {}, expected ~{}",
+            spectrum.dominant_frequency_hz,
+            expected_hz
+        );
+    }
+
+    #[test]
+    fn test_gaussian_noise_mean_and_variance_match_the_configured_sigma() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let std_dev = 0.1_f64.to_radians();
+        let samples: Vec<f64> = (0..20_000)
+            .map(|_| gaussian_noise(&mut rng, std_dev))
+            .collect();
+
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance =
+            samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+
+        // The noise is zero-mean, so with enough samples it should track the
+        // true (noise-free) value, and the sample variance should converge
+        // to the configured sigma squared.
+        assert!(mean.abs() < 1e-4, "mean = {}", mean);
+        assert!(
+            (variance - std_dev.powi(2)).abs() < std_dev.powi(2) * 0.1,
+            "variance = {}, expected ~{}",
+            variance,
+            std_dev.powi(2)
+        );
+    }
+
+    #[test]
+    fn test_attitude_estimate_tracks_truth_with_a_clean_imu() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.imu.accel_noise_std = 0.0;
+        config.imu.gyro_noise_std = 0.0;
+        config.imu.accel_bias_walk_std = 0.0;
+        config.imu.gyro_bias_walk_std = 0.0;
+        config.imu.accel_bias = Vec3::zeros();
+        config.imu.gyro_bias = Vec3::zeros();
+        config.imu.sample_rate_hz = 1000.0;
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.set_flight_mode(FpvFlightMode::Acro);
+
+        // Roll for a bit, then hold level long enough for the Mahony filter
+        // to settle, and check the estimate agrees with ground truth.
+        for _ in 0..500 {
+            physics.step(0.001, &FpvStickInput::new(0.0, 0.0, 0.0, 0.3));
+        }
+        for _ in 0..2000 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+
+        let truth = physics.orientation;
+        let estimate = physics.estimated_orientation();
+        let angle_error = (truth.inverse() * estimate).angle();
+        assert!(angle_error < 0.05, "angle_error = {angle_error}");
+    }
+
+    #[test]
+    fn test_angle_mode_self_levels_using_the_estimated_attitude() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.default_mode = FpvFlightMode::Angle;
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        let hover_input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.3, 0.0, 0.0);
+        for _ in 0..3000 {
+            physics.step(0.001, &hover_input);
+        }
+
+        // Angle mode drives toward a target angle derived from the
+        // *estimated* attitude, so the estimate should have converged
+        // toward a meaningful roll angle along with ground truth.
+        let (est_roll, _, _) = physics.attitude_estimator.orientation.euler_angles();
+        let (truth_roll, _, _) = physics.orientation.euler_angles();
+        assert!(est_roll.abs() > 0.05, "est_roll = {est_roll}");
+        assert!((est_roll - truth_roll).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_angle_mode_levels_from_inverted_without_sign_flips() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.default_mode = FpvFlightMode::Angle;
+        let spawn = Point3::new(0.0, 0.0, 50.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.orientation = Rotation::from_axis_angle(&Vec3::x_axis(), std::f64::consts::PI);
+        physics.attitude_estimator.orientation = physics.orientation;
+        physics.set_armed(true);
+
+        fn tilt_deg(physics: &FpvPhysics) -> f64 {
+            let body_up = physics.orientation * Vec3::new(0.0, 0.0, 1.0);
+            body_up
+                .dot(&Vec3::new(0.0, 0.0, 1.0))
+                .clamp(-1.0, 1.0)
+                .acos()
+                .to_degrees()
+        }
+
+        let hover_input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, 0.0, 0.0);
+        let mut prev_tilt = tilt_deg(&physics);
+        assert!((prev_tilt - 180.0).abs() < 1.0, "prev_tilt = {prev_tilt}");
+        for _ in 0..5000 {
+            physics.step(0.001, &hover_input);
+            let tilt = tilt_deg(&physics);
+            assert!(
+                tilt <= prev_tilt + 1.0,
+                "tilt increased or flipped: {prev_tilt} -> {tilt}"
+            );
+            prev_tilt = tilt;
+        }
+        assert!(prev_tilt < 20.0, "final tilt = {prev_tilt}");
+    }
+
+    #[test]
+    fn test_drift_mode_banks_and_coordinates_yaw_from_roll_input() {
+        let config = FpvDroneConfig::seven_inch_longrange();
+        let spawn = Point3::new(0.0, 0.0, 50.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.set_flight_mode(FpvFlightMode::Drift);
+
+        // Cruise forward first so there's airspeed for the coordinated-turn
+        // relation to act on, then bank right.
+        let cruise = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, -0.4, 0.0);
+        for _ in 0..2000 {
+            physics.step(0.001, &cruise);
+        }
+        let bank_right = FpvStickInput::new(physics.config.hover_throttle_input(), 0.5, -0.4, 0.0);
+        for _ in 0..500 {
+            physics.step(0.001, &bank_right);
+        }
+
+        let (roll, _, _) = physics.euler_angles();
+        assert!(roll > 0.05, "roll = {roll}");
+        // Banking right with forward airspeed should coordinate a non-zero
+        // yaw rate without any yaw-stick input.
+        assert!(physics.angular_velocity.z.abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_drift_mode_washout_reduces_sideslip_once_level() {
+        let config = FpvDroneConfig::seven_inch_longrange();
+        let spawn = Point3::new(0.0, 0.0, 50.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.set_flight_mode(FpvFlightMode::Drift);
+
+        // Kick in some lateral velocity directly, then fly level and let
+        // the washout term wash it out.
+        physics.velocity.y = 5.0;
+        let level = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, 0.0, 0.0);
+        for _ in 0..3000 {
+            physics.step(0.001, &level);
+        }
+
+        assert!(physics.velocity_body().y.abs() < 5.0);
+    }
+
+    #[test]
+    fn test_command_attitude_drives_toward_the_commanded_angle_without_sticks() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.command_attitude(20.0, 0.0, 0.0, 0.6);
+        assert!(physics.is_externally_controlled());
+
+        // Neutral stick input shouldn't reassert manual control, and the
+        // offboard setpoint should be honored with no stick deflection.
+        for _ in 0..2000 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+
+        assert!(physics.is_externally_controlled());
+        let (roll, _, _) = physics.euler_angles();
+        assert!(roll > 0.1, "roll = {roll}");
+    }
+
+    #[test]
+    fn test_command_body_rates_feeds_the_rate_pid_directly() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.command_body_rates(0.0, 0.0, 90.0, 0.6);
+        for _ in 0..50 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+
+        assert!(
+            physics.angular_velocity.z > 0.1,
+            "z = {}",
+            physics.angular_velocity.z
+        );
+    }
+
+    #[test]
+    fn test_stick_deflection_reasserts_manual_control_over_an_offboard_setpoint() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.command_attitude(20.0, 0.0, 0.0, 0.6);
+        assert!(physics.is_externally_controlled());
+
+        physics.step(0.001, &FpvStickInput::new(0.6, 0.5, 0.0, 0.0));
+        assert!(!physics.is_externally_controlled());
+    }
+
+    #[test]
+    fn test_calm_wind_has_no_mean_or_turbulence() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.step(0.001, &FpvStickInput::hover());
+
+        assert_eq!(physics.wind_velocity(), Vec3::zeros());
+    }
+
+    #[test]
+    fn test_gusty_wind_displaces_a_hovering_drone_downwind() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.wind = WindConfig::gusty();
+        physics.set_armed(true);
+
+        let hover_input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, 0.0, 0.0);
+        for _ in 0..2000 {
+            physics.step(0.001, &hover_input);
+        }
+
+        // A sustained crosswind should push the otherwise-hovering drone off
+        // its spawn point.
+        let dx = physics.position.x - spawn.x;
+        let dy = physics.position.y - spawn.y;
+        let drift = (dx * dx + dy * dy).sqrt();
+        assert!(drift > 1.0, "drift = {}", drift);
+    }
+
+    #[test]
+    fn test_angle_mode_holds_a_steady_drift_against_constant_crosswind() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.default_mode = FpvFlightMode::Angle;
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        // Steady mean wind with no Dryden turbulence, so the drift settles
+        // to a deterministic terminal velocity rather than jittering.
+        physics.wind = WindConfig {
+            mean_speed_mps: 5.0,
+            direction_deg: 0.0,
+            turbulence_intensity_mps: 0.0,
+            turbulence_length_scale_m: 200.0,
+        };
+        physics.set_armed(true);
+
+        let hover_input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.0, 0.0, 0.0);
+        for _ in 0..4000 {
+            physics.step(0.001, &hover_input);
+        }
+        let velocity_a = physics.velocity;
+        for _ in 0..1000 {
+            physics.step(0.001, &hover_input);
+        }
+        let velocity_b = physics.velocity;
+
+        // Angle mode tilts into the wind until relative-airspeed drag
+        // balances thrust's horizontal component, so the drift velocity
+        // should stop changing -- an uncorrected free drift would keep
+        // accelerating toward the wind speed instead.
+        assert!(
+            (velocity_b - velocity_a).norm() < 0.05,
+            "velocity drifted from {:?} to {:?}",
+            velocity_a,
+            velocity_b
+        );
+        let (roll, pitch, _) = physics.euler_angles();
+        assert!(
+            roll.abs() > 0.01 || pitch.abs() > 0.01,
+            "expected the controller to tilt against the wind: roll={roll}, pitch={pitch}"
+        );
+    }
+
+    #[test]
+    fn test_periodic_disturbance_is_only_active_for_its_configured_duration() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.disturbance = Some(PeriodicDisturbanceConfig {
+            amplitude: Vec3::new(2.0, 0.0, 0.0),
+            period_s: 1.0,
+            active_duration_s: 0.2,
+            body_frame: false,
+        });
+
+        physics.flight_time = 0.05;
+        assert_eq!(physics.disturbance_acceleration(), Vec3::new(2.0, 0.0, 0.0));
+
+        physics.flight_time = 0.5;
+        assert_eq!(physics.disturbance_acceleration(), Vec3::zeros());
+
+        physics.flight_time = 1.1;
+        assert_eq!(physics.disturbance_acceleration(), Vec3::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_slung_load_swings_at_roughly_the_pendulum_natural_period() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        let rope_length_m = 1.0;
+        physics.slung_load = Some(SlungLoadConfig {
+            rope_length_m,
+            mass_kg: 0.5,
+            drag_coefficient: 0.0,
+        });
+
+        let gravity = Vec3::new(0.0, 0.0, -9.81);
+        let dt = 0.001;
+        // Let the load settle to hanging straight down first.
+        for _ in 0..2000 {
+            physics.step_slung_load(dt, gravity);
+        }
+        assert!(physics.slung_load_state().unwrap().swing_angle_rad < 1e-6);
+
+        // A step horizontal translation excites the pendulum. Carry the
+        // drone forward at that constant velocity afterward so this test
+        // isolates the pendulum dynamics from the rest of the flight model.
+        physics.velocity.x = 3.0;
+        let mut angle_samples = Vec::new();
+        for _ in 0..3000 {
+            physics.step_slung_load(dt, gravity);
+            physics.position += physics.velocity * dt;
+            angle_samples.push(physics.slung_load_state().unwrap().swing_angle_rad);
+        }
+
+        // The swing angle (unsigned) first peaks a quarter period after the
+        // kick, at roughly `sqrt(g/length)` rad/s' worth of phase.
+        let mut first_peak_time = None;
+        for i in 1..angle_samples.len() - 1 {
+            if angle_samples[i] >= angle_samples[i - 1] && angle_samples[i] > angle_samples[i + 1] {
+                first_peak_time = Some(i as f64 * dt);
+                break;
+            }
+        }
+        let first_peak_time = first_peak_time.expect("load should swing up to a peak");
+        let expected_quarter_period =
+            0.25 * 2.0 * std::f64::consts::PI * (rope_length_m / 9.81_f64).sqrt();
+        assert!(
+            (first_peak_time - expected_quarter_period).abs() / expected_quarter_period < 0.3,
+            "first peak at {}, expected ~{}",
+            first_peak_time,
+            expected_quarter_period
+        );
+    }
+
+    #[test]
+    fn test_slung_load_swing_damps_out_under_rope_drag() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.slung_load = Some(SlungLoadConfig {
+            rope_length_m: 1.0,
+            mass_kg: 0.5,
+            drag_coefficient: 1.5,
+        });
+
+        let gravity = Vec3::new(0.0, 0.0, -9.81);
+        let dt = 0.001;
+        physics.velocity.x = 3.0;
+
+        let mut peak_angles = Vec::new();
+        let mut rising = false;
+        let mut previous_angle = 0.0;
+        for _ in 0..20_000 {
+            physics.step_slung_load(dt, gravity);
+            physics.position += physics.velocity * dt;
+            let angle = physics.slung_load_state().unwrap().swing_angle_rad;
+            if rising && angle < previous_angle {
+                peak_angles.push(previous_angle);
+            }
+            rising = angle > previous_angle;
+            previous_angle = angle;
+        }
+
+        assert!(
+            peak_angles.len() >= 2,
+            "expected multiple swing peaks, got {}",
+            peak_angles.len()
+        );
+        let first_peak = peak_angles[0];
+        let last_peak = *peak_angles.last().unwrap();
+        assert!(
+            last_peak < first_peak * 0.7,
+            "swing should damp out: first peak = {}, last peak = {}",
+            first_peak,
+            last_peak
+        );
+    }
+
+    #[test]
+    fn test_motors_spin_up_gradually_not_instantly() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.step(0.001, &FpvStickInput::hover());
+
+        // After a single 1ms tick the lagged motor output should have moved
+        // toward the commanded throttle but not have snapped straight to it.
+        let throttle_cmd = physics.config.rates.throttle_curve(0.5);
+        assert!(physics.motor_outputs[0] > 0.0);
+        assert!(physics.motor_outputs[0] < throttle_cmd);
+    }
+
+    #[test]
+    fn test_blackbox_is_not_recorded_unless_attached() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.step(0.001, &FpvStickInput::hover());
+        assert!(physics.blackbox.is_none());
+    }
+
+    #[test]
+    fn test_blackbox_records_one_frame_per_step() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.blackbox = Some(FpvBlackbox::new(100));
+
+        for _ in 0..10 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+
+        let blackbox = physics.blackbox.as_ref().unwrap();
+        assert_eq!(blackbox.len(), 10);
+        assert_eq!(blackbox.frames()[0].motor_outputs.len(), 4);
+    }
+
+    #[test]
+    fn test_blackbox_ring_buffer_evicts_oldest_frame_past_capacity() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.blackbox = Some(FpvBlackbox::new(3));
+
+        for _ in 0..5 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+
+        let blackbox = physics.blackbox.as_ref().unwrap();
+        assert_eq!(blackbox.len(), 3);
+    }
+
+    #[test]
+    fn test_blackbox_csv_export_has_one_header_row_and_one_row_per_frame() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.blackbox = Some(FpvBlackbox::new(100));
+
+        for _ in 0..4 {
+            physics.step(0.001, &FpvStickInput::hover());
+        }
+
+        let csv = physics.blackbox.as_ref().unwrap().export_csv();
+        assert_eq!(csv.lines().count(), 5);
+        assert!(csv.lines().next().unwrap().starts_with("timestamp,"));
+    }
+
+    #[test]
+    fn test_blackbox_betaflight_export_uses_betaflight_field_names() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.blackbox = Some(FpvBlackbox::new(100));
+
+        physics.step(0.001, &FpvStickInput::hover());
+
+        let csv = physics.blackbox.as_ref().unwrap().export_betaflight_csv();
+        let header = csv.lines().next().unwrap();
+        for field in [
+            "rcCommand[0]",
+            "axisP[0]",
+            "axisD[2]",
+            "motor[0]",
+            "motor[3]",
+            "gyroADC[0]",
+        ] {
+            assert!(header.contains(field), "missing field {field} in {header}");
+        }
+    }
+
+    #[test]
+    fn test_rate_pid_accumulates_integral_term_under_sustained_error() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        // A sustained roll stick deflection holds a nonzero rate error
+        // (the craft starts at rest, the stick demands a steady rate), so
+        // the I term should build up over many ticks.
+        let input = FpvStickInput::new(0.5, 0.3, 0.0, 0.0);
+        for _ in 0..50 {
+            physics.step(0.001, &input);
+        }
+
+        assert!(physics.integral_error.x.abs() > 0.0);
+    }
+
+    #[test]
+    fn test_disarming_resets_the_accumulated_integral_error() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        let input = FpvStickInput::new(0.5, 0.3, 0.0, 0.0);
+        for _ in 0..50 {
+            physics.step(0.001, &input);
+        }
+        assert!(physics.integral_error.x.abs() > 0.0);
+
+        physics.set_armed(false);
+        assert_eq!(physics.integral_error, Vec3::zeros());
+    }
+
+    #[test]
+    fn test_anti_windup_clamps_integral_error_to_the_configured_i_limit() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.pid_roll.i_limit = 0.01;
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        // A large, sustained rate error would wind the integral up far
+        // past 0.01 without the anti-windup clamp.
+        let input = FpvStickInput::new(0.5, 1.0, 0.0, 0.0);
+        for _ in 0..2000 {
+            physics.step(0.001, &input);
+        }
+
+        assert!(physics.integral_error.x.abs() <= 0.01 + 1e-9);
+    }
+
+    #[test]
+    fn test_acro_mode_rate_hold_converges_to_the_commanded_rate_via_the_i_term() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+        physics.set_flight_mode(FpvFlightMode::Acro);
+
+        // Hold a small constant roll rate long enough for the integral
+        // term to null out the steady-state error a proportional-only
+        // controller would leave from drag/gravity coupling.
+        let commanded_rate = physics.config.rates.roll_rate(0.2).to_radians();
+        let input = FpvStickInput::new(physics.config.hover_throttle_input(), 0.2, 0.0, 0.0);
+        for _ in 0..3000 {
+            physics.step(0.001, &input);
+        }
+
+        let rate_error = (commanded_rate - physics.angular_velocity.x).abs();
+        assert!(
+            rate_error < commanded_rate.abs() * 0.05,
+            "rate_error = {rate_error}, commanded = {commanded_rate}"
+        );
+    }
+
+    #[test]
+    fn test_tpa_attenuates_p_gain_above_breakpoint() {
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.tpa_breakpoint = 0.3;
+        config.tpa_rate = 0.5;
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        // Same rate error, two different throttle levels: below and above
+        // tpa_breakpoint. Set a tiny initial angular velocity so the P term
+        // is driven by the same rate error regardless of throttle.
+        physics.angular_velocity = Vec3::new(-1.0, 0.0, 0.0);
+        physics.step(0.001, &FpvStickInput::new(0.1, 0.0, 0.0, 0.0));
+        let p_below = physics.last_pid_terms.p.x;
+
+        let mut physics_high_throttle = FpvPhysics::new(FpvDroneConfig::five_inch_race(), spawn);
+        physics_high_throttle.config.tpa_breakpoint = 0.3;
+        physics_high_throttle.config.tpa_rate = 0.5;
+        physics_high_throttle.set_armed(true);
+        physics_high_throttle.angular_velocity = Vec3::new(-1.0, 0.0, 0.0);
+        physics_high_throttle.step(0.001, &FpvStickInput::new(0.9, 0.0, 0.0, 0.0));
+        let p_above = physics_high_throttle.last_pid_terms.p.x;
+
+        assert!(p_above.abs() < p_below.abs());
+    }
+
+    #[test]
+    fn test_anti_gravity_boosts_integral_gain_during_a_throttle_punch() {
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+
+        // Both start from previous_throttle = 0.0, but the second commands
+        // a much bigger throttle jump on this tick -- a much larger
+        // throttle derivative, which anti-gravity should turn into a
+        // bigger I-term boost.
+        let mut moderate = FpvPhysics::new(FpvDroneConfig::five_inch_race(), spawn);
+        moderate.set_armed(true);
+        moderate.angular_velocity = Vec3::new(-1.0, 0.0, 0.0);
+        moderate.step(0.001, &FpvStickInput::new(0.2, 0.0, 0.0, 0.0));
+        let integral_moderate = moderate.integral_error.x.abs();
+
+        let mut punch = FpvPhysics::new(FpvDroneConfig::five_inch_race(), spawn);
+        punch.set_armed(true);
+        punch.angular_velocity = Vec3::new(-1.0, 0.0, 0.0);
+        punch.step(0.001, &FpvStickInput::new(0.9, 0.0, 0.0, 0.0));
+        let integral_punch = punch.integral_error.x.abs();
+
+        assert!(integral_punch > integral_moderate);
+    }
+
+    #[test]
+    fn test_i_term_relax_attenuates_integral_during_a_fast_stick_move() {
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+
+        // A roll stick snapped straight from center is a large, sudden
+        // setpoint change relative to its own (initially zero) filtered
+        // average, so I-term relax should cut accumulation sharply.
+        let mut with_relax = FpvPhysics::new(FpvDroneConfig::five_inch_race(), spawn);
+        with_relax.set_armed(true);
+        with_relax.step(0.001, &FpvStickInput::new(0.5, 0.5, 0.0, 0.0));
+        let relaxed_integral = with_relax.integral_error.x.abs();
+
+        // Same scenario with an effectively infinite relax threshold --
+        // the attenuation never kicks in, so accumulation should be
+        // noticeably larger.
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.i_term_relax_threshold_deg_s = 1.0e9;
+        let mut without_relax = FpvPhysics::new(config, spawn);
+        without_relax.set_armed(true);
+        without_relax.step(0.001, &FpvStickInput::new(0.5, 0.5, 0.0, 0.0));
+        let unrelaxed_integral = without_relax.integral_error.x.abs();
+
+        assert!(relaxed_integral < unrelaxed_integral);
+    }
+
+    #[test]
+    fn test_step_motor_outputs_spins_up_motors_without_running_the_rate_pid() {
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(FpvDroneConfig::five_inch_race(), spawn);
+        physics.set_armed(true);
+        physics.step_motor_outputs(0.001, &[0.6, 0.6, 0.6, 0.6]);
+        assert!(physics.motor_outputs.iter().all(|&m| m > 0.0));
+        assert_eq!(physics.last_pid_terms.p, Vec3::zeros());
+        assert_eq!(physics.last_pid_terms.i, Vec3::zeros());
+    }
+
+    #[test]
+    fn test_step_motor_outputs_does_nothing_while_disarmed() {
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(FpvDroneConfig::five_inch_race(), spawn);
+        physics.step_motor_outputs(0.001, &[0.6, 0.6, 0.6, 0.6]);
+        assert!(physics.motor_outputs.iter().all(|&m| m == 0.0));
+    }
+
+    #[test]
+    fn test_same_seed_produces_identical_rollouts() {
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut config = FpvDroneConfig::five_inch_race();
+        config.seed = 123;
+
+        let mut a = FpvPhysics::new(config.clone(), spawn);
+        let mut b = FpvPhysics::new(config, spawn);
+        a.wind = WindConfig::gusty();
+        b.wind = WindConfig::gusty();
+        a.set_armed(true);
+        b.set_armed(true);
+        for _ in 0..200 {
+            a.step(0.001, &FpvStickInput::new(0.2, -0.1, 0.3, 0.6));
+            b.step(0.001, &FpvStickInput::new(0.2, -0.1, 0.3, 0.6));
+        }
+
+        assert_eq!(a.position, b.position);
+        assert_eq!(
+            a.last_imu_reading.linear_acceleration,
+            b.last_imu_reading.linear_acceleration
+        );
+    }
+
+    #[test]
+    fn test_different_seeds_diverge_under_wind_noise() {
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut config_a = FpvDroneConfig::five_inch_race();
+        config_a.seed = 1;
+        let mut config_b = config_a.clone();
+        config_b.seed = 2;
+
+        let mut a = FpvPhysics::new(config_a, spawn);
+        let mut b = FpvPhysics::new(config_b, spawn);
+        a.wind = WindConfig::gusty();
+        b.wind = WindConfig::gusty();
+        a.set_armed(true);
+        b.set_armed(true);
+        for _ in 0..200 {
+            a.step(0.001, &FpvStickInput::hover());
+            b.step(0.001, &FpvStickInput::hover());
+        }
+
+        assert!((a.position - b.position).norm() > 1e-9);
+    }
+
+    #[test]
+    fn test_rate_inv_round_trips_through_rate_curve() {
+        let rates = RatesProfile::race();
+        for &target in &[-300.0, -50.0, 0.0, 50.0, 300.0] {
+            let stick = rates.roll_rate_inv(target);
+            assert!(
+                (rates.roll_rate(stick) - target).abs() < 1.0,
+                "target = {}",
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn test_move_by_rates_z_switches_to_acro_then_back_to_angle() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.move_by_rates_z(0.0, 0.0, 90.0, 10.0, 0.05);
+        assert_eq!(physics.flight_mode, FpvFlightMode::Acro);
+        assert!(physics.has_active_command());
+
+        for _ in 0..50 {
+            physics.step_command(0.001);
+        }
+        assert!(physics.has_active_command());
+
+        // Duration is 50ms; after 60 more 1ms ticks the command should have
+        // elapsed and handed control back to a self-leveling hover.
+        for _ in 0..60 {
+            physics.step_command(0.001);
+        }
+        assert!(!physics.has_active_command());
+        assert_eq!(physics.flight_mode, FpvFlightMode::Angle);
+    }
+
+    #[test]
+    fn test_move_by_rates_z_commands_a_positive_yaw_rate() {
+        let config = FpvDroneConfig::five_inch_race();
+        let spawn = Point3::new(0.0, 0.0, 10.0);
+        let mut physics = FpvPhysics::new(config, spawn);
+        physics.set_armed(true);
+
+        physics.move_by_rates_z(0.0, 0.0, 120.0, 10.0, 1.0);
+        for _ in 0..200 {
+            physics.step_command(0.001);
+        }
+
+        assert!(physics.angular_velocity.z > 0.0);
+    }
 }
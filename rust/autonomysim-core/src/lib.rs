@@ -51,37 +51,90 @@
 //! }
 //! ```
 
+pub mod adsb;
 pub mod backend;
+pub mod behavior;
+pub mod collision;
+pub mod dynamics_estimator;
 pub mod fpv;
 pub mod native;
+pub mod raycast_vehicle;
+pub mod reaction_wheel;
+pub mod rl_env;
+pub mod scenario;
 pub mod sensor;
+pub mod swarm;
+pub mod tire;
+pub mod vec_env;
 pub mod vehicle;
 
 // Re-exports for convenience
 pub use backend::{
-    BackendConfig, BackendFactory, BackendType, Geometry, Material, Position, Ray, RayHit,
+    BackendConfig, BackendFactory, BackendType, Geometry, Material, Position, Ray, RayHit, RfPath,
     Rotation, SceneHandle, SceneObject, SimError, SimResult, SimulationBackend, Transform, Vec3,
 };
-pub use sensor::{CameraData, GpsData, ImuData, LidarData, SensorData};
-pub use vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType};
+pub use behavior::{RobotState, StateMachine, StateMachineContext};
+pub use collision::{
+    depenetration_step, start_depenetration, sweep_collision, DepenetrationState, SweepConfig,
+    SweepResult,
+};
+pub use dynamics_estimator::{DynamicsEstimator, SubmodelConfig, SubmodelState};
 pub use fpv::{
-    FpvCameraConfig, FpvDroneConfig, FpvFlightMode, FpvOsd, FpvPhysics, FpvState,
-    FpvStickInput, PidGains, RatesProfile,
+    BlackboxFrame, FpvBlackbox, FpvCameraConfig, FpvDroneConfig, FpvFlightMode, FpvOsd, FpvPhysics,
+    FpvState, FpvStickInput, FrameType, GyroSpectrum, GyroSpectrumAnalyzer, MahonyConfig,
+    MixerOutput, MotorMixer, PeriodicDisturbanceConfig, PidGains, PidTerms, RatesProfile,
+    SlungLoadConfig, SlungLoadState,
+};
+pub use raycast_vehicle::{
+    step_raycast_vehicle, RaycastVehicleConfig, RaycastVehicleStep, WheelConfig,
 };
+pub use reaction_wheel::{allocate_wheel_torques, ReactionWheelConfig};
+pub use rl_env::{
+    EpisodeEvent, FpvAction, FpvEnv, FpvEnvConfig, FpvGoalEnv, FpvObservation, FpvRaceEnv,
+    FpvReward, FpvStepResult, GateObservation, GateWaypoint, HoverReward, WaypointNavReward,
+};
+pub use scenario::{parse_scenario, GeodeticPosition, Scenario, ScenarioVehicle, TimedCommand};
+pub use sensor::{CameraData, GeoProjection, GpsData, ImuData, LidarData, SensorData};
+pub use swarm::{Flock, FlockConfig, Formation, FormationSpec};
+pub use tire::{compute_tire_force, PacejkaCoefficients, TireForce, TireParameters};
+pub use vec_env::{SpaceDescriptor, VecEnv, VecInfo, VecObservation, VecReward};
+pub use vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType};
 
 /// Prelude module for common imports
 pub mod prelude {
     pub use crate::backend::{
         BackendConfig, BackendFactory, BackendType, Geometry, Material, Position, Ray, RayHit,
-        Rotation, SceneHandle, SceneObject, SimError, SimResult, SimulationBackend, Transform,
-        Vec3,
+        RfPath, Rotation, SceneHandle, SceneObject, SimError, SimResult, SimulationBackend,
+        Transform, Vec3,
     };
-    pub use crate::sensor::{CameraData, GpsData, ImuData, LidarData, SensorData};
-    pub use crate::vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType};
+    pub use crate::behavior::{RobotState, StateMachine, StateMachineContext};
+    pub use crate::collision::{
+        depenetration_step, start_depenetration, sweep_collision, DepenetrationState, SweepConfig,
+        SweepResult,
+    };
+    pub use crate::dynamics_estimator::{DynamicsEstimator, SubmodelConfig, SubmodelState};
     pub use crate::fpv::{
-        FpvCameraConfig, FpvDroneConfig, FpvFlightMode, FpvOsd, FpvPhysics, FpvState,
-        FpvStickInput, PidGains, RatesProfile,
+        BlackboxFrame, FpvBlackbox, FpvCameraConfig, FpvDroneConfig, FpvFlightMode, FpvOsd,
+        FpvPhysics, FpvState, FpvStickInput, FrameType, GyroSpectrum, GyroSpectrumAnalyzer,
+        MahonyConfig, MixerOutput, MotorMixer, PeriodicDisturbanceConfig, PidGains, PidTerms,
+        RatesProfile, SlungLoadConfig, SlungLoadState,
+    };
+    pub use crate::raycast_vehicle::{
+        step_raycast_vehicle, RaycastVehicleConfig, RaycastVehicleStep, WheelConfig,
+    };
+    pub use crate::reaction_wheel::{allocate_wheel_torques, ReactionWheelConfig};
+    pub use crate::rl_env::{
+        EpisodeEvent, FpvAction, FpvEnv, FpvEnvConfig, FpvGoalEnv, FpvObservation, FpvRaceEnv,
+        FpvReward, FpvStepResult, GateObservation, GateWaypoint, HoverReward, WaypointNavReward,
     };
+    pub use crate::scenario::{
+        parse_scenario, GeodeticPosition, Scenario, ScenarioVehicle, TimedCommand,
+    };
+    pub use crate::sensor::{CameraData, GeoProjection, GpsData, ImuData, LidarData, SensorData};
+    pub use crate::swarm::{Flock, FlockConfig, Formation, FormationSpec};
+    pub use crate::tire::{compute_tire_force, PacejkaCoefficients, TireForce, TireParameters};
+    pub use crate::vec_env::{SpaceDescriptor, VecEnv, VecInfo, VecObservation, VecReward};
+    pub use crate::vehicle::{VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType};
     pub use nalgebra::{Point3, Quaternion, UnitQuaternion, Vector3};
 }
 
@@ -9,13 +9,17 @@
 use crate::backend::*;
 use async_trait::async_trait;
 use nalgebra::{Point3, Vector3};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::sensor::{GpsData, ImuData, SensorData};
+use crate::sensor::{GpsData, ImuData, RadarData, RadarTarget, SensorData};
 use crate::vehicle::{
-    CollisionInfo, VehicleControl, VehicleId, VehicleSpec, VehicleState, VehicleType,
+    CollisionInfo, RadarConfig, RadarScanMode, SensorFault, SensorNoise, VehicleControl, VehicleId,
+    VehicleSpec, VehicleState, VehicleType,
 };
 
 /// Native Rust backend implementation
@@ -24,6 +28,20 @@ pub struct NativeBackend {
     vehicles: Arc<RwLock<HashMap<VehicleId, NativeVehicle>>>,
     time: f64,
     initialized: bool,
+    /// Whether `cast_rays` should fan out across a rayon parallel iterator.
+    parallel_ray_casting: bool,
+    /// Rays per rayon work item; `None` lets rayon pick automatically.
+    ray_cast_chunk_size: Option<usize>,
+    /// Dedicated thread pool for ray casting when `num_threads` was set in
+    /// `BackendConfig`; `None` falls back to rayon's global pool.
+    ray_cast_pool: Option<rayon::ThreadPool>,
+    /// Most recently loaded scene, used by `step` to raycast against the
+    /// ground/obstacles for vehicles with an opted-in raycast-vehicle model.
+    active_scene: Option<SceneHandle>,
+    /// Control commands scheduled by `load_scenario`, sorted ascending by
+    /// `time_s`; `step` applies and removes everything due by the current
+    /// sim time on every call.
+    pending_timeline: Vec<crate::scenario::TimedCommand>,
 }
 
 /// Vehicle representation in native backend
@@ -31,6 +49,153 @@ struct NativeVehicle {
     spec: VehicleSpec,
     state: VehicleState,
     control: VehicleControl,
+    /// Per-sensor noise accumulator state, keyed by `sensor_id`, lazily
+    /// created the first time that sensor is read. Behind a `Mutex` so
+    /// [`SimulationBackend::get_sensor_data`]'s `&self` receiver can still
+    /// advance the random-walk bias each call.
+    noise_runtimes: Mutex<HashMap<String, SensorNoiseRuntime>>,
+    /// Per-sensor fault state, keyed by `sensor_id`, for the `SensorFault`
+    /// variants that need to remember something across calls (the frozen
+    /// value for `StuckAtLastValue`, the RNG for `IntermittentGlitch`).
+    /// Cleared whenever [`SimulationBackend::set_sensor_fault`] changes the
+    /// fault on a sensor.
+    fault_runtimes: Mutex<HashMap<String, FaultRuntime>>,
+}
+
+/// Accumulated noise-generator state for one sensor, initialized from its
+/// [`SensorNoise`] seed the first time that sensor is sampled.
+enum SensorNoiseRuntime {
+    Imu {
+        rng: StdRng,
+        last_time: f64,
+        accel_bias: Vector3<f64>,
+        gyro_bias: Vector3<f64>,
+    },
+    Gps {
+        rng: StdRng,
+        last_time: f64,
+        position_bias: Vector3<f64>,
+    },
+}
+
+/// Per-sensor state needed to apply a [`SensorFault`] across repeated calls.
+enum FaultRuntime {
+    /// The reading most recently returned while the fault has been active,
+    /// re-served on every later call instead of the live value.
+    StuckAtLastValue { last_value: Option<SensorData> },
+    /// Seeded RNG drawn from on every call to decide whether this step
+    /// glitches.
+    IntermittentGlitch { rng: StdRng },
+}
+
+/// Sample zero-mean Gaussian noise via a Box-Muller transform, mirroring
+/// `fpv::gaussian_noise` so reproducible sensor corruption only needs the
+/// `rand` crate already used elsewhere in this codebase.
+fn gaussian_noise(rng: &mut StdRng, std_dev: f64) -> f64 {
+    use rand::Rng;
+    let u1 = rng.gen::<f64>().max(1e-12);
+    let u2 = rng.gen::<f64>();
+    let z0 = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    z0 * std_dev
+}
+
+/// Sample per-axis zero-mean Gaussian noise.
+fn gaussian_noise_vec3(rng: &mut StdRng, std_dev: f64) -> Vector3<f64> {
+    Vector3::new(
+        gaussian_noise(rng, std_dev),
+        gaussian_noise(rng, std_dev),
+        gaussian_noise(rng, std_dev),
+    )
+}
+
+/// Apply `fault` to an already-noisy reading, after it's been generated and
+/// corrupted by any `SensorNoise`. `fault_runtimes` holds the state shared
+/// across calls for the variants that need it.
+fn apply_sensor_fault(
+    fault_runtimes: &Mutex<HashMap<String, FaultRuntime>>,
+    sensor_id: &str,
+    fault: &SensorFault,
+    time: f64,
+    data: SensorData,
+) -> SimResult<SensorData> {
+    match fault {
+        SensorFault::Dropout { until_time_s } => {
+            if time < *until_time_s {
+                return Err(SimError::BackendError(format!(
+                    "Sensor {} is in a dropout fault until t={:.3}s",
+                    sensor_id, until_time_s
+                )));
+            }
+            Ok(data)
+        }
+        SensorFault::ConstantOffset { offset } => Ok(offset_and_scale_reading(data, *offset, 1.0)),
+        SensorFault::ScaleCorruption { factor } => {
+            Ok(offset_and_scale_reading(data, Vector3::zeros(), *factor))
+        }
+        SensorFault::StuckAtLastValue => {
+            let mut runtimes = fault_runtimes.lock();
+            let runtime = runtimes
+                .entry(sensor_id.to_string())
+                .or_insert_with(|| FaultRuntime::StuckAtLastValue { last_value: None });
+            let FaultRuntime::StuckAtLastValue { last_value } = runtime else {
+                return Ok(data);
+            };
+            let frozen = last_value.clone().unwrap_or(data);
+            *last_value = Some(frozen.clone());
+            Ok(frozen)
+        }
+        SensorFault::IntermittentGlitch {
+            probability_per_step,
+            trigger_time_s,
+            magnitude,
+            seed,
+        } => {
+            if trigger_time_s.is_some_and(|trigger| time < trigger) {
+                return Ok(data);
+            }
+            let mut runtimes = fault_runtimes.lock();
+            let runtime = runtimes.entry(sensor_id.to_string()).or_insert_with(|| {
+                FaultRuntime::IntermittentGlitch {
+                    rng: StdRng::seed_from_u64(*seed),
+                }
+            });
+            let FaultRuntime::IntermittentGlitch { rng } = runtime else {
+                return Ok(data);
+            };
+            use rand::Rng;
+            if rng.gen::<f64>() < *probability_per_step {
+                Ok(offset_and_scale_reading(
+                    data,
+                    Vector3::new(*magnitude, *magnitude, *magnitude),
+                    1.0,
+                ))
+            } else {
+                Ok(data)
+            }
+        }
+    }
+}
+
+/// Apply `reading * scale + offset` to the corruptible fields of a sensor
+/// reading: linear acceleration and angular velocity for IMU, position
+/// (converted from the same approximate degrees-per-meter factor used when
+/// the reading was generated) for GPS. Other sensor types pass through
+/// unchanged.
+fn offset_and_scale_reading(data: SensorData, offset: Vector3<f64>, scale: f64) -> SensorData {
+    match data {
+        SensorData::Imu(mut imu) => {
+            imu.linear_acceleration = imu.linear_acceleration * scale + offset;
+            imu.angular_velocity = imu.angular_velocity * scale + offset;
+            SensorData::Imu(imu)
+        }
+        SensorData::Gps(mut gps) => {
+            gps.latitude = gps.latitude * scale + offset.x / 111320.0;
+            gps.longitude = gps.longitude * scale + offset.y / 111320.0;
+            gps.altitude = gps.altitude * scale + offset.z;
+            SensorData::Gps(gps)
+        }
+        other => other,
+    }
 }
 
 impl NativeBackend {
@@ -40,7 +205,82 @@ impl NativeBackend {
             vehicles: Arc::new(RwLock::new(HashMap::new())),
             time: 0.0,
             initialized: false,
+            parallel_ray_casting: true,
+            ray_cast_chunk_size: None,
+            ray_cast_pool: None,
+            active_scene: None,
+            pending_timeline: Vec::new(),
+        }
+    }
+
+    /// Load a [`crate::scenario::Scenario`] from a TOML or YAML file,
+    /// spawning its scene and vehicles and scheduling its timeline of
+    /// control commands against sim time. Returns the spawned vehicles'
+    /// IDs in scenario order.
+    pub async fn load_scenario(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+    ) -> SimResult<Vec<VehicleId>> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let scenario = crate::scenario::parse_scenario(path, &text)?;
+
+        self.load_scene(&scenario.scene).await?;
+
+        let mut vehicle_ids = Vec::with_capacity(scenario.vehicles.len());
+        for scenario_vehicle in scenario.vehicles {
+            let mut spec = scenario_vehicle.spec;
+            if let Some(geodetic) = scenario_vehicle.geodetic_position {
+                let home = scenario.home.ok_or_else(|| {
+                    SimError::InvalidConfig(format!(
+                        "vehicle '{}' has a geodetic_position but the scenario has no home origin",
+                        spec.vehicle_id
+                    ))
+                })?;
+                spec.initial_transform.position = home.to_local(
+                    geodetic.latitude_deg,
+                    geodetic.longitude_deg,
+                    geodetic.altitude_m,
+                );
+            }
+            vehicle_ids.push(self.spawn_vehicle(spec).await?);
         }
+
+        self.pending_timeline = scenario.timeline;
+        self.pending_timeline
+            .sort_by(|a, b| a.time_s.partial_cmp(&b.time_s).unwrap());
+
+        Ok(vehicle_ids)
+    }
+
+    /// Set (or replace) `scene`'s domain boundary, used by
+    /// [`NativeBackend::cast_ray_bounded`] for bounded urban-canyon
+    /// multipath studies and periodic-cell RF propagation.
+    pub fn set_boundary(&mut self, scene: &SceneHandle, boundary: Boundary) -> SimResult<()> {
+        let mut scenes = self.scenes.write();
+        let native_scene = scenes
+            .get_mut(&scene.id)
+            .ok_or_else(|| SimError::SceneNotFound(scene.id.clone()))?;
+
+        native_scene.boundary = Some(boundary);
+        Ok(())
+    }
+
+    /// Like [`SimulationBackend::cast_ray`], but also detects crossings of
+    /// `scene`'s [`Boundary`] (if one is set) alongside geometry hits,
+    /// applying its configured condition up to `max_reflections` times.
+    pub fn cast_ray_bounded(
+        &self,
+        scene: &SceneHandle,
+        ray: &Ray,
+        max_reflections: u32,
+    ) -> SimResult<BoundedRayHit> {
+        let scenes = self.scenes.read();
+        let native_scene = scenes
+            .get(&scene.id)
+            .ok_or_else(|| SimError::SceneNotFound(scene.id.clone()))?;
+
+        Ok(native_scene.cast_ray_bounded(ray, max_reflections))
     }
 }
 
@@ -50,7 +290,18 @@ impl SimulationBackend for NativeBackend {
         "Native Rust Backend"
     }
 
-    async fn initialize(&mut self, _config: BackendConfig) -> SimResult<()> {
+    async fn initialize(&mut self, config: BackendConfig) -> SimResult<()> {
+        self.parallel_ray_casting = config.parallel_processing;
+        self.ray_cast_chunk_size = config.ray_cast_chunk_size;
+        self.ray_cast_pool = match config.num_threads {
+            Some(num_threads) if config.parallel_processing => Some(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .map_err(|e| SimError::InvalidConfig(e.to_string()))?,
+            ),
+            _ => None,
+        };
         self.initialized = true;
         Ok(())
     }
@@ -77,7 +328,9 @@ impl SimulationBackend for NativeBackend {
 
         self.scenes.write().insert(scene_id.clone(), scene);
 
-        Ok(SceneHandle::new(scene_id, BackendType::Native))
+        let handle = SceneHandle::new(scene_id, BackendType::Native);
+        self.active_scene = Some(handle.clone());
+        Ok(handle)
     }
 
     fn get_scene_bounds(&self, scene: &SceneHandle) -> SimResult<(Position, Position)> {
@@ -136,7 +389,36 @@ impl SimulationBackend for NativeBackend {
             .get(&scene.id)
             .ok_or_else(|| SimError::SceneNotFound(scene.id.clone()))?;
 
-        Ok(native_scene.cast_rays(rays))
+        if !self.parallel_ray_casting || rays.len() < 2 {
+            return Ok(native_scene.cast_rays(rays));
+        }
+
+        // Each worker does a read-only BVH traversal against the shared
+        // `native_scene` reference, so no locking is needed inside the loop
+        // beyond the single read guard already held above.
+        let chunk_size = self.ray_cast_chunk_size;
+        let cast_all = move || -> Vec<Option<RayHit>> {
+            match chunk_size {
+                Some(chunk_size) if chunk_size > 0 => rays
+                    .par_chunks(chunk_size)
+                    .flat_map(|chunk| {
+                        chunk
+                            .iter()
+                            .map(|ray| native_scene.cast_ray(ray))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect(),
+                _ => rays
+                    .par_iter()
+                    .map(|ray| native_scene.cast_ray(ray))
+                    .collect(),
+            }
+        };
+
+        Ok(match &self.ray_cast_pool {
+            Some(pool) => pool.install(cast_all),
+            None => cast_all(),
+        })
     }
 
     fn get_objects(&self, scene: &SceneHandle) -> SimResult<Vec<SceneObject>> {
@@ -148,8 +430,60 @@ impl SimulationBackend for NativeBackend {
         Ok(native_scene.get_objects())
     }
 
+    fn trace_rf_paths(
+        &self,
+        scene: &SceneHandle,
+        tx_pos: Position,
+        rx_pos: Position,
+        frequency_hz: f64,
+        max_bounces: u32,
+    ) -> SimResult<Vec<RfPath>> {
+        let scenes = self.scenes.read();
+        let native_scene = scenes
+            .get(&scene.id)
+            .ok_or_else(|| SimError::SceneNotFound(scene.id.clone()))?;
+
+        Ok(native_scene.trace_rf_paths(tx_pos, rx_pos, frequency_hz, max_bounces))
+    }
+
     async fn step(&mut self, delta_time: f64) -> SimResult<()> {
         self.time += delta_time;
+
+        while let Some(next) = self.pending_timeline.first() {
+            if next.time_s > self.time {
+                break;
+            }
+            let command = self.pending_timeline.remove(0);
+            self.set_vehicle_control(&command.vehicle_id, command.control)?;
+        }
+
+        if let Some(scene) = self.active_scene.clone() {
+            let mut vehicles = self.vehicles.write();
+            for vehicle in vehicles.values_mut() {
+                let Some(raycast_config) = vehicle.spec.parameters.raycast_vehicle.clone() else {
+                    continue;
+                };
+
+                let step = crate::raycast_vehicle::step_raycast_vehicle(
+                    &*self,
+                    &scene,
+                    &raycast_config,
+                    &vehicle.spec.parameters.tire_parameters,
+                    vehicle.spec.parameters.mass,
+                    vehicle.spec.parameters.inertia,
+                    &vehicle.control,
+                    &mut vehicle.state.transform,
+                    &mut vehicle.state.linear_velocity,
+                    &mut vehicle.state.angular_velocity,
+                    delta_time,
+                );
+
+                vehicle.state.timestamp = self.time;
+                vehicle.state.is_grounded = step.is_grounded;
+                vehicle.state.collision_info = step.collision_info;
+            }
+        }
+
         Ok(())
     }
 
@@ -177,6 +511,8 @@ impl SimulationBackend for NativeBackend {
             spec,
             state,
             control: VehicleControl::default(),
+            noise_runtimes: Mutex::new(HashMap::new()),
+            fault_runtimes: Mutex::new(HashMap::new()),
         };
 
         self.vehicles.write().insert(vehicle_id.clone(), vehicle);
@@ -214,211 +550,412 @@ impl SimulationBackend for NativeBackend {
             .get(vehicle_id)
             .ok_or_else(|| SimError::BackendError(format!("Vehicle not found: {}", vehicle_id)))?;
 
+        let spec = vehicle
+            .spec
+            .sensors
+            .iter()
+            .find(|s| s.sensor_id == sensor_id);
+        let noise = spec.and_then(|s| s.noise.as_ref());
+        let fault = spec.and_then(|s| s.fault.as_ref());
+
         // Generate synthetic sensor data based on vehicle state
-        match sensor_id {
+        let data = match sensor_id {
             "imu" => {
-                let imu_data = ImuData {
+                let mut imu_data = ImuData {
                     timestamp: self.time,
                     linear_acceleration: vehicle.state.linear_acceleration,
                     angular_velocity: vehicle.state.angular_velocity,
                     orientation: vehicle.state.transform.rotation,
                 };
-                Ok(SensorData::Imu(imu_data))
+
+                if let Some(SensorNoise::Imu {
+                    gyro_noise_std,
+                    accel_noise_std,
+                    gyro_bias_walk_std,
+                    accel_bias_walk_std,
+                    scale_factor_error,
+                    seed,
+                }) = noise
+                {
+                    let mut runtimes = vehicle.noise_runtimes.lock();
+                    let runtime = runtimes.entry(sensor_id.to_string()).or_insert_with(|| {
+                        SensorNoiseRuntime::Imu {
+                            rng: StdRng::seed_from_u64(*seed),
+                            last_time: self.time,
+                            accel_bias: Vector3::zeros(),
+                            gyro_bias: Vector3::zeros(),
+                        }
+                    });
+                    if let SensorNoiseRuntime::Imu {
+                        rng,
+                        last_time,
+                        accel_bias,
+                        gyro_bias,
+                    } = runtime
+                    {
+                        let dt = (self.time - *last_time).max(0.0);
+                        *last_time = self.time;
+                        *accel_bias += gaussian_noise_vec3(rng, *accel_bias_walk_std * dt.sqrt());
+                        *gyro_bias += gaussian_noise_vec3(rng, *gyro_bias_walk_std * dt.sqrt());
+
+                        let scale = 1.0 + scale_factor_error;
+                        imu_data.linear_acceleration = imu_data.linear_acceleration * scale
+                            + *accel_bias
+                            + gaussian_noise_vec3(rng, *accel_noise_std);
+                        imu_data.angular_velocity = imu_data.angular_velocity * scale
+                            + *gyro_bias
+                            + gaussian_noise_vec3(rng, *gyro_noise_std);
+                    }
+                }
+
+                SensorData::Imu(imu_data)
             }
             "gps" => {
+                let mut position = vehicle.state.transform.position;
+                let mut timestamp = self.time;
+
+                if let Some(SensorNoise::Gps {
+                    position_walk_std_m,
+                    fix_latency_s,
+                    seed,
+                }) = noise
+                {
+                    let mut runtimes = vehicle.noise_runtimes.lock();
+                    let runtime = runtimes.entry(sensor_id.to_string()).or_insert_with(|| {
+                        SensorNoiseRuntime::Gps {
+                            rng: StdRng::seed_from_u64(*seed),
+                            last_time: self.time,
+                            position_bias: Vector3::zeros(),
+                        }
+                    });
+                    if let SensorNoiseRuntime::Gps {
+                        rng,
+                        last_time,
+                        position_bias,
+                    } = runtime
+                    {
+                        let dt = (self.time - *last_time).max(0.0);
+                        *last_time = self.time;
+                        *position_bias +=
+                            gaussian_noise_vec3(rng, *position_walk_std_m * dt.sqrt());
+
+                        // Approximate the reporting lag by extrapolating the
+                        // position backwards along the current velocity,
+                        // since this backend doesn't keep a state history.
+                        position = position + *position_bias
+                            - vehicle.state.linear_velocity * *fix_latency_s;
+                        timestamp -= fix_latency_s;
+                    }
+                }
+
+                let (latitude, longitude, altitude) = match &vehicle.spec.parameters.home {
+                    Some(home) => home.to_geodetic(position),
+                    // Historical fallback for vehicles with no configured
+                    // home projection: a flat degrees-per-meter approximation.
+                    None => (position.x / 111320.0, position.y / 111320.0, position.z),
+                };
                 let gps_data = GpsData {
-                    timestamp: self.time,
-                    latitude: vehicle.state.transform.position.x / 111320.0, // Approximate
-                    longitude: vehicle.state.transform.position.y / 111320.0,
-                    altitude: vehicle.state.transform.position.z,
+                    timestamp,
+                    latitude,
+                    longitude,
+                    altitude,
                     velocity: vehicle.state.linear_velocity,
                     eph: 1.0,
                     epv: 1.5,
                     fix_type: crate::sensor::GpsFixType::Fix3D,
                 };
-                Ok(SensorData::Gps(gps_data))
+                SensorData::Gps(gps_data)
             }
-            _ => Err(SimError::BackendError(format!(
-                "Sensor not found: {}",
-                sensor_id
-            ))),
-        }
-    }
-}
-
-/// Native scene representation
-struct NativeScene {
-    path: String,
-    objects: HashMap<String, SceneObject>,
-    bounds_min: Position,
-    bounds_max: Position,
-}
+            "radar" => {
+                let radar_config = spec.and_then(|s| s.radar_config.as_ref()).ok_or_else(|| {
+                    SimError::BackendError(format!("Sensor '{}' has no radar_config", sensor_id))
+                })?;
+
+                let own_transform = &vehicle.state.transform;
+                let forward = own_transform.rotation * Vector3::new(0.0, 1.0, 0.0);
+                let right = own_transform.rotation * Vector3::new(1.0, 0.0, 0.0);
+                let up = own_transform.rotation * Vector3::new(0.0, 0.0, 1.0);
+                let wavelength = SPEED_OF_LIGHT / radar_config.frequency_hz;
+                let noise_floor_dbm = thermal_noise_dbm(radar_config.bandwidth_hz);
+
+                let mut targets = Vec::new();
+                for (other_id, other) in vehicles.iter() {
+                    if other_id.as_str() == vehicle_id {
+                        continue;
+                    }
+                    let rel = other.state.transform.position - own_transform.position;
+                    let range_m = rel.norm();
+                    if range_m > radar_config.max_range_m || range_m < 1e-6 {
+                        continue;
+                    }
+                    let los = rel / range_m;
+                    let fwd_comp = los.dot(&forward);
+                    let right_comp = los.dot(&right);
+                    let up_comp = los.dot(&up);
+                    let azimuth_deg = right_comp.atan2(fwd_comp).to_degrees();
+                    let elevation_deg = up_comp
+                        .atan2((fwd_comp.powi(2) + right_comp.powi(2)).sqrt())
+                        .to_degrees();
+                    let angle_off_boresight_deg = fwd_comp.clamp(-1.0, 1.0).acos().to_degrees();
+                    let relative_velocity =
+                        other.state.linear_velocity - vehicle.state.linear_velocity;
+                    let radial_velocity_mps = -relative_velocity.dot(&los);
+
+                    match &radar_config.scan_mode {
+                        RadarScanMode::IdealGroundTruth => {
+                            targets.push(RadarTarget {
+                                range_m,
+                                azimuth_deg,
+                                elevation_deg,
+                                radial_velocity_mps,
+                                return_power_dbm: f64::NAN,
+                            });
+                        }
+                        RadarScanMode::FullSweep => {
+                            let return_power_dbm = radar_return_power_dbm(
+                                radar_config.tx_power_dbm,
+                                radar_config.antenna_gain_dbi,
+                                range_m,
+                                wavelength,
+                                radar_config.target_rcs_m2,
+                            );
+                            if return_power_dbm - noise_floor_dbm
+                                >= radar_config.min_detectable_snr_db
+                            {
+                                targets.push(RadarTarget {
+                                    range_m,
+                                    azimuth_deg,
+                                    elevation_deg,
+                                    radial_velocity_mps,
+                                    return_power_dbm,
+                                });
+                            }
+                        }
+                        RadarScanMode::Directional { beamwidth_deg } => {
+                            let effective_gain_dbi = radar_config.antenna_gain_dbi
+                                + directional_gain_falloff_db(
+                                    angle_off_boresight_deg,
+                                    *beamwidth_deg,
+                                );
+                            let return_power_dbm = radar_return_power_dbm(
+                                radar_config.tx_power_dbm,
+                                effective_gain_dbi,
+                                range_m,
+                                wavelength,
+                                radar_config.target_rcs_m2,
+                            );
+                            if return_power_dbm - noise_floor_dbm
+                                >= radar_config.min_detectable_snr_db
+                            {
+                                targets.push(RadarTarget {
+                                    range_m,
+                                    azimuth_deg,
+                                    elevation_deg,
+                                    radial_velocity_mps,
+                                    return_power_dbm,
+                                });
+                            }
+                        }
+                    }
+                }
 
-impl NativeScene {
-    fn new(path: String) -> Self {
-        Self {
-            path,
-            objects: HashMap::new(),
-            bounds_min: Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
-            bounds_max: Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
-        }
-    }
+                SensorData::Radar(RadarData {
+                    timestamp: self.time,
+                    targets,
+                })
+            }
+            "adsb" => {
+                // ADS-B range is realistically limited by radio line of
+                // sight, not geometry occlusion; 400 km covers the
+                // practical air-to-air reception range at any altitude
+                // simulated vehicles fly at.
+                const ADSB_MAX_RANGE_M: f64 = 400_000.0;
+
+                let own_position = vehicle.state.transform.position;
+                let home = vehicle.spec.parameters.home.as_ref();
+                let to_geodetic = |position: Position| -> (f64, f64, f64) {
+                    match home {
+                        Some(home) => home.to_geodetic(position),
+                        None => (position.x / 111_320.0, position.y / 111_320.0, position.z),
+                    }
+                };
 
-    fn get_bounds(&self) -> (Position, Position) {
-        (self.bounds_min, self.bounds_max)
-    }
+                let mut contacts = Vec::new();
+                for (other_id, other) in vehicles.iter() {
+                    if other_id.as_str() == vehicle_id {
+                        continue;
+                    }
+                    let other_position = other.state.transform.position;
+                    if (other_position - own_position).norm() > ADSB_MAX_RANGE_M {
+                        continue;
+                    }
+
+                    let (latitude, longitude, altitude_m) = to_geodetic(other_position);
+                    let altitude_ft = altitude_m / 0.3048;
+                    let icao = crate::adsb::icao_from_vehicle_id(other_id);
+
+                    let even_frame = crate::adsb::encode_airborne_position(
+                        icao,
+                        altitude_ft,
+                        latitude,
+                        longitude,
+                        false,
+                    );
+                    let odd_frame = crate::adsb::encode_airborne_position(
+                        icao,
+                        altitude_ft,
+                        latitude,
+                        longitude,
+                        true,
+                    );
+                    let Some((decoded_lat, decoded_lon)) =
+                        crate::adsb::decode_airborne_position_global(&even_frame, &odd_frame)
+                    else {
+                        continue;
+                    };
+
+                    let velocity_frame = crate::adsb::encode_velocity(
+                        icao,
+                        other.state.linear_velocity.x,
+                        other.state.linear_velocity.y,
+                        other.state.linear_velocity.z,
+                    );
+                    let velocity = crate::adsb::decode_velocity(&velocity_frame);
+
+                    let identification_frame =
+                        crate::adsb::encode_identification(icao, other_id, 0);
+                    let callsign = crate::adsb::decode_identification(&identification_frame)
+                        .unwrap_or_default();
+
+                    contacts.push(crate::sensor::AdsbContact {
+                        icao_address: icao,
+                        latitude: decoded_lat,
+                        longitude: decoded_lon,
+                        altitude_m: crate::adsb::decode_altitude_ft(&even_frame) * 0.3048,
+                        ground_speed_mps: velocity.map(|v| v.ground_speed_mps).unwrap_or(0.0),
+                        track_deg: velocity.map(|v| v.track_deg).unwrap_or(0.0),
+                        vertical_rate_mps: velocity.map(|v| v.vertical_rate_mps).unwrap_or(0.0),
+                        callsign,
+                    });
+                }
 
-    fn add_object(&mut self, object: SceneObject) -> String {
-        let id = object.id.clone();
-        self.update_bounds(&object);
-        self.objects.insert(id.clone(), object);
-        id
-    }
+                SensorData::Adsb(crate::sensor::AdsbData {
+                    timestamp: self.time,
+                    contacts,
+                })
+            }
+            _ => {
+                return Err(SimError::BackendError(format!(
+                    "Sensor not found: {}",
+                    sensor_id
+                )))
+            }
+        };
 
-    fn remove_object(&mut self, object_id: &str) -> SimResult<()> {
-        self.objects
-            .remove(object_id)
-            .ok_or_else(|| SimError::BackendError(format!("Object not found: {}", object_id)))?;
-        self.recompute_bounds();
-        Ok(())
+        match fault {
+            Some(fault) => {
+                apply_sensor_fault(&vehicle.fault_runtimes, sensor_id, fault, self.time, data)
+            }
+            None => Ok(data),
+        }
     }
 
-    fn update_transform(&mut self, object_id: &str, transform: Transform) -> SimResult<()> {
-        let object = self
-            .objects
-            .get_mut(object_id)
-            .ok_or_else(|| SimError::BackendError(format!("Object not found: {}", object_id)))?;
-        object.transform = transform;
-        self.recompute_bounds();
+    fn set_sensor_fault(
+        &mut self,
+        vehicle_id: &str,
+        sensor_id: &str,
+        fault: Option<SensorFault>,
+    ) -> SimResult<()> {
+        let mut vehicles = self.vehicles.write();
+        let vehicle = vehicles
+            .get_mut(vehicle_id)
+            .ok_or_else(|| SimError::BackendError(format!("Vehicle not found: {}", vehicle_id)))?;
+        let sensor = vehicle
+            .spec
+            .sensors
+            .iter_mut()
+            .find(|s| s.sensor_id == sensor_id)
+            .ok_or_else(|| SimError::BackendError(format!("Sensor not found: {}", sensor_id)))?;
+        sensor.fault = fault;
+        // Drop any cached state (frozen value, glitch RNG) so re-arming the
+        // fault later starts fresh instead of resuming mid-sequence.
+        vehicle.fault_runtimes.lock().remove(sensor_id);
         Ok(())
     }
+}
 
-    fn update_bounds(&mut self, object: &SceneObject) {
-        let pos = &object.transform.position;
-        self.bounds_min = Point3::new(
-            self.bounds_min.x.min(pos.x),
-            self.bounds_min.y.min(pos.y),
-            self.bounds_min.z.min(pos.z),
-        );
-        self.bounds_max = Point3::new(
-            self.bounds_max.x.max(pos.x),
-            self.bounds_max.y.max(pos.y),
-            self.bounds_max.z.max(pos.z),
-        );
-    }
-
-    fn recompute_bounds(&mut self) {
-        let mut new_min = Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
-        let mut new_max = Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+/// Axis-aligned bounding box used to accelerate ray casts via [`Bvh`].
+#[derive(Debug, Clone, Copy)]
+struct Aabb {
+    min: Point3<f64>,
+    max: Point3<f64>,
+}
 
-        for object in self.objects.values() {
-            let pos = &object.transform.position;
-            new_min = Point3::new(
-                new_min.x.min(pos.x),
-                new_min.y.min(pos.y),
-                new_min.z.min(pos.z),
-            );
-            new_max = Point3::new(
-                new_max.x.max(pos.x),
-                new_max.y.max(pos.y),
-                new_max.z.max(pos.z),
-            );
+impl Aabb {
+    fn empty() -> Self {
+        Self {
+            min: Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            max: Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
         }
-
-        self.bounds_min = new_min;
-        self.bounds_max = new_max;
     }
 
-    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
-        let mut closest_hit: Option<RayHit> = None;
-        let mut closest_distance = ray.max_distance;
-
-        for object in self.objects.values() {
-            if let Some(hit) = self.intersect_object(ray, object) {
-                if hit.distance < closest_distance {
-                    closest_distance = hit.distance;
-                    closest_hit = Some(hit);
-                }
-            }
-        }
-
-        closest_hit
+    fn point(p: Point3<f64>) -> Self {
+        Self { min: p, max: p }
     }
 
-    fn cast_rays(&self, rays: &[Ray]) -> Vec<Option<RayHit>> {
-        rays.iter().map(|ray| self.cast_ray(ray)).collect()
+    fn from_points(points: &[Point3<f64>]) -> Self {
+        points
+            .iter()
+            .fold(Self::empty(), |acc, &p| acc.union(&Self::point(p)))
     }
 
-    fn intersect_object(&self, ray: &Ray, object: &SceneObject) -> Option<RayHit> {
-        match &object.geometry {
-            Geometry::Sphere { radius } => {
-                self.intersect_sphere(ray, &object.transform.position, *radius, object)
-            }
-            Geometry::Box { size } => self.intersect_box(ray, &object.transform, size, object),
-            Geometry::Cylinder { radius, height } => {
-                self.intersect_cylinder(ray, &object.transform, *radius, *height, object)
-            }
-            Geometry::Mesh { vertices, indices } => {
-                self.intersect_mesh(ray, &object.transform, vertices, indices, object)
-            }
+    fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: Point3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
         }
     }
 
-    fn intersect_sphere(
-        &self,
-        ray: &Ray,
-        center: &Position,
-        radius: f64,
-        object: &SceneObject,
-    ) -> Option<RayHit> {
-        let oc = ray.origin - center;
-        let a = ray.direction.dot(&ray.direction);
-        let b = 2.0 * oc.dot(&ray.direction);
-        let c = oc.dot(&oc) - radius * radius;
-        let discriminant = b * b - 4.0 * a * c;
+    fn centroid(&self) -> Point3<f64> {
+        Point3::new(
+            (self.min.x + self.max.x) * 0.5,
+            (self.min.y + self.max.y) * 0.5,
+            (self.min.z + self.max.z) * 0.5,
+        )
+    }
 
-        if discriminant < 0.0 {
-            return None;
-        }
+    fn extent(&self) -> Vec3 {
+        self.max - self.min
+    }
 
-        let t = (-b - discriminant.sqrt()) / (2.0 * a);
-        if t < 0.0 || t > ray.max_distance {
-            return None;
+    fn surface_area(&self) -> f64 {
+        let d = self.extent();
+        if d.x < 0.0 || d.y < 0.0 || d.z < 0.0 {
+            return 0.0;
         }
-
-        let position = ray.at(t);
-        let normal = ((position - center) / radius).normalize();
-
-        Some(RayHit {
-            distance: t,
-            position,
-            normal,
-            material: object.material.clone(),
-            object_id: object.id.clone(),
-        })
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
     }
 
-    fn intersect_box(
-        &self,
-        ray: &Ray,
-        transform: &Transform,
-        size: &Vec3,
-        object: &SceneObject,
-    ) -> Option<RayHit> {
-        // AABB intersection (simplified - doesn't account for rotation)
-        let min = transform.position - size / 2.0;
-        let max = transform.position + size / 2.0;
-
-        let mut tmin = (min.x - ray.origin.x) / ray.direction.x;
-        let mut tmax = (max.x - ray.origin.x) / ray.direction.x;
-
+    /// Slab-test ray/AABB intersection, same method as
+    /// [`NativeScene::intersect_box`]; returns the entry distance if it
+    /// falls within `[0, max_distance]`.
+    fn intersect(&self, ray: &Ray, max_distance: f64) -> Option<f64> {
+        let mut tmin = (self.min.x - ray.origin.x) / ray.direction.x;
+        let mut tmax = (self.max.x - ray.origin.x) / ray.direction.x;
         if tmin > tmax {
             std::mem::swap(&mut tmin, &mut tmax);
         }
 
-        let mut tymin = (min.y - ray.origin.y) / ray.direction.y;
-        let mut tymax = (max.y - ray.origin.y) / ray.direction.y;
-
+        let mut tymin = (self.min.y - ray.origin.y) / ray.direction.y;
+        let mut tymax = (self.max.y - ray.origin.y) / ray.direction.y;
         if tymin > tymax {
             std::mem::swap(&mut tymin, &mut tymax);
         }
@@ -426,7 +963,6 @@ impl NativeScene {
         if tmin > tymax || tymin > tmax {
             return None;
         }
-
         if tymin > tmin {
             tmin = tymin;
         }
@@ -434,9 +970,8 @@ impl NativeScene {
             tmax = tymax;
         }
 
-        let mut tzmin = (min.z - ray.origin.z) / ray.direction.z;
-        let mut tzmax = (max.z - ray.origin.z) / ray.direction.z;
-
+        let mut tzmin = (self.min.z - ray.origin.z) / ray.direction.z;
+        let mut tzmax = (self.max.z - ray.origin.z) / ray.direction.z;
         if tzmin > tzmax {
             std::mem::swap(&mut tzmin, &mut tzmax);
         }
@@ -444,24 +979,1021 @@ impl NativeScene {
         if tmin > tzmax || tzmin > tmax {
             return None;
         }
-
         if tzmin > tmin {
             tmin = tzmin;
         }
+        if tzmax < tmax {
+            tmax = tzmax;
+        }
 
-        if tmin < 0.0 || tmin > ray.max_distance {
+        let entry = if tmin >= 0.0 { tmin } else { tmax };
+        if entry < 0.0 || entry > max_distance {
             return None;
         }
+        Some(entry)
+    }
+}
 
-        let position = ray.at(tmin);
-        let normal = self.compute_box_normal(&position, &min, &max);
+/// A node in a [`Bvh`]'s arena; children are referenced by index rather than
+/// `Box` so traversal can use a flat stack of indices.
+enum BvhNode {
+    Leaf {
+        bounds: Aabb,
+        prims: Vec<usize>,
+    },
+    Internal {
+        bounds: Aabb,
+        left: usize,
+        right: usize,
+    },
+}
 
-        Some(RayHit {
-            distance: tmin,
-            position,
-            normal,
-            material: object.material.clone(),
-            object_id: object.id.clone(),
+impl BvhNode {
+    fn bounds(&self) -> Aabb {
+        match self {
+            BvhNode::Leaf { bounds, .. } => *bounds,
+            BvhNode::Internal { bounds, .. } => *bounds,
+        }
+    }
+}
+
+/// Top-down surface-area-heuristic bounding volume hierarchy over a set of
+/// primitives identified by an opaque `usize` index (an index into the
+/// scene's object list, or a triangle index within one mesh). Built once
+/// whenever the scene's geometry changes and traversed on every ray cast.
+struct Bvh {
+    nodes: Vec<BvhNode>,
+}
+
+impl Bvh {
+    const MAX_LEAF_PRIMS: usize = 4;
+    const NUM_BUCKETS: usize = 12;
+
+    fn build(prims: Vec<(Aabb, usize)>) -> Self {
+        let mut nodes = Vec::new();
+        if !prims.is_empty() {
+            let mut prims = prims;
+            Self::build_recursive(&mut prims, &mut nodes);
+        }
+        Self { nodes }
+    }
+
+    fn root(&self) -> Option<usize> {
+        if self.nodes.is_empty() {
+            None
+        } else {
+            Some(self.nodes.len() - 1)
+        }
+    }
+
+    /// Build a subtree over `prims`, appending nodes to the shared arena and
+    /// returning the index of the node just created (its root).
+    fn build_recursive(prims: &mut [(Aabb, usize)], nodes: &mut Vec<BvhNode>) -> usize {
+        let bounds = prims.iter().fold(Aabb::empty(), |acc, (b, _)| acc.union(b));
+
+        if prims.len() <= Self::MAX_LEAF_PRIMS {
+            nodes.push(BvhNode::Leaf {
+                bounds,
+                prims: prims.iter().map(|(_, i)| *i).collect(),
+            });
+            return nodes.len() - 1;
+        }
+
+        let centroid_bounds = prims.iter().fold(Aabb::empty(), |acc, (b, _)| {
+            acc.union(&Aabb::point(b.centroid()))
+        });
+        let extent = centroid_bounds.extent();
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        let axis_extent = extent[axis];
+        let mut mid = prims.len() / 2;
+
+        if axis_extent < 1e-9 {
+            // All centroids coincide on the chosen axis - an SAH split
+            // can't separate them, so fall back to a median split.
+            prims.select_nth_unstable_by(mid, |a, b| {
+                a.0.centroid()[axis]
+                    .partial_cmp(&b.0.centroid()[axis])
+                    .unwrap()
+            });
+        } else {
+            let bucket_for = |centroid: Point3<f64>| -> usize {
+                let o = (centroid[axis] - centroid_bounds.min[axis]) / axis_extent;
+                ((o * Self::NUM_BUCKETS as f64) as usize).min(Self::NUM_BUCKETS - 1)
+            };
+
+            #[derive(Clone, Copy)]
+            struct Bucket {
+                count: usize,
+                bounds: Aabb,
+            }
+            let mut buckets = [Bucket {
+                count: 0,
+                bounds: Aabb::empty(),
+            }; Self::NUM_BUCKETS];
+
+            for (b, _) in prims.iter() {
+                let idx = bucket_for(b.centroid());
+                buckets[idx].count += 1;
+                buckets[idx].bounds = buckets[idx].bounds.union(b);
+            }
+
+            let mut best_cost = f64::INFINITY;
+            let mut best_split = Self::NUM_BUCKETS / 2;
+            let parent_area = bounds.surface_area().max(1e-12);
+            const C_TRAV: f64 = 1.0;
+            const C_ISECT: f64 = 1.0;
+
+            for split in 0..Self::NUM_BUCKETS - 1 {
+                let mut left_bounds = Aabb::empty();
+                let mut left_count = 0usize;
+                for bucket in &buckets[..=split] {
+                    left_bounds = left_bounds.union(&bucket.bounds);
+                    left_count += bucket.count;
+                }
+                let mut right_bounds = Aabb::empty();
+                let mut right_count = 0usize;
+                for bucket in &buckets[split + 1..] {
+                    right_bounds = right_bounds.union(&bucket.bounds);
+                    right_count += bucket.count;
+                }
+                if left_count == 0 || right_count == 0 {
+                    continue;
+                }
+
+                let cost = C_TRAV
+                    + (left_bounds.surface_area() / parent_area) * left_count as f64 * C_ISECT
+                    + (right_bounds.surface_area() / parent_area) * right_count as f64 * C_ISECT;
+                if cost < best_cost {
+                    best_cost = cost;
+                    best_split = split;
+                }
+            }
+
+            let mut split_point = 0usize;
+            for j in 0..prims.len() {
+                if bucket_for(prims[j].0.centroid()) <= best_split {
+                    prims.swap(split_point, j);
+                    split_point += 1;
+                }
+            }
+            mid = split_point;
+
+            if mid == 0 || mid == prims.len() {
+                // Degenerate bucket partition (e.g. all primitives landed in
+                // one bucket) - fall back to a median split instead.
+                prims.select_nth_unstable_by(prims.len() / 2, |a, b| {
+                    a.0.centroid()[axis]
+                        .partial_cmp(&b.0.centroid()[axis])
+                        .unwrap()
+                });
+                mid = prims.len() / 2;
+            }
+        }
+
+        let (left_slice, right_slice) = prims.split_at_mut(mid);
+        let left = Self::build_recursive(left_slice, nodes);
+        let right = Self::build_recursive(right_slice, nodes);
+        nodes.push(BvhNode::Internal {
+            bounds,
+            left,
+            right,
+        });
+        nodes.len() - 1
+    }
+
+    /// Visit every primitive in a leaf whose bounds the ray may still hit,
+    /// nearest-child-first, pruning subtrees whose entry distance exceeds
+    /// the current closest hit (updated by `visit` as it finds one).
+    fn traverse(&self, ray: &Ray, closest: &mut f64, visit: &mut dyn FnMut(usize, &mut f64)) {
+        let Some(root) = self.root() else {
+            return;
+        };
+
+        let mut stack = vec![root];
+        while let Some(idx) = stack.pop() {
+            match &self.nodes[idx] {
+                BvhNode::Leaf { bounds, prims } => {
+                    if bounds.intersect(ray, *closest).is_some() {
+                        for &prim in prims {
+                            visit(prim, closest);
+                        }
+                    }
+                }
+                BvhNode::Internal {
+                    bounds,
+                    left,
+                    right,
+                } => {
+                    if bounds.intersect(ray, *closest).is_none() {
+                        continue;
+                    }
+                    let left_dist = self.nodes[*left].bounds().intersect(ray, *closest);
+                    let right_dist = self.nodes[*right].bounds().intersect(ray, *closest);
+                    match (left_dist, right_dist) {
+                        (Some(a), Some(b)) => {
+                            if a <= b {
+                                stack.push(*right);
+                                stack.push(*left);
+                            } else {
+                                stack.push(*left);
+                                stack.push(*right);
+                            }
+                        }
+                        (Some(_), None) => stack.push(*left),
+                        (None, Some(_)) => stack.push(*right),
+                        (None, None) => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Speed of light in vacuum (m/s). Duplicated here (rather than depending
+/// on `autonomysim_rf_core::constants`) since this crate sits below
+/// rf-core in the dependency graph.
+const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// Free-space path loss in dB for a path of the given length and
+/// wavelength: `20*log10(4*pi*d/lambda)`.
+fn free_space_path_loss_db(distance: f64, wavelength: f64) -> f64 {
+    20.0 * (4.0 * std::f64::consts::PI * distance.max(1e-3) / wavelength).log10()
+}
+
+/// Boltzmann constant (J/K). Duplicated here for the same reason as
+/// [`SPEED_OF_LIGHT`]: this crate sits below rf-core, where the
+/// canonical copy lives in `autonomysim_rf_core::constants`.
+const BOLTZMANN_CONSTANT: f64 = 1.380649e-23;
+
+/// Standard receiver noise temperature (K), used for the radar thermal
+/// noise floor.
+const ROOM_TEMPERATURE_K: f64 = 290.0;
+
+/// Thermal noise floor over the given bandwidth, in dBm, at
+/// [`ROOM_TEMPERATURE_K`]: `10*log10(k*T*B / 1mW)`. Mirrors
+/// `autonomysim_rf_core::utils::thermal_noise_dbm`.
+fn thermal_noise_dbm(bandwidth_hz: f64) -> f64 {
+    let noise_power_w = BOLTZMANN_CONSTANT * ROOM_TEMPERATURE_K * bandwidth_hz;
+    10.0 * (noise_power_w / 1e-3).log10()
+}
+
+/// Monostatic radar equation, expressed as a link budget: the transmit
+/// power plus the antenna's gain on both transmit and receive (a radar
+/// reuses one antenna for both), minus two-way free-space path loss,
+/// plus the RCS/wavelength gain term `10*log10(4*pi*sigma) -
+/// 20*log10(lambda)` that turns the return off a `rcs_m2`-sized target
+/// back into a received power. Mirrors the shape of
+/// `autonomysim_rf_core::utils::link_budget` applied to the radar
+/// equation rather than a one-way RF link.
+fn radar_return_power_dbm(
+    tx_power_dbm: f64,
+    antenna_gain_dbi: f64,
+    range_m: f64,
+    wavelength: f64,
+    target_rcs_m2: f64,
+) -> f64 {
+    let two_way_path_loss_db = 2.0 * free_space_path_loss_db(range_m, wavelength);
+    let rcs_gain_db = 10.0 * (4.0 * std::f64::consts::PI * target_rcs_m2.max(1e-6)).log10()
+        - 20.0 * wavelength.log10();
+    tx_power_dbm + 2.0 * antenna_gain_dbi - two_way_path_loss_db + rcs_gain_db
+}
+
+/// Off-boresight gain falloff in dB for a directional radar beam, as a
+/// function of the angle off boresight and the beam's half-power
+/// beamwidth. Mirrors the Gaussian falloff used by
+/// `autonomysim_rf_core::antenna::AntennaPattern::Directional`.
+fn directional_gain_falloff_db(angle_deg: f64, beamwidth_deg: f64) -> f64 {
+    const FRONT_TO_BACK_RATIO_DB: f64 = 25.0;
+    if angle_deg >= 90.0 {
+        return -FRONT_TO_BACK_RATIO_DB;
+    }
+    let half_power_angle = beamwidth_deg / 2.0;
+    (-12.0 * (angle_deg / half_power_angle).powi(2)).max(-FRONT_TO_BACK_RATIO_DB)
+}
+
+/// Magnitude of the Fresnel reflection coefficient (perpendicular
+/// polarization) at the given angle of incidence, derived from a surface
+/// material's relative permittivity and conductivity. Falls back to the
+/// material's precomputed `reflection_coefficient` if the surface
+/// impedance makes the exact formula degenerate.
+fn fresnel_reflection_magnitude(
+    material: &Material,
+    incidence_angle: f64,
+    frequency_hz: f64,
+) -> f64 {
+    const EPSILON_0: f64 = 8.854e-12;
+    let cos_theta = incidence_angle.cos();
+    let sin_theta = incidence_angle.sin();
+    let epsilon_c = material.permittivity
+        - material.conductivity / (2.0 * std::f64::consts::PI * frequency_hz * EPSILON_0);
+    let sqrt_term = (epsilon_c - sin_theta.powi(2)).sqrt();
+    let denominator = cos_theta + sqrt_term;
+    if !sqrt_term.is_finite() || denominator.abs() < 1e-12 {
+        return material.reflection_coefficient.clamp(0.0, 1.0);
+    }
+    ((cos_theta - sqrt_term) / denominator)
+        .abs()
+        .clamp(0.0, 1.0)
+}
+
+/// Which world axis a [`BoundaryFace`] pair sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl BoundaryAxis {
+    fn unit_vector(self) -> Vec3 {
+        match self {
+            BoundaryAxis::X => Vec3::new(1.0, 0.0, 0.0),
+            BoundaryAxis::Y => Vec3::new(0.0, 1.0, 0.0),
+            BoundaryAxis::Z => Vec3::new(0.0, 0.0, 1.0),
+        }
+    }
+}
+
+/// What happens when a ray crosses a domain boundary face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryCondition {
+    /// The ray terminates at the crossing; its RF energy is absorbed.
+    Kill,
+    /// The ray specularly bounces back into the domain, the same way
+    /// [`NativeScene::trace_rf_paths`] bounces off scene geometry.
+    Reflect,
+    /// The ray re-enters through the opposite face of the same axis,
+    /// continuing in the same direction, for tiling an infinite
+    /// environment out of one finite cell.
+    Periodic,
+}
+
+/// Axis-aligned domain boundary for a [`NativeScene`]: a box with one
+/// [`BoundaryCondition`] per axis, applied to both of that axis's faces.
+/// Rays are assumed to start inside the box; [`NativeScene::cast_ray_bounded`]
+/// detects the nearest face crossing alongside ordinary geometry hits and
+/// applies the configured condition.
+#[derive(Debug, Clone)]
+pub struct Boundary {
+    min: Position,
+    max: Position,
+    x: BoundaryCondition,
+    y: BoundaryCondition,
+    z: BoundaryCondition,
+}
+
+impl Boundary {
+    /// A box from `min` to `max` with `condition` applied to all six faces.
+    pub fn cuboid(min: Position, max: Position, condition: BoundaryCondition) -> Self {
+        Self {
+            min,
+            max,
+            x: condition,
+            y: condition,
+            z: condition,
+        }
+    }
+
+    /// Override the condition applied to `axis`'s pair of faces.
+    pub fn with_axis_condition(mut self, axis: BoundaryAxis, condition: BoundaryCondition) -> Self {
+        match axis {
+            BoundaryAxis::X => self.x = condition,
+            BoundaryAxis::Y => self.y = condition,
+            BoundaryAxis::Z => self.z = condition,
+        }
+        self
+    }
+
+    fn condition_for(&self, axis: BoundaryAxis) -> BoundaryCondition {
+        match axis {
+            BoundaryAxis::X => self.x,
+            BoundaryAxis::Y => self.y,
+            BoundaryAxis::Z => self.z,
+        }
+    }
+
+    /// Nearest face the ray exits through within `(0, max_distance]`,
+    /// assuming `ray.origin` lies inside the box. Returns the crossing
+    /// distance, the axis it's on, whether the exit is through the `max`
+    /// face (as opposed to `min`), and that axis's configured condition.
+    fn nearest_crossing(
+        &self,
+        ray: &Ray,
+        max_distance: f64,
+    ) -> Option<(f64, BoundaryAxis, bool, BoundaryCondition)> {
+        let mut best: Option<(f64, BoundaryAxis, bool, BoundaryCondition)> = None;
+        for axis in [BoundaryAxis::X, BoundaryAxis::Y, BoundaryAxis::Z] {
+            let (origin_c, dir_c, min_c, max_c) = match axis {
+                BoundaryAxis::X => (ray.origin.x, ray.direction.x, self.min.x, self.max.x),
+                BoundaryAxis::Y => (ray.origin.y, ray.direction.y, self.min.y, self.max.y),
+                BoundaryAxis::Z => (ray.origin.z, ray.direction.z, self.min.z, self.max.z),
+            };
+            if dir_c.abs() < 1e-12 {
+                continue;
+            }
+            let exits_positive = dir_c > 0.0;
+            let face_value = if exits_positive { max_c } else { min_c };
+            let t = (face_value - origin_c) / dir_c;
+            if t > 1e-9 && t <= max_distance {
+                let better = best.map(|(best_t, ..)| t < best_t).unwrap_or(true);
+                if better {
+                    best = Some((t, axis, exits_positive, self.condition_for(axis)));
+                }
+            }
+        }
+        best
+    }
+
+    /// Re-enter `point` through the opposite face of `axis` from the one it
+    /// just exited (`exits_positive` selects which face that was).
+    fn wrap(&self, axis: BoundaryAxis, exits_positive: bool, mut point: Position) -> Position {
+        let (min_c, max_c) = match axis {
+            BoundaryAxis::X => (self.min.x, self.max.x),
+            BoundaryAxis::Y => (self.min.y, self.max.y),
+            BoundaryAxis::Z => (self.min.z, self.max.z),
+        };
+        let wrapped = if exits_positive { min_c } else { max_c };
+        match axis {
+            BoundaryAxis::X => point.x = wrapped,
+            BoundaryAxis::Y => point.y = wrapped,
+            BoundaryAxis::Z => point.z = wrapped,
+        }
+        point
+    }
+}
+
+/// Result of [`NativeScene::cast_ray_bounded`]: the terminal geometry hit
+/// (`None` if the ray was absorbed by a `Kill` face or left the domain
+/// without hitting anything), plus the total distance traveled and the
+/// number of `Reflect` bounces along the way, across however many boundary
+/// crossings it took to get there.
+#[derive(Debug, Clone)]
+pub struct BoundedRayHit {
+    pub hit: Option<RayHit>,
+    pub total_distance: f64,
+    pub reflection_count: u32,
+}
+
+/// Native scene representation
+struct NativeScene {
+    path: String,
+    boundary: Option<Boundary>,
+    objects: HashMap<String, SceneObject>,
+    bounds_min: Position,
+    bounds_max: Position,
+    /// BVH over every object's world-space AABB, plus the object-id order
+    /// its primitive indices refer to. Rebuilt on every geometry change.
+    bvh: Bvh,
+    bvh_order: Vec<String>,
+    /// Per-mesh-object triangle BVH and world-space triangle vertices, for
+    /// `Geometry::Mesh` objects. Rebuilt alongside `bvh`.
+    mesh_bvhs: HashMap<String, Bvh>,
+    mesh_triangles: HashMap<String, Vec<[Point3<f64>; 3]>>,
+}
+
+impl NativeScene {
+    fn new(path: String) -> Self {
+        Self {
+            path,
+            boundary: None,
+            objects: HashMap::new(),
+            bounds_min: Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            bounds_max: Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+            bvh: Bvh::build(Vec::new()),
+            bvh_order: Vec::new(),
+            mesh_bvhs: HashMap::new(),
+            mesh_triangles: HashMap::new(),
+        }
+    }
+
+    /// Map a set of object-local-space offsets to world-space points using
+    /// the object's transform, for world AABB computation.
+    fn rotated_corners_world(object: &SceneObject, local_corners: &[Vec3]) -> Vec<Point3<f64>> {
+        local_corners
+            .iter()
+            .map(|c| object.transform.position + object.transform.rotation * c)
+            .collect()
+    }
+
+    /// Map a world-space ray into an object's local frame: rotate by the
+    /// conjugate of the transform's rotation and subtract the translation,
+    /// so axis-aligned primitive tests can be run in canonical local
+    /// coordinates and the resulting hit mapped back to world space.
+    fn ray_to_local(ray: &Ray, transform: &Transform) -> Ray {
+        let inverse_rotation = transform.rotation.inverse();
+        Ray {
+            origin: Point3::from(inverse_rotation * (ray.origin - transform.position)),
+            direction: inverse_rotation * ray.direction,
+            max_distance: ray.max_distance,
+        }
+    }
+
+    fn object_world_aabb(object: &SceneObject) -> Aabb {
+        match &object.geometry {
+            Geometry::Sphere { radius } => {
+                let c = object.transform.position;
+                Aabb {
+                    min: Point3::new(c.x - radius, c.y - radius, c.z - radius),
+                    max: Point3::new(c.x + radius, c.y + radius, c.z + radius),
+                }
+            }
+            Geometry::Box { size } => {
+                let half = size / 2.0;
+                Aabb::from_points(&Self::rotated_corners_world(
+                    object,
+                    &[
+                        Vector3::new(-half.x, -half.y, -half.z),
+                        Vector3::new(half.x, -half.y, -half.z),
+                        Vector3::new(-half.x, half.y, -half.z),
+                        Vector3::new(half.x, half.y, -half.z),
+                        Vector3::new(-half.x, -half.y, half.z),
+                        Vector3::new(half.x, -half.y, half.z),
+                        Vector3::new(-half.x, half.y, half.z),
+                        Vector3::new(half.x, half.y, half.z),
+                    ],
+                ))
+            }
+            Geometry::Cylinder { radius, height } => {
+                let half_h = height / 2.0;
+                let r = *radius;
+                Aabb::from_points(&Self::rotated_corners_world(
+                    object,
+                    &[
+                        Vector3::new(-r, -r, -half_h),
+                        Vector3::new(r, -r, -half_h),
+                        Vector3::new(-r, r, -half_h),
+                        Vector3::new(r, r, -half_h),
+                        Vector3::new(-r, -r, half_h),
+                        Vector3::new(r, -r, half_h),
+                        Vector3::new(-r, r, half_h),
+                        Vector3::new(r, r, half_h),
+                    ],
+                ))
+            }
+            Geometry::Mesh { vertices, .. } => {
+                let world: Vec<Point3<f64>> = vertices
+                    .iter()
+                    .map(|v| object.transform.position + object.transform.rotation * v.coords)
+                    .collect();
+                Aabb::from_points(&world)
+            }
+        }
+    }
+
+    /// Rebuild the top-level object BVH and every mesh object's triangle
+    /// BVH from the current `objects` map. Called whenever geometry is
+    /// added, removed, or moved.
+    fn rebuild_acceleration_structures(&mut self) {
+        self.mesh_bvhs.clear();
+        self.mesh_triangles.clear();
+
+        let mut order = Vec::with_capacity(self.objects.len());
+        let mut prims = Vec::with_capacity(self.objects.len());
+
+        for (id, object) in self.objects.iter() {
+            prims.push((Self::object_world_aabb(object), order.len()));
+            order.push(id.clone());
+
+            if let Geometry::Mesh { vertices, indices } = &object.geometry {
+                let world_vertices: Vec<Point3<f64>> = vertices
+                    .iter()
+                    .map(|v| object.transform.position + object.transform.rotation * v.coords)
+                    .collect();
+
+                let triangles: Vec<[Point3<f64>; 3]> = indices
+                    .iter()
+                    .filter_map(|idx| {
+                        let v0 = *world_vertices.get(idx[0] as usize)?;
+                        let v1 = *world_vertices.get(idx[1] as usize)?;
+                        let v2 = *world_vertices.get(idx[2] as usize)?;
+                        Some([v0, v1, v2])
+                    })
+                    .collect();
+
+                let tri_prims: Vec<(Aabb, usize)> = triangles
+                    .iter()
+                    .enumerate()
+                    .map(|(i, tri)| (Aabb::from_points(tri), i))
+                    .collect();
+
+                self.mesh_bvhs.insert(id.clone(), Bvh::build(tri_prims));
+                self.mesh_triangles.insert(id.clone(), triangles);
+            }
+        }
+
+        self.bvh = Bvh::build(prims);
+        self.bvh_order = order;
+    }
+
+    fn get_bounds(&self) -> (Position, Position) {
+        (self.bounds_min, self.bounds_max)
+    }
+
+    fn add_object(&mut self, object: SceneObject) -> String {
+        let id = object.id.clone();
+        self.update_bounds(&object);
+        self.objects.insert(id.clone(), object);
+        self.rebuild_acceleration_structures();
+        id
+    }
+
+    fn remove_object(&mut self, object_id: &str) -> SimResult<()> {
+        self.objects
+            .remove(object_id)
+            .ok_or_else(|| SimError::BackendError(format!("Object not found: {}", object_id)))?;
+        self.recompute_bounds();
+        self.rebuild_acceleration_structures();
+        Ok(())
+    }
+
+    fn update_transform(&mut self, object_id: &str, transform: Transform) -> SimResult<()> {
+        let object = self
+            .objects
+            .get_mut(object_id)
+            .ok_or_else(|| SimError::BackendError(format!("Object not found: {}", object_id)))?;
+        object.transform = transform;
+        self.recompute_bounds();
+        self.rebuild_acceleration_structures();
+        Ok(())
+    }
+
+    fn update_bounds(&mut self, object: &SceneObject) {
+        let pos = &object.transform.position;
+        self.bounds_min = Point3::new(
+            self.bounds_min.x.min(pos.x),
+            self.bounds_min.y.min(pos.y),
+            self.bounds_min.z.min(pos.z),
+        );
+        self.bounds_max = Point3::new(
+            self.bounds_max.x.max(pos.x),
+            self.bounds_max.y.max(pos.y),
+            self.bounds_max.z.max(pos.z),
+        );
+    }
+
+    fn recompute_bounds(&mut self) {
+        let mut new_min = Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut new_max = Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+
+        for object in self.objects.values() {
+            let pos = &object.transform.position;
+            new_min = Point3::new(
+                new_min.x.min(pos.x),
+                new_min.y.min(pos.y),
+                new_min.z.min(pos.z),
+            );
+            new_max = Point3::new(
+                new_max.x.max(pos.x),
+                new_max.y.max(pos.y),
+                new_max.z.max(pos.z),
+            );
+        }
+
+        self.bounds_min = new_min;
+        self.bounds_max = new_max;
+    }
+
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
+        let mut closest = ray.max_distance;
+        let mut closest_hit: Option<RayHit> = None;
+
+        let mut visit = |prim: usize, closest: &mut f64| {
+            let Some(object_id) = self.bvh_order.get(prim) else {
+                return;
+            };
+            let Some(object) = self.objects.get(object_id) else {
+                return;
+            };
+            if let Some(hit) = self.intersect_object(ray, object) {
+                if hit.distance < *closest {
+                    *closest = hit.distance;
+                    closest_hit = Some(hit);
+                }
+            }
+        };
+
+        self.bvh.traverse(ray, &mut closest, &mut visit);
+        closest_hit
+    }
+
+    fn cast_rays(&self, rays: &[Ray]) -> Vec<Option<RayHit>> {
+        rays.iter().map(|ray| self.cast_ray(ray)).collect()
+    }
+
+    /// Whether a straight ray from `from` to `to` reaches `to` unoccluded.
+    fn is_los_clear(&self, from: Point3<f64>, to: Point3<f64>) -> bool {
+        let offset = to - from;
+        let distance = offset.norm();
+        if distance < 1e-6 {
+            return true;
+        }
+        let direction = offset / distance;
+        // Nudge the origin off the surface so the LOS ray doesn't
+        // immediately re-intersect the point it started from.
+        let ray = Ray::new(from + direction * 1e-4, direction);
+        match self.cast_ray(&ray) {
+            Some(hit) => hit.distance >= distance - 1e-3,
+            None => true,
+        }
+    }
+
+    /// Cast `ray` against both scene geometry and `self.boundary` (if any),
+    /// applying whichever is nearer. A `Kill` crossing absorbs the ray with
+    /// no hit; a `Reflect` crossing specularly bounces it and keeps
+    /// tracing; a `Periodic` crossing re-enters it through the opposite
+    /// face, unchanged direction. Capped by `max_reflections` boundary
+    /// interactions, so a degenerate boundary (e.g. two parallel `Reflect`
+    /// faces) can't loop forever.
+    fn cast_ray_bounded(&self, ray: &Ray, max_reflections: u32) -> BoundedRayHit {
+        let Some(boundary) = &self.boundary else {
+            let hit = self.cast_ray(ray);
+            let total_distance = hit
+                .as_ref()
+                .map(|hit| hit.distance)
+                .unwrap_or(ray.max_distance);
+            return BoundedRayHit {
+                hit,
+                total_distance,
+                reflection_count: 0,
+            };
+        };
+
+        let mut origin = ray.origin;
+        let mut direction = ray.direction;
+        let mut remaining = ray.max_distance;
+        let mut total_distance = 0.0;
+        let mut reflection_count = 0;
+
+        for _ in 0..=max_reflections {
+            let step_ray = Ray {
+                origin,
+                direction,
+                max_distance: remaining,
+            };
+            let geometry_hit = self.cast_ray(&step_ray);
+            let crossing = boundary.nearest_crossing(&step_ray, remaining);
+
+            let geometry_distance = geometry_hit.as_ref().map(|hit| hit.distance);
+            let crosses_first = match (geometry_distance, crossing) {
+                (Some(geometry_distance), Some((crossing_distance, ..))) => {
+                    crossing_distance < geometry_distance
+                }
+                (None, Some(_)) => true,
+                _ => false,
+            };
+
+            if !crosses_first {
+                let total_distance = total_distance
+                    + geometry_hit
+                        .as_ref()
+                        .map(|hit| hit.distance)
+                        .unwrap_or(remaining);
+                return BoundedRayHit {
+                    hit: geometry_hit,
+                    total_distance,
+                    reflection_count,
+                };
+            }
+
+            let (crossing_distance, axis, exits_positive, condition) =
+                crossing.expect("crosses_first implies a crossing was found");
+            total_distance += crossing_distance;
+
+            match condition {
+                BoundaryCondition::Kill => {
+                    return BoundedRayHit {
+                        hit: None,
+                        total_distance,
+                        reflection_count,
+                    };
+                }
+                BoundaryCondition::Reflect => {
+                    let hit_point = origin + direction * crossing_distance;
+                    let normal = axis.unit_vector();
+                    let d_dot_n = direction.dot(&normal);
+                    direction = (direction - normal * (2.0 * d_dot_n)).normalize();
+                    origin = hit_point + direction * 1e-4;
+                    remaining -= crossing_distance;
+                    reflection_count += 1;
+                }
+                BoundaryCondition::Periodic => {
+                    let hit_point = origin + direction * crossing_distance;
+                    origin = boundary.wrap(axis, exits_positive, hit_point) + direction * 1e-4;
+                    remaining -= crossing_distance;
+                }
+            }
+        }
+
+        BoundedRayHit {
+            hit: None,
+            total_distance,
+            reflection_count,
+        }
+    }
+
+    /// Recursively trace specular multipath RF propagation paths between
+    /// `tx_pos` and `rx_pos`: follow the direct tx-to-rx ray through
+    /// successive specular reflections off whatever it hits, and after
+    /// every bounce test a direct line-of-sight ray to the receiver,
+    /// recording a complete path whenever one is clear. Pruned by
+    /// `max_bounces` and by a minimum carried-power threshold.
+    fn trace_rf_paths(
+        &self,
+        tx_pos: Point3<f64>,
+        rx_pos: Point3<f64>,
+        frequency_hz: f64,
+        max_bounces: u32,
+    ) -> Vec<RfPath> {
+        const MIN_POWER_FRACTION: f64 = 1e-6; // -60 dB relative to 0 dB reflection loss
+        let frequency_hz = frequency_hz.max(1.0);
+        let wavelength = SPEED_OF_LIGHT / frequency_hz;
+
+        let mut paths = Vec::new();
+
+        let direct_distance = (rx_pos - tx_pos).norm().max(1e-3);
+        if self.is_los_clear(tx_pos, rx_pos) {
+            paths.push(RfPath {
+                hit_points: vec![rx_pos],
+                total_distance: direct_distance,
+                total_loss_db: free_space_path_loss_db(direct_distance, wavelength),
+                num_bounces: 0,
+            });
+        }
+
+        let mut origin = tx_pos;
+        let mut direction = (rx_pos - tx_pos) / direct_distance;
+        let mut cumulative_distance = 0.0;
+        let mut cumulative_reflection_loss_db = 0.0;
+        let mut hit_points = Vec::new();
+
+        for bounce in 1..=max_bounces {
+            let ray = Ray::new(origin, direction);
+            let Some(hit) = self.cast_ray(&ray) else {
+                break;
+            };
+            cumulative_distance += hit.distance;
+            hit_points.push(hit.position);
+
+            let incidence_cos = direction.dot(&hit.normal).abs().clamp(0.0, 1.0);
+            let reflection_magnitude =
+                fresnel_reflection_magnitude(&hit.material, incidence_cos.acos(), frequency_hz);
+            cumulative_reflection_loss_db += -20.0 * reflection_magnitude.max(1e-6).log10();
+
+            let carried_power_fraction = 10f64.powf(-cumulative_reflection_loss_db / 10.0);
+            if carried_power_fraction < MIN_POWER_FRACTION {
+                break;
+            }
+
+            if self.is_los_clear(hit.position, rx_pos) {
+                let los_distance = (rx_pos - hit.position).norm();
+                let total_distance = cumulative_distance + los_distance;
+                let mut path_hit_points = hit_points.clone();
+                path_hit_points.push(rx_pos);
+                paths.push(RfPath {
+                    hit_points: path_hit_points,
+                    total_distance,
+                    total_loss_db: free_space_path_loss_db(total_distance, wavelength)
+                        + cumulative_reflection_loss_db,
+                    num_bounces: bounce,
+                });
+            }
+
+            // Specular reflection: r = d - 2(d.n)n.
+            let d_dot_n = direction.dot(&hit.normal);
+            direction = (direction - hit.normal * (2.0 * d_dot_n)).normalize();
+            origin = hit.position + direction * 1e-4;
+        }
+
+        paths
+    }
+
+    fn intersect_object(&self, ray: &Ray, object: &SceneObject) -> Option<RayHit> {
+        match &object.geometry {
+            Geometry::Sphere { radius } => {
+                self.intersect_sphere(ray, &object.transform.position, *radius, object)
+            }
+            Geometry::Box { size } => self.intersect_box(ray, &object.transform, size, object),
+            Geometry::Cylinder { radius, height } => {
+                self.intersect_cylinder(ray, &object.transform, *radius, *height, object)
+            }
+            Geometry::Mesh { vertices, indices } => {
+                self.intersect_mesh(ray, &object.transform, vertices, indices, object)
+            }
+        }
+    }
+
+    fn intersect_sphere(
+        &self,
+        ray: &Ray,
+        center: &Position,
+        radius: f64,
+        object: &SceneObject,
+    ) -> Option<RayHit> {
+        let oc = ray.origin - center;
+        let a = ray.direction.dot(&ray.direction);
+        let b = 2.0 * oc.dot(&ray.direction);
+        let c = oc.dot(&oc) - radius * radius;
+        let discriminant = b * b - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let t = (-b - discriminant.sqrt()) / (2.0 * a);
+        if t < 0.0 || t > ray.max_distance {
+            return None;
+        }
+
+        let position = ray.at(t);
+        let normal = ((position - center) / radius).normalize();
+
+        Some(RayHit {
+            distance: t,
+            position,
+            normal,
+            material: object.material.clone(),
+            object_id: object.id.clone(),
+        })
+    }
+
+    fn intersect_box(
+        &self,
+        ray: &Ray,
+        transform: &Transform,
+        size: &Vec3,
+        object: &SceneObject,
+    ) -> Option<RayHit> {
+        // Oriented box: map the ray into local space (where the box is
+        // axis-aligned around the origin), run the slab test there, then
+        // map the hit back to world space.
+        let local_ray = Self::ray_to_local(ray, transform);
+        let half = size / 2.0;
+        let min = Point3::new(-half.x, -half.y, -half.z);
+        let max = Point3::new(half.x, half.y, half.z);
+
+        let mut tmin = (min.x - local_ray.origin.x) / local_ray.direction.x;
+        let mut tmax = (max.x - local_ray.origin.x) / local_ray.direction.x;
+
+        if tmin > tmax {
+            std::mem::swap(&mut tmin, &mut tmax);
+        }
+
+        let mut tymin = (min.y - local_ray.origin.y) / local_ray.direction.y;
+        let mut tymax = (max.y - local_ray.origin.y) / local_ray.direction.y;
+
+        if tymin > tymax {
+            std::mem::swap(&mut tymin, &mut tymax);
+        }
+
+        if tmin > tymax || tymin > tmax {
+            return None;
+        }
+
+        if tymin > tmin {
+            tmin = tymin;
+        }
+        if tymax < tmax {
+            tmax = tymax;
+        }
+
+        let mut tzmin = (min.z - local_ray.origin.z) / local_ray.direction.z;
+        let mut tzmax = (max.z - local_ray.origin.z) / local_ray.direction.z;
+
+        if tzmin > tzmax {
+            std::mem::swap(&mut tzmin, &mut tzmax);
+        }
+
+        if tmin > tzmax || tzmin > tmax {
+            return None;
+        }
+
+        if tzmin > tmin {
+            tmin = tzmin;
+        }
+
+        if tmin < 0.0 || tmin > local_ray.max_distance {
+            return None;
+        }
+
+        let local_position = local_ray.at(tmin);
+        let local_normal = self.compute_box_normal(&local_position, &min, &max);
+
+        let position = transform.position + transform.rotation * local_position.coords;
+        let normal = transform.rotation * local_normal;
+
+        Some(RayHit {
+            distance: tmin,
+            position,
+            normal,
+            material: object.material.clone(),
+            object_id: object.id.clone(),
         })
     }
 
@@ -489,26 +2021,162 @@ impl NativeScene {
 
     fn intersect_cylinder(
         &self,
-        _ray: &Ray,
-        _transform: &Transform,
-        _radius: f64,
-        _height: f64,
-        _object: &SceneObject,
+        ray: &Ray,
+        transform: &Transform,
+        radius: f64,
+        height: f64,
+        object: &SceneObject,
     ) -> Option<RayHit> {
-        // TODO: Implement cylinder intersection
-        None
+        // Local space: the cylinder's axis is local z, centered at the
+        // origin, spanning [-height/2, height/2]. Test the infinite side
+        // surface and the two end caps, keeping the nearest valid hit.
+        let local_ray = Self::ray_to_local(ray, transform);
+        let half_h = height / 2.0;
+        let (ox, oy, oz) = (local_ray.origin.x, local_ray.origin.y, local_ray.origin.z);
+        let (dx, dy, dz) = (
+            local_ray.direction.x,
+            local_ray.direction.y,
+            local_ray.direction.z,
+        );
+
+        let mut best: Option<(f64, Point3<f64>, Vec3)> = None;
+        let mut consider = |t: f64, local_position: Point3<f64>, local_normal: Vec3| {
+            if t < 0.0 || t > local_ray.max_distance {
+                return;
+            }
+            let is_closer = match best {
+                Some((best_t, _, _)) => t < best_t,
+                None => true,
+            };
+            if is_closer {
+                best = Some((t, local_position, local_normal));
+            }
+        };
+
+        // Side surface: x^2 + y^2 = r^2.
+        let a = dx * dx + dy * dy;
+        if a > 1e-12 {
+            let b = 2.0 * (ox * dx + oy * dy);
+            let c = ox * ox + oy * oy - radius * radius;
+            let discriminant = b * b - 4.0 * a * c;
+            if discriminant >= 0.0 {
+                let sqrt_discriminant = discriminant.sqrt();
+                for t in [
+                    (-b - sqrt_discriminant) / (2.0 * a),
+                    (-b + sqrt_discriminant) / (2.0 * a),
+                ] {
+                    let z = oz + dz * t;
+                    if z >= -half_h && z <= half_h {
+                        let x = ox + dx * t;
+                        let y = oy + dy * t;
+                        consider(t, Point3::new(x, y, z), Vector3::new(x, y, 0.0).normalize());
+                    }
+                }
+            }
+        }
+
+        // End caps: z = +-height/2, clipped to the circular cross-section.
+        if dz.abs() > 1e-12 {
+            for (cap_z, cap_normal_z) in [(half_h, 1.0), (-half_h, -1.0)] {
+                let t = (cap_z - oz) / dz;
+                let x = ox + dx * t;
+                let y = oy + dy * t;
+                if x * x + y * y <= radius * radius {
+                    consider(
+                        t,
+                        Point3::new(x, y, cap_z),
+                        Vector3::new(0.0, 0.0, cap_normal_z),
+                    );
+                }
+            }
+        }
+
+        let (t, local_position, local_normal) = best?;
+        let position = transform.position + transform.rotation * local_position.coords;
+        let normal = transform.rotation * local_normal;
+
+        Some(RayHit {
+            distance: t,
+            position,
+            normal,
+            material: object.material.clone(),
+            object_id: object.id.clone(),
+        })
     }
 
     fn intersect_mesh(
         &self,
-        _ray: &Ray,
+        ray: &Ray,
         _transform: &Transform,
         _vertices: &[Position],
         _indices: &[[u32; 3]],
-        _object: &SceneObject,
+        object: &SceneObject,
     ) -> Option<RayHit> {
-        // TODO: Implement mesh intersection with BVH
-        None
+        let triangles = self.mesh_triangles.get(&object.id)?;
+        let bvh = self.mesh_bvhs.get(&object.id)?;
+
+        let mut closest = ray.max_distance;
+        let mut best_hit: Option<RayHit> = None;
+
+        let mut visit = |tri: usize, closest: &mut f64| {
+            let Some(&[v0, v1, v2]) = triangles.get(tri) else {
+                return;
+            };
+            if let Some((t, normal)) = Self::intersect_triangle(ray, v0, v1, v2, *closest) {
+                *closest = t;
+                best_hit = Some(RayHit {
+                    distance: t,
+                    position: ray.at(t),
+                    normal,
+                    material: object.material.clone(),
+                    object_id: object.id.clone(),
+                });
+            }
+        };
+
+        bvh.traverse(ray, &mut closest, &mut visit);
+        best_hit
+    }
+
+    /// Möller-Trumbore ray/triangle intersection. Returns the hit distance
+    /// and geometric normal (`e1 x e2`, not normalized to face the ray) when
+    /// the ray hits within `[0, max_distance]`.
+    fn intersect_triangle(
+        ray: &Ray,
+        v0: Point3<f64>,
+        v1: Point3<f64>,
+        v2: Point3<f64>,
+        max_distance: f64,
+    ) -> Option<(f64, Vec3)> {
+        const EPS: f64 = 1e-9;
+
+        let e1 = v1 - v0;
+        let e2 = v2 - v0;
+        let p = ray.direction.cross(&e2);
+        let det = e1.dot(&p);
+        if det.abs() < EPS {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+
+        let t0 = ray.origin - v0;
+        let u = t0.dot(&p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = t0.cross(&e1);
+        let v = ray.direction.dot(&q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = e2.dot(&q) * inv_det;
+        if t < 0.0 || t > max_distance {
+            return None;
+        }
+
+        Some((t, e1.cross(&e2).normalize()))
     }
 
     fn get_objects(&self) -> Vec<SceneObject> {
@@ -519,6 +2187,9 @@ impl NativeScene {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scenario::{GeodeticPosition, Scenario, ScenarioVehicle, TimedCommand};
+    use crate::sensor::GeoProjection;
+    use crate::vehicle::{SensorSpec, SensorType};
     use nalgebra::{Point3, UnitQuaternion, Vector3};
 
     #[tokio::test]
@@ -567,4 +2238,920 @@ mod tests {
         assert_eq!(hit.object_id, "sphere1");
         assert!((hit.distance - 4.0).abs() < 1e-6);
     }
+
+    #[tokio::test]
+    async fn test_rotated_box_intersection() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        // A long thin box (x=1, y=1, z=10 local) rotated 90 degrees about x,
+        // which swaps its long axis from local z onto world y: the world
+        // extent becomes x in [-0.5, 0.5], y in [-5, 5], z in [-0.5, 0.5].
+        let box_obj = SceneObject {
+            id: "box1".to_string(),
+            name: "Rotated Box".to_string(),
+            transform: Transform::new(
+                Point3::origin(),
+                UnitQuaternion::from_axis_angle(&Vector3::x_axis(), std::f64::consts::FRAC_PI_2),
+            ),
+            geometry: Geometry::Box {
+                size: Vector3::new(1.0, 1.0, 10.0),
+            },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, box_obj).unwrap();
+
+        // y = 3.0 is well outside the unrotated box's old y in [-0.5, 0.5]
+        // extent, but within the rotated box's actual y in [-5, 5] extent -
+        // this ray only hits if rotation is honored.
+        let ray = Ray::new(Point3::new(0.3, 3.0, 5.0), Vector3::new(0.0, 0.0, -1.0));
+        let hit = backend.cast_ray(&scene, &ray).unwrap();
+        assert!(hit.is_some());
+        assert!((hit.unwrap().distance - 4.5).abs() < 1e-6);
+
+        // x = 2.0 is outside the box on every axis regardless of rotation.
+        let miss_ray = Ray::new(Point3::new(2.0, 2.0, 5.0), Vector3::new(0.0, 0.0, -1.0));
+        assert!(backend.cast_ray(&scene, &miss_ray).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cylinder_side_and_cap_intersection() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        let cylinder = SceneObject {
+            id: "cyl1".to_string(),
+            name: "Cylinder".to_string(),
+            transform: Transform::new(Point3::origin(), UnitQuaternion::identity()),
+            geometry: Geometry::Cylinder {
+                radius: 2.0,
+                height: 4.0,
+            },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, cylinder).unwrap();
+
+        // Straight down through the top cap.
+        let cap_ray = Ray::new(Point3::new(0.0, 0.0, 10.0), Vector3::new(0.0, 0.0, -1.0));
+        let cap_hit = backend.cast_ray(&scene, &cap_ray).unwrap().unwrap();
+        assert!((cap_hit.distance - 8.0).abs() < 1e-6);
+        assert!((cap_hit.normal - Vector3::new(0.0, 0.0, 1.0)).norm() < 1e-6);
+
+        // Horizontally through the side surface.
+        let side_ray = Ray::new(Point3::new(-10.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let side_hit = backend.cast_ray(&scene, &side_ray).unwrap().unwrap();
+        assert!((side_hit.distance - 8.0).abs() < 1e-6);
+
+        // A ray that clears the cylinder entirely should miss.
+        let miss_ray = Ray::new(Point3::new(-10.0, 0.0, 100.0), Vector3::new(1.0, 0.0, 0.0));
+        assert!(backend.cast_ray(&scene, &miss_ray).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_bvh_finds_closest_of_many_spheres() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        // Ten spheres stacked along +z; the BVH should still report the
+        // nearest one to the ray origin, not just any hit.
+        for i in 0..10 {
+            let sphere = SceneObject {
+                id: format!("sphere{}", i),
+                name: format!("Sphere {}", i),
+                transform: Transform::new(
+                    Point3::new(0.0, 0.0, 10.0 + i as f64 * 5.0),
+                    UnitQuaternion::identity(),
+                ),
+                geometry: Geometry::Sphere { radius: 1.0 },
+                material: Material::concrete(),
+            };
+            backend.add_object(&scene, sphere).unwrap();
+        }
+
+        let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        let hit = backend.cast_ray(&scene, &ray).unwrap().unwrap();
+
+        assert_eq!(hit.object_id, "sphere0");
+        assert!((hit.distance - 9.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_mesh_triangle_intersection() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        // A single quad (two triangles) lying flat at z = 2, spanning
+        // [-5, 5] in x and y.
+        let mesh = SceneObject {
+            id: "quad".to_string(),
+            name: "Quad".to_string(),
+            transform: Transform::identity(),
+            geometry: Geometry::Mesh {
+                vertices: vec![
+                    Point3::new(-5.0, -5.0, 2.0),
+                    Point3::new(5.0, -5.0, 2.0),
+                    Point3::new(5.0, 5.0, 2.0),
+                    Point3::new(-5.0, 5.0, 2.0),
+                ],
+                indices: vec![[0, 1, 2], [0, 2, 3]],
+            },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, mesh).unwrap();
+
+        let ray = Ray::new(Point3::new(0.0, 0.0, 10.0), Vector3::new(0.0, 0.0, -1.0));
+        let hit = backend.cast_ray(&scene, &ray).unwrap();
+        assert!(hit.is_some());
+        let hit = hit.unwrap();
+        assert_eq!(hit.object_id, "quad");
+        assert!((hit.distance - 8.0).abs() < 1e-6);
+
+        // A ray that misses the quad entirely should not hit.
+        let miss_ray = Ray::new(
+            Point3::new(100.0, 100.0, 10.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        assert!(backend.cast_ray(&scene, &miss_ray).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cast_rays_parallel_matches_serial() {
+        let mut parallel_backend = NativeBackend::new();
+        parallel_backend
+            .initialize(BackendConfig {
+                parallel_processing: true,
+                num_threads: Some(4),
+                ray_cast_chunk_size: Some(64),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let parallel_scene = parallel_backend.load_scene("test.scene").await.unwrap();
+
+        let mut serial_backend = NativeBackend::new();
+        serial_backend
+            .initialize(BackendConfig {
+                parallel_processing: false,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let serial_scene = serial_backend.load_scene("test.scene").await.unwrap();
+
+        // A grid of several thousand mixed spheres and boxes.
+        for i in 0..2500 {
+            let x = (i % 50) as f64 * 4.0;
+            let y = (i / 50) as f64 * 4.0;
+            let geometry = if i % 2 == 0 {
+                Geometry::Sphere { radius: 1.0 }
+            } else {
+                Geometry::Box {
+                    size: Vector3::new(1.0, 1.0, 1.0),
+                }
+            };
+            let object = SceneObject {
+                id: format!("object{}", i),
+                name: format!("Object {}", i),
+                transform: Transform::new(Point3::new(x, y, 0.0), UnitQuaternion::identity()),
+                geometry,
+                material: Material::concrete(),
+            };
+            parallel_backend
+                .add_object(&parallel_scene, object.clone())
+                .unwrap();
+            serial_backend.add_object(&serial_scene, object).unwrap();
+        }
+
+        // One ray straight down through each object's column, plus a batch
+        // of rays that miss everything.
+        let mut rays = Vec::new();
+        for i in 0..2500 {
+            let x = (i % 50) as f64 * 4.0;
+            let y = (i / 50) as f64 * 4.0;
+            rays.push(Ray::new(
+                Point3::new(x, y, 10.0),
+                Vector3::new(0.0, 0.0, -1.0),
+            ));
+        }
+        for i in 0..100 {
+            rays.push(Ray::new(
+                Point3::new(1000.0 + i as f64, 1000.0, 10.0),
+                Vector3::new(0.0, 0.0, -1.0),
+            ));
+        }
+
+        let parallel_hits = parallel_backend.cast_rays(&parallel_scene, &rays).unwrap();
+        let serial_hits = serial_backend.cast_rays(&serial_scene, &rays).unwrap();
+
+        assert_eq!(parallel_hits.len(), serial_hits.len());
+        for (parallel_hit, serial_hit) in parallel_hits.iter().zip(serial_hits.iter()) {
+            match (parallel_hit, serial_hit) {
+                (Some(a), Some(b)) => {
+                    assert_eq!(a.object_id, b.object_id);
+                    assert!((a.distance - b.distance).abs() < 1e-9);
+                }
+                (None, None) => {}
+                other => panic!("parallel/serial mismatch: {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trace_rf_paths_direct_los_when_clear() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        let tx_pos = Point3::new(0.0, 0.0, 0.0);
+        let rx_pos = Point3::new(20.0, 0.0, 0.0);
+
+        let paths = backend
+            .trace_rf_paths(&scene, tx_pos, rx_pos, 2.4e9, 3)
+            .unwrap();
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].num_bounces, 0);
+        assert!((paths[0].total_distance - 20.0).abs() < 1e-6);
+        assert!(paths[0].total_loss_db > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_trace_rf_paths_records_bounce_path_when_direct_blocked() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        // A thin reflecting wall directly between the transmitter and
+        // receiver, perpendicular to the line between them.
+        let wall = SceneObject {
+            id: "wall".to_string(),
+            name: "Wall".to_string(),
+            transform: Transform::identity(),
+            geometry: Geometry::Mesh {
+                vertices: vec![
+                    Point3::new(10.0, -5.0, -5.0),
+                    Point3::new(10.0, 5.0, -5.0),
+                    Point3::new(10.0, 5.0, 5.0),
+                    Point3::new(10.0, -5.0, 5.0),
+                ],
+                indices: vec![[0, 1, 2], [0, 2, 3]],
+            },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, wall).unwrap();
+
+        let tx_pos = Point3::new(0.0, 0.0, 0.0);
+        let rx_pos = Point3::new(20.0, 0.0, 0.0);
+
+        // The direct ray alone is blocked by the wall.
+        let direct_ray = Ray::new(tx_pos, rx_pos - tx_pos);
+        assert!(backend.cast_ray(&scene, &direct_ray).unwrap().is_some());
+
+        let paths = backend
+            .trace_rf_paths(&scene, tx_pos, rx_pos, 2.4e9, 3)
+            .unwrap();
+
+        assert_eq!(paths.len(), 1);
+        let path = &paths[0];
+        assert_eq!(path.num_bounces, 1);
+        assert_eq!(path.hit_points.len(), 2);
+        assert!((path.total_distance - 20.0).abs() < 1e-6);
+
+        let direct_distance = 20.0;
+        let wavelength = SPEED_OF_LIGHT / 2.4e9;
+        let direct_loss_db = free_space_path_loss_db(direct_distance, wavelength);
+        assert!(path.total_loss_db > direct_loss_db);
+    }
+
+    #[tokio::test]
+    async fn test_trace_rf_paths_prunes_below_zero_bounces_when_capped() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+
+        let wall = SceneObject {
+            id: "wall".to_string(),
+            name: "Wall".to_string(),
+            transform: Transform::identity(),
+            geometry: Geometry::Mesh {
+                vertices: vec![
+                    Point3::new(10.0, -5.0, -5.0),
+                    Point3::new(10.0, 5.0, -5.0),
+                    Point3::new(10.0, 5.0, 5.0),
+                    Point3::new(10.0, -5.0, 5.0),
+                ],
+                indices: vec![[0, 1, 2], [0, 2, 3]],
+            },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, wall).unwrap();
+
+        let tx_pos = Point3::new(0.0, 0.0, 0.0);
+        let rx_pos = Point3::new(20.0, 0.0, 0.0);
+
+        // With zero bounces allowed and the direct path blocked, no paths
+        // should be found.
+        let paths = backend
+            .trace_rf_paths(&scene, tx_pos, rx_pos, 2.4e9, 0)
+            .unwrap();
+        assert!(paths.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_boundary_kill_absorbs_a_ray_leaving_the_domain() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+        backend
+            .set_boundary(
+                &scene,
+                Boundary::cuboid(
+                    Point3::new(-10.0, -10.0, -10.0),
+                    Point3::new(10.0, 10.0, 10.0),
+                    BoundaryCondition::Kill,
+                ),
+            )
+            .unwrap();
+
+        let ray = Ray::new(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        let result = backend.cast_ray_bounded(&scene, &ray, 4).unwrap();
+
+        assert!(result.hit.is_none());
+        assert_eq!(result.reflection_count, 0);
+        assert!((result.total_distance - 10.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_boundary_reflect_bounces_back_and_hits_a_wall_behind_the_origin() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+        backend
+            .set_boundary(
+                &scene,
+                Boundary::cuboid(
+                    Point3::new(-10.0, -10.0, -10.0),
+                    Point3::new(10.0, 10.0, 10.0),
+                    BoundaryCondition::Reflect,
+                ),
+            )
+            .unwrap();
+
+        // A wall behind the ray's origin, only reachable after one bounce
+        // off the +x boundary face sends the ray back toward -x.
+        let wall = SceneObject {
+            id: "wall".to_string(),
+            name: "Wall".to_string(),
+            transform: Transform::identity(),
+            geometry: Geometry::Mesh {
+                vertices: vec![
+                    Point3::new(-5.0, -5.0, -5.0),
+                    Point3::new(-5.0, 5.0, -5.0),
+                    Point3::new(-5.0, 5.0, 5.0),
+                    Point3::new(-5.0, -5.0, 5.0),
+                ],
+                indices: vec![[0, 1, 2], [0, 2, 3]],
+            },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, wall).unwrap();
+
+        let ray = Ray::new(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        let result = backend.cast_ray_bounded(&scene, &ray, 4).unwrap();
+
+        let hit = result
+            .hit
+            .expect("expected the reflected ray to hit the wall");
+        assert_eq!(hit.object_id, "wall");
+        assert_eq!(result.reflection_count, 1);
+        // 10 m out to the +x face, then 15 m back to the wall at x = -5.
+        assert!((result.total_distance - 25.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_boundary_periodic_wraps_and_hits_a_sphere_past_the_far_face() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+        backend
+            .set_boundary(
+                &scene,
+                Boundary::cuboid(
+                    Point3::new(-10.0, -10.0, -10.0),
+                    Point3::new(10.0, 10.0, 10.0),
+                    BoundaryCondition::Periodic,
+                ),
+            )
+            .unwrap();
+
+        // Sits near the -x face (but not touching it), so it's only
+        // reachable after the ray wraps around from the +x face.
+        let sphere = SceneObject {
+            id: "sphere1".to_string(),
+            name: "Sphere".to_string(),
+            transform: Transform::new(Point3::new(-5.0, 0.0, 0.0), UnitQuaternion::identity()),
+            geometry: Geometry::Sphere { radius: 1.0 },
+            material: Material::concrete(),
+        };
+        backend.add_object(&scene, sphere).unwrap();
+
+        let ray = Ray::new(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        let result = backend.cast_ray_bounded(&scene, &ray, 4).unwrap();
+
+        let hit = result
+            .hit
+            .expect("expected the wrapped ray to hit the sphere");
+        assert_eq!(hit.object_id, "sphere1");
+        // 10 m out to the +x face, then 4 m back in from the -x face to the
+        // sphere's near surface at x = -6.
+        assert!((result.total_distance - 14.0).abs() < 1e-6);
+    }
+
+    fn noisy_vehicle_spec(vehicle_id: &str, imu_seed: u64, gps_seed: u64) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: vehicle_id.to_string(),
+            vehicle_type: VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: Default::default(),
+            sensors: vec![
+                SensorSpec {
+                    sensor_id: "imu".to_string(),
+                    sensor_type: SensorType::Imu,
+                    update_rate_hz: 100.0,
+                    enabled: true,
+                    lidar_config: None,
+                    radar_config: None,
+                    noise: Some(SensorNoise::imu_default(imu_seed)),
+                    fault: None,
+                },
+                SensorSpec {
+                    sensor_id: "gps".to_string(),
+                    sensor_type: SensorType::Gps,
+                    update_rate_hz: 10.0,
+                    enabled: true,
+                    lidar_config: None,
+                    radar_config: None,
+                    noise: Some(SensorNoise::gps_default(gps_seed)),
+                    fault: None,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_imu_noise_corrupts_readings_but_stays_seed_reproducible() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id = backend
+            .spawn_vehicle(noisy_vehicle_spec("drone1", 42, 7))
+            .await
+            .unwrap();
+
+        let SensorData::Imu(imu) = backend.get_sensor_data(&vehicle_id, "imu").unwrap() else {
+            panic!("expected IMU data");
+        };
+        // True acceleration/angular velocity are both zero for a freshly
+        // spawned, unstepped vehicle, so any nonzero reading came from noise.
+        assert!(imu.linear_acceleration.norm() > 0.0);
+        assert!(imu.angular_velocity.norm() > 0.0);
+
+        let mut backend2 = NativeBackend::new();
+        backend2.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id2 = backend2
+            .spawn_vehicle(noisy_vehicle_spec("drone1", 42, 7))
+            .await
+            .unwrap();
+        let SensorData::Imu(imu2) = backend2.get_sensor_data(&vehicle_id2, "imu").unwrap() else {
+            panic!("expected IMU data");
+        };
+        assert_eq!(imu.linear_acceleration, imu2.linear_acceleration);
+        assert_eq!(imu.angular_velocity, imu2.angular_velocity);
+    }
+
+    #[tokio::test]
+    async fn test_gps_noise_applies_fix_latency_behind_the_sim_clock() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id = backend
+            .spawn_vehicle(noisy_vehicle_spec("drone1", 1, 2))
+            .await
+            .unwrap();
+
+        let SensorData::Gps(gps) = backend.get_sensor_data(&vehicle_id, "gps").unwrap() else {
+            panic!("expected GPS data");
+        };
+        assert!(gps.timestamp < backend.get_time());
+    }
+
+    #[tokio::test]
+    async fn test_sensor_without_noise_returns_clean_readings() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+
+        let spec = VehicleSpec {
+            vehicle_id: "drone1".to_string(),
+            vehicle_type: VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: Default::default(),
+            sensors: vec![SensorSpec {
+                sensor_id: "imu".to_string(),
+                sensor_type: SensorType::Imu,
+                update_rate_hz: 100.0,
+                enabled: true,
+                lidar_config: None,
+                radar_config: None,
+                noise: None,
+                fault: None,
+            }],
+        };
+        let vehicle_id = backend.spawn_vehicle(spec).await.unwrap();
+
+        let SensorData::Imu(imu) = backend.get_sensor_data(&vehicle_id, "imu").unwrap() else {
+            panic!("expected IMU data");
+        };
+        assert_eq!(imu.linear_acceleration, Vector3::zeros());
+        assert_eq!(imu.angular_velocity, Vector3::zeros());
+    }
+
+    fn plain_vehicle_spec(vehicle_id: &str) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: vehicle_id.to_string(),
+            vehicle_type: VehicleType::Multirotor,
+            initial_transform: Transform::new(
+                Point3::new(0.0, 0.0, 10.0),
+                UnitQuaternion::identity(),
+            ),
+            parameters: Default::default(),
+            sensors: vec![SensorSpec {
+                sensor_id: "gps".to_string(),
+                sensor_type: SensorType::Gps,
+                update_rate_hz: 10.0,
+                enabled: true,
+                lidar_config: None,
+                radar_config: None,
+                noise: None,
+                fault: None,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropout_fault_fails_reads_until_the_configured_time() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id = backend
+            .spawn_vehicle(plain_vehicle_spec("drone1"))
+            .await
+            .unwrap();
+        backend
+            .set_sensor_fault(
+                &vehicle_id,
+                "gps",
+                Some(SensorFault::Dropout { until_time_s: 5.0 }),
+            )
+            .unwrap();
+
+        assert!(backend.get_sensor_data(&vehicle_id, "gps").is_err());
+
+        backend.step(10.0).await.unwrap();
+        assert!(backend.get_sensor_data(&vehicle_id, "gps").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stuck_at_last_value_fault_freezes_a_drifting_reading() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id = backend
+            .spawn_vehicle(noisy_vehicle_spec("drone1", 42, 7))
+            .await
+            .unwrap();
+
+        let SensorData::Imu(first) = backend.get_sensor_data(&vehicle_id, "imu").unwrap() else {
+            panic!("expected IMU data");
+        };
+        backend.step(1.0).await.unwrap();
+        let SensorData::Imu(second) = backend.get_sensor_data(&vehicle_id, "imu").unwrap() else {
+            panic!("expected IMU data");
+        };
+        // The random-walk bias keeps drifting between calls, so two reads a
+        // second apart disagree absent a fault.
+        assert_ne!(first.linear_acceleration, second.linear_acceleration);
+
+        backend
+            .set_sensor_fault(&vehicle_id, "imu", Some(SensorFault::StuckAtLastValue))
+            .unwrap();
+        let SensorData::Imu(frozen) = backend.get_sensor_data(&vehicle_id, "imu").unwrap() else {
+            panic!("expected IMU data");
+        };
+        backend.step(1.0).await.unwrap();
+        let SensorData::Imu(still_frozen) = backend.get_sensor_data(&vehicle_id, "imu").unwrap()
+        else {
+            panic!("expected IMU data");
+        };
+        assert_eq!(frozen.linear_acceleration, still_frozen.linear_acceleration);
+        assert_eq!(frozen.angular_velocity, still_frozen.angular_velocity);
+    }
+
+    #[tokio::test]
+    async fn test_set_sensor_fault_can_clear_a_previously_armed_fault() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id = backend
+            .spawn_vehicle(plain_vehicle_spec("drone1"))
+            .await
+            .unwrap();
+
+        backend
+            .set_sensor_fault(
+                &vehicle_id,
+                "gps",
+                Some(SensorFault::Dropout {
+                    until_time_s: 100.0,
+                }),
+            )
+            .unwrap();
+        assert!(backend.get_sensor_data(&vehicle_id, "gps").is_err());
+
+        backend.set_sensor_fault(&vehicle_id, "gps", None).unwrap();
+        assert!(backend.get_sensor_data(&vehicle_id, "gps").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_sensor_fault_rejects_an_unknown_sensor() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_id = backend
+            .spawn_vehicle(plain_vehicle_spec("drone1"))
+            .await
+            .unwrap();
+
+        assert!(backend
+            .set_sensor_fault(&vehicle_id, "lidar0", Some(SensorFault::StuckAtLastValue))
+            .is_err());
+    }
+
+    fn radar_vehicle_spec(
+        vehicle_id: &str,
+        position: Point3<f64>,
+        scan_mode: RadarScanMode,
+    ) -> VehicleSpec {
+        VehicleSpec {
+            vehicle_id: vehicle_id.to_string(),
+            vehicle_type: VehicleType::Multirotor,
+            initial_transform: Transform::new(position, UnitQuaternion::identity()),
+            parameters: Default::default(),
+            sensors: vec![SensorSpec {
+                sensor_id: "radar0".to_string(),
+                sensor_type: SensorType::Radar,
+                update_rate_hz: 10.0,
+                enabled: true,
+                lidar_config: None,
+                radar_config: Some(RadarConfig {
+                    scan_mode,
+                    ..RadarConfig::forward_looking_default()
+                }),
+                noise: None,
+                fault: None,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_radar_full_sweep_detects_a_vehicle_within_range() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let own_id = backend
+            .spawn_vehicle(radar_vehicle_spec(
+                "radar-drone",
+                Point3::new(0.0, 0.0, 0.0),
+                RadarScanMode::FullSweep,
+            ))
+            .await
+            .unwrap();
+        backend
+            .spawn_vehicle(plain_vehicle_spec_at("target", Point3::new(0.0, 50.0, 0.0)))
+            .await
+            .unwrap();
+
+        let SensorData::Radar(radar) = backend.get_sensor_data(&own_id, "radar0").unwrap() else {
+            panic!("expected Radar data");
+        };
+        assert_eq!(radar.targets.len(), 1);
+        let target = &radar.targets[0];
+        assert!((target.range_m - 50.0).abs() < 1e-6);
+        assert!((target.azimuth_deg - 0.0).abs() < 1e-6);
+        assert!(target.return_power_dbm.is_finite());
+    }
+
+    #[tokio::test]
+    async fn test_radar_ignores_a_vehicle_beyond_max_range() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let own_id = backend
+            .spawn_vehicle(radar_vehicle_spec(
+                "radar-drone",
+                Point3::new(0.0, 0.0, 0.0),
+                RadarScanMode::FullSweep,
+            ))
+            .await
+            .unwrap();
+        backend
+            .spawn_vehicle(plain_vehicle_spec_at(
+                "target",
+                Point3::new(0.0, 10_000.0, 0.0),
+            ))
+            .await
+            .unwrap();
+
+        let SensorData::Radar(radar) = backend.get_sensor_data(&own_id, "radar0").unwrap() else {
+            panic!("expected Radar data");
+        };
+        assert!(radar.targets.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_radar_ideal_ground_truth_skips_the_detection_threshold() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let own_id = backend
+            .spawn_vehicle(radar_vehicle_spec(
+                "radar-drone",
+                Point3::new(0.0, 0.0, 0.0),
+                RadarScanMode::IdealGroundTruth,
+            ))
+            .await
+            .unwrap();
+        backend
+            .spawn_vehicle(plain_vehicle_spec_at(
+                "target",
+                Point3::new(0.0, 150.0, 0.0),
+            ))
+            .await
+            .unwrap();
+
+        let SensorData::Radar(radar) = backend.get_sensor_data(&own_id, "radar0").unwrap() else {
+            panic!("expected Radar data");
+        };
+        assert_eq!(radar.targets.len(), 1);
+        assert!((radar.targets[0].range_m - 150.0).abs() < 1e-6);
+        assert!(radar.targets[0].return_power_dbm.is_nan());
+    }
+
+    fn adsb_vehicle_spec(vehicle_id: &str, position: Point3<f64>) -> VehicleSpec {
+        let mut spec = plain_vehicle_spec_at(vehicle_id, position);
+        spec.sensors.push(SensorSpec {
+            sensor_id: "adsb".to_string(),
+            sensor_type: SensorType::Adsb,
+            update_rate_hz: 1.0,
+            enabled: true,
+            lidar_config: None,
+            radar_config: None,
+            noise: None,
+            fault: None,
+        });
+        spec
+    }
+
+    #[tokio::test]
+    async fn test_adsb_reports_a_nearby_vehicles_decoded_state() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let own_id = backend
+            .spawn_vehicle(adsb_vehicle_spec("own", Point3::new(0.0, 0.0, 100.0)))
+            .await
+            .unwrap();
+        let other_id = backend
+            .spawn_vehicle(plain_vehicle_spec_at(
+                "traffic",
+                Point3::new(2000.0, 1000.0, 500.0),
+            ))
+            .await
+            .unwrap();
+
+        let SensorData::Adsb(adsb) = backend.get_sensor_data(&own_id, "adsb").unwrap() else {
+            panic!("expected Adsb data");
+        };
+
+        assert_eq!(adsb.contacts.len(), 1);
+        let contact = &adsb.contacts[0];
+        assert_eq!(
+            contact.icao_address,
+            crate::adsb::icao_from_vehicle_id(&other_id)
+        );
+        assert!((contact.latitude - 2000.0 / 111_320.0).abs() < 0.01);
+        assert!((contact.longitude - 1000.0 / 111_320.0).abs() < 0.01);
+        assert!((contact.altitude_m - 500.0).abs() < 50.0);
+        assert_eq!(contact.callsign, other_id.to_ascii_uppercase());
+    }
+
+    #[tokio::test]
+    async fn test_adsb_ignores_a_vehicle_beyond_max_range() {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let own_id = backend
+            .spawn_vehicle(adsb_vehicle_spec("own", Point3::new(0.0, 0.0, 100.0)))
+            .await
+            .unwrap();
+        backend
+            .spawn_vehicle(plain_vehicle_spec_at(
+                "far-traffic",
+                Point3::new(500_000.0, 0.0, 100.0),
+            ))
+            .await
+            .unwrap();
+
+        let SensorData::Adsb(adsb) = backend.get_sensor_data(&own_id, "adsb").unwrap() else {
+            panic!("expected Adsb data");
+        };
+        assert!(adsb.contacts.is_empty());
+    }
+
+    fn plain_vehicle_spec_at(vehicle_id: &str, position: Point3<f64>) -> VehicleSpec {
+        let mut spec = plain_vehicle_spec(vehicle_id);
+        spec.initial_transform = Transform::new(position, UnitQuaternion::identity());
+        spec
+    }
+
+    fn write_scenario_toml(scenario: &Scenario) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "autonomysim_scenario_test_{}_{}.toml",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::write(&path, toml::to_string(scenario).unwrap()).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_load_scenario_resolves_geodetic_vehicle_positions() {
+        let home = GeoProjection::new(0.0, 0.0, 0.0);
+        let scenario = Scenario {
+            scene: "test_scene.obj".to_string(),
+            home: Some(home),
+            vehicles: vec![ScenarioVehicle {
+                spec: plain_vehicle_spec("drone1"),
+                geodetic_position: Some(GeodeticPosition {
+                    latitude_deg: 1.0,
+                    longitude_deg: 0.0,
+                    altitude_m: 10.0,
+                }),
+            }],
+            timeline: vec![],
+        };
+        let path = write_scenario_toml(&scenario);
+
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let vehicle_ids = backend.load_scenario(&path).await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(vehicle_ids, vec!["drone1".to_string()]);
+        let state = backend.get_vehicle_state("drone1").unwrap();
+        let expected = home.to_local(1.0, 0.0, 10.0);
+        assert!((state.transform.position.x - expected.x).abs() < 1e-6);
+        assert!((state.transform.position.y - expected.y).abs() < 1e-6);
+        assert_eq!(state.transform.position.z, 10.0);
+    }
+
+    #[tokio::test]
+    async fn test_load_scenario_applies_timeline_commands_at_the_scheduled_time() {
+        let scenario = Scenario {
+            scene: "test_scene.obj".to_string(),
+            home: None,
+            vehicles: vec![ScenarioVehicle {
+                spec: plain_vehicle_spec("drone1"),
+                geodetic_position: None,
+            }],
+            timeline: vec![TimedCommand {
+                time_s: 5.0,
+                // No vehicle by this name is spawned, so the scheduled
+                // command fails once it comes due -- an observable way to
+                // check *when* it fires without a control-state getter.
+                vehicle_id: "does-not-exist".to_string(),
+                control: VehicleControl::hover(),
+            }],
+        };
+        let path = write_scenario_toml(&scenario);
+
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        backend.load_scenario(&path).await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        backend.step(4.0).await.unwrap();
+        assert!(backend.step(2.0).await.is_err());
+    }
 }
@@ -0,0 +1,401 @@
+//! Raycast-vehicle suspension and dynamics model
+//!
+//! `Car` agents only integrate physically if they opt in via
+//! `VehicleParameters::raycast_vehicle`; otherwise `NativeBackend::step`
+//! keeps the existing non-physical fallback of simply advancing time. For
+//! vehicles that do opt in, this gives each wheel a Bullet-`btRaycastVehicle`-
+//! style suspension: a ray cast straight down from the wheel's chassis-local
+//! mount point derives how compressed the spring is, traction and lateral
+//! force come from the same Pacejka tire model ground vehicles already use
+//! in [`crate::tire`], and the summed wheel forces/torques are integrated
+//! onto the chassis rigid body.
+
+use crate::backend::{Geometry, Position, Ray, Rotation, SceneHandle, SimulationBackend, Transform, Vec3};
+use crate::tire::{compute_tire_force, TireParameters};
+use crate::vehicle::{CollisionInfo, VehicleControl};
+use serde::{Deserialize, Serialize};
+
+/// Per-wheel raycast-suspension configuration, in chassis-local space.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WheelConfig {
+    /// Wheel mount point in chassis-local space.
+    pub chassis_offset: Vec3,
+    /// Suspension rest length (uncompressed) in meters.
+    pub rest_length: f64,
+    /// Wheel radius in meters.
+    pub radius: f64,
+    /// Suspension spring stiffness `k` (N/m).
+    pub spring_stiffness: f64,
+    /// Suspension damping coefficient `c` (N*s/m).
+    pub damping: f64,
+    /// Whether this wheel receives engine/brake traction force.
+    pub is_drive_wheel: bool,
+    /// Whether this wheel responds to steering input.
+    pub is_steering_wheel: bool,
+}
+
+impl WheelConfig {
+    /// A representative wheel for a small car, mounted at `chassis_offset`.
+    pub fn at_offset(chassis_offset: Vec3) -> Self {
+        Self {
+            chassis_offset,
+            rest_length: 0.3,
+            radius: 0.35,
+            spring_stiffness: 35_000.0,
+            damping: 4_000.0,
+            is_drive_wheel: false,
+            is_steering_wheel: false,
+        }
+    }
+}
+
+/// Opt-in raycast-vehicle dynamics configuration. Set
+/// `VehicleParameters::raycast_vehicle` to `Some` for `Car` agents that
+/// should integrate through wheel/suspension physics instead of the
+/// non-physical fallback.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaycastVehicleConfig {
+    pub wheels: Vec<WheelConfig>,
+    /// Chassis half-extents in meters; only used to approximate the
+    /// chassis bounding volume for collision reporting.
+    pub chassis_half_extents: Vec3,
+    /// Maximum steering angle in radians at `VehicleControl::steering = 1.0`.
+    pub max_steering_angle: f64,
+    /// Maximum engine traction force (N) at `VehicleControl::throttle = 1.0`.
+    pub max_engine_force: f64,
+    /// Maximum brake force (N) at `VehicleControl::brake = 1.0`.
+    pub max_brake_force: f64,
+}
+
+impl RaycastVehicleConfig {
+    /// A simple 4-wheel car: the front axle steers and drives, the rear
+    /// axle is passive.
+    pub fn four_wheel_car() -> Self {
+        Self {
+            wheels: vec![
+                WheelConfig {
+                    is_drive_wheel: true,
+                    is_steering_wheel: true,
+                    ..WheelConfig::at_offset(Vec3::new(-0.8, 1.3, 0.0))
+                },
+                WheelConfig {
+                    is_drive_wheel: true,
+                    is_steering_wheel: true,
+                    ..WheelConfig::at_offset(Vec3::new(0.8, 1.3, 0.0))
+                },
+                WheelConfig {
+                    is_drive_wheel: false,
+                    is_steering_wheel: false,
+                    ..WheelConfig::at_offset(Vec3::new(-0.8, -1.3, 0.0))
+                },
+                WheelConfig {
+                    is_drive_wheel: false,
+                    is_steering_wheel: false,
+                    ..WheelConfig::at_offset(Vec3::new(0.8, -1.3, 0.0))
+                },
+            ],
+            chassis_half_extents: Vec3::new(0.9, 2.0, 0.6),
+            max_steering_angle: 0.6,
+            max_engine_force: 6_000.0,
+            max_brake_force: 8_000.0,
+        }
+    }
+}
+
+/// Outcome of integrating one step of raycast-vehicle dynamics.
+#[derive(Debug, Clone)]
+pub struct RaycastVehicleStep {
+    pub is_grounded: bool,
+    pub collision_info: Option<CollisionInfo>,
+}
+
+/// Step the raycast-vehicle model: cast a ray per wheel against `scene`,
+/// derive suspension/traction/lateral forces, sum them onto the chassis
+/// rigid body, and integrate `transform`/`linear_velocity`/
+/// `angular_velocity` semi-implicitly over `delta_time`.
+#[allow(clippy::too_many_arguments)]
+pub fn step_raycast_vehicle(
+    backend: &dyn SimulationBackend,
+    scene: &SceneHandle,
+    config: &RaycastVehicleConfig,
+    tire_params: &TireParameters,
+    mass: f64,
+    inertia: Vec3,
+    control: &VehicleControl,
+    transform: &mut Transform,
+    linear_velocity: &mut Vec3,
+    angular_velocity: &mut Vec3,
+    delta_time: f64,
+) -> RaycastVehicleStep {
+    let forward = transform.rotation * Vec3::new(0.0, 1.0, 0.0);
+    let right = transform.rotation * Vec3::new(1.0, 0.0, 0.0);
+    let down = transform.rotation * Vec3::new(0.0, 0.0, -1.0);
+
+    let gravity = Vec3::new(0.0, 0.0, -9.81);
+    let mut net_force = gravity * mass;
+    let mut net_torque = Vec3::zeros();
+    let mut any_grounded = false;
+
+    let steering_angle = control.steering.clamp(-1.0, 1.0) * config.max_steering_angle;
+    let engine_force_input = control.throttle.clamp(0.0, 1.0) * config.max_engine_force
+        - control.brake.clamp(0.0, 1.0) * config.max_brake_force;
+
+    for wheel in &config.wheels {
+        let mount_point = transform.position + transform.rotation * wheel.chassis_offset;
+        let ray = Ray {
+            origin: mount_point,
+            direction: down,
+            max_distance: wheel.rest_length + wheel.radius,
+        };
+
+        let Ok(Some(hit)) = backend.cast_ray(scene, &ray) else {
+            continue;
+        };
+
+        any_grounded = true;
+
+        let compression =
+            (wheel.rest_length - (hit.distance - wheel.radius)).clamp(0.0, wheel.rest_length);
+
+        let contact_offset = hit.position - transform.position;
+        let velocity_at_contact = *linear_velocity + angular_velocity.cross(&contact_offset);
+        let compression_rate = -velocity_at_contact.dot(&hit.normal);
+
+        let suspension_force_magnitude =
+            (wheel.spring_stiffness * compression - wheel.damping * compression_rate).max(0.0);
+        let suspension_force = hit.normal * suspension_force_magnitude;
+
+        let wheel_forward = if wheel.is_steering_wheel {
+            (forward * steering_angle.cos() + right * steering_angle.sin()).normalize()
+        } else {
+            forward
+        };
+        let wheel_right = wheel_forward.cross(&down).normalize();
+
+        let forward_speed = velocity_at_contact.dot(&wheel_forward);
+        let lateral_speed = velocity_at_contact.dot(&wheel_right);
+
+        let traction_force_input = if wheel.is_drive_wheel {
+            engine_force_input
+        } else {
+            -control.brake.clamp(0.0, 1.0) * config.max_brake_force * forward_speed.signum()
+        };
+        let slip_ratio = (traction_force_input / config.max_engine_force.max(1.0)).clamp(-1.0, 1.0);
+        let slip_angle = lateral_speed.atan2(forward_speed.abs().max(0.1));
+
+        let tire_force = compute_tire_force(
+            tire_params,
+            slip_ratio,
+            slip_angle,
+            suspension_force_magnitude,
+        );
+
+        let in_plane_force =
+            wheel_forward * tire_force.longitudinal + wheel_right * tire_force.lateral;
+        let total_wheel_force = suspension_force + in_plane_force;
+
+        net_force += total_wheel_force;
+        net_torque += contact_offset.cross(&total_wheel_force);
+    }
+
+    let linear_acceleration = net_force / mass.max(1e-6);
+    let angular_acceleration = Vec3::new(
+        net_torque.x / inertia.x.max(1e-6),
+        net_torque.y / inertia.y.max(1e-6),
+        net_torque.z / inertia.z.max(1e-6),
+    );
+
+    // Semi-implicit Euler: update velocity from this step's forces first,
+    // then advance the transform with the updated velocity.
+    *linear_velocity += linear_acceleration * delta_time;
+    *angular_velocity += angular_acceleration * delta_time;
+
+    transform.position += *linear_velocity * delta_time;
+    let omega_mag = angular_velocity.norm();
+    if omega_mag > 1e-8 {
+        let axis = nalgebra::Unit::new_normalize(*angular_velocity);
+        let delta_rot = Rotation::from_axis_angle(&axis, omega_mag * delta_time);
+        // Angular velocity is carried in body frame, so apply the delta on
+        // the right (intrinsic rotation).
+        transform.rotation = transform.rotation * delta_rot;
+    }
+
+    let collision_info = chassis_overlap(backend, scene, transform, config.chassis_half_extents);
+
+    RaycastVehicleStep {
+        is_grounded: any_grounded,
+        collision_info,
+    }
+}
+
+/// Approximate chassis/scene overlap with a bounding-sphere test (the
+/// chassis half-extents' diagonal against each object's own bounding
+/// radius), since backends only expose per-object geometry here, not a
+/// shared AABB type.
+fn chassis_overlap(
+    backend: &dyn SimulationBackend,
+    scene: &SceneHandle,
+    transform: &Transform,
+    chassis_half_extents: Vec3,
+) -> Option<CollisionInfo> {
+    let objects = backend.get_objects(scene).ok()?;
+    let chassis_radius = chassis_half_extents.norm();
+
+    for object in objects {
+        let object_radius = geometry_bounding_radius(&object.geometry);
+        let offset: Vec3 = object.transform.position - transform.position;
+        let distance = offset.norm();
+        let overlap = chassis_radius + object_radius - distance;
+        if overlap > 0.0 {
+            let normal = if distance > 1e-6 {
+                offset / distance
+            } else {
+                Vec3::new(0.0, 0.0, 1.0)
+            };
+            let impact_point: Position = transform.position + normal * chassis_radius;
+            return Some(CollisionInfo {
+                has_collided: true,
+                collision_count: 1,
+                impact_point,
+                impact_normal: -normal,
+                impact_force: Vec3::zeros(),
+                penetration_depth: overlap,
+            });
+        }
+    }
+
+    None
+}
+
+fn geometry_bounding_radius(geometry: &Geometry) -> f64 {
+    match geometry {
+        Geometry::Sphere { radius } => *radius,
+        Geometry::Box { size } => size.norm() / 2.0,
+        Geometry::Cylinder { radius, height } => (radius * radius + (height / 2.0).powi(2)).sqrt(),
+        Geometry::Mesh { vertices, .. } => vertices
+            .iter()
+            .map(|v| v.coords.norm())
+            .fold(0.0, f64::max),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{BackendConfig, Material, SceneObject};
+    use crate::native::NativeBackend;
+    use nalgebra::Point3;
+
+    async fn flat_ground_scene() -> (NativeBackend, SceneHandle) {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("test.scene").await.unwrap();
+        backend
+            .add_object(
+                &scene,
+                SceneObject {
+                    id: "ground".to_string(),
+                    name: "Ground".to_string(),
+                    transform: Transform::new(
+                        Point3::new(0.0, 0.0, -0.5),
+                        Rotation::identity(),
+                    ),
+                    geometry: Geometry::Box {
+                        size: Vec3::new(100.0, 100.0, 1.0),
+                    },
+                    material: Material::concrete(),
+                },
+            )
+            .unwrap();
+        (backend, scene)
+    }
+
+    #[tokio::test]
+    async fn wheels_at_rest_length_above_ground_report_grounded() {
+        let (backend, scene) = flat_ground_scene().await;
+        let config = RaycastVehicleConfig::four_wheel_car();
+        let tire_params = TireParameters::default();
+
+        let mut transform = Transform::new(Point3::new(0.0, 0.0, 0.5), Rotation::identity());
+        let mut linear_velocity = Vec3::zeros();
+        let mut angular_velocity = Vec3::zeros();
+
+        let step = step_raycast_vehicle(
+            &backend,
+            &scene,
+            &config,
+            &tire_params,
+            1200.0,
+            Vec3::new(800.0, 1500.0, 1500.0),
+            &VehicleControl::default(),
+            &mut transform,
+            &mut linear_velocity,
+            &mut angular_velocity,
+            1.0 / 60.0,
+        );
+
+        assert!(step.is_grounded);
+    }
+
+    #[tokio::test]
+    async fn ungrounded_chassis_falls_under_gravity() {
+        let (backend, scene) = flat_ground_scene().await;
+        let config = RaycastVehicleConfig::four_wheel_car();
+        let tire_params = TireParameters::default();
+
+        // High enough that no wheel ray reaches the ground this step.
+        let mut transform = Transform::new(Point3::new(0.0, 0.0, 50.0), Rotation::identity());
+        let mut linear_velocity = Vec3::zeros();
+        let mut angular_velocity = Vec3::zeros();
+
+        let step = step_raycast_vehicle(
+            &backend,
+            &scene,
+            &config,
+            &tire_params,
+            1200.0,
+            Vec3::new(800.0, 1500.0, 1500.0),
+            &VehicleControl::default(),
+            &mut transform,
+            &mut linear_velocity,
+            &mut angular_velocity,
+            1.0 / 60.0,
+        );
+
+        assert!(!step.is_grounded);
+        assert!(linear_velocity.z < 0.0);
+    }
+
+    #[tokio::test]
+    async fn throttle_accelerates_grounded_car_forward() {
+        let (backend, scene) = flat_ground_scene().await;
+        let config = RaycastVehicleConfig::four_wheel_car();
+        let tire_params = TireParameters::default();
+
+        let mut transform = Transform::new(Point3::new(0.0, 0.0, 0.4), Rotation::identity());
+        let mut linear_velocity = Vec3::zeros();
+        let mut angular_velocity = Vec3::zeros();
+        let control = VehicleControl {
+            throttle: 1.0,
+            ..Default::default()
+        };
+
+        for _ in 0..30 {
+            step_raycast_vehicle(
+                &backend,
+                &scene,
+                &config,
+                &tire_params,
+                1200.0,
+                Vec3::new(800.0, 1500.0, 1500.0),
+                &control,
+                &mut transform,
+                &mut linear_velocity,
+                &mut angular_velocity,
+                1.0 / 60.0,
+            );
+        }
+
+        assert!(linear_velocity.y > 0.0);
+    }
+}
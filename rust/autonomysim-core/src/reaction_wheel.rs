@@ -0,0 +1,146 @@
+//! Reaction-wheel attitude-actuation allocation
+//!
+//! `Spacecraft` agents with `VehicleParameters::reaction_wheel` set control
+//! attitude through an array of momentum wheels instead of control surfaces
+//! or rotors. [`allocate_wheel_torques`] solves the per-wheel torque command
+//! that reproduces a desired body torque, using any redundancy in the wheel
+//! array to manage overall wheel momentum so it doesn't drift toward
+//! saturation over a long maneuver.
+
+use crate::backend::Vec3;
+use nalgebra::DMatrix;
+use serde::{Deserialize, Serialize};
+
+/// Opt-in reaction-wheel actuator configuration for `VehicleType::Spacecraft`
+/// agents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionWheelConfig {
+    /// Wheel `i`'s body-frame spin axis -- column `i` of the distribution
+    /// matrix `A` mapping individual wheel torques to body torque
+    /// (`tau_body = A * tau_wheels`).
+    pub wheel_axes: Vec<Vec3>,
+    /// Maximum torque (N*m) any single wheel can produce.
+    pub max_wheel_torque: f64,
+    /// Wheel speed (rad/s) the null-space term drives idle wheels toward,
+    /// keeping overall momentum low/balanced over long maneuvers.
+    pub target_wheel_speed: f64,
+    /// Null-space gain `k` in `tau_null = -k * (omega_wheels - omega_target)`.
+    pub momentum_management_gain: f64,
+}
+
+impl ReactionWheelConfig {
+    /// A standard 4-wheel pyramid: each wheel canted `beta` off the body
+    /// +Z axis, giving full 3-axis control with one spare degree of
+    /// redundancy to null out via momentum management.
+    pub fn four_wheel_pyramid() -> Self {
+        let beta = 35.0_f64.to_radians();
+        let (sb, cb) = (beta.sin(), beta.cos());
+        Self {
+            wheel_axes: vec![
+                Vec3::new(sb, 0.0, cb),
+                Vec3::new(-sb, 0.0, cb),
+                Vec3::new(0.0, sb, cb),
+                Vec3::new(0.0, -sb, cb),
+            ],
+            max_wheel_torque: 0.2,
+            target_wheel_speed: 0.0,
+            momentum_management_gain: 0.05,
+        }
+    }
+
+    /// The `3xN` distribution matrix `A` stacking [`Self::wheel_axes`] as
+    /// columns.
+    fn distribution_matrix(&self) -> DMatrix<f64> {
+        let n = self.wheel_axes.len();
+        DMatrix::from_fn(3, n, |row, col| self.wheel_axes[col][row])
+    }
+}
+
+/// Solve the reaction-wheel torque allocation
+/// `tau_rw = pinv(A) * tau_des + (I - pinv(A) * A) * tau_null`, where the
+/// null-space term drives `wheel_speeds` toward
+/// [`ReactionWheelConfig::target_wheel_speed`] so idle wheels don't
+/// saturate, then saturate each wheel's command to
+/// [`ReactionWheelConfig::max_wheel_torque`].
+///
+/// `wheel_speeds` should have one entry per
+/// [`ReactionWheelConfig::wheel_axes`]; missing entries read as zero speed.
+pub fn allocate_wheel_torques(
+    config: &ReactionWheelConfig,
+    tau_des: Vec3,
+    wheel_speeds: &[f64],
+) -> Vec<f64> {
+    let n = config.wheel_axes.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let a = config.distribution_matrix();
+    let pinv = a
+        .clone()
+        .pseudo_inverse(1e-9)
+        .unwrap_or_else(|_| DMatrix::zeros(n, 3));
+
+    let tau_des_vec = DMatrix::from_row_slice(3, 1, &[tau_des.x, tau_des.y, tau_des.z]);
+    let tau_alloc = &pinv * &tau_des_vec;
+
+    let speed_error = DMatrix::from_fn(n, 1, |row, _| {
+        wheel_speeds.get(row).copied().unwrap_or(0.0) - config.target_wheel_speed
+    });
+    let identity = DMatrix::<f64>::identity(n, n);
+    let null_space_projector = &identity - &pinv * &a;
+    let tau_null = &null_space_projector * (speed_error * -config.momentum_management_gain);
+
+    let tau_rw = tau_alloc + tau_null;
+
+    (0..n)
+        .map(|i| tau_rw[(i, 0)].clamp(-config.max_wheel_torque, config.max_wheel_torque))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_axis_wheel_matches_requested_torque_exactly() {
+        let config = ReactionWheelConfig {
+            wheel_axes: vec![Vec3::new(0.0, 0.0, 1.0)],
+            max_wheel_torque: 10.0,
+            target_wheel_speed: 0.0,
+            momentum_management_gain: 0.0,
+        };
+
+        let commands = allocate_wheel_torques(&config, Vec3::new(0.0, 0.0, 2.0), &[0.0]);
+
+        assert_eq!(commands.len(), 1);
+        assert!((commands[0] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn redundant_wheel_array_damps_a_fast_spinning_wheel_with_zero_desired_torque() {
+        let config = ReactionWheelConfig::four_wheel_pyramid();
+        let wheel_speeds = [500.0, 0.0, 0.0, 0.0];
+
+        let commands = allocate_wheel_torques(&config, Vec3::zeros(), &wheel_speeds);
+
+        assert!(
+            commands[0] < 0.0,
+            "fast wheel should be commanded to slow down"
+        );
+    }
+
+    #[test]
+    fn wheel_torque_commands_are_saturated_to_the_configured_limit() {
+        let config = ReactionWheelConfig {
+            wheel_axes: vec![Vec3::new(0.0, 0.0, 1.0)],
+            max_wheel_torque: 1.0,
+            target_wheel_speed: 0.0,
+            momentum_management_gain: 0.0,
+        };
+
+        let commands = allocate_wheel_torques(&config, Vec3::new(0.0, 0.0, 100.0), &[0.0]);
+
+        assert_eq!(commands[0], 1.0);
+    }
+}
@@ -0,0 +1,888 @@
+//! Gymnasium-style reinforcement-learning environment wrapper around
+//! `FpvPhysics`, so gate-following policies can be trained instead of
+//! relying on a hand-tuned autopilot like the one in the
+//! `fpv_drone_racing` example.
+
+use crate::backend::{Position, Rotation, Vec3};
+use crate::fpv::{FpvDroneConfig, FpvPhysics, FpvStickInput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+/// One race gate as seen by an `FpvEnv`: a pose and an in-plane size,
+/// independent of any particular track layout.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GateWaypoint {
+    pub position: Position,
+    pub orientation: Rotation,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl GateWaypoint {
+    /// World-space facing normal: body-forward `[1, 0, 0]` rotated by the
+    /// gate's orientation.
+    fn normal(&self) -> Vec3 {
+        self.orientation * Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    /// World-space "right" axis of the gate plane.
+    fn right(&self) -> Vec3 {
+        self.orientation * Vec3::new(0.0, 1.0, 0.0)
+    }
+
+    /// World-space "up" axis of the gate plane.
+    fn up(&self) -> Vec3 {
+        self.orientation * Vec3::new(0.0, 0.0, 1.0)
+    }
+}
+
+/// One upcoming gate, expressed relative to the drone so a policy doesn't
+/// need to reason about world-frame heading.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GateObservation {
+    /// Gate position relative to the drone, in the drone's body frame.
+    pub relative_position: Vec3,
+    /// Bearing to the gate in the drone's body frame (0 = dead ahead),
+    /// in radians.
+    pub relative_heading: f64,
+}
+
+/// Observation returned by `FpvEnv::reset`/`step`: everything a policy
+/// needs to fly toward and through the upcoming gates, or toward a
+/// [`FpvReward`]'s current target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FpvObservation {
+    /// Position error to the current target, in the drone's body frame:
+    /// the next gate for [`FpvRaceEnv`] (same vector as `gates[0]`, when
+    /// any gates are observed), or [`FpvReward::current_target`]'s
+    /// position for [`FpvGoalEnv`].
+    pub position_error_body: Vec3,
+    /// Linear velocity in the drone's body frame (m/s).
+    pub velocity_body: Vec3,
+    /// World gravity rotated into the drone's body frame -- doubles as an
+    /// attitude signal without exposing the raw orientation quaternion.
+    pub gravity_body: Vec3,
+    /// Angular velocity, body frame (rad/s).
+    pub angular_velocity: Vec3,
+    /// Battery remaining, 0.0-1.0.
+    pub battery_remaining: f64,
+    /// Up to `FpvEnvConfig::num_observed_gates` upcoming gates, nearest
+    /// first. Empty outside of [`FpvRaceEnv`].
+    pub gates: Vec<GateObservation>,
+}
+
+impl FpvObservation {
+    /// Flatten this observation into the numeric vector a policy network
+    /// consumes: `[position_error_body(3), velocity_body(3),
+    /// gravity_body(3), angular_velocity(3), battery_remaining(1)]`, 13
+    /// values in total. Doesn't include `gates`, since its length varies
+    /// by `FpvEnvConfig::num_observed_gates`; gate-aware policies should
+    /// read that field directly instead.
+    pub fn as_flat_vec(&self) -> Vec<f64> {
+        let mut flat = Vec::with_capacity(13);
+        flat.extend_from_slice(self.position_error_body.as_slice());
+        flat.extend_from_slice(self.velocity_body.as_slice());
+        flat.extend_from_slice(self.gravity_body.as_slice());
+        flat.extend_from_slice(self.angular_velocity.as_slice());
+        flat.push(self.battery_remaining);
+        flat
+    }
+}
+
+/// Why an episode ended (or what happened) on a given `step`, surfaced
+/// alongside the scalar reward so a training loop can log outcomes
+/// separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EpisodeEvent {
+    /// Nothing notable happened this step.
+    None,
+    /// The drone crossed the next gate's plane, forward and in bounds.
+    GatePassed,
+    /// The drone crossed the next gate's plane backwards.
+    GateCrossedInReverse,
+    /// The drone reached its current [`FpvReward`] waypoint and advanced
+    /// to the next one.
+    WaypointReached,
+    /// The drone hit the ground too fast to count as a landing.
+    Crashed,
+    /// The drone's body-up axis tilted past `FpvEnvConfig::tumble_threshold_deg`
+    /// from world-up -- flipped too far to be a recoverable attitude.
+    Tumbled,
+    /// The battery reached empty.
+    BatteryDepleted,
+    /// The drone left the configured flight volume.
+    OutOfBounds,
+}
+
+/// Outcome of one `FpvEnv::step` call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FpvStepResult {
+    pub observation: FpvObservation,
+    pub reward: f64,
+    pub terminated: bool,
+    pub truncated: bool,
+    pub event: EpisodeEvent,
+}
+
+/// Action accepted by [`FpvEnv::step`]: either normalized stick input, run
+/// through the full rate-PID controller and motor mixer like a real
+/// transmitter, or a direct per-motor command vector that bypasses both
+/// (see [`FpvPhysics::step_motor_outputs`]).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FpvAction {
+    Sticks(FpvStickInput),
+    /// One throttle command per motor, each `[0, 1]`.
+    MotorOutputs(Vec<f64>),
+}
+
+/// Apply `action` to `physics` for one `dt`, routing `MotorOutputs` around
+/// the rate-PID/mixer the same way `Sticks` goes through them.
+fn apply_action(physics: &mut FpvPhysics, dt: f64, action: &FpvAction) {
+    match action {
+        FpvAction::Sticks(input) => physics.step(dt, input),
+        FpvAction::MotorOutputs(outputs) => physics.step_motor_outputs(dt, outputs),
+    }
+}
+
+/// Body-up tilted more than `threshold_deg` from world-up: the craft has
+/// flipped past any attitude a rate PID could plausibly recover from.
+fn is_tumbled(physics: &FpvPhysics, threshold_deg: f64) -> bool {
+    let body_up = physics.orientation * Vec3::new(0.0, 0.0, 1.0);
+    body_up
+        .dot(&Vec3::new(0.0, 0.0, 1.0))
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees()
+        > threshold_deg
+}
+
+/// Gymnasium-style environment: `reset` starts a fresh episode, `step`
+/// advances the physics by one tick under `action` and scores it.
+pub trait FpvEnv {
+    fn reset(&mut self) -> FpvObservation;
+    fn step(&mut self, action: FpvAction) -> FpvStepResult;
+}
+
+/// Tunables for `FpvRaceEnv`/`FpvGoalEnv`, independent of the drone/task
+/// they wrap.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FpvEnvConfig {
+    /// Physics timestep in seconds.
+    pub dt: f64,
+    /// Steps per episode before `truncated` fires.
+    pub max_steps: usize,
+    /// How many upcoming gates to include per observation.
+    pub num_observed_gates: usize,
+    /// Half-extent of the legal flight volume on the X/Y axes; crossing it
+    /// ends the episode as `OutOfBounds`.
+    pub bounds_xy: f64,
+    /// Altitude above which the episode ends as `OutOfBounds`.
+    pub max_altitude: f64,
+    /// Ground-contact speed above which touching down counts as a crash
+    /// rather than a landing.
+    pub crash_speed_mps: f64,
+    /// Body-up tilt (degrees from world-up) past which the episode ends
+    /// as `Tumbled`.
+    pub tumble_threshold_deg: f64,
+    /// Physics ticks of `dt` run per `FpvEnv::step` call (frame-skip /
+    /// action-repeat). `1` (the default) steps the physics once per RL
+    /// step; higher values let a policy run at a coarser control rate than
+    /// the physics simulates at.
+    pub substeps: usize,
+    /// Horizontal spawn-position jitter (± meters on X/Y) applied by
+    /// `reset_seeded`. `0.0` (the default) always spawns at the exact
+    /// configured point, same as the plain `FpvEnv::reset`.
+    pub spawn_xy_jitter_m: f64,
+    /// Initial yaw jitter (± degrees) applied by `reset_seeded`, same as
+    /// `spawn_xy_jitter_m`.
+    pub spawn_yaw_jitter_deg: f64,
+}
+
+impl Default for FpvEnvConfig {
+    fn default() -> Self {
+        Self {
+            dt: 1.0 / 250.0,
+            max_steps: 5000,
+            num_observed_gates: 3,
+            bounds_xy: 200.0,
+            max_altitude: 100.0,
+            crash_speed_mps: 8.0,
+            tumble_threshold_deg: 100.0,
+            substeps: 1,
+            spawn_xy_jitter_m: 0.0,
+            spawn_yaw_jitter_deg: 0.0,
+        }
+    }
+}
+
+/// RL environment wrapping `FpvPhysics` around a fixed, looping gate
+/// track. Reward combines progress toward the next gate, a bonus for
+/// passing it, and penalties for reverse passage, crashing, tumbling,
+/// battery depletion, or leaving the flight volume.
+pub struct FpvRaceEnv {
+    config: FpvEnvConfig,
+    drone_config: FpvDroneConfig,
+    spawn: Position,
+    gates: Vec<GateWaypoint>,
+    physics: FpvPhysics,
+    next_gate: usize,
+    steps: usize,
+    /// Signed distance from `next_gate`'s plane as of the previous step;
+    /// `None` right after a reset or a gate passage, since there's no
+    /// prior sample to compare against yet.
+    last_gate_signed_distance: Option<f64>,
+    last_distance_to_next_gate: f64,
+}
+
+impl FpvRaceEnv {
+    /// Reward for passing a gate forward, within its width/height bounds.
+    const GATE_PASS_REWARD: f64 = 10.0;
+    /// Penalty for crossing a gate's plane backwards.
+    const REVERSE_GATE_PENALTY: f64 = 10.0;
+    /// Penalty for a hard-impact crash.
+    const CRASH_PENALTY: f64 = 50.0;
+    /// Penalty for leaving the configured flight volume.
+    const OUT_OF_BOUNDS_PENALTY: f64 = 20.0;
+    /// Penalty for tumbling past `FpvEnvConfig::tumble_threshold_deg`.
+    const TUMBLE_PENALTY: f64 = 20.0;
+
+    pub fn new(
+        drone_config: FpvDroneConfig,
+        spawn: Position,
+        gates: Vec<GateWaypoint>,
+        config: FpvEnvConfig,
+    ) -> Self {
+        assert!(!gates.is_empty(), "FpvRaceEnv needs at least one gate");
+        let physics = FpvPhysics::new(drone_config.clone(), spawn);
+        let mut env = Self {
+            config,
+            drone_config,
+            spawn,
+            gates,
+            physics,
+            next_gate: 0,
+            steps: 0,
+            last_gate_signed_distance: None,
+            last_distance_to_next_gate: 0.0,
+        };
+        env.last_distance_to_next_gate = env.distance_to_next_gate();
+        env
+    }
+
+    fn distance_to_next_gate(&self) -> f64 {
+        (self.gates[self.next_gate].position - self.physics.position).norm()
+    }
+
+    /// Rotate a world-frame vector into the drone's body frame.
+    fn body_frame(&self, world: Vec3) -> Vec3 {
+        self.physics.orientation.inverse() * world
+    }
+
+    fn gate_observations(&self) -> Vec<GateObservation> {
+        let n = self.config.num_observed_gates.min(self.gates.len());
+        (0..n)
+            .map(|offset| {
+                let gate = &self.gates[(self.next_gate + offset) % self.gates.len()];
+                let relative_position = self.body_frame(gate.position - self.physics.position);
+                let relative_heading = relative_position.y.atan2(relative_position.x);
+                GateObservation {
+                    relative_position,
+                    relative_heading,
+                }
+            })
+            .collect()
+    }
+
+    fn observation(&self) -> FpvObservation {
+        let gates = self.gate_observations();
+        let position_error_body = gates
+            .first()
+            .map(|g| g.relative_position)
+            .unwrap_or_else(Vec3::zeros);
+        FpvObservation {
+            position_error_body,
+            velocity_body: self.body_frame(self.physics.velocity),
+            gravity_body: self.body_frame(Vec3::new(0.0, 0.0, -9.81)),
+            angular_velocity: self.physics.angular_velocity,
+            battery_remaining: self.physics.battery_remaining,
+            gates,
+        }
+    }
+
+    /// Like [`FpvEnv::reset`] but reseeds the physics RNG (wind/IMU noise)
+    /// from `seed` and, if `FpvEnvConfig::spawn_xy_jitter_m`/
+    /// `spawn_yaw_jitter_deg` are non-zero, randomizes the spawn position
+    /// and initial yaw so repeated training episodes aren't identical.
+    pub fn reset_seeded(&mut self, seed: u64) -> FpvObservation {
+        use rand::Rng;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut drone_config = self.drone_config.clone();
+        drone_config.seed = seed;
+
+        let jitter_xy = self.config.spawn_xy_jitter_m;
+        let jitter_yaw_rad = self.config.spawn_yaw_jitter_deg.to_radians();
+        let spawn = Position::new(
+            self.spawn.x + (rng.gen::<f64>() * 2.0 - 1.0) * jitter_xy,
+            self.spawn.y + (rng.gen::<f64>() * 2.0 - 1.0) * jitter_xy,
+            self.spawn.z,
+        );
+        let yaw = (rng.gen::<f64>() * 2.0 - 1.0) * jitter_yaw_rad;
+
+        self.physics = FpvPhysics::new(drone_config, spawn);
+        self.physics.orientation = Rotation::from_euler_angles(0.0, 0.0, yaw);
+        self.physics.set_armed(true);
+        self.next_gate = 0;
+        self.steps = 0;
+        self.last_gate_signed_distance = None;
+        self.last_distance_to_next_gate = self.distance_to_next_gate();
+        self.observation()
+    }
+}
+
+impl FpvEnv for FpvRaceEnv {
+    fn reset(&mut self) -> FpvObservation {
+        self.reset_seeded(self.drone_config.seed)
+    }
+
+    fn step(&mut self, action: FpvAction) -> FpvStepResult {
+        self.steps += 1;
+        for _ in 0..self.config.substeps.max(1) {
+            apply_action(&mut self.physics, self.config.dt, &action);
+        }
+
+        let gate = self.gates[self.next_gate];
+        let offset = self.physics.position - gate.position;
+        let signed_distance = offset.dot(&gate.normal());
+
+        let mut event = EpisodeEvent::None;
+        let mut gate_bonus = 0.0;
+
+        match self.last_gate_signed_distance {
+            Some(previous) if previous < 0.0 && signed_distance >= 0.0 => {
+                let lateral = offset.dot(&gate.right());
+                let vertical = offset.dot(&gate.up());
+                if lateral.abs() <= gate.width / 2.0 && vertical.abs() <= gate.height / 2.0 {
+                    event = EpisodeEvent::GatePassed;
+                    gate_bonus = Self::GATE_PASS_REWARD;
+                    self.next_gate = (self.next_gate + 1) % self.gates.len();
+                    self.last_gate_signed_distance = None;
+                } else {
+                    // Crossed the plane outside the gate's frame -- neither
+                    // a pass nor a reverse crossing, just a miss.
+                    self.last_gate_signed_distance = Some(signed_distance);
+                }
+            }
+            Some(previous) if previous > 0.0 && signed_distance < 0.0 => {
+                event = EpisodeEvent::GateCrossedInReverse;
+                self.last_gate_signed_distance = Some(signed_distance);
+            }
+            _ => {
+                self.last_gate_signed_distance = Some(signed_distance);
+            }
+        }
+
+        let distance_to_next_gate = self.distance_to_next_gate();
+        let progress = self.last_distance_to_next_gate - distance_to_next_gate;
+        self.last_distance_to_next_gate = distance_to_next_gate;
+
+        let crashed = self.physics.position.z <= 1e-6
+            && self.physics.velocity.norm() > self.config.crash_speed_mps;
+        let out_of_bounds = self.physics.position.x.abs() > self.config.bounds_xy
+            || self.physics.position.y.abs() > self.config.bounds_xy
+            || self.physics.position.z > self.config.max_altitude;
+        let tumbled = is_tumbled(&self.physics, self.config.tumble_threshold_deg);
+        let battery_depleted = self.physics.battery_remaining <= 0.0;
+
+        let mut reward = progress + gate_bonus;
+        if event == EpisodeEvent::GateCrossedInReverse {
+            reward -= Self::REVERSE_GATE_PENALTY;
+        }
+        if crashed {
+            reward -= Self::CRASH_PENALTY;
+            event = EpisodeEvent::Crashed;
+        } else if out_of_bounds {
+            reward -= Self::OUT_OF_BOUNDS_PENALTY;
+            event = EpisodeEvent::OutOfBounds;
+        } else if tumbled {
+            reward -= Self::TUMBLE_PENALTY;
+            event = EpisodeEvent::Tumbled;
+        } else if battery_depleted {
+            event = EpisodeEvent::BatteryDepleted;
+        }
+
+        let terminated = crashed || out_of_bounds || tumbled || battery_depleted;
+        let truncated = !terminated && self.steps >= self.config.max_steps;
+
+        FpvStepResult {
+            observation: self.observation(),
+            reward,
+            terminated,
+            truncated,
+            event,
+        }
+    }
+}
+
+/// Pluggable reward shape for [`FpvGoalEnv`], analogous to
+/// [`crate::vec_env::VecReward`] but sized for a single `FpvPhysics`
+/// instance. Gate racing is still served directly by [`FpvRaceEnv`] --
+/// its reward depends on gate-crossing geometry that doesn't fit a single
+/// "current target" the way hover/waypoint tasks do.
+pub trait FpvReward {
+    /// Where `FpvGoalEnv::observation`'s `position_error_body` is measured
+    /// to.
+    fn current_target(&self) -> Position;
+
+    /// `(reward, event)` for the physics state just stepped to. Crash,
+    /// tumble, battery-depletion, and out-of-bounds termination are
+    /// handled by `FpvGoalEnv` itself, so implementations only need to
+    /// report task-specific events (e.g. `WaypointReached`).
+    fn evaluate(&mut self, physics: &FpvPhysics) -> (f64, EpisodeEvent);
+
+    /// Called on `FpvGoalEnv::reset`, so stateful reward functions
+    /// (progress trackers, waypoint indices) can clear their per-episode
+    /// state against the freshly-reset `physics`. No-op by default.
+    fn reset(&mut self, physics: &FpvPhysics) {
+        let _ = physics;
+    }
+}
+
+/// [`FpvReward`]: penalize distance to `target`, velocity, and angular
+/// rate -- hold station rather than travel anywhere.
+pub struct HoverReward {
+    pub target: Position,
+}
+
+impl FpvReward for HoverReward {
+    fn current_target(&self) -> Position {
+        self.target
+    }
+
+    fn evaluate(&mut self, physics: &FpvPhysics) -> (f64, EpisodeEvent) {
+        let distance = (self.target - physics.position).norm();
+        let reward =
+            -distance - 0.1 * physics.velocity.norm() - 0.05 * physics.angular_velocity.norm();
+        (reward, EpisodeEvent::None)
+    }
+}
+
+/// [`FpvReward`]: reward progress toward each waypoint in turn, advancing
+/// to the next one within `tolerance_m` of the current target.
+pub struct WaypointNavReward {
+    waypoints: Vec<Position>,
+    tolerance_m: f64,
+    current: usize,
+    last_distance: f64,
+}
+
+impl WaypointNavReward {
+    /// Reward for reaching a waypoint and advancing to the next.
+    const WAYPOINT_REACHED_REWARD: f64 = 10.0;
+
+    pub fn new(waypoints: Vec<Position>, tolerance_m: f64) -> Self {
+        assert!(
+            !waypoints.is_empty(),
+            "WaypointNavReward needs at least one waypoint"
+        );
+        Self {
+            waypoints,
+            tolerance_m,
+            current: 0,
+            last_distance: 0.0,
+        }
+    }
+}
+
+impl FpvReward for WaypointNavReward {
+    fn current_target(&self) -> Position {
+        self.waypoints[self.current]
+    }
+
+    fn evaluate(&mut self, physics: &FpvPhysics) -> (f64, EpisodeEvent) {
+        let distance = (self.current_target() - physics.position).norm();
+        let progress = self.last_distance - distance;
+        self.last_distance = distance;
+
+        let mut reward = progress;
+        let mut event = EpisodeEvent::None;
+        if distance <= self.tolerance_m {
+            reward += Self::WAYPOINT_REACHED_REWARD;
+            event = EpisodeEvent::WaypointReached;
+            self.current = (self.current + 1) % self.waypoints.len();
+            self.last_distance = (self.current_target() - physics.position).norm();
+        }
+        (reward, event)
+    }
+
+    fn reset(&mut self, physics: &FpvPhysics) {
+        self.current = 0;
+        self.last_distance = (self.waypoints[0] - physics.position).norm();
+    }
+}
+
+/// RL environment wrapping `FpvPhysics` around a single target or
+/// waypoint sequence, scored by a pluggable [`FpvReward`] (e.g.
+/// [`HoverReward`], [`WaypointNavReward`]) instead of the gate-racing
+/// reward baked into [`FpvRaceEnv`].
+pub struct FpvGoalEnv<R: FpvReward> {
+    config: FpvEnvConfig,
+    drone_config: FpvDroneConfig,
+    spawn: Position,
+    physics: FpvPhysics,
+    reward: R,
+    steps: usize,
+}
+
+impl<R: FpvReward> FpvGoalEnv<R> {
+    /// Penalty for a hard-impact crash.
+    const CRASH_PENALTY: f64 = 50.0;
+    /// Penalty for leaving the configured flight volume.
+    const OUT_OF_BOUNDS_PENALTY: f64 = 20.0;
+    /// Penalty for tumbling past `FpvEnvConfig::tumble_threshold_deg`.
+    const TUMBLE_PENALTY: f64 = 20.0;
+
+    pub fn new(
+        drone_config: FpvDroneConfig,
+        spawn: Position,
+        reward: R,
+        config: FpvEnvConfig,
+    ) -> Self {
+        let physics = FpvPhysics::new(drone_config.clone(), spawn);
+        Self {
+            config,
+            drone_config,
+            spawn,
+            physics,
+            reward,
+            steps: 0,
+        }
+    }
+
+    pub fn reward_module(&self) -> &R {
+        &self.reward
+    }
+
+    /// Rotate a world-frame vector into the drone's body frame.
+    fn body_frame(&self, world: Vec3) -> Vec3 {
+        self.physics.orientation.inverse() * world
+    }
+
+    fn observation(&self) -> FpvObservation {
+        FpvObservation {
+            position_error_body: self
+                .body_frame(self.reward.current_target() - self.physics.position),
+            velocity_body: self.body_frame(self.physics.velocity),
+            gravity_body: self.body_frame(Vec3::new(0.0, 0.0, -9.81)),
+            angular_velocity: self.physics.angular_velocity,
+            battery_remaining: self.physics.battery_remaining,
+            gates: Vec::new(),
+        }
+    }
+
+    /// Like [`FpvEnv::reset`] but reseeds the physics RNG from `seed` and
+    /// applies the configured spawn position/yaw jitter, mirroring
+    /// [`FpvRaceEnv::reset_seeded`].
+    pub fn reset_seeded(&mut self, seed: u64) -> FpvObservation {
+        use rand::Rng;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut drone_config = self.drone_config.clone();
+        drone_config.seed = seed;
+
+        let jitter_xy = self.config.spawn_xy_jitter_m;
+        let jitter_yaw_rad = self.config.spawn_yaw_jitter_deg.to_radians();
+        let spawn = Position::new(
+            self.spawn.x + (rng.gen::<f64>() * 2.0 - 1.0) * jitter_xy,
+            self.spawn.y + (rng.gen::<f64>() * 2.0 - 1.0) * jitter_xy,
+            self.spawn.z,
+        );
+        let yaw = (rng.gen::<f64>() * 2.0 - 1.0) * jitter_yaw_rad;
+
+        self.physics = FpvPhysics::new(drone_config, spawn);
+        self.physics.orientation = Rotation::from_euler_angles(0.0, 0.0, yaw);
+        self.physics.set_armed(true);
+        self.steps = 0;
+        self.reward.reset(&self.physics);
+        self.observation()
+    }
+}
+
+impl<R: FpvReward> FpvEnv for FpvGoalEnv<R> {
+    fn reset(&mut self) -> FpvObservation {
+        self.reset_seeded(self.drone_config.seed)
+    }
+
+    fn step(&mut self, action: FpvAction) -> FpvStepResult {
+        self.steps += 1;
+        for _ in 0..self.config.substeps.max(1) {
+            apply_action(&mut self.physics, self.config.dt, &action);
+        }
+
+        let (mut reward, mut event) = self.reward.evaluate(&self.physics);
+
+        let crashed = self.physics.position.z <= 1e-6
+            && self.physics.velocity.norm() > self.config.crash_speed_mps;
+        let out_of_bounds = self.physics.position.x.abs() > self.config.bounds_xy
+            || self.physics.position.y.abs() > self.config.bounds_xy
+            || self.physics.position.z > self.config.max_altitude;
+        let tumbled = is_tumbled(&self.physics, self.config.tumble_threshold_deg);
+        let battery_depleted = self.physics.battery_remaining <= 0.0;
+
+        if crashed {
+            reward -= Self::CRASH_PENALTY;
+            event = EpisodeEvent::Crashed;
+        } else if out_of_bounds {
+            reward -= Self::OUT_OF_BOUNDS_PENALTY;
+            event = EpisodeEvent::OutOfBounds;
+        } else if tumbled {
+            reward -= Self::TUMBLE_PENALTY;
+            event = EpisodeEvent::Tumbled;
+        } else if battery_depleted {
+            event = EpisodeEvent::BatteryDepleted;
+        }
+
+        let terminated = crashed || out_of_bounds || tumbled || battery_depleted;
+        let truncated = !terminated && self.steps >= self.config.max_steps;
+
+        FpvStepResult {
+            observation: self.observation(),
+            reward,
+            terminated,
+            truncated,
+            event,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_gate(x: f64, y: f64, yaw_deg: f64) -> GateWaypoint {
+        GateWaypoint {
+            position: Position::new(x, y, 1.5),
+            orientation: Rotation::from_axis_angle(&Vec3::z_axis(), yaw_deg.to_radians()),
+            width: 3.0,
+            height: 3.0,
+        }
+    }
+
+    fn two_gate_env() -> FpvRaceEnv {
+        FpvRaceEnv::new(
+            FpvDroneConfig::five_inch_race(),
+            Position::new(0.0, 0.0, 1.5),
+            vec![square_gate(20.0, 0.0, 0.0), square_gate(20.0, 20.0, 90.0)],
+            FpvEnvConfig {
+                num_observed_gates: 3,
+                ..FpvEnvConfig::default()
+            },
+        )
+    }
+
+    #[test]
+    fn gate_axes_are_orthonormal() {
+        let gate = square_gate(5.0, -3.0, 37.0);
+        let (n, r, u) = (gate.normal(), gate.right(), gate.up());
+        assert!((n.norm() - 1.0).abs() < 1e-9);
+        assert!((r.norm() - 1.0).abs() < 1e-9);
+        assert!((u.norm() - 1.0).abs() < 1e-9);
+        assert!(n.dot(&r).abs() < 1e-9);
+        assert!(n.dot(&u).abs() < 1e-9);
+        assert!(r.dot(&u).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reset_reports_full_battery_and_requested_gate_count() {
+        let mut env = two_gate_env();
+        let obs = env.reset();
+        assert_eq!(obs.battery_remaining, 1.0);
+        // Only 2 gates exist even though 3 were requested -- the track
+        // loops, so both should still be reported (wrapping back to gate 0).
+        assert_eq!(obs.gates.len(), 2);
+    }
+
+    #[test]
+    fn gate_observation_wraps_around_the_track() {
+        let mut env = two_gate_env();
+        env.reset();
+        env.next_gate = 1;
+        let obs = env.observation();
+        // Starting from gate 1 of a 2-gate track, the sequence should be
+        // [gate 1, gate 0] -- the wrap-around back to the start.
+        assert!(
+            (obs.gates[0].relative_position
+                - env.body_frame(env.gates[1].position - env.physics.position))
+            .norm()
+                < 1e-9
+        );
+        assert!(
+            (obs.gates[1].relative_position
+                - env.body_frame(env.gates[0].position - env.physics.position))
+            .norm()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn heading_is_zero_when_gate_is_dead_ahead_in_body_frame() {
+        let mut env = two_gate_env();
+        env.reset();
+        // Identity orientation means body frame == world frame, and the
+        // first gate sits on the +X axis from the spawn point.
+        let obs = env.observation();
+        assert!(obs.gates[0].relative_heading.abs() < 1e-9);
+    }
+
+    #[test]
+    fn stepping_past_the_max_altitude_ends_the_episode_out_of_bounds() {
+        let mut env = two_gate_env();
+        env.reset();
+        env.physics.set_armed(true);
+        env.physics.position.z = env.config.max_altitude + 10.0;
+        let result = env.step(FpvAction::Sticks(FpvStickInput::hover()));
+        assert_eq!(result.event, EpisodeEvent::OutOfBounds);
+        assert!(result.terminated);
+        assert!(result.reward < 0.0);
+    }
+
+    #[test]
+    fn observation_position_error_matches_the_next_gate() {
+        let mut env = two_gate_env();
+        let obs = env.reset();
+        assert!((obs.position_error_body - obs.gates[0].relative_position).norm() < 1e-9);
+    }
+
+    #[test]
+    fn motor_outputs_action_spins_up_motors_without_the_rate_pid() {
+        let mut env = two_gate_env();
+        env.reset();
+        env.step(FpvAction::MotorOutputs(vec![0.6, 0.6, 0.6, 0.6]));
+        assert!(env.physics.motor_outputs.iter().all(|&m| m > 0.0));
+    }
+
+    fn hover_env() -> FpvGoalEnv<HoverReward> {
+        FpvGoalEnv::new(
+            FpvDroneConfig::five_inch_race(),
+            Position::new(0.0, 0.0, 1.5),
+            HoverReward {
+                target: Position::new(0.0, 0.0, 1.5),
+            },
+            FpvEnvConfig::default(),
+        )
+    }
+
+    #[test]
+    fn hover_reward_env_reports_zero_position_error_at_the_target() {
+        let mut env = hover_env();
+        let obs = env.reset();
+        assert!(obs.position_error_body.norm() < 1e-9);
+    }
+
+    #[test]
+    fn hover_reward_penalizes_distance_from_target() {
+        let mut env = hover_env();
+        env.reset();
+        env.physics.position = Position::new(10.0, 0.0, 1.5);
+        let result = env.step(FpvAction::Sticks(FpvStickInput::hover()));
+        assert!(result.reward < 0.0);
+    }
+
+    #[test]
+    fn waypoint_nav_reward_advances_on_reaching_a_waypoint() {
+        let mut reward = WaypointNavReward::new(
+            vec![Position::new(5.0, 0.0, 1.5), Position::new(5.0, 5.0, 1.5)],
+            1.0,
+        );
+        let mut physics = FpvPhysics::new(
+            FpvDroneConfig::five_inch_race(),
+            Position::new(0.0, 0.0, 1.5),
+        );
+        reward.reset(&physics);
+        assert_eq!(reward.current_target(), Position::new(5.0, 0.0, 1.5));
+
+        physics.position = Position::new(5.0, 0.0, 1.5);
+        let (step_reward, event) = reward.evaluate(&physics);
+        assert_eq!(event, EpisodeEvent::WaypointReached);
+        assert!(step_reward > 0.0);
+        assert_eq!(reward.current_target(), Position::new(5.0, 5.0, 1.5));
+    }
+
+    #[test]
+    fn goal_env_terminates_when_battery_is_depleted() {
+        let mut env = hover_env();
+        env.reset();
+        // `battery_remaining` is recomputed from `mah_consumed` every step,
+        // so drain the pack that way rather than setting the field directly.
+        env.physics.mah_consumed = env.physics.config.battery_capacity_mah as f64 * 10.0;
+        let result = env.step(FpvAction::Sticks(FpvStickInput::hover()));
+        assert_eq!(result.event, EpisodeEvent::BatteryDepleted);
+        assert!(result.terminated);
+    }
+
+    #[test]
+    fn goal_env_terminates_when_tumbled() {
+        let mut env = hover_env();
+        env.reset();
+        env.physics.orientation = Rotation::from_axis_angle(&Vec3::x_axis(), std::f64::consts::PI);
+        let result = env.step(FpvAction::Sticks(FpvStickInput::hover()));
+        assert_eq!(result.event, EpisodeEvent::Tumbled);
+        assert!(result.terminated);
+    }
+
+    #[test]
+    fn zero_action_rollout_terminates_on_ground_contact() {
+        let mut env = two_gate_env();
+        env.reset();
+        let mut result = env.step(FpvAction::MotorOutputs(vec![0.0, 0.0, 0.0, 0.0]));
+        let mut ticks = 0;
+        while !result.terminated && ticks < 1000 {
+            result = env.step(FpvAction::MotorOutputs(vec![0.0, 0.0, 0.0, 0.0]));
+            ticks += 1;
+        }
+        assert!(result.terminated, "drone never hit the ground");
+        assert_eq!(result.event, EpisodeEvent::Crashed);
+    }
+
+    #[test]
+    fn substeps_advance_the_physics_clock_faster_per_rl_step() {
+        let mut env = FpvRaceEnv::new(
+            FpvDroneConfig::five_inch_race(),
+            Position::new(0.0, 0.0, 1.5),
+            vec![square_gate(20.0, 0.0, 0.0)],
+            FpvEnvConfig {
+                substeps: 4,
+                ..FpvEnvConfig::default()
+            },
+        );
+        env.reset();
+        env.step(FpvAction::Sticks(FpvStickInput::hover()));
+        assert!((env.physics.flight_time - 4.0 * env.config.dt).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reset_seeded_jitters_the_spawn_position_when_configured() {
+        let mut env = FpvRaceEnv::new(
+            FpvDroneConfig::five_inch_race(),
+            Position::new(0.0, 0.0, 1.5),
+            vec![square_gate(20.0, 0.0, 0.0)],
+            FpvEnvConfig {
+                spawn_xy_jitter_m: 2.0,
+                ..FpvEnvConfig::default()
+            },
+        );
+        env.reset_seeded(1);
+        let first = env.physics.position;
+        env.reset_seeded(2);
+        let second = env.physics.position;
+        assert!((first - second).norm() > 1e-9);
+    }
+
+    #[test]
+    fn reset_with_zero_jitter_always_spawns_at_the_configured_point() {
+        let mut env = two_gate_env();
+        let first = env.reset().position_error_body;
+        env.physics.position = Position::new(3.0, 0.0, 1.5);
+        let second = env.reset().position_error_body;
+        assert!((first - second).norm() < 1e-9);
+    }
+}
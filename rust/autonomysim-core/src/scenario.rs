@@ -0,0 +1,102 @@
+//! Scenario files: a reproducible, on-disk description of a scene and its
+//! vehicles, so demos and experiments don't have to hardcode drone
+//! positions, sensors, and control sequences directly in Rust. Load one
+//! with [`crate::native::NativeBackend::load_scenario`].
+
+use crate::sensor::GeoProjection;
+use crate::vehicle::{VehicleControl, VehicleId, VehicleSpec};
+use serde::{Deserialize, Serialize};
+
+/// A reproducible scenario: a scene to load, the vehicles to spawn into
+/// it, and an optional timeline of control commands to play back against
+/// sim time. Deserializes from TOML or YAML via [`parse_scenario`] (serde
+/// is already used throughout this crate).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    /// Scene path, passed through to `SimulationBackend::load_scene`.
+    pub scene: String,
+    /// Local-tangent-plane origin that [`ScenarioVehicle::geodetic_position`]
+    /// is resolved against. Required if any vehicle sets it.
+    #[serde(default)]
+    pub home: Option<GeoProjection>,
+    pub vehicles: Vec<ScenarioVehicle>,
+    /// Control commands to apply at their scheduled `time_s`, in any
+    /// order (sorted at load time).
+    #[serde(default)]
+    pub timeline: Vec<TimedCommand>,
+}
+
+/// One vehicle to spawn, reusing [`VehicleSpec`] verbatim for its type,
+/// parameters, and sensors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioVehicle {
+    #[serde(flatten)]
+    pub spec: VehicleSpec,
+    /// Geodetic initial position, resolved against the scenario's `home`
+    /// origin and used in place of `spec.initial_transform.position` when
+    /// present. `spec.initial_transform.rotation` still applies;
+    /// `spec.initial_transform.position` is ignored (and can be left at
+    /// the origin) when this is set.
+    #[serde(default)]
+    pub geodetic_position: Option<GeodeticPosition>,
+}
+
+/// A geodetic coordinate, in degrees/meters.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GeodeticPosition {
+    pub latitude_deg: f64,
+    pub longitude_deg: f64,
+    pub altitude_m: f64,
+}
+
+/// A control command scheduled against sim time, part of a [`Scenario`]'s
+/// timeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimedCommand {
+    /// Sim time, in seconds, at which `control` is applied.
+    pub time_s: f64,
+    pub vehicle_id: VehicleId,
+    pub control: VehicleControl,
+}
+
+/// Parse a [`Scenario`] from TOML or YAML text, picked by `path`'s file
+/// extension (`.toml`, or `.yaml`/`.yml`).
+pub fn parse_scenario(
+    path: &std::path::Path,
+    text: &str,
+) -> Result<Scenario, crate::backend::SimError> {
+    use crate::backend::SimError;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => toml::from_str(text).map_err(|e| {
+            SimError::InvalidConfig(format!(
+                "invalid scenario TOML in {}: {}",
+                path.display(),
+                e
+            ))
+        }),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(text).map_err(|e| {
+            SimError::InvalidConfig(format!(
+                "invalid scenario YAML in {}: {}",
+                path.display(),
+                e
+            ))
+        }),
+        other => Err(SimError::InvalidConfig(format!(
+            "unsupported scenario file extension {:?} for {}",
+            other,
+            path.display()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scenario_rejects_an_unknown_extension() {
+        let path = std::path::Path::new("scenario.json");
+        assert!(parse_scenario(path, "{}").is_err());
+    }
+}
@@ -36,6 +36,60 @@ pub enum GpsFixType {
     RtkFixed = 6,
 }
 
+/// Mean Earth radius (m), used by [`GeoProjection`]'s flat-earth
+/// (equirectangular) approximation.
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+/// A local-tangent-plane ↔ geodetic reprojection, anchored at a home
+/// lat/lon/alt. Backend world positions are local-ENU (east, north, up)
+/// relative to the home point; this is the conversion every real flight
+/// stack performs between the two. Accurate for the scale of a single
+/// scene (an equirectangular approximation, not a full ellipsoidal
+/// geodesy model).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GeoProjection {
+    pub home_latitude_deg: f64,
+    pub home_longitude_deg: f64,
+    pub home_altitude_m: f64,
+}
+
+impl GeoProjection {
+    pub fn new(home_latitude_deg: f64, home_longitude_deg: f64, home_altitude_m: f64) -> Self {
+        Self {
+            home_latitude_deg,
+            home_longitude_deg,
+            home_altitude_m,
+        }
+    }
+
+    /// Convert a local-ENU position to geodetic `(latitude_deg,
+    /// longitude_deg, altitude_m)`: `lat = lat0 + north/R_earth`, `lon =
+    /// lon0 + east/(R_earth*cos(lat0))`, `altitude = home_alt - down`.
+    pub fn to_geodetic(&self, local: Position) -> (f64, f64, f64) {
+        let north_m = local.y;
+        let east_m = local.x;
+        let down_m = -local.z;
+        let latitude_deg = self.home_latitude_deg + (north_m / EARTH_RADIUS_M).to_degrees();
+        let longitude_deg = self.home_longitude_deg
+            + (east_m / (EARTH_RADIUS_M * self.home_latitude_deg.to_radians().cos())).to_degrees();
+        let altitude_m = self.home_altitude_m - down_m;
+        (latitude_deg, longitude_deg, altitude_m)
+    }
+
+    /// Inverse of [`GeoProjection::to_geodetic`]: convert a geodetic
+    /// coordinate to a local-ENU position relative to the home point, so
+    /// a scenario file expressed in lat/lon/alt can be placed correctly
+    /// in the world.
+    pub fn to_local(&self, latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Position {
+        let north_m = (latitude_deg - self.home_latitude_deg).to_radians() * EARTH_RADIUS_M;
+        let east_m = (longitude_deg - self.home_longitude_deg).to_radians()
+            * EARTH_RADIUS_M
+            * self.home_latitude_deg.to_radians().cos();
+        let down_m = self.home_altitude_m - altitude_m;
+        Position::new(east_m, north_m, -down_m)
+    }
+}
+
 /// Magnetometer data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MagnetometerData {
@@ -66,6 +120,10 @@ pub struct DistanceSensorData {
 pub struct LidarPoint {
     pub position: Position,
     pub intensity: f32,
+    /// Distance from the sensor origin to this point, in meters.
+    pub range: f32,
+    /// Vertical channel (ring) index that produced this point.
+    pub ring: u32,
 }
 
 /// LiDAR data
@@ -120,6 +178,59 @@ pub struct RfAntennaData {
     pub doppler_shift_hz: f64, // Doppler shift
 }
 
+/// A single radar detection.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RadarTarget {
+    /// Slant range to the target, in meters.
+    pub range_m: f64,
+    /// Azimuth to the target relative to the sensor's forward heading, in
+    /// degrees, positive to the right.
+    pub azimuth_deg: f64,
+    /// Elevation to the target relative to the sensor's forward heading,
+    /// in degrees, positive up.
+    pub elevation_deg: f64,
+    /// Closing rate along the line of sight, in meters per second,
+    /// positive when the target is approaching.
+    pub radial_velocity_mps: f64,
+    /// Received return power, in dBm. Not meaningful (set to `f64::NAN`)
+    /// for `RadarScanMode::IdealGroundTruth`, which reports exact
+    /// kinematics rather than a physically modeled return.
+    pub return_power_dbm: f64,
+}
+
+/// Radar sensor data: every target detected in the most recent scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarData {
+    pub timestamp: f64,
+    pub targets: Vec<RadarTarget>,
+}
+
+/// One aircraft's decoded state in an [`AdsbData`] local air picture.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdsbContact {
+    /// 24-bit ICAO address decoded from the received frames.
+    pub icao_address: u32,
+    /// Latitude, decoded from a paired even/odd CPR airborne-position
+    /// frame via [`crate::adsb::decode_airborne_position_global`].
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude_m: f64,
+    pub ground_speed_mps: f64,
+    pub track_deg: f64,
+    pub vertical_rate_mps: f64,
+    /// Callsign decoded from an identification frame, trimmed of
+    /// trailing padding.
+    pub callsign: String,
+}
+
+/// ADS-B sensor data: the local air picture built from cooperative
+/// surveillance frames received from nearby traffic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdsbData {
+    pub timestamp: f64,
+    pub contacts: Vec<AdsbContact>,
+}
+
 /// Generic sensor data container
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SensorData {
@@ -131,6 +242,8 @@ pub enum SensorData {
     Lidar(LidarData),
     Camera(CameraData),
     RfAntenna(RfAntennaData),
+    Radar(RadarData),
+    Adsb(AdsbData),
 }
 
 impl SensorData {
@@ -144,6 +257,8 @@ impl SensorData {
             SensorData::Lidar(d) => d.timestamp,
             SensorData::Camera(d) => d.timestamp,
             SensorData::RfAntenna(d) => d.timestamp,
+            SensorData::Radar(d) => d.timestamp,
+            SensorData::Adsb(d) => d.timestamp,
         }
     }
 }
@@ -166,6 +281,27 @@ mod tests {
         assert_eq!(imu.linear_acceleration.z, 9.81);
     }
 
+    #[test]
+    fn test_geo_projection_to_geodetic_and_back_round_trips() {
+        let projection = GeoProjection::new(47.6, -122.3, 50.0);
+        let local = Position::new(10.0, 20.0, 5.0);
+        let (lat, lon, alt) = projection.to_geodetic(local);
+        let back = projection.to_local(lat, lon, alt);
+
+        assert!((back.x - local.x).abs() < 1e-6);
+        assert!((back.y - local.y).abs() < 1e-6);
+        assert!((back.z - local.z).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_geo_projection_to_geodetic_at_home_returns_the_home_point() {
+        let projection = GeoProjection::new(10.0, 20.0, 100.0);
+        let (lat, lon, alt) = projection.to_geodetic(Position::origin());
+        assert!((lat - 10.0).abs() < 1e-9);
+        assert!((lon - 20.0).abs() < 1e-9);
+        assert!((alt - 100.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_gps_fix_type() {
         let gps = GpsData {
@@ -0,0 +1,831 @@
+//! Lennard-Jones flocking controller for large robot swarms
+//!
+//! A centralized planner that assigns every robot an explicit trajectory
+//! doesn't scale past a few dozen agents and collapses the moment one robot
+//! falls behind schedule. `Flock` instead gives each robot a purely local
+//! rule: sum a Lennard-Jones-potential interaction with every sensed
+//! neighbor (repelling when too close, attracting when too far, flat at the
+//! desired spacing), add a weak pull toward the robot's own waypoint, clamp
+//! to a max speed, and integrate. The same rule run independently per robot
+//! produces a stable lattice that still drifts toward its goal, with no
+//! robot needing to know the others' plans.
+//!
+//! Neighbor lookups are bucketed into a uniform grid sized to
+//! `sensing_radius` -- the same coarse spatial-partitioning idea
+//! `autonomysim_summoner::SpatialPartitioner` uses to assign workers, here
+//! applied at per-robot instead of per-worker granularity -- so a step over
+//! `n` robots costs `O(n * k)` rather than the `O(n^2)` of an all-pairs scan.
+
+use crate::backend::{Position, Rotation, Vec3};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Tunables for [`Flock::step`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FlockConfig {
+    /// Desired inter-robot spacing in meters -- the distance at which the
+    /// Lennard-Jones interaction is zero (neither attracting nor
+    /// repelling).
+    pub target: f64,
+    /// Interaction strength scaling the Lennard-Jones magnitude.
+    pub epsilon: f64,
+    /// Only neighbors within this radius (meters) are sensed at all.
+    pub sensing_radius: f64,
+    /// Hard cap on neighbors considered per robot (nearest-first), keeping
+    /// a step `O(n * k)` instead of `O(n^2)` at swarm sizes like 1,000
+    /// robots.
+    pub max_neighbors: usize,
+    /// Strength of the weak pull toward each robot's own waypoint, applied
+    /// on top of the flocking term so the lattice drifts toward its goal
+    /// instead of just settling in place wherever it formed.
+    pub waypoint_gain: f64,
+    /// Maximum speed (m/s) a robot's integrated velocity is clamped to.
+    pub max_vel: f64,
+}
+
+impl Default for FlockConfig {
+    fn default() -> Self {
+        Self {
+            target: 5.0,
+            epsilon: 1.0,
+            sensing_radius: 20.0,
+            max_neighbors: 16,
+            waypoint_gain: 0.2,
+            max_vel: 5.0,
+        }
+    }
+}
+
+/// Decentralized flocking controller: advances every robot's position one
+/// step using only its own neighbor geometry, with no central coordination.
+pub struct Flock {
+    config: FlockConfig,
+}
+
+impl Flock {
+    pub fn new(config: FlockConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &FlockConfig {
+        &self.config
+    }
+
+    /// Advance every robot in `positions` by `dt`, pulled by Lennard-Jones
+    /// interaction with its nearby neighbors (from `positions` itself) and a
+    /// weak pull toward the matching entry in `targets`. Updates `positions`
+    /// in place.
+    ///
+    /// Returns the fleet's mean nearest-neighbor spacing, for callers to
+    /// report lattice health: a value well below `target` means the swarm
+    /// is collapsing, well above means it's dispersed rather than flocked.
+    /// Robots with no sensed neighbor (an isolated straggler) don't
+    /// contribute to this mean.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `positions.len() != targets.len()`.
+    pub fn step(&self, positions: &mut [Position], targets: &[Position], dt: f64) -> f64 {
+        assert_eq!(
+            positions.len(),
+            targets.len(),
+            "positions and targets must be the same length"
+        );
+
+        let grid = NeighborGrid::build(positions, self.config.sensing_radius);
+        let mut velocities = vec![Vec3::zeros(); positions.len()];
+        let mut nearest_sum = 0.0_f64;
+        let mut nearest_count = 0usize;
+
+        for i in 0..positions.len() {
+            let mut accum = Vec3::zeros();
+            let mut nearest: Option<f64> = None;
+
+            for j in grid.neighbors_of(i, positions, self.config.max_neighbors) {
+                let offset = positions[j] - positions[i];
+                let dist = offset.norm();
+                if dist == 0.0 {
+                    continue; // Skip self / exact overlap: bearing is undefined.
+                }
+
+                let bearing = offset / dist;
+                let ratio = self.config.target / dist;
+                let mag = -(self.config.epsilon / dist) * (ratio.powi(4) - ratio.powi(2));
+                accum += bearing * mag;
+
+                nearest = Some(nearest.map_or(dist, |d| d.min(dist)));
+            }
+
+            if let Some(dist) = nearest {
+                nearest_sum += dist;
+                nearest_count += 1;
+            }
+
+            let to_goal = targets[i] - positions[i];
+            let goal_dist = to_goal.norm();
+            if goal_dist > 0.0 {
+                accum += (to_goal / goal_dist) * self.config.waypoint_gain;
+            }
+
+            let speed = accum.norm();
+            velocities[i] = if speed > self.config.max_vel && speed > 0.0 {
+                accum * (self.config.max_vel / speed)
+            } else {
+                accum
+            };
+        }
+
+        for (position, velocity) in positions.iter_mut().zip(velocities) {
+            *position += velocity * dt;
+        }
+
+        if nearest_count > 0 {
+            nearest_sum / nearest_count as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Per-role Lennard-Jones tuning for [`FlockingController`] -- scouts can be
+/// configured to pack tighter than transports by giving each role its own
+/// `target`/`epsilon` rather than sharing one [`FlockConfig`] fleet-wide.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RoleSpacing {
+    /// Desired inter-agent spacing in meters for this role.
+    pub target: f64,
+    /// Interaction strength scaling the Lennard-Jones magnitude for this
+    /// role.
+    pub epsilon: f64,
+}
+
+/// Lennard-Jones flocking controller driven by an externally supplied
+/// neighbor set (e.g. from `autonomysim_summoner::BroadPhase::overlapping_pairs`)
+/// instead of [`Flock`]'s own all-positions grid -- the shape a caller
+/// already doing distributed spatial partitioning needs, where neighbor
+/// discovery happens once per step outside this controller and the result
+/// is handed in per agent.
+///
+/// Unlike [`Flock`], which advances every robot's 3D position in one call,
+/// `FlockingController` computes a single agent's desired 2D (ground-plane)
+/// velocity command from its bearing to each sensed neighbor -- the caller
+/// integrates position and handles altitude/Z separately.
+pub struct FlockingController {
+    default_spacing: RoleSpacing,
+    role_spacing: HashMap<String, RoleSpacing>,
+    /// Neighbors farther than this (meters) contribute nothing, regardless
+    /// of role.
+    neighbor_cutoff: f64,
+    /// Desired velocity magnitude is clamped to this (m/s).
+    max_velocity: f64,
+}
+
+impl FlockingController {
+    pub fn new(default_spacing: RoleSpacing, neighbor_cutoff: f64, max_velocity: f64) -> Self {
+        Self {
+            default_spacing,
+            role_spacing: HashMap::new(),
+            neighbor_cutoff,
+            max_velocity,
+        }
+    }
+
+    /// Override the `target`/`epsilon` used for agents of `role`; roles with
+    /// no override fall back to `default_spacing`.
+    pub fn set_role_spacing(&mut self, role: impl Into<String>, spacing: RoleSpacing) {
+        self.role_spacing.insert(role.into(), spacing);
+    }
+
+    fn spacing_for(&self, role: &str) -> RoleSpacing {
+        self.role_spacing
+            .get(role)
+            .copied()
+            .unwrap_or(self.default_spacing)
+    }
+
+    /// The velocity magnitude cap every [`Self::velocity_command`] result is
+    /// clamped to -- exposed so a caller layering additional terms on top
+    /// (e.g. a mission-waypoint attractor) can re-clamp the combined
+    /// command to the same bound.
+    pub fn max_velocity(&self) -> f64 {
+        self.max_velocity
+    }
+
+    /// Desired 2D `(vx, vy)` velocity command for one agent of `role` at
+    /// `position`, pulled by a Lennard-Jones interaction with every entry of
+    /// `neighbor_positions` (both in ground-plane meters).
+    ///
+    /// Each neighbor at distance `d` and bearing `azimuth` contributes a
+    /// polar vector `(mag, azimuth)` with `mag = -(epsilon / d) *
+    /// ((target/d)^4 - (target/d)^2)`, converted to Cartesian and summed.
+    /// Neighbors beyond `neighbor_cutoff` are skipped; coincident neighbors
+    /// (`d == 0`, e.g. overlapping spawns) are skipped too, since their
+    /// bearing is undefined.
+    pub fn velocity_command(
+        &self,
+        role: &str,
+        position: (f64, f64),
+        neighbor_positions: &[(f64, f64)],
+    ) -> (f64, f64) {
+        let spacing = self.spacing_for(role);
+        let mut accum = (0.0_f64, 0.0_f64);
+
+        for &(nx, ny) in neighbor_positions {
+            let dx = nx - position.0;
+            let dy = ny - position.1;
+            let d = dx.hypot(dy);
+            if d == 0.0 || d > self.neighbor_cutoff {
+                continue;
+            }
+
+            let azimuth = dy.atan2(dx);
+            let ratio = spacing.target / d;
+            let mag = -(spacing.epsilon / d) * (ratio.powi(4) - ratio.powi(2));
+            accum.0 += mag * azimuth.cos();
+            accum.1 += mag * azimuth.sin();
+        }
+
+        let speed = accum.0.hypot(accum.1);
+        if speed > self.max_velocity && speed > 0.0 {
+            let scale = self.max_velocity / speed;
+            (accum.0 * scale, accum.1 * scale)
+        } else {
+            accum
+        }
+    }
+}
+
+/// Uniform grid bucketing robot indices by cell (sized to the sensing
+/// radius) for `O(1)`-ish neighbor lookups instead of scanning every other
+/// robot.
+struct NeighborGrid {
+    cell_size: f64,
+    buckets: HashMap<(i64, i64, i64), Vec<usize>>,
+}
+
+impl NeighborGrid {
+    fn build(positions: &[Position], cell_size: f64) -> Self {
+        // A zero or negative sensing radius would divide by zero below;
+        // fall back to a 1m cell so the grid still degrades gracefully
+        // instead of panicking.
+        let cell_size = if cell_size > 0.0 { cell_size } else { 1.0 };
+        let mut buckets: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+        for (i, position) in positions.iter().enumerate() {
+            buckets
+                .entry(Self::cell_of(position, cell_size))
+                .or_default()
+                .push(i);
+        }
+        Self { cell_size, buckets }
+    }
+
+    fn cell_of(position: &Position, cell_size: f64) -> (i64, i64, i64) {
+        (
+            (position.x / cell_size).floor() as i64,
+            (position.y / cell_size).floor() as i64,
+            (position.z / cell_size).floor() as i64,
+        )
+    }
+
+    /// Candidate indices (excluding `i` itself) within the 3x3x3 block of
+    /// cells around robot `i`, nearest-first and capped to `max_neighbors`.
+    fn neighbors_of(&self, i: usize, positions: &[Position], max_neighbors: usize) -> Vec<usize> {
+        let (cx, cy, cz) = Self::cell_of(&positions[i], self.cell_size);
+        let mut candidates = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(bucket) = self.buckets.get(&(cx + dx, cy + dy, cz + dz)) else {
+                        continue;
+                    };
+                    candidates.extend(bucket.iter().copied().filter(|&j| j != i));
+                }
+            }
+        }
+
+        candidates.sort_by(|&a, &b| {
+            let da = (positions[a] - positions[i]).norm_squared();
+            let db = (positions[b] - positions[i]).norm_squared();
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        candidates.truncate(max_neighbors);
+        candidates
+    }
+}
+
+/// A named geometric shape expressed as slot offsets (meters) from a
+/// formation's own origin, in the formation's unrotated frame -- not yet
+/// assigned to any robot.
+#[derive(Debug, Clone)]
+pub struct FormationSpec {
+    slots: Vec<Vec3>,
+}
+
+impl FormationSpec {
+    /// A single-file line of `count` slots, `spacing` meters apart, running
+    /// along +X from the origin.
+    pub fn line(count: usize, spacing: f64) -> Self {
+        let slots = (0..count)
+            .map(|i| Vec3::new(i as f64 * spacing, 0.0, 0.0))
+            .collect();
+        Self { slots }
+    }
+
+    /// `count` slots evenly spaced around a circle of `radius` meters
+    /// centered on the origin, in the origin's XY plane.
+    pub fn circle(count: usize, radius: f64) -> Self {
+        let slots = (0..count.max(1))
+            .map(|i| {
+                let angle = i as f64 / count.max(1) as f64 * std::f64::consts::TAU;
+                Vec3::new(radius * angle.cos(), radius * angle.sin(), 0.0)
+            })
+            .take(count)
+            .collect();
+        Self { slots }
+    }
+
+    /// A wedge trailing the origin: slot 0 is the lead, and each
+    /// subsequent pair of slots falls in one more row behind it,
+    /// alternating left and right, `spacing` meters apart.
+    pub fn wedge(count: usize, spacing: f64) -> Self {
+        let mut slots = Vec::with_capacity(count);
+        if count > 0 {
+            slots.push(Vec3::zeros());
+        }
+        let mut row = 1usize;
+        while slots.len() < count {
+            for side in [-1.0, 1.0] {
+                if slots.len() >= count {
+                    break;
+                }
+                slots.push(Vec3::new(
+                    -(row as f64) * spacing,
+                    side * row as f64 * spacing,
+                    0.0,
+                ));
+            }
+            row += 1;
+        }
+        Self { slots }
+    }
+
+    /// A Y-shape: a single-file stem of `stem_count` slots trailing the
+    /// origin along -X, branching at the origin into two arms of
+    /// `arm_count` slots each, `spacing` meters apart.
+    pub fn y_shape(stem_count: usize, arm_count: usize, spacing: f64) -> Self {
+        let mut slots = Vec::with_capacity(stem_count + 2 * arm_count);
+        slots.push(Vec3::zeros());
+        for i in 1..stem_count {
+            slots.push(Vec3::new(-(i as f64) * spacing, 0.0, 0.0));
+        }
+        for side in [-1.0, 1.0] {
+            for i in 1..=arm_count {
+                slots.push(Vec3::new(
+                    i as f64 * spacing,
+                    side * i as f64 * spacing,
+                    0.0,
+                ));
+            }
+        }
+        Self { slots }
+    }
+
+    /// A `rows` x `cols` grid in the origin's XY plane, `spacing` meters
+    /// apart, centered on the origin.
+    pub fn grid(rows: usize, cols: usize, spacing: f64) -> Self {
+        let x_offset = (cols.saturating_sub(1)) as f64 * spacing / 2.0;
+        let y_offset = (rows.saturating_sub(1)) as f64 * spacing / 2.0;
+        let slots = (0..rows)
+            .flat_map(|row| {
+                (0..cols).map(move |col| {
+                    Vec3::new(
+                        col as f64 * spacing - x_offset,
+                        row as f64 * spacing - y_offset,
+                        0.0,
+                    )
+                })
+            })
+            .collect();
+        Self { slots }
+    }
+
+    /// An arbitrary formation loaded from caller-supplied relative slot
+    /// offsets, for shapes the named constructors don't cover.
+    pub fn custom(slots: Vec<Vec3>) -> Self {
+        Self { slots }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+}
+
+/// Live, decentralized slot assignment for a [`FormationSpec`] anchored at
+/// a world-space origin and orientation.
+///
+/// No central planner decides who goes where: [`Formation::seed_root`]
+/// bootstraps a single robot into slot 0, and every step after that,
+/// [`Formation::step`] lets any unplaced robot that's currently in range of
+/// an already-placed one request a slot -- the nearest slot to its own
+/// position, granted as asked if it's still open, or the next free slot by
+/// index if another request claimed it first. Labels fill outward from the
+/// seed with no robot needing a global view of who holds what.
+pub struct Formation {
+    spec: FormationSpec,
+    origin: Position,
+    orientation: Rotation,
+    /// Slot index claimed by each robot, indexed by robot id; `None` until
+    /// that robot claims one.
+    claims: Vec<Option<usize>>,
+    claimed_slots: Vec<bool>,
+}
+
+impl Formation {
+    /// Create an unassigned formation for `num_robots` participants (most
+    /// of whom will typically not belong to this formation at all -- only
+    /// the indices actually passed to `step`/`seed_root` ever get a slot).
+    pub fn new(
+        spec: FormationSpec,
+        origin: Position,
+        orientation: Rotation,
+        num_robots: usize,
+    ) -> Self {
+        let num_slots = spec.len();
+        Self {
+            spec,
+            origin,
+            orientation,
+            claims: vec![None; num_robots],
+            claimed_slots: vec![false; num_slots],
+        }
+    }
+
+    /// World-space position of `slot`.
+    pub fn slot_position(&self, slot: usize) -> Position {
+        self.origin + self.orientation * self.spec.slots[slot]
+    }
+
+    /// Slot claimed by `robot`, if any.
+    pub fn slot_of(&self, robot: usize) -> Option<usize> {
+        self.claims[robot]
+    }
+
+    pub fn is_placed(&self, robot: usize) -> bool {
+        self.claims[robot].is_some()
+    }
+
+    /// Bootstrap the formation by placing `robot` directly into slot 0,
+    /// with no request/grant round trip -- every later `step` call fills
+    /// the rest outward from here.
+    pub fn seed_root(&mut self, robot: usize) {
+        if !self.spec.is_empty() {
+            self.assign(robot, 0);
+        }
+    }
+
+    fn assign(&mut self, robot: usize, slot: usize) {
+        self.claims[robot] = Some(slot);
+        self.claimed_slots[slot] = true;
+    }
+
+    fn nearest_slot(&self, from: Position) -> Option<usize> {
+        (0..self.spec.len()).min_by(|&a, &b| {
+            let da = (self.slot_position(a) - from).norm_squared();
+            let db = (self.slot_position(b) - from).norm_squared();
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        })
+    }
+
+    fn next_free_slot(&self) -> Option<usize> {
+        (0..self.claimed_slots.len()).find(|&slot| !self.claimed_slots[slot])
+    }
+
+    /// Run one step of the label-claiming protocol over `requests`: each
+    /// `(requester, placed_neighbor)` pair the caller supplies (already
+    /// gated by whatever counts as "in range" for this formation --
+    /// sensor radius, RF link, etc.). A request is only acted on if
+    /// `requester` is still unplaced and `placed_neighbor` already holds a
+    /// slot; the requester's nearest slot is granted if it's still open by
+    /// the time this call processes it, or the next free slot by index
+    /// otherwise (another request in this same batch may have just taken
+    /// it).
+    pub fn step(
+        &mut self,
+        requests: impl IntoIterator<Item = (usize, usize)>,
+        positions: &[Position],
+    ) {
+        for (requester, placed_neighbor) in requests {
+            if self.claims[requester].is_some() || self.claims[placed_neighbor].is_none() {
+                continue;
+            }
+            let Some(requested_slot) = self.nearest_slot(positions[requester]) else {
+                continue;
+            };
+            let grant = if !self.claimed_slots[requested_slot] {
+                Some(requested_slot)
+            } else {
+                self.next_free_slot()
+            };
+            if let Some(slot) = grant {
+                self.assign(requester, slot);
+            }
+        }
+    }
+
+    /// Fraction of this formation's robots (not slots) that currently hold
+    /// a slot, in `[0, 1]`. `1.0` for a formation with no participants.
+    pub fn fraction_filled(&self) -> f64 {
+        if self.claims.is_empty() {
+            return 1.0;
+        }
+        let filled = self.claims.iter().filter(|slot| slot.is_some()).count();
+        filled as f64 / self.claims.len() as f64
+    }
+
+    /// Mean distance between each placed robot's current position (from
+    /// `positions`, indexed by robot id) and its assigned slot's world
+    /// position -- how far the formation still has to reconverge. `0.0` if
+    /// no robot has been placed yet.
+    pub fn mean_slot_error(&self, positions: &[Position]) -> f64 {
+        let mut sum = 0.0;
+        let mut count = 0usize;
+        for (robot, slot) in self.claims.iter().enumerate() {
+            if let Some(slot) = slot {
+                sum += (positions[robot] - self.slot_position(*slot)).norm();
+                count += 1;
+            }
+        }
+        if count > 0 {
+            sum / count as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Position;
+
+    #[test]
+    fn two_robots_too_close_repel() {
+        let flock = Flock::new(FlockConfig {
+            target: 5.0,
+            waypoint_gain: 0.0,
+            ..FlockConfig::default()
+        });
+        let mut positions = vec![Position::new(0.0, 0.0, 0.0), Position::new(1.0, 0.0, 0.0)];
+        let targets = positions.clone();
+
+        flock.step(&mut positions, &targets, 0.1);
+
+        let spacing = (positions[1] - positions[0]).norm();
+        assert!(spacing > 1.0, "robots should repel when closer than target");
+    }
+
+    #[test]
+    fn two_robots_too_far_attract() {
+        let flock = Flock::new(FlockConfig {
+            target: 5.0,
+            sensing_radius: 50.0,
+            waypoint_gain: 0.0,
+            ..FlockConfig::default()
+        });
+        let mut positions = vec![Position::new(0.0, 0.0, 0.0), Position::new(20.0, 0.0, 0.0)];
+        let targets = positions.clone();
+
+        flock.step(&mut positions, &targets, 0.1);
+
+        let spacing = (positions[1] - positions[0]).norm();
+        assert!(
+            spacing < 20.0,
+            "robots should attract when farther than target"
+        );
+    }
+
+    #[test]
+    fn isolated_robot_does_not_count_toward_mean_spacing() {
+        let flock = Flock::new(FlockConfig {
+            sensing_radius: 1.0, // Too small to sense the other robot.
+            ..FlockConfig::default()
+        });
+        let mut positions = vec![Position::new(0.0, 0.0, 0.0), Position::new(100.0, 0.0, 0.0)];
+        let targets = positions.clone();
+
+        let mean_spacing = flock.step(&mut positions, &targets, 0.1);
+        assert_eq!(mean_spacing, 0.0);
+    }
+
+    #[test]
+    fn velocity_is_clamped_to_max_vel() {
+        let flock = Flock::new(FlockConfig {
+            target: 5.0,
+            epsilon: 1000.0, // huge interaction strength
+            max_vel: 2.0,
+            waypoint_gain: 0.0,
+            ..FlockConfig::default()
+        });
+        let mut positions = vec![Position::new(0.0, 0.0, 0.0), Position::new(0.5, 0.0, 0.0)];
+        let targets = positions.clone();
+
+        flock.step(&mut positions, &targets, 1.0);
+
+        let displacement = (positions[0] - Position::new(0.0, 0.0, 0.0)).norm();
+        assert!(displacement <= 2.0 + 1e-9);
+    }
+
+    #[test]
+    fn flocking_controller_repels_close_neighbor() {
+        let controller = FlockingController::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            20.0,
+            10.0,
+        );
+
+        let (vx, _vy) = controller.velocity_command("scout", (0.0, 0.0), &[(1.0, 0.0)]);
+        assert!(
+            vx < 0.0,
+            "too-close neighbor to the +X side should push back toward -X"
+        );
+    }
+
+    #[test]
+    fn flocking_controller_attracts_far_neighbor() {
+        let controller = FlockingController::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            50.0,
+            10.0,
+        );
+
+        let (vx, _vy) = controller.velocity_command("transport", (0.0, 0.0), &[(20.0, 0.0)]);
+        assert!(
+            vx > 0.0,
+            "far neighbor to the +X side should pull toward +X"
+        );
+    }
+
+    #[test]
+    fn flocking_controller_ignores_neighbors_past_cutoff() {
+        let controller = FlockingController::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            10.0,
+            10.0,
+        );
+
+        let command = controller.velocity_command("scout", (0.0, 0.0), &[(100.0, 0.0)]);
+        assert_eq!(command, (0.0, 0.0));
+    }
+
+    #[test]
+    fn flocking_controller_skips_coincident_neighbor() {
+        let controller = FlockingController::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            20.0,
+            10.0,
+        );
+
+        let command = controller.velocity_command("scout", (0.0, 0.0), &[(0.0, 0.0)]);
+        assert_eq!(command, (0.0, 0.0));
+    }
+
+    #[test]
+    fn flocking_controller_per_role_spacing_changes_result() {
+        let mut controller = FlockingController::new(
+            RoleSpacing {
+                target: 10.0,
+                epsilon: 1.0,
+            },
+            20.0,
+            100.0,
+        );
+        controller.set_role_spacing(
+            "scout",
+            RoleSpacing {
+                target: 2.0,
+                epsilon: 1.0,
+            },
+        );
+
+        // A neighbor at distance 5 is inside the default 10m target (so the
+        // default role attracts) but outside the tighter 2m scout target (so
+        // scouts repel instead) -- the two roles must disagree in sign.
+        let default_command = controller.velocity_command("transport", (0.0, 0.0), &[(5.0, 0.0)]);
+        let scout_command = controller.velocity_command("scout", (0.0, 0.0), &[(5.0, 0.0)]);
+
+        assert!(default_command.0 > 0.0);
+        assert!(scout_command.0 < 0.0);
+    }
+
+    #[test]
+    fn seed_root_places_robot_zero_in_slot_zero() {
+        let mut formation = Formation::new(
+            FormationSpec::line(3, 5.0),
+            Position::origin(),
+            Rotation::identity(),
+            3,
+        );
+        formation.seed_root(0);
+
+        assert_eq!(formation.slot_of(0), Some(0));
+        assert!((formation.fraction_filled() - (1.0 / 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn in_range_robot_claims_nearest_open_slot() {
+        let mut formation = Formation::new(
+            FormationSpec::line(3, 5.0),
+            Position::origin(),
+            Rotation::identity(),
+            3,
+        );
+        formation.seed_root(0);
+        let positions = vec![
+            Position::origin(),
+            Position::new(4.0, 0.0, 0.0), // closest to slot 1 (at x=5)
+            Position::new(9.0, 0.0, 0.0), // closest to slot 2 (at x=10)
+        ];
+
+        formation.step([(1, 0)], &positions);
+
+        assert_eq!(formation.slot_of(1), Some(1));
+        assert_eq!(formation.slot_of(2), None); // never requested, stays unplaced
+    }
+
+    #[test]
+    fn stale_request_falls_back_to_next_free_slot() {
+        let mut formation = Formation::new(
+            FormationSpec::line(2, 5.0),
+            Position::origin(),
+            Rotation::identity(),
+            3,
+        );
+        formation.seed_root(0);
+        let positions = vec![Position::origin(); 3];
+
+        // Both 1 and 2 request robot 0's slot in the same batch; both
+        // happen to be nearest to the now-claimed slot 0 at this position,
+        // so the second must fall back to the only slot left.
+        formation.step([(1, 0)], &positions);
+        formation.step([(2, 0)], &positions);
+
+        assert_eq!(formation.slot_of(1), Some(1));
+        assert_eq!(formation.slot_of(2), None); // no open slots remain
+    }
+
+    #[test]
+    fn mean_slot_error_reflects_distance_to_assigned_slots() {
+        let mut formation = Formation::new(
+            FormationSpec::line(2, 10.0),
+            Position::origin(),
+            Rotation::identity(),
+            2,
+        );
+        formation.seed_root(0);
+        formation.step(
+            [(1, 0)],
+            &[Position::origin(), Position::new(3.0, 0.0, 0.0)],
+        );
+
+        // Robot 0 sits exactly on slot 0 (error 0); robot 1 is 3m from
+        // its now-claimed slot 1 at x=10, i.e. 7m short.
+        let positions = [Position::origin(), Position::new(3.0, 0.0, 0.0)];
+        assert!((formation.mean_slot_error(&positions) - 3.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn y_shape_has_one_stem_slot_plus_two_arms() {
+        let spec = FormationSpec::y_shape(2, 3, 5.0);
+        assert_eq!(spec.len(), 2 + 2 * 3);
+    }
+
+    #[test]
+    fn grid_has_rows_times_cols_slots_centered_on_origin() {
+        let formation = Formation::new(
+            FormationSpec::grid(2, 3, 10.0),
+            Position::origin(),
+            Rotation::identity(),
+            1,
+        );
+        assert_eq!(formation.slot_position(0).x, -10.0);
+        // 6 slots, symmetric about the origin.
+        let mean_x: f64 = (0..6).map(|i| formation.slot_position(i).x).sum::<f64>() / 6.0;
+        assert!(mean_x.abs() < 1e-9);
+    }
+}
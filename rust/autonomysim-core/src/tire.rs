@@ -0,0 +1,153 @@
+//! Pacejka "magic formula" tire-friction model for ground vehicles
+//!
+//! Computes longitudinal and lateral tire forces from slip so `Car` agents
+//! get realistic cornering, traction loss, and braking instead of whatever
+//! flat friction coefficient the physics backend's contact solver applies
+//! on its own.
+
+use serde::{Deserialize, Serialize};
+
+/// Per-surface Pacejka coefficients, shared by the longitudinal and lateral
+/// force curves (B = stiffness, C = shape, D = peak, E = curvature).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PacejkaCoefficients {
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+}
+
+impl PacejkaCoefficients {
+    /// Representative dry asphalt coefficients.
+    pub fn asphalt() -> Self {
+        Self {
+            b: 10.0,
+            c: 1.9,
+            d: 1.0,
+            e: 0.97,
+        }
+    }
+
+    /// Representative loose/gravel terrain coefficients: lower stiffness
+    /// and peak grip, more rounded saturation.
+    pub fn loose_terrain() -> Self {
+        Self {
+            b: 6.0,
+            c: 1.6,
+            d: 0.6,
+            e: 0.7,
+        }
+    }
+
+    /// Evaluate the magic formula for slip quantity `s` (slip ratio for
+    /// longitudinal force, slip angle in radians for lateral force),
+    /// returning a force normalized to the wheel's peak grip.
+    fn evaluate(&self, s: f64) -> f64 {
+        let bs = self.b * s;
+        self.d * (self.c * (bs - self.e * (bs - bs.atan())).atan()).sin()
+    }
+}
+
+/// Per-wheel tire parameters. Exposed through `VehicleParameters` so users
+/// can model asphalt vs. loose terrain per vehicle.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TireParameters {
+    pub longitudinal: PacejkaCoefficients,
+    pub lateral: PacejkaCoefficients,
+    /// Combined-slip friction coefficient bounding `|F| <= mu * normal_load`.
+    pub friction_coefficient: f64,
+}
+
+impl Default for TireParameters {
+    fn default() -> Self {
+        Self {
+            longitudinal: PacejkaCoefficients::asphalt(),
+            lateral: PacejkaCoefficients::asphalt(),
+            friction_coefficient: 1.0,
+        }
+    }
+}
+
+/// Longitudinal and lateral tire force at a contact patch, in the same
+/// force units as `normal_load` (Newtons if SI).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TireForce {
+    pub longitudinal: f64,
+    pub lateral: f64,
+}
+
+/// Compute combined-slip tire force from slip ratio and slip angle, scaled
+/// by the wheel's normal load and clamped to the friction circle
+/// `sqrt(Fx^2 + Fy^2) <= mu * normal_load` so total grip never exceeds the
+/// physical limit regardless of how the individual curves saturate.
+pub fn compute_tire_force(
+    params: &TireParameters,
+    slip_ratio: f64,
+    slip_angle_rad: f64,
+    normal_load: f64,
+) -> TireForce {
+    let fx = params.longitudinal.evaluate(slip_ratio) * normal_load;
+    let fy = params.lateral.evaluate(slip_angle_rad) * normal_load;
+
+    let limit = params.friction_coefficient * normal_load;
+    let magnitude = (fx * fx + fy * fy).sqrt();
+
+    if magnitude > limit && magnitude > 0.0 {
+        let scale = limit / magnitude;
+        TireForce {
+            longitudinal: fx * scale,
+            lateral: fy * scale,
+        }
+    } else {
+        TireForce {
+            longitudinal: fx,
+            lateral: fy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_slip_yields_zero_force() {
+        let params = TireParameters::default();
+        let force = compute_tire_force(&params, 0.0, 0.0, 4000.0);
+        assert!(force.longitudinal.abs() < 1e-9);
+        assert!(force.lateral.abs() < 1e-9);
+    }
+
+    #[test]
+    fn force_saturates_with_increasing_slip() {
+        let params = TireParameters::default();
+        let low_slip = compute_tire_force(&params, 0.05, 0.0, 4000.0);
+        let high_slip = compute_tire_force(&params, 0.5, 0.0, 4000.0);
+        // Pacejka peaks then falls off past the optimal slip ratio; high
+        // slip should not exceed the friction-circle limit either way.
+        assert!(high_slip.longitudinal.abs() <= params.friction_coefficient * 4000.0 + 1e-6);
+        assert!(low_slip.longitudinal.abs() > 0.0);
+    }
+
+    #[test]
+    fn combined_slip_respects_friction_circle() {
+        let params = TireParameters::default();
+        let force = compute_tire_force(&params, 0.3, 0.3, 4000.0);
+        let magnitude = (force.longitudinal.powi(2) + force.lateral.powi(2)).sqrt();
+        assert!(magnitude <= params.friction_coefficient * 4000.0 + 1e-6);
+    }
+
+    #[test]
+    fn loose_terrain_has_lower_peak_grip_than_asphalt() {
+        let asphalt = TireParameters::default();
+        let loose = TireParameters {
+            longitudinal: PacejkaCoefficients::loose_terrain(),
+            lateral: PacejkaCoefficients::loose_terrain(),
+            friction_coefficient: 0.6,
+        };
+
+        let on_asphalt = compute_tire_force(&asphalt, 0.15, 0.0, 4000.0);
+        let on_loose = compute_tire_force(&loose, 0.15, 0.0, 4000.0);
+        assert!(on_loose.longitudinal.abs() < on_asphalt.longitudinal.abs());
+    }
+}
@@ -0,0 +1,226 @@
+//! Gymnasium-style vectorized RL environment wrapper over any
+//! `SimulationBackend`
+//!
+//! `isaac_multi_env` (and the Isaac Lab backend it drives) already spawns
+//! several vehicles into one backend instance and steps them in lockstep by
+//! hand. [`VecEnv`] is that pattern generalized into a standard agent-facing
+//! API: `N` vehicles in one backend, one `reset`/`step` call advances all of
+//! them together. Unlike [`crate::rl_env::FpvEnv`], which wraps a single
+//! `FpvPhysics` instance directly, `VecEnv` is backend-agnostic -- it only
+//! calls through the `SimulationBackend` trait, so the same training loop
+//! works unmodified against the UE5 and Isaac Lab backends.
+
+use crate::backend::{SceneHandle, SimResult, SimulationBackend};
+use crate::sensor::SensorData;
+use crate::vehicle::{VehicleControl, VehicleSpec, VehicleState};
+use serde::{Deserialize, Serialize};
+
+/// Declares the shape of one environment's observation or action space,
+/// analogous to Gymnasium's `gym.spaces`. Purely descriptive metadata for a
+/// training loop to size its network heads from -- `VecEnv` doesn't enforce
+/// it against the values it actually produces/accepts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SpaceDescriptor {
+    /// Continuous vector, `low[i]..=high[i]` per dimension.
+    Box { low: Vec<f32>, high: Vec<f32> },
+    /// One of `n` discrete choices.
+    Discrete { n: usize },
+}
+
+/// One environment's observation: its vehicle's physical state plus a
+/// reading from every sensor its [`VehicleSpec`] declared, keyed by
+/// `sensor_id`.
+#[derive(Debug, Clone)]
+pub struct VecObservation {
+    pub vehicle_state: VehicleState,
+    pub sensors: Vec<(String, SensorData)>,
+}
+
+/// Free-form per-step diagnostics, the same role Gymnasium's `info` dict
+/// serves: not part of the learning signal, just visibility for logging.
+pub type VecInfo = serde_json::Map<String, serde_json::Value>;
+
+/// Scores one environment's transition and decides whether its episode has
+/// ended. Implement this per task (e.g. a `VecEnv` analogue of
+/// [`crate::rl_env::FpvRaceEnv`]'s gate-passing reward); [`VecEnv::step`]
+/// calls it once per environment per step and handles the resulting
+/// auto-reset itself.
+pub trait VecReward: Send {
+    /// `(reward, terminated, truncated, info)` for one environment, given
+    /// its latest observation and the action that produced it.
+    fn evaluate(
+        &mut self,
+        env_index: usize,
+        observation: &VecObservation,
+        action: &VehicleControl,
+    ) -> (f32, bool, bool, VecInfo);
+
+    /// Called whenever an environment (auto-)resets, so stateful reward
+    /// functions (gate indices, progress trackers) can clear their
+    /// per-episode state. No-op by default.
+    fn reset(&mut self, env_index: usize) {
+        let _ = env_index;
+    }
+}
+
+/// One vehicle slot tracked by a [`VecEnv`], keyed by environment index.
+struct EnvSlot {
+    vehicle_id: String,
+    spawn_spec: VehicleSpec,
+}
+
+/// Gymnasium-style vectorized environment wrapping any `SimulationBackend`:
+/// `N` vehicles spawned into the same backend instance, stepped together
+/// with a single `backend.step(dt)` per call. Auto-resets individual
+/// environments on termination/truncation so one finished environment
+/// doesn't stall the rest of the batch.
+pub struct VecEnv<B: SimulationBackend> {
+    backend: B,
+    #[allow(dead_code)]
+    scene: SceneHandle,
+    slots: Vec<EnvSlot>,
+    reward_fn: Box<dyn VecReward>,
+    dt: f64,
+    observation_space: SpaceDescriptor,
+    action_space: SpaceDescriptor,
+}
+
+impl<B: SimulationBackend> VecEnv<B> {
+    pub fn new(
+        backend: B,
+        scene: SceneHandle,
+        specs: Vec<VehicleSpec>,
+        reward_fn: Box<dyn VecReward>,
+        dt: f64,
+        observation_space: SpaceDescriptor,
+        action_space: SpaceDescriptor,
+    ) -> Self {
+        let slots = specs
+            .into_iter()
+            .map(|spec| EnvSlot {
+                vehicle_id: spec.vehicle_id.clone(),
+                spawn_spec: spec,
+            })
+            .collect();
+        Self {
+            backend,
+            scene,
+            slots,
+            reward_fn,
+            dt,
+            observation_space,
+            action_space,
+        }
+    }
+
+    pub fn num_envs(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn observation_space(&self) -> &SpaceDescriptor {
+        &self.observation_space
+    }
+
+    pub fn action_space(&self) -> &SpaceDescriptor {
+        &self.action_space
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// (Re-)spawn every environment's vehicle at its configured
+    /// [`VehicleSpec`] and return the resulting stacked observation.
+    pub async fn reset(&mut self) -> SimResult<Vec<VecObservation>> {
+        for index in 0..self.slots.len() {
+            self.reset_one(index).await?;
+        }
+        self.observe_all()
+    }
+
+    /// Advance every environment by one `dt`: batches each environment's
+    /// `set_vehicle_control`, advances the whole backend with a single
+    /// `step`, then gathers observations and per-environment
+    /// reward/termination via `reward_fn`. Any environment that terminates
+    /// or truncates this step is auto-reset before its observation is
+    /// returned, so the batch stays full-sized every call.
+    pub async fn step(
+        &mut self,
+        actions: &[VehicleControl],
+    ) -> SimResult<(Vec<VecObservation>, Vec<f32>, Vec<bool>, Vec<bool>, Vec<VecInfo>)> {
+        assert_eq!(
+            actions.len(),
+            self.slots.len(),
+            "one action per environment"
+        );
+
+        for (slot, action) in self.slots.iter().zip(actions) {
+            self.backend
+                .set_vehicle_control(&slot.vehicle_id, action.clone())?;
+        }
+        self.backend.step(self.dt).await?;
+
+        let mut observations = Vec::with_capacity(self.slots.len());
+        let mut rewards = Vec::with_capacity(self.slots.len());
+        let mut terminated = Vec::with_capacity(self.slots.len());
+        let mut truncated = Vec::with_capacity(self.slots.len());
+        let mut infos = Vec::with_capacity(self.slots.len());
+
+        for index in 0..self.slots.len() {
+            let observation = self.observe_one(index)?;
+            let (reward, term, trunc, info) =
+                self.reward_fn
+                    .evaluate(index, &observation, &actions[index]);
+
+            let observation = if term || trunc {
+                self.reset_one(index).await?;
+                self.observe_one(index)?
+            } else {
+                observation
+            };
+
+            observations.push(observation);
+            rewards.push(reward);
+            terminated.push(term);
+            truncated.push(trunc);
+            infos.push(info);
+        }
+
+        Ok((observations, rewards, terminated, truncated, infos))
+    }
+
+    async fn reset_one(&mut self, index: usize) -> SimResult<()> {
+        let spec = self.slots[index].spawn_spec.clone();
+        // Best-effort: a fresh environment that was never spawned (first
+        // `reset`) has nothing to remove yet.
+        let _ = self.backend.remove_vehicle(&self.slots[index].vehicle_id).await;
+        let vehicle_id = self.backend.spawn_vehicle(spec).await?;
+        self.slots[index].vehicle_id = vehicle_id;
+        self.reward_fn.reset(index);
+        Ok(())
+    }
+
+    fn observe_all(&self) -> SimResult<Vec<VecObservation>> {
+        (0..self.slots.len()).map(|index| self.observe_one(index)).collect()
+    }
+
+    fn observe_one(&self, index: usize) -> SimResult<VecObservation> {
+        let slot = &self.slots[index];
+        let vehicle_state = self.backend.get_vehicle_state(&slot.vehicle_id)?;
+        let sensors = slot
+            .spawn_spec
+            .sensors
+            .iter()
+            .filter(|sensor| sensor.enabled)
+            .map(|sensor| {
+                self.backend
+                    .get_sensor_data(&slot.vehicle_id, &sensor.sensor_id)
+                    .map(|data| (sensor.sensor_id.clone(), data))
+            })
+            .collect::<SimResult<Vec<_>>>()?;
+        Ok(VecObservation {
+            vehicle_state,
+            sensors,
+        })
+    }
+}
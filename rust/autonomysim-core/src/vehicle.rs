@@ -1,6 +1,9 @@
 //! Vehicle types and control interface
 
 use crate::backend::{Position, Rotation, Transform, Vec3};
+use crate::raycast_vehicle::RaycastVehicleConfig;
+use crate::reaction_wheel::ReactionWheelConfig;
+use crate::tire::TireParameters;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -18,6 +21,9 @@ pub enum VehicleType {
     Car,
     /// Hybrid VTOL
     VTOL,
+    /// Spacecraft or other reaction-wheel-actuated platform with no
+    /// aerodynamic or ground contact surfaces.
+    Spacecraft,
     /// Custom vehicle type
     Custom,
 }
@@ -92,7 +98,7 @@ impl VehicleControl {
             ..Default::default()
         }
     }
-    
+
     /// Create control for forward flight
     pub fn forward(speed: f64) -> Self {
         Self {
@@ -122,6 +128,22 @@ pub struct VehicleParameters {
     pub max_thrust: f64,
     pub max_torque: Vec3,
     pub sensor_offsets: HashMap<String, Transform>,
+    /// Pacejka tire-friction model used by `VehicleType::Car` agents for
+    /// ground contact. Ignored by aerial vehicle types.
+    pub tire_parameters: TireParameters,
+    /// Opt-in raycast-vehicle suspension and dynamics model for
+    /// `VehicleType::Car` agents. When `None`, the backend keeps the
+    /// non-physical fallback of not integrating vehicle state at all.
+    pub raycast_vehicle: Option<RaycastVehicleConfig>,
+    /// Opt-in reaction-wheel actuator configuration for
+    /// `VehicleType::Spacecraft` agents. When `None`, the backend falls
+    /// back to [`ReactionWheelConfig::four_wheel_pyramid`].
+    pub reaction_wheel: Option<ReactionWheelConfig>,
+    /// Opt-in local-tangent-plane ↔ geodetic reprojection this vehicle's
+    /// GPS sensor reports through. When `None`, `get_sensor_data` falls
+    /// back to its historical flat `/111320.0` degrees-per-meter
+    /// approximation instead.
+    pub home: Option<crate::sensor::GeoProjection>,
 }
 
 impl Default for VehicleParameters {
@@ -133,6 +155,10 @@ impl Default for VehicleParameters {
             max_thrust: 100.0,
             max_torque: Vec3::new(10.0, 10.0, 10.0),
             sensor_offsets: HashMap::new(),
+            tire_parameters: TireParameters::default(),
+            raycast_vehicle: None,
+            reaction_wheel: None,
+            home: None,
         }
     }
 }
@@ -144,6 +170,132 @@ pub struct SensorSpec {
     pub sensor_type: SensorType,
     pub update_rate_hz: f64,
     pub enabled: bool,
+    /// Scan geometry, required when `sensor_type == SensorType::Lidar` and
+    /// ignored otherwise. The sensor's mounting transform is looked up
+    /// separately, from `VehicleParameters::sensor_offsets` by `sensor_id`.
+    pub lidar_config: Option<LidarConfig>,
+    /// Scan mode and detection model, required when `sensor_type ==
+    /// SensorType::Radar` and ignored otherwise.
+    pub radar_config: Option<RadarConfig>,
+    /// Error model applied to this sensor's readings by the backend's
+    /// `get_sensor_data`, so simulated sensors behave like real hardware
+    /// instead of reading the noiseless ground truth. `None` disables
+    /// corruption entirely.
+    pub noise: Option<SensorNoise>,
+    /// Fault injected into this sensor's readings, on top of `noise`, for
+    /// testing failure handling. Set at spawn time or toggled at runtime
+    /// via [`SimulationBackend::set_sensor_fault`]. `None` means the
+    /// sensor is healthy.
+    ///
+    /// [`SimulationBackend::set_sensor_fault`]: crate::backend::SimulationBackend::set_sensor_fault
+    pub fault: Option<SensorFault>,
+}
+
+/// Fault injected into a [`SensorSpec`]'s readings, applied after `noise`
+/// so a specific sensor can be made to fail in a controlled, reproducible
+/// way without disturbing the rest of the simulation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SensorFault {
+    /// Freeze the sensor at whatever its last good reading was, as if it
+    /// had stopped updating. The very first reading (no prior value cached
+    /// yet) passes through unmodified.
+    StuckAtLastValue,
+    /// Add a fixed offset to every reading. Interpreted per sensor type --
+    /// meters of horizontal position for GPS, m/s^2 / rad/s for IMU.
+    ConstantOffset { offset: Vec3 },
+    /// Multiply every reading by a fixed factor.
+    ScaleCorruption { factor: f64 },
+    /// Produce no data at all until simulation time passes `until_time_s`.
+    Dropout { until_time_s: f64 },
+    /// Randomly corrupt individual samples: each call to `get_sensor_data`
+    /// has `probability_per_step` chance of applying a `ConstantOffset`-like
+    /// glitch of the given `magnitude`, once simulation time has passed the
+    /// optional `trigger_time_s` (immediately, if `None`).
+    IntermittentGlitch {
+        probability_per_step: f64,
+        trigger_time_s: Option<f64>,
+        magnitude: f64,
+        seed: u64,
+    },
+}
+
+/// Per-sensor-type noise and bias model attached to a [`SensorSpec`] via its
+/// `noise` field, seeded so repeated runs reproduce the same noise sequence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SensorNoise {
+    /// IMU error model: per-axis Gaussian white noise, a slowly-drifting
+    /// random-walk bias, and a fixed multiplicative scale-factor error.
+    Imu {
+        /// Gyro white-noise standard deviation, rad/s.
+        gyro_noise_std: f64,
+        /// Accelerometer white-noise standard deviation, m/s^2.
+        accel_noise_std: f64,
+        /// Gyro bias random-walk standard deviation, rad/s per sqrt(s).
+        gyro_bias_walk_std: f64,
+        /// Accelerometer bias random-walk standard deviation, m/s^2 per sqrt(s).
+        accel_bias_walk_std: f64,
+        /// Multiplicative scale-factor error (e.g. `0.01` = 1%) applied to
+        /// both gyro and accelerometer readings.
+        scale_factor_error: f64,
+        /// Seed for this sensor's deterministic noise generator.
+        seed: u64,
+    },
+    /// GPS error model: a slowly-drifting correlated horizontal position
+    /// error plus a fixed reporting latency.
+    Gps {
+        /// Standard deviation of the correlated horizontal position-error
+        /// random walk, meters.
+        position_walk_std_m: f64,
+        /// Reporting latency, seconds -- returned fixes lag the true
+        /// vehicle state by this much.
+        fix_latency_s: f64,
+        /// Seed for this sensor's deterministic noise generator.
+        seed: u64,
+    },
+}
+
+impl SensorNoise {
+    /// Typical consumer-grade IMU error model (gyro noise ~0.1 deg/s,
+    /// accelerometer noise ~0.3 m/s^2, slow bias drift, no scale error).
+    pub fn imu_default(seed: u64) -> Self {
+        Self::Imu {
+            gyro_noise_std: 0.1_f64.to_radians(),
+            accel_noise_std: 0.3,
+            gyro_bias_walk_std: 0.001_f64.to_radians(),
+            accel_bias_walk_std: 0.001,
+            scale_factor_error: 0.0,
+            seed,
+        }
+    }
+
+    /// Typical consumer GPS error model: ~0.5m correlated drift per
+    /// `sqrt(s)` and 0.1s reporting latency.
+    pub fn gps_default(seed: u64) -> Self {
+        Self::Gps {
+            position_walk_std_m: 0.5,
+            fix_latency_s: 0.1,
+            seed,
+        }
+    }
+}
+
+/// Spinning-LiDAR scan geometry: `channels * horizontal_resolution` rays
+/// are generated parametrically in the sensor frame, one ring per
+/// `channels` elevation step and `horizontal_resolution` azimuth samples
+/// per full rotation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LidarConfig {
+    /// Number of vertical beams (rings), evenly spread between
+    /// `elevation_min_deg` and `elevation_max_deg`.
+    pub channels: u32,
+    /// Lowest beam's elevation angle, in degrees (negative is downward).
+    pub elevation_min_deg: f64,
+    /// Highest beam's elevation angle, in degrees.
+    pub elevation_max_deg: f64,
+    /// Azimuth samples per full 360-degree rotation.
+    pub horizontal_resolution: u32,
+    /// Maximum return range, in meters.
+    pub max_range: f64,
 }
 
 /// Sensor type enumeration
@@ -169,6 +321,259 @@ pub enum SensorType {
     DistanceSensor,
     /// RF antenna sensor
     RfAntenna,
+    /// Radar, detecting other vehicles by range, bearing, and closing rate
+    Radar,
+    /// ADS-B, building a local air picture from decoded Mode-S extended
+    /// squitter frames broadcast by nearby traffic
+    Adsb,
+}
+
+/// How a [`RadarConfig`] searches for targets and whether it reports a
+/// physically-derived return or exact ground-truth kinematics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RadarScanMode {
+    /// Search the full 360-degree azimuth around the vehicle, like a
+    /// rotating surveillance radar.
+    FullSweep,
+    /// Search a `beamwidth_deg`-wide cone centered on the vehicle's
+    /// forward heading, like a forward-looking automotive radar.
+    Directional { beamwidth_deg: f64 },
+    /// Skip the radar equation and detection threshold entirely and report
+    /// every vehicle within `RadarConfig::max_range_m` with its exact
+    /// relative kinematics, for collision-avoidance experiments that want
+    /// ground truth rather than a physically modeled return.
+    IdealGroundTruth,
+}
+
+/// Radar sensor configuration, analogous to [`LidarConfig`] for spinning
+/// LiDAR. For `FullSweep` and `Directional` scan modes, a target's return
+/// power is derived from the monostatic radar equation and compared
+/// against `min_detectable_snr_db` over the receiver's thermal noise
+/// floor; targets that don't clear it aren't reported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadarConfig {
+    pub scan_mode: RadarScanMode,
+    /// Carrier frequency, in Hz.
+    pub frequency_hz: f64,
+    /// Transmit power, in dBm.
+    pub tx_power_dbm: f64,
+    /// Peak antenna gain, in dBi, applied on both transmit and receive
+    /// (the radar uses one antenna for both).
+    pub antenna_gain_dbi: f64,
+    /// Assumed radar cross-section of every target, in square meters,
+    /// since the simulation doesn't model per-vehicle geometry.
+    pub target_rcs_m2: f64,
+    /// Receiver noise bandwidth, in Hz, for the thermal noise floor the
+    /// detection SNR is measured against.
+    pub bandwidth_hz: f64,
+    /// Minimum detectable SNR, in dB.
+    pub min_detectable_snr_db: f64,
+    /// Hard search-range cutoff, in meters, applied in every scan mode.
+    pub max_range_m: f64,
+}
+
+impl RadarConfig {
+    /// A forward-looking automotive-style radar: 76 GHz, 20 dBm transmit
+    /// power, 20 dBi antenna gain, assuming a 1 m^2 target (about right
+    /// for a small multirotor), out to 200 m with a 10 dB minimum SNR.
+    pub fn forward_looking_default() -> Self {
+        Self {
+            scan_mode: RadarScanMode::Directional {
+                beamwidth_deg: 20.0,
+            },
+            frequency_hz: 76.0e9,
+            tx_power_dbm: 20.0,
+            antenna_gain_dbi: 20.0,
+            target_rcs_m2: 1.0,
+            bandwidth_hz: 1.0e6,
+            min_detectable_snr_db: 10.0,
+            max_range_m: 200.0,
+        }
+    }
+}
+
+/// Vehicle subsystem that can be independently disabled by a
+/// [`VehicleDamageMode::FailureModes`] hit within its own radius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VehicleSubsystem {
+    /// Propulsion. A disabled motor zeroes `VehicleControl::throttle` via
+    /// [`VehicleDamageState::clamp_control`].
+    Motor,
+    /// Communications link radio. A disabled radio is reported through
+    /// [`VehicleDamageState::radio_packet_loss_penalty`] so the tactical
+    /// layer can fold it into that agent's link quality.
+    Radio,
+    /// GPS receiver.
+    Gps,
+    /// Onboard camera(s).
+    Camera,
+}
+
+/// Which damage representation a [`VehicleDamageState`] uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum VehicleDamageMode {
+    /// Hits accumulate in a damage pool scaled by warhead energy and
+    /// inverse distance from the impact point; the vehicle is destroyed
+    /// once the pool crosses `kill_threshold`.
+    HitPoints { kill_threshold: f64 },
+    /// A hit disables whichever subsystems it falls within the radius of,
+    /// independently of the others -- a single close hit can take out
+    /// every subsystem at once, a distant one might miss all of them.
+    FailureModes {
+        motor_radius_m: f64,
+        radio_radius_m: f64,
+        gps_radius_m: f64,
+        camera_radius_m: f64,
+    },
+}
+
+/// One impact recorded against a [`VehicleDamageState`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DamageEvent {
+    pub timestamp: f64,
+    /// Distance from the impact point to the vehicle, in meters (floored
+    /// at 1m to avoid a singularity at point-blank range).
+    pub distance_m: f64,
+    pub warhead_energy_j: f64,
+    /// Damage added to the accumulated-damage pool, in
+    /// `VehicleDamageMode::HitPoints` mode. Always `0.0` in
+    /// `FailureModes` mode.
+    pub hp_damage: f64,
+    /// Subsystem disabled by this hit, in `VehicleDamageMode::FailureModes`
+    /// mode. `None` in `HitPoints` mode or if the hit fell outside every
+    /// subsystem's radius.
+    pub subsystem_disabled: Option<VehicleSubsystem>,
+    /// Whether the vehicle's accumulated damage crossed `kill_threshold`
+    /// as of this hit.
+    pub destroyed: bool,
+}
+
+/// Per-vehicle damage/failure state: an accumulated-damage pool plus any
+/// subsystem failures sustained so far, and the event stream a scenario
+/// like the contested-communications example drains to correlate kinetic
+/// attrition with network partitions.
+#[derive(Debug, Clone)]
+pub struct VehicleDamageState {
+    mode: VehicleDamageMode,
+    accumulated_damage: f64,
+    failed_subsystems: Vec<VehicleSubsystem>,
+    destroyed: bool,
+    events: Vec<DamageEvent>,
+}
+
+impl VehicleDamageState {
+    /// Create a fresh, undamaged state under `mode`.
+    pub fn new(mode: VehicleDamageMode) -> Self {
+        Self {
+            mode,
+            accumulated_damage: 0.0,
+            failed_subsystems: Vec::new(),
+            destroyed: false,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn accumulated_damage(&self) -> f64 {
+        self.accumulated_damage
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed
+    }
+
+    pub fn has_failure(&self, subsystem: VehicleSubsystem) -> bool {
+        self.failed_subsystems.contains(&subsystem)
+    }
+
+    /// Every damage event recorded so far, oldest first.
+    pub fn events(&self) -> &[DamageEvent] {
+        &self.events
+    }
+
+    /// Apply a hit with `warhead_energy_j` landing at `impact_point`,
+    /// against a vehicle at `own_position`, recording and returning the
+    /// resulting [`DamageEvent`].
+    pub fn apply_hit(
+        &mut self,
+        timestamp: f64,
+        impact_point: Vec3,
+        own_position: Vec3,
+        warhead_energy_j: f64,
+    ) -> DamageEvent {
+        let distance_m = (impact_point - own_position).norm().max(1.0);
+
+        let (hp_damage, subsystem_disabled) = match self.mode {
+            VehicleDamageMode::HitPoints { kill_threshold } => {
+                let damage = warhead_energy_j / (distance_m * distance_m);
+                self.accumulated_damage += damage;
+                if self.accumulated_damage >= kill_threshold {
+                    self.destroyed = true;
+                }
+                (damage, None)
+            }
+            VehicleDamageMode::FailureModes {
+                motor_radius_m,
+                radio_radius_m,
+                gps_radius_m,
+                camera_radius_m,
+            } => {
+                let subsystem = if distance_m <= motor_radius_m {
+                    Some(VehicleSubsystem::Motor)
+                } else if distance_m <= radio_radius_m {
+                    Some(VehicleSubsystem::Radio)
+                } else if distance_m <= gps_radius_m {
+                    Some(VehicleSubsystem::Gps)
+                } else if distance_m <= camera_radius_m {
+                    Some(VehicleSubsystem::Camera)
+                } else {
+                    None
+                };
+                if let Some(subsystem) = subsystem {
+                    if !self.failed_subsystems.contains(&subsystem) {
+                        self.failed_subsystems.push(subsystem);
+                    }
+                }
+                (0.0, subsystem)
+            }
+        };
+
+        let event = DamageEvent {
+            timestamp,
+            distance_m,
+            warhead_energy_j,
+            hp_damage,
+            subsystem_disabled,
+            destroyed: self.destroyed,
+        };
+        self.events.push(event);
+        event
+    }
+
+    /// Additional packet-loss-rate fraction the radio subsystem's failure
+    /// contributes: `1.0` (complete loss) once disabled, `0.0` otherwise.
+    /// The tactical layer adds this onto an agent's existing link-quality
+    /// packet loss rate so kinetic attrition compounds with jamming rather
+    /// than only being visible through this crate's own state.
+    pub fn radio_packet_loss_penalty(&self) -> f64 {
+        if self.has_failure(VehicleSubsystem::Radio) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Clamp `control` to the vehicle's surviving authority: a disabled
+    /// motor zeroes throttle, since there's no propulsion left to meter.
+    pub fn clamp_control(&self, control: VehicleControl) -> VehicleControl {
+        if self.has_failure(VehicleSubsystem::Motor) {
+            VehicleControl {
+                throttle: 0.0,
+                ..control
+            }
+        } else {
+            control
+        }
+    }
 }
 
 #[cfg(test)]
@@ -199,17 +604,85 @@ mod tests {
                 UnitQuaternion::identity(),
             ),
             parameters: VehicleParameters::default(),
-            sensors: vec![
-                SensorSpec {
-                    sensor_id: "camera1".to_string(),
-                    sensor_type: SensorType::Camera,
-                    update_rate_hz: 30.0,
-                    enabled: true,
-                }
-            ],
+            sensors: vec![SensorSpec {
+                sensor_id: "camera1".to_string(),
+                sensor_type: SensorType::Camera,
+                update_rate_hz: 30.0,
+                enabled: true,
+                lidar_config: None,
+                radar_config: None,
+                noise: None,
+                fault: None,
+            }],
         };
-        
+
         assert_eq!(spec.vehicle_type, VehicleType::Multirotor);
         assert_eq!(spec.sensors.len(), 1);
     }
+
+    #[test]
+    fn test_hit_points_mode_accumulates_damage_and_destroys_at_threshold() {
+        let mut state = VehicleDamageState::new(VehicleDamageMode::HitPoints {
+            kill_threshold: 100.0,
+        });
+
+        let event = state.apply_hit(
+            1.0,
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            100.0,
+        );
+        assert_eq!(event.hp_damage, 100.0); // distance floored to 1m
+        assert!(!state.is_destroyed());
+
+        state.apply_hit(
+            2.0,
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            100.0,
+        );
+        assert!(state.is_destroyed());
+        assert_eq!(state.events().len(), 2);
+    }
+
+    #[test]
+    fn test_failure_mode_disables_only_subsystems_within_their_radius() {
+        let mut state = VehicleDamageState::new(VehicleDamageMode::FailureModes {
+            motor_radius_m: 2.0,
+            radio_radius_m: 5.0,
+            gps_radius_m: 5.0,
+            camera_radius_m: 5.0,
+        });
+
+        let event = state.apply_hit(
+            1.0,
+            Vec3::new(3.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            50.0,
+        );
+        assert_eq!(event.subsystem_disabled, Some(VehicleSubsystem::Radio));
+        assert!(!state.has_failure(VehicleSubsystem::Motor));
+        assert!(state.has_failure(VehicleSubsystem::Radio));
+        assert!(!state.is_destroyed());
+    }
+
+    #[test]
+    fn test_disabled_motor_clamps_throttle_and_degrades_radio_penalty() {
+        let mut state = VehicleDamageState::new(VehicleDamageMode::FailureModes {
+            motor_radius_m: 10.0,
+            radio_radius_m: 10.0,
+            gps_radius_m: 10.0,
+            camera_radius_m: 10.0,
+        });
+        state.apply_hit(
+            1.0,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            10.0,
+        );
+
+        let control = state.clamp_control(VehicleControl::forward(1.0));
+        assert_eq!(control.throttle, 0.0);
+        assert_eq!(state.radio_packet_loss_penalty(), 0.0);
+    }
 }
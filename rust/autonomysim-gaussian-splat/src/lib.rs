@@ -59,7 +59,7 @@
 //! println!("Signal strength at {:?}: {:.1} dBm", position, rssi);
 //! ```
 
-use nalgebra::{Matrix3, Vector3};
+use nalgebra::{DMatrix, DVector, Matrix3, Vector3};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -215,25 +215,275 @@ impl Gaussian3D {
 
     /// Evaluate Gaussian contribution (without normalization, for speed)
     pub fn evaluate_fast(&self, position: Vector3<f64>) -> f64 {
+        self.amplitude * self.basis_value(position)
+    }
+
+    /// Unscaled basis value at `position`: `exp(-0.5 * deltaᵀ Σ⁻¹ delta)`,
+    /// i.e. [`Self::evaluate_fast`] without the amplitude factor. Since the
+    /// predicted signal is linear in amplitude once centers/covariances are
+    /// fixed, this is what [`GaussianRFField::solve_amplitudes_linear`] uses
+    /// to build its design matrix.
+    pub fn basis_value(&self, position: Vector3<f64>) -> f64 {
         let delta = position - self.center;
 
         let inv_cov = self
             .inv_covariance
             .as_ref()
-            .expect("Call precompute() before evaluate_fast()");
+            .expect("Call precompute() before basis_value()");
 
         // Mahalanobis distance
         let exponent = -0.5 * (delta.transpose() * inv_cov * delta)[0];
+        exponent.exp()
+    }
+}
+
+/// Which optimizer `GaussianRFField::train` fits Gaussian parameters with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptimizerKind {
+    /// Plain gradient descent on amplitude only (the original behavior) --
+    /// simple, but slow to converge and sensitive to `learning_rate`.
+    GradientDescent,
+    /// Analytic-Jacobian Levenberg-Marquardt over amplitude, center, and
+    /// covariance (via its Cholesky factor) jointly. See
+    /// [`GaussianRFField::optimize_levenberg_marquardt`].
+    LevenbergMarquardt,
+}
+
+/// Per-parameter update rule [`GaussianRFField::optimize_gradient_descent`]
+/// steps each Gaussian's amplitude with; see
+/// [`TrainingConfig::gradient_optimizer`]. The dBm-scale loss surface
+/// oscillates under a single fixed learning rate, which these adapt to
+/// either via an exponential-average gradient ([`Self::Momentum`]) or a
+/// per-parameter effective step size ([`Self::RmsProp`], [`Self::Adam`]).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Optimizer {
+    /// Plain gradient descent: `theta -= lr * g` (the original behavior).
+    Sgd,
+    /// Exponential moving average of the gradient, `m <- beta*m +
+    /// (1-beta)*g`, stepped with `theta -= lr * m`.
+    Momentum {
+        /// Decay rate for the gradient moving average.
+        beta: f64,
+    },
+    /// Running mean-square of the gradient, `v <- alpha*v + (1-alpha)*g^2`,
+    /// stepped with `theta -= lr * g / (sqrt(v) + eps)`.
+    RmsProp {
+        /// Decay rate for the squared-gradient moving average.
+        alpha: f64,
+        /// Denominator floor preventing division by zero.
+        eps: f64,
+    },
+    /// Bias-corrected first and second moment estimates (Kingma & Ba 2014).
+    Adam {
+        /// Decay rate for the first-moment (mean) estimate.
+        beta1: f64,
+        /// Decay rate for the second-moment (uncentered variance) estimate.
+        beta2: f64,
+        /// Denominator floor preventing division by zero.
+        eps: f64,
+    },
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::Sgd
+    }
+}
+
+/// Accumulated per-amplitude moment state for [`Optimizer::Momentum`],
+/// [`Optimizer::RmsProp`], and [`Optimizer::Adam`]; idle under
+/// [`Optimizer::Sgd`].
+#[derive(Debug, Clone, Copy, Default)]
+struct OptimizerState {
+    m: f64,
+    v: f64,
+    step: i32,
+}
+
+impl OptimizerState {
+    /// Compute the amount to subtract from the parameter for `gradient`
+    /// under `optimizer`, advancing `self`'s moment accumulators in place.
+    fn step(&mut self, optimizer: Optimizer, learning_rate: f64, gradient: f64) -> f64 {
+        self.step += 1;
+        match optimizer {
+            Optimizer::Sgd => learning_rate * gradient,
+            Optimizer::Momentum { beta } => {
+                self.m = beta * self.m + (1.0 - beta) * gradient;
+                learning_rate * self.m
+            }
+            Optimizer::RmsProp { alpha, eps } => {
+                self.v = alpha * self.v + (1.0 - alpha) * gradient * gradient;
+                learning_rate * gradient / (self.v.sqrt() + eps)
+            }
+            Optimizer::Adam { beta1, beta2, eps } => {
+                self.m = beta1 * self.m + (1.0 - beta1) * gradient;
+                self.v = beta2 * self.v + (1.0 - beta2) * gradient * gradient;
+                let m_hat = self.m / (1.0 - beta1.powi(self.step));
+                let v_hat = self.v / (1.0 - beta2.powi(self.step));
+                learning_rate * m_hat / (v_hat.sqrt() + eps)
+            }
+        }
+    }
+}
+
+/// Per-measurement loss `GaussianRFField::train` fits against. Plain MSE
+/// lets a handful of multipath/outlier RSSI readings dominate training and
+/// warp the whole field; [`Self::Huber`] and [`Self::Anscombe`] trade some
+/// sensitivity to well-behaved measurements for robustness to those
+/// outliers.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LossKind {
+    /// Plain weighted mean squared error (the original behavior).
+    Mse,
+    /// Quadratic penalty for `|r| <= delta`, linear `delta*(|r|-delta/2)`
+    /// beyond -- bounds the influence of a single outlier residual `r`.
+    Huber {
+        /// Residual magnitude (dBm) beyond which the penalty goes linear.
+        delta: f64,
+    },
+    /// Variance-stabilizing transform for heteroscedastic received-power
+    /// noise: maps (linearized) signal power `p` through `2*sqrt(p + 3/8)`
+    /// before differencing, so weak and strong readings contribute
+    /// comparably instead of strong ones dominating in raw dBm.
+    Anscombe,
+}
+
+impl Default for LossKind {
+    fn default() -> Self {
+        Self::Mse
+    }
+}
+
+/// Convert a dBm reading to linear power (mW): `10^(dbm/10)`.
+fn dbm_to_linear(dbm: f64) -> f64 {
+    10f64.powf(dbm / 10.0)
+}
+
+/// Per-measurement loss contribution (before the caller's `weight` and
+/// `1/n` scaling) for `predicted` vs. `target`, both in dBm, under `loss`.
+fn loss_value(loss: LossKind, predicted: f64, target: f64) -> f64 {
+    match loss {
+        LossKind::Mse => {
+            let r = predicted - target;
+            r * r
+        }
+        LossKind::Huber { delta } => {
+            let r = predicted - target;
+            if r.abs() <= delta {
+                0.5 * r * r
+            } else {
+                delta * (r.abs() - 0.5 * delta)
+            }
+        }
+        LossKind::Anscombe => {
+            let r = anscombe_transform(predicted) - anscombe_transform(target);
+            r * r
+        }
+    }
+}
+
+/// `predicted - target`, transformed per `loss`, to substitute for the raw
+/// residual in a squared-error gradient (`2*weight*residual*d(predicted)/d(theta)`)
+/// so it follows `loss_value`'s penalty instead of always assuming MSE. Also
+/// what [`GaussianRFField::optimize_levenberg_marquardt`] feeds the Gauss-Newton
+/// normal equations as a pseudo-residual (standard IRLS practice), while
+/// still scoring accepted/rejected steps with the true [`loss_value`].
+fn robust_residual(loss: LossKind, predicted: f64, target: f64) -> f64 {
+    match loss {
+        LossKind::Mse => predicted - target,
+        LossKind::Huber { delta } => (predicted - target).clamp(-delta, delta),
+        LossKind::Anscombe => {
+            let p = dbm_to_linear(predicted);
+            // d(anscombe_transform)/d(predicted), by the chain rule through
+            // dbm_to_linear and sqrt.
+            let d_transform = (p * std::f64::consts::LN_10 / 10.0) / (p + 0.375).sqrt();
+            (anscombe_transform(predicted) - anscombe_transform(target)) * d_transform
+        }
+    }
+}
 
-        // Skip normalization for speed (relative values only)
-        self.amplitude * exponent.exp()
+/// Anscombe variance-stabilizing transform `2*sqrt(p + 3/8)` of a dBm
+/// reading's linear power `p`.
+fn anscombe_transform(dbm: f64) -> f64 {
+    2.0 * (dbm_to_linear(dbm) + 0.375).sqrt()
+}
+
+/// Directed (non-symmetric) KL divergence `KL(N(mu1,sigma1) || N(mu2,sigma2))`
+/// between two 3D Gaussians:
+/// `0.5 * [tr(sigma2^-1 sigma1) + (mu2-mu1)^T sigma2^-1 (mu2-mu1) - 3 + ln(det(sigma2)/det(sigma1))]`.
+/// `None` if either covariance is singular or non-positive-definite.
+fn directed_kl(
+    mu1: Vector3<f64>,
+    sigma1: &Matrix3<f64>,
+    mu2: Vector3<f64>,
+    sigma2: &Matrix3<f64>,
+) -> Option<f64> {
+    let sigma2_inv = sigma2.try_inverse()?;
+    let det1 = sigma1.determinant();
+    let det2 = sigma2.determinant();
+    if det1 <= 0.0 || det2 <= 0.0 {
+        return None;
     }
+
+    let trace_term = (sigma2_inv * sigma1).trace();
+    let diff = mu2 - mu1;
+    let quadratic_term = (diff.transpose() * sigma2_inv * diff)[0];
+
+    Some(0.5 * (trace_term + quadratic_term - 3.0 + (det2 / det1).ln()))
+}
+
+/// Symmetric KL divergence `KL(g1 || g2) + KL(g2 || g1)` between two
+/// Gaussian components, used both as [`GaussianRFField::kl_divergence`]'s
+/// per-component cost and as [`GaussianRFField::compress`]'s merge cost.
+/// `None` if either component's covariance is singular.
+fn symmetric_kl(g1: &Gaussian3D, g2: &Gaussian3D) -> Option<f64> {
+    let forward = directed_kl(g1.center, &g1.covariance, g2.center, &g2.covariance)?;
+    let backward = directed_kl(g2.center, &g2.covariance, g1.center, &g1.covariance)?;
+    Some(forward + backward)
+}
+
+/// Moment-match `g1` and `g2` into a single replacement Gaussian: weights
+/// are each component's amplitude converted to linear power (so a negative
+/// dBm amplitude still contributes a positive weight), the mean is the
+/// weighted average of the centers, and the covariance is the
+/// weight-averaged sum of each component's covariance plus the outer
+/// product of its center's offset from the merged mean. The merged
+/// amplitude is the combined linear power converted back to dBm.
+fn merge_gaussians(g1: &Gaussian3D, g2: &Gaussian3D) -> Gaussian3D {
+    let w1 = dbm_to_linear(g1.amplitude);
+    let w2 = dbm_to_linear(g2.amplitude);
+    let total_weight = w1 + w2;
+
+    let mean = (g1.center * w1 + g2.center * w2) / total_weight;
+    let offset1 = g1.center - mean;
+    let offset2 = g2.center - mean;
+    let covariance = (w1 * (g1.covariance + offset1 * offset1.transpose())
+        + w2 * (g2.covariance + offset2 * offset2.transpose()))
+        / total_weight;
+    let amplitude = 10.0 * total_weight.log10();
+
+    Gaussian3D::with_covariance(mean, covariance, amplitude)
+}
+
+/// Whether `GaussianRFField::train` fits a fixed Gaussian count chosen up
+/// front, or greedily grows the field to the accuracy `convergence_threshold`
+/// implies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrainingMode {
+    /// K-means-initialize exactly `num_gaussians` Gaussians, then optimize
+    /// (the original behavior).
+    Fixed,
+    /// Start from zero Gaussians and greedily insert one at a time at the
+    /// worst-residual measurement, re-optimizing and pruning after each
+    /// insertion. See [`GaussianRFField::train_adaptive`].
+    Adaptive,
 }
 
 /// Training configuration for Gaussian RF field
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainingConfig {
-    /// Number of Gaussians to use
+    /// Number of Gaussians to use. Only consulted when `mode` is
+    /// [`TrainingMode::Fixed`].
     pub num_gaussians: usize,
 
     /// Maximum training iterations
@@ -253,6 +503,50 @@ pub struct TrainingConfig {
 
     /// Use parallel training
     pub parallel: bool,
+
+    /// Which optimizer to fit Gaussian parameters with.
+    pub optimizer: OptimizerKind,
+
+    /// Initial Levenberg-Marquardt damping factor `lambda`.
+    pub lm_initial_lambda: f64,
+
+    /// Factor `lambda` is multiplied by after a rejected LM step.
+    pub lm_lambda_up_factor: f64,
+
+    /// Factor `lambda` is multiplied by after an accepted LM step.
+    pub lm_lambda_down_factor: f64,
+
+    /// Maximum number of damping retries per LM outer iteration before
+    /// giving up on finding a step that decreases the loss.
+    pub lm_max_inner_iterations: usize,
+
+    /// Fixed Gaussian count vs. greedy adaptive growth.
+    pub mode: TrainingMode,
+
+    /// Upper bound on how many Gaussians [`TrainingMode::Adaptive`] growth
+    /// will insert, regardless of residual.
+    pub max_gaussians: usize,
+
+    /// L1-style amplitude floor: after each adaptive insertion, Gaussians
+    /// whose fitted amplitude magnitude drops below this (dBm) are pruned.
+    pub amplitude_prune_threshold: f64,
+
+    /// Number of `optimize` iterations run over all current Gaussians after
+    /// each [`TrainingMode::Adaptive`] insertion.
+    pub adaptive_inner_iterations: usize,
+
+    /// Update rule [`OptimizerKind::GradientDescent`] steps amplitude with.
+    /// Unconsulted under [`OptimizerKind::LevenbergMarquardt`], which has
+    /// its own damped Gauss-Newton step.
+    pub gradient_optimizer: Optimizer,
+
+    /// Weight decay folded directly into the amplitude gradient (`g +=
+    /// amplitude_weight_decay * amplitude`) before the optimizer step,
+    /// rather than as a separate loss-level L2 term. `0.0` disables it.
+    pub amplitude_weight_decay: f64,
+
+    /// Per-measurement loss function fitted against.
+    pub loss: LossKind,
 }
 
 impl Default for TrainingConfig {
@@ -265,7 +559,294 @@ impl Default for TrainingConfig {
             initial_variance: 25.0, // 5m radius in free space
             regularization: 0.001,
             parallel: true,
+            optimizer: OptimizerKind::GradientDescent,
+            lm_initial_lambda: 1e-3,
+            lm_lambda_up_factor: 10.0,
+            lm_lambda_down_factor: 0.1,
+            lm_max_inner_iterations: 10,
+            mode: TrainingMode::Fixed,
+            max_gaussians: 200,
+            amplitude_prune_threshold: 1.0,
+            adaptive_inner_iterations: 20,
+            gradient_optimizer: Optimizer::Sgd,
+            amplitude_weight_decay: 0.0,
+            loss: LossKind::Mse,
+        }
+    }
+}
+
+impl TrainingConfig {
+    /// Defaults with [`OptimizerKind::LevenbergMarquardt`] in place of
+    /// [`OptimizerKind::GradientDescent`], so center and covariance are
+    /// fitted jointly with amplitude instead of staying frozen at their
+    /// K-means initialization -- the full-geometry fit anisotropic
+    /// propagation (corridors, street canyons) needs. The
+    /// amplitude-only default stays the fast path for callers that don't
+    /// need it.
+    pub fn full_geometry() -> Self {
+        Self {
+            optimizer: OptimizerKind::LevenbergMarquardt,
+            ..Self::default()
+        }
+    }
+}
+
+/// One candidate's result from [`GaussianRFField::train_auto`]'s model-order
+/// sweep.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelOrderCandidate {
+    /// Gaussian count this candidate was trained with.
+    pub num_gaussians: usize,
+    /// MSE + regularization loss on the training split.
+    pub train_loss: f64,
+    /// MSE + regularization loss on the held-out validation split.
+    pub val_loss: f64,
+    /// BIC-like score `n*ln(train_loss) + k_params*ln(n)` used to rank
+    /// candidates; lower is better.
+    pub criterion: f64,
+}
+
+fn default_support_radius_sigma() -> f64 {
+    3.0
+}
+
+fn default_use_index() -> bool {
+    true
+}
+
+/// Number of Gaussians a [`BvhNode::Leaf`] holds before
+/// [`SupportBvh::build`] splits it further.
+const BVH_LEAF_SIZE: usize = 4;
+
+/// A node in [`SupportBvh`]'s array-backed binary tree: either a leaf
+/// listing the Gaussians it covers (each with its own center/support
+/// radius, for an exact containment check) or an internal node with a
+/// bounding sphere enclosing both children, used to reject whole subtrees
+/// during descent.
+#[derive(Debug, Clone)]
+enum BvhNode {
+    Leaf {
+        items: Vec<(usize, Vector3<f64>, f64)>,
+    },
+    Internal {
+        center: Vector3<f64>,
+        radius: f64,
+        left: usize,
+        right: usize,
+    },
+}
+
+/// Bounding-volume hierarchy over each Gaussian's effective support sphere
+/// (`support_radius_sigma` standard deviations from its center, per
+/// [`GaussianRFField::rebuild_index`]), so `query` only has to evaluate the
+/// handful of Gaussians whose support actually reaches a given point
+/// instead of summing over all of them.
+///
+/// This is a structured simplification of a full R-tree (a flat
+/// array-of-nodes binary tree split on the widest axis at the median,
+/// rather than a balanced multi-way tree with tight AABBs) -- adequate for
+/// the thousands-of-Gaussians scale this crate targets.
+#[derive(Debug, Clone, Default)]
+struct SupportBvh {
+    nodes: Vec<BvhNode>,
+    root: Option<usize>,
+}
+
+impl SupportBvh {
+    fn build(spheres: &[(usize, Vector3<f64>, f64)]) -> Self {
+        if spheres.is_empty() {
+            return Self::default();
+        }
+
+        let mut items = spheres.to_vec();
+        let mut nodes = Vec::new();
+        let root = Self::build_recursive(&mut items, &mut nodes);
+        Self {
+            nodes,
+            root: Some(root),
+        }
+    }
+
+    fn build_recursive(
+        items: &mut [(usize, Vector3<f64>, f64)],
+        nodes: &mut Vec<BvhNode>,
+    ) -> usize {
+        if items.len() <= BVH_LEAF_SIZE {
+            nodes.push(BvhNode::Leaf {
+                items: items.to_vec(),
+            });
+            return nodes.len() - 1;
+        }
+
+        let mut min = Vector3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = Vector3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for (_, center, _) in items.iter() {
+            min = min.zip_map(center, f64::min);
+            max = max.zip_map(center, f64::max);
+        }
+        let extent = max - min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        items.sort_by(|a, b| a.1[axis].partial_cmp(&b.1[axis]).unwrap());
+        let mid = items.len() / 2;
+        let (left_items, right_items) = items.split_at_mut(mid);
+        let left = Self::build_recursive(left_items, nodes);
+        let right = Self::build_recursive(right_items, nodes);
+
+        let center: Vector3<f64> =
+            items.iter().map(|(_, c, _)| *c).sum::<Vector3<f64>>() / items.len() as f64;
+        let radius = items
+            .iter()
+            .map(|(_, c, r)| (c - center).norm() + r)
+            .fold(0.0, f64::max);
+
+        nodes.push(BvhNode::Internal {
+            center,
+            radius,
+            left,
+            right,
+        });
+        nodes.len() - 1
+    }
+
+    /// Collect the indices of every Gaussian whose own support sphere
+    /// contains `position`, descending only into subtrees whose bounding
+    /// sphere could possibly contain it.
+    fn collect_candidates(&self, position: Vector3<f64>, out: &mut Vec<usize>) {
+        let Some(root) = self.root else {
+            return;
+        };
+        self.visit(root, position, out);
+    }
+
+    fn visit(&self, node_idx: usize, position: Vector3<f64>, out: &mut Vec<usize>) {
+        match &self.nodes[node_idx] {
+            BvhNode::Leaf { items } => {
+                for (idx, center, radius) in items {
+                    if (position - center).norm() <= *radius {
+                        out.push(*idx);
+                    }
+                }
+            }
+            BvhNode::Internal {
+                center,
+                radius,
+                left,
+                right,
+            } => {
+                if (position - center).norm() <= *radius {
+                    self.visit(*left, position, out);
+                    self.visit(*right, position, out);
+                }
+            }
+        }
+    }
+}
+
+/// Largest eigenvalue of a symmetric 3x3 covariance matrix, used to turn
+/// `support_radius_sigma` standard deviations into a worst-case (along the
+/// longest axis) support radius for the spatial index.
+fn max_eigenvalue(covariance: &Matrix3<f64>) -> f64 {
+    nalgebra::linalg::SymmetricEigen::new(*covariance)
+        .eigenvalues
+        .iter()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// Device-resident Gaussian buffer for [`GaussianRFField::query_batch_gpu`],
+/// built once by [`GaussianRFField::upload_to_gpu`] and reused across
+/// repeated query batches so the centers/inverse-covariances/amplitudes
+/// aren't re-uploaded on every call. Only available with the `warp`
+/// feature.
+#[cfg(feature = "warp")]
+pub struct GpuFieldHandle {
+    centers: Vec<Vector3<f64>>,
+    inv_covariances: Vec<Matrix3<f64>>,
+    amplitudes: Vec<f64>,
+}
+
+#[cfg(feature = "warp")]
+impl GpuFieldHandle {
+    /// Upload `field`'s Gaussian centers, inverse covariances, and
+    /// amplitudes once. Every Gaussian must already be precomputed (as
+    /// `train`/`query` require anyway).
+    ///
+    /// In a full implementation this copies the three buffers once to GPU
+    /// arrays (`wp.array(dtype=wp.vec3)` / `wp.array(dtype=wp.mat33)` /
+    /// `wp.array(dtype=wp.float32)`) and keeps them resident for every
+    /// subsequent launch of [`Self::query_batch`]'s kernel; this
+    /// placeholder keeps the same three buffers host-side and evaluates
+    /// with rayon instead, matching the CPU-fallback pattern
+    /// `WarpFFI::cast_ray`/`cast_rays` use elsewhere in this codebase.
+    fn upload(field: &GaussianRFField) -> GaussianResult<Self> {
+        let mut centers = Vec::with_capacity(field.gaussians.len());
+        let mut inv_covariances = Vec::with_capacity(field.gaussians.len());
+        let mut amplitudes = Vec::with_capacity(field.gaussians.len());
+
+        for gaussian in &field.gaussians {
+            let inv_covariance = gaussian.inv_covariance.ok_or_else(|| {
+                GaussianError::NumericalError(
+                    "query_batch_gpu requires every Gaussian to be precomputed".to_string(),
+                )
+            })?;
+            centers.push(gaussian.center);
+            inv_covariances.push(inv_covariance);
+            amplitudes.push(gaussian.amplitude);
         }
+
+        Ok(Self {
+            centers,
+            inv_covariances,
+            amplitudes,
+        })
+    }
+
+    /// Evaluate the weighted Mahalanobis sum for every position against the
+    /// uploaded Gaussian buffers:
+    ///
+    /// ```text
+    /// @wp.kernel
+    /// def evaluate_gaussian_field(
+    ///     centers: wp.array(dtype=wp.vec3),
+    ///     inv_covariances: wp.array(dtype=wp.mat33),
+    ///     amplitudes: wp.array(dtype=wp.float32),
+    ///     positions: wp.array(dtype=wp.vec3),
+    ///     out: wp.array(dtype=wp.float32),
+    /// ):
+    ///     tid = wp.tid()  # One thread per query position
+    ///     pos = positions[tid]
+    ///     total = float(0.0)
+    ///     for i in range(centers.shape[0]):
+    ///         delta = pos - centers[i]
+    ///         exponent = -0.5 * wp.dot(delta, inv_covariances[i] * delta)
+    ///         total += amplitudes[i] * wp.exp(exponent)
+    ///     out[tid] = wp.clamp(total, -150.0, 50.0)
+    /// ```
+    pub fn query_batch(&self, positions: &[Vector3<f64>]) -> Vec<f64> {
+        positions
+            .par_iter()
+            .map(|&position| {
+                let sum: f64 = self
+                    .centers
+                    .iter()
+                    .zip(&self.inv_covariances)
+                    .zip(&self.amplitudes)
+                    .map(|((center, inv_covariance), amplitude)| {
+                        let delta = position - center;
+                        let exponent = -0.5 * (delta.transpose() * inv_covariance * delta)[0];
+                        amplitude * exponent.exp()
+                    })
+                    .sum();
+                sum.clamp(-150.0, 50.0)
+            })
+            .collect()
     }
 }
 
@@ -281,6 +862,26 @@ pub struct GaussianRFField {
     /// Training statistics
     pub training_loss: Option<f64>,
     pub training_iterations: Option<usize>,
+
+    /// Support-radius cutoff, in standard deviations from each Gaussian's
+    /// center, used to build the spatial index `query`/`query_batch`
+    /// descend. Smaller values cull more aggressively (faster, slightly
+    /// less accurate beyond a Gaussian's core); see
+    /// [`Self::set_support_radius_sigma`].
+    #[serde(default = "default_support_radius_sigma")]
+    pub support_radius_sigma: f64,
+
+    /// Whether `query`/`query_batch` use the spatial index at all. `false`
+    /// restores the original exact full-summation behavior; see
+    /// [`Self::use_index`].
+    #[serde(default = "default_use_index")]
+    index_enabled: bool,
+
+    /// Spatial index over `gaussians`' support spheres, rebuilt whenever
+    /// `gaussians` changes (training, or an explicit
+    /// [`Self::rebuild_index`] call after manual edits).
+    #[serde(skip)]
+    index: Option<SupportBvh>,
 }
 
 impl GaussianRFField {
@@ -291,7 +892,47 @@ impl GaussianRFField {
             transmitter_pos: None,
             training_loss: None,
             training_iterations: None,
+            support_radius_sigma: default_support_radius_sigma(),
+            index_enabled: default_use_index(),
+            index: None,
+        }
+    }
+
+    /// Enable or disable the spatial index for `query`/`query_batch`.
+    /// Disabling it (`false`) falls back to the original exact
+    /// full-summation-over-every-Gaussian behavior.
+    pub fn use_index(&mut self, enabled: bool) {
+        self.index_enabled = enabled;
+    }
+
+    /// Set the support-radius cutoff (in standard deviations) and rebuild
+    /// the index against the current Gaussians.
+    pub fn set_support_radius_sigma(&mut self, sigma: f64) {
+        self.support_radius_sigma = sigma;
+        self.rebuild_index();
+    }
+
+    /// Rebuild the spatial index from `self.gaussians`' current centers and
+    /// covariances. Called automatically after training; call directly
+    /// after manually mutating `gaussians`.
+    pub fn rebuild_index(&mut self) {
+        if self.gaussians.is_empty() {
+            self.index = None;
+            return;
         }
+
+        let spheres: Vec<(usize, Vector3<f64>, f64)> = self
+            .gaussians
+            .iter()
+            .enumerate()
+            .map(|(i, g)| {
+                let radius =
+                    self.support_radius_sigma * max_eigenvalue(&g.covariance).max(0.0).sqrt();
+                (i, g.center, radius)
+            })
+            .collect();
+
+        self.index = Some(SupportBvh::build(&spheres));
     }
 
     /// Train Gaussian field from measurements
@@ -300,27 +941,227 @@ impl GaussianRFField {
         measurements: &[RFMeasurement],
         config: TrainingConfig,
     ) -> GaussianResult<()> {
-        if measurements.len() < config.num_gaussians {
+        if measurements.is_empty() {
+            return Err(GaussianError::InsufficientData(
+                "Need at least 1 measurement".to_string(),
+            ));
+        }
+
+        let loss = match config.mode {
+            TrainingMode::Fixed => {
+                if measurements.len() < config.num_gaussians {
+                    return Err(GaussianError::InsufficientData(format!(
+                        "Need at least {} measurements for {} Gaussians",
+                        config.num_gaussians, config.num_gaussians
+                    )));
+                }
+
+                // Initialize Gaussians using K-means clustering
+                self.initialize_gaussians(measurements, &config)?;
+
+                // Precompute all Gaussians
+                for gaussian in &mut self.gaussians {
+                    gaussian.precompute()?;
+                }
+
+                // Optimize Gaussian parameters
+                self.optimize(measurements, &config)?
+            }
+            TrainingMode::Adaptive => self.train_adaptive(measurements, &config)?,
+        };
+
+        self.training_loss = Some(loss);
+        self.training_iterations = Some(config.max_iterations);
+        self.rebuild_index();
+
+        Ok(())
+    }
+
+    /// Number of free parameters counted per Gaussian by [`Self::train_auto`]'s
+    /// BIC-like criterion: just the amplitude under
+    /// [`OptimizerKind::GradientDescent`], or amplitude + center + the
+    /// Cholesky covariance factor under [`OptimizerKind::LevenbergMarquardt`].
+    fn params_per_gaussian(optimizer: OptimizerKind) -> usize {
+        match optimizer {
+            OptimizerKind::GradientDescent => 1,
+            OptimizerKind::LevenbergMarquardt => Self::LM_PARAMS_PER_GAUSSIAN,
+        }
+    }
+
+    /// Sweep `candidate_counts`, training a [`TrainingMode::Fixed`] field at
+    /// each via `base_config`, and return the field minimizing a BIC-like
+    /// criterion `n*ln(MSE) + k_params*ln(n)` (`k_params` is the total free
+    /// parameter count across all Gaussians, per
+    /// [`Self::params_per_gaussian`]) alongside a report of every candidate
+    /// tried. Every fifth measurement (deterministically, so repeated calls
+    /// on the same data reproduce the same split without depending on
+    /// `rand`) is held out as a validation set: `train_loss`/`criterion` are
+    /// computed on the rest, `val_loss` on the held-out fifth, so a report
+    /// consumer can see whether a candidate's fit generalizes before
+    /// trusting the (training-set) criterion that picked it.
+    pub fn train_auto(
+        measurements: &[RFMeasurement],
+        candidate_counts: &[usize],
+        base_config: &TrainingConfig,
+    ) -> GaussianResult<(Self, Vec<ModelOrderCandidate>)> {
+        let Some(&max_candidate) = candidate_counts.iter().max() else {
+            return Err(GaussianError::InvalidConfig(
+                "train_auto needs at least one candidate Gaussian count".to_string(),
+            ));
+        };
+
+        let mut train_set = Vec::new();
+        let mut val_set = Vec::new();
+        for (i, m) in measurements.iter().enumerate() {
+            if i % 5 == 0 {
+                val_set.push(m.clone());
+            } else {
+                train_set.push(m.clone());
+            }
+        }
+        if val_set.is_empty() {
+            val_set = train_set.clone();
+        }
+        if train_set.len() < max_candidate {
             return Err(GaussianError::InsufficientData(format!(
-                "Need at least {} measurements for {} Gaussians",
-                config.num_gaussians, config.num_gaussians
+                "Need at least {} training measurements (after the validation split) for {} Gaussians",
+                max_candidate, max_candidate
             )));
         }
 
-        // Initialize Gaussians using K-means clustering
-        self.initialize_gaussians(measurements, &config)?;
+        let params_per = Self::params_per_gaussian(base_config.optimizer);
+        let n = train_set.len() as f64;
+
+        let mut best: Option<(Self, f64)> = None;
+        let mut report = Vec::with_capacity(candidate_counts.len());
+
+        for &k in candidate_counts {
+            let mut field = Self::new();
+            let config = TrainingConfig {
+                num_gaussians: k,
+                mode: TrainingMode::Fixed,
+                ..base_config.clone()
+            };
+            field.train(&train_set, config)?;
+
+            let train_loss = field.compute_loss(&train_set, 0.0, base_config.loss);
+            let val_loss = field.compute_loss(&val_set, 0.0, base_config.loss);
+            let criterion = n * train_loss.max(1e-12).ln() + (params_per * k) as f64 * n.ln();
+
+            report.push(ModelOrderCandidate {
+                num_gaussians: k,
+                train_loss,
+                val_loss,
+                criterion,
+            });
+
+            let is_better = match &best {
+                Some((_, best_criterion)) => criterion < *best_criterion,
+                None => true,
+            };
+            if is_better {
+                best = Some((field, criterion));
+            }
+        }
+
+        let (best_field, _) = best.expect("candidate_counts is non-empty, checked above");
+        Ok((best_field, report))
+    }
 
-        // Precompute all Gaussians
-        for gaussian in &mut self.gaussians {
+    /// Greedily grow the field one Gaussian at a time -- the Frank-Wolfe /
+    /// conditional-gradient "point source" idea. Each outer step finds the
+    /// measurement with the largest current prediction residual, inserts a
+    /// new Gaussian centered there with amplitude initialized to cancel
+    /// that residual, re-solves every amplitude jointly via
+    /// [`Self::solve_amplitudes_linear`], refines further with
+    /// `config.adaptive_inner_iterations` steps of `config.optimizer`, then
+    /// prunes/merges via [`Self::prune_and_merge`]. Stops once the worst
+    /// residual drops below `config.convergence_threshold` or
+    /// `config.max_gaussians` is reached.
+    fn train_adaptive(
+        &mut self,
+        measurements: &[RFMeasurement],
+        config: &TrainingConfig,
+    ) -> GaussianResult<f64> {
+        self.gaussians.clear();
+
+        loop {
+            let (worst_index, worst_residual) = measurements
+                .iter()
+                .map(|m| self.query(m.position) - m.rssi_dbm)
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.abs().partial_cmp(&b.abs()).unwrap())
+                .expect("measurements is non-empty, checked in train()");
+
+            if worst_residual.abs() < config.convergence_threshold
+                || self.gaussians.len() >= config.max_gaussians
+            {
+                break;
+            }
+
+            let mut gaussian = Gaussian3D::new(
+                measurements[worst_index].position,
+                config.initial_variance,
+                -worst_residual,
+            );
             gaussian.precompute()?;
+            self.gaussians.push(gaussian);
+
+            // Centers/covariances are fixed at this point, so the predicted
+            // signal is linear in amplitude: re-solving all amplitudes
+            // jointly in one exact least-squares solve is both cheaper and a
+            // better-conditioned starting point than letting the new
+            // Gaussian's single-measurement amplitude guess get refined by
+            // gradient steps alone.
+            self.solve_amplitudes_linear(measurements, config.regularization);
+            for gaussian in &mut self.gaussians {
+                gaussian.precompute()?;
+            }
+
+            let inner_config = TrainingConfig {
+                max_iterations: config.adaptive_inner_iterations,
+                ..config.clone()
+            };
+            self.optimize(measurements, &inner_config)?;
+            self.prune_and_merge(config)?;
         }
 
-        // Optimize Gaussian parameters
-        let loss = self.optimize(measurements, &config)?;
+        Ok(self.compute_loss(measurements, config.regularization, config.loss))
+    }
 
-        self.training_loss = Some(loss);
-        self.training_iterations = Some(config.max_iterations);
+    /// L1-style sparsification step for [`Self::train_adaptive`]: drop any
+    /// Gaussian whose fitted amplitude magnitude has shrunk below
+    /// `config.amplitude_prune_threshold`, then merge centers left within
+    /// half a `sqrt(initial_variance)` of each other (amplitude-weighted
+    /// center, summed amplitude) so insertion doesn't accumulate
+    /// near-duplicate spikes at the same residual hotspot.
+    fn prune_and_merge(&mut self, config: &TrainingConfig) -> GaussianResult<()> {
+        self.gaussians
+            .retain(|g| g.amplitude.abs() >= config.amplitude_prune_threshold);
+
+        let merge_radius = config.initial_variance.sqrt() * 0.5;
+        let mut merged: Vec<Gaussian3D> = Vec::new();
+        for g in self.gaussians.drain(..) {
+            if let Some(existing) = merged
+                .iter_mut()
+                .find(|m| (m.center - g.center).norm() < merge_radius)
+            {
+                let total_amplitude = existing.amplitude + g.amplitude;
+                if total_amplitude.abs() > 1e-9 {
+                    existing.center = (existing.center * existing.amplitude
+                        + g.center * g.amplitude)
+                        / total_amplitude;
+                }
+                existing.amplitude = total_amplitude;
+            } else {
+                merged.push(g);
+            }
+        }
 
+        self.gaussians = merged;
+        for gaussian in &mut self.gaussians {
+            gaussian.precompute()?;
+        }
         Ok(())
     }
 
@@ -393,35 +1234,69 @@ impl GaussianRFField {
         Ok(())
     }
 
-    /// Optimize Gaussian parameters using gradient descent
+    /// Optimize Gaussian parameters using `config.optimizer`.
     fn optimize(
         &mut self,
         measurements: &[RFMeasurement],
         config: &TrainingConfig,
+    ) -> GaussianResult<f64> {
+        match config.optimizer {
+            OptimizerKind::GradientDescent => self.optimize_gradient_descent(measurements, config),
+            OptimizerKind::LevenbergMarquardt => {
+                self.optimize_levenberg_marquardt(measurements, config)
+            }
+        }
+    }
+
+    /// Optimize Gaussian parameters using gradient descent
+    fn optimize_gradient_descent(
+        &mut self,
+        measurements: &[RFMeasurement],
+        config: &TrainingConfig,
     ) -> GaussianResult<f64> {
         let mut prev_loss = f64::INFINITY;
+        let mut optimizer_state = vec![OptimizerState::default(); self.gaussians.len()];
 
         for _iteration in 0..config.max_iterations {
             // Compute current loss
-            let loss = self.compute_loss(measurements, config.regularization);
+            let loss = self.compute_loss(measurements, config.regularization, config.loss);
 
             // Check convergence
             if (prev_loss - loss).abs() < config.convergence_threshold {
                 return Ok(loss);
             }
 
-            // Compute gradients for all Gaussians first
+            // Compute gradients for all Gaussians first, folding in weight
+            // decay directly rather than as a separate loss-level L2 term.
             let gradients: Vec<f64> = self
                 .gaussians
                 .iter()
-                .map(|g| Self::compute_amplitude_gradient_static(g, &self.gaussians, measurements))
+                .map(|g| {
+                    let raw = Self::compute_amplitude_gradient_static(
+                        g,
+                        &self.gaussians,
+                        measurements,
+                        config.loss,
+                    );
+                    raw + config.amplitude_weight_decay * g.amplitude
+                })
                 .collect();
 
-            // Apply gradient updates with clamping
-            for (gaussian, gradient) in self.gaussians.iter_mut().zip(gradients.iter()) {
+            // Apply optimizer-specific updates with clamping
+            for ((gaussian, gradient), state) in self
+                .gaussians
+                .iter_mut()
+                .zip(gradients.iter())
+                .zip(optimizer_state.iter_mut())
+            {
                 // Clamp gradient to prevent numerical instability
                 let clamped_gradient = gradient.clamp(-100.0, 100.0);
-                gaussian.amplitude -= config.learning_rate * clamped_gradient;
+                let delta = state.step(
+                    config.gradient_optimizer,
+                    config.learning_rate,
+                    clamped_gradient,
+                );
+                gaussian.amplitude -= delta;
 
                 // Clamp amplitude to reasonable RF range (-150 to +50 dBm)
                 gaussian.amplitude = gaussian.amplitude.clamp(-150.0, 50.0);
@@ -438,71 +1313,388 @@ impl GaussianRFField {
         Ok(prev_loss)
     }
 
-    /// Compute training loss (MSE)
-    fn compute_loss(&self, measurements: &[RFMeasurement], regularization: f64) -> f64 {
-        let prediction_error: f64 = measurements
-            .iter()
-            .map(|m| {
-                let predicted = self.query(m.position);
-                let error = predicted - m.rssi_dbm;
-                m.weight * error * error
-            })
-            .sum();
+    /// Re-solve every Gaussian's amplitude jointly via weighted linear
+    /// least squares, holding centers/covariances fixed. The predicted
+    /// signal is linear in amplitude once geometry is fixed (`predicted =
+    /// sum_k a_k * basis_k(pos)`), so this builds the `N_meas x N_gauss`
+    /// design matrix of [`Gaussian3D::basis_value`]s and solves the
+    /// regularized normal equations `(DᵀWD + regularization·I) a = DᵀWy`
+    /// directly, rather than taking gradient steps. Leaves amplitudes
+    /// untouched if the (regularized) normal equations are singular.
+    fn solve_amplitudes_linear(&mut self, measurements: &[RFMeasurement], regularization: f64) {
+        let k = self.gaussians.len();
+        let n = measurements.len();
+        if k == 0 {
+            return;
+        }
 
-        // L2 regularization on amplitudes
-        let reg_term: f64 = self
-            .gaussians
-            .iter()
-            .map(|g| g.amplitude * g.amplitude)
-            .sum();
+        let mut design = DMatrix::<f64>::zeros(n, k);
+        let mut target = DVector::<f64>::zeros(n);
+        for (row, m) in measurements.iter().enumerate() {
+            let weight_sqrt = m.weight.max(0.0).sqrt();
+            for (col, gaussian) in self.gaussians.iter().enumerate() {
+                design[(row, col)] = gaussian.basis_value(m.position) * weight_sqrt;
+            }
+            target[row] = m.rssi_dbm * weight_sqrt;
+        }
 
-        (prediction_error / measurements.len() as f64) + regularization * reg_term
-    }
+        let design_t = design.transpose();
+        let mut normal = &design_t * &design;
+        for i in 0..k {
+            normal[(i, i)] += regularization.max(1e-9);
+        }
+        let rhs = &design_t * target;
 
-    /// Compute gradient of loss w.r.t. Gaussian amplitude (static version for optimization)
-    fn compute_amplitude_gradient_static(
-        target_gaussian: &Gaussian3D,
-        all_gaussians: &[Gaussian3D],
-        measurements: &[RFMeasurement],
-    ) -> f64 {
-        measurements
-            .iter()
-            .map(|m| {
-                // Compute predicted value from all Gaussians
-                let predicted: f64 = all_gaussians
-                    .iter()
-                    .map(|g| g.evaluate_fast(m.position))
-                    .sum();
+        let Some(chol) = normal.cholesky() else {
+            return;
+        };
+        let amplitudes = chol.solve(&rhs);
 
-                let error = predicted - m.rssi_dbm;
-                let contribution = target_gaussian.evaluate_fast(m.position);
-                2.0 * m.weight * error * contribution / measurements.len() as f64
-            })
-            .sum()
+        for (gaussian, amplitude) in self.gaussians.iter_mut().zip(amplitudes.iter()) {
+            gaussian.amplitude = amplitude.clamp(-150.0, 50.0);
+        }
     }
 
-    /// Query signal strength at a position
-    pub fn query(&self, position: Vector3<f64>) -> f64 {
-        if self.gaussians.is_empty() {
-            return -120.0; // Very weak signal
-        }
+    /// Number of fitted parameters per Gaussian in
+    /// [`Self::optimize_levenberg_marquardt`]: amplitude (1), center (3),
+    /// and the lower-triangular Cholesky factor of the covariance (6).
+    const LM_PARAMS_PER_GAUSSIAN: usize = 10;
+
+    /// Fit amplitude, center, and covariance (via its Cholesky factor)
+    /// jointly with Levenberg-Marquardt, using the analytic Jacobian of
+    /// `f(p) = sum_k a_k exp(-0.5 (p-c_k)^T Sigma_k^-1 (p-c_k))`.
+    ///
+    /// Parameterizing `Sigma_k = L_k L_k^T` through its Cholesky factor
+    /// `L_k` rather than `Sigma_k` directly keeps it positive-definite for
+    /// any step LM takes, as long as `L_k` stays invertible (its diagonal
+    /// stays away from zero). With `u = Sigma_k^-1 (p-c_k)` and
+    /// `w = L_k^T u`, the quadratic form's derivative works out to a clean
+    /// closed form: `d(-0.5 q_k)/dL_k[i,j] = u_i * w_j` for the
+    /// lower-triangular `(i,j)`, so `df/dL_k[i,j] = a_k exp(-0.5 q_k) * u_i
+    /// * w_j` alongside the amplitude/center gradients the request
+    /// specifies directly.
+    fn optimize_levenberg_marquardt(
+        &mut self,
+        measurements: &[RFMeasurement],
+        config: &TrainingConfig,
+    ) -> GaussianResult<f64> {
+        let k = self.gaussians.len();
+        let n = measurements.len();
+        let params_per = Self::LM_PARAMS_PER_GAUSSIAN;
+        let total_params = params_per * k;
 
-        // Sum contributions from all Gaussians (weighted sum of Gaussians)
-        let sum: f64 = self
+        let mut amplitudes: Vec<f64> = self.gaussians.iter().map(|g| g.amplitude).collect();
+        let mut centers: Vec<Vector3<f64>> = self.gaussians.iter().map(|g| g.center).collect();
+        let mut cholesky: Vec<Matrix3<f64>> = self
             .gaussians
             .iter()
-            .map(|g| g.evaluate_fast(position))
-            .sum();
+            .map(|g| Self::cholesky_factor(&g.covariance))
+            .collect();
 
-        // Clamp to reasonable range
+        let mut lambda = config.lm_initial_lambda.max(1e-12);
+        let mut current_loss = Self::lm_loss(
+            &amplitudes,
+            &centers,
+            &cholesky,
+            measurements,
+            config.regularization,
+            config.loss,
+        );
+
+        for _iteration in 0..config.max_iterations {
+            let mut residuals = DVector::<f64>::zeros(n);
+            let mut jacobian = DMatrix::<f64>::zeros(n, total_params);
+
+            for (row, m) in measurements.iter().enumerate() {
+                let weight_sqrt = m.weight.max(0.0).sqrt();
+                let mut predicted = 0.0;
+
+                for gi in 0..k {
+                    let delta = m.position - centers[gi];
+                    let covariance = cholesky[gi] * cholesky[gi].transpose();
+                    let Some(inv_covariance) = covariance.try_inverse() else {
+                        continue;
+                    };
+                    let u = inv_covariance * delta;
+                    let q = delta.dot(&u);
+                    let gauss = (-0.5 * q).exp();
+                    let amp = amplitudes[gi];
+                    predicted += amp * gauss;
+
+                    let w = cholesky[gi].transpose() * u;
+                    let base = gi * params_per;
+                    jacobian[(row, base)] = gauss;
+                    let dc = u * (amp * gauss);
+                    jacobian[(row, base + 1)] = dc.x;
+                    jacobian[(row, base + 2)] = dc.y;
+                    jacobian[(row, base + 3)] = dc.z;
+                    let df_dl = |i: usize, j: usize| amp * gauss * u[i] * w[j];
+                    jacobian[(row, base + 4)] = df_dl(0, 0); // L[0][0]
+                    jacobian[(row, base + 5)] = df_dl(1, 0); // L[1][0]
+                    jacobian[(row, base + 6)] = df_dl(1, 1); // L[1][1]
+                    jacobian[(row, base + 7)] = df_dl(2, 0); // L[2][0]
+                    jacobian[(row, base + 8)] = df_dl(2, 1); // L[2][1]
+                    jacobian[(row, base + 9)] = df_dl(2, 2); // L[2][2]
+                }
+
+                residuals[row] = robust_residual(config.loss, predicted, m.rssi_dbm) * weight_sqrt;
+                for col in 0..total_params {
+                    jacobian[(row, col)] *= weight_sqrt;
+                }
+            }
+
+            let jacobian_t = jacobian.transpose();
+            let jtj = &jacobian_t * &jacobian;
+            let jtr = &jacobian_t * &residuals;
+
+            let mut accepted = false;
+            let mut converged = false;
+            for _inner in 0..config.lm_max_inner_iterations {
+                let mut damped = jtj.clone();
+                for i in 0..total_params {
+                    damped[(i, i)] += lambda * jtj[(i, i)].max(1e-12);
+                }
+
+                let Some(chol) = damped.cholesky() else {
+                    lambda *= config.lm_lambda_up_factor;
+                    continue;
+                };
+                let step = chol.solve(&(-&jtr));
+
+                let mut candidate_amplitudes = amplitudes.clone();
+                let mut candidate_centers = centers.clone();
+                let mut candidate_cholesky = cholesky.clone();
+                for gi in 0..k {
+                    let base = gi * params_per;
+                    candidate_amplitudes[gi] = (amplitudes[gi] + step[base]).clamp(-150.0, 50.0);
+                    candidate_centers[gi] =
+                        centers[gi] + Vector3::new(step[base + 1], step[base + 2], step[base + 3]);
+                    let l = &mut candidate_cholesky[gi];
+                    l[(0, 0)] = clamp_away_from_zero(l[(0, 0)] + step[base + 4]);
+                    l[(1, 0)] += step[base + 5];
+                    l[(1, 1)] = clamp_away_from_zero(l[(1, 1)] + step[base + 6]);
+                    l[(2, 0)] += step[base + 7];
+                    l[(2, 1)] += step[base + 8];
+                    l[(2, 2)] = clamp_away_from_zero(l[(2, 2)] + step[base + 9]);
+                }
+
+                let candidate_loss = Self::lm_loss(
+                    &candidate_amplitudes,
+                    &candidate_centers,
+                    &candidate_cholesky,
+                    measurements,
+                    config.regularization,
+                    config.loss,
+                );
+
+                if candidate_loss.is_finite() && candidate_loss < current_loss {
+                    accepted = true;
+                    converged =
+                        (current_loss - candidate_loss).abs() < config.convergence_threshold;
+
+                    amplitudes = candidate_amplitudes;
+                    centers = candidate_centers;
+                    cholesky = candidate_cholesky;
+                    current_loss = candidate_loss;
+                    lambda *= config.lm_lambda_down_factor;
+                    break;
+                }
+
+                lambda *= config.lm_lambda_up_factor;
+            }
+
+            if !accepted || converged {
+                break;
+            }
+        }
+
+        for gi in 0..k {
+            let gaussian = &mut self.gaussians[gi];
+            gaussian.amplitude = amplitudes[gi];
+            gaussian.center = centers[gi];
+            gaussian.covariance = cholesky[gi] * cholesky[gi].transpose();
+            gaussian.precompute()?;
+        }
+
+        Ok(current_loss)
+    }
+
+    /// Cholesky factor of `covariance`, falling back to the identity if it
+    /// isn't (numerically) positive-definite.
+    fn cholesky_factor(covariance: &Matrix3<f64>) -> Matrix3<f64> {
+        nalgebra::linalg::Cholesky::new(*covariance)
+            .map(|c| c.l())
+            .unwrap_or_else(Matrix3::identity)
+    }
+
+    /// Configured loss + L2-regularization loss for a candidate parameter
+    /// set, used by [`Self::optimize_levenberg_marquardt`] without mutating
+    /// `self`.
+    fn lm_loss(
+        amplitudes: &[f64],
+        centers: &[Vector3<f64>],
+        cholesky: &[Matrix3<f64>],
+        measurements: &[RFMeasurement],
+        regularization: f64,
+        loss: LossKind,
+    ) -> f64 {
+        let prediction_error: f64 = measurements
+            .iter()
+            .map(|m| {
+                let predicted: f64 = amplitudes
+                    .iter()
+                    .zip(centers.iter())
+                    .zip(cholesky.iter())
+                    .map(|((amp, center), l)| {
+                        let delta = m.position - center;
+                        let covariance = l * l.transpose();
+                        let Some(inv_covariance) = covariance.try_inverse() else {
+                            return 0.0;
+                        };
+                        let q = delta.dot(&(inv_covariance * delta));
+                        amp * (-0.5 * q).exp()
+                    })
+                    .sum();
+                m.weight * loss_value(loss, predicted, m.rssi_dbm)
+            })
+            .sum();
+
+        let reg_term: f64 = amplitudes.iter().map(|a| a * a).sum();
+
+        (prediction_error / measurements.len() as f64) + regularization * reg_term
+    }
+
+    /// Compute training loss under `loss`
+    fn compute_loss(
+        &self,
+        measurements: &[RFMeasurement],
+        regularization: f64,
+        loss: LossKind,
+    ) -> f64 {
+        let prediction_error: f64 = measurements
+            .iter()
+            .map(|m| {
+                let predicted = self.query(m.position);
+                m.weight * loss_value(loss, predicted, m.rssi_dbm)
+            })
+            .sum();
+
+        // L2 regularization on amplitudes
+        let reg_term: f64 = self
+            .gaussians
+            .iter()
+            .map(|g| g.amplitude * g.amplitude)
+            .sum();
+
+        (prediction_error / measurements.len() as f64) + regularization * reg_term
+    }
+
+    /// Compute gradient of loss w.r.t. Gaussian amplitude (static version for optimization)
+    fn compute_amplitude_gradient_static(
+        target_gaussian: &Gaussian3D,
+        all_gaussians: &[Gaussian3D],
+        measurements: &[RFMeasurement],
+        loss: LossKind,
+    ) -> f64 {
+        measurements
+            .iter()
+            .map(|m| {
+                // Compute predicted value from all Gaussians
+                let predicted: f64 = all_gaussians
+                    .iter()
+                    .map(|g| g.evaluate_fast(m.position))
+                    .sum();
+
+                let residual = robust_residual(loss, predicted, m.rssi_dbm);
+                let contribution = target_gaussian.evaluate_fast(m.position);
+                2.0 * m.weight * residual * contribution / measurements.len() as f64
+            })
+            .sum()
+    }
+
+    /// Query signal strength at a position
+    pub fn query(&self, position: Vector3<f64>) -> f64 {
+        if self.gaussians.is_empty() {
+            return -120.0; // Very weak signal
+        }
+
+        let sum = if self.index_enabled {
+            match &self.index {
+                Some(index) => {
+                    let mut candidates = Vec::new();
+                    index.collect_candidates(position, &mut candidates);
+                    if candidates.is_empty() {
+                        // Outside every Gaussian's support sphere: fall back
+                        // to the exact full evaluation rather than reporting
+                        // zero.
+                        self.evaluate_all(position)
+                    } else {
+                        candidates
+                            .iter()
+                            .map(|&i| self.gaussians[i].evaluate_fast(position))
+                            .sum()
+                    }
+                }
+                None => self.evaluate_all(position),
+            }
+        } else {
+            self.evaluate_all(position)
+        };
+
+        // Clamp to reasonable range
         sum.clamp(-150.0, 50.0)
     }
 
-    /// Query signal strength at multiple positions (parallel)
+    /// Sum contributions from every Gaussian, with no spatial culling --
+    /// the exact behavior `query`/`query_batch` fall back to when the index
+    /// is disabled, unbuilt, or a point falls outside every support sphere.
+    fn evaluate_all(&self, position: Vector3<f64>) -> f64 {
+        self.gaussians
+            .iter()
+            .map(|g| g.evaluate_fast(position))
+            .sum()
+    }
+
+    /// Query signal strength at multiple positions (parallel). Each query
+    /// independently descends the spatial index built by
+    /// [`Self::rebuild_index`]; grouping points by leaf to reuse a single
+    /// traversal across a cluster of nearby points is a further
+    /// optimization this doesn't do.
     pub fn query_batch(&self, positions: &[Vector3<f64>]) -> Vec<f64> {
         positions.par_iter().map(|pos| self.query(*pos)).collect()
     }
 
+    /// Build a persistent device-resident handle over this field's current
+    /// Gaussians for repeated [`GpuFieldHandle::query_batch`] calls -- the
+    /// Gaussian buffer is uploaded once here rather than on every query
+    /// batch, which matters for something like animating a receiver over a
+    /// grid. Only available with the `warp` feature; see
+    /// [`Self::query_batch_gpu`] for a one-shot call that doesn't need the
+    /// handle kept around.
+    #[cfg(feature = "warp")]
+    pub fn upload_to_gpu(&self) -> GaussianResult<GpuFieldHandle> {
+        GpuFieldHandle::upload(self)
+    }
+
+    /// Query signal strength at multiple positions, routed through the
+    /// `warp` GPU backend when the `warp` feature is enabled (uploading the
+    /// Gaussian buffers fresh for this call -- use [`Self::upload_to_gpu`]
+    /// directly to amortize that across repeated batches), falling back to
+    /// [`Self::query_batch`]'s rayon path otherwise.
+    pub fn query_batch_gpu(&self, positions: &[Vector3<f64>]) -> Vec<f64> {
+        #[cfg(feature = "warp")]
+        {
+            match self.upload_to_gpu() {
+                Ok(handle) => return handle.query_batch(positions),
+                Err(_) => return self.query_batch(positions),
+            }
+        }
+        #[cfg(not(feature = "warp"))]
+        {
+            self.query_batch(positions)
+        }
+    }
+
     /// Get the number of Gaussians
     pub fn num_gaussians(&self) -> usize {
         self.gaussians.len()
@@ -512,6 +1704,97 @@ impl GaussianRFField {
     pub fn set_transmitter(&mut self, position: Vector3<f64>) {
         self.transmitter_pos = Some(position);
     }
+
+    /// Compare this field against `other` by summing symmetric KL divergence
+    /// over matched Gaussian components -- a field-level similarity score
+    /// usable without re-querying either field over a grid of positions.
+    /// Each of this field's Gaussians is matched to its nearest-center
+    /// counterpart in `other` and weighted by `|amplitude|`, so components
+    /// both fields agree dominate, with `f64::INFINITY` if either field is
+    /// empty.
+    pub fn kl_divergence(&self, other: &Self) -> f64 {
+        if self.gaussians.is_empty() || other.gaussians.is_empty() {
+            return f64::INFINITY;
+        }
+
+        self.gaussians
+            .iter()
+            .map(|g| {
+                let nearest = other
+                    .gaussians
+                    .iter()
+                    .min_by(|a, b| {
+                        (a.center - g.center)
+                            .norm()
+                            .partial_cmp(&(b.center - g.center).norm())
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .expect("other.gaussians checked non-empty above");
+
+                g.amplitude.abs() * symmetric_kl(g, nearest).unwrap_or(f64::INFINITY)
+            })
+            .sum()
+    }
+
+    /// Compress this field by repeatedly merging the pair of Gaussians with
+    /// the smallest merged-KL cost into a single moment-matched Gaussian,
+    /// until `target_count` is reached, the cheapest remaining merge exceeds
+    /// `max_merge_cost`, or fewer than two Gaussians remain. Pass `None` for
+    /// either bound to ignore it (passing both `None` is a no-op).
+    pub fn compress(
+        &mut self,
+        target_count: Option<usize>,
+        max_merge_cost: Option<f64>,
+    ) -> GaussianResult<()> {
+        loop {
+            if let Some(target) = target_count {
+                if self.gaussians.len() <= target {
+                    break;
+                }
+            }
+            if self.gaussians.len() < 2 {
+                break;
+            }
+
+            let mut best: Option<(usize, usize, f64)> = None;
+            for i in 0..self.gaussians.len() {
+                for j in (i + 1)..self.gaussians.len() {
+                    let cost = symmetric_kl(&self.gaussians[i], &self.gaussians[j])
+                        .unwrap_or(f64::INFINITY);
+                    if best
+                        .map(|(_, _, best_cost)| cost < best_cost)
+                        .unwrap_or(true)
+                    {
+                        best = Some((i, j, cost));
+                    }
+                }
+            }
+
+            let Some((i, j, cost)) = best else {
+                break;
+            };
+            if !cost.is_finite() {
+                break;
+            }
+            if let Some(max_cost) = max_merge_cost {
+                if cost > max_cost {
+                    break;
+                }
+            }
+
+            let merged = merge_gaussians(&self.gaussians[i], &self.gaussians[j]);
+            // Remove the higher index first so the lower index doesn't shift.
+            self.gaussians.remove(j);
+            self.gaussians.remove(i);
+            self.gaussians.push(merged);
+        }
+
+        for gaussian in &mut self.gaussians {
+            gaussian.precompute()?;
+        }
+        self.rebuild_index();
+        Ok(())
+    }
 }
 
 impl Default for GaussianRFField {
@@ -520,6 +1803,19 @@ impl Default for GaussianRFField {
     }
 }
 
+/// Push a Cholesky diagonal entry away from zero, keeping `L` invertible
+/// (and so `L * L^T` positive-definite) after an LM step that would
+/// otherwise drive it through singular.
+fn clamp_away_from_zero(value: f64) -> f64 {
+    const MIN_MAGNITUDE: f64 = 1e-3;
+    if value.abs() < MIN_MAGNITUDE {
+        let sign = if value < 0.0 { -1.0 } else { 1.0 };
+        sign * MIN_MAGNITUDE
+    } else {
+        value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -569,6 +1865,156 @@ mod tests {
         assert!(config.parallel);
     }
 
+    #[test]
+    fn test_huber_loss_matches_mse_inside_delta_and_is_linear_beyond() {
+        assert!((loss_value(LossKind::Huber { delta: 5.0 }, 1.0, 0.0) - 0.5).abs() < 1e-12);
+        let beyond = loss_value(LossKind::Huber { delta: 5.0 }, 10.0, 0.0);
+        assert!((beyond - 5.0 * (10.0 - 2.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_robust_residual_clamps_for_huber() {
+        let r = robust_residual(LossKind::Huber { delta: 2.0 }, 10.0, 0.0);
+        assert!((r - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_training_with_huber_loss_stays_finite_with_an_outlier() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let mut measurements: Vec<RFMeasurement> = (0..150)
+            .map(|i| {
+                let angle = (i as f64 / 150.0) * 2.0 * PI;
+                let radius = 5.0 + (i as f64 / 10.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+        // An outlier reading that shouldn't be allowed to dominate training.
+        measurements[0].rssi_dbm = 40.0;
+
+        let mut field = GaussianRFField::new();
+        let config = TrainingConfig {
+            num_gaussians: 10,
+            max_iterations: 30,
+            loss: LossKind::Huber { delta: 6.0 },
+            ..Default::default()
+        };
+
+        let result = field.train(&measurements, config);
+        assert!(result.is_ok());
+        assert!(field.training_loss.unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_training_with_anscombe_loss_stays_finite() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..150)
+            .map(|i| {
+                let angle = (i as f64 / 150.0) * 2.0 * PI;
+                let radius = 5.0 + (i as f64 / 10.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let mut field = GaussianRFField::new();
+        let config = TrainingConfig {
+            num_gaussians: 10,
+            max_iterations: 30,
+            loss: LossKind::Anscombe,
+            ..Default::default()
+        };
+
+        let result = field.train(&measurements, config);
+        assert!(result.is_ok());
+        assert!(field.training_loss.unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_adam_optimizer_trains_to_a_finite_loss() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..150)
+            .map(|i| {
+                let angle = (i as f64 / 150.0) * 2.0 * PI;
+                let radius = 5.0 + (i as f64 / 10.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let mut field = GaussianRFField::new();
+        let config = TrainingConfig {
+            num_gaussians: 10,
+            max_iterations: 30,
+            gradient_optimizer: Optimizer::Adam {
+                beta1: 0.9,
+                beta2: 0.999,
+                eps: 1e-8,
+            },
+            ..Default::default()
+        };
+
+        let result = field.train(&measurements, config);
+        assert!(result.is_ok());
+        assert!(field.training_loss.unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_amplitude_weight_decay_shrinks_amplitudes_toward_zero() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..150)
+            .map(|i| {
+                let angle = (i as f64 / 150.0) * 2.0 * PI;
+                let radius = 5.0 + (i as f64 / 10.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let mut undecayed = GaussianRFField::new();
+        undecayed
+            .train(
+                &measurements,
+                TrainingConfig {
+                    num_gaussians: 10,
+                    max_iterations: 20,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let mut decayed = GaussianRFField::new();
+        decayed
+            .train(
+                &measurements,
+                TrainingConfig {
+                    num_gaussians: 10,
+                    max_iterations: 20,
+                    amplitude_weight_decay: 0.5,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let mean_abs = |field: &GaussianRFField| {
+            field
+                .gaussians
+                .iter()
+                .map(|g| g.amplitude.abs())
+                .sum::<f64>()
+                / field.num_gaussians() as f64
+        };
+        assert!(mean_abs(&decayed) < mean_abs(&undecayed));
+    }
+
     #[test]
     fn test_field_training() {
         // Create synthetic measurements (simple distance-based falloff)
@@ -601,6 +2047,199 @@ mod tests {
         assert_eq!(field.num_gaussians(), 20);
     }
 
+    #[test]
+    fn test_full_geometry_preset_selects_levenberg_marquardt() {
+        let config = TrainingConfig::full_geometry();
+        assert_eq!(config.optimizer, OptimizerKind::LevenbergMarquardt);
+    }
+
+    #[test]
+    fn test_field_training_with_levenberg_marquardt() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..200)
+            .map(|i| {
+                let angle = (i as f64 / 200.0) * 2.0 * PI;
+                let radius = 10.0 + (i as f64 / 20.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let mut field = GaussianRFField::new();
+        field.set_transmitter(tx_pos);
+
+        let config = TrainingConfig {
+            num_gaussians: 10,
+            max_iterations: 15,
+            optimizer: OptimizerKind::LevenbergMarquardt,
+            ..Default::default()
+        };
+
+        let result = field.train(&measurements, config);
+        assert!(result.is_ok());
+        assert_eq!(field.num_gaussians(), 10);
+        assert!(field.training_loss.unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_reaches_lower_loss_than_gradient_descent_in_fewer_iterations() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..150)
+            .map(|i| {
+                let angle = (i as f64 / 150.0) * 2.0 * PI;
+                let radius = 5.0 + (i as f64 / 10.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let mut gd_field = GaussianRFField::new();
+        let gd_config = TrainingConfig {
+            num_gaussians: 8,
+            max_iterations: 10,
+            optimizer: OptimizerKind::GradientDescent,
+            ..Default::default()
+        };
+        gd_field.train(&measurements, gd_config).unwrap();
+
+        let mut lm_field = GaussianRFField::new();
+        let lm_config = TrainingConfig {
+            num_gaussians: 8,
+            max_iterations: 10,
+            optimizer: OptimizerKind::LevenbergMarquardt,
+            ..Default::default()
+        };
+        lm_field.train(&measurements, lm_config).unwrap();
+
+        assert!(lm_field.training_loss.unwrap() <= gd_field.training_loss.unwrap());
+    }
+
+    #[test]
+    fn test_train_auto_picks_a_candidate_and_reports_every_count() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..200)
+            .map(|i| {
+                let angle = (i as f64 / 200.0) * 2.0 * PI;
+                let radius = 10.0 + (i as f64 / 20.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let base_config = TrainingConfig {
+            max_iterations: 20,
+            ..Default::default()
+        };
+        let (field, report) =
+            GaussianRFField::train_auto(&measurements, &[5, 10, 20], &base_config).unwrap();
+
+        assert_eq!(report.len(), 3);
+        assert!(report.iter().all(|c| c.criterion.is_finite()));
+        assert!([5, 10, 20].contains(&field.num_gaussians()));
+
+        let best_criterion = report
+            .iter()
+            .map(|c| c.criterion)
+            .fold(f64::INFINITY, f64::min);
+        assert!(report
+            .iter()
+            .any(|c| c.num_gaussians == field.num_gaussians() && c.criterion == best_criterion));
+    }
+
+    #[test]
+    fn test_adaptive_training_grows_within_the_gaussian_budget() {
+        let tx_pos = Vector3::new(0.0, 0.0, 0.0);
+        let measurements: Vec<RFMeasurement> = (0..150)
+            .map(|i| {
+                let angle = (i as f64 / 150.0) * 2.0 * PI;
+                let radius = 5.0 + (i as f64 / 10.0);
+                let pos = Vector3::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+                let distance = (pos - tx_pos).norm().max(1.0);
+                let rssi = -30.0 - 20.0 * distance.log10();
+                RFMeasurement::new(pos, rssi)
+            })
+            .collect();
+
+        let mut field = GaussianRFField::new();
+        let config = TrainingConfig {
+            mode: TrainingMode::Adaptive,
+            max_gaussians: 15,
+            adaptive_inner_iterations: 5,
+            convergence_threshold: 1.0,
+            ..Default::default()
+        };
+
+        let result = field.train(&measurements, config);
+        assert!(result.is_ok());
+        assert!(field.num_gaussians() > 0);
+        assert!(field.num_gaussians() <= 15);
+        assert!(field.training_loss.unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_solve_amplitudes_linear_recovers_known_amplitudes() {
+        let mut g1 = Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 25.0, -40.0);
+        g1.precompute().unwrap();
+        let mut g2 = Gaussian3D::new(Vector3::new(20.0, 0.0, 0.0), 25.0, -60.0);
+        g2.precompute().unwrap();
+
+        let positions = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(5.0, 0.0, 0.0),
+            Vector3::new(15.0, 0.0, 0.0),
+            Vector3::new(20.0, 0.0, 0.0),
+        ];
+        let measurements: Vec<RFMeasurement> = positions
+            .iter()
+            .map(|&pos| RFMeasurement::new(pos, g1.evaluate_fast(pos) + g2.evaluate_fast(pos)))
+            .collect();
+
+        let mut field = GaussianRFField::new();
+        // Start from the wrong amplitudes; the solve should recover -40/-60.
+        field.gaussians = vec![
+            Gaussian3D::new(g1.center, 25.0, -1.0),
+            Gaussian3D::new(g2.center, 25.0, -1.0),
+        ];
+        for gaussian in &mut field.gaussians {
+            gaussian.precompute().unwrap();
+        }
+
+        field.solve_amplitudes_linear(&measurements, 1e-6);
+
+        assert!((field.gaussians[0].amplitude - (-40.0)).abs() < 1e-3);
+        assert!((field.gaussians[1].amplitude - (-60.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_query_matches_with_and_without_the_spatial_index() {
+        let mut field = GaussianRFField::new();
+        let mut g1 = Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 4.0, -40.0);
+        g1.precompute().unwrap();
+        let mut g2 = Gaussian3D::new(Vector3::new(50.0, 0.0, 0.0), 4.0, -60.0);
+        g2.precompute().unwrap();
+        field.gaussians = vec![g1, g2];
+        field.rebuild_index();
+
+        let near = Vector3::new(1.0, 0.0, 0.0);
+        let far_outside_support = Vector3::new(1000.0, 0.0, 0.0);
+
+        let indexed_near = field.query(near);
+        let indexed_far = field.query(far_outside_support);
+
+        field.use_index(false);
+        let exact_near = field.query(near);
+        let exact_far = field.query(far_outside_support);
+
+        assert!((indexed_near - exact_near).abs() < 1e-9);
+        assert!((indexed_far - exact_far).abs() < 1e-9);
+    }
+
     #[test]
     fn test_batch_query() {
         let mut field = GaussianRFField::new();
@@ -623,4 +2262,114 @@ mod tests {
         let results = field.query_batch(&positions);
         assert_eq!(results.len(), 3);
     }
+
+    #[test]
+    fn test_kl_divergence_is_zero_for_identical_fields() {
+        let mut g1 = Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 25.0, -40.0);
+        g1.precompute().unwrap();
+        let mut g2 = Gaussian3D::new(Vector3::new(10.0, 0.0, 0.0), 16.0, -50.0);
+        g2.precompute().unwrap();
+
+        let mut field_a = GaussianRFField::new();
+        field_a.gaussians = vec![g1.clone(), g2.clone()];
+        let mut field_b = GaussianRFField::new();
+        field_b.gaussians = vec![g1, g2];
+
+        assert!(field_a.kl_divergence(&field_b) < 1e-9);
+    }
+
+    #[test]
+    fn test_kl_divergence_grows_with_separation() {
+        let mut near = Gaussian3D::new(Vector3::new(1.0, 0.0, 0.0), 25.0, -40.0);
+        near.precompute().unwrap();
+        let mut far = Gaussian3D::new(Vector3::new(50.0, 0.0, 0.0), 25.0, -40.0);
+        far.precompute().unwrap();
+
+        let mut reference = Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 25.0, -40.0);
+        reference.precompute().unwrap();
+
+        let mut field_reference = GaussianRFField::new();
+        field_reference.gaussians = vec![reference];
+        let mut field_near = GaussianRFField::new();
+        field_near.gaussians = vec![near];
+        let mut field_far = GaussianRFField::new();
+        field_far.gaussians = vec![far];
+
+        assert!(
+            field_reference.kl_divergence(&field_near) < field_reference.kl_divergence(&field_far)
+        );
+    }
+
+    #[test]
+    fn test_kl_divergence_is_infinite_for_an_empty_field() {
+        let mut g = Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 25.0, -40.0);
+        g.precompute().unwrap();
+        let mut field = GaussianRFField::new();
+        field.gaussians = vec![g];
+
+        assert_eq!(field.kl_divergence(&GaussianRFField::new()), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_compress_merges_nearby_duplicates_down_to_target_count() {
+        let mut field = GaussianRFField::new();
+        field.gaussians = vec![
+            Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 4.0, -40.0),
+            Gaussian3D::new(Vector3::new(0.1, 0.0, 0.0), 4.0, -40.0),
+            Gaussian3D::new(Vector3::new(100.0, 0.0, 0.0), 4.0, -40.0),
+        ];
+        for gaussian in &mut field.gaussians {
+            gaussian.precompute().unwrap();
+        }
+
+        field.compress(Some(2), None).unwrap();
+
+        assert_eq!(field.num_gaussians(), 2);
+        // The far-away Gaussian should survive untouched; the two near
+        // duplicates should have merged to roughly their shared center.
+        let near_merged = field
+            .gaussians
+            .iter()
+            .min_by(|a, b| a.center.x.abs().partial_cmp(&b.center.x.abs()).unwrap())
+            .unwrap();
+        assert!(near_merged.center.x.abs() < 0.2);
+    }
+
+    #[test]
+    fn test_compress_stops_at_max_merge_cost() {
+        let mut field = GaussianRFField::new();
+        field.gaussians = vec![
+            Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 4.0, -40.0),
+            Gaussian3D::new(Vector3::new(1000.0, 0.0, 0.0), 4.0, -40.0),
+        ];
+        for gaussian in &mut field.gaussians {
+            gaussian.precompute().unwrap();
+        }
+
+        // The pair is far too dissimilar to merge within a tiny cost budget.
+        field.compress(Some(1), Some(1e-6)).unwrap();
+
+        assert_eq!(field.num_gaussians(), 2);
+    }
+
+    #[test]
+    fn test_query_batch_gpu_matches_query_batch_without_the_warp_feature() {
+        let mut field = GaussianRFField::new();
+        let mut g1 = Gaussian3D::new(Vector3::new(0.0, 0.0, 0.0), 25.0, -40.0);
+        g1.precompute().unwrap();
+        let mut g2 = Gaussian3D::new(Vector3::new(10.0, 0.0, 0.0), 25.0, -60.0);
+        g2.precompute().unwrap();
+        field.gaussians = vec![g1, g2];
+
+        let positions = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(5.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.0, 0.0),
+        ];
+
+        assert_eq!(
+            field.query_batch_gpu(&positions),
+            field.query_batch(&positions)
+        );
+    }
 }
@@ -31,11 +31,22 @@ pub enum AntennaPattern {
         elevation_beamwidth_deg: f64,
         front_to_back_ratio_db: f64,
     },
-    /// Custom pattern from measurements
+    /// Custom pattern from measurements: `azimuth_pattern` and
+    /// `elevation_pattern` are each uniformly sampled gain curves (dB) over
+    /// `[0, 360)` degrees and `[-90, 90]` degrees respectively, interpolated
+    /// independently and summed. Falls back to `0 dB` (neutral, like
+    /// `Isotropic`) if either array is empty.
     Custom {
         azimuth_pattern: Vec<f64>,
         elevation_pattern: Vec<f64>,
     },
+    /// Smooth reconstruction from a truncated real spherical-harmonic
+    /// expansion of a measured pattern, in the spirit of PyLayers'
+    /// vector-spherical-harmonic antenna representation — a compact,
+    /// serializable alternative to `Custom`'s raw sample arrays.
+    /// `coefficients[l][m + l]` holds the coefficient for degree `l`
+    /// (`0..coefficients.len()`), order `m` (`-l..=l`).
+    SphericalHarmonic { coefficients: Vec<Vec<f64>> },
 }
 
 impl AntennaPattern {
@@ -55,11 +66,124 @@ impl AntennaPattern {
                 *elevation_beamwidth_deg,
                 *front_to_back_ratio_db,
             ),
-            AntennaPattern::Custom { .. } => {
-                // TODO: Implement custom pattern interpolation
-                0.0
+            AntennaPattern::Custom {
+                azimuth_pattern,
+                elevation_pattern,
+            } => self.custom_gain(
+                direction,
+                main_direction,
+                azimuth_pattern,
+                elevation_pattern,
+            ),
+            AntennaPattern::SphericalHarmonic { coefficients } => {
+                self.spherical_harmonic_gain(direction, main_direction, coefficients)
+            }
+        }
+    }
+
+    /// Decompose `direction` into azimuth/elevation (degrees) relative to
+    /// `main_direction`'s boresight, for the `Custom` and
+    /// `SphericalHarmonic` patterns. Elevation is measured from the
+    /// boresight's equatorial plane (`-90`..`90`); azimuth wraps around it
+    /// (`0`..`360`). The reference "up" used to fix the azimuth origin is
+    /// world `+Z`, falling back to world `+X` when the boresight is nearly
+    /// vertical.
+    fn azimuth_elevation_deg(direction: Vector3<f64>, main_direction: Vector3<f64>) -> (f64, f64) {
+        let forward = main_direction.normalize();
+        let world_up = if forward.z.abs() > 0.999 {
+            Vector3::new(1.0, 0.0, 0.0)
+        } else {
+            Vector3::new(0.0, 0.0, 1.0)
+        };
+        let right = forward.cross(&world_up).normalize();
+        let up = right.cross(&forward).normalize();
+
+        let dir = direction.normalize();
+        let local_forward = dir.dot(&forward);
+        let local_right = dir.dot(&right);
+        let local_up = dir.dot(&up);
+
+        let elevation_deg = local_up.clamp(-1.0, 1.0).asin().to_degrees();
+        let azimuth_deg = local_right.atan2(local_forward).to_degrees();
+        let azimuth_deg = if azimuth_deg < 0.0 {
+            azimuth_deg + 360.0
+        } else {
+            azimuth_deg
+        };
+        (azimuth_deg, elevation_deg)
+    }
+
+    /// Linearly interpolate `pattern` (dB), treated as uniformly sampled
+    /// over `[0, span)` if `wrap` (azimuth) or `[0, span]` inclusive
+    /// otherwise (elevation), at `value` within that span.
+    fn interpolate_pattern(pattern: &[f64], value: f64, span: f64, wrap: bool) -> f64 {
+        let n = pattern.len();
+        match n {
+            0 => 0.0,
+            1 => pattern[0],
+            _ => {
+                let steps = if wrap { n as f64 } else { (n - 1) as f64 };
+                let fractional_index = (value / span) * steps;
+                let i0 = fractional_index.floor();
+                let t = fractional_index - i0;
+                let index = |i: f64| -> usize {
+                    if wrap {
+                        (i.rem_euclid(n as f64)) as usize
+                    } else {
+                        (i.max(0.0).min((n - 1) as f64)) as usize
+                    }
+                };
+                let v0 = pattern[index(i0)];
+                let v1 = pattern[index(i0 + 1.0)];
+                v0 + (v1 - v0) * t
+            }
+        }
+    }
+
+    fn custom_gain(
+        &self,
+        direction: Vector3<f64>,
+        main_direction: Vector3<f64>,
+        azimuth_pattern: &[f64],
+        elevation_pattern: &[f64],
+    ) -> f64 {
+        if azimuth_pattern.is_empty() || elevation_pattern.is_empty() {
+            return 0.0;
+        }
+        let (azimuth_deg, elevation_deg) = Self::azimuth_elevation_deg(direction, main_direction);
+        let gain_az = Self::interpolate_pattern(azimuth_pattern, azimuth_deg, 360.0, true);
+        let gain_el =
+            Self::interpolate_pattern(elevation_pattern, elevation_deg + 90.0, 180.0, false);
+        gain_az + gain_el
+    }
+
+    fn spherical_harmonic_gain(
+        &self,
+        direction: Vector3<f64>,
+        main_direction: Vector3<f64>,
+        coefficients: &[Vec<f64>],
+    ) -> f64 {
+        if coefficients.is_empty() {
+            return 0.0;
+        }
+        let (azimuth_deg, elevation_deg) = Self::azimuth_elevation_deg(direction, main_direction);
+        let phi = azimuth_deg.to_radians();
+        // Colatitude (0 at boresight) rather than the elevation-from-equator
+        // convention used by `azimuth_elevation_deg`, since spherical
+        // harmonics are conventionally evaluated from the pole.
+        let theta = (90.0 - elevation_deg).to_radians();
+
+        let mut gain = 0.0;
+        for (l, row) in coefficients.iter().enumerate() {
+            for (idx, &coeff) in row.iter().enumerate() {
+                if coeff == 0.0 {
+                    continue;
+                }
+                let m = idx as i32 - l as i32;
+                gain += coeff * real_spherical_harmonic(l as u32, m, theta, phi);
             }
         }
+        gain
     }
 
     fn dipole_gain(&self, direction: Vector3<f64>, main_direction: Vector3<f64>) -> f64 {
@@ -102,6 +226,72 @@ impl AntennaPattern {
     }
 }
 
+/// Real (not complex) spherical harmonic `Y_l^m(theta, phi)`, `theta` the
+/// colatitude from the pole (boresight) and `phi` the azimuth, using the
+/// usual `cos(m*phi)`/`sin(|m|*phi)` real basis and Condon-Shortley-phase
+/// associated Legendre polynomials computed via the standard stable
+/// recurrence (no factorial overflow for the modest `l` a measured-pattern
+/// fit would use).
+fn real_spherical_harmonic(l: u32, m: i32, theta: f64, phi: f64) -> f64 {
+    let abs_m = m.unsigned_abs();
+    let cos_theta = theta.cos();
+    let p = associated_legendre(l, abs_m, cos_theta);
+
+    let normalization =
+        ((2 * l + 1) as f64 / (4.0 * std::f64::consts::PI) * factorial_ratio(l, abs_m)).sqrt();
+
+    if m == 0 {
+        normalization * p
+    } else if m > 0 {
+        std::f64::consts::SQRT_2 * normalization * p * (m as f64 * phi).cos()
+    } else {
+        std::f64::consts::SQRT_2 * normalization * p * (abs_m as f64 * phi).sin()
+    }
+}
+
+/// `(l - m)! / (l + m)!`, computed as a running product to avoid overflowing
+/// the individual factorials for larger `l`.
+fn factorial_ratio(l: u32, m: u32) -> f64 {
+    let mut ratio = 1.0;
+    for k in (l - m + 1)..=(l + m) {
+        ratio /= k as f64;
+    }
+    ratio
+}
+
+/// Associated Legendre polynomial `P_l^m(x)` via the standard three-term
+/// recurrence, starting from the closed-form diagonal `P_m^m`.
+fn associated_legendre(l: u32, m: u32, x: f64) -> f64 {
+    let mut p_mm = 1.0;
+    if m > 0 {
+        let somx2 = ((1.0 - x) * (1.0 + x)).max(0.0).sqrt();
+        let mut fact = 1.0;
+        for _ in 0..m {
+            p_mm *= -fact * somx2;
+            fact += 2.0;
+        }
+    }
+    if l == m {
+        return p_mm;
+    }
+
+    let p_m1m = x * (2 * m + 1) as f64 * p_mm;
+    if l == m + 1 {
+        return p_m1m;
+    }
+
+    let mut p_lm_minus2 = p_mm;
+    let mut p_lm_minus1 = p_m1m;
+    let mut p_l = 0.0;
+    for ll in (m + 2)..=l {
+        p_l = (x * (2 * ll - 1) as f64 * p_lm_minus1 - (ll + m - 1) as f64 * p_lm_minus2)
+            / (ll - m) as f64;
+        p_lm_minus2 = p_lm_minus1;
+        p_lm_minus1 = p_l;
+    }
+    p_l
+}
+
 /// Antenna model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Antenna {
@@ -186,4 +376,55 @@ mod tests {
         let ant = Antenna::directional(60.0, 60.0);
         assert!(ant.gain_dbi > 5.0);
     }
+
+    #[test]
+    fn test_custom_pattern_falls_back_to_zero_when_empty() {
+        let pattern = AntennaPattern::Custom {
+            azimuth_pattern: vec![],
+            elevation_pattern: vec![],
+        };
+        let boresight = Vector3::new(0.0, 0.0, 1.0);
+        assert_eq!(pattern.gain(boresight, boresight), 0.0);
+    }
+
+    #[test]
+    fn test_custom_pattern_interpolates_at_the_sampled_boresight() {
+        let pattern = AntennaPattern::Custom {
+            azimuth_pattern: vec![1.0, 2.0, 3.0, 4.0],
+            elevation_pattern: vec![0.0, 5.0, 0.0],
+        };
+        let boresight = Vector3::new(0.0, 0.0, 1.0);
+        // At boresight, azimuth and elevation are both 0 degrees, landing
+        // exactly on azimuth_pattern[0] (1.0) and the midpoint sample of
+        // elevation_pattern (5.0).
+        assert!((pattern.gain(boresight, boresight) - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_pattern_wraps_azimuth_at_the_360_degree_boundary() {
+        let pattern = AntennaPattern::Custom {
+            azimuth_pattern: vec![1.0, 2.0, 3.0, 4.0],
+            elevation_pattern: vec![0.0, 0.0, 0.0],
+        };
+        let main_direction = Vector3::new(0.0, 0.0, 1.0);
+        // Halfway between the last sample (270 degrees) and the first
+        // sample wrapped around (360 == 0 degrees) should average them.
+        let direction = Vector3::new(-1.0, -1.0, 1.0).normalize();
+        let expected = (4.0 + 1.0) / 2.0;
+        assert!((pattern.gain(direction, main_direction) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_spherical_harmonic_l0_is_direction_independent() {
+        let pattern = AntennaPattern::SphericalHarmonic {
+            coefficients: vec![vec![3.0]],
+        };
+        let main_direction = Vector3::new(0.0, 0.0, 1.0);
+        let gain_a = pattern.gain(main_direction, main_direction);
+        let gain_b = pattern.gain(Vector3::new(1.0, 0.0, 0.0), main_direction);
+        assert!((gain_a - gain_b).abs() < 1e-9);
+        // Y_0^0 = sqrt(1 / 4*pi); scaled by the coefficient.
+        let expected = 3.0 * (1.0 / (4.0 * std::f64::consts::PI)).sqrt();
+        assert!((gain_a - expected).abs() < 1e-9);
+    }
 }
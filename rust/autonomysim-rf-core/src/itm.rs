@@ -0,0 +1,543 @@
+//! Terrain-driven Longley-Rice (ITM) point-to-point path-loss predictor
+//!
+//! `itm_path_loss` used to be a one-line toy tacked onto log-distance. This
+//! module instead samples a real elevation profile between transmitter and
+//! receiver by ray-marching the scene backend, derives the terrain
+//! irregularity and effective antenna heights/horizon geometry the ITS
+//! "Longley-Rice" model uses, and combines line-of-sight, diffraction, and
+//! troposcatter regimes with a reliability-based variability adjustment.
+//!
+//! This is a structured simplification of the full ITM reference
+//! implementation (which spans large empirical coefficient tables) rather
+//! than a byte-for-byte port, but follows the same named stages so its
+//! behavior (terrain roughness raising loss, reliability widening the
+//! confidence interval, climate/polarization shifting ground reflection)
+//! matches the real model's shape.
+
+use crate::models::KnifeEdgeDiffraction;
+use crate::propagation::{RFError, RFResult};
+use autonomysim_core::backend::{Position, Ray, SceneHandle, SimulationBackend};
+use nalgebra::{Point3, Vector3};
+use serde::{Deserialize, Serialize};
+
+/// ITU/ITS climate codes, each implying a different tropospheric
+/// refractivity gradient and hence troposcatter behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Climate {
+    Equatorial,
+    ContinentalSubtropical,
+    MaritimeSubtropical,
+    Desert,
+    ContinentalTemperate,
+    MaritimeTemperateOverLand,
+    MaritimeTemperateOverSea,
+}
+
+impl Climate {
+    /// Surface refractivity gradient scale factor used by the simplified
+    /// troposcatter term below.
+    fn refractivity_factor(&self) -> f64 {
+        match self {
+            Climate::Equatorial => 1.3,
+            Climate::ContinentalSubtropical => 1.2,
+            Climate::MaritimeSubtropical => 1.15,
+            Climate::Desert => 0.9,
+            Climate::ContinentalTemperate => 1.0,
+            Climate::MaritimeTemperateOverLand => 1.05,
+            Climate::MaritimeTemperateOverSea => 1.1,
+        }
+    }
+}
+
+/// Antenna polarization, affecting the ground-reflection coefficient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Polarization {
+    Horizontal,
+    Vertical,
+}
+
+/// Median path loss plus a confidence interval around it, covering the
+/// requested time/location/situation reliability fractions.
+#[derive(Debug, Clone, Copy)]
+pub struct ItmResult {
+    pub median_loss_db: f64,
+    pub confidence_lower_db: f64,
+    pub confidence_upper_db: f64,
+}
+
+/// Ray-march from `tx` to `rx`, sampling `samples` evenly spaced ground
+/// elevations via a downward `cast_ray` at each point. Points with no
+/// ground hit default to elevation `0.0`.
+pub fn sample_terrain_profile(
+    backend: &dyn SimulationBackend,
+    scene: &SceneHandle,
+    tx: Position,
+    rx: Position,
+    samples: usize,
+) -> RFResult<Vec<f64>> {
+    if samples < 2 {
+        return Err(RFError::ComputationError(
+            "terrain profile requires at least 2 samples".to_string(),
+        ));
+    }
+
+    const PROBE_HEIGHT: f64 = 10_000.0;
+    let mut profile = Vec::with_capacity(samples);
+
+    for i in 0..samples {
+        let t = i as f64 / (samples - 1) as f64;
+        let x = tx.x + (rx.x - tx.x) * t;
+        let y = tx.y + (rx.y - tx.y) * t;
+
+        let ray = Ray {
+            origin: Point3::new(x, y, PROBE_HEIGHT),
+            direction: Vector3::new(0.0, 0.0, -1.0),
+            max_distance: PROBE_HEIGHT * 2.0,
+        };
+
+        let elevation = backend
+            .cast_ray(scene, &ray)?
+            .map(|hit| hit.position.z)
+            .unwrap_or(0.0);
+        profile.push(elevation);
+    }
+
+    Ok(profile)
+}
+
+/// Interdecile range of the terrain profile (difference between the 90th
+/// and 10th percentile elevations), the irregularity parameter `dh` the
+/// Longley-Rice model conditions its variability on.
+pub fn interdecile_range(profile: &[f64]) -> f64 {
+    let mut sorted = profile.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = sorted.len();
+    let idx = |fraction: f64| -> f64 {
+        let pos = fraction * (n - 1) as f64;
+        let lower = pos.floor() as usize;
+        let upper = pos.ceil() as usize;
+        if lower == upper {
+            sorted[lower]
+        } else {
+            let frac = pos - lower as f64;
+            sorted[lower] * (1.0 - frac) + sorted[upper] * frac
+        }
+    };
+
+    (idx(0.9) - idx(0.1)).abs()
+}
+
+/// Effective antenna heights above the average terrain elevation, clamped
+/// to at least 1m to avoid degenerate (non-positive) horizon geometry.
+pub fn effective_heights(tx: Position, rx: Position, profile: &[f64]) -> (f64, f64) {
+    let average_terrain: f64 = profile.iter().sum::<f64>() / profile.len() as f64;
+    let ht = (tx.z - average_terrain).max(1.0);
+    let hr = (rx.z - average_terrain).max(1.0);
+    (ht, hr)
+}
+
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+const KE: f64 = 4.0 / 3.0;
+
+/// Smooth-earth radio horizon distance for an antenna at effective height
+/// `h`, using effective earth radius `ke * a` (the standard `ke = 4/3`
+/// refraction correction).
+fn horizon_distance(h: f64) -> f64 {
+    (2.0 * KE * EARTH_RADIUS_M * h).sqrt()
+}
+
+/// Distance to, and elevation angle of, the terrain point (real or
+/// smooth-earth) that limits an antenna's radio horizon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizonGeometry {
+    pub distance_m: f64,
+    pub elevation_angle_rad: f64,
+}
+
+/// Scan the interior of `profile` (excluding the antenna endpoints) for the
+/// terrain point with the largest elevation angle as seen from an antenna
+/// at height `antenna_z` on a path of length `path_distance` -- the actual
+/// Longley-Rice horizon search, rather than the pure [`horizon_distance`]
+/// smooth-earth formula it falls back to when nothing in the profile
+/// actually obstructs the path. Elevation angle includes the standard `ke =
+/// 4/3` earth-curvature correction (`-distance / (2 * ke * a)`), so a
+/// profile point only counts as a horizon obstacle if it pokes up above
+/// where the curved earth itself would put the skyline. `from_rx` scans the
+/// same profile measuring distance from the far end instead of the near
+/// one, for the receiver's side of the same path.
+pub fn scan_horizon(
+    profile: &[f64],
+    antenna_z: f64,
+    path_distance: f64,
+    from_rx: bool,
+) -> HorizonGeometry {
+    let smooth_earth_fallback = HorizonGeometry {
+        distance_m: path_distance.max(1.0),
+        elevation_angle_rad: f64::NEG_INFINITY,
+    };
+
+    let n = profile.len();
+    if n < 3 || path_distance <= 0.0 {
+        return smooth_earth_fallback;
+    }
+
+    let mut best = smooth_earth_fallback;
+
+    for (i, &elevation) in profile.iter().enumerate().take(n - 1).skip(1) {
+        let distance_from_tx = (i as f64 / (n - 1) as f64) * path_distance;
+        let distance = if from_rx {
+            path_distance - distance_from_tx
+        } else {
+            distance_from_tx
+        };
+        if distance <= 1.0 {
+            continue;
+        }
+
+        let angle = (elevation - antenna_z) / distance - distance / (2.0 * KE * EARTH_RADIUS_M);
+        if angle > best.elevation_angle_rad {
+            best = HorizonGeometry {
+                distance_m: distance,
+                elevation_angle_rad: angle,
+            };
+        }
+    }
+
+    best
+}
+
+/// The horizon distance actually limiting this antenna's view along
+/// `profile`: the scanned terrain obstacle's distance when
+/// [`scan_horizon`] finds one that pokes up above the curved-earth skyline
+/// (`elevation_angle_rad > 0`), otherwise the plain smooth-earth
+/// [`horizon_distance`] for its effective height.
+fn effective_horizon_distance(
+    profile: &[f64],
+    antenna_z: f64,
+    effective_height: f64,
+    path_distance: f64,
+    from_rx: bool,
+) -> f64 {
+    let smooth_earth = horizon_distance(effective_height);
+    let scanned = scan_horizon(profile, antenna_z, path_distance, from_rx);
+    if scanned.elevation_angle_rad > 0.0 {
+        scanned.distance_m.min(smooth_earth)
+    } else {
+        smooth_earth
+    }
+}
+
+/// Inverse standard-normal CDF (Acklam's rational approximation), used to
+/// turn a reliability fraction into the number of standard deviations of
+/// variability margin to apply.
+fn inverse_normal_cdf(p: f64) -> f64 {
+    let p = p.clamp(1e-6, 1.0 - 1e-6);
+
+    // Acklam's approximation coefficients.
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e+01,
+        2.209_460_984_245_205e+02,
+        -2.759_285_104_469_687e+02,
+        1.383_577_518_672_690e+02,
+        -3.066_479_806_614_716e+01,
+        2.506_628_277_459_239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e+01,
+        1.615_858_368_580_409e+02,
+        -1.556_989_798_598_866e+02,
+        6.680_131_188_771_972e+01,
+        -1.328_068_155_288_572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-03,
+        -3.223_964_580_411_365e-01,
+        -2.400_758_277_161_838e+00,
+        -2.549_732_539_343_734e+00,
+        4.374_664_141_464_968e+00,
+        2.938_163_982_698_783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-03,
+        3.224_671_290_700_398e-01,
+        2.445_134_137_142_996e+00,
+        3.754_408_661_907_416e+00,
+    ];
+
+    const P_LOW: f64 = 0.02425;
+    let p_high = 1.0 - P_LOW;
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= p_high {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    }
+}
+
+/// Free-space loss used as the baseline for every regime below.
+fn free_space_loss_db(distance: f64, wavelength: f64) -> f64 {
+    20.0 * (4.0 * std::f64::consts::PI * distance / wavelength).log10()
+}
+
+/// Run the point-to-point predictor: derive terrain irregularity and
+/// horizon geometry from `profile`, pick the line-of-sight, diffraction, or
+/// troposcatter regime, and apply a reliability-based variability margin.
+#[allow(clippy::too_many_arguments)]
+pub fn compute_itm_path_loss(
+    profile: &[f64],
+    tx: Position,
+    rx: Position,
+    wavelength: f64,
+    ground_conductivity: f64,
+    ground_permittivity: f64,
+    polarization: Polarization,
+    climate: Climate,
+    time_reliability: f64,
+    location_reliability: f64,
+    situation_reliability: f64,
+) -> ItmResult {
+    let distance = (tx - rx).norm();
+    let dh = interdecile_range(profile);
+    let (ht, hr) = effective_heights(tx, rx, profile);
+
+    let d_lt = effective_horizon_distance(profile, tx.z, ht, distance, false);
+    let d_lr = effective_horizon_distance(profile, rx.z, hr, distance, true);
+    let los_limit = d_lt + d_lr;
+
+    let free_space = free_space_loss_db(distance, wavelength);
+    let frequency_hz = crate::constants::SPEED_OF_LIGHT / wavelength;
+
+    let base_loss = if distance <= los_limit {
+        // Line-of-sight: free space plus a ground-reflection correction
+        // whose strength depends on conductivity/permittivity/polarization
+        // via the Fresnel coefficients already used for ray tracing.
+        let grazing_angle = ((ht + hr) / distance.max(1.0)).atan();
+        let reflection = match polarization {
+            Polarization::Horizontal => crate::models::FresnelCalculator::reflection_perpendicular(
+                grazing_angle,
+                ground_permittivity,
+                ground_conductivity,
+                frequency_hz,
+            ),
+            Polarization::Vertical => crate::models::FresnelCalculator::reflection_parallel(
+                grazing_angle,
+                ground_permittivity,
+                ground_conductivity,
+                frequency_hz,
+            ),
+        };
+        // A near-total reflection (|Γ| -> 1) causes deep interference
+        // nulls; approximate the extra loss via the reflection magnitude.
+        free_space + 6.0 * reflection
+    } else {
+        // Beyond the radio horizon: knife-edge diffraction over the
+        // terrain's interdecile roughness, using the horizon distances as
+        // the two diffraction legs.
+        free_space + KnifeEdgeDiffraction::calculate_loss(dh.max(1.0), d_lt, d_lr, wavelength)
+    };
+
+    // Troposcatter becomes competitive at long range; take whichever
+    // mechanism implies the lower loss, as the real model does when modes
+    // overlap.
+    let troposcatter = free_space
+        + 0.2 * (distance / 1000.0)
+        + 10.0 * climate.refractivity_factor().log10().abs().max(0.01);
+    let combined = base_loss.min(troposcatter);
+
+    // Variability: standard deviation grows with terrain roughness, and the
+    // reliability fractions set how many standard deviations of margin to
+    // carry (matching the direction that a *higher* reliability fraction
+    // should report *more* conservative, i.e. higher, loss).
+    let sigma = 2.0 + dh.sqrt() * 0.5;
+    let combined_reliability =
+        (time_reliability * location_reliability * situation_reliability).clamp(1e-3, 1.0 - 1e-6);
+    let z = inverse_normal_cdf(combined_reliability);
+
+    ItmResult {
+        median_loss_db: combined,
+        confidence_lower_db: combined - z.abs() * sigma,
+        confidence_upper_db: combined + z.abs() * sigma,
+    }
+}
+
+/// Ground/climate inputs for [`compute_path_loss_itm`], bundled the way a
+/// point-to-point caller naturally has them on hand (e.g. pulled straight
+/// off [`crate::propagation::PropagationConfig`]) instead of passed as the
+/// five trailing positional arguments [`compute_itm_path_loss`] takes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TerrainParams {
+    pub ground_conductivity: f64,
+    pub ground_permittivity: f64,
+    pub polarization: Polarization,
+    pub climate: Climate,
+}
+
+/// Point-to-point ITM path loss: the same terrain-profile-driven regime
+/// selection as [`compute_itm_path_loss`], but grouping the ground/climate
+/// inputs into [`TerrainParams`] and taking a single `confidence_percent`
+/// (0-100) in place of three separate reliability fractions, for callers
+/// that want "the loss at the X% confidence level" rather than a
+/// three-axis time/location/situation reliability product. Returns the
+/// median loss alongside the lower/upper variability quantiles implied by
+/// that confidence level.
+pub fn compute_path_loss_itm(
+    tx: Position,
+    rx: Position,
+    profile: &[f64],
+    wavelength: f64,
+    terrain_params: &TerrainParams,
+    confidence_percent: f64,
+) -> ItmResult {
+    let reliability = (confidence_percent / 100.0).clamp(0.0, 1.0);
+    compute_itm_path_loss(
+        profile,
+        tx,
+        rx,
+        wavelength,
+        terrain_params.ground_conductivity,
+        terrain_params.ground_permittivity,
+        terrain_params.polarization,
+        terrain_params.climate,
+        reliability,
+        reliability,
+        reliability,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_terrain_has_zero_interdecile_range() {
+        let profile = vec![10.0; 20];
+        assert_eq!(interdecile_range(&profile), 0.0);
+    }
+
+    #[test]
+    fn rough_terrain_has_larger_interdecile_range_than_flat() {
+        let flat = vec![10.0; 50];
+        let rough: Vec<f64> = (0..50).map(|i| 10.0 + (i as f64 % 7.0) * 5.0).collect();
+        assert!(interdecile_range(&rough) > interdecile_range(&flat));
+    }
+
+    #[test]
+    fn effective_heights_are_clamped_above_local_terrain() {
+        let tx = Point3::new(0.0, 0.0, 105.0);
+        let rx = Point3::new(1000.0, 0.0, 110.0);
+        let profile = vec![100.0; 10];
+
+        let (ht, hr) = effective_heights(tx, rx, &profile);
+        assert!((ht - 5.0).abs() < 1e-9);
+        assert!((hr - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse_normal_cdf_is_symmetric_about_median() {
+        let z_low = inverse_normal_cdf(0.1);
+        let z_high = inverse_normal_cdf(0.9);
+        assert!((z_low + z_high).abs() < 1e-3);
+    }
+
+    #[test]
+    fn higher_reliability_widens_the_confidence_margin() {
+        let profile: Vec<f64> = (0..32).map(|i| (i as f64 % 5.0) * 3.0).collect();
+        let tx = Point3::new(0.0, 0.0, 30.0);
+        let rx = Point3::new(5000.0, 0.0, 30.0);
+
+        let low_reliability = compute_itm_path_loss(
+            &profile,
+            tx,
+            rx,
+            0.125,
+            0.005,
+            15.0,
+            Polarization::Vertical,
+            Climate::ContinentalTemperate,
+            0.5,
+            0.5,
+            0.5,
+        );
+        let high_reliability = compute_itm_path_loss(
+            &profile,
+            tx,
+            rx,
+            0.125,
+            0.005,
+            15.0,
+            Polarization::Vertical,
+            Climate::ContinentalTemperate,
+            0.95,
+            0.95,
+            0.95,
+        );
+
+        let low_width = low_reliability.confidence_upper_db - low_reliability.confidence_lower_db;
+        let high_width =
+            high_reliability.confidence_upper_db - high_reliability.confidence_lower_db;
+        assert!(high_width >= low_width);
+    }
+
+    #[test]
+    fn scan_horizon_finds_no_obstacle_over_flat_terrain() {
+        let profile = vec![0.0; 20];
+        let horizon = scan_horizon(&profile, 30.0, 5000.0, false);
+        assert!(horizon.elevation_angle_rad <= 0.0);
+    }
+
+    #[test]
+    fn scan_horizon_finds_a_mountain_poking_above_the_curved_earth_skyline() {
+        let mut profile = vec![0.0; 20];
+        profile[10] = 500.0;
+        let horizon = scan_horizon(&profile, 10.0, 5000.0, false);
+        assert!(horizon.elevation_angle_rad > 0.0);
+        assert!((horizon.distance_m - 2500.0).abs() < 300.0);
+    }
+
+    #[test]
+    fn a_mountain_shrinks_the_radio_horizon_used_by_compute_itm_path_loss() {
+        let tx = Point3::new(0.0, 0.0, 30.0);
+        let rx = Point3::new(20_000.0, 0.0, 30.0);
+        let flat_profile = vec![0.0; 40];
+        let mut blocked_profile = flat_profile.clone();
+        blocked_profile[20] = 500.0;
+
+        let terrain_params = TerrainParams {
+            ground_conductivity: 0.005,
+            ground_permittivity: 15.0,
+            polarization: Polarization::Vertical,
+            climate: Climate::ContinentalTemperate,
+        };
+
+        let open = compute_path_loss_itm(tx, rx, &flat_profile, 0.125, &terrain_params, 50.0);
+        let blocked = compute_path_loss_itm(tx, rx, &blocked_profile, 0.125, &terrain_params, 50.0);
+        assert!(blocked.median_loss_db >= open.median_loss_db);
+    }
+
+    #[test]
+    fn compute_path_loss_itm_median_matches_the_fifty_percent_confidence_level() {
+        let profile: Vec<f64> = (0..32).map(|i| (i as f64 % 5.0) * 3.0).collect();
+        let tx = Point3::new(0.0, 0.0, 30.0);
+        let rx = Point3::new(5000.0, 0.0, 30.0);
+        let terrain_params = TerrainParams {
+            ground_conductivity: 0.005,
+            ground_permittivity: 15.0,
+            polarization: Polarization::Vertical,
+            climate: Climate::ContinentalTemperate,
+        };
+
+        let result = compute_path_loss_itm(tx, rx, &profile, 0.125, &terrain_params, 50.0);
+        assert!((result.confidence_lower_db - result.median_loss_db).abs() < 1e-6);
+        assert!((result.confidence_upper_db - result.median_loss_db).abs() < 1e-6);
+    }
+}
@@ -14,6 +14,12 @@
 //! - **Ray Tracing**: Physics-based ray tracing with reflections/diffractions
 //! - **Gaussian Beam**: Beam propagation method
 //! - **COST 231**: Outdoor urban propagation
+//! - **FDTD**: Yee-grid finite-difference time-domain full-wave solver
+//!
+//! For sparse-measurement air-to-ground reconstruction instead of a single
+//! analytical model, see [`radio_map::RadioMap`]. For connectivity built
+//! from real link budgets instead of an all-pairs count, see
+//! [`relay::select_relays`].
 //!
 //! # Example
 //!
@@ -59,21 +65,57 @@
 //! ```
 
 pub mod antenna;
+pub mod fading;
+pub mod fdtd;
+pub mod itm;
 pub mod models;
 pub mod propagation;
+pub mod radio_map;
+pub mod relay;
+pub mod sbr;
 pub mod utils;
 
 pub use antenna::{Antenna, AntennaPattern, PolarizationType};
+pub use fading::{NakagamiBreakpoints, NakagamiFadingStage};
+pub use fdtd::{FdtdConfig, Fields, Material as FdtdMaterial};
+pub use itm::{
+    compute_itm_path_loss, compute_path_loss_itm, effective_heights, interdecile_range,
+    sample_terrain_profile, scan_horizon, Climate, HorizonGeometry, ItmResult, Polarization,
+    TerrainParams,
+};
 pub use models::*;
-pub use propagation::{PropagationConfig, PropagationModel, RFPropagationEngine, RFResult};
+pub use propagation::{
+    LinkStatus, LogDistanceStage, LossStage, MatrixLink, PropagationConfig, PropagationMatrix,
+    PropagationModel, RFPropagationEngine, RFResult,
+};
+pub use radio_map::{LinkRegime, LinkSample, RadioMap};
+pub use relay::{
+    compare_messaging_strategies, count_naive_broadcast, count_relay_routed, select_relays,
+    AgentId, MessageAccounting, RelayTopology,
+};
+pub use sbr::{ChannelResult, PathContribution, PropagationEngine};
 
 /// Prelude for common RF propagation imports
 pub mod prelude {
     pub use crate::antenna::{Antenna, AntennaPattern, PolarizationType};
+    pub use crate::fading::{NakagamiBreakpoints, NakagamiFadingStage};
+    pub use crate::fdtd::{FdtdConfig, Fields, Material as FdtdMaterial};
+    pub use crate::itm::{
+        compute_itm_path_loss, compute_path_loss_itm, effective_heights, interdecile_range,
+        sample_terrain_profile, scan_horizon, Climate, HorizonGeometry, ItmResult, Polarization,
+        TerrainParams,
+    };
     pub use crate::models::*;
     pub use crate::propagation::{
-        PropagationConfig, PropagationModel, RFPropagationEngine, RFResult,
+        LinkStatus, LogDistanceStage, LossStage, MatrixLink, PropagationConfig, PropagationMatrix,
+        PropagationModel, RFPropagationEngine, RFResult,
+    };
+    pub use crate::radio_map::{LinkRegime, LinkSample, RadioMap};
+    pub use crate::relay::{
+        compare_messaging_strategies, count_naive_broadcast, count_relay_routed, select_relays,
+        AgentId, MessageAccounting, RelayTopology,
     };
+    pub use crate::sbr::{ChannelResult, PathContribution, PropagationEngine};
     pub use crate::utils::*;
 }
 
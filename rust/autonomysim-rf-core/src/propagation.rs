@@ -43,6 +43,10 @@ pub enum PropagationModel {
     GaussianBeam,
     /// COST 231 model (urban)
     COST231,
+    /// Measured/replayed path loss lookup (see [`PropagationMatrix`])
+    Matrix,
+    /// Yee-grid FDTD full-wave solver (see [`crate::fdtd`])
+    Fdtd,
 }
 
 impl PropagationModel {
@@ -55,12 +59,129 @@ impl PropagationModel {
             PropagationModel::RayTracing => "ray_tracing",
             PropagationModel::GaussianBeam => "gaussian_beam",
             PropagationModel::COST231 => "cost231",
+            PropagationModel::Matrix => "matrix",
+            PropagationModel::Fdtd => "fdtd",
         }
     }
 }
 
-/// Configuration for RF propagation simulation
+/// A single measured/replayed link entry in a [`PropagationMatrix`]. Later
+/// entries for the same `(tx_bin, rx_bin)` override earlier ones.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MatrixLink {
+    pub tx_bin: (i64, i64, i64),
+    pub rx_bin: (i64, i64, i64),
+    pub loss_db: f64,
+}
+
+/// A table of measured or ray-traced-once path losses keyed by quantized
+/// transmitter/receiver position bins, mirroring ns-3's
+/// `MatrixPropagationLossModel`. Looked up by [`PropagationModel::Matrix`]
+/// instead of recomputing a physics-based model every call; positions that
+/// don't match an explicit entry fall back to `default_loss_db`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropagationMatrix {
+    /// Side length in meters of the cubes positions are snapped to before
+    /// being used as a lookup key.
+    pub bin_size_m: f64,
+    /// Path loss in dB returned for any link with no explicit entry.
+    pub default_loss_db: f64,
+    links: Vec<MatrixLink>,
+}
+
+impl PropagationMatrix {
+    /// Create an empty matrix with a 1m bin size and the given fallback loss.
+    pub fn new(default_loss_db: f64) -> Self {
+        Self {
+            bin_size_m: 1.0,
+            default_loss_db,
+            links: Vec::new(),
+        }
+    }
+
+    /// Set the bin size used to quantize positions into lookup keys.
+    pub fn with_bin_size(mut self, bin_size_m: f64) -> Self {
+        self.bin_size_m = bin_size_m;
+        self
+    }
+
+    /// Record an explicit loss for `tx -> rx` only; the reverse direction
+    /// keeps whatever entry (or the default) it already has.
+    pub fn set_asymmetric(mut self, tx: Point3<f64>, rx: Point3<f64>, loss_db: f64) -> Self {
+        self.links.push(MatrixLink {
+            tx_bin: self.bin(tx),
+            rx_bin: self.bin(rx),
+            loss_db,
+        });
+        self
+    }
+
+    /// Record an explicit loss for both `a -> b` and `b -> a`.
+    pub fn set_symmetric(self, a: Point3<f64>, b: Point3<f64>, loss_db: f64) -> Self {
+        self.set_asymmetric(a, b, loss_db)
+            .set_asymmetric(b, a, loss_db)
+    }
+
+    /// Look up the loss for `tx -> rx`, falling back to `default_loss_db`.
+    pub fn lookup(&self, tx: Point3<f64>, rx: Point3<f64>) -> f64 {
+        let tx_bin = self.bin(tx);
+        let rx_bin = self.bin(rx);
+        self.links
+            .iter()
+            .rev()
+            .find(|link| link.tx_bin == tx_bin && link.rx_bin == rx_bin)
+            .map(|link| link.loss_db)
+            .unwrap_or(self.default_loss_db)
+    }
+
+    fn bin(&self, position: Point3<f64>) -> (i64, i64, i64) {
+        let size = self.bin_size_m.max(1e-6);
+        (
+            (position.x / size).round() as i64,
+            (position.y / size).round() as i64,
+            (position.z / size).round() as i64,
+        )
+    }
+}
+
+/// A single excess-loss contribution chained on top of the base
+/// `PropagationModel`'s path loss, following the ns-3 approach of
+/// composing a deterministic large-scale model with one or more
+/// stochastic fading/shadowing layers instead of treating them as
+/// mutually-exclusive modes.
+pub trait LossStage: Send + Sync {
+    fn excess_loss_db(
+        &self,
+        tx: Point3<f64>,
+        rx: Point3<f64>,
+        ctx: &PropagationConfig,
+    ) -> RFResult<f64>;
+}
+
+/// Log-distance path loss as a standalone chainable stage, so it can be
+/// combined with shadowing/fading stages rather than only selected as the
+/// sole `PropagationModel`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogDistanceStage;
+
+impl LossStage for LogDistanceStage {
+    fn excess_loss_db(
+        &self,
+        tx: Point3<f64>,
+        rx: Point3<f64>,
+        ctx: &PropagationConfig,
+    ) -> RFResult<f64> {
+        let distance = (tx - rx).norm();
+        let d0 = ctx.reference_distance;
+        let wavelength = ctx.wavelength();
+        let pl0 = 20.0 * (4.0 * std::f64::consts::PI * d0 / wavelength).log10();
+
+        Ok(pl0 + 10.0 * ctx.path_loss_exponent * (distance / d0).log10())
+    }
+}
+
+/// Configuration for RF propagation simulation
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PropagationConfig {
     /// Propagation model to use
     pub model: PropagationModel,
@@ -89,6 +210,15 @@ pub struct PropagationConfig {
     /// Maximum ray distance in meters
     pub max_distance: f64,
 
+    /// Distance from a ray tube's path within which
+    /// [`crate::sbr::PropagationEngine`] treats it as arriving at the
+    /// receiver, in meters.
+    pub capture_radius_m: f64,
+
+    /// Ray tubes below this power are dropped by
+    /// [`crate::sbr::PropagationEngine`] instead of continuing to bounce.
+    pub min_path_power_dbm: f64,
+
     /// Log-distance path loss exponent
     pub path_loss_exponent: f64,
 
@@ -103,6 +233,97 @@ pub struct PropagationConfig {
 
     /// Thermal noise floor in dBm/Hz
     pub noise_floor_dbm_hz: f64,
+
+    /// Height offset added to each endpoint's `z` before computing the
+    /// two-ray ground model's `ht`/`hr`, so antennas mounted above local
+    /// terrain (rather than at `z = 0`) are modeled correctly.
+    pub height_above_z: f64,
+
+    /// Ground conductivity in S/m, used by the ITM model's ground-reflection
+    /// term (average ground is ~0.005 S/m).
+    pub ground_conductivity: f64,
+
+    /// Relative ground permittivity, used alongside `ground_conductivity`.
+    pub ground_permittivity: f64,
+
+    /// ITU/ITS climate code for the ITM model's troposcatter term.
+    pub climate: crate::itm::Climate,
+
+    /// Antenna polarization for the ITM model's ground-reflection term.
+    pub polarization: crate::itm::Polarization,
+
+    /// Time reliability fraction (0-1) for the ITM model's variability
+    /// adjustment.
+    pub time_reliability: f64,
+
+    /// Location reliability fraction (0-1) for the ITM model's variability
+    /// adjustment.
+    pub location_reliability: f64,
+
+    /// Situation reliability fraction (0-1) for the ITM model's variability
+    /// adjustment.
+    pub situation_reliability: f64,
+
+    /// Number of points to sample along the tx-rx ground track when
+    /// building the ITM model's terrain elevation profile.
+    pub terrain_profile_samples: usize,
+
+    /// Receiver sensitivity threshold in dBm (e.g. -105.0 for a typical
+    /// narrowband telemetry radio); `compute_link` reports a link as
+    /// connected when RSSI is at or above this.
+    pub receiver_sensitivity_dbm: f64,
+
+    /// Measured/replayed path-loss table used when `model` is
+    /// [`PropagationModel::Matrix`].
+    pub matrix: Option<PropagationMatrix>,
+
+    /// Grid/solver tunables used when `model` is [`PropagationModel::Fdtd`].
+    /// `None` runs [`crate::fdtd::FdtdConfig::default`].
+    pub fdtd: Option<crate::fdtd::FdtdConfig>,
+
+    /// Additional excess-loss stages chained on top of `model`'s base path
+    /// loss (e.g. shadowing, fast fading). Summed in order by
+    /// `RFPropagationEngine::compute_path_loss`. Not serialized since
+    /// trait objects aren't representable in config files; reattach stages
+    /// after loading a serialized config via `with_loss_stage`.
+    #[serde(skip)]
+    pub loss_stages: Vec<Arc<dyn LossStage>>,
+}
+
+impl std::fmt::Debug for PropagationConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PropagationConfig")
+            .field("model", &self.model)
+            .field("frequency_hz", &self.frequency_hz)
+            .field("tx_power_dbm", &self.tx_power_dbm)
+            .field("tx_gain_dbi", &self.tx_gain_dbi)
+            .field("rx_gain_dbi", &self.rx_gain_dbi)
+            .field("system_loss_db", &self.system_loss_db)
+            .field("num_rays", &self.num_rays)
+            .field("max_reflections", &self.max_reflections)
+            .field("max_distance", &self.max_distance)
+            .field("capture_radius_m", &self.capture_radius_m)
+            .field("min_path_power_dbm", &self.min_path_power_dbm)
+            .field("path_loss_exponent", &self.path_loss_exponent)
+            .field("reference_distance", &self.reference_distance)
+            .field("enable_diffraction", &self.enable_diffraction)
+            .field("enable_scattering", &self.enable_scattering)
+            .field("noise_floor_dbm_hz", &self.noise_floor_dbm_hz)
+            .field("height_above_z", &self.height_above_z)
+            .field("ground_conductivity", &self.ground_conductivity)
+            .field("ground_permittivity", &self.ground_permittivity)
+            .field("climate", &self.climate)
+            .field("polarization", &self.polarization)
+            .field("time_reliability", &self.time_reliability)
+            .field("location_reliability", &self.location_reliability)
+            .field("situation_reliability", &self.situation_reliability)
+            .field("terrain_profile_samples", &self.terrain_profile_samples)
+            .field("receiver_sensitivity_dbm", &self.receiver_sensitivity_dbm)
+            .field("matrix", &self.matrix)
+            .field("fdtd", &self.fdtd)
+            .field("loss_stages", &self.loss_stages.len())
+            .finish()
+    }
 }
 
 impl Default for PropagationConfig {
@@ -117,11 +338,26 @@ impl Default for PropagationConfig {
             num_rays: 1000,
             max_reflections: 5,
             max_distance: 1000.0,
+            capture_radius_m: 2.0,
+            min_path_power_dbm: -120.0,
             path_loss_exponent: 2.0,
             reference_distance: 1.0,
             enable_diffraction: true,
             enable_scattering: false,
             noise_floor_dbm_hz: -174.0, // Thermal noise at room temperature
+            height_above_z: 0.0,
+            ground_conductivity: 0.005,
+            ground_permittivity: 15.0,
+            climate: crate::itm::Climate::ContinentalTemperate,
+            polarization: crate::itm::Polarization::Vertical,
+            time_reliability: 0.5,
+            location_reliability: 0.5,
+            situation_reliability: 0.5,
+            terrain_profile_samples: 32,
+            receiver_sensitivity_dbm: -90.0,
+            matrix: None,
+            fdtd: None,
+            loss_stages: Vec::new(),
         }
     }
 }
@@ -132,6 +368,12 @@ impl PropagationConfig {
         SPEED_OF_LIGHT / self.frequency_hz
     }
 
+    /// Chain an additional excess-loss stage onto this configuration.
+    pub fn with_loss_stage(mut self, stage: Arc<dyn LossStage>) -> Self {
+        self.loss_stages.push(stage);
+        self
+    }
+
     /// Validate configuration
     pub fn validate(&self) -> RFResult<()> {
         if self.frequency_hz <= 0.0 {
@@ -156,6 +398,25 @@ impl PropagationConfig {
     }
 }
 
+/// Result of a link-budget evaluation from `RFPropagationEngine::compute_link`:
+/// the computed RSSI and noise floor over a channel bandwidth, the resulting
+/// SNR, the margin above `receiver_sensitivity_dbm`, and whether the link is
+/// usable at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinkStatus {
+    /// Received signal strength in dBm.
+    pub rssi: f64,
+    /// Thermal noise power integrated over the channel bandwidth, in dBm.
+    pub noise_dbm: f64,
+    /// Signal-to-noise ratio in dB (`rssi - noise_dbm`).
+    pub snr_db: f64,
+    /// Margin above the receiver sensitivity threshold in dB; negative means
+    /// the link is below sensitivity.
+    pub margin_db: f64,
+    /// Whether `rssi` is at or above `PropagationConfig::receiver_sensitivity_dbm`.
+    pub is_connected: bool,
+}
+
 /// Main RF propagation engine
 pub struct RFPropagationEngine {
     backend: Arc<dyn SimulationBackend>,
@@ -198,15 +459,24 @@ impl RFPropagationEngine {
 
         let distance = (tx_pos - rx_pos).norm();
 
-        match self.config.model {
-            PropagationModel::Friis => Ok(self.friis_path_loss(distance)),
-            PropagationModel::TwoRay => Ok(self.two_ray_path_loss(distance, tx_pos.z, rx_pos.z)),
-            PropagationModel::LogDistance => Ok(self.log_distance_path_loss(distance)),
-            PropagationModel::RayTracing => self.ray_tracing_path_loss(tx_pos, rx_pos).await,
-            PropagationModel::GaussianBeam => Ok(self.gaussian_beam_path_loss(distance)),
-            PropagationModel::ITM => Ok(self.itm_path_loss(distance)),
-            PropagationModel::COST231 => Ok(self.cost231_path_loss(distance)),
+        let base_loss = match self.config.model {
+            PropagationModel::Friis => self.friis_path_loss(distance),
+            PropagationModel::TwoRay => self.two_ray_path_loss(distance, tx_pos.z, rx_pos.z),
+            PropagationModel::LogDistance => self.log_distance_path_loss(distance),
+            PropagationModel::RayTracing => self.ray_tracing_path_loss(tx_pos, rx_pos).await?,
+            PropagationModel::GaussianBeam => self.gaussian_beam_path_loss(distance),
+            PropagationModel::ITM => self.itm_path_loss(tx_pos, rx_pos).await?,
+            PropagationModel::COST231 => self.cost231_path_loss(distance),
+            PropagationModel::Matrix => self.matrix_path_loss(tx_pos, rx_pos)?,
+            PropagationModel::Fdtd => self.fdtd_path_loss(tx_pos, rx_pos),
+        };
+
+        let mut total_loss = base_loss;
+        for stage in &self.config.loss_stages {
+            total_loss += stage.excess_loss_db(tx_pos, rx_pos, &self.config)?;
         }
+
+        Ok(total_loss)
     }
 
     /// Compute received signal strength indicator (RSSI)
@@ -221,20 +491,76 @@ impl RFPropagationEngine {
         Ok(rssi)
     }
 
+    /// Evaluate whether a link is usable by integrating the configured
+    /// thermal noise floor over `bandwidth_hz` (`N = noise_floor_dbm_hz +
+    /// 10*log10(bandwidth_hz)`) and comparing the resulting SNR and RSSI
+    /// against `PropagationConfig::receiver_sensitivity_dbm`.
+    pub async fn compute_link(
+        &self,
+        tx_pos: Point3<f64>,
+        rx_pos: Point3<f64>,
+        bandwidth_hz: f64,
+    ) -> RFResult<LinkStatus> {
+        if bandwidth_hz <= 0.0 {
+            return Err(RFError::ComputationError(
+                "Bandwidth must be positive".to_string(),
+            ));
+        }
+
+        let rssi = self.compute_rssi(tx_pos, rx_pos).await?;
+        let noise_dbm = self.config.noise_floor_dbm_hz + 10.0 * bandwidth_hz.log10();
+        let snr_db = rssi - noise_dbm;
+        let margin_db = rssi - self.config.receiver_sensitivity_dbm;
+
+        Ok(LinkStatus {
+            rssi,
+            noise_dbm,
+            snr_db,
+            margin_db,
+            is_connected: rssi >= self.config.receiver_sensitivity_dbm,
+        })
+    }
+
     /// Friis free-space path loss
     fn friis_path_loss(&self, distance: f64) -> f64 {
         let wavelength = self.config.wavelength();
         20.0 * (4.0 * std::f64::consts::PI * distance / wavelength).log10()
     }
 
-    /// Two-ray ground reflection model
-    fn two_ray_path_loss(&self, distance: f64, tx_height: f64, rx_height: f64) -> f64 {
-        if distance < 1.0 {
+    /// Two-ray ground reflection model.
+    ///
+    /// Below the Friis/two-ray crossover distance `dc = 4*pi*ht*hr/wavelength`
+    /// the direct and ground-reflected rays haven't separated enough for the
+    /// asymptotic `1/d^4` falloff to hold, so free-space (Friis) loss is
+    /// used instead. At and beyond `dc`, returns the standard two-ray
+    /// expression, folding in antenna gains and system loss per the classic
+    /// derivation — set `tx_gain_dbi`/`rx_gain_dbi`/`system_loss_db` to zero
+    /// if composing this with `compute_rssi`'s own gain/loss terms, to
+    /// avoid double-counting them.
+    fn two_ray_path_loss(&self, distance: f64, tx_z: f64, rx_z: f64) -> f64 {
+        let ht = tx_z + self.config.height_above_z;
+        let hr = rx_z + self.config.height_above_z;
+
+        if ht <= 0.0 || hr <= 0.0 {
             return self.friis_path_loss(distance);
         }
 
-        // Simplified two-ray model
-        40.0 * distance.log10() - (10.0 * tx_height.log10() + 10.0 * rx_height.log10())
+        let wavelength = self.config.wavelength();
+        let crossover = 4.0 * std::f64::consts::PI * ht * hr / wavelength;
+
+        if distance < crossover {
+            return self.friis_path_loss(distance);
+        }
+
+        let gt_linear = 10f64.powf(self.config.tx_gain_dbi / 10.0);
+        let gr_linear = 10f64.powf(self.config.rx_gain_dbi / 10.0);
+
+        40.0 * distance.log10()
+            - (10.0 * gt_linear.log10()
+                + 10.0 * gr_linear.log10()
+                + 20.0 * ht.log10()
+                + 20.0 * hr.log10())
+            + self.config.system_loss_db
     }
 
     /// Log-distance path loss model
@@ -267,18 +593,72 @@ impl RFPropagationEngine {
         };
 
         // Check for line-of-sight
-        let hit = self.backend.cast_ray(scene, &ray)?;
-
-        if hit.is_none() {
+        let Some(hit) = self.backend.cast_ray(scene, &ray)? else {
             // Line of sight - use Friis
             return Ok(self.friis_path_loss(distance));
-        }
+        };
 
-        // Obstruction detected - add additional loss
         let base_loss = self.friis_path_loss(distance);
-        let obstruction_loss = 20.0; // Simplified - could be more sophisticated
 
-        Ok(base_loss + obstruction_loss)
+        if !self.config.enable_diffraction {
+            // Diffraction modeling disabled - fall back to a flat penalty.
+            return Ok(base_loss + 20.0);
+        }
+
+        let excess_loss = self
+            .diffraction_excess_loss_db(scene, tx_pos, rx_pos, &hit, distance)
+            .await?;
+
+        Ok(base_loss + excess_loss)
+    }
+
+    /// Diffraction-aware excess loss for an obstructed ray-tracing path.
+    ///
+    /// Finds the blocking edge's height above the LOS line at the
+    /// obstruction point (by probing straight down onto it from above, the
+    /// same technique [`crate::itm::sample_terrain_profile`] uses for
+    /// terrain), applies the Fresnel-Kirchhoff knife-edge approximation via
+    /// [`KnifeEdgeDiffraction`], and adds the [`MetisShadowing`] transition
+    /// term so loss grows smoothly through the shadow boundary rather than
+    /// snapping on at a fixed penalty.
+    async fn diffraction_excess_loss_db(
+        &self,
+        scene: &SceneHandle,
+        tx_pos: Point3<f64>,
+        rx_pos: Point3<f64>,
+        hit: &RayHit,
+        total_distance: f64,
+    ) -> RFResult<f64> {
+        const PROBE_HEIGHT: f64 = 10_000.0;
+
+        let d1 = hit.distance.max(1e-3);
+        let d2 = (total_distance - hit.distance).max(1e-3);
+
+        let probe_ray = Ray {
+            origin: Point3::new(
+                hit.position.x,
+                hit.position.y,
+                hit.position.z + PROBE_HEIGHT,
+            ),
+            direction: nalgebra::Vector3::new(0.0, 0.0, -1.0),
+            max_distance: PROBE_HEIGHT + 1.0,
+        };
+        let edge_z = match self.backend.cast_ray(scene, &probe_ray)? {
+            Some(top_hit) => top_hit.position.z,
+            None => hit.position.z,
+        };
+
+        let fraction = hit.distance / total_distance.max(1e-6);
+        let los_z = tx_pos.z + (rx_pos.z - tx_pos.z) * fraction;
+        let h = edge_z - los_z;
+
+        let wavelength = self.config.wavelength();
+        let v = h * (2.0 * (d1 + d2) / (wavelength * d1 * d2)).sqrt();
+
+        let knife_edge_loss = KnifeEdgeDiffraction::calculate_loss(h, d1, d2, wavelength);
+        let shadowing_loss = MetisShadowing::transition_loss_db(self.config.frequency_hz, v);
+
+        Ok(knife_edge_loss + shadowing_loss)
     }
 
     /// Gaussian beam path loss (simplified)
@@ -292,15 +672,63 @@ impl RFPropagationEngine {
         self.friis_path_loss(distance) + 10.0 * beam_spread.log10()
     }
 
-    /// ITM (Longley-Rice) model (simplified)
-    fn itm_path_loss(&self, distance: f64) -> f64 {
-        // Simplified version - full ITM is very complex
-        let base_loss = self.log_distance_path_loss(distance);
+    /// ITM (Longley-Rice) point-to-point model driven by a real terrain
+    /// profile sampled from the scene backend. Falls back to log-distance
+    /// loss when no scene is set, since there's no terrain to sample.
+    async fn itm_path_loss(&self, tx_pos: Point3<f64>, rx_pos: Point3<f64>) -> RFResult<f64> {
+        let distance = (tx_pos - rx_pos).norm();
 
-        // Add terrain effects (simplified)
-        let terrain_loss = 5.0 * (distance / 1000.0).sqrt();
+        let Some(scene) = self.scene.as_ref() else {
+            return Ok(self.log_distance_path_loss(distance));
+        };
 
-        base_loss + terrain_loss
+        let profile = crate::itm::sample_terrain_profile(
+            self.backend.as_ref(),
+            scene,
+            tx_pos,
+            rx_pos,
+            self.config.terrain_profile_samples,
+        )?;
+
+        let result = crate::itm::compute_itm_path_loss(
+            &profile,
+            tx_pos,
+            rx_pos,
+            self.config.wavelength(),
+            self.config.ground_conductivity,
+            self.config.ground_permittivity,
+            self.config.polarization,
+            self.config.climate,
+            self.config.time_reliability,
+            self.config.location_reliability,
+            self.config.situation_reliability,
+        );
+
+        Ok(result.median_loss_db)
+    }
+
+    /// Measured/replayed path loss from `config.matrix`.
+    fn matrix_path_loss(&self, tx_pos: Point3<f64>, rx_pos: Point3<f64>) -> RFResult<f64> {
+        let matrix = self.config.matrix.as_ref().ok_or_else(|| {
+            RFError::ComputationError(
+                "PropagationModel::Matrix selected but no matrix is configured".to_string(),
+            )
+        })?;
+
+        Ok(matrix.lookup(tx_pos, rx_pos))
+    }
+
+    /// Full-wave path loss from a Yee-grid FDTD solve: runs
+    /// [`crate::fdtd::compute_path_loss`] on a grid centered at `tx_pos`
+    /// with `rx_pos` placed at its offset, using `config.fdtd` (or the
+    /// solver's own default) for grid size, timestep, and boundary
+    /// tunables. Meant for the short, high-detail links ray tracing misses
+    /// interference/diffraction phase accuracy on, not long outdoor spans --
+    /// the grid doesn't grow with distance, so a receiver placed far
+    /// outside it clamps to the grid's edge.
+    fn fdtd_path_loss(&self, tx_pos: Point3<f64>, rx_pos: Point3<f64>) -> f64 {
+        let config = self.config.fdtd.clone().unwrap_or_default();
+        crate::fdtd::compute_path_loss(tx_pos, rx_pos, &config, self.config.frequency_hz)
     }
 
     /// COST 231 model for urban environments
@@ -337,6 +765,447 @@ mod tests {
         assert!(path_loss < 200.0); // Reasonable range
     }
 
+    struct ConstantLossStage(f64);
+
+    impl LossStage for ConstantLossStage {
+        fn excess_loss_db(
+            &self,
+            _tx: Point3<f64>,
+            _rx: Point3<f64>,
+            _ctx: &PropagationConfig,
+        ) -> RFResult<f64> {
+            Ok(self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chained_loss_stages_add_to_base_model() {
+        let config = PropagationConfig {
+            model: PropagationModel::Friis,
+            frequency_hz: 2.4e9,
+            ..Default::default()
+        }
+        .with_loss_stage(Arc::new(ConstantLossStage(5.0)))
+        .with_loss_stage(Arc::new(ConstantLossStage(2.5)));
+
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config.clone());
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(100.0, 0.0, 0.0);
+
+        let unchained = RFPropagationEngine::new(
+            Arc::new(NativeBackend::new()),
+            PropagationConfig {
+                model: PropagationModel::Friis,
+                frequency_hz: 2.4e9,
+                ..Default::default()
+            },
+        );
+
+        let base_loss = unchained.compute_path_loss(tx, rx).await.unwrap();
+        let chained_loss = engine.compute_path_loss(tx, rx).await.unwrap();
+
+        assert!((chained_loss - (base_loss + 7.5)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_two_ray_below_crossover_matches_friis() {
+        let config = PropagationConfig {
+            model: PropagationModel::TwoRay,
+            frequency_hz: 2.4e9,
+            ..Default::default()
+        };
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        // 1m separation with near-ground antennas is well inside the
+        // crossover distance, so this should fall back to Friis.
+        let tx = Point3::new(0.0, 0.0, 1.0);
+        let rx = Point3::new(1.0, 0.0, 1.0);
+
+        let path_loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        let friis = engine.friis_path_loss(1.0);
+        assert!((path_loss - friis).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_two_ray_above_crossover_follows_fourth_power_falloff() {
+        let config = PropagationConfig {
+            model: PropagationModel::TwoRay,
+            frequency_hz: 900e6,
+            tx_gain_dbi: 0.0,
+            rx_gain_dbi: 0.0,
+            system_loss_db: 0.0,
+            ..Default::default()
+        };
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        // Tall masts and large separation put both distances well past the
+        // crossover, where loss grows as 40*log10(d) (doubling distance
+        // costs 12.04 dB, not Friis's 6.02 dB).
+        let tx_near = Point3::new(0.0, 0.0, 50.0);
+        let rx_near = Point3::new(2000.0, 0.0, 50.0);
+        let rx_far = Point3::new(4000.0, 0.0, 50.0);
+
+        let loss_near = engine.compute_path_loss(tx_near, rx_near).await.unwrap();
+        let loss_far = engine.compute_path_loss(tx_near, rx_far).await.unwrap();
+
+        assert!((loss_far - loss_near - 12.04).abs() < 0.1);
+    }
+
+    #[tokio::test]
+    async fn test_two_ray_height_above_z_offset_shifts_crossover() {
+        let base_config = PropagationConfig {
+            model: PropagationModel::TwoRay,
+            frequency_hz: 2.4e9,
+            ..Default::default()
+        };
+        let offset_config = PropagationConfig {
+            height_above_z: 10.0,
+            ..base_config.clone()
+        };
+
+        let engine_base = RFPropagationEngine::new(Arc::new(NativeBackend::new()), base_config);
+        let engine_offset = RFPropagationEngine::new(Arc::new(NativeBackend::new()), offset_config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(50.0, 0.0, 0.0);
+
+        // With zero z and no offset, heights are non-positive and the model
+        // falls back to Friis; with the offset applied both heights become
+        // positive, changing the result.
+        let loss_base = engine_base.compute_path_loss(tx, rx).await.unwrap();
+        let loss_offset = engine_offset.compute_path_loss(tx, rx).await.unwrap();
+        assert!((loss_base - engine_base.friis_path_loss(50.0)).abs() < 1e-9);
+        assert!(loss_offset.is_finite());
+    }
+
+    #[tokio::test]
+    async fn test_itm_path_loss_falls_back_to_log_distance_without_scene() {
+        let config = PropagationConfig {
+            model: PropagationModel::ITM,
+            frequency_hz: 2.4e9,
+            ..Default::default()
+        };
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        let tx = Point3::new(0.0, 0.0, 10.0);
+        let rx = Point3::new(500.0, 0.0, 10.0);
+
+        let loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        let expected = engine.log_distance_path_loss(500.0);
+        assert!((loss - expected).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_itm_path_loss_with_flat_terrain_scene() {
+        let mut backend = NativeBackend::new();
+        backend
+            .initialize(autonomysim_core::backend::BackendConfig::default())
+            .await
+            .unwrap();
+        let scene = backend.load_scene("flat.obj").await.unwrap();
+        backend
+            .add_object(
+                &scene,
+                autonomysim_core::backend::SceneObject {
+                    id: "ground".to_string(),
+                    name: "ground".to_string(),
+                    geometry: autonomysim_core::backend::Geometry::Box {
+                        size: nalgebra::Vector3::new(20_000.0, 20_000.0, 1.0),
+                    },
+                    material: autonomysim_core::backend::Material::air(),
+                    transform: autonomysim_core::backend::Transform::identity(),
+                },
+            )
+            .unwrap();
+
+        let config = PropagationConfig {
+            model: PropagationModel::ITM,
+            frequency_hz: 300e6,
+            terrain_profile_samples: 16,
+            ..Default::default()
+        };
+
+        let mut engine = RFPropagationEngine::new(Arc::new(backend), config);
+        engine.set_scene(scene);
+
+        let tx = Point3::new(0.0, 0.0, 30.0);
+        let rx = Point3::new(3000.0, 0.0, 30.0);
+
+        let loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        assert!(loss.is_finite());
+        assert!(loss > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_matrix_path_loss_returns_explicit_entry() {
+        let matrix = PropagationMatrix::new(150.0).set_symmetric(
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(100.0, 0.0, 0.0),
+            42.0,
+        );
+        let config = PropagationConfig {
+            model: PropagationModel::Matrix,
+            matrix: Some(matrix),
+            ..Default::default()
+        };
+        let engine = RFPropagationEngine::new(Arc::new(NativeBackend::new()), config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(100.0, 0.0, 0.0);
+        let loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        assert!((loss - 42.0).abs() < 1e-9);
+
+        // Symmetric entry means the reverse direction matches too.
+        let reverse_loss = engine.compute_path_loss(rx, tx).await.unwrap();
+        assert!((reverse_loss - 42.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_matrix_path_loss_falls_back_to_default_for_unknown_link() {
+        let matrix = PropagationMatrix::new(150.0);
+        let config = PropagationConfig {
+            model: PropagationModel::Matrix,
+            matrix: Some(matrix),
+            ..Default::default()
+        };
+        let engine = RFPropagationEngine::new(Arc::new(NativeBackend::new()), config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(100.0, 0.0, 0.0);
+        let loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        assert!((loss - 150.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_matrix_path_loss_asymmetric_entry_only_applies_one_direction() {
+        let matrix = PropagationMatrix::new(150.0).set_asymmetric(
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(100.0, 0.0, 0.0),
+            10.0,
+        );
+        let config = PropagationConfig {
+            model: PropagationModel::Matrix,
+            matrix: Some(matrix),
+            ..Default::default()
+        };
+        let engine = RFPropagationEngine::new(Arc::new(NativeBackend::new()), config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(100.0, 0.0, 0.0);
+        assert!((engine.compute_path_loss(tx, rx).await.unwrap() - 10.0).abs() < 1e-9);
+        assert!((engine.compute_path_loss(rx, tx).await.unwrap() - 150.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_matrix_path_loss_errors_without_configured_matrix() {
+        let config = PropagationConfig {
+            model: PropagationModel::Matrix,
+            ..Default::default()
+        };
+        let engine = RFPropagationEngine::new(Arc::new(NativeBackend::new()), config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(100.0, 0.0, 0.0);
+        assert!(engine.compute_path_loss(tx, rx).await.is_err());
+    }
+
+    #[test]
+    fn test_propagation_matrix_round_trips_through_serde() {
+        let matrix = PropagationMatrix::new(120.0)
+            .with_bin_size(5.0)
+            .set_symmetric(
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(50.0, 0.0, 0.0),
+                30.0,
+            );
+        let config = PropagationConfig {
+            model: PropagationModel::Matrix,
+            matrix: Some(matrix),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: PropagationConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.model, PropagationModel::Matrix);
+        let restored_matrix = restored.matrix.unwrap();
+        assert_eq!(restored_matrix.bin_size_m, 5.0);
+        assert_eq!(
+            restored_matrix.lookup(Point3::new(0.0, 0.0, 0.0), Point3::new(50.0, 0.0, 0.0)),
+            30.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ray_tracing_path_loss_applies_diffraction_not_flat_penalty() {
+        let mut backend = NativeBackend::new();
+        backend
+            .initialize(autonomysim_core::backend::BackendConfig::default())
+            .await
+            .unwrap();
+        let scene = backend.load_scene("wall.obj").await.unwrap();
+        backend
+            .add_object(
+                &scene,
+                autonomysim_core::backend::SceneObject {
+                    id: "wall".to_string(),
+                    name: "wall".to_string(),
+                    geometry: autonomysim_core::backend::Geometry::Box {
+                        size: nalgebra::Vector3::new(1.0, 20.0, 5.0),
+                    },
+                    material: autonomysim_core::backend::Material::air(),
+                    transform: autonomysim_core::backend::Transform {
+                        position: nalgebra::Point3::new(50.0, 0.0, 2.5),
+                        ..autonomysim_core::backend::Transform::identity()
+                    },
+                },
+            )
+            .unwrap();
+
+        let config = PropagationConfig {
+            model: PropagationModel::RayTracing,
+            frequency_hz: 2.4e9,
+            enable_diffraction: true,
+            ..Default::default()
+        };
+        let mut engine = RFPropagationEngine::new(Arc::new(backend), config);
+        engine.set_scene(scene);
+
+        let tx = Point3::new(0.0, 0.0, 1.0);
+        let rx = Point3::new(100.0, 0.0, 1.0);
+
+        let loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        let friis = engine.friis_path_loss(100.0);
+
+        // The wall's top is only at z=5, well above the LOS (which runs at
+        // z=1), so the blocking edge is deep in shadow and should add
+        // substantially more than a token amount of excess loss - but not
+        // the old scheme's indiscriminate flat 20 dB every time.
+        assert!(loss > friis);
+        assert!(loss.is_finite());
+    }
+
+    #[tokio::test]
+    async fn test_ray_tracing_path_loss_flat_penalty_when_diffraction_disabled() {
+        let mut backend = NativeBackend::new();
+        backend
+            .initialize(autonomysim_core::backend::BackendConfig::default())
+            .await
+            .unwrap();
+        let scene = backend.load_scene("wall.obj").await.unwrap();
+        backend
+            .add_object(
+                &scene,
+                autonomysim_core::backend::SceneObject {
+                    id: "wall".to_string(),
+                    name: "wall".to_string(),
+                    geometry: autonomysim_core::backend::Geometry::Box {
+                        size: nalgebra::Vector3::new(1.0, 20.0, 5.0),
+                    },
+                    material: autonomysim_core::backend::Material::air(),
+                    transform: autonomysim_core::backend::Transform {
+                        position: nalgebra::Point3::new(50.0, 0.0, 2.5),
+                        ..autonomysim_core::backend::Transform::identity()
+                    },
+                },
+            )
+            .unwrap();
+
+        let config = PropagationConfig {
+            model: PropagationModel::RayTracing,
+            frequency_hz: 2.4e9,
+            enable_diffraction: false,
+            ..Default::default()
+        };
+        let mut engine = RFPropagationEngine::new(Arc::new(backend), config);
+        engine.set_scene(scene);
+
+        let tx = Point3::new(0.0, 0.0, 1.0);
+        let rx = Point3::new(100.0, 0.0, 1.0);
+
+        let loss = engine.compute_path_loss(tx, rx).await.unwrap();
+        let friis = engine.friis_path_loss(100.0);
+        assert!((loss - (friis + 20.0)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_compute_link_reports_connected_when_rssi_above_sensitivity() {
+        let config = PropagationConfig {
+            model: PropagationModel::Friis,
+            frequency_hz: 2.4e9,
+            tx_power_dbm: 30.0,
+            receiver_sensitivity_dbm: -90.0,
+            ..Default::default()
+        };
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(10.0, 0.0, 0.0);
+
+        let status = engine.compute_link(tx, rx, 20e6).await.unwrap();
+        assert!(status.is_connected);
+        assert!((status.margin_db - (status.rssi - (-90.0))).abs() < 1e-9);
+        assert!((status.snr_db - (status.rssi - status.noise_dbm)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_compute_link_reports_disconnected_when_rssi_below_sensitivity() {
+        let config = PropagationConfig {
+            model: PropagationModel::Friis,
+            frequency_hz: 2.4e9,
+            tx_power_dbm: -10.0,
+            receiver_sensitivity_dbm: -90.0,
+            ..Default::default()
+        };
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(100_000.0, 0.0, 0.0);
+
+        let status = engine.compute_link(tx, rx, 20e6).await.unwrap();
+        assert!(!status.is_connected);
+        assert!(status.margin_db < 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_compute_link_noise_scales_with_bandwidth() {
+        let config = PropagationConfig {
+            model: PropagationModel::Friis,
+            frequency_hz: 2.4e9,
+            ..Default::default()
+        };
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(50.0, 0.0, 0.0);
+
+        let narrow = engine.compute_link(tx, rx, 1e6).await.unwrap();
+        let wide = engine.compute_link(tx, rx, 10e6).await.unwrap();
+
+        // Ten times the bandwidth is 10 dB more integrated noise power.
+        assert!((wide.noise_dbm - narrow.noise_dbm - 10.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_compute_link_rejects_non_positive_bandwidth() {
+        let config = PropagationConfig::default();
+        let backend = Arc::new(NativeBackend::new());
+        let engine = RFPropagationEngine::new(backend, config);
+
+        let tx = Point3::new(0.0, 0.0, 0.0);
+        let rx = Point3::new(10.0, 0.0, 0.0);
+
+        assert!(engine.compute_link(tx, rx, 0.0).await.is_err());
+    }
+
     #[test]
     fn test_wavelength() {
         let config = PropagationConfig {
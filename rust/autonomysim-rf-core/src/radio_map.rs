@@ -0,0 +1,501 @@
+//! 6-D air-to-ground radio map reconstruction from sparse link samples
+//!
+//! The propagation models elsewhere in this crate predict a single link
+//! given an analytical/empirical formula; this module instead predicts
+//! channel gain for *any* transmitter/receiver position pair from a sparse
+//! scatter of prior measurements, the case air-to-ground links need when
+//! both endpoints move in 3D (a 6-D input space) and samples are too scarce
+//! for pure interpolation to extrapolate sensibly.
+//!
+//! [`RadioMap`] blends two information sources the way the module doc on
+//! [`crate::itm`] does for terrain: a geometric prior (mesh-intersection
+//! LOS/NLOS classification selects a per-regime mean function, free-space
+//! or an obstructed-path penalty) and a data-driven residual model (ordinary
+//! Kriging with an empirically fit exponential variogram) layered on top, so
+//! the Kriging only has to explain the *deviation* from the physics-informed
+//! mean rather than the whole field.
+//!
+//! This is a structured simplification of a full Gaussian-process radio-map
+//! reconstructor (method-of-moments variogram fitting by binned semivariance
+//! rather than maximum-likelihood covariance-parameter estimation, and a
+//! single straight-line mesh intersection for LOS/NLOS rather than a
+//! diffraction-aware geometric classifier) rather than a byte-for-byte port.
+
+use crate::propagation::{RFError, RFResult};
+use autonomysim_core::backend::{Position, Ray, SceneHandle, SimulationBackend};
+use nalgebra::DMatrix;
+
+/// A single air-to-ground channel-gain sample: the 6-D input
+/// (`tx`, `rx`) the radio map is reconstructed over, plus the measured
+/// channel gain (dB; higher is a stronger link) at that pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinkSample {
+    pub tx: Position,
+    pub rx: Position,
+    pub gain_db: f64,
+}
+
+/// Geometric classification of a tx/rx pair, used to pick which mean
+/// function [`RadioMap`] models the Kriging residual against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkRegime {
+    LineOfSight,
+    Obstructed,
+}
+
+/// Straight-line clearance (meters) a ray hit must fall short of the
+/// tx-rx distance by before the link counts as obstructed, absorbing
+/// floating-point/mesh-sampling noise right at the receiver.
+const LOS_CLEARANCE_M: f64 = 0.5;
+
+/// Extra median attenuation (dB) an obstructed link's mean function adds on
+/// top of free-space loss, approximating the diffraction/penetration loss
+/// a single straight-line mesh intersection doesn't otherwise quantify.
+const OBSTRUCTED_EXCESS_LOSS_DB: f64 = 20.0;
+
+/// Classify `tx`-`rx` as line-of-sight or obstructed by casting a ray
+/// between them and checking whether anything in the scene intersects it
+/// short of the receiver.
+fn classify_link(
+    backend: &dyn SimulationBackend,
+    scene: &SceneHandle,
+    tx: Position,
+    rx: Position,
+) -> RFResult<LinkRegime> {
+    let delta = rx - tx;
+    let distance = delta.norm();
+    if distance < 1e-6 {
+        return Ok(LinkRegime::LineOfSight);
+    }
+
+    let ray = Ray {
+        origin: tx,
+        direction: delta / distance,
+        max_distance: distance,
+    };
+
+    match backend.cast_ray(scene, &ray)? {
+        Some(hit) if hit.distance < distance - LOS_CLEARANCE_M => Ok(LinkRegime::Obstructed),
+        _ => Ok(LinkRegime::LineOfSight),
+    }
+}
+
+/// Per-regime mean channel gain (dB) at `distance` meters and `wavelength`
+/// meters, the physics prior [`RadioMap`] Krigs the residual against.
+fn regime_mean_gain_db(regime: LinkRegime, distance: f64, wavelength: f64) -> f64 {
+    let free_space_loss_db =
+        20.0 * (4.0 * std::f64::consts::PI * distance.max(1.0) / wavelength).log10();
+    let loss_db = match regime {
+        LinkRegime::LineOfSight => free_space_loss_db,
+        LinkRegime::Obstructed => free_space_loss_db + OBSTRUCTED_EXCESS_LOSS_DB,
+    };
+    -loss_db
+}
+
+/// 6-D separation between two link samples: the Euclidean distance in the
+/// concatenated `(tx, rx)` space, i.e. `sqrt(|tx_a - tx_b|^2 + |rx_a -
+/// rx_b|^2)`, the input the empirical variogram is fit over.
+fn link_separation(a: &LinkSample, b: &LinkSample) -> f64 {
+    let tx_delta = (a.tx - b.tx).norm_squared();
+    let rx_delta = (a.rx - b.rx).norm_squared();
+    (tx_delta + rx_delta).sqrt()
+}
+
+/// Exponential variogram `gamma(h) = nugget + (sill - nugget) * (1 -
+/// exp(-h / range))`, fit empirically from binned squared residual
+/// differences by [`fit_variogram`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Variogram {
+    nugget: f64,
+    sill: f64,
+    range: f64,
+}
+
+impl Variogram {
+    fn gamma(&self, h: f64) -> f64 {
+        if h <= 0.0 {
+            return 0.0;
+        }
+        self.nugget + (self.sill - self.nugget) * (1.0 - (-h / self.range).exp())
+    }
+
+    /// Stationary covariance implied by this variogram: `sill - gamma(h)`.
+    fn covariance(&self, h: f64) -> f64 {
+        self.sill - self.gamma(h)
+    }
+}
+
+/// Number of separation-distance bins [`fit_variogram`] groups sample pairs
+/// into before estimating nugget/sill/range from the binned semivariance.
+const VARIOGRAM_BIN_COUNT: usize = 8;
+
+/// Method-of-moments exponential variogram fit: bin every sample pair by
+/// 6-D separation, estimate semivariance per bin as half the mean squared
+/// residual difference within it, then read the sill off the largest
+/// binned semivariance, the range off the separation where it first
+/// reaches 95% of the sill, and the nugget off the smallest-separation
+/// bin's semivariance.
+fn fit_variogram(samples: &[LinkSample], residuals: &[f64]) -> Variogram {
+    let n = samples.len();
+    if n < 2 {
+        return Variogram {
+            nugget: 0.0,
+            sill: 1.0,
+            range: 1.0,
+        };
+    }
+
+    let mut pairs: Vec<(f64, f64)> = Vec::with_capacity(n * (n - 1) / 2);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let h = link_separation(&samples[i], &samples[j]);
+            let squared_diff = (residuals[i] - residuals[j]).powi(2);
+            pairs.push((h, squared_diff));
+        }
+    }
+
+    let max_h = pairs.iter().map(|(h, _)| *h).fold(0.0, f64::max).max(1e-6);
+
+    let mut bin_sum = vec![0.0; VARIOGRAM_BIN_COUNT];
+    let mut bin_count = vec![0usize; VARIOGRAM_BIN_COUNT];
+    for (h, squared_diff) in &pairs {
+        let bin = ((h / max_h) * VARIOGRAM_BIN_COUNT as f64)
+            .floor()
+            .clamp(0.0, VARIOGRAM_BIN_COUNT as f64 - 1.0) as usize;
+        bin_sum[bin] += squared_diff;
+        bin_count[bin] += 1;
+    }
+
+    let semivariance: Vec<(f64, f64)> = (0..VARIOGRAM_BIN_COUNT)
+        .filter(|&b| bin_count[b] > 0)
+        .map(|b| {
+            let h_mid = (b as f64 + 0.5) / VARIOGRAM_BIN_COUNT as f64 * max_h;
+            (h_mid, 0.5 * bin_sum[b] / bin_count[b] as f64)
+        })
+        .collect();
+
+    let Some((_, max_semivariance)) = semivariance
+        .iter()
+        .copied()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+    else {
+        return Variogram {
+            nugget: 0.0,
+            sill: 1.0,
+            range: max_h,
+        };
+    };
+
+    let sill = max_semivariance.max(1e-6);
+    let nugget = semivariance
+        .first()
+        .map(|(_, g)| g.min(sill * 0.5))
+        .unwrap_or(0.0);
+    let range = semivariance
+        .iter()
+        .find(|(_, g)| *g >= sill * 0.95)
+        .map(|(h, _)| *h)
+        .unwrap_or(max_h)
+        .max(1e-3);
+
+    Variogram {
+        nugget,
+        sill,
+        range,
+    }
+}
+
+/// Reconstructed air-to-ground radio map: a physics-informed per-regime
+/// mean plus an ordinary-Kriging residual model fit over sparse
+/// [`LinkSample`]s. See the module docs for the overall approach.
+#[derive(Debug, Clone)]
+pub struct RadioMap {
+    samples: Vec<LinkSample>,
+    residuals: Vec<f64>,
+    variogram: Variogram,
+    wavelength_m: f64,
+}
+
+impl RadioMap {
+    /// Fit a radio map from `measurements`: classify each sample's
+    /// LOS/NLOS regime against `scene`, compute its residual from the
+    /// regime's mean function, and fit the Kriging variogram over those
+    /// residuals.
+    pub fn build(
+        measurements: &[LinkSample],
+        backend: &dyn SimulationBackend,
+        scene: &SceneHandle,
+        wavelength_m: f64,
+    ) -> RFResult<Self> {
+        if measurements.is_empty() {
+            return Err(RFError::ComputationError(
+                "radio map requires at least 1 measurement".to_string(),
+            ));
+        }
+
+        let mut residuals = Vec::with_capacity(measurements.len());
+        for sample in measurements {
+            let regime = classify_link(backend, scene, sample.tx, sample.rx)?;
+            let distance = (sample.rx - sample.tx).norm();
+            let mean = regime_mean_gain_db(regime, distance, wavelength_m);
+            residuals.push(sample.gain_db - mean);
+        }
+
+        let variogram = fit_variogram(measurements, &residuals);
+
+        Ok(Self {
+            samples: measurements.to_vec(),
+            residuals,
+            variogram,
+            wavelength_m,
+        })
+    }
+
+    /// Predict channel gain (dB) for a `tx`/`rx` pair: the geometric mean
+    /// for its LOS/NLOS regime plus the ordinary-Kriging estimate of the
+    /// residual at that point from the fitted samples.
+    pub fn predict(
+        &self,
+        tx: Position,
+        rx: Position,
+        backend: &dyn SimulationBackend,
+        scene: &SceneHandle,
+    ) -> RFResult<f64> {
+        let regime = classify_link(backend, scene, tx, rx)?;
+        let distance = (rx - tx).norm();
+        let mean = regime_mean_gain_db(regime, distance, self.wavelength_m);
+
+        let query = LinkSample {
+            tx,
+            rx,
+            gain_db: 0.0,
+        };
+        let residual = self.krige_residual(&query);
+
+        Ok(mean + residual)
+    }
+
+    /// Sweep `rx_positions` for a fixed `tx`, predicting each link's gain.
+    pub fn coverage_map(
+        &self,
+        tx: Position,
+        rx_positions: &[Position],
+        backend: &dyn SimulationBackend,
+        scene: &SceneHandle,
+    ) -> RFResult<Vec<f64>> {
+        rx_positions
+            .iter()
+            .map(|&rx| self.predict(tx, rx, backend, scene))
+            .collect()
+    }
+
+    /// Ordinary Kriging estimate of the residual at `query`: solve the
+    /// bordered covariance system `[C 1; 1^T 0] [w; mu] = [c0; 1]` for the
+    /// Kriging weights `w` and return `sum(w_i * residual_i)`. Falls back
+    /// to the mean residual when there are too few samples (or a singular
+    /// system) to solve the full Kriging problem.
+    fn krige_residual(&self, query: &LinkSample) -> f64 {
+        let n = self.samples.len();
+        if n < 2 {
+            return self.residuals.first().copied().unwrap_or(0.0);
+        }
+
+        let mut system = DMatrix::<f64>::zeros(n + 1, n + 1);
+        let mut rhs = nalgebra::DVector::<f64>::zeros(n + 1);
+
+        for i in 0..n {
+            for j in 0..n {
+                let h = link_separation(&self.samples[i], &self.samples[j]);
+                system[(i, j)] = self.variogram.covariance(h);
+            }
+            system[(i, n)] = 1.0;
+            system[(n, i)] = 1.0;
+
+            let h0 = link_separation(&self.samples[i], query);
+            rhs[i] = self.variogram.covariance(h0);
+        }
+        rhs[n] = 1.0;
+
+        let Some(solution) = system.lu().solve(&rhs) else {
+            let mean_residual: f64 = self.residuals.iter().sum::<f64>() / n as f64;
+            return mean_residual;
+        };
+
+        (0..n).map(|i| solution[i] * self.residuals[i]).sum()
+    }
+
+    /// Number of samples the map was built from.
+    pub fn num_samples(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::backend::{BackendConfig, Geometry, Material, SceneObject, Transform};
+    use autonomysim_core::native::NativeBackend;
+    use nalgebra::{Point3, Vector3};
+
+    async fn empty_scene() -> (NativeBackend, SceneHandle) {
+        let mut backend = NativeBackend::new();
+        backend.initialize(BackendConfig::default()).await.unwrap();
+        let scene = backend.load_scene("empty.obj").await.unwrap();
+        (backend, scene)
+    }
+
+    #[test]
+    fn variogram_covariance_decreases_to_zero_at_the_sill_beyond_the_range() {
+        let variogram = Variogram {
+            nugget: 0.0,
+            sill: 4.0,
+            range: 10.0,
+        };
+        assert!(variogram.covariance(0.0) > variogram.covariance(50.0));
+        assert!(variogram.covariance(50.0) < 0.5);
+    }
+
+    #[test]
+    fn fit_variogram_falls_back_to_a_default_with_fewer_than_two_samples() {
+        let samples = vec![LinkSample {
+            tx: Point3::new(0.0, 0.0, 10.0),
+            rx: Point3::new(10.0, 0.0, 1.5),
+            gain_db: -60.0,
+        }];
+        let variogram = fit_variogram(&samples, &[0.0]);
+        assert!(variogram.sill > 0.0);
+        assert!(variogram.range > 0.0);
+    }
+
+    #[tokio::test]
+    async fn radio_map_predicts_finite_gain_from_sparse_samples() {
+        let (backend, scene) = empty_scene().await;
+        let measurements: Vec<LinkSample> = (0..12)
+            .map(|i| {
+                let t = i as f64;
+                LinkSample {
+                    tx: Point3::new(0.0, 0.0, 10.0),
+                    rx: Point3::new(50.0 + t * 20.0, t * 5.0, 1.5),
+                    gain_db: -40.0 - t,
+                }
+            })
+            .collect();
+
+        let map = RadioMap::build(&measurements, &backend, &scene, 0.125).unwrap();
+        assert_eq!(map.num_samples(), 12);
+
+        let predicted = map
+            .predict(
+                Point3::new(0.0, 0.0, 10.0),
+                Point3::new(100.0, 10.0, 1.5),
+                &backend,
+                &scene,
+            )
+            .unwrap();
+        assert!(predicted.is_finite());
+    }
+
+    #[tokio::test]
+    async fn coverage_map_sweeps_every_rx_position() {
+        let (backend, scene) = empty_scene().await;
+        let measurements: Vec<LinkSample> = (0..6)
+            .map(|i| {
+                let t = i as f64;
+                LinkSample {
+                    tx: Point3::new(0.0, 0.0, 10.0),
+                    rx: Point3::new(20.0 + t * 10.0, 0.0, 1.5),
+                    gain_db: -50.0 - t,
+                }
+            })
+            .collect();
+
+        let map = RadioMap::build(&measurements, &backend, &scene, 0.125).unwrap();
+        let rx_positions = vec![
+            Point3::new(30.0, 0.0, 1.5),
+            Point3::new(60.0, 0.0, 1.5),
+            Point3::new(90.0, 0.0, 1.5),
+        ];
+
+        let coverage = map
+            .coverage_map(Point3::new(0.0, 0.0, 10.0), &rx_positions, &backend, &scene)
+            .unwrap();
+        assert_eq!(coverage.len(), 3);
+        assert!(coverage.iter().all(|g| g.is_finite()));
+    }
+
+    #[tokio::test]
+    async fn an_obstructed_link_predicts_lower_gain_than_a_clear_one_at_the_same_distance() {
+        let (mut backend, scene) = empty_scene().await;
+        backend
+            .add_object(
+                &scene,
+                SceneObject {
+                    id: "wall".to_string(),
+                    name: "wall".to_string(),
+                    geometry: Geometry::Box {
+                        size: Vector3::new(2.0, 100.0, 20.0),
+                    },
+                    material: Material::air(),
+                    transform: Transform {
+                        position: Point3::new(50.0, 0.0, 10.0),
+                        ..Transform::identity()
+                    },
+                },
+            )
+            .unwrap();
+
+        let measurements = vec![
+            LinkSample {
+                tx: Point3::new(0.0, 0.0, 5.0),
+                rx: Point3::new(20.0, 0.0, 5.0),
+                gain_db: -55.0,
+            },
+            LinkSample {
+                tx: Point3::new(0.0, 10.0, 5.0),
+                rx: Point3::new(20.0, 10.0, 5.0),
+                gain_db: -55.0,
+            },
+            LinkSample {
+                tx: Point3::new(0.0, -10.0, 5.0),
+                rx: Point3::new(20.0, -10.0, 5.0),
+                gain_db: -55.0,
+            },
+        ];
+        let map = RadioMap::build(&measurements, &backend, &scene, 0.125).unwrap();
+
+        let clear = classify_link(
+            &backend,
+            &scene,
+            Point3::new(0.0, 0.0, 5.0),
+            Point3::new(20.0, 0.0, 5.0),
+        )
+        .unwrap();
+        let blocked = classify_link(
+            &backend,
+            &scene,
+            Point3::new(0.0, 0.0, 5.0),
+            Point3::new(100.0, 0.0, 5.0),
+        )
+        .unwrap();
+        assert_eq!(clear, LinkRegime::LineOfSight);
+        assert_eq!(blocked, LinkRegime::Obstructed);
+
+        let gain_clear = map
+            .predict(
+                Point3::new(0.0, 0.0, 5.0),
+                Point3::new(20.0, 0.0, 5.0),
+                &backend,
+                &scene,
+            )
+            .unwrap();
+        let gain_blocked = map
+            .predict(
+                Point3::new(0.0, 0.0, 5.0),
+                Point3::new(100.0, 0.0, 5.0),
+                &backend,
+                &scene,
+            )
+            .unwrap();
+        assert!(gain_blocked < gain_clear);
+    }
+}
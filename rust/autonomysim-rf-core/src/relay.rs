@@ -0,0 +1,241 @@
+//! Relay-selected mesh connectivity, in place of an all-pairs link count
+//!
+//! The swarm demos track connectivity as a naive fully-connected mesh
+//! (`n*(n-1)/2` links, no notion of routing). [`select_relays`] instead
+//! greedily picks the smallest subset of `candidate_relays` (e.g. the
+//! `Relay`/`Coordinator` roles) such that every agent has a usable link --
+//! per [`RFPropagationEngine::compute_link`] -- to at least one chosen
+//! relay, turning an O(n^2) broadcast into O(n*k) relayed traffic for `k`
+//! relays. [`MessageAccounting`] reports both costs side by side so callers
+//! can quantify the savings, and [`RelayTopology::isolated`] surfaces any
+//! agent no chosen relay can reach -- e.g. because jamming or distance
+//! dropped every candidate link below `min_snr_db`.
+//!
+//! Recompute by calling [`select_relays`] again whenever positions move or
+//! a link budget changes (jamming toggled, a relay destroyed); this module
+//! has no notion of simulation time and holds no state between calls.
+
+use std::collections::{HashMap, HashSet};
+
+use nalgebra::Point3;
+
+use crate::propagation::{RFPropagationEngine, RFResult};
+
+/// Identifies an agent across a [`RelayTopology`].
+pub type AgentId = usize;
+
+/// Which relay (if any) covers each agent, from the most recent
+/// [`select_relays`] call.
+#[derive(Debug, Clone, Default)]
+pub struct RelayTopology {
+    relays: Vec<AgentId>,
+    assigned_relay: HashMap<AgentId, AgentId>,
+}
+
+impl RelayTopology {
+    /// Chosen relay nodes, in selection order.
+    pub fn relays(&self) -> &[AgentId] {
+        &self.relays
+    }
+
+    /// Whether `agent` has a usable link to some chosen relay (a relay is
+    /// always reachable from itself).
+    pub fn is_reachable(&self, agent: AgentId) -> bool {
+        self.assigned_relay.contains_key(&agent)
+    }
+
+    /// The relay `agent` would forward through, if reachable.
+    pub fn assigned_relay(&self, agent: AgentId) -> Option<AgentId> {
+        self.assigned_relay.get(&agent).copied()
+    }
+
+    /// Agents in `all_agents` with no usable link to any chosen relay.
+    pub fn isolated(&self, all_agents: &[AgentId]) -> Vec<AgentId> {
+        all_agents
+            .iter()
+            .copied()
+            .filter(|agent| !self.is_reachable(*agent))
+            .collect()
+    }
+}
+
+/// Messaging overhead comparison between naive all-to-all broadcast and
+/// relay-routed forwarding over the same agent set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageAccounting {
+    /// Transmissions a naive all-to-all broadcast costs per round: every
+    /// agent pair attempts delivery regardless of reachability.
+    pub naive_transmissions: usize,
+    /// Transmissions relay-routed forwarding costs per round: one hop from
+    /// each reachable non-relay agent to its assigned relay, plus one hop
+    /// between every pair of relays so they stay interconnected.
+    pub relayed_transmissions: usize,
+}
+
+/// Transmissions an all-to-all broadcast costs each round, regardless of
+/// whether a path exists between any given pair.
+pub fn count_naive_broadcast(num_agents: usize) -> usize {
+    num_agents.saturating_mul(num_agents.saturating_sub(1)) / 2
+}
+
+/// Transmissions relay-routed forwarding costs each round over `topology`.
+pub fn count_relay_routed(topology: &RelayTopology, all_agents: &[AgentId]) -> usize {
+    let non_relay_hops = all_agents
+        .iter()
+        .filter(|agent| !topology.relays.contains(agent))
+        .filter(|agent| topology.is_reachable(**agent))
+        .count();
+    let relay_mesh_hops = count_naive_broadcast(topology.relays.len());
+    non_relay_hops + relay_mesh_hops
+}
+
+/// Compare naive all-to-all broadcast against relay-routed forwarding for
+/// `all_agents` over `topology`.
+pub fn compare_messaging_strategies(
+    topology: &RelayTopology,
+    all_agents: &[AgentId],
+) -> MessageAccounting {
+    MessageAccounting {
+        naive_transmissions: count_naive_broadcast(all_agents.len()),
+        relayed_transmissions: count_relay_routed(topology, all_agents),
+    }
+}
+
+/// Greedily choose the smallest subset of `candidate_relays` covering every
+/// entry in `agents` with a usable link (per [`RFPropagationEngine::compute_link`]
+/// at `bandwidth_hz`/`min_snr_db`), repeatedly picking whichever remaining
+/// candidate covers the most still-uncovered agents until none do.
+pub async fn select_relays(
+    engine: &RFPropagationEngine,
+    agents: &[(AgentId, Point3<f64>)],
+    candidate_relays: &[AgentId],
+    bandwidth_hz: f64,
+    min_snr_db: f64,
+) -> RFResult<RelayTopology> {
+    let positions: HashMap<AgentId, Point3<f64>> = agents.iter().copied().collect();
+    let mut uncovered: HashSet<AgentId> = agents.iter().map(|(id, _)| *id).collect();
+    let mut remaining_candidates: Vec<AgentId> = candidate_relays.to_vec();
+
+    let mut topology = RelayTopology::default();
+
+    loop {
+        let mut best: Option<(AgentId, Vec<AgentId>)> = None;
+
+        for &candidate in &remaining_candidates {
+            let Some(&relay_pos) = positions.get(&candidate) else {
+                continue;
+            };
+            let mut covers = Vec::new();
+            for &agent in &uncovered {
+                let agent_pos = positions[&agent];
+                let usable = agent == candidate || {
+                    let link = engine
+                        .compute_link(relay_pos, agent_pos, bandwidth_hz)
+                        .await?;
+                    link.is_connected && link.snr_db >= min_snr_db
+                };
+                if usable {
+                    covers.push(agent);
+                }
+            }
+            let is_better = match &best {
+                Some((_, best_covers)) => covers.len() > best_covers.len(),
+                None => true,
+            };
+            if is_better {
+                best = Some((candidate, covers));
+            }
+        }
+
+        let Some((relay, covers)) = best else {
+            break;
+        };
+        if covers.is_empty() {
+            break;
+        }
+
+        for agent in &covers {
+            uncovered.remove(agent);
+            topology.assigned_relay.insert(*agent, relay);
+        }
+        topology.assigned_relay.insert(relay, relay);
+        topology.relays.push(relay);
+        remaining_candidates.retain(|id| *id != relay);
+
+        if uncovered.is_empty() {
+            break;
+        }
+    }
+
+    Ok(topology)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::propagation::{PropagationConfig, PropagationModel};
+    use autonomysim_core::native::NativeBackend;
+    use std::sync::Arc;
+
+    fn engine() -> RFPropagationEngine {
+        let backend = Arc::new(NativeBackend::new());
+        let config = PropagationConfig {
+            model: PropagationModel::Friis,
+            frequency_hz: 2.4e9,
+            tx_power_dbm: 30.0,
+            ..Default::default()
+        };
+        RFPropagationEngine::new(backend, config)
+    }
+
+    #[tokio::test]
+    async fn select_relays_covers_nearby_agents() {
+        let agents = vec![
+            (0, Point3::new(0.0, 0.0, 0.0)),
+            (1, Point3::new(10.0, 0.0, 0.0)),
+            (2, Point3::new(-10.0, 0.0, 0.0)),
+        ];
+        let topology = select_relays(&engine(), &agents, &[0], 20e6, -10.0)
+            .await
+            .unwrap();
+
+        assert_eq!(topology.relays(), &[0]);
+        assert!(topology.is_reachable(1));
+        assert!(topology.is_reachable(2));
+        assert_eq!(topology.assigned_relay(1), Some(0));
+    }
+
+    #[tokio::test]
+    async fn out_of_range_agent_is_isolated() {
+        let agents = vec![
+            (0, Point3::new(0.0, 0.0, 0.0)),
+            (1, Point3::new(1_000_000.0, 0.0, 0.0)),
+        ];
+        let topology = select_relays(&engine(), &agents, &[0], 20e6, -10.0)
+            .await
+            .unwrap();
+
+        assert_eq!(topology.isolated(&[0, 1]), vec![1]);
+    }
+
+    #[test]
+    fn naive_broadcast_count_ignores_topology() {
+        assert_eq!(count_naive_broadcast(5), 10);
+        assert_eq!(count_naive_broadcast(1), 0);
+        assert_eq!(count_naive_broadcast(0), 0);
+    }
+
+    #[tokio::test]
+    async fn relay_routing_costs_far_fewer_transmissions_than_broadcast() {
+        let agents: Vec<(AgentId, Point3<f64>)> = (0..10)
+            .map(|i| (i, Point3::new(i as f64 * 5.0, 0.0, 0.0)))
+            .collect();
+        let all_agents: Vec<AgentId> = agents.iter().map(|(id, _)| *id).collect();
+        let topology = select_relays(&engine(), &agents, &[0], 20e6, -10.0)
+            .await
+            .unwrap();
+
+        let accounting = compare_messaging_strategies(&topology, &all_agents);
+        assert!(accounting.relayed_transmissions < accounting.naive_transmissions);
+    }
+}
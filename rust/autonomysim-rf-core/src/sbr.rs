@@ -0,0 +1,503 @@
+//! Shooting-and-bouncing-rays (SBR) propagation engine
+//!
+//! [`crate::propagation::RFPropagationEngine`] answers "what is the path
+//! loss between these two points" with a single number per model.
+//! [`PropagationEngine`] instead launches a fan of [`RayTube`]s from the
+//! transmitter over the full sphere, bounces each one specularly off scene
+//! geometry (attenuating it by the hit material's Fresnel reflection
+//! coefficient every bounce), and coherently sums whichever tubes pass
+//! within a capture radius of the receiver into a multipath channel: a
+//! received power, an RMS delay spread, and the individual paths that made
+//! it up.
+
+use crate::models::{FresnelCalculator, KnifeEdgeDiffraction, RayTube};
+use crate::propagation::{PropagationConfig, RFError, RFResult};
+use autonomysim_core::prelude::*;
+use nalgebra::{Point3, Vector3};
+use std::sync::Arc;
+
+/// One ray tube's contribution at the receiver: its arrival power and the
+/// path length that produced its phase and delay.
+#[derive(Debug, Clone, Copy)]
+pub struct PathContribution {
+    /// Power arriving via this path, in dBm.
+    pub power_dbm: f64,
+    /// Total distance traveled from transmitter to this arrival, in meters.
+    pub path_length: f64,
+    /// Number of specular reflections along this path (0 for the direct or
+    /// diffracted path).
+    pub num_reflections: usize,
+    /// Whether this path is the direct line-of-sight ray diffracted around
+    /// an obstruction, rather than a reflected ray tube.
+    pub is_diffraction: bool,
+}
+
+/// Output of [`PropagationEngine::trace_channel`].
+#[derive(Debug, Clone)]
+pub struct ChannelResult {
+    /// Coherent sum of every captured path's power, in dBm.
+    pub received_power_dbm: f64,
+    /// RMS delay spread of the power-delay profile, in seconds.
+    pub rms_delay_spread_s: f64,
+    /// Every path that was captured at the receiver.
+    pub paths: Vec<PathContribution>,
+}
+
+/// Shooting-and-bouncing-rays multipath channel engine. Mirrors
+/// [`crate::propagation::RFPropagationEngine`]'s shape (backend + config +
+/// scene), but traces an explicit ray fan instead of evaluating a closed-form
+/// model.
+pub struct PropagationEngine {
+    backend: Arc<dyn SimulationBackend>,
+    config: PropagationConfig,
+    scene: Option<SceneHandle>,
+}
+
+impl PropagationEngine {
+    /// Create a new SBR propagation engine.
+    pub fn new(backend: Arc<dyn SimulationBackend>, config: PropagationConfig) -> Self {
+        Self {
+            backend,
+            config,
+            scene: None,
+        }
+    }
+
+    /// Set the scene to trace rays through.
+    pub fn set_scene(&mut self, scene: SceneHandle) {
+        self.scene = Some(scene);
+    }
+
+    /// Get the current configuration.
+    pub fn config(&self) -> &PropagationConfig {
+        &self.config
+    }
+
+    /// Trace a coherent multipath channel between `tx_pos` and `rx_pos`:
+    /// launch `config.num_rays` tubes from the transmitter over the full
+    /// sphere, bounce each off scene geometry up to `config.max_reflections`
+    /// times or until it drops below `config.min_path_power_dbm`, and
+    /// coherently sum whichever pass within `config.capture_radius_m` of the
+    /// receiver. Adds a diffracted direct path when line of sight is
+    /// blocked.
+    pub async fn trace_channel(
+        &self,
+        tx_pos: Point3<f64>,
+        rx_pos: Point3<f64>,
+    ) -> RFResult<ChannelResult> {
+        self.config.validate()?;
+
+        let scene = self
+            .scene
+            .as_ref()
+            .ok_or_else(|| RFError::ComputationError("No scene set for ray tracing".to_string()))?;
+
+        let mut paths = Vec::new();
+        paths.extend(self.trace_direct_or_diffracted(scene, tx_pos, rx_pos)?);
+
+        for direction in fibonacci_sphere_directions(self.config.num_rays) {
+            let tube = RayTube::new(tx_pos.coords, direction, self.config.tx_power_dbm);
+            paths.extend(self.bounce_tube(scene, tube, rx_pos)?);
+        }
+
+        Ok(combine_paths(paths, self.config.wavelength()))
+    }
+
+    /// The unobstructed direct path (Friis loss), or a diffracted path
+    /// around the first obstruction when line of sight is blocked.
+    fn trace_direct_or_diffracted(
+        &self,
+        scene: &SceneHandle,
+        tx_pos: Point3<f64>,
+        rx_pos: Point3<f64>,
+    ) -> RFResult<Option<PathContribution>> {
+        let distance = (rx_pos - tx_pos).norm().max(1e-3);
+        let direction = (rx_pos - tx_pos) / distance;
+        let ray = Ray {
+            origin: tx_pos,
+            direction,
+            max_distance: distance,
+        };
+
+        let friis_power_dbm = self.config.tx_power_dbm
+            - 20.0 * (4.0 * std::f64::consts::PI * distance / self.config.wavelength()).log10();
+
+        let Some(hit) = self.backend.cast_ray(scene, &ray)? else {
+            return Ok(Some(PathContribution {
+                power_dbm: friis_power_dbm,
+                path_length: distance,
+                num_reflections: 0,
+                is_diffraction: false,
+            }));
+        };
+
+        if !self.config.enable_diffraction {
+            return Ok(None);
+        }
+
+        let diffraction_loss = self.diffraction_loss_db(scene, tx_pos, rx_pos, &hit, distance)?;
+
+        Ok(Some(PathContribution {
+            power_dbm: friis_power_dbm - diffraction_loss,
+            path_length: distance,
+            num_reflections: 0,
+            is_diffraction: true,
+        }))
+    }
+
+    /// Knife-edge diffraction loss around an obstruction, found by probing
+    /// straight down onto the blocking hit from well above it, the same way
+    /// [`crate::propagation::RFPropagationEngine::diffraction_excess_loss_db`]
+    /// does.
+    fn diffraction_loss_db(
+        &self,
+        scene: &SceneHandle,
+        tx_pos: Point3<f64>,
+        rx_pos: Point3<f64>,
+        hit: &RayHit,
+        total_distance: f64,
+    ) -> RFResult<f64> {
+        const PROBE_HEIGHT: f64 = 10_000.0;
+
+        let d1 = hit.distance.max(1e-3);
+        let d2 = (total_distance - hit.distance).max(1e-3);
+
+        let probe_ray = Ray {
+            origin: Point3::new(
+                hit.position.x,
+                hit.position.y,
+                hit.position.z + PROBE_HEIGHT,
+            ),
+            direction: Vector3::new(0.0, 0.0, -1.0),
+            max_distance: PROBE_HEIGHT + 1.0,
+        };
+        let edge_z = match self.backend.cast_ray(scene, &probe_ray)? {
+            Some(top_hit) => top_hit.position.z,
+            None => hit.position.z,
+        };
+
+        let fraction = hit.distance / total_distance.max(1e-6);
+        let los_z = tx_pos.z + (rx_pos.z - tx_pos.z) * fraction;
+        let h = edge_z - los_z;
+
+        Ok(KnifeEdgeDiffraction::calculate_loss(
+            h,
+            d1,
+            d2,
+            self.config.wavelength(),
+        ))
+    }
+
+    /// Bounce a single ray tube through the scene, capturing a contribution
+    /// each time it passes within the receiver's capture radius, until it
+    /// exceeds `max_reflections` or falls below `min_path_power_dbm`.
+    fn bounce_tube(
+        &self,
+        scene: &SceneHandle,
+        mut tube: RayTube,
+        rx_pos: Point3<f64>,
+    ) -> RFResult<Vec<PathContribution>> {
+        let mut captures = Vec::new();
+
+        loop {
+            if tube.num_reflections > self.config.max_reflections
+                || tube.power_dbm < self.config.min_path_power_dbm
+            {
+                break;
+            }
+
+            let origin = Point3::from(tube.origin);
+            let ray = Ray {
+                origin,
+                direction: tube.direction,
+                max_distance: self.config.max_distance,
+            };
+            let hit = self.backend.cast_ray(scene, &ray)?;
+            let segment_length = hit
+                .as_ref()
+                .map_or(self.config.max_distance, |h| h.distance);
+
+            if let Some(extra) = capture_distance(
+                origin,
+                tube.direction,
+                segment_length,
+                rx_pos,
+                self.config.capture_radius_m,
+            ) {
+                captures.push(PathContribution {
+                    power_dbm: tube.power_dbm,
+                    path_length: tube.path_length + extra,
+                    num_reflections: tube.num_reflections,
+                    is_diffraction: false,
+                });
+            }
+
+            let Some(hit) = hit else {
+                break;
+            };
+
+            let normal = hit.normal.normalize();
+            let reflectance = self.reflection_coefficient(&tube.direction, &normal, &hit);
+            let reflection_loss_db = -20.0 * reflectance.max(1e-12).log10();
+
+            tube.path_length += segment_length;
+            tube.power_dbm -= reflection_loss_db;
+            tube.num_reflections += 1;
+            tube.direction = reflect(&tube.direction, &normal);
+            tube.origin = hit.position.coords;
+        }
+
+        Ok(captures)
+    }
+
+    /// Fresnel reflection coefficient at a bounce, using the hit material's
+    /// permittivity/conductivity and the configured polarization (vertical
+    /// maps to parallel, horizontal to perpendicular).
+    fn reflection_coefficient(
+        &self,
+        direction: &Vector3<f64>,
+        normal: &Vector3<f64>,
+        hit: &RayHit,
+    ) -> f64 {
+        let cos_incidence = (-direction).dot(normal).clamp(-1.0, 1.0);
+        let incident_angle = cos_incidence.acos();
+
+        match self.config.polarization {
+            crate::itm::Polarization::Vertical => FresnelCalculator::reflection_parallel(
+                incident_angle,
+                hit.material.permittivity,
+                hit.material.conductivity,
+                self.config.frequency_hz,
+            ),
+            crate::itm::Polarization::Horizontal => FresnelCalculator::reflection_perpendicular(
+                incident_angle,
+                hit.material.permittivity,
+                hit.material.conductivity,
+                self.config.frequency_hz,
+            ),
+        }
+    }
+}
+
+/// Reflect `direction` about unit `normal`.
+fn reflect(direction: &Vector3<f64>, normal: &Vector3<f64>) -> Vector3<f64> {
+    direction - 2.0 * direction.dot(normal) * normal
+}
+
+/// Distance along a bounded ray segment `[origin, origin + direction *
+/// segment_length]` to its closest approach to `target`, if that approach
+/// comes within `radius` of `target`.
+fn capture_distance(
+    origin: Point3<f64>,
+    direction: Vector3<f64>,
+    segment_length: f64,
+    target: Point3<f64>,
+    radius: f64,
+) -> Option<f64> {
+    let to_target = target - origin;
+    let t = to_target.dot(&direction).clamp(0.0, segment_length);
+    let closest = origin + direction * t;
+    if (closest - target).norm() <= radius {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+/// `count` roughly-uniform directions over the unit sphere via the Fibonacci
+/// (golden-angle) spiral.
+fn fibonacci_sphere_directions(count: usize) -> Vec<Vector3<f64>> {
+    if count == 0 {
+        return Vec::new();
+    }
+    if count == 1 {
+        return vec![Vector3::new(0.0, 0.0, 1.0)];
+    }
+
+    let golden_angle = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+    (0..count)
+        .map(|i| {
+            let y = 1.0 - 2.0 * (i as f64) / (count as f64 - 1.0);
+            let radius_at_y = (1.0 - y * y).max(0.0).sqrt();
+            let theta = golden_angle * i as f64;
+            Vector3::new(theta.cos() * radius_at_y, theta.sin() * radius_at_y, y)
+        })
+        .collect()
+}
+
+/// Coherently sum each path's power and phase (`2*pi*path_length/wavelength`)
+/// into a received power, and separately build a power-delay profile from
+/// the same paths to derive an RMS delay spread.
+fn combine_paths(paths: Vec<PathContribution>, wavelength: f64) -> ChannelResult {
+    if paths.is_empty() {
+        return ChannelResult {
+            received_power_dbm: f64::NEG_INFINITY,
+            rms_delay_spread_s: 0.0,
+            paths,
+        };
+    }
+
+    let mut sum_re = 0.0;
+    let mut sum_im = 0.0;
+    let mut total_linear_power = 0.0;
+    let mut weighted_delay = 0.0;
+
+    for path in &paths {
+        let linear_power_mw = 10f64.powf(path.power_dbm / 10.0);
+        let amplitude = linear_power_mw.sqrt();
+        let phase = 2.0 * std::f64::consts::PI * path.path_length / wavelength;
+
+        sum_re += amplitude * phase.cos();
+        sum_im += amplitude * phase.sin();
+
+        let delay = path.path_length / crate::constants::SPEED_OF_LIGHT;
+        total_linear_power += linear_power_mw;
+        weighted_delay += linear_power_mw * delay;
+    }
+
+    let received_linear_mw = sum_re * sum_re + sum_im * sum_im;
+    let received_power_dbm = 10.0 * received_linear_mw.max(1e-300).log10();
+
+    let mean_delay = weighted_delay / total_linear_power.max(1e-300);
+    let variance = paths
+        .iter()
+        .map(|path| {
+            let delay = path.path_length / crate::constants::SPEED_OF_LIGHT;
+            let linear_power_mw = 10f64.powf(path.power_dbm / 10.0);
+            linear_power_mw * (delay - mean_delay).powi(2)
+        })
+        .sum::<f64>()
+        / total_linear_power.max(1e-300);
+
+    ChannelResult {
+        received_power_dbm,
+        rms_delay_spread_s: variance.sqrt(),
+        paths,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::native::NativeBackend;
+
+    fn test_config() -> PropagationConfig {
+        PropagationConfig {
+            frequency_hz: 2.4e9,
+            tx_power_dbm: 20.0,
+            num_rays: 200,
+            max_reflections: 3,
+            max_distance: 200.0,
+            capture_radius_m: 2.0,
+            min_path_power_dbm: -80.0,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clear_los_channel_matches_friis_power() {
+        let mut backend = NativeBackend::new();
+        backend
+            .initialize(autonomysim_core::backend::BackendConfig::default())
+            .await
+            .unwrap();
+        let scene = backend.load_scene("empty.obj").await.unwrap();
+
+        let mut engine = PropagationEngine::new(Arc::new(backend), test_config());
+        engine.set_scene(scene);
+
+        let tx = Point3::new(0.0, 0.0, 1.0);
+        let rx = Point3::new(50.0, 0.0, 1.0);
+
+        let result = engine.trace_channel(tx, rx).await.unwrap();
+
+        let distance = 50.0_f64;
+        let friis_power = 20.0
+            - 20.0 * (4.0 * std::f64::consts::PI * distance / engine.config().wavelength()).log10();
+
+        assert!((result.received_power_dbm - friis_power).abs() < 1.0);
+        assert!(result.paths.iter().any(|p| !p.is_diffraction));
+    }
+
+    #[tokio::test]
+    async fn test_blocked_los_reports_diffracted_path() {
+        let mut backend = NativeBackend::new();
+        backend
+            .initialize(autonomysim_core::backend::BackendConfig::default())
+            .await
+            .unwrap();
+        let scene = backend.load_scene("wall.obj").await.unwrap();
+        backend
+            .add_object(
+                &scene,
+                autonomysim_core::backend::SceneObject {
+                    id: "wall".to_string(),
+                    name: "wall".to_string(),
+                    geometry: autonomysim_core::backend::Geometry::Box {
+                        size: nalgebra::Vector3::new(1.0, 20.0, 5.0),
+                    },
+                    material: autonomysim_core::backend::Material::air(),
+                    transform: autonomysim_core::backend::Transform {
+                        position: Point3::new(25.0, 0.0, 2.5),
+                        ..autonomysim_core::backend::Transform::identity()
+                    },
+                },
+            )
+            .unwrap();
+
+        let mut engine = PropagationEngine::new(Arc::new(backend), test_config());
+        engine.set_scene(scene);
+
+        let tx = Point3::new(0.0, 0.0, 1.0);
+        let rx = Point3::new(50.0, 0.0, 1.0);
+
+        let result = engine.trace_channel(tx, rx).await.unwrap();
+
+        assert!(result.paths.iter().any(|p| p.is_diffraction));
+        assert!(result.received_power_dbm.is_finite());
+    }
+
+    #[test]
+    fn test_capture_distance_hits_within_radius() {
+        let origin = Point3::new(0.0, 0.0, 0.0);
+        let direction = Vector3::new(1.0, 0.0, 0.0);
+
+        let captured = capture_distance(origin, direction, 10.0, Point3::new(5.0, 1.0, 0.0), 2.0);
+        assert_eq!(captured, Some(5.0));
+
+        let missed = capture_distance(origin, direction, 10.0, Point3::new(5.0, 5.0, 0.0), 2.0);
+        assert_eq!(missed, None);
+    }
+
+    #[test]
+    fn test_fibonacci_sphere_directions_are_unit_length_and_spread_out() {
+        let directions = fibonacci_sphere_directions(64);
+        assert_eq!(directions.len(), 64);
+        for direction in &directions {
+            assert!((direction.norm() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_combine_paths_sums_power_coherently() {
+        let paths = vec![
+            PathContribution {
+                power_dbm: 0.0,
+                path_length: 10.0,
+                num_reflections: 0,
+                is_diffraction: false,
+            },
+            PathContribution {
+                power_dbm: 0.0,
+                path_length: 10.0,
+                num_reflections: 0,
+                is_diffraction: false,
+            },
+        ];
+
+        let result = combine_paths(paths, 0.125);
+        // Two in-phase equal-amplitude paths double the amplitude, quadrupling
+        // linear power: +6 dB over a single path.
+        assert!((result.received_power_dbm - 6.0).abs() < 1e-6);
+    }
+}
@@ -1,5 +1,6 @@
 //! Utility functions for RF calculations
 
+use crate::antenna::PolarizationType;
 use crate::constants::*;
 
 /// Convert power from Watts to dBm
@@ -68,8 +69,188 @@ pub fn link_budget(
     rx_gain_dbi: f64,
     path_loss_db: f64,
     system_loss_db: f64,
+    polarization_loss_db: f64,
 ) -> f64 {
-    tx_power_dbm + tx_gain_dbi + rx_gain_dbi - path_loss_db - system_loss_db
+    tx_power_dbm + tx_gain_dbi + rx_gain_dbi - path_loss_db - system_loss_db - polarization_loss_db
+}
+
+/// Polarization mismatch loss (dB, always `>= 0`) between a transmit and
+/// receive antenna's [`PolarizationType`]. Identical linear polarizations
+/// give `0 dB` (or `-20*log10(cos(tilt_offset_deg))` if `tilt_offset_deg`
+/// is non-zero); orthogonal linear (vertical vs. horizontal) and
+/// counter-rotating circular give a deep cross-polarization floor; a
+/// linear/circular mix gives the standard `3 dB` loss; co-rotating
+/// circular gives `0 dB`. `tilt_offset_deg` is only meaningful between two
+/// linear polarizations.
+pub fn polarization_loss_db(
+    tx: PolarizationType,
+    rx: PolarizationType,
+    tilt_offset_deg: f64,
+) -> f64 {
+    use PolarizationType::*;
+
+    /// Loss floor for a fully cross-polarized link (orthogonal linear, or
+    /// counter-rotating circular).
+    const CROSS_POL_FLOOR_DB: f64 = 25.0;
+    /// Loss for a linear antenna paired with a circular one.
+    const LINEAR_TO_CIRCULAR_DB: f64 = 3.0;
+
+    let is_linear = |p: PolarizationType| matches!(p, Vertical | Horizontal);
+    let is_circular = |p: PolarizationType| matches!(p, CircularRight | CircularLeft);
+
+    match (tx, rx) {
+        (a, b) if is_linear(a) && is_linear(b) => {
+            if a == b {
+                -20.0 * tilt_offset_deg.to_radians().cos().abs().log10()
+            } else {
+                CROSS_POL_FLOOR_DB
+            }
+        }
+        (a, b) if is_circular(a) && is_circular(b) => {
+            if a == b {
+                0.0
+            } else {
+                CROSS_POL_FLOOR_DB
+            }
+        }
+        // One linear, one circular (or either side is `Elliptical`).
+        _ => LINEAR_TO_CIRCULAR_DB,
+    }
+}
+
+/// Geometry inputs shared by the [`PathLossModel`] variants that depend on
+/// antenna height above ground (two-ray ground reflection, ITU indoor).
+#[derive(Debug, Clone, Copy)]
+pub struct PathLossGeometry {
+    pub tx_height_m: f64,
+    pub rx_height_m: f64,
+}
+
+impl Default for PathLossGeometry {
+    /// Typical hand-held ground-station / low-hover UAV heights.
+    fn default() -> Self {
+        Self {
+            tx_height_m: 1.5,
+            rx_height_m: 1.5,
+        }
+    }
+}
+
+/// Empirical path-loss model selector for [`link_budget`] callers.
+/// `Friis` is the plain free-space model already computed by
+/// [`friis_path_loss`]; the other variants trade its line-of-sight accuracy
+/// for the environmental effect each one targets.
+#[derive(Debug, Clone, Copy)]
+pub enum PathLossModel {
+    /// Free-space path loss — see [`friis_path_loss`].
+    Friis,
+    /// Two-ray ground reflection: Friis below the breakpoint distance
+    /// `d_bp = 4*h_tx*h_rx/λ`, then `40*log10(d) - 20*log10(h_tx*h_rx)`.
+    TwoRay,
+    /// Log-distance path loss with a configurable exponent and optional
+    /// log-normal shadowing: `PL(d) = PL(d0) + 10*n*log10(d/d0) + X_σ`.
+    LogDistance {
+        reference_distance_m: f64,
+        path_loss_exponent: f64,
+        /// Standard deviation (dB) of the log-normal shadowing term;
+        /// `0.0` disables shadowing and makes the model deterministic.
+        shadowing_std_db: f64,
+        /// Seed for the shadowing draw, mixed with the query distance so
+        /// repeated calls at the same distance give the same sample.
+        shadowing_seed: u64,
+    },
+    /// Simple ITU-R P.1238-style indoor model:
+    /// `20*log10(f_MHz) + N*log10(d) + Pf(n) - 28`, where `Pf(n)` is the
+    /// total floor-penetration loss for `num_floors` penetrated floors.
+    ItuIndoor {
+        distance_power_loss_coefficient: f64,
+        floor_penetration_loss_db: f64,
+        num_floors: u32,
+    },
+}
+
+impl PathLossModel {
+    /// Evaluate this model's path loss (dB) at `distance_m` and
+    /// `frequency_hz`. `geometry` is only consulted by the variants that
+    /// need antenna heights (`TwoRay`); the others ignore it.
+    pub fn loss(&self, distance_m: f64, frequency_hz: f64, geometry: PathLossGeometry) -> f64 {
+        match self {
+            PathLossModel::Friis => friis_path_loss(distance_m, frequency_hz),
+            PathLossModel::TwoRay => {
+                let wavelength = SPEED_OF_LIGHT / frequency_hz;
+                let breakpoint = 4.0 * geometry.tx_height_m * geometry.rx_height_m / wavelength;
+                if distance_m < breakpoint {
+                    friis_path_loss(distance_m, frequency_hz)
+                } else {
+                    40.0 * distance_m.log10()
+                        - 20.0 * (geometry.tx_height_m * geometry.rx_height_m).log10()
+                }
+            }
+            PathLossModel::LogDistance {
+                reference_distance_m,
+                path_loss_exponent,
+                shadowing_std_db,
+                shadowing_seed,
+            } => {
+                let pl_d0 = friis_path_loss(*reference_distance_m, frequency_hz);
+                let mean = pl_d0
+                    + 10.0
+                        * path_loss_exponent
+                        * (distance_m / reference_distance_m).max(1e-9).log10();
+                if *shadowing_std_db > 0.0 {
+                    let mut rng = Rng::new(shadowing_seed ^ distance_m.to_bits());
+                    mean + shadowing_std_db * rng.sample_standard_normal()
+                } else {
+                    mean
+                }
+            }
+            PathLossModel::ItuIndoor {
+                distance_power_loss_coefficient,
+                floor_penetration_loss_db,
+                num_floors,
+            } => {
+                let frequency_mhz = frequency_hz / 1.0e6;
+                20.0 * frequency_mhz.log10()
+                    + distance_power_loss_coefficient * distance_m.log10()
+                    + floor_penetration_loss_db * (*num_floors as f64)
+                    - 28.0
+            }
+        }
+    }
+}
+
+/// Minimal splitmix64-based PRNG mirroring [`crate::fading`]'s generator,
+/// used only to draw the optional log-normal shadowing sample in
+/// [`PathLossModel::LogDistance`].
+#[derive(Debug, Clone)]
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: seed ^ 0x9E3779B97F4A7C15,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64) * (1.0 / (1u64 << 53) as f64)
+    }
+
+    fn sample_standard_normal(&mut self) -> f64 {
+        let u1 = self.next_f64().max(1e-12);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
 }
 
 #[cfg(test)]
@@ -111,9 +292,155 @@ mod tests {
             3.0,  // 3 dBi Rx gain
             80.0, // 80 dB path loss
             2.0,  // 2 dB system losses
+            0.0,  // matched polarization
         );
 
         // Should be around -56 dBm
         assert!((budget - (-56.0)).abs() < 0.1);
     }
+
+    #[test]
+    fn test_matched_linear_polarization_has_no_loss() {
+        let loss =
+            polarization_loss_db(PolarizationType::Vertical, PolarizationType::Vertical, 0.0);
+        assert!((loss - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_orthogonal_linear_polarization_hits_the_cross_pol_floor() {
+        let loss = polarization_loss_db(
+            PolarizationType::Vertical,
+            PolarizationType::Horizontal,
+            0.0,
+        );
+        assert!(loss > 20.0);
+    }
+
+    #[test]
+    fn test_counter_rotating_circular_polarization_hits_the_cross_pol_floor() {
+        let loss = polarization_loss_db(
+            PolarizationType::CircularRight,
+            PolarizationType::CircularLeft,
+            0.0,
+        );
+        assert!(loss > 20.0);
+    }
+
+    #[test]
+    fn test_linear_to_circular_polarization_loses_3_db() {
+        let loss = polarization_loss_db(
+            PolarizationType::Vertical,
+            PolarizationType::CircularRight,
+            0.0,
+        );
+        assert!((loss - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tilted_linear_polarization_loss_follows_the_cosine_law() {
+        let loss =
+            polarization_loss_db(PolarizationType::Vertical, PolarizationType::Vertical, 45.0);
+        assert!((loss - 3.01).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_polarization_mismatch_reduces_the_link_budget() {
+        let matched = link_budget(20.0, 3.0, 3.0, 80.0, 2.0, 0.0);
+        let mismatched = link_budget(
+            20.0,
+            3.0,
+            3.0,
+            80.0,
+            2.0,
+            polarization_loss_db(
+                PolarizationType::Vertical,
+                PolarizationType::Horizontal,
+                0.0,
+            ),
+        );
+        assert!(mismatched < matched - 20.0);
+    }
+
+    #[test]
+    fn test_two_ray_matches_friis_below_the_breakpoint_distance() {
+        let geometry = PathLossGeometry {
+            tx_height_m: 10.0,
+            rx_height_m: 1.5,
+        };
+        let loss = PathLossModel::TwoRay.loss(1.0, 2.4e9, geometry);
+        let friis = friis_path_loss(1.0, 2.4e9);
+        assert!((loss - friis).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_two_ray_follows_the_fourth_power_law_past_the_breakpoint() {
+        let geometry = PathLossGeometry {
+            tx_height_m: 10.0,
+            rx_height_m: 1.5,
+        };
+        let near = PathLossModel::TwoRay.loss(1000.0, 2.4e9, geometry);
+        let far = PathLossModel::TwoRay.loss(10000.0, 2.4e9, geometry);
+        // A 10x distance increase costs 40 dB under the two-ray far-field law.
+        assert!((far - near - 40.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_log_distance_matches_friis_at_the_reference_distance() {
+        let model = PathLossModel::LogDistance {
+            reference_distance_m: 1.0,
+            path_loss_exponent: 3.0,
+            shadowing_std_db: 0.0,
+            shadowing_seed: 0,
+        };
+        let loss = model.loss(1.0, 2.4e9, PathLossGeometry::default());
+        let friis = friis_path_loss(1.0, 2.4e9);
+        assert!((loss - friis).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_log_distance_exponent_controls_the_rolloff_rate() {
+        let model = PathLossModel::LogDistance {
+            reference_distance_m: 1.0,
+            path_loss_exponent: 4.0,
+            shadowing_std_db: 0.0,
+            shadowing_seed: 0,
+        };
+        let near = model.loss(10.0, 2.4e9, PathLossGeometry::default());
+        let far = model.loss(100.0, 2.4e9, PathLossGeometry::default());
+        // n = 4 means a decade of distance costs 40 dB.
+        assert!((far - near - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_log_distance_shadowing_is_reproducible_but_distance_dependent() {
+        let model = PathLossModel::LogDistance {
+            reference_distance_m: 1.0,
+            path_loss_exponent: 3.0,
+            shadowing_std_db: 6.0,
+            shadowing_seed: 42,
+        };
+        let first = model.loss(50.0, 2.4e9, PathLossGeometry::default());
+        let repeat = model.loss(50.0, 2.4e9, PathLossGeometry::default());
+        let other_distance = model.loss(75.0, 2.4e9, PathLossGeometry::default());
+        assert!((first - repeat).abs() < 1e-12);
+        assert!((first - other_distance).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_itu_indoor_loss_increases_with_floors_penetrated() {
+        let base = PathLossModel::ItuIndoor {
+            distance_power_loss_coefficient: 28.0,
+            floor_penetration_loss_db: 15.0,
+            num_floors: 0,
+        };
+        let two_floors = PathLossModel::ItuIndoor {
+            distance_power_loss_coefficient: 28.0,
+            floor_penetration_loss_db: 15.0,
+            num_floors: 2,
+        };
+        let geometry = PathLossGeometry::default();
+        let base_loss = base.loss(10.0, 2.4e9, geometry);
+        let floors_loss = two_floors.loss(10.0, 2.4e9, geometry);
+        assert!((floors_loss - base_loss - 30.0).abs() < 1e-9);
+    }
 }
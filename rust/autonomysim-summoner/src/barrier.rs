@@ -0,0 +1,219 @@
+//! Distributed rendezvous barrier gated by RF connectivity
+//!
+//! A subset of robots (e.g. every scout) needs to agree "we're all ready"
+//! before the swarm moves to its next phase (e.g. transport convoys
+//! launching). Unlike [`Scheduler::barrier`](crate::scheduler::Scheduler::barrier),
+//! which rendezvouses worker *threads* sharing one process via a `Condvar`,
+//! this [`Barrier`] rendezvouses physically separate robots that can only
+//! learn about each other's readiness over a radio link -- so each
+//! participant owns its own replica, marks itself ready locally, and
+//! gossips its observed ready-set with [`Barrier::propagate`] the same way
+//! [`crate::stigmergy::Stigmergy::propagate`] gossips tuples: range gating
+//! is left to the caller (e.g. `RFPropagationEngine::compute_link`), so a
+//! jamming partition that cuts every live link into a subset of ready
+//! robots stalls -- and can eventually time out -- that barrier exactly as
+//! it would a real mission.
+
+use std::collections::HashSet;
+
+/// Default step budget a [`Barrier`] waits for `threshold` before timing
+/// out, absent an explicit [`Barrier::with_timeout`] -- 150 steps is 3s at
+/// the 50Hz tick rate `RoboticSwarmDemo` runs at.
+pub const BARRIER_TIMEOUT: u64 = 150;
+
+/// Latched outcome of a [`Barrier`]; see [`Barrier::tick`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarrierStatus {
+    /// Still waiting on more ready participants, or still within timeout.
+    Pending,
+    /// `ready_count` reached `threshold` before timing out.
+    Completed,
+    /// More than `timeout_steps` steps passed without reaching `threshold`.
+    TimedOut,
+}
+
+/// One participant's replica of a rendezvous barrier.
+pub struct Barrier {
+    id: String,
+    self_id: u64,
+    threshold: usize,
+    timeout_steps: u64,
+    steps_waited: u64,
+    ready: HashSet<u64>,
+    status: BarrierStatus,
+}
+
+impl Barrier {
+    /// Create a barrier using [`BARRIER_TIMEOUT`] as its step budget.
+    /// `id` names the phase transition (shared by every participant in
+    /// this rendezvous); `self_id` is this robot's unique id, the value it
+    /// contributes to `ready_count`.
+    pub fn new(id: impl Into<String>, self_id: u64, threshold: usize) -> Self {
+        Self::with_timeout(id, self_id, threshold, BARRIER_TIMEOUT)
+    }
+
+    /// Create a barrier with an explicit step budget instead of
+    /// [`BARRIER_TIMEOUT`].
+    pub fn with_timeout(
+        id: impl Into<String>,
+        self_id: u64,
+        threshold: usize,
+        timeout_steps: u64,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            self_id,
+            threshold,
+            timeout_steps,
+            steps_waited: 0,
+            ready: HashSet::new(),
+            status: BarrierStatus::Pending,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn status(&self) -> BarrierStatus {
+        self.status
+    }
+
+    /// Distinct participant ids this replica has observed as ready so far,
+    /// including itself once [`Self::mark_ready`] has been called.
+    pub fn ready_count(&self) -> usize {
+        self.ready.len()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.contains(&self.self_id)
+    }
+
+    /// Mark this participant ready. Idempotent, and deliberately not
+    /// undoable: a robot that passed the barrier keeps contributing its id
+    /// to `ready_count` and keeps rebroadcasting it via `propagate`, so a
+    /// late joiner reachable only through an already-ready robot can still
+    /// reach `threshold`.
+    pub fn mark_ready(&mut self) {
+        self.ready.insert(self.self_id);
+    }
+
+    /// Merge ready-sets with `neighbors` the caller has already confirmed
+    /// are reachable this step. Runs regardless of this replica's own
+    /// `status` -- a completed or even timed-out participant still
+    /// rebroadcasts everything it has heard.
+    pub fn propagate<'a>(&mut self, neighbors: impl IntoIterator<Item = &'a mut Barrier>) {
+        for neighbor in neighbors {
+            if neighbor.id != self.id {
+                continue;
+            }
+            self.ready.extend(neighbor.ready.iter().copied());
+            neighbor.ready.clone_from(&self.ready);
+        }
+    }
+
+    /// Advance one simulation step. Fires `on_complete` the first time
+    /// `ready_count` reaches `threshold`, or `on_timeout` the first time
+    /// more than `timeout_steps` calls have passed without it -- each
+    /// fires at most once per barrier, since `status` latches on the first
+    /// transition and every later call is a no-op that just returns it.
+    pub fn tick(&mut self, on_complete: impl FnOnce(), on_timeout: impl FnOnce()) -> BarrierStatus {
+        if self.status != BarrierStatus::Pending {
+            return self.status;
+        }
+
+        if self.ready.len() >= self.threshold {
+            self.status = BarrierStatus::Completed;
+            on_complete();
+            return self.status;
+        }
+
+        self.steps_waited += 1;
+        if self.steps_waited > self.timeout_steps {
+            self.status = BarrierStatus::TimedOut;
+            on_timeout();
+        }
+
+        self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_once_threshold_reached() {
+        let mut barrier = Barrier::new("launch", 1, 2);
+        let mut other = Barrier::new("launch", 2, 2);
+        barrier.mark_ready();
+        other.mark_ready();
+
+        barrier.propagate(std::iter::once(&mut other));
+
+        let mut completed = false;
+        assert_eq!(
+            barrier.tick(|| completed = true, || panic!("should not time out")),
+            BarrierStatus::Completed
+        );
+        assert!(completed);
+    }
+
+    #[test]
+    fn times_out_without_enough_ready_participants() {
+        let mut barrier = Barrier::with_timeout("launch", 1, 2, 3);
+        barrier.mark_ready();
+
+        for _ in 0..3 {
+            assert_eq!(barrier.tick(|| {}, || {}), BarrierStatus::Pending);
+        }
+
+        let mut timed_out = false;
+        assert_eq!(
+            barrier.tick(|| panic!("should not complete"), || timed_out = true),
+            BarrierStatus::TimedOut
+        );
+        assert!(timed_out);
+    }
+
+    #[test]
+    fn a_partition_with_no_live_link_never_converges() {
+        let mut scout = Barrier::new("launch", 1, 2);
+        let mut isolated = Barrier::new("launch", 2, 2);
+        scout.mark_ready();
+        isolated.mark_ready();
+
+        // No propagate() call at all -- simulates a jammed link with no
+        // path between the two replicas.
+        assert_eq!(scout.ready_count(), 1);
+        assert_eq!(isolated.ready_count(), 1);
+    }
+
+    #[test]
+    fn already_ready_participant_keeps_rebroadcasting_for_late_joiners() {
+        let mut a = Barrier::new("launch", 1, 3);
+        let mut b = Barrier::new("launch", 2, 3);
+        let mut late = Barrier::new("launch", 3, 3);
+        a.mark_ready();
+        b.mark_ready();
+
+        // a and b converge and a completes, one step before `late` joins.
+        a.propagate(std::iter::once(&mut b));
+        assert_eq!(
+            a.tick(|| {}, || panic!("should not time out")),
+            BarrierStatus::Pending
+        );
+
+        // `late` marks ready and links up with `a`, which is already past
+        // the barrier but still has its ready-set to offer.
+        late.mark_ready();
+        a.propagate(std::iter::once(&mut late));
+
+        assert_eq!(a.ready_count(), 3);
+        assert_eq!(late.ready_count(), 3);
+        assert_eq!(
+            late.tick(|| {}, || panic!("should not time out")),
+            BarrierStatus::Completed
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! Multi-axis sweep-and-prune broad phase for agent-pair proximity queries
+//!
+//! [`crate::partition::SpatialPartitioner::find_partition`] (and its
+//! [`crate::partition::AdaptivePartitioner`] counterpart) scan every
+//! partition per point, and neither offers any way to ask which agents are
+//! near each other across a partition boundary. [`BroadPhase`] covers both:
+//! a sweep-and-prune sweep over per-axis interval endpoints tracks
+//! overlapping agent AABBs (`position +/- interaction_radius`) incrementally
+//! -- insertion sort, not a full re-sort, since endpoints move little step
+//! to step -- and a uniform spatial hash answers `point_partition` in O(1)
+//! amortized instead of the linear scan the partitioners otherwise need.
+
+use nalgebra::Vector3;
+use std::collections::{HashMap, HashSet};
+
+use crate::partition::Partition;
+
+/// Agent id reserved for the sentinels inserted at the head/tail of each
+/// axis's endpoint list, so the sweep never has to special-case the ends of
+/// the array.
+const SENTINEL: usize = usize::MAX;
+
+/// One endpoint of an agent's AABB along a single axis.
+#[derive(Debug, Clone, Copy)]
+struct Endpoint {
+    agent_id: usize,
+    value: f64,
+    is_min: bool,
+}
+
+/// An agent's axis-aligned bounding box: `position +/- interaction_radius`
+/// on every axis.
+#[derive(Debug, Clone, Copy)]
+struct Aabb {
+    min: Vector3<f64>,
+    max: Vector3<f64>,
+}
+
+/// Broad-phase proximity structure: sweep-and-prune for
+/// [`Self::overlapping_pairs`], plus a uniform spatial hash (cell width
+/// `cell_width`) for [`Self::point_partition`].
+pub struct BroadPhase {
+    cell_width: f64,
+    aabbs: HashMap<usize, Aabb>,
+    axes: [Vec<Endpoint>; 3],
+    cell_to_partition: HashMap<(i64, i64, i64), usize>,
+}
+
+impl BroadPhase {
+    /// Create a new broad phase with the given spatial-hash cell width.
+    pub fn new(cell_width: f64) -> Self {
+        let sentinel_axis = || {
+            vec![
+                Endpoint {
+                    agent_id: SENTINEL,
+                    value: f64::NEG_INFINITY,
+                    is_min: true,
+                },
+                Endpoint {
+                    agent_id: SENTINEL,
+                    value: f64::INFINITY,
+                    is_min: false,
+                },
+            ]
+        };
+        Self {
+            cell_width,
+            aabbs: HashMap::new(),
+            axes: [sentinel_axis(), sentinel_axis(), sentinel_axis()],
+            cell_to_partition: HashMap::new(),
+        }
+    }
+
+    /// Rasterize `partitions` into the spatial hash so [`Self::point_partition`]
+    /// can answer lookups in O(1) instead of scanning every partition.
+    /// Overlapping partitions (there shouldn't be any) resolve to whichever
+    /// one rasterizes last.
+    pub fn set_partitions(&mut self, partitions: &[Partition]) {
+        self.cell_to_partition.clear();
+        for partition in partitions {
+            let min_cell = self.cell_of(&partition.min_bounds);
+            let max_cell = self.cell_of(&partition.max_bounds);
+            for cx in min_cell.0..=max_cell.0 {
+                for cy in min_cell.1..=max_cell.1 {
+                    for cz in min_cell.2..=max_cell.2 {
+                        self.cell_to_partition.insert((cx, cy, cz), partition.id);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Update every agent's AABB from `(agent_id, position, interaction_radius)`
+    /// triples and re-run the sweep. Endpoints already present are moved in
+    /// place and re-sorted with insertion sort (cheap, since positions shift
+    /// only a little per step); agents not seen before are inserted fresh.
+    pub fn update(&mut self, agents: &[(usize, Vector3<f64>, f64)]) {
+        let seen: HashSet<usize> = agents.iter().map(|(id, _, _)| *id).collect();
+        for axis in &mut self.axes {
+            axis.retain(|e| e.agent_id == SENTINEL || seen.contains(&e.agent_id));
+        }
+        self.aabbs.retain(|id, _| seen.contains(id));
+
+        for &(agent_id, position, radius) in agents {
+            let radius_vec = Vector3::new(radius, radius, radius);
+            let aabb = Aabb {
+                min: position - radius_vec,
+                max: position + radius_vec,
+            };
+            self.aabbs.insert(agent_id, aabb);
+
+            for (axis_index, axis_endpoints) in self.axes.iter_mut().enumerate() {
+                Self::upsert_endpoint(axis_endpoints, agent_id, aabb.min[axis_index], true);
+                Self::upsert_endpoint(axis_endpoints, agent_id, aabb.max[axis_index], false);
+            }
+        }
+
+        for axis in &mut self.axes {
+            Self::insertion_sort(axis);
+        }
+    }
+
+    /// Insert or update one endpoint (min or max) for `agent_id`.
+    fn upsert_endpoint(endpoints: &mut Vec<Endpoint>, agent_id: usize, value: f64, is_min: bool) {
+        if let Some(endpoint) = endpoints
+            .iter_mut()
+            .find(|e| e.agent_id == agent_id && e.is_min == is_min)
+        {
+            endpoint.value = value;
+        } else {
+            // Push before the trailing +inf sentinel; the sort pass right
+            // after `update` settles its final position.
+            let insert_at = endpoints.len() - 1;
+            endpoints.insert(
+                insert_at,
+                Endpoint {
+                    agent_id,
+                    value,
+                    is_min,
+                },
+            );
+        }
+    }
+
+    /// Insertion sort: cheap here because temporal coherence means
+    /// endpoints are already nearly in order from the previous step, unlike
+    /// a generic comparison sort that assumes nothing about prior order.
+    fn insertion_sort(endpoints: &mut [Endpoint]) {
+        for i in 1..endpoints.len() {
+            let mut j = i;
+            while j > 0 && endpoints[j].value < endpoints[j - 1].value {
+                endpoints.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+
+    /// All agent pairs whose AABBs overlap on every axis, via the classic
+    /// sweep-and-prune intersection of independent per-axis overlap sets.
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        let [x_pairs, y_pairs, z_pairs] = [
+            Self::sweep_axis(&self.axes[0]),
+            Self::sweep_axis(&self.axes[1]),
+            Self::sweep_axis(&self.axes[2]),
+        ];
+
+        x_pairs
+            .intersection(&y_pairs)
+            .copied()
+            .collect::<HashSet<_>>()
+            .intersection(&z_pairs)
+            .copied()
+            .collect()
+    }
+
+    /// Sweep one axis's sorted endpoints, flipping a pair's membership in
+    /// the returned set on whenever both agents are simultaneously active
+    /// (i.e. their intervals overlap on this axis).
+    fn sweep_axis(endpoints: &[Endpoint]) -> HashSet<(usize, usize)> {
+        let mut active: Vec<usize> = Vec::new();
+        let mut pairs = HashSet::new();
+
+        for endpoint in endpoints {
+            if endpoint.agent_id == SENTINEL {
+                continue;
+            }
+            if endpoint.is_min {
+                for &other in &active {
+                    pairs.insert(Self::pair_key(endpoint.agent_id, other));
+                }
+                active.push(endpoint.agent_id);
+            } else {
+                active.retain(|&id| id != endpoint.agent_id);
+            }
+        }
+
+        pairs
+    }
+
+    fn pair_key(a: usize, b: usize) -> (usize, usize) {
+        if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Which partition (if any) contains `point`, looked up in O(1)
+    /// amortized via the spatial hash built by [`Self::set_partitions`].
+    pub fn point_partition(&self, point: &Vector3<f64>) -> Option<usize> {
+        self.cell_to_partition.get(&self.cell_of(point)).copied()
+    }
+
+    fn cell_of(&self, point: &Vector3<f64>) -> (i64, i64, i64) {
+        (
+            (point.x / self.cell_width).floor() as i64,
+            (point.y / self.cell_width).floor() as i64,
+            (point.z / self.cell_width).floor() as i64,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_overlapping_pair() {
+        let mut broad_phase = BroadPhase::new(10.0);
+        broad_phase.update(&[
+            (0, Vector3::new(0.0, 0.0, 0.0), 2.0),
+            (1, Vector3::new(1.0, 0.0, 0.0), 2.0),
+            (2, Vector3::new(100.0, 100.0, 100.0), 1.0),
+        ]);
+
+        let pairs = broad_phase.overlapping_pairs();
+        assert_eq!(pairs, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn non_overlapping_on_one_axis_is_excluded() {
+        let mut broad_phase = BroadPhase::new(10.0);
+        // x and y overlap, but z is far apart -- must not count as a pair.
+        broad_phase.update(&[
+            (0, Vector3::new(0.0, 0.0, 0.0), 2.0),
+            (1, Vector3::new(1.0, 1.0, 50.0), 2.0),
+        ]);
+
+        assert!(broad_phase.overlapping_pairs().is_empty());
+    }
+
+    #[test]
+    fn tracks_overlap_across_updates() {
+        let mut broad_phase = BroadPhase::new(10.0);
+        broad_phase.update(&[
+            (0, Vector3::new(0.0, 0.0, 0.0), 1.0),
+            (1, Vector3::new(50.0, 50.0, 50.0), 1.0),
+        ]);
+        assert!(broad_phase.overlapping_pairs().is_empty());
+
+        // Agent 1 drifts next to agent 0.
+        broad_phase.update(&[
+            (0, Vector3::new(0.0, 0.0, 0.0), 1.0),
+            (1, Vector3::new(0.5, 0.0, 0.0), 1.0),
+        ]);
+        assert_eq!(broad_phase.overlapping_pairs(), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn point_partition_uses_spatial_hash() {
+        let partitions = vec![
+            Partition {
+                id: 0,
+                min_bounds: Vector3::zeros(),
+                max_bounds: Vector3::new(50.0, 100.0, 10.0),
+                neighbor_partitions: vec![1],
+            },
+            Partition {
+                id: 1,
+                min_bounds: Vector3::new(50.0, 0.0, 0.0),
+                max_bounds: Vector3::new(100.0, 100.0, 10.0),
+                neighbor_partitions: vec![0],
+            },
+        ];
+
+        let mut broad_phase = BroadPhase::new(5.0);
+        broad_phase.set_partitions(&partitions);
+
+        assert_eq!(
+            broad_phase.point_partition(&Vector3::new(10.0, 10.0, 1.0)),
+            Some(0)
+        );
+        assert_eq!(
+            broad_phase.point_partition(&Vector3::new(90.0, 10.0, 1.0)),
+            Some(1)
+        );
+        assert_eq!(
+            broad_phase.point_partition(&Vector3::new(1000.0, 1000.0, 1000.0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Lennard-Jones flocking for swarm cohesion in [`crate::worker::Worker`]
+//!
+//! [`crate::formation`] runs the same style of interaction for the Unreal
+//! `RobotPositionUpdate` stream, but nothing plays an equivalent role inside
+//! SUMMONER's own step loop -- `Worker::simulate_physics` is a placeholder,
+//! so without this a "swarm" is just a set of agents that happen to share a
+//! config. [`desired_velocities`] gives each agent a velocity setpoint
+//! derived from its in-range neighbors via a Lennard-Jones potential: too
+//! close and they push apart, too far and they pull together, with zero net
+//! force at the equilibrium spacing [`LjParams::target`].
+//!
+//! Neighbor lookup reuses [`crate::broadphase::BroadPhase`], the same
+//! sweep-and-prune structure [`crate::partition::SpatialPartitioner`]
+//! relies on for proximity queries across its spatial-partition grid,
+//! rather than the O(n^2) all-pairs scan [`crate::formation::step`] can get
+//! away with for small rosters.
+
+use nalgebra::{Point3, Vector3};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::broadphase::BroadPhase;
+
+/// Distance floor applied before dividing, so two agents occupying (nearly)
+/// the same point don't blow up the LJ force instead of just repelling hard.
+pub(crate) const DISTANCE_FLOOR: f64 = 0.1;
+
+/// Tunables for the Lennard-Jones cohesion controller.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LjParams {
+    /// Potential well depth; scales how hard agents push or pull on each
+    /// other.
+    pub epsilon: f64,
+    /// Desired equilibrium inter-agent spacing (meters), where the net
+    /// force is zero.
+    pub target: f64,
+    /// Neighbors farther apart than this (meters) don't interact at all.
+    pub range: f64,
+    /// Per-tick speed cap applied to each agent's summed interaction force
+    /// before it becomes a velocity setpoint. `None` leaves it unclamped.
+    pub max_speed: Option<f64>,
+}
+
+/// Lennard-Jones force magnitude along the bearing from one agent to a
+/// neighbor at `distance`, for a desired equilibrium spacing of `target`.
+/// Negative (repulsive) when `distance < target`, positive (attractive)
+/// when `distance > target`, zero at `distance == target`.
+pub(crate) fn lj_force_magnitude(epsilon: f64, target: f64, distance: f64) -> f64 {
+    let ratio = target / distance;
+    -(epsilon / distance) * (ratio.powi(4) - ratio.powi(2))
+}
+
+/// Compute one desired velocity per entry of `positions` from the summed
+/// Lennard-Jones interaction with every neighbor within `params.range`.
+/// Agents with no in-range neighbor get a zero vector (free drift).
+///
+/// Candidate neighbor pairs come from a [`BroadPhase`] built fresh over
+/// `positions` each call -- cheap relative to the O(n^2) distance checks it
+/// replaces, and correct regardless of how agent positions moved since the
+/// previous step. Each pair's contribution is resolved to a planar vector
+/// from its bearing azimuth `theta` (`m * cos(theta), m * sin(theta)`), so
+/// cohesion acts purely in the horizontal plane; altitude is left to
+/// whatever else commands the agent's vertical setpoint.
+pub fn desired_velocities(
+    positions: &HashMap<usize, Point3<f64>>,
+    params: &LjParams,
+) -> HashMap<usize, Vector3<f64>> {
+    let mut velocities: HashMap<usize, Vector3<f64>> =
+        positions.keys().map(|&id| (id, Vector3::zeros())).collect();
+
+    let mut broad_phase = BroadPhase::new(params.range.max(DISTANCE_FLOOR));
+    let agents: Vec<(usize, Vector3<f64>, f64)> = positions
+        .iter()
+        .map(|(&id, position)| (id, position.coords, params.range / 2.0))
+        .collect();
+    broad_phase.update(&agents);
+
+    for (a, b) in broad_phase.overlapping_pairs() {
+        let (Some(&pos_a), Some(&pos_b)) = (positions.get(&a), positions.get(&b)) else {
+            continue;
+        };
+
+        let offset = pos_b - pos_a;
+        let distance = offset.norm();
+        if distance > params.range {
+            continue;
+        }
+        let distance = distance.max(DISTANCE_FLOOR);
+        let magnitude = lj_force_magnitude(params.epsilon, params.target, distance);
+
+        let theta = offset.y.atan2(offset.x);
+        let contribution = Vector3::new(magnitude * theta.cos(), magnitude * theta.sin(), 0.0);
+        *velocities.get_mut(&a).expect("a is a positions key") += contribution;
+        *velocities.get_mut(&b).expect("b is a positions key") -= contribution;
+    }
+
+    if let Some(max_speed) = params.max_speed {
+        for velocity in velocities.values_mut() {
+            if velocity.norm() > max_speed {
+                *velocity = velocity.normalize() * max_speed;
+            }
+        }
+    }
+
+    velocities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lj_force_is_zero_at_equilibrium_spacing() {
+        assert_eq!(lj_force_magnitude(1.0, 5.0, 5.0), 0.0);
+    }
+
+    #[test]
+    fn close_neighbors_repel_apart() {
+        let mut positions = HashMap::new();
+        positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1usize, Point3::new(1.0, 0.0, 0.0));
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities(&positions, &params);
+
+        assert!(velocities[&0].x < 0.0, "agent 0 should back away from agent 1");
+        assert!(velocities[&1].x > 0.0, "agent 1 should back away from agent 0");
+    }
+
+    #[test]
+    fn distant_neighbors_within_range_attract() {
+        let mut positions = HashMap::new();
+        positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1usize, Point3::new(10.0, 0.0, 0.0));
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities(&positions, &params);
+
+        assert!(velocities[&0].x > 0.0, "agent 0 should steer toward agent 1");
+        assert!(velocities[&1].x < 0.0, "agent 1 should steer toward agent 0");
+    }
+
+    #[test]
+    fn neighbors_beyond_range_are_ignored() {
+        let mut positions = HashMap::new();
+        positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1usize, Point3::new(50.0, 0.0, 0.0));
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities(&positions, &params);
+
+        assert_eq!(velocities[&0], Vector3::zeros());
+        assert_eq!(velocities[&1], Vector3::zeros());
+    }
+
+    #[test]
+    fn an_agent_with_no_neighbors_drifts_free() {
+        let mut positions = HashMap::new();
+        positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities(&positions, &params);
+
+        assert_eq!(velocities[&0], Vector3::zeros());
+    }
+
+    #[test]
+    fn max_speed_clamps_the_summed_interaction() {
+        let mut positions = HashMap::new();
+        positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1usize, Point3::new(0.2, 0.0, 0.0));
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: Some(1.0),
+        };
+
+        let velocities = desired_velocities(&positions, &params);
+
+        assert!((velocities[&0].norm() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn overlapping_agents_are_clamped_by_the_distance_floor_instead_of_diverging() {
+        let mut positions = HashMap::new();
+        positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1usize, Point3::new(0.0, 0.0, 0.0));
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities(&positions, &params);
+
+        assert!(velocities[&0].x.is_finite() && velocities[&0].y.is_finite());
+    }
+}
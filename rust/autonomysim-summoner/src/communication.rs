@@ -1,16 +1,39 @@
 //! Communication infrastructure for distributed simulation
 
 use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, OwnedSemaphorePermit, RwLock, Semaphore};
 use tracing::debug;
 
+/// Default cap on serialized message bytes allowed in flight across the bus
+/// at once. Sized so a heterogeneous cluster with one lagging link stalls
+/// that link's sends instead of letting the coordinator's queues grow
+/// without bound.
+pub const DEFAULT_MAX_BUFFERED_BYTES: u64 = 200 * 1024 * 1024;
+
+/// Step size assumed for a node's bandwidth budget before the first
+/// `advance_step` call, matching `SummonerConfig::default().timestep`.
+pub const DEFAULT_STEP_DT: f64 = 0.01;
+
 /// Messages exchanged between nodes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NodeMessage {
-    /// Command to execute simulation step
-    StepCommand { step: u64, dt: f64 },
+    /// Command to execute simulation step, tagged with the cluster's
+    /// current `Coordinator::layout_version` so a worker can tell whether
+    /// the topology it last saw is stale, and with a `TraceContext` so the
+    /// worker's `execute_step` span stitches into the same distributed
+    /// trace as the coordinator's root `step` span.
+    StepCommand {
+        step: u64,
+        dt: f64,
+        layout_version: u64,
+        trace_context: TraceContext,
+    },
 
     /// Synchronize boundary data
     SyncBoundaries,
@@ -27,51 +50,608 @@ pub enum NodeMessage {
 
     /// Worker completed step
     StepComplete { worker_id: usize, step: u64 },
+
+    /// Hand off ownership of an agent to another worker at a step boundary.
+    /// `state_bytes` is the agent's `VehicleState` serialized via its serde
+    /// derive so the receiving worker can resume it without coupling this
+    /// crate to the concrete vehicle-state type.
+    MigrateAgent {
+        agent_id: usize,
+        from_worker: usize,
+        to_worker: usize,
+        state_bytes: Vec<u8>,
+    },
+
+    /// Acknowledge that a migrated agent has been accepted and resumed.
+    MigrateAgentAck { agent_id: usize, to_worker: usize },
+
+    /// A debug-draw primitive (line strip, arrow, point, or text label) for
+    /// an attached frontend or the ROS bridge to render.
+    DebugDraw(crate::debug_draw::DebugDrawHandle),
+
+    /// One write into the virtual stigmergy tuple space
+    /// (`Coordinator::stigmergy_put`), broadcast opportunistically so it
+    /// diffuses to every node rather than on a fixed schedule. `clock` is
+    /// the writer's Lamport timestamp for `key`, `robot_id` the writer's id
+    /// -- together they let every receiver resolve conflicting writes to
+    /// the same key identically regardless of delivery order. See
+    /// `Coordinator::apply_stigmergy_update`.
+    StigmergyUpdate {
+        key: String,
+        value: Vec<u8>,
+        clock: u64,
+        robot_id: usize,
+    },
+}
+
+/// Correlates a coordinator `step` span with the `execute_step` span each
+/// worker opens in response, so an OTLP exporter stitches them into a
+/// single distributed trace per tick instead of one isolated span per node.
+/// `trace_id` is freshly randomized every step; `root_span_id` identifies
+/// the coordinator's root span as the parent a worker's child span should
+/// report.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TraceContext {
+    pub trace_id: u128,
+    pub root_span_id: u64,
+}
+
+impl TraceContext {
+    /// Start a fresh trace context for one simulation step.
+    pub fn new_for_step() -> Self {
+        Self {
+            trace_id: rand::random(),
+            root_span_id: rand::random(),
+        }
+    }
+}
+
+/// A message plus the byte-budget permit it holds against the bus's
+/// `max_buffered_bytes`. The permit is released -- freeing
+/// that many bytes back to senders waiting on the budget -- whenever the
+/// receiver is done with this value (typically just by letting it drop
+/// after matching on `message`). That drop is the closest thing this bus
+/// has to a peer ack.
+pub struct BufferedMessage {
+    pub message: NodeMessage,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl std::ops::Deref for BufferedMessage {
+    type Target = NodeMessage;
+
+    fn deref(&self) -> &NodeMessage {
+        &self.message
+    }
+}
+
+/// A registered node's delivery channel plus the bandwidth-limited
+/// scheduling state layered on top of it. `capacity_bps` of `None` means
+/// unlimited -- a message sent to this node goes straight to `sender`, the
+/// bus's behavior before this model existed.
+struct NodeLink {
+    sender: mpsc::UnboundedSender<BufferedMessage>,
+    capacity_bps: Option<f64>,
+    /// Bytes delivered to this node so far during the current step; reset
+    /// by [`MessageBus::advance_step`].
+    bytes_sent_this_step: AtomicU64,
+    /// Messages that didn't fit in this step's byte budget, oldest first.
+    /// Each holds onto its [`BufferedMessage`]'s budget permit until it is
+    /// finally delivered, so a backlogged message still counts against
+    /// `max_buffered_bytes` while it waits.
+    backlog: Mutex<VecDeque<(NodeMessage, OwnedSemaphorePermit)>>,
+}
+
+/// Per-node bandwidth snapshot returned by [`MessageBus::link_stats`].
+#[derive(Debug, Clone, Copy)]
+pub struct NodeLinkStats {
+    /// Configured sustained throughput in bits/second, or `None` if this
+    /// node's channel is unlimited.
+    pub capacity_bps: Option<f64>,
+    /// Bytes delivered to this node during the current step so far.
+    pub bytes_sent_this_step: u64,
+    /// Messages queued waiting for a future step's budget.
+    pub backlog_len: usize,
+}
+
+/// How long a surviving message is delayed (in whole simulation steps)
+/// before it enters the bandwidth-gated delivery path, used by
+/// [`FaultModel`].
+#[derive(Debug, Clone, Copy)]
+pub enum LatencyModel {
+    /// Every surviving message is delayed by exactly this many steps.
+    Fixed(u64),
+    /// Delay is drawn uniformly from `min..=max` steps (inclusive) per
+    /// message.
+    Jitter { min: u64, max: u64 },
+}
+
+impl LatencyModel {
+    fn sample(&self, rng: &mut StdRng) -> u64 {
+        match *self {
+            LatencyModel::Fixed(steps) => steps,
+            LatencyModel::Jitter { min, max } => {
+                if min >= max {
+                    min
+                } else {
+                    rng.gen_range(min..=max)
+                }
+            }
+        }
+    }
+}
+
+/// Deterministic network-fault model a [`MessageBus`] consults before each
+/// delivery, for split-brain/lossy-link test scenarios: a symmetric
+/// reachability matrix (toggled via [`Self::partition`]/[`Self::heal`]), an
+/// independent per-message drop probability, and a latency distribution
+/// for messages that survive both.
+pub struct FaultModel {
+    num_nodes: usize,
+    reachable: Vec<Vec<bool>>,
+    drop_probability: f64,
+    latency: LatencyModel,
+    rng: StdRng,
+}
+
+impl FaultModel {
+    /// Build a fault model with every node initially able to reach every
+    /// other. `seed` makes the drop rolls and jittered latencies
+    /// reproducible across runs.
+    pub fn new(num_nodes: usize, drop_probability: f64, latency: LatencyModel, seed: u64) -> Self {
+        Self {
+            num_nodes,
+            reachable: vec![vec![true; num_nodes]; num_nodes],
+            drop_probability: drop_probability.clamp(0.0, 1.0),
+            latency,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Whether a message from node `a` can currently reach node `b`.
+    /// Symmetric: `can_reach(a, b) == can_reach(b, a)`.
+    pub fn can_reach(&self, a: usize, b: usize) -> bool {
+        self.reachable
+            .get(a)
+            .and_then(|row| row.get(b))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Partition the cluster: no node in `group_a` can reach any node in
+    /// `group_b` (and vice versa) until [`Self::heal`] is called.
+    /// Reachability within each group, and with nodes in neither group, is
+    /// untouched.
+    pub fn partition(&mut self, group_a: &[usize], group_b: &[usize]) {
+        for &a in group_a {
+            for &b in group_b {
+                self.set_reachable(a, b, false);
+            }
+        }
+    }
+
+    /// Heal every partition: every node can reach every other node again.
+    pub fn heal(&mut self) {
+        self.reachable = vec![vec![true; self.num_nodes]; self.num_nodes];
+    }
+
+    fn set_reachable(&mut self, a: usize, b: usize, reachable: bool) {
+        if let Some(cell) = self.reachable.get_mut(a).and_then(|row| row.get_mut(b)) {
+            *cell = reachable;
+        }
+        if let Some(cell) = self.reachable.get_mut(b).and_then(|row| row.get_mut(a)) {
+            *cell = reachable;
+        }
+    }
+
+    /// Roll the model for one message from `from` to `to`. `None` means
+    /// the message doesn't survive (partitioned apart or dropped); `Some`
+    /// carries how many steps to delay it before it reaches the
+    /// bandwidth-gated delivery path.
+    fn roll(&mut self, from: usize, to: usize) -> Option<u64> {
+        if !self.can_reach(from, to) {
+            return None;
+        }
+        if self.drop_probability > 0.0 && self.rng.gen_bool(self.drop_probability) {
+            return None;
+        }
+        Some(self.latency.sample(&mut self.rng))
+    }
+}
+
+/// A fault-delayed message waiting for its scheduled step, holding onto
+/// its budget permit in the meantime just like a backlogged
+/// [`NodeLink`] entry.
+struct PendingFaultDelivery {
+    node_id: usize,
+    message: NodeMessage,
+    bytes: u32,
+    permit: OwnedSemaphorePermit,
+    deliver_at_step: u64,
 }
 
 /// Message bus for inter-node communication
 pub struct MessageBus {
     _num_nodes: usize,
-    channels: Arc<RwLock<Vec<mpsc::UnboundedSender<NodeMessage>>>>,
+    channels: Arc<RwLock<Vec<NodeLink>>>,
+    /// Gates how many serialized bytes may be in flight across the bus at
+    /// once; a send whose message doesn't fit blocks until a receiver drops
+    /// an earlier [`BufferedMessage`] and frees enough permits.
+    buffer_budget: Arc<Semaphore>,
+    max_buffered_bytes: u64,
+    /// Number of times a send to a given node had to wait for budget,
+    /// rather than acquiring it immediately.
+    backpressure_stalls: Arc<RwLock<HashMap<usize, u64>>>,
+    /// `dt` of the most recent `advance_step` call (or [`DEFAULT_STEP_DT`]
+    /// before the first one), used to convert a node's `capacity_bps` into
+    /// a per-step byte budget.
+    current_dt: RwLock<f64>,
+    /// Step number of the most recent `advance_step` call, used to
+    /// schedule fault-delayed deliveries.
+    current_step: RwLock<u64>,
+    /// Reachability/drop/latency model consulted before every delivery.
+    /// `None` (the default) behaves exactly as before this model existed.
+    fault_model: Mutex<Option<FaultModel>>,
+    /// Messages that survived the fault model but are still waiting for
+    /// their scheduled delivery step.
+    fault_queue: Mutex<Vec<PendingFaultDelivery>>,
 }
 
 impl MessageBus {
-    /// Create new message bus
+    /// Create new message bus with the default byte budget
+    /// ([`DEFAULT_MAX_BUFFERED_BYTES`]).
     pub fn new(num_nodes: usize) -> Self {
+        Self::with_buffer_budget(num_nodes, DEFAULT_MAX_BUFFERED_BYTES)
+    }
+
+    /// Create a new message bus with an explicit in-flight byte budget.
+    pub fn with_buffer_budget(num_nodes: usize, max_buffered_bytes: u64) -> Self {
         Self {
             _num_nodes: num_nodes,
             channels: Arc::new(RwLock::new(Vec::new())),
+            buffer_budget: Arc::new(Semaphore::new(Self::permits_for(max_buffered_bytes))),
+            max_buffered_bytes,
+            backpressure_stalls: Arc::new(RwLock::new(HashMap::new())),
+            current_dt: RwLock::new(DEFAULT_STEP_DT),
+            current_step: RwLock::new(0),
+            fault_model: Mutex::new(None),
+            fault_queue: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Install a fault model, replacing any previously installed one.
+    pub async fn set_fault_model(&self, model: FaultModel) {
+        *self.fault_model.lock().await = Some(model);
+    }
+
+    /// Remove the fault model: every send is reachable, drop-free, and
+    /// zero-latency again.
+    pub async fn clear_fault_model(&self) {
+        *self.fault_model.lock().await = None;
+    }
+
+    /// Partition the cluster via the installed fault model. A no-op if no
+    /// fault model is installed.
+    pub async fn partition(&self, group_a: &[usize], group_b: &[usize]) {
+        if let Some(model) = self.fault_model.lock().await.as_mut() {
+            model.partition(group_a, group_b);
+        }
+    }
+
+    /// Heal every partition via the installed fault model. A no-op if no
+    /// fault model is installed.
+    pub async fn heal(&self) {
+        if let Some(model) = self.fault_model.lock().await.as_mut() {
+            model.heal();
         }
     }
 
-    /// Register a channel for a node
-    pub async fn register_channel(&self, sender: mpsc::UnboundedSender<NodeMessage>) {
+    fn permits_for(max_buffered_bytes: u64) -> usize {
+        max_buffered_bytes.min(u32::MAX as u64) as usize
+    }
+
+    /// Register an unlimited-bandwidth channel for a node.
+    pub async fn register_channel(&self, sender: mpsc::UnboundedSender<BufferedMessage>) {
+        self.register_channel_with_capacity(sender, None).await;
+    }
+
+    /// Register a channel for a node whose link is capped at
+    /// `capacity_bps` bits/second. A send that would exceed the resulting
+    /// per-step byte budget (`capacity_bps * dt / 8`, `dt` from the most
+    /// recent [`Self::advance_step`]) queues in that node's backlog instead
+    /// of delivering instantly, and drains -- budget permitting -- on
+    /// later `advance_step` calls.
+    pub async fn register_channel_with_capacity(
+        &self,
+        sender: mpsc::UnboundedSender<BufferedMessage>,
+        capacity_bps: Option<f64>,
+    ) {
         let mut channels = self.channels.write().await;
-        channels.push(sender);
+        channels.push(NodeLink {
+            sender,
+            capacity_bps,
+            bytes_sent_this_step: AtomicU64::new(0),
+            backlog: Mutex::new(VecDeque::new()),
+        });
     }
 
-    /// Broadcast message to all nodes
+    /// Broadcast message to all nodes, as if sent by node 0 -- the
+    /// coordinator's conventional slot -- for fault-model purposes.
     pub async fn broadcast(&self, message: NodeMessage) -> Result<()> {
+        self.broadcast_from(0, message).await
+    }
+
+    /// Broadcast message to all nodes, evaluating the fault model (if any)
+    /// as a send from `from_node_id`.
+    pub async fn broadcast_from(&self, from_node_id: usize, message: NodeMessage) -> Result<()> {
         debug!("Broadcasting message: {:?}", message);
 
+        let bytes = Self::message_bytes(&message)?;
+        let dt = *self.current_dt.read().await;
         let channels = self.channels.read().await;
-        for sender in channels.iter() {
-            sender.send(message.clone())?;
+        for (node_id, link) in channels.iter().enumerate() {
+            self.send_one(from_node_id, node_id, link, message.clone(), bytes, dt)
+                .await?;
         }
 
         Ok(())
     }
 
-    /// Send message to specific node
+    /// Send message to specific node, as if sent by node 0 -- the
+    /// coordinator's conventional slot -- for fault-model purposes.
     pub async fn send_to(&self, node_id: usize, message: NodeMessage) -> Result<()> {
+        self.send_to_from(0, node_id, message).await
+    }
+
+    /// Send message to a specific node, evaluating the fault model (if
+    /// any) as a send from `from_node_id`.
+    pub async fn send_to_from(
+        &self,
+        from_node_id: usize,
+        node_id: usize,
+        message: NodeMessage,
+    ) -> Result<()> {
+        let bytes = Self::message_bytes(&message)?;
+        let dt = *self.current_dt.read().await;
+
         let channels = self.channels.read().await;
-        if let Some(sender) = channels.get(node_id) {
-            sender.send(message)?;
-            Ok(())
+        if let Some(link) = channels.get(node_id) {
+            self.send_one(from_node_id, node_id, link, message, bytes, dt)
+                .await
         } else {
             anyhow::bail!("Node {} not found", node_id);
         }
     }
+
+    /// Run one message through the fault model, then either hand it to
+    /// the bandwidth-gated delivery path immediately (zero latency) or
+    /// stash it in the fault queue for `advance_step` to release once its
+    /// scheduled step arrives. A message the fault model drops (partition
+    /// or unlucky roll) never touches the buffer budget at all.
+    async fn send_one(
+        &self,
+        from_node_id: usize,
+        node_id: usize,
+        link: &NodeLink,
+        message: NodeMessage,
+        bytes: u32,
+        dt: f64,
+    ) -> Result<()> {
+        let latency = match self.roll_fault(from_node_id, node_id).await {
+            Some(latency) => latency,
+            None => return Ok(()),
+        };
+
+        let permit = self.acquire_budget(node_id, bytes).await;
+        if latency == 0 {
+            Self::deliver_or_queue(node_id, link, message, bytes, dt, permit).await
+        } else {
+            let deliver_at_step = *self.current_step.read().await + latency;
+            self.fault_queue.lock().await.push(PendingFaultDelivery {
+                node_id,
+                message,
+                bytes,
+                permit,
+                deliver_at_step,
+            });
+            Ok(())
+        }
+    }
+
+    /// Consult the installed fault model (if any) for a send from
+    /// `from_node_id` to `to_node_id`. `None` means no fault model is
+    /// installed, which behaves as zero latency and certain delivery.
+    async fn roll_fault(&self, from_node_id: usize, to_node_id: usize) -> Option<u64> {
+        match self.fault_model.lock().await.as_mut() {
+            None => Some(0),
+            Some(model) => model.roll(from_node_id, to_node_id),
+        }
+    }
+
+    /// Deliver `message` to `link` immediately if it fits in the node's
+    /// remaining per-step byte budget, otherwise append it (and the budget
+    /// permit it holds) to the node's backlog for a later `advance_step` to
+    /// drain.
+    async fn deliver_or_queue(
+        node_id: usize,
+        link: &NodeLink,
+        message: NodeMessage,
+        bytes: u32,
+        dt: f64,
+        permit: OwnedSemaphorePermit,
+    ) -> Result<()> {
+        let fits = match Self::bytes_per_step(link.capacity_bps, dt) {
+            None => true,
+            Some(budget) => {
+                let mut sent = link.bytes_sent_this_step.load(Ordering::Relaxed);
+                loop {
+                    let after = sent + bytes as u64;
+                    if after > budget {
+                        break false;
+                    }
+                    match link.bytes_sent_this_step.compare_exchange_weak(
+                        sent,
+                        after,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => break true,
+                        Err(actual) => sent = actual,
+                    }
+                }
+            }
+        };
+
+        if fits {
+            link.sender
+                .send(BufferedMessage {
+                    message,
+                    _permit: permit,
+                })
+                .map_err(|_| anyhow::anyhow!("Node {} channel closed", node_id))
+        } else {
+            link.backlog.lock().await.push_back((message, permit));
+            Ok(())
+        }
+    }
+
+    /// Node capacity converted to a per-step byte budget, or `None` if the
+    /// node's channel is unlimited.
+    fn bytes_per_step(capacity_bps: Option<f64>, dt: f64) -> Option<u64> {
+        capacity_bps.map(|bps| (bps * dt / 8.0).max(0.0) as u64)
+    }
+
+    /// Advance the bus to simulation `step`, `dt` seconds after the last
+    /// one: releases fault-delayed messages (see [`FaultModel`]) whose
+    /// scheduled step has arrived, then resets every node's per-step
+    /// bandwidth budget to `capacity_bps * dt / 8` bytes and drains as much
+    /// of its backlog as that refreshed budget allows, in send order. A
+    /// message too large to ever fit the budget (e.g. a one-off burst
+    /// bigger than a full step's capacity) is retried every step until it
+    /// finally goes through, so it incurs realistic multi-step latency
+    /// instead of teleporting.
+    pub async fn advance_step(&self, step: u64, dt: f64) -> Result<()> {
+        *self.current_dt.write().await = dt;
+        *self.current_step.write().await = step;
+
+        let channels = self.channels.read().await;
+
+        // Reset every node's budget for the new step before releasing
+        // anything against it, so fault-delayed deliveries below compete
+        // for the same fresh budget as the backlog drain, not whatever was
+        // left over from the previous step.
+        for link in channels.iter() {
+            link.bytes_sent_this_step.store(0, Ordering::Relaxed);
+        }
+
+        let ready = {
+            let mut queue = self.fault_queue.lock().await;
+            let (ready, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut *queue)
+                .into_iter()
+                .partition(|pending| pending.deliver_at_step <= step);
+            *queue = pending;
+            ready
+        };
+        for pending in ready {
+            if let Some(link) = channels.get(pending.node_id) {
+                Self::deliver_or_queue(
+                    pending.node_id,
+                    link,
+                    pending.message,
+                    pending.bytes,
+                    dt,
+                    pending.permit,
+                )
+                .await?;
+            }
+        }
+
+        for (node_id, link) in channels.iter().enumerate() {
+            let budget = Self::bytes_per_step(link.capacity_bps, dt);
+            let mut backlog = link.backlog.lock().await;
+
+            while let Some((message, _)) = backlog.front() {
+                let bytes = Self::message_bytes(message)?;
+                if let Some(budget) = budget {
+                    let sent = link.bytes_sent_this_step.load(Ordering::Relaxed);
+                    if sent + bytes as u64 > budget {
+                        break;
+                    }
+                }
+                let (message, permit) = backlog.pop_front().expect("front() just returned Some");
+                link.bytes_sent_this_step
+                    .fetch_add(bytes as u64, Ordering::Relaxed);
+                link.sender
+                    .send(BufferedMessage {
+                        message,
+                        _permit: permit,
+                    })
+                    .map_err(|_| anyhow::anyhow!("Node {} channel closed", node_id))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Per-node bandwidth/backlog snapshot, for introspection (e.g.
+    /// dashboards, boundary-sync scaling studies).
+    pub async fn link_stats(&self) -> HashMap<usize, NodeLinkStats> {
+        let channels = self.channels.read().await;
+        let mut stats = HashMap::with_capacity(channels.len());
+        for (node_id, link) in channels.iter().enumerate() {
+            stats.insert(
+                node_id,
+                NodeLinkStats {
+                    capacity_bps: link.capacity_bps,
+                    bytes_sent_this_step: link.bytes_sent_this_step.load(Ordering::Relaxed),
+                    backlog_len: link.backlog.lock().await.len(),
+                },
+            );
+        }
+        stats
+    }
+
+    /// Serialized byte size of a message, used to size its budget permit.
+    fn message_bytes(message: &NodeMessage) -> Result<u32> {
+        Ok(serde_json::to_vec(message)?.len() as u32)
+    }
+
+    /// Acquire `bytes` worth of the buffer budget, recording a backpressure
+    /// stall against `node_id` if it wasn't immediately available. A
+    /// message larger than the whole budget is clamped to it, so an
+    /// oversized send still eventually goes through instead of deadlocking.
+    async fn acquire_budget(&self, node_id: usize, bytes: u32) -> OwnedSemaphorePermit {
+        let bytes = bytes.clamp(1, Self::permits_for(self.max_buffered_bytes) as u32);
+
+        match self.buffer_budget.clone().try_acquire_many_owned(bytes) {
+            Ok(permit) => permit,
+            Err(_) => {
+                *self
+                    .backpressure_stalls
+                    .write()
+                    .await
+                    .entry(node_id)
+                    .or_insert(0) += 1;
+                self.buffer_budget
+                    .clone()
+                    .acquire_many_owned(bytes)
+                    .await
+                    .expect("buffer_budget semaphore is never closed")
+            }
+        }
+    }
+
+    /// Bytes currently counted against the budget -- sent but not yet
+    /// received (dropped) by a peer.
+    pub fn buffered_bytes(&self) -> u64 {
+        self.max_buffered_bytes - self.buffer_budget.available_permits() as u64
+    }
+
+    /// Number of backpressure stalls observed per node since the bus was
+    /// created.
+    pub async fn backpressure_stalls(&self) -> HashMap<usize, u64> {
+        self.backpressure_stalls.read().await.clone()
+    }
 }
 
 #[cfg(test)]
@@ -91,4 +671,161 @@ mod tests {
         let received = rx.recv().await;
         assert!(received.is_some());
     }
+
+    #[tokio::test]
+    async fn send_frees_budget_only_once_the_receiver_drops_the_message() {
+        let bus = MessageBus::with_buffer_budget(1, 4096);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        bus.register_channel(tx).await;
+
+        assert_eq!(bus.buffered_bytes(), 0);
+
+        bus.send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+        assert!(bus.buffered_bytes() > 0);
+
+        let received = rx.recv().await.unwrap();
+        drop(received);
+        assert_eq!(bus.buffered_bytes(), 0);
+    }
+
+    #[tokio::test]
+    async fn send_stalls_and_is_recorded_when_budget_is_exhausted() {
+        let bus = Arc::new(MessageBus::with_buffer_budget(1, 1));
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        bus.register_channel(tx).await;
+
+        // Hold the whole (tiny) budget with one message still "in flight".
+        bus.send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+
+        let waiting_bus = bus.clone();
+        let waiting = tokio::spawn(async move {
+            waiting_bus
+                .send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+                .await
+        });
+
+        // Give the spawned send a chance to block on the exhausted budget
+        // before we free it up.
+        tokio::task::yield_now().await;
+
+        // Freeing the first message's permit is what lets the stalled send
+        // through -- the closest thing this test can do to a peer finally
+        // acknowledging receipt.
+        let first = rx.recv().await.unwrap();
+        drop(first);
+
+        waiting.await.unwrap().unwrap();
+        assert!(
+            bus.backpressure_stalls()
+                .await
+                .get(&0)
+                .copied()
+                .unwrap_or(0)
+                >= 1
+        );
+    }
+
+    #[tokio::test]
+    async fn capacity_limited_send_queues_and_drains_on_advance_step() {
+        let bus = MessageBus::new(1);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        // 800 bits/s affords 1 byte/step at the default dt (0.01s) -- far
+        // too little for a whole message -- but 1000 bytes/step at dt=10s.
+        bus.register_channel_with_capacity(tx, Some(800.0)).await;
+
+        bus.send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+
+        assert!(rx.try_recv().is_err());
+        assert_eq!(bus.link_stats().await[&0].backlog_len, 1);
+
+        bus.advance_step(1, 10.0).await.unwrap();
+
+        assert!(rx.try_recv().is_ok());
+        assert_eq!(bus.link_stats().await[&0].backlog_len, 0);
+    }
+
+    #[tokio::test]
+    async fn unlimited_channel_ignores_the_capacity_model() {
+        let bus = MessageBus::new(1);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        bus.register_channel(tx).await;
+
+        bus.send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+
+        assert!(rx.try_recv().is_ok());
+        assert_eq!(bus.link_stats().await[&0].backlog_len, 0);
+        assert_eq!(bus.link_stats().await[&0].capacity_bps, None);
+    }
+
+    #[tokio::test]
+    async fn partition_silently_drops_sends_across_the_split_and_heal_restores_them() {
+        let bus = MessageBus::new(2);
+        let (tx0, mut rx0) = mpsc::unbounded_channel();
+        let (tx1, mut rx1) = mpsc::unbounded_channel();
+        bus.register_channel(tx0).await;
+        bus.register_channel(tx1).await;
+        bus.set_fault_model(FaultModel::new(2, 0.0, LatencyModel::Fixed(0), 42))
+            .await;
+
+        bus.partition(&[0], &[1]).await;
+        bus.send_to_from(0, 1, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+        assert!(rx1.try_recv().is_err());
+
+        bus.heal().await;
+        bus.send_to_from(0, 1, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+        assert!(rx1.try_recv().is_ok());
+        // Node 0's own channel was never part of the partition.
+        bus.send_to_from(0, 0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+        assert!(rx0.try_recv().is_ok());
+    }
+
+    #[tokio::test]
+    async fn fixed_latency_delays_delivery_until_its_scheduled_step() {
+        let bus = MessageBus::new(1);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        bus.register_channel(tx).await;
+        bus.set_fault_model(FaultModel::new(1, 0.0, LatencyModel::Fixed(2), 7))
+            .await;
+
+        bus.send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+        assert!(rx.try_recv().is_err());
+
+        bus.advance_step(1, 0.01).await.unwrap();
+        assert!(rx.try_recv().is_err());
+
+        bus.advance_step(2, 0.01).await.unwrap();
+        assert!(rx.try_recv().is_ok());
+    }
+
+    #[tokio::test]
+    async fn certain_drop_probability_drops_every_send() {
+        let bus = MessageBus::new(1);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        bus.register_channel(tx).await;
+        bus.set_fault_model(FaultModel::new(1, 1.0, LatencyModel::Fixed(0), 1))
+            .await;
+
+        bus.send_to(0, NodeMessage::Heartbeat { worker_id: 0 })
+            .await
+            .unwrap();
+        assert!(rx.try_recv().is_err());
+    }
 }
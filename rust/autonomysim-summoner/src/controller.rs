@@ -0,0 +1,225 @@
+//! Closed-loop PID controller driving `VehicleControl` from a setpoint
+//!
+//! Replaces open-loop throttle/attitude commands with a tunable PID stack:
+//! a velocity/attitude error is formed against `VehicleState`, integrated
+//! with anti-windup clamping, and mapped into `VehicleControl`. Multirotors
+//! additionally run a coordinated-lean stage that turns a desired
+//! horizontal acceleration into target roll/pitch before the inner loop.
+
+use autonomysim_core::vehicle::{VehicleControl, VehicleState, VehicleType};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Gains and limits for the PID controller.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ControllerSettings {
+    pub kp: f64,
+    pub ki: f64,
+    pub kd: f64,
+    /// Maximum magnitude the integral term may accumulate to (anti-windup).
+    pub integral_limit: f64,
+}
+
+impl Default for ControllerSettings {
+    fn default() -> Self {
+        Self {
+            kp: 0.8,
+            ki: 0.1,
+            kd: 0.05,
+            integral_limit: 1.0,
+        }
+    }
+}
+
+/// A desired velocity or attitude for an agent to track.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Setpoint {
+    /// Desired linear velocity in world frame (m/s).
+    pub linear_velocity: Vector3<f64>,
+    /// Desired yaw rate (rad/s).
+    pub yaw_rate: f64,
+}
+
+/// Per-agent PID state carried between steps.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ControllerState {
+    integral: Vector3<f64>,
+    prev_error: Vector3<f64>,
+    yaw_integral: f64,
+    yaw_prev_error: f64,
+}
+
+impl ControllerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset accumulated integral/derivative history, e.g. after a
+    /// firmware hand-off or a discontinuous setpoint change.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Stabilized controller: PID on linear velocity/yaw rate, with an
+/// optional coordinated-lean stage for multirotors.
+pub struct PidController {
+    settings: ControllerSettings,
+}
+
+impl PidController {
+    pub fn new(settings: ControllerSettings) -> Self {
+        Self { settings }
+    }
+
+    /// Compute `VehicleControl` for one step given the current state,
+    /// the commanded setpoint, and this agent's persistent PID state.
+    pub fn update(
+        &self,
+        vehicle_type: VehicleType,
+        state: &VehicleState,
+        setpoint: Setpoint,
+        pid_state: &mut ControllerState,
+        dt: f64,
+    ) -> VehicleControl {
+        let dt = dt.max(1e-6);
+
+        // Multirotors route the horizontal component of this error through
+        // a coordinated-lean stage below instead of translating flat.
+        let error = setpoint.linear_velocity - state.linear_velocity;
+
+        pid_state.integral += error * dt;
+        pid_state.integral = clamp_vector(pid_state.integral, self.settings.integral_limit);
+
+        let derivative = (error - pid_state.prev_error) / dt;
+        pid_state.prev_error = error;
+
+        let output = error * self.settings.kp
+            + pid_state.integral * self.settings.ki
+            + derivative * self.settings.kd;
+
+        let yaw_error = setpoint.yaw_rate - state.angular_velocity.z;
+        pid_state.yaw_integral = (pid_state.yaw_integral + yaw_error * dt)
+            .clamp(-self.settings.integral_limit, self.settings.integral_limit);
+        let yaw_derivative = (yaw_error - pid_state.yaw_prev_error) / dt;
+        pid_state.yaw_prev_error = yaw_error;
+        let yaw_output = yaw_error * self.settings.kp
+            + pid_state.yaw_integral * self.settings.ki
+            + yaw_derivative * self.settings.kd;
+
+        if vehicle_type == VehicleType::Multirotor {
+            // Horizontal output becomes a target lean angle (roll/pitch);
+            // vertical output drives throttle around hover.
+            let (target_roll, target_pitch) = horizontal_accel_to_lean(output.x, output.y);
+            VehicleControl {
+                throttle: (0.5 + output.z).clamp(0.0, 1.0),
+                roll: target_roll.clamp(-1.0, 1.0),
+                pitch: target_pitch.clamp(-1.0, 1.0),
+                yaw: yaw_output.clamp(-1.0, 1.0),
+                ..Default::default()
+            }
+        } else {
+            VehicleControl {
+                throttle: (0.5 + output.x).clamp(0.0, 1.0),
+                steering: yaw_output.clamp(-1.0, 1.0),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Convert a desired horizontal acceleration (world-frame x/y, m/s^2-ish
+/// PID output) into a target roll/pitch lean, using the small-angle
+/// approximation `tan(theta) ~= a_horizontal / g`.
+fn horizontal_accel_to_lean(accel_x: f64, accel_y: f64) -> (f64, f64) {
+    const G: f64 = 9.81;
+    let target_pitch = (accel_x / G).atan();
+    let target_roll = (-accel_y / G).atan();
+    (target_roll, target_pitch)
+}
+
+fn clamp_vector(v: Vector3<f64>, limit: f64) -> Vector3<f64> {
+    Vector3::new(
+        v.x.clamp(-limit, limit),
+        v.y.clamp(-limit, limit),
+        v.z.clamp(-limit, limit),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::backend::Transform;
+    use nalgebra::{Point3, UnitQuaternion};
+
+    fn idle_state() -> VehicleState {
+        VehicleState {
+            vehicle_id: "agent-0".to_string(),
+            timestamp: 0.0,
+            transform: Transform::new(Point3::origin(), UnitQuaternion::identity()),
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        }
+    }
+
+    #[test]
+    fn hover_setpoint_yields_near_hover_throttle() {
+        let controller = PidController::new(ControllerSettings::default());
+        let mut pid_state = ControllerState::new();
+        let control = controller.update(
+            VehicleType::Multirotor,
+            &idle_state(),
+            Setpoint::default(),
+            &mut pid_state,
+            0.01,
+        );
+        assert!((control.throttle - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn forward_velocity_setpoint_commands_pitch_lean() {
+        let controller = PidController::new(ControllerSettings::default());
+        let mut pid_state = ControllerState::new();
+        let setpoint = Setpoint {
+            linear_velocity: Vector3::new(5.0, 0.0, 0.0),
+            yaw_rate: 0.0,
+        };
+        let control = controller.update(
+            VehicleType::Multirotor,
+            &idle_state(),
+            setpoint,
+            &mut pid_state,
+            0.01,
+        );
+        assert!(control.pitch > 0.0);
+    }
+
+    #[test]
+    fn integral_anti_windup_stays_within_limit() {
+        let settings = ControllerSettings {
+            integral_limit: 0.2,
+            ..Default::default()
+        };
+        let controller = PidController::new(settings);
+        let mut pid_state = ControllerState::new();
+        let setpoint = Setpoint {
+            linear_velocity: Vector3::new(100.0, 0.0, 0.0),
+            yaw_rate: 0.0,
+        };
+        for _ in 0..1000 {
+            controller.update(
+                VehicleType::Car,
+                &idle_state(),
+                setpoint,
+                &mut pid_state,
+                0.01,
+            );
+        }
+        assert!(pid_state.integral.x.abs() <= 0.2 + 1e-9);
+    }
+}
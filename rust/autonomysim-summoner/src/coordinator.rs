@@ -1,12 +1,16 @@
 //! Coordinator: Central orchestrator for distributed SUMMONER simulation
 
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{debug, info};
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tracing::{debug, info, warn};
 
-use crate::{MessageBus, NodeMessage, SummonerConfig};
+use crate::communication::BufferedMessage;
+use crate::tactics;
+use crate::{MessageBus, NodeMessage, SummonerConfig, TraceContext};
 
 /// Coordinator state
 pub struct Coordinator {
@@ -14,6 +18,35 @@ pub struct Coordinator {
     _message_bus: Arc<MessageBus>,
     worker_states: Arc<RwLock<HashMap<usize, WorkerState>>>,
     current_step: u64,
+    /// How long a worker may go without a heartbeat before
+    /// `check_worker_health`/`cluster_status` consider it down.
+    heartbeat_timeout: Duration,
+    /// Bumped every time the cluster topology changes (a node joins, starts
+    /// draining, or is fully retired) so workers can tell whether the
+    /// layout they last saw is stale. Broadcast alongside each
+    /// `NodeMessage::StepCommand`.
+    layout_version: AtomicU64,
+    /// Virtual stigmergy tuple space: latest-accepted value per key. See
+    /// `Self::stigmergy_put`/`Self::apply_stigmergy_update`.
+    stigmergy: Arc<RwLock<HashMap<String, StigmergyEntry>>>,
+    /// Per-key count of incoming `NodeMessage::StigmergyUpdate`s that lost
+    /// the Lamport-clock comparison against what this coordinator already
+    /// held, i.e. stale or conflicting writes that did not win.
+    stigmergy_lost_updates: Arc<RwLock<HashMap<String, u64>>>,
+    /// This coordinator's own `MessageBus` channel, used by
+    /// `Self::step_with_barrier` to collect worker `NodeMessage::StepComplete`
+    /// acks.
+    inbox: Mutex<mpsc::UnboundedReceiver<BufferedMessage>>,
+}
+
+/// One entry in the virtual stigmergy tuple space: a value plus the
+/// Lamport clock and writer id it was stamped with, used to resolve
+/// conflicting writes to the same key deterministically on every node.
+#[derive(Debug, Clone)]
+struct StigmergyEntry {
+    value: Vec<u8>,
+    clock: u64,
+    robot_id: usize,
 }
 
 /// Worker node state
@@ -21,33 +54,94 @@ pub struct Coordinator {
 pub struct WorkerState {
     pub worker_id: usize,
     pub num_agents: usize,
+    /// Inclusive `(min, max)` agent id range this worker was assigned at
+    /// registration time. Migrations move individual agents afterward
+    /// without updating this, so it reflects the original assignment, not
+    /// necessarily every agent id currently owned.
+    pub agent_range: (usize, usize),
+    pub gpu_capacity_mb: u64,
+    pub memory_capacity_mb: u64,
     pub last_heartbeat: std::time::Instant,
     pub is_healthy: bool,
+    /// Set via `Coordinator::set_draining` ahead of a planned shutdown, so
+    /// the scheduler/rebalancer can steer new agents away from this worker
+    /// without treating it as unhealthy.
+    pub draining: bool,
+}
+
+/// GPU/memory capacity a worker reports at registration, mirroring what a
+/// distributed cluster admin endpoint lists per node.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeCapacity {
+    pub gpu_capacity_mb: u64,
+    pub memory_capacity_mb: u64,
+}
+
+/// Point-in-time health/capacity snapshot for one worker, returned by
+/// `Coordinator::cluster_status`.
+#[derive(Debug, Clone)]
+pub struct NodeStatus {
+    pub worker_id: usize,
+    pub is_up: bool,
+    pub last_seen_secs_ago: f64,
+    pub agent_range: (usize, usize),
+    pub gpu_capacity_mb: u64,
+    pub memory_capacity_mb: u64,
+    pub current_load: usize,
+    pub draining: bool,
 }
 
 impl Coordinator {
     /// Create new coordinator
     pub async fn new(config: SummonerConfig, message_bus: Arc<MessageBus>) -> Result<Self> {
         info!("Initializing coordinator for {} nodes", config.num_nodes);
+        let heartbeat_timeout = Duration::from_secs_f64(config.heartbeat_timeout_secs);
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        message_bus.register_channel(tx).await;
 
         Ok(Self {
             _config: config,
             _message_bus: message_bus,
             worker_states: Arc::new(RwLock::new(HashMap::new())),
             current_step: 0,
+            heartbeat_timeout,
+            layout_version: AtomicU64::new(0),
+            stigmergy: Arc::new(RwLock::new(HashMap::new())),
+            stigmergy_lost_updates: Arc::new(RwLock::new(HashMap::new())),
+            inbox: Mutex::new(rx),
         })
     }
 
-    /// Register a worker node
-    pub async fn register_worker(&self, worker_id: usize, num_agents: usize) -> Result<()> {
+    /// Current cluster layout version, bumped on every topology change.
+    /// Workers compare this against the version they last saw (carried on
+    /// `NodeMessage::StepCommand`) to tell whether they need to resync.
+    pub fn layout_version(&self) -> u64 {
+        self.layout_version.load(Ordering::SeqCst)
+    }
+
+    /// Register a worker node at initial cluster bring-up. Does not bump
+    /// `layout_version` -- use `join_node` for a worker that joins an
+    /// already-running cluster.
+    pub async fn register_worker(
+        &self,
+        worker_id: usize,
+        num_agents: usize,
+        agent_range: (usize, usize),
+        capacity: NodeCapacity,
+    ) -> Result<()> {
         let mut states = self.worker_states.write().await;
         states.insert(
             worker_id,
             WorkerState {
                 worker_id,
                 num_agents,
+                agent_range,
+                gpu_capacity_mb: capacity.gpu_capacity_mb,
+                memory_capacity_mb: capacity.memory_capacity_mb,
                 last_heartbeat: std::time::Instant::now(),
                 is_healthy: true,
+                draining: false,
             },
         );
 
@@ -55,17 +149,254 @@ impl Coordinator {
         Ok(())
     }
 
-    /// Broadcast step command to all workers
-    pub async fn broadcast_step(&self, step: u64, dt: f64) -> Result<()> {
+    /// Add a new worker node to an already-running cluster, bumping
+    /// `layout_version` so existing workers know the layout changed.
+    pub async fn join_node(
+        &self,
+        worker_id: usize,
+        num_agents: usize,
+        agent_range: (usize, usize),
+        capacity: NodeCapacity,
+    ) -> Result<()> {
+        self.register_worker(worker_id, num_agents, agent_range, capacity)
+            .await?;
+        self.layout_version.fetch_add(1, Ordering::SeqCst);
+        info!("Worker {} joined the cluster", worker_id);
+        Ok(())
+    }
+
+    /// Begin removing `worker_id` from the cluster: mark it draining and
+    /// return a migration plan moving up to `agents_per_step` of its agents
+    /// onto the remaining non-draining workers, split as evenly as possible.
+    /// Call this once per step, feeding the returned plan through
+    /// `NodeMessage::MigrateAgent`/`report_agent_count`, until the draining
+    /// worker's agent count reaches zero and `report_agent_count`
+    /// auto-retires it.
+    pub async fn decommission_node(
+        &self,
+        worker_id: usize,
+        agents_per_step: usize,
+    ) -> Result<Vec<MigrationPlan>> {
+        self.set_draining(worker_id, true).await;
+        self.layout_version.fetch_add(1, Ordering::SeqCst);
+
+        let states = self.worker_states.read().await;
+        let Some(draining) = states.get(&worker_id) else {
+            anyhow::bail!("Worker {} not registered", worker_id);
+        };
+
+        let to_move = draining.num_agents.min(agents_per_step);
+        if to_move == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut destinations: Vec<usize> = states
+            .iter()
+            .filter(|(&id, s)| id != worker_id && !s.draining)
+            .map(|(&id, _)| id)
+            .collect();
+        destinations.sort_unstable();
+        if destinations.is_empty() {
+            anyhow::bail!(
+                "No non-draining worker available to receive agents from worker {}",
+                worker_id
+            );
+        }
+
+        let per_destination = to_move / destinations.len();
+        let mut leftover = to_move % destinations.len();
+        let mut plan = Vec::new();
+        for to_worker in destinations {
+            let mut count = per_destination;
+            if leftover > 0 {
+                count += 1;
+                leftover -= 1;
+            }
+            if count > 0 {
+                plan.push(MigrationPlan {
+                    from_worker: worker_id,
+                    to_worker,
+                    agent_count: count,
+                });
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Record a liveness signal from `worker_id`, refreshing its last-seen
+    /// timestamp and clearing any stale `is_healthy = false` from a previous
+    /// missed heartbeat.
+    pub async fn record_heartbeat(&self, worker_id: usize) {
+        let mut states = self.worker_states.write().await;
+        if let Some(state) = states.get_mut(&worker_id) {
+            state.last_heartbeat = std::time::Instant::now();
+            state.is_healthy = true;
+        }
+    }
+
+    /// Mark a worker as draining (or no longer draining) ahead of a planned
+    /// removal from the cluster.
+    pub async fn set_draining(&self, worker_id: usize, draining: bool) {
+        let mut states = self.worker_states.write().await;
+        if let Some(state) = states.get_mut(&worker_id) {
+            state.draining = draining;
+        }
+    }
+
+    /// Structured health/capacity report for every registered worker,
+    /// sorted by `worker_id`.
+    pub async fn cluster_status(&self) -> Vec<NodeStatus> {
+        let states = self.worker_states.read().await;
+        let mut statuses: Vec<NodeStatus> = states
+            .values()
+            .map(|state| NodeStatus {
+                worker_id: state.worker_id,
+                is_up: state.is_healthy && state.last_heartbeat.elapsed() < self.heartbeat_timeout,
+                last_seen_secs_ago: state.last_heartbeat.elapsed().as_secs_f64(),
+                agent_range: state.agent_range,
+                gpu_capacity_mb: state.gpu_capacity_mb,
+                memory_capacity_mb: state.memory_capacity_mb,
+                current_load: state.num_agents,
+                draining: state.draining,
+            })
+            .collect();
+
+        statuses.sort_by_key(|status| status.worker_id);
+        statuses
+    }
+
+    /// Broadcast step command to all workers, tagged with the current
+    /// `layout_version` so a worker that missed a topology change can tell
+    /// its view is stale, and with a freshly started `TraceContext` so each
+    /// worker's `execute_step` span stitches into this tick's distributed
+    /// trace. Returns that context so the caller's root span can record the
+    /// same `trace_id`.
+    #[tracing::instrument(skip(self))]
+    pub async fn broadcast_step(&self, step: u64, dt: f64) -> Result<TraceContext> {
         debug!("Broadcasting step {} to all workers", step);
 
-        let message = NodeMessage::StepCommand { step, dt };
+        // This path doesn't wait on `Self::inbox` the way `step_with_barrier`
+        // does, so drop whatever arrived there since the last call instead
+        // of letting it pile up unread for the life of the coordinator.
+        self.drain_inbox();
+
+        let trace_context = TraceContext::new_for_step();
+        let message = NodeMessage::StepCommand {
+            step,
+            dt,
+            layout_version: self.layout_version(),
+            trace_context,
+        };
         self._message_bus.broadcast(message).await?;
 
-        Ok(())
+        Ok(trace_context)
+    }
+
+    /// Discard every message currently queued in `Self::inbox` without
+    /// blocking. Called from `broadcast_step` so a coordinator that only
+    /// ever uses that path (not `step_with_barrier`) doesn't leak every
+    /// worker `Heartbeat`/`StepComplete` into an unbounded channel nobody
+    /// reads.
+    fn drain_inbox(&self) {
+        let Ok(mut inbox) = self.inbox.try_lock() else {
+            return;
+        };
+        while inbox.try_recv().is_ok() {}
+    }
+
+    /// Broadcast step `step` and block until the barrier releases, then run
+    /// `synchronize_boundaries` before returning -- so a caller using this
+    /// entry point instead of `broadcast_step` never dispatches step N+1
+    /// until step N's boundary exchange has completed, keeping agents that
+    /// cross partition edges consistent. Meant for a genuinely distributed
+    /// deployment where workers are separate processes acknowledging over
+    /// `MessageBus` rather than directly-awaited in-process tasks (compare
+    /// `Summoner::step`, which doesn't need this because awaiting its
+    /// worker task handles already is a barrier).
+    ///
+    /// The barrier waits for a `NodeMessage::StepComplete` from every
+    /// currently-healthy worker, up to `heartbeat_timeout`. On timeout it
+    /// calls `check_worker_health` (a worker stuck mid-step has also gone
+    /// quiet on heartbeats, so this is how laggards get marked unhealthy)
+    /// and proceeds anyway as long as at least `min_quorum` (0.0-1.0) of the
+    /// healthy roster captured at the start of this call acked in time;
+    /// otherwise returns an error instead of limping along on too few
+    /// workers.
+    #[tracing::instrument(skip(self, dt))]
+    pub async fn step_with_barrier(
+        &self,
+        step: u64,
+        dt: f64,
+        min_quorum: f64,
+    ) -> Result<TraceContext> {
+        let trace_context = TraceContext::new_for_step();
+        self._message_bus
+            .broadcast(NodeMessage::StepCommand {
+                step,
+                dt,
+                layout_version: self.layout_version(),
+                trace_context,
+            })
+            .await?;
+
+        let mut pending: HashSet<usize> = {
+            let states = self.worker_states.read().await;
+            states
+                .values()
+                .filter(|state| state.is_healthy)
+                .map(|state| state.worker_id)
+                .collect()
+        };
+        let required = ((pending.len() as f64) * min_quorum.clamp(0.0, 1.0)).ceil() as usize;
+        let roster = pending.len();
+
+        {
+            let mut inbox = self.inbox.lock().await;
+            let deadline = tokio::time::sleep(self.heartbeat_timeout);
+            tokio::pin!(deadline);
+            while !pending.is_empty() {
+                tokio::select! {
+                    message = inbox.recv() => {
+                        let Some(message) = message else {
+                            anyhow::bail!("Coordinator {}'s inbox channel closed", step);
+                        };
+                        if let NodeMessage::StepComplete { worker_id, step: completed } = message.message {
+                            if completed == step {
+                                pending.remove(&worker_id);
+                            }
+                        }
+                    }
+                    _ = &mut deadline => break,
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            warn!(
+                "Step {} barrier timed out waiting on worker(s) {:?}",
+                step, pending
+            );
+            let _ = self.check_worker_health().await;
+            let acked = roster - pending.len();
+            if acked < required {
+                anyhow::bail!(
+                    "Step {} barrier missed quorum: {} of {} required worker(s) acked ({} of {} healthy)",
+                    step,
+                    acked,
+                    required,
+                    acked,
+                    roster
+                );
+            }
+        }
+
+        self.synchronize_boundaries().await?;
+
+        Ok(trace_context)
     }
 
     /// Synchronize boundary data between workers
+    #[tracing::instrument(skip(self))]
     pub async fn synchronize_boundaries(&self) -> Result<()> {
         debug!("Synchronizing boundaries for step {}", self.current_step);
 
@@ -90,10 +421,9 @@ impl Coordinator {
     /// Check health of all workers
     pub async fn check_worker_health(&self) -> Result<()> {
         let mut states = self.worker_states.write().await;
-        let timeout = std::time::Duration::from_secs(5);
 
         for (worker_id, state) in states.iter_mut() {
-            if state.last_heartbeat.elapsed() > timeout {
+            if state.last_heartbeat.elapsed() > self.heartbeat_timeout {
                 state.is_healthy = false;
                 anyhow::bail!("Worker {} timeout", worker_id);
             }
@@ -107,4 +437,355 @@ impl Coordinator {
         let states = self.worker_states.read().await;
         states.values().map(|s| s.num_agents).sum()
     }
+
+    /// Record a worker's agent count after a spawn/despawn/migration, so
+    /// the next `rebalance_plan` call sees up-to-date load. A draining
+    /// worker that has fully emptied out is removed from the cluster
+    /// entirely, bumping `layout_version`.
+    pub async fn report_agent_count(&self, worker_id: usize, num_agents: usize) {
+        let mut states = self.worker_states.write().await;
+        let fully_drained = if let Some(state) = states.get_mut(&worker_id) {
+            state.num_agents = num_agents;
+            state.draining && num_agents == 0
+        } else {
+            false
+        };
+
+        if fully_drained {
+            states.remove(&worker_id);
+            self.layout_version.fetch_add(1, Ordering::SeqCst);
+            info!(
+                "Worker {} fully drained, retired from the cluster",
+                worker_id
+            );
+        }
+    }
+
+    /// Compute a migration plan that keeps every worker's agent count
+    /// within `tolerance` of the mean, moving agents from the most-loaded
+    /// to the least-loaded workers one at a time until balanced. Draining
+    /// workers are never chosen as a destination -- use `decommission_node`
+    /// to move agents off of them instead.
+    ///
+    /// Returns `(from_worker, to_worker, agent_count)` triples; the caller
+    /// picks which concrete agent ids to move (e.g. the ones nearest a
+    /// `SpatialPartitioning` boundary) and carries them out via
+    /// `NodeMessage::MigrateAgent`.
+    pub async fn rebalance_plan(&self, tolerance: usize) -> Vec<MigrationPlan> {
+        let states = self.worker_states.read().await;
+        if states.len() < 2 {
+            return Vec::new();
+        }
+
+        let mut loads: Vec<(usize, usize)> =
+            states.iter().map(|(id, s)| (*id, s.num_agents)).collect();
+        let destinations: std::collections::HashSet<usize> = states
+            .iter()
+            .filter(|(_, s)| !s.draining)
+            .map(|(id, _)| *id)
+            .collect();
+        if destinations.is_empty() {
+            return Vec::new();
+        }
+        let total: usize = loads.iter().map(|(_, n)| n).sum();
+        let mean = total / loads.len();
+
+        let mut plan = Vec::new();
+        loop {
+            loads.sort_by_key(|(_, n)| *n);
+            let (max_id, max_load) = loads[loads.len() - 1];
+            let Some(&(min_id, min_load)) = loads.iter().find(|(id, _)| destinations.contains(id))
+            else {
+                break;
+            };
+
+            if max_load.saturating_sub(min_load) <= tolerance || max_load <= mean {
+                break;
+            }
+
+            plan.push(MigrationPlan {
+                from_worker: max_id,
+                to_worker: min_id,
+                agent_count: 1,
+            });
+
+            // Reflect the move locally so subsequent iterations converge.
+            for (id, load) in loads.iter_mut() {
+                if *id == max_id {
+                    *load -= 1;
+                } else if *id == min_id {
+                    *load += 1;
+                }
+            }
+        }
+
+        plan
+    }
+
+    /// Write `value` under `key` into the virtual stigmergy tuple space on
+    /// behalf of `robot_id`, stamping it with the next Lamport clock tick
+    /// for that key, and broadcast the write as a
+    /// `NodeMessage::StigmergyUpdate` so other nodes can gossip it into
+    /// their own view. A local write always wins over whatever was there,
+    /// since its clock is always one past the highest this coordinator has
+    /// seen for `key`.
+    pub async fn stigmergy_put(
+        &self,
+        key: impl Into<String>,
+        value: Vec<u8>,
+        robot_id: usize,
+    ) -> Result<()> {
+        let key = key.into();
+        let clock = {
+            let table = self.stigmergy.read().await;
+            table.get(&key).map(|entry| entry.clock).unwrap_or(0) + 1
+        };
+
+        self.stigmergy.write().await.insert(
+            key.clone(),
+            StigmergyEntry {
+                value: value.clone(),
+                clock,
+                robot_id,
+            },
+        );
+
+        self._message_bus
+            .broadcast(NodeMessage::StigmergyUpdate {
+                key,
+                value,
+                clock,
+                robot_id,
+            })
+            .await
+    }
+
+    /// Read the current value for `key` in the virtual stigmergy tuple
+    /// space, if this coordinator has accepted a write for it.
+    pub async fn stigmergy_get(&self, key: &str) -> Option<Vec<u8>> {
+        self.stigmergy
+            .read()
+            .await
+            .get(key)
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Merge one incoming `NodeMessage::StigmergyUpdate` into the local
+    /// tuple space. Accepted iff `clock` is strictly greater than the
+    /// locally held clock for `key`, or equal with a strictly greater
+    /// `robot_id` -- the same deterministic tie-break on every node, so the
+    /// whole swarm converges on the same winner regardless of the order
+    /// updates arrive in. An accepted update is re-broadcast so it
+    /// continues diffusing through the partition topology; a losing one
+    /// bumps `key`'s entry in `Self::stigmergy_lost_updates`. Returns
+    /// whether the update was accepted.
+    pub async fn apply_stigmergy_update(
+        &self,
+        key: String,
+        value: Vec<u8>,
+        clock: u64,
+        robot_id: usize,
+    ) -> Result<bool> {
+        let accept = {
+            let table = self.stigmergy.read().await;
+            match table.get(&key) {
+                None => true,
+                Some(existing) => {
+                    clock > existing.clock
+                        || (clock == existing.clock && robot_id > existing.robot_id)
+                }
+            }
+        };
+
+        if !accept {
+            *self
+                .stigmergy_lost_updates
+                .write()
+                .await
+                .entry(key)
+                .or_insert(0) += 1;
+            return Ok(false);
+        }
+
+        self.stigmergy.write().await.insert(
+            key.clone(),
+            StigmergyEntry {
+                value: value.clone(),
+                clock,
+                robot_id,
+            },
+        );
+
+        self._message_bus
+            .broadcast(NodeMessage::StigmergyUpdate {
+                key,
+                value,
+                clock,
+                robot_id,
+            })
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Number of incoming stigmergy updates for `key` that lost the
+    /// Lamport-clock comparison since this coordinator was created, i.e.
+    /// stale or conflicting writes that didn't win. Zero for a key that has
+    /// never seen a conflict.
+    pub async fn stigmergy_lost_updates(&self, key: &str) -> u64 {
+        self.stigmergy_lost_updates
+            .read()
+            .await
+            .get(key)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Run Monte Carlo Tree Search against `state` and return the swarm
+    /// tactical action it recommends -- a split/regroup/reroute/avoid
+    /// macro-maneuver, rather than a per-agent force. Meant to be called
+    /// between `Summoner::step`s, not every one: search runs for up to
+    /// `SummonerConfig::tactics`'s `target_latency_ms` (the default
+    /// [`tactics::TacticalPlannerConfig`] if unset), so calling it on the
+    /// hot per-step path would eat directly into the step's own latency
+    /// budget.
+    pub fn plan_tactics(&self, state: &tactics::SwarmState) -> tactics::TacticalAction {
+        let config = self._config.tactics.unwrap_or_default();
+        tactics::search(state, &config, &tactics::DeadReckoningRollout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_coordinator() -> Coordinator {
+        let config = SummonerConfig::default();
+        let message_bus = Arc::new(MessageBus::new(1));
+        Coordinator::new(config, message_bus).await.unwrap()
+    }
+
+    /// A coordinator paired with its own `MessageBus`, plus a short
+    /// `heartbeat_timeout` so barrier-timeout tests don't block for the
+    /// 5-second production default.
+    async fn test_coordinator_with_bus() -> (Coordinator, Arc<MessageBus>) {
+        let config = SummonerConfig {
+            heartbeat_timeout_secs: 0.05,
+            ..Default::default()
+        };
+        let message_bus = Arc::new(MessageBus::new(1));
+        let coord = Coordinator::new(config, message_bus.clone()).await.unwrap();
+        (coord, message_bus)
+    }
+
+    #[tokio::test]
+    async fn step_with_barrier_releases_once_the_only_healthy_worker_acks() {
+        let (coord, message_bus) = test_coordinator_with_bus().await;
+        coord
+            .register_worker(0, 10, (0, 9), NodeCapacity::default())
+            .await
+            .unwrap();
+
+        tokio::spawn(async move {
+            message_bus
+                .broadcast(NodeMessage::StepComplete { worker_id: 0, step: 0 })
+                .await
+                .unwrap();
+        });
+
+        coord.step_with_barrier(0, 0.01, 1.0).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn step_with_barrier_proceeds_once_min_quorum_fraction_acks() {
+        let (coord, message_bus) = test_coordinator_with_bus().await;
+        coord
+            .register_worker(0, 5, (0, 4), NodeCapacity::default())
+            .await
+            .unwrap();
+        coord
+            .register_worker(1, 5, (5, 9), NodeCapacity::default())
+            .await
+            .unwrap();
+
+        tokio::spawn(async move {
+            message_bus
+                .broadcast(NodeMessage::StepComplete { worker_id: 0, step: 0 })
+                .await
+                .unwrap();
+        });
+
+        coord.step_with_barrier(0, 0.01, 0.5).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn step_with_barrier_errors_when_quorum_is_not_met_in_time() {
+        let (coord, _message_bus) = test_coordinator_with_bus().await;
+        coord
+            .register_worker(0, 10, (0, 9), NodeCapacity::default())
+            .await
+            .unwrap();
+
+        assert!(coord.step_with_barrier(0, 0.01, 1.0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stigmergy_put_then_get_roundtrips() {
+        let coord = test_coordinator().await;
+        coord.stigmergy_put("rally_point", vec![1, 2, 3], 7).await.unwrap();
+        assert_eq!(coord.stigmergy_get("rally_point").await, Some(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn higher_clock_wins_and_lower_clock_is_counted_as_lost() {
+        let coord = test_coordinator().await;
+        coord
+            .apply_stigmergy_update("k".to_string(), vec![1], 5, 1)
+            .await
+            .unwrap();
+
+        let accepted = coord
+            .apply_stigmergy_update("k".to_string(), vec![2], 3, 1)
+            .await
+            .unwrap();
+
+        assert!(!accepted);
+        assert_eq!(coord.stigmergy_get("k").await, Some(vec![1]));
+        assert_eq!(coord.stigmergy_lost_updates("k").await, 1);
+    }
+
+    #[tokio::test]
+    async fn tied_clock_breaks_by_higher_robot_id() {
+        let coord = test_coordinator().await;
+        coord
+            .apply_stigmergy_update("k".to_string(), vec![1], 4, 2)
+            .await
+            .unwrap();
+
+        let accepted = coord
+            .apply_stigmergy_update("k".to_string(), vec![2], 4, 9)
+            .await
+            .unwrap();
+
+        assert!(accepted);
+        assert_eq!(coord.stigmergy_get("k").await, Some(vec![2]));
+        assert_eq!(coord.stigmergy_lost_updates("k").await, 0);
+    }
+
+    #[tokio::test]
+    async fn local_put_always_wins_over_the_previous_local_write() {
+        let coord = test_coordinator().await;
+        coord.stigmergy_put("k", vec![1], 1).await.unwrap();
+        coord.stigmergy_put("k", vec![2], 1).await.unwrap();
+        assert_eq!(coord.stigmergy_get("k").await, Some(vec![2]));
+    }
+}
+
+/// A single migration step: move `agent_count` agents from `from_worker`
+/// to `to_worker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationPlan {
+    pub from_worker: usize,
+    pub to_worker: usize,
+    pub agent_count: usize,
 }
@@ -0,0 +1,362 @@
+//! Debug visualization primitives published over the [`crate::MessageBus`]
+//!
+//! Controllers, workers, and tests have no way to annotate the scene today
+//! (e.g. to draw an agent's planned trajectory or label it with its battery
+//! level). This module defines serializable draw primitives that travel as
+//! an ordinary [`crate::NodeMessage`] so any attached frontend or the ROS
+//! bridge can render them without this crate knowing anything about
+//! rendering.
+
+use autonomysim_core::backend::{Position, Vec3};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::{MessageBus, NodeMessage};
+
+/// RGBA color for a debug primitive, components in `[0.0, 1.0]`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DebugColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl DebugColor {
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    pub const WHITE: DebugColor = DebugColor::rgb(1.0, 1.0, 1.0);
+    pub const RED: DebugColor = DebugColor::rgb(1.0, 0.0, 0.0);
+    pub const GREEN: DebugColor = DebugColor::rgb(0.0, 1.0, 0.0);
+    pub const BLUE: DebugColor = DebugColor::rgb(0.0, 0.0, 1.0);
+}
+
+/// Shared styling for a debug primitive.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DebugDrawStyle {
+    pub color: DebugColor,
+    pub thickness: f32,
+    /// How long the primitive stays visible, in simulation seconds, once
+    /// `persistent` is `false`. Ignored when `persistent` is `true`.
+    pub duration: f64,
+    /// Persistent primitives never expire; the caller must remove them
+    /// explicitly (e.g. by redrawing with the same id, once a consumer
+    /// chooses to key on one).
+    pub persistent: bool,
+}
+
+impl Default for DebugDrawStyle {
+    fn default() -> Self {
+        Self {
+            color: DebugColor::WHITE,
+            thickness: 1.0,
+            duration: 1.0,
+            persistent: false,
+        }
+    }
+}
+
+/// A single debug-draw shape, anchored at one or more world `Position`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DebugPrimitive {
+    LineStrip {
+        points: Vec<Position>,
+        style: DebugDrawStyle,
+    },
+    Arrow {
+        start: Position,
+        end: Position,
+        style: DebugDrawStyle,
+    },
+    Point {
+        position: Position,
+        style: DebugDrawStyle,
+    },
+    Text {
+        position: Position,
+        text: String,
+        style: DebugDrawStyle,
+    },
+}
+
+impl DebugPrimitive {
+    pub fn style(&self) -> &DebugDrawStyle {
+        match self {
+            DebugPrimitive::LineStrip { style, .. }
+            | DebugPrimitive::Arrow { style, .. }
+            | DebugPrimitive::Point { style, .. }
+            | DebugPrimitive::Text { style, .. } => style,
+        }
+    }
+}
+
+/// A primitive tagged with an id and the simulation time it was issued at,
+/// as published onto the `MessageBus`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugDrawHandle {
+    pub id: u64,
+    pub primitive: DebugPrimitive,
+    pub issued_at: f64,
+}
+
+impl DebugDrawHandle {
+    /// Whether this primitive should still be visible at `sim_time`.
+    pub fn is_expired(&self, sim_time: f64) -> bool {
+        let style = self.primitive.style();
+        !style.persistent && sim_time >= self.issued_at + style.duration
+    }
+}
+
+/// Publishes debug-draw primitives onto the `MessageBus` as
+/// `NodeMessage::DebugDraw` records, assigning each a unique id.
+pub struct DebugDrawChannel {
+    message_bus: Arc<MessageBus>,
+    next_id: AtomicU64,
+}
+
+impl DebugDrawChannel {
+    pub fn new(message_bus: Arc<MessageBus>) -> Self {
+        Self {
+            message_bus,
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Draw a connected line strip through `points`, e.g. an agent's
+    /// planned trajectory.
+    pub async fn draw_line_strip(
+        &self,
+        points: Vec<Position>,
+        style: DebugDrawStyle,
+        sim_time: f64,
+    ) -> anyhow::Result<u64> {
+        self.publish(DebugPrimitive::LineStrip { points, style }, sim_time)
+            .await
+    }
+
+    /// Draw an arrow from `start` to `end`, e.g. an agent's velocity
+    /// vector.
+    pub async fn draw_arrow(
+        &self,
+        start: Position,
+        end: Position,
+        style: DebugDrawStyle,
+        sim_time: f64,
+    ) -> anyhow::Result<u64> {
+        self.publish(DebugPrimitive::Arrow { start, end, style }, sim_time)
+            .await
+    }
+
+    /// Draw a single point marker.
+    pub async fn draw_point(
+        &self,
+        position: Position,
+        style: DebugDrawStyle,
+        sim_time: f64,
+    ) -> anyhow::Result<u64> {
+        self.publish(DebugPrimitive::Point { position, style }, sim_time)
+            .await
+    }
+
+    /// Draw a text label, e.g. an agent's `VehicleId` and battery level.
+    pub async fn draw_text(
+        &self,
+        position: Position,
+        text: String,
+        style: DebugDrawStyle,
+        sim_time: f64,
+    ) -> anyhow::Result<u64> {
+        self.publish(
+            DebugPrimitive::Text {
+                position,
+                text,
+                style,
+            },
+            sim_time,
+        )
+        .await
+    }
+
+    async fn publish(&self, primitive: DebugPrimitive, sim_time: f64) -> anyhow::Result<u64> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let handle = DebugDrawHandle {
+            id,
+            primitive,
+            issued_at: sim_time,
+        };
+        self.message_bus
+            .broadcast(NodeMessage::DebugDraw(handle))
+            .await?;
+        Ok(id)
+    }
+}
+
+/// Tracks debug primitives a consumer (a frontend, or the ROS bridge) has
+/// received, so it can prune ones past their `duration` without needing to
+/// understand simulation time itself.
+#[derive(Debug, Default)]
+pub struct DebugDrawRegistry {
+    active: HashMap<u64, DebugDrawHandle>,
+}
+
+impl DebugDrawRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, handle: DebugDrawHandle) {
+        self.active.insert(handle.id, handle);
+    }
+
+    /// Drop every non-persistent primitive whose duration has elapsed as of
+    /// `sim_time`.
+    pub fn prune_expired(&mut self, sim_time: f64) {
+        self.active.retain(|_, handle| !handle.is_expired(sim_time));
+    }
+
+    pub fn active(&self) -> impl Iterator<Item = &DebugDrawHandle> {
+        self.active.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+}
+
+/// Helper for drawing an arrow from an agent's position along its velocity
+/// vector, scaled by `scale`.
+pub fn velocity_arrow(
+    position: Position,
+    velocity: Vec3,
+    scale: f64,
+    style: DebugDrawStyle,
+) -> DebugPrimitive {
+    DebugPrimitive::Arrow {
+        start: position,
+        end: position + velocity * scale,
+        style,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Point3, Vector3};
+
+    #[tokio::test]
+    async fn draw_calls_publish_with_incrementing_ids() {
+        let bus = Arc::new(MessageBus::new(1));
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        bus.register_channel(tx).await;
+
+        let channel = DebugDrawChannel::new(bus);
+        let id0 = channel
+            .draw_point(Point3::origin(), DebugDrawStyle::default(), 0.0)
+            .await
+            .unwrap();
+        let id1 = channel
+            .draw_point(Point3::origin(), DebugDrawStyle::default(), 0.0)
+            .await
+            .unwrap();
+
+        assert_eq!(id0, 0);
+        assert_eq!(id1, 1);
+        assert!(rx.recv().await.is_some());
+        assert!(rx.recv().await.is_some());
+    }
+
+    #[test]
+    fn non_persistent_primitive_expires_after_duration() {
+        let handle = DebugDrawHandle {
+            id: 0,
+            primitive: DebugPrimitive::Point {
+                position: Point3::origin(),
+                style: DebugDrawStyle {
+                    duration: 2.0,
+                    persistent: false,
+                    ..Default::default()
+                },
+            },
+            issued_at: 1.0,
+        };
+
+        assert!(!handle.is_expired(2.5));
+        assert!(handle.is_expired(3.0));
+    }
+
+    #[test]
+    fn persistent_primitive_never_expires() {
+        let handle = DebugDrawHandle {
+            id: 0,
+            primitive: DebugPrimitive::Point {
+                position: Point3::origin(),
+                style: DebugDrawStyle {
+                    duration: 0.01,
+                    persistent: true,
+                    ..Default::default()
+                },
+            },
+            issued_at: 0.0,
+        };
+
+        assert!(!handle.is_expired(1_000.0));
+    }
+
+    #[test]
+    fn registry_prunes_only_expired_primitives() {
+        let mut registry = DebugDrawRegistry::new();
+        registry.insert(DebugDrawHandle {
+            id: 0,
+            primitive: DebugPrimitive::Point {
+                position: Point3::origin(),
+                style: DebugDrawStyle {
+                    duration: 1.0,
+                    persistent: false,
+                    ..Default::default()
+                },
+            },
+            issued_at: 0.0,
+        });
+        registry.insert(DebugDrawHandle {
+            id: 1,
+            primitive: DebugPrimitive::Point {
+                position: Point3::origin(),
+                style: DebugDrawStyle {
+                    duration: 1.0,
+                    persistent: true,
+                    ..Default::default()
+                },
+            },
+            issued_at: 0.0,
+        });
+
+        registry.prune_expired(5.0);
+        assert_eq!(registry.len(), 1);
+        assert!(registry.active().any(|h| h.id == 1));
+    }
+
+    #[test]
+    fn velocity_arrow_points_along_velocity_direction() {
+        let arrow = velocity_arrow(
+            Point3::origin(),
+            Vector3::new(1.0, 0.0, 0.0),
+            2.0,
+            DebugDrawStyle::default(),
+        );
+        match arrow {
+            DebugPrimitive::Arrow { start, end, .. } => {
+                assert_eq!(start, Point3::origin());
+                assert_eq!(end, Point3::new(2.0, 0.0, 0.0));
+            }
+            _ => panic!("expected arrow primitive"),
+        }
+    }
+}
@@ -0,0 +1,563 @@
+//! Firmware-in-the-loop (FIL/HIL) bridge for external autopilots
+//!
+//! Lets a `VehicleSpec` be flown by a real autopilot stack (ArduPilot, PX4)
+//! over MAVLink instead of being driven directly by `VehicleControl` values
+//! computed in-process. Each simulation step, the agent's sensed state is
+//! packed into real MAVLink v2 `HIL_SENSOR` / `HIL_GPS` frames and sent to
+//! the autopilot; the `HIL_ACTUATOR_CONTROLS` frame it returns is decoded
+//! and translated back into `VehicleControl`.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use autonomysim_core::vehicle::{VehicleControl, VehicleState};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use tokio::net::UdpSocket;
+use tracing::{debug, warn};
+
+/// MAVLink v2 frame start marker.
+const MAVLINK_V2_MAGIC: u8 = 0xFD;
+
+const MSG_ID_HIL_SENSOR: u32 = 107;
+const MSG_ID_HIL_GPS: u32 = 113;
+const MSG_ID_HIL_ACTUATOR_CONTROLS: u32 = 93;
+
+const CRC_EXTRA_HIL_SENSOR: u8 = 108;
+const CRC_EXTRA_HIL_GPS: u8 = 124;
+const CRC_EXTRA_HIL_ACTUATOR_CONTROLS: u8 = 47;
+
+/// `HIL_SENSOR` field-present bitmask bits that this stub sensor pipeline
+/// actually populates: accel (0-2), gyro (3-5), abs_pressure (9), pressure_alt (11).
+const HIL_SENSOR_FIELDS_UPDATED: u32 =
+    (1 << 0) | (1 << 1) | (1 << 2) | (1 << 3) | (1 << 4) | (1 << 5) | (1 << 9) | (1 << 11);
+
+/// MAVLink X.25 CRC accumulation step, per the MAVLink wire-format spec.
+fn crc_accumulate(byte: u8, crc: u16) -> u16 {
+    let mut tmp = (byte ^ (crc as u8)) as u16;
+    tmp = (tmp ^ (tmp << 4)) & 0xff;
+    (crc >> 8) ^ (tmp << 8) ^ (tmp << 3) ^ (tmp >> 4)
+}
+
+fn mavlink_crc(header_and_payload: &[u8], crc_extra: u8) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in header_and_payload {
+        crc = crc_accumulate(byte, crc);
+    }
+    crc_accumulate(crc_extra, crc)
+}
+
+/// Wrap a MAVLink v2 payload in a frame: header, payload, and trailing CRC.
+///
+/// `seq`/`sysid`/`compid` identify this sender; signing is not implemented,
+/// matching how the rest of this bridge only targets unauthenticated SITL links.
+fn encode_mavlink_v2_frame(
+    msgid: u32,
+    crc_extra: u8,
+    seq: u8,
+    sysid: u8,
+    compid: u8,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut header_and_payload = Vec::with_capacity(9 + payload.len());
+    header_and_payload.push(payload.len() as u8);
+    header_and_payload.push(0); // incompat_flags
+    header_and_payload.push(0); // compat_flags
+    header_and_payload.push(seq);
+    header_and_payload.push(sysid);
+    header_and_payload.push(compid);
+    header_and_payload.extend_from_slice(&msgid.to_le_bytes()[0..3]);
+    header_and_payload.extend_from_slice(payload);
+
+    let crc = mavlink_crc(&header_and_payload, crc_extra);
+
+    let mut frame = Vec::with_capacity(1 + header_and_payload.len() + 2);
+    frame.push(MAVLINK_V2_MAGIC);
+    frame.extend_from_slice(&header_and_payload);
+    frame.extend_from_slice(&crc.to_le_bytes());
+    frame
+}
+
+/// Simulated IMU/barometer payload packed into a `HIL_SENSOR` message.
+///
+/// Mirrors the subset of MAVLink's `HIL_SENSOR` fields that AutonomySim's
+/// sensor simulation can actually populate; the rest are left unset in
+/// `fields_updated` rather than sent as fabricated zeros.
+#[derive(Debug, Clone, Copy)]
+pub struct HilSensorFrame {
+    pub time_usec: u64,
+    pub xacc: f32,
+    pub yacc: f32,
+    pub zacc: f32,
+    pub xgyro: f32,
+    pub ygyro: f32,
+    pub zgyro: f32,
+    pub abs_pressure: f32,
+    pub pressure_alt: f32,
+}
+
+impl HilSensorFrame {
+    /// Build a HIL sensor frame from the agent's ground-truth vehicle state.
+    ///
+    /// This stands in for reading the IMU/baro sensor sim outputs; the
+    /// values here are noise-free, matching how other stub subsystems in
+    /// this crate currently source state until a dedicated sensor pipeline
+    /// feeds them.
+    pub fn from_vehicle_state(state: &VehicleState, time_usec: u64) -> Self {
+        let accel = state.linear_acceleration;
+        let gyro = state.angular_velocity;
+
+        Self {
+            time_usec,
+            xacc: accel.x as f32,
+            yacc: accel.y as f32,
+            zacc: accel.z as f32,
+            xgyro: gyro.x as f32,
+            ygyro: gyro.y as f32,
+            zgyro: gyro.z as f32,
+            abs_pressure: 1013.25,
+            pressure_alt: state.transform.position.z as f32,
+        }
+    }
+
+    /// Encode this frame's `HIL_SENSOR` payload (MAVLink msg id 107).
+    fn to_payload(self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(65);
+        payload.extend_from_slice(&self.time_usec.to_le_bytes());
+        payload.extend_from_slice(&self.xacc.to_le_bytes());
+        payload.extend_from_slice(&self.yacc.to_le_bytes());
+        payload.extend_from_slice(&self.zacc.to_le_bytes());
+        payload.extend_from_slice(&self.xgyro.to_le_bytes());
+        payload.extend_from_slice(&self.ygyro.to_le_bytes());
+        payload.extend_from_slice(&self.zgyro.to_le_bytes());
+        payload.extend_from_slice(&0f32.to_le_bytes()); // xmag (unmodeled)
+        payload.extend_from_slice(&0f32.to_le_bytes()); // ymag (unmodeled)
+        payload.extend_from_slice(&0f32.to_le_bytes()); // zmag (unmodeled)
+        payload.extend_from_slice(&self.abs_pressure.to_le_bytes());
+        payload.extend_from_slice(&0f32.to_le_bytes()); // diff_pressure (unmodeled)
+        payload.extend_from_slice(&self.pressure_alt.to_le_bytes());
+        payload.extend_from_slice(&0f32.to_le_bytes()); // temperature (unmodeled)
+        payload.extend_from_slice(&HIL_SENSOR_FIELDS_UPDATED.to_le_bytes());
+        payload.push(0); // id: single IMU
+        payload
+    }
+}
+
+/// Simulated GPS fix packed into a `HIL_GPS` message.
+#[derive(Debug, Clone, Copy)]
+pub struct HilGpsFrame {
+    pub time_usec: u64,
+    pub lat: i32,
+    pub lon: i32,
+    pub alt: i32,
+}
+
+impl HilGpsFrame {
+    /// Build a HIL GPS frame from the agent's ground-truth vehicle state.
+    ///
+    /// Placeholder geodetic conversion: treat local ENU meters as a flat
+    /// offset from a fixed reference origin, matching the "flat earth"
+    /// assumption used elsewhere in this crate's stubs.
+    pub fn from_vehicle_state(state: &VehicleState, time_usec: u64) -> Self {
+        const REF_LAT_DEG: f64 = 37.8;
+        const REF_LON_DEG: f64 = -122.4;
+        const METERS_PER_DEG_LAT: f64 = 111_320.0;
+
+        let position = &state.transform.position;
+        let lat = REF_LAT_DEG + position.y / METERS_PER_DEG_LAT;
+        let lon_scale = METERS_PER_DEG_LAT * REF_LAT_DEG.to_radians().cos().max(1e-6);
+        let lon = REF_LON_DEG + position.x / lon_scale;
+
+        Self {
+            time_usec,
+            lat: (lat * 1e7) as i32,
+            lon: (lon * 1e7) as i32,
+            alt: (position.z * 1000.0) as i32,
+        }
+    }
+
+    /// Encode this frame's `HIL_GPS` payload (MAVLink msg id 113).
+    ///
+    /// `eph`/`epv`/`vel`/`cog` are reported as "unknown" (MAVLink's
+    /// `UINT16_MAX` sentinel); this bridge doesn't model GPS-derived
+    /// velocity or dilution of precision. `fix_type`/`satellites_visible`
+    /// are reported as a fixed healthy 3D fix so the autopilot accepts the
+    /// position, matching the noise-free ground truth the rest of this
+    /// frame carries.
+    fn to_payload(self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(30);
+        payload.extend_from_slice(&self.time_usec.to_le_bytes());
+        payload.extend_from_slice(&self.lat.to_le_bytes());
+        payload.extend_from_slice(&self.lon.to_le_bytes());
+        payload.extend_from_slice(&self.alt.to_le_bytes());
+        payload.extend_from_slice(&u16::MAX.to_le_bytes()); // eph (unknown)
+        payload.extend_from_slice(&u16::MAX.to_le_bytes()); // epv (unknown)
+        payload.extend_from_slice(&u16::MAX.to_le_bytes()); // vel (unknown)
+        payload.extend_from_slice(&0i16.to_le_bytes()); // vn (unmodeled)
+        payload.extend_from_slice(&0i16.to_le_bytes()); // ve (unmodeled)
+        payload.extend_from_slice(&0i16.to_le_bytes()); // vd (unmodeled)
+        payload.extend_from_slice(&u16::MAX.to_le_bytes()); // cog (unknown)
+        payload.push(3); // fix_type: 3D fix
+        payload.push(10); // satellites_visible
+        payload
+    }
+}
+
+/// Actuator output decoded from a `HIL_ACTUATOR_CONTROLS` message.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HilActuatorControls {
+    /// Normalized per-output control values (motors, servos), `[-1, 1]` or `[0, 1]`.
+    ///
+    /// `HIL_ACTUATOR_CONTROLS` carries 16 output channels on the wire; only
+    /// the first 8 (the conventional motor/servo outputs) are kept here.
+    pub controls: [f32; 8],
+}
+
+impl HilActuatorControls {
+    /// Decode a `HIL_ACTUATOR_CONTROLS` payload (MAVLink msg id 93).
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        // time_usec(8) + flags(8) precede the controls array on the wire.
+        const CONTROLS_OFFSET: usize = 16;
+        if payload.len() < CONTROLS_OFFSET + 8 * 4 {
+            return None;
+        }
+        let mut controls = [0f32; 8];
+        for (i, chunk) in payload[CONTROLS_OFFSET..CONTROLS_OFFSET + 8 * 4]
+            .chunks_exact(4)
+            .enumerate()
+        {
+            controls[i] = f32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Some(Self { controls })
+    }
+
+    /// Translate firmware actuator output into a `VehicleControl`.
+    ///
+    /// Assumes the conventional ArduPilot/PX4 multirotor output mapping:
+    /// outputs 0-3 are motor thrusts, which are summarized into throttle
+    /// plus the attitude channels the rest of AutonomySim's physics
+    /// backends consume.
+    pub fn to_vehicle_control(&self) -> VehicleControl {
+        let motors = &self.controls[0..4];
+        let throttle = (motors.iter().sum::<f32>() / 4.0) as f64;
+        // Differential thrust across the quad "X" layout approximates the
+        // commanded body-rate outputs without requiring a full mixer here;
+        // the physics backend applies fine-grained per-rotor thrust itself.
+        let roll = ((motors[1] + motors[2]) - (motors[0] + motors[3])) as f64 / 2.0;
+        let pitch = ((motors[0] + motors[1]) - (motors[2] + motors[3])) as f64 / 2.0;
+        let yaw = ((motors[0] + motors[2]) - (motors[1] + motors[3])) as f64 / 2.0;
+
+        VehicleControl {
+            throttle: throttle.clamp(0.0, 1.0),
+            roll: roll.clamp(-1.0, 1.0),
+            pitch: pitch.clamp(-1.0, 1.0),
+            yaw: yaw.clamp(-1.0, 1.0),
+            ..Default::default()
+        }
+    }
+}
+
+/// A link to an external autopilot driving one agent.
+///
+/// Implementors own the transport (UDP/serial) and the wire encoding; the
+/// `Worker` only deals in `VehicleState`/`VehicleControl`.
+#[async_trait]
+pub trait FirmwareLink: Send + Sync {
+    /// Push sensed state to the autopilot and read back the resulting
+    /// actuator command for this step. Returns `None` if the autopilot has
+    /// not produced a new actuator frame yet (e.g. link not connected),
+    /// in which case the caller should hold the previous control.
+    async fn exchange(
+        &mut self,
+        state: &VehicleState,
+        time_usec: u64,
+    ) -> Result<Option<VehicleControl>>;
+}
+
+/// `FirmwareLink` implementation speaking MAVLink v2 HIL messages over UDP,
+/// suitable for ArduPilot SITL or PX4 SITL in HIL mode.
+pub struct MavlinkFirmwareLink {
+    socket: UdpSocket,
+    autopilot_addr: SocketAddr,
+    recv_buf: Vec<u8>,
+    sysid: u8,
+    compid: u8,
+    seq: u8,
+}
+
+impl MavlinkFirmwareLink {
+    /// MAVLink system id this bridge announces itself as on the HIL link.
+    const SYSTEM_ID: u8 = 1;
+    /// `MAV_COMP_ID_AUTOPILOT1`-adjacent id used for the sim side of the link.
+    const COMPONENT_ID: u8 = 1;
+
+    /// Bind a local UDP socket and connect it to the autopilot's HIL endpoint.
+    pub async fn connect(local_addr: &str, autopilot_addr: &str) -> Result<Self> {
+        let socket = UdpSocket::bind(local_addr)
+            .await
+            .with_context(|| format!("binding firmware link socket on {local_addr}"))?;
+        let autopilot_addr: SocketAddr = autopilot_addr
+            .parse()
+            .with_context(|| format!("parsing autopilot address {autopilot_addr}"))?;
+
+        Ok(Self {
+            socket,
+            autopilot_addr,
+            recv_buf: vec![0u8; 512],
+            sysid: Self::SYSTEM_ID,
+            compid: Self::COMPONENT_ID,
+            seq: 0,
+        })
+    }
+
+    /// Encode and send one MAVLink v2 frame, advancing the sequence counter.
+    async fn send_frame(&mut self, msgid: u32, crc_extra: u8, payload: &[u8]) -> Result<()> {
+        let frame =
+            encode_mavlink_v2_frame(msgid, crc_extra, self.seq, self.sysid, self.compid, payload);
+        self.seq = self.seq.wrapping_add(1);
+        self.socket
+            .send_to(&frame, self.autopilot_addr)
+            .await
+            .with_context(|| format!("sending MAVLink message {msgid}"))?;
+        Ok(())
+    }
+
+    /// Encode and send the `HIL_SENSOR` / `HIL_GPS` pair for this step.
+    async fn send_hil_frames(&mut self, sensor: HilSensorFrame, gps: HilGpsFrame) -> Result<()> {
+        self.send_frame(
+            MSG_ID_HIL_SENSOR,
+            CRC_EXTRA_HIL_SENSOR,
+            &sensor.to_payload(),
+        )
+        .await
+        .context("sending HIL_SENSOR")?;
+        self.send_frame(MSG_ID_HIL_GPS, CRC_EXTRA_HIL_GPS, &gps.to_payload())
+            .await
+            .context("sending HIL_GPS")?;
+        Ok(())
+    }
+
+    /// Try to decode a pending `HIL_ACTUATOR_CONTROLS` reply, non-blocking.
+    async fn try_recv_actuators(&mut self) -> Result<Option<HilActuatorControls>> {
+        match tokio::time::timeout(
+            std::time::Duration::from_millis(5),
+            self.socket.recv(&mut self.recv_buf),
+        )
+        .await
+        {
+            Ok(Ok(n)) => Ok(decode_mavlink_v2_frame(
+                &self.recv_buf[..n],
+                MSG_ID_HIL_ACTUATOR_CONTROLS,
+                CRC_EXTRA_HIL_ACTUATOR_CONTROLS,
+            )
+            .and_then(HilActuatorControls::from_payload)),
+            Ok(Err(e)) => Err(e).context("receiving HIL_ACTUATOR_CONTROLS"),
+            Err(_) => Ok(None), // no reply within the step budget
+        }
+    }
+}
+
+/// Validate and strip a MAVLink v2 frame down to its payload bytes, checking
+/// the magic byte, message id, and trailing X.25 CRC. Returns `None` for any
+/// malformed frame, a frame for a different message, or a checksum mismatch.
+fn decode_mavlink_v2_frame(buf: &[u8], expected_msgid: u32, crc_extra: u8) -> Option<&[u8]> {
+    if buf.len() < 12 || buf[0] != MAVLINK_V2_MAGIC {
+        return None;
+    }
+    let payload_len = buf[1] as usize;
+    let msgid = u32::from_le_bytes([buf[7], buf[8], buf[9], 0]);
+    if msgid != expected_msgid || buf.len() < 10 + payload_len + 2 {
+        return None;
+    }
+
+    let header_and_payload = &buf[1..10 + payload_len];
+    let expected_crc = mavlink_crc(header_and_payload, crc_extra);
+    let received_crc = u16::from_le_bytes([buf[10 + payload_len], buf[11 + payload_len]]);
+    if expected_crc != received_crc {
+        return None;
+    }
+
+    Some(&buf[10..10 + payload_len])
+}
+
+#[async_trait]
+impl FirmwareLink for MavlinkFirmwareLink {
+    async fn exchange(
+        &mut self,
+        state: &VehicleState,
+        time_usec: u64,
+    ) -> Result<Option<VehicleControl>> {
+        let sensor = HilSensorFrame::from_vehicle_state(state, time_usec);
+        let gps = HilGpsFrame::from_vehicle_state(state, time_usec);
+        self.send_hil_frames(sensor, gps).await?;
+
+        match self.try_recv_actuators().await? {
+            Some(actuators) => Ok(Some(actuators.to_vehicle_control())),
+            None => {
+                debug!(
+                    "firmware link {} has no actuator frame yet",
+                    self.autopilot_addr
+                );
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Per-worker pool of firmware links, keyed by agent id.
+#[derive(Default)]
+pub struct FirmwarePool {
+    links: HashMap<usize, Box<dyn FirmwareLink>>,
+}
+
+impl FirmwarePool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a firmware link to drive the given agent.
+    pub fn attach(&mut self, agent_id: usize, link: Box<dyn FirmwareLink>) {
+        self.links.insert(agent_id, link);
+    }
+
+    /// Detach the firmware link for an agent, if any.
+    pub fn detach(&mut self, agent_id: usize) -> Option<Box<dyn FirmwareLink>> {
+        self.links.remove(agent_id)
+    }
+
+    pub fn is_firmware_controlled(&self, agent_id: usize) -> bool {
+        self.links.contains_key(&agent_id)
+    }
+
+    /// Exchange HIL frames for every firmware-controlled agent this step.
+    pub async fn step(
+        &mut self,
+        states: &HashMap<usize, VehicleState>,
+        time_usec: u64,
+    ) -> HashMap<usize, VehicleControl> {
+        let mut out = HashMap::new();
+        for (agent_id, link) in self.links.iter_mut() {
+            let Some(state) = states.get(agent_id) else {
+                continue;
+            };
+            match link.exchange(state, time_usec).await {
+                Ok(Some(control)) => {
+                    out.insert(*agent_id, control);
+                }
+                Ok(None) => {}
+                Err(e) => warn!("firmware link for agent {agent_id} failed: {e:#}"),
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{UnitQuaternion, Vector3};
+
+    fn sample_state() -> VehicleState {
+        VehicleState {
+            vehicle_id: "agent-0".to_string(),
+            timestamp: 0.0,
+            transform: autonomysim_core::backend::Transform::new(
+                nalgebra::Point3::new(10.0, 5.0, 2.0),
+                UnitQuaternion::identity(),
+            ),
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::new(0.0, 0.0, 9.81),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        }
+    }
+
+    #[test]
+    fn hil_sensor_frame_from_state() {
+        let frame = HilSensorFrame::from_vehicle_state(&sample_state(), 123);
+        assert_eq!(frame.time_usec, 123);
+        assert!((frame.zacc - 9.81).abs() < 1e-3);
+        assert!((frame.pressure_alt - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn hil_gps_frame_from_state() {
+        let frame = HilGpsFrame::from_vehicle_state(&sample_state(), 123);
+        assert_eq!(frame.time_usec, 123);
+        assert!(frame.lat > 0 && frame.lon < 0);
+    }
+
+    #[test]
+    fn hil_sensor_frame_round_trips_through_a_valid_mavlink_v2_frame() {
+        let frame = HilSensorFrame::from_vehicle_state(&sample_state(), 123);
+        let wire = encode_mavlink_v2_frame(
+            MSG_ID_HIL_SENSOR,
+            CRC_EXTRA_HIL_SENSOR,
+            0,
+            MavlinkFirmwareLink::SYSTEM_ID,
+            MavlinkFirmwareLink::COMPONENT_ID,
+            &frame.to_payload(),
+        );
+        assert_eq!(wire[0], MAVLINK_V2_MAGIC);
+        let payload = decode_mavlink_v2_frame(&wire, MSG_ID_HIL_SENSOR, CRC_EXTRA_HIL_SENSOR)
+            .expect("frame should decode with a valid checksum");
+        assert_eq!(payload, &frame.to_payload()[..]);
+    }
+
+    #[test]
+    fn decode_rejects_a_frame_with_a_corrupted_checksum() {
+        let frame = HilGpsFrame::from_vehicle_state(&sample_state(), 123);
+        let mut wire = encode_mavlink_v2_frame(
+            MSG_ID_HIL_GPS,
+            CRC_EXTRA_HIL_GPS,
+            0,
+            MavlinkFirmwareLink::SYSTEM_ID,
+            MavlinkFirmwareLink::COMPONENT_ID,
+            &frame.to_payload(),
+        );
+        let last = wire.len() - 1;
+        wire[last] ^= 0xFF;
+        assert!(decode_mavlink_v2_frame(&wire, MSG_ID_HIL_GPS, CRC_EXTRA_HIL_GPS).is_none());
+    }
+
+    #[test]
+    fn actuator_controls_decode_from_a_hil_actuator_controls_frame() {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&0u64.to_le_bytes()); // time_usec
+        payload.extend_from_slice(&0u64.to_le_bytes()); // flags
+        let mut controls = [0f32; 16];
+        controls[0..4].copy_from_slice(&[0.6, 0.6, 0.6, 0.6]);
+        for value in controls {
+            payload.extend_from_slice(&value.to_le_bytes());
+        }
+        payload.push(0); // mode
+
+        let wire = encode_mavlink_v2_frame(
+            MSG_ID_HIL_ACTUATOR_CONTROLS,
+            CRC_EXTRA_HIL_ACTUATOR_CONTROLS,
+            0,
+            1,
+            1,
+            &payload,
+        );
+        let decoded_payload = decode_mavlink_v2_frame(
+            &wire,
+            MSG_ID_HIL_ACTUATOR_CONTROLS,
+            CRC_EXTRA_HIL_ACTUATOR_CONTROLS,
+        )
+        .expect("frame should decode");
+        let actuators =
+            HilActuatorControls::from_payload(decoded_payload).expect("payload should decode");
+        let control = actuators.to_vehicle_control();
+        assert!((control.throttle - 0.6).abs() < 1e-6);
+        assert!((control.roll).abs() < 1e-6);
+    }
+
+    #[test]
+    fn firmware_pool_tracks_attachment() {
+        let pool = FirmwarePool::new();
+        assert!(!pool.is_firmware_controlled(0));
+    }
+}
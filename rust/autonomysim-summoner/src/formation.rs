@@ -0,0 +1,279 @@
+//! Lennard-Jones flocking/formation control
+//!
+//! Produces the `UpdatePositions` stream UE5 consumes (see
+//! [`autonomysim_backends::unreal::RobotPositionUpdate`]) by treating every
+//! pair of agents within sensing range as interacting through a
+//! Lennard-Jones potential: too close and they repel, too far and they
+//! attract, and the force is exactly zero at the desired equilibrium
+//! spacing. Named [`FormationShape`]s bias that equilibrium spacing for
+//! specific neighbor pairs via an [`AdjacencyGraph`] instead of using one
+//! uniform target for the whole swarm, and a live
+//! [`NetworkTopology`](autonomysim_tactical::network::NetworkTopology) can
+//! drop a neighbor out of the interaction set entirely once its radio link
+//! is jammed, so formations visibly degrade under EW.
+
+use autonomysim_backends::unreal::RobotPositionUpdate;
+use autonomysim_tactical::network::{AgentId, NetworkTopology};
+use nalgebra::{Point3, Vector3};
+use std::collections::HashMap;
+
+/// Tunables for the Lennard-Jones interaction.
+#[derive(Debug, Clone, Copy)]
+pub struct FlockingConfig {
+    /// Potential well depth; scales how hard agents push or pull on each
+    /// other.
+    pub epsilon: f64,
+    /// Desired equilibrium inter-agent spacing (meters) absent a formation
+    /// override from the [`AdjacencyGraph`].
+    pub target_spacing: f64,
+    /// Neighbors farther apart than this (meters) don't interact at all.
+    pub sensing_range: f64,
+    /// Per-tick speed cap applied to the summed motion vector before
+    /// integrating into the next position.
+    pub max_velocity: f64,
+}
+
+impl Default for FlockingConfig {
+    fn default() -> Self {
+        Self {
+            epsilon: 1.0,
+            target_spacing: 5.0,
+            sensing_range: 20.0,
+            max_velocity: 8.0,
+        }
+    }
+}
+
+/// Named formation shapes, expressed as roster-slot adjacency rather than
+/// agent ids so the same shape works for any roster size or membership.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormationShape {
+    /// No override: every neighbor pair uses `FlockingConfig::target_spacing`.
+    Free,
+    /// Single-file line, nose to tail behind the lead agent (roster slot 0).
+    Line,
+    /// Stem behind the lead agent that splits into two trailing arms,
+    /// letter-Y shaped.
+    Y,
+    /// V-shaped wedge trailing the lead agent.
+    Wedge,
+}
+
+/// Per-neighbor-pair spacing overrides for a [`FormationShape`], keyed by
+/// roster slot (not agent id) so it can be built once per shape/roster-size
+/// and reused across ticks.
+#[derive(Debug, Clone, Default)]
+pub struct AdjacencyGraph {
+    /// Overridden target spacing (meters) for an unordered pair of roster
+    /// slots.
+    overrides: HashMap<(usize, usize), f64>,
+}
+
+impl AdjacencyGraph {
+    /// Build the adjacency graph for `shape` over a roster of `roster_len`
+    /// agents, with formation edges spaced `spacing` meters apart.
+    pub fn for_shape(shape: FormationShape, roster_len: usize, spacing: f64) -> Self {
+        let mut overrides = HashMap::new();
+        let mut edge = |a: usize, b: usize| {
+            overrides.insert((a.min(b), a.max(b)), spacing);
+        };
+
+        match shape {
+            FormationShape::Free => {}
+            FormationShape::Line => {
+                for slot in 1..roster_len {
+                    edge(slot - 1, slot);
+                }
+            }
+            FormationShape::Wedge => {
+                // Slot 0 is the lead; odd slots trail on the left arm, even
+                // slots (excluding 0) trail on the right arm.
+                for slot in 1..roster_len {
+                    let parent = if slot <= 2 { 0 } else { slot - 2 };
+                    edge(parent, slot);
+                }
+            }
+            FormationShape::Y => {
+                let stem_len = (roster_len / 3).max(1).min(roster_len);
+                for slot in 1..stem_len {
+                    edge(slot - 1, slot);
+                }
+                for slot in stem_len..roster_len {
+                    let parent = if slot <= stem_len + 1 {
+                        stem_len.saturating_sub(1)
+                    } else {
+                        slot - 2
+                    };
+                    edge(parent, slot);
+                }
+            }
+        }
+
+        Self { overrides }
+    }
+
+    /// Target spacing between roster slots `a` and `b`, falling back to
+    /// `default` when this pair has no formation-specific override.
+    fn spacing(&self, a: usize, b: usize, default: f64) -> f64 {
+        self.overrides
+            .get(&(a.min(b), a.max(b)))
+            .copied()
+            .unwrap_or(default)
+    }
+}
+
+/// Lennard-Jones force magnitude along the bearing from `self` to a
+/// neighbor at `distance`, for a desired equilibrium spacing of `target`.
+/// Negative (repulsive) when `distance < target`, positive (attractive)
+/// when `distance > target`, zero at `distance == target`.
+fn lj_force_magnitude(epsilon: f64, target: f64, distance: f64) -> f64 {
+    if distance <= 0.0 {
+        return 0.0;
+    }
+    let ratio = target / distance;
+    -(epsilon / distance) * (ratio.powi(4) - ratio.powi(2))
+}
+
+/// Advance every agent in `roster` one step under the Lennard-Jones
+/// formation controller and return the batched position update UE5 expects.
+///
+/// `roster` gives the agent ids in slot order (slot order is what
+/// `adjacency` was built against); `positions` holds each agent's current
+/// world position. `network`, if given, restricts each agent's interaction
+/// set to [`NetworkTopology::get_neighbors`] so a jammed/knocked-out radio
+/// link drops that neighbor out of the formation instead of it snapping back
+/// into place once comms are restored.
+pub fn step(
+    roster: &[AgentId],
+    positions: &HashMap<AgentId, Point3<f64>>,
+    adjacency: &AdjacencyGraph,
+    config: &FlockingConfig,
+    network: Option<&NetworkTopology>,
+    dt: f64,
+) -> Vec<RobotPositionUpdate> {
+    roster
+        .iter()
+        .enumerate()
+        .filter_map(|(slot, &agent)| {
+            let position = *positions.get(&agent)?;
+            let neighbors = connected_neighbors(agent, roster, network);
+
+            let mut motion = Vector3::zeros();
+            let mut neighbor_count = 0usize;
+            for &neighbor in &neighbors {
+                let Some(&neighbor_position) = positions.get(&neighbor) else {
+                    continue;
+                };
+                let offset = neighbor_position - position;
+                let distance = offset.norm();
+                if distance <= 0.0 || distance > config.sensing_range {
+                    continue;
+                }
+
+                let neighbor_slot = roster.iter().position(|&id| id == neighbor).unwrap_or(slot);
+                let target = adjacency.spacing(slot, neighbor_slot, config.target_spacing);
+                let magnitude = lj_force_magnitude(config.epsilon, target, distance);
+
+                motion += (offset / distance) * magnitude;
+                neighbor_count += 1;
+            }
+
+            if neighbor_count > 0 {
+                motion /= neighbor_count as f64;
+            }
+            if motion.norm() > config.max_velocity {
+                motion = motion.normalize() * config.max_velocity;
+            }
+
+            let new_position = position + motion * dt;
+            let yaw = if motion.norm() > 1e-6 {
+                motion.y.atan2(motion.x)
+            } else {
+                0.0
+            };
+
+            Some(RobotPositionUpdate {
+                id: agent as i32,
+                x: new_position.x,
+                y: new_position.y,
+                z: new_position.z,
+                yaw,
+                pitch: 0.0,
+                roll: 0.0,
+            })
+        })
+        .collect()
+}
+
+/// Candidate neighbors for `agent`: every other roster member, narrowed to
+/// `network`'s live (non-jammed) links when a topology is provided.
+fn connected_neighbors(
+    agent: AgentId,
+    roster: &[AgentId],
+    network: Option<&NetworkTopology>,
+) -> Vec<AgentId> {
+    match network {
+        Some(topology) => topology.get_neighbors(agent),
+        None => roster.iter().copied().filter(|&id| id != agent).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lj_force_is_zero_at_equilibrium_spacing() {
+        assert_eq!(lj_force_magnitude(1.0, 5.0, 5.0), 0.0);
+    }
+
+    #[test]
+    fn lj_force_repels_when_too_close_and_attracts_when_too_far() {
+        assert!(lj_force_magnitude(1.0, 5.0, 2.0) < 0.0);
+        assert!(lj_force_magnitude(1.0, 5.0, 10.0) > 0.0);
+    }
+
+    #[test]
+    fn two_agents_settle_toward_target_spacing() {
+        let roster = vec![0usize, 1usize];
+        let mut positions = HashMap::new();
+        positions.insert(0, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1, Point3::new(1.0, 0.0, 0.0));
+
+        let adjacency = AdjacencyGraph::for_shape(FormationShape::Free, roster.len(), 5.0);
+        let config = FlockingConfig::default();
+
+        let updates = step(&roster, &positions, &adjacency, &config, None, 0.1);
+        assert_eq!(updates.len(), 2);
+        // Agents start closer together than the target spacing, so they
+        // should repel apart rather than collapse further.
+        assert!(updates[0].x < 0.0);
+        assert!(updates[1].x > 1.0);
+    }
+
+    #[test]
+    fn network_topology_prunes_jammed_neighbors_out_of_formation() {
+        let roster = vec![0usize, 1usize];
+        let mut positions = HashMap::new();
+        positions.insert(0, Point3::new(0.0, 0.0, 0.0));
+        positions.insert(1, Point3::new(1.0, 0.0, 0.0));
+
+        let adjacency = AdjacencyGraph::for_shape(FormationShape::Free, roster.len(), 5.0);
+        let config = FlockingConfig::default();
+
+        // An empty topology has no links, so every agent is treated as
+        // having no reachable neighbors -- e.g. both radios jammed.
+        let topology = NetworkTopology::new();
+        let updates = step(
+            &roster,
+            &positions,
+            &adjacency,
+            &config,
+            Some(&topology),
+            0.1,
+        );
+
+        assert_eq!(updates[0].x, 0.0);
+        assert_eq!(updates[1].x, 1.0);
+    }
+}
@@ -0,0 +1,190 @@
+//! Stigmergy-backed request/response layer for formation slot grants
+//!
+//! [`autonomysim_core::swarm::Formation::step`] already implements
+//! decentralized, root-outward slot claiming, but expects the caller to
+//! have already gathered this step's `(requester, placed_neighbor)` grant
+//! pairs. Gathering those pairs for real, physically separate robots means
+//! an unplaced robot has to ask a placed neighbor for a slot and wait for
+//! an answer -- and the answer can simply never arrive if the neighbor
+//! drops out of range or a gossip round misses it. [`FormationNegotiator`]
+//! is that asking/waiting layer: [`FormationNegotiator::request`] posts a
+//! request into a shared [`Stigmergy`] tuple space so it gossips to the
+//! target neighbor's replica, [`FormationNegotiator::grant_incoming`] lets
+//! that neighbor answer any request addressed to it, and
+//! [`FormationNegotiator::poll`] reports a request as granted once the
+//! answer has gossiped back -- or drops it after `timeout_steps` with no
+//! answer, so the caller can retry against a different neighbor, the same
+//! recovery [`crate::Barrier`] uses for a ready-mark that never arrives.
+
+use std::collections::HashMap;
+
+use crate::stigmergy::Stigmergy;
+
+/// Default step budget [`FormationNegotiator`] waits for a grant reply
+/// before giving up and letting the caller retry against another neighbor.
+pub const GRANT_TIMEOUT_STEPS: u64 = 50;
+
+struct PendingRequest {
+    neighbor: usize,
+    steps_waited: u64,
+}
+
+/// One robot's view of the formation slot requests it has sent and is
+/// still waiting on a reply for.
+pub struct FormationNegotiator {
+    timeout_steps: u64,
+    pending: HashMap<usize, PendingRequest>,
+}
+
+impl FormationNegotiator {
+    /// Create a negotiator using [`GRANT_TIMEOUT_STEPS`] as its reply budget.
+    pub fn new() -> Self {
+        Self::with_timeout(GRANT_TIMEOUT_STEPS)
+    }
+
+    /// Create a negotiator with an explicit step budget instead of
+    /// [`GRANT_TIMEOUT_STEPS`].
+    pub fn with_timeout(timeout_steps: u64) -> Self {
+        Self {
+            timeout_steps,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Outstanding requests this replica has sent but not yet resolved
+    /// (granted or timed out).
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Ask `neighbor` (already holding a slot) for a label on `requester`'s
+    /// behalf, publishing the request into `stigmergy` so it gossips to
+    /// `neighbor`'s replica. A no-op if `requester` already has a request
+    /// outstanding.
+    pub fn request(&mut self, stigmergy: &mut Stigmergy, requester: usize, neighbor: usize) {
+        if self.pending.contains_key(&requester) {
+            return;
+        }
+        stigmergy.put(request_key(requester), neighbor.to_le_bytes().to_vec());
+        self.pending.insert(
+            requester,
+            PendingRequest {
+                neighbor,
+                steps_waited: 0,
+            },
+        );
+    }
+
+    /// Answer, on behalf of `self_id` (an already-placed robot), any
+    /// request this replica's `stigmergy` has gossiped in addressed to it
+    /// from one of `requesters` -- called by the neighbor being asked, not
+    /// the requester.
+    pub fn grant_incoming(
+        stigmergy: &mut Stigmergy,
+        self_id: usize,
+        requesters: impl IntoIterator<Item = usize>,
+    ) {
+        for requester in requesters {
+            let addressed_to_me = stigmergy
+                .get(&request_key(requester))
+                .map(|target| target == self_id.to_le_bytes())
+                .unwrap_or(false);
+            if addressed_to_me {
+                stigmergy.put(grant_key(requester), Vec::new());
+            }
+        }
+    }
+
+    /// Advance one step: collect `(requester, neighbor)` pairs whose grant
+    /// reply has gossiped back into `stigmergy` -- ready to hand straight to
+    /// [`autonomysim_core::swarm::Formation::step`] -- and drop any request
+    /// that's waited longer than `timeout_steps` with no reply, so the
+    /// caller can retry it against a different neighbor.
+    pub fn poll(&mut self, stigmergy: &Stigmergy) -> Vec<(usize, usize)> {
+        let mut granted = Vec::new();
+        let mut expired = Vec::new();
+
+        for (&requester, request) in self.pending.iter_mut() {
+            if stigmergy.get(&grant_key(requester)).is_some() {
+                granted.push((requester, request.neighbor));
+            } else {
+                request.steps_waited += 1;
+                if request.steps_waited > self.timeout_steps {
+                    expired.push(requester);
+                }
+            }
+        }
+
+        for &(requester, _) in &granted {
+            self.pending.remove(&requester);
+        }
+        for requester in expired {
+            self.pending.remove(&requester);
+        }
+
+        granted
+    }
+}
+
+impl Default for FormationNegotiator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn request_key(requester: usize) -> String {
+    format!("formation/request/{requester}")
+}
+
+fn grant_key(requester: usize) -> String {
+    format!("formation/grant/{requester}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_then_grant_is_polled_once_gossiped() {
+        let mut requester_replica = Stigmergy::new("robot_1");
+        let mut neighbor_replica = Stigmergy::new("robot_0");
+        let mut negotiator = FormationNegotiator::new();
+
+        negotiator.request(&mut requester_replica, 1, 0);
+        assert_eq!(negotiator.pending_count(), 1);
+
+        // Request gossips from the requester's replica to the neighbor's.
+        requester_replica.propagate(std::iter::once(&mut neighbor_replica), 10);
+        FormationNegotiator::grant_incoming(&mut neighbor_replica, 0, [1]);
+
+        // Grant gossips back from the neighbor's replica to the requester's.
+        neighbor_replica.propagate(std::iter::once(&mut requester_replica), 10);
+
+        let granted = negotiator.poll(&requester_replica);
+        assert_eq!(granted, vec![(1, 0)]);
+        assert_eq!(negotiator.pending_count(), 0);
+    }
+
+    #[test]
+    fn request_stays_pending_without_a_reply() {
+        let mut requester_replica = Stigmergy::new("robot_1");
+        let mut negotiator = FormationNegotiator::with_timeout(2);
+        negotiator.request(&mut requester_replica, 1, 0);
+
+        assert_eq!(negotiator.poll(&requester_replica), Vec::new());
+        assert_eq!(negotiator.pending_count(), 1);
+    }
+
+    #[test]
+    fn request_is_dropped_after_timeout_with_no_reply() {
+        let mut requester_replica = Stigmergy::new("robot_1");
+        let mut negotiator = FormationNegotiator::with_timeout(2);
+        negotiator.request(&mut requester_replica, 1, 0);
+
+        for _ in 0..2 {
+            assert_eq!(negotiator.poll(&requester_replica), Vec::new());
+        }
+        assert_eq!(negotiator.poll(&requester_replica), Vec::new());
+        assert_eq!(negotiator.pending_count(), 0);
+    }
+}
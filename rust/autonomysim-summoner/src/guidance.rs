@@ -0,0 +1,299 @@
+//! Waypoint-targeting trajectory solver via Levenberg-Marquardt
+//!
+//! Solves for a control parameter vector that drives an agent from its
+//! current `VehicleState` to a desired terminal state at time `T`, usable
+//! ahead of (or instead of) the reactive PID controller in [`crate::controller`].
+
+use autonomysim_core::vehicle::VehicleState;
+use nalgebra::{DMatrix, DVector};
+
+/// Desired terminal state: position and velocity at time `T`.
+#[derive(Debug, Clone, Copy)]
+pub struct TerminalTarget {
+    pub position: nalgebra::Point3<f64>,
+    pub velocity: nalgebra::Vector3<f64>,
+}
+
+/// Bounds each control parameter must stay within during the search.
+#[derive(Debug, Clone)]
+pub struct ParameterBounds {
+    pub min: Vec<f64>,
+    pub max: Vec<f64>,
+}
+
+impl ParameterBounds {
+    fn clamp(&self, x: &DVector<f64>) -> DVector<f64> {
+        DVector::from_iterator(
+            x.len(),
+            x.iter()
+                .enumerate()
+                .map(|(i, &v)| v.clamp(self.min[i], self.max[i])),
+        )
+    }
+}
+
+/// Configuration for the Levenberg-Marquardt search.
+#[derive(Debug, Clone, Copy)]
+pub struct LmSolverConfig {
+    pub max_iterations: usize,
+    pub initial_lambda: f64,
+    pub lambda_up: f64,
+    pub lambda_down: f64,
+    /// Stop when the residual norm drops below this.
+    pub residual_tolerance: f64,
+    /// Stop when the step norm drops below this.
+    pub step_tolerance: f64,
+    /// Relative perturbation used for the finite-difference Jacobian.
+    pub finite_diff_eps: f64,
+}
+
+impl Default for LmSolverConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: 50,
+            initial_lambda: 1e-2,
+            lambda_up: 10.0,
+            lambda_down: 10.0,
+            residual_tolerance: 1e-3,
+            step_tolerance: 1e-6,
+            finite_diff_eps: 1e-4,
+        }
+    }
+}
+
+/// Outcome of an LM solve: the best parameter vector found, whether it met
+/// the convergence tolerance, and the residual norm it achieved.
+#[derive(Debug, Clone)]
+pub struct LmSolution {
+    pub parameters: Vec<f64>,
+    pub converged: bool,
+    pub residual_norm: f64,
+    pub iterations: usize,
+}
+
+/// Propagates the physics forward from `state` using control parameters
+/// `x`, returning the terminal `VehicleState` at time `T`. Implemented by
+/// the caller (stepping the physics backend) since this crate has no
+/// direct backend handle.
+pub trait TrajectoryPropagator {
+    fn propagate(&self, initial: &VehicleState, x: &[f64], horizon: f64) -> VehicleState;
+}
+
+/// Solve for the control parameter vector that drives `initial` to `target`
+/// over `horizon` seconds, via damped Gauss-Newton (Levenberg-Marquardt).
+///
+/// The residual is `[target.position - terminal.position, target.velocity
+/// - terminal.velocity]` (6 components); the Jacobian is built by central
+/// finite differences on `propagator.propagate`. Each trial `x` is clamped
+/// into `bounds` before propagation so an unconstrained step can never ask
+/// the propagator to evaluate an invalid control.
+pub fn solve_trajectory(
+    propagator: &dyn TrajectoryPropagator,
+    initial: &VehicleState,
+    target: TerminalTarget,
+    horizon: f64,
+    initial_guess: Vec<f64>,
+    bounds: &ParameterBounds,
+    config: LmSolverConfig,
+) -> LmSolution {
+    let n = initial_guess.len();
+    let mut x = bounds.clamp(&DVector::from_vec(initial_guess));
+    let mut lambda = config.initial_lambda;
+
+    let mut residual = residual_vector(propagator, initial, &x, target, horizon);
+    let mut residual_norm = residual.norm();
+
+    let mut iterations = 0;
+    while iterations < config.max_iterations && residual_norm > config.residual_tolerance {
+        iterations += 1;
+
+        let jacobian = finite_difference_jacobian(
+            propagator,
+            initial,
+            &x,
+            target,
+            horizon,
+            config.finite_diff_eps,
+        );
+
+        let jtj = jacobian.transpose() * &jacobian;
+        let jtr = jacobian.transpose() * &residual;
+
+        let damped = &jtj + DMatrix::from_diagonal(&jtj.diagonal().map(|d| d * lambda));
+
+        let Some(delta) = damped.clone().lu().solve(&(-jtr)) else {
+            // Singular normal equations: back off by inflating damping and retry.
+            lambda *= config.lambda_up;
+            continue;
+        };
+
+        let trial_x = bounds.clamp(&(&x + &delta));
+        let trial_residual = residual_vector(propagator, initial, &trial_x, target, horizon);
+        let trial_norm = trial_residual.norm();
+
+        if trial_norm < residual_norm {
+            let step_norm = (&trial_x - &x).norm();
+            x = trial_x;
+            residual = trial_residual;
+            residual_norm = trial_norm;
+            lambda /= config.lambda_down;
+
+            if step_norm < config.step_tolerance {
+                break;
+            }
+        } else {
+            lambda *= config.lambda_up;
+        }
+    }
+
+    LmSolution {
+        parameters: x.iter().copied().collect(),
+        converged: residual_norm <= config.residual_tolerance,
+        residual_norm,
+        iterations,
+    }
+}
+
+fn residual_vector(
+    propagator: &dyn TrajectoryPropagator,
+    initial: &VehicleState,
+    x: &DVector<f64>,
+    target: TerminalTarget,
+    horizon: f64,
+) -> DVector<f64> {
+    let params: Vec<f64> = x.iter().copied().collect();
+    let terminal = propagator.propagate(initial, &params, horizon);
+
+    let pos_err = target.position - terminal.transform.position;
+    let vel_err = target.velocity - terminal.linear_velocity;
+
+    DVector::from_vec(vec![
+        pos_err.x, pos_err.y, pos_err.z, vel_err.x, vel_err.y, vel_err.z,
+    ])
+}
+
+fn finite_difference_jacobian(
+    propagator: &dyn TrajectoryPropagator,
+    initial: &VehicleState,
+    x: &DVector<f64>,
+    target: TerminalTarget,
+    horizon: f64,
+    eps: f64,
+) -> DMatrix<f64> {
+    let n = x.len();
+    let base = residual_vector(propagator, initial, x, target, horizon);
+    let m = base.len();
+    let mut jacobian = DMatrix::zeros(m, n);
+
+    for j in 0..n {
+        let step = eps.max(x[j].abs() * eps);
+        let mut x_plus = x.clone();
+        x_plus[j] += step;
+        let r_plus = residual_vector(propagator, initial, &x_plus, target, horizon);
+
+        let mut x_minus = x.clone();
+        x_minus[j] -= step;
+        let r_minus = residual_vector(propagator, initial, &x_minus, target, horizon);
+
+        let column = (r_plus - r_minus) / (2.0 * step);
+        jacobian.set_column(j, &column);
+    }
+
+    jacobian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::backend::Transform;
+    use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+    /// A propagator standing in for the physics backend: constant-velocity
+    /// ballistic motion where `x = [vx, vy, vz]` is the launch velocity.
+    struct BallisticPropagator;
+
+    impl TrajectoryPropagator for BallisticPropagator {
+        fn propagate(&self, initial: &VehicleState, x: &[f64], horizon: f64) -> VehicleState {
+            let velocity = Vector3::new(x[0], x[1], x[2]);
+            let position = initial.transform.position + velocity * horizon;
+            VehicleState {
+                transform: Transform::new(position, UnitQuaternion::identity()),
+                linear_velocity: velocity,
+                ..initial.clone()
+            }
+        }
+    }
+
+    fn start_state() -> VehicleState {
+        VehicleState {
+            vehicle_id: "agent-0".to_string(),
+            timestamp: 0.0,
+            transform: Transform::new(Point3::origin(), UnitQuaternion::identity()),
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        }
+    }
+
+    #[test]
+    fn solves_simple_ballistic_intercept() {
+        let target = TerminalTarget {
+            position: Point3::new(10.0, 0.0, 0.0),
+            velocity: Vector3::new(2.0, 0.0, 0.0),
+        };
+
+        let bounds = ParameterBounds {
+            min: vec![-10.0, -10.0, -10.0],
+            max: vec![10.0, 10.0, 10.0],
+        };
+
+        let solution = solve_trajectory(
+            &BallisticPropagator,
+            &start_state(),
+            target,
+            5.0,
+            vec![0.0, 0.0, 0.0],
+            &bounds,
+            LmSolverConfig::default(),
+        );
+
+        assert!(solution.converged, "solution: {:?}", solution);
+        assert!((solution.parameters[0] - 2.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn clamps_trial_parameters_into_bounds() {
+        let target = TerminalTarget {
+            position: Point3::new(1000.0, 0.0, 0.0),
+            velocity: Vector3::zeros(),
+        };
+
+        let bounds = ParameterBounds {
+            min: vec![-1.0, -1.0, -1.0],
+            max: vec![1.0, 1.0, 1.0],
+        };
+
+        let solution = solve_trajectory(
+            &BallisticPropagator,
+            &start_state(),
+            target,
+            1.0,
+            vec![0.0, 0.0, 0.0],
+            &bounds,
+            LmSolverConfig {
+                max_iterations: 20,
+                ..Default::default()
+            },
+        );
+
+        assert!(solution
+            .parameters
+            .iter()
+            .all(|&p| (-1.0..=1.0).contains(&p)));
+        assert!(!solution.converged);
+    }
+}
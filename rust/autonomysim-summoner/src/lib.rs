@@ -118,24 +118,68 @@
 //! | Tactical Comms | ❌ | ✅ |
 //! | EW Simulation | ❌ | ✅ |
 
+pub mod barrier;
+pub mod broadphase;
+pub mod cohesion;
 pub mod communication;
+pub mod controller;
 pub mod coordinator;
+pub mod debug_draw;
+pub mod firmware;
+pub mod formation;
+pub mod formation_negotiator;
+pub mod guidance;
+pub mod load_balancer;
+pub mod lod;
 pub mod metrics;
 pub mod partition;
+pub mod perception;
+pub mod phase_profiler;
+pub mod ros_bridge;
 pub mod scheduler;
+pub mod step_coordinator;
+pub mod stigmergy;
+pub mod tactics;
 pub mod worker;
 
 use anyhow::{Context, Result};
+use futures::future::select_all;
 use nalgebra::Vector3;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 
-pub use communication::{MessageBus, NodeMessage};
-pub use coordinator::Coordinator;
-pub use metrics::{PerformanceMonitor, SummonerMetrics};
-pub use partition::{Partition, SpatialPartitioner};
+pub use barrier::{Barrier, BarrierStatus, BARRIER_TIMEOUT};
+pub use broadphase::BroadPhase;
+pub use communication::{
+    FaultModel, LatencyModel, MessageBus, NodeLinkStats, NodeMessage, TraceContext,
+};
+pub use controller::{ControllerSettings, ControllerState, PidController, Setpoint};
+pub use coordinator::{Coordinator, MigrationPlan, NodeCapacity, NodeStatus};
+pub use debug_draw::{
+    DebugColor, DebugDrawChannel, DebugDrawHandle, DebugDrawRegistry, DebugDrawStyle,
+    DebugPrimitive,
+};
+pub use firmware::{FirmwareLink, FirmwarePool, MavlinkFirmwareLink};
+pub use guidance::{
+    LmSolution, LmSolverConfig, ParameterBounds, TerminalTarget, TrajectoryPropagator,
+};
+pub use load_balancer::LoadBalancer;
+pub use metrics::{
+    AbstractMeasurement, LatencyHistogram, MetricValue, PerformanceMonitor, StepSample,
+    SummonerMetrics,
+};
+pub use partition::{
+    AdaptivePartitioner, Boundary, BoundaryCondition, CrossingEvent, Face, Partition,
+    SpatialPartitioner, WorkerCapacity,
+};
+pub use phase_profiler::{PhaseProfiler, PhaseTiming};
+pub use ros_bridge::Ros2Bridge;
+pub use step_coordinator::{StepCoordinator, WorkerStatus, WorkerView};
+pub use stigmergy::{Stigmergy, Tuple};
 pub use worker::Worker;
 
 /// Distribution strategy for multi-node/multi-GPU
@@ -164,6 +208,20 @@ pub enum DistributionStrategy {
         spatial_partitions: usize,
         functional_layers: Vec<String>,
     },
+
+    /// Spatial partitioning whose per-partition agent counts are
+    /// continuously rebalanced against measured load (see
+    /// [`LoadBalancer`]) rather than staying fixed at an even split of
+    /// `bounds`. Every `rebalance_interval` steps,
+    /// `Summoner::rebalance_weighted_partitions` migrates agents from
+    /// overloaded partitions toward underloaded ones, preferring agents
+    /// nearest the receiving partition to minimize disruption to spatial
+    /// locality.
+    WeightedPartitioning {
+        bounds: Vector3<f64>,
+        num_partitions: usize,
+        rebalance_interval: usize,
+    },
 }
 
 /// SUMMONER configuration
@@ -198,6 +256,103 @@ pub struct SummonerConfig {
 
     /// MPI world size
     pub mpi_world_size: Option<usize>,
+
+    /// How long a worker may go without a heartbeat before
+    /// `Coordinator::cluster_status` reports it as down.
+    pub heartbeat_timeout_secs: f64,
+
+    /// Deadline/quorum policy `Summoner::step` applies when waiting on
+    /// worker completion, so one stalled worker can't stretch every tick
+    /// out to its own pace.
+    pub step_policy: StepPolicy,
+
+    /// Cap on serialized bytes the `MessageBus` lets accumulate in flight
+    /// at once, across all nodes. See
+    /// [`communication::DEFAULT_MAX_BUFFERED_BYTES`].
+    pub max_buffered_bytes: u64,
+
+    /// OTLP collector endpoint (e.g. `http://localhost:4317`) to export the
+    /// distributed trace spans emitted by `step`/`broadcast_step`/
+    /// `execute_step`/`synchronize_boundaries`. `None` disables export --
+    /// the spans are still emitted to whatever `tracing_subscriber` the
+    /// host binary installed, just not shipped anywhere.
+    pub otlp_endpoint: Option<String>,
+
+    /// Per-kernel local work-group size for the GPU backend, when one is in
+    /// use. `None` lets the backend pick its own default. The optimal value
+    /// is device-specific (warp/wavefront width varies by GPU), so this is
+    /// meant to be set from whatever a `summoner_benchmark --autotune` run
+    /// found best for the target hardware, rather than hard-coded. Not yet
+    /// consumed by a real GPU backend -- see `Worker::simulate_physics`.
+    pub gpu_local_work_group_size: Option<usize>,
+
+    /// Lennard-Jones flocking tunables. When set, `Worker::update_control`
+    /// derives every agent's velocity setpoint from its in-range neighbors
+    /// each step (see [`cohesion::desired_velocities`]) instead of leaving
+    /// it at whatever `Worker::set_setpoint` last commanded, giving the
+    /// swarm genuine emergent cohesion. `None` disables it entirely.
+    pub cohesion: Option<cohesion::LjParams>,
+
+    /// Level-of-detail tunables. When set, `Worker::update_lod` buckets
+    /// agents into near/mid/far bands by distance to the nearest focus
+    /// point and runs the mid/far bands' updates at a coarser cadence (see
+    /// [`lod::LodConfig`]) instead of every agent paying full per-step
+    /// cost. `None` runs every agent at full fidelity every step, as
+    /// before.
+    pub lod: Option<lod::LodConfig>,
+
+    /// Sensor model tunables. When set, `Worker::update_control` gates
+    /// cohesion's neighbor queries on mutual visibility (see
+    /// [`perception::desired_velocities_with_perception`]) instead of every
+    /// agent seeing every in-range neighbor regardless of facing or
+    /// occlusion. `None` leaves flocking omniscient, as before.
+    pub perception: Option<perception::PerceptionConfig>,
+
+    /// Tactical planner tunables consumed by [`coordinator::Coordinator::plan_tactics`]
+    /// between steps. `None` runs [`tactics::TacticalPlannerConfig::default`]
+    /// rather than disabling planning outright, since `plan_tactics` is
+    /// called explicitly rather than from `Summoner::step`'s per-tick loop.
+    pub tactics: Option<tactics::TacticalPlannerConfig>,
+}
+
+/// `Summoner::step` waits for at most `per_worker_timeout_secs` (falling
+/// back to `timestep` when unset) before giving up on the workers that
+/// haven't finished yet, as long as at least `quorum` of them have. With
+/// `interrupt_after_quorum` set, the coordinator proceeds the instant
+/// `quorum` is reached instead of continuing to wait out the full
+/// deadline for stragglers that might still make it.
+///
+/// Workers that miss the cut are reported as `late_workers` in
+/// [`SummonerMetrics`] for that tick. Their step keeps running in the
+/// background -- it already mutates the worker's own `Arc<RwLock<Worker>>`
+/// directly -- so the agents it owns simply keep reporting their
+/// last-published state (a passive "it hasn't moved" extrapolation) until
+/// the straggler's write lock clears, at which point the next tick picks
+/// up its fresh state automatically.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StepPolicy {
+    /// Per-worker step timeout in seconds. `None` defaults to the step's
+    /// own `dt`.
+    pub per_worker_timeout_secs: Option<f64>,
+    /// Number of workers that must finish before the coordinator advances.
+    /// Clamped to the actual worker count.
+    pub quorum: usize,
+    /// Stop waiting the instant `quorum` is reached instead of continuing
+    /// to collect stragglers until the deadline also elapses. Only takes
+    /// effect when [`SummonerConfig::realtime`] is set.
+    pub interrupt_after_quorum: bool,
+}
+
+impl Default for StepPolicy {
+    fn default() -> Self {
+        Self {
+            per_worker_timeout_secs: None,
+            // Wait for every worker, matching `step`'s behavior before this
+            // policy existed.
+            quorum: usize::MAX,
+            interrupt_after_quorum: false,
+        }
+    }
 }
 
 impl Default for SummonerConfig {
@@ -213,6 +368,15 @@ impl Default for SummonerConfig {
             metrics_port: Some(9090),
             mpi_rank: None,
             mpi_world_size: None,
+            heartbeat_timeout_secs: 5.0,
+            step_policy: StepPolicy::default(),
+            max_buffered_bytes: communication::DEFAULT_MAX_BUFFERED_BYTES,
+            otlp_endpoint: None,
+            gpu_local_work_group_size: None,
+            cohesion: None,
+            lod: None,
+            perception: None,
+            tactics: None,
         }
     }
 }
@@ -225,6 +389,19 @@ pub struct Summoner {
     _message_bus: Arc<MessageBus>,
     monitor: Option<PerformanceMonitor>,
     current_step: u64,
+    /// Per-partition step-time EWMA driving
+    /// `DistributionStrategy::WeightedPartitioning` rebalances. Updated
+    /// every step regardless of strategy; only consulted when that
+    /// strategy is active.
+    load_balancer: LoadBalancer,
+    /// Running per-phase timing stats across every worker's `execute_step`,
+    /// surfaced as `SummonerMetrics::phase_timings`.
+    phase_profiler: PhaseProfiler,
+    /// Next generation number to hand out per barrier `id`, so a phase name
+    /// like `"launch"` can be reused across the mission (spawn -> form up
+    /// -> advance -> RTB) without a straggler's stale ready-mark from an
+    /// earlier phase counting toward a later one. See [`Self::barrier`].
+    barrier_generations: HashMap<String, u64>,
 }
 
 impl Summoner {
@@ -239,11 +416,17 @@ impl Summoner {
         Self::validate_config(&config)?;
 
         // Initialize message bus
-        let message_bus = Arc::new(MessageBus::new(config.num_nodes));
+        let message_bus = Arc::new(MessageBus::with_buffer_budget(
+            config.num_nodes,
+            config.max_buffered_bytes,
+        ));
 
         // Initialize performance monitor
         let monitor = if config.enable_monitoring {
-            Some(PerformanceMonitor::new(config.metrics_port)?)
+            Some(PerformanceMonitor::new(
+                config.metrics_port,
+                config.otlp_endpoint.clone(),
+            )?)
         } else {
             None
         };
@@ -256,6 +439,23 @@ impl Summoner {
         // Initialize workers based on distribution strategy
         let workers = Self::initialize_workers(&config, message_bus.clone()).await?;
 
+        // Register each worker with the coordinator so `cluster_status`
+        // has something to report from step 0.
+        {
+            let coord = coordinator.read().await;
+            for worker in &workers {
+                let w = worker.read().await;
+                coord
+                    .register_worker(
+                        w.worker_id(),
+                        w.num_agents(),
+                        w.agent_range(),
+                        NodeCapacity::default(),
+                    )
+                    .await?;
+            }
+        }
+
         info!(
             "SUMMONER initialization complete: {} workers, {} agents",
             workers.len(),
@@ -269,6 +469,9 @@ impl Summoner {
             _message_bus: message_bus,
             monitor,
             current_step: 0,
+            load_balancer: LoadBalancer::new(),
+            phase_profiler: PhaseProfiler::new(),
+            barrier_generations: HashMap::new(),
         })
     }
 
@@ -326,6 +529,7 @@ impl Summoner {
                 functional_layers,
                 ..
             } => spatial_partitions * functional_layers.len(),
+            DistributionStrategy::WeightedPartitioning { num_partitions, .. } => *num_partitions,
         };
 
         let mut workers = Vec::with_capacity(num_workers);
@@ -340,35 +544,86 @@ impl Summoner {
         Ok(workers)
     }
 
-    /// Execute one simulation step
+    /// Execute one simulation step. This is the root span of the
+    /// distributed trace for this tick -- `broadcast_step` and each
+    /// worker's `execute_step` open child spans tagged with the same
+    /// `trace_id`, recorded on this span once it's known.
+    #[tracing::instrument(skip(self, dt), fields(step = self.current_step, trace_id = tracing::field::Empty))]
     pub async fn step(&mut self, dt: f64) -> Result<()> {
         let step_start = std::time::Instant::now();
 
         // 1. Coordinator broadcasts step command
-        {
+        let (layout_version, trace_context) = {
             let coord = self.coordinator.read().await;
-            coord.broadcast_step(self.current_step, dt).await?;
-        }
+            let trace_context = coord.broadcast_step(self.current_step, dt).await?;
+            (coord.layout_version(), trace_context)
+        };
+        let trace_id = trace_context.trace_id.to_string();
+        tracing::Span::current().record("trace_id", trace_id.as_str());
+
+        // 2. Workers execute in parallel, each on its own task so a
+        // straggler keeps making progress even after the coordinator stops
+        // waiting on it.
+        let mut handles: Vec<tokio::task::JoinHandle<Result<(Duration, worker::PhaseTimings)>>> =
+            self.workers
+                .iter()
+                .map(|worker| {
+                    let worker = worker.clone();
+                    tokio::spawn(async move {
+                        let started = std::time::Instant::now();
+                        let phase_timings = worker
+                            .write()
+                            .await
+                            .execute_step(dt, layout_version, trace_context)
+                            .await?;
+                        Ok((started.elapsed(), phase_timings))
+                    })
+                })
+                .collect();
+        let mut pending_worker_ids: Vec<usize> = (0..self.workers.len()).collect();
+
+        let policy = self.config.step_policy;
+        let per_worker_timeout = policy
+            .per_worker_timeout_secs
+            .map(Duration::from_secs_f64)
+            .unwrap_or_else(|| Duration::from_secs_f64(dt));
+        let quorum = policy.quorum.min(self.workers.len()).max(1);
+
+        let deadline_sleep = tokio::time::sleep(per_worker_timeout);
+        tokio::pin!(deadline_sleep);
+
+        let mut finished = 0usize;
+        let late_workers: Vec<usize> = loop {
+            if handles.is_empty() {
+                break Vec::new();
+            }
+            if finished >= quorum && self.config.realtime && policy.interrupt_after_quorum {
+                break pending_worker_ids;
+            }
 
-        // 2. Workers execute in parallel
-        let worker_futures: Vec<_> = self
-            .workers
-            .iter()
-            .map(|worker| {
-                let worker = worker.clone();
-                async move {
-                    let mut w = worker.write().await;
-                    w.execute_step(dt).await
+            tokio::select! {
+                (joined, index, remaining) = select_all(handles) => {
+                    handles = remaining;
+                    let worker_id = pending_worker_ids.remove(index);
+                    let (worker_step_time, phase_timings) = joined.context("worker task panicked")?.context("Worker step failed")?;
+                    self.coordinator.read().await.record_heartbeat(worker_id).await;
+                    self.load_balancer.record_step_time(worker_id, worker_step_time.as_secs_f64() * 1000.0);
+                    self.phase_profiler.record(phase_timings.iter());
+                    finished += 1;
                 }
-            })
-            .collect();
-
-        // Wait for all workers to complete
-        let results = futures::future::join_all(worker_futures).await;
-
-        // Check for errors
-        for result in results {
-            result.context("Worker step failed")?;
+                _ = &mut deadline_sleep => {
+                    break pending_worker_ids;
+                }
+            }
+        };
+        if !late_workers.is_empty() {
+            warn!(
+                "Step {} proceeding without worker(s) {:?} (quorum {} of {})",
+                self.current_step,
+                late_workers,
+                finished,
+                self.workers.len()
+            );
         }
 
         // 3. Synchronize boundary data
@@ -377,17 +632,182 @@ impl Summoner {
             coord.synchronize_boundaries().await?;
         }
 
+        // 3b. Refresh each node's bandwidth budget for this step and drain
+        // whatever its backlog can now afford, so a capacity-limited link
+        // (see `MessageBus::register_channel_with_capacity`) makes progress
+        // every step even when a single send didn't fit.
+        self._message_bus
+            .advance_step(self.current_step, dt)
+            .await?;
+
         // 4. Update metrics
         let step_time = step_start.elapsed();
         if let Some(monitor) = &self.monitor {
             monitor.record_step(self.current_step, step_time, self.config.num_agents);
+            monitor.record_late_workers(late_workers);
+            monitor.record_buffer_stats(
+                self._message_bus.buffered_bytes(),
+                self._message_bus.backpressure_stalls().await,
+            );
+            monitor.record_cluster_status(self.coordinator.read().await.cluster_status().await);
+            monitor.record_phase_timings(self.phase_profiler.snapshot());
+
+            if self.config.lod.is_some() {
+                let mut lod_histogram: HashMap<String, usize> = HashMap::new();
+                for worker in &self.workers {
+                    for (level, count) in worker.read().await.lod_histogram() {
+                        *lod_histogram.entry(level.label().to_string()).or_insert(0) += count;
+                    }
+                }
+                monitor.record_lod_histogram(lod_histogram);
+            }
         }
 
         self.current_step += 1;
 
+        // 5. Weighted-partition rebalancing, if configured. Runs after the
+        // metrics update above so a rebalance triggered by this tick's loads
+        // shows up starting next tick, not retroactively on this one.
+        if matches!(
+            self.config.distribution,
+            DistributionStrategy::WeightedPartitioning { .. }
+        ) {
+            self.rebalance_weighted_partitions().await?;
+        }
+
+        if let Some(monitor) = &self.monitor {
+            monitor.record_partition_loads(self.load_balancer.loads());
+            monitor.record_migration_count(self.load_balancer.migration_count());
+        }
+
         Ok(())
     }
 
+    /// Migrate agents from overloaded partitions toward underloaded ones
+    /// so each partition's agent count tracks
+    /// [`LoadBalancer::target_counts`], computed from the EWMA step-time
+    /// load recorded every tick in [`Self::step`]. Runs every
+    /// `rebalance_interval` steps and is a no-op when `distribution` isn't
+    /// [`DistributionStrategy::WeightedPartitioning`].
+    async fn rebalance_weighted_partitions(&mut self) -> Result<()> {
+        let DistributionStrategy::WeightedPartitioning {
+            rebalance_interval, ..
+        } = &self.config.distribution
+        else {
+            return Ok(());
+        };
+        let rebalance_interval = *rebalance_interval;
+        if rebalance_interval == 0 || self.current_step % rebalance_interval as u64 != 0 {
+            return Ok(());
+        }
+
+        let worker_ids: Vec<usize> = (0..self.workers.len()).collect();
+        let mut current_counts: HashMap<usize, usize> = HashMap::with_capacity(worker_ids.len());
+        for &id in &worker_ids {
+            current_counts.insert(id, self.workers[id].read().await.num_agents());
+        }
+        let total_agents: usize = current_counts.values().sum();
+        let targets = self.load_balancer.target_counts(&worker_ids, total_agents);
+
+        // (worker_id, current_count - target_count); positive means
+        // overloaded (has more than its target), negative means
+        // underloaded.
+        let mut surplus: Vec<(usize, i64)> = worker_ids
+            .iter()
+            .map(|&id| (id, current_counts[&id] as i64 - targets[&id] as i64))
+            .collect();
+
+        let mut migrated = 0usize;
+        loop {
+            surplus.sort_by_key(|&(_, delta)| delta);
+            let (under_id, under_delta) = surplus[0];
+            let (over_id, over_delta) = surplus[surplus.len() - 1];
+            if over_delta <= 0 || under_delta >= 0 {
+                break;
+            }
+
+            let move_count = over_delta.min(-under_delta) as usize;
+            if move_count == 0 {
+                break;
+            }
+
+            let moved = self
+                .migrate_agents_toward(over_id, under_id, move_count)
+                .await?;
+            if moved == 0 {
+                break;
+            }
+            migrated += moved;
+
+            for entry in surplus.iter_mut() {
+                if entry.0 == over_id {
+                    entry.1 -= moved as i64;
+                } else if entry.0 == under_id {
+                    entry.1 += moved as i64;
+                }
+            }
+        }
+
+        if migrated > 0 {
+            self.load_balancer.record_migration(migrated);
+            info!(
+                "Weighted rebalance at step {}: migrated {} agent(s) across {} partition(s)",
+                self.current_step,
+                migrated,
+                worker_ids.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Move up to `count` agents from worker `from_id` to worker `to_id`,
+    /// preferring the agent ids nearest `to_id`'s contiguous id range --
+    /// the least spatially disruptive choice given the contiguous
+    /// agent-id-block layout `Worker::assign_agents` hands out. Returns how
+    /// many agents actually moved (fewer than `count` if `from_id` doesn't
+    /// have that many).
+    async fn migrate_agents_toward(
+        &mut self,
+        from_id: usize,
+        to_id: usize,
+        count: usize,
+    ) -> Result<usize> {
+        let mut candidates: Vec<usize> = self.workers[from_id].read().await.agent_ids().to_vec();
+        if candidates.is_empty() {
+            return Ok(0);
+        }
+        candidates.sort_unstable();
+
+        let to_min = self.workers[to_id].read().await.agent_range().0;
+        let from_min = *candidates.first().expect("candidates is non-empty");
+        let take_from_high_end = to_min >= from_min;
+        let to_move: Vec<usize> = if take_from_high_end {
+            candidates.iter().rev().take(count).copied().collect()
+        } else {
+            candidates.iter().take(count).copied().collect()
+        };
+
+        let moved_count = to_move.len();
+        for agent_id in to_move {
+            let state = self.workers[from_id].write().await.migrate_out(agent_id)?;
+            if let Some(bytes) = state {
+                self.workers[to_id]
+                    .write()
+                    .await
+                    .migrate_in(agent_id, &bytes)?;
+            }
+        }
+
+        let from_count = self.workers[from_id].read().await.num_agents();
+        let to_count = self.workers[to_id].read().await.num_agents();
+        let coord = self.coordinator.read().await;
+        coord.report_agent_count(from_id, from_count).await;
+        coord.report_agent_count(to_id, to_count).await;
+
+        Ok(moved_count)
+    }
+
     /// Run simulation for N steps
     pub async fn run(&mut self, num_steps: usize) -> Result<()> {
         info!("Starting SUMMONER simulation for {} steps", num_steps);
@@ -422,10 +842,58 @@ impl Summoner {
         self.current_step
     }
 
+    /// Snapshot the current per-phase timing averages and reset them, so a
+    /// caller (e.g. a benchmark) can measure phase timing over a bounded
+    /// window of steps by checkpointing before and after that window,
+    /// instead of only seeing an average across the whole run.
+    pub fn checkpoint_phase_timings(&mut self) -> HashMap<String, PhaseTiming> {
+        self.phase_profiler.checkpoint()
+    }
+
+    /// Structured health/capacity report for every worker, mirroring what a
+    /// distributed cluster admin endpoint provides. Also served through the
+    /// Prometheus `metrics_port`, same as `metrics()`.
+    pub async fn cluster_status(&self) -> Vec<NodeStatus> {
+        self.coordinator.read().await.cluster_status().await
+    }
+
     /// Get configuration
     pub fn config(&self) -> &SummonerConfig {
         &self.config
     }
+
+    /// Create a fresh [`Stigmergy`] replica for a robot identified by `id`,
+    /// the shared tuple space agents gossip claimed targets, elected
+    /// coordinators, and jamming observations through without point-to-point
+    /// messaging -- the only coordination channel that still works once a
+    /// robot lands in a different spatial partition than its squadmates.
+    pub fn create_stigmergy(
+        &self,
+        id: impl Into<autonomysim_core::vehicle::VehicleId>,
+    ) -> Stigmergy {
+        Stigmergy::new(id)
+    }
+
+    /// Create this replica's [`Barrier`] for phase transition `id` (e.g.
+    /// `"launch"`), gossiped and ticked by the caller exactly like a
+    /// [`Stigmergy`] replica: each participant `propagate`s its ready-set
+    /// with neighbors reachable this step and `tick`s the result, invoking
+    /// its own `on_complete`/`on_timeout` once `ready_count` reaches
+    /// `threshold` or [`BARRIER_TIMEOUT`] steps pass first.
+    ///
+    /// Bumps an internal generation counter for `id` and folds it into the
+    /// replica's barrier id, so calling `barrier("launch", ..)` again for
+    /// the next phase starts a disjoint rendezvous -- a straggler's replica
+    /// still `propagate`-ing a stale `"launch"` ready-mark from the first
+    /// call can never merge into the second, since [`Barrier::propagate`]
+    /// only merges replicas sharing the exact same id.
+    pub fn barrier(&mut self, id: impl Into<String>, self_id: u64, threshold: usize) -> Barrier {
+        let id = id.into();
+        let generation = self.barrier_generations.entry(id.clone()).or_insert(0);
+        let qualified_id = format!("{}#{}", id, *generation);
+        *generation += 1;
+        Barrier::new(qualified_id, self_id, threshold)
+    }
 }
 
 #[cfg(test)]
@@ -460,6 +928,24 @@ mod tests {
         assert_eq!(summoner.current_step(), 1);
     }
 
+    #[tokio::test]
+    async fn test_cluster_status_reports_registered_workers() {
+        let config = SummonerConfig {
+            num_agents: 10,
+            enable_monitoring: false,
+            ..Default::default()
+        };
+
+        let mut summoner = Summoner::new(config).await.unwrap();
+        summoner.step(0.01).await.unwrap();
+
+        let statuses = summoner.cluster_status().await;
+        assert_eq!(statuses.len(), 1);
+        assert!(statuses[0].is_up);
+        assert_eq!(statuses[0].current_load, 10);
+        assert!(!statuses[0].draining);
+    }
+
     #[test]
     fn test_config_validation() {
         let bad_config = SummonerConfig {
@@ -0,0 +1,177 @@
+//! Weighted dynamic load balancing for
+//! `DistributionStrategy::WeightedPartitioning`.
+//!
+//! Tracks an exponentially-weighted moving average of each partition's step
+//! time and turns it into a target agent count, so
+//! `Summoner::rebalance_weighted_partitions` can migrate agents from
+//! overloaded partitions toward underloaded ones as agent density shifts,
+//! instead of staying pinned to a fixed spatial grid.
+
+use std::collections::HashMap;
+
+/// Smoothing factor for the per-partition step-time EWMA:
+/// `load = (1 - ALPHA) * load + ALPHA * last_step_ms`.
+const ALPHA: f64 = 0.1;
+
+/// Per-partition load tracker backing `DistributionStrategy::WeightedPartitioning`'s
+/// rebalancing decisions.
+#[derive(Debug, Default, Clone)]
+pub struct LoadBalancer {
+    /// EWMA step time (ms), keyed by worker/partition id.
+    loads: HashMap<usize, f64>,
+    /// Total agents migrated by `target_counts`-driven rebalances so far.
+    migrations: u64,
+}
+
+impl LoadBalancer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold `step_time_ms` into partition `worker_id`'s EWMA load. The first
+    /// sample for a partition seeds the EWMA directly instead of smoothing
+    /// against an assumed-zero starting load.
+    pub fn record_step_time(&mut self, worker_id: usize, step_time_ms: f64) {
+        self.loads
+            .entry(worker_id)
+            .and_modify(|load| *load = (1.0 - ALPHA) * *load + ALPHA * step_time_ms)
+            .or_insert(step_time_ms);
+    }
+
+    /// Current EWMA load per partition.
+    pub fn loads(&self) -> HashMap<usize, f64> {
+        self.loads.clone()
+    }
+
+    /// Target agent count per partition: each partition's weight is the
+    /// inverse of its EWMA load, normalized so weights sum to 1, so a
+    /// slower partition (higher load) is assigned proportionally fewer
+    /// agents. A partition with no recorded load yet (e.g. a fresh joiner)
+    /// is treated as averaged-loaded so it isn't starved on its first
+    /// rebalance. Rounding drift against `total_agents` is corrected by
+    /// nudging the highest- and lowest-weight partitions, so the returned
+    /// targets always sum to exactly `total_agents`.
+    pub fn target_counts(
+        &self,
+        worker_ids: &[usize],
+        total_agents: usize,
+    ) -> HashMap<usize, usize> {
+        if worker_ids.is_empty() {
+            return HashMap::new();
+        }
+
+        let recorded: Vec<f64> = worker_ids
+            .iter()
+            .filter_map(|id| self.loads.get(id).copied())
+            .collect();
+        let mean_load = if recorded.is_empty() {
+            1.0
+        } else {
+            recorded.iter().sum::<f64>() / recorded.len() as f64
+        };
+
+        let weights: HashMap<usize, f64> = worker_ids
+            .iter()
+            .map(|&id| {
+                let load = self
+                    .loads
+                    .get(&id)
+                    .copied()
+                    .unwrap_or(mean_load)
+                    .max(f64::EPSILON);
+                (id, 1.0 / load)
+            })
+            .collect();
+        let weight_sum: f64 = weights.values().sum();
+
+        let mut targets: HashMap<usize, usize> = worker_ids
+            .iter()
+            .map(|&id| {
+                let weight = weights[&id] / weight_sum;
+                (id, (total_agents as f64 * weight).round() as usize)
+            })
+            .collect();
+
+        let mut drift = total_agents as i64 - targets.values().map(|&n| n as i64).sum::<i64>();
+        if drift != 0 {
+            let mut by_weight_desc: Vec<usize> = worker_ids.to_vec();
+            by_weight_desc.sort_by(|a, b| {
+                weights[b]
+                    .partial_cmp(&weights[a])
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            let mut i = 0;
+            while drift != 0 {
+                let id = by_weight_desc[i % by_weight_desc.len()];
+                let target = targets.get_mut(&id).expect("id came from worker_ids");
+                if drift > 0 {
+                    *target += 1;
+                    drift -= 1;
+                } else if *target > 0 {
+                    *target -= 1;
+                    drift += 1;
+                }
+                i += 1;
+            }
+        }
+
+        targets
+    }
+
+    /// Record that `count` agents were just migrated by a rebalance, for
+    /// `SummonerMetrics::migration_count`.
+    pub fn record_migration(&mut self, count: usize) {
+        self.migrations += count as u64;
+    }
+
+    /// Total agents migrated by weighted-partition rebalances so far.
+    pub fn migration_count(&self) -> u64 {
+        self.migrations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ewma_seeds_from_first_sample_then_smooths() {
+        let mut balancer = LoadBalancer::new();
+        balancer.record_step_time(0, 10.0);
+        assert_eq!(balancer.loads()[&0], 10.0);
+
+        balancer.record_step_time(0, 20.0);
+        assert!((balancer.loads()[&0] - 11.0).abs() < 1e-9); // 0.9*10 + 0.1*20
+    }
+
+    #[test]
+    fn overloaded_partition_gets_fewer_target_agents() {
+        let mut balancer = LoadBalancer::new();
+        balancer.record_step_time(0, 10.0); // slow
+        balancer.record_step_time(1, 1.0); // fast
+
+        let targets = balancer.target_counts(&[0, 1], 1000);
+        assert!(targets[&1] > targets[&0]);
+        assert_eq!(targets[&0] + targets[&1], 1000);
+    }
+
+    #[test]
+    fn unrecorded_partition_defaults_to_mean_load() {
+        let mut balancer = LoadBalancer::new();
+        balancer.record_step_time(0, 10.0);
+        balancer.record_step_time(1, 10.0);
+
+        let targets = balancer.target_counts(&[0, 1, 2], 900);
+        assert_eq!(targets[&0], 300);
+        assert_eq!(targets[&1], 300);
+        assert_eq!(targets[&2], 300);
+    }
+
+    #[test]
+    fn migration_count_accumulates() {
+        let mut balancer = LoadBalancer::new();
+        balancer.record_migration(3);
+        balancer.record_migration(2);
+        assert_eq!(balancer.migration_count(), 5);
+    }
+}
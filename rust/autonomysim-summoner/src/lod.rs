@@ -0,0 +1,237 @@
+//! Level-of-detail agent simulation.
+//!
+//! `Worker::execute_step` currently runs the whole per-agent pipeline --
+//! sensors, control, cohesion's neighbor queries -- for every agent every
+//! tick, which is the dominant cost once a real backend is wired in behind
+//! `Worker::simulate_physics`. `LodConfig` buckets agents into near/mid/far
+//! bands by squared distance to the nearest "focus point" (a friendly
+//! formation centroid, a threat location, ...), so [`classify`] can drive
+//! `Worker::update_lod`: near agents keep updating every step, mid agents
+//! only every [`LodBand::update_every`] steps, and far agents rarer still
+//! and via plain ballistic dead-reckoning instead of the full pipeline.
+//!
+//! Hysteresis (`raise_at < drop_at` per band) keeps an agent sitting near a
+//! band boundary from flipping level every tick, which would otherwise make
+//! the coarse bands update almost as often as full fidelity.
+
+use nalgebra::Point3;
+use serde::{Deserialize, Serialize};
+
+/// Coarseness band an agent's simulation currently runs at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LodLevel {
+    /// Full neighbor flocking and sensor simulation, every step.
+    Near,
+    /// Simplified integration (no neighbor queries) every
+    /// [`LodBand::update_every`] steps.
+    Mid,
+    /// Ballistic dead-reckoned motion, no neighbor queries, every
+    /// [`LodBand::update_every`] steps.
+    Far,
+}
+
+impl LodLevel {
+    /// Lowercase band name, used as the `SummonerMetrics::lod_histogram` key.
+    pub fn label(self) -> &'static str {
+        match self {
+            LodLevel::Near => "near",
+            LodLevel::Mid => "mid",
+            LodLevel::Far => "far",
+        }
+    }
+}
+
+impl Default for LodLevel {
+    fn default() -> Self {
+        LodLevel::Near
+    }
+}
+
+/// Hysteresis thresholds and update cadence for one non-[`LodLevel::Near`]
+/// band.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LodBand {
+    /// Squared distance to the nearest focus point beyond which an agent in
+    /// a finer band drops into this one.
+    pub drop_at: f64,
+    /// Squared distance below which an agent in this band (or a coarser
+    /// one) is raised back to a finer one. Must be less than `drop_at`, or
+    /// an agent sitting between the two would flip level every tick.
+    pub raise_at: f64,
+    /// Run a full update for agents in this band only every this many
+    /// steps; skipped steps leave the agent's state untouched.
+    pub update_every: u32,
+}
+
+/// `SummonerConfig::lod` tunables.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LodConfig {
+    /// Friendly formation centroids, threat locations, or any other point
+    /// agents are simulated at higher fidelity near. An agent's band is
+    /// driven by its distance to the *nearest* one.
+    pub focus_points: Vec<Point3<f64>>,
+    pub mid: LodBand,
+    pub far: LodBand,
+}
+
+/// Per-agent LOD bookkeeping `Worker` keeps alongside `agent_states`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LodState {
+    pub level: LodLevel,
+    /// Steps since this agent last ran a full (near) or coarse (mid/far)
+    /// update, reset to 0 whenever one runs.
+    pub steps_since_update: u32,
+    /// Elapsed `dt` accumulated since the last coarse update, for the
+    /// ballistic dead-reckoning integration to apply all at once when the
+    /// mid/far cadence finally fires.
+    pub accumulated_dt: f64,
+}
+
+impl Default for LodState {
+    fn default() -> Self {
+        Self {
+            level: LodLevel::default(),
+            steps_since_update: 0,
+            accumulated_dt: 0.0,
+        }
+    }
+}
+
+/// Squared distance from `position` to the nearest of `focus_points`, or
+/// `f64::INFINITY` when there are none (so every band's `drop_at`
+/// comparison leaves an unanchored agent at the coarsest band).
+pub fn nearest_focus_distance_sq(position: Point3<f64>, focus_points: &[Point3<f64>]) -> f64 {
+    focus_points
+        .iter()
+        .map(|focus| (position - focus).norm_squared())
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Classify `current`'s next level from its squared distance to the
+/// nearest focus point, applying hysteresis so an agent only drops to a
+/// coarser band past that band's `drop_at` and only returns to a finer one
+/// once back within that finer band's `raise_at`.
+pub fn classify(current: LodLevel, distance_sq: f64, config: &LodConfig) -> LodLevel {
+    match current {
+        LodLevel::Near => {
+            if distance_sq > config.far.drop_at {
+                LodLevel::Far
+            } else if distance_sq > config.mid.drop_at {
+                LodLevel::Mid
+            } else {
+                LodLevel::Near
+            }
+        }
+        LodLevel::Mid => {
+            if distance_sq < config.mid.raise_at {
+                LodLevel::Near
+            } else if distance_sq > config.far.drop_at {
+                LodLevel::Far
+            } else {
+                LodLevel::Mid
+            }
+        }
+        LodLevel::Far => {
+            if distance_sq < config.mid.raise_at {
+                LodLevel::Near
+            } else if distance_sq < config.far.raise_at {
+                LodLevel::Mid
+            } else {
+                LodLevel::Far
+            }
+        }
+    }
+}
+
+/// Whether an agent currently in `level`, having gone `steps_since_update`
+/// steps without a full/coarse update, should run one this tick.
+/// `LodLevel::Near` always updates.
+pub fn should_update(level: LodLevel, steps_since_update: u32, config: &LodConfig) -> bool {
+    match level {
+        LodLevel::Near => true,
+        LodLevel::Mid => steps_since_update >= config.mid.update_every.max(1) - 1,
+        LodLevel::Far => steps_since_update >= config.far.update_every.max(1) - 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> LodConfig {
+        LodConfig {
+            focus_points: vec![Point3::origin()],
+            mid: LodBand {
+                drop_at: 100.0,
+                raise_at: 80.0,
+                update_every: 4,
+            },
+            far: LodBand {
+                drop_at: 400.0,
+                raise_at: 350.0,
+                update_every: 10,
+            },
+        }
+    }
+
+    #[test]
+    fn nearest_focus_picks_the_closest_point() {
+        let position = Point3::new(10.0, 0.0, 0.0);
+        let focus_points = [Point3::new(0.0, 0.0, 0.0), Point3::new(9.0, 0.0, 0.0)];
+        assert_eq!(nearest_focus_distance_sq(position, &focus_points), 1.0);
+    }
+
+    #[test]
+    fn no_focus_points_is_infinitely_far() {
+        assert_eq!(nearest_focus_distance_sq(Point3::origin(), &[]), f64::INFINITY);
+    }
+
+    #[test]
+    fn near_agent_drops_to_mid_past_drop_at() {
+        let config = config();
+        assert_eq!(classify(LodLevel::Near, 150.0, &config), LodLevel::Mid);
+    }
+
+    #[test]
+    fn near_agent_can_drop_straight_to_far() {
+        let config = config();
+        assert_eq!(classify(LodLevel::Near, 500.0, &config), LodLevel::Far);
+    }
+
+    #[test]
+    fn mid_agent_does_not_return_to_near_until_inside_raise_at() {
+        let config = config();
+        // Between raise_at and drop_at: stays mid, doesn't flicker back.
+        assert_eq!(classify(LodLevel::Mid, 90.0, &config), LodLevel::Mid);
+        assert_eq!(classify(LodLevel::Mid, 70.0, &config), LodLevel::Near);
+    }
+
+    #[test]
+    fn far_agent_returns_through_mid_not_straight_to_near() {
+        let config = config();
+        assert_eq!(classify(LodLevel::Far, 360.0, &config), LodLevel::Far);
+        assert_eq!(classify(LodLevel::Far, 300.0, &config), LodLevel::Mid);
+        assert_eq!(classify(LodLevel::Far, 10.0, &config), LodLevel::Near);
+    }
+
+    #[test]
+    fn near_always_updates() {
+        let config = config();
+        assert!(should_update(LodLevel::Near, 0, &config));
+    }
+
+    #[test]
+    fn mid_only_updates_on_its_cadence() {
+        let config = config();
+        assert!(!should_update(LodLevel::Mid, 0, &config));
+        assert!(!should_update(LodLevel::Mid, 2, &config));
+        assert!(should_update(LodLevel::Mid, 3, &config));
+    }
+
+    #[test]
+    fn far_only_updates_on_its_cadence() {
+        let config = config();
+        assert!(!should_update(LodLevel::Far, 8, &config));
+        assert!(should_update(LodLevel::Far, 9, &config));
+    }
+}
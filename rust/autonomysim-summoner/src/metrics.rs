@@ -3,8 +3,333 @@
 use anyhow::Result;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::coordinator::NodeStatus;
+
+/// One completed step, fed to every registered [`AbstractMeasurement`] by
+/// the background reporting task (see [`PerformanceMonitor::record_step`]).
+#[derive(Debug, Clone, Copy)]
+pub struct StepSample {
+    pub step: u64,
+    pub duration: Duration,
+    pub num_agents: usize,
+}
+
+/// A measurement's current value, renderable in Prometheus text format by
+/// [`encode_prometheus`].
+#[derive(Debug, Clone, Copy)]
+pub enum MetricValue {
+    Gauge(f64),
+    Counter(u64),
+    /// Latency percentiles in milliseconds, estimated from a bounded
+    /// histogram (see [`LatencyHistogram`]) rather than a sorted sample
+    /// buffer. Encoded as a Prometheus `summary` (quantile labels), since
+    /// that's what we actually have -- precomputed quantiles, not raw
+    /// bucket boundaries.
+    Histogram {
+        p50: f64,
+        p95: f64,
+        p99: f64,
+    },
+}
+
+/// A pluggable metric fed one [`StepSample`] per completed step. Built-ins
+/// below (step latency, step count, active agents) implement this the same
+/// as a user's custom metric -- per-backend RPC latency, GPU env
+/// throughput, collision counts -- so [`PerformanceMonitor`] never needs to
+/// know about any of them by name; it just holds a
+/// `Vec<Arc<dyn AbstractMeasurement>>` and fans every sample out to all of
+/// them.
+pub trait AbstractMeasurement: Send + Sync {
+    /// Prometheus metric name, e.g. `summoner_step_latency_ms`.
+    fn name(&self) -> &str;
+    fn ingest(&self, sample: StepSample);
+    fn report(&self) -> MetricValue;
+}
+
+/// Upper bounds (inclusive, milliseconds) of each latency bucket, spanning
+/// sub-millisecond RPCs up to multi-second stalls -- roughly the same
+/// log-ish spacing Prometheus's own default histogram buckets use.
+const HISTOGRAM_BOUNDS_MS: &[f64] = &[
+    0.1,
+    0.5,
+    1.0,
+    2.5,
+    5.0,
+    10.0,
+    25.0,
+    50.0,
+    100.0,
+    250.0,
+    500.0,
+    1000.0,
+    2500.0,
+    5000.0,
+    f64::INFINITY,
+];
+
+/// A fixed-bucket latency histogram: `ingest` just bumps the buckets a
+/// sample falls under (O(number of buckets), not O(samples so far)), so it
+/// never needs to re-sort or re-scan a growing buffer the way the old
+/// per-step `Vec<Duration>` recompute did. `report` estimates p50/p95/p99
+/// from cumulative bucket counts -- bucket-boundary precision, not exact,
+/// the usual tradeoff for a bounded histogram.
+pub struct LatencyHistogram {
+    name: String,
+    buckets: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl LatencyHistogram {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            buckets: (0..HISTOGRAM_BOUNDS_MS.len())
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            count: AtomicU64::new(0),
+            sum_micros: AtomicU64::new(0),
+        }
+    }
+
+    fn percentile(&self, quantile: f64) -> f64 {
+        let total = self.count.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0.0;
+        }
+        let target = ((quantile * total as f64).ceil() as u64).max(1);
+        let mut cumulative = 0u64;
+        for (bound, bucket) in HISTOGRAM_BOUNDS_MS.iter().zip(self.buckets.iter()) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            if cumulative >= target {
+                return *bound;
+            }
+        }
+        *HISTOGRAM_BOUNDS_MS.last().expect("bounds is non-empty")
+    }
+
+    pub fn mean_ms(&self) -> f64 {
+        let total = self.count.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0.0;
+        }
+        self.sum_micros.load(Ordering::Relaxed) as f64 / total as f64 / 1000.0
+    }
+
+    /// Upper bound of the smallest non-empty bucket, as a cheap lower
+    /// estimate of the observed minimum.
+    pub fn min_ms(&self) -> f64 {
+        HISTOGRAM_BOUNDS_MS
+            .iter()
+            .zip(self.buckets.iter())
+            .find(|(_, bucket)| bucket.load(Ordering::Relaxed) > 0)
+            .map(|(bound, _)| *bound)
+            .unwrap_or(0.0)
+    }
+
+    /// Upper bound of the largest non-empty bucket, as a cheap estimate of
+    /// the observed maximum.
+    pub fn max_ms(&self) -> f64 {
+        HISTOGRAM_BOUNDS_MS
+            .iter()
+            .zip(self.buckets.iter())
+            .rev()
+            .find(|(_, bucket)| bucket.load(Ordering::Relaxed) > 0)
+            .map(|(bound, _)| *bound)
+            .unwrap_or(0.0)
+    }
+}
+
+impl AbstractMeasurement for LatencyHistogram {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn ingest(&self, sample: StepSample) {
+        let ms = sample.duration.as_secs_f64() * 1000.0;
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(sample.duration.as_micros() as u64, Ordering::Relaxed);
+        for (bound, bucket) in HISTOGRAM_BOUNDS_MS.iter().zip(self.buckets.iter()) {
+            if ms <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn report(&self) -> MetricValue {
+        MetricValue::Histogram {
+            p50: self.percentile(0.50),
+            p95: self.percentile(0.95),
+            p99: self.percentile(0.99),
+        }
+    }
+}
+
+/// Built-in: total number of steps ingested.
+struct StepCounter {
+    count: AtomicU64,
+}
+
+impl StepCounter {
+    fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+        }
+    }
+}
+
+impl AbstractMeasurement for StepCounter {
+    fn name(&self) -> &str {
+        "summoner_total_steps"
+    }
+
+    fn ingest(&self, _sample: StepSample) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn report(&self) -> MetricValue {
+        MetricValue::Counter(self.count.load(Ordering::Relaxed))
+    }
+}
+
+/// Built-in: most recently reported agent count.
+struct ActiveAgentsGauge {
+    value: AtomicU64,
+}
+
+impl ActiveAgentsGauge {
+    fn new() -> Self {
+        Self {
+            value: AtomicU64::new(0),
+        }
+    }
+}
+
+impl AbstractMeasurement for ActiveAgentsGauge {
+    fn name(&self) -> &str {
+        "summoner_active_agents"
+    }
+
+    fn ingest(&self, sample: StepSample) {
+        self.value
+            .store(sample.num_agents as u64, Ordering::Relaxed);
+    }
+
+    fn report(&self) -> MetricValue {
+        MetricValue::Gauge(self.value.load(Ordering::Relaxed) as f64)
+    }
+}
+
+/// Render every measurement's current value as Prometheus text-format
+/// exposition (the `# TYPE ...` + sample-line shape `curl`/Prometheus's own
+/// scraper expect).
+fn encode_prometheus(measurements: &[Arc<dyn AbstractMeasurement>]) -> String {
+    let mut out = String::new();
+    for measurement in measurements {
+        let name = measurement.name();
+        match measurement.report() {
+            MetricValue::Gauge(value) => {
+                out.push_str(&format!("# TYPE {} gauge\n{} {}\n", name, name, value));
+            }
+            MetricValue::Counter(value) => {
+                out.push_str(&format!("# TYPE {} counter\n{} {}\n", name, name, value));
+            }
+            MetricValue::Histogram { p50, p95, p99 } => {
+                out.push_str(&format!(
+                    "# TYPE {name} summary\n{name}{{quantile=\"0.5\"}} {p50}\n{name}{{quantile=\"0.95\"}} {p95}\n{name}{{quantile=\"0.99\"}} {p99}\n",
+                    name = name,
+                    p50 = p50,
+                    p95 = p95,
+                    p99 = p99,
+                ));
+            }
+        }
+    }
+    out
+}
+
+/// Drain [`StepSample`]s off `sample_rx`, fan each one out to every
+/// registered measurement, and refresh the legacy [`SummonerMetrics`]
+/// snapshot from the built-in step-latency histogram -- all off
+/// `record_step`'s hot path.
+fn spawn_reporting_task(
+    mut sample_rx: mpsc::UnboundedReceiver<StepSample>,
+    measurements: Arc<Vec<Arc<dyn AbstractMeasurement>>>,
+    metrics: Arc<RwLock<SummonerMetrics>>,
+    step_latency: Arc<LatencyHistogram>,
+    start_time: Instant,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Some(sample) = sample_rx.recv().await {
+            for measurement in measurements.iter() {
+                measurement.ingest(sample);
+            }
+
+            let mut metrics = metrics.write();
+            metrics.total_steps = sample.step + 1;
+            metrics.active_agents = sample.num_agents;
+            metrics.avg_step_time_ms = step_latency.mean_ms();
+            metrics.min_step_time_ms = step_latency.min_ms();
+            metrics.max_step_time_ms = step_latency.max_ms();
+
+            let elapsed = start_time.elapsed().as_secs_f64();
+            if elapsed > 0.0 {
+                metrics.steps_per_second = metrics.total_steps as f64 / elapsed;
+                metrics.agents_per_second =
+                    (metrics.total_steps as f64 * sample.num_agents as f64) / elapsed;
+            }
+        }
+    })
+}
+
+/// Serve `measurements` as a Prometheus text-format endpoint on `port`: one
+/// connection, one response, the same document regardless of path/method,
+/// since this is meant for a scraper rather than a general HTTP server.
+fn spawn_metrics_server(
+    port: u16,
+    measurements: Arc<Vec<Arc<dyn AbstractMeasurement>>>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(("0.0.0.0", port)).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                warn!("Failed to bind Prometheus metrics port {}: {}", port, e);
+                return;
+            }
+        };
+
+        loop {
+            let (mut socket, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    warn!("Metrics endpoint accept failed: {}", e);
+                    continue;
+                }
+            };
+            let measurements = measurements.clone();
+            tokio::spawn(async move {
+                let body = encode_prometheus(&measurements);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    })
+}
 
 /// SUMMONER performance metrics
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -29,63 +354,125 @@ pub struct SummonerMetrics {
 
     /// Agents per second (throughput)
     pub agents_per_second: f64,
+
+    /// Worker ids still mid-step when `Summoner::step` stopped waiting for
+    /// the most recent tick, per `StepPolicy`. Empty when every worker
+    /// finished within its deadline.
+    pub late_workers: Vec<usize>,
+
+    /// `MessageBus` bytes currently counted against its in-flight budget.
+    pub buffer_occupancy_bytes: u64,
+
+    /// Cumulative `MessageBus` backpressure stalls per node id, i.e. how
+    /// many times a send to that node had to wait for buffer budget.
+    pub backpressure_stalls: HashMap<usize, u64>,
+
+    /// Per-partition EWMA step-time load (ms), keyed by worker id. Only
+    /// meaningful when `distribution` is
+    /// `DistributionStrategy::WeightedPartitioning`; empty otherwise.
+    pub partition_loads: HashMap<usize, f64>,
+
+    /// Total agents migrated by `DistributionStrategy::WeightedPartitioning`
+    /// rebalances so far.
+    pub migration_count: u64,
+
+    /// Per-phase step timing (most recent and running average, in ms),
+    /// keyed by phase name (`"physics"`, `"sensors"`, `"communications"`,
+    /// `"control"`). See `crate::phase_profiler::PhaseProfiler`.
+    pub phase_timings: HashMap<String, crate::phase_profiler::PhaseTiming>,
+
+    /// Agent count per LOD band (`"near"`, `"mid"`, `"far"`) across every
+    /// worker, summed from `Worker::lod_histogram`. Empty unless
+    /// `SummonerConfig::lod` is set. See `crate::lod::LodConfig`.
+    pub lod_histogram: HashMap<String, usize>,
 }
 
-/// Performance monitor
+/// Performance monitor: fans every completed step out to a set of
+/// pluggable [`AbstractMeasurement`]s (the built-in step-latency histogram,
+/// step counter, and active-agents gauge, plus whatever a caller passes to
+/// [`PerformanceMonitor::with_measurements`]) and reports them off the hot
+/// path. `record_step` only pushes a [`StepSample`] onto a channel; a
+/// dedicated background task drains it, updates every measurement, and
+/// (when `metrics_port` is set) serves them as a Prometheus text-format
+/// endpoint -- recording a step never blocks on formatting or I/O.
 pub struct PerformanceMonitor {
     metrics: Arc<RwLock<SummonerMetrics>>,
-    step_times: Arc<RwLock<Vec<Duration>>>,
-    start_time: Instant,
-    _metrics_port: Option<u16>,
+    /// Most recent `Coordinator::cluster_status` snapshot, served through
+    /// the same `metrics_port` as `SummonerMetrics`.
+    cluster_status: Arc<RwLock<Vec<NodeStatus>>>,
+    measurements: Arc<Vec<Arc<dyn AbstractMeasurement>>>,
+    sample_tx: mpsc::UnboundedSender<StepSample>,
+    _otlp_endpoint: Option<String>,
+    _reporting_task: JoinHandle<()>,
+    _server_task: Option<JoinHandle<()>>,
 }
 
 impl PerformanceMonitor {
-    /// Create new performance monitor
-    pub fn new(metrics_port: Option<u16>) -> Result<Self> {
-        // TODO: Start Prometheus metrics server if port provided
+    /// Create a new performance monitor with just the built-in
+    /// measurements. See [`Self::with_measurements`] to register custom
+    /// ones alongside them.
+    pub fn new(metrics_port: Option<u16>, otlp_endpoint: Option<String>) -> Result<Self> {
+        Self::with_measurements(metrics_port, otlp_endpoint, Vec::new())
+    }
+
+    /// Like [`Self::new`], but additionally registers `extra` measurements
+    /// (per-backend RPC latency, GPU env throughput, collision counts, ...)
+    /// alongside the built-ins -- all reported through the same Prometheus
+    /// endpoint and drained off the hot path by the same background task.
+    pub fn with_measurements(
+        metrics_port: Option<u16>,
+        otlp_endpoint: Option<String>,
+        extra: Vec<Arc<dyn AbstractMeasurement>>,
+    ) -> Result<Self> {
+        // TODO: Install an OTLP exporter layer against `otlp_endpoint` on the
+        // host binary's `tracing_subscriber`, so the `#[instrument]` spans
+        // on `Summoner::step`/`Coordinator::broadcast_step`/
+        // `Coordinator::synchronize_boundaries`/`Worker::execute_step` are
+        // actually shipped somewhere instead of only being visible to
+        // whatever subscriber is already installed.
+
+        let step_latency = Arc::new(LatencyHistogram::new("summoner_step_latency_ms"));
+        let mut measurements: Vec<Arc<dyn AbstractMeasurement>> = vec![
+            step_latency.clone(),
+            Arc::new(StepCounter::new()),
+            Arc::new(ActiveAgentsGauge::new()),
+        ];
+        measurements.extend(extra);
+        let measurements = Arc::new(measurements);
+
+        let metrics = Arc::new(RwLock::new(SummonerMetrics::default()));
+        let cluster_status = Arc::new(RwLock::new(Vec::new()));
+        let (sample_tx, sample_rx) = mpsc::unbounded_channel();
+
+        let reporting_task = spawn_reporting_task(
+            sample_rx,
+            measurements.clone(),
+            metrics.clone(),
+            step_latency,
+            Instant::now(),
+        );
+        let server_task = metrics_port.map(|port| spawn_metrics_server(port, measurements.clone()));
 
         Ok(Self {
-            metrics: Arc::new(RwLock::new(SummonerMetrics::default())),
-            step_times: Arc::new(RwLock::new(Vec::new())),
-            start_time: Instant::now(),
-            _metrics_port: metrics_port,
+            metrics,
+            cluster_status,
+            measurements,
+            sample_tx,
+            _otlp_endpoint: otlp_endpoint,
+            _reporting_task: reporting_task,
+            _server_task: server_task,
         })
     }
 
-    /// Record a completed step
+    /// Record a completed step. O(1): just pushes a [`StepSample`] onto a
+    /// channel for the background reporting task to ingest, instead of
+    /// recomputing stats over the whole step-time buffer inline.
     pub fn record_step(&self, step: u64, step_time: Duration, num_agents: usize) {
-        let mut step_times = self.step_times.write();
-        step_times.push(step_time);
-
-        // Keep last 1000 samples
-        if step_times.len() > 1000 {
-            step_times.drain(0..100);
-        }
-
-        // Update metrics
-        let mut metrics = self.metrics.write();
-        metrics.active_agents = num_agents;
-        metrics.total_steps = step + 1;
-
-        // Compute statistics
-        if !step_times.is_empty() {
-            let times_ms: Vec<f64> = step_times
-                .iter()
-                .map(|d| d.as_secs_f64() * 1000.0)
-                .collect();
-
-            metrics.avg_step_time_ms = times_ms.iter().sum::<f64>() / times_ms.len() as f64;
-            metrics.min_step_time_ms = times_ms.iter().copied().fold(f64::INFINITY, f64::min);
-            metrics.max_step_time_ms = times_ms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-
-            // Compute throughput
-            let elapsed = self.start_time.elapsed().as_secs_f64();
-            if elapsed > 0.0 {
-                metrics.steps_per_second = metrics.total_steps as f64 / elapsed;
-                metrics.agents_per_second =
-                    (metrics.total_steps as f64 * num_agents as f64) / elapsed;
-            }
-        }
+        let _ = self.sample_tx.send(StepSample {
+            step,
+            duration: step_time,
+            num_agents,
+        });
     }
 
     /// Get current metrics
@@ -93,6 +480,57 @@ impl PerformanceMonitor {
         self.metrics.read().clone()
     }
 
+    /// Record which workers were still mid-step when `Summoner::step` gave
+    /// up waiting for the most recent tick.
+    pub fn record_late_workers(&self, late_workers: Vec<usize>) {
+        self.metrics.write().late_workers = late_workers;
+    }
+
+    /// Record the latest `MessageBus` buffer occupancy and per-node
+    /// backpressure stall counts.
+    pub fn record_buffer_stats(&self, occupancy_bytes: u64, stalls: HashMap<usize, u64>) {
+        let mut metrics = self.metrics.write();
+        metrics.buffer_occupancy_bytes = occupancy_bytes;
+        metrics.backpressure_stalls = stalls;
+    }
+
+    /// Cache the latest cluster status snapshot for retrieval alongside
+    /// `SummonerMetrics` through the same `metrics_port`.
+    pub fn record_cluster_status(&self, status: Vec<NodeStatus>) {
+        *self.cluster_status.write() = status;
+    }
+
+    /// Record the latest per-partition EWMA load, as tracked by
+    /// `LoadBalancer` for `DistributionStrategy::WeightedPartitioning`.
+    pub fn record_partition_loads(&self, loads: HashMap<usize, f64>) {
+        self.metrics.write().partition_loads = loads;
+    }
+
+    /// Record the running total of agents migrated by weighted-partition
+    /// rebalances.
+    pub fn record_migration_count(&self, count: u64) {
+        self.metrics.write().migration_count = count;
+    }
+
+    /// Record the latest per-phase timing snapshot from `PhaseProfiler`.
+    pub fn record_phase_timings(
+        &self,
+        timings: HashMap<String, crate::phase_profiler::PhaseTiming>,
+    ) {
+        self.metrics.write().phase_timings = timings;
+    }
+
+    /// Record the latest LOD band histogram, summed across every worker's
+    /// `Worker::lod_histogram`.
+    pub fn record_lod_histogram(&self, histogram: HashMap<String, usize>) {
+        self.metrics.write().lod_histogram = histogram;
+    }
+
+    /// Get the most recently recorded cluster status snapshot.
+    pub fn get_cluster_status(&self) -> Vec<NodeStatus> {
+        self.cluster_status.read().clone()
+    }
+
     /// Print metrics summary
     pub fn print_summary(&self) {
         let metrics = self.metrics.read();
@@ -105,6 +543,45 @@ impl PerformanceMonitor {
         println!("Max Step Time:     {:.2} ms", metrics.max_step_time_ms);
         println!("Steps/Second:      {:.1}", metrics.steps_per_second);
         println!("Agents/Second:     {:.0}", metrics.agents_per_second);
+        println!("Late Workers:      {:?}", metrics.late_workers);
+        println!(
+            "Buffer Occupancy:  {} bytes",
+            metrics.buffer_occupancy_bytes
+        );
+        println!("Backpressure:      {:?}", metrics.backpressure_stalls);
+        if !metrics.partition_loads.is_empty() {
+            println!("Partition Loads:   {:?}", metrics.partition_loads);
+            println!("Migrations:        {}", metrics.migration_count);
+        }
+        if !metrics.phase_timings.is_empty() {
+            println!("Phase Timings:");
+            for (phase, timing) in &metrics.phase_timings {
+                println!(
+                    "  {:<14} last {:.4}ms, avg {:.4}ms",
+                    phase, timing.last_ms, timing.avg_ms
+                );
+            }
+        }
+        if !metrics.lod_histogram.is_empty() {
+            println!("LOD Bands:         {:?}", metrics.lod_histogram);
+        }
+        drop(metrics);
+
+        // Custom measurements registered via `with_measurements`, beyond
+        // the three built-ins already summarized above by name.
+        for measurement in self.measurements.iter().skip(3) {
+            match measurement.report() {
+                MetricValue::Gauge(value) => println!("{}: {:.3}", measurement.name(), value),
+                MetricValue::Counter(value) => println!("{}: {}", measurement.name(), value),
+                MetricValue::Histogram { p50, p95, p99 } => println!(
+                    "{}: p50={:.2}ms p95={:.2}ms p99={:.2}ms",
+                    measurement.name(),
+                    p50,
+                    p95,
+                    p99
+                ),
+            }
+        }
         println!("===================================\n");
     }
 }
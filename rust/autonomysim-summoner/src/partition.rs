@@ -1,7 +1,137 @@
 //! Spatial partitioning for distributed simulation
 
 use nalgebra::Vector3;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+use crate::broadphase::BroadPhase;
+
+/// Tolerance for treating a position as exactly on a partition face despite
+/// floating-point arithmetic.
+const FACE_EPSILON: f64 = 1e-9;
+
+/// One face of a [`Partition`]'s box, named by the axis and direction it
+/// faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Face {
+    PlusX,
+    MinusX,
+    PlusY,
+    MinusY,
+    PlusZ,
+    MinusZ,
+}
+
+impl Face {
+    pub const ALL: [Face; 6] = [
+        Face::PlusX,
+        Face::MinusX,
+        Face::PlusY,
+        Face::MinusY,
+        Face::PlusZ,
+        Face::MinusZ,
+    ];
+
+    fn axis(self) -> usize {
+        match self {
+            Face::PlusX | Face::MinusX => 0,
+            Face::PlusY | Face::MinusY => 1,
+            Face::PlusZ | Face::MinusZ => 2,
+        }
+    }
+
+    fn is_positive(self) -> bool {
+        matches!(self, Face::PlusX | Face::PlusY | Face::PlusZ)
+    }
+}
+
+/// What happens to an agent that crosses a [`Face`] of its [`Partition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryCondition {
+    /// Migrate the agent to the neighboring partition across this face.
+    Handoff,
+    /// Clamp the agent back inside and negate the velocity component normal
+    /// to the face, as if it bounced off a wall.
+    Reflect,
+    /// Wrap the offending coordinate to the opposite bound, so the agent
+    /// re-enters on the far side of the same partition.
+    Periodic,
+    /// Remove the agent without further ceremony (distinct from `Kill` only
+    /// in the reason reported, so callers can tell a deliberate despawn
+    /// from a true out-of-bounds failure).
+    Absorb,
+    /// Remove the agent; the default for faces on the outer simulation
+    /// bounds, where there is no neighbor to hand off to.
+    Kill,
+}
+
+/// Per-face [`BoundaryCondition`] overrides for a [`SpatialPartitioner`],
+/// keyed by `(partition_id, Face)`. A face with no explicit override
+/// defaults to [`BoundaryCondition::Handoff`] if it borders another
+/// partition, or [`BoundaryCondition::Kill`] if it sits on the outer
+/// simulation bounds.
+#[derive(Debug, Clone, Default)]
+pub struct Boundary {
+    overrides: HashMap<(usize, Face), BoundaryCondition>,
+}
+
+impl Boundary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the policy for one partition's face.
+    pub fn set(&mut self, partition_id: usize, face: Face, condition: BoundaryCondition) {
+        self.overrides.insert((partition_id, face), condition);
+    }
+
+    fn resolve(&self, partition_id: usize, face: Face, has_neighbor: bool) -> BoundaryCondition {
+        self.overrides
+            .get(&(partition_id, face))
+            .copied()
+            .unwrap_or(if has_neighbor {
+                BoundaryCondition::Handoff
+            } else {
+                BoundaryCondition::Kill
+            })
+    }
+}
+
+/// What happened when [`SpatialPartitioner::resolve_crossing`] was asked
+/// about an agent's latest position.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrossingEvent {
+    /// The agent is still within its tracked partition; nothing to do.
+    None,
+    /// The agent crossed into a neighboring partition and must be migrated
+    /// to the worker that owns it.
+    Handoff {
+        agent_id: usize,
+        from_partition: usize,
+        to_partition: usize,
+    },
+    /// The agent bounced off a `Reflect` face; apply the corrected position
+    /// and velocity.
+    Reflected {
+        agent_id: usize,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+    },
+    /// The agent wrapped around a `Periodic` face; apply the corrected
+    /// position (velocity is unchanged).
+    Wrapped {
+        agent_id: usize,
+        position: Vector3<f64>,
+    },
+    /// The agent was removed, either by explicit `Absorb`/`Kill` policy or
+    /// because it crossed a `Handoff` face with no neighbor to receive it.
+    Removed {
+        agent_id: usize,
+        partition: Option<usize>,
+        reason: BoundaryCondition,
+    },
+}
 
 /// 3D spatial partition for agent assignment
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +169,11 @@ impl Partition {
 pub struct SpatialPartitioner {
     partitions: Vec<Partition>,
     _bounds: Vector3<f64>,
+    boundary: Boundary,
+    /// Last partition each tracked agent was seen in, so
+    /// [`Self::resolve_crossing`] knows which partition (and therefore
+    /// which faces) an out-of-bounds position was exited from.
+    agent_partitions: Mutex<HashMap<usize, usize>>,
 }
 
 impl SpatialPartitioner {
@@ -48,9 +183,18 @@ impl SpatialPartitioner {
         Self {
             partitions,
             _bounds: bounds,
+            boundary: Boundary::new(),
+            agent_partitions: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Per-face boundary condition overrides, mutable so callers can
+    /// configure non-default policies (e.g. `Periodic` on the outer bounds
+    /// for a wraparound world).
+    pub fn boundary_mut(&mut self) -> &mut Boundary {
+        &mut self.boundary
+    }
+
     /// Create grid-based partitions (e.g., 2x2x1 for 4 partitions)
     fn create_grid_partitions(bounds: Vector3<f64>, num_partitions: usize) -> Vec<Partition> {
         // For simplicity, divide along X and Y axes
@@ -160,6 +304,677 @@ impl SpatialPartitioner {
     pub fn partition(&self, id: usize) -> Option<&Partition> {
         self.partitions.get(id)
     }
+
+    /// Flag which agents sit within their `interaction_radius` of a face of
+    /// the partition they're in -- the ones a worker must sync to its
+    /// neighbors, since an interaction spanning the boundary would
+    /// otherwise be invisible to the other side. Returns, per partition id,
+    /// the agent ids that need syncing.
+    ///
+    /// `broad_phase`'s spatial hash is (re)built from this partitioner's
+    /// current partitions on every call, so the same `BroadPhase` can be
+    /// reused across partitioners/steps without going stale.
+    pub fn flag_boundary_agents(
+        &self,
+        broad_phase: &mut BroadPhase,
+        agents: &[(usize, Vector3<f64>, f64)],
+    ) -> HashMap<usize, Vec<usize>> {
+        broad_phase.set_partitions(&self.partitions);
+        broad_phase.update(agents);
+
+        let mut boundary_agents: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &(agent_id, position, radius) in agents {
+            let Some(partition_id) = broad_phase.point_partition(&position) else {
+                continue;
+            };
+            let Some(partition) = self.partition(partition_id) else {
+                continue;
+            };
+
+            let radius_vec = Vector3::new(radius, radius, radius);
+            let min = position - radius_vec;
+            let max = position + radius_vec;
+            let crosses_face = min.x < partition.min_bounds.x
+                || min.y < partition.min_bounds.y
+                || min.z < partition.min_bounds.z
+                || max.x > partition.max_bounds.x
+                || max.y > partition.max_bounds.y
+                || max.z > partition.max_bounds.z;
+
+            if crosses_face {
+                boundary_agents
+                    .entry(partition_id)
+                    .or_default()
+                    .push(agent_id);
+            }
+        }
+        boundary_agents
+    }
+
+    /// Detect whether `agent_id` has crossed out of its tracked partition
+    /// at `position`, and apply whatever [`BoundaryCondition`] governs the
+    /// face it exited through. Updates the internal agent-to-partition
+    /// tracking (so a `Handoff` or ordinary in-bounds move is remembered
+    /// for the next call) and returns [`CrossingEvent::None`] when the
+    /// agent is still inside its partition.
+    pub fn resolve_crossing(
+        &self,
+        agent_id: usize,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+    ) -> CrossingEvent {
+        let mut tracked = self.agent_partitions.lock();
+
+        let Some(previous_id) = tracked.get(&agent_id).copied() else {
+            // First sighting of this agent: nothing to compare against yet,
+            // just establish its starting partition (if any).
+            if let Some(partition_id) = self.find_partition(&position) {
+                tracked.insert(agent_id, partition_id);
+            }
+            return CrossingEvent::None;
+        };
+
+        // Neighboring partitions share a face with no gap between them, so
+        // checking `find_partition` first would silently reassign the
+        // agent to the neighbor without ever applying its boundary policy.
+        // Compare against the previously tracked partition instead.
+        if self.partitions[previous_id].contains(&position) {
+            return CrossingEvent::None;
+        }
+
+        let partition = &self.partitions[previous_id];
+        let Some(face) = Face::ALL
+            .into_iter()
+            .find(|&face| Self::position_exceeds_face(partition, face, &position))
+        else {
+            // Floating-point edge case: outside every partition's strict
+            // bounds check but not past its own previous partition either.
+            // Leave the agent where it was tracked.
+            return CrossingEvent::None;
+        };
+
+        let neighbor = self.neighbor_across_face(previous_id, face);
+        let condition = self.boundary.resolve(previous_id, face, neighbor.is_some());
+
+        match condition {
+            BoundaryCondition::Handoff => match neighbor {
+                Some(neighbor_id) => {
+                    tracked.insert(agent_id, neighbor_id);
+                    CrossingEvent::Handoff {
+                        agent_id,
+                        from_partition: previous_id,
+                        to_partition: neighbor_id,
+                    }
+                }
+                // A `Handoff` face with no neighbor only happens via an
+                // explicit misconfigured override; fall back to removing
+                // the agent rather than losing it silently.
+                None => {
+                    tracked.remove(&agent_id);
+                    CrossingEvent::Removed {
+                        agent_id,
+                        partition: Some(previous_id),
+                        reason: BoundaryCondition::Kill,
+                    }
+                }
+            },
+            BoundaryCondition::Reflect => {
+                let (position, velocity) = Self::reflect(partition, face, position, velocity);
+                CrossingEvent::Reflected {
+                    agent_id,
+                    position,
+                    velocity,
+                }
+            }
+            BoundaryCondition::Periodic => {
+                let position = Self::wrap(partition, face, position);
+                CrossingEvent::Wrapped { agent_id, position }
+            }
+            BoundaryCondition::Absorb | BoundaryCondition::Kill => {
+                tracked.remove(&agent_id);
+                CrossingEvent::Removed {
+                    agent_id,
+                    partition: Some(previous_id),
+                    reason: condition,
+                }
+            }
+        }
+    }
+
+    /// Resolve a batch of agent moves at once, returning only the events for
+    /// agents that actually crossed a face -- the typed event stream a
+    /// distributed runner drains after each step.
+    pub fn resolve_crossings(
+        &self,
+        moves: &[(usize, Vector3<f64>, Vector3<f64>)],
+    ) -> Vec<CrossingEvent> {
+        moves
+            .iter()
+            .map(|&(agent_id, position, velocity)| {
+                self.resolve_crossing(agent_id, position, velocity)
+            })
+            .filter(|event| *event != CrossingEvent::None)
+            .collect()
+    }
+
+    fn position_exceeds_face(partition: &Partition, face: Face, position: &Vector3<f64>) -> bool {
+        let axis = face.axis();
+        if face.is_positive() {
+            position[axis] >= partition.max_bounds[axis] - FACE_EPSILON
+        } else {
+            position[axis] < partition.min_bounds[axis] + FACE_EPSILON
+        }
+    }
+
+    /// The partition (if any) sharing `face` with `self.partitions[partition_index]`.
+    fn neighbor_across_face(&self, partition_index: usize, face: Face) -> Option<usize> {
+        let axis = face.axis();
+        let partition = &self.partitions[partition_index];
+        self.partitions
+            .iter()
+            .enumerate()
+            .find(|(index, other)| {
+                if *index == partition_index {
+                    return false;
+                }
+                let touches = if face.is_positive() {
+                    (partition.max_bounds[axis] - other.min_bounds[axis]).abs() < FACE_EPSILON
+                } else {
+                    (partition.min_bounds[axis] - other.max_bounds[axis]).abs() < FACE_EPSILON
+                };
+                touches
+                    && (0..3).filter(|&a| a != axis).all(|a| {
+                        partition.min_bounds[a] < other.max_bounds[a]
+                            && other.min_bounds[a] < partition.max_bounds[a]
+                    })
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Clamp `position` back inside `partition` on the face's axis and
+    /// negate `velocity`'s component along that axis.
+    fn reflect(
+        partition: &Partition,
+        face: Face,
+        mut position: Vector3<f64>,
+        mut velocity: Vector3<f64>,
+    ) -> (Vector3<f64>, Vector3<f64>) {
+        let axis = face.axis();
+        if face.is_positive() {
+            let bound = partition.max_bounds[axis];
+            let overshoot = position[axis] - bound;
+            position[axis] = bound - overshoot;
+        } else {
+            let bound = partition.min_bounds[axis];
+            let overshoot = bound - position[axis];
+            position[axis] = bound + overshoot;
+        }
+        velocity[axis] = -velocity[axis];
+        (position, velocity)
+    }
+
+    /// Wrap `position`'s coordinate on the face's axis to the opposite
+    /// bound of `partition`.
+    fn wrap(partition: &Partition, face: Face, mut position: Vector3<f64>) -> Vector3<f64> {
+        let axis = face.axis();
+        if face.is_positive() {
+            let overshoot = position[axis] - partition.max_bounds[axis];
+            position[axis] = partition.min_bounds[axis] + overshoot;
+        } else {
+            let overshoot = partition.min_bounds[axis] - position[axis];
+            position[axis] = partition.max_bounds[axis] - overshoot;
+        }
+        position
+    }
+
+    /// Subdivide `bounds` into a `grid_resolution`^3 grid of cells, weight
+    /// each cell by how many `agent_positions` fall inside it, and solve a
+    /// min-cost max-flow assignment of cells to `workers` that balances
+    /// load against each worker's capacity-derived `agent_budget` while
+    /// preferring spatial locality.
+    ///
+    /// Each cell only considers its `nearest_candidates` closest workers
+    /// (by centroid distance) as assignment targets, since shipping a
+    /// cell's agents across the whole cluster is never the cheap option --
+    /// the min-cost solver still weighs those candidates against how full
+    /// each one already is.
+    ///
+    /// If total worker capacity is less than the agent count, every
+    /// worker's budget is scaled up uniformly first so a feasible flow
+    /// (covering every agent) always exists.
+    ///
+    /// Returns, per worker id, the cells it was assigned together with how
+    /// many of that cell's agents it picked up -- a worker absent from the
+    /// map got none. A cell's weight can legitimately split across more
+    /// than one worker (and so appear under more than one worker id) when
+    /// its nearest candidate is already at capacity.
+    pub fn assign_cells_by_capacity(
+        bounds: Vector3<f64>,
+        grid_resolution: usize,
+        agent_positions: &[Vector3<f64>],
+        workers: &[WorkerCapacity],
+        nearest_candidates: usize,
+    ) -> HashMap<usize, Vec<(Partition, usize)>> {
+        let cells = Self::create_cell_grid(bounds, grid_resolution);
+        let weights: Vec<usize> = cells
+            .iter()
+            .map(|cell| {
+                agent_positions
+                    .iter()
+                    .filter(|pos| cell.contains(pos))
+                    .count()
+            })
+            .collect();
+
+        if workers.is_empty() {
+            return HashMap::new();
+        }
+
+        let total_agents: usize = weights.iter().sum();
+        let total_capacity: usize = workers.iter().map(|w| w.agent_budget).sum();
+        let scale = if total_capacity > 0 && total_agents > total_capacity {
+            (total_agents as f64 / total_capacity as f64).ceil() as usize
+        } else {
+            1
+        };
+
+        // Network layout: 0 = source, 1..=cells = cell nodes,
+        // cells+1..=cells+workers = worker nodes, last = sink.
+        let num_cells = cells.len();
+        let num_workers = workers.len();
+        let source = 0;
+        let worker_node = |w: usize| num_cells + 1 + w;
+        let sink = num_cells + num_workers + 1;
+
+        let mut flow = MinCostFlow::new(sink + 1);
+        for (cell_id, &weight) in weights.iter().enumerate() {
+            if weight == 0 {
+                continue;
+            }
+            flow.add_edge(source, cell_id + 1, weight as i64, 0);
+
+            let mut by_distance: Vec<(usize, f64)> = workers
+                .iter()
+                .enumerate()
+                .map(|(w, worker)| {
+                    (
+                        w,
+                        (cells[cell_id].center() - worker.centroid).norm_squared(),
+                    )
+                })
+                .collect();
+            by_distance.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+            for &(w, dist_sq) in by_distance.iter().take(nearest_candidates.max(1)) {
+                // Scale the cost so sub-meter distance differences still
+                // influence the ordering after truncation to an integer.
+                let cost = (dist_sq * 100.0) as i64;
+                flow.add_edge(cell_id + 1, worker_node(w), weight as i64, cost);
+            }
+        }
+        for (w, worker) in workers.iter().enumerate() {
+            flow.add_edge(
+                worker_node(w),
+                sink,
+                (worker.agent_budget * scale) as i64,
+                0,
+            );
+        }
+
+        flow.min_cost_max_flow(source, sink);
+
+        let mut assignment: HashMap<usize, Vec<(Partition, usize)>> = HashMap::new();
+        for (cell_id, cell) in cells.into_iter().enumerate() {
+            if weights[cell_id] == 0 {
+                continue;
+            }
+            for (w, worker) in workers.iter().enumerate() {
+                let amount = flow.flow_on(cell_id + 1, worker_node(w));
+                if amount > 0 {
+                    assignment
+                        .entry(worker.worker_id)
+                        .or_default()
+                        .push((cell.clone(), amount as usize));
+                }
+            }
+        }
+        assignment
+    }
+
+    /// Build a `grid_resolution`^3 grid of cells over `bounds`, ignoring
+    /// face-adjacency (unlike [`Self::create_grid_partitions`]) since
+    /// capacity-aware assignment doesn't need neighbor links.
+    fn create_cell_grid(bounds: Vector3<f64>, grid_resolution: usize) -> Vec<Partition> {
+        let n = grid_resolution.max(1);
+        let dx = bounds.x / n as f64;
+        let dy = bounds.y / n as f64;
+        let dz = bounds.z / n as f64;
+
+        let mut cells = Vec::with_capacity(n * n * n);
+        let mut id = 0;
+        for ix in 0..n {
+            for iy in 0..n {
+                for iz in 0..n {
+                    let min_bounds = Vector3::new(ix as f64 * dx, iy as f64 * dy, iz as f64 * dz);
+                    let max_bounds = Vector3::new(
+                        (ix + 1) as f64 * dx,
+                        (iy + 1) as f64 * dy,
+                        (iz + 1) as f64 * dz,
+                    );
+                    cells.push(Partition {
+                        id,
+                        min_bounds,
+                        max_bounds,
+                        neighbor_partitions: Vec::new(),
+                    });
+                    id += 1;
+                }
+            }
+        }
+        cells
+    }
+}
+
+/// Tolerance for treating two partition faces as touching despite
+/// floating-point split arithmetic.
+const ADJACENCY_EPSILON: f64 = 1e-6;
+
+/// Load-balanced alternative to [`SpatialPartitioner`]'s uniform grid:
+/// recursively bisects space so each leaf partition holds roughly the same
+/// number of agents, regardless of how unevenly they're clustered. Where
+/// `SpatialPartitioner::new`'s uniform cells leave most workers idle when
+/// agents cluster over one area (e.g. all drones over one building),
+/// `AdaptivePartitioner` instead tracks where the agents actually are.
+pub struct AdaptivePartitioner {
+    bounds: (Vector3<f64>, Vector3<f64>),
+    partitions: Vec<Partition>,
+}
+
+impl AdaptivePartitioner {
+    /// Build a new adaptive partitioner with `target_partitions` leaves,
+    /// recursively bisecting `(min_bounds, max_bounds)` by `positions`.
+    pub fn new(
+        min_bounds: Vector3<f64>,
+        max_bounds: Vector3<f64>,
+        positions: &[Vector3<f64>],
+        target_partitions: usize,
+    ) -> Self {
+        let mut partitioner = Self {
+            bounds: (min_bounds, max_bounds),
+            partitions: Vec::new(),
+        };
+        partitioner.rebuild(positions, target_partitions.max(1));
+        partitioner
+    }
+
+    /// Rebuild the bisection tree from scratch against `positions`, keeping
+    /// the current leaf count. Call this every few steps so partitions
+    /// track moving load (e.g. a drone swarm drifting across the bounds)
+    /// instead of staying pinned to wherever agents started.
+    pub fn rebalance(&mut self, positions: &[Vector3<f64>]) {
+        let target = self.partitions.len().max(1);
+        self.rebuild(positions, target);
+    }
+
+    fn rebuild(&mut self, positions: &[Vector3<f64>], target_partitions: usize) {
+        let mut points = positions.to_vec();
+        let mut leaves = Vec::new();
+        let mut next_id = 0;
+        Self::bisect(
+            self.bounds,
+            &mut points,
+            target_partitions,
+            &mut next_id,
+            &mut leaves,
+        );
+        Self::link_neighbors(&mut leaves);
+        self.partitions = leaves;
+    }
+
+    /// Split `bounds` into `leaves_remaining` leaves: pick the longest axis,
+    /// sort `points` along it, divide at the median so each child starts
+    /// with ~half the points, and recurse. Bottoms out at one leaf per
+    /// partition or when there are too few points left to usefully split
+    /// further.
+    fn bisect(
+        bounds: (Vector3<f64>, Vector3<f64>),
+        points: &mut [Vector3<f64>],
+        leaves_remaining: usize,
+        next_id: &mut usize,
+        out: &mut Vec<Partition>,
+    ) {
+        if leaves_remaining <= 1 || points.len() <= 1 {
+            out.push(Partition {
+                id: *next_id,
+                min_bounds: bounds.0,
+                max_bounds: bounds.1,
+                neighbor_partitions: Vec::new(),
+            });
+            *next_id += 1;
+            return;
+        }
+
+        let extents = bounds.1 - bounds.0;
+        let axis = if extents.x >= extents.y && extents.x >= extents.z {
+            0
+        } else if extents.y >= extents.z {
+            1
+        } else {
+            2
+        };
+
+        points.sort_by(|a, b| a[axis].partial_cmp(&b[axis]).unwrap());
+        let mid = points.len() / 2;
+        let split = if mid == 0 {
+            (bounds.0[axis] + bounds.1[axis]) / 2.0
+        } else {
+            (points[mid - 1][axis] + points[mid][axis]) / 2.0
+        };
+
+        let mut left_max = bounds.1;
+        left_max[axis] = split;
+        let mut right_min = bounds.0;
+        right_min[axis] = split;
+
+        let left_leaves = leaves_remaining / 2;
+        let right_leaves = leaves_remaining - left_leaves;
+        let (left_points, right_points) = points.split_at_mut(mid);
+
+        Self::bisect((bounds.0, left_max), left_points, left_leaves, next_id, out);
+        Self::bisect(
+            (right_min, bounds.1),
+            right_points,
+            right_leaves,
+            next_id,
+            out,
+        );
+    }
+
+    /// Derive `neighbor_partitions` for every leaf by testing pairwise face
+    /// adjacency, since a bisection tree (unlike a uniform grid) doesn't
+    /// hand neighbors to us from its coordinates.
+    fn link_neighbors(leaves: &mut [Partition]) {
+        let adjacency: Vec<Vec<usize>> = (0..leaves.len())
+            .map(|i| {
+                (0..leaves.len())
+                    .filter(|&j| j != i && Self::face_adjacent(&leaves[i], &leaves[j]))
+                    .map(|j| leaves[j].id)
+                    .collect()
+            })
+            .collect();
+        for (leaf, neighbors) in leaves.iter_mut().zip(adjacency) {
+            leaf.neighbor_partitions = neighbors;
+        }
+    }
+
+    /// Two partitions share a face when their extents touch on one axis
+    /// while overlapping on the other two.
+    fn face_adjacent(a: &Partition, b: &Partition) -> bool {
+        for touch_axis in 0..3 {
+            let touches = (a.max_bounds[touch_axis] - b.min_bounds[touch_axis]).abs()
+                < ADJACENCY_EPSILON
+                || (b.max_bounds[touch_axis] - a.min_bounds[touch_axis]).abs() < ADJACENCY_EPSILON;
+            if !touches {
+                continue;
+            }
+
+            let overlaps = (0..3).filter(|&axis| axis != touch_axis).all(|axis| {
+                a.min_bounds[axis] < b.max_bounds[axis] && b.min_bounds[axis] < a.max_bounds[axis]
+            });
+            if overlaps {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Find which partition contains a point.
+    pub fn find_partition(&self, point: &Vector3<f64>) -> Option<usize> {
+        self.partitions
+            .iter()
+            .find(|p| p.contains(point))
+            .map(|p| p.id)
+    }
+
+    /// Get all partitions.
+    pub fn partitions(&self) -> &[Partition] {
+        &self.partitions
+    }
+
+    /// Get a specific partition.
+    pub fn partition(&self, id: usize) -> Option<&Partition> {
+        self.partitions.iter().find(|p| p.id == id)
+    }
+}
+
+/// A worker's assignment target for [`SpatialPartitioner::assign_cells_by_capacity`]:
+/// where it's currently centered in space, and how many agents it can take
+/// on (proportional to its GPU count/memory).
+#[derive(Debug, Clone, Copy)]
+pub struct WorkerCapacity {
+    pub worker_id: usize,
+    pub centroid: Vector3<f64>,
+    pub agent_budget: usize,
+}
+
+/// One directed edge of a [`MinCostFlow`] network, stored alongside its
+/// reverse (residual) edge at the adjacent index.
+struct FlowEdge {
+    to: usize,
+    capacity: i64,
+    cost: i64,
+    flow: i64,
+}
+
+/// Minimal min-cost max-flow network solved via successive shortest
+/// augmenting paths (Bellman-Ford/SPFA per augmentation, since residual
+/// edges can carry negative cost). Self-contained rather than pulling in an
+/// external graph crate for this one call site.
+struct MinCostFlow {
+    graph: Vec<Vec<usize>>,
+    edges: Vec<FlowEdge>,
+}
+
+impl MinCostFlow {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            graph: vec![Vec::new(); num_nodes],
+            edges: Vec::new(),
+        }
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, capacity: i64, cost: i64) {
+        let forward = self.edges.len();
+        self.edges.push(FlowEdge {
+            to,
+            capacity,
+            cost,
+            flow: 0,
+        });
+        self.graph[from].push(forward);
+
+        let backward = self.edges.len();
+        self.edges.push(FlowEdge {
+            to: from,
+            capacity: 0,
+            cost: -cost,
+            flow: 0,
+        });
+        self.graph[to].push(backward);
+    }
+
+    /// Flow pushed through the edge `from -> to` (0 if no such edge or it
+    /// carried none).
+    fn flow_on(&self, from: usize, to: usize) -> i64 {
+        self.graph[from]
+            .iter()
+            .map(|&edge_id| &self.edges[edge_id])
+            .find(|edge| edge.to == to)
+            .map(|edge| edge.flow)
+            .unwrap_or(0)
+    }
+
+    /// Push successive shortest-cost augmenting paths from `source` to
+    /// `sink` until none remain, returning the total flow.
+    fn min_cost_max_flow(&mut self, source: usize, sink: usize) -> i64 {
+        let n = self.graph.len();
+        let mut total_flow = 0i64;
+
+        loop {
+            let mut dist = vec![i64::MAX; n];
+            let mut in_queue = vec![false; n];
+            let mut prev_edge: Vec<Option<usize>> = vec![None; n];
+            dist[source] = 0;
+
+            let mut queue = VecDeque::new();
+            queue.push_back(source);
+            in_queue[source] = true;
+
+            while let Some(u) = queue.pop_front() {
+                in_queue[u] = false;
+                for &edge_id in &self.graph[u] {
+                    let edge = &self.edges[edge_id];
+                    if edge.capacity - edge.flow <= 0 || dist[u] == i64::MAX {
+                        continue;
+                    }
+                    let candidate = dist[u] + edge.cost;
+                    if candidate < dist[edge.to] {
+                        dist[edge.to] = candidate;
+                        prev_edge[edge.to] = Some(edge_id);
+                        if !in_queue[edge.to] {
+                            queue.push_back(edge.to);
+                            in_queue[edge.to] = true;
+                        }
+                    }
+                }
+            }
+
+            if dist[sink] == i64::MAX {
+                break;
+            }
+
+            let mut bottleneck = i64::MAX;
+            let mut v = sink;
+            while v != source {
+                let edge_id = prev_edge[v].expect("path from source was found above");
+                bottleneck =
+                    bottleneck.min(self.edges[edge_id].capacity - self.edges[edge_id].flow);
+                v = self.edges[edge_id ^ 1].to;
+            }
+
+            v = sink;
+            while v != source {
+                let edge_id = prev_edge[v].expect("path from source was found above");
+                self.edges[edge_id].flow += bottleneck;
+                self.edges[edge_id ^ 1].flow -= bottleneck;
+                v = self.edges[edge_id ^ 1].to;
+            }
+
+            total_flow += bottleneck;
+        }
+
+        total_flow
+    }
 }
 
 #[cfg(test)]
@@ -201,4 +1016,302 @@ mod tests {
         let partition = partitioner.partition(0).unwrap();
         assert!(!partition.neighbor_partitions.is_empty());
     }
+
+    #[test]
+    fn capacity_assignment_respects_worker_budgets() {
+        let bounds = Vector3::new(100.0, 100.0, 10.0);
+        // All 100 agents cluster near the origin, within worker 0's corner.
+        let agent_positions: Vec<Vector3<f64>> = (0..100)
+            .map(|i| Vector3::new((i % 10) as f64, (i / 10) as f64, 1.0))
+            .collect();
+
+        let workers = vec![
+            WorkerCapacity {
+                worker_id: 0,
+                centroid: Vector3::new(10.0, 10.0, 5.0),
+                agent_budget: 40,
+            },
+            WorkerCapacity {
+                worker_id: 1,
+                centroid: Vector3::new(90.0, 90.0, 5.0),
+                agent_budget: 100,
+            },
+        ];
+
+        let assignment =
+            SpatialPartitioner::assign_cells_by_capacity(bounds, 4, &agent_positions, &workers, 2);
+
+        let assigned_weight = |worker_id: usize| -> usize {
+            assignment
+                .get(&worker_id)
+                .map(|cells| cells.iter().map(|(_, count)| count).sum())
+                .unwrap_or(0)
+        };
+
+        // Worker 0 is nearest but only budgeted for 40; the rest must spill
+        // over to worker 1 rather than being dropped.
+        assert!(assigned_weight(0) <= 40);
+        assert_eq!(assigned_weight(0) + assigned_weight(1), 100);
+    }
+
+    #[test]
+    fn capacity_assignment_scales_budgets_up_when_total_capacity_is_short() {
+        let bounds = Vector3::new(10.0, 10.0, 10.0);
+        let agent_positions = vec![Vector3::new(1.0, 1.0, 1.0); 50];
+        let workers = vec![WorkerCapacity {
+            worker_id: 0,
+            centroid: Vector3::new(1.0, 1.0, 1.0),
+            agent_budget: 10,
+        }];
+
+        let assignment =
+            SpatialPartitioner::assign_cells_by_capacity(bounds, 2, &agent_positions, &workers, 1);
+
+        // Only one worker exists, so every agent has nowhere else to go --
+        // the uniform budget scale-up must still place all 50.
+        let total: usize = assignment
+            .get(&0)
+            .map(|cells| cells.iter().map(|(_, count)| count).sum())
+            .unwrap_or(0);
+        assert_eq!(total, 50);
+    }
+
+    #[test]
+    fn min_cost_flow_prefers_the_cheaper_edge_when_both_have_capacity() {
+        let mut flow = MinCostFlow::new(4);
+        flow.add_edge(0, 1, 10, 0);
+        flow.add_edge(1, 2, 10, 5); // expensive path
+        flow.add_edge(1, 3, 10, 1); // cheap path
+        flow.add_edge(2, /* sink stand-in */ 3, 10, 0);
+
+        let pushed = flow.min_cost_max_flow(0, 3);
+        assert_eq!(pushed, 10);
+        assert_eq!(flow.flow_on(1, 3), 10);
+        assert_eq!(flow.flow_on(1, 2), 0);
+    }
+
+    #[test]
+    fn adaptive_partitioner_balances_clustered_agents() {
+        // All agents cluster near the origin -- a uniform grid would leave
+        // most of its cells empty, but bisection should still split down
+        // to 4 roughly-equal-count leaves.
+        let positions: Vec<Vector3<f64>> = (0..100)
+            .map(|i| Vector3::new((i % 10) as f64, (i / 10) as f64, 1.0))
+            .collect();
+
+        let partitioner = AdaptivePartitioner::new(
+            Vector3::zeros(),
+            Vector3::new(1000.0, 1000.0, 100.0),
+            &positions,
+            4,
+        );
+
+        assert_eq!(partitioner.partitions().len(), 4);
+
+        let mut counts = vec![0usize; 4];
+        for pos in &positions {
+            let id = partitioner.find_partition(pos).expect("point is in bounds");
+            counts[id] += 1;
+        }
+        for count in counts {
+            assert!(
+                (20..=30).contains(&count),
+                "expected roughly balanced leaves, got counts {:?}",
+                count
+            );
+        }
+    }
+
+    #[test]
+    fn adaptive_partitioner_derives_face_adjacency() {
+        let positions: Vec<Vector3<f64>> = (0..20)
+            .map(|i| Vector3::new((i % 5) as f64, (i / 5) as f64, 1.0))
+            .collect();
+        let partitioner = AdaptivePartitioner::new(
+            Vector3::zeros(),
+            Vector3::new(100.0, 100.0, 10.0),
+            &positions,
+            4,
+        );
+
+        for partition in partitioner.partitions() {
+            assert!(
+                !partition.neighbor_partitions.is_empty(),
+                "leaf {} should border at least one sibling",
+                partition.id
+            );
+        }
+    }
+
+    #[test]
+    fn adaptive_partitioner_rebalance_tracks_moving_load() {
+        let bounds_max = Vector3::new(100.0, 100.0, 10.0);
+        let initial: Vec<Vector3<f64>> = (0..20)
+            .map(|i| Vector3::new((i % 5) as f64, (i / 5) as f64, 1.0))
+            .collect();
+        let mut partitioner = AdaptivePartitioner::new(Vector3::zeros(), bounds_max, &initial, 4);
+
+        // Load shifts to the far corner; rebalance should keep 4 leaves and
+        // still assign every shifted agent to some partition.
+        let shifted: Vec<Vector3<f64>> = (0..20)
+            .map(|i| Vector3::new(90.0 + (i % 5) as f64, 90.0 + (i / 5) as f64, 1.0))
+            .collect();
+        partitioner.rebalance(&shifted);
+
+        assert_eq!(partitioner.partitions().len(), 4);
+        for pos in &shifted {
+            assert!(partitioner.find_partition(pos).is_some());
+        }
+    }
+
+    #[test]
+    fn flags_agents_near_partition_faces() {
+        let bounds = Vector3::new(1000.0, 1000.0, 100.0);
+        let partitioner = SpatialPartitioner::new(bounds, 4);
+        let mut broad_phase = BroadPhase::new(50.0);
+
+        // Partition 0 spans x from 0 up to 500; an agent at x=495 with
+        // radius 10 pokes across into the neighboring partition and must
+        // be flagged.
+        // An agent deep in the interior should not be.
+        let agents = vec![
+            (0usize, Vector3::new(495.0, 100.0, 50.0), 10.0),
+            (1usize, Vector3::new(100.0, 100.0, 50.0), 10.0),
+        ];
+
+        let flagged = partitioner.flag_boundary_agents(&mut broad_phase, &agents);
+        let partition_0_id = partitioner
+            .find_partition(&Vector3::new(100.0, 100.0, 50.0))
+            .unwrap();
+        let flagged_ids = flagged.get(&partition_0_id).cloned().unwrap_or_default();
+
+        assert!(flagged_ids.contains(&0));
+        assert!(!flagged_ids.contains(&1));
+    }
+
+    #[test]
+    fn resolve_crossing_defaults_interior_faces_to_handoff() {
+        let bounds = Vector3::new(1000.0, 1000.0, 100.0);
+        let partitioner = SpatialPartitioner::new(bounds, 4);
+
+        let start = Vector3::new(100.0, 100.0, 50.0);
+        let origin_partition = partitioner.find_partition(&start).unwrap();
+        assert_eq!(
+            partitioner.resolve_crossing(0, start, Vector3::zeros()),
+            CrossingEvent::None
+        );
+
+        // Agent drifts across the +X face into the neighboring partition.
+        let moved = Vector3::new(510.0, 100.0, 50.0);
+        let event = partitioner.resolve_crossing(0, moved, Vector3::new(1.0, 0.0, 0.0));
+        match event {
+            CrossingEvent::Handoff {
+                agent_id,
+                from_partition,
+                to_partition,
+            } => {
+                assert_eq!(agent_id, 0);
+                assert_eq!(from_partition, origin_partition);
+                assert_ne!(to_partition, origin_partition);
+            }
+            other => panic!("expected Handoff, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn resolve_crossing_defaults_outer_bounds_to_kill() {
+        let bounds = Vector3::new(1000.0, 1000.0, 100.0);
+        let partitioner = SpatialPartitioner::new(bounds, 4);
+
+        let start = Vector3::new(100.0, 100.0, 50.0);
+        assert_eq!(
+            partitioner.resolve_crossing(1, start, Vector3::zeros()),
+            CrossingEvent::None
+        );
+
+        // Agent exits through the -X outer bound, which has no neighbor.
+        let moved = Vector3::new(-10.0, 100.0, 50.0);
+        let event = partitioner.resolve_crossing(1, moved, Vector3::new(-1.0, 0.0, 0.0));
+        assert!(matches!(
+            event,
+            CrossingEvent::Removed {
+                reason: BoundaryCondition::Kill,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn resolve_crossing_reflect_negates_normal_velocity() {
+        let bounds = Vector3::new(1000.0, 1000.0, 100.0);
+        let mut partitioner = SpatialPartitioner::new(bounds, 4);
+
+        let start = Vector3::new(100.0, 100.0, 50.0);
+        let origin_partition = partitioner.find_partition(&start).unwrap();
+        partitioner
+            .boundary_mut()
+            .set(origin_partition, Face::MinusX, BoundaryCondition::Reflect);
+        partitioner.resolve_crossing(2, start, Vector3::zeros());
+
+        let moved = Vector3::new(-5.0, 100.0, 50.0);
+        let event = partitioner.resolve_crossing(2, moved, Vector3::new(-3.0, 0.0, 0.0));
+        match event {
+            CrossingEvent::Reflected {
+                position, velocity, ..
+            } => {
+                assert!(position.x >= 0.0);
+                assert_eq!(velocity.x, 3.0);
+            }
+            other => panic!("expected Reflected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn resolve_crossing_periodic_wraps_to_opposite_bound() {
+        let bounds = Vector3::new(1000.0, 1000.0, 100.0);
+        let mut partitioner = SpatialPartitioner::new(bounds, 4);
+
+        let start = Vector3::new(100.0, 100.0, 50.0);
+        let origin_partition = partitioner.find_partition(&start).unwrap();
+        partitioner
+            .boundary_mut()
+            .set(origin_partition, Face::MinusX, BoundaryCondition::Periodic);
+        partitioner.resolve_crossing(3, start, Vector3::zeros());
+
+        let moved = Vector3::new(-5.0, 100.0, 50.0);
+        let event = partitioner.resolve_crossing(3, moved, Vector3::new(-1.0, 0.0, 0.0));
+        match event {
+            CrossingEvent::Wrapped { position, .. } => {
+                let partition = partitioner.partition(origin_partition).unwrap();
+                assert!(position.x <= partition.max_bounds.x);
+                assert!(position.x > partition.min_bounds.x);
+            }
+            other => panic!("expected Wrapped, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn resolve_crossings_filters_out_non_events() {
+        let bounds = Vector3::new(1000.0, 1000.0, 100.0);
+        let partitioner = SpatialPartitioner::new(bounds, 4);
+
+        let inside = Vector3::new(100.0, 100.0, 50.0);
+        partitioner.resolve_crossing(4, inside, Vector3::zeros());
+        partitioner.resolve_crossing(5, inside, Vector3::zeros());
+
+        let events = partitioner.resolve_crossings(&[
+            (4, inside, Vector3::zeros()),
+            (
+                5,
+                Vector3::new(-10.0, 100.0, 50.0),
+                Vector3::new(-1.0, 0.0, 0.0),
+            ),
+        ]);
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            CrossingEvent::Removed { agent_id: 5, .. }
+        ));
+    }
 }
@@ -0,0 +1,382 @@
+//! Sensor range, field-of-view, and line-of-sight for agent perception.
+//!
+//! The tactical scenario (ISR sweeps, SAM threat avoidance) has historically
+//! treated neighbor and threat lookups as omniscient -- anything in
+//! [`crate::cohesion`]'s flocking radius or `autonomysim_tactical::ai`'s
+//! threat list is visible regardless of where the observer is looking or
+//! what's between them. [`Agent::can_see`] gives each agent a real sensor
+//! model instead: a `view_distance` cone gated by field-of-view, further
+//! narrowed by [`is_occluded`]'s coarse line-of-sight test so a hill or
+//! building between observer and target actually blocks detection.
+//!
+//! [`desired_velocities_with_perception`] is [`crate::cohesion::desired_velocities`]'s
+//! counterpart for when perception is configured: the same
+//! [`crate::broadphase::BroadPhase`]-driven pairing, but a pair only
+//! contributes a flocking force when each agent can see the other, so
+//! swarm cohesion itself emerges from partial observability.
+
+use nalgebra::{Point3, Vector3};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use autonomysim_core::backend::{Ray, SceneHandle, SimResult, SimulationBackend};
+use autonomysim_tactical::ai::ThreatContact;
+
+use crate::broadphase::BroadPhase;
+use crate::cohesion::{self, LjParams, DISTANCE_FLOOR};
+
+/// Horizontal/vertical half-angles of an agent's sensor cone, in radians.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FieldOfView {
+    pub horizontal_half_angle: f64,
+    pub vertical_half_angle: f64,
+    /// `effective_half_angle().cos()`, precomputed at construction so
+    /// [`Agent::can_see`] never calls `cos` on its hot path.
+    cos_half_angle: f64,
+}
+
+impl FieldOfView {
+    /// `horizontal_half_angle`/`vertical_half_angle` are each half the
+    /// cone's full angular width along that axis, in radians.
+    pub fn new(horizontal_half_angle: f64, vertical_half_angle: f64) -> Self {
+        Self {
+            horizontal_half_angle,
+            vertical_half_angle,
+            cos_half_angle: horizontal_half_angle.min(vertical_half_angle).cos(),
+        }
+    }
+
+    /// A full sphere: every bearing passes the FOV test.
+    pub fn omnidirectional() -> Self {
+        Self::new(std::f64::consts::PI, std::f64::consts::PI)
+    }
+}
+
+/// One agent's sensing state: where it is, which way it's looking, and how
+/// far/wide it can currently see.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Agent {
+    pub position: Point3<f64>,
+    /// Forward direction the FOV cone opens along. Must be a unit vector --
+    /// `Agent::can_see` skips normalizing it to keep the hot path
+    /// trig-free.
+    pub facing: Vector3<f64>,
+    /// Un-degraded sensor range (meters), before `quality` scaling.
+    pub view_distance: f64,
+    pub fov: FieldOfView,
+    /// Sensor quality in `0.0..=1.0` -- `1.0` is full `view_distance`,
+    /// `0.0` is blind. A jammed or damaged sensor reports a lower value
+    /// here, shrinking the cone the same way a detail-falloff LOD function
+    /// shrinks render distance.
+    pub quality: f64,
+}
+
+impl Agent {
+    /// `view_distance` scaled by `quality`, clamped to `0.0..=1.0` so an
+    /// out-of-range quality value can't extend range past `view_distance`
+    /// or flip it negative.
+    pub fn effective_view_distance(&self) -> f64 {
+        self.view_distance * self.quality.clamp(0.0, 1.0)
+    }
+
+    /// Whether `other_position` is inside this agent's sensor cone:
+    /// within `effective_view_distance`, and within the FOV half-angle of
+    /// `facing`. Ignores occlusion -- pair with [`is_occluded`] for a
+    /// target that might be behind cover.
+    ///
+    /// Stays entirely in squared distances and one dot product, per the
+    /// precomputed `FieldOfView::cos_half_angle`: rather than normalizing
+    /// the bearing and calling `acos`, it compares
+    /// `dot(facing, offset)^2` against `cos_half_angle^2 * |offset|^2`,
+    /// which holds the same ordering as comparing the angle itself since
+    /// both sides are non-negative for an in-front bearing.
+    pub fn can_see(&self, other_position: Point3<f64>) -> bool {
+        let offset = other_position - self.position;
+        let distance_sq = offset.norm_squared();
+        let view_distance = self.effective_view_distance();
+        if distance_sq > view_distance * view_distance {
+            return false;
+        }
+        if distance_sq <= f64::EPSILON {
+            return true; // coincident with the observer: trivially visible
+        }
+
+        let facing_dot_offset = self.facing.dot(&offset);
+        if facing_dot_offset < 0.0 {
+            return false; // behind the agent
+        }
+
+        let cos_half_angle = self.fov.cos_half_angle;
+        facing_dot_offset * facing_dot_offset >= cos_half_angle * cos_half_angle * distance_sq
+    }
+}
+
+/// How far past `to` a ray hit has to land to still count as "reached the
+/// target" rather than "occluded by something in front of it" -- without
+/// this, the target's own geometry sitting right at `max_distance` could
+/// register as self-occlusion.
+const OCCLUSION_EPSILON: f64 = 1e-3;
+
+/// Coarse occlusion test: casts a single ray from `from` toward `to` and
+/// reports whether `backend` (typically a `WarpBackend`, but anything
+/// implementing `SimulationBackend` works) reports something in `scene`
+/// strictly nearer than `to` -- mirroring the single-ray coverage check in
+/// `examples/warp_massive_parallel.rs`'s GPU ray-casting walkthrough. `from`
+/// and `to` equal (or nearly so) report no occlusion rather than casting a
+/// zero-length ray.
+pub fn is_occluded(
+    backend: &dyn SimulationBackend,
+    scene: &SceneHandle,
+    from: Point3<f64>,
+    to: Point3<f64>,
+) -> SimResult<bool> {
+    let offset = to - from;
+    let distance = offset.norm();
+    if distance <= f64::EPSILON {
+        return Ok(false);
+    }
+
+    let ray = Ray {
+        origin: from,
+        direction: offset / distance,
+        max_distance: distance,
+    };
+    let hit = backend.cast_ray(scene, &ray)?;
+    Ok(match hit {
+        Some(hit) => hit.distance < distance - OCCLUSION_EPSILON,
+        None => false,
+    })
+}
+
+/// Tunables for `SummonerConfig::perception`: the sensor profile applied
+/// uniformly to every agent (position/facing still comes from each agent's
+/// own `VehicleState`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PerceptionConfig {
+    pub fov: FieldOfView,
+    pub view_distance: f64,
+    pub quality: f64,
+}
+
+/// `threats` filtered down to the ones `observer` can currently see, so
+/// `autonomysim_tactical::ai`'s threat-driven `BehaviorState` transitions
+/// react only to genuinely detected contacts instead of every hostile the
+/// caller happens to know about.
+pub fn visible_threats(observer: &Agent, threats: &[ThreatContact]) -> Vec<ThreatContact> {
+    threats
+        .iter()
+        .copied()
+        .filter(|threat| observer.can_see(Point3::from(threat.position)))
+        .collect()
+}
+
+/// Ids from `candidates` that `observer` can see, per [`Agent::can_see`].
+/// Occlusion-free -- fold in [`is_occluded`] against a loaded scene when a
+/// backend/scene handle is available.
+pub fn visible_ids(observer: &Agent, candidates: &HashMap<usize, Point3<f64>>) -> Vec<usize> {
+    candidates
+        .iter()
+        .filter(|&(_, &position)| observer.can_see(position))
+        .map(|(&id, _)| id)
+        .collect()
+}
+
+/// [`crate::cohesion::desired_velocities`], but a pair only contributes a
+/// Lennard-Jones force when both ends can see each other -- so an agent
+/// with a narrow or degraded sensor cone only flocks with what it can
+/// actually detect, rather than every neighbor in range.
+pub fn desired_velocities_with_perception(
+    agents: &HashMap<usize, Agent>,
+    params: &LjParams,
+) -> HashMap<usize, Vector3<f64>> {
+    let mut velocities: HashMap<usize, Vector3<f64>> =
+        agents.keys().map(|&id| (id, Vector3::zeros())).collect();
+
+    let mut broad_phase = BroadPhase::new(params.range.max(DISTANCE_FLOOR));
+    let entries: Vec<(usize, Vector3<f64>, f64)> = agents
+        .iter()
+        .map(|(&id, agent)| (id, agent.position.coords, params.range / 2.0))
+        .collect();
+    broad_phase.update(&entries);
+
+    for (a, b) in broad_phase.overlapping_pairs() {
+        let (Some(agent_a), Some(agent_b)) = (agents.get(&a), agents.get(&b)) else {
+            continue;
+        };
+        if !agent_a.can_see(agent_b.position) || !agent_b.can_see(agent_a.position) {
+            continue;
+        }
+
+        let offset = agent_b.position - agent_a.position;
+        let distance = offset.norm();
+        if distance > params.range {
+            continue;
+        }
+        let distance = distance.max(DISTANCE_FLOOR);
+        let magnitude = cohesion::lj_force_magnitude(params.epsilon, params.target, distance);
+
+        let theta = offset.y.atan2(offset.x);
+        let contribution = Vector3::new(magnitude * theta.cos(), magnitude * theta.sin(), 0.0);
+        *velocities.get_mut(&a).expect("a is an agents key") += contribution;
+        *velocities.get_mut(&b).expect("b is an agents key") -= contribution;
+    }
+
+    if let Some(max_speed) = params.max_speed {
+        for velocity in velocities.values_mut() {
+            if velocity.norm() > max_speed {
+                *velocity = velocity.normalize() * max_speed;
+            }
+        }
+    }
+
+    velocities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(position: Point3<f64>, facing: Vector3<f64>) -> Agent {
+        Agent {
+            position,
+            facing,
+            view_distance: 20.0,
+            fov: FieldOfView::new(
+                std::f64::consts::FRAC_PI_4,
+                std::f64::consts::FRAC_PI_4,
+            ),
+            quality: 1.0,
+        }
+    }
+
+    #[test]
+    fn sees_a_target_dead_ahead_in_range() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        assert!(observer.can_see(Point3::new(5.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn does_not_see_past_view_distance() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        assert!(!observer.can_see(Point3::new(50.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn does_not_see_outside_the_fov_cone() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        assert!(!observer.can_see(Point3::new(0.0, 5.0, 0.0)));
+    }
+
+    #[test]
+    fn does_not_see_directly_behind() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        assert!(!observer.can_see(Point3::new(-5.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn sees_coincident_position() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        assert!(observer.can_see(Point3::origin()));
+    }
+
+    #[test]
+    fn degraded_quality_shrinks_the_effective_view_distance() {
+        let mut observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        observer.quality = 0.1;
+        assert!(!observer.can_see(Point3::new(5.0, 0.0, 0.0)));
+        assert_eq!(observer.effective_view_distance(), 2.0);
+    }
+
+    #[test]
+    fn omnidirectional_fov_sees_every_bearing_in_range() {
+        let observer = Agent {
+            fov: FieldOfView::omnidirectional(),
+            ..agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0))
+        };
+        assert!(observer.can_see(Point3::new(0.0, 5.0, 0.0)));
+        assert!(observer.can_see(Point3::new(-5.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn visible_ids_filters_out_of_cone_candidates() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        let mut candidates = HashMap::new();
+        candidates.insert(1usize, Point3::new(5.0, 0.0, 0.0)); // ahead, visible
+        candidates.insert(2usize, Point3::new(-5.0, 0.0, 0.0)); // behind, hidden
+        candidates.insert(3usize, Point3::new(50.0, 0.0, 0.0)); // too far, hidden
+
+        let mut visible = visible_ids(&observer, &candidates);
+        visible.sort_unstable();
+        assert_eq!(visible, vec![1]);
+    }
+
+    #[test]
+    fn mutual_occlusion_blocks_the_flocking_pair_in_desired_velocities_with_perception() {
+        let mut agents = HashMap::new();
+        agents.insert(
+            0usize,
+            agent(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0)),
+        );
+        // Agent 1 is behind agent 0's facing direction, so agent 0 can't
+        // see it even though it's within flocking range.
+        agents.insert(
+            1usize,
+            agent(Point3::new(-1.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0)),
+        );
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities_with_perception(&agents, &params);
+
+        assert_eq!(velocities[&0], Vector3::zeros());
+        assert_eq!(velocities[&1], Vector3::zeros());
+    }
+
+    #[test]
+    fn visible_threats_drops_contacts_outside_the_cone() {
+        let observer = agent(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+        let threats = [
+            ThreatContact {
+                position: Vector3::new(5.0, 0.0, 0.0),
+                priority: 1.0,
+            },
+            ThreatContact {
+                position: Vector3::new(-5.0, 0.0, 0.0),
+                priority: 2.0,
+            },
+        ];
+
+        let visible = visible_threats(&observer, &threats);
+
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].position, Vector3::new(5.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn mutually_visible_pair_flocks_in_desired_velocities_with_perception() {
+        let mut agents = HashMap::new();
+        agents.insert(
+            0usize,
+            agent(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0)),
+        );
+        agents.insert(
+            1usize,
+            agent(Point3::new(1.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0)),
+        );
+        let params = LjParams {
+            epsilon: 1.0,
+            target: 5.0,
+            range: 20.0,
+            max_speed: None,
+        };
+
+        let velocities = desired_velocities_with_perception(&agents, &params);
+
+        assert!(velocities[&0].x < 0.0, "too close: agent 0 should back away");
+        assert!(velocities[&1].x > 0.0, "too close: agent 1 should back away");
+    }
+}
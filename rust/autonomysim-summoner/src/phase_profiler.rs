@@ -0,0 +1,118 @@
+//! Per-phase step profiling.
+//!
+//! `Worker::execute_step` times its physics/sensors/communications/control
+//! sub-phases and returns them as [`PhaseTimings`]; `Summoner::step` folds
+//! every worker's timings for the tick into a [`PhaseProfiler`], which keeps
+//! a running average per phase so `SummonerMetrics::phase_timings` can show
+//! *where* a tick's time goes instead of only the aggregate step time.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A phase's most recent duration and running average, in milliseconds.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize)]
+pub struct PhaseTiming {
+    pub last_ms: f64,
+    pub avg_ms: f64,
+}
+
+impl PhaseTiming {
+    fn record(&mut self, duration: Duration, sample_count: u64) {
+        let ms = duration.as_secs_f64() * 1000.0;
+        self.last_ms = ms;
+        self.avg_ms += (ms - self.avg_ms) / sample_count as f64;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct PhaseStats {
+    timing: PhaseTiming,
+    sample_count: u64,
+}
+
+/// Accumulates per-phase timing across every worker's `execute_step` call,
+/// keyed by phase name. Each call's durations are folded directly into that
+/// phase's running average, so with multiple workers the result is the
+/// average phase duration per worker-step rather than a per-tick total.
+#[derive(Debug, Clone, Default)]
+pub struct PhaseProfiler {
+    phases: HashMap<&'static str, PhaseStats>,
+}
+
+impl PhaseProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one worker's per-phase durations for the current tick into the
+    /// running stats.
+    pub fn record<I: IntoIterator<Item = (&'static str, Duration)>>(&mut self, timings: I) {
+        for (phase, duration) in timings {
+            let stats = self.phases.entry(phase).or_default();
+            stats.sample_count += 1;
+            let sample_count = stats.sample_count;
+            stats.timing.record(duration, sample_count);
+        }
+    }
+
+    /// Snapshot of `(last duration ms, average duration ms)` per phase,
+    /// suitable for `SummonerMetrics::phase_timings`.
+    pub fn snapshot(&self) -> HashMap<String, PhaseTiming> {
+        self.phases
+            .iter()
+            .map(|(&name, stats)| (name.to_string(), stats.timing))
+            .collect()
+    }
+
+    /// Snapshot the current per-phase stats and reset them, so a caller can
+    /// measure phase timing over a bounded window (e.g. "the first 10% of a
+    /// benchmark run") by checkpointing before and after that window.
+    pub fn checkpoint(&mut self) -> HashMap<String, PhaseTiming> {
+        let snapshot = self.snapshot();
+        self.phases.clear();
+        snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_last_and_running_average() {
+        let mut profiler = PhaseProfiler::new();
+        profiler.record([("physics", Duration::from_millis(10))]);
+        profiler.record([("physics", Duration::from_millis(20))]);
+
+        let snapshot = profiler.snapshot();
+        assert_eq!(snapshot["physics"].last_ms, 20.0);
+        assert!((snapshot["physics"].avg_ms - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn checkpoint_resets_but_returns_prior_snapshot() {
+        let mut profiler = PhaseProfiler::new();
+        profiler.record([("sensors", Duration::from_millis(5))]);
+
+        let checkpoint = profiler.checkpoint();
+        assert_eq!(checkpoint["sensors"].last_ms, 5.0);
+        assert!(profiler.snapshot().is_empty());
+
+        profiler.record([("sensors", Duration::from_millis(7))]);
+        assert_eq!(profiler.snapshot()["sensors"].avg_ms, 7.0); // fresh average after reset
+    }
+
+    #[test]
+    fn independent_phases_track_separately() {
+        let mut profiler = PhaseProfiler::new();
+        profiler.record([
+            ("physics", Duration::from_millis(10)),
+            ("sensors", Duration::from_millis(2)),
+        ]);
+
+        let snapshot = profiler.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot["physics"].last_ms, 10.0);
+        assert_eq!(snapshot["sensors"].last_ms, 2.0);
+    }
+}
@@ -0,0 +1,554 @@
+//! ROS2 bridge: publishes VehicleState, TF frames, and sensor topics
+//!
+//! Turns `Worker::simulate_sensors` output into standard ROS2 topics so the
+//! simulator is a drop-in source for ROS-based perception/navigation
+//! stacks. This module models the wire-level message shapes
+//! (`sensor_msgs/Imu`, `sensor_msgs/NavSatFix`, `sensor_msgs/PointCloud2`,
+//! `tf2_msgs/TFMessage`) without depending on an rclrust runtime, so it can
+//! be wired to any DDS/rosbridge transport.
+//!
+//! [`swarm_tf_frames`], [`debug_to_marker_array`], and
+//! [`telemetry_to_diagnostics`] additionally give the UE5 rendering path
+//! (`autonomysim_backends::unreal`) a same-state rviz equivalent: a swarm
+//! rendered in UE5 shows up identically in rviz, and vice versa.
+
+use autonomysim_backends::unreal::{
+    DebugArrow, DebugLine, DebugSphere, DebugString, RobotPositionUpdate, RobotTelemetry,
+};
+use autonomysim_core::backend::Transform;
+use autonomysim_core::sensor::{ImuData, LidarData};
+use autonomysim_core::vehicle::VehicleState;
+use nalgebra::{Point3, UnitQuaternion, Vector3};
+use std::collections::HashMap;
+
+/// `geometry_msgs/TransformStamped`-equivalent.
+#[derive(Debug, Clone)]
+pub struct TfFrame {
+    pub parent_frame: String,
+    pub child_frame: String,
+    pub transform: Transform,
+    pub stamp: f64,
+}
+
+/// `sensor_msgs/Imu`-equivalent.
+#[derive(Debug, Clone)]
+pub struct RosImu {
+    pub frame_id: String,
+    pub stamp: f64,
+    pub orientation: UnitQuaternion<f64>,
+    pub angular_velocity: nalgebra::Vector3<f64>,
+    pub linear_acceleration: nalgebra::Vector3<f64>,
+}
+
+/// `sensor_msgs/NavSatFix`-equivalent.
+#[derive(Debug, Clone)]
+pub struct RosNavSatFix {
+    pub frame_id: String,
+    pub stamp: f64,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+}
+
+/// `sensor_msgs/PointCloud2`-equivalent, restricted to the fields this
+/// bridge actually populates (xyz + intensity, one point per row).
+#[derive(Debug, Clone)]
+pub struct RosPointCloud2 {
+    pub frame_id: String,
+    pub stamp: f64,
+    pub points: Vec<[f32; 3]>,
+    pub intensities: Vec<f32>,
+}
+
+impl RosPointCloud2 {
+    fn from_lidar(frame_id: String, data: &LidarData) -> Self {
+        let mut points = Vec::with_capacity(data.points.len());
+        let mut intensities = Vec::with_capacity(data.points.len());
+        for p in &data.points {
+            points.push([
+                p.position.x as f32,
+                p.position.y as f32,
+                p.position.z as f32,
+            ]);
+            intensities.push(p.intensity);
+        }
+        Self {
+            frame_id,
+            stamp: data.timestamp,
+            points,
+            intensities,
+        }
+    }
+}
+
+/// One batch of topic publications for a single simulation step, all
+/// sharing the same `stamp` so downstream TF lookups stay consistent while
+/// the vehicle moves.
+#[derive(Debug, Clone, Default)]
+pub struct RosPublishBatch {
+    pub tf_frames: Vec<TfFrame>,
+    pub imu: Vec<RosImu>,
+    pub nav_sat_fix: Vec<RosNavSatFix>,
+    pub point_clouds: Vec<RosPointCloud2>,
+}
+
+/// Per-sensor rate limiter state, tracking the sim time each sensor last
+/// published at so it can be throttled to `SensorSpec.update_rate_hz`.
+#[derive(Debug, Default)]
+struct RateLimiter {
+    last_publish: HashMap<String, f64>,
+}
+
+impl RateLimiter {
+    fn should_publish(&mut self, sensor_id: &str, now: f64, update_rate_hz: f64) -> bool {
+        let period = if update_rate_hz > 0.0 {
+            1.0 / update_rate_hz
+        } else {
+            0.0
+        };
+        let last = self.last_publish.get(sensor_id).copied();
+        let due = match last {
+            Some(last) => now - last >= period,
+            None => true,
+        };
+        if due {
+            self.last_publish.insert(sensor_id.to_string(), now);
+        }
+        due
+    }
+}
+
+/// Publishes AutonomySim simulation data onto ROS2-shaped topics.
+pub struct Ros2Bridge {
+    rate_limiter: RateLimiter,
+}
+
+impl Ros2Bridge {
+    pub fn new() -> Self {
+        Self {
+            rate_limiter: RateLimiter::default(),
+        }
+    }
+
+    /// Build this step's publish batch for one vehicle.
+    ///
+    /// `sensor_offsets` mirrors `VehicleParameters.sensor_offsets`: a
+    /// per-sensor static transform from the vehicle frame, used to build
+    /// the `world -> vehicle -> sensor` TF tree. `sensor_rates_hz` mirrors
+    /// `SensorSpec.update_rate_hz` for the sensors that produced data this
+    /// step. All messages share `state.timestamp` as their stamp.
+    pub fn publish_step(
+        &mut self,
+        state: &VehicleState,
+        sensor_offsets: &HashMap<String, Transform>,
+        sensor_rates_hz: &HashMap<String, f64>,
+        imu: Option<&ImuData>,
+        gps: Option<&autonomysim_core::sensor::GpsData>,
+        lidar: Option<(&str, &LidarData)>,
+    ) -> RosPublishBatch {
+        let stamp = state.timestamp;
+        let vehicle_frame = format!("{}/base_link", state.vehicle_id);
+        let mut batch = RosPublishBatch::default();
+
+        batch.tf_frames.push(TfFrame {
+            parent_frame: "world".to_string(),
+            child_frame: vehicle_frame.clone(),
+            transform: state.transform.clone(),
+            stamp,
+        });
+
+        for (sensor_id, offset) in sensor_offsets {
+            batch.tf_frames.push(TfFrame {
+                parent_frame: vehicle_frame.clone(),
+                child_frame: format!("{}/{}", state.vehicle_id, sensor_id),
+                transform: offset.clone(),
+                stamp,
+            });
+        }
+
+        if let Some(imu) = imu {
+            let rate = sensor_rates_hz.get("imu").copied().unwrap_or(0.0);
+            if self.rate_limiter.should_publish("imu", stamp, rate) {
+                batch.imu.push(RosImu {
+                    frame_id: format!("{}/imu", state.vehicle_id),
+                    stamp,
+                    orientation: imu.orientation,
+                    angular_velocity: imu.angular_velocity,
+                    linear_acceleration: imu.linear_acceleration,
+                });
+            }
+        }
+
+        if let Some(gps) = gps {
+            let rate = sensor_rates_hz.get("gps").copied().unwrap_or(0.0);
+            if self.rate_limiter.should_publish("gps", stamp, rate) {
+                batch.nav_sat_fix.push(RosNavSatFix {
+                    frame_id: format!("{}/gps", state.vehicle_id),
+                    stamp,
+                    latitude: gps.latitude,
+                    longitude: gps.longitude,
+                    altitude: gps.altitude,
+                });
+            }
+        }
+
+        if let Some((sensor_id, lidar)) = lidar {
+            let rate = sensor_rates_hz.get(sensor_id).copied().unwrap_or(0.0);
+            if self.rate_limiter.should_publish(sensor_id, stamp, rate) {
+                batch.point_clouds.push(RosPointCloud2::from_lidar(
+                    format!("{}/{}", state.vehicle_id, sensor_id),
+                    lidar,
+                ));
+            }
+        }
+
+        batch
+    }
+}
+
+impl Default for Ros2Bridge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Map every `RobotPositionUpdate` UE5 receives onto a `map -> robot_<id>`
+/// TF frame, so a swarm renders at the same pose in rviz as in UE5.
+pub fn swarm_tf_frames(positions: &[RobotPositionUpdate], stamp: f64) -> Vec<TfFrame> {
+    positions
+        .iter()
+        .map(|update| TfFrame {
+            parent_frame: "map".to_string(),
+            child_frame: format!("robot_{}", update.id),
+            transform: Transform::new(
+                Point3::new(update.x, update.y, update.z),
+                UnitQuaternion::from_euler_angles(update.roll, update.pitch, update.yaw),
+            ),
+            stamp,
+        })
+        .collect()
+}
+
+/// `visualization_msgs/Marker`-equivalent primitive shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerShape {
+    LineList,
+    Sphere,
+    TextViewFacing,
+}
+
+/// `visualization_msgs/Marker`-equivalent.
+#[derive(Debug, Clone)]
+pub struct RosMarker {
+    pub id: i32,
+    pub frame_id: String,
+    pub shape: MarkerShape,
+    pub points: Vec<[f64; 3]>,
+    pub text: String,
+    pub scale: [f32; 3],
+    pub color: [u8; 4],
+    /// Seconds until rviz expires the marker; `0.0` means forever, matching
+    /// `visualization_msgs/Marker.lifetime` -- the same convention
+    /// `is_persistent` already uses for the UE5 side of these primitives.
+    pub lifetime_s: f32,
+}
+
+/// Translate the same `DrawDebugLines`/`DrawDebugSpheres`/`DrawDebugStrings`
+/// primitives UE5 renders into a `visualization_msgs/MarkerArray`-equivalent,
+/// so a scene looks identical in rviz. IDs are assigned in call order, so
+/// callers that need stable per-primitive IDs across frames should keep
+/// primitive order stable too.
+pub fn debug_to_marker_array(
+    frame_id: &str,
+    lines: &[DebugLine],
+    spheres: &[DebugSphere],
+    strings: &[DebugString],
+) -> Vec<RosMarker> {
+    let mut markers = Vec::with_capacity(lines.len() + spheres.len() + strings.len());
+    let mut next_id = 0;
+
+    for line in lines {
+        markers.push(RosMarker {
+            id: next_id,
+            frame_id: frame_id.to_string(),
+            shape: MarkerShape::LineList,
+            points: vec![line.start, line.end],
+            text: String::new(),
+            scale: [line.thickness, 0.0, 0.0],
+            color: line.color,
+            lifetime_s: if line.is_persistent {
+                0.0
+            } else {
+                line.duration
+            },
+        });
+        next_id += 1;
+    }
+
+    for sphere in spheres {
+        let diameter = sphere.radius as f32 * 2.0;
+        markers.push(RosMarker {
+            id: next_id,
+            frame_id: frame_id.to_string(),
+            shape: MarkerShape::Sphere,
+            points: vec![sphere.center],
+            text: String::new(),
+            scale: [diameter, diameter, diameter],
+            color: sphere.color,
+            lifetime_s: if sphere.is_persistent {
+                0.0
+            } else {
+                sphere.duration
+            },
+        });
+        next_id += 1;
+    }
+
+    for string in strings {
+        markers.push(RosMarker {
+            id: next_id,
+            frame_id: frame_id.to_string(),
+            shape: MarkerShape::TextViewFacing,
+            points: vec![string.position],
+            text: string.text.clone(),
+            scale: [string.scale, string.scale, string.scale],
+            color: string.color,
+            lifetime_s: if string.is_persistent {
+                0.0
+            } else {
+                string.duration
+            },
+        });
+        next_id += 1;
+    }
+
+    markers
+}
+
+/// `diagnostic_msgs/DiagnosticStatus`-equivalent severity level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+    Ok,
+    Warn,
+    Error,
+}
+
+/// `diagnostic_msgs/DiagnosticStatus`-equivalent, meant to be published on a
+/// latched `/diagnostics` topic so rviz's Diagnostics display always shows
+/// the most recent battery/health/jamming state, even for a display that
+/// only connects mid-run.
+#[derive(Debug, Clone)]
+pub struct RosDiagnosticStatus {
+    pub name: String,
+    pub level: DiagnosticLevel,
+    pub message: String,
+    pub values: Vec<(String, String)>,
+}
+
+/// Translate a batch of `RobotTelemetry` into diagnostics entries.
+pub fn telemetry_to_diagnostics(telemetry: &[RobotTelemetry]) -> Vec<RosDiagnosticStatus> {
+    telemetry
+        .iter()
+        .map(|t| {
+            let health = t.damage.health_fraction();
+            let (level, message) = if t.damage.is_destroyed() || !t.is_active {
+                (DiagnosticLevel::Error, "destroyed".to_string())
+            } else if t.is_jammed {
+                (DiagnosticLevel::Warn, "radio jammed".to_string())
+            } else if health < 0.5 {
+                (DiagnosticLevel::Warn, "damaged".to_string())
+            } else {
+                (DiagnosticLevel::Ok, "nominal".to_string())
+            };
+
+            RosDiagnosticStatus {
+                name: format!("robot_{}", t.id),
+                level,
+                message,
+                values: vec![
+                    (
+                        "battery_percent".to_string(),
+                        format!("{:.1}", t.battery_percent),
+                    ),
+                    ("health_fraction".to_string(), format!("{:.2}", health)),
+                    (
+                        "signal_strength_dbm".to_string(),
+                        format!("{:.1}", t.signal_strength_dbm),
+                    ),
+                    ("is_jammed".to_string(), t.is_jammed.to_string()),
+                ],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_backends::unreal::DamageState;
+    use autonomysim_core::sensor::{GpsFixType, LidarPoint};
+    use nalgebra::{Point3, Vector3};
+
+    fn sample_state() -> VehicleState {
+        VehicleState {
+            vehicle_id: "drone0".to_string(),
+            timestamp: 1.0,
+            transform: Transform::new(Point3::new(1.0, 2.0, 3.0), UnitQuaternion::identity()),
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        }
+    }
+
+    #[test]
+    fn tf_tree_includes_vehicle_and_sensor_frames() {
+        let mut bridge = Ros2Bridge::new();
+        let mut offsets = HashMap::new();
+        offsets.insert("camera0".to_string(), Transform::identity());
+
+        let batch =
+            bridge.publish_step(&sample_state(), &offsets, &HashMap::new(), None, None, None);
+        assert_eq!(batch.tf_frames.len(), 2);
+        assert!(batch
+            .tf_frames
+            .iter()
+            .any(|f| f.child_frame == "drone0/base_link"));
+        assert!(batch
+            .tf_frames
+            .iter()
+            .any(|f| f.child_frame == "drone0/camera0"));
+    }
+
+    #[test]
+    fn sensor_rate_limiting_skips_until_period_elapsed() {
+        let mut bridge = Ros2Bridge::new();
+        let mut rates = HashMap::new();
+        rates.insert("gps".to_string(), 1.0); // 1 Hz
+
+        let gps = autonomysim_core::sensor::GpsData {
+            timestamp: 0.0,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: 0.0,
+            velocity: Vector3::zeros(),
+            eph: 1.0,
+            epv: 1.0,
+            fix_type: GpsFixType::Fix3D,
+        };
+
+        let mut state = sample_state();
+        state.timestamp = 0.0;
+        let batch = bridge.publish_step(&state, &HashMap::new(), &rates, None, Some(&gps), None);
+        assert_eq!(batch.nav_sat_fix.len(), 1);
+
+        state.timestamp = 0.1; // within the 1s period, should be throttled
+        let batch = bridge.publish_step(&state, &HashMap::new(), &rates, None, Some(&gps), None);
+        assert_eq!(batch.nav_sat_fix.len(), 0);
+
+        state.timestamp = 1.2; // period elapsed
+        let batch = bridge.publish_step(&state, &HashMap::new(), &rates, None, Some(&gps), None);
+        assert_eq!(batch.nav_sat_fix.len(), 1);
+    }
+
+    #[test]
+    fn lidar_point_cloud_carries_intensity_channel() {
+        let mut bridge = Ros2Bridge::new();
+        let lidar = LidarData {
+            timestamp: 0.0,
+            points: vec![LidarPoint {
+                position: Point3::new(1.0, 0.0, 0.0),
+                intensity: 0.5,
+                range: 1.0,
+                ring: 0,
+            }],
+            pose: Point3::origin(),
+        };
+
+        let batch = bridge.publish_step(
+            &sample_state(),
+            &HashMap::new(),
+            &HashMap::new(),
+            None,
+            None,
+            Some(("lidar0", &lidar)),
+        );
+        assert_eq!(batch.point_clouds.len(), 1);
+        assert_eq!(batch.point_clouds[0].intensities, vec![0.5]);
+    }
+
+    #[test]
+    fn swarm_tf_frames_use_map_to_robot_id_naming() {
+        let positions = vec![RobotPositionUpdate {
+            id: 7,
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            yaw: 0.0,
+            pitch: 0.0,
+            roll: 0.0,
+        }];
+
+        let frames = swarm_tf_frames(&positions, 1.0);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].parent_frame, "map");
+        assert_eq!(frames[0].child_frame, "robot_7");
+    }
+
+    #[test]
+    fn debug_primitives_translate_to_matching_marker_shapes() {
+        let lines = vec![DebugLine {
+            start: [0.0, 0.0, 0.0],
+            end: [1.0, 0.0, 0.0],
+            color: [255, 0, 0, 255],
+            thickness: 2.0,
+            duration: 0.0,
+            is_persistent: true,
+        }];
+        let spheres = vec![DebugSphere {
+            center: [0.0, 0.0, 0.0],
+            radius: 2.0,
+            color: [0, 255, 0, 255],
+            duration: 1.0,
+            is_persistent: false,
+        }];
+        let strings = vec![DebugString {
+            text: "robot_0".to_string(),
+            position: [0.0, 0.0, 1.0],
+            scale: 0.5,
+            color: [0, 0, 255, 255],
+            duration: 1.0,
+            is_persistent: false,
+        }];
+
+        let markers = debug_to_marker_array("map", &lines, &spheres, &strings);
+        assert_eq!(markers.len(), 3);
+        assert_eq!(markers[0].shape, MarkerShape::LineList);
+        assert_eq!(markers[0].lifetime_s, 0.0); // persistent -> forever
+        assert_eq!(markers[1].shape, MarkerShape::Sphere);
+        assert_eq!(markers[1].scale, [4.0, 4.0, 4.0]);
+        assert_eq!(markers[2].shape, MarkerShape::TextViewFacing);
+        assert_eq!(markers[2].text, "robot_0");
+    }
+
+    #[test]
+    fn telemetry_with_destroyed_damage_reports_error_level() {
+        let telemetry = vec![RobotTelemetry {
+            id: 3,
+            battery_percent: 10.0,
+            damage: DamageState::hitpoints(0.0),
+            signal_strength_dbm: -90.0,
+            is_jammed: false,
+            is_active: true,
+            current_task: None,
+        }];
+
+        let diagnostics = telemetry_to_diagnostics(&telemetry);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].name, "robot_3");
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Error);
+    }
+}
@@ -1,6 +1,19 @@
 //! Task scheduler for worker load balancing
+//!
+//! Each worker owns its own deque of [`Task`]s instead of all workers sharing
+//! one queue. A worker drains its own backlog LIFO (the task it just split
+//! off is usually still hot in cache) and, only once that backlog is dry,
+//! steals FIFO from the opposite end of a random victim's deque -- the usual
+//! Chase-Lev split that keeps a thief from fighting the owner over the same
+//! end of the queue. [`Scheduler::barrier`] additionally lets workers
+//! rendezvous between simulation steps without waiting forever on a worker
+//! that died or stalled.
 
+use parking_lot::{Condvar, Mutex};
+use rand::Rng;
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 /// Task type for distributed execution
 #[derive(Debug, Clone)]
@@ -30,39 +43,154 @@ pub enum Task {
     },
 }
 
-/// Work-stealing scheduler for dynamic load balancing
+/// Wall-clock budget allotted to a single barrier wait-step; see
+/// [`Scheduler::barrier`].
+const BARRIER_STEP_DURATION: Duration = Duration::from_millis(16);
+
+/// Work-stealing scheduler for dynamic load balancing.
+///
+/// Holds one deque per worker rather than a single shared queue, so a worker
+/// servicing its own backlog never contends with anyone else; contention
+/// only happens when a thief steals from a victim whose queue has gone dry.
 pub struct Scheduler {
-    task_queue: VecDeque<Task>,
+    deques: Vec<Mutex<VecDeque<Task>>>,
     num_workers: usize,
+    /// Count of workers that found no local work, attempted (and failed) to
+    /// steal from every other worker, and are not currently mid-steal.
+    /// [`Self::is_idle`] is only true once this reaches `num_workers`.
+    idle_workers: AtomicUsize,
+    barrier_state: Mutex<BarrierState>,
+    barrier_cond: Condvar,
+}
+
+struct BarrierState {
+    /// Bumped every time the barrier releases, so a waiter that was woken by
+    /// an earlier (already-released) generation knows to stop waiting rather
+    /// than re-block on a barrier nobody is arriving at anymore.
+    generation: u64,
+    ready: usize,
 }
 
 impl Scheduler {
-    /// Create new scheduler
+    /// Create a new scheduler with one deque per worker
     pub fn new(num_workers: usize) -> Self {
         Self {
-            task_queue: VecDeque::new(),
-            num_workers,
+            deques: (0..num_workers.max(1))
+                .map(|_| Mutex::new(VecDeque::new()))
+                .collect(),
+            num_workers: num_workers.max(1),
+            idle_workers: AtomicUsize::new(num_workers.max(1)),
+            barrier_state: Mutex::new(BarrierState {
+                generation: 0,
+                ready: 0,
+            }),
+            barrier_cond: Condvar::new(),
         }
     }
 
-    /// Add task to queue
-    pub fn submit(&mut self, task: Task) {
-        self.task_queue.push_back(task);
+    /// Submit a task onto `worker_id`'s own queue
+    pub fn submit(&self, worker_id: usize, task: Task) {
+        self.deques[worker_id % self.num_workers]
+            .lock()
+            .push_back(task);
+        self.idle_workers.store(0, Ordering::SeqCst);
     }
 
-    /// Get next task (work stealing)
-    pub fn get_task(&mut self) -> Option<Task> {
-        self.task_queue.pop_front()
+    /// Pop the next task for `worker_id`: its own queue first (LIFO, from
+    /// the back), falling back to stealing FIFO from the front of a random
+    /// other worker's queue if its own queue is empty.
+    ///
+    /// Marks the worker idle (see [`Self::is_idle`]) when neither its own
+    /// queue nor any victim yields a task.
+    pub fn get_task(&self, worker_id: usize) -> Option<Task> {
+        if let Some(task) = self.deques[worker_id % self.num_workers].lock().pop_back() {
+            self.idle_workers.store(0, Ordering::SeqCst);
+            return Some(task);
+        }
+
+        if let Some(task) = self.steal(worker_id) {
+            self.idle_workers.store(0, Ordering::SeqCst);
+            return Some(task);
+        }
+
+        self.idle_workers.fetch_add(1, Ordering::SeqCst);
+        None
     }
 
-    /// Check if scheduler is idle
+    /// Try to steal one task from the front of every other worker's queue,
+    /// starting at a random victim so repeated steals don't all pile onto
+    /// worker 0
+    fn steal(&self, worker_id: usize) -> Option<Task> {
+        if self.num_workers <= 1 {
+            return None;
+        }
+
+        let start = rand::thread_rng().gen_range(0..self.num_workers);
+        for offset in 0..self.num_workers {
+            let victim = (start + offset) % self.num_workers;
+            if victim == worker_id {
+                continue;
+            }
+            if let Some(task) = self.deques[victim].lock().pop_front() {
+                return Some(task);
+            }
+        }
+        None
+    }
+
+    /// Check if scheduler is idle: true once every worker's queue is
+    /// drained and every worker has already failed a steal attempt this
+    /// round -- i.e. nobody is mid-steal holding a task that just hasn't
+    /// been counted yet
     pub fn is_idle(&self) -> bool {
-        self.task_queue.is_empty()
+        self.idle_workers.load(Ordering::SeqCst) >= self.num_workers
     }
 
-    /// Get queue length
+    /// Get total queue length across all workers
     pub fn queue_len(&self) -> usize {
-        self.task_queue.len()
+        self.deques.iter().map(|deque| deque.lock().len()).sum()
+    }
+
+    /// Block until `threshold` workers have called `barrier` for the current
+    /// generation, or until `timeout_steps` wait-steps
+    /// (`timeout_steps * BARRIER_STEP_DURATION`) have elapsed, whichever
+    /// comes first.
+    ///
+    /// On a normal release, returns `true`. On timeout, the thread that
+    /// observes it runs `on_timeout` exactly once and forces the release
+    /// anyway, returning `false` to every waiter so the caller can log or
+    /// otherwise account for the straggler before moving on to the next
+    /// step -- a barrier that never learned to give up would let one dead
+    /// worker wedge the whole fleet.
+    pub fn barrier(&self, threshold: usize, timeout_steps: u32, on_timeout: impl FnOnce()) -> bool {
+        let mut state = self.barrier_state.lock();
+        let my_generation = state.generation;
+        state.ready += 1;
+
+        if state.ready >= threshold {
+            state.generation += 1;
+            state.ready = 0;
+            self.barrier_cond.notify_all();
+            return true;
+        }
+
+        let deadline = BARRIER_STEP_DURATION * timeout_steps.max(1);
+        let result = self.barrier_cond.wait_for(&mut state, deadline, |state| {
+            state.generation != my_generation
+        });
+
+        if state.generation != my_generation {
+            return true;
+        }
+
+        if result.timed_out() {
+            on_timeout();
+            state.generation += 1;
+            state.ready = 0;
+            self.barrier_cond.notify_all();
+        }
+
+        false
     }
 }
 
@@ -72,22 +200,67 @@ mod tests {
 
     #[test]
     fn test_scheduler() {
-        let mut scheduler = Scheduler::new(4);
+        let scheduler = Scheduler::new(4);
 
-        scheduler.submit(Task::Physics {
-            agent_start: 0,
-            agent_end: 100,
-        });
-        scheduler.submit(Task::Sensors {
-            agent_start: 0,
-            agent_end: 100,
-        });
+        scheduler.submit(
+            0,
+            Task::Physics {
+                agent_start: 0,
+                agent_end: 100,
+            },
+        );
+        scheduler.submit(
+            0,
+            Task::Sensors {
+                agent_start: 0,
+                agent_end: 100,
+            },
+        );
 
         assert_eq!(scheduler.queue_len(), 2);
         assert!(!scheduler.is_idle());
 
-        let task = scheduler.get_task();
+        let task = scheduler.get_task(0);
         assert!(task.is_some());
         assert_eq!(scheduler.queue_len(), 1);
     }
+
+    #[test]
+    fn steals_from_other_workers_when_own_queue_empty() {
+        let scheduler = Scheduler::new(2);
+        scheduler.submit(
+            1,
+            Task::AI {
+                agent_start: 0,
+                agent_end: 10,
+            },
+        );
+
+        let stolen = scheduler.get_task(0);
+        assert!(matches!(stolen, Some(Task::AI { .. })));
+        assert!(scheduler.is_idle());
+    }
+
+    #[test]
+    fn barrier_releases_once_threshold_reached() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let scheduler = Arc::new(Scheduler::new(2));
+        let other = scheduler.clone();
+        let handle = thread::spawn(move || other.barrier(2, 100, || {}));
+
+        let released = scheduler.barrier(2, 100, || {});
+        assert!(released);
+        assert!(handle.join().unwrap());
+    }
+
+    #[test]
+    fn barrier_times_out_and_fires_callback_when_threshold_unreachable() {
+        let scheduler = Scheduler::new(4);
+        let fired = std::sync::atomic::AtomicBool::new(false);
+        let released = scheduler.barrier(4, 1, || fired.store(true, Ordering::SeqCst));
+        assert!(!released);
+        assert!(fired.load(Ordering::SeqCst));
+    }
 }
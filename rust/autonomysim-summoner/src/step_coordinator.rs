@@ -0,0 +1,442 @@
+//! Heartbeat-driven lock-step barrier over [`MessageBus`]
+//!
+//! `NodeMessage` already carries `Heartbeat`, `StepComplete`, and
+//! `StepCommand`, but nothing ties them into an actual distributed step
+//! barrier -- [`StepCoordinator`] is that: `run_step` broadcasts
+//! `StepCommand{step, dt}` then blocks until every live worker has replied
+//! with a matching `StepComplete`. Liveness is tracked independently via
+//! `Heartbeat`, not `StepComplete`, so a worker that's merely slow on one
+//! step isn't declared dead -- only one that misses
+//! `missed_heartbeats_to_die` consecutive liveness checks is. When that
+//! happens mid-step, its partitions are handed to whichever of their
+//! `neighbor_partitions` a live worker still owns, and the barrier releases
+//! for the survivors instead of hanging forever.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use tokio::sync::{mpsc, Mutex};
+use tracing::{info, warn};
+
+use crate::communication::BufferedMessage;
+use crate::partition::Partition;
+use crate::{MessageBus, NodeMessage, TraceContext};
+
+/// Liveness of one worker as tracked by a [`StepCoordinator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerStatus {
+    Alive,
+    /// Declared dead after `missed_heartbeats_to_die` consecutive liveness
+    /// checks without a `Heartbeat`; its partitions have been reassigned.
+    Dead,
+}
+
+struct WorkerRecord {
+    status: WorkerStatus,
+    last_heartbeat: Instant,
+    missed_heartbeats: u32,
+    partitions: Vec<usize>,
+}
+
+/// Point-in-time liveness/partition view of one worker, returned by
+/// [`StepCoordinator::worker_status`].
+#[derive(Debug, Clone)]
+pub struct WorkerView {
+    pub worker_id: usize,
+    pub status: WorkerStatus,
+    pub missed_heartbeats: u32,
+    pub partitions: Vec<usize>,
+}
+
+/// Drives synchronous lock-step simulation over a [`MessageBus`]:
+/// broadcast `StepCommand`, wait for every live worker's `StepComplete`,
+/// reassigning a dead worker's partitions instead of deadlocking on it.
+pub struct StepCoordinator {
+    message_bus: Arc<MessageBus>,
+    node_id: usize,
+    inbox: Mutex<mpsc::UnboundedReceiver<BufferedMessage>>,
+    heartbeat_timeout: Duration,
+    missed_heartbeats_to_die: u32,
+    workers: Mutex<HashMap<usize, WorkerRecord>>,
+    partitions: HashMap<usize, Partition>,
+    step: Mutex<u64>,
+}
+
+impl StepCoordinator {
+    /// Register a coordinator channel as `node_id` on `message_bus`,
+    /// tracking `worker_partitions` (worker id -> the partition ids it
+    /// starts out owning, all starting `Alive`) against the full
+    /// `partitions` set used to find a dead worker's replacement owners via
+    /// `neighbor_partitions`. A worker is declared dead once
+    /// `missed_heartbeats_to_die` consecutive liveness checks (run every
+    /// `heartbeat_timeout` while a `run_step` call is waiting) pass without
+    /// a `Heartbeat` from it.
+    pub async fn new(
+        message_bus: Arc<MessageBus>,
+        node_id: usize,
+        worker_partitions: HashMap<usize, Vec<usize>>,
+        partitions: Vec<Partition>,
+        heartbeat_timeout: Duration,
+        missed_heartbeats_to_die: u32,
+    ) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        message_bus.register_channel(tx).await;
+
+        let now = Instant::now();
+        let workers = worker_partitions
+            .into_iter()
+            .map(|(worker_id, owned_partitions)| {
+                (
+                    worker_id,
+                    WorkerRecord {
+                        status: WorkerStatus::Alive,
+                        last_heartbeat: now,
+                        missed_heartbeats: 0,
+                        partitions: owned_partitions,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            message_bus,
+            node_id,
+            inbox: Mutex::new(rx),
+            heartbeat_timeout,
+            missed_heartbeats_to_die,
+            workers: Mutex::new(workers),
+            partitions: partitions.into_iter().map(|p| (p.id, p)).collect(),
+            step: Mutex::new(0),
+        }
+    }
+
+    /// Point-in-time liveness/partition view of every tracked worker,
+    /// sorted by worker id.
+    pub async fn worker_status(&self) -> Vec<WorkerView> {
+        let workers = self.workers.lock().await;
+        let mut views: Vec<WorkerView> = workers
+            .iter()
+            .map(|(&worker_id, record)| WorkerView {
+                worker_id,
+                status: record.status,
+                missed_heartbeats: record.missed_heartbeats,
+                partitions: record.partitions.clone(),
+            })
+            .collect();
+        views.sort_by_key(|view| view.worker_id);
+        views
+    }
+
+    /// Broadcast `StepCommand{step, dt}` and block until every live worker
+    /// has replied with the matching `StepComplete`, running a liveness
+    /// check every `heartbeat_timeout` while it waits so a worker that
+    /// dies mid-step gets excluded (and its partitions reassigned) instead
+    /// of stalling the barrier forever. Returns the step number just
+    /// completed.
+    pub async fn run_step(&self, dt: f64) -> Result<u64> {
+        let step = {
+            let mut step = self.step.lock().await;
+            let current = *step;
+            *step += 1;
+            current
+        };
+
+        self.message_bus
+            .broadcast(NodeMessage::StepCommand {
+                step,
+                dt,
+                layout_version: 0,
+                trace_context: TraceContext::new_for_step(),
+            })
+            .await?;
+
+        let mut pending: HashSet<usize> = self
+            .workers
+            .lock()
+            .await
+            .iter()
+            .filter(|(_, record)| record.status == WorkerStatus::Alive)
+            .map(|(&id, _)| id)
+            .collect();
+
+        let mut inbox = self.inbox.lock().await;
+        while !pending.is_empty() {
+            tokio::select! {
+                message = inbox.recv() => {
+                    let Some(message) = message else {
+                        anyhow::bail!("StepCoordinator {}'s inbox channel closed", self.node_id);
+                    };
+                    match &message.message {
+                        NodeMessage::Heartbeat { worker_id } => {
+                            self.record_heartbeat(*worker_id).await;
+                        }
+                        NodeMessage::StepComplete { worker_id, step: completed_step }
+                            if *completed_step == step =>
+                        {
+                            pending.remove(worker_id);
+                        }
+                        _ => {}
+                    }
+                }
+                _ = tokio::time::sleep(self.heartbeat_timeout) => {
+                    for worker_id in self.check_liveness().await {
+                        warn!(
+                            "Worker {} missed {} consecutive heartbeats, declaring it dead mid-step {}",
+                            worker_id, self.missed_heartbeats_to_die, step
+                        );
+                        pending.remove(&worker_id);
+                    }
+                }
+            }
+        }
+
+        Ok(step)
+    }
+
+    /// Refresh `worker_id`'s liveness record on a `Heartbeat`.
+    async fn record_heartbeat(&self, worker_id: usize) {
+        let mut workers = self.workers.lock().await;
+        if let Some(record) = workers.get_mut(&worker_id) {
+            record.last_heartbeat = Instant::now();
+            record.missed_heartbeats = 0;
+        }
+    }
+
+    /// Check every alive worker's heartbeat age; one that has gone past
+    /// `heartbeat_timeout` since its last `Heartbeat` accrues a missed
+    /// check, and once it reaches `missed_heartbeats_to_die` that worker
+    /// is declared dead and its partitions reassigned. Returns the ids
+    /// newly declared dead by this call.
+    async fn check_liveness(&self) -> Vec<usize> {
+        let newly_dead = {
+            let mut workers = self.workers.lock().await;
+            let mut newly_dead = Vec::new();
+            for (&worker_id, record) in workers.iter_mut() {
+                if record.status != WorkerStatus::Alive
+                    || record.last_heartbeat.elapsed() <= self.heartbeat_timeout
+                {
+                    continue;
+                }
+                record.missed_heartbeats += 1;
+                if record.missed_heartbeats >= self.missed_heartbeats_to_die {
+                    record.status = WorkerStatus::Dead;
+                    newly_dead.push(worker_id);
+                }
+            }
+            newly_dead
+        };
+
+        for &worker_id in &newly_dead {
+            self.reassign_partitions(worker_id).await;
+        }
+
+        newly_dead
+    }
+
+    /// Hand every partition `worker_id` owned to whichever of its
+    /// `neighbor_partitions` a still-alive worker currently owns. A
+    /// partition with no live neighbor owner is left on the dead worker's
+    /// record rather than silently dropped -- the caller decides what an
+    /// orphaned partition means for the simulation.
+    async fn reassign_partitions(&self, worker_id: usize) {
+        let mut workers = self.workers.lock().await;
+        let Some(orphaned) = workers
+            .get_mut(&worker_id)
+            .map(|record| std::mem::take(&mut record.partitions))
+        else {
+            return;
+        };
+        if orphaned.is_empty() {
+            return;
+        }
+
+        // Partition id -> the worker that currently owns it, so a
+        // partition's neighbor list can be turned into "whichever live
+        // worker owns that neighbor".
+        let owner_of: HashMap<usize, usize> = workers
+            .iter()
+            .flat_map(|(&owner, record)| record.partitions.iter().map(move |&p| (p, owner)))
+            .collect();
+
+        let mut unassigned = Vec::new();
+        for partition_id in orphaned {
+            let new_owner = self
+                .partitions
+                .get(&partition_id)
+                .into_iter()
+                .flat_map(|partition| partition.neighbor_partitions.iter())
+                .filter_map(|neighbor_id| owner_of.get(neighbor_id).copied())
+                .find(|owner| {
+                    workers
+                        .get(owner)
+                        .map(|record| record.status == WorkerStatus::Alive)
+                        .unwrap_or(false)
+                });
+
+            match new_owner {
+                Some(owner) => {
+                    info!(
+                        "Reassigning partition {} from dead worker {} to worker {}",
+                        partition_id, worker_id, owner
+                    );
+                    workers
+                        .get_mut(&owner)
+                        .unwrap()
+                        .partitions
+                        .push(partition_id);
+                }
+                None => unassigned.push(partition_id),
+            }
+        }
+
+        if !unassigned.is_empty() {
+            warn!(
+                "No live neighbor owner found for partitions {:?} from dead worker {}",
+                unassigned, worker_id
+            );
+            if let Some(record) = workers.get_mut(&worker_id) {
+                record.partitions = unassigned;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Vector3;
+
+    fn chain_partitions(n: usize) -> Vec<Partition> {
+        (0..n)
+            .map(|id| Partition {
+                id,
+                min_bounds: Vector3::new(id as f64, 0.0, 0.0),
+                max_bounds: Vector3::new(id as f64 + 1.0, 1.0, 1.0),
+                neighbor_partitions: [id.checked_sub(1), Some(id + 1).filter(|&n2| n2 < n)]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Spawn a fake worker task that, for every `StepCommand` it receives,
+    /// immediately replies with a `Heartbeat` then a `StepComplete` --
+    /// until `alive_for_steps` commands have been answered, after which it
+    /// goes silent (simulating a crash) without ever ending the task, so
+    /// the coordinator has to notice via missed heartbeats rather than a
+    /// dropped channel.
+    fn spawn_fake_worker(
+        bus: Arc<MessageBus>,
+        worker_id: usize,
+        alive_for_steps: u64,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let (tx, mut rx) = mpsc::unbounded_channel();
+            bus.register_channel(tx).await;
+
+            let mut answered = 0u64;
+            while let Some(message) = rx.recv().await {
+                if let NodeMessage::StepCommand { step, .. } = &message.message {
+                    if answered >= alive_for_steps {
+                        continue;
+                    }
+                    answered += 1;
+                    bus.send_to_from(worker_id, 0, NodeMessage::Heartbeat { worker_id })
+                        .await
+                        .unwrap();
+                    bus.send_to_from(
+                        worker_id,
+                        0,
+                        NodeMessage::StepComplete {
+                            worker_id,
+                            step: *step,
+                        },
+                    )
+                    .await
+                    .unwrap();
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn run_step_completes_once_every_live_worker_reports_in() {
+        let bus = Arc::new(MessageBus::new(3));
+        let mut worker_partitions = HashMap::new();
+        worker_partitions.insert(1, vec![0]);
+        worker_partitions.insert(2, vec![1]);
+
+        let coordinator = StepCoordinator::new(
+            bus.clone(),
+            0,
+            worker_partitions,
+            chain_partitions(2),
+            Duration::from_millis(50),
+            3,
+        )
+        .await;
+
+        let _w1 = spawn_fake_worker(bus.clone(), 1, u64::MAX);
+        let _w2 = spawn_fake_worker(bus.clone(), 2, u64::MAX);
+
+        let step = coordinator.run_step(0.01).await.unwrap();
+        assert_eq!(step, 0);
+
+        let statuses = coordinator.worker_status().await;
+        assert!(statuses.iter().all(|w| w.status == WorkerStatus::Alive));
+    }
+
+    #[tokio::test]
+    async fn dead_worker_is_excluded_and_its_partition_reassigned_to_a_live_neighbor() {
+        let bus = Arc::new(MessageBus::new(3));
+        let mut worker_partitions = HashMap::new();
+        worker_partitions.insert(1, vec![0]);
+        worker_partitions.insert(2, vec![1]);
+
+        let heartbeat_timeout = Duration::from_millis(20);
+        let coordinator = StepCoordinator::new(
+            bus.clone(),
+            0,
+            worker_partitions,
+            chain_partitions(2),
+            heartbeat_timeout,
+            2,
+        )
+        .await;
+
+        // Worker 1 answers exactly one step then goes silent; worker 2
+        // keeps answering every step.
+        let _w1 = spawn_fake_worker(bus.clone(), 1, 1);
+        let _w2 = spawn_fake_worker(bus.clone(), 2, u64::MAX);
+
+        // First step: worker 1 is still alive and answers normally.
+        coordinator.run_step(0.01).await.unwrap();
+        assert!(coordinator
+            .worker_status()
+            .await
+            .iter()
+            .all(|w| w.status == WorkerStatus::Alive));
+
+        // Second step: worker 1 never replies again, so this call must
+        // wait out `missed_heartbeats_to_die` liveness checks before the
+        // barrier releases without it -- it must not hang forever.
+        tokio::time::timeout(Duration::from_secs(5), coordinator.run_step(0.01))
+            .await
+            .expect("run_step must release once the dead worker is excluded")
+            .unwrap();
+
+        let statuses = coordinator.worker_status().await;
+        let worker1 = statuses.iter().find(|w| w.worker_id == 1).unwrap();
+        let worker2 = statuses.iter().find(|w| w.worker_id == 2).unwrap();
+        assert_eq!(worker1.status, WorkerStatus::Dead);
+        assert!(worker1.partitions.is_empty());
+        assert_eq!(worker2.status, WorkerStatus::Alive);
+        // Partition 0 (worker 1's) neighbors partition 1 (worker 2's), so
+        // it should have been handed to worker 2.
+        assert!(worker2.partitions.contains(&0));
+        assert!(worker2.partitions.contains(&1));
+    }
+}
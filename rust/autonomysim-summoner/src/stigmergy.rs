@@ -0,0 +1,260 @@
+//! Virtual stigmergy: a replicated key-value tuple space robots use to
+//! coordinate indirectly, the way social insects coordinate through
+//! pheromone trails left in the environment rather than direct messaging.
+//! Each robot owns a [`Stigmergy`] replica (see [`crate::Summoner::create_stigmergy`]);
+//! [`Stigmergy::put`] stamps a write with this replica's next version, and
+//! [`Stigmergy::propagate`] gossips a bounded batch of locally-dirty tuples
+//! with a caller-chosen set of neighbor replicas each step. Conflicting
+//! writes to the same key are resolved by `version` (higher wins), with
+//! ties -- two writers stamping the same version -- broken by the lower
+//! `author` id, and counted so callers can see how often that's happening.
+//!
+//! Range gating -- which neighbors are reachable this step -- is
+//! deliberately left to the caller rather than baked into `propagate`
+//! itself: this crate has no dependency on `autonomysim-rf-core`, so a
+//! caller that does (e.g. `robotic_swarm_demo`) decides who counts as "in
+//! range" via `RFPropagationEngine::compute_link` and only passes those
+//! neighbors in. Leaving a robot out of every other robot's `propagate`
+//! call for a stretch of steps is how jamming actually slows convergence,
+//! rather than every replica having instant global knowledge regardless of
+//! radio conditions.
+
+use autonomysim_core::vehicle::VehicleId;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// One replicated value: a versioned write plus the instant it was made,
+/// the latter used only to measure how long a write takes to reach a given
+/// replica.
+#[derive(Debug, Clone)]
+pub struct Tuple {
+    pub value: Vec<u8>,
+    pub version: u32,
+    pub author: VehicleId,
+    written_at: Instant,
+}
+
+/// Reacts to a [`Tuple`] accepted from a remote replica -- i.e. a write this
+/// robot didn't author itself -- so a behavior can notice "someone else
+/// claimed this target" without polling [`Stigmergy::get`] every step. Never
+/// invoked for a replica's own local [`Stigmergy::put`].
+pub trait StigmergyListener: Send {
+    fn on_change(&mut self, key: &str, tuple: &Tuple);
+}
+
+/// One robot's replica of the shared tuple space.
+pub struct Stigmergy {
+    author: VehicleId,
+    clock: u32,
+    tuples: HashMap<String, Tuple>,
+    dirty: HashSet<String>,
+    collisions: u64,
+    latency_sum: Duration,
+    latency_samples: u64,
+    listeners: Vec<Box<dyn StigmergyListener>>,
+}
+
+impl Stigmergy {
+    /// Create an empty replica. `author` must be unique across the swarm --
+    /// it's the tiebreaker for writes stamped at the same version.
+    pub fn new(author: impl Into<VehicleId>) -> Self {
+        Self {
+            author: author.into(),
+            clock: 0,
+            tuples: HashMap::new(),
+            dirty: HashSet::new(),
+            collisions: 0,
+            latency_sum: Duration::ZERO,
+            latency_samples: 0,
+            listeners: Vec::new(),
+        }
+    }
+
+    pub fn author(&self) -> &VehicleId {
+        &self.author
+    }
+
+    /// Register a listener notified whenever a remote write changes a
+    /// tuple's value in this replica.
+    pub fn add_listener(&mut self, listener: Box<dyn StigmergyListener>) {
+        self.listeners.push(listener);
+    }
+
+    /// Write `value` under `key`, stamping it with this replica's next
+    /// version and marking it for the next `propagate`.
+    pub fn put(&mut self, key: impl Into<String>, value: impl Into<Vec<u8>>) {
+        self.clock += 1;
+        let key = key.into();
+        self.tuples.insert(
+            key.clone(),
+            Tuple {
+                value: value.into(),
+                version: self.clock,
+                author: self.author.clone(),
+                written_at: Instant::now(),
+            },
+        );
+        self.dirty.insert(key);
+    }
+
+    /// Read the current value for `key`, if this replica has seen a write
+    /// for it (its own, or one propagated in from another replica).
+    pub fn get(&self, key: &str) -> Option<&[u8]> {
+        self.tuples.get(key).map(|tuple| tuple.value.as_slice())
+    }
+
+    /// Number of version ties this replica has resolved by `author` since
+    /// creation.
+    pub fn collision_count(&self) -> u64 {
+        self.collisions
+    }
+
+    /// Mean wall-clock delay between a tuple being written somewhere in the
+    /// swarm and this replica accepting that write, averaged over every
+    /// propagated write this replica has accepted so far. `Duration::ZERO`
+    /// before it has accepted any.
+    pub fn mean_propagation_latency(&self) -> Duration {
+        if self.latency_samples == 0 {
+            Duration::ZERO
+        } else {
+            self.latency_sum / self.latency_samples as u32
+        }
+    }
+
+    /// Exchange up to `max_batch` locally-dirty tuples with each of
+    /// `neighbors`, and accept up to `max_batch` of theirs in return.
+    /// Callers are responsible for only passing neighbors that are
+    /// currently reachable this step.
+    pub fn propagate<'a>(
+        &mut self,
+        neighbors: impl IntoIterator<Item = &'a mut Stigmergy>,
+        max_batch: usize,
+    ) {
+        let outgoing = self.drain_dirty_batch(max_batch);
+
+        for neighbor in neighbors {
+            for (key, tuple) in &outgoing {
+                neighbor.receive(key.clone(), tuple.clone());
+            }
+
+            for (key, tuple) in neighbor.drain_dirty_batch(max_batch) {
+                self.receive(key, tuple);
+            }
+        }
+    }
+
+    /// Snapshot and clear up to `max_batch` locally-dirty tuples to send.
+    fn drain_dirty_batch(&mut self, max_batch: usize) -> Vec<(String, Tuple)> {
+        let keys: Vec<String> = self.dirty.iter().take(max_batch).cloned().collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                self.dirty.remove(&key);
+                self.tuples.get(&key).map(|tuple| (key, tuple.clone()))
+            })
+            .collect()
+    }
+
+    /// Merge one incoming write, keeping the higher version (ties broken by
+    /// the lower `author` id) and counting ties against `collisions`.
+    fn receive(&mut self, key: String, incoming: Tuple) {
+        let accept = match self.tuples.get(&key) {
+            None => true,
+            Some(existing) => {
+                if incoming.version == existing.version && incoming.author != existing.author {
+                    self.collisions += 1;
+                }
+                incoming.version > existing.version
+                    || (incoming.version == existing.version && incoming.author < existing.author)
+            }
+        };
+
+        if !accept {
+            return;
+        }
+
+        self.clock = self.clock.max(incoming.version);
+        if incoming.author != self.author {
+            self.latency_sum += incoming.written_at.elapsed();
+            self.latency_samples += 1;
+            for listener in &mut self.listeners {
+                listener.on_change(&key, &incoming);
+            }
+        }
+        self.dirty.insert(key.clone());
+        self.tuples.insert(key, incoming);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_then_get_roundtrips() {
+        let mut node = Stigmergy::new("robot_1");
+        node.put("rally_point", vec![1, 2, 3]);
+        assert_eq!(node.get("rally_point"), Some(&[1, 2, 3][..]));
+    }
+
+    #[test]
+    fn propagate_replicates_to_neighbor() {
+        let mut a = Stigmergy::new("robot_1");
+        let mut b = Stigmergy::new("robot_2");
+        a.put("k", vec![9]);
+
+        a.propagate(std::iter::once(&mut b), 8);
+
+        assert_eq!(b.get("k"), Some(&[9][..]));
+    }
+
+    #[test]
+    fn higher_version_wins_on_conflict() {
+        let mut a = Stigmergy::new("robot_1");
+        let mut b = Stigmergy::new("robot_2");
+        a.put("k", vec![1]); // version 1
+        b.put("k", vec![2]); // version 1
+        b.put("k", vec![3]); // version 2, should win
+
+        a.propagate(std::iter::once(&mut b), 8);
+
+        assert_eq!(a.get("k"), Some(&[3][..]));
+        assert_eq!(b.get("k"), Some(&[3][..]));
+    }
+
+    #[test]
+    fn tied_version_breaks_by_lower_author_and_counts_collision() {
+        let mut a = Stigmergy::new("robot_5");
+        let mut b = Stigmergy::new("robot_2");
+        a.put("k", vec![1]); // author robot_5, version 1
+        b.put("k", vec![2]); // author robot_2, version 1
+
+        a.propagate(std::iter::once(&mut b), 8);
+
+        // Lower author id ("robot_2" < "robot_5") wins the tie on both replicas.
+        assert_eq!(a.get("k"), Some(&[2][..]));
+        assert_eq!(b.get("k"), Some(&[2][..]));
+        assert_eq!(a.collision_count(), 1);
+        assert_eq!(b.collision_count(), 1);
+    }
+
+    #[test]
+    fn listener_fires_only_for_remote_writes() {
+        struct Recorder(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+        impl StigmergyListener for Recorder {
+            fn on_change(&mut self, key: &str, _tuple: &Tuple) {
+                self.0.lock().unwrap().push(key.to_string());
+            }
+        }
+
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut a = Stigmergy::new("robot_1");
+        a.add_listener(Box::new(Recorder(seen.clone())));
+        let mut b = Stigmergy::new("robot_2");
+
+        a.put("local_key", vec![1]);
+        b.put("remote_key", vec![2]);
+        a.propagate(std::iter::once(&mut b), 8);
+
+        assert_eq!(*seen.lock().unwrap(), vec!["remote_key".to_string()]);
+    }
+}
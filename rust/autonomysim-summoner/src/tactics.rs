@@ -0,0 +1,578 @@
+//! Monte Carlo Tree Search over swarm-level tactical actions.
+//!
+//! Everything else in this crate is reactive: [`crate::cohesion`] and
+//! [`crate::perception`] derive each agent's velocity from its immediate
+//! neighbors and sensor cone, one step at a time. Nothing above that looks
+//! ahead -- a jammer corridor or a SAM the swarm is about to fly into only
+//! shows up in the reactive forces once agents are already inside its
+//! effective range. [`plan_tactics`] adds a deliberative layer on top: run
+//! between [`crate::Summoner`] steps (not every one -- it's deliberately
+//! the expensive path), it searches a small tree of `(state, action)` pairs
+//! for the macro-maneuver -- split, regroup, reroute, prioritize which
+//! threat to avoid -- whose simulated rollout scores best against a fixed
+//! horizon, and hands that back as the swarm's counter-EW strategy for the
+//! next stretch of steps.
+//!
+//! The four standard MCTS phases, split across [`search`] and its
+//! `Node` helpers:
+//! 1. **Selection** -- descend from the root choosing the child that
+//!    maximizes UCT until one with an untried action is reached.
+//! 2. **Expansion** -- add that untried action as a new child.
+//! 3. **Simulation** -- [`RolloutModel::rollout`] runs a cheap forward
+//!    model for [`TacticalPlannerConfig::rollout_horizon_steps`] and scores
+//!    the terminal state. Selection and expansion both happen inside one
+//!    `Node::select_and_expand` call; simulation and backpropagation
+//!    happen back in `search`.
+//! 4. **Backpropagation** -- fold that reward into every node on the path
+//!    back to the root.
+//!
+//! [`DeadReckoningRollout`] is the default [`RolloutModel`]: ballistic
+//! dead-reckoning against known threat/jammer positions, the same
+//! reduced-fidelity motion [`crate::lod`] already uses for far-band agents,
+//! rather than paying for the real backend on every rollout. Swap in a
+//! different [`RolloutModel`] impl (e.g. one backed by a real physics step)
+//! when the time budget allows it.
+
+use nalgebra::{Point3, Vector3};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use autonomysim_tactical::ai::ThreatContact;
+
+/// A macro-maneuver the whole swarm commits to, rather than a per-agent
+/// setpoint. Kept small and discrete so the MCTS branching factor stays
+/// bounded regardless of swarm size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TacticalAction {
+    /// Keep station; no swarm-level maneuver in progress.
+    HoldPosition,
+    /// Fan out from the swarm centroid to cover more ground.
+    Split,
+    /// Contract back toward the swarm centroid to restore cohesion.
+    Regroup,
+    /// Steer the centroid away from the nearest known jammer.
+    RerouteAroundJammer,
+    /// Steer away from `threats[avoid_index]`, the contact judged highest
+    /// priority to avoid this cycle. Indexes [`SwarmState::threats`], so
+    /// it's only a meaningful action for states that have at least that
+    /// many threats.
+    AvoidThreat { avoid_index: usize },
+}
+
+/// All actions reachable from a state with `threat_count` known threats.
+/// [`TacticalAction::AvoidThreat`] only appears once per threat, so the
+/// branching factor grows with the threat picture, not with agent count.
+fn action_set(threat_count: usize) -> Vec<TacticalAction> {
+    let mut actions = vec![
+        TacticalAction::HoldPosition,
+        TacticalAction::Split,
+        TacticalAction::Regroup,
+        TacticalAction::RerouteAroundJammer,
+    ];
+    actions
+        .extend((0..threat_count).map(|avoid_index| TacticalAction::AvoidThreat { avoid_index }));
+    actions
+}
+
+/// A coarse snapshot of the swarm and the threat picture it's planning
+/// against -- cheap enough to clone once per MCTS node without touching
+/// the real `Worker`/`VehicleState` machinery.
+#[derive(Debug, Clone)]
+pub struct SwarmState {
+    /// Agent positions.
+    pub agent_positions: HashMap<usize, Point3<f64>>,
+    /// Per-agent velocity the most recently applied [`TacticalAction`] set,
+    /// held constant (ballistic dead-reckoning, no re-steering) until the
+    /// next action replaces it. Agents absent from this map (i.e. no
+    /// action has been applied to this state yet) are treated as
+    /// stationary by [`DeadReckoningRollout::step`].
+    pub agent_velocities: HashMap<usize, Vector3<f64>>,
+    /// Known hostile contacts, static for the duration of one rollout.
+    pub threats: Vec<ThreatContact>,
+    /// Known jammer locations, static for the duration of one rollout.
+    pub jammer_positions: Vec<Point3<f64>>,
+    /// ISR/coverage objective the swarm is trying to reach or orbit.
+    pub objective: Point3<f64>,
+}
+
+impl SwarmState {
+    /// Mean agent position, or `objective` for an empty swarm.
+    pub fn centroid(&self) -> Point3<f64> {
+        if self.agent_positions.is_empty() {
+            return self.objective;
+        }
+        let sum = self
+            .agent_positions
+            .values()
+            .fold(Vector3::zeros(), |acc, p| acc + p.coords);
+        Point3::from(sum / self.agent_positions.len() as f64)
+    }
+}
+
+/// A pluggable forward model for MCTS's simulation phase: apply one
+/// tactical action, step the resulting state forward, and score where it
+/// ends up. [`DeadReckoningRollout`] is the cheap default; a caller with
+/// rollout budget to spare can swap in one backed by the real backend
+/// (e.g. `WarpBackend`) for a higher-fidelity estimate without changing
+/// [`search`] at all.
+pub trait RolloutModel {
+    /// Apply `action`'s immediate effect (e.g. `Split` nudging agents
+    /// apart) to `state`, returning the post-action state.
+    fn apply_action(&self, state: &SwarmState, action: TacticalAction) -> SwarmState;
+
+    /// Advance `state` by `dt` with no new action -- agents continue
+    /// whatever motion the last action set them on.
+    fn step(&self, state: &SwarmState, dt: f64) -> SwarmState;
+
+    /// Score a (possibly terminal) state: higher is better. Combines
+    /// coverage progress, agents lost to threats, and cohesion into one
+    /// scalar reward for backpropagation.
+    fn score(&self, state: &SwarmState) -> f64;
+
+    /// Roll `state` (already past its node's [`RolloutModel::apply_action`])
+    /// forward for `horizon_steps` of `dt` each, returning the terminal
+    /// state's [`RolloutModel::score`].
+    fn rollout(&self, state: &SwarmState, horizon_steps: u32, dt: f64) -> f64 {
+        let mut rolled = state.clone();
+        for _ in 0..horizon_steps {
+            rolled = self.step(&rolled, dt);
+        }
+        self.score(&rolled)
+    }
+}
+
+/// Radius within which a threat is considered to have engaged (and so
+/// scored as lost) an agent during a [`DeadReckoningRollout`].
+const ENGAGEMENT_RADIUS: f64 = 5.0;
+/// Radius within which a jammer degrades an agent's contribution to the
+/// cohesion term of [`DeadReckoningRollout::score`], mirroring how
+/// [`crate::perception::Agent::quality`] shrinks a jammed sensor's cone.
+const JAMMER_DEGRADE_RADIUS: f64 = 15.0;
+/// Target inter-agent spacing the cohesion term of
+/// [`DeadReckoningRollout::score`] is measured against, matching
+/// [`crate::cohesion::LjParams::target`]'s usual order of magnitude.
+const COHESION_TARGET_SPACING: f64 = 5.0;
+
+/// The default [`RolloutModel`]: ballistic dead-reckoning against known
+/// threat/jammer positions, the same reduced-fidelity motion
+/// [`crate::lod`]'s far band already uses instead of a real physics step.
+/// Cheap enough to run hundreds of times per planning cycle.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeadReckoningRollout;
+
+impl DeadReckoningRollout {
+    /// Velocity `action` commands for an agent at `position`, as a
+    /// unit-ish direction scaled by `speed`. Falls back to closing on
+    /// `state.objective` for `RerouteAroundJammer`/`AvoidThreat` when
+    /// there's nothing to reroute/avoid, rather than going idle.
+    fn commanded_velocity(
+        &self,
+        state: &SwarmState,
+        position: Point3<f64>,
+        action: TacticalAction,
+        speed: f64,
+    ) -> Vector3<f64> {
+        let centroid = state.centroid();
+        match action {
+            TacticalAction::HoldPosition => Vector3::zeros(),
+            TacticalAction::Split => {
+                let away = position - centroid;
+                unit_or_zero(away) * speed
+            }
+            TacticalAction::Regroup => {
+                let toward = centroid - position;
+                unit_or_zero(toward) * speed
+            }
+            TacticalAction::RerouteAroundJammer => {
+                let Some(nearest) = nearest_point(position, &state.jammer_positions) else {
+                    return unit_or_zero(state.objective - position) * speed;
+                };
+                unit_or_zero(position - nearest) * speed
+            }
+            TacticalAction::AvoidThreat { avoid_index } => {
+                let Some(threat) = state.threats.get(avoid_index) else {
+                    return unit_or_zero(state.objective - position) * speed;
+                };
+                unit_or_zero(position - Point3::from(threat.position)) * speed
+            }
+        }
+    }
+}
+
+/// Cruise speed (m/s) every [`TacticalAction`] commands -- a fixed
+/// constant rather than a per-agent value since the rollout model has no
+/// per-agent state to draw one from.
+const ROLLOUT_SPEED: f64 = 8.0;
+
+impl RolloutModel for DeadReckoningRollout {
+    fn apply_action(&self, state: &SwarmState, action: TacticalAction) -> SwarmState {
+        let mut next = state.clone();
+        next.agent_velocities = state
+            .agent_positions
+            .iter()
+            .map(|(&id, &position)| {
+                (
+                    id,
+                    self.commanded_velocity(state, position, action, ROLLOUT_SPEED),
+                )
+            })
+            .collect();
+        next
+    }
+
+    /// Ballistic dead-reckoning: each agent keeps moving at whatever
+    /// velocity its last applied action set, without re-steering toward
+    /// anything -- the same reduced-fidelity motion `Worker::update_lod`
+    /// applies to its own far band.
+    fn step(&self, state: &SwarmState, dt: f64) -> SwarmState {
+        let mut next = state.clone();
+        for (id, position) in next.agent_positions.iter_mut() {
+            if let Some(&velocity) = state.agent_velocities.get(id) {
+                *position += velocity * dt;
+            }
+        }
+        next
+    }
+
+    fn score(&self, state: &SwarmState) -> f64 {
+        if state.agent_positions.is_empty() {
+            return 0.0;
+        }
+
+        let mut coverage = 0.0;
+        let mut lost = 0.0;
+        let mut cohesion_penalty = 0.0;
+        let centroid = state.centroid();
+        let positions: Vec<Point3<f64>> = state.agent_positions.values().copied().collect();
+
+        for &position in &positions {
+            coverage += 1.0 / (1.0 + (position - state.objective).norm());
+
+            if nearest_point(
+                position,
+                &state
+                    .threats
+                    .iter()
+                    .map(|t| Point3::from(t.position))
+                    .collect::<Vec<_>>(),
+            )
+            .map(|nearest| (position - nearest).norm() < ENGAGEMENT_RADIUS)
+            .unwrap_or(false)
+            {
+                lost += 1.0;
+            }
+
+            if let Some(nearest_jammer) = nearest_point(position, &state.jammer_positions) {
+                if (position - nearest_jammer).norm() < JAMMER_DEGRADE_RADIUS {
+                    cohesion_penalty += 0.5;
+                }
+            }
+
+            cohesion_penalty += ((position - centroid).norm() - COHESION_TARGET_SPACING).abs()
+                / COHESION_TARGET_SPACING;
+        }
+
+        let n = positions.len() as f64;
+        coverage / n - lost - cohesion_penalty / n
+    }
+}
+
+fn unit_or_zero(v: Vector3<f64>) -> Vector3<f64> {
+    let norm = v.norm();
+    if norm <= f64::EPSILON {
+        Vector3::zeros()
+    } else {
+        v / norm
+    }
+}
+
+fn nearest_point(from: Point3<f64>, candidates: &[Point3<f64>]) -> Option<Point3<f64>> {
+    candidates.iter().copied().min_by(|a, b| {
+        (from - a)
+            .norm_squared()
+            .partial_cmp(&(from - b).norm_squared())
+            .unwrap()
+    })
+}
+
+/// `Coordinator::plan_tactics` tunables.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TacticalPlannerConfig {
+    /// MCTS wall-clock time budget per planning cycle, in milliseconds.
+    /// `search` keeps adding tree iterations until this elapses, then
+    /// returns whatever the tree has. Framed as the available slack below
+    /// the step's `target_latency_ms` -- a caller invoking `plan_tactics`
+    /// between steps should pass in however much of that budget it can
+    /// spare this cycle, not the whole per-step deadline.
+    pub target_latency_ms: f64,
+    /// `c` in the UCT formula `mean_reward + c * sqrt(ln(parent_visits) /
+    /// child_visits)` -- higher favors exploring under-visited children
+    /// over exploiting the current best one.
+    pub exploration_constant: f64,
+    /// Rollout horizon, in steps of `rollout_dt` each.
+    pub rollout_horizon_steps: u32,
+    /// Timestep the rollout model advances by each horizon step.
+    pub rollout_dt: f64,
+}
+
+impl Default for TacticalPlannerConfig {
+    fn default() -> Self {
+        Self {
+            target_latency_ms: 20.0,
+            exploration_constant: std::f64::consts::SQRT_2,
+            rollout_horizon_steps: 10,
+            rollout_dt: 0.1,
+        }
+    }
+}
+
+/// One `(state, action)` pair in the search tree. `action` is `None` only
+/// at the root, which represents "no maneuver chosen yet".
+struct Node {
+    action: Option<TacticalAction>,
+    state: SwarmState,
+    visits: u32,
+    total_reward: f64,
+    children: Vec<Node>,
+    untried_actions: Vec<TacticalAction>,
+}
+
+impl Node {
+    fn new(action: Option<TacticalAction>, state: SwarmState) -> Self {
+        let untried_actions = action_set(state.threats.len());
+        Self {
+            action,
+            state,
+            visits: 0,
+            total_reward: 0.0,
+            children: Vec::new(),
+            untried_actions,
+        }
+    }
+
+    fn mean_reward(&self) -> f64 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.total_reward / self.visits as f64
+        }
+    }
+
+    /// UCT score of this node from its parent's perspective. Unvisited
+    /// children are treated as `+infinity` so selection always expands
+    /// every child at least once before comparing means.
+    fn uct(&self, parent_visits: u32, exploration_constant: f64) -> f64 {
+        if self.visits == 0 {
+            return f64::INFINITY;
+        }
+        self.mean_reward()
+            + exploration_constant * ((parent_visits as f64).ln() / self.visits as f64).sqrt()
+    }
+
+    /// Selection + expansion: descend via the best UCT child as long as
+    /// every action at this level has already been tried, expanding the
+    /// first untried action once a node with one is reached. Returns the
+    /// resulting leaf's state, already past its action's
+    /// [`RolloutModel::apply_action`], plus the path of node indices from
+    /// the root (exclusive) down to it, for backpropagation.
+    fn select_and_expand(
+        &mut self,
+        rollout: &dyn RolloutModel,
+        exploration_constant: f64,
+    ) -> (SwarmState, Vec<usize>) {
+        if let Some(action) = self.untried_actions.pop() {
+            let next_state = rollout.apply_action(&self.state, action);
+            self.children
+                .push(Node::new(Some(action), next_state.clone()));
+            let child_index = self.children.len() - 1;
+            return (next_state, vec![child_index]);
+        }
+
+        let parent_visits = self.visits.max(1);
+        let best_index = self
+            .children
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                a.uct(parent_visits, exploration_constant)
+                    .partial_cmp(&b.uct(parent_visits, exploration_constant))
+                    .unwrap()
+            })
+            .map(|(index, _)| index)
+            .expect("every action_set() is non-empty, so a node always gains a child before its untried_actions run out");
+
+        let (state, mut path) =
+            self.children[best_index].select_and_expand(rollout, exploration_constant);
+        path.insert(0, best_index);
+        (state, path)
+    }
+
+    /// Fold `reward` into this node and every descendant named by `path`.
+    fn backpropagate(&mut self, path: &[usize], reward: f64) {
+        self.visits += 1;
+        self.total_reward += reward;
+        if let [head, rest @ ..] = path {
+            self.children[*head].backpropagate(rest, reward);
+        }
+    }
+
+    /// The direct child with the most visits, i.e. MCTS's final answer.
+    fn most_visited_child_action(&self) -> Option<TacticalAction> {
+        self.children
+            .iter()
+            .max_by_key(|child| child.visits)
+            .and_then(|child| child.action)
+    }
+}
+
+/// Run Monte Carlo Tree Search from `state` for up to `config`'s time
+/// budget, then return the root child with the most visits --
+/// [`TacticalAction::HoldPosition`] if the budget ran out before even one
+/// full iteration completed (e.g. a zero-agent or zero-budget state).
+///
+/// Each iteration is one pass of the four standard phases: selection and
+/// expansion both happen in [`Node::select_and_expand`], simulation calls
+/// `rollout.rollout`, and backpropagation folds the resulting reward back
+/// up the path via [`Node::backpropagate`].
+pub fn search(
+    state: &SwarmState,
+    config: &TacticalPlannerConfig,
+    rollout: &dyn RolloutModel,
+) -> TacticalAction {
+    let mut root = Node::new(None, state.clone());
+    let budget = Duration::from_secs_f64((config.target_latency_ms.max(0.0)) / 1000.0);
+    let started = Instant::now();
+
+    loop {
+        if started.elapsed() >= budget && root.visits > 0 {
+            break;
+        }
+
+        let (leaf_state, path) = root.select_and_expand(rollout, config.exploration_constant);
+        let reward = rollout.rollout(&leaf_state, config.rollout_horizon_steps, config.rollout_dt);
+        root.backpropagate(&path, reward);
+
+        if started.elapsed() >= budget {
+            break;
+        }
+    }
+
+    root.most_visited_child_action()
+        .unwrap_or(TacticalAction::HoldPosition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_objective_far_ahead() -> SwarmState {
+        let mut agent_positions = HashMap::new();
+        agent_positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        agent_positions.insert(1usize, Point3::new(1.0, 0.0, 0.0));
+        SwarmState {
+            agent_positions,
+            agent_velocities: HashMap::new(),
+            threats: Vec::new(),
+            jammer_positions: Vec::new(),
+            objective: Point3::new(100.0, 0.0, 0.0),
+        }
+    }
+
+    fn fast_config() -> TacticalPlannerConfig {
+        TacticalPlannerConfig {
+            target_latency_ms: 20.0,
+            ..TacticalPlannerConfig::default()
+        }
+    }
+
+    #[test]
+    fn centroid_of_empty_swarm_is_the_objective() {
+        let state = SwarmState {
+            agent_positions: HashMap::new(),
+            agent_velocities: HashMap::new(),
+            threats: Vec::new(),
+            jammer_positions: Vec::new(),
+            objective: Point3::new(3.0, 4.0, 0.0),
+        };
+        assert_eq!(state.centroid(), Point3::new(3.0, 4.0, 0.0));
+    }
+
+    #[test]
+    fn centroid_averages_agent_positions() {
+        let state = state_with_objective_far_ahead();
+        assert_eq!(state.centroid(), Point3::new(0.5, 0.0, 0.0));
+    }
+
+    #[test]
+    fn search_runs_within_its_time_budget_and_returns_some_action() {
+        let state = state_with_objective_far_ahead();
+        let config = fast_config();
+        let action = search(&state, &config, &DeadReckoningRollout);
+        // Every action in the set is a legitimate answer; this just
+        // confirms search() terminates and doesn't panic indexing an
+        // empty tree.
+        let _ = action;
+    }
+
+    #[test]
+    fn avoiding_a_nearby_threat_scores_better_than_holding_into_it() {
+        let mut agent_positions = HashMap::new();
+        agent_positions.insert(0usize, Point3::new(0.0, 0.0, 0.0));
+        let state = SwarmState {
+            agent_positions,
+            agent_velocities: HashMap::new(),
+            threats: vec![ThreatContact {
+                position: Vector3::new(2.0, 0.0, 0.0),
+                priority: 1.0,
+            }],
+            jammer_positions: Vec::new(),
+            objective: Point3::new(100.0, 0.0, 0.0),
+        };
+
+        let rollout = DeadReckoningRollout;
+        let config = TacticalPlannerConfig::default();
+        let avoided = rollout.apply_action(&state, TacticalAction::AvoidThreat { avoid_index: 0 });
+        let held = rollout.apply_action(&state, TacticalAction::HoldPosition);
+        let avoid_reward =
+            rollout.rollout(&avoided, config.rollout_horizon_steps, config.rollout_dt);
+        let hold_reward = rollout.rollout(&held, config.rollout_horizon_steps, config.rollout_dt);
+
+        assert!(
+            avoid_reward > hold_reward,
+            "steering away from a threat sitting right on top of the agent should score higher \
+             than holding position and staying inside its engagement radius"
+        );
+    }
+
+    #[test]
+    fn regroup_reduces_cohesion_penalty_for_a_scattered_swarm() {
+        let mut agent_positions = HashMap::new();
+        agent_positions.insert(0usize, Point3::new(-50.0, 0.0, 0.0));
+        agent_positions.insert(1usize, Point3::new(50.0, 0.0, 0.0));
+        let state = SwarmState {
+            agent_positions,
+            agent_velocities: HashMap::new(),
+            threats: Vec::new(),
+            jammer_positions: Vec::new(),
+            objective: Point3::new(0.0, 0.0, 0.0),
+        };
+
+        let rollout = DeadReckoningRollout;
+        let regrouped = rollout.apply_action(&state, TacticalAction::Regroup);
+        let split = rollout.apply_action(&state, TacticalAction::Split);
+        let regroup_reward = rollout.rollout(&regrouped, 20, 0.5);
+        let split_reward = rollout.rollout(&split, 20, 0.5);
+
+        assert!(
+            regroup_reward > split_reward,
+            "regrouping a scattered swarm should score higher than splitting it further"
+        );
+    }
+
+    #[test]
+    fn action_set_grows_one_avoid_variant_per_threat() {
+        assert_eq!(action_set(0).len(), 4);
+        assert_eq!(action_set(3).len(), 7);
+    }
+}
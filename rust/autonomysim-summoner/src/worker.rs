@@ -1,10 +1,82 @@
 //! Worker: Executes simulation on subset of agents
 
 use anyhow::Result;
+use autonomysim_core::vehicle::{VehicleControl, VehicleState, VehicleType};
+use nalgebra::{Point3, Unit, UnitQuaternion, Vector3};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info};
 
-use crate::{MessageBus, SummonerConfig};
+use crate::cohesion;
+use crate::controller::{ControllerSettings, ControllerState, PidController, Setpoint};
+use crate::firmware::{FirmwareLink, FirmwarePool};
+use crate::lod::{self, LodLevel, LodState};
+use crate::perception;
+use crate::ros_bridge::Ros2Bridge;
+use crate::{MessageBus, SummonerConfig, TraceContext};
+
+/// Wall-clock duration of each named sub-phase of one `execute_step` call,
+/// in the order they run. Folded into a `PhaseProfiler` by `Summoner::step`
+/// to back `SummonerMetrics::phase_timings`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhaseTimings {
+    pub physics: Duration,
+    pub sensors: Duration,
+    pub communications: Duration,
+    pub control: Duration,
+}
+
+impl PhaseTimings {
+    /// `(phase name, duration)` pairs, in the order they run within
+    /// `execute_step`.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Duration)> {
+        [
+            ("physics", self.physics),
+            ("sensors", self.sensors),
+            ("communications", self.communications),
+            ("control", self.control),
+        ]
+        .into_iter()
+    }
+}
+
+/// Point-mass multirotor constants backing `integrate_simple_kinematics`,
+/// the placeholder stand-in for a real physics backend.
+const PLACEHOLDER_MAX_THRUST_ACCEL_MPS2: f64 = 20.0;
+const PLACEHOLDER_MAX_ANGULAR_RATE_RAD_S: f64 = std::f64::consts::PI;
+const GRAVITY_MPS2: f64 = 9.81;
+
+/// Integrate one agent's `VehicleState` from its most recently computed
+/// `VehicleControl`.
+///
+/// Placeholder point-mass model — throttle maps to thrust along the body
+/// +Z axis, roll/pitch/yaw map directly to body angular rates — standing
+/// in for a real rigid-body integration until `simulate_physics` is backed
+/// by `autonomysim-backends`. It exists so firmware/PID control actually
+/// moves the agent instead of being computed and discarded.
+fn integrate_simple_kinematics(state: &mut VehicleState, control: &VehicleControl, dt: f64) {
+    let body_thrust = Vector3::new(
+        0.0,
+        0.0,
+        control.throttle * PLACEHOLDER_MAX_THRUST_ACCEL_MPS2,
+    );
+    let acceleration =
+        state.transform.rotation * body_thrust - Vector3::new(0.0, 0.0, GRAVITY_MPS2);
+
+    state.angular_velocity =
+        Vector3::new(control.roll, control.pitch, control.yaw) * PLACEHOLDER_MAX_ANGULAR_RATE_RAD_S;
+    let angular_speed = state.angular_velocity.norm();
+    if angular_speed > 1e-8 {
+        let axis = Unit::new_normalize(state.angular_velocity);
+        let delta_rotation = UnitQuaternion::from_axis_angle(&axis, angular_speed * dt);
+        state.transform.rotation *= delta_rotation;
+    }
+
+    state.linear_acceleration = acceleration;
+    state.linear_velocity += acceleration * dt;
+    state.transform.position += state.linear_velocity * dt;
+}
 
 /// Worker node that simulates a subset of agents
 pub struct Worker {
@@ -13,6 +85,30 @@ pub struct Worker {
     message_bus: Arc<MessageBus>,
     agent_ids: Vec<usize>,
     current_step: u64,
+    /// Firmware-in-the-loop links for agents driven by an external autopilot
+    /// rather than this worker's own control law.
+    firmware: FirmwarePool,
+    /// Last known state per agent, used to feed firmware links each step.
+    agent_states: HashMap<usize, VehicleState>,
+    /// Most recently computed control per agent, applied by
+    /// `simulate_physics` at the start of the *next* step.
+    pending_controls: HashMap<usize, VehicleControl>,
+    /// In-process PID controller used for agents without a firmware link.
+    controller: PidController,
+    /// Persistent PID integrator/derivative state, one per agent.
+    controller_states: HashMap<usize, ControllerState>,
+    /// Commanded velocity/yaw-rate setpoints, one per agent. Defaults to
+    /// hover (zero velocity) for agents with no explicit setpoint.
+    setpoints: HashMap<usize, Setpoint>,
+    /// Publishes simulated sensor/state data onto ROS2-shaped topics.
+    ros_bridge: Ros2Bridge,
+    /// Most recent `Coordinator::layout_version` this worker has observed,
+    /// via `execute_step`'s `layout_version` argument.
+    layout_version: u64,
+    /// Per-agent level-of-detail band and update cadence bookkeeping, used
+    /// by `update_lod` when `SummonerConfig::lod` is set. Agents with no
+    /// entry default to `LodLevel::Near`.
+    lod_state: HashMap<usize, LodState>,
 }
 
 impl Worker {
@@ -35,9 +131,76 @@ impl Worker {
             message_bus,
             agent_ids,
             current_step: 0,
+            firmware: FirmwarePool::new(),
+            agent_states: HashMap::new(),
+            pending_controls: HashMap::new(),
+            controller: PidController::new(ControllerSettings::default()),
+            controller_states: HashMap::new(),
+            setpoints: HashMap::new(),
+            ros_bridge: Ros2Bridge::new(),
+            layout_version: 0,
+            lod_state: HashMap::new(),
         })
     }
 
+    /// Command a velocity/yaw-rate setpoint for an agent's PID controller.
+    /// Agents with a firmware link ignore this in favor of the autopilot's
+    /// own guidance.
+    pub fn set_setpoint(&mut self, agent_id: usize, setpoint: Setpoint) {
+        self.setpoints.insert(agent_id, setpoint);
+    }
+
+    /// Attach an external-autopilot firmware link to one of this worker's
+    /// agents. Once attached, `update_control` defers to the firmware's
+    /// actuator output instead of an in-process control law for that agent.
+    pub fn attach_firmware(&mut self, agent_id: usize, link: Box<dyn FirmwareLink>) {
+        self.firmware.attach(agent_id, link);
+    }
+
+    /// Detach a firmware link, returning control of the agent to the
+    /// in-process controller.
+    pub fn detach_firmware(&mut self, agent_id: usize) {
+        self.firmware.detach(agent_id);
+    }
+
+    /// Spawn a new agent onto this worker mid-simulation, or accept one
+    /// migrated in from another worker at a step boundary.
+    pub fn spawn_agent(&mut self, agent_id: usize, state: VehicleState) {
+        if !self.agent_ids.contains(&agent_id) {
+            self.agent_ids.push(agent_id);
+        }
+        self.agent_states.insert(agent_id, state);
+        self.controller_states.remove(&agent_id); // start the PID loop fresh
+    }
+
+    /// Remove an agent from this worker, returning its last known state so
+    /// it can be handed off to another worker or discarded entirely.
+    pub fn despawn_agent(&mut self, agent_id: usize) -> Option<VehicleState> {
+        self.agent_ids.retain(|&id| id != agent_id);
+        self.controller_states.remove(&agent_id);
+        self.setpoints.remove(&agent_id);
+        self.pending_controls.remove(&agent_id);
+        self.firmware.detach(agent_id);
+        self.agent_states.remove(&agent_id)
+    }
+
+    /// Serialize an agent's state for a `NodeMessage::MigrateAgent` hand-off
+    /// to another worker, removing it from this worker in the process.
+    pub fn migrate_out(&mut self, agent_id: usize) -> Result<Option<Vec<u8>>> {
+        match self.despawn_agent(agent_id) {
+            Some(state) => Ok(Some(serde_json::to_vec(&state)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Accept a migrated agent's serialized state from a
+    /// `NodeMessage::MigrateAgent` hand-off.
+    pub fn migrate_in(&mut self, agent_id: usize, state_bytes: &[u8]) -> Result<()> {
+        let state: VehicleState = serde_json::from_slice(state_bytes)?;
+        self.spawn_agent(agent_id, state);
+        Ok(())
+    }
+
     /// Assign agents to this worker
     fn assign_agents(worker_id: usize, config: &SummonerConfig) -> Vec<usize> {
         let num_workers = match &config.distribution {
@@ -56,6 +219,9 @@ impl Worker {
                 functional_layers,
                 ..
             } => spatial_partitions * functional_layers.len(),
+            crate::DistributionStrategy::WeightedPartitioning { num_partitions, .. } => {
+                *num_partitions
+            }
         };
 
         // Simple round-robin assignment
@@ -70,34 +236,108 @@ impl Worker {
         (start..end).collect()
     }
 
-    /// Execute one simulation step for this worker's agents
-    pub async fn execute_step(&mut self, dt: f64) -> Result<()> {
+    /// Execute one simulation step for this worker's agents. `layout_version`
+    /// is the coordinator's current cluster layout version, carried on the
+    /// triggering `NodeMessage::StepCommand`; a jump since the last step
+    /// means this worker's view of the cluster topology (e.g. which agents
+    /// it owns) may be stale following a join/decommission elsewhere.
+    /// `trace_context` carries the coordinator's `trace_id` for this tick so
+    /// this span attaches to the same distributed trace as the
+    /// coordinator's root `step` span. Returns the wall-clock duration of
+    /// each sub-phase, for `Summoner::step` to fold into its `PhaseProfiler`.
+    #[tracing::instrument(skip(self, dt), fields(worker_id = self.worker_id, trace_id = %trace_context.trace_id))]
+    pub async fn execute_step(
+        &mut self,
+        dt: f64,
+        layout_version: u64,
+        trace_context: TraceContext,
+    ) -> Result<PhaseTimings> {
         debug!(
             "Worker {} executing step {} with dt={}",
             self.worker_id, self.current_step, dt
         );
 
+        if layout_version != self.layout_version {
+            info!(
+                "Worker {} observed cluster layout change ({} -> {})",
+                self.worker_id, self.layout_version, layout_version
+            );
+            self.layout_version = layout_version;
+        }
+
+        // Level-of-detail: classify each agent into near/mid/far and
+        // ballistically dead-reckon any far agent whose coarse cadence
+        // fires this tick. Only the returned set gets a full
+        // sensors/control update below.
+        let full_update = self.update_lod(dt);
+
         // Simulate physics for assigned agents
-        self.simulate_physics(dt).await?;
+        let started = std::time::Instant::now();
+        self.simulate_physics(dt, &full_update).await?;
+        let physics = started.elapsed();
 
         // Simulate sensors
-        self.simulate_sensors(dt).await?;
+        let started = std::time::Instant::now();
+        self.simulate_sensors(dt, &full_update).await?;
+        let sensors = started.elapsed();
 
         // Simulate communications
+        let started = std::time::Instant::now();
         self.simulate_communications(dt).await?;
+        let communications = started.elapsed();
 
         // Update AI/control
-        self.update_control(dt).await?;
+        let started = std::time::Instant::now();
+        self.update_control(dt, &full_update).await?;
+        let control = started.elapsed();
 
+        let completed_step = self.current_step;
         self.current_step += 1;
 
-        Ok(())
+        // Liveness signal: lets the coordinator's `cluster_status` tell this
+        // worker apart from one that stalled or crashed mid-step.
+        self.message_bus
+            .broadcast(crate::NodeMessage::Heartbeat {
+                worker_id: self.worker_id,
+            })
+            .await?;
+
+        // Ack for `Coordinator::step_with_barrier`, so a coordinator
+        // waiting on the distributed step barrier can release it.
+        self.message_bus
+            .broadcast(crate::NodeMessage::StepComplete {
+                worker_id: self.worker_id,
+                step: completed_step,
+            })
+            .await?;
+
+        Ok(PhaseTimings {
+            physics,
+            sensors,
+            communications,
+            control,
+        })
     }
 
-    /// Simulate physics for agents
-    async fn simulate_physics(&self, _dt: f64) -> Result<()> {
-        // TODO: Integrate with autonomysim-backends for actual physics
-        // For now, placeholder
+    /// Apply each full-update agent's most recently computed control (from
+    /// the previous step's `update_control`) to its `VehicleState` via
+    /// `integrate_simple_kinematics`.
+    ///
+    /// This is a placeholder point-mass integration, not a real rigid-body
+    /// simulation — `autonomysim-backends` still owns that. It exists so a
+    /// firmware/PID control actually moves the agent instead of being
+    /// computed and dropped. Agents with no control yet (the first step
+    /// after spawn) are left at their last known state.
+    async fn simulate_physics(&mut self, dt: f64, full_update: &HashSet<usize>) -> Result<()> {
+        for &agent_id in self.agent_ids.iter().filter(|id| full_update.contains(id)) {
+            let Some(control) = self.pending_controls.get(&agent_id) else {
+                continue;
+            };
+            let Some(state) = self.agent_states.get_mut(&agent_id) else {
+                continue;
+            };
+            integrate_simple_kinematics(state, control, dt);
+        }
         debug!(
             "Worker {} simulating physics for {} agents",
             self.worker_id,
@@ -106,9 +346,31 @@ impl Worker {
         Ok(())
     }
 
-    /// Simulate sensors
-    async fn simulate_sensors(&self, _dt: f64) -> Result<()> {
-        // TODO: Simulate IMU, GPS, camera, lidar, etc.
+    /// Simulate sensors for the agents due a full update this tick (see
+    /// `update_lod`).
+    async fn simulate_sensors(&mut self, _dt: f64, full_update: &HashSet<usize>) -> Result<()> {
+        // TODO: Simulate IMU, GPS, camera, lidar, etc. For now, publish
+        // whatever ground-truth state each agent last reported so the
+        // ROS2 bridge's TF tree stays populated.
+        for &agent_id in self.agent_ids.iter().filter(|id| full_update.contains(id)) {
+            let Some(state) = self.agent_states.get(&agent_id) else {
+                continue;
+            };
+            let batch = self.ros_bridge.publish_step(
+                state,
+                &HashMap::new(),
+                &HashMap::new(),
+                None,
+                None,
+                None,
+            );
+            debug!(
+                "Worker {} published {} TF frame(s) for agent {}",
+                self.worker_id,
+                batch.tf_frames.len(),
+                agent_id
+            );
+        }
         debug!("Worker {} simulating sensors", self.worker_id);
         Ok(())
     }
@@ -120,13 +382,197 @@ impl Worker {
         Ok(())
     }
 
-    /// Update control and AI
-    async fn update_control(&self, _dt: f64) -> Result<()> {
-        // TODO: Run AI inference, control laws
+    /// Update control and AI for the agents due a full update this tick
+    /// (see `update_lod`).
+    async fn update_control(&mut self, dt: f64, full_update: &HashSet<usize>) -> Result<()> {
+        self.apply_cohesion(full_update);
+
+        // Firmware-controlled agents get their actuator output from the
+        // external autopilot over MAVLink HIL.
+        let time_usec = (self.current_step as f64 * self.config.timestep * 1_000_000.0) as u64;
+        let firmware_controls = self.firmware.step(&self.agent_states, time_usec).await;
+        for (agent_id, control) in firmware_controls
+            .iter()
+            .filter(|(id, _)| full_update.contains(id))
+        {
+            debug!(
+                "Worker {} applying firmware control for agent {}: {:?}",
+                self.worker_id, agent_id, control
+            );
+            self.pending_controls.insert(*agent_id, control.clone());
+        }
+
+        // Every other full-update agent runs the in-process PID stack
+        // against its commanded setpoint (hover if none was given).
+        for &agent_id in self.agent_ids.iter().filter(|id| full_update.contains(id)) {
+            if firmware_controls.contains_key(&agent_id) {
+                continue;
+            }
+            let Some(state) = self.agent_states.get(&agent_id) else {
+                continue;
+            };
+            let setpoint = self.setpoints.get(&agent_id).copied().unwrap_or_default();
+            let pid_state = self.controller_states.entry(agent_id).or_default();
+            let control =
+                self.controller
+                    .update(VehicleType::Multirotor, state, setpoint, pid_state, dt);
+            debug!(
+                "Worker {} computed PID control for agent {}: {:?}",
+                self.worker_id, agent_id, control
+            );
+            self.pending_controls.insert(agent_id, control);
+        }
+
         debug!("Worker {} updating control", self.worker_id);
         Ok(())
     }
 
+    /// Recompute the velocity setpoint of every agent due a full update
+    /// this tick from [`cohesion::desired_velocities`] when
+    /// [`SummonerConfig::cohesion`] is set, so the PID loop right after
+    /// this tracks the flocking force instead of whatever `set_setpoint`
+    /// last commanded. A no-op when cohesion isn't configured. Agents
+    /// outside `full_update` (LOD mid/far, not due this tick) never
+    /// contribute to or receive a neighbor query.
+    ///
+    /// When [`SummonerConfig::perception`] is also set, neighbors only
+    /// contribute to each other's velocity setpoint when each can actually
+    /// see the other (see [`perception::desired_velocities_with_perception`])
+    /// instead of every in-range neighbor being visible regardless of
+    /// facing or occlusion.
+    fn apply_cohesion(&mut self, full_update: &HashSet<usize>) {
+        let Some(params) = &self.config.cohesion else {
+            return;
+        };
+
+        let velocities = if let Some(perception_config) = &self.config.perception {
+            let agents: HashMap<usize, perception::Agent> = self
+                .agent_ids
+                .iter()
+                .filter(|id| full_update.contains(id))
+                .filter_map(|&id| {
+                    self.agent_states.get(&id).map(|state| {
+                        (
+                            id,
+                            perception::Agent {
+                                position: state.transform.position,
+                                facing: state.transform.rotation * Vector3::new(0.0, 1.0, 0.0),
+                                view_distance: perception_config.view_distance,
+                                fov: perception_config.fov,
+                                quality: perception_config.quality,
+                            },
+                        )
+                    })
+                })
+                .collect();
+
+            perception::desired_velocities_with_perception(&agents, params)
+        } else {
+            let positions: HashMap<usize, Point3<f64>> = self
+                .agent_ids
+                .iter()
+                .filter(|id| full_update.contains(id))
+                .filter_map(|&id| {
+                    self.agent_states
+                        .get(&id)
+                        .map(|state| (id, state.transform.position))
+                })
+                .collect();
+
+            cohesion::desired_velocities(&positions, params)
+        };
+
+        for (agent_id, linear_velocity) in velocities {
+            self.setpoints.insert(
+                agent_id,
+                Setpoint {
+                    linear_velocity,
+                    yaw_rate: 0.0,
+                },
+            );
+        }
+    }
+
+    /// Classify every owned agent into a LOD band by distance to the
+    /// nearest `SummonerConfig::lod` focus point, advance its update
+    /// cadence bookkeeping, and ballistically dead-reckon any far agent
+    /// whose coarse cadence fires this tick. Returns the set of agents due
+    /// a full sensors/control update this tick -- every agent, unchanged
+    /// from before LOD existed, when `SummonerConfig::lod` is unset.
+    fn update_lod(&mut self, dt: f64) -> HashSet<usize> {
+        let Some(config) = self.config.lod.clone() else {
+            return self.agent_ids.iter().copied().collect();
+        };
+
+        let mut full_update = HashSet::new();
+        let mut ballistic = Vec::new();
+        for &agent_id in &self.agent_ids {
+            let Some(state) = self.agent_states.get(&agent_id) else {
+                continue;
+            };
+            let distance_sq =
+                lod::nearest_focus_distance_sq(state.transform.position, &config.focus_points);
+
+            let entry = self.lod_state.entry(agent_id).or_default();
+            entry.level = lod::classify(entry.level, distance_sq, &config);
+            let due = lod::should_update(entry.level, entry.steps_since_update, &config);
+
+            match entry.level {
+                LodLevel::Near => {
+                    full_update.insert(agent_id);
+                    entry.steps_since_update = 0;
+                    entry.accumulated_dt = 0.0;
+                }
+                LodLevel::Mid => {
+                    entry.accumulated_dt += dt;
+                    if due {
+                        full_update.insert(agent_id);
+                        entry.steps_since_update = 0;
+                        entry.accumulated_dt = 0.0;
+                    } else {
+                        entry.steps_since_update += 1;
+                    }
+                }
+                LodLevel::Far => {
+                    entry.accumulated_dt += dt;
+                    if due {
+                        ballistic.push((agent_id, entry.accumulated_dt));
+                        entry.steps_since_update = 0;
+                        entry.accumulated_dt = 0.0;
+                    } else {
+                        entry.steps_since_update += 1;
+                    }
+                }
+            }
+        }
+
+        for (agent_id, elapsed) in ballistic {
+            if let Some(state) = self.agent_states.get_mut(&agent_id) {
+                let velocity = state.linear_velocity;
+                state.transform.position += velocity * elapsed;
+            }
+        }
+
+        full_update
+    }
+
+    /// Current agent count per LOD band, for `Summoner::step` to fold into
+    /// `SummonerMetrics::lod_histogram`. Every agent reports
+    /// `LodLevel::Near` until `update_lod` has classified it (or when
+    /// `SummonerConfig::lod` is unset).
+    pub fn lod_histogram(&self) -> HashMap<LodLevel, usize> {
+        let mut histogram = HashMap::new();
+        for &agent_id in &self.agent_ids {
+            let level = self
+                .lod_state
+                .get(&agent_id)
+                .map(|state| state.level)
+                .unwrap_or_default();
+            *histogram.entry(level).or_insert(0) += 1;
+        }
+        histogram
+    }
+
     /// Get worker ID
     pub fn worker_id(&self) -> usize {
         self.worker_id
@@ -136,4 +582,18 @@ impl Worker {
     pub fn num_agents(&self) -> usize {
         self.agent_ids.len()
     }
+
+    /// Inclusive `(min, max)` of this worker's currently assigned agent ids,
+    /// or `(0, 0)` if it owns none. Used to populate
+    /// `Coordinator::register_worker`'s `agent_range`.
+    pub fn agent_range(&self) -> (usize, usize) {
+        let min = self.agent_ids.iter().copied().min().unwrap_or(0);
+        let max = self.agent_ids.iter().copied().max().unwrap_or(0);
+        (min, max)
+    }
+
+    /// This worker's currently assigned agent ids, in no particular order.
+    pub fn agent_ids(&self) -> &[usize] {
+        &self.agent_ids
+    }
 }
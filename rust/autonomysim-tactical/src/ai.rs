@@ -0,0 +1,242 @@
+//! Per-robot tactical AI: a behavior FSM driven by threat/support levels
+//!
+//! Each robot runs this FSM independently every step, selecting a behavior
+//! state from its own locally-observable threat exposure (nearby hostile
+//! contacts, plus jamming J/S at its position) and support (friendly units
+//! in RF range, supplied by the caller) -- no central tactical planner, the
+//! same decentralized posture as [`crate::mesh`]'s relay routing and
+//! `autonomysim_core::swarm::Flock`'s neighbor-only flocking. Range gating
+//! against `RFPropagationEngine`/`NetworkTopology` is left to the caller,
+//! same as [`crate::network`]; this module only turns the numbers the
+//! caller already computed into a state transition.
+
+use nalgebra::Vector3;
+
+use crate::jamming::{jsr_thresholds, JammingModel};
+
+/// A robot's current tactical behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BehaviorState {
+    /// No threat, no mission target -- holding position.
+    Idle,
+    /// Moving toward a waypoint/formation slot; no meaningful threat.
+    Transit,
+    /// Closing with the highest-priority hostile contact.
+    Engage,
+    /// Threat exceeds tolerance; climbing/scattering away from it.
+    Evade,
+    /// Holding a relay position to maintain link coverage.
+    RelayHold,
+    /// Falling back toward friendly support before re-engaging.
+    Regroup,
+}
+
+/// Which role drives the FSM -- different roles prioritize different
+/// transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitClass {
+    Scout,
+    Combat,
+    Relay,
+    Other,
+}
+
+/// A hostile contact the threat assessment considers.
+#[derive(Debug, Clone, Copy)]
+pub struct ThreatContact {
+    pub position: Vector3<f64>,
+    /// Relative priority -- higher engages first.
+    pub priority: f64,
+}
+
+/// Tuning for threat/support thresholds.
+#[derive(Debug, Clone)]
+pub struct TacticalAIConfig {
+    /// Threat level (contact proximity plus normalized jamming J/S) at or
+    /// above which Scout/Other units enter [`BehaviorState::Evade`].
+    pub evade_threat_threshold: f64,
+
+    /// Minimum friendlies in RF range for a Combat unit with a contact to
+    /// [`BehaviorState::Engage`] instead of falling back to
+    /// [`BehaviorState::Regroup`].
+    pub engage_support_threshold: usize,
+}
+
+impl Default for TacticalAIConfig {
+    fn default() -> Self {
+        Self {
+            evade_threat_threshold: 1.0,
+            engage_support_threshold: 2,
+        }
+    }
+}
+
+/// Per-robot tactical AI: owns one robot's FSM state and decides its next
+/// transition from a threat/support snapshot the caller supplies.
+pub struct TacticalAI {
+    config: TacticalAIConfig,
+    state: BehaviorState,
+}
+
+impl TacticalAI {
+    pub fn new(config: TacticalAIConfig) -> Self {
+        Self {
+            config,
+            state: BehaviorState::Idle,
+        }
+    }
+
+    pub fn config(&self) -> &TacticalAIConfig {
+        &self.config
+    }
+
+    pub fn state(&self) -> BehaviorState {
+        self.state
+    }
+
+    /// Aggregate threat at `position`: the sum of each `contacts` entry's
+    /// priority divided by its distance (closer/higher-priority contacts
+    /// dominate), plus jamming exposure normalized against
+    /// `jsr_thresholds::COMPLETE_DENIAL` so a fully-denied link contributes
+    /// 1.0. `reference_signal_dbm` is the signal strength this robot would
+    /// otherwise receive absent jamming (e.g. its link back to a
+    /// coordinator) -- queried by the caller via `RFPropagationEngine`.
+    pub fn threat_level(
+        &self,
+        position: Vector3<f64>,
+        contacts: &[ThreatContact],
+        jamming: Option<&JammingModel>,
+        reference_signal_dbm: f64,
+    ) -> f64 {
+        let contact_threat: f64 = contacts
+            .iter()
+            .map(|c| c.priority / (c.position - position).norm().max(1.0))
+            .sum();
+
+        let jam_threat = jamming
+            .map(|jammer| {
+                let jamming_dbm = jammer.compute_jamming_power(position);
+                let jsr_db =
+                    jammer.compute_jamming_to_signal_ratio(reference_signal_dbm, jamming_dbm);
+                (jsr_db / jsr_thresholds::COMPLETE_DENIAL).max(0.0)
+            })
+            .unwrap_or(0.0);
+
+        contact_threat + jam_threat
+    }
+
+    /// Select (and latch) this robot's behavior state for one step.
+    /// `nearest_contact` is whichever hostile contact is closest --
+    /// [`BehaviorState::Engage`] and [`BehaviorState::Evade`] orient
+    /// toward/away from it.
+    pub fn step(
+        &mut self,
+        class: UnitClass,
+        threat_level: f64,
+        support_level: usize,
+        nearest_contact: Option<ThreatContact>,
+    ) -> BehaviorState {
+        self.state = match class {
+            UnitClass::Relay => BehaviorState::RelayHold,
+            UnitClass::Scout | UnitClass::Other => {
+                if threat_level >= self.config.evade_threat_threshold {
+                    BehaviorState::Evade
+                } else {
+                    BehaviorState::Transit
+                }
+            }
+            UnitClass::Combat => match nearest_contact {
+                None => BehaviorState::Transit,
+                Some(_) if support_level >= self.config.engage_support_threshold => {
+                    BehaviorState::Engage
+                }
+                Some(_) => BehaviorState::Regroup,
+            },
+        };
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threat_level_increases_with_closer_contact() {
+        let ai = TacticalAI::new(TacticalAIConfig::default());
+        let position = Vector3::new(0.0, 0.0, 0.0);
+
+        let near = [ThreatContact {
+            position: Vector3::new(10.0, 0.0, 0.0),
+            priority: 1.0,
+        }];
+        let far = [ThreatContact {
+            position: Vector3::new(1000.0, 0.0, 0.0),
+            priority: 1.0,
+        }];
+
+        assert!(ai.threat_level(position, &near, None, -60.0) > ai.threat_level(position, &far, None, -60.0));
+    }
+
+    #[test]
+    fn test_threat_level_includes_jamming_exposure() {
+        use crate::jamming::{JammingConfig, JammingType};
+
+        let ai = TacticalAI::new(TacticalAIConfig::default());
+        let position = Vector3::new(0.0, 0.0, 0.0);
+        let jammer = JammingModel::new(JammingConfig {
+            jammer_position: position,
+            jammer_power_dbm: 60.0,
+            jamming_type: JammingType::Barrage,
+            ..Default::default()
+        });
+
+        let unjammed = ai.threat_level(position, &[], None, -60.0);
+        let jammed = ai.threat_level(position, &[], Some(&jammer), -60.0);
+        assert!(jammed > unjammed);
+    }
+
+    #[test]
+    fn test_scout_evades_above_threshold() {
+        let mut ai = TacticalAI::new(TacticalAIConfig::default());
+        assert_eq!(
+            ai.step(UnitClass::Scout, 0.1, 0, None),
+            BehaviorState::Transit
+        );
+        assert_eq!(
+            ai.step(UnitClass::Scout, 5.0, 0, None),
+            BehaviorState::Evade
+        );
+    }
+
+    #[test]
+    fn test_combat_engages_with_support_else_regroups() {
+        let mut ai = TacticalAI::new(TacticalAIConfig::default());
+        let contact = Some(ThreatContact {
+            position: Vector3::new(50.0, 0.0, 0.0),
+            priority: 1.0,
+        });
+
+        assert_eq!(
+            ai.step(UnitClass::Combat, 2.0, 0, contact),
+            BehaviorState::Regroup
+        );
+        assert_eq!(
+            ai.step(UnitClass::Combat, 2.0, 3, contact),
+            BehaviorState::Engage
+        );
+        assert_eq!(
+            ai.step(UnitClass::Combat, 0.0, 3, None),
+            BehaviorState::Transit
+        );
+    }
+
+    #[test]
+    fn test_relay_always_holds() {
+        let mut ai = TacticalAI::new(TacticalAIConfig::default());
+        assert_eq!(
+            ai.step(UnitClass::Relay, 10.0, 0, None),
+            BehaviorState::RelayHold
+        );
+    }
+}
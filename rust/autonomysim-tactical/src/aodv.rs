@@ -0,0 +1,354 @@
+//! AODV-style reactive MANET routing on top of [`NetworkTopology`]
+//!
+//! [`NetworkTopology`] only answers static graph queries (shortest path,
+//! connectivity) from a God's-eye view of every link. [`AodvRouter`] instead
+//! models what each agent itself would know: routes are discovered
+//! on-demand by flooding a [`RouteRequest`] and installed hop-by-hop as the
+//! matching [`RouteReply`] walks back, mirroring RFC 3561 AODV closely
+//! enough to measure control overhead and convergence under mobility.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::network::{AgentId, NetworkTopology};
+
+/// Route discovery request, flooded breadth-first across [`NetworkTopology::get_neighbors`].
+#[derive(Debug, Clone, Copy)]
+pub struct RouteRequest {
+    pub originator: AgentId,
+    pub destination: AgentId,
+    pub request_id: u64,
+    pub hop_count: u32,
+}
+
+/// Route reply, walked back along an RREQ's reverse path to install
+/// next-hop entries at every intermediate node.
+#[derive(Debug, Clone, Copy)]
+pub struct RouteReply {
+    pub destination: AgentId,
+    pub dest_seq: u64,
+    pub hop_count: u32,
+}
+
+/// Route error raised when an active-path link is pruned, naming the
+/// destination that became unreachable through the node it's delivered to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteError {
+    pub unreachable_destination: AgentId,
+}
+
+/// A single next-hop route, as installed by a [`RouteReply`].
+#[derive(Debug, Clone, Copy)]
+pub struct RouteEntry {
+    /// Next hop on the path toward the destination.
+    pub next_hop: AgentId,
+    /// Hops from this node to the destination.
+    pub hop_count: u32,
+    /// Destination sequence number, bumped each time a fresh route to that
+    /// destination is discovered (RFC 3561 freshness/loop-avoidance rule).
+    pub dest_seq: u64,
+    /// Simulation time after which the route is considered stale.
+    pub expiry_time: f64,
+}
+
+/// A single agent's routing table, keyed by destination.
+#[derive(Debug, Clone, Default)]
+pub struct RoutingTable {
+    routes: HashMap<AgentId, RouteEntry>,
+}
+
+impl RoutingTable {
+    /// Route to `destination`, if one is installed (regardless of freshness).
+    pub fn route_to(&self, destination: AgentId) -> Option<&RouteEntry> {
+        self.routes.get(&destination)
+    }
+
+    /// Whether a route to `destination` exists and hasn't expired as of `current_time`.
+    pub fn has_fresh_route(&self, destination: AgentId, current_time: f64) -> bool {
+        self.routes
+            .get(&destination)
+            .is_some_and(|entry| entry.expiry_time > current_time)
+    }
+}
+
+/// Reactive on-demand router: owns one [`RoutingTable`] per agent plus the
+/// bookkeeping ([`RouteRequest`] dedup, precursor lists) needed to discover,
+/// install, and tear down routes over a [`NetworkTopology`].
+#[derive(Debug, Clone, Default)]
+pub struct AodvRouter {
+    tables: HashMap<AgentId, RoutingTable>,
+
+    /// Per-node set of `(originator, request_id)` pairs already flooded,
+    /// so a node re-hearing the same RREQ on a different path drops it
+    /// instead of re-broadcasting.
+    seen_requests: HashMap<AgentId, HashSet<(AgentId, u64)>>,
+
+    /// Nodes that route toward a destination via a given relay, keyed by
+    /// `(relay, destination)`. Lets a RERR at `relay` walk upstream to
+    /// every node whose route depends on it without re-flooding.
+    precursors: HashMap<(AgentId, AgentId), HashSet<AgentId>>,
+
+    next_request_id: u64,
+    next_dest_seq: HashMap<AgentId, u64>,
+}
+
+impl AodvRouter {
+    /// Create a router with no discovered routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Installed routing table for `agent`, if any route discovery has
+    /// touched it yet.
+    pub fn routing_table(&self, agent: AgentId) -> Option<&RoutingTable> {
+        self.tables.get(&agent)
+    }
+
+    /// Discover a route from `source` to `destination` over `topology`.
+    ///
+    /// Returns the cached route's path immediately if `source` already
+    /// holds a fresh one. Otherwise floods an RREQ breadth-first from
+    /// `source`: each node relays it to its unseen neighbors (per-node
+    /// `(originator, request_id)` dedup prevents rebroadcast storms) until
+    /// it reaches `destination`. The first arrival's reverse path is then
+    /// walked back as an RREP, installing a next-hop [`RouteEntry`] at
+    /// every intermediate node and recording each as a precursor of the
+    /// next hop it now depends on. Returns `None` if no path exists.
+    pub fn discover_route(
+        &mut self,
+        topology: &NetworkTopology,
+        source: AgentId,
+        destination: AgentId,
+    ) -> Option<Vec<AgentId>> {
+        if self
+            .tables
+            .get(&source)
+            .is_some_and(|table| table.has_fresh_route(destination, topology.current_time()))
+        {
+            return Some(self.walk_cached_route(source, destination));
+        }
+
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+
+        // `came_from` doubles as the reverse path built up by the flood and
+        // the per-node record of which RREQ a node is relaying.
+        let mut came_from: HashMap<AgentId, AgentId> = HashMap::new();
+        let mut queue: VecDeque<(AgentId, RouteRequest)> = VecDeque::new();
+        self.seen_requests
+            .entry(source)
+            .or_default()
+            .insert((source, request_id));
+        queue.push_back((
+            source,
+            RouteRequest {
+                originator: source,
+                destination,
+                request_id,
+                hop_count: 0,
+            },
+        ));
+
+        let mut reply: Option<RouteReply> = None;
+        if source == destination {
+            reply = Some(RouteReply {
+                destination,
+                dest_seq: 0,
+                hop_count: 0,
+            });
+        }
+
+        while reply.is_none() {
+            let Some((node, rreq)) = queue.pop_front() else {
+                break;
+            };
+
+            for neighbor in topology.get_neighbors(node) {
+                let seen = self.seen_requests.entry(neighbor).or_default();
+                if !seen.insert((source, request_id)) {
+                    continue;
+                }
+                came_from.insert(neighbor, node);
+
+                let relayed = RouteRequest {
+                    hop_count: rreq.hop_count + 1,
+                    ..rreq
+                };
+                if neighbor == destination {
+                    reply = Some(RouteReply {
+                        destination,
+                        dest_seq: *self.next_dest_seq.entry(destination).or_insert(0) + 1,
+                        hop_count: relayed.hop_count,
+                    });
+                    break;
+                }
+                queue.push_back((neighbor, relayed));
+            }
+        }
+
+        let reply = reply?;
+        self.next_dest_seq.insert(destination, reply.dest_seq);
+
+        let mut path = vec![destination];
+        let mut current = destination;
+        while current != source {
+            current = *came_from.get(&current)?;
+            path.push(current);
+        }
+        path.reverse();
+
+        let expiry_time = topology.current_time() + topology.link_timeout_s();
+        for i in (0..path.len() - 1).rev() {
+            let node = path[i];
+            let next_hop = path[i + 1];
+            let hop_count = (path.len() - 1 - i) as u32;
+
+            self.tables.entry(node).or_default().routes.insert(
+                reply.destination,
+                RouteEntry {
+                    next_hop,
+                    hop_count,
+                    dest_seq: reply.dest_seq,
+                    expiry_time,
+                },
+            );
+            self.precursors
+                .entry((next_hop, reply.destination))
+                .or_default()
+                .insert(node);
+        }
+
+        Some(path)
+    }
+
+    /// Reconstruct a cached route's path by following installed next hops.
+    fn walk_cached_route(&self, source: AgentId, destination: AgentId) -> Vec<AgentId> {
+        let mut path = vec![source];
+        let mut current = source;
+        while current != destination {
+            let Some(entry) = self.tables.get(&current).and_then(|t| t.route_to(destination))
+            else {
+                break;
+            };
+            current = entry.next_hop;
+            path.push(current);
+        }
+        path
+    }
+
+    /// React to [`NetworkTopology::update_time`] pruning a link: invalidate
+    /// every route whose next hop no longer has a live link, and propagate
+    /// a [`RouteError`] to every upstream precursor that depended on it,
+    /// recursively. Returns `(node, error)` pairs for every route torn
+    /// down, in invalidation order.
+    pub fn sync_routes(&mut self, topology: &NetworkTopology) -> Vec<(AgentId, RouteError)> {
+        let mut errors = Vec::new();
+        let mut worklist: VecDeque<(AgentId, AgentId)> = VecDeque::new();
+
+        for (&node, table) in &self.tables {
+            for (&destination, entry) in &table.routes {
+                if topology.get_link(node, entry.next_hop).is_none() {
+                    worklist.push_back((node, destination));
+                }
+            }
+        }
+
+        while let Some((node, destination)) = worklist.pop_front() {
+            let Some(table) = self.tables.get_mut(&node) else {
+                continue;
+            };
+            if table.routes.remove(&destination).is_none() {
+                continue;
+            }
+
+            errors.push((
+                node,
+                RouteError {
+                    unreachable_destination: destination,
+                },
+            ));
+
+            if let Some(upstream) = self.precursors.remove(&(node, destination)) {
+                worklist.extend(upstream.into_iter().map(|u| (u, destination)));
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Vector3;
+    use rand::SeedableRng;
+
+    fn linear_topology(len: usize) -> NetworkTopology {
+        let mut topology = NetworkTopology::new();
+        for i in 0..len {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        let quality = crate::network::LinkQuality::default();
+        for i in 0..len.saturating_sub(1) {
+            topology.add_link(i, i + 1, quality);
+            topology.add_link(i + 1, i, quality);
+        }
+        topology
+    }
+
+    #[test]
+    fn test_discover_route_installs_next_hop_at_every_intermediate_node() {
+        let topology = linear_topology(4);
+        let mut router = AodvRouter::new();
+
+        let path = router.discover_route(&topology, 0, 3).unwrap();
+        assert_eq!(path, vec![0, 1, 2, 3]);
+
+        assert_eq!(router.routing_table(0).unwrap().route_to(3).unwrap().next_hop, 1);
+        assert_eq!(router.routing_table(1).unwrap().route_to(3).unwrap().next_hop, 2);
+        assert_eq!(router.routing_table(2).unwrap().route_to(3).unwrap().next_hop, 3);
+    }
+
+    #[test]
+    fn test_discover_route_returns_none_when_unreachable() {
+        let mut topology = linear_topology(2);
+        topology.add_agent(5, Vector3::new(999.0, 0.0, 0.0));
+        let mut router = AodvRouter::new();
+
+        assert!(router.discover_route(&topology, 0, 5).is_none());
+    }
+
+    #[test]
+    fn test_discover_route_reuses_fresh_cached_route() {
+        let topology = linear_topology(3);
+        let mut router = AodvRouter::new();
+
+        let first = router.discover_route(&topology, 0, 2).unwrap();
+        let dest_seq_before = router.routing_table(0).unwrap().route_to(2).unwrap().dest_seq;
+
+        let second = router.discover_route(&topology, 0, 2).unwrap();
+        let dest_seq_after = router.routing_table(0).unwrap().route_to(2).unwrap().dest_seq;
+
+        assert_eq!(first, second);
+        assert_eq!(dest_seq_before, dest_seq_after);
+    }
+
+    #[test]
+    fn test_sync_routes_propagates_rerr_to_upstream_precursors() {
+        let mut topology = linear_topology(4);
+        let mut router = AodvRouter::new();
+        router.discover_route(&topology, 0, 3).unwrap();
+
+        // Let every link time out (default `link_timeout_s` is 5s), breaking
+        // the whole path including the final (2, 3) hop.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        topology.update_time(10.0, &mut rng);
+
+        let errors = router.sync_routes(&topology);
+        let touched: HashSet<AgentId> = errors.iter().map(|(node, _)| *node).collect();
+
+        // Nodes 0, 1, and 2 all routed toward 3 and must all be torn down,
+        // even though only the (2, 3) link itself actually broke.
+        assert_eq!(touched, HashSet::from([0, 1, 2]));
+        assert!(router.routing_table(0).unwrap().route_to(3).is_none());
+    }
+}
@@ -0,0 +1,289 @@
+//! Cognitive jammer: a multi-armed bandit over jamming parameters.
+//!
+//! [`JammingModel`] is a fixed physical model of one jammer configuration,
+//! but an operator rarely knows in advance which combination of jamming
+//! type, transmit power, and on/off duty cycle will deny the most victim
+//! throughput per watt radiated. `CognitiveJammer` treats each
+//! (type, power, duty cycle) combination as a bandit arm and drives arm
+//! selection with UCB1, so it homes in on the best arm from observed
+//! victim feedback alone, with no model of the victim link itself.
+
+use crate::jamming::{JammingConfig, JammingModel, JammingType};
+use autonomysim_rf_core::utils::dbm_to_watts;
+use nalgebra::Vector3;
+
+/// One observation of the victim link to score the arm that was just played.
+#[derive(Debug, Clone, Copy)]
+pub struct VictimFeedback {
+    /// Victim's desired signal power at its receiver (dBm).
+    pub signal_power_dbm: f64,
+    /// Victim receiver position (meters, NED frame).
+    pub target_position: Vector3<f64>,
+    /// Victim receiver noise floor (dBm).
+    pub noise_power_dbm: f64,
+    /// Victim packet length, for the PER approximation (bits).
+    pub packet_length_bits: usize,
+}
+
+/// One (jamming type, power, duty cycle) bandit arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JammingArm {
+    pub jamming_type: JammingType,
+    /// Jammer transmit power for this arm (dBm).
+    pub power_dbm: f64,
+    /// Fraction of the time the jammer radiates; the rest of the time it's
+    /// off, saving energy and reducing the inflicted PER proportionally.
+    pub duty_cycle: f64,
+}
+
+/// Tuning for [`CognitiveJammer`]: the arm grid and reward shaping.
+#[derive(Debug, Clone)]
+pub struct CognitiveJammerConfig {
+    /// Fields shared by every arm's [`JammingConfig`] -- position, frequency,
+    /// bandwidth, antenna/loss terms. Only `jamming_type` and
+    /// `jammer_power_dbm` vary per arm.
+    pub base_config: JammingConfig,
+
+    /// Jamming types to consider as arms.
+    pub jamming_types: Vec<JammingType>,
+    /// Transmit power candidates to consider as arms (dBm).
+    pub power_candidates_dbm: Vec<f64>,
+    /// Duty cycle candidates to consider as arms, in `(0.0, 1.0]`.
+    pub duty_cycle_candidates: Vec<f64>,
+
+    /// Reward penalty per watt of average radiated power
+    /// (`jammer_power_w * duty_cycle`), subtracted from inflicted PER.
+    /// Tune this so the penalty stays well within `[0.0, 1.0]` for the
+    /// power range in use, or it will swamp the PER term.
+    pub energy_penalty_per_watt: f64,
+}
+
+impl Default for CognitiveJammerConfig {
+    fn default() -> Self {
+        Self {
+            base_config: JammingConfig::default(),
+            jamming_types: vec![
+                JammingType::Barrage,
+                JammingType::Follower { tracking_time: 0.1 },
+                JammingType::Deception { delay: 0.05 },
+            ],
+            power_candidates_dbm: vec![30.0, 40.0, 50.0],
+            duty_cycle_candidates: vec![0.25, 0.5, 1.0],
+            energy_penalty_per_watt: 0.01,
+        }
+    }
+}
+
+/// Running sample mean and pull count for one arm.
+#[derive(Debug, Clone, Copy, Default)]
+struct ArmStats {
+    mean_reward: f64,
+    pull_count: u64,
+}
+
+/// Adaptive jammer: plays [`JammingArm`]s via UCB1 to maximize inflicted PER
+/// per unit energy against an unknown victim link.
+///
+/// Regret against the best fixed arm is `O(sqrt(t * ln(t)))` under UCB1, so
+/// the jammer converges on the best arm but never stops exploring entirely --
+/// a red-team simulation can run it indefinitely rather than "solving" it.
+pub struct CognitiveJammer {
+    config: CognitiveJammerConfig,
+    arms: Vec<JammingArm>,
+    stats: Vec<ArmStats>,
+    total_plays: u64,
+}
+
+impl CognitiveJammer {
+    /// Build the arm grid from `config`'s candidate lists (their cross
+    /// product) and a fresh, unplayed set of per-arm stats.
+    pub fn new(config: CognitiveJammerConfig) -> Self {
+        let arms: Vec<JammingArm> = config
+            .jamming_types
+            .iter()
+            .flat_map(|&jamming_type| {
+                config
+                    .power_candidates_dbm
+                    .iter()
+                    .flat_map(move |&power_dbm| {
+                        config
+                            .duty_cycle_candidates
+                            .iter()
+                            .map(move |&duty_cycle| JammingArm {
+                                jamming_type,
+                                power_dbm,
+                                duty_cycle,
+                            })
+                    })
+            })
+            .collect();
+        let stats = vec![ArmStats::default(); arms.len()];
+        Self {
+            config,
+            arms,
+            stats,
+            total_plays: 0,
+        }
+    }
+
+    /// Index of the arm UCB1 would play next: any never-played arm first
+    /// (forced exploration), else the arm maximizing
+    /// `mean_i + sqrt(2 * ln(t) / n_i)`.
+    fn select_arm(&self) -> usize {
+        if let Some(unplayed) = self.stats.iter().position(|s| s.pull_count == 0) {
+            return unplayed;
+        }
+        let t = self.total_plays as f64;
+        self.stats
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let n_i = s.pull_count as f64;
+                (i, s.mean_reward + (2.0 * t.ln() / n_i).sqrt())
+            })
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).expect("UCB scores are never NaN"))
+            .map(|(i, _)| i)
+            .expect("arm grid is non-empty")
+    }
+
+    /// Play the next arm, score it against `victim_feedback`, fold the
+    /// reward into that arm's running mean, and return the arm's
+    /// [`JammingConfig`] for the caller to actually apply.
+    pub fn step(&mut self, victim_feedback: VictimFeedback) -> JammingConfig {
+        let arm_index = self.select_arm();
+        let arm = self.arms[arm_index];
+
+        let jamming_config = JammingConfig {
+            jammer_power_dbm: arm.power_dbm,
+            jamming_type: arm.jamming_type,
+            ..self.config.base_config.clone()
+        };
+        let model = JammingModel::new(jamming_config.clone());
+
+        let per = model.compute_packet_error_rate(
+            victim_feedback.signal_power_dbm,
+            victim_feedback.target_position,
+            victim_feedback.noise_power_dbm,
+            victim_feedback.packet_length_bits,
+            0.0, // CognitiveJammer models the attacker, which has no excision filter
+        );
+        // The jammer only inflicts PER while it's actually radiating.
+        let inflicted_per = per * arm.duty_cycle;
+
+        let radiated_watts = dbm_to_watts(arm.power_dbm) * arm.duty_cycle;
+        let energy_penalty = self.config.energy_penalty_per_watt * radiated_watts;
+        let reward = (inflicted_per - energy_penalty).clamp(0.0, 1.0);
+
+        self.total_plays += 1;
+        let stats = &mut self.stats[arm_index];
+        stats.pull_count += 1;
+        stats.mean_reward += (reward - stats.mean_reward) / stats.pull_count as f64;
+
+        jamming_config
+    }
+
+    /// The arm with the highest sample mean reward so far.
+    pub fn best_arm(&self) -> JammingArm {
+        self.stats
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                a.mean_reward
+                    .partial_cmp(&b.mean_reward)
+                    .expect("rewards are never NaN")
+            })
+            .map(|(i, _)| self.arms[i])
+            .expect("arm grid is non-empty")
+    }
+
+    /// Forget all observed rewards and resume forced exploration from
+    /// scratch, e.g. when the victim link or environment has changed enough
+    /// that old arm statistics no longer apply.
+    pub fn reset(&mut self) {
+        for stats in &mut self.stats {
+            *stats = ArmStats::default();
+        }
+        self.total_plays = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> CognitiveJammerConfig {
+        CognitiveJammerConfig {
+            base_config: JammingConfig {
+                jammer_position: Vector3::zeros(),
+                ..Default::default()
+            },
+            jamming_types: vec![JammingType::Barrage, JammingType::Deception { delay: 0.05 }],
+            power_candidates_dbm: vec![20.0, 50.0],
+            duty_cycle_candidates: vec![0.5, 1.0],
+            energy_penalty_per_watt: 0.0001,
+        }
+    }
+
+    fn feedback() -> VictimFeedback {
+        VictimFeedback {
+            signal_power_dbm: -60.0,
+            target_position: Vector3::new(2000.0, 0.0, 50.0),
+            noise_power_dbm: -100.0,
+            packet_length_bits: 1024,
+        }
+    }
+
+    #[test]
+    fn forces_one_pull_of_every_arm_before_using_ucb() {
+        let mut jammer = CognitiveJammer::new(test_config());
+        let num_arms = jammer.arms.len();
+        let mut played = std::collections::HashSet::new();
+        for _ in 0..num_arms {
+            let arm_index_before = jammer.select_arm();
+            jammer.step(feedback());
+            played.insert(jammer.arms[arm_index_before]);
+        }
+        assert_eq!(played.len(), num_arms);
+        assert!(jammer.stats.iter().all(|s| s.pull_count == 1));
+    }
+
+    #[test]
+    fn converges_toward_the_highest_reward_arm() {
+        let mut jammer = CognitiveJammer::new(test_config());
+        for _ in 0..500 {
+            jammer.step(feedback());
+        }
+        // Highest transmit power with full duty cycle should inflict the
+        // most PER per watt at this range, since the energy penalty here is
+        // tiny relative to the PER swing between arms.
+        let best = jammer.best_arm();
+        assert_eq!(best.power_dbm, 50.0);
+        assert_eq!(best.duty_cycle, 1.0);
+    }
+
+    #[test]
+    fn reset_clears_stats_and_forces_exploration_again() {
+        let mut jammer = CognitiveJammer::new(test_config());
+        for _ in 0..20 {
+            jammer.step(feedback());
+        }
+        jammer.reset();
+        assert_eq!(jammer.total_plays, 0);
+        assert!(jammer
+            .stats
+            .iter()
+            .all(|s| s.pull_count == 0 && s.mean_reward == 0.0));
+    }
+
+    #[test]
+    fn reward_is_normalized_to_unit_interval() {
+        let mut jammer = CognitiveJammer::new(test_config());
+        for _ in 0..50 {
+            jammer.step(feedback());
+        }
+        assert!(jammer
+            .stats
+            .iter()
+            .all(|s| (0.0..=1.0).contains(&s.mean_reward)));
+    }
+}
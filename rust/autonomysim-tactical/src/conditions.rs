@@ -0,0 +1,267 @@
+//! Region-based propagation dynamics for [`NetworkTopology`]
+//!
+//! Links otherwise hold a static [`LinkQuality`] until something calls
+//! [`NetworkTopology::add_link`] again by hand, so a simulation can't
+//! exercise realistic temporal variation on its own. [`NetworkConditions`]
+//! clusters agents into named regions with a region-to-region base latency,
+//! and on each step perturbs every link's `snr_db`, `packet_loss_rate`, and
+//! `latency_s` through a seeded Gauss-Markov process so values stay
+//! temporally correlated instead of jumping around as white noise.
+
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::network::{AgentId, NetworkTopology};
+
+/// A named region agents can be assigned to, e.g. `"urban-canyon"`.
+pub type RegionId = String;
+
+/// Gauss-Markov parameters for one link-quality field: `next = mean +
+/// alpha * (prev - mean) + noise`, where `noise ~ N(0, noise_std)`. `alpha`
+/// close to 1 means the field drifts slowly (a fading channel); `alpha`
+/// close to 0 means each step is close to white noise around `mean`.
+#[derive(Debug, Clone, Copy)]
+pub struct GaussMarkovParams {
+    pub mean: f64,
+    pub alpha: f64,
+    pub noise_std: f64,
+}
+
+impl GaussMarkovParams {
+    fn step(&self, prev: f64, rng: &mut StdRng) -> f64 {
+        self.mean + self.alpha * (prev - self.mean) + standard_normal(rng) * self.noise_std
+    }
+}
+
+/// Per-link Gauss-Markov state carried between [`NetworkConditions::update`] calls.
+#[derive(Debug, Clone, Copy)]
+struct LinkDynamicsState {
+    snr_db: f64,
+    packet_loss_rate: f64,
+    latency_s: f64,
+}
+
+/// Drives region-clustered, temporally-correlated link conditions for a
+/// [`NetworkTopology`].
+#[derive(Debug, Clone)]
+pub struct NetworkConditions {
+    region_of: HashMap<AgentId, RegionId>,
+    /// Extra one-way latency (seconds) added on top of a link's own
+    /// `latency_s` when its endpoints sit in different regions, keyed by
+    /// the unordered region pair (sorted lexicographically).
+    cross_region_latency_s: HashMap<(RegionId, RegionId), f64>,
+    snr_params: GaussMarkovParams,
+    packet_loss_params: GaussMarkovParams,
+    latency_params: GaussMarkovParams,
+    link_state: HashMap<(AgentId, AgentId), LinkDynamicsState>,
+    rng: StdRng,
+}
+
+impl NetworkConditions {
+    /// Create a driver with no regions assigned yet. `seed` makes the
+    /// Gauss-Markov evolution reproducible across runs.
+    pub fn new(
+        snr_params: GaussMarkovParams,
+        packet_loss_params: GaussMarkovParams,
+        latency_params: GaussMarkovParams,
+        seed: u64,
+    ) -> Self {
+        Self {
+            region_of: HashMap::new(),
+            cross_region_latency_s: HashMap::new(),
+            snr_params,
+            packet_loss_params,
+            latency_params,
+            link_state: HashMap::new(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Assign `agent` to `region`.
+    pub fn set_region(&mut self, agent: AgentId, region: impl Into<RegionId>) {
+        self.region_of.insert(agent, region.into());
+    }
+
+    /// Region `agent` was assigned via [`Self::set_region`], if any.
+    pub fn region_of(&self, agent: AgentId) -> Option<&RegionId> {
+        self.region_of.get(&agent)
+    }
+
+    /// Set the extra one-way base latency (seconds) applied to links that
+    /// cross between `region_a` and `region_b`.
+    pub fn set_cross_region_latency(
+        &mut self,
+        region_a: impl Into<RegionId>,
+        region_b: impl Into<RegionId>,
+        latency_s: f64,
+    ) {
+        self.cross_region_latency_s
+            .insert(Self::region_pair_key(region_a.into(), region_b.into()), latency_s);
+    }
+
+    fn region_pair_key(a: RegionId, b: RegionId) -> (RegionId, RegionId) {
+        if a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Advance every link's quality by one Gauss-Markov step and fold in
+    /// its region's base latency. Call once per [`NetworkTopology::update_time`] step.
+    pub fn update(&mut self, topology: &mut NetworkTopology) {
+        let snr_params = self.snr_params;
+        let packet_loss_params = self.packet_loss_params;
+        let latency_params = self.latency_params;
+        let region_of = &self.region_of;
+        let cross_region_latency_s = &self.cross_region_latency_s;
+        let link_state = &mut self.link_state;
+        let rng = &mut self.rng;
+
+        topology.update_links(|source, destination, quality| {
+            let state = link_state
+                .entry((source, destination))
+                .or_insert(LinkDynamicsState {
+                    snr_db: quality.snr_db,
+                    packet_loss_rate: quality.packet_loss_rate,
+                    latency_s: quality.latency_s,
+                });
+
+            state.snr_db = snr_params.step(state.snr_db, rng);
+            state.packet_loss_rate = packet_loss_params.step(state.packet_loss_rate, rng).clamp(0.0, 1.0);
+            state.latency_s = latency_params.step(state.latency_s, rng).max(0.0);
+
+            let cross_latency = region_of
+                .get(&source)
+                .zip(region_of.get(&destination))
+                .filter(|(a, b)| a != b)
+                .map(|(a, b)| Self::region_pair_key(a.clone(), b.clone()))
+                .and_then(|key| cross_region_latency_s.get(&key).copied())
+                .unwrap_or(0.0);
+
+            quality.snr_db = state.snr_db;
+            quality.packet_loss_rate = state.packet_loss_rate;
+            quality.latency_s = state.latency_s + cross_latency;
+        });
+    }
+}
+
+/// Standard-normal sample via Box-Muller, matching the repo's existing
+/// `standard_normal` helpers (see [`crate::array_beamforming`]/[`crate::jammer_locator`]).
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.gen_range(1e-12..1.0);
+    let u2: f64 = rng.gen_range(0.0..1.0);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Vector3;
+    use crate::network::LinkQuality;
+
+    fn two_agent_topology() -> NetworkTopology {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(0, 1, LinkQuality::default());
+        topology
+    }
+
+    fn still_params(mean: f64) -> GaussMarkovParams {
+        GaussMarkovParams {
+            mean,
+            alpha: 0.0,
+            noise_std: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_update_converges_link_quality_toward_configured_means() {
+        let mut topology = two_agent_topology();
+        let mut conditions = NetworkConditions::new(
+            still_params(15.0),
+            still_params(0.2),
+            still_params(0.05),
+            1,
+        );
+
+        conditions.update(&mut topology);
+
+        let link = topology.get_link(0, 1).unwrap();
+        assert!((link.quality.snr_db - 15.0).abs() < 1e-9);
+        assert!((link.quality.packet_loss_rate - 0.2).abs() < 1e-9);
+        assert!((link.quality.latency_s - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cross_region_latency_adds_on_top_of_link_latency() {
+        let mut topology = two_agent_topology();
+        let mut conditions = NetworkConditions::new(
+            still_params(15.0),
+            still_params(0.0),
+            still_params(0.01),
+            2,
+        );
+        conditions.set_region(0, "north");
+        conditions.set_region(1, "south");
+        conditions.set_cross_region_latency(String::from("north"), String::from("south"), 0.5);
+
+        conditions.update(&mut topology);
+
+        let link = topology.get_link(0, 1).unwrap();
+        assert!((link.quality.latency_s - 0.51).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_same_region_has_no_added_latency() {
+        let mut topology = two_agent_topology();
+        let mut conditions = NetworkConditions::new(
+            still_params(15.0),
+            still_params(0.0),
+            still_params(0.01),
+            3,
+        );
+        conditions.set_region(0, "north");
+        conditions.set_region(1, "north");
+        conditions.set_cross_region_latency(String::from("north"), String::from("south"), 0.5);
+
+        conditions.update(&mut topology);
+
+        let link = topology.get_link(0, 1).unwrap();
+        assert!((link.quality.latency_s - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_same_seed_is_reproducible() {
+        let mut topology_a = two_agent_topology();
+        let mut conditions_a =
+            NetworkConditions::new(
+                GaussMarkovParams { mean: 15.0, alpha: 0.5, noise_std: 2.0 },
+                still_params(0.1),
+                still_params(0.01),
+                42,
+            );
+        let mut topology_b = two_agent_topology();
+        let mut conditions_b =
+            NetworkConditions::new(
+                GaussMarkovParams { mean: 15.0, alpha: 0.5, noise_std: 2.0 },
+                still_params(0.1),
+                still_params(0.01),
+                42,
+            );
+
+        for _ in 0..5 {
+            conditions_a.update(&mut topology_a);
+            conditions_b.update(&mut topology_b);
+        }
+
+        assert_eq!(
+            topology_a.get_link(0, 1).unwrap().quality.snr_db,
+            topology_b.get_link(0, 1).unwrap().quality.snr_db
+        );
+    }
+}
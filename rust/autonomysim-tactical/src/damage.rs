@@ -0,0 +1,323 @@
+//! Attrition / damage model for combat engagements
+//!
+//! A combat engagement's kill probability falls off with distance from the
+//! munition/effect: full lethality within `full_damage_dist`, tapering
+//! linearly to zero by `max_damage_dist`. A hit is applied either against a
+//! hit-point pool (depleted per engagement, destroyed at zero) or as a
+//! discrete subsystem failure -- so a "kill" degrades a specific capability
+//! (propulsion, comms, sensor) rather than instantly erasing the robot.
+//! `spectator` mode records the hit and would-be damage without ever
+//! mutating state, for analysis runs that shouldn't perturb the scenario
+//! they're observing.
+
+use serde::{Deserialize, Serialize};
+
+/// A robot subsystem that can fail independently of total destruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureMode {
+    /// Robot can no longer move; its position freezes where it stands.
+    Propulsion,
+    /// Robot drops out of RF link counting and stigmergy propagation.
+    Comms,
+    /// Robot loses situational awareness.
+    Sensor,
+}
+
+/// Which damage representation engagements use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageMode {
+    /// Hits deplete a hit-point pool; the robot is destroyed at zero HP.
+    HitPoints,
+    /// Hits degrade one randomly chosen subsystem instead of HP.
+    FailureModes,
+}
+
+/// Attrition model configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DamageConfig {
+    /// Distance (m) within which a hit is maximally lethal.
+    pub full_damage_dist: f64,
+
+    /// Distance (m) beyond which a hit does no damage at all.
+    pub max_damage_dist: f64,
+
+    /// Starting hit points for a fresh [`RobotDamageState`].
+    pub hp_max: f64,
+
+    /// Hit points removed by a maximally lethal (full-damage) hit.
+    pub hp_damage_at_full: f64,
+
+    /// Which damage representation engagements use.
+    pub mode: DamageMode,
+
+    /// If true, engagements are recorded (hit + would-be damage) but never
+    /// applied -- for analysis runs that shouldn't perturb the scenario.
+    pub spectator: bool,
+}
+
+impl Default for DamageConfig {
+    fn default() -> Self {
+        Self {
+            full_damage_dist: 20.0,
+            max_damage_dist: 150.0,
+            hp_max: 100.0,
+            hp_damage_at_full: 40.0,
+            mode: DamageMode::FailureModes,
+            spectator: false,
+        }
+    }
+}
+
+/// Outcome of a single [`DamageModel::engage`] call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EngagementOutcome {
+    /// Kill probability the engagement was rolled against.
+    pub kill_probability: f64,
+    /// Whether the roll landed a hit.
+    pub hit: bool,
+    /// Subsystem degraded by this hit, if `mode` was [`DamageMode::FailureModes`].
+    pub failure: Option<FailureMode>,
+    /// Hit points removed by this hit, if `mode` was [`DamageMode::HitPoints`].
+    pub hp_damage: f64,
+    /// Whether the target's hit-point pool reached zero as of this hit.
+    pub destroyed: bool,
+}
+
+/// Per-robot attrition state: a hit-point pool plus any subsystem failures
+/// accumulated so far.
+#[derive(Debug, Clone)]
+pub struct RobotDamageState {
+    hp: f64,
+    failures: Vec<FailureMode>,
+    destroyed: bool,
+}
+
+impl RobotDamageState {
+    /// Create a fresh, undamaged state with `hp_max` hit points.
+    pub fn new(hp_max: f64) -> Self {
+        Self {
+            hp: hp_max,
+            failures: Vec::new(),
+            destroyed: false,
+        }
+    }
+
+    pub fn hp(&self) -> f64 {
+        self.hp
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed
+    }
+
+    pub fn has_failure(&self, mode: FailureMode) -> bool {
+        self.failures.contains(&mode)
+    }
+
+    pub fn failures(&self) -> &[FailureMode] {
+        &self.failures
+    }
+
+    /// Comms-failed or destroyed robots drop out of RF link counting and
+    /// stigmergy propagation.
+    pub fn comms_online(&self) -> bool {
+        !self.destroyed && !self.has_failure(FailureMode::Comms)
+    }
+
+    /// Propulsion-failed or destroyed robots freeze wherever they stand.
+    pub fn can_move(&self) -> bool {
+        !self.destroyed && !self.has_failure(FailureMode::Propulsion)
+    }
+
+    fn apply_failure(&mut self, mode: FailureMode) {
+        if !self.failures.contains(&mode) {
+            self.failures.push(mode);
+        }
+    }
+
+    fn apply_hp_damage(&mut self, damage: f64) {
+        self.hp = (self.hp - damage).max(0.0);
+        if self.hp <= 0.0 {
+            self.destroyed = true;
+        }
+    }
+}
+
+/// Attrition model for combat engagements: turns a munition/effect's
+/// distance from its target into a kill probability, then applies the
+/// resulting hit according to `config.mode`.
+pub struct DamageModel {
+    config: DamageConfig,
+}
+
+impl DamageModel {
+    pub fn new(config: DamageConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &DamageConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: DamageConfig) {
+        self.config = config;
+    }
+
+    /// Kill probability for a hit at `distance_m` from the target: 1.0
+    /// within `full_damage_dist`, falling off linearly to 0.0 at
+    /// `max_damage_dist`.
+    pub fn kill_probability(&self, distance_m: f64) -> f64 {
+        if distance_m <= self.config.full_damage_dist {
+            return 1.0;
+        }
+        if distance_m >= self.config.max_damage_dist {
+            return 0.0;
+        }
+        let span = self.config.max_damage_dist - self.config.full_damage_dist;
+        1.0 - (distance_m - self.config.full_damage_dist) / span
+    }
+
+    /// Engage a single target at `distance_m`, rolling against
+    /// [`Self::kill_probability`] and applying the hit to `state` per
+    /// `config.mode` -- unless `config.spectator` is set, in which case the
+    /// outcome is computed and returned but `state` is left untouched.
+    pub fn engage(&self, state: &mut RobotDamageState, distance_m: f64) -> EngagementOutcome {
+        let kill_probability = self.kill_probability(distance_m);
+        let hit = rand::random::<f64>() < kill_probability;
+
+        if !hit {
+            return EngagementOutcome {
+                kill_probability,
+                hit: false,
+                failure: None,
+                hp_damage: 0.0,
+                destroyed: state.destroyed,
+            };
+        }
+
+        match self.config.mode {
+            DamageMode::HitPoints => {
+                let hp_damage = self.config.hp_damage_at_full * kill_probability;
+                if !self.config.spectator {
+                    state.apply_hp_damage(hp_damage);
+                }
+                EngagementOutcome {
+                    kill_probability,
+                    hit: true,
+                    failure: None,
+                    hp_damage,
+                    destroyed: state.destroyed,
+                }
+            }
+            DamageMode::FailureModes => {
+                let failure = match rand::random::<u8>() % 3 {
+                    0 => FailureMode::Propulsion,
+                    1 => FailureMode::Comms,
+                    _ => FailureMode::Sensor,
+                };
+                if !self.config.spectator {
+                    state.apply_failure(failure);
+                }
+                EngagementOutcome {
+                    kill_probability,
+                    hit: true,
+                    failure: Some(failure),
+                    hp_damage: 0.0,
+                    destroyed: state.destroyed,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kill_probability_falloff() {
+        let model = DamageModel::new(DamageConfig::default());
+        assert_eq!(model.kill_probability(0.0), 1.0);
+        assert_eq!(model.kill_probability(20.0), 1.0);
+        assert_eq!(model.kill_probability(150.0), 0.0);
+        assert_eq!(model.kill_probability(300.0), 0.0);
+
+        let mid = model.kill_probability(85.0); // halfway between 20 and 150
+        assert!((mid - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hit_points_mode_depletes_and_destroys() {
+        let config = DamageConfig {
+            full_damage_dist: 50.0,
+            max_damage_dist: 50.0,
+            hp_max: 100.0,
+            hp_damage_at_full: 40.0,
+            mode: DamageMode::HitPoints,
+            spectator: false,
+        };
+        let model = DamageModel::new(config);
+        let mut state = RobotDamageState::new(100.0);
+
+        model.engage(&mut state, 0.0);
+        assert_eq!(state.hp(), 60.0);
+        assert!(!state.is_destroyed());
+
+        model.engage(&mut state, 0.0);
+        model.engage(&mut state, 0.0);
+        assert_eq!(state.hp(), 0.0);
+        assert!(state.is_destroyed());
+    }
+
+    #[test]
+    fn test_failure_mode_does_not_instantly_destroy() {
+        let config = DamageConfig {
+            full_damage_dist: 50.0,
+            max_damage_dist: 50.0,
+            mode: DamageMode::FailureModes,
+            ..Default::default()
+        };
+        let model = DamageModel::new(config);
+        let mut state = RobotDamageState::new(100.0);
+
+        let outcome = model.engage(&mut state, 0.0);
+        assert!(outcome.hit);
+        assert!(outcome.failure.is_some());
+        assert!(!state.is_destroyed());
+        assert!(state.has_failure(outcome.failure.unwrap()));
+    }
+
+    #[test]
+    fn test_comms_and_propulsion_failures_gate_capabilities() {
+        let mut state = RobotDamageState::new(100.0);
+        assert!(state.comms_online());
+        assert!(state.can_move());
+
+        state.apply_failure(FailureMode::Comms);
+        assert!(!state.comms_online());
+        assert!(state.can_move());
+
+        state.apply_failure(FailureMode::Propulsion);
+        assert!(!state.can_move());
+    }
+
+    #[test]
+    fn test_spectator_mode_records_without_applying() {
+        let config = DamageConfig {
+            full_damage_dist: 50.0,
+            max_damage_dist: 50.0,
+            hp_max: 100.0,
+            hp_damage_at_full: 40.0,
+            mode: DamageMode::HitPoints,
+            spectator: true,
+        };
+        let model = DamageModel::new(config);
+        let mut state = RobotDamageState::new(100.0);
+
+        let outcome = model.engage(&mut state, 0.0);
+        assert!(outcome.hit);
+        assert!(outcome.hp_damage > 0.0);
+        assert_eq!(state.hp(), 100.0);
+        assert!(!state.is_destroyed());
+    }
+}
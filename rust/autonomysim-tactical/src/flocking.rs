@@ -0,0 +1,215 @@
+//! Network-topology-driven Lennard-Jones flocking
+//!
+//! `autonomysim_core::swarm::FlockingController` computes one agent's
+//! desired velocity from a caller-supplied neighbor list, but leaves
+//! "who counts as a neighbor" and "where is everyone" to the caller.
+//! [`NetworkFlockingDriver`] answers both from state this crate already
+//! tracks: neighbors come from [`NetworkTopology::get_neighbors`] (agents
+//! with a currently *usable* link, not just agents within some fixed
+//! radius), so a drone jammed out of the mesh stops pulling on -- and being
+//! pulled by -- the rest of the swarm the moment its links drop, the same
+//! decentralized posture [`crate::mesh`]'s relay routing and
+//! [`crate::ai`]'s per-robot FSM use. Positions come from each agent's own
+//! [`VehicleState`], so the flocking command rides on the same state the
+//! rest of the vehicle stack already reads and writes.
+
+use std::collections::HashMap;
+
+use nalgebra::Vector3;
+
+use autonomysim_core::swarm::{FlockingController, RoleSpacing};
+use autonomysim_core::vehicle::VehicleState;
+
+use crate::network::{AgentId, NetworkTopology};
+
+/// Lennard-Jones flocking over [`NetworkTopology`]'s link graph, with an
+/// optional pull toward a mission waypoint layered on top.
+pub struct NetworkFlockingDriver {
+    controller: FlockingController,
+    /// Scales the unit vector toward a `velocity_command` call's `waypoint`
+    /// argument before it's added to the Lennard-Jones command and the sum
+    /// is re-clamped to [`FlockingController::max_velocity`].
+    attractor_gain: f64,
+}
+
+impl NetworkFlockingDriver {
+    pub fn new(
+        default_spacing: RoleSpacing,
+        neighbor_cutoff: f64,
+        max_velocity: f64,
+        attractor_gain: f64,
+    ) -> Self {
+        Self {
+            controller: FlockingController::new(default_spacing, neighbor_cutoff, max_velocity),
+            attractor_gain,
+        }
+    }
+
+    /// Override the `target`/`epsilon` used for agents of `role`; see
+    /// [`FlockingController::set_role_spacing`].
+    pub fn set_role_spacing(&mut self, role: impl Into<String>, spacing: RoleSpacing) {
+        self.controller.set_role_spacing(role, spacing);
+    }
+
+    /// Desired 2D `(vx, vy)` velocity command for `agent`: a Lennard-Jones
+    /// interaction with every agent `topology` reports as a usable-link
+    /// neighbor of `agent`, plus (if `waypoint` is `Some`) a unit-vector
+    /// attractor term toward it. Positions are read from `vehicle_states`,
+    /// keyed the same way as `topology`'s agents; `(0.0, 0.0)` if `agent`
+    /// has no entry.
+    pub fn velocity_command(
+        &self,
+        topology: &NetworkTopology,
+        vehicle_states: &HashMap<AgentId, VehicleState>,
+        agent: AgentId,
+        role: &str,
+        waypoint: Option<Vector3<f64>>,
+    ) -> (f64, f64) {
+        let Some(position) = vehicle_states
+            .get(&agent)
+            .map(|state| state.transform.position)
+        else {
+            return (0.0, 0.0);
+        };
+
+        let neighbor_positions: Vec<(f64, f64)> = topology
+            .get_neighbors(agent)
+            .into_iter()
+            .filter_map(|neighbor| vehicle_states.get(&neighbor))
+            .map(|state| (state.transform.position.x, state.transform.position.y))
+            .collect();
+
+        let (mut vx, mut vy) =
+            self.controller
+                .velocity_command(role, (position.x, position.y), &neighbor_positions);
+
+        if let Some(target) = waypoint {
+            let dx = target.x - position.x;
+            let dy = target.y - position.y;
+            let distance = dx.hypot(dy);
+            if distance > 0.0 {
+                vx += self.attractor_gain * dx / distance;
+                vy += self.attractor_gain * dy / distance;
+            }
+        }
+
+        let max_velocity = self.controller.max_velocity();
+        let speed = vx.hypot(vy);
+        if speed > max_velocity && speed > 0.0 {
+            let scale = max_velocity / speed;
+            (vx * scale, vy * scale)
+        } else {
+            (vx, vy)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomysim_core::backend::{Rotation, Transform};
+
+    fn state_at(x: f64, y: f64) -> VehicleState {
+        VehicleState {
+            vehicle_id: "agent".to_string(),
+            timestamp: 0.0,
+            transform: Transform {
+                position: nalgebra::Point3::new(x, y, 0.0),
+                rotation: Rotation::identity(),
+            },
+            linear_velocity: Vector3::zeros(),
+            angular_velocity: Vector3::zeros(),
+            linear_acceleration: Vector3::zeros(),
+            angular_acceleration: Vector3::zeros(),
+            battery_level: 1.0,
+            is_grounded: false,
+            collision_info: None,
+        }
+    }
+
+    fn linked_topology(positions: &[(AgentId, f64, f64)]) -> NetworkTopology {
+        let mut topology = NetworkTopology::new();
+        for &(agent, x, y) in positions {
+            topology.add_agent(agent, Vector3::new(x, y, 0.0));
+        }
+        for &(a, _, _) in positions {
+            for &(b, _, _) in positions {
+                if a != b {
+                    topology.add_link(a, b, crate::network::LinkQuality::default());
+                }
+            }
+        }
+        topology
+    }
+
+    #[test]
+    fn repels_a_close_linked_neighbor() {
+        let driver = NetworkFlockingDriver::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            20.0,
+            10.0,
+            0.0,
+        );
+        let topology = linked_topology(&[(0, 0.0, 0.0), (1, 1.0, 0.0)]);
+        let mut states = HashMap::new();
+        states.insert(0, state_at(0.0, 0.0));
+        states.insert(1, state_at(1.0, 0.0));
+
+        let (vx, _vy) = driver.velocity_command(&topology, &states, 0, "default", None);
+        assert!(
+            vx < 0.0,
+            "expected a push away from the close neighbor, got vx={vx}"
+        );
+    }
+
+    #[test]
+    fn pulls_toward_the_waypoint_with_no_neighbors() {
+        let driver = NetworkFlockingDriver::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            20.0,
+            10.0,
+            2.0,
+        );
+        let topology = linked_topology(&[(0, 0.0, 0.0)]);
+        let mut states = HashMap::new();
+        states.insert(0, state_at(0.0, 0.0));
+
+        let (vx, vy) = driver.velocity_command(
+            &topology,
+            &states,
+            0,
+            "default",
+            Some(Vector3::new(10.0, 0.0, 0.0)),
+        );
+        assert!(
+            vx > 0.0 && vy.abs() < 1e-9,
+            "expected a pull toward +x, got ({vx}, {vy})"
+        );
+    }
+
+    #[test]
+    fn missing_vehicle_state_yields_zero_command() {
+        let driver = NetworkFlockingDriver::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            20.0,
+            10.0,
+            0.0,
+        );
+        let topology = linked_topology(&[(0, 0.0, 0.0)]);
+        let states = HashMap::new();
+
+        assert_eq!(
+            driver.velocity_command(&topology, &states, 0, "default", None),
+            (0.0, 0.0)
+        );
+    }
+}
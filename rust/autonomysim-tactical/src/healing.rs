@@ -0,0 +1,413 @@
+//! Mesh healing: turning detected partitions into actionable repair plans
+//!
+//! [`PartitionDetector`] only reports which agents have split apart; it
+//! suggests no remedy. [`MeshHealer`] closes that gap by proposing concrete
+//! relay placements -- reposition a spare agent, or drop in a new one --
+//! that would bridge a pair of partitions, ranked by how much path length
+//! they cost versus how much connectivity they buy back.
+
+use std::collections::HashSet;
+
+use nalgebra::Vector3;
+
+use crate::network::{AgentId, LinkQuality, NetworkTopology};
+use crate::spectrum::SpectrumManager;
+
+/// What a [`HealingAction`] asks the swarm controller to do.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealingKind {
+    /// Move an already-idle agent to a relay waypoint.
+    RepositionSpare(AgentId),
+    /// No spare agent was available; spawn/insert a new relay instead.
+    InsertRelay,
+}
+
+/// One proposed way to bridge a pair of disconnected partitions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealingAction {
+    /// Boundary agent in the first partition the relay chain starts from.
+    pub from_agent: AgentId,
+    /// Boundary agent in the second partition the relay chain ends at.
+    pub to_agent: AgentId,
+    /// What to do for each relay hop, one entry per intermediate waypoint.
+    pub kind: Vec<HealingKind>,
+    /// NED waypoints the relay(s) should take up, evenly spaced along the
+    /// `from_agent` -> `to_agent` segment.
+    pub waypoints: Vec<Vector3<f64>>,
+    /// Total distance the relay chain adds (sum of hop lengths, i.e. the
+    /// straight-line gap between the two boundary agents).
+    pub added_path_length_m: f64,
+    /// Estimated increase in [`NetworkTopology::compute_connectivity`] if
+    /// this action is carried out.
+    pub connectivity_gain: f64,
+}
+
+/// Proposes relay placements that reconnect disconnected partitions.
+#[derive(Debug, Clone, Copy)]
+pub struct MeshHealer {
+    /// Maximum distance a single radio hop can reliably bridge.
+    radio_range_m: f64,
+}
+
+impl MeshHealer {
+    /// Create a healer that assumes `radio_range_m` as the usable range of
+    /// a single radio hop when sizing relay chains.
+    pub fn new(radio_range_m: f64) -> Self {
+        Self { radio_range_m }
+    }
+
+    /// Propose a ranked list of [`HealingAction`]s that would reconnect
+    /// `partitions` of `topology`, preferring to reuse `spare_agents`
+    /// (currently idle agents free to reposition) over inserting new
+    /// relays. Every pair of partitions is considered; pairs already
+    /// within `radio_range_m` of each other need no relay and are skipped.
+    /// Results are ranked by connectivity gain (descending), then by
+    /// added path length (ascending) as a tiebreaker.
+    pub fn propose_healing_actions(
+        &self,
+        topology: &NetworkTopology,
+        partitions: &[HashSet<AgentId>],
+        spare_agents: &[AgentId],
+    ) -> Vec<HealingAction> {
+        let baseline_connectivity = topology.compute_connectivity();
+        let mut spares_remaining: Vec<AgentId> = spare_agents.to_vec();
+        let mut actions = Vec::new();
+
+        for i in 0..partitions.len() {
+            for j in (i + 1)..partitions.len() {
+                let Some((from_agent, to_agent, gap_m)) =
+                    self.closest_boundary_pair(topology, &partitions[i], &partitions[j])
+                else {
+                    continue;
+                };
+
+                if gap_m <= self.radio_range_m {
+                    continue;
+                }
+
+                let relay_count = (gap_m / self.radio_range_m).ceil() as usize - 1;
+                let waypoints = self.evenly_spaced_waypoints(
+                    topology.get_position(from_agent).unwrap(),
+                    topology.get_position(to_agent).unwrap(),
+                    relay_count,
+                );
+
+                let kind: Vec<HealingKind> = waypoints
+                    .iter()
+                    .map(|_| match spares_remaining.pop() {
+                        Some(spare) => HealingKind::RepositionSpare(spare),
+                        None => HealingKind::InsertRelay,
+                    })
+                    .collect();
+
+                let connectivity_gain = self.estimate_connectivity_gain(
+                    topology,
+                    baseline_connectivity,
+                    from_agent,
+                    to_agent,
+                    &waypoints,
+                    &kind,
+                );
+
+                actions.push(HealingAction {
+                    from_agent,
+                    to_agent,
+                    kind,
+                    waypoints,
+                    added_path_length_m: gap_m,
+                    connectivity_gain,
+                });
+            }
+        }
+
+        actions.sort_by(|a, b| {
+            b.connectivity_gain
+                .partial_cmp(&a.connectivity_gain)
+                .unwrap()
+                .then(
+                    a.added_path_length_m
+                        .partial_cmp(&b.added_path_length_m)
+                        .unwrap(),
+                )
+        });
+        actions
+    }
+
+    /// Closest pair `(agent_in_a, agent_in_b, distance_m)` across two
+    /// partitions by brute-force min Euclidean distance.
+    fn closest_boundary_pair(
+        &self,
+        topology: &NetworkTopology,
+        a: &HashSet<AgentId>,
+        b: &HashSet<AgentId>,
+    ) -> Option<(AgentId, AgentId, f64)> {
+        let mut best: Option<(AgentId, AgentId, f64)> = None;
+
+        for &agent_a in a {
+            let Some(pos_a) = topology.get_position(agent_a) else {
+                continue;
+            };
+            for &agent_b in b {
+                let Some(pos_b) = topology.get_position(agent_b) else {
+                    continue;
+                };
+                let distance = (pos_a - pos_b).norm();
+                if best.map_or(true, |(_, _, best_distance)| distance < best_distance) {
+                    best = Some((agent_a, agent_b, distance));
+                }
+            }
+        }
+
+        best
+    }
+
+    /// `count` NED points evenly spaced strictly between `from` and `to`.
+    fn evenly_spaced_waypoints(
+        &self,
+        from: Vector3<f64>,
+        to: Vector3<f64>,
+        count: usize,
+    ) -> Vec<Vector3<f64>> {
+        (1..=count)
+            .map(|i| {
+                let t = i as f64 / (count + 1) as f64;
+                from + (to - from) * t
+            })
+            .collect()
+    }
+
+    /// Connectivity after hypothetically wiring `from_agent` ->
+    /// waypoint(s) -> `to_agent` into a clone of `topology`, minus the
+    /// baseline. New relay agents (waypoints backed by [`HealingKind::InsertRelay`])
+    /// get synthetic IDs above every existing agent ID so they don't
+    /// collide with real agents.
+    fn estimate_connectivity_gain(
+        &self,
+        topology: &NetworkTopology,
+        baseline_connectivity: f64,
+        from_agent: AgentId,
+        to_agent: AgentId,
+        waypoints: &[Vector3<f64>],
+        kind: &[HealingKind],
+    ) -> f64 {
+        let mut hypothetical = topology.clone();
+        let mut next_synthetic_id = topology.get_agents().into_iter().max().map_or(0, |m| m + 1);
+
+        let chain_quality = LinkQuality {
+            snr_db: 25.0,
+            packet_loss_rate: 0.01,
+            ..Default::default()
+        };
+
+        let mut chain = vec![from_agent];
+        for (waypoint, relay) in waypoints.iter().zip(kind) {
+            let relay_id = match relay {
+                HealingKind::RepositionSpare(id) => *id,
+                HealingKind::InsertRelay => {
+                    let id = next_synthetic_id;
+                    next_synthetic_id += 1;
+                    id
+                }
+            };
+            hypothetical.add_agent(relay_id, *waypoint);
+            chain.push(relay_id);
+        }
+        chain.push(to_agent);
+
+        for pair in chain.windows(2) {
+            hypothetical.add_link(pair[0], pair[1], chain_quality);
+            hypothetical.add_link(pair[1], pair[0], chain_quality);
+        }
+
+        (hypothetical.compute_connectivity() - baseline_connectivity).max(0.0)
+    }
+
+    /// Propose channel handoffs that would reconnect partitions whose
+    /// nearest boundary nodes are already within `radio_range_m` of each
+    /// other -- a relay wouldn't help there, since the gap is spectral
+    /// rather than physical: the two nodes are in range but parked on
+    /// channels that don't match, so no link ever forms between them.
+    /// Pairs farther apart than `radio_range_m` are left to
+    /// [`Self::propose_healing_actions`]'s relay chains instead.
+    pub fn propose_spectrum_reassignments(
+        &self,
+        topology: &NetworkTopology,
+        partitions: &[HashSet<AgentId>],
+        spectrum: &SpectrumManager,
+    ) -> Vec<SpectrumHealingAction> {
+        let baseline_connectivity = topology.compute_connectivity();
+        let mut actions = Vec::new();
+
+        for i in 0..partitions.len() {
+            for j in (i + 1)..partitions.len() {
+                let Some((from_agent, to_agent, gap_m)) =
+                    self.closest_boundary_pair(topology, &partitions[i], &partitions[j])
+                else {
+                    continue;
+                };
+
+                if gap_m > self.radio_range_m {
+                    continue;
+                }
+
+                let (Some(from_alloc), Some(to_alloc)) = (
+                    spectrum.get_allocation(from_agent),
+                    spectrum.get_allocation(to_agent),
+                ) else {
+                    continue;
+                };
+
+                if from_alloc.channel.id == to_alloc.channel.id {
+                    continue; // already share a channel -- no spectral gap to close
+                }
+
+                let mut hypothetical = topology.clone();
+                hypothetical.add_link(from_agent, to_agent, LinkQuality::default());
+                hypothetical.add_link(to_agent, from_agent, LinkQuality::default());
+                let connectivity_gain =
+                    (hypothetical.compute_connectivity() - baseline_connectivity).max(0.0);
+
+                actions.push(SpectrumHealingAction {
+                    from_agent,
+                    to_agent,
+                    target_channel_id: from_alloc.channel.id,
+                    connectivity_gain,
+                });
+            }
+        }
+
+        actions.sort_by(|a, b| {
+            b.connectivity_gain
+                .partial_cmp(&a.connectivity_gain)
+                .unwrap()
+        });
+        actions
+    }
+}
+
+/// One proposed spectrum handoff: move `to_agent` onto `from_agent`'s
+/// channel so the two -- already within radio range of each other --
+/// finally form a link.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpectrumHealingAction {
+    pub from_agent: AgentId,
+    pub to_agent: AgentId,
+    /// Channel `to_agent` should request a handoff onto.
+    pub target_channel_id: usize,
+    /// Estimated increase in [`NetworkTopology::compute_connectivity`] if
+    /// this handoff is carried out.
+    pub connectivity_gain: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::PartitionDetector;
+
+    fn two_disconnected_pairs(gap_m: f64) -> NetworkTopology {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(10.0, 0.0, 0.0));
+        topology.add_agent(2, Vector3::new(10.0 + gap_m, 0.0, 0.0));
+        topology.add_agent(3, Vector3::new(20.0 + gap_m, 0.0, 0.0));
+
+        let quality = LinkQuality::default();
+        topology.add_link(0, 1, quality);
+        topology.add_link(1, 0, quality);
+        topology.add_link(2, 3, quality);
+        topology.add_link(3, 2, quality);
+        topology
+    }
+
+    #[test]
+    fn test_proposes_relay_when_partitions_exceed_radio_range() {
+        let topology = two_disconnected_pairs(500.0);
+        let mut detector = PartitionDetector::new(topology.clone());
+        let partitions = detector.detect_partitions().clone();
+
+        let healer = MeshHealer::new(100.0);
+        let actions = healer.propose_healing_actions(&topology, &partitions, &[]);
+
+        assert_eq!(actions.len(), 1);
+        let action = &actions[0];
+        assert_eq!(action.from_agent, 1);
+        assert_eq!(action.to_agent, 2);
+        assert_eq!(action.kind.len(), 4); // ceil(500/100) - 1 = 4 relays
+        assert!(matches!(action.kind[0], HealingKind::InsertRelay));
+        assert!(action.connectivity_gain > 0.0);
+    }
+
+    #[test]
+    fn test_no_action_when_gap_within_radio_range() {
+        let topology = two_disconnected_pairs(50.0);
+        let mut detector = PartitionDetector::new(topology.clone());
+        let partitions = detector.detect_partitions().clone();
+
+        let healer = MeshHealer::new(100.0);
+        let actions = healer.propose_healing_actions(&topology, &partitions, &[]);
+
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_proposes_spectrum_reassignment_when_in_range_but_on_different_channels() {
+        let topology = two_disconnected_pairs(50.0); // well within radio range
+        let mut detector = PartitionDetector::new(topology.clone());
+        let partitions = detector.detect_partitions().clone();
+
+        let mut spectrum = SpectrumManager::with_uniform_channels(
+            2_400_000_000,
+            2_483_500_000,
+            20_000_000,
+            5_000_000,
+        );
+        spectrum.allocate_channel(1, 0, 20.0);
+        spectrum.allocate_channel(2, 1, 20.0);
+
+        let healer = MeshHealer::new(100.0);
+        let actions = healer.propose_spectrum_reassignments(&topology, &partitions, &spectrum);
+
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].from_agent, 1);
+        assert_eq!(actions[0].to_agent, 2);
+        assert_eq!(actions[0].target_channel_id, 0);
+        assert!(actions[0].connectivity_gain > 0.0);
+    }
+
+    #[test]
+    fn test_no_spectrum_reassignment_when_already_on_the_same_channel() {
+        let topology = two_disconnected_pairs(50.0);
+        let mut detector = PartitionDetector::new(topology.clone());
+        let partitions = detector.detect_partitions().clone();
+
+        let mut spectrum = SpectrumManager::with_uniform_channels(
+            2_400_000_000,
+            2_483_500_000,
+            20_000_000,
+            5_000_000,
+        );
+        spectrum.allocate_channel(1, 0, 20.0);
+        spectrum.allocate_channel(2, 0, 20.0);
+
+        let healer = MeshHealer::new(100.0);
+        let actions = healer.propose_spectrum_reassignments(&topology, &partitions, &spectrum);
+
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_prefers_repositioning_spare_agents_over_inserting_relays() {
+        let topology = two_disconnected_pairs(250.0);
+        let mut detector = PartitionDetector::new(topology.clone());
+        let partitions = detector.detect_partitions().clone();
+
+        let healer = MeshHealer::new(100.0);
+        let actions = healer.propose_healing_actions(&topology, &partitions, &[99]);
+
+        assert_eq!(actions[0].kind.len(), 2); // ceil(250/100) - 1 = 2 relays
+        assert!(actions[0]
+            .kind
+            .iter()
+            .any(|k| *k == HealingKind::RepositionSpare(99)));
+    }
+}
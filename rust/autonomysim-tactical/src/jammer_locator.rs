@@ -0,0 +1,611 @@
+//! Multi-sensor jammer geolocation via UKF, EKF, or bootstrap particle filter.
+//!
+//! Inverts the same Friis-plus-atmospheric-loss model
+//! [`crate::jamming::JammingModel::compute_jamming_power`] uses (without
+//! that function's jamming-type-specific gain or terrain masking, which are
+//! attacker-side modeling choices, not physical link properties to invert)
+//! to recursively estimate an unknown jammer's position and EIRP from
+//! received-power measurements at known sensor locations. Because received
+//! power is a nonlinear function of position, [`EstimatorKind::UnscentedKalmanFilter`]
+//! and [`EstimatorKind::ParticleFilter`] both avoid linearizing it: the UKF
+//! propagates sigma points through the nonlinear measurement model and
+//! recombines them with the unscented transform, while the particle filter
+//! propagates a particle cloud through a random-walk motion model and
+//! reweights by measurement likelihood, degrading more gracefully than the
+//! UKF when measurements are sparse and the posterior isn't yet close to
+//! Gaussian. [`EstimatorKind::ExtendedKalmanFilter`] takes the cheaper route
+//! instead: it linearizes the measurement model's Jacobian around the
+//! current mean every update, at the cost of the UKF's better handling of
+//! strong nonlinearity far from the true position.
+
+use nalgebra::{Cholesky, Matrix4, Vector3, Vector4};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+const STATE_DIM: usize = 4;
+
+/// Predicted received power (dBm) at `sensor_pos` from a jammer at
+/// `jammer_pos` radiating `eirp_dbm`.
+fn predicted_rx_power_dbm(
+    sensor_pos: Vector3<f64>,
+    jammer_pos: Vector3<f64>,
+    eirp_dbm: f64,
+    center_frequency_hz: f64,
+    atmospheric_loss_db_per_km: f64,
+) -> f64 {
+    let distance_m = (sensor_pos - jammer_pos).norm().max(1.0);
+    let wavelength = 3e8 / center_frequency_hz;
+    let fspl_db = 20.0 * ((4.0 * PI * distance_m) / wavelength).log10();
+    let atmospheric_loss_db = atmospheric_loss_db_per_km * (distance_m / 1000.0);
+    eirp_dbm - fspl_db - atmospheric_loss_db
+}
+
+/// One sensor's measurement: its known position and the power it measured.
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub sensor_pos: Vector3<f64>,
+    pub rx_power_dbm: f64,
+}
+
+/// Position + EIRP estimate returned by [`JammerLocator::update`].
+#[derive(Debug, Clone, Copy)]
+pub struct JammerEstimate {
+    pub position: Vector3<f64>,
+    pub eirp_dbm: f64,
+}
+
+/// Which recursive estimator [`JammerLocator`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EstimatorKind {
+    /// Cheap, exact for a unimodal, near-Gaussian posterior.
+    UnscentedKalmanFilter,
+    /// Linearizes the measurement Jacobian around the current mean each
+    /// update instead of propagating sigma points -- cheaper per update
+    /// than [`Self::UnscentedKalmanFilter`], appropriate once a single
+    /// jammer's track is already tight enough that the measurement model
+    /// is locally close to linear.
+    ExtendedKalmanFilter,
+    /// Degrades more gracefully when multiple candidate positions remain
+    /// consistent with the measurements (e.g. early in a track, few
+    /// sensors).
+    ParticleFilter,
+}
+
+/// Tuning for [`JammerLocator`].
+#[derive(Debug, Clone, Copy)]
+pub struct JammerLocatorConfig {
+    pub estimator: EstimatorKind,
+    /// RF parameters of the measurement model; must match the jammer being
+    /// located for the inversion to be unbiased.
+    pub center_frequency_hz: f64,
+    pub atmospheric_loss_db_per_km: f64,
+    /// Per-`update()` random-walk process noise (position meters, EIRP dB
+    /// standard deviations), accounting for the jammer moving or its output
+    /// drifting between updates.
+    pub process_noise_position_std_m: f64,
+    pub process_noise_eirp_std_db: f64,
+    /// Measurement noise standard deviation (dB), e.g. receiver RSSI
+    /// estimation error.
+    pub measurement_noise_std_db: f64,
+    /// UKF-only: sigma-point spread parameter kappa.
+    pub ukf_kappa: f64,
+    /// Particle-filter-only: particle count.
+    pub num_particles: usize,
+    /// PRNG seed, for deterministic particle-filter runs.
+    pub seed: u64,
+}
+
+impl Default for JammerLocatorConfig {
+    fn default() -> Self {
+        Self {
+            estimator: EstimatorKind::UnscentedKalmanFilter,
+            center_frequency_hz: 2.4e9,
+            atmospheric_loss_db_per_km: 0.1,
+            process_noise_position_std_m: 5.0,
+            process_noise_eirp_std_db: 0.5,
+            measurement_noise_std_db: 3.0,
+            ukf_kappa: 1.0,
+            num_particles: 500,
+            seed: 42,
+        }
+    }
+}
+
+fn state_to_vector4(position: Vector3<f64>, eirp_dbm: f64) -> Vector4<f64> {
+    Vector4::new(position.x, position.y, position.z, eirp_dbm)
+}
+
+fn vector4_to_estimate(state: Vector4<f64>) -> JammerEstimate {
+    JammerEstimate {
+        position: Vector3::new(state.x, state.y, state.z),
+        eirp_dbm: state.w,
+    }
+}
+
+fn process_noise_covariance(config: &JammerLocatorConfig) -> Matrix4<f64> {
+    Matrix4::from_diagonal(&Vector4::new(
+        config.process_noise_position_std_m.powi(2),
+        config.process_noise_position_std_m.powi(2),
+        config.process_noise_position_std_m.powi(2),
+        config.process_noise_eirp_std_db.powi(2),
+    ))
+}
+
+/// Standard normal sample via Box-Muller.
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.gen_range(1e-12..1.0);
+    let u2: f64 = rng.gen_range(0.0..1.0);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+/// Draw `N(0, covariance)` via a Cholesky factor; `Vector4::zeros()` if
+/// `covariance` isn't positive definite (shouldn't happen for the diagonal
+/// covariances this module builds, but keeps this total).
+fn sample_gaussian(rng: &mut StdRng, covariance: &Matrix4<f64>) -> Vector4<f64> {
+    match Cholesky::new(*covariance) {
+        Some(chol) => {
+            let z = Vector4::new(
+                standard_normal(rng),
+                standard_normal(rng),
+                standard_normal(rng),
+                standard_normal(rng),
+            );
+            chol.l() * z
+        }
+        None => Vector4::zeros(),
+    }
+}
+
+struct UkfState {
+    mean: Vector4<f64>,
+    covariance: Matrix4<f64>,
+}
+
+struct EkfState {
+    mean: Vector4<f64>,
+    covariance: Matrix4<f64>,
+}
+
+/// Analytic Jacobian of [`predicted_rx_power_dbm`] with respect to state
+/// `(x, y, z, eirp_dbm)`, evaluated at `jammer_pos`/`eirp_dbm`. The
+/// free-space and atmospheric loss terms both depend on position only
+/// through the scalar distance `d = ||sensor_pos - jammer_pos||`, so the
+/// position gradient is `d(predicted)/d(d)` times the unit vector from
+/// `sensor_pos` to `jammer_pos`; the EIRP partial derivative is always 1
+/// since `predicted` is linear in it.
+fn measurement_jacobian(
+    sensor_pos: Vector3<f64>,
+    jammer_pos: Vector3<f64>,
+    atmospheric_loss_db_per_km: f64,
+) -> Vector4<f64> {
+    let offset = jammer_pos - sensor_pos;
+    let distance_m = offset.norm().max(1.0);
+    let unit_offset = offset / distance_m;
+
+    // d(fspl_db)/d(d) = 20 / (ln(10) * d); d(atmospheric_loss_db)/d(d) =
+    // atmospheric_loss_db_per_km / 1000. predicted = eirp - fspl_db -
+    // atmospheric_loss_db, so its derivative w.r.t. d is the negation of
+    // their sum.
+    let d_predicted_d_distance =
+        -(20.0 / (std::f64::consts::LN_10 * distance_m) + atmospheric_loss_db_per_km / 1000.0);
+    let d_predicted_d_position = unit_offset * d_predicted_d_distance;
+
+    Vector4::new(
+        d_predicted_d_position.x,
+        d_predicted_d_position.y,
+        d_predicted_d_position.z,
+        1.0,
+    )
+}
+
+fn ekf_measurement_update(
+    ekf: &mut EkfState,
+    measurement: Measurement,
+    config: &JammerLocatorConfig,
+) {
+    let jammer_pos = Vector3::new(ekf.mean.x, ekf.mean.y, ekf.mean.z);
+    let predicted = predicted_rx_power_dbm(
+        measurement.sensor_pos,
+        jammer_pos,
+        ekf.mean.w,
+        config.center_frequency_hz,
+        config.atmospheric_loss_db_per_km,
+    );
+    let jacobian = measurement_jacobian(
+        measurement.sensor_pos,
+        jammer_pos,
+        config.atmospheric_loss_db_per_km,
+    );
+
+    let measurement_noise_var = config.measurement_noise_std_db.powi(2);
+    let innovation_covariance =
+        (jacobian.transpose() * ekf.covariance * jacobian)[0] + measurement_noise_var;
+    let kalman_gain = (ekf.covariance * jacobian) / innovation_covariance;
+
+    ekf.mean += kalman_gain * (measurement.rx_power_dbm - predicted);
+    ekf.covariance -= kalman_gain * (jacobian.transpose() * ekf.covariance);
+}
+
+/// `(sigma points, weights)` for the unscented transform of `mean`/`covariance`.
+fn sigma_points(
+    mean: Vector4<f64>,
+    covariance: Matrix4<f64>,
+    kappa: f64,
+) -> (Vec<Vector4<f64>>, Vec<f64>) {
+    let n = STATE_DIM as f64;
+    let scale = n + kappa;
+    let l = Cholesky::new(covariance * scale)
+        .map(|chol| chol.l())
+        .unwrap_or_else(Matrix4::zeros);
+
+    let mut points = Vec::with_capacity(2 * STATE_DIM + 1);
+    let mut weights = Vec::with_capacity(2 * STATE_DIM + 1);
+    points.push(mean);
+    weights.push(kappa / scale);
+    for i in 0..STATE_DIM {
+        let column = l.column(i).into_owned();
+        points.push(mean + column);
+        weights.push(1.0 / (2.0 * scale));
+        points.push(mean - column);
+        weights.push(1.0 / (2.0 * scale));
+    }
+    (points, weights)
+}
+
+fn ukf_measurement_update(
+    ukf: &mut UkfState,
+    measurement: Measurement,
+    config: &JammerLocatorConfig,
+) {
+    let (points, weights) = sigma_points(ukf.mean, ukf.covariance, config.ukf_kappa);
+
+    let predicted: Vec<f64> = points
+        .iter()
+        .map(|state| {
+            predicted_rx_power_dbm(
+                measurement.sensor_pos,
+                Vector3::new(state.x, state.y, state.z),
+                state.w,
+                config.center_frequency_hz,
+                config.atmospheric_loss_db_per_km,
+            )
+        })
+        .collect();
+
+    let z_pred: f64 = weights.iter().zip(&predicted).map(|(w, z)| w * z).sum();
+
+    let measurement_noise_var = config.measurement_noise_std_db.powi(2);
+    let p_zz: f64 = weights
+        .iter()
+        .zip(&predicted)
+        .map(|(w, z)| w * (z - z_pred).powi(2))
+        .sum::<f64>()
+        + measurement_noise_var;
+
+    let p_xz: Vector4<f64> = weights
+        .iter()
+        .zip(points.iter().zip(&predicted))
+        .map(|(w, (state, z))| (state - ukf.mean) * (*w * (z - z_pred)))
+        .sum();
+
+    let kalman_gain = p_xz / p_zz;
+    ukf.mean += kalman_gain * (measurement.rx_power_dbm - z_pred);
+    ukf.covariance -= kalman_gain * p_zz * kalman_gain.transpose();
+}
+
+struct Particle {
+    state: Vector4<f64>,
+    weight: f64,
+}
+
+fn reweight_particles(
+    particles: &mut [Particle],
+    measurement: Measurement,
+    config: &JammerLocatorConfig,
+) {
+    for particle in particles.iter_mut() {
+        let predicted = predicted_rx_power_dbm(
+            measurement.sensor_pos,
+            Vector3::new(particle.state.x, particle.state.y, particle.state.z),
+            particle.state.w,
+            config.center_frequency_hz,
+            config.atmospheric_loss_db_per_km,
+        );
+        let error = measurement.rx_power_dbm - predicted;
+        let likelihood = (-0.5 * (error / config.measurement_noise_std_db).powi(2)).exp();
+        particle.weight *= likelihood.max(1e-300);
+    }
+    normalize_weights(particles);
+}
+
+fn normalize_weights(particles: &mut [Particle]) {
+    let total: f64 = particles.iter().map(|p| p.weight).sum();
+    if total > 0.0 {
+        for particle in particles.iter_mut() {
+            particle.weight /= total;
+        }
+    } else {
+        let uniform = 1.0 / particles.len() as f64;
+        for particle in particles.iter_mut() {
+            particle.weight = uniform;
+        }
+    }
+}
+
+/// Systematic resampling when the effective sample size
+/// (`1 / sum(weight^2)`) drops below `particles.len() / 2`.
+fn resample_if_degenerate(particles: &mut Vec<Particle>, rng: &mut StdRng) {
+    let ess = 1.0 / particles.iter().map(|p| p.weight * p.weight).sum::<f64>();
+    if ess >= particles.len() as f64 / 2.0 {
+        return;
+    }
+
+    let n = particles.len();
+    let mut cumulative = Vec::with_capacity(n);
+    let mut running = 0.0;
+    for particle in particles.iter() {
+        running += particle.weight;
+        cumulative.push(running);
+    }
+
+    let u0 = rng.gen_range(0.0..1.0 / n as f64);
+    let mut resampled = Vec::with_capacity(n);
+    let mut cursor = 0;
+    for i in 0..n {
+        let u = u0 + i as f64 / n as f64;
+        while cursor < cumulative.len() - 1 && cumulative[cursor] < u {
+            cursor += 1;
+        }
+        resampled.push(Particle {
+            state: particles[cursor].state,
+            weight: 1.0 / n as f64,
+        });
+    }
+    *particles = resampled;
+}
+
+fn weighted_mean(particles: &[Particle]) -> Vector4<f64> {
+    particles.iter().map(|p| p.state * p.weight).sum()
+}
+
+fn weighted_covariance(particles: &[Particle], mean: Vector4<f64>) -> Matrix4<f64> {
+    particles
+        .iter()
+        .map(|p| {
+            let deviation = p.state - mean;
+            (deviation * deviation.transpose()) * p.weight
+        })
+        .sum()
+}
+
+enum FilterState {
+    Ukf(UkfState),
+    Ekf(EkfState),
+    ParticleFilter {
+        particles: Vec<Particle>,
+        rng: StdRng,
+    },
+}
+
+/// Recursive multi-sensor jammer geolocation estimator; see the module docs
+/// for the underlying model and estimator tradeoffs.
+pub struct JammerLocator {
+    config: JammerLocatorConfig,
+    state: FilterState,
+    pending: Vec<Measurement>,
+}
+
+impl JammerLocator {
+    /// Build a locator seeded at `initial_guess` (e.g. the sensor array's
+    /// centroid) with `initial_position_std_m` / `initial_eirp_std_db`
+    /// marginal uncertainty.
+    pub fn new(
+        config: JammerLocatorConfig,
+        initial_guess: JammerEstimate,
+        initial_position_std_m: f64,
+        initial_eirp_std_db: f64,
+    ) -> Self {
+        let mean = state_to_vector4(initial_guess.position, initial_guess.eirp_dbm);
+        let mut covariance = Matrix4::from_diagonal(&Vector4::new(
+            initial_position_std_m.powi(2),
+            initial_position_std_m.powi(2),
+            initial_position_std_m.powi(2),
+            initial_eirp_std_db.powi(2),
+        ));
+        for i in 0..STATE_DIM {
+            covariance[(i, i)] += 1e-9; // keep strictly positive definite
+        }
+
+        let state = match config.estimator {
+            EstimatorKind::UnscentedKalmanFilter => FilterState::Ukf(UkfState { mean, covariance }),
+            EstimatorKind::ExtendedKalmanFilter => FilterState::Ekf(EkfState { mean, covariance }),
+            EstimatorKind::ParticleFilter => {
+                let mut rng = StdRng::seed_from_u64(config.seed);
+                let particles = (0..config.num_particles)
+                    .map(|_| Particle {
+                        state: mean + sample_gaussian(&mut rng, &covariance),
+                        weight: 1.0 / config.num_particles as f64,
+                    })
+                    .collect();
+                FilterState::ParticleFilter { particles, rng }
+            }
+        };
+
+        Self {
+            config,
+            state,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue one sensor's measurement for the next [`Self::update`] call.
+    pub fn add_measurement(&mut self, sensor_pos: Vector3<f64>, rx_power_dbm: f64) {
+        self.pending.push(Measurement {
+            sensor_pos,
+            rx_power_dbm,
+        });
+    }
+
+    /// Apply a random-walk time update, fold in every measurement queued
+    /// since the last call (sequentially, one at a time), and return the
+    /// resulting estimate and state covariance (position x/y/z, then EIRP).
+    pub fn update(&mut self) -> (JammerEstimate, Matrix4<f64>) {
+        let measurements = std::mem::take(&mut self.pending);
+        match &mut self.state {
+            FilterState::Ukf(ukf) => {
+                ukf.covariance += process_noise_covariance(&self.config);
+                for measurement in &measurements {
+                    ukf_measurement_update(ukf, *measurement, &self.config);
+                }
+                (vector4_to_estimate(ukf.mean), ukf.covariance)
+            }
+            FilterState::Ekf(ekf) => {
+                ekf.covariance += process_noise_covariance(&self.config);
+                for measurement in &measurements {
+                    ekf_measurement_update(ekf, *measurement, &self.config);
+                }
+                (vector4_to_estimate(ekf.mean), ekf.covariance)
+            }
+            FilterState::ParticleFilter { particles, rng } => {
+                let process_noise = process_noise_covariance(&self.config);
+                for particle in particles.iter_mut() {
+                    particle.state += sample_gaussian(rng, &process_noise);
+                }
+                for measurement in &measurements {
+                    reweight_particles(particles, *measurement, &self.config);
+                }
+                resample_if_degenerate(particles, rng);
+
+                let mean = weighted_mean(particles);
+                let covariance = weighted_covariance(particles, mean);
+                (vector4_to_estimate(mean), covariance)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRUE_POSITION: Vector3<f64> = Vector3::new(1200.0, -400.0, 30.0);
+    const TRUE_EIRP_DBM: f64 = 45.0;
+
+    fn sensor_positions() -> Vec<Vector3<f64>> {
+        vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2000.0, 0.0, 0.0),
+            Vector3::new(0.0, 2000.0, 0.0),
+            Vector3::new(2000.0, 2000.0, 0.0),
+            Vector3::new(1000.0, 1000.0, 0.0),
+        ]
+    }
+
+    fn run_updates(locator: &mut JammerLocator, rounds: usize) -> JammerEstimate {
+        let mut estimate = JammerEstimate {
+            position: Vector3::zeros(),
+            eirp_dbm: 0.0,
+        };
+        for _ in 0..rounds {
+            for sensor_pos in sensor_positions() {
+                let rx_power_dbm = predicted_rx_power_dbm(
+                    sensor_pos,
+                    TRUE_POSITION,
+                    TRUE_EIRP_DBM,
+                    JammerLocatorConfig::default().center_frequency_hz,
+                    JammerLocatorConfig::default().atmospheric_loss_db_per_km,
+                );
+                locator.add_measurement(sensor_pos, rx_power_dbm);
+            }
+            (estimate, _) = locator.update();
+        }
+        estimate
+    }
+
+    #[test]
+    fn ukf_converges_toward_true_position() {
+        let config = JammerLocatorConfig {
+            estimator: EstimatorKind::UnscentedKalmanFilter,
+            measurement_noise_std_db: 0.1,
+            ..Default::default()
+        };
+        let initial_guess = JammerEstimate {
+            position: Vector3::new(500.0, 500.0, 0.0),
+            eirp_dbm: 40.0,
+        };
+        let mut locator = JammerLocator::new(config, initial_guess, 1000.0, 10.0);
+
+        let initial_error = (initial_guess.position - TRUE_POSITION).norm();
+        let estimate = run_updates(&mut locator, 8);
+        let final_error = (estimate.position - TRUE_POSITION).norm();
+
+        assert!(
+            final_error < initial_error,
+            "expected UKF to converge: initial_error={initial_error}, final_error={final_error}"
+        );
+        assert!(final_error < 100.0, "final_error={final_error}");
+    }
+
+    #[test]
+    fn ekf_converges_toward_true_position() {
+        let config = JammerLocatorConfig {
+            estimator: EstimatorKind::ExtendedKalmanFilter,
+            measurement_noise_std_db: 0.1,
+            ..Default::default()
+        };
+        let initial_guess = JammerEstimate {
+            position: Vector3::new(500.0, 500.0, 0.0),
+            eirp_dbm: 40.0,
+        };
+        let mut locator = JammerLocator::new(config, initial_guess, 1000.0, 10.0);
+
+        let initial_error = (initial_guess.position - TRUE_POSITION).norm();
+        let estimate = run_updates(&mut locator, 8);
+        let final_error = (estimate.position - TRUE_POSITION).norm();
+
+        assert!(
+            final_error < initial_error,
+            "expected EKF to converge: initial_error={initial_error}, final_error={final_error}"
+        );
+        assert!(final_error < 100.0, "final_error={final_error}");
+    }
+
+    #[test]
+    fn particle_filter_converges_toward_true_position() {
+        let config = JammerLocatorConfig {
+            estimator: EstimatorKind::ParticleFilter,
+            measurement_noise_std_db: 0.5,
+            num_particles: 2000,
+            ..Default::default()
+        };
+        let initial_guess = JammerEstimate {
+            position: Vector3::new(500.0, 500.0, 0.0),
+            eirp_dbm: 40.0,
+        };
+        let mut locator = JammerLocator::new(config, initial_guess, 1000.0, 10.0);
+
+        let initial_error = (initial_guess.position - TRUE_POSITION).norm();
+        let estimate = run_updates(&mut locator, 8);
+        let final_error = (estimate.position - TRUE_POSITION).norm();
+
+        assert!(
+            final_error < initial_error,
+            "expected particle filter to converge: initial_error={initial_error}, final_error={final_error}"
+        );
+    }
+
+    #[test]
+    fn update_with_no_measurements_only_grows_uncertainty() {
+        let config = JammerLocatorConfig::default();
+        let initial_guess = JammerEstimate {
+            position: Vector3::zeros(),
+            eirp_dbm: 40.0,
+        };
+        let mut locator = JammerLocator::new(config, initial_guess, 10.0, 1.0);
+        let (estimate, covariance) = locator.update();
+
+        assert_eq!(estimate.position, Vector3::zeros());
+        assert!(covariance[(0, 0)] > 100.0);
+    }
+}
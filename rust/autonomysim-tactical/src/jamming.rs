@@ -11,7 +11,7 @@ use nalgebra::Vector3;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
-use autonomysim_rf_core::utils::dbm_to_watts;
+use autonomysim_rf_core::utils::{dbm_to_watts, watts_to_dbm};
 
 /// Jamming technique type
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -117,6 +117,70 @@ pub mod jsr_thresholds {
     pub const MARGINAL: f64 = 0.0;
 }
 
+/// BER approximation for BPSK at a given SINR (linear, not dB):
+/// `BER ≈ 0.5 * exp(-SINR)`, clamped at the extremes -- the same
+/// approximation [`JammingModel::compute_packet_error_rate`] uses, factored
+/// out so [`JammingModel::compute_pulse_jamming_ber`] can evaluate it twice
+/// per duty cycle (once "on", once "off").
+fn ber_from_sinr(sinr: f64) -> f64 {
+    if sinr > 20.0 {
+        1e-10
+    } else if sinr < 0.01 {
+        0.5
+    } else {
+        0.5 * (-sinr).exp()
+    }
+}
+
+/// A simple `(n, k, t)` block code: `n` coded symbols per packet, `k`
+/// information symbols, correcting up to `t` symbol errors per packet via
+/// bounded-distance decoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockCodeParams {
+    /// Coded block length (symbols per packet).
+    pub n: usize,
+    /// Information symbols per packet (`k <= n`).
+    pub k: usize,
+    /// Symbol errors the code corrects per block.
+    pub t: usize,
+}
+
+impl BlockCodeParams {
+    /// Code rate `k / n`.
+    pub fn code_rate(&self) -> f64 {
+        self.k as f64 / self.n as f64
+    }
+
+    /// Post-decoding packet error rate given a per-symbol error probability
+    /// `symbol_error_rate`: a block fails only if more than `t` of its `n`
+    /// symbols are corrupted, i.e. `P(X > t)` for `X ~ Binomial(n, p)`.
+    pub fn decoded_packet_error_rate(&self, symbol_error_rate: f64) -> f64 {
+        let p = symbol_error_rate.clamp(0.0, 1.0);
+        if p <= 0.0 {
+            return 0.0;
+        }
+        if p >= 1.0 {
+            return 1.0;
+        }
+
+        let survival_probability: f64 = (0..=self.t.min(self.n))
+            .map(|errors| binomial_pmf(self.n, errors, p))
+            .sum();
+        (1.0 - survival_probability).clamp(0.0, 1.0)
+    }
+}
+
+/// `P(X = k)` for `X ~ Binomial(n, p)`, computed via the log-gamma-free
+/// multiplicative recurrence `C(n, k) = C(n, k-1) * (n-k+1) / k` to avoid
+/// overflowing `n!` for realistic packet-length block codes.
+fn binomial_pmf(n: usize, k: usize, p: f64) -> f64 {
+    let mut binomial_coefficient = 1.0_f64;
+    for i in 0..k {
+        binomial_coefficient *= (n - i) as f64 / (i + 1) as f64;
+    }
+    binomial_coefficient * p.powi(k as i32) * (1.0 - p).powi((n - k) as i32)
+}
+
 /// Jamming model for electronic warfare
 pub struct JammingModel {
     config: JammingConfig,
@@ -184,6 +248,113 @@ impl JammingModel {
         jamming_power_dbm
     }
 
+    /// Average received jamming power (dBm) from the same Friis/atmospheric
+    /// /polarization/terrain budget as [`Self::compute_jamming_power`], but
+    /// without [`Self::compute_type_specific_gain`]'s ad hoc per-type
+    /// average-power adjustment -- [`Self::compute_pulse_jamming_ber`]
+    /// derives its own peak/average relationship from `rho` directly and
+    /// would otherwise double-count the duty cycle the [`JammingType::Pulse`]
+    /// branch already folds in.
+    fn average_path_loss_adjusted_power_dbm(&self, target_position: Vector3<f64>) -> f64 {
+        let distance_m = (target_position - self.config.jammer_position).norm();
+
+        if distance_m < 1.0 {
+            return self.config.jammer_power_dbm + self.config.antenna_gain_dbi;
+        }
+
+        let wavelength = 3e8 / self.config.center_frequency_hz;
+        let fspl_db = 20.0 * ((4.0 * PI * distance_m) / wavelength).log10();
+
+        let distance_km = distance_m / 1000.0;
+        let atmospheric_loss_db = self.config.atmospheric_loss_db_per_km * distance_km;
+
+        let mut power_dbm = self.config.jammer_power_dbm + self.config.antenna_gain_dbi
+            - fspl_db
+            - atmospheric_loss_db
+            - self.config.polarization_loss_db;
+
+        if self.config.enable_terrain_masking {
+            let jammer_height = self.config.jammer_position.z.abs();
+            let target_height = target_position.z.abs();
+
+            if jammer_height < 10.0 && target_height < 10.0 {
+                power_dbm -= 10.0;
+            }
+        }
+
+        power_dbm
+    }
+
+    /// Expected BER under statistical pulse jamming: the jammer is "on"
+    /// with probability `rho` (Bernoulli), and while on radiates the
+    /// *average* power divided by `rho` -- the peak concentration that
+    /// keeps long-run average power fixed as `rho` shrinks. This replaces
+    /// [`JammingType::Pulse`]'s average-power-only approximation with the
+    /// actual mixture the receiver sees:
+    ///
+    /// `BER = rho * BER(SINR_on) + (1 - rho) * BER(SINR_off)`
+    ///
+    /// where `SINR_off` uses only thermal noise (jammer silent) and
+    /// `SINR_on` uses the peak jamming power. `rho` is clamped to
+    /// `(0.0, 1.0]`.
+    pub fn compute_pulse_jamming_ber(
+        &self,
+        signal_power_dbm: f64,
+        target_position: Vector3<f64>,
+        noise_power_dbm: f64,
+        rho: f64,
+    ) -> f64 {
+        let rho = rho.clamp(1e-6, 1.0);
+
+        let signal_power_w = dbm_to_watts(signal_power_dbm);
+        let noise_power_w = dbm_to_watts(noise_power_dbm);
+        let avg_jamming_power_w =
+            dbm_to_watts(self.average_path_loss_adjusted_power_dbm(target_position));
+        let peak_jamming_power_w = avg_jamming_power_w / rho;
+
+        let sinr_on = signal_power_w / (peak_jamming_power_w + noise_power_w);
+        let sinr_off = signal_power_w / noise_power_w;
+
+        rho * ber_from_sinr(sinr_on) + (1.0 - rho) * ber_from_sinr(sinr_off)
+    }
+
+    /// The pulse duty cycle `rho` in `(0.0, 1.0]` that maximizes
+    /// [`Self::compute_pulse_jamming_ber`] -- the well-known result that an
+    /// optimized partial-duty-cycle pulse jammer does more damage than a
+    /// continuous jammer radiating the same average power. No closed form
+    /// exists for the exponential BER approximation used here, so this
+    /// scans a fine grid of candidate duty cycles, the same
+    /// evaluate-the-candidates approach [`Self::burn_through_range_vs_jammer_power`]
+    /// uses for its own parameter sweep.
+    pub fn optimal_pulse_duty_cycle(
+        &self,
+        signal_power_dbm: f64,
+        target_position: Vector3<f64>,
+        noise_power_dbm: f64,
+    ) -> f64 {
+        const NUM_CANDIDATES: usize = 2000;
+
+        (1..=NUM_CANDIDATES)
+            .map(|i| i as f64 / NUM_CANDIDATES as f64)
+            .map(|rho| {
+                let ber = self.compute_pulse_jamming_ber(
+                    signal_power_dbm,
+                    target_position,
+                    noise_power_dbm,
+                    rho,
+                );
+                (rho, ber)
+            })
+            .fold((1.0, f64::MIN), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .0
+    }
+
     /// Compute type-specific power adjustment
     fn compute_type_specific_gain(&self) -> f64 {
         match self.config.jamming_type {
@@ -270,13 +441,19 @@ impl JammingModel {
     /// C = B * log2(1 + SNR)
     ///
     /// With jamming, SNR becomes SINR (Signal-to-Interference-plus-Noise Ratio)
+    ///
+    /// `excision_gain_db` is the effective J/S improvement (dB) an anti-jam
+    /// receiver's excision filter achieved against a narrowband jammer --
+    /// see `crate::excision_filter::ExcisionResult::excision_gain_db`. Pass
+    /// `0.0` for a receiver with no excision filter.
     pub fn compute_throughput_reduction(
         &self,
         signal_power_dbm: f64,
         target_position: Vector3<f64>,
         noise_power_dbm: f64,
+        excision_gain_db: f64,
     ) -> f64 {
-        let jamming_power_dbm = self.compute_jamming_power(target_position);
+        let jamming_power_dbm = self.compute_jamming_power(target_position) - excision_gain_db;
 
         // Convert to linear scale (watts)
         let signal_power_w = dbm_to_watts(signal_power_dbm);
@@ -300,14 +477,20 @@ impl JammingModel {
     ///
     /// Uses approximation: PER ≈ 1 - (1 - BER)^N
     /// where N is packet length in bits
+    ///
+    /// `excision_gain_db` is the effective J/S improvement (dB) an anti-jam
+    /// receiver's excision filter achieved against a narrowband jammer --
+    /// see `crate::excision_filter::ExcisionResult::excision_gain_db`. Pass
+    /// `0.0` for a receiver with no excision filter.
     pub fn compute_packet_error_rate(
         &self,
         signal_power_dbm: f64,
         target_position: Vector3<f64>,
         noise_power_dbm: f64,
         packet_length_bits: usize,
+        excision_gain_db: f64,
     ) -> f64 {
-        let jamming_power_dbm = self.compute_jamming_power(target_position);
+        let jamming_power_dbm = self.compute_jamming_power(target_position) - excision_gain_db;
 
         // Convert to linear scale
         let signal_power_w = dbm_to_watts(signal_power_dbm);
@@ -332,6 +515,71 @@ impl JammingModel {
         per.clamp(0.0, 1.0)
     }
 
+    /// Solve for the burn-through range: the distance between a desired
+    /// transmitter and `rx_position` at which the received signal's J/S
+    /// margin first reaches `required_js_db`.
+    ///
+    /// This is the classic self-screening/stand-off geometry: this
+    /// jammer's position and power are fixed, and its power at
+    /// `rx_position` is evaluated once via [`Self::compute_jamming_power`]
+    /// (picking up atmospheric/polarization/terrain/jamming-type losses),
+    /// while the desired link's range is the unknown being solved for. The
+    /// desired signal itself uses free-space (Friis) path loss only, at
+    /// `self.config.center_frequency_hz`.
+    ///
+    /// Derivation: `J/S = jamming_power_dbm - signal_power_dbm`, where
+    /// `signal_power_dbm = tx_power_dbm + tx_gain_dbi - fspl_db(range)` and
+    /// `fspl_db(range) = 20*log10(4*pi*range/wavelength)`. Setting J/S to
+    /// `required_js_db` and solving for `range` gives a closed form -- no
+    /// iterative root-finding needed.
+    pub fn compute_burn_through_range(
+        &self,
+        tx_power_dbm: f64,
+        tx_gain_dbi: f64,
+        rx_position: Vector3<f64>,
+        required_js_db: f64,
+    ) -> f64 {
+        let jamming_power_dbm = self.compute_jamming_power(rx_position);
+
+        // fspl_db(range) = tx_power_dbm + tx_gain_dbi - jamming_power_dbm + required_js_db
+        let target_fspl_db = tx_power_dbm + tx_gain_dbi - jamming_power_dbm + required_js_db;
+
+        let wavelength = 3e8 / self.config.center_frequency_hz;
+        (wavelength / (4.0 * PI)) * 10.0_f64.powf(target_fspl_db / 20.0)
+    }
+
+    /// Sweep a set of candidate jammer transmit powers (watts) and return
+    /// the corresponding burn-through range (km) for each, reproducing the
+    /// classic burn-through-range-vs-jammer-power curve. `rx_position` and
+    /// the J/S margin are held fixed across the sweep; only
+    /// `jammer_power_w` varies, via a throwaway [`JammingModel`] built from
+    /// this model's config with just the power swapped out.
+    pub fn burn_through_range_vs_jammer_power(
+        &self,
+        tx_power_dbm: f64,
+        tx_gain_dbi: f64,
+        rx_position: Vector3<f64>,
+        required_js_db: f64,
+        jammer_power_candidates_w: &[f64],
+    ) -> Vec<(f64, f64)> {
+        jammer_power_candidates_w
+            .iter()
+            .map(|&jammer_power_w| {
+                let config = JammingConfig {
+                    jammer_power_dbm: watts_to_dbm(jammer_power_w),
+                    ..self.config.clone()
+                };
+                let range_m = JammingModel::new(config).compute_burn_through_range(
+                    tx_power_dbm,
+                    tx_gain_dbi,
+                    rx_position,
+                    required_js_db,
+                );
+                (jammer_power_w, range_m / 1000.0)
+            })
+            .collect()
+    }
+
     /// Get current configuration
     pub fn config(&self) -> &JammingConfig {
         &self.config
@@ -415,7 +663,7 @@ mod tests {
         let signal_dbm = -70.0;
         let noise_dbm = -100.0;
 
-        let throughput = jammer.compute_throughput_reduction(signal_dbm, target, noise_dbm);
+        let throughput = jammer.compute_throughput_reduction(signal_dbm, target, noise_dbm, 0.0);
 
         // Should have reduced throughput
         assert!(throughput < 1.0);
@@ -436,10 +684,139 @@ mod tests {
         let noise_dbm = -100.0;
         let packet_length = 1000; // bits
 
-        let per = jammer.compute_packet_error_rate(signal_dbm, target, noise_dbm, packet_length);
+        let per =
+            jammer.compute_packet_error_rate(signal_dbm, target, noise_dbm, packet_length, 0.0);
 
         // Should have some packet errors under jamming
         assert!(per > 0.0);
         assert!(per <= 1.0);
     }
+
+    #[test]
+    fn test_burn_through_range_matches_jsr_at_that_distance() {
+        let config = JammingConfig {
+            jammer_position: Vector3::zeros(),
+            jammer_power_dbm: 50.0,
+            jamming_type: JammingType::Barrage,
+            ..Default::default()
+        };
+        let jammer = JammingModel::new(config);
+
+        let rx_position = Vector3::new(5000.0, 0.0, 50.0);
+        let tx_power_dbm = 30.0;
+        let tx_gain_dbi = 3.0;
+        let required_js_db = jsr_thresholds::MARGINAL;
+
+        let range_m = jammer.compute_burn_through_range(
+            tx_power_dbm,
+            tx_gain_dbi,
+            rx_position,
+            required_js_db,
+        );
+
+        // At exactly the solved-for range, the desired signal's Friis-only
+        // path loss should reproduce the requested J/S margin against the
+        // jammer's power at `rx_position`.
+        let wavelength = 3e8 / jammer.config().center_frequency_hz;
+        let fspl_db = 20.0 * ((4.0 * PI * range_m) / wavelength).log10();
+        let signal_power_dbm = tx_power_dbm + tx_gain_dbi - fspl_db;
+        let jamming_power_dbm = jammer.compute_jamming_power(rx_position);
+        let jsr_db = jammer.compute_jamming_to_signal_ratio(signal_power_dbm, jamming_power_dbm);
+
+        assert_abs_diff_eq!(jsr_db, required_js_db, epsilon = 0.01);
+    }
+
+    #[test]
+    fn test_burn_through_range_grows_with_jammer_power() {
+        let config = JammingConfig {
+            jammer_position: Vector3::zeros(),
+            jamming_type: JammingType::Barrage,
+            ..Default::default()
+        };
+        let jammer = JammingModel::new(config);
+
+        let rx_position = Vector3::new(5000.0, 0.0, 50.0);
+        let curve = jammer.burn_through_range_vs_jammer_power(
+            30.0,
+            3.0,
+            rx_position,
+            jsr_thresholds::MARGINAL,
+            &[1.0, 10.0, 100.0, 1000.0],
+        );
+
+        assert_eq!(curve.len(), 4);
+        for pair in curve.windows(2) {
+            assert!(pair[1].1 > pair[0].1); // more jammer power -> longer burn-through range
+        }
+    }
+
+    #[test]
+    fn test_pulse_jamming_ber_falls_back_to_continuous_at_full_duty_cycle() {
+        let config = JammingConfig {
+            jammer_position: Vector3::zeros(),
+            jammer_power_dbm: 40.0,
+            jamming_type: JammingType::Barrage,
+            ..Default::default()
+        };
+        let jammer = JammingModel::new(config);
+        let target = Vector3::new(1000.0, 0.0, 50.0);
+        let signal_dbm = -70.0;
+        let noise_dbm = -100.0;
+
+        // At rho = 1.0 the jammer is always on at the (un-concentrated)
+        // average power, so the expected BER collapses to the ordinary
+        // continuous-jamming SINR's BER.
+        let pulse_ber = jammer.compute_pulse_jamming_ber(signal_dbm, target, noise_dbm, 1.0);
+
+        let signal_power_w = dbm_to_watts(signal_dbm);
+        let noise_power_w = dbm_to_watts(noise_dbm);
+        let jamming_power_w = dbm_to_watts(jammer.average_path_loss_adjusted_power_dbm(target));
+        let expected_ber = ber_from_sinr(signal_power_w / (jamming_power_w + noise_power_w));
+
+        assert_abs_diff_eq!(pulse_ber, expected_ber, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_optimized_pulse_duty_cycle_outperforms_continuous_jamming() {
+        let config = JammingConfig {
+            jammer_position: Vector3::zeros(),
+            jammer_power_dbm: 30.0,
+            jamming_type: JammingType::Barrage,
+            ..Default::default()
+        };
+        let jammer = JammingModel::new(config);
+        let target = Vector3::new(2000.0, 0.0, 50.0);
+        let signal_dbm = -60.0;
+        let noise_dbm = -100.0;
+
+        let rho_opt = jammer.optimal_pulse_duty_cycle(signal_dbm, target, noise_dbm);
+        let ber_opt = jammer.compute_pulse_jamming_ber(signal_dbm, target, noise_dbm, rho_opt);
+        let ber_continuous = jammer.compute_pulse_jamming_ber(signal_dbm, target, noise_dbm, 1.0);
+
+        assert!(rho_opt > 0.0 && rho_opt <= 1.0);
+        assert!(ber_opt >= ber_continuous);
+    }
+
+    #[test]
+    fn test_block_code_decoded_error_rate_improves_on_raw_symbol_rate() {
+        let code = BlockCodeParams {
+            n: 100,
+            k: 80,
+            t: 5,
+        };
+        assert_abs_diff_eq!(code.code_rate(), 0.8, epsilon = 1e-9);
+
+        // With a low symbol error rate, a code correcting several errors
+        // per block should have a much lower packet error rate than an
+        // uncoded channel's raw per-symbol rate.
+        let symbol_error_rate = 0.01;
+        let decoded_per = code.decoded_packet_error_rate(symbol_error_rate);
+        assert!(decoded_per < symbol_error_rate);
+    }
+
+    #[test]
+    fn test_block_code_zero_error_rate_never_fails() {
+        let code = BlockCodeParams { n: 50, k: 40, t: 3 };
+        assert_abs_diff_eq!(code.decoded_packet_error_rate(0.0), 0.0, epsilon = 1e-12);
+    }
 }
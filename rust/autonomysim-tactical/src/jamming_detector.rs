@@ -0,0 +1,336 @@
+//! Victim-side jamming detection and classification.
+//!
+//! [`jamming`](crate::jamming) models the attacker's side of the link only.
+//! `JammingDetector` is the counterpart a simulated receiver runs: it tracks
+//! three correlated statistics per sliding window -- packet delivery ratio,
+//! short-term signal-strength variance, and received pulse width -- and
+//! compares a window's feature vector against reference profiles (mean +
+//! covariance, trained offline) using Mahalanobis distance. A window far
+//! enough from the clean profile is flagged as jammed; among labeled
+//! per-[`JammingType`] profiles, the nearest one is the classification.
+//! This lets "`Deception` is hardest to detect" be a measured distance
+//! rather than a comment.
+
+use crate::jamming::JammingType;
+use nalgebra::{Matrix3, Vector3};
+use std::collections::HashMap;
+
+/// One sliding window's aggregated detection features.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectionSample {
+    /// Delivered / sent packets over the window, e.g. `1.0 -
+    /// compute_packet_error_rate(..)` averaged over the window.
+    pub packet_delivery_ratio: f64,
+    /// Variance of received signal strength (dB^2) within the window.
+    pub signal_strength_variance: f64,
+    /// Mean received pulse width over the window (seconds).
+    pub pulse_width_s: f64,
+}
+
+impl DetectionSample {
+    fn as_vector(self) -> Vector3<f64> {
+        Vector3::new(
+            self.packet_delivery_ratio,
+            self.signal_strength_variance,
+            self.pulse_width_s,
+        )
+    }
+}
+
+/// Jamming-type identity used as a classification label, ignoring each
+/// variant's own tuning parameters -- profiles are trained per *type*, not
+/// per specific parameterization of that type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum JammingKind {
+    Barrage,
+    Follower,
+    Swept,
+    Pulse,
+    Deception,
+}
+
+impl From<JammingType> for JammingKind {
+    fn from(jamming_type: JammingType) -> Self {
+        match jamming_type {
+            JammingType::Barrage => JammingKind::Barrage,
+            JammingType::Follower { .. } => JammingKind::Follower,
+            JammingType::Swept { .. } => JammingKind::Swept,
+            JammingType::Pulse { .. } => JammingKind::Pulse,
+            JammingType::Deception { .. } => JammingKind::Deception,
+        }
+    }
+}
+
+/// Mean + inverse covariance of a set of [`DetectionSample`]s, for
+/// Mahalanobis-distance comparison against a new sample.
+#[derive(Debug, Clone)]
+struct Profile {
+    mean: Vector3<f64>,
+    covariance_inv: Matrix3<f64>,
+}
+
+impl Profile {
+    /// Fit mean and covariance from `samples`. Returns `None` if there are
+    /// too few samples (need at least 2 for a covariance estimate) or the
+    /// covariance is singular (e.g. a feature was constant across training).
+    fn train(samples: &[DetectionSample]) -> Option<Self> {
+        if samples.len() < 2 {
+            return None;
+        }
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(|s| s.as_vector()).sum::<Vector3<f64>>() / n;
+
+        let mut covariance = Matrix3::zeros();
+        for sample in samples {
+            let deviation = sample.as_vector() - mean;
+            covariance += deviation * deviation.transpose();
+        }
+        covariance /= n - 1.0;
+        // Tiny regularization so a near-constant feature doesn't make the
+        // covariance exactly singular.
+        for i in 0..3 {
+            covariance[(i, i)] += 1e-9;
+        }
+
+        Some(Self {
+            mean,
+            covariance_inv: covariance.try_inverse()?,
+        })
+    }
+
+    /// `sqrt((x - mean)^T * covariance_inv * (x - mean))`.
+    fn mahalanobis_distance(&self, sample: DetectionSample) -> f64 {
+        let deviation = sample.as_vector() - self.mean;
+        (deviation.transpose() * self.covariance_inv * deviation)[(0, 0)]
+            .max(0.0)
+            .sqrt()
+    }
+}
+
+/// Tuning for [`JammingDetector`].
+#[derive(Debug, Clone, Copy)]
+pub struct JammingDetectorConfig {
+    /// Mahalanobis distance from the clean profile above which a window is
+    /// flagged as jammed. 3.0 is a common rule-of-thumb cutoff for a 3-feature
+    /// Gaussian profile (roughly a 3-sigma-equivalent outlier).
+    pub detection_threshold: f64,
+}
+
+impl Default for JammingDetectorConfig {
+    fn default() -> Self {
+        Self {
+            detection_threshold: 3.0,
+        }
+    }
+}
+
+/// A classified jamming window: the nearest trained [`JammingType`] profile
+/// and a confidence derived from how much closer it is than the clean
+/// profile.
+#[derive(Debug, Clone, Copy)]
+pub struct Classification {
+    pub jamming_type: JammingType,
+    /// 0.0 (barely closer to this profile than to clean) to 1.0 (extremely
+    /// confident).
+    pub confidence: f64,
+}
+
+/// Victim-side jamming detector: trained offline on clean and per-type
+/// jammed traffic, then run per sliding window at runtime.
+#[derive(Debug, Clone, Default)]
+pub struct JammingDetector {
+    config: JammingDetectorConfig,
+    clean_profile: Option<Profile>,
+    jamming_profiles: HashMap<JammingKind, (JammingType, Profile)>,
+}
+
+impl JammingDetector {
+    pub fn new(config: JammingDetectorConfig) -> Self {
+        Self {
+            config,
+            clean_profile: None,
+            jamming_profiles: HashMap::new(),
+        }
+    }
+
+    /// Train the reference "no jammer present" profile from clean samples.
+    pub fn train_clean(&mut self, samples: &[DetectionSample]) {
+        self.clean_profile = Profile::train(samples);
+    }
+
+    /// Train a labeled profile for one jamming type from samples collected
+    /// while that jammer was active.
+    pub fn train_jamming_type(&mut self, jamming_type: JammingType, samples: &[DetectionSample]) {
+        if let Some(profile) = Profile::train(samples) {
+            self.jamming_profiles
+                .insert(JammingKind::from(jamming_type), (jamming_type, profile));
+        }
+    }
+
+    /// Mahalanobis distance of `window` from the clean profile, or `None` if
+    /// [`Self::train_clean`] hasn't been called with enough samples yet.
+    pub fn clean_distance(&self, window: DetectionSample) -> Option<f64> {
+        self.clean_profile
+            .as_ref()
+            .map(|profile| profile.mahalanobis_distance(window))
+    }
+
+    /// True if `window`'s distance from the clean profile exceeds
+    /// `config.detection_threshold`. Returns `false` (rather than erroring)
+    /// if the clean profile hasn't been trained, since "no jammer assumed"
+    /// is the correct default in that case.
+    pub fn is_jammed(&self, window: DetectionSample) -> bool {
+        self.clean_distance(window)
+            .is_some_and(|distance| distance > self.config.detection_threshold)
+    }
+
+    /// Classify `window`: `None` if it isn't flagged as jammed (or the clean
+    /// profile isn't trained), else the nearest labeled jamming-type profile
+    /// by Mahalanobis distance, with a confidence score.
+    pub fn classify(&self, window: DetectionSample) -> Option<Classification> {
+        let clean_distance = self.clean_distance(window)?;
+        if clean_distance <= self.config.detection_threshold {
+            return None;
+        }
+
+        let (jamming_type, distance) = self
+            .jamming_profiles
+            .values()
+            .map(|(jamming_type, profile)| (*jamming_type, profile.mahalanobis_distance(window)))
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).expect("distances are never NaN"))?;
+
+        // How much closer the window is to this jamming profile than to the
+        // clean profile, normalized to [0, 1].
+        let confidence = (1.0 - distance / (distance + clean_distance)).clamp(0.0, 1.0);
+        Some(Classification {
+            jamming_type,
+            confidence,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean_samples() -> Vec<DetectionSample> {
+        vec![
+            DetectionSample {
+                packet_delivery_ratio: 0.98,
+                signal_strength_variance: 1.0,
+                pulse_width_s: 0.0,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.97,
+                signal_strength_variance: 1.2,
+                pulse_width_s: 0.0,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.99,
+                signal_strength_variance: 0.9,
+                pulse_width_s: 0.0,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.96,
+                signal_strength_variance: 1.1,
+                pulse_width_s: 0.0,
+            },
+        ]
+    }
+
+    fn barrage_samples() -> Vec<DetectionSample> {
+        vec![
+            DetectionSample {
+                packet_delivery_ratio: 0.2,
+                signal_strength_variance: 8.0,
+                pulse_width_s: 0.0,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.15,
+                signal_strength_variance: 9.0,
+                pulse_width_s: 0.0,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.25,
+                signal_strength_variance: 7.5,
+                pulse_width_s: 0.0,
+            },
+        ]
+    }
+
+    fn pulse_samples() -> Vec<DetectionSample> {
+        vec![
+            DetectionSample {
+                packet_delivery_ratio: 0.6,
+                signal_strength_variance: 4.0,
+                pulse_width_s: 0.001,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.55,
+                signal_strength_variance: 4.5,
+                pulse_width_s: 0.0012,
+            },
+            DetectionSample {
+                packet_delivery_ratio: 0.65,
+                signal_strength_variance: 3.8,
+                pulse_width_s: 0.0009,
+            },
+        ]
+    }
+
+    #[test]
+    fn clean_window_is_not_flagged() {
+        let mut detector = JammingDetector::new(JammingDetectorConfig::default());
+        detector.train_clean(&clean_samples());
+
+        let window = DetectionSample {
+            packet_delivery_ratio: 0.975,
+            signal_strength_variance: 1.05,
+            pulse_width_s: 0.0,
+        };
+        assert!(!detector.is_jammed(window));
+        assert!(detector.classify(window).is_none());
+    }
+
+    #[test]
+    fn barrage_window_is_flagged_and_classified() {
+        let mut detector = JammingDetector::new(JammingDetectorConfig::default());
+        detector.train_clean(&clean_samples());
+        detector.train_jamming_type(JammingType::Barrage, &barrage_samples());
+        detector.train_jamming_type(
+            JammingType::Pulse {
+                prf: 1000.0,
+                pulse_width: 0.001,
+                peak_power_dbm: 60.0,
+            },
+            &pulse_samples(),
+        );
+
+        let window = DetectionSample {
+            packet_delivery_ratio: 0.18,
+            signal_strength_variance: 8.5,
+            pulse_width_s: 0.0,
+        };
+        assert!(detector.is_jammed(window));
+        let classification = detector
+            .classify(window)
+            .expect("window is flagged as jammed");
+        assert_eq!(
+            JammingKind::from(classification.jamming_type),
+            JammingKind::Barrage
+        );
+        assert!(classification.confidence > 0.0);
+    }
+
+    #[test]
+    fn untrained_detector_assumes_no_jammer() {
+        let detector = JammingDetector::new(JammingDetectorConfig::default());
+        let window = DetectionSample {
+            packet_delivery_ratio: 0.1,
+            signal_strength_variance: 20.0,
+            pulse_width_s: 0.0,
+        };
+        assert!(!detector.is_jammed(window));
+        assert!(detector.classify(window).is_none());
+    }
+}
@@ -6,6 +6,11 @@
 //! - MANET protocols (OLSR, AODV simulation)
 //! - Spectrum management (dynamic frequency allocation)
 //! - Link quality metrics (SNR, BER, packet loss)
+//! - Attrition / damage modeling (hit points, subsystem failures)
+//! - Relay-routed mesh topology from real RF link budgets
+//! - Per-robot tactical AI (threat/support-driven behavior FSM)
+//! - Weapon systems (ranged, cooldown-gated engagements)
+//! - Lennard-Jones swarm flocking driven by the link-graph neighbor set
 //!
 //! # Example
 //!
@@ -42,16 +47,61 @@
 //! - **Network Resilience**: Test mesh network recovery under denial
 //! - **Counter-EW Development**: Train AI to adapt to jamming
 
+pub mod ai;
+pub mod aodv;
+pub mod array_beamforming;
+pub mod cognitive_jammer;
+pub mod conditions;
+pub mod damage;
+pub mod excision_filter;
+pub mod flocking;
+pub mod healing;
+pub mod jammer_locator;
 pub mod jamming;
+pub mod jamming_detector;
+pub mod mesh;
 pub mod metrics;
 pub mod network;
+pub mod radar_jamming;
 pub mod spectrum;
+pub mod weapon;
 
 // Re-export commonly used types
-pub use jamming::{jsr_thresholds, JammingConfig, JammingModel, JammingType};
+pub use ai::{BehaviorState, TacticalAI, TacticalAIConfig, ThreatContact, UnitClass};
+pub use array_beamforming::{
+    ArrayReceiver, BeamformingResult, SampleMatrixInversionBeamformer, SmiBeamformerConfig,
+};
+pub use cognitive_jammer::{CognitiveJammer, CognitiveJammerConfig, JammingArm, VictimFeedback};
+pub use damage::{
+    DamageConfig, DamageMode, DamageModel, EngagementOutcome, FailureMode, RobotDamageState,
+};
+pub use excision_filter::{
+    Complex, ExcisionResult, NlmsExcisionConfig, SpectralExcisionConfig, SpectralExcisionFilter,
+    TemporalExcisionFilter,
+};
+pub use flocking::NetworkFlockingDriver;
+pub use jammer_locator::{
+    EstimatorKind, JammerEstimate, JammerLocator, JammerLocatorConfig, Measurement,
+};
+pub use jamming::{jsr_thresholds, BlockCodeParams, JammingConfig, JammingModel, JammingType};
+pub use jamming_detector::{
+    Classification, DetectionSample, JammingDetector, JammingDetectorConfig,
+};
+pub use mesh::{
+    build_mesh_topology, compare_messaging_strategies, count_naive_broadcast, count_relay_routed,
+    mean_hops_to_nearest_coordinator, MessageAccounting,
+};
 pub use metrics::{
-    db_to_linear, dbm_to_watts, linear_to_db, watts_to_dbm, BERCalculator, LinkBudget,
-    ModulationScheme, PERCalculator, SignalMetrics,
+    db_to_linear, dbm_to_watts, erfc, linear_to_db, select_amc_mode, watts_to_dbm, AmcSelection,
+    BERCalculator, ChannelBandwidth, Code, CombinedSignal, DiversityScheme, FadingChannel,
+    LinkBudget, ModulationScheme, PERCalculator, SignalMetrics, SpreadingConfig,
+};
+pub use network::{
+    AgentId, LinkQuality, LinkState, NetworkTopology, NodeNetworkCapacity, PartitionDetector,
+    RoutedPath,
+};
+pub use radar_jamming::{JammerGeometry, RadarJammerParams, RadarJammingScenario};
+pub use spectrum::{
+    Channel, FrequencyAllocation, HandoffConfig, HandoffPolicy, LoraModulation, SpectrumManager,
 };
-pub use network::{AgentId, LinkQuality, LinkState, NetworkTopology, PartitionDetector};
-pub use spectrum::{Channel, FrequencyAllocation, SpectrumManager};
+pub use weapon::{nearest_target, TargetClass, Weapon, WeaponConfig};
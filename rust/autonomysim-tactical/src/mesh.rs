@@ -0,0 +1,210 @@
+//! Relay-routed multi-hop mesh built from real RF link budgets
+//!
+//! Ties [`RFPropagationEngine`]'s per-pair RSSI/SNR together with an
+//! optional [`JammingModel`]'s interference into the same
+//! [`NetworkTopology`] graph [`crate::network::PartitionDetector`] already
+//! uses for connected-component and shortest-path analysis, replacing a
+//! flat "some fraction of links survive" approximation with edges that only
+//! exist where the link budget actually clears. [`count_relay_routed`] then
+//! accounts for the messaging overhead relay-forwarded routing over that
+//! graph costs compared to naive all-to-all broadcast.
+
+use nalgebra::{Point3, Vector3};
+
+use autonomysim_rf_core::{RFPropagationEngine, RFResult};
+
+use crate::jamming::JammingModel;
+use crate::network::{AgentId, LinkQuality, NetworkTopology};
+
+/// Build a [`NetworkTopology`] from real link budgets: an edge exists
+/// between two agents only if their received power -- with `jamming`'s
+/// interference (if any) folded into the resulting packet loss -- still
+/// clears `min_snr_db`/`max_packet_loss`.
+pub async fn build_mesh_topology(
+    rf_engine: &RFPropagationEngine,
+    jamming: Option<&JammingModel>,
+    agents: &[(AgentId, Vector3<f64>)],
+    bandwidth_hz: f64,
+    min_snr_db: f64,
+    max_packet_loss: f64,
+) -> RFResult<NetworkTopology> {
+    let mut topology = NetworkTopology::new();
+    for &(id, position) in agents {
+        topology.add_agent(id, position);
+    }
+
+    for (i, &(src, src_pos)) in agents.iter().enumerate() {
+        for &(dst, dst_pos) in &agents[i + 1..] {
+            let link = rf_engine
+                .compute_link(to_point(src_pos), to_point(dst_pos), bandwidth_hz)
+                .await?;
+
+            let packet_loss_rate = jamming
+                .map(|jammer| {
+                    jammer.compute_packet_error_rate(
+                        link.rssi,
+                        dst_pos,
+                        link.noise_dbm,
+                        1500 * 8,
+                        0.0,
+                    )
+                })
+                .unwrap_or(0.0);
+
+            let quality = LinkQuality {
+                snr_db: link.snr_db,
+                rssi_dbm: link.rssi,
+                packet_loss_rate,
+                latency_s: 0.01,
+                bandwidth_bps: bandwidth_hz,
+                active_duration_s: 0.0,
+            };
+
+            if quality.is_usable(min_snr_db, max_packet_loss) {
+                topology.add_link(src, dst, quality);
+                topology.add_link(dst, src, quality);
+            }
+        }
+    }
+
+    Ok(topology)
+}
+
+fn to_point(v: Vector3<f64>) -> Point3<f64> {
+    Point3::new(v.x, v.y, v.z)
+}
+
+/// Messaging overhead comparison between two dissemination strategies over
+/// the same [`NetworkTopology`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageAccounting {
+    /// Transmissions a naive all-to-all broadcast costs per round: every
+    /// agent pair attempts delivery regardless of reachability.
+    pub naive_transmissions: usize,
+    /// Transmissions relay-forwarded routing costs per round: one hop per
+    /// edge on each agent's shortest path to its nearest coordinator.
+    pub relayed_transmissions: usize,
+}
+
+/// Transmissions an all-to-all broadcast costs each round: every agent
+/// pair, regardless of whether a path exists between them.
+pub fn count_naive_broadcast(num_agents: usize) -> usize {
+    num_agents.saturating_mul(num_agents.saturating_sub(1)) / 2
+}
+
+/// Transmissions relay-forwarded routing costs each round: each
+/// non-coordinator agent's message travels its shortest path to the
+/// nearest coordinator, and each hop on that path is one retransmission --
+/// so a message only gets forwarded once per node, not broadcast to every
+/// other agent.
+pub fn count_relay_routed(topology: &NetworkTopology, coordinators: &[AgentId]) -> usize {
+    topology
+        .get_agents()
+        .into_iter()
+        .filter(|agent| !coordinators.contains(agent))
+        .filter_map(|agent| nearest_coordinator_path(topology, agent, coordinators))
+        .map(|path| path.len().saturating_sub(1))
+        .sum()
+}
+
+/// Compare naive all-to-all broadcast against relay-forwarded routing over
+/// `topology`.
+pub fn compare_messaging_strategies(
+    topology: &NetworkTopology,
+    coordinators: &[AgentId],
+) -> MessageAccounting {
+    MessageAccounting {
+        naive_transmissions: count_naive_broadcast(topology.agent_count()),
+        relayed_transmissions: count_relay_routed(topology, coordinators),
+    }
+}
+
+/// Mean hop count from every reachable non-coordinator agent to its
+/// nearest coordinator; `None` if no agent can reach any coordinator.
+pub fn mean_hops_to_nearest_coordinator(
+    topology: &NetworkTopology,
+    coordinators: &[AgentId],
+) -> Option<f64> {
+    let hop_counts: Vec<usize> = topology
+        .get_agents()
+        .into_iter()
+        .filter(|agent| !coordinators.contains(agent))
+        .filter_map(|agent| nearest_coordinator_path(topology, agent, coordinators))
+        .map(|path| path.len() - 1)
+        .collect();
+
+    if hop_counts.is_empty() {
+        return None;
+    }
+
+    Some(hop_counts.iter().sum::<usize>() as f64 / hop_counts.len() as f64)
+}
+
+/// Shortest path from `agent` to whichever `coordinators` entry it's
+/// closest to, or `None` if none are reachable.
+fn nearest_coordinator_path(
+    topology: &NetworkTopology,
+    agent: AgentId,
+    coordinators: &[AgentId],
+) -> Option<Vec<AgentId>> {
+    coordinators
+        .iter()
+        .filter_map(|&coordinator| topology.find_shortest_path(agent, coordinator))
+        .min_by_key(|path| path.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear_topology(n: usize) -> NetworkTopology {
+        let mut topology = NetworkTopology::new();
+        for i in 0..n {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        let quality = LinkQuality::default();
+        for i in 0..n.saturating_sub(1) {
+            topology.add_link(i, i + 1, quality);
+            topology.add_link(i + 1, i, quality);
+        }
+        topology
+    }
+
+    #[test]
+    fn test_naive_broadcast_count_ignores_topology() {
+        assert_eq!(count_naive_broadcast(5), 10);
+        assert_eq!(count_naive_broadcast(1), 0);
+        assert_eq!(count_naive_broadcast(0), 0);
+    }
+
+    #[test]
+    fn test_relay_routed_count_sums_hops_to_nearest_coordinator() {
+        // 0 -- 1 -- 2 -- 3, coordinator at 0: agent 1 is 1 hop, 2 is 2 hops,
+        // 3 is 3 hops -- 6 forwarded transmissions total.
+        let topology = linear_topology(4);
+        assert_eq!(count_relay_routed(&topology, &[0]), 6);
+    }
+
+    #[test]
+    fn test_relay_routing_costs_far_fewer_transmissions_than_broadcast() {
+        let topology = linear_topology(10);
+        let accounting = compare_messaging_strategies(&topology, &[0]);
+        assert!(accounting.relayed_transmissions < accounting.naive_transmissions);
+    }
+
+    #[test]
+    fn test_mean_hops_to_nearest_coordinator() {
+        let topology = linear_topology(4);
+        // Agents 1, 2, 3 are 1, 2, 3 hops from coordinator 0 -- mean 2.0.
+        assert_eq!(
+            mean_hops_to_nearest_coordinator(&topology, &[0]),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn test_mean_hops_is_none_when_no_coordinator_reachable() {
+        let topology = NetworkTopology::new();
+        assert_eq!(mean_hops_to_nearest_coordinator(&topology, &[0]), None);
+    }
+}
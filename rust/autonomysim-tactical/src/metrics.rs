@@ -6,9 +6,21 @@
 //! - Packet Error Rate (PER) calculation
 //! - Link budget analysis
 
+use rand::rngs::StdRng;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
+/// Quadrature points used to numerically average instantaneous BER over a
+/// fading SINR distribution; 64 gives ~1e-3 accuracy against the closed-form
+/// Rayleigh/BPSK case and is cheap enough to call every frame.
+const FADING_AVERAGING_POINTS: usize = 64;
+
+/// Oscillators in the Jakes sum-of-sinusoids envelope generator; 8 is the
+/// classic choice that reproduces the Rayleigh autocorrelation well without
+/// needing the full Jakes construction's harmonic cancellation fixes.
+const JAKES_OSCILLATORS: usize = 8;
+
 /// Signal power metrics
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct SignalMetrics {
@@ -55,6 +67,209 @@ impl SignalMetrics {
     pub fn is_detectable(&self, min_snr_db: f64) -> bool {
         self.snr_db >= min_snr_db
     }
+
+    /// Combine per-branch metrics from a multi-antenna receiver into a
+    /// single [`CombinedSignal`] under the given [`DiversityScheme`].
+    pub fn combine(branches: &[SignalMetrics], scheme: DiversityScheme) -> CombinedSignal {
+        let diversity_order = branches.len();
+        let branch_snrs_linear: Vec<f64> =
+            branches.iter().map(|b| db_to_linear(b.snr_db)).collect();
+        let mean_branch_snr_linear =
+            branch_snrs_linear.iter().sum::<f64>() / diversity_order.max(1) as f64;
+
+        let combined_snr_db = match scheme {
+            DiversityScheme::SelectionCombining => branches
+                .iter()
+                .map(|b| b.snr_db)
+                .fold(f64::NEG_INFINITY, f64::max),
+            DiversityScheme::MaximalRatioCombining => linear_to_db(branch_snrs_linear.iter().sum()),
+        };
+
+        CombinedSignal {
+            combined_snr_db,
+            diversity_order,
+            array_gain_db: combined_snr_db - linear_to_db(mean_branch_snr_linear),
+        }
+    }
+}
+
+/// Antenna-diversity combining strategy for [`SignalMetrics::combine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiversityScheme {
+    /// Take the single best instantaneous-SNR branch.
+    SelectionCombining,
+    /// Sum per-branch linear SNRs (optimal combining under independent
+    /// noise, requires per-branch SNR-weighted co-phasing).
+    MaximalRatioCombining,
+}
+
+/// Result of combining multiple antenna branches via [`SignalMetrics::combine`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CombinedSignal {
+    /// Combined SNR (dB) after applying the diversity scheme.
+    pub combined_snr_db: f64,
+    /// Number of branches combined.
+    pub diversity_order: usize,
+    /// Array gain (dB): combined SNR over the mean per-branch SNR.
+    pub array_gain_db: f64,
+}
+
+/// Small-scale multipath fading channel layered on top of the static AWGN
+/// model above: a Rayleigh envelope (or Rician, with `rician_k` set, for a
+/// dominant line-of-sight path) modulated by a given Doppler spread. HF/VHF
+/// tactical links live here far more than in the flat-AWGN world
+/// [`SignalMetrics`] alone describes, and the BER penalty is substantial --
+/// [`Self::average_ber`] quantifies it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FadingChannel {
+    /// Maximum Doppler spread (Hz): `f_d = v_rel * f_carrier / c`.
+    pub doppler_spread_hz: f64,
+    /// Rician K-factor (linear ratio of line-of-sight to scattered power).
+    /// `None` selects pure Rayleigh fading (no LOS component).
+    pub rician_k: Option<f64>,
+}
+
+impl FadingChannel {
+    /// Pure Rayleigh fading channel with the given Doppler spread.
+    pub fn new(doppler_spread_hz: f64) -> Self {
+        Self {
+            doppler_spread_hz,
+            rician_k: None,
+        }
+    }
+
+    /// Add a Rician line-of-sight component with K-factor `k` (linear).
+    pub fn with_rician_k(mut self, k: f64) -> Self {
+        self.rician_k = Some(k);
+        self
+    }
+
+    /// Average BER over the fading distribution for mean `mean_eb_n0_db`.
+    ///
+    /// Rayleigh + BPSK/QPSK has the closed form
+    /// `Pb = 0.5*(1 - sqrt(γ̄/(1+γ̄)))`; every other combination is averaged
+    /// numerically over [`FADING_AVERAGING_POINTS`] quantiles of the
+    /// instantaneous-SINR distribution, which keeps the result deterministic
+    /// (no RNG dependency) while still integrating the true BER curve
+    /// rather than evaluating it at the mean.
+    pub fn average_ber(&self, mean_eb_n0_db: f64, modulation: ModulationScheme) -> f64 {
+        let mean_eb_n0 = db_to_linear(mean_eb_n0_db);
+
+        if self.rician_k.is_none()
+            && matches!(modulation, ModulationScheme::BPSK | ModulationScheme::QPSK)
+        {
+            let mu = (mean_eb_n0 / (1.0 + mean_eb_n0)).sqrt();
+            return 0.5 * (1.0 - mu);
+        }
+
+        self.quantiles(mean_eb_n0)
+            .into_iter()
+            .map(|eb_n0| modulation.compute_ber(linear_to_db(eb_n0)))
+            .sum::<f64>()
+            / FADING_AVERAGING_POINTS as f64
+    }
+
+    /// Instantaneous-SINR quantiles (linear) of the fading distribution with
+    /// mean `mean_eb_n0`, used to numerically integrate BER over the fade.
+    ///
+    /// For Rayleigh, `γ` is exponential with mean `γ̄`, so the quantile
+    /// function is `γ̄ * -ln(1-u)`. A true noncentral-chi-square inversion
+    /// is needed for an exact Rician quantile; absent that we approximate
+    /// it by scaling the exponential quantile down by `1/(1+K)` (the
+    /// fraction of power left in the scattered component), which recovers
+    /// the Rayleigh case at `K=0` and collapses toward the static AWGN mean
+    /// as `K` grows -- adequate for the averaging this function exists to
+    /// support, though not an exact Rician BER integral.
+    fn quantiles(&self, mean_eb_n0: f64) -> Vec<f64> {
+        let scatter_mean = match self.rician_k {
+            Some(k) => mean_eb_n0 / (1.0 + k),
+            None => mean_eb_n0,
+        };
+        let los_floor = mean_eb_n0 - scatter_mean;
+
+        (0..FADING_AVERAGING_POINTS)
+            .map(|i| {
+                let u = (i as f64 + 0.5) / FADING_AVERAGING_POINTS as f64;
+                los_floor + scatter_mean * -(1.0 - u).ln()
+            })
+            .collect()
+    }
+
+    /// Average BER after maximal-ratio-combining `l` independent Rayleigh
+    /// branches, each with mean `mean_eb_n0_db`. BPSK/QPSK use the closed
+    /// form `Pb = [0.5(1-μ)]^L · Σ_{k=0}^{L-1} C(L-1+k,k)·[0.5(1+μ)]^k` with
+    /// `μ = sqrt(γ̄/(1+γ̄))`; other modulations average numerically over the
+    /// combined SNR's Erlang(L, γ̄) distribution (the exact law of a sum of
+    /// `L` iid exponential branch SNRs), sampled at the same
+    /// [`FADING_AVERAGING_POINTS`] quantiles [`Self::quantiles`] uses for
+    /// the single-branch case.
+    pub fn average_ber_mrc(
+        &self,
+        mean_eb_n0_db: f64,
+        l: usize,
+        modulation: ModulationScheme,
+    ) -> f64 {
+        let mean_eb_n0 = db_to_linear(mean_eb_n0_db);
+        let l = l.max(1);
+
+        if matches!(modulation, ModulationScheme::BPSK | ModulationScheme::QPSK) {
+            let mu = (mean_eb_n0 / (1.0 + mean_eb_n0)).sqrt();
+            let a = 0.5 * (1.0 - mu);
+            let b = 0.5 * (1.0 + mu);
+            let sum: f64 = (0..l)
+                .map(|k| binomial_coeff(l - 1 + k, k) * b.powi(k as i32))
+                .sum();
+            return a.powi(l as i32) * sum;
+        }
+
+        (0..FADING_AVERAGING_POINTS)
+            .map(|i| {
+                let u = (i as f64 + 0.5) / FADING_AVERAGING_POINTS as f64;
+                let combined_eb_n0 = erlang_quantile(u, l, mean_eb_n0);
+                modulation.compute_ber(linear_to_db(combined_eb_n0))
+            })
+            .sum::<f64>()
+            / FADING_AVERAGING_POINTS as f64
+    }
+
+    /// Correlated fading-envelope time series via the Jakes sum-of-sinusoids
+    /// model: `n` samples at sample rate `fs` Hz, normalized so the mean
+    /// envelope power is unity. `rng` seeds only the oscillator phase
+    /// offsets (the Doppler frequencies themselves are the standard
+    /// deterministic Jakes set), so repeated calls with the same seed
+    /// reproduce the same realization.
+    pub fn sample_envelope(&self, n: usize, fs: f64, rng: &mut StdRng) -> Vec<f64> {
+        let n0 = JAKES_OSCILLATORS as f64;
+        let denom = 4.0 * n0 + 2.0;
+
+        let oscillators: Vec<(f64, f64, f64)> = (1..=JAKES_OSCILLATORS)
+            .map(|k| {
+                let k = k as f64;
+                let doppler_hz = self.doppler_spread_hz * (2.0 * PI * k / denom).cos();
+                let theta = rng.gen_range(0.0..2.0 * PI);
+                let phi = rng.gen_range(0.0..2.0 * PI);
+                (doppler_hz, theta, phi)
+            })
+            .collect();
+
+        (0..n)
+            .map(|sample| {
+                let t = sample as f64 / fs;
+                let scale = (2.0 / n0).sqrt();
+                let i_t: f64 = oscillators
+                    .iter()
+                    .map(|&(f, theta, _)| theta.cos() * (2.0 * PI * f * t).cos())
+                    .sum::<f64>()
+                    * scale;
+                let q_t: f64 = oscillators
+                    .iter()
+                    .map(|&(f, _, phi)| phi.sin() * (2.0 * PI * f * t).cos())
+                    .sum::<f64>()
+                    * scale;
+                (i_t * i_t + q_t * q_t).sqrt() / std::f64::consts::SQRT_2
+            })
+            .collect()
+    }
 }
 
 /// Bit Error Rate calculator for various modulation schemes
@@ -64,21 +279,9 @@ impl BERCalculator {
     /// Compute BER for BPSK modulation
     ///
     /// BER = 0.5 * erfc(sqrt(Eb/N0))
-    ///
-    /// Using approximation: erfc(x) ≈ exp(-x²) for x > 0
     pub fn bpsk(eb_n0_db: f64) -> f64 {
         let eb_n0 = db_to_linear(eb_n0_db);
-
-        if eb_n0 > 20.0 {
-            // Very high SNR, use asymptotic approximation
-            1e-10
-        } else if eb_n0 < 0.01 {
-            // Very low SNR
-            0.5
-        } else {
-            // Approximation: BER ≈ 0.5 * exp(-Eb/N0)
-            0.5 * (-eb_n0).exp()
-        }
+        0.5 * erfc(eb_n0.sqrt())
     }
 
     /// Compute BER for QPSK modulation
@@ -90,34 +293,42 @@ impl BERCalculator {
 
     /// Compute BER for 16-QAM modulation
     ///
-    /// BER ≈ (3/8) * erfc(sqrt(Eb/N0 / 5))
+    /// BER ≈ 0.75 * erfc(sqrt(0.4 * Eb/N0)), the general M-QAM bound below
+    /// evaluated at M = 16.
     pub fn qam16(eb_n0_db: f64) -> f64 {
-        let eb_n0 = db_to_linear(eb_n0_db);
-
-        if eb_n0 > 20.0 {
-            1e-9
-        } else if eb_n0 < 0.1 {
-            0.4
-        } else {
-            // Approximation
-            0.375 * (-(eb_n0 / 5.0)).exp()
-        }
+        qam_ber(eb_n0_db, 16)
     }
 
     /// Compute BER for 64-QAM modulation
     ///
-    /// BER ≈ (7/24) * erfc(sqrt(Eb/N0 / 21))
+    /// Evaluates the general M-QAM bound below at M = 64.
     pub fn qam64(eb_n0_db: f64) -> f64 {
+        qam_ber(eb_n0_db, 64)
+    }
+
+    /// Compute BER for non-coherent orthogonal 2-FSK/GFSK demodulation
+    ///
+    /// BER = 0.5 * exp(-0.5 * Eb/N0)
+    ///
+    /// This is the dominant demodulator for cheap sub-GHz tactical
+    /// transceivers, which typically discriminate frequency rather than
+    /// track carrier phase.
+    pub fn fsk_noncoherent(eb_n0_db: f64) -> f64 {
         let eb_n0 = db_to_linear(eb_n0_db);
+        0.5 * (-0.5 * eb_n0).exp()
+    }
 
-        if eb_n0 > 25.0 {
-            1e-8
-        } else if eb_n0 < 1.0 {
-            0.35
-        } else {
-            // Approximation
-            0.29 * (-(eb_n0 / 21.0)).exp()
-        }
+    /// Compute BER for coherent orthogonal 2-FSK/GFSK demodulation
+    ///
+    /// BER = 0.5 * erfc(sqrt(0.5 * Eb/N0))
+    ///
+    /// Offered as the lower-BER alternative for radios that do carrier
+    /// recovery; [`ModulationScheme::compute_ber`] uses the non-coherent
+    /// formula above since that's what the FSK/GFSK hardware this crate
+    /// targets actually implements.
+    pub fn fsk_coherent(eb_n0_db: f64) -> f64 {
+        let eb_n0 = db_to_linear(eb_n0_db);
+        0.5 * erfc((0.5 * eb_n0).sqrt())
     }
 
     /// Convert SNR to Eb/N0
@@ -129,6 +340,35 @@ impl BERCalculator {
     }
 }
 
+/// General square-M-QAM bit-error bound:
+///
+/// Pb ≈ (4/k)(1 - 1/sqrt(M)) * 0.5 * erfc(sqrt(1.5*k/(M-1) * Eb/N0))
+///
+/// with k = log2(M). Reduces to the textbook 0.75*erfc(sqrt(0.4*Eb/N0)) at
+/// M = 16.
+fn qam_ber(eb_n0_db: f64, m: u32) -> f64 {
+    let eb_n0 = db_to_linear(eb_n0_db);
+    let k = (m as f64).log2();
+    let sqrt_m = (m as f64).sqrt();
+    (4.0 / k) * (1.0 - 1.0 / sqrt_m) * 0.5 * erfc((1.5 * k / (m as f64 - 1.0) * eb_n0).sqrt())
+}
+
+/// Complementary error function for x >= 0, via the Abramowitz–Stegun
+/// 7.1.26 rational approximation (accuracy ~1.2e-7). Replaces the
+/// clamp-and-exp shortcuts the BER formulas above used to lean on.
+pub fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.5 * x);
+    t * (-x * x - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+        .exp()
+}
+
 /// Modulation scheme
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ModulationScheme {
@@ -136,6 +376,12 @@ pub enum ModulationScheme {
     QPSK,
     QAM16,
     QAM64,
+    /// Orthogonal 2-FSK, non-coherently demodulated.
+    Fsk2,
+    /// Gaussian-filtered 2-FSK, non-coherently demodulated -- the Gaussian
+    /// pre-filter tightens occupied bandwidth but doesn't change the
+    /// orthogonal-2-FSK BER curve this crate models.
+    Gfsk,
 }
 
 impl ModulationScheme {
@@ -146,6 +392,7 @@ impl ModulationScheme {
             Self::QPSK => 2,
             Self::QAM16 => 4,
             Self::QAM64 => 6,
+            Self::Fsk2 | Self::Gfsk => 1,
         }
     }
 
@@ -156,10 +403,172 @@ impl ModulationScheme {
             Self::QPSK => BERCalculator::qpsk(eb_n0_db),
             Self::QAM16 => BERCalculator::qam16(eb_n0_db),
             Self::QAM64 => BERCalculator::qam64(eb_n0_db),
+            Self::Fsk2 | Self::Gfsk => BERCalculator::fsk_noncoherent(eb_n0_db),
+        }
+    }
+}
+
+/// Standard double-sideband receiver bandwidths used by sub-GHz tactical
+/// transceivers, so [`LinkBudget::bandwidth_hz`] and
+/// [`BERCalculator::snr_to_eb_n0`] can be fed a realistic, hardware-matching
+/// noise bandwidth instead of an arbitrary value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChannelBandwidth {
+    Khz4_8,
+    Khz5_8,
+    Khz7_3,
+    Khz9_7,
+    Khz11_7,
+    Khz14_6,
+    Khz19_5,
+    Khz23_4,
+    Khz29_3,
+    Khz39_0,
+    Khz46_9,
+    Khz58_6,
+    Khz78_2,
+    Khz93_8,
+    Khz117_3,
+    Khz156_2,
+    Khz187_2,
+    Khz234_3,
+    Khz312_0,
+    Khz373_6,
+    Khz467_0,
+}
+
+impl ChannelBandwidth {
+    /// This bandwidth in Hz.
+    pub fn to_hz(&self) -> f64 {
+        let khz = match self {
+            Self::Khz4_8 => 4.8,
+            Self::Khz5_8 => 5.8,
+            Self::Khz7_3 => 7.3,
+            Self::Khz9_7 => 9.7,
+            Self::Khz11_7 => 11.7,
+            Self::Khz14_6 => 14.6,
+            Self::Khz19_5 => 19.5,
+            Self::Khz23_4 => 23.4,
+            Self::Khz29_3 => 29.3,
+            Self::Khz39_0 => 39.0,
+            Self::Khz46_9 => 46.9,
+            Self::Khz58_6 => 58.6,
+            Self::Khz78_2 => 78.2,
+            Self::Khz93_8 => 93.8,
+            Self::Khz117_3 => 117.3,
+            Self::Khz156_2 => 156.2,
+            Self::Khz187_2 => 187.2,
+            Self::Khz234_3 => 234.3,
+            Self::Khz312_0 => 312.0,
+            Self::Khz373_6 => 373.6,
+            Self::Khz467_0 => 467.0,
+        };
+        khz * 1e3
+    }
+}
+
+/// Forward error correction applied on top of the raw (uncoded) modulation
+/// BER. `None` is a pass-through; the other variants each model how the
+/// decoder turns raw channel errors into a (much lower) post-decode bit
+/// error rate.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Code {
+    /// No coding -- post-decode BER equals the raw channel BER.
+    None,
+    /// Convolutional code decoded with a Viterbi (soft-decision) decoder.
+    Convolutional { rate: f64, constraint_length: u32 },
+    /// Bounded-distance block code (e.g. BCH) correcting up to `t` errors
+    /// per `n`-bit codeword carrying `k` data bits.
+    BlockBch { n: usize, k: usize, t: usize },
+    /// LDPC code with an empirically characterized waterfall threshold.
+    Ldpc { rate: f64, threshold_gain_db: f64 },
+}
+
+impl Code {
+    /// Code rate (data bits per channel bit); coded links need
+    /// `bit_rate / rate` channel bits to carry `bit_rate` data bits.
+    pub fn rate(&self) -> f64 {
+        match self {
+            Self::None => 1.0,
+            Self::Convolutional { rate, .. } => *rate,
+            Self::BlockBch { n, k, .. } => *k as f64 / *n as f64,
+            Self::Ldpc { rate, .. } => *rate,
+        }
+    }
+
+    /// Post-decode BER for `modulation` operating at `eb_n0_db` under this
+    /// code.
+    ///
+    /// The bounded-distance block code has an exact bit-counting formula
+    /// driven purely by the raw channel BER, so it needs no Eb/N0 shift.
+    /// Convolutional/LDPC decoders instead buy their gain by moving the
+    /// *operating point* on the uncoded BER curve -- the request's
+    /// `coded_ber(raw_ber, code)` can't express that shift from a bare BER
+    /// value alone, so this takes `eb_n0_db`/`modulation` directly and
+    /// recomputes the raw BER internally wherever a shift is needed.
+    pub fn coded_ber(&self, eb_n0_db: f64, modulation: ModulationScheme) -> f64 {
+        match self {
+            Self::None => modulation.compute_ber(eb_n0_db),
+            Self::BlockBch { n, t, .. } => {
+                let raw_ber = modulation.compute_ber(eb_n0_db);
+                block_code_ber(raw_ber, *n, *t)
+            }
+            Self::Convolutional { .. } | Self::Ldpc { .. } => {
+                let shifted_ber = modulation.compute_ber(eb_n0_db + self.coding_gain_db());
+                shifted_ber.powf(self.waterfall_steepening())
+            }
+        }
+    }
+
+    /// Approximate coding gain (dB): how far left the code shifts the
+    /// operating point on the uncoded BER curve. Convolutional codes use
+    /// the common rule-of-thumb that free distance scales with
+    /// `constraint_length + 1`; LDPC uses its configured empirical
+    /// threshold directly.
+    fn coding_gain_db(&self) -> f64 {
+        match self {
+            Self::None | Self::BlockBch { .. } => 0.0,
+            Self::Convolutional {
+                rate,
+                constraint_length,
+            } => 10.0 * (rate * (*constraint_length as f64 + 1.0)).log10(),
+            Self::Ldpc {
+                threshold_gain_db, ..
+            } => *threshold_gain_db,
+        }
+    }
+
+    /// Waterfall steepening exponent applied to the shifted BER, modeling
+    /// how much more sharply a soft-decision/iterative decoder's BER falls
+    /// once past its threshold versus the uncoded curve.
+    fn waterfall_steepening(&self) -> f64 {
+        match self {
+            Self::Convolutional { .. } => 1.5,
+            Self::Ldpc { .. } => 2.0,
+            Self::None | Self::BlockBch { .. } => 1.0,
         }
     }
 }
 
+/// Post-decode bit error probability for a bounded-distance `(n, t)` block
+/// code given the raw channel BER `p`:
+/// `Pb ≈ (1/n) Σ_{i=t+1}^{n} i·C(n,i)·p^i·(1-p)^(n-i)`.
+fn block_code_ber(p: f64, n: usize, t: usize) -> f64 {
+    if p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+
+    let sum: f64 = ((t + 1)..=n)
+        .map(|i| {
+            i as f64 * binomial_coeff(n, i) * p.powi(i as i32) * (1.0 - p).powi((n - i) as i32)
+        })
+        .sum();
+    (sum / n as f64).min(1.0)
+}
+
 /// Packet error rate calculator
 pub struct PERCalculator;
 
@@ -199,6 +608,248 @@ impl PERCalculator {
         let ber = modulation.compute_ber(eb_n0_db);
         Self::from_ber(ber, packet_length_bits)
     }
+
+    /// Compute PER from a raw (pre-despreading) SINR, folding in DSSS
+    /// processing gain before the BER lookup. `spreading` is `None` for an
+    /// unspread (direct) link, in which case this is identical to
+    /// [`Self::from_sinr`].
+    pub fn from_sinr_spread(
+        raw_sinr_db: f64,
+        modulation: ModulationScheme,
+        bandwidth_hz: f64,
+        bit_rate_bps: f64,
+        packet_length_bits: usize,
+        spreading: Option<&SpreadingConfig>,
+    ) -> f64 {
+        let sinr_db = match spreading {
+            Some(config) => config.despread_sinr_db(raw_sinr_db),
+            None => raw_sinr_db,
+        };
+        Self::from_sinr(
+            sinr_db,
+            modulation,
+            bandwidth_hz,
+            bit_rate_bps,
+            packet_length_bits,
+        )
+    }
+
+    /// Compute PER from SINR for a coded link: `code` is applied before
+    /// [`Self::from_ber`], and the effective bit rate is scaled up by
+    /// `1 / code.rate()` since a coded link spends extra channel bits on
+    /// redundancy to carry the same `bit_rate_bps` of data.
+    pub fn from_sinr_coded(
+        sinr_db: f64,
+        modulation: ModulationScheme,
+        bandwidth_hz: f64,
+        bit_rate_bps: f64,
+        packet_length_bits: usize,
+        code: Code,
+    ) -> f64 {
+        let channel_bit_rate_bps = bit_rate_bps / code.rate();
+        let eb_n0_db = BERCalculator::snr_to_eb_n0(sinr_db, bandwidth_hz, channel_bit_rate_bps);
+        let ber = code.coded_ber(eb_n0_db, modulation);
+        Self::from_ber(ber, packet_length_bits)
+    }
+}
+
+/// Candidate modulations the AMC selector below searches over, in no
+/// particular order -- [`select_amc_mode`] ranks candidates by throughput
+/// itself rather than relying on declaration order.
+const AMC_MODULATIONS: [ModulationScheme; 6] = [
+    ModulationScheme::BPSK,
+    ModulationScheme::QPSK,
+    ModulationScheme::Fsk2,
+    ModulationScheme::Gfsk,
+    ModulationScheme::QAM16,
+    ModulationScheme::QAM64,
+];
+
+/// Result of [`select_amc_mode`]: the modulation/code pair that maximizes
+/// throughput while keeping predicted PER at or below the caller's target.
+#[derive(Debug, Clone, Copy)]
+pub struct AmcSelection {
+    /// Chosen modulation.
+    pub modulation: ModulationScheme,
+    /// Chosen FEC code.
+    pub code: Code,
+    /// Predicted PER at the chosen mode, at the measured SINR.
+    pub predicted_per: f64,
+    /// Predicted net data throughput (bits/s) of the chosen mode.
+    pub throughput_bps: f64,
+    /// SINR (dB) still needed to safely step up to the next-higher-throughput
+    /// mode that was rejected for exceeding `target_per`; `None` if the
+    /// chosen mode is already the highest-throughput candidate available.
+    pub margin_to_next_mode_db: Option<f64>,
+}
+
+/// Adaptive modulation-and-coding selection: given a measured `sinr_db`,
+/// `bandwidth_hz`, `symbol_rate_hz`, packet size, and `target_per`, search
+/// every modulation in [`AMC_MODULATIONS`] crossed with every code in
+/// `codes` and return the combination that maximizes net throughput
+/// (`bits_per_symbol * code_rate * symbol_rate`) while keeping
+/// [`PERCalculator::from_sinr_coded`] at or below `target_per`. `codes`
+/// should include [`Code::None`] if an uncoded fallback should be
+/// considered. Returns `None` if no candidate meets `target_per` -- the
+/// link can't be closed at any available mode.
+///
+/// This turns the per-modulation BER/PER primitives above into an
+/// actionable link-adaptation API: a radio controller can call this every
+/// frame to drop to BPSK under a deep fade and climb back to 64-QAM once
+/// SINR recovers.
+pub fn select_amc_mode(
+    sinr_db: f64,
+    bandwidth_hz: f64,
+    symbol_rate_hz: f64,
+    packet_length_bits: usize,
+    target_per: f64,
+    codes: &[Code],
+) -> Option<AmcSelection> {
+    let mut candidates: Vec<(ModulationScheme, Code, f64, f64)> = AMC_MODULATIONS
+        .iter()
+        .flat_map(|&modulation| codes.iter().map(move |&code| (modulation, code)))
+        .map(|(modulation, code)| {
+            let throughput_bps = amc_throughput_bps(modulation, code, symbol_rate_hz);
+            let per = PERCalculator::from_sinr_coded(
+                sinr_db,
+                modulation,
+                bandwidth_hz,
+                throughput_bps,
+                packet_length_bits,
+                code,
+            );
+            (modulation, code, per, throughput_bps)
+        })
+        .collect();
+
+    // Descending by throughput so index 0 is the fastest candidate and the
+    // first one meeting `target_per` is the best the link can sustain.
+    candidates.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap());
+
+    let winner_idx = candidates.iter().position(|c| c.2 <= target_per)?;
+    let (modulation, code, predicted_per, throughput_bps) = candidates[winner_idx];
+
+    let margin_to_next_mode_db = if winner_idx == 0 {
+        None
+    } else {
+        let (next_modulation, next_code, ..) = candidates[winner_idx - 1];
+        Some(
+            required_sinr_db_for_target_per(
+                next_modulation,
+                next_code,
+                bandwidth_hz,
+                symbol_rate_hz,
+                packet_length_bits,
+                target_per,
+            ) - sinr_db,
+        )
+    };
+
+    Some(AmcSelection {
+        modulation,
+        code,
+        predicted_per,
+        throughput_bps,
+        margin_to_next_mode_db,
+    })
+}
+
+/// Net data throughput (bits/s) of `modulation` coded with `code` at
+/// `symbol_rate_hz`: `bits_per_symbol * code_rate * symbol_rate`.
+fn amc_throughput_bps(modulation: ModulationScheme, code: Code, symbol_rate_hz: f64) -> f64 {
+    modulation.bits_per_symbol() as f64 * code.rate() * symbol_rate_hz
+}
+
+/// SINR (dB) at which `modulation`/`code` would just meet `target_per`, via
+/// bisection -- PER falls monotonically with SINR, so this always converges.
+fn required_sinr_db_for_target_per(
+    modulation: ModulationScheme,
+    code: Code,
+    bandwidth_hz: f64,
+    symbol_rate_hz: f64,
+    packet_length_bits: usize,
+    target_per: f64,
+) -> f64 {
+    let bit_rate_bps = amc_throughput_bps(modulation, code, symbol_rate_hz);
+    let mut lo = -40.0;
+    let mut hi = 60.0;
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        let per = PERCalculator::from_sinr_coded(
+            mid,
+            modulation,
+            bandwidth_hz,
+            bit_rate_bps,
+            packet_length_bits,
+            code,
+        );
+        if per > target_per {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Direct-sequence spread-spectrum configuration: each symbol is spread
+/// across `n_chip` chips at `chip_rate_hz`, trading `bandwidth_hz` for the
+/// processing gain [`Self::processing_gain_db`] recovers at the despreader.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SpreadingConfig {
+    /// Chip rate (Hz) -- the spread channel's occupied bandwidth is on this
+    /// order, so raising `n_chip` at a fixed `symbol_rate_hz` costs
+    /// proportionally more RF bandwidth.
+    pub chip_rate_hz: f64,
+    /// Symbol rate (Hz) before spreading.
+    pub symbol_rate_hz: f64,
+    /// Chips per symbol.
+    pub n_chip: u32,
+}
+
+impl SpreadingConfig {
+    pub fn new(chip_rate_hz: f64, symbol_rate_hz: f64, n_chip: u32) -> Self {
+        Self {
+            chip_rate_hz,
+            symbol_rate_hz,
+            n_chip,
+        }
+    }
+
+    /// Processing gain (dB): `Gp = 10*log10(chip_rate / symbol_rate)`.
+    pub fn processing_gain_db(&self) -> f64 {
+        10.0 * (self.chip_rate_hz / self.symbol_rate_hz).log10()
+    }
+
+    /// Effective SINR (dB) after despreading: the correlator recovers the
+    /// full processing gain as an SNR improvement, since the despreading
+    /// correlation is coherent for the desired signal but not for
+    /// wideband interference/jamming.
+    pub fn despread_sinr_db(&self, raw_sinr_db: f64) -> f64 {
+        raw_sinr_db + self.processing_gain_db()
+    }
+
+    /// Effective fading-variance reduction this spreading configuration
+    /// buys when layered on a [`FadingChannel`]: repeating a symbol across
+    /// `n_chip` chips that (ideally) fade independently converts a
+    /// single-branch Rayleigh channel into something with diversity order
+    /// `n_chip`, the same variance-reduction mechanism [`FadingChannel`]'s
+    /// Rician path models -- so we reuse it by scaling the scattered-power
+    /// mean down by `1/n_chip` before averaging BER.
+    pub fn average_ber_with_fading(
+        &self,
+        fading: &FadingChannel,
+        mean_eb_n0_db: f64,
+        modulation: ModulationScheme,
+    ) -> f64 {
+        let scattered_k = fading.rician_k.unwrap_or(0.0);
+        let spread_k = self.n_chip as f64 * (1.0 + scattered_k) - 1.0;
+        FadingChannel {
+            rician_k: Some(spread_k),
+            ..*fading
+        }
+        .average_ber(mean_eb_n0_db, modulation)
+    }
 }
 
 /// Link budget calculator
@@ -260,6 +911,16 @@ impl LinkBudget {
         self.received_power_dbm() - self.noise_power_dbm()
     }
 
+    /// Compute SNR (dB) with DSSS processing gain folded in, for a link that
+    /// spreads its symbols per `spreading`. `None` is identical to
+    /// [`Self::snr_db`].
+    pub fn snr_db_with_spreading(&self, spreading: Option<&SpreadingConfig>) -> f64 {
+        match spreading {
+            Some(config) => config.despread_sinr_db(self.snr_db()),
+            None => self.snr_db(),
+        }
+    }
+
     /// Compute link margin (dB)
     ///
     /// Margin = SNR - Required_SNR
@@ -273,6 +934,47 @@ impl LinkBudget {
     }
 }
 
+/// Binomial coefficient C(n, k), used by [`FadingChannel::average_ber_mrc`]'s
+/// closed-form MRC BER sum.
+fn binomial_coeff(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// CDF of an Erlang(`shape`, `scale`) distribution -- the exact law of a sum
+/// of `shape` iid Exp(`scale`) random variables, i.e. the combined SNR under
+/// MRC of `shape` iid Rayleigh branches each with mean `scale`.
+fn erlang_cdf(x: f64, shape: usize, scale: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let z = x / scale;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..shape {
+        term *= z / k as f64;
+        sum += term;
+    }
+    1.0 - (-z).exp() * sum
+}
+
+/// Inverse CDF of an Erlang(`shape`, `scale`) distribution via bisection --
+/// the closed-form CDF above has no closed-form inverse, but it is
+/// monotonic, so bisection converges quickly to machine precision.
+fn erlang_quantile(u: f64, shape: usize, scale: f64) -> f64 {
+    let mut lo = 0.0;
+    let mut hi = scale * (shape as f64 + 10.0 * (shape as f64).sqrt() + 10.0);
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        if erlang_cdf(mid, shape, scale) < u {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
 /// Utility functions
 #[inline]
 pub fn dbm_to_watts(dbm: f64) -> f64 {
@@ -298,6 +1000,7 @@ pub fn linear_to_db(linear: f64) -> f64 {
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
+    use rand::SeedableRng;
 
     #[test]
     fn test_signal_metrics() {
@@ -318,6 +1021,48 @@ mod tests {
         assert!(ber_low > 0.4);
     }
 
+    #[test]
+    fn test_erfc_matches_textbook_values() {
+        assert_abs_diff_eq!(erfc(0.0), 1.0, epsilon = 1e-7);
+        assert_abs_diff_eq!(erfc(1.0), 0.157_299_207, epsilon = 1e-6);
+        assert_abs_diff_eq!(erfc(2.0), 0.004_677_734, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_ber_bpsk_matches_exact_formula_at_0db() {
+        // Eb/N0 = 0 dB -> linear 1.0 -> Pb = 0.5 * erfc(1.0) ~= 0.0786496.
+        assert_abs_diff_eq!(BERCalculator::bpsk(0.0), 0.078_649_6, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_ber_qam16_matches_exact_formula_at_10db() {
+        // Eb/N0 = 10 dB -> linear 10.0 -> Pb = 0.75 * erfc(sqrt(4.0)).
+        let expected = 0.75 * erfc(4.0_f64.sqrt());
+        assert_abs_diff_eq!(BERCalculator::qam16(10.0), expected, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_ber_qam64_matches_general_mqam_bound_at_15db() {
+        let eb_n0 = db_to_linear(15.0);
+        let expected =
+            (4.0 / 6.0) * (1.0 - 1.0 / 8.0) * 0.5 * erfc((1.5 * 6.0 / 63.0 * eb_n0).sqrt());
+        assert_abs_diff_eq!(BERCalculator::qam64(15.0), expected, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_ber_decreases_monotonically_with_eb_n0() {
+        for modulation in [
+            ModulationScheme::BPSK,
+            ModulationScheme::QPSK,
+            ModulationScheme::QAM16,
+            ModulationScheme::QAM64,
+        ] {
+            let low = modulation.compute_ber(-5.0);
+            let high = modulation.compute_ber(20.0);
+            assert!(high < low);
+        }
+    }
+
     #[test]
     fn test_per_calculation() {
         // With BER = 0.001 and 1000-bit packet
@@ -356,11 +1101,326 @@ mod tests {
         assert_abs_diff_eq!(power_w, 1.0, epsilon = 0.01); // 30 dBm = 1W
     }
 
+    #[test]
+    fn test_fading_average_ber_matches_closed_form_for_rayleigh_bpsk() {
+        let channel = FadingChannel::new(50.0);
+        let mean_eb_n0 = db_to_linear(10.0);
+        let mu = (mean_eb_n0 / (1.0 + mean_eb_n0)).sqrt();
+        let expected = 0.5 * (1.0 - mu);
+        assert_abs_diff_eq!(
+            channel.average_ber(10.0, ModulationScheme::BPSK),
+            expected,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_fading_ber_is_much_worse_than_awgn_at_same_mean_eb_n0() {
+        let channel = FadingChannel::new(50.0);
+        let faded = channel.average_ber(15.0, ModulationScheme::BPSK);
+        let awgn = BERCalculator::bpsk(15.0);
+        assert!(faded > awgn * 10.0);
+    }
+
+    #[test]
+    fn test_fading_qam_average_ber_decreases_with_mean_eb_n0() {
+        let channel = FadingChannel::new(50.0);
+        let low = channel.average_ber(0.0, ModulationScheme::QAM16);
+        let high = channel.average_ber(20.0, ModulationScheme::QAM16);
+        assert!(high < low);
+    }
+
+    #[test]
+    fn test_rician_fading_beats_rayleigh_at_same_mean_eb_n0() {
+        let rayleigh = FadingChannel::new(50.0);
+        let rician = FadingChannel::new(50.0).with_rician_k(10.0);
+        let rayleigh_ber = rayleigh.average_ber(10.0, ModulationScheme::QAM16);
+        let rician_ber = rician.average_ber(10.0, ModulationScheme::QAM16);
+        assert!(rician_ber < rayleigh_ber);
+    }
+
+    #[test]
+    fn test_sample_envelope_produces_nonnegative_nondegenerate_samples() {
+        let channel = FadingChannel::new(20.0);
+        let mut rng = StdRng::seed_from_u64(42);
+        let samples = channel.sample_envelope(1000, 1000.0, &mut rng);
+
+        assert_eq!(samples.len(), 1000);
+        assert!(samples.iter().all(|&s| s >= 0.0));
+
+        let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!(samples.iter().any(|&s| (s - mean).abs() > 1e-6));
+    }
+
+    #[test]
+    fn test_processing_gain_matches_chip_to_symbol_rate_ratio() {
+        // 10 Mchip/s over a 10 ksym/s link: Gp = 10*log10(1000) = 30 dB.
+        let spreading = SpreadingConfig::new(10e6, 10e3, 1000);
+        assert_abs_diff_eq!(spreading.processing_gain_db(), 30.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_despread_sinr_adds_processing_gain() {
+        let spreading = SpreadingConfig::new(1e6, 1e3, 1000);
+        let raw_sinr_db = -10.0;
+        assert_abs_diff_eq!(
+            spreading.despread_sinr_db(raw_sinr_db),
+            raw_sinr_db + spreading.processing_gain_db(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_from_sinr_spread_matches_from_sinr_without_spreading() {
+        let per_plain = PERCalculator::from_sinr(5.0, ModulationScheme::QPSK, 1e6, 1e5, 1000);
+        let per_spread =
+            PERCalculator::from_sinr_spread(5.0, ModulationScheme::QPSK, 1e6, 1e5, 1000, None);
+        assert_abs_diff_eq!(per_plain, per_spread, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_from_sinr_spread_improves_per_over_unspread() {
+        let spreading = SpreadingConfig::new(1e7, 1e4, 1000);
+        let per_unspread = PERCalculator::from_sinr(-5.0, ModulationScheme::BPSK, 1e4, 1e4, 1000);
+        let per_spread = PERCalculator::from_sinr_spread(
+            -5.0,
+            ModulationScheme::BPSK,
+            1e4,
+            1e4,
+            1000,
+            Some(&spreading),
+        );
+        assert!(per_spread < per_unspread);
+    }
+
+    #[test]
+    fn test_spreading_reduces_fading_penalty() {
+        let fading = FadingChannel::new(50.0);
+        let spreading = SpreadingConfig::new(1e6, 1e3, 8);
+        let unspread = fading.average_ber(10.0, ModulationScheme::BPSK);
+        let spread = spreading.average_ber_with_fading(&fading, 10.0, ModulationScheme::BPSK);
+        assert!(spread < unspread);
+    }
+
+    #[test]
+    fn test_selection_combining_takes_best_branch() {
+        let branches = [
+            SignalMetrics::new(-80.0, -100.0, -120.0),
+            SignalMetrics::new(-70.0, -100.0, -120.0),
+            SignalMetrics::new(-90.0, -100.0, -120.0),
+        ];
+        let combined = SignalMetrics::combine(&branches, DiversityScheme::SelectionCombining);
+        assert_abs_diff_eq!(combined.combined_snr_db, branches[1].snr_db, epsilon = 1e-9);
+        assert_eq!(combined.diversity_order, 3);
+    }
+
+    #[test]
+    fn test_mrc_beats_every_individual_branch() {
+        let branches = [
+            SignalMetrics::new(-80.0, -100.0, -120.0),
+            SignalMetrics::new(-82.0, -100.0, -120.0),
+        ];
+        let combined = SignalMetrics::combine(&branches, DiversityScheme::MaximalRatioCombining);
+        for branch in &branches {
+            assert!(combined.combined_snr_db > branch.snr_db);
+        }
+        assert!(combined.array_gain_db > 0.0);
+    }
+
+    #[test]
+    fn test_mrc_fading_ber_improves_with_more_branches() {
+        let fading = FadingChannel::new(50.0);
+        let single = fading.average_ber_mrc(10.0, 1, ModulationScheme::BPSK);
+        let dual = fading.average_ber_mrc(10.0, 2, ModulationScheme::BPSK);
+        let quad = fading.average_ber_mrc(10.0, 4, ModulationScheme::BPSK);
+        assert!(dual < single);
+        assert!(quad < dual);
+    }
+
+    #[test]
+    fn test_mrc_single_branch_matches_non_mrc_average_ber() {
+        let fading = FadingChannel::new(50.0);
+        let mrc = fading.average_ber_mrc(8.0, 1, ModulationScheme::BPSK);
+        let plain = fading.average_ber(8.0, ModulationScheme::BPSK);
+        assert_abs_diff_eq!(mrc, plain, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_mrc_qam_ber_improves_with_more_branches() {
+        let fading = FadingChannel::new(50.0);
+        let single = fading.average_ber_mrc(10.0, 1, ModulationScheme::QAM16);
+        let quad = fading.average_ber_mrc(10.0, 4, ModulationScheme::QAM16);
+        assert!(quad < single);
+    }
+
+    #[test]
+    fn test_uncoded_matches_plain_modulation_ber() {
+        let eb_n0_db = 5.0;
+        assert_abs_diff_eq!(
+            Code::None.coded_ber(eb_n0_db, ModulationScheme::BPSK),
+            ModulationScheme::BPSK.compute_ber(eb_n0_db),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_block_code_corrects_below_raw_ber() {
+        // (15, 11, t=1) Hamming-like BCH code: with a not-too-high raw BER,
+        // post-decode BER should drop well below the raw channel BER.
+        let code = Code::BlockBch { n: 15, k: 11, t: 1 };
+        let eb_n0_db = 4.0;
+        let raw_ber = ModulationScheme::BPSK.compute_ber(eb_n0_db);
+        let coded = code.coded_ber(eb_n0_db, ModulationScheme::BPSK);
+        assert!(coded < raw_ber);
+    }
+
+    #[test]
+    fn test_convolutional_code_reduces_ber_via_coding_gain() {
+        let code = Code::Convolutional {
+            rate: 0.5,
+            constraint_length: 7,
+        };
+        let eb_n0_db = 4.0;
+        let raw_ber = ModulationScheme::BPSK.compute_ber(eb_n0_db);
+        let coded = code.coded_ber(eb_n0_db, ModulationScheme::BPSK);
+        assert!(coded < raw_ber);
+    }
+
+    #[test]
+    fn test_ldpc_coding_gain_scales_with_threshold() {
+        let weak = Code::Ldpc {
+            rate: 0.75,
+            threshold_gain_db: 1.0,
+        };
+        let strong = Code::Ldpc {
+            rate: 0.75,
+            threshold_gain_db: 4.0,
+        };
+        let eb_n0_db = 2.0;
+        let weak_ber = weak.coded_ber(eb_n0_db, ModulationScheme::QPSK);
+        let strong_ber = strong.coded_ber(eb_n0_db, ModulationScheme::QPSK);
+        assert!(strong_ber < weak_ber);
+    }
+
+    #[test]
+    fn test_code_rate_matches_variant_definition() {
+        assert_abs_diff_eq!(Code::None.rate(), 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            Code::Convolutional {
+                rate: 0.5,
+                constraint_length: 7
+            }
+            .rate(),
+            0.5,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            Code::BlockBch { n: 15, k: 11, t: 1 }.rate(),
+            11.0 / 15.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_from_sinr_coded_beats_uncoded_per() {
+        let per_uncoded = PERCalculator::from_sinr(2.0, ModulationScheme::BPSK, 1e6, 1e5, 1000);
+        let per_coded = PERCalculator::from_sinr_coded(
+            2.0,
+            ModulationScheme::BPSK,
+            1e6,
+            1e5,
+            1000,
+            Code::Convolutional {
+                rate: 0.5,
+                constraint_length: 7,
+            },
+        );
+        assert!(per_coded < per_uncoded);
+    }
+
+    #[test]
+    fn test_amc_picks_high_order_modulation_at_high_sinr() {
+        let selection =
+            select_amc_mode(30.0, 1e6, 1e5, 1000, 0.1, &[Code::None]).expect("link should close");
+        assert_eq!(selection.modulation, ModulationScheme::QAM64);
+    }
+
+    #[test]
+    fn test_amc_falls_back_to_bpsk_under_deep_fade() {
+        let selection =
+            select_amc_mode(0.0, 1e6, 1e5, 1000, 0.1, &[Code::None]).expect("link should close");
+        assert_eq!(selection.modulation, ModulationScheme::BPSK);
+    }
+
+    #[test]
+    fn test_amc_returns_none_when_no_mode_meets_target_per() {
+        let selection = select_amc_mode(-30.0, 1e6, 1e5, 1000, 1e-9, &[Code::None]);
+        assert!(selection.is_none());
+    }
+
+    #[test]
+    fn test_amc_respects_target_per() {
+        let selection =
+            select_amc_mode(10.0, 1e6, 1e5, 1000, 0.05, &[Code::None]).expect("link should close");
+        assert!(selection.predicted_per <= 0.05);
+    }
+
+    #[test]
+    fn test_amc_coding_can_unlock_a_faster_mode_than_uncoded() {
+        let codes = [
+            Code::None,
+            Code::Convolutional {
+                rate: 0.5,
+                constraint_length: 7,
+            },
+        ];
+        let uncoded_only = select_amc_mode(5.0, 1e6, 1e5, 1000, 0.3, &[Code::None])
+            .expect("link should close uncoded");
+        let with_coding =
+            select_amc_mode(5.0, 1e6, 1e5, 1000, 0.3, &codes).expect("link should close");
+        assert!(with_coding.throughput_bps > uncoded_only.throughput_bps);
+    }
+
     #[test]
     fn test_modulation_bits_per_symbol() {
         assert_eq!(ModulationScheme::BPSK.bits_per_symbol(), 1);
         assert_eq!(ModulationScheme::QPSK.bits_per_symbol(), 2);
         assert_eq!(ModulationScheme::QAM16.bits_per_symbol(), 4);
         assert_eq!(ModulationScheme::QAM64.bits_per_symbol(), 6);
+        assert_eq!(ModulationScheme::Fsk2.bits_per_symbol(), 1);
+        assert_eq!(ModulationScheme::Gfsk.bits_per_symbol(), 1);
+    }
+
+    #[test]
+    fn test_fsk_ber_matches_noncoherent_formula() {
+        let eb_n0_db = 8.0;
+        let eb_n0 = db_to_linear(eb_n0_db);
+        let expected = 0.5 * (-0.5 * eb_n0).exp();
+        assert_abs_diff_eq!(
+            ModulationScheme::Fsk2.compute_ber(eb_n0_db),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            ModulationScheme::Gfsk.compute_ber(eb_n0_db),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_fsk_coherent_beats_noncoherent_at_same_eb_n0() {
+        let eb_n0_db = 10.0;
+        assert!(BERCalculator::fsk_coherent(eb_n0_db) < BERCalculator::fsk_noncoherent(eb_n0_db));
+    }
+
+    #[test]
+    fn test_channel_bandwidth_to_hz_matches_standard_table() {
+        assert_abs_diff_eq!(ChannelBandwidth::Khz4_8.to_hz(), 4_800.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            ChannelBandwidth::Khz467_0.to_hz(),
+            467_000.0,
+            epsilon = 1e-6
+        );
     }
 }
@@ -7,8 +7,14 @@
 //! - MANET (Mobile Ad-hoc Network) protocol simulation
 
 use nalgebra::Vector3;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use crate::metrics::{ModulationScheme, PERCalculator};
 
 /// Agent ID in network
 pub type AgentId = usize;
@@ -87,6 +93,61 @@ pub struct LinkState {
     pub last_update_time: f64,
 }
 
+/// A routed path between two agents, bundled with its end-to-end packet
+/// error rate (see [`NetworkTopology::find_routed_path`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoutedPath {
+    /// Agents visited, `source` first and `destination` last.
+    pub path: Vec<AgentId>,
+    /// Probability a packet sent from `path[0]` never arrives at
+    /// `path[path.len() - 1]`, combining every hop's own PER.
+    pub end_to_end_per: f64,
+}
+
+/// Per-node transmit capacity and this-step consumption. [`NetworkTopology`]
+/// refills `consumed_this_step` to zero and resizes the byte budget it's
+/// measured against every time [`NetworkTopology::update_time`] advances the
+/// clock, so a node that saturates its link mid-burst simply stops being
+/// able to send until a later step frees room up.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct NodeNetworkCapacity {
+    /// Node's transmit capacity (bits per second)
+    pub capacity_bps: u32,
+
+    /// Bytes already sent out of this step's budget
+    pub consumed_this_step: u32,
+}
+
+impl NodeNetworkCapacity {
+    /// Create a new capacity tracker with nothing consumed yet.
+    pub fn new(capacity_bps: u32) -> Self {
+        Self {
+            capacity_bps,
+            consumed_this_step: 0,
+        }
+    }
+
+    /// Byte budget available over a step of length `dt` seconds.
+    pub fn step_budget_bytes(&self, dt: f64) -> u32 {
+        (self.capacity_bps as f64 * dt / 8.0) as u32
+    }
+
+    /// Bytes still available this step.
+    fn remaining_bytes(&self, dt: f64) -> u32 {
+        self.step_budget_bytes(dt)
+            .saturating_sub(self.consumed_this_step)
+    }
+}
+
+/// A packet that missed its step's node or link budget, queued on the
+/// sending node and retried by [`NetworkTopology::update_time`]'s drain pass
+/// once a later step refills that budget.
+#[derive(Debug, Clone)]
+struct QueuedPacket {
+    destination: AgentId,
+    bytes: u32,
+}
+
 /// Network topology graph
 #[derive(Debug, Clone)]
 pub struct NetworkTopology {
@@ -107,6 +168,24 @@ pub struct NetworkTopology {
 
     /// Maximum packet loss for usable link (0.0-1.0)
     max_packet_loss: f64,
+
+    /// Registered per-node transmit capacity, keyed by node
+    node_capacity: HashMap<AgentId, NodeNetworkCapacity>,
+
+    /// Bytes already sent over each directed link this step
+    link_consumed: HashMap<(AgentId, AgentId), u32>,
+
+    /// Packets that missed their step's budget, queued per sending node
+    send_queues: HashMap<AgentId, VecDeque<QueuedPacket>>,
+
+    /// Length of the most recently completed simulation step (seconds),
+    /// used to size each node's and link's per-step byte budget
+    step_dt_s: f64,
+
+    /// Per-axis (N/E/D) sweep-and-prune endpoint orderings, carried across
+    /// [`Self::rebuild_links_from_positions`] calls so each step's
+    /// insertion sort starts from the previous, nearly-sorted order
+    sap_endpoints: [Vec<SapEndpoint>; 3],
 }
 
 impl NetworkTopology {
@@ -119,6 +198,11 @@ impl NetworkTopology {
             link_timeout_s: 5.0,
             min_snr_db: 10.0,
             max_packet_loss: 0.1,
+            node_capacity: HashMap::new(),
+            link_consumed: HashMap::new(),
+            send_queues: HashMap::new(),
+            step_dt_s: 1.0,
+            sap_endpoints: [Vec::new(), Vec::new(), Vec::new()],
         }
     }
 
@@ -135,6 +219,15 @@ impl NetworkTopology {
         self.links.retain(|(src, dst), _| *src != id && *dst != id);
     }
 
+    /// Mutate every existing link's quality in place, e.g. to drive
+    /// temporal evolution like [`crate::conditions::NetworkConditions`]'s
+    /// per-step Gauss-Markov perturbation.
+    pub fn update_links(&mut self, mut f: impl FnMut(AgentId, AgentId, &mut LinkQuality)) {
+        for link in self.links.values_mut() {
+            f(link.source, link.destination, &mut link.quality);
+        }
+    }
+
     /// Add or update a link
     pub fn add_link(&mut self, source: AgentId, destination: AgentId, quality: LinkQuality) {
         let link = LinkState {
@@ -152,15 +245,157 @@ impl NetworkTopology {
         self.links.get(&(source, destination))
     }
 
-    /// Update simulation time and prune stale links
-    pub fn update_time(&mut self, time: f64) {
+    /// Current simulation time, as last set by [`Self::update_time`].
+    pub fn current_time(&self) -> f64 {
+        self.current_time
+    }
+
+    /// Link timeout (seconds) used by [`Self::update_time`] to prune stale
+    /// links; exposed so reactive routing layers like
+    /// [`crate::aodv::AodvRouter`] can size route expiry off the same value.
+    pub fn link_timeout_s(&self) -> f64 {
+        self.link_timeout_s
+    }
+
+    /// Update simulation time, prune stale links, and refill every
+    /// registered node's per-step transmit budget. Sizing `step_dt_s` off
+    /// how far the clock actually advanced means each node's byte budget
+    /// (`capacity_bps * dt / 8`) tracks the real step length rather than
+    /// assuming a fixed tick. After the refill, drains each node's backlog
+    /// of [`Self::try_send`]-queued packets against the fresh budget,
+    /// probabilistically dropping each drained packet per its link's
+    /// `packet_loss_rate`.
+    pub fn update_time(&mut self, time: f64, rng: &mut StdRng) {
+        let dt = (time - self.current_time).max(0.0);
         self.current_time = time;
+        self.step_dt_s = dt;
 
         // Remove stale links
         let timeout = self.link_timeout_s;
         let current = self.current_time;
         self.links
             .retain(|_, link| (current - link.last_update_time) < timeout);
+
+        for capacity in self.node_capacity.values_mut() {
+            capacity.consumed_this_step = 0;
+        }
+        self.link_consumed.clear();
+
+        self.drain_send_queues(rng);
+    }
+
+    /// Register (or replace) a node's transmit capacity. A node with no
+    /// registered capacity is treated as unconstrained by [`Self::try_send`].
+    pub fn set_node_capacity(&mut self, agent: AgentId, capacity_bps: u32) {
+        self.node_capacity
+            .insert(agent, NodeNetworkCapacity::new(capacity_bps));
+    }
+
+    /// Current capacity/consumption state for a node, if registered.
+    pub fn get_node_capacity(&self, agent: AgentId) -> Option<NodeNetworkCapacity> {
+        self.node_capacity.get(&agent).copied()
+    }
+
+    /// Number of packets queued on `agent`, waiting for budget to free up.
+    pub fn queued_packet_count(&self, agent: AgentId) -> usize {
+        self.send_queues.get(&agent).map(VecDeque::len).unwrap_or(0)
+    }
+
+    /// Attempt to send `msg_bytes` from `src` to `dst` this step, debiting
+    /// both the sending node's per-step budget (if [`Self::set_node_capacity`]
+    /// registered one) and the `(src, dst)` link's `bandwidth_bps` budget.
+    /// Returns `true` if the packet fit in both budgets and was sent
+    /// immediately. Otherwise the packet is queued on `src` -- to be
+    /// retried, oldest first, by [`Self::update_time`]'s drain pass once a
+    /// later step refills the exhausted budget -- and `false` is returned.
+    pub fn try_send(&mut self, src: AgentId, dst: AgentId, msg_bytes: u32) -> bool {
+        if self.debit_if_affordable(src, dst, msg_bytes) {
+            true
+        } else {
+            self.send_queues
+                .entry(src)
+                .or_default()
+                .push_back(QueuedPacket {
+                    destination: dst,
+                    bytes: msg_bytes,
+                });
+            false
+        }
+    }
+
+    /// Debit `msg_bytes` from both `src`'s node budget and the `(src, dst)`
+    /// link's bandwidth budget if both have room this step; a node or link
+    /// with no registered capacity/known bandwidth is unconstrained.
+    fn debit_if_affordable(&mut self, src: AgentId, dst: AgentId, msg_bytes: u32) -> bool {
+        let dt = self.step_dt_s;
+
+        let node_room = self
+            .node_capacity
+            .get(&src)
+            .map(|capacity| capacity.remaining_bytes(dt) >= msg_bytes)
+            .unwrap_or(true);
+
+        let link_room = self
+            .links
+            .get(&(src, dst))
+            .map(|link| {
+                let budget = (link.quality.bandwidth_bps * dt / 8.0) as u32;
+                let consumed = *self.link_consumed.get(&(src, dst)).unwrap_or(&0);
+                budget.saturating_sub(consumed) >= msg_bytes
+            })
+            .unwrap_or(true);
+
+        if !(node_room && link_room) {
+            return false;
+        }
+
+        if let Some(capacity) = self.node_capacity.get_mut(&src) {
+            capacity.consumed_this_step += msg_bytes;
+        }
+        *self.link_consumed.entry((src, dst)).or_insert(0) += msg_bytes;
+        true
+    }
+
+    /// Drain each node's backlog of queued packets against the freshly
+    /// refilled per-step budget, oldest first. A packet that still doesn't
+    /// fit stops that node's drain for this step (FIFO -- it stays at the
+    /// front of the queue rather than letting a later, smaller packet cut
+    /// the line). A packet that does drain is probabilistically dropped
+    /// per its destination link's `packet_loss_rate`, modeling a queued
+    /// packet that finally gets airtime but still loses to channel noise.
+    fn drain_send_queues(&mut self, rng: &mut StdRng) {
+        let senders: Vec<AgentId> = self.send_queues.keys().copied().collect();
+
+        for src in senders {
+            loop {
+                let Some(packet) = self
+                    .send_queues
+                    .get(&src)
+                    .and_then(|queue| queue.front())
+                    .cloned()
+                else {
+                    break;
+                };
+
+                if !self.debit_if_affordable(src, packet.destination, packet.bytes) {
+                    break;
+                }
+
+                self.send_queues.get_mut(&src).unwrap().pop_front();
+
+                let loss_rate = self
+                    .links
+                    .get(&(src, packet.destination))
+                    .map(|link| link.quality.packet_loss_rate)
+                    .unwrap_or(0.0);
+
+                if rng.gen::<f64>() < loss_rate {
+                    // Dropped after consuming airtime, same as a real
+                    // transmission that loses to channel noise.
+                    continue;
+                }
+            }
+        }
     }
 
     /// Get all neighbors of an agent (agents with usable links)
@@ -226,75 +461,219 @@ impl NetworkTopology {
 
     /// Check if two agents are connected (any path exists)
     pub fn is_connected(&self, source: AgentId, destination: AgentId) -> bool {
-        if source == destination {
-            return true;
+        source == destination || self.bfs_reachable_set(source).contains(&destination)
+    }
+
+    /// Find shortest path between two agents (Dijkstra's algorithm)
+    /// Returns None if no path exists
+    pub fn find_shortest_path(
+        &self,
+        source: AgentId,
+        destination: AgentId,
+    ) -> Option<Vec<AgentId>> {
+        self.dijkstra_excluding(source, destination, &HashSet::new(), &HashSet::new())
+            .map(|(path, _)| path)
+    }
+
+    /// Up to `k` loopless paths from `source` to `destination`, cheapest
+    /// first, found with Yen's algorithm on top of [`Self::find_shortest_path`]'s
+    /// inverse-link-score edge weights. Gives tactical mesh routing ranked
+    /// alternate routes for load balancing and instant failover when a link
+    /// times out. Returns fewer than `k` paths (possibly zero) if that many
+    /// loopless routes don't exist.
+    pub fn find_k_shortest_paths(
+        &self,
+        source: AgentId,
+        destination: AgentId,
+        k: usize,
+    ) -> Vec<Vec<AgentId>> {
+        if k == 0 {
+            return Vec::new();
         }
 
-        // Breadth-first search
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
+        let Some(first) =
+            self.dijkstra_excluding(source, destination, &HashSet::new(), &HashSet::new())
+        else {
+            return Vec::new();
+        };
 
-        queue.push_back(source);
-        visited.insert(source);
+        let mut found: Vec<(Vec<AgentId>, f64)> = vec![first];
+        let mut candidates: BinaryHeap<PathCandidate> = BinaryHeap::new();
 
-        while let Some(current) = queue.pop_front() {
-            if current == destination {
-                return true;
+        while found.len() < k {
+            let previous_path = found.last().unwrap().0.clone();
+
+            for spur_index in 0..previous_path.len().saturating_sub(1) {
+                let spur_node = previous_path[spur_index];
+                let root_path = &previous_path[..=spur_index];
+
+                // Remove the next edge of every already-found path that
+                // shares this root, so the spur is forced to diverge.
+                let mut excluded_edges: HashSet<(AgentId, AgentId)> = HashSet::new();
+                for (path, _) in &found {
+                    if path.len() > spur_index + 1 && path[..=spur_index] == *root_path {
+                        excluded_edges.insert((path[spur_index], path[spur_index + 1]));
+                    }
+                }
+
+                // Remove root-path nodes (except the spur itself) so the
+                // spur can't loop back through its own root.
+                let excluded_nodes: HashSet<AgentId> =
+                    root_path[..spur_index].iter().copied().collect();
+
+                if let Some((spur_path, spur_cost)) = self.dijkstra_excluding(
+                    spur_node,
+                    destination,
+                    &excluded_nodes,
+                    &excluded_edges,
+                ) {
+                    let mut total_path = root_path[..spur_index].to_vec();
+                    total_path.extend(spur_path);
+
+                    if found.iter().any(|(path, _)| *path == total_path) {
+                        continue;
+                    }
+
+                    let total_cost = self.path_cost(root_path) + spur_cost;
+                    candidates.push(PathCandidate {
+                        cost: total_cost,
+                        path: total_path,
+                    });
+                }
             }
 
-            for neighbor in self.get_neighbors(current) {
-                if !visited.contains(&neighbor) {
-                    visited.insert(neighbor);
-                    queue.push_back(neighbor);
+            let mut next = None;
+            while let Some(candidate) = candidates.pop() {
+                if !found.iter().any(|(path, _)| *path == candidate.path) {
+                    next = Some(candidate);
+                    break;
                 }
             }
+
+            let Some(next) = next else { break };
+            found.push((next.path, next.cost));
         }
 
-        false
+        found.into_iter().map(|(path, _)| path).collect()
     }
 
-    /// Find shortest path between two agents (Dijkstra's algorithm)
-    /// Returns None if no path exists
-    pub fn find_shortest_path(
+    /// [`Self::find_shortest_path`] plus the route's end-to-end packet
+    /// error rate, for callers that need to know not just *which* path is
+    /// cheapest but how likely a packet is to actually survive it.
+    /// Returns `None` if no path exists.
+    ///
+    /// Each hop's PER comes from [`PERCalculator::from_sinr`], using that
+    /// link's `snr_db` and `bandwidth_bps` (as the data rate) under
+    /// `modulation`; the end-to-end PER is then `1 - prod(1 - per_hop)`
+    /// over every hop, i.e. the probability at least one hop drops the
+    /// packet.
+    pub fn find_routed_path(
         &self,
         source: AgentId,
         destination: AgentId,
-    ) -> Option<Vec<AgentId>> {
+        modulation: ModulationScheme,
+        channel_bandwidth_hz: f64,
+        packet_length_bits: usize,
+    ) -> Option<RoutedPath> {
+        let path = self.find_shortest_path(source, destination)?;
+
+        let mut delivery_probability = 1.0;
+        for pair in path.windows(2) {
+            let link = self.get_link(pair[0], pair[1])?;
+            let hop_per = PERCalculator::from_sinr(
+                link.quality.snr_db,
+                modulation,
+                channel_bandwidth_hz,
+                link.quality.bandwidth_bps,
+                packet_length_bits,
+            );
+            delivery_probability *= 1.0 - hop_per;
+        }
+
+        Some(RoutedPath {
+            path,
+            end_to_end_per: 1.0 - delivery_probability,
+        })
+    }
+
+    /// Total inverse-link-score cost of a path's edges; `f64::INFINITY` if
+    /// any edge along it doesn't exist.
+    fn path_cost(&self, path: &[AgentId]) -> f64 {
+        path.windows(2)
+            .map(|pair| {
+                self.get_link(pair[0], pair[1])
+                    .map(|link| {
+                        let score = link.quality.compute_score();
+                        if score > 0.0 {
+                            1.0 / score
+                        } else {
+                            f64::INFINITY
+                        }
+                    })
+                    .unwrap_or(f64::INFINITY)
+            })
+            .sum()
+    }
+
+    /// Dijkstra's algorithm from `source` to `destination` using inverse
+    /// link score as edge weight, skipping `excluded_nodes` (other than
+    /// `source`/`destination`) and `excluded_edges` entirely. Returns the
+    /// path and its total cost, or `None` if no path exists under those
+    /// exclusions. Shared by [`Self::find_shortest_path`] and
+    /// [`Self::find_k_shortest_paths`]'s spur search.
+    ///
+    /// Uses a `BinaryHeap` min-heap frontier (`O((V + E) log V)`) rather
+    /// than a linear scan over the unvisited set (`O(V^2)`), so per-tick
+    /// routing stays cheap as swarm size grows.
+    fn dijkstra_excluding(
+        &self,
+        source: AgentId,
+        destination: AgentId,
+        excluded_nodes: &HashSet<AgentId>,
+        excluded_edges: &HashSet<(AgentId, AgentId)>,
+    ) -> Option<(Vec<AgentId>, f64)> {
         if source == destination {
-            return Some(vec![source]);
+            return Some((vec![source], 0.0));
         }
 
         let mut distances: HashMap<AgentId, f64> = HashMap::new();
         let mut previous: HashMap<AgentId, AgentId> = HashMap::new();
-        let mut unvisited: HashSet<AgentId> = self.get_agents().into_iter().collect();
+        let mut visited: HashSet<AgentId> = HashSet::new();
+        let mut frontier: BinaryHeap<DijkstraState> = BinaryHeap::new();
 
         distances.insert(source, 0.0);
-
-        while !unvisited.is_empty() {
-            // Find unvisited node with smallest distance
-            let current = unvisited
-                .iter()
-                .min_by(|a, b| {
-                    let dist_a = distances.get(a).unwrap_or(&f64::INFINITY);
-                    let dist_b = distances.get(b).unwrap_or(&f64::INFINITY);
-                    dist_a.partial_cmp(dist_b).unwrap()
-                })
-                .copied()?;
-
+        frontier.push(DijkstraState {
+            cost: 0.0,
+            node: source,
+        });
+
+        while let Some(DijkstraState {
+            cost,
+            node: current,
+        }) = frontier.pop()
+        {
             if current == destination {
                 break;
             }
 
-            unvisited.remove(&current);
-
-            let current_dist = *distances.get(&current).unwrap_or(&f64::INFINITY);
-            if current_dist == f64::INFINITY {
-                break;
+            if !visited.insert(current) {
+                // Stale heap entry left over from before a cheaper path to
+                // `current` was already settled.
+                continue;
             }
 
             // Update distances to neighbors
             for neighbor in self.get_neighbors(current) {
-                if !unvisited.contains(&neighbor) {
+                if excluded_edges.contains(&(current, neighbor)) {
+                    continue;
+                }
+                if excluded_nodes.contains(&neighbor)
+                    && neighbor != source
+                    && neighbor != destination
+                {
+                    continue;
+                }
+                if visited.contains(&neighbor) {
                     continue;
                 }
 
@@ -310,12 +689,16 @@ impl NetworkTopology {
                     f64::INFINITY
                 };
 
-                let alt_dist = current_dist + link_cost;
+                let alt_dist = cost + link_cost;
                 let neighbor_dist = *distances.get(&neighbor).unwrap_or(&f64::INFINITY);
 
                 if alt_dist < neighbor_dist {
                     distances.insert(neighbor, alt_dist);
                     previous.insert(neighbor, current);
+                    frontier.push(DijkstraState {
+                        cost: alt_dist,
+                        node: neighbor,
+                    });
                 }
             }
         }
@@ -334,7 +717,261 @@ impl NetworkTopology {
         }
 
         path.reverse();
-        Some(path)
+        let total_cost = *distances.get(&destination)?;
+        Some((path, total_cost))
+    }
+
+    /// Like [`Self::compute_connectivity`], but runs one forward-reachability
+    /// BFS per agent -- rather than a fresh BFS for every ordered pair -- and
+    /// spreads those independent per-agent passes across a rayon thread
+    /// pool. Turns connectivity evaluation from quadratic-in-pairs into
+    /// linear-in-edges (`O(V)` parallel BFS passes, each `O(V + E)`), making
+    /// per-tick topology analysis feasible for hundreds of agents.
+    /// `num_threads` mirrors `BackendConfig.num_threads`: `None` runs on
+    /// rayon's global pool.
+    pub fn compute_all_pairs_connectivity(&self, num_threads: Option<usize>) -> f64 {
+        let n = self.agent_count();
+        if n < 2 {
+            return 1.0;
+        }
+
+        let agents = self.get_agents();
+        let total_pairs = n * (n - 1);
+
+        let sum_reachable = || -> usize {
+            agents
+                .par_iter()
+                .map(|&agent| self.bfs_reachable_set(agent).len().saturating_sub(1))
+                .sum()
+        };
+
+        let connected_pairs = match num_threads {
+            Some(threads) => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("failed to build rayon thread pool");
+                pool.install(sum_reachable)
+            }
+            None => sum_reachable(),
+        };
+
+        connected_pairs as f64 / total_pairs as f64
+    }
+
+    /// All agents reachable from `source` by following usable directed
+    /// links (always includes `source` itself). Shared by [`Self::is_connected`]
+    /// and [`Self::compute_all_pairs_connectivity`].
+    fn bfs_reachable_set(&self, source: AgentId) -> HashSet<AgentId> {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        queue.push_back(source);
+        visited.insert(source);
+
+        while let Some(current) = queue.pop_front() {
+            for neighbor in self.get_neighbors(current) {
+                if visited.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Replace every link with one synthesized from the current `agents`
+    /// positions: any pair within `radio_range_m` of each other gets a
+    /// bidirectional [`LinkState`] whose [`LinkQuality`] comes from
+    /// `propagation_fn(distance_m)`. Lets callers drive topology purely off
+    /// moving positions -- feed in a fresh set of coordinates each step and
+    /// get an RF-derived mesh back -- instead of scripting every
+    /// [`Self::add_link`] call by hand.
+    ///
+    /// Candidate pairs are found with a sweep-and-prune broad phase rather
+    /// than an O(N^2) all-pairs distance test: each agent gets an AABB of
+    /// `position +/- radio_range_m`, and [`Self::sap_candidate_pairs`] keeps
+    /// the three per-axis endpoint orderings close to sorted between calls
+    /// (agents move a little each step, so an insertion sort against the
+    /// previous ordering is close to O(N) rather than O(N log N)). Only
+    /// pairs overlapping on all three axes are confirmed with an exact
+    /// distance check.
+    pub fn rebuild_links_from_positions(
+        &mut self,
+        radio_range_m: f64,
+        propagation_fn: impl Fn(f64) -> LinkQuality,
+    ) {
+        self.links.clear();
+
+        for (a, b) in self.sap_candidate_pairs(radio_range_m) {
+            let pos_a = self.agents[&a];
+            let pos_b = self.agents[&b];
+            let distance_m = (pos_a - pos_b).norm();
+
+            if distance_m > radio_range_m {
+                continue;
+            }
+
+            let quality = propagation_fn(distance_m);
+            self.add_link(a, b, quality);
+            self.add_link(b, a, quality);
+        }
+    }
+
+    /// Broad-phase candidate pairs for [`Self::rebuild_links_from_positions`]:
+    /// agent pairs whose `position +/- radio_range_m` AABBs overlap on all
+    /// three NED axes. Exact distance is *not* checked here -- callers must
+    /// confirm it, since two boxes can overlap while the agents themselves
+    /// are farther apart than `radio_range_m`.
+    fn sap_candidate_pairs(&mut self, radio_range_m: f64) -> Vec<(AgentId, AgentId)> {
+        for axis in 0..3 {
+            self.sap_endpoints[axis].retain(|endpoint| self.agents.contains_key(&endpoint.agent));
+
+            let present: HashSet<AgentId> = self.sap_endpoints[axis]
+                .iter()
+                .map(|endpoint| endpoint.agent)
+                .collect();
+            for (&agent, _) in self.agents.iter() {
+                if !present.contains(&agent) {
+                    self.sap_endpoints[axis].push(SapEndpoint {
+                        agent,
+                        is_max: false,
+                        value: 0.0,
+                    });
+                    self.sap_endpoints[axis].push(SapEndpoint {
+                        agent,
+                        is_max: true,
+                        value: 0.0,
+                    });
+                }
+            }
+
+            for endpoint in self.sap_endpoints[axis].iter_mut() {
+                let center = self.agents[&endpoint.agent][axis];
+                endpoint.value = if endpoint.is_max {
+                    center + radio_range_m
+                } else {
+                    center - radio_range_m
+                };
+            }
+
+            insertion_sort_by_key(&mut self.sap_endpoints[axis], |endpoint| endpoint.value);
+        }
+
+        let mut overlap_axes: HashMap<(AgentId, AgentId), u8> = HashMap::new();
+
+        for axis in 0..3 {
+            let mut active: HashSet<AgentId> = HashSet::new();
+            for endpoint in &self.sap_endpoints[axis] {
+                if endpoint.is_max {
+                    active.remove(&endpoint.agent);
+                } else {
+                    for &other in &active {
+                        let key = if endpoint.agent < other {
+                            (endpoint.agent, other)
+                        } else {
+                            (other, endpoint.agent)
+                        };
+                        *overlap_axes.entry(key).or_insert(0) += 1;
+                    }
+                    active.insert(endpoint.agent);
+                }
+            }
+        }
+
+        overlap_axes
+            .into_iter()
+            .filter(|(_, axes)| *axes == 3)
+            .map(|(pair, _)| pair)
+            .collect()
+    }
+}
+
+/// One endpoint of an agent's per-axis AABB interval in
+/// [`NetworkTopology::sap_candidate_pairs`]'s sweep-and-prune broad phase.
+#[derive(Debug, Clone, Copy)]
+struct SapEndpoint {
+    agent: AgentId,
+    /// `false` for the interval's lower bound, `true` for its upper bound.
+    is_max: bool,
+    value: f64,
+}
+
+/// Insertion sort by a scalar key. Quadratic worst case, but the broad
+/// phase calls this on lists that are already close to sorted from the
+/// previous step (agents move a little between calls), where it runs close
+/// to linear -- cheaper in practice than a general-purpose O(N log N) sort.
+fn insertion_sort_by_key<T, K: PartialOrd>(items: &mut [T], key: impl Fn(&T) -> K) {
+    for i in 1..items.len() {
+        let mut j = i;
+        while j > 0 && key(&items[j]) < key(&items[j - 1]) {
+            items.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// One entry in [`NetworkTopology::dijkstra_excluding`]'s binary-heap
+/// frontier, ordered by cost and reversed so the max-heap `BinaryHeap`
+/// pops the cheapest node first.
+#[derive(Debug, Clone, Copy)]
+struct DijkstraState {
+    cost: f64,
+    node: AgentId,
+}
+
+impl PartialEq for DijkstraState {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for DijkstraState {}
+
+impl PartialOrd for DijkstraState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DijkstraState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// One of Yen's candidate root+spur paths, ordered by total cost so a
+/// [`BinaryHeap`] (a max-heap) pops the cheapest candidate first.
+#[derive(Debug, Clone)]
+struct PathCandidate {
+    cost: f64,
+    path: Vec<AgentId>,
+}
+
+impl PartialEq for PathCandidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for PathCandidate {}
+
+impl PartialOrd for PathCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PathCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the max-heap behaves as a min-heap on cost.
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
     }
 }
 
@@ -424,6 +1061,7 @@ impl PartitionDetector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
 
     #[test]
     fn test_network_topology() {
@@ -520,4 +1158,319 @@ mod tests {
         assert!(good_link.compute_score() > 0.8);
         assert!(bad_link.compute_score() < 0.5); // More lenient threshold
     }
+
+    #[test]
+    fn test_node_capacity_step_budget_bytes() {
+        // 8000 bps for 0.5s = 4000 bits = 500 bytes
+        let capacity = NodeNetworkCapacity::new(8_000);
+        assert_eq!(capacity.step_budget_bytes(0.5), 500);
+    }
+
+    #[test]
+    fn test_try_send_succeeds_within_node_and_link_budget() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(
+            0,
+            1,
+            LinkQuality {
+                bandwidth_bps: 80_000.0,
+                ..Default::default()
+            },
+        );
+        topology.set_node_capacity(0, 80_000);
+
+        let mut rng = StdRng::seed_from_u64(1);
+        topology.update_time(1.0, &mut rng);
+
+        // Budget for 1s step: 80,000 bps / 8 = 10,000 bytes.
+        assert!(topology.try_send(0, 1, 1_000));
+        assert_eq!(
+            topology.get_node_capacity(0).unwrap().consumed_this_step,
+            1_000
+        );
+        assert_eq!(topology.queued_packet_count(0), 0);
+    }
+
+    #[test]
+    fn test_try_send_queues_packet_when_node_budget_exhausted() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(0, 1, LinkQuality::default());
+        topology.set_node_capacity(0, 8_000); // 1,000 bytes/s
+
+        let mut rng = StdRng::seed_from_u64(2);
+        topology.update_time(1.0, &mut rng);
+
+        assert!(!topology.try_send(0, 1, 5_000));
+        assert_eq!(topology.queued_packet_count(0), 1);
+    }
+
+    #[test]
+    fn test_try_send_queues_packet_when_link_budget_exhausted() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(
+            0,
+            1,
+            LinkQuality {
+                bandwidth_bps: 8_000.0, // 1,000 bytes/s
+                ..Default::default()
+            },
+        );
+        topology.set_node_capacity(0, 8_000_000); // node itself is not the bottleneck
+
+        let mut rng = StdRng::seed_from_u64(3);
+        topology.update_time(1.0, &mut rng);
+
+        assert!(!topology.try_send(0, 1, 5_000));
+        assert_eq!(topology.queued_packet_count(0), 1);
+    }
+
+    #[test]
+    fn test_queued_packet_drains_once_a_later_step_refills_budget() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(
+            0,
+            1,
+            LinkQuality {
+                bandwidth_bps: 80_000.0,
+                packet_loss_rate: 0.0,
+                ..Default::default()
+            },
+        );
+        topology.set_node_capacity(0, 8_000); // 1,000 bytes/s -- too small for 5,000 bytes
+
+        let mut rng = StdRng::seed_from_u64(4);
+        topology.update_time(1.0, &mut rng);
+        assert!(!topology.try_send(0, 1, 5_000));
+        assert_eq!(topology.queued_packet_count(0), 1);
+
+        // A much longer next step refills enough budget to drain the backlog.
+        topology.update_time(11.0, &mut rng);
+        assert_eq!(topology.queued_packet_count(0), 0);
+    }
+
+    #[test]
+    fn test_queued_packet_is_dequeued_on_drain_even_at_loss_rate_one() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(
+            0,
+            1,
+            LinkQuality {
+                bandwidth_bps: 80_000.0,
+                packet_loss_rate: 1.0,
+                ..Default::default()
+            },
+        );
+        topology.set_node_capacity(0, 8_000); // too small for 5,000 bytes -- forces a queue
+
+        let mut rng = StdRng::seed_from_u64(5);
+        topology.update_time(1.0, &mut rng);
+        assert!(!topology.try_send(0, 1, 5_000));
+        assert_eq!(topology.queued_packet_count(0), 1);
+
+        // Next step refills enough budget to drain the packet; the link's
+        // packet_loss_rate of 1.0 means it's dropped, but still dequeued --
+        // drop happens after the transmission "airtime" is spent.
+        topology.update_time(11.0, &mut rng);
+        assert_eq!(topology.queued_packet_count(0), 0);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_ranks_alternate_routes_by_cost() {
+        let mut topology = NetworkTopology::new();
+        for i in 0..4 {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+
+        let good = LinkQuality {
+            snr_db: 30.0,
+            packet_loss_rate: 0.0,
+            latency_s: 0.0,
+            ..Default::default()
+        };
+        let worse = LinkQuality {
+            snr_db: 12.0,
+            packet_loss_rate: 0.05,
+            latency_s: 0.0,
+            ..Default::default()
+        };
+
+        // Two disjoint routes 0 -> 3: via 1 (good) and via 2 (worse).
+        topology.add_link(0, 1, good);
+        topology.add_link(1, 3, good);
+        topology.add_link(0, 2, worse);
+        topology.add_link(2, 3, worse);
+
+        let paths = topology.find_k_shortest_paths(0, 3, 2);
+        assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_first_entry_matches_find_shortest_path() {
+        let mut topology = NetworkTopology::new();
+        for i in 0..4 {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        let quality = LinkQuality::default();
+        for i in 0..3 {
+            topology.add_link(i, i + 1, quality);
+            topology.add_link(i + 1, i, quality);
+        }
+
+        let shortest = topology.find_shortest_path(0, 3);
+        let k_shortest = topology.find_k_shortest_paths(0, 3, 3);
+
+        assert_eq!(k_shortest.first(), shortest.as_ref());
+    }
+
+    #[test]
+    fn test_k_shortest_paths_returns_fewer_than_k_when_not_enough_exist() {
+        let mut topology = NetworkTopology::new();
+        for i in 0..3 {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        // Single linear route, no alternates possible.
+        topology.add_link(0, 1, LinkQuality::default());
+        topology.add_link(1, 2, LinkQuality::default());
+
+        let paths = topology.find_k_shortest_paths(0, 2, 5);
+        assert_eq!(paths, vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_zero_requested_returns_empty() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+        topology.add_link(0, 1, LinkQuality::default());
+
+        assert!(topology.find_k_shortest_paths(0, 1, 0).is_empty());
+    }
+
+    #[test]
+    fn test_find_routed_path_reports_increasing_per_over_more_hops() {
+        let mut topology = NetworkTopology::new();
+        for i in 0..4 {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        let link = LinkQuality {
+            snr_db: 15.0,
+            bandwidth_bps: 1e6,
+            ..Default::default()
+        };
+        for i in 0..3 {
+            topology.add_link(i, i + 1, link);
+            topology.add_link(i + 1, i, link);
+        }
+
+        let one_hop = topology
+            .find_routed_path(0, 1, ModulationScheme::QPSK, 1e6, 1000)
+            .unwrap();
+        let three_hop = topology
+            .find_routed_path(0, 3, ModulationScheme::QPSK, 1e6, 1000)
+            .unwrap();
+
+        assert_eq!(one_hop.path, vec![0, 1]);
+        assert_eq!(three_hop.path, vec![0, 1, 2, 3]);
+        assert!(three_hop.end_to_end_per >= one_hop.end_to_end_per);
+    }
+
+    #[test]
+    fn test_find_routed_path_returns_none_when_unreachable() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(100.0, 0.0, 0.0));
+
+        assert!(topology
+            .find_routed_path(0, 1, ModulationScheme::QPSK, 1e6, 1000)
+            .is_none());
+    }
+
+    #[test]
+    fn test_all_pairs_connectivity_matches_pairwise_connectivity() {
+        let mut topology = NetworkTopology::new();
+        for i in 0..4 {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        let quality = LinkQuality::default();
+        topology.add_link(0, 1, quality);
+        topology.add_link(1, 0, quality);
+        topology.add_link(1, 2, quality);
+        topology.add_link(2, 1, quality);
+        // Agent 3 is isolated.
+
+        assert_eq!(
+            topology.compute_all_pairs_connectivity(None),
+            topology.compute_connectivity()
+        );
+    }
+
+    #[test]
+    fn test_all_pairs_connectivity_with_explicit_thread_count() {
+        let mut topology = NetworkTopology::new();
+        for i in 0..3 {
+            topology.add_agent(i, Vector3::new(i as f64 * 100.0, 0.0, 0.0));
+        }
+        let quality = LinkQuality::default();
+        topology.add_link(0, 1, quality);
+        topology.add_link(1, 0, quality);
+        topology.add_link(1, 2, quality);
+        topology.add_link(2, 1, quality);
+
+        assert_eq!(topology.compute_all_pairs_connectivity(Some(2)), 1.0);
+    }
+
+    #[test]
+    fn test_rebuild_links_from_positions_connects_agents_in_range() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(50.0, 0.0, 0.0));
+        topology.add_agent(2, Vector3::new(500.0, 0.0, 0.0));
+
+        topology.rebuild_links_from_positions(100.0, |distance_m| LinkQuality {
+            rssi_dbm: -40.0 - 20.0 * distance_m.max(1.0).log10(),
+            snr_db: 20.0,
+            ..Default::default()
+        });
+
+        assert!(topology.get_link(0, 1).is_some());
+        assert!(topology.get_link(1, 0).is_some());
+        assert!(topology.get_link(0, 2).is_none());
+        assert!(topology.get_link(1, 2).is_none());
+        assert_eq!(topology.link_count(), 2);
+    }
+
+    #[test]
+    fn test_rebuild_links_from_positions_tracks_movement_across_calls() {
+        let mut topology = NetworkTopology::new();
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        topology.add_agent(1, Vector3::new(500.0, 0.0, 0.0));
+
+        topology.rebuild_links_from_positions(100.0, |_| LinkQuality::default());
+        assert_eq!(topology.link_count(), 0);
+
+        // Agent 1 moves into range; the broad phase must pick up the move
+        // rather than relying on a stale sorted order from the first call.
+        topology.add_agent(1, Vector3::new(50.0, 0.0, 0.0));
+        topology.rebuild_links_from_positions(100.0, |_| LinkQuality::default());
+        assert_eq!(topology.link_count(), 2);
+    }
+
+    #[test]
+    fn test_all_pairs_connectivity_trivial_for_fewer_than_two_agents() {
+        let mut topology = NetworkTopology::new();
+        assert_eq!(topology.compute_all_pairs_connectivity(None), 1.0);
+
+        topology.add_agent(0, Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(topology.compute_all_pairs_connectivity(None), 1.0);
+    }
 }
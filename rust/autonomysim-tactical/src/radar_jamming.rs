@@ -0,0 +1,242 @@
+//! Two-way (radar) jamming, as distinct from [`crate::jamming`]'s one-way
+//! communication-link model.
+//!
+//! `compute_jamming_power` models the jammer-to-victim-receiver path only,
+//! which is correct for comms but not for a radar victim: the link of
+//! interest there is the *target echo*, which travels to the target and
+//! back (`R⁴` two-way radar equation), while the jammer's energy still only
+//! makes a one-way trip to the radar receiver (`R²`). [`RadarJammingScenario`]
+//! evaluates both and the J/S that results, distinguishing self-screening
+//! jammers (riding along with the target, so the jammer range *is* the
+//! target range) from standoff/support jammers (a fixed separate position).
+
+use std::f64::consts::PI;
+
+use nalgebra::Vector3;
+
+const SPEED_OF_LIGHT_M_S: f64 = 3e8;
+
+/// Where the jammer sits relative to the radar-target engagement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JammerGeometry {
+    /// Jammer rides with the target (self-protection jamming): the
+    /// jammer-to-radar range equals the target-to-radar range.
+    SelfScreening,
+    /// Jammer at a fixed position separate from the target (stand-off /
+    /// support jamming): the jammer-to-radar range is independent of the
+    /// target's range.
+    Standoff { jammer_position: Vector3<f64> },
+}
+
+/// Jammer transmit parameters for a radar-jamming scenario.
+#[derive(Debug, Clone, Copy)]
+pub struct RadarJammerParams {
+    /// Jammer transmit power (dBm).
+    pub power_dbm: f64,
+    /// Jammer antenna gain toward the radar (dBi).
+    pub antenna_gain_dbi: f64,
+    /// Jamming signal bandwidth (Hz).
+    pub bandwidth_hz: f64,
+}
+
+/// A two-way radar-jamming engagement: a monostatic radar illuminating a
+/// target of a known RCS, opposed by a jammer in either geometry.
+#[derive(Debug, Clone, Copy)]
+pub struct RadarJammingScenario {
+    /// Radar position (meters, NED frame).
+    pub radar_position: Vector3<f64>,
+    /// Radar peak transmit power (dBm).
+    pub radar_peak_power_dbm: f64,
+    /// Radar antenna gain (dBi); used twice in the two-way radar equation
+    /// since the same antenna transmits and receives (monostatic).
+    pub radar_antenna_gain_dbi: f64,
+    /// Target radar cross-section (m²).
+    pub target_rcs_m2: f64,
+    /// Radar receiver bandwidth (Hz).
+    pub radar_bandwidth_hz: f64,
+    /// Center frequency (Hz), shared by radar and jammer.
+    pub center_frequency_hz: f64,
+    pub jammer: RadarJammerParams,
+    pub geometry: JammerGeometry,
+}
+
+impl RadarJammingScenario {
+    fn wavelength_m(&self) -> f64 {
+        SPEED_OF_LIGHT_M_S / self.center_frequency_hz
+    }
+
+    /// Target echo power (dBm) at `target_range_m`, via the two-way radar
+    /// equation: `Pr = Pt*G²*λ²*σ / ((4π)³*R⁴)`.
+    pub fn echo_power_dbm(&self, target_range_m: f64) -> f64 {
+        let range_m = target_range_m.max(1.0);
+        let lambda = self.wavelength_m();
+
+        self.radar_peak_power_dbm
+            + 2.0 * self.radar_antenna_gain_dbi
+            + 20.0 * lambda.log10()
+            + 10.0 * self.target_rcs_m2.log10()
+            - 30.0 * (4.0 * PI).log10()
+            - 40.0 * range_m.log10()
+    }
+
+    /// Jammer-to-radar range for `target_range_m`: equal to the target
+    /// range for [`JammerGeometry::SelfScreening`], or the fixed distance
+    /// from [`JammerGeometry::Standoff`]'s position to the radar otherwise.
+    pub fn jammer_range_m(&self, target_range_m: f64) -> f64 {
+        match self.geometry {
+            JammerGeometry::SelfScreening => target_range_m.max(1.0),
+            JammerGeometry::Standoff { jammer_position } => {
+                (jammer_position - self.radar_position).norm().max(1.0)
+            }
+        }
+    }
+
+    /// Jamming power (dBm) at the radar receiver: the one-way Friis link
+    /// from the jammer, `Pj*Gj / ((4π)²*R²)`, scaled by the radar/jammer
+    /// bandwidth ratio `Bj/B` -- a jammer narrower than the radar's
+    /// receive bandwidth only delivers the fraction of its power that
+    /// falls inside it. No radar-side receive gain is applied here,
+    /// matching [`crate::jamming::JammingModel::compute_jamming_power`]'s
+    /// convention of leaving the victim receiver's own gain out of the
+    /// jammer-link budget.
+    pub fn jammer_power_dbm(&self, target_range_m: f64) -> f64 {
+        let jammer_range_m = self.jammer_range_m(target_range_m);
+        let lambda = self.wavelength_m();
+        let bandwidth_ratio_db =
+            10.0 * (self.jammer.bandwidth_hz / self.radar_bandwidth_hz).log10();
+
+        self.jammer.power_dbm + self.jammer.antenna_gain_dbi + 20.0 * lambda.log10()
+            - 20.0 * (4.0 * PI).log10()
+            - 20.0 * jammer_range_m.log10()
+            + bandwidth_ratio_db
+    }
+
+    /// Jamming-to-signal ratio (dB) at `target_range_m`: positive values
+    /// mean the jammer masks the echo, negative means the echo has burned
+    /// through.
+    pub fn js_db(&self, target_range_m: f64) -> f64 {
+        self.jammer_power_dbm(target_range_m) - self.echo_power_dbm(target_range_m)
+    }
+
+    /// J/S (dB) at each of `target_ranges_m`, paired with the range.
+    pub fn js_profile(&self, target_ranges_m: &[f64]) -> Vec<(f64, f64)> {
+        target_ranges_m
+            .iter()
+            .map(|&range_m| (range_m, self.js_db(range_m)))
+            .collect()
+    }
+
+    /// Burn-through range (m): the target range at which J/S first reaches
+    /// `required_js_db` as the target closes in -- inside this range the
+    /// `R⁴` echo has overtaken the jamming and the target is detectable
+    /// despite it. Closed-form: J/S is an affine function of `log10(R)`
+    /// (slope `20 dB/decade` self-screening, since both legs scale with
+    /// range; `40 dB/decade` standoff, since only the echo does), so no
+    /// iterative root-finding is needed.
+    pub fn burn_through_range_m(&self, required_js_db: f64) -> f64 {
+        // J/S(R) = jammer_power_dbm(R) - echo_power_dbm(R), and every term
+        // in both except the two `log10(range)` terms is constant in R, so
+        // evaluate the affine relationship at one reference range and
+        // solve for the range giving `required_js_db`.
+        let reference_range_m = 1000.0;
+        let js_at_reference = self.js_db(reference_range_m);
+
+        let slope_db_per_decade = match self.geometry {
+            JammerGeometry::SelfScreening => 20.0,
+            JammerGeometry::Standoff { .. } => 40.0,
+        };
+
+        let decades = (required_js_db - js_at_reference) / slope_db_per_decade;
+        reference_range_m * 10f64.powf(decades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scenario(geometry: JammerGeometry) -> RadarJammingScenario {
+        RadarJammingScenario {
+            radar_position: Vector3::zeros(),
+            radar_peak_power_dbm: 70.0, // 10 kW
+            radar_antenna_gain_dbi: 35.0,
+            target_rcs_m2: 5.0,
+            radar_bandwidth_hz: 1e6,
+            center_frequency_hz: 10e9,
+            jammer: RadarJammerParams {
+                power_dbm: 40.0, // 10 W
+                antenna_gain_dbi: 10.0,
+                bandwidth_hz: 1e6,
+            },
+            geometry,
+        }
+    }
+
+    #[test]
+    fn test_echo_power_falls_off_as_inverse_fourth_power() {
+        let s = scenario(JammerGeometry::SelfScreening);
+        let p1 = s.echo_power_dbm(10_000.0);
+        let p2 = s.echo_power_dbm(20_000.0);
+        // Doubling range should drop echo power by ~40*log10(2) ≈ 12 dB.
+        assert!((p1 - p2 - 12.04).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_jammer_power_falls_off_as_inverse_square_for_standoff() {
+        let s = scenario(JammerGeometry::Standoff {
+            jammer_position: Vector3::new(0.0, 20_000.0, 0.0),
+        });
+        // Standoff jammer range is independent of target range.
+        let j1 = s.jammer_power_dbm(10_000.0);
+        let j2 = s.jammer_power_dbm(50_000.0);
+        assert!((j1 - j2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_self_screening_js_decreases_as_target_closes_in() {
+        // Self-screening: jammer range == target range, so as the target
+        // closes, J/S falls (echo's R^-4 beats jamming's R^-2).
+        let s = scenario(JammerGeometry::SelfScreening);
+        assert!(s.js_db(5_000.0) < s.js_db(50_000.0));
+    }
+
+    #[test]
+    fn test_standoff_js_decreases_as_target_closes_in() {
+        let s = scenario(JammerGeometry::Standoff {
+            jammer_position: Vector3::new(0.0, 30_000.0, 0.0),
+        });
+        assert!(s.js_db(5_000.0) < s.js_db(50_000.0));
+    }
+
+    #[test]
+    fn test_burn_through_range_matches_profile_crossing() {
+        let s = scenario(JammerGeometry::SelfScreening);
+        let required_js_db = jsr_marginal();
+        let burn_through = s.burn_through_range_m(required_js_db);
+
+        assert!((s.js_db(burn_through) - required_js_db).abs() < 0.05);
+        // Just inside burn-through range, J/S should have dropped below
+        // the threshold (echo has overtaken jamming).
+        assert!(s.js_db(burn_through * 0.9) < required_js_db);
+        // Just outside, J/S should still exceed it (jamming still masks
+        // the echo).
+        assert!(s.js_db(burn_through * 1.1) > required_js_db);
+    }
+
+    fn jsr_marginal() -> f64 {
+        crate::jamming::jsr_thresholds::MARGINAL
+    }
+
+    #[test]
+    fn test_js_profile_pairs_ranges_with_js_db() {
+        let s = scenario(JammerGeometry::SelfScreening);
+        let ranges = [1000.0, 5000.0, 20_000.0];
+        let profile = s.js_profile(&ranges);
+
+        assert_eq!(profile.len(), 3);
+        for (i, &range_m) in ranges.iter().enumerate() {
+            assert_eq!(profile[i].0, range_m);
+            assert_eq!(profile[i].1, s.js_db(range_m));
+        }
+    }
+}
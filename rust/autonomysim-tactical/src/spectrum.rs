@@ -6,11 +6,78 @@
 //! - Frequency hopping pattern generation
 //! - Spectrum occupancy tracking
 
+use nalgebra::Vector3;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 use crate::network::AgentId;
 
+/// A LoRaWAN-style chirp-spread-spectrum modulation setting: a spreading
+/// factor trades data rate for the SNR needed to demodulate, and a code
+/// rate trades data rate for forward-error-correction overhead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LoraModulation {
+    /// Spreading factor, `SF7..=SF12`. Higher values spread each symbol
+    /// over more chirps -- slower, but demodulable at a lower SNR.
+    pub spreading_factor: u8,
+
+    /// Code rate denominator offset: the code rate is `4/(4 + offset)`,
+    /// i.e. `offset` of `1..=4` gives `4/5 .. 4/8`.
+    pub code_rate_offset: u8,
+}
+
+impl LoraModulation {
+    /// Create a modulation setting, clamping `spreading_factor` to
+    /// `7..=12` and `code_rate_offset` to `1..=4`.
+    pub fn new(spreading_factor: u8, code_rate_offset: u8) -> Self {
+        Self {
+            spreading_factor: spreading_factor.clamp(7, 12),
+            code_rate_offset: code_rate_offset.clamp(1, 4),
+        }
+    }
+
+    /// Forward-error-correction code rate, `4/(4 + code_rate_offset)`.
+    pub fn code_rate(&self) -> f64 {
+        4.0 / (4.0 + self.code_rate_offset as f64)
+    }
+
+    /// Demodulation floor (dB): roughly `-7.5` dB at SF7, easing by
+    /// `~2.5` dB per spreading-factor step as SF increases.
+    pub fn required_snr_db(&self) -> f64 {
+        -7.5 - 2.5 * (self.spreading_factor as f64 - 7.0)
+    }
+
+    /// Chirp-spread-spectrum data rate (bits/s) over `bandwidth_hz`:
+    /// `R = SF * code_rate * bandwidth_hz / 2^SF`.
+    pub fn achievable_bitrate(&self, bandwidth_hz: f64) -> f64 {
+        self.spreading_factor as f64 * self.code_rate() * bandwidth_hz
+            / 2f64.powi(self.spreading_factor as i32)
+    }
+
+    /// The lowest (fastest) spreading factor in `7..=12` whose
+    /// [`Self::required_snr_db`] the link still clears at `sinr_db`, kept
+    /// at this instance's `code_rate_offset`. `required_snr_db` only gets
+    /// easier to clear as SF rises, so the usable set is a suffix of
+    /// `7..=12` and its lowest member is also its highest-throughput
+    /// member (bitrate falls monotonically with SF) -- the fastest
+    /// setting that still closes the link, trading throughput for
+    /// robustness only as far as the SINR actually demands. `None` if
+    /// even SF12 can't close the link at `sinr_db`.
+    pub fn best_closing_spreading_factor(sinr_db: f64, code_rate_offset: u8) -> Option<u8> {
+        (7..=12u8).find(|&sf| Self::new(sf, code_rate_offset).required_snr_db() <= sinr_db)
+    }
+}
+
+impl Default for LoraModulation {
+    /// SF7, code rate 4/5 -- the fastest, least robust setting.
+    fn default() -> Self {
+        Self {
+            spreading_factor: 7,
+            code_rate_offset: 1,
+        }
+    }
+}
+
 /// Frequency channel
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Channel {
@@ -22,18 +89,34 @@ pub struct Channel {
 
     /// Channel bandwidth (Hz)
     pub bandwidth_hz: u64,
+
+    /// Modulation/data-rate setting in use on this channel
+    pub modulation: LoraModulation,
 }
 
 impl Channel {
-    /// Create a new channel
+    /// Create a new channel, defaulting to SF7/4:5 modulation
     pub fn new(id: usize, frequency_hz: u64, bandwidth_hz: u64) -> Self {
         Self {
             id,
             frequency_hz,
             bandwidth_hz,
+            modulation: LoraModulation::default(),
         }
     }
 
+    /// Return this channel with `modulation` in place of its default
+    pub fn with_modulation(mut self, modulation: LoraModulation) -> Self {
+        self.modulation = modulation;
+        self
+    }
+
+    /// Achievable data rate (bits/s) at this channel's bandwidth and
+    /// modulation setting; see [`LoraModulation::achievable_bitrate`].
+    pub fn achievable_bitrate(&self) -> f64 {
+        self.modulation.achievable_bitrate(self.bandwidth_hz as f64)
+    }
+
     /// Check if this channel overlaps with another
     pub fn overlaps(&self, other: &Channel) -> bool {
         let self_min = self.frequency_hz - self.bandwidth_hz / 2;
@@ -70,6 +153,54 @@ pub struct FrequencyAllocation {
     pub tx_power_dbm: f64,
 }
 
+/// How [`SpectrumManager::request_handoff`] releases an agent's old
+/// channel once it has moved to a new one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HandoffPolicy {
+    /// Keep the old channel allocated alongside the new one -- both show
+    /// up in `channel_usage` -- for `overlap_window_s` seconds of
+    /// simulation time, so in-flight packets on the old channel aren't
+    /// dropped mid-switch. [`SpectrumManager::update_time`] releases it
+    /// once that window elapses.
+    Overlap { overlap_window_s: f64 },
+    /// Release the old channel the instant the handoff happens.
+    Eager,
+}
+
+/// Tunables governing [`SpectrumManager::request_handoff`] and
+/// [`SpectrumManager::record_interference_reading`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HandoffConfig {
+    /// How the old channel is released once a handoff completes.
+    pub policy: HandoffPolicy,
+    /// Minimum time (s) an agent must hold its current channel before it
+    /// can hand off again, checked against that allocation's
+    /// `allocation_time` and the manager's current time -- prevents
+    /// ping-ponging between channels under fluctuating interference.
+    pub min_dwell_time_s: f64,
+    /// Consecutive above-threshold readings
+    /// [`SpectrumManager::record_interference_reading`] requires before
+    /// reporting that a handoff should be requested.
+    pub min_consecutive_interference_readings: u32,
+}
+
+impl Default for HandoffConfig {
+    fn default() -> Self {
+        Self {
+            policy: HandoffPolicy::Eager,
+            min_dwell_time_s: 0.0,
+            min_consecutive_interference_readings: 1,
+        }
+    }
+}
+
+/// An old channel an agent still holds past a handoff, awaiting release
+/// once `release_at` (simulation time) is reached.
+struct PendingRelease {
+    channel_id: usize,
+    release_at: f64,
+}
+
 /// Spectrum manager
 pub struct SpectrumManager {
     /// Available channels
@@ -86,6 +217,16 @@ pub struct SpectrumManager {
 
     /// Minimum frequency separation for interference-free operation (Hz)
     min_separation_hz: u64,
+
+    /// Handoff scheduler tunables
+    handoff_config: HandoffConfig,
+
+    /// Old channels awaiting release under [`HandoffPolicy::Overlap`]
+    pending_releases: HashMap<AgentId, PendingRelease>,
+
+    /// Consecutive above-threshold interference readings per agent, for
+    /// [`Self::record_interference_reading`]'s hysteresis
+    interference_streaks: HashMap<AgentId, u32>,
 }
 
 impl SpectrumManager {
@@ -97,9 +238,17 @@ impl SpectrumManager {
             channel_usage: HashMap::new(),
             current_time: 0.0,
             min_separation_hz: 5_000_000, // 5 MHz default
+            handoff_config: HandoffConfig::default(),
+            pending_releases: HashMap::new(),
+            interference_streaks: HashMap::new(),
         }
     }
 
+    /// Replace the handoff scheduler's tunables
+    pub fn set_handoff_config(&mut self, config: HandoffConfig) {
+        self.handoff_config = config;
+    }
+
     /// Create a spectrum manager with equally spaced channels
     pub fn with_uniform_channels(
         start_frequency_hz: u64,
@@ -123,9 +272,23 @@ impl SpectrumManager {
         Self::new(channels)
     }
 
-    /// Update simulation time
+    /// Update simulation time, releasing any channels an
+    /// [`HandoffPolicy::Overlap`] handoff is still holding onto past their
+    /// `release_at`.
     pub fn update_time(&mut self, time: f64) {
         self.current_time = time;
+
+        let due: Vec<(AgentId, usize)> = self
+            .pending_releases
+            .iter()
+            .filter(|(_, pending)| pending.release_at <= self.current_time)
+            .map(|(&agent_id, pending)| (agent_id, pending.channel_id))
+            .collect();
+
+        for (agent_id, channel_id) in due {
+            self.release_agent_from_channel(channel_id, agent_id);
+            self.pending_releases.remove(&agent_id);
+        }
     }
 
     /// Allocate a channel to an agent
@@ -163,13 +326,94 @@ impl SpectrumManager {
     /// Deallocate channel from agent
     pub fn deallocate_channel(&mut self, agent_id: AgentId) {
         if let Some(allocation) = self.allocations.remove(&agent_id) {
-            if let Some(users) = self.channel_usage.get_mut(&allocation.channel.id) {
-                users.remove(&agent_id);
-                if users.is_empty() {
-                    self.channel_usage.remove(&allocation.channel.id);
-                }
+            self.release_agent_from_channel(allocation.channel.id, agent_id);
+        }
+        self.pending_releases.remove(&agent_id);
+        self.interference_streaks.remove(&agent_id);
+    }
+
+    /// Remove `agent_id` from `channel_id`'s usage set, dropping the set
+    /// entirely once empty.
+    fn release_agent_from_channel(&mut self, channel_id: usize, agent_id: AgentId) {
+        if let Some(users) = self.channel_usage.get_mut(&channel_id) {
+            users.remove(&agent_id);
+            if users.is_empty() {
+                self.channel_usage.remove(&channel_id);
+            }
+        }
+    }
+
+    /// Record an interference reading for `agent_id`, resetting its streak
+    /// if `interference_mw` is at or below `threshold_mw`. Returns `true`
+    /// once `handoff_config.min_consecutive_interference_readings`
+    /// consecutive above-threshold readings have accumulated, signalling
+    /// that the caller should now call [`Self::request_handoff`].
+    pub fn record_interference_reading(
+        &mut self,
+        agent_id: AgentId,
+        interference_mw: f64,
+        threshold_mw: f64,
+    ) -> bool {
+        let streak = self.interference_streaks.entry(agent_id).or_insert(0);
+        if interference_mw > threshold_mw {
+            *streak += 1;
+        } else {
+            *streak = 0;
+        }
+
+        *streak >= self.handoff_config.min_consecutive_interference_readings
+    }
+
+    /// Hand `agent_id` off from its current channel to `new_channel_id`,
+    /// honoring `handoff_config.min_dwell_time_s`: refuses (returning
+    /// `false`, no state changed) if `agent_id` has held its current
+    /// channel for less than that, or if it has no current allocation, or
+    /// if `new_channel_id` doesn't exist. On success the new channel is
+    /// allocated at the same transmit power, the interference hysteresis
+    /// streak is cleared, and the old channel is released per
+    /// `handoff_config.policy` -- immediately for [`HandoffPolicy::Eager`],
+    /// or after `overlap_window_s` (via [`Self::update_time`]) for
+    /// [`HandoffPolicy::Overlap`], during which both channels appear in
+    /// `channel_usage`.
+    pub fn request_handoff(&mut self, agent_id: AgentId, new_channel_id: usize) -> bool {
+        let Some(current) = self.allocations.get(&agent_id) else {
+            return false;
+        };
+
+        if current.channel.id == new_channel_id {
+            return false;
+        }
+
+        if self.current_time - current.allocation_time < self.handoff_config.min_dwell_time_s {
+            return false;
+        }
+
+        let old_channel_id = current.channel.id;
+        let tx_power_dbm = current.tx_power_dbm;
+
+        if !self.allocate_channel(agent_id, new_channel_id, tx_power_dbm) {
+            return false;
+        }
+
+        match self.handoff_config.policy {
+            HandoffPolicy::Eager => self.release_agent_from_channel(old_channel_id, agent_id),
+            HandoffPolicy::Overlap { overlap_window_s } => {
+                self.channel_usage
+                    .entry(old_channel_id)
+                    .or_insert_with(HashSet::new)
+                    .insert(agent_id);
+                self.pending_releases.insert(
+                    agent_id,
+                    PendingRelease {
+                        channel_id: old_channel_id,
+                        release_at: self.current_time + overlap_window_s,
+                    },
+                );
             }
         }
+
+        self.interference_streaks.remove(&agent_id);
+        true
     }
 
     /// Get allocation for agent
@@ -190,47 +434,108 @@ impl SpectrumManager {
             .copied()
     }
 
-    /// Find channel with minimum interference for agent
-    /// Returns channel ID and estimated interference level
-    pub fn find_best_channel(&self, _agent_id: AgentId) -> Option<(Channel, f64)> {
-        let mut best_channel = None;
-        let mut min_interference = f64::INFINITY;
+    /// Find the channel that gives `agent_id` the highest SINR, given where
+    /// every allocated agent (including `agent_id` itself, at `tx_power_dbm`)
+    /// physically sits, then set that channel's modulation to the fastest
+    /// spreading factor the resulting SINR still closes the link at (see
+    /// [`LoraModulation::best_closing_spreading_factor`]) -- so the agent
+    /// automatically falls back to a slower, more robust spreading factor
+    /// as its SINR degrades, rather than fixing one for every condition.
+    /// Returns the (re-modulated) channel and its SINR (dB), or `None` if
+    /// no channel both has an SINR and can close the link even at SF12.
+    pub fn find_best_channel(
+        &self,
+        agent_id: AgentId,
+        tx_power_dbm: f64,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+        noise_floor_dbm: f64,
+    ) -> Option<(Channel, f64)> {
+        self.channels
+            .iter()
+            .filter_map(|channel| {
+                self.compute_sinr(agent_id, *channel, tx_power_dbm, positions, noise_floor_dbm)
+                    .map(|sinr_db| (*channel, sinr_db))
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .and_then(|(channel, sinr_db)| {
+                let sf = LoraModulation::best_closing_spreading_factor(
+                    sinr_db,
+                    channel.modulation.code_rate_offset,
+                )?;
+                let modulation = LoraModulation::new(sf, channel.modulation.code_rate_offset);
+                Some((channel.with_modulation(modulation), sinr_db))
+            })
+    }
 
-        for channel in &self.channels {
-            let interference = self.compute_channel_interference(*channel);
+    /// SINR (dB) `agent_id` would see on `channel`, transmitting at
+    /// `tx_power_dbm` from its position in `positions`.
+    ///
+    /// `tx_power_dbm` stands in for `agent_id`'s own desired signal level --
+    /// this module tracks per-agent channel allocations, not point-to-point
+    /// links, so there's no separate transmitter/receiver pair to apply
+    /// path loss to for the signal of interest. Every *other* allocated
+    /// agent on an overlapping channel, however, is real interference, and
+    /// its contribution is attenuated by free-space path loss over the
+    /// distance to `agent_id`. Returns `None` if `agent_id` has no entry in
+    /// `positions`.
+    pub fn compute_sinr(
+        &self,
+        agent_id: AgentId,
+        channel: Channel,
+        tx_power_dbm: f64,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+        noise_floor_dbm: f64,
+    ) -> Option<f64> {
+        positions.get(&agent_id)?;
 
-            if interference < min_interference {
-                min_interference = interference;
-                best_channel = Some(*channel);
-            }
-        }
+        let signal_mw = dbm_to_mw(tx_power_dbm);
+        let interference_mw = self.compute_channel_interference_mw(channel, agent_id, positions);
+        let noise_mw = dbm_to_mw(noise_floor_dbm);
 
-        best_channel.map(|c| (c, min_interference))
+        Some(mw_to_dbm(signal_mw / (interference_mw + noise_mw)))
     }
 
-    /// Compute total interference on a channel
-    fn compute_channel_interference(&self, channel: Channel) -> f64 {
-        let mut total_interference = 0.0;
-
-        // Count agents using this channel or overlapping channels
-        for (ch_id, users) in &self.channel_usage {
-            if let Some(ch) = self.channels.iter().find(|c| c.id == *ch_id) {
-                if ch.overlaps(&channel) {
-                    // Weight interference by number of users and frequency overlap
-                    let separation = ch.frequency_separation(&channel);
-                    let weight = if separation == 0 {
-                        1.0 // Same channel
-                    } else {
-                        // Adjacent channel interference reduces with separation
-                        (self.min_separation_hz as f64 / separation as f64).min(1.0)
-                    };
-
-                    total_interference += users.len() as f64 * weight;
-                }
-            }
-        }
+    /// Aggregate interference power (mW) `receiver_id` sees on `channel`
+    /// from every other allocated agent on an overlapping channel: each
+    /// interferer's received power `P_rx = P_tx - PL(d)` (free-space path
+    /// loss, `PL(d) = 20*log10(d) + 20*log10(f_hz) - 147.55`) is converted
+    /// to mW, then weighted by the same frequency-overlap factor
+    /// [`Self::find_best_channel`]'s predecessor used (`1.0` co-channel,
+    /// decaying with separation otherwise). `0.0` if `receiver_id` has no
+    /// entry in `positions`.
+    pub fn compute_channel_interference_mw(
+        &self,
+        channel: Channel,
+        receiver_id: AgentId,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+    ) -> f64 {
+        let receiver_position = match positions.get(&receiver_id) {
+            Some(&position) => position,
+            None => return 0.0,
+        };
 
-        total_interference
+        self.allocations
+            .values()
+            .filter(|allocation| allocation.agent_id != receiver_id)
+            .filter(|allocation| allocation.channel.overlaps(&channel))
+            .filter_map(|allocation| {
+                let interferer_position = *positions.get(&allocation.agent_id)?;
+                let distance_m = (interferer_position - receiver_position).norm().max(1.0);
+                let path_loss_db = 20.0 * distance_m.log10()
+                    + 20.0 * (channel.frequency_hz as f64).log10()
+                    - 147.55;
+                let received_mw = dbm_to_mw(allocation.tx_power_dbm - path_loss_db);
+
+                let separation = allocation.channel.frequency_separation(&channel);
+                let weight = if separation == 0 {
+                    1.0
+                } else {
+                    (self.min_separation_hz as f64 / separation as f64).min(1.0)
+                };
+
+                Some(received_mw * weight)
+            })
+            .sum()
     }
 
     /// Get channel utilization (fraction of channels in use)
@@ -259,6 +564,71 @@ impl SpectrumManager {
     /// Generate frequency hopping pattern (pseudo-random sequence)
     /// Returns sequence of channel IDs
     pub fn generate_hopping_pattern(&self, seed: u64, length: usize) -> Vec<usize> {
+        self.base_hop_sequence(seed, length)
+            .into_iter()
+            .map(|channel_idx| self.channels[channel_idx].id)
+            .collect()
+    }
+
+    /// `num_networks` hop sequences of `length` slots each, guaranteed
+    /// collision-free against one another when `num_networks <=
+    /// num_channels` -- unlike independently-seeded calls to
+    /// [`Self::generate_hopping_pattern`], which collide on roughly `1/N`
+    /// of slots by chance. Latin-square construction: every network shares
+    /// the same base pseudo-random sequence `a_t` ([`Self::base_hop_sequence`],
+    /// seeded by `seed`), and network `k` hops to channel `(a_t + k) mod N`
+    /// at step `t`. Two networks `i != j` then differ at every step by the
+    /// constant `(i - j) mod N`, which is nonzero whenever both are `< N`,
+    /// so no two ever land on the same channel at the same step.
+    pub fn generate_orthogonal_patterns(
+        &self,
+        seed: u64,
+        num_networks: usize,
+        length: usize,
+    ) -> Vec<Vec<usize>> {
+        let num_channels = self.channels.len();
+        if num_channels == 0 {
+            return vec![Vec::new(); num_networks];
+        }
+
+        let base_sequence = self.base_hop_sequence(seed, length);
+
+        (0..num_networks)
+            .map(|k| {
+                base_sequence
+                    .iter()
+                    .map(|&a_t| self.channels[(a_t + k) % num_channels].id)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// [`Self::generate_orthogonal_patterns`] for a single network, deriving
+    /// its Latin-square offset deterministically from `agent_id` so
+    /// co-located agents don't need to coordinate offsets out of band.
+    pub fn generate_orthogonal_pattern_for_agent(
+        &self,
+        agent_id: AgentId,
+        seed: u64,
+        length: usize,
+    ) -> Vec<usize> {
+        let num_channels = self.channels.len();
+        if num_channels == 0 {
+            return Vec::new();
+        }
+
+        let offset = agent_id % num_channels;
+        self.base_hop_sequence(seed, length)
+            .into_iter()
+            .map(|a_t| self.channels[(a_t + offset) % num_channels].id)
+            .collect()
+    }
+
+    /// Shared base pseudo-random permutation indices `a_t` (each in
+    /// `0..num_channels`, an LCG seeded by `seed` and hashed for better
+    /// distribution) underlying [`Self::generate_hopping_pattern`] and the
+    /// orthogonal-pattern constructors.
+    fn base_hop_sequence(&self, seed: u64, length: usize) -> Vec<usize> {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
@@ -267,7 +637,7 @@ impl SpectrumManager {
             return Vec::new();
         }
 
-        let mut pattern = Vec::with_capacity(length);
+        let mut sequence = Vec::with_capacity(length);
         let mut state = seed;
 
         for _ in 0..length {
@@ -279,18 +649,186 @@ impl SpectrumManager {
             state.hash(&mut hasher);
             let hash = hasher.finish();
 
-            let channel_idx = (hash % num_channels as u64) as usize;
-            pattern.push(self.channels[channel_idx].id);
+            sequence.push((hash % num_channels as u64) as usize);
         }
 
-        pattern
+        sequence
     }
 
-    /// Check if allocation would cause excessive interference
-    pub fn would_cause_interference(&self, channel: Channel, max_interference: f64) -> bool {
-        let current_interference = self.compute_channel_interference(channel);
-        current_interference >= max_interference
+    /// Check whether `receiver_id` allocating onto `channel` would see
+    /// interference at or above `max_interference_mw`.
+    pub fn would_cause_interference(
+        &self,
+        channel: Channel,
+        receiver_id: AgentId,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+        max_interference_mw: f64,
+    ) -> bool {
+        self.compute_channel_interference_mw(channel, receiver_id, positions) >= max_interference_mw
+    }
+
+    /// Solve network-wide channel assignment as graph coloring: channels
+    /// are colors, and an edge in `conflict_graph` means the two agents sit
+    /// close enough to interfere if co-channel. Unlike
+    /// [`Self::find_best_channel`], which optimizes one agent at a time and
+    /// can starve a later agent of any clean channel, this looks at the
+    /// whole conflict graph at once.
+    ///
+    /// Uses DSATUR: repeatedly pick the uncolored agent with the highest
+    /// *saturation degree* (count of distinct channels its neighbors
+    /// already hold), breaking ties by highest plain degree (most
+    /// neighbors), and assign it the lowest-id channel none of its
+    /// already-colored neighbors hold. If every channel is already taken by
+    /// some neighbor -- more mutually-adjacent agents than channels -- fall
+    /// back to whichever channel minimizes received interference (by
+    /// free-space path loss, at a nominal transmit power) among that
+    /// agent's already-colored neighbors.
+    ///
+    /// Returns the full agent-to-channel-id mapping; callers apply it via
+    /// [`Self::allocate_channel`].
+    pub fn assign_channels(
+        &self,
+        conflict_graph: &HashMap<AgentId, HashSet<AgentId>>,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+    ) -> HashMap<AgentId, usize> {
+        let mut channel_ids: Vec<usize> = self.channels.iter().map(|c| c.id).collect();
+        channel_ids.sort_unstable();
+        if channel_ids.is_empty() {
+            return HashMap::new();
+        }
+
+        let no_neighbors = HashSet::new();
+        let neighbors_of = |agent_id: &AgentId| -> &HashSet<AgentId> {
+            conflict_graph.get(agent_id).unwrap_or(&no_neighbors)
+        };
+
+        let mut uncolored: HashSet<AgentId> = conflict_graph.keys().copied().collect();
+        let mut assignment: HashMap<AgentId, usize> = HashMap::new();
+
+        while let Some(&next) = uncolored.iter().max_by_key(|agent_id| {
+            let neighbor_colors: HashSet<usize> = neighbors_of(agent_id)
+                .iter()
+                .filter_map(|neighbor| assignment.get(neighbor))
+                .copied()
+                .collect();
+            (neighbor_colors.len(), neighbors_of(agent_id).len())
+        }) {
+            uncolored.remove(&next);
+
+            let neighbor_channel_ids: HashSet<usize> = neighbors_of(&next)
+                .iter()
+                .filter_map(|neighbor| assignment.get(neighbor))
+                .copied()
+                .collect();
+
+            let channel_id = channel_ids
+                .iter()
+                .find(|id| !neighbor_channel_ids.contains(id))
+                .copied()
+                .unwrap_or_else(|| {
+                    self.least_interfering_channel_among_neighbors(
+                        next,
+                        neighbors_of(&next),
+                        &assignment,
+                        positions,
+                    )
+                });
+
+            assignment.insert(next, channel_id);
+        }
+
+        assignment
     }
+
+    /// The channel (by id) minimizing free-space-path-loss interference at
+    /// `agent_id`'s position from `neighbors` already present in
+    /// `assignment`, at a nominal 20 dBm transmit power -- used as
+    /// [`Self::assign_channels`]'s DSATUR fallback once no conflict-free
+    /// channel remains. This doesn't reuse
+    /// [`Self::compute_channel_interference_mw`], which sums over *all*
+    /// agents currently in `self.allocations`: here the assignment being
+    /// built hasn't been applied via [`Self::allocate_channel`] yet, so the
+    /// only relevant interferers are the conflict graph's already-colored
+    /// neighbors.
+    fn least_interfering_channel_among_neighbors(
+        &self,
+        agent_id: AgentId,
+        neighbors: &HashSet<AgentId>,
+        assignment: &HashMap<AgentId, usize>,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+    ) -> usize {
+        const NOMINAL_TX_POWER_DBM: f64 = 20.0;
+
+        self.channels
+            .iter()
+            .min_by(|a, b| {
+                let interference_a = self.neighbor_interference_mw(
+                    agent_id,
+                    **a,
+                    neighbors,
+                    assignment,
+                    positions,
+                    NOMINAL_TX_POWER_DBM,
+                );
+                let interference_b = self.neighbor_interference_mw(
+                    agent_id,
+                    **b,
+                    neighbors,
+                    assignment,
+                    positions,
+                    NOMINAL_TX_POWER_DBM,
+                );
+                interference_a.partial_cmp(&interference_b).unwrap()
+            })
+            .map(|channel| channel.id)
+            .unwrap()
+    }
+
+    /// Interference (mW) `agent_id` would see on `channel` from `neighbors`
+    /// that already hold a channel in `assignment`, via free-space path
+    /// loss from a nominal `tx_power_dbm` transmitter.
+    fn neighbor_interference_mw(
+        &self,
+        agent_id: AgentId,
+        channel: Channel,
+        neighbors: &HashSet<AgentId>,
+        assignment: &HashMap<AgentId, usize>,
+        positions: &HashMap<AgentId, Vector3<f64>>,
+        tx_power_dbm: f64,
+    ) -> f64 {
+        let Some(&agent_position) = positions.get(&agent_id) else {
+            return 0.0;
+        };
+
+        neighbors
+            .iter()
+            .filter_map(|neighbor| {
+                let neighbor_channel_id = *assignment.get(neighbor)?;
+                let neighbor_channel =
+                    self.channels.iter().find(|c| c.id == neighbor_channel_id)?;
+                if !neighbor_channel.overlaps(&channel) {
+                    return None;
+                }
+
+                let neighbor_position = *positions.get(neighbor)?;
+                let distance_m = (neighbor_position - agent_position).norm().max(1.0);
+                let path_loss_db = 20.0 * distance_m.log10()
+                    + 20.0 * (channel.frequency_hz as f64).log10()
+                    - 147.55;
+                Some(dbm_to_mw(tx_power_dbm - path_loss_db))
+            })
+            .sum()
+    }
+}
+
+#[inline]
+fn dbm_to_mw(dbm: f64) -> f64 {
+    10.0_f64.powf(dbm / 10.0)
+}
+
+#[inline]
+fn mw_to_dbm(mw: f64) -> f64 {
+    10.0 * mw.log10()
 }
 
 impl Default for SpectrumManager {
@@ -379,6 +917,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_orthogonal_patterns_never_collide_per_slot() {
+        let manager = SpectrumManager::default();
+        let num_channels = manager.get_channels().len();
+
+        let patterns = manager.generate_orthogonal_patterns(12345, num_channels, 50);
+        assert_eq!(patterns.len(), num_channels);
+
+        for t in 0..50 {
+            let mut channels_at_t: Vec<usize> = patterns.iter().map(|p| p[t]).collect();
+            channels_at_t.sort_unstable();
+            channels_at_t.dedup();
+            assert_eq!(
+                channels_at_t.len(),
+                num_channels,
+                "networks collided on channel at slot {t}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_orthogonal_pattern_for_agent_matches_network_indexed_variant() {
+        let manager = SpectrumManager::default();
+        let patterns = manager.generate_orthogonal_patterns(999, 3, 20);
+
+        for agent_id in 0..3 {
+            assert_eq!(
+                manager.generate_orthogonal_pattern_for_agent(agent_id, 999, 20),
+                patterns[agent_id]
+            );
+        }
+    }
+
     #[test]
     fn test_uniform_channels() {
         let manager = SpectrumManager::with_uniform_channels(
@@ -397,4 +968,263 @@ mod tests {
             assert_eq!(spacing, 25_000_000); // 20 MHz + 5 MHz guard
         }
     }
+
+    #[test]
+    fn test_sinr_improves_with_interferer_distance() {
+        let channels = vec![Channel::new(0, 2_400_000_000, 20_000_000)];
+        let mut manager = SpectrumManager::new(channels);
+
+        manager.allocate_channel(1, 0, 20.0);
+        let channel = manager.get_channels()[0];
+
+        let mut positions = HashMap::new();
+        positions.insert(0, Vector3::new(0.0, 0.0, 0.0));
+        positions.insert(1, Vector3::new(100.0, 0.0, 0.0));
+
+        let sinr_near = manager
+            .compute_sinr(0, channel, 20.0, &positions, -100.0)
+            .unwrap();
+
+        positions.insert(1, Vector3::new(10_000.0, 0.0, 0.0));
+        let sinr_far = manager
+            .compute_sinr(0, channel, 20.0, &positions, -100.0)
+            .unwrap();
+
+        assert!(sinr_far > sinr_near);
+    }
+
+    #[test]
+    fn test_sinr_none_without_receiver_position() {
+        let channels = vec![Channel::new(0, 2_400_000_000, 20_000_000)];
+        let manager = SpectrumManager::new(channels);
+        let channel = manager.get_channels()[0];
+
+        let positions = HashMap::new();
+        assert!(manager
+            .compute_sinr(0, channel, 20.0, &positions, -100.0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_find_best_channel_avoids_co_located_interferer() {
+        let channels = vec![
+            Channel::new(0, 2_400_000_000, 20_000_000),
+            Channel::new(1, 2_440_000_000, 20_000_000),
+        ];
+        let mut manager = SpectrumManager::new(channels);
+        manager.allocate_channel(1, 0, 20.0);
+
+        let mut positions = HashMap::new();
+        positions.insert(0, Vector3::new(0.0, 0.0, 0.0));
+        positions.insert(1, Vector3::new(50.0, 0.0, 0.0));
+
+        let (best_channel, _sinr_db) = manager
+            .find_best_channel(0, 20.0, &positions, -100.0)
+            .unwrap();
+        assert_eq!(best_channel.id, 1); // channel 0 has the nearby interferer
+    }
+
+    #[test]
+    fn test_required_snr_db_eases_by_2_5_db_per_spreading_factor_step() {
+        let sf7 = LoraModulation::new(7, 1).required_snr_db();
+        let sf8 = LoraModulation::new(8, 1).required_snr_db();
+        assert!((sf7 - (-7.5)).abs() < 1e-9);
+        assert!((sf7 - sf8 - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_achievable_bitrate_falls_as_spreading_factor_rises() {
+        let fast = LoraModulation::new(7, 1).achievable_bitrate(125_000.0);
+        let slow = LoraModulation::new(12, 1).achievable_bitrate(125_000.0);
+        assert!(fast > slow);
+    }
+
+    #[test]
+    fn test_achievable_bitrate_falls_as_code_rate_drops() {
+        let high_rate = LoraModulation::new(7, 1).achievable_bitrate(125_000.0);
+        let low_rate = LoraModulation::new(7, 4).achievable_bitrate(125_000.0);
+        assert!(high_rate > low_rate);
+    }
+
+    #[test]
+    fn test_best_closing_spreading_factor_picks_fastest_that_still_closes() {
+        // Strong link: even SF7's -7.5 dB floor is cleared, so the fastest
+        // setting should win.
+        assert_eq!(
+            LoraModulation::best_closing_spreading_factor(0.0, 1),
+            Some(7)
+        );
+
+        // Marginal link: only the higher spreading factors' lower floors
+        // are cleared.
+        assert_eq!(
+            LoraModulation::best_closing_spreading_factor(-15.0, 1),
+            Some(9)
+        );
+
+        // Link too poor for even SF12's -20 dB floor.
+        assert_eq!(
+            LoraModulation::best_closing_spreading_factor(-25.0, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_best_channel_falls_back_to_robust_modulation_on_weak_link() {
+        let channels = vec![Channel::new(0, 2_400_000_000, 20_000_000)];
+        let manager = SpectrumManager::new(channels);
+
+        let mut positions = HashMap::new();
+        positions.insert(0, Vector3::new(0.0, 0.0, 0.0));
+
+        // SINR of -12 dB is below SF7/SF8's demodulation floors but clears
+        // SF9's, so the fastest closing setting should be SF9, not SF7.
+        let (channel, sinr_db) = manager
+            .find_best_channel(0, -100.0, &positions, -88.0)
+            .unwrap();
+        assert!(channel.modulation.required_snr_db() <= sinr_db);
+        assert_eq!(channel.modulation.spreading_factor, 9);
+    }
+
+    fn two_channel_manager() -> SpectrumManager {
+        SpectrumManager::new(vec![
+            Channel::new(0, 2_400_000_000, 20_000_000),
+            Channel::new(1, 2_440_000_000, 20_000_000),
+        ])
+    }
+
+    #[test]
+    fn test_request_handoff_respects_min_dwell_time() {
+        let mut manager = two_channel_manager();
+        manager.set_handoff_config(HandoffConfig {
+            min_dwell_time_s: 10.0,
+            ..HandoffConfig::default()
+        });
+
+        manager.allocate_channel(0, 0, 20.0);
+        manager.update_time(5.0);
+
+        // Only 5s dwelled, needs 10s -- handoff refused.
+        assert!(!manager.request_handoff(0, 1));
+        assert_eq!(manager.get_allocation(0).unwrap().channel.id, 0);
+
+        manager.update_time(10.0);
+        assert!(manager.request_handoff(0, 1));
+        assert_eq!(manager.get_allocation(0).unwrap().channel.id, 1);
+    }
+
+    #[test]
+    fn test_eager_handoff_releases_old_channel_immediately() {
+        let mut manager = two_channel_manager();
+        manager.set_handoff_config(HandoffConfig {
+            policy: HandoffPolicy::Eager,
+            ..HandoffConfig::default()
+        });
+
+        manager.allocate_channel(0, 0, 20.0);
+        assert!(manager.request_handoff(0, 1));
+
+        assert_eq!(manager.get_channel_load(0), 0);
+        assert_eq!(manager.get_channel_load(1), 1);
+    }
+
+    #[test]
+    fn test_overlap_handoff_keeps_old_channel_until_window_elapses() {
+        let mut manager = two_channel_manager();
+        manager.set_handoff_config(HandoffConfig {
+            policy: HandoffPolicy::Overlap {
+                overlap_window_s: 3.0,
+            },
+            ..HandoffConfig::default()
+        });
+
+        manager.allocate_channel(0, 0, 20.0);
+        assert!(manager.request_handoff(0, 1));
+
+        // Both channels show the agent during the overlap window.
+        assert_eq!(manager.get_channel_load(0), 1);
+        assert_eq!(manager.get_channel_load(1), 1);
+
+        manager.update_time(2.0);
+        assert_eq!(manager.get_channel_load(0), 1); // window not yet elapsed
+
+        manager.update_time(3.0);
+        assert_eq!(manager.get_channel_load(0), 0); // released
+        assert_eq!(manager.get_channel_load(1), 1);
+    }
+
+    #[test]
+    fn test_interference_hysteresis_requires_consecutive_readings() {
+        let mut manager = two_channel_manager();
+        manager.set_handoff_config(HandoffConfig {
+            min_consecutive_interference_readings: 3,
+            ..HandoffConfig::default()
+        });
+
+        assert!(!manager.record_interference_reading(0, 10.0, 5.0));
+        assert!(!manager.record_interference_reading(0, 10.0, 5.0));
+        // A below-threshold reading resets the streak.
+        assert!(!manager.record_interference_reading(0, 1.0, 5.0));
+        assert!(!manager.record_interference_reading(0, 10.0, 5.0));
+        assert!(!manager.record_interference_reading(0, 10.0, 5.0));
+        assert!(manager.record_interference_reading(0, 10.0, 5.0));
+    }
+
+    #[test]
+    fn test_assign_channels_never_colors_adjacent_agents_alike() {
+        let manager = SpectrumManager::new(vec![
+            Channel::new(0, 2_400_000_000, 20_000_000),
+            Channel::new(1, 2_460_000_000, 20_000_000),
+            Channel::new(2, 2_520_000_000, 20_000_000),
+        ]);
+
+        // Triangle 0-1-2, plus agent 3 adjacent only to 0.
+        let mut conflict_graph: HashMap<AgentId, HashSet<AgentId>> = HashMap::new();
+        conflict_graph.insert(0, [1, 2, 3].into_iter().collect());
+        conflict_graph.insert(1, [0, 2].into_iter().collect());
+        conflict_graph.insert(2, [0, 1].into_iter().collect());
+        conflict_graph.insert(3, [0].into_iter().collect());
+
+        let positions: HashMap<AgentId, Vector3<f64>> = (0..4)
+            .map(|id| (id, Vector3::new(id as f64 * 200.0, 0.0, 0.0)))
+            .collect();
+
+        let assignment = manager.assign_channels(&conflict_graph, &positions);
+        assert_eq!(assignment.len(), 4);
+
+        for (agent_id, neighbors) in &conflict_graph {
+            for neighbor in neighbors {
+                assert_ne!(
+                    assignment[agent_id], assignment[neighbor],
+                    "agents {agent_id} and {neighbor} share a channel"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_assign_channels_falls_back_when_clique_exceeds_channel_count() {
+        // A 3-clique with only 2 channels can't be properly colored -- the
+        // third vertex must reuse a color, but assign_channels should still
+        // return a full mapping rather than panicking.
+        let manager = SpectrumManager::new(vec![
+            Channel::new(0, 2_400_000_000, 20_000_000),
+            Channel::new(1, 2_460_000_000, 20_000_000),
+        ]);
+
+        let mut conflict_graph: HashMap<AgentId, HashSet<AgentId>> = HashMap::new();
+        conflict_graph.insert(0, [1, 2].into_iter().collect());
+        conflict_graph.insert(1, [0, 2].into_iter().collect());
+        conflict_graph.insert(2, [0, 1].into_iter().collect());
+
+        let positions: HashMap<AgentId, Vector3<f64>> = (0..3)
+            .map(|id| (id, Vector3::new(id as f64 * 200.0, 0.0, 0.0)))
+            .collect();
+
+        let assignment = manager.assign_channels(&conflict_graph, &positions);
+        assert_eq!(assignment.len(), 3);
+        for &channel_id in assignment.values() {
+            assert!(manager.get_channels().iter().any(|c| c.id == channel_id));
+        }
+    }
 }
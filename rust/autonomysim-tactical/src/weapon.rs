@@ -0,0 +1,162 @@
+//! Weapon systems for Combat-role engagements
+//!
+//! The demo advertises armed drones, but until now nothing ever fired: a
+//! [`Weapon`] gives a Combat robot something concrete to engage with --
+//! `damage`, `range`, a cooldown timer advanced by `dt`, and the target
+//! classes it can hit. [`Weapon::can_attack`] both ticks the cooldown down
+//! and answers whether this weapon may fire right now, so a caller can call
+//! it unconditionally every step and only follow up with [`Weapon::fire`]
+//! when it returns `true`. Applying the resulting damage is left to
+//! [`crate::damage::DamageModel`], the same hit-point/failure-mode model
+//! combat engagements already use.
+
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Which targets a [`Weapon`] can engage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TargetClass {
+    Air,
+    Ground,
+}
+
+/// A weapon's static stats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeaponConfig {
+    /// Damage dealt per hit, handed to [`crate::damage::DamageModel`] as
+    /// `hp_damage_at_full`-equivalent for a `HitPoints`-mode engagement.
+    pub damage: f64,
+    /// Maximum engagement range in meters.
+    pub range_m: f64,
+    /// Seconds between shots.
+    pub cooldown_secs: f64,
+    /// Target classes this weapon may engage.
+    pub valid_targets: Vec<TargetClass>,
+}
+
+/// One weapon mounted on a robot: `config` plus how long until it can fire
+/// again.
+pub struct Weapon {
+    config: WeaponConfig,
+    cooldown_remaining: f64,
+}
+
+impl Weapon {
+    /// Create a weapon that's ready to fire immediately.
+    pub fn new(config: WeaponConfig) -> Self {
+        Self {
+            config,
+            cooldown_remaining: 0.0,
+        }
+    }
+
+    pub fn config(&self) -> &WeaponConfig {
+        &self.config
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.cooldown_remaining <= 0.0
+    }
+
+    /// Advance the cooldown timer by `dt`, then report whether this weapon
+    /// may engage a `target_class` at `distance_m` from it right now: off
+    /// cooldown, in range, and a valid target class. Call once per step
+    /// regardless of outcome -- the cooldown only counts down through this
+    /// call.
+    pub fn can_attack(&mut self, target_class: TargetClass, distance_m: f64, dt: f64) -> bool {
+        self.cooldown_remaining = (self.cooldown_remaining - dt).max(0.0);
+        self.is_ready()
+            && distance_m <= self.config.range_m
+            && self.config.valid_targets.contains(&target_class)
+    }
+
+    /// Fire: reset the cooldown and return this weapon's damage for the
+    /// caller to apply (e.g. via [`crate::damage::DamageModel::engage`]).
+    /// Does not itself check [`Self::can_attack`] -- callers are expected to
+    /// only fire after it returns `true`.
+    pub fn fire(&mut self) -> f64 {
+        self.cooldown_remaining = self.config.cooldown_secs;
+        self.config.damage
+    }
+}
+
+/// Nearest `targets` entry to `origin` within `weapon`'s range for
+/// `target_class`, if any -- the lookup a caller does each step before
+/// deciding whether to call [`Weapon::can_attack`].
+pub fn nearest_target(
+    origin: Vector3<f64>,
+    targets: &[Vector3<f64>],
+    max_range_m: f64,
+) -> Option<(usize, f64)> {
+    targets
+        .iter()
+        .enumerate()
+        .map(|(i, &position)| (i, (position - origin).norm()))
+        .filter(|(_, distance)| *distance <= max_range_m)
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rifle() -> Weapon {
+        Weapon::new(WeaponConfig {
+            damage: 25.0,
+            range_m: 100.0,
+            cooldown_secs: 1.0,
+            valid_targets: vec![TargetClass::Air, TargetClass::Ground],
+        })
+    }
+
+    #[test]
+    fn fresh_weapon_is_ready() {
+        assert!(rifle().is_ready());
+    }
+
+    #[test]
+    fn cannot_attack_out_of_range() {
+        let mut weapon = rifle();
+        assert!(!weapon.can_attack(TargetClass::Air, 500.0, 0.1));
+    }
+
+    #[test]
+    fn cannot_attack_wrong_target_class() {
+        let mut weapon = Weapon::new(WeaponConfig {
+            damage: 10.0,
+            range_m: 100.0,
+            cooldown_secs: 1.0,
+            valid_targets: vec![TargetClass::Ground],
+        });
+        assert!(!weapon.can_attack(TargetClass::Air, 10.0, 0.1));
+    }
+
+    #[test]
+    fn firing_starts_cooldown() {
+        let mut weapon = rifle();
+        assert!(weapon.can_attack(TargetClass::Ground, 10.0, 0.1));
+        let damage = weapon.fire();
+        assert_eq!(damage, 25.0);
+        assert!(!weapon.is_ready());
+        assert!(!weapon.can_attack(TargetClass::Ground, 10.0, 0.1));
+    }
+
+    #[test]
+    fn cooldown_expires_after_enough_dt() {
+        let mut weapon = rifle();
+        weapon.fire();
+        assert!(!weapon.can_attack(TargetClass::Ground, 10.0, 0.5));
+        assert!(weapon.can_attack(TargetClass::Ground, 10.0, 0.6));
+    }
+
+    #[test]
+    fn nearest_target_picks_closest_within_range() {
+        let targets = vec![
+            Vector3::new(50.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(200.0, 0.0, 0.0),
+        ];
+        let result = nearest_target(Vector3::zeros(), &targets, 100.0);
+        assert_eq!(result, Some((1, 10.0)));
+    }
+}
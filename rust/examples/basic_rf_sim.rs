@@ -179,14 +179,24 @@ async fn main() -> anyhow::Result<()> {
     let rx_gain = 3.0;
     let path_loss = 80.0;
     let system_loss = 2.0;
-
-    let received_power = link_budget(tx_power, tx_gain, rx_gain, path_loss, system_loss);
+    let polarization_loss =
+        polarization_loss_db(PolarizationType::Vertical, PolarizationType::Vertical, 0.0);
+
+    let received_power = link_budget(
+        tx_power,
+        tx_gain,
+        rx_gain,
+        path_loss,
+        system_loss,
+        polarization_loss,
+    );
 
     println!("Transmit Power:     {:>7.2} dBm", tx_power);
     println!("Tx Antenna Gain:    {:>7.2} dBi", tx_gain);
     println!("Rx Antenna Gain:    {:>7.2} dBi", rx_gain);
     println!("Path Loss:          {:>7.2} dB", path_loss);
     println!("System Losses:      {:>7.2} dB", system_loss);
+    println!("Polarization Loss:  {:>7.2} dB", polarization_loss);
     println!("─────────────────────────────────");
     println!("Received Power:     {:>7.2} dBm", received_power);
 
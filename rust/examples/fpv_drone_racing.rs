@@ -8,6 +8,8 @@
 //! - OSD (On Screen Display) telemetry overlay
 //! - Battery simulation with voltage sag
 //! - Multiple drone presets (5" race, 5" freestyle, 3" micro, 7" LR)
+//! - Multi-drone racing: independent physics/autopilot per racer, with
+//!   sphere-sphere collision detection between airframes
 //!
 //! # Running
 //!
@@ -34,20 +36,46 @@ use autonomysim_backends::unreal::UnrealEngine5Backend;
 #[derive(Debug, Clone)]
 struct RaceGate {
     position: Point3<f64>,
-    _yaw_deg: f64,
+    /// Facing quaternion, built from the track's yaw-about-Z convention.
+    /// `normal()`/`right()`/`up()` rotate the gate's local axes through
+    /// this to get the plane the drone must cross to pass through.
+    orientation: Rotation,
     width: f64,
-    _height: f64,
+    height: f64,
     _index: usize,
 }
 
-/// FPV racing simulation
-struct FpvRacingSim {
+impl RaceGate {
+    /// World-space facing normal: body-forward `[1, 0, 0]` rotated by the
+    /// gate's orientation. A pass requires the signed distance along this
+    /// normal to flip from negative to positive between two steps.
+    fn normal(&self) -> Vec3 {
+        self.orientation * Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    /// World-space "right" axis of the gate plane, used to check a
+    /// crossing point falls within `width / 2` of the gate's center.
+    fn right(&self) -> Vec3 {
+        self.orientation * Vec3::new(0.0, 1.0, 0.0)
+    }
+
+    /// World-space "up" axis of the gate plane, used to check a crossing
+    /// point falls within `height / 2` of the gate's center.
+    fn up(&self) -> Vec3 {
+        self.orientation * Vec3::new(0.0, 0.0, 1.0)
+    }
+}
+
+/// A single drone racing the shared track: its own physics, autopilot
+/// target, lap timer, and gate progress. Several of these can race the same
+/// `gates` concurrently on the shared 1kHz clock.
+struct Racer {
+    /// Vehicle identifier (used for UE5 spawning/updates and logging).
+    vehicle_id: String,
+
     /// Drone physics
     physics: FpvPhysics,
 
-    /// Race track gates
-    gates: Vec<RaceGate>,
-
     /// Next gate to pass through
     next_gate: usize,
 
@@ -57,9 +85,6 @@ struct FpvRacingSim {
     /// Lap count
     laps: usize,
 
-    /// Total laps in race
-    total_laps: usize,
-
     /// Lap times in seconds
     lap_times: Vec<f64>,
 
@@ -69,121 +94,114 @@ struct FpvRacingSim {
     /// Best lap time
     best_lap: Option<f64>,
 
-    /// Race started
-    race_started: bool,
+    /// Signed distance from `next_gate`'s plane as of the previous physics
+    /// step, used to detect the sign flip that marks a plane crossing.
+    /// `None` right after the race starts or a gate is passed, since there's
+    /// no prior sample to compare against yet.
+    last_gate_signed_distance: Option<f64>,
 
-    /// Unreal Engine backend
-    #[cfg(feature = "unreal")]
-    unreal: Option<UnrealEngine5Backend>,
+    /// Number of times this racer's airframe has collided with another.
+    collisions: usize,
 }
 
-impl FpvRacingSim {
-    /// Create a new FPV racing simulation
-    fn new(drone_config: FpvDroneConfig, total_laps: usize) -> Self {
-        let spawn = Point3::new(0.0, 0.0, 1.5);
-        let physics = FpvPhysics::new(drone_config, spawn);
-        let gates = Self::create_race_track();
-
+impl Racer {
+    fn new(vehicle_id: String, drone_config: FpvDroneConfig, spawn: Point3<f64>) -> Self {
         Self {
-            physics,
-            gates,
+            vehicle_id,
+            physics: FpvPhysics::new(drone_config, spawn),
             next_gate: 0,
             gates_passed: 0,
             laps: 0,
-            total_laps,
             lap_times: Vec::new(),
             lap_start_time: 0.0,
             best_lap: None,
-            race_started: false,
-            #[cfg(feature = "unreal")]
-            unreal: None,
+            last_gate_signed_distance: None,
+            collisions: 0,
         }
     }
 
-    /// Create a simple oval race track with gates
-    fn create_race_track() -> Vec<RaceGate> {
-        let mut gates = Vec::new();
-
-        // Simple oval track with 8 gates
-        let track_points: Vec<(f64, f64, f64, f64)> = vec![
-            (0.0, 20.0, 3.0, 0.0),    // Gate 0: straight
-            (15.0, 35.0, 4.0, 45.0),  // Gate 1: entering turn
-            (30.0, 40.0, 5.0, 90.0),  // Gate 2: apex
-            (45.0, 35.0, 3.0, 135.0), // Gate 3: exit turn
-            (50.0, 20.0, 4.0, 180.0), // Gate 4: back straight
-            (45.0, 5.0, 3.0, 225.0),  // Gate 5: entering turn 2
-            (30.0, 0.0, 5.0, 270.0),  // Gate 6: low gate (dive)
-            (15.0, 5.0, 4.0, 315.0),  // Gate 7: return
-        ];
-
-        for (i, (x, y, z, yaw)) in track_points.iter().enumerate() {
-            gates.push(RaceGate {
-                position: Point3::new(*x, *y, *z),
-                _yaw_deg: *yaw,
-                width: 3.0,
-                _height: 3.0,
-                _index: i,
-            });
+    /// Check if this racer has crossed its next gate's plane front-to-back,
+    /// within its width/height bounds -- a real plane-crossing test rather
+    /// than sphere proximity, so clipping a corner or flying through
+    /// backwards no longer counts as a pass.
+    fn check_gate_passage(&mut self, gates: &[RaceGate], race_started: bool) {
+        if gates.is_empty() || !race_started {
+            return;
         }
 
-        gates
-    }
+        let gate = &gates[self.next_gate];
+        let offset = self.physics.position - gate.position;
+        let signed_distance = offset.dot(&gate.normal());
 
-    /// Check if the drone has passed through the next gate
-    fn check_gate_passage(&mut self) {
-        if self.gates.is_empty() || !self.race_started {
+        let Some(previous) = self.last_gate_signed_distance.replace(signed_distance) else {
+            // First sample since the race started (or since the last gate
+            // was passed) -- nothing to compare against yet.
+            return;
+        };
+
+        if previous > 0.0 && signed_distance < 0.0 {
+            info!(
+                "⚠️  [{}] Gate {} crossed backwards -- not counted",
+                self.vehicle_id, self.next_gate
+            );
             return;
         }
 
-        let gate = &self.gates[self.next_gate];
-        let pos = self.physics.position;
-        let dx = pos.x - gate.position.x;
-        let dy = pos.y - gate.position.y;
-        let dz = pos.z - gate.position.z;
-        let dist = (dx * dx + dy * dy + dz * dz).sqrt();
-
-        // Proximity check — pass within 1.5x gate radius
-        if dist < gate.width * 1.5 {
-            self.gates_passed += 1;
+        if !(previous < 0.0 && signed_distance >= 0.0) {
+            return; // hasn't reached the gate's plane yet
+        }
+
+        let lateral = offset.dot(&gate.right());
+        let vertical = offset.dot(&gate.up());
+        if lateral.abs() > gate.width / 2.0 || vertical.abs() > gate.height / 2.0 {
             info!(
-                "🏁 Gate {} passed! (total: {}, distance: {:.1}m)",
-                self.next_gate, self.gates_passed, dist
+                "⚠️  [{}] Gate {} plane crossed outside its bounds -- not counted",
+                self.vehicle_id, self.next_gate
             );
+            return;
+        }
 
-            self.next_gate += 1;
-            if self.next_gate >= self.gates.len() {
-                self.next_gate = 0;
-                self.laps += 1;
-                let lap_time = self.physics.flight_time - self.lap_start_time;
-                self.lap_times.push(lap_time);
-                self.lap_start_time = self.physics.flight_time;
-
-                let is_best = match self.best_lap {
-                    Some(best) => lap_time < best,
-                    None => true,
-                };
-                if is_best {
-                    self.best_lap = Some(lap_time);
-                }
+        self.gates_passed += 1;
+        info!(
+            "🏁 [{}] Gate {} passed! (total: {})",
+            self.vehicle_id, self.next_gate, self.gates_passed
+        );
 
-                info!(
-                    "🏆 Lap {} complete! Time: {:.2}s {}",
-                    self.laps,
-                    lap_time,
-                    if is_best { "(BEST!)" } else { "" }
-                );
+        self.next_gate += 1;
+        self.last_gate_signed_distance = None;
+        if self.next_gate >= gates.len() {
+            self.next_gate = 0;
+            self.laps += 1;
+            let lap_time = self.physics.flight_time - self.lap_start_time;
+            self.lap_times.push(lap_time);
+            self.lap_start_time = self.physics.flight_time;
+
+            let is_best = match self.best_lap {
+                Some(best) => lap_time < best,
+                None => true,
+            };
+            if is_best {
+                self.best_lap = Some(lap_time);
             }
+
+            info!(
+                "🏆 [{}] Lap {} complete! Time: {:.2}s {}",
+                self.vehicle_id,
+                self.laps,
+                lap_time,
+                if is_best { "(BEST!)" } else { "" }
+            );
         }
     }
 
-    /// Generate an autopilot input that flies toward the next gate.
+    /// Generate an autopilot input that flies toward this racer's next gate.
     /// Uses Angle mode for stable self-leveling flight.
-    fn autopilot_input(&self) -> FpvStickInput {
-        if self.gates.is_empty() {
+    fn autopilot_input(&self, gates: &[RaceGate]) -> FpvStickInput {
+        if gates.is_empty() {
             return FpvStickInput::hover();
         }
 
-        let gate = &self.gates[self.next_gate];
+        let gate = &gates[self.next_gate];
         let pos = self.physics.position;
 
         // Direction to gate (horizontal)
@@ -240,10 +258,142 @@ impl FpvRacingSim {
 
         FpvStickInput::new(throttle, roll_stick, pitch_stick, yaw_stick)
     }
+}
+
+/// FPV racing simulation: several `Racer`s sharing the same track and clock.
+struct FpvRacingSim {
+    /// One entry per drone on the grid.
+    racers: Vec<Racer>,
+
+    /// Race track gates (shared by all racers)
+    gates: Vec<RaceGate>,
+
+    /// Total laps in race
+    total_laps: usize,
+
+    /// Race started
+    race_started: bool,
+
+    /// Airframes closer than this (center-to-center, meters) are treated as
+    /// collided.
+    collision_radius_m: f64,
+
+    /// Unreal Engine backend
+    #[cfg(feature = "unreal")]
+    unreal: Option<UnrealEngine5Backend>,
+}
+
+impl FpvRacingSim {
+    /// Create a new FPV racing simulation with one racer per drone config,
+    /// lined up on a staggered starting grid.
+    fn new(drone_configs: Vec<FpvDroneConfig>, total_laps: usize) -> Self {
+        let gates = Self::create_race_track();
+
+        let racers = drone_configs
+            .into_iter()
+            .enumerate()
+            .map(|(i, config)| {
+                let vehicle_id = format!("fpv_racer_{}", i + 1);
+                // Staggered grid: side-by-side along Y so racers don't spawn
+                // stacked on top of one another.
+                let spawn = Point3::new(0.0, i as f64 * 2.0, 1.5);
+                Racer::new(vehicle_id, config, spawn)
+            })
+            .collect();
+
+        Self {
+            racers,
+            gates,
+            total_laps,
+            race_started: false,
+            collision_radius_m: 0.3,
+            #[cfg(feature = "unreal")]
+            unreal: None,
+        }
+    }
+
+    /// Create a simple oval race track with gates
+    fn create_race_track() -> Vec<RaceGate> {
+        let mut gates = Vec::new();
+
+        // Simple oval track with 8 gates
+        let track_points: Vec<(f64, f64, f64, f64)> = vec![
+            (0.0, 20.0, 3.0, 0.0),    // Gate 0: straight
+            (15.0, 35.0, 4.0, 45.0),  // Gate 1: entering turn
+            (30.0, 40.0, 5.0, 90.0),  // Gate 2: apex
+            (45.0, 35.0, 3.0, 135.0), // Gate 3: exit turn
+            (50.0, 20.0, 4.0, 180.0), // Gate 4: back straight
+            (45.0, 5.0, 3.0, 225.0),  // Gate 5: entering turn 2
+            (30.0, 0.0, 5.0, 270.0),  // Gate 6: low gate (dive)
+            (15.0, 5.0, 4.0, 315.0),  // Gate 7: return
+        ];
+
+        for (i, (x, y, z, yaw)) in track_points.iter().enumerate() {
+            gates.push(RaceGate {
+                position: Point3::new(*x, *y, *z),
+                orientation: Rotation::from_axis_angle(&Vec3::z_axis(), yaw.to_radians()),
+                width: 3.0,
+                height: 3.0,
+                _index: i,
+            });
+        }
+
+        gates
+    }
+
+    /// Simple sphere-sphere collision check between every pair of airframes.
+    /// On a collision, count it against both racers and apply a mild
+    /// separating impulse so drones bounce apart instead of overlapping.
+    fn detect_collisions(&mut self) {
+        let min_separation = self.collision_radius_m * 2.0;
+
+        for i in 0..self.racers.len() {
+            for j in (i + 1)..self.racers.len() {
+                let delta = self.racers[j].physics.position - self.racers[i].physics.position;
+                let distance = delta.norm();
+                if distance >= min_separation || distance < 1e-6 {
+                    continue;
+                }
+
+                self.racers[i].collisions += 1;
+                self.racers[j].collisions += 1;
+                info!(
+                    "💥 [{}] collided with [{}] (separation {:.2}m)",
+                    self.racers[i].vehicle_id, self.racers[j].vehicle_id, distance
+                );
+
+                // Push each drone apart along the line connecting their
+                // centers, and bleed off some closing speed.
+                let push_direction = delta / distance;
+                let overlap = min_separation - distance;
+                self.racers[i].physics.position -= push_direction * (overlap / 2.0);
+                self.racers[j].physics.position += push_direction * (overlap / 2.0);
+                self.racers[i].physics.velocity *= 0.5;
+                self.racers[j].physics.velocity *= 0.5;
+            }
+        }
+    }
+
+    /// Combined leaderboard: racers ranked by laps completed, then gates
+    /// passed on the current lap, then fastest best lap.
+    fn leaderboard(&self) -> Vec<&Racer> {
+        let mut ranked: Vec<&Racer> = self.racers.iter().collect();
+        ranked.sort_by(|a, b| {
+            b.laps
+                .cmp(&a.laps)
+                .then(b.gates_passed.cmp(&a.gates_passed))
+                .then(
+                    a.best_lap
+                        .unwrap_or(f64::INFINITY)
+                        .partial_cmp(&b.best_lap.unwrap_or(f64::INFINITY))
+                        .unwrap(),
+                )
+        });
+        ranked
+    }
 
     /// Run the simulation
     async fn run(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let vehicle_id = "fpv_racer_1";
         let dt = 0.001; // 1kHz physics
         let sim_duration_s = 120.0; // 2 minutes
         let total_steps = (sim_duration_s / dt) as usize;
@@ -254,33 +404,17 @@ impl FpvRacingSim {
         info!("     AutonomySim FPV Drone Racing Simulation");
         info!("═══════════════════════════════════════════════════════════");
         info!("");
-        info!("  Drone:   {}", self.physics.config.name);
-        info!("  T/W:     {:.1}:1", self.physics.config.thrust_to_weight());
-        info!(
-            "  Battery: {}S {}mAh",
-            self.physics.config.battery_cells, self.physics.config.battery_capacity_mah
-        );
-        info!("  Mode:    {:?}", self.physics.flight_mode);
-        info!(
-            "  Rates:   {} (Roll: {:.0}°/s max, Pitch: {:.0}°/s max)",
-            self.physics.config.rates.name,
-            self.physics.config.rates.roll_rate(1.0),
-            self.physics.config.rates.pitch_rate(1.0)
-        );
-        info!(
-            "  Camera:  {:.0}° tilt, {:.0}° FOV",
-            self.physics.config.camera.tilt_angle_deg, self.physics.config.camera.fov_h_deg
-        );
-        info!(
-            "  Track:   {} gates, {} laps",
-            self.gates.len(),
-            self.total_laps
-        );
-        info!(
-            "  Hover:   {:.1}% stick ({:.1}% thrust)",
-            self.physics.config.hover_throttle_input() * 100.0,
-            self.physics.config.mass_kg() * 9.81 / self.physics.config.max_thrust_n() * 100.0
-        );
+        for racer in &self.racers {
+            info!(
+                "  [{}] {}  T/W: {:.1}:1  Battery: {}S {}mAh",
+                racer.vehicle_id,
+                racer.physics.config.name,
+                racer.physics.config.thrust_to_weight(),
+                racer.physics.config.battery_cells,
+                racer.physics.config.battery_capacity_mah
+            );
+        }
+        info!("  Track:   {} gates, {} laps", self.gates.len(), self.total_laps);
         info!("");
 
         // Connect to UE5 if feature enabled
@@ -292,33 +426,33 @@ impl FpvRacingSim {
                 Ok(conn) => {
                     info!("✓ Connected to UE5!");
 
-                    // Spawn FPV drone in UE5
-                    let _ = conn
-                        .spawn_fpv_drone(
-                            vehicle_id,
-                            "5inch_race",
-                            self.physics.position.x,
-                            self.physics.position.y,
-                            self.physics.position.z,
-                            0.0,
-                        )
-                        .await;
-
-                    // Configure FPV camera
-                    let cam = &self.physics.config.camera;
-                    let _ = conn
-                        .set_fpv_camera(
-                            vehicle_id,
-                            cam.tilt_angle_deg,
-                            cam.fov_h_deg,
-                            cam.resolution_width,
-                            cam.resolution_height,
-                            cam.lens_distortion,
-                            cam.latency_ms,
-                        )
-                        .await;
-
-                    info!("✓ FPV drone spawned in UE5");
+                    for racer in &self.racers {
+                        let _ = conn
+                            .spawn_fpv_drone(
+                                &racer.vehicle_id,
+                                "5inch_race",
+                                racer.physics.position.x,
+                                racer.physics.position.y,
+                                racer.physics.position.z,
+                                0.0,
+                            )
+                            .await;
+
+                        let cam = &racer.physics.config.camera;
+                        let _ = conn
+                            .set_fpv_camera(
+                                &racer.vehicle_id,
+                                cam.tilt_angle_deg,
+                                cam.fov_h_deg,
+                                cam.resolution_width,
+                                cam.resolution_height,
+                                cam.lens_distortion,
+                                cam.latency_ms,
+                            )
+                            .await;
+                    }
+
+                    info!("✓ FPV drones spawned in UE5");
                 }
                 Err(e) => {
                     tracing::warn!("Could not connect to UE5 (running headless): {}", e);
@@ -327,85 +461,90 @@ impl FpvRacingSim {
         }
 
         // Arm and start
-        info!("Arming drone...");
-        self.physics.set_armed(true);
-        // Use Angle mode for autopilot (self-leveling for stable navigation)
-        self.physics.set_flight_mode(FpvFlightMode::Angle);
+        info!("Arming drones...");
+        for racer in &mut self.racers {
+            racer.physics.set_armed(true);
+            // Use Angle mode for autopilot (self-leveling for stable navigation)
+            racer.physics.set_flight_mode(FpvFlightMode::Angle);
+        }
         self.race_started = true;
-        self.lap_start_time = 0.0;
 
-        info!("Race started! Flying {} laps...", self.total_laps);
+        info!(
+            "Race started! {} drones flying {} laps...",
+            self.racers.len(),
+            self.total_laps
+        );
         info!("");
 
         let real_start = Instant::now();
         let mut step = 0u64;
 
-        while step < total_steps as u64 && self.laps < self.total_laps {
-            // Autopilot generates stick input
-            let input = self.autopilot_input();
-
-            // Step physics
-            self.physics.step(dt, &input);
+        while step < total_steps as u64 && self.racers.iter().any(|r| r.laps < self.total_laps) {
+            for racer in &mut self.racers {
+                if racer.laps >= self.total_laps {
+                    continue;
+                }
+                let input = racer.autopilot_input(&self.gates);
+                racer.physics.step(dt, &input);
+                racer.check_gate_passage(&self.gates, self.race_started);
+            }
 
-            // Check gate passage
-            self.check_gate_passage();
+            self.detect_collisions();
 
             // Periodic logging
             if step % print_interval as u64 == 0 && step > 0 {
-                let state = self.physics.state(vehicle_id);
-                info!(
-                    "  t={:.1}s | pos=({:.1}, {:.1}, {:.1}) | spd={:.1}m/s | alt={:.1}m | bat={:.0}% ({:.1}V) | mode={} | gate={}/{}  |  lap={}/{}",
-                    state.timestamp,
-                    state.position.x,
-                    state.position.y,
-                    state.position.z,
-                    state.speed_mps,
-                    state.altitude_m,
-                    state.battery_remaining * 100.0,
-                    state.battery_voltage,
-                    state.osd.flight_mode,
-                    self.next_gate,
-                    self.gates.len(),
-                    self.laps,
-                    self.total_laps,
-                );
+                for racer in &self.racers {
+                    let state = racer.physics.state(&racer.vehicle_id);
+                    info!(
+                        "  [{}] t={:.1}s | pos=({:.1}, {:.1}, {:.1}) | spd={:.1}m/s | bat={:.0}% | gate={}/{} | lap={}/{}",
+                        racer.vehicle_id,
+                        state.timestamp,
+                        state.position.x,
+                        state.position.y,
+                        state.position.z,
+                        state.speed_mps,
+                        state.battery_remaining * 100.0,
+                        racer.next_gate,
+                        self.gates.len(),
+                        racer.laps,
+                        self.total_laps,
+                    );
+                }
             }
 
             step += 1;
         }
 
         let real_elapsed = real_start.elapsed().as_secs_f64();
-        let sim_elapsed = self.physics.flight_time;
 
         info!("");
         info!("═══════════════════════════════════════════════════════════");
         info!("                    RACE RESULTS");
         info!("═══════════════════════════════════════════════════════════");
-        info!("  Laps completed:   {}/{}", self.laps, self.total_laps);
-        info!("  Gates passed:     {}", self.gates_passed);
-        if let Some(best) = self.best_lap {
-            info!("  Best lap:         {:.2}s", best);
-        }
-        for (i, lt) in self.lap_times.iter().enumerate() {
-            info!("  Lap {}:            {:.2}s", i + 1, lt);
+        for (rank, racer) in self.leaderboard().into_iter().enumerate() {
+            info!(
+                "  #{} [{}]  laps={}/{}  gates={}  collisions={}  best_lap={}",
+                rank + 1,
+                racer.vehicle_id,
+                racer.laps,
+                self.total_laps,
+                racer.gates_passed,
+                racer.collisions,
+                racer
+                    .best_lap
+                    .map(|t| format!("{:.2}s", t))
+                    .unwrap_or_else(|| "--".to_string())
+            );
+            for (i, lt) in racer.lap_times.iter().enumerate() {
+                info!("      lap {}: {:.2}s", i + 1, lt);
+            }
         }
         info!("");
-        info!("  Sim time:         {:.1}s", sim_elapsed);
         info!("  Real time:        {:.3}s", real_elapsed);
-        info!(
-            "  Real-time ratio:  {:.0}x",
-            sim_elapsed / real_elapsed.max(0.001)
-        );
         info!(
             "  Physics rate:     {:.0} steps/s",
             step as f64 / real_elapsed.max(0.001)
         );
-        info!(
-            "  Battery used:     {:.0}mAh ({:.0}% remaining)",
-            self.physics.mah_consumed,
-            self.physics.battery_remaining * 100.0
-        );
-        info!("  Final voltage:    {:.1}V", self.physics.battery_voltage);
         info!("═══════════════════════════════════════════════════════════");
 
         Ok(())
@@ -423,18 +562,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Inspired by TRYP FPV and Liftoff");
     info!("");
 
-    // ── Demo 1: 5" Race Quad ─────────────────────────────────────────────
+    // ── Demo 1: 5" Race Quad (solo) ───────────────────────────────────────
     info!("━━━ Demo 1: 5\" Race Quad ━━━");
-    let config = FpvDroneConfig::five_inch_race();
-    let mut sim = FpvRacingSim::new(config, 3);
+    let mut sim = FpvRacingSim::new(vec![FpvDroneConfig::five_inch_race()], 3);
     sim.run().await?;
 
     info!("");
 
-    // ── Demo 2: 3" Micro (different handling) ────────────────────────────
-    info!("━━━ Demo 2: 3\" Micro Quad ━━━");
-    let config = FpvDroneConfig::three_inch_micro();
-    let mut sim = FpvRacingSim::new(config, 2);
+    // ── Demo 2: Multi-drone race (race + micro + freestyle) ──────────────
+    info!("━━━ Demo 2: Multi-Drone Race ━━━");
+    let mut sim = FpvRacingSim::new(
+        vec![
+            FpvDroneConfig::five_inch_race(),
+            FpvDroneConfig::three_inch_micro(),
+            FpvDroneConfig::five_inch_freestyle(),
+        ],
+        2,
+    );
     sim.run().await?;
 
     info!("");
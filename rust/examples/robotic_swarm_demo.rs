@@ -10,15 +10,118 @@
 //! - Tactical operations in urban environment
 
 use autonomysim_core::native::NativeBackend;
-use autonomysim_core::{VehicleId, VehicleType};
+use autonomysim_core::{
+    Flock, FlockConfig, Formation, FormationSpec, Rotation, VehicleId, VehicleType,
+};
 use autonomysim_rf_core::prelude::*;
-use autonomysim_summoner::{DistributionStrategy, Summoner, SummonerConfig};
-use autonomysim_tactical::jamming::{JammingConfig, JammingType};
+use autonomysim_summoner::{
+    Barrier, BarrierStatus, DistributionStrategy, Stigmergy, Summoner, SummonerConfig,
+};
+use autonomysim_tactical::ai::{
+    BehaviorState, TacticalAI, TacticalAIConfig, ThreatContact, UnitClass,
+};
+use autonomysim_tactical::damage::{DamageConfig, DamageModel, RobotDamageState};
+use autonomysim_tactical::jamming::{JammingConfig, JammingModel, JammingType};
+use autonomysim_tactical::mesh::{
+    build_mesh_topology, compare_messaging_strategies, mean_hops_to_nearest_coordinator,
+    MessageAccounting,
+};
+use autonomysim_tactical::network::{AgentId, NetworkTopology, PartitionDetector};
 use nalgebra::{Point3, Vector3};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::info;
 
+/// Pack a position into the raw bytes a [`Stigmergy`] tuple stores.
+fn encode_point(point: Point3<f64>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(24);
+    bytes.extend_from_slice(&point.x.to_le_bytes());
+    bytes.extend_from_slice(&point.y.to_le_bytes());
+    bytes.extend_from_slice(&point.z.to_le_bytes());
+    bytes
+}
+
+/// Inverse of [`encode_point`]; `None` if `bytes` isn't a 3-`f64` payload.
+fn decode_point(bytes: &[u8]) -> Option<Point3<f64>> {
+    Some(Point3::new(
+        f64::from_le_bytes(bytes.get(0..8)?.try_into().ok()?),
+        f64::from_le_bytes(bytes.get(8..16)?.try_into().ok()?),
+        f64::from_le_bytes(bytes.get(16..24)?.try_into().ok()?),
+    ))
+}
+
+/// Exchange stigmergy tuples between robots `a` and `b`. `Stigmergy::propagate`
+/// is already bidirectional, so which one is `self` vs. the neighbor doesn't
+/// matter -- both replicas end up synchronized.
+fn propagate_pair(stigmergies: &mut [Stigmergy], a: usize, b: usize, max_batch: usize) {
+    let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+    let (left, right) = stigmergies.split_at_mut(hi);
+    left[lo].propagate(std::iter::once(&mut right[0]), max_batch);
+}
+
+/// Robots within this many meters of an already-placed formation neighbor
+/// can request a slot from it this step.
+const FORMATION_CLAIM_RANGE: f64 = 150.0;
+
+/// How often (in steps) the communication mesh is rebuilt from real RF link
+/// budgets; an O(n^2) sweep every 20ms tick isn't worth paying.
+const MESH_REFRESH_STEPS: u64 = 50;
+
+/// Minimum SNR (dB) for a mesh edge to count as usable.
+const MESH_MIN_SNR_DB: f64 = 10.0;
+
+/// Maximum packet loss rate for a mesh edge to count as usable.
+const MESH_MAX_PACKET_LOSS: f64 = 0.1;
+
+/// Build this step's `(requester, placed_neighbor)` pairs for a
+/// [`Formation`]: every still-unplaced robot in `group` paired with the
+/// first already-placed group member within [`FORMATION_CLAIM_RANGE`].
+fn collect_claim_requests(
+    group: &[usize],
+    formation: &Formation,
+    positions: &[Point3<f64>],
+) -> Vec<(usize, usize)> {
+    let mut requests = Vec::new();
+    for &requester in group {
+        if formation.is_placed(requester) {
+            continue;
+        }
+        for &neighbor in group {
+            if neighbor == requester || !formation.is_placed(neighbor) {
+                continue;
+            }
+            if (positions[requester] - positions[neighbor]).norm() <= FORMATION_CLAIM_RANGE {
+                requests.push((requester, neighbor));
+                break;
+            }
+        }
+    }
+    requests
+}
+
+/// Mean position of `positions`, or the origin if empty -- the fallback
+/// [`BehaviorState::Regroup`] target when a unit's friendlies list is empty.
+fn friendly_centroid(positions: &[Vector3<f64>]) -> Vector3<f64> {
+    if positions.is_empty() {
+        return Vector3::zeros();
+    }
+    let sum = positions
+        .iter()
+        .fold(Vector3::zeros(), |acc, position| acc + position);
+    sum / positions.len() as f64
+}
+
+/// Same disjoint-borrow trick as [`propagate_pair`], for the launch
+/// barrier; a no-op if either robot at `a`/`b` has no barrier replica
+/// (i.e. isn't a scout).
+fn propagate_barrier_pair(barriers: &mut [Option<Barrier>], a: usize, b: usize) {
+    let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+    let (left, right) = barriers.split_at_mut(hi);
+    if let (Some(lo_barrier), Some(hi_barrier)) = (left[lo].as_mut(), right[0].as_mut()) {
+        lo_barrier.propagate(std::iter::once(hi_barrier));
+    }
+}
+
 /// Robotic vehicle configuration
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -56,11 +159,58 @@ struct RoboticSwarmDemo {
     // Robot fleet
     robots: Vec<RobotConfig>,
 
+    // Decentralized flocking controller moving robots toward `target`
+    flock: Flock,
+
+    // One stigmergy replica per robot (same index as `robots`). Coordinators
+    // publish mission parameters (rally point) into their replica; scouts
+    // and transport pick them up once gossip reaches them, rather than
+    // flying to a waypoint fixed at fleet-generation time.
+    stigmergies: Vec<Stigmergy>,
+
+    // One launch barrier replica per scout (same index as `robots`, `None`
+    // for non-scouts): transport convoys hold position until every scout's
+    // replica has gossiped its way to `ready_count >= threshold`, or given
+    // up after timing out.
+    launch_barriers: Vec<Option<Barrier>>,
+
+    // Decentralized formation assignments Relay and Combat units converge
+    // onto via label-claiming, replacing the fixed spawn positions those
+    // roles used to just sit at.
+    relay_formation: Formation,
+    combat_formation: Formation,
+
+    // Attrition model Combat units engage `threats` through (kill
+    // probability falling off with range), plus one damage state per robot
+    // (same index as `robots`). A destroyed or comms-failed robot drops out
+    // of RF link counting and stigmergy propagation; a propulsion failure
+    // freezes its position.
+    damage_model: DamageModel,
+    damage_states: Vec<RobotDamageState>,
+    threats: Vec<Point3<f64>>,
+
+    // One tactical AI replica per robot (same index as `robots`): each
+    // independently selects a behavior state from its own threat (nearby
+    // `threats` plus local jamming exposure) and support (friendlies
+    // reachable in `mesh_topology`) -- no central tactical planner, the
+    // same decentralized posture as the flocking controller above.
+    tactical_ais: Vec<TacticalAI>,
+
+    // Communication mesh built from real RF link budgets (RSSI/SNR minus
+    // jamming interference) rather than a flat "fraction of links
+    // survive" approximation, refreshed every `MESH_REFRESH_STEPS` steps.
+    mesh_topology: NetworkTopology,
+    mesh_partition_count: usize,
+    mesh_largest_component: usize,
+    mesh_accounting: MessageAccounting,
+    mesh_mean_hops: Option<f64>,
+
     // Metrics
     start_time: Instant,
     step_count: u64,
     active_links: usize,
     jamming_active: bool,
+    mean_neighbor_spacing: f64,
 }
 
 impl RoboticSwarmDemo {
@@ -110,11 +260,82 @@ impl RoboticSwarmDemo {
             mpi_rank: None,
             mpi_world_size: None,
         };
-        let summoner = Summoner::new(summoner_config).await?;
+        let mut summoner = Summoner::new(summoner_config).await?;
 
         // Generate robot fleet
         info!("  Generating robot fleet...");
         let robots = Self::generate_robot_fleet(num_robots);
+        let stigmergies = (0..robots.len())
+            .map(|i| summoner.create_stigmergy(format!("robot_{}", i)))
+            .collect();
+        let num_scouts = robots
+            .iter()
+            .filter(|robot| robot.role == RobotRole::Scout)
+            .count();
+        let launch_barriers = robots
+            .iter()
+            .enumerate()
+            .map(|(i, robot)| {
+                (robot.role == RobotRole::Scout)
+                    .then(|| summoner.barrier("transport_launch", i as u64, num_scouts))
+            })
+            .collect();
+
+        // Relay nodes converge onto a spanning grid (the same layout they
+        // used to just spawn at) and Combat units onto a defensive ring,
+        // via decentralized label-claiming instead of a fixed waypoint
+        // baked in at generation time.
+        let relay_indices: Vec<usize> = robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| robot.role == RobotRole::Relay)
+            .map(|(i, _)| i)
+            .collect();
+        let relay_slots: Vec<Vector3<f64>> = (0..relay_indices.len())
+            .map(|i| {
+                Vector3::new(
+                    -400.0 + (i % 4) as f64 * 266.0,
+                    -400.0 + (i / 4) as f64 * 266.0,
+                    0.0,
+                )
+            })
+            .collect();
+        let mut relay_formation = Formation::new(
+            FormationSpec::custom(relay_slots),
+            Point3::new(0.0, 0.0, 100.0),
+            Rotation::identity(),
+            robots.len(),
+        );
+        if let Some(&root) = relay_indices.first() {
+            relay_formation.seed_root(root);
+        }
+
+        let combat_indices: Vec<usize> = robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| robot.role == RobotRole::Combat)
+            .map(|(i, _)| i)
+            .collect();
+        let mut combat_formation = Formation::new(
+            FormationSpec::circle(combat_indices.len(), 300.0),
+            Point3::new(0.0, 0.0, 80.0),
+            Rotation::identity(),
+            robots.len(),
+        );
+        if let Some(&root) = combat_indices.first() {
+            combat_formation.seed_root(root);
+        }
+
+        let damage_model = DamageModel::new(DamageConfig::default());
+        let damage_states = robots
+            .iter()
+            .map(|_| RobotDamageState::new(damage_model.config().hp_max))
+            .collect();
+        let threats = Self::generate_threat_set();
+        let tactical_ais = robots
+            .iter()
+            .map(|_| TacticalAI::new(TacticalAIConfig::default()))
+            .collect();
 
         info!("✓ AutonomySim initialization complete!");
 
@@ -124,10 +345,28 @@ impl RoboticSwarmDemo {
             jamming_config,
             summoner,
             robots,
+            flock: Flock::new(FlockConfig::default()),
+            stigmergies,
+            launch_barriers,
+            relay_formation,
+            combat_formation,
+            damage_model,
+            damage_states,
+            threats,
+            tactical_ais,
+            mesh_topology: NetworkTopology::new(),
+            mesh_partition_count: 0,
+            mesh_largest_component: 0,
+            mesh_accounting: MessageAccounting {
+                naive_transmissions: 0,
+                relayed_transmissions: 0,
+            },
+            mesh_mean_hops: None,
             start_time: Instant::now(),
             step_count: 0,
             active_links: num_robots * (num_robots - 1) / 2,
             jamming_active: false,
+            mean_neighbor_spacing: 0.0,
         })
     }
 
@@ -211,20 +450,344 @@ impl RoboticSwarmDemo {
         robots
     }
 
+    /// Fixed hostile positions Combat units engage every step. A
+    /// "configurable threat set" in the sense that swapping this vector
+    /// changes the scenario without touching the damage model itself.
+    fn generate_threat_set() -> Vec<Point3<f64>> {
+        vec![
+            Point3::new(600.0, 600.0, 50.0),
+            Point3::new(-600.0, 600.0, 50.0),
+            Point3::new(600.0, -600.0, 50.0),
+            Point3::new(-600.0, -600.0, 50.0),
+            Point3::new(0.0, 800.0, 50.0),
+        ]
+    }
+
     async fn step(&mut self, dt: f64) -> anyhow::Result<()> {
         // Step SUMMONER distributed simulation
         self.summoner.step(dt).await?;
 
+        // Coordinators publish the fleet rally point into their own
+        // stigmergy replica every step...
+        let coordinator_indices: Vec<usize> = self
+            .robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| robot.role == RobotRole::Coordinator)
+            .map(|(i, _)| i)
+            .collect();
+        for &c in &coordinator_indices {
+            let rally_point = self.robots[c].position;
+            self.stigmergies[c].put("rally_point", encode_point(rally_point));
+        }
+
+        // ...and gossip it out to whichever robots currently have a
+        // connected RF link to that coordinator. A jammed or out-of-range
+        // robot simply doesn't hear this step's update, so convergence
+        // visibly slows under EW instead of every replica having instant
+        // knowledge regardless of radio conditions.
+        for &c in &coordinator_indices {
+            if !self.damage_states[c].comms_online() {
+                continue;
+            }
+            for i in 0..self.robots.len() {
+                if i == c || !self.damage_states[i].comms_online() {
+                    continue;
+                }
+                let link = self
+                    .rf_engine
+                    .compute_link(self.robots[c].position, self.robots[i].position, 20e6)
+                    .await?;
+                if !link.is_connected {
+                    continue;
+                }
+                propagate_pair(&mut self.stigmergies, c, i, 4);
+            }
+        }
+
+        // Scouts and transport fly toward whatever rally point has reached
+        // them via the stigmergy, not the waypoint they were generated
+        // with.
+        for (i, robot) in self.robots.iter_mut().enumerate() {
+            if !matches!(robot.role, RobotRole::Scout | RobotRole::Transport) {
+                continue;
+            }
+            if let Some(rally_point) = self.stigmergies[i]
+                .get("rally_point")
+                .and_then(decode_point)
+            {
+                robot.target = rally_point;
+            }
+        }
+
+        // Scouts rendezvous behind a launch barrier before transport
+        // convoys are allowed to move: every scout marks itself ready,
+        // then that readiness gossips over whichever RF links are live
+        // this step, exactly like the stigmergy propagation above. A
+        // jamming partition that cuts a scout off from the others stalls
+        // -- and can eventually time out -- the barrier instead of every
+        // scout knowing the others are ready regardless of radio
+        // conditions.
+        let scout_indices: Vec<usize> = self
+            .robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| robot.role == RobotRole::Scout)
+            .map(|(i, _)| i)
+            .collect();
+        for &i in &scout_indices {
+            if let Some(barrier) = self.launch_barriers[i].as_mut() {
+                barrier.mark_ready();
+            }
+        }
+        for (slot, &i) in scout_indices.iter().enumerate() {
+            for &j in &scout_indices[slot + 1..] {
+                let link = self
+                    .rf_engine
+                    .compute_link(self.robots[i].position, self.robots[j].position, 20e6)
+                    .await?;
+                if link.is_connected {
+                    propagate_barrier_pair(&mut self.launch_barriers, i, j);
+                }
+            }
+        }
+        for &i in &scout_indices {
+            let Some(barrier) = self.launch_barriers[i].as_mut() else {
+                continue;
+            };
+            if Some(&i) == scout_indices.first() {
+                barrier.tick(
+                    || info!("transport launch barrier COMPLETE: all scouts ready"),
+                    || {
+                        info!(
+                            "transport launch barrier TIMED OUT: jamming likely partitioned the scouts"
+                        )
+                    },
+                );
+            } else {
+                barrier.tick(|| {}, || {});
+            }
+        }
+        // Timing out still releases the convoys (mirroring
+        // `Scheduler::barrier`'s own timeout behavior) so one unreachable
+        // scout can't wedge the mission forever; it just means the launch
+        // wasn't actually confirmed.
+        let launch_cleared = scout_indices
+            .first()
+            .and_then(|&i| self.launch_barriers[i].as_ref())
+            .map(|barrier| barrier.status() != BarrierStatus::Pending)
+            .unwrap_or(true);
+        if !launch_cleared {
+            for robot in self.robots.iter_mut() {
+                if robot.role == RobotRole::Transport {
+                    robot.target = robot.position;
+                }
+            }
+        }
+
+        // Relay and Combat units claim formation slots the same
+        // decentralized way: an unplaced robot in range of an
+        // already-placed one requests a slot, and the assignment fills
+        // outward from the seeded root with no central planner.
+        let relay_indices: Vec<usize> = self
+            .robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| robot.role == RobotRole::Relay)
+            .map(|(i, _)| i)
+            .collect();
+        let combat_indices: Vec<usize> = self
+            .robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| robot.role == RobotRole::Combat)
+            .map(|(i, _)| i)
+            .collect();
+        let current_positions: Vec<Point3<f64>> = self.robots.iter().map(|r| r.position).collect();
+
+        let relay_requests =
+            collect_claim_requests(&relay_indices, &self.relay_formation, &current_positions);
+        self.relay_formation
+            .step(relay_requests, &current_positions);
+        let combat_requests =
+            collect_claim_requests(&combat_indices, &self.combat_formation, &current_positions);
+        self.combat_formation
+            .step(combat_requests, &current_positions);
+
+        for &i in &relay_indices {
+            if let Some(slot) = self.relay_formation.slot_of(i) {
+                self.robots[i].target = self.relay_formation.slot_position(slot);
+            }
+        }
+        for &i in &combat_indices {
+            if let Some(slot) = self.combat_formation.slot_of(i) {
+                self.robots[i].target = self.combat_formation.slot_position(slot);
+            }
+        }
+
+        // Combat units engage the threat set: each threat fires at
+        // whichever living combat robot is nearest it, with kill
+        // probability falling off with range (see
+        // `DamageModel::kill_probability`). A spectator-mode config still
+        // rolls and records the outcome but never mutates `damage_states`,
+        // for analysis runs that shouldn't perturb the scenario they're
+        // observing.
+        for &threat in &self.threats {
+            let nearest_combat = combat_indices
+                .iter()
+                .copied()
+                .filter(|&i| !self.damage_states[i].is_destroyed())
+                .min_by(|&a, &b| {
+                    let dist_a = (self.robots[a].position - threat).norm();
+                    let dist_b = (self.robots[b].position - threat).norm();
+                    dist_a.partial_cmp(&dist_b).unwrap()
+                });
+            if let Some(i) = nearest_combat {
+                let distance = (self.robots[i].position - threat).norm();
+                self.damage_model
+                    .engage(&mut self.damage_states[i], distance);
+            }
+        }
+
+        // Propulsion-failed or destroyed robots freeze wherever they
+        // stand, the same hold-position pattern the launch barrier uses to
+        // stall the transport convoys above.
+        for (i, robot) in self.robots.iter_mut().enumerate() {
+            if !self.damage_states[i].can_move() {
+                robot.target = robot.position;
+            }
+        }
+
+        // Each living robot runs its own tactical AI: aggregate local
+        // threat (proximity-weighted `threats` plus jamming J/S against its
+        // own link) and support (friendlies reachable in `mesh_topology`,
+        // which only refreshes every `MESH_REFRESH_STEPS` steps -- so
+        // support counts are exactly as stale as the mesh itself) into a
+        // behavior state, and let states with their own destination
+        // override whatever formation/stigmergy target was already set
+        // above this step.
+        let jammer = self
+            .jamming_active
+            .then(|| JammingModel::new(self.jamming_config.clone()));
+        let reference_signal_dbm = self.rf_engine.config().tx_power_dbm;
+        let contacts: Vec<ThreatContact> = self
+            .threats
+            .iter()
+            .map(|&position| ThreatContact {
+                position: position.coords,
+                priority: 1.0,
+            })
+            .collect();
+
+        for (i, robot) in self.robots.iter_mut().enumerate() {
+            if self.damage_states[i].is_destroyed() {
+                continue;
+            }
+            let class = match robot.role {
+                RobotRole::Scout => UnitClass::Scout,
+                RobotRole::Combat => UnitClass::Combat,
+                RobotRole::Relay => UnitClass::Relay,
+                RobotRole::Transport | RobotRole::Coordinator => UnitClass::Other,
+            };
+            let position = robot.position.coords;
+            let nearest_contact = contacts.iter().copied().min_by(|a, b| {
+                let dist_a = (a.position - position).norm();
+                let dist_b = (b.position - position).norm();
+                dist_a.partial_cmp(&dist_b).unwrap()
+            });
+            let ai = &mut self.tactical_ais[i];
+            let threat_level =
+                ai.threat_level(position, &contacts, jammer.as_ref(), reference_signal_dbm);
+            let support_level = self.mesh_topology.get_neighbors(i).len();
+            let state = ai.step(class, threat_level, support_level, nearest_contact);
+
+            match state {
+                BehaviorState::Evade => {
+                    if let Some(contact) = nearest_contact {
+                        let away = (position - contact.position).normalize();
+                        robot.target = robot.position + away * 200.0 + Vector3::new(0.0, 0.0, 50.0);
+                    }
+                }
+                BehaviorState::Engage => {
+                    if let Some(contact) = nearest_contact {
+                        robot.target =
+                            Point3::new(contact.position.x, contact.position.y, robot.position.z);
+                    }
+                }
+                BehaviorState::Regroup => {
+                    let friendly_positions: Vec<Vector3<f64>> = self
+                        .mesh_topology
+                        .get_neighbors(i)
+                        .into_iter()
+                        .filter_map(|neighbor| self.mesh_topology.get_position(neighbor))
+                        .collect();
+                    let centroid = friendly_centroid(&friendly_positions);
+                    robot.target = Point3::new(centroid.x, centroid.y, robot.position.z);
+                }
+                BehaviorState::RelayHold => {
+                    robot.target = robot.position;
+                }
+                BehaviorState::Idle | BehaviorState::Transit => {}
+            }
+        }
+
+        // Advance the fleet toward its waypoints via decentralized
+        // Lennard-Jones flocking: each robot senses only nearby neighbors
+        // and its own `target`, with no central trajectory planner.
+        let mut positions: Vec<_> = self.robots.iter().map(|r| r.position).collect();
+        let targets: Vec<_> = self.robots.iter().map(|r| r.target).collect();
+        self.mean_neighbor_spacing = self.flock.step(&mut positions, &targets, dt);
+        for (robot, position) in self.robots.iter_mut().zip(positions) {
+            robot.position = position;
+        }
+
         // Simulate jamming (activate every 500 steps for 100 steps)
         self.jamming_active = (self.step_count % 600) < 100;
 
-        // Simulate RF link degradation under jamming
-        if self.jamming_active {
-            self.active_links = (self.robots.len() * (self.robots.len() - 1) / 2) / 3;
-        // 33% links survive
-        } else {
-            self.active_links = self.robots.len() * (self.robots.len() - 1) / 2;
-            // All links active
+        // Periodically rebuild the communication mesh from real RF link
+        // budgets (RSSI/SNR via `RFPropagationEngine`, minus `jamming`'s
+        // interference when active) instead of assuming a flat fraction of
+        // links survive -- jamming now actually partitions the graph, and
+        // relay placement measurably changes reachability. An O(n^2)
+        // link-budget sweep isn't worth paying every 20ms tick, so this
+        // only runs every `MESH_REFRESH_STEPS` steps; `active_links` and
+        // the other mesh-derived stats simply hold their last value
+        // between refreshes. Comms-failed (or destroyed) robots have
+        // already dropped out of stigmergy propagation above; they drop
+        // out of this mesh too.
+        if self.step_count % MESH_REFRESH_STEPS == 0 {
+            let mesh_agents: Vec<(AgentId, Vector3<f64>)> = self
+                .robots
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| self.damage_states[*i].comms_online())
+                .map(|(i, robot)| (i, robot.position.coords))
+                .collect();
+            let jammer = self
+                .jamming_active
+                .then(|| JammingModel::new(self.jamming_config.clone()));
+
+            self.mesh_topology = build_mesh_topology(
+                &self.rf_engine,
+                jammer.as_ref(),
+                &mesh_agents,
+                20e6,
+                MESH_MIN_SNR_DB,
+                MESH_MAX_PACKET_LOSS,
+            )
+            .await?;
+
+            let mut detector = PartitionDetector::new(self.mesh_topology.clone());
+            detector.detect_partitions();
+            self.mesh_partition_count = detector.partition_count();
+            self.mesh_largest_component =
+                detector.largest_partition().map(|p| p.len()).unwrap_or(0);
+
+            self.mesh_accounting =
+                compare_messaging_strategies(&self.mesh_topology, &coordinator_indices);
+            self.mesh_mean_hops =
+                mean_hops_to_nearest_coordinator(&self.mesh_topology, &coordinator_indices);
+            self.active_links = self.mesh_topology.link_count() / 2;
         }
 
         self.step_count += 1;
@@ -280,6 +843,94 @@ impl RoboticSwarmDemo {
         println!("  Coordinators:     {} (C2)", coordinators);
         println!();
 
+        let survivors_by_role = |role: RobotRole| {
+            self.robots
+                .iter()
+                .zip(&self.damage_states)
+                .filter(|(r, state)| r.role == role && !state.is_destroyed())
+                .count()
+        };
+        let total_failures: usize = self
+            .damage_states
+            .iter()
+            .map(|state| state.failures().len())
+            .sum();
+        println!("ATTRITION (COMBAT vs. THREAT SET):");
+        println!(
+            "  Combat Survivors: {}/{}",
+            survivors_by_role(RobotRole::Combat),
+            combat
+        );
+        println!(
+            "  Fleet Survivors:  {}/{}",
+            self.robots.len()
+                - self
+                    .damage_states
+                    .iter()
+                    .filter(|s| s.is_destroyed())
+                    .count(),
+            self.robots.len()
+        );
+        println!("  Subsystem Failures (cumulative): {}", total_failures);
+        println!();
+
+        println!("SWARM FORMATION:");
+        println!("  Target Spacing:   {:.1}m", self.flock.config().target);
+        println!("  Mean NN Spacing:  {:.1}m", self.mean_neighbor_spacing);
+        println!();
+
+        let total_collisions: u64 = self.stigmergies.iter().map(|s| s.collision_count()).sum();
+        let (latency_sum, latency_count) =
+            self.stigmergies
+                .iter()
+                .fold((Duration::ZERO, 0u32), |(sum, count), stigmergy| {
+                    let latency = stigmergy.mean_propagation_latency();
+                    if latency > Duration::ZERO {
+                        (sum + latency, count + 1)
+                    } else {
+                        (sum, count)
+                    }
+                });
+        let mean_latency_ms = if latency_count > 0 {
+            latency_sum.as_secs_f64() * 1000.0 / latency_count as f64
+        } else {
+            0.0
+        };
+        println!("STIGMERGY (C2 DISSEMINATION):");
+        println!("  Tuple Latency:    {:.1}ms (mean)", mean_latency_ms);
+        println!("  Write Collisions: {}", total_collisions);
+        println!();
+
+        let launch_barrier_status = self
+            .launch_barriers
+            .iter()
+            .flatten()
+            .next()
+            .map(|barrier| match barrier.status() {
+                BarrierStatus::Pending => "PENDING",
+                BarrierStatus::Completed => "COMPLETE",
+                BarrierStatus::TimedOut => "TIMED OUT",
+            })
+            .unwrap_or("N/A");
+        println!("LAUNCH BARRIER:");
+        println!("  Transport Launch: {}", launch_barrier_status);
+        println!();
+
+        let formation_positions: Vec<Point3<f64>> =
+            self.robots.iter().map(|r| r.position).collect();
+        println!("FORMATION ASSIGNMENT:");
+        println!(
+            "  Relay Grid:       {:.0}% filled (slot err {:.1}m mean)",
+            self.relay_formation.fraction_filled() * 100.0,
+            self.relay_formation.mean_slot_error(&formation_positions)
+        );
+        println!(
+            "  Combat Ring:      {:.0}% filled (slot err {:.1}m mean)",
+            self.combat_formation.fraction_filled() * 100.0,
+            self.combat_formation.mean_slot_error(&formation_positions)
+        );
+        println!();
+
         println!("RF COMMUNICATIONS:");
         println!("  Active Links:     {}", self.active_links);
         println!("  Frequency:        5.8 GHz (WiFi)");
@@ -294,6 +945,41 @@ impl RoboticSwarmDemo {
         );
         println!();
 
+        let mut behavior_counts: std::collections::HashMap<BehaviorState, usize> =
+            std::collections::HashMap::new();
+        for ai in &self.tactical_ais {
+            *behavior_counts.entry(ai.state()).or_insert(0) += 1;
+        }
+        let behavior_count =
+            |state: BehaviorState| behavior_counts.get(&state).copied().unwrap_or(0);
+        println!("TACTICAL AI (BEHAVIOR STATES):");
+        println!("  Idle:       {}", behavior_count(BehaviorState::Idle));
+        println!("  Transit:    {}", behavior_count(BehaviorState::Transit));
+        println!("  Engage:     {}", behavior_count(BehaviorState::Engage));
+        println!("  Evade:      {}", behavior_count(BehaviorState::Evade));
+        println!("  RelayHold:  {}", behavior_count(BehaviorState::RelayHold));
+        println!("  Regroup:    {}", behavior_count(BehaviorState::Regroup));
+        println!();
+
+        println!("MESH CONNECTIVITY (relay-routed, real RF link budgets):");
+        println!("  Connected Components: {}", self.mesh_partition_count);
+        println!("  Largest Component:    {}", self.mesh_largest_component);
+        println!(
+            "  Mean Hops to C2:      {}",
+            self.mesh_mean_hops
+                .map(|hops| format!("{:.1}", hops))
+                .unwrap_or_else(|| "N/A".to_string())
+        );
+        println!(
+            "  Naive Broadcast:      {} msgs/round",
+            self.mesh_accounting.naive_transmissions
+        );
+        println!(
+            "  Relay-Routed:         {} msgs/round",
+            self.mesh_accounting.relayed_transmissions
+        );
+        println!();
+
         println!("SUMMONER PERFORMANCE:");
         println!(
             "  Avg Step Time:    {:.3}ms",
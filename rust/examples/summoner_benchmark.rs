@@ -6,35 +6,157 @@
 //! - GPU utilization analysis
 //! - Throughput measurements
 //! - Memory efficiency tests
+//!
+//! Supports `--format {text,json}` for CI-consumable output, and
+//! `--baseline <path> [--max-regression <fraction>]` to gate a run against
+//! a previously saved JSON run (see [`BenchmarkRun`]), exiting non-zero if
+//! any matched `(num_agents, num_gpus, distribution)` result regresses
+//! beyond the tolerance.
 
 use autonomysim_summoner::{DistributionStrategy, Summoner, SummonerConfig};
 use nalgebra::Vector3;
-use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct BenchmarkResult {
     num_agents: usize,
+    num_gpus: usize,
+    distribution: String,
     num_steps: usize,
-    total_time: Duration,
+    total_time_secs: f64,
     avg_step_time_ms: f64,
     min_step_time_ms: f64,
     max_step_time_ms: f64,
     steps_per_second: f64,
     agents_per_second: f64,
     memory_mb: f64,
+    /// Phases whose average duration grew by more than ~50% from the first
+    /// ~10% of steps to the last ~10%, per [`detect_phase_degradation`].
+    /// Empty means no phase showed a meaningful slowdown over the run.
+    degradation_warnings: Vec<String>,
 }
 
 impl BenchmarkResult {
     fn print(&self) {
         println!("  Agents:           {}", self.num_agents);
         println!("  Steps:            {}", self.num_steps);
-        println!("  Total Time:       {:.3}s", self.total_time.as_secs_f64());
+        println!("  Total Time:       {:.3}s", self.total_time_secs);
         println!("  Avg Step Time:    {:.3}ms", self.avg_step_time_ms);
         println!("  Min Step Time:    {:.3}ms", self.min_step_time_ms);
         println!("  Max Step Time:    {:.3}ms", self.max_step_time_ms);
         println!("  Steps/Second:     {:.1}", self.steps_per_second);
         println!("  Agents/Second:    {:.0}", self.agents_per_second);
         println!("  Memory Usage:     {:.1}MB", self.memory_mb);
+        if !self.degradation_warnings.is_empty() {
+            println!("  Phase Degradation:");
+            for warning in &self.degradation_warnings {
+                println!("    - {}", warning);
+            }
+        }
+    }
+
+    /// Identity used to match a result against the same scenario in a
+    /// baseline run.
+    fn key(&self) -> (usize, usize, &str) {
+        (self.num_agents, self.num_gpus, self.distribution.as_str())
+    }
+}
+
+/// System metadata recorded alongside a [`BenchmarkRun`] so a saved JSON
+/// run is self-describing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SystemInfo {
+    cpu: String,
+    test_date: String,
+    configuration: String,
+}
+
+/// A complete benchmark invocation's results, serializable as the
+/// `--format json` payload and as the `--baseline` file format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BenchmarkRun {
+    system: SystemInfo,
+    scaling: Vec<BenchmarkResult>,
+    distribution_comparison: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkRun {
+    fn all_results(&self) -> impl Iterator<Item = &BenchmarkResult> {
+        self.scaling
+            .iter()
+            .chain(self.distribution_comparison.iter())
+    }
+}
+
+/// Short, stable label for a [`DistributionStrategy`], used as the
+/// `distribution` component of [`BenchmarkResult::key`].
+fn distribution_label(strategy: &DistributionStrategy) -> String {
+    match strategy {
+        DistributionStrategy::SingleNode => "single_node".to_string(),
+        DistributionStrategy::SpatialPartitioning { num_partitions, .. } => {
+            format!("spatial_partitioning_{}", num_partitions)
+        }
+        DistributionStrategy::FunctionalDecomposition { .. } => {
+            "functional_decomposition".to_string()
+        }
+        DistributionStrategy::Hybrid { .. } => "hybrid".to_string(),
+        DistributionStrategy::WeightedPartitioning { num_partitions, .. } => {
+            format!("weighted_partitioning_{}", num_partitions)
+        }
+    }
+}
+
+/// Run `num_steps` steps, and flag any phase whose average duration grew
+/// by more than ~50% from the first ~10% of those steps to the last ~10%
+/// -- e.g. a neighbor-query or cross-partition-sync phase that starts fast
+/// but degrades as the run goes on, which a whole-run average alone would
+/// hide. Uses `Summoner::checkpoint_phase_timings` to isolate each window's
+/// timings: the middle steps' timings are checkpointed (and discarded)
+/// too, so they don't bleed into the "last 10%" window.
+async fn detect_phase_degradation(
+    summoner: &mut Summoner,
+    num_steps: usize,
+) -> anyhow::Result<Vec<String>> {
+    let window = (num_steps / 10).max(1);
+
+    summoner.checkpoint_phase_timings(); // discard warm-up timings
+    for _ in 0..window {
+        summoner.step(0.01).await?;
+    }
+    let early = summoner.checkpoint_phase_timings();
+
+    let middle = num_steps.saturating_sub(window * 2);
+    for _ in 0..middle {
+        summoner.step(0.01).await?;
+    }
+    summoner.checkpoint_phase_timings(); // discard the middle window
+
+    for _ in 0..window {
+        summoner.step(0.01).await?;
     }
+    let late = summoner.checkpoint_phase_timings();
+
+    let mut warnings: Vec<String> = early
+        .iter()
+        .filter_map(|(phase, early_timing)| {
+            let late_timing = late.get(phase)?;
+            if early_timing.avg_ms > 0.0 && late_timing.avg_ms > early_timing.avg_ms * 1.5 {
+                Some(format!(
+                    "{} slowed {:.1}x over the run ({:.4}ms -> {:.4}ms)",
+                    phase,
+                    late_timing.avg_ms / early_timing.avg_ms,
+                    early_timing.avg_ms,
+                    late_timing.avg_ms
+                ))
+            } else {
+                None
+            }
+        })
+        .collect();
+    warnings.sort();
+
+    Ok(warnings)
 }
 
 async fn benchmark_agent_count(
@@ -42,22 +164,22 @@ async fn benchmark_agent_count(
     num_steps: usize,
     num_gpus: usize,
 ) -> anyhow::Result<BenchmarkResult> {
-    println!("\n=== Benchmarking {} agents ===", num_agents);
+    eprintln!("\n=== Benchmarking {} agents ===", num_agents);
+
+    let distribution = DistributionStrategy::SpatialPartitioning {
+        bounds: Vector3::new(10000.0, 10000.0, 1000.0),
+        num_partitions: num_gpus,
+    };
+    let distribution_label = distribution_label(&distribution);
 
     let config = SummonerConfig {
         num_agents,
-        distribution: DistributionStrategy::SpatialPartitioning {
-            bounds: Vector3::new(10000.0, 10000.0, 1000.0),
-            num_partitions: num_gpus,
-        },
+        distribution,
         num_gpus,
-        num_nodes: 1,
         timestep: 0.01,
-        realtime: false,
         enable_monitoring: true,
         metrics_port: None,
-        mpi_rank: None,
-        mpi_world_size: None,
+        ..SummonerConfig::default()
     };
 
     let mut summoner = Summoner::new(config).await?;
@@ -67,67 +189,173 @@ async fn benchmark_agent_count(
         summoner.step(0.01).await?;
     }
 
-    // Benchmark
+    // Benchmark, with per-phase degradation detection comparing the first
+    // ~10% of steps against the last ~10%.
     let start = Instant::now();
-    for _ in 0..num_steps {
-        summoner.step(0.01).await?;
-    }
+    let degradation_warnings = detect_phase_degradation(&mut summoner, num_steps).await?;
     let total_time = start.elapsed();
 
     let metrics = summoner.metrics();
 
     Ok(BenchmarkResult {
         num_agents,
+        num_gpus,
+        distribution: distribution_label,
         num_steps,
-        total_time,
+        total_time_secs: total_time.as_secs_f64(),
         avg_step_time_ms: metrics.avg_step_time_ms,
         min_step_time_ms: metrics.min_step_time_ms,
         max_step_time_ms: metrics.max_step_time_ms,
         steps_per_second: metrics.steps_per_second,
         agents_per_second: metrics.agents_per_second,
         memory_mb: (num_agents * std::mem::size_of::<f64>() * 10) as f64 / 1_048_576.0,
+        degradation_warnings,
     })
 }
 
-async fn benchmark_distribution_strategies(num_agents: usize) -> anyhow::Result<()> {
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║           DISTRIBUTION STRATEGY COMPARISON                   ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
+/// `num_partitions` candidates swept by [`autotune`]. Capped at 32 since
+/// beyond that, per-partition agent counts on a 10-20K-agent run get too
+/// small for coordination overhead to pay for itself.
+const AUTOTUNE_PARTITION_CANDIDATES: &[usize] = &[1, 2, 4, 8, 16, 32];
+
+/// GPU local work-group size candidates swept by [`autotune`] -- the usual
+/// warp/wavefront-aligned sizes, since a non-aligned size wastes a
+/// fraction of every dispatch regardless of partition count.
+const AUTOTUNE_LOCAL_SIZE_CANDIDATES: &[usize] = &[32, 64, 128, 256];
+
+/// Sweep a grid of `num_partitions` x GPU local work-group size candidates
+/// for `num_agents`/`num_gpus`, and return whichever combination minimizes
+/// `avg_step_time_ms`. Each candidate only runs a short warm-up plus a
+/// fixed measurement window (not a full scenario's step count), since this
+/// is meant to be cheap enough to run once per target device rather than
+/// once per full benchmark suite.
+///
+/// The winning `num_partitions`/work-group size should be copied into the
+/// `DistributionStrategy::SpatialPartitioning`/`SummonerConfig` used for
+/// production runs on that hardware -- this function doesn't persist them
+/// anywhere itself.
+async fn autotune(
+    num_agents: usize,
+    num_gpus: usize,
+) -> anyhow::Result<(usize, usize, BenchmarkResult)> {
+    const WARMUP_STEPS: usize = 10;
+    const MEASURE_STEPS: usize = 50;
+
+    eprintln!(
+        "\n=== Auto-tuning partitions/work-group size for {} agents ===",
+        num_agents
+    );
+
+    let mut best: Option<(usize, usize, BenchmarkResult)> = None;
+
+    for &num_partitions in AUTOTUNE_PARTITION_CANDIDATES {
+        if num_partitions > num_agents {
+            continue;
+        }
+        for &local_size in AUTOTUNE_LOCAL_SIZE_CANDIDATES {
+            let config = SummonerConfig {
+                num_agents,
+                distribution: DistributionStrategy::SpatialPartitioning {
+                    bounds: Vector3::new(10000.0, 10000.0, 1000.0),
+                    num_partitions,
+                },
+                num_gpus,
+                timestep: 0.01,
+                enable_monitoring: true,
+                metrics_port: None,
+                gpu_local_work_group_size: Some(local_size),
+                ..SummonerConfig::default()
+            };
+
+            let mut summoner = Summoner::new(config).await?;
+            for _ in 0..WARMUP_STEPS {
+                summoner.step(0.01).await?;
+            }
+
+            let start = Instant::now();
+            for _ in 0..MEASURE_STEPS {
+                summoner.step(0.01).await?;
+            }
+            let total_time = start.elapsed();
+
+            let metrics = summoner.metrics();
+            let result = BenchmarkResult {
+                num_agents,
+                num_gpus,
+                distribution: format!("spatial_partitioning_{}_ws{}", num_partitions, local_size),
+                num_steps: MEASURE_STEPS,
+                total_time_secs: total_time.as_secs_f64(),
+                avg_step_time_ms: metrics.avg_step_time_ms,
+                min_step_time_ms: metrics.min_step_time_ms,
+                max_step_time_ms: metrics.max_step_time_ms,
+                steps_per_second: metrics.steps_per_second,
+                agents_per_second: metrics.agents_per_second,
+                memory_mb: (num_agents * std::mem::size_of::<f64>() * 10) as f64 / 1_048_576.0,
+                degradation_warnings: Vec::new(),
+            };
+
+            eprintln!(
+                "  partitions={:<3} work_group={:<4} avg_step={:.3}ms",
+                num_partitions, local_size, result.avg_step_time_ms
+            );
+
+            let better = match &best {
+                None => true,
+                Some((_, _, current_best)) => {
+                    result.avg_step_time_ms < current_best.avg_step_time_ms
+                }
+            };
+            if better {
+                best = Some((num_partitions, local_size, result));
+            }
+        }
+    }
+
+    Ok(best
+        .expect("AUTOTUNE_PARTITION_CANDIDATES always includes 1, which never exceeds num_agents"))
+}
+
+async fn benchmark_distribution_strategies(
+    num_agents: usize,
+    format: OutputFormat,
+) -> anyhow::Result<Vec<BenchmarkResult>> {
+    eprintln!("\n╔══════════════════════════════════════════════════════════════╗");
+    eprintln!("║           DISTRIBUTION STRATEGY COMPARISON                   ║");
+    eprintln!("╚══════════════════════════════════════════════════════════════╝\n");
 
     let strategies = vec![
-        ("Single Node", DistributionStrategy::SingleNode),
-        (
-            "Spatial 2x2",
-            DistributionStrategy::SpatialPartitioning {
-                bounds: Vector3::new(10000.0, 10000.0, 1000.0),
-                num_partitions: 4,
-            },
-        ),
-        (
-            "Spatial 4x4",
-            DistributionStrategy::SpatialPartitioning {
-                bounds: Vector3::new(10000.0, 10000.0, 1000.0),
-                num_partitions: 16,
-            },
-        ),
+        DistributionStrategy::SingleNode,
+        DistributionStrategy::SpatialPartitioning {
+            bounds: Vector3::new(10000.0, 10000.0, 1000.0),
+            num_partitions: 4,
+        },
+        DistributionStrategy::SpatialPartitioning {
+            bounds: Vector3::new(10000.0, 10000.0, 1000.0),
+            num_partitions: 16,
+        },
+        DistributionStrategy::WeightedPartitioning {
+            bounds: Vector3::new(10000.0, 10000.0, 1000.0),
+            num_partitions: 16,
+            rebalance_interval: 20,
+        },
     ];
 
+    let num_gpus = 4;
+    let num_steps = 100;
     let mut results = Vec::new();
 
-    for (name, strategy) in strategies {
-        println!("Testing: {}", name);
+    for strategy in strategies {
+        let label = distribution_label(&strategy);
+        eprintln!("Testing: {}", label);
 
         let config = SummonerConfig {
             num_agents,
             distribution: strategy,
-            num_gpus: 4,
-            num_nodes: 1,
+            num_gpus,
             timestep: 0.01,
-            realtime: false,
             enable_monitoring: true,
             metrics_port: None,
-            mpi_rank: None,
-            mpi_world_size: None,
+            ..SummonerConfig::default()
         };
 
         let mut summoner = Summoner::new(config).await?;
@@ -139,246 +367,628 @@ async fn benchmark_distribution_strategies(num_agents: usize) -> anyhow::Result<
 
         // Benchmark
         let start = Instant::now();
-        for _ in 0..100 {
-            summoner.step(0.01).await?;
-        }
-        let _elapsed = start.elapsed();
+        let degradation_warnings = detect_phase_degradation(&mut summoner, num_steps).await?;
+        let total_time = start.elapsed();
 
         let metrics = summoner.metrics();
-        results.push((name, metrics.avg_step_time_ms, metrics.agents_per_second));
-
-        println!(
+        eprintln!(
             "  Avg Step: {:.3}ms, Throughput: {:.0} agents/s\n",
             metrics.avg_step_time_ms, metrics.agents_per_second
         );
+
+        results.push(BenchmarkResult {
+            num_agents,
+            num_gpus,
+            distribution: label,
+            num_steps,
+            total_time_secs: total_time.as_secs_f64(),
+            avg_step_time_ms: metrics.avg_step_time_ms,
+            min_step_time_ms: metrics.min_step_time_ms,
+            max_step_time_ms: metrics.max_step_time_ms,
+            steps_per_second: metrics.steps_per_second,
+            agents_per_second: metrics.agents_per_second,
+            memory_mb: (num_agents * std::mem::size_of::<f64>() * 10) as f64 / 1_048_576.0,
+            degradation_warnings,
+        });
     }
 
-    println!("\n=== SUMMARY ===");
-    println!("┌────────────────────┬──────────────┬────────────────────┐");
-    println!("│ Strategy           │ Step Time    │ Throughput         │");
-    println!("├────────────────────┼──────────────┼────────────────────┤");
-    for (name, step_time, throughput) in results {
-        println!(
-            "│ {:<18} │ {:>8.3} ms │ {:>13.0} ag/s │",
-            name, step_time, throughput
-        );
+    if format == OutputFormat::Text {
+        println!("\n=== SUMMARY ===");
+        println!("┌────────────────────┬──────────────┬────────────────────┐");
+        println!("│ Strategy           │ Step Time    │ Throughput         │");
+        println!("├────────────────────┼──────────────┼────────────────────┤");
+        for result in &results {
+            println!(
+                "│ {:<18} │ {:>8.3} ms │ {:>13.0} ag/s │",
+                result.distribution, result.avg_step_time_ms, result.agents_per_second
+            );
+        }
+        println!("└────────────────────┴──────────────┴────────────────────┘");
     }
-    println!("└────────────────────┴──────────────┴────────────────────┘");
 
-    Ok(())
+    Ok(results)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+/// One named benchmark scenario: the agent count/step count/GPU count to
+/// run through [`benchmark_agent_count`], plus the success contract its
+/// measured [`BenchmarkResult`] must clear.
+struct Scenario {
+    name: &'static str,
+    num_agents: usize,
+    num_steps: usize,
+    num_gpus: usize,
+    criteria: SuccessCriteria,
+}
+
+/// Pass/fail thresholds a scenario's [`BenchmarkResult`] is judged
+/// against, e.g. "10K agents must stay under 1ms average and 3ms tail".
+#[derive(Debug, Clone, Copy)]
+struct SuccessCriteria {
+    min_agents_per_second: f64,
+    max_avg_step_time_ms: f64,
+    max_max_step_time_ms: f64,
+}
+
+impl SuccessCriteria {
+    /// Returns one description per threshold the result violated; empty
+    /// means the scenario passed.
+    fn evaluate(&self, result: &BenchmarkResult) -> Vec<String> {
+        let mut failures = Vec::new();
+
+        if result.agents_per_second < self.min_agents_per_second {
+            failures.push(format!(
+                "agents_per_second {:.0} below minimum {:.0}",
+                result.agents_per_second, self.min_agents_per_second
+            ));
+        }
+        if result.avg_step_time_ms > self.max_avg_step_time_ms {
+            failures.push(format!(
+                "avg_step_time_ms {:.3} exceeds maximum {:.3}",
+                result.avg_step_time_ms, self.max_avg_step_time_ms
+            ));
+        }
+        if result.max_step_time_ms > self.max_max_step_time_ms {
+            failures.push(format!(
+                "max_step_time_ms {:.3} exceeds maximum {:.3} (tail latency)",
+                result.max_step_time_ms, self.max_max_step_time_ms
+            ));
+        }
+        if !result.degradation_warnings.is_empty() {
+            failures.push(format!(
+                "phase degradation detected: {}",
+                result.degradation_warnings.join("; ")
+            ));
+        }
+
+        failures
+    }
+}
+
+fn scaling_scenarios() -> Vec<Scenario> {
+    vec![
+        Scenario {
+            name: "100_agents",
+            num_agents: 100,
+            num_steps: 1000,
+            num_gpus: 1,
+            criteria: SuccessCriteria {
+                min_agents_per_second: 50_000.0,
+                max_avg_step_time_ms: 2.0,
+                max_max_step_time_ms: 6.0,
+            },
+        },
+        Scenario {
+            name: "1k_agents",
+            num_agents: 1_000,
+            num_steps: 500,
+            num_gpus: 2,
+            criteria: SuccessCriteria {
+                min_agents_per_second: 200_000.0,
+                max_avg_step_time_ms: 1.0,
+                max_max_step_time_ms: 3.0,
+            },
+        },
+        Scenario {
+            name: "5k_agents",
+            num_agents: 5_000,
+            num_steps: 200,
+            num_gpus: 4,
+            criteria: SuccessCriteria {
+                min_agents_per_second: 500_000.0,
+                max_avg_step_time_ms: 1.0,
+                max_max_step_time_ms: 3.0,
+            },
+        },
+        Scenario {
+            name: "10k_agents",
+            num_agents: 10_000,
+            num_steps: 100,
+            num_gpus: 4,
+            criteria: SuccessCriteria {
+                min_agents_per_second: 1_000_000.0,
+                max_avg_step_time_ms: 1.0,
+                max_max_step_time_ms: 3.0,
+            },
+        },
+        Scenario {
+            name: "20k_agents",
+            num_agents: 20_000,
+            num_steps: 50,
+            num_gpus: 4,
+            criteria: SuccessCriteria {
+                // CRUSHING HADEAN
+                min_agents_per_second: 2_000_000.0,
+                max_avg_step_time_ms: 2.0,
+                max_max_step_time_ms: 6.0,
+            },
+        },
+    ]
+}
+
+struct Cli {
+    format: OutputFormat,
+    baseline: Option<String>,
+    max_regression: f64,
+    /// Agent count to auto-tune for, via `--autotune <num_agents>`. When
+    /// set, `main` only runs [`autotune`] and skips the full benchmark
+    /// suite.
+    autotune: Option<usize>,
+}
+
+fn parse_cli() -> Cli {
+    let mut format = OutputFormat::Text;
+    let mut baseline = None;
+    let mut max_regression = 0.05;
+    let mut autotune = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = args
+                    .next()
+                    .expect("--format requires a value ('text' or 'json')");
+                format = match value.as_str() {
+                    "text" => OutputFormat::Text,
+                    "json" => OutputFormat::Json,
+                    other => panic!("unknown --format '{}': expected 'text' or 'json'", other),
+                };
+            }
+            "--baseline" => {
+                baseline = Some(args.next().expect("--baseline requires a path"));
+            }
+            "--max-regression" => {
+                let value = args.next().expect("--max-regression requires a value");
+                max_regression = value
+                    .parse()
+                    .unwrap_or_else(|_| panic!("--max-regression '{}' is not a float", value));
+            }
+            "--autotune" => {
+                let value = args
+                    .next()
+                    .expect("--autotune requires a value (num_agents)");
+                autotune = Some(
+                    value
+                        .parse()
+                        .unwrap_or_else(|_| panic!("--autotune '{}' is not a usize", value)),
+                );
+            }
+            other => panic!("unknown argument '{}'", other),
+        }
+    }
+
+    Cli {
+        format,
+        baseline,
+        max_regression,
+        autotune,
+    }
+}
+
+/// One metric's baseline-vs-current comparison. `delta_fraction` is
+/// positive when the metric got worse (slower step time / higher memory,
+/// or lower throughput), regardless of which direction "better" points for
+/// that particular metric.
+struct MetricDelta {
+    metric: &'static str,
+    baseline: f64,
+    current: f64,
+    delta_fraction: f64,
+    regressed: bool,
+}
+
+fn compare_metric(
+    metric: &'static str,
+    baseline: f64,
+    current: f64,
+    max_regression: f64,
+    lower_is_better: bool,
+) -> MetricDelta {
+    let delta_fraction = if baseline == 0.0 {
+        0.0
+    } else if lower_is_better {
+        (current - baseline) / baseline
+    } else {
+        (baseline - current) / baseline
+    };
+
+    MetricDelta {
+        metric,
+        baseline,
+        current,
+        delta_fraction,
+        regressed: delta_fraction > max_regression,
+    }
+}
+
+/// Compare every `current` result against its matching baseline result (by
+/// [`BenchmarkResult::key`]), printing a report for anything that
+/// regressed beyond `max_regression`. Returns whether any regression was
+/// found. Results with no matching baseline scenario are skipped, not
+/// treated as regressions.
+fn check_regressions(
+    current: &[BenchmarkResult],
+    baseline: &BenchmarkRun,
+    max_regression: f64,
+) -> bool {
+    let mut any_regression = false;
+
+    for result in current {
+        let Some(base) = baseline.all_results().find(|b| b.key() == result.key()) else {
+            continue;
+        };
+
+        let deltas = [
+            compare_metric(
+                "avg_step_time_ms",
+                base.avg_step_time_ms,
+                result.avg_step_time_ms,
+                max_regression,
+                true,
+            ),
+            compare_metric(
+                "steps_per_second",
+                base.steps_per_second,
+                result.steps_per_second,
+                max_regression,
+                false,
+            ),
+            compare_metric(
+                "agents_per_second",
+                base.agents_per_second,
+                result.agents_per_second,
+                max_regression,
+                false,
+            ),
+            compare_metric(
+                "memory_mb",
+                base.memory_mb,
+                result.memory_mb,
+                max_regression,
+                true,
+            ),
+        ];
+
+        let regressed: Vec<&MetricDelta> = deltas.iter().filter(|d| d.regressed).collect();
+        if !regressed.is_empty() {
+            any_regression = true;
+            eprintln!(
+                "REGRESSION: agents={} gpus={} distribution={}",
+                result.num_agents, result.num_gpus, result.distribution
+            );
+            for delta in regressed {
+                eprintln!(
+                    "  {:<20} baseline={:.4} current={:.4} delta={:+.1}%",
+                    delta.metric,
+                    delta.baseline,
+                    delta.current,
+                    delta.delta_fraction * 100.0
+                );
+            }
+        }
+    }
+
+    any_regression
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║         SUMMONER PERFORMANCE BENCHMARK SUITE                  ║");
-    println!("║              PROVING DOMINANCE OVER HADEAN                   ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
-
-    println!("System Configuration:");
-    println!("  CPU: Multi-core");
-    println!("  Test Date: November 4, 2025");
-    println!("  Configuration: 4 GPUs (simulated), Single Node");
-    println!();
-
-    // === Scaling Benchmark ===
-    println!("╔══════════════════════════════════════════════════════════════╗");
-    println!("║                 AGENT SCALING BENCHMARK                      ║");
-    println!("╚══════════════════════════════════════════════════════════════╝");
-
-    let test_configs = vec![
-        (100, 1000, 1),   // 100 agents, 1000 steps, 1 GPU
-        (1_000, 500, 2),  // 1K agents, 500 steps, 2 GPUs
-        (5_000, 200, 4),  // 5K agents, 200 steps, 4 GPUs
-        (10_000, 100, 4), // 10K agents, 100 steps, 4 GPUs
-        (20_000, 50, 4),  // 20K agents, 50 steps, 4 GPUs (CRUSHING HADEAN)
-    ];
+    let cli = parse_cli();
+    let format = cli.format;
+
+    if let Some(num_agents) = cli.autotune {
+        let (best_partitions, best_local_size, result) = autotune(num_agents, 4).await?;
+        if format == OutputFormat::Json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "num_agents": num_agents,
+                    "best_partitions": best_partitions,
+                    "best_local_size": best_local_size,
+                    "result": result,
+                }))?
+            );
+        } else {
+            println!("\nBest configuration for {} agents:", num_agents);
+            println!("  num_partitions:   {}", best_partitions);
+            println!("  local_size:       {}", best_local_size);
+            result.print();
+        }
+        return Ok(());
+    }
+
+    if format == OutputFormat::Text {
+        println!("\n╔══════════════════════════════════════════════════════════════╗");
+        println!("║         SUMMONER PERFORMANCE BENCHMARK SUITE                  ║");
+        println!("║              PROVING DOMINANCE OVER HADEAN                   ║");
+        println!("╚══════════════════════════════════════════════════════════════╝\n");
+
+        println!("System Configuration:");
+        println!("  CPU: Multi-core");
+        println!("  Test Date: November 4, 2025");
+        println!("  Configuration: 4 GPUs (simulated), Single Node");
+        println!();
+
+        // === Scaling Benchmark ===
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                 AGENT SCALING BENCHMARK                      ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+    }
 
+    let scenarios = scaling_scenarios();
     let mut results = Vec::new();
+    let mut failed_scenarios: Vec<(&'static str, Vec<String>)> = Vec::new();
+
+    for scenario in &scenarios {
+        let result =
+            benchmark_agent_count(scenario.num_agents, scenario.num_steps, scenario.num_gpus)
+                .await?;
+        let failures = scenario.criteria.evaluate(&result);
+
+        if format == OutputFormat::Text {
+            result.print();
+            if failures.is_empty() {
+                println!("  Result:           PASS");
+            } else {
+                println!("  Result:           FAIL");
+                for failure in &failures {
+                    println!("    - {}", failure);
+                }
+            }
+        } else {
+            for failure in &failures {
+                eprintln!("[{}] FAIL: {}", scenario.name, failure);
+            }
+        }
 
-    for (num_agents, num_steps, num_gpus) in test_configs {
-        let result = benchmark_agent_count(num_agents, num_steps, num_gpus).await?;
-        result.print();
+        if !failures.is_empty() {
+            failed_scenarios.push((scenario.name, failures));
+        }
         results.push(result);
     }
 
-    // === Performance Summary ===
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║              PERFORMANCE SUMMARY TABLE                       ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
+    if format == OutputFormat::Text {
+        // === Performance Summary ===
+        println!("\n╔══════════════════════════════════════════════════════════════╗");
+        println!("║              PERFORMANCE SUMMARY TABLE                       ║");
+        println!("╚══════════════════════════════════════════════════════════════╝\n");
+
+        println!("┌─────────┬────────┬─────────────┬─────────────┬──────────────────┐");
+        println!("│ Agents  │ GPUs   │ Step Time   │ Steps/Sec   │ Agents/Sec       │");
+        println!("├─────────┼────────┼─────────────┼─────────────┼──────────────────┤");
+
+        for result in &results {
+            println!(
+                "│ {:>7} │ {:>6} │ {:>7.3} ms │ {:>9.1} │ {:>16.0} │",
+                result.num_agents,
+                result.num_gpus,
+                result.avg_step_time_ms,
+                result.steps_per_second,
+                result.agents_per_second
+            );
+        }
+
+        println!("└─────────┴────────┴─────────────┴─────────────┴──────────────────┘");
+
+        // === HADEAN Comparison ===
+        println!("\n╔══════════════════════════════════════════════════════════════╗");
+        println!("║          SUMMONER vs HADEAN: DEFINITIVE PROOF                 ║");
+        println!("╚══════════════════════════════════════════════════════════════╝\n");
 
-    println!("┌─────────┬────────┬─────────────┬─────────────┬──────────────────┐");
-    println!("│ Agents  │ GPUs   │ Step Time   │ Steps/Sec   │ Agents/Sec       │");
-    println!("├─────────┼────────┼─────────────┼─────────────┼──────────────────┤");
+        println!("HADEAN Documented Limits:");
+        println!("  Max Agents:        ~100");
+        println!("  Throughput:        ~10,000 agents/second");
+        println!("  Platform:          Cloud-only (no edge deployment)");
+        println!("  RF Models:         Basic line-of-sight");
+        println!("  EW Capability:     None");
+        println!();
 
-    for result in &results {
+        println!("SUMMONER Measured Performance:");
+        let result_10k = results.iter().find(|r| r.num_agents == 10_000).unwrap();
+        let result_20k = results.iter().find(|r| r.num_agents == 20_000).unwrap();
+
+        println!("  Max Agents:        20,000+ (200x HADEAN)");
         println!(
-            "│ {:>7} │ {:>6} │ {:>7.3} ms │ {:>9.1} │ {:>16.0} │",
-            result.num_agents,
-            if result.num_agents <= 100 {
-                1
-            } else if result.num_agents <= 1000 {
-                2
-            } else {
-                4
-            },
-            result.avg_step_time_ms,
-            result.steps_per_second,
-            result.agents_per_second
+            "  Throughput:        {:.0} agents/second ({:.0}x HADEAN)",
+            result_10k.agents_per_second,
+            result_10k.agents_per_second / 10_000.0
         );
-    }
+        println!("  Platform:          Edge + Cloud (tactical deployment)");
+        println!("  RF Models:         7 physics-based models");
+        println!("  EW Capability:     5 jamming techniques + network resilience");
+        println!();
 
-    println!("└─────────┴────────┴─────────────┴─────────────┴──────────────────┘");
-
-    // === HADEAN Comparison ===
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║          SUMMONER vs HADEAN: DEFINITIVE PROOF                 ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
-
-    println!("HADEAN Documented Limits:");
-    println!("  Max Agents:        ~100");
-    println!("  Throughput:        ~10,000 agents/second");
-    println!("  Platform:          Cloud-only (no edge deployment)");
-    println!("  RF Models:         Basic line-of-sight");
-    println!("  EW Capability:     None");
-    println!();
-
-    println!("SUMMONER Measured Performance:");
-    let result_10k = results.iter().find(|r| r.num_agents == 10_000).unwrap();
-    let result_20k = results.iter().find(|r| r.num_agents == 20_000).unwrap();
-
-    println!("  Max Agents:        20,000+ (200x HADEAN)");
-    println!(
-        "  Throughput:        {:.0} agents/second ({:.0}x HADEAN)",
-        result_10k.agents_per_second,
-        result_10k.agents_per_second / 10_000.0
-    );
-    println!("  Platform:          Edge + Cloud (tactical deployment)");
-    println!("  RF Models:         7 physics-based models");
-    println!("  EW Capability:     5 jamming techniques + network resilience");
-    println!();
-
-    println!("COMPETITIVE ADVANTAGE:");
-    println!("  ✅ Agent Scale:     200x more agents");
-    println!(
-        "  ✅ Throughput:      {:.0}x faster",
-        result_10k.agents_per_second / 10_000.0
-    );
-    println!(
-        "  ✅ Latency:         {:.3}ms @ 10K agents (HADEAN fails)",
-        result_10k.avg_step_time_ms
-    );
-    println!(
-        "  ✅ Latency:         {:.3}ms @ 20K agents (200x HADEAN limit)",
-        result_20k.avg_step_time_ms
-    );
-    println!("  ✅ Edge Deployment: Real-time tactical operations");
-    println!("  ✅ EW Simulation:   Mission-critical contested comms");
-    println!();
+        println!("COMPETITIVE ADVANTAGE:");
+        println!("  ✅ Agent Scale:     200x more agents");
+        println!(
+            "  ✅ Throughput:      {:.0}x faster",
+            result_10k.agents_per_second / 10_000.0
+        );
+        println!(
+            "  ✅ Latency:         {:.3}ms @ 10K agents (HADEAN fails)",
+            result_10k.avg_step_time_ms
+        );
+        println!(
+            "  ✅ Latency:         {:.3}ms @ 20K agents (200x HADEAN limit)",
+            result_20k.avg_step_time_ms
+        );
+        println!("  ✅ Edge Deployment: Real-time tactical operations");
+        println!("  ✅ EW Simulation:   Mission-critical contested comms");
+        println!();
+    }
 
     // === Distribution Strategy Benchmark ===
-    benchmark_distribution_strategies(10_000).await?;
+    let distribution_results = benchmark_distribution_strategies(10_000, format).await?;
+
+    if format == OutputFormat::Text {
+        // === Scaling Curve Analysis ===
+        println!("\n╔══════════════════════════════════════════════════════════════╗");
+        println!("║              SCALING EFFICIENCY ANALYSIS                     ║");
+        println!("╚══════════════════════════════════════════════════════════════╝\n");
+
+        println!("Testing scaling efficiency from 100 to 20,000 agents:\n");
+
+        let base_result = results.iter().find(|r| r.num_agents == 100).unwrap();
+        let base_throughput = base_result.agents_per_second / 100.0; // per-agent throughput
+
+        println!("┌─────────┬──────────────────┬────────────────────┬──────────────┐");
+        println!("│ Agents  │ Per-Agent Time   │ Scaling Efficiency │ Status       │");
+        println!("├─────────┼──────────────────┼────────────────────┼──────────────┤");
+
+        for result in &results {
+            let per_agent_throughput = result.agents_per_second / result.num_agents as f64;
+            let efficiency = (per_agent_throughput / base_throughput) * 100.0;
+            let status = if efficiency > 80.0 {
+                "✅ Excellent"
+            } else if efficiency > 60.0 {
+                "✓ Good"
+            } else {
+                "⚠ Acceptable"
+            };
+
+            println!(
+                "│ {:>7} │ {:>12.6} ms │ {:>17.1}% │ {:<12} │",
+                result.num_agents,
+                (1000.0 / per_agent_throughput),
+                efficiency,
+                status
+            );
+        }
 
-    // === Scaling Curve Analysis ===
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║              SCALING EFFICIENCY ANALYSIS                     ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
+        println!("└─────────┴──────────────────┴────────────────────┴──────────────┘");
 
-    println!("Testing scaling efficiency from 100 to 20,000 agents:\n");
+        // === Final Verdict ===
+        println!("\n╔══════════════════════════════════════════════════════════════╗");
+        println!("║                     FINAL VERDICT                            ║");
+        println!("╚══════════════════════════════════════════════════════════════╝\n");
 
-    let base_result = results.iter().find(|r| r.num_agents == 100).unwrap();
-    let base_throughput = base_result.agents_per_second / 100.0; // per-agent throughput
+        let result_10k = results.iter().find(|r| r.num_agents == 10_000).unwrap();
+        let result_20k = results.iter().find(|r| r.num_agents == 20_000).unwrap();
 
-    println!("┌─────────┬──────────────────┬────────────────────┬──────────────┐");
-    println!("│ Agents  │ Per-Agent Time   │ Scaling Efficiency │ Status       │");
-    println!("├─────────┼──────────────────┼────────────────────┼──────────────┤");
-
-    for result in &results {
-        let per_agent_throughput = result.agents_per_second / result.num_agents as f64;
-        let efficiency = (per_agent_throughput / base_throughput) * 100.0;
-        let status = if efficiency > 80.0 {
-            "✅ Excellent"
-        } else if efficiency > 60.0 {
-            "✓ Good"
-        } else {
-            "⚠ Acceptable"
-        };
+        println!("PROOF ESTABLISHED:");
+        println!();
+        println!("1. SCALE: SUMMONER handles 20,000 agents (200x HADEAN's ~100 limit)");
+        println!("   → HADEAN: Cannot simulate beyond ~100 agents");
+        println!("   → SUMMONER: Benchmarked at 20,000 agents with excellent performance");
+        println!();
 
         println!(
-            "│ {:>7} │ {:>12.6} ms │ {:>17.1}% │ {:<12} │",
-            result.num_agents,
-            (1000.0 / per_agent_throughput),
-            efficiency,
-            status
+            "2. THROUGHPUT: SUMMONER achieves {:.0} agents/second ({:.0}x HADEAN)",
+            result_10k.agents_per_second,
+            result_10k.agents_per_second / 10_000.0
         );
-    }
+        println!("   → HADEAN: ~10,000 agents/second (documented)");
+        println!(
+            "   → SUMMONER: {:.0} agents/second (measured)",
+            result_10k.agents_per_second
+        );
+        println!();
 
-    println!("└─────────┴──────────────────┴────────────────────┴──────────────┘");
+        println!("3. LATENCY: SUMMONER maintains <1ms at 10,000 agents");
+        println!("   → HADEAN: N/A (fails at this scale)");
+        println!(
+            "   → SUMMONER: {:.3}ms average @ 10K agents",
+            result_10k.avg_step_time_ms
+        );
+        println!();
 
-    // === Final Verdict ===
-    println!("\n╔══════════════════════════════════════════════════════════════╗");
-    println!("║                     FINAL VERDICT                            ║");
-    println!("╚══════════════════════════════════════════════════════════════╝\n");
+        println!("4. SCALING: SUMMONER scales efficiently to 200x HADEAN's limit");
+        println!("   → 100 agents:   {:.3}ms", results[0].avg_step_time_ms);
+        println!(
+            "   → 10,000 agents: {:.3}ms (100x scale)",
+            result_10k.avg_step_time_ms
+        );
+        println!(
+            "   → 20,000 agents: {:.3}ms (200x scale)",
+            result_20k.avg_step_time_ms
+        );
+        println!();
+
+        println!("5. OPERATIONAL CAPABILITIES:");
+        println!("   → Multi-GPU distribution: ✅ Implemented");
+        println!("   → Spatial partitioning: ✅ Benchmarked");
+        println!("   → Real-time performance: ✅ Verified (<1ms @ 10K)");
+        println!("   → Tactical edge deployment: ✅ Enabled");
+        println!("   → RF propagation (7 models): ✅ Integrated");
+        println!("   → EW simulation (5 types): ✅ Operational");
+        println!();
+
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                    Q.E.D. - PROVEN                           ║");
+        println!("║                                                              ║");
+        println!(
+            "║  SUMMONER provides 200x agent scale and {}x throughput    ║",
+            (result_10k.agents_per_second / 10_000.0) as i64
+        );
+        println!("║  advantage over HADEAN. HADEAN cannot compete at this scale. ║");
+        println!("║                                                              ║");
+        println!("║         AutonomySim/SUMMONER: MARKET DOMINATION              ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+    }
 
-    println!("PROOF ESTABLISHED:");
-    println!();
-    println!("1. SCALE: SUMMONER handles 20,000 agents (200x HADEAN's ~100 limit)");
-    println!("   → HADEAN: Cannot simulate beyond ~100 agents");
-    println!("   → SUMMONER: Benchmarked at 20,000 agents with excellent performance");
-    println!();
+    let run = BenchmarkRun {
+        system: SystemInfo {
+            cpu: "Multi-core".to_string(),
+            test_date: "2025-11-04".to_string(),
+            configuration: "4 GPUs (simulated), Single Node".to_string(),
+        },
+        scaling: results,
+        distribution_comparison: distribution_results,
+    };
 
-    println!(
-        "2. THROUGHPUT: SUMMONER achieves {:.0} agents/second ({:.0}x HADEAN)",
-        result_10k.agents_per_second,
-        result_10k.agents_per_second / 10_000.0
-    );
-    println!("   → HADEAN: ~10,000 agents/second (documented)");
-    println!(
-        "   → SUMMONER: {:.0} agents/second (measured)",
-        result_10k.agents_per_second
-    );
-    println!();
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&run)?);
+    }
 
-    println!("3. LATENCY: SUMMONER maintains <1ms at 10,000 agents");
-    println!("   → HADEAN: N/A (fails at this scale)");
-    println!(
-        "   → SUMMONER: {:.3}ms average @ 10K agents",
-        result_10k.avg_step_time_ms
-    );
-    println!();
+    if let Some(baseline_path) = &cli.baseline {
+        let baseline_json = std::fs::read_to_string(baseline_path)?;
+        let baseline_run: BenchmarkRun = serde_json::from_str(&baseline_json)?;
+        let all_current: Vec<BenchmarkResult> = run.all_results().cloned().collect();
+
+        if check_regressions(&all_current, &baseline_run, cli.max_regression) {
+            eprintln!(
+                "\nOne or more metrics regressed beyond the {:.1}% tolerance.",
+                cli.max_regression * 100.0
+            );
+            std::process::exit(1);
+        }
+    }
 
-    println!("4. SCALING: SUMMONER scales efficiently to 200x HADEAN's limit");
-    println!("   → 100 agents:   {:.3}ms", results[0].avg_step_time_ms);
-    println!(
-        "   → 10,000 agents: {:.3}ms (100x scale)",
-        result_10k.avg_step_time_ms
-    );
-    println!(
-        "   → 20,000 agents: {:.3}ms (200x scale)",
-        result_20k.avg_step_time_ms
-    );
-    println!();
-
-    println!("5. OPERATIONAL CAPABILITIES:");
-    println!("   → Multi-GPU distribution: ✅ Implemented");
-    println!("   → Spatial partitioning: ✅ Benchmarked");
-    println!("   → Real-time performance: ✅ Verified (<1ms @ 10K)");
-    println!("   → Tactical edge deployment: ✅ Enabled");
-    println!("   → RF propagation (7 models): ✅ Integrated");
-    println!("   → EW simulation (5 types): ✅ Operational");
-    println!();
-
-    println!("╔══════════════════════════════════════════════════════════════╗");
-    println!("║                    Q.E.D. - PROVEN                           ║");
-    println!("║                                                              ║");
-    println!(
-        "║  SUMMONER provides 200x agent scale and {}x throughput    ║",
-        (result_10k.agents_per_second / 10_000.0) as i64
-    );
-    println!("║  advantage over HADEAN. HADEAN cannot compete at this scale. ║");
-    println!("║                                                              ║");
-    println!("║         AutonomySim/SUMMONER: MARKET DOMINATION              ║");
-    println!("╚══════════════════════════════════════════════════════════════╝");
+    if !failed_scenarios.is_empty() {
+        eprintln!("\nScenarios that failed their success criteria:");
+        for (name, failures) in &failed_scenarios {
+            for failure in failures {
+                eprintln!("  [{}] {}", name, failure);
+            }
+        }
+        std::process::exit(1);
+    }
 
     Ok(())
 }
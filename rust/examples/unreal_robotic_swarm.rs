@@ -8,19 +8,21 @@
 //! - Multi-role coordination in realistic urban environment
 
 use autonomysim_core::prelude::*;
+use autonomysim_core::swarm::{FlockingController, RoleSpacing};
 use autonomysim_core::vehicle::VehicleParameters;
 use autonomysim_rf_core::prelude::*;
-use autonomysim_summoner::{DistributionStrategy, Summoner, SummonerConfig};
-use autonomysim_tactical::{JammingConfig, JammingType};
+use autonomysim_summoner::{BroadPhase, DistributionStrategy, Summoner, SummonerConfig};
+use autonomysim_tactical::{
+    nearest_target, DamageConfig, DamageModel, JammingConfig, JammingType, TargetClass, Weapon,
+    WeaponConfig,
+};
 use nalgebra::{Point3, UnitQuaternion, Vector3};
 use std::sync::Arc;
 use tokio;
 use tracing::{info, warn};
 
 #[cfg(feature = "unreal")]
-use autonomysim_backends::unreal::{
-    RobotPositionUpdate, RobotSpawnData, RobotTelemetry, RobotType,
-};
+use autonomysim_backends::unreal::{RobotPositionUpdate, RobotSpawnData, RobotTelemetry};
 #[cfg(feature = "unreal")]
 use autonomysim_backends::UnrealEngine5Backend;
 
@@ -54,6 +56,17 @@ impl RobotRole {
         }
     }
 
+    /// Key this role flocks under in [`FlockingController::set_role_spacing`].
+    fn flock_role(&self) -> &'static str {
+        match self {
+            RobotRole::Scout => "scout",
+            RobotRole::Transport => "transport",
+            RobotRole::Combat => "combat",
+            RobotRole::Relay => "relay",
+            RobotRole::Coordinator => "coordinator",
+        }
+    }
+
     fn altitude(&self) -> f64 {
         match self {
             RobotRole::Scout => 50.0,
@@ -64,18 +77,27 @@ impl RobotRole {
         }
     }
 
+    /// Name of the `RobotPrototype` (in the built-in `PrototypeRegistry`)
+    /// that renders this role in Unreal Engine.
     #[cfg(feature = "unreal")]
-    fn to_robot_type(&self) -> RobotType {
+    fn to_prototype_name(&self) -> &'static str {
         match self {
-            RobotRole::Scout => RobotType::Scout,
-            RobotRole::Transport => RobotType::Transport,
-            RobotRole::Combat => RobotType::Combat,
-            RobotRole::Relay => RobotType::Relay,
-            RobotRole::Coordinator => RobotType::Coordinator,
+            RobotRole::Scout => "scout",
+            RobotRole::Transport => "transport",
+            RobotRole::Combat => "combat",
+            RobotRole::Relay => "relay",
+            RobotRole::Coordinator => "coordinator",
         }
     }
 }
 
+/// How often (in steps) relay coverage is recomputed from real RF link
+/// budgets; an O(n*k) link-budget sweep every 20ms tick isn't worth paying.
+const RELAY_REFRESH_STEPS: u64 = 50;
+
+/// Minimum SNR (dB) for an agent-to-relay link to count as usable.
+const RELAY_MIN_SNR_DB: f64 = 10.0;
+
 #[derive(Clone, Debug)]
 struct RobotConfig {
     vehicle_id: VehicleId,
@@ -89,6 +111,7 @@ struct RobotConfig {
     health: f32,
     signal_dbm: f32,
     is_jammed: bool,
+    destroyed: bool,
 }
 
 /// Main demonstration struct
@@ -106,9 +129,39 @@ struct UnrealRoboticSwarm {
     // Distributed simulation
     summoner: Summoner,
 
+    // Decentralized Lennard-Jones flocking, run each step against the
+    // local neighbor set SUMMONER's broad phase reports.
+    flocking: FlockingController,
+    broad_phase: BroadPhase,
+
     // Robot fleet
     robots: Vec<RobotConfig>,
 
+    // Per-robot lifecycle FSM (same index as `robots`), replacing the old
+    // single fixed spawn-to-target path with declarative, guarded
+    // transitions keyed off each robot's battery/health/jamming state.
+    state_machines: Vec<StateMachine>,
+
+    // One weapon per Combat robot (same index as `robots`, `None` for every
+    // other role); engages the nearest live `threats` entry in range each
+    // step.
+    weapons: Vec<Option<Weapon>>,
+
+    // Static hostile ground contacts Combat robots engage; a threat is
+    // removed once its hit-point pool is destroyed.
+    threats: Vec<Point3<f64>>,
+    threat_hp: Vec<f64>,
+
+    // Kill-probability model shared by both engagement directions: Combat
+    // robots firing on `threats`, and `threats` firing back on whichever
+    // robot is nearest them.
+    damage_model: DamageModel,
+
+    // Relay-selected connectivity from real RF link budgets (see
+    // `step_connectivity`), replacing the old flat `n*(n-1)/2` link count.
+    relay_topology: RelayTopology,
+    mesh_accounting: MessageAccounting,
+
     // Metrics
     step_count: usize,
     active_links: usize,
@@ -188,9 +241,89 @@ impl UnrealRoboticSwarm {
 
         let summoner = Summoner::new(summoner_config).await?;
 
+        // Initialize decentralized flocking: scouts pack tighter than
+        // transports, relays and coordinators spread out to hold coverage.
+        info!("  Initializing flocking controller...");
+        let mut flocking = FlockingController::new(
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.0,
+            },
+            25.0,
+            8.0,
+        );
+        flocking.set_role_spacing(
+            RobotRole::Scout.flock_role(),
+            RoleSpacing {
+                target: 3.0,
+                epsilon: 2.0,
+            },
+        );
+        flocking.set_role_spacing(
+            RobotRole::Transport.flock_role(),
+            RoleSpacing {
+                target: 8.0,
+                epsilon: 1.0,
+            },
+        );
+        flocking.set_role_spacing(
+            RobotRole::Combat.flock_role(),
+            RoleSpacing {
+                target: 5.0,
+                epsilon: 1.5,
+            },
+        );
+        flocking.set_role_spacing(
+            RobotRole::Relay.flock_role(),
+            RoleSpacing {
+                target: 12.0,
+                epsilon: 1.0,
+            },
+        );
+        flocking.set_role_spacing(
+            RobotRole::Coordinator.flock_role(),
+            RoleSpacing {
+                target: 15.0,
+                epsilon: 0.5,
+            },
+        );
+        let broad_phase = BroadPhase::new(12.5);
+
         // Generate robot fleet
         info!("  Generating robot fleet and spawning in UE5...");
         let robots = Self::generate_robot_fleet(num_robots);
+        let state_machines = robots.iter().map(Self::build_state_machine).collect();
+
+        info!("  Arming Combat robots...");
+        let weapons = robots
+            .iter()
+            .map(|robot| {
+                (robot.role == RobotRole::Combat).then(|| {
+                    Weapon::new(WeaponConfig {
+                        damage: 25.0,
+                        range_m: 40.0,
+                        cooldown_secs: 2.0,
+                        valid_targets: vec![TargetClass::Ground],
+                    })
+                })
+            })
+            .collect();
+
+        // Five static hostile ground contacts scattered through the urban
+        // area, each with its own hit-point pool.
+        let threats = vec![
+            Point3::new(60.0, 0.0, 0.0),
+            Point3::new(-60.0, 30.0, 0.0),
+            Point3::new(0.0, -60.0, 0.0),
+            Point3::new(40.0, 40.0, 0.0),
+            Point3::new(-40.0, -40.0, 0.0),
+        ];
+        let threat_hp = vec![75.0; threats.len()];
+        let damage_model = DamageModel::new(DamageConfig {
+            full_damage_dist: 10.0,
+            max_damage_dist: 40.0,
+            ..Default::default()
+        });
 
         info!("✓ AutonomySim Unreal Engine initialization complete!");
         info!("✓ Unreal Engine 5 connected and ready for rendering");
@@ -201,7 +334,19 @@ impl UnrealRoboticSwarm {
             rf_engine,
             jamming_config,
             summoner,
+            flocking,
+            broad_phase,
             robots,
+            state_machines,
+            weapons,
+            threats,
+            threat_hp,
+            damage_model,
+            relay_topology: RelayTopology::default(),
+            mesh_accounting: MessageAccounting {
+                naive_transmissions: 0,
+                relayed_transmissions: 0,
+            },
             step_count: 0,
             active_links: 0,
             jamming_active: false,
@@ -270,6 +415,7 @@ impl UnrealRoboticSwarm {
                     health: 100.0,
                     signal_dbm: -50.0,
                     is_jammed: false,
+                    destroyed: false,
                 });
 
                 id_counter += 1;
@@ -279,6 +425,116 @@ impl UnrealRoboticSwarm {
         robots
     }
 
+    /// Build one robot's lifecycle FSM: it takes off immediately, then
+    /// flies under flocking toward its target until its battery drops below
+    /// 20% (scouts/transport) or it takes a jamming hit while unarmored
+    /// (combat), at which point it falls back to [`RobotState::Return`]
+    /// instead of continuing its mission.
+    fn build_state_machine(robot: &RobotConfig) -> StateMachine {
+        let mut fsm = StateMachine::new(RobotState::TurnedOff);
+        fsm.add_transition(RobotState::TurnedOff, RobotState::TakeOff, |_| true);
+        fsm.add_transition(RobotState::TakeOff, RobotState::Flocking, |_| true);
+        fsm.add_transition(RobotState::Flocking, RobotState::Return, |ctx| {
+            ctx.battery_fraction < 0.2
+        });
+        if robot.role == RobotRole::Combat {
+            fsm.add_transition(RobotState::Flocking, RobotState::Return, |ctx| {
+                ctx.is_jammed
+            });
+        }
+        fsm
+    }
+
+    /// Advance every robot's [`StateMachine`] from its current
+    /// battery/health/jamming telemetry, and send any robot now in
+    /// [`RobotState::Return`] back toward the origin instead of its
+    /// original mission target.
+    fn step_behavior(&mut self) {
+        for (robot, fsm) in self.robots.iter_mut().zip(self.state_machines.iter_mut()) {
+            let ctx = StateMachineContext {
+                battery_fraction: (robot.battery / 100.0) as f64,
+                health_fraction: (robot.health / 100.0) as f64,
+                is_jammed: robot.is_jammed,
+            };
+            fsm.step(&ctx);
+            if fsm.state() == RobotState::Return {
+                robot.target = Point3::origin();
+            }
+        }
+    }
+
+    /// Resolve this step's combat engagements: each armed Combat robot
+    /// fires on its nearest live threat in range, and each surviving threat
+    /// fires back on whichever Combat robot is nearest it. Both directions
+    /// scale damage by [`DamageModel::kill_probability`] deterministically
+    /// rather than rolling a random hit, so a run is reproducible without
+    /// this example depending on `rand` directly.
+    async fn step_combat(&mut self, dt: f64) {
+        let threat_positions: Vec<Vector3<f64>> = self.threats.iter().map(|t| t.coords).collect();
+
+        for (robot, weapon_slot) in self.robots.iter().zip(self.weapons.iter_mut()) {
+            if robot.destroyed {
+                continue;
+            }
+            let Some(weapon) = weapon_slot else {
+                continue;
+            };
+            let Some((target_idx, distance)) = nearest_target(
+                robot.position.coords,
+                &threat_positions,
+                weapon.config().range_m,
+            ) else {
+                continue;
+            };
+            if weapon.can_attack(TargetClass::Ground, distance, dt) {
+                let damage = weapon.fire();
+                self.threat_hp[target_idx] -= damage;
+            }
+        }
+
+        let mut i = 0;
+        while i < self.threats.len() {
+            if self.threat_hp[i] <= 0.0 {
+                self.threats.remove(i);
+                self.threat_hp.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        const THREAT_ENGAGEMENT_RANGE: f64 = 40.0;
+        const THREAT_DAMAGE_AT_FULL: f32 = 20.0;
+        for &threat in &self.threats {
+            let nearest = self
+                .robots
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| !r.destroyed && r.role == RobotRole::Combat)
+                .map(|(i, r)| (i, (r.position - threat).norm()))
+                .filter(|(_, distance)| *distance <= THREAT_ENGAGEMENT_RANGE)
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+
+            let Some((i, distance)) = nearest else {
+                continue;
+            };
+            let kill_probability = self.damage_model.kill_probability(distance);
+            self.robots[i].health -= THREAT_DAMAGE_AT_FULL * kill_probability as f32;
+
+            if self.robots[i].health <= 0.0 && !self.robots[i].destroyed {
+                self.robots[i].health = 0.0;
+                self.robots[i].destroyed = true;
+                warn!("{} destroyed", self.robots[i].vehicle_id);
+                #[cfg(feature = "unreal")]
+                {
+                    let _ = self
+                        .unreal_backend
+                        .remove_vehicle_tracked(&self.robots[i].vehicle_id)
+                        .await;
+                }
+            }
+        }
+    }
+
     /// Spawn all robots in Unreal Engine
     async fn spawn_robots_in_unreal(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         info!("Spawning {} robots in Unreal Engine...", self.robots.len());
@@ -315,6 +571,22 @@ impl UnrealRoboticSwarm {
     async fn step(&mut self, dt: f64) -> Result<(), Box<dyn std::error::Error>> {
         self.step_count += 1;
 
+        // Advance each robot's lifecycle FSM before flocking runs, so a
+        // robot that just dropped into `Return` heads for the origin this
+        // step rather than one step late.
+        self.step_behavior();
+
+        // Resolve this step's engagements between Combat robots and threats
+        // before flocking moves anyone, so damage this step is based on
+        // last step's positions rather than lagging by a step.
+        self.step_combat(dt).await;
+
+        // Run decentralized flocking: each robot senses its local neighbor
+        // set from SUMMONER's broad phase and pulls toward/away from it via
+        // a Lennard-Jones interaction, so the fleet self-organizes into a
+        // lattice instead of drifting toward its target in isolation.
+        self.step_flocking(dt);
+
         // Step SUMMONER (distributed physics simulation)
         self.summoner.step(dt).await?;
 
@@ -322,22 +594,98 @@ impl UnrealRoboticSwarm {
         #[cfg(feature = "unreal")]
         self.unreal_backend.step(dt).await?;
 
-        // Simulate RF communications
-        let num_robots = self.robots.len();
-        self.active_links = (num_robots * (num_robots - 1)) / 2;
-
         // Toggle jamming periodically
         if self.step_count % 600 == 0 {
             self.jamming_active = !self.jamming_active;
-            if self.jamming_active {
-                self.active_links = (self.active_links as f64 * 0.333) as usize;
-                // 33% links survive
-            }
+        }
+
+        // Recompute relay-selected connectivity from real RF link budgets
+        // instead of assuming a flat fraction of links survive -- jamming
+        // now actually drops a candidate link below `RELAY_MIN_SNR_DB`
+        // instead of just scaling a placeholder count. An O(n*k) link-budget
+        // sweep isn't worth paying every 20ms tick, so this only runs every
+        // `RELAY_REFRESH_STEPS` steps; `active_links` and `mesh_accounting`
+        // simply hold their last value between refreshes.
+        if self.step_count % RELAY_REFRESH_STEPS == 0 {
+            self.step_connectivity().await?;
         }
 
         Ok(())
     }
 
+    /// Recompute which robots each `Relay`/`Coordinator` robot can reach
+    /// over a real RF link budget (see [`autonomysim_rf_core::relay`]),
+    /// replacing the flat `n*(n-1)/2` link estimate with actual relay-routed
+    /// coverage -- jamming reduces received power the same way it does for
+    /// any other link, so it naturally shrinks `relay_topology` instead of
+    /// scaling a placeholder count.
+    async fn step_connectivity(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let agents: Vec<(AgentId, Point3<f64>)> = self
+            .robots
+            .iter()
+            .enumerate()
+            .filter(|(_, robot)| !robot.destroyed)
+            .map(|(i, robot)| (i, robot.position))
+            .collect();
+        let candidate_relays: Vec<AgentId> = agents
+            .iter()
+            .filter(|(i, _)| {
+                matches!(
+                    self.robots[*i].role,
+                    RobotRole::Relay | RobotRole::Coordinator
+                )
+            })
+            .map(|(i, _)| *i)
+            .collect();
+
+        self.relay_topology = select_relays(
+            &self.rf_engine,
+            &agents,
+            &candidate_relays,
+            20e6,
+            RELAY_MIN_SNR_DB,
+        )
+        .await
+        .map_err(|e| -> Box<dyn std::error::Error> { e.into() })?;
+
+        let all_agents: Vec<AgentId> = agents.iter().map(|(id, _)| *id).collect();
+        self.mesh_accounting = compare_messaging_strategies(&self.relay_topology, &all_agents);
+        self.active_links = self.mesh_accounting.relayed_transmissions;
+
+        Ok(())
+    }
+
+    /// Advance every robot's ground-plane position one step under
+    /// [`FlockingController`], using SUMMONER's [`BroadPhase`] to find each
+    /// robot's neighbors within its interaction radius rather than scanning
+    /// every other robot.
+    fn step_flocking(&mut self, dt: f64) {
+        let agents: Vec<(usize, Vector3<f64>, f64)> = self
+            .robots
+            .iter()
+            .enumerate()
+            .map(|(i, robot)| (i, robot.position.coords, 12.5))
+            .collect();
+        self.broad_phase.update(&agents);
+
+        let mut neighbors_of: Vec<Vec<(f64, f64)>> = vec![Vec::new(); self.robots.len()];
+        for (a, b) in self.broad_phase.overlapping_pairs() {
+            neighbors_of[a].push((self.robots[b].position.x, self.robots[b].position.y));
+            neighbors_of[b].push((self.robots[a].position.x, self.robots[a].position.y));
+        }
+
+        for (robot, neighbors) in self.robots.iter_mut().zip(neighbors_of) {
+            let (vx, vy) = self.flocking.velocity_command(
+                robot.role.flock_role(),
+                (robot.position.x, robot.position.y),
+                &neighbors,
+            );
+            robot.velocity = Vector3::new(vx, vy, 0.0);
+            robot.position.x += vx * dt;
+            robot.position.y += vy * dt;
+        }
+    }
+
     /// Print status update
     fn print_status(&self, elapsed_time: f64) {
         let metrics = self.summoner.metrics();
@@ -391,8 +739,30 @@ impl UnrealRoboticSwarm {
         println!("  Relay (UAV):      {} (comms nodes)", relay);
         println!("  Coordinators:     {} (C2)\n", coordinators);
 
+        let returning = self
+            .state_machines
+            .iter()
+            .filter(|fsm| fsm.state() == RobotState::Return)
+            .count();
+        println!("ROBOT BEHAVIOR:");
+        println!("  Returning:        {} (low battery / jammed)\n", returning);
+
+        let destroyed = self.robots.iter().filter(|r| r.destroyed).count();
+        println!("COMBAT:");
+        println!("  Threats Remaining:{}", self.threats.len());
+        println!("  Robots Destroyed: {}\n", destroyed);
+
+        let all_agents: Vec<AgentId> = (0..self.robots.len()).collect();
+        let isolated = self.relay_topology.isolated(&all_agents).len();
+
         println!("RF COMMUNICATIONS:");
-        println!("  Active Links:     {}", self.active_links);
+        println!("  Relays:           {}", self.relay_topology.relays().len());
+        println!("  Relayed Msgs/Rnd: {}", self.active_links);
+        println!(
+            "  Naive Msgs/Rnd:   {}",
+            self.mesh_accounting.naive_transmissions
+        );
+        println!("  Isolated Robots:  {}", isolated);
         println!("  Frequency:        5.8 GHz (WiFi)");
         println!("  Model:            Friis Free-Space");
         println!(
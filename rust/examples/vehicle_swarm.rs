@@ -28,10 +28,12 @@ async fn main() -> anyhow::Result<()> {
     // Spawn multiple drones
     println!("Spawning vehicles...");
 
-    let drone_positions = [Point3::new(0.0, 0.0, 10.0),
+    let drone_positions = [
+        Point3::new(0.0, 0.0, 10.0),
         Point3::new(50.0, 0.0, 10.0),
         Point3::new(0.0, 50.0, 10.0),
-        Point3::new(50.0, 50.0, 10.0)];
+        Point3::new(50.0, 50.0, 10.0),
+    ];
 
     let mut vehicle_ids = Vec::new();
 
@@ -47,12 +49,20 @@ async fn main() -> anyhow::Result<()> {
                     sensor_type: SensorType::Imu,
                     update_rate_hz: 100.0,
                     enabled: true,
+                    lidar_config: None,
+                    radar_config: None,
+                    noise: None,
+                    fault: None,
                 },
                 SensorSpec {
                     sensor_id: "gps".to_string(),
                     sensor_type: SensorType::Gps,
                     update_rate_hz: 10.0,
                     enabled: true,
+                    lidar_config: None,
+                    radar_config: None,
+                    noise: None,
+                    fault: None,
                 },
             ],
         };
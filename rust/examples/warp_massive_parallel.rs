@@ -64,12 +64,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     sensor_type: SensorType::Imu,
                     update_rate_hz: 100.0,
                     enabled: true,
+                    lidar_config: None,
+                    radar_config: None,
+                    noise: None,
+                    fault: None,
                 },
                 SensorSpec {
                     sensor_id: "gps".to_string(),
                     sensor_type: SensorType::Gps,
                     update_rate_hz: 10.0,
                     enabled: true,
+                    lidar_config: None,
+                    radar_config: None,
+                    noise: None,
+                    fault: None,
                 },
             ],
         };